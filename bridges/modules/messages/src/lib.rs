@@ -85,6 +85,7 @@ pub mod weights;
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
 pub mod migration;
+pub mod view_functions;
 
 pub use call_ext::*;
 pub use pallet::*;
@@ -0,0 +1,89 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! View functions of the messages pallet.
+
+use crate::{Config, Pallet};
+
+use bp_messages::{InboundLaneData, OutboundLaneData};
+use bp_runtime::AccountIdOf;
+use codec::{Decode, Encode};
+use frame_support::view_functions::{
+	DispatchViewFunction, ViewFunction, ViewFunctionDispatchError, ViewFunctionId,
+};
+use sp_std::marker::PhantomData;
+
+/// Data of the given outbound lane, if the lane exists.
+#[derive(Encode, Decode)]
+pub struct OutboundLaneDataOf<T: Config<I>, I: 'static>(pub T::LaneId, PhantomData<I>);
+
+impl<T: Config<I>, I: 'static> OutboundLaneDataOf<T, I> {
+	/// Create a query for the given lane.
+	pub fn new(lane: T::LaneId) -> Self {
+		Self(lane, PhantomData)
+	}
+}
+
+impl<T: Config<I>, I: 'static> ViewFunction for OutboundLaneDataOf<T, I> {
+	type ReturnType = Option<OutboundLaneData>;
+
+	fn id() -> ViewFunctionId {
+		ViewFunctionId::new::<Pallet<T, I>>("outbound_lane_data")
+	}
+
+	fn invoke(self) -> Self::ReturnType {
+		Pallet::<T, I>::outbound_lane_data(self.0)
+	}
+}
+
+/// Data of the given inbound lane, if the lane exists.
+#[derive(Encode, Decode)]
+pub struct InboundLaneDataOf<T: Config<I>, I: 'static>(pub T::LaneId, PhantomData<I>);
+
+impl<T: Config<I>, I: 'static> InboundLaneDataOf<T, I> {
+	/// Create a query for the given lane.
+	pub fn new(lane: T::LaneId) -> Self {
+		Self(lane, PhantomData)
+	}
+}
+
+impl<T: Config<I>, I: 'static> ViewFunction for InboundLaneDataOf<T, I> {
+	type ReturnType = Option<InboundLaneData<AccountIdOf<crate::BridgedChainOf<T, I>>>>;
+
+	fn id() -> ViewFunctionId {
+		ViewFunctionId::new::<Pallet<T, I>>("inbound_lane_data")
+	}
+
+	fn invoke(self) -> Self::ReturnType {
+		Pallet::<T, I>::inbound_lane_data(self.0)
+	}
+}
+
+impl<T: Config<I>, I: 'static> DispatchViewFunction for Pallet<T, I> {
+	fn dispatch_view_function<O: codec::Output>(
+		id: &ViewFunctionId,
+		input: &mut &[u8],
+		output: &mut O,
+	) -> Result<(), ViewFunctionDispatchError> {
+		if *id == OutboundLaneDataOf::<T, I>::id() {
+			OutboundLaneDataOf::<T, I>::execute(input, output)
+		} else if *id == InboundLaneDataOf::<T, I>::id() {
+			InboundLaneDataOf::<T, I>::execute(input, output)
+		} else {
+			Err(ViewFunctionDispatchError::NotFound(*id))
+		}
+	}
+}
@@ -633,10 +633,37 @@ pub mod pallet {
 				"Invalid `LaneToBridge` configuration, probably missing or not removed laneId!"
 			);
 
+			// check that the deposits of all bridges are held.
+			Self::do_try_state_for_deposits()?;
+
 			// check connected `pallet_bridge_messages` state.
 			Self::do_try_state_for_messages()
 		}
 
+		/// Ensure that the deposit of every bridge is held from its owner.
+		pub fn do_try_state_for_deposits() -> Result<(), sp_runtime::TryRuntimeError> {
+			use frame_support::traits::fungible::InspectHold;
+			use sp_runtime::traits::Saturating;
+			use sp_std::collections::btree_map::BTreeMap;
+
+			let mut deposits = BTreeMap::<_, BalanceOf<ThisChainOf<T, I>>>::new();
+			for bridge in Bridges::<T, I>::iter_values() {
+				let deposit =
+					deposits.entry(bridge.bridge_owner_account).or_insert_with(Zero::zero);
+				*deposit = deposit.saturating_add(bridge.deposit);
+			}
+
+			for (bridge_owner_account, deposit) in deposits {
+				log::info!(target: LOG_TARGET, "Checking `do_try_state_for_deposits` for bridge_owner_account: {bridge_owner_account:?}...");
+				ensure!(
+					T::Currency::balance_on_hold(&HoldReason::BridgeDeposit.into(), &bridge_owner_account) == deposit,
+					"Held `BridgeDeposit` is different than the sum of the deposits of the bridges of the owner!"
+				);
+			}
+
+			Ok(())
+		}
+
 		/// Ensure the correctness of the state of the bridge.
 		pub fn do_try_state_for_bridge(
 			bridge_id: BridgeId,
@@ -1657,6 +1684,32 @@ mod tests {
 			assert!(lanes_manager.create_outbound_lane(lane_id).is_ok());
 			assert_err!(XcmOverBridge::do_try_state(), TryRuntimeError::Other("Found `LaneToBridge` inconsistency for `OutboundLanes`'s lane_id - missing mapping!"));
 			cleanup(bridge_id, vec![lane_id]);
+
+			// error - deposit is not held
+			test_bridge_state(
+				bridge_id,
+				Bridge {
+					bridge_origin_relative_location: Box::new(VersionedLocation::from(
+						bridge_origin_relative_location.clone(),
+					)),
+					bridge_origin_universal_location: Box::new(VersionedInteriorLocation::from(
+						bridge_origin_universal_location.clone(),
+					)),
+					bridge_destination_universal_location: Box::new(
+						VersionedInteriorLocation::from(
+							bridge_destination_universal_location.clone(),
+						),
+					),
+					state: BridgeState::Opened,
+					bridge_owner_account: bridge_owner_account.clone(),
+					deposit: BridgeDeposit::get(),
+					lane_id,
+				},
+				(lane_id, bridge_id),
+				(lane_id, lane_id),
+				Some(TryRuntimeError::Other("Held `BridgeDeposit` is different than the sum of the deposits of the bridges of the owner!")),
+			);
+			cleanup(bridge_id, vec![lane_id]);
 		});
 	}
 
@@ -140,6 +140,7 @@ impl pallet_message_queue::Config for Test {
 	type ServiceWeight = ServiceWeight;
 	type IdleMaxServiceWeight = ();
 	type QueuePausedQuery = ();
+	type ServiceWeightShare = ();
 }
 
 parameter_types! {
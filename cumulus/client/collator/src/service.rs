@@ -17,6 +17,7 @@
 //! The Cumulus [`CollatorService`] is a utility struct for performing common
 //! operations used in parachain consensus/authoring.
 
+use cumulus_client_diagnostics::InclusionDiagnostics;
 use cumulus_client_network::WaitToAnnounce;
 use cumulus_primitives_core::{CollationInfo, CollectCollationInfo, ParachainBlockData};
 
@@ -24,12 +25,15 @@ use sc_client_api::BlockBackend;
 use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_consensus::BlockStatus;
 use sp_core::traits::SpawnNamed;
-use sp_runtime::traits::{Block as BlockT, HashingFor, Header as HeaderT, Zero};
+use sp_runtime::traits::{
+	Block as BlockT, HashingFor, Header as HeaderT, NumberFor, UniqueSaturatedInto, Zero,
+};
 
 use cumulus_client_consensus_common::ParachainCandidate;
 use polkadot_node_primitives::{
 	BlockData, Collation, CollationSecondedSignal, MaybeCompressedPoV, PoV,
 };
+use polkadot_primitives::{BlockNumber as RelayBlockNumber, CoreIndex, Hash as PHash};
 
 use codec::Encode;
 use futures::channel::oneshot;
@@ -70,6 +74,20 @@ pub trait ServiceInterface<Block: BlockT> {
 
 	/// Directly announce a block on the network.
 	fn announce_block(&self, block_hash: Block::Hash, data: Option<Vec<u8>>);
+
+	/// Note a collation built on `relay_parent` for `core_index` in the inclusion diagnostics of
+	/// this collator, if it keeps any.
+	fn note_collation(
+		&self,
+		_block_hash: Block::Hash,
+		_block_number: NumberFor<Block>,
+		_collation: &Collation,
+		_relay_parent: PHash,
+		_relay_parent_number: RelayBlockNumber,
+		_max_pov_size: u32,
+		_core_index: CoreIndex,
+	) {
+	}
 }
 
 /// The [`CollatorService`] provides common utilities for parachain consensus and authoring.
@@ -82,6 +100,7 @@ pub struct CollatorService<Block: BlockT, BS, RA> {
 	wait_to_announce: Arc<Mutex<WaitToAnnounce<Block>>>,
 	announce_block: Arc<dyn Fn(Block::Hash, Option<Vec<u8>>) + Send + Sync>,
 	runtime_api: Arc<RA>,
+	inclusion_diagnostics: Option<InclusionDiagnostics<Block::Hash>>,
 }
 
 impl<Block: BlockT, BS, RA> Clone for CollatorService<Block, BS, RA> {
@@ -91,6 +110,7 @@ impl<Block: BlockT, BS, RA> Clone for CollatorService<Block, BS, RA> {
 			wait_to_announce: self.wait_to_announce.clone(),
 			announce_block: self.announce_block.clone(),
 			runtime_api: self.runtime_api.clone(),
+			inclusion_diagnostics: self.inclusion_diagnostics.clone(),
 		}
 	}
 }
@@ -112,7 +132,22 @@ where
 		let wait_to_announce =
 			Arc::new(Mutex::new(WaitToAnnounce::new(spawner, announce_block.clone())));
 
-		Self { block_status, wait_to_announce, announce_block, runtime_api }
+		Self {
+			block_status,
+			wait_to_announce,
+			announce_block,
+			runtime_api,
+			inclusion_diagnostics: None,
+		}
+	}
+
+	/// Note the collations built by this service in `inclusion_diagnostics`.
+	pub fn with_inclusion_diagnostics(
+		mut self,
+		inclusion_diagnostics: InclusionDiagnostics<Block::Hash>,
+	) -> Self {
+		self.inclusion_diagnostics = Some(inclusion_diagnostics);
+		self
 	}
 
 	/// Checks the status of the given block hash in the Parachain.
@@ -329,4 +364,31 @@ where
 	fn announce_block(&self, block_hash: Block::Hash, data: Option<Vec<u8>>) {
 		(self.announce_block)(block_hash, data)
 	}
+
+	fn note_collation(
+		&self,
+		block_hash: Block::Hash,
+		block_number: NumberFor<Block>,
+		collation: &Collation,
+		relay_parent: PHash,
+		relay_parent_number: RelayBlockNumber,
+		max_pov_size: u32,
+		core_index: CoreIndex,
+	) {
+		let Some(inclusion_diagnostics) = &self.inclusion_diagnostics else { return };
+
+		let pov_size = match &collation.proof_of_validity {
+			MaybeCompressedPoV::Raw(pov) | MaybeCompressedPoV::Compressed(pov) =>
+				pov.encoded_size(),
+		};
+		inclusion_diagnostics.note_collation(
+			block_hash,
+			block_number.unique_saturated_into(),
+			relay_parent,
+			relay_parent_number,
+			pov_size,
+			max_pov_size,
+			Some(core_index),
+		);
+	}
 }
@@ -117,18 +117,23 @@ where
 
 	/// Explicitly creates the inherent data for parachain block authoring and overrides
 	/// the timestamp inherent data with the one provided, if any.
+	///
+	/// The relay chain state proof additionally contains the `additional_relay_keys` requested
+	/// by the runtime.
 	pub async fn create_inherent_data(
 		&self,
 		relay_parent: PHash,
 		validation_data: &PersistedValidationData,
 		parent_hash: Block::Hash,
 		timestamp: impl Into<Option<Timestamp>>,
+		additional_relay_keys: Vec<Vec<u8>>,
 	) -> Result<(ParachainInherentData, InherentData), Box<dyn Error + Send + Sync + 'static>> {
 		let paras_inherent_data = ParachainInherentDataProvider::create_at(
 			relay_parent,
 			&self.relay_client,
 			validation_data,
 			self.para_id,
+			additional_relay_keys,
 		)
 		.await;
 
@@ -26,7 +26,9 @@ use cumulus_client_consensus_common::{
 	self as consensus_common, load_abridged_host_configuration, ParentSearchParams,
 };
 use cumulus_primitives_aura::{AuraUnincludedSegmentApi, Slot};
-use cumulus_primitives_core::{relay_chain::Hash as ParaHash, BlockT, ClaimQueueOffset};
+use cumulus_primitives_core::{
+	relay_chain::Hash as ParaHash, BlockT, ClaimQueueOffset, RelayStateKeysApi,
+};
 use cumulus_relay_chain_interface::RelayChainInterface;
 use polkadot_node_subsystem_util::runtime::ClaimQueueSnapshot;
 use polkadot_primitives::{
@@ -34,7 +36,7 @@ use polkadot_primitives::{
 	ValidationCodeHash,
 };
 use sc_consensus_aura::{standalone as aura_internal, AuraApi};
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_core::Pair;
 use sp_keystore::KeystorePtr;
 use sp_timestamp::Timestamp;
@@ -157,6 +159,45 @@ async fn cores_scheduled_for_para(
 		.collect()
 }
 
+/// Fetch the relay chain storage keys the runtime at `parent_hash` requests to be included in the
+/// relay chain state proof.
+///
+/// Returns no keys if the runtime does not support the [`RelayStateKeysApi`].
+fn relay_state_keys<Block: BlockT, Client>(
+	client: &Client,
+	parent_hash: Block::Hash,
+) -> Vec<Vec<u8>>
+where
+	Client: ProvideRuntimeApi<Block>,
+	Client::Api: RelayStateKeysApi<Block>,
+{
+	let runtime_api = client.runtime_api();
+
+	match runtime_api.has_api::<dyn RelayStateKeysApi<Block>>(parent_hash) {
+		Ok(true) => {},
+		Ok(false) => return Vec::new(),
+		Err(err) => {
+			tracing::debug!(
+				target: crate::LOG_TARGET,
+				?err,
+				?parent_hash,
+				"Failed to check for the relay state keys runtime API",
+			);
+			return Vec::new()
+		},
+	}
+
+	runtime_api.relay_state_keys(parent_hash).unwrap_or_else(|err| {
+		tracing::warn!(
+			target: crate::LOG_TARGET,
+			?err,
+			?parent_hash,
+			"Failed to fetch the relay state keys requested by the runtime",
+		);
+		Vec::new()
+	})
+}
+
 // Checks if we own the slot at the given block and whether there
 // is space in the unincluded segment.
 async fn can_build_upon<Block: BlockT, Client, P>(
@@ -20,7 +20,7 @@ use cumulus_client_collator::service::ServiceInterface as CollatorServiceInterfa
 use cumulus_client_consensus_common::{self as consensus_common, ParachainBlockImportMarker};
 use cumulus_client_consensus_proposer::ProposerInterface;
 use cumulus_primitives_aura::AuraUnincludedSegmentApi;
-use cumulus_primitives_core::{GetCoreSelectorApi, PersistedValidationData};
+use cumulus_primitives_core::{GetCoreSelectorApi, PersistedValidationData, RelayStateKeysApi};
 use cumulus_relay_chain_interface::RelayChainInterface;
 
 use polkadot_primitives::{
@@ -30,12 +30,13 @@ use polkadot_primitives::{
 };
 
 use futures::prelude::*;
+use prometheus_endpoint::Registry;
 use sc_client_api::{backend::AuxStore, BlockBackend, BlockOf, UsageProvider};
 use sc_consensus::BlockImport;
 use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_application_crypto::AppPublic;
 use sp_blockchain::HeaderBackend;
-use sp_consensus_aura::{AuraApi, Slot};
+use sp_consensus_aura::{AuraApi, Slot, SlotDuration};
 use sp_core::{crypto::Pair, U256};
 use sp_inherents::CreateInherentDataProviders;
 use sp_keystore::KeystorePtr;
@@ -43,7 +44,7 @@ use sp_runtime::traits::{Block as BlockT, Header as HeaderT, Member, One};
 use sp_timestamp::Timestamp;
 use std::{collections::BTreeSet, sync::Arc, time::Duration};
 
-use super::CollatorMessage;
+use super::{metrics::Metrics, CollatorMessage, CoreSelectionPolicy};
 use crate::{
 	collator::{self as collator_util},
 	collators::{check_validation_code_or_log, cores_scheduled_for_para},
@@ -95,12 +96,19 @@ pub struct BuilderTaskParams<
 	/// likelihood of encountering unfavorable notification arrival timings (i.e. we don't want to
 	/// wait for relay chain notifications because we woke up too early).
 	pub slot_drift: Duration,
+	/// Decides on which of the scheduled cores blocks are built.
+	pub core_selection_policy: Arc<dyn CoreSelectionPolicy>,
+	/// The slot duration of the relay chain.
+	pub relay_chain_slot_duration: Duration,
+	/// Prometheus registry to register the per-core metrics with.
+	pub prometheus_registry: Option<Registry>,
 }
 
 #[derive(Debug)]
 struct SlotInfo {
 	pub timestamp: Timestamp,
 	pub slot: Slot,
+	pub slot_duration: SlotDuration,
 }
 
 #[derive(Debug)]
@@ -151,7 +159,11 @@ where
 		let time_until_next_slot = time_until_next_slot(slot_duration.as_duration(), self.drift);
 		tokio::time::sleep(time_until_next_slot).await;
 		let timestamp = sp_timestamp::Timestamp::current();
-		Ok(SlotInfo { slot: Slot::from_timestamp(timestamp, slot_duration), timestamp })
+		Ok(SlotInfo {
+			slot: Slot::from_timestamp(timestamp, slot_duration),
+			timestamp,
+			slot_duration,
+		})
 	}
 }
 
@@ -170,8 +182,10 @@ where
 		+ Send
 		+ Sync
 		+ 'static,
-	Client::Api:
-		AuraApi<Block, P::Public> + GetCoreSelectorApi<Block> + AuraUnincludedSegmentApi<Block>,
+	Client::Api: AuraApi<Block, P::Public>
+		+ GetCoreSelectorApi<Block>
+		+ AuraUnincludedSegmentApi<Block>
+		+ RelayStateKeysApi<Block>,
 	Backend: sc_client_api::Backend<Block> + 'static,
 	RelayClient: RelayChainInterface + Clone + 'static,
 	CIDP: CreateInherentDataProviders<Block, ()> + 'static,
@@ -200,8 +214,19 @@ where
 			authoring_duration,
 			para_backend,
 			slot_drift,
+			core_selection_policy,
+			relay_chain_slot_duration,
+			prometheus_registry,
 		} = params;
 
+		let metrics = prometheus_registry.as_ref().and_then(|registry| {
+			Metrics::register(registry)
+				.map_err(|err| {
+					tracing::warn!(target: LOG_TARGET, ?err, "Failed to register slot-based collator metrics")
+				})
+				.ok()
+		});
+
 		let slot_timer = SlotTimer::<_, _, P>::new_with_drift(para_client.clone(), slot_drift);
 
 		let mut collator = {
@@ -266,6 +291,10 @@ where
 				continue;
 			};
 
+			if let Some(metrics) = &metrics {
+				metrics.on_scheduled_cores(scheduled_cores.len());
+			}
+
 			if scheduled_cores.is_empty() {
 				tracing::debug!(target: LOG_TARGET, "Parachain not scheduled, skipping slot.");
 				continue;
@@ -278,14 +307,29 @@ where
 				);
 			}
 
-			let core_selector = core_selector.0 as usize % scheduled_cores.len();
-			let Some(core_index) = scheduled_cores.get(core_selector) else {
-				// This cannot really happen, as we modulo the core selector with the
-				// scheduled_cores length and we check that the scheduled_cores is not empty.
-				continue;
+			// Only check once per relay parent.
+			if claimed_cores.is_empty() {
+				check_velocity(
+					scheduled_cores.len(),
+					relay_chain_slot_duration,
+					para_slot.slot_duration.as_duration(),
+				);
+			}
+
+			let Some(core_index) =
+				core_selection_policy.select_core(&core_selector, scheduled_cores, claimed_cores)
+			else {
+				tracing::debug!(
+					target: LOG_TARGET,
+					?core_selector,
+					?scheduled_cores,
+					?claimed_cores,
+					"No core selected at this relay chain slot, skipping slot."
+				);
+				continue
 			};
 
-			if !claimed_cores.insert(*core_index) {
+			if !claimed_cores.insert(core_index) {
 				tracing::debug!(
 					target: LOG_TARGET,
 					"Core {:?} was already claimed at this relay chain slot",
@@ -350,6 +394,7 @@ where
 					&validation_data,
 					parent_hash,
 					slot_claim.timestamp(),
+					crate::collators::relay_state_keys(&*para_client, parent_hash),
 				)
 				.await
 			{
@@ -397,10 +442,17 @@ where
 				)
 				.await
 			else {
-				tracing::error!(target: crate::LOG_TARGET, "Unable to build block at slot.");
+				tracing::error!(target: crate::LOG_TARGET, ?core_index, "Unable to build block at slot.");
+				if let Some(metrics) = &metrics {
+					metrics.on_block_build_failure(core_index);
+				}
 				continue;
 			};
 
+			if let Some(metrics) = &metrics {
+				metrics.on_block_built(core_index);
+			}
+
 			let new_block_hash = candidate.block.header().hash();
 
 			// Announce the newly built block to our peers.
@@ -408,10 +460,12 @@ where
 
 			if let Err(err) = collator_sender.unbounded_send(CollatorMessage {
 				relay_parent,
+				relay_parent_number: validation_data.relay_parent_number,
+				max_pov_size: validation_data.max_pov_size,
 				parent_header,
 				parachain_candidate: candidate,
 				validation_code_hash,
-				core_index: *core_index,
+				core_index,
 			}) {
 				tracing::error!(target: crate::LOG_TARGET, ?err, "Unable to send block to collation task.");
 				return
@@ -420,6 +474,29 @@ where
 	}
 }
 
+/// Check that enough parachain blocks can be built per relay chain block to make use of all
+/// `scheduled_cores` and warn if not.
+fn check_velocity(
+	scheduled_cores: usize,
+	relay_chain_slot_duration: Duration,
+	para_slot_duration: Duration,
+) {
+	let blocks_per_relay_block =
+		(relay_chain_slot_duration.as_millis() / para_slot_duration.as_millis().max(1)).max(1);
+
+	if scheduled_cores as u128 > blocks_per_relay_block {
+		tracing::warn!(
+			target: LOG_TARGET,
+			scheduled_cores,
+			blocks_per_relay_block,
+			?relay_chain_slot_duration,
+			?para_slot_duration,
+			"Parachain is scheduled on more cores than blocks can be built per relay chain block. \
+			 Some cores will stay unused, consider lowering the parachain slot duration."
+		);
+	}
+}
+
 /// Contains relay chain data necessary for parachain block building.
 #[derive(Clone)]
 struct RelayChainData {
@@ -93,6 +93,8 @@ async fn handle_collation_message<Block: BlockT>(
 		parachain_candidate,
 		validation_code_hash,
 		relay_parent,
+		relay_parent_number,
+		max_pov_size,
 		core_index,
 	} = message;
 
@@ -123,6 +125,16 @@ async fn handle_collation_message<Block: BlockT>(
 		);
 	}
 
+	collator_service.note_collation(
+		hash,
+		number,
+		&collation,
+		relay_parent,
+		relay_parent_number,
+		max_pov_size,
+		core_index,
+	);
+
 	tracing::debug!(target: LOG_TARGET, ?core_index, %hash, %number, "Submitting collation for core.");
 	overseer_handle
 		.send_msg(
@@ -0,0 +1,226 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Policies deciding on which of the scheduled cores the slot-based collator builds a block.
+
+use polkadot_primitives::{vstaging::CoreSelector, CoreIndex};
+use std::collections::BTreeSet;
+
+/// Decides on which of the scheduled cores the next block should be built.
+///
+/// When a parachain has more than one core assigned at a relay parent (elastic scaling), the
+/// collator builds one block per core. The policy is asked once per parachain slot.
+pub trait CoreSelectionPolicy: Send + Sync {
+	/// Select the core the next block should be built on.
+	///
+	/// - `core_selector`: The core selector returned by the runtime for the parent block.
+	/// - `scheduled_cores`: The cores the para is scheduled on at the claim queue offset.
+	/// - `claimed_cores`: The cores that already got a block built at the current relay parent.
+	///
+	/// Returning `None` skips block production for the current slot.
+	fn select_core(
+		&self,
+		core_selector: &CoreSelector,
+		scheduled_cores: &[CoreIndex],
+		claimed_cores: &BTreeSet<CoreIndex>,
+	) -> Option<CoreIndex>;
+}
+
+/// Selects the core based on the core selector provided by the runtime.
+///
+/// This is the default policy. It selects `core_selector % scheduled_cores.len()`, which ensures
+/// that all collators of the para pick the same core for a given parachain block. A core that
+/// was already claimed at the current relay parent is not selected again.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RuntimeCoreSelection;
+
+impl CoreSelectionPolicy for RuntimeCoreSelection {
+	fn select_core(
+		&self,
+		core_selector: &CoreSelector,
+		scheduled_cores: &[CoreIndex],
+		claimed_cores: &BTreeSet<CoreIndex>,
+	) -> Option<CoreIndex> {
+		if scheduled_cores.is_empty() {
+			return None
+		}
+
+		let core_index = scheduled_cores[core_selector.0 as usize % scheduled_cores.len()];
+		(!claimed_cores.contains(&core_index)).then_some(core_index)
+	}
+}
+
+/// Selects the first scheduled core that was not claimed yet at the current relay parent.
+///
+/// This ignores the core selector of the runtime and is therefore only suitable for parachains
+/// where a single collator builds all blocks of a relay parent.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FirstUnclaimedCore;
+
+impl CoreSelectionPolicy for FirstUnclaimedCore {
+	fn select_core(
+		&self,
+		_core_selector: &CoreSelector,
+		scheduled_cores: &[CoreIndex],
+		claimed_cores: &BTreeSet<CoreIndex>,
+	) -> Option<CoreIndex> {
+		scheduled_cores.iter().find(|core| !claimed_cores.contains(core)).copied()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use polkadot_node_subsystem_util::runtime::ClaimQueueSnapshot;
+	use polkadot_primitives::Id as ParaId;
+	use std::collections::{BTreeMap, VecDeque};
+
+	const PARA: ParaId = ParaId::new(1000);
+	const OTHER: ParaId = ParaId::new(2000);
+
+	fn cores(indices: &[u32]) -> Vec<CoreIndex> {
+		indices.iter().copied().map(CoreIndex).collect()
+	}
+
+	fn claimed(indices: &[u32]) -> BTreeSet<CoreIndex> {
+		indices.iter().copied().map(CoreIndex).collect()
+	}
+
+	/// Mirrors how the collator derives the scheduled cores from the claim queue.
+	fn scheduled_at(claim_queue: &ClaimQueueSnapshot, offset: usize) -> Vec<CoreIndex> {
+		claim_queue
+			.iter_claims_at_depth(offset)
+			.filter_map(|(core_index, para_id)| (para_id == PARA).then_some(core_index))
+			.collect()
+	}
+
+	fn claim_queue() -> ClaimQueueSnapshot {
+		let mut claim_queue = BTreeMap::new();
+		claim_queue.insert(CoreIndex(0), VecDeque::from([PARA, OTHER]));
+		claim_queue.insert(CoreIndex(1), VecDeque::from([OTHER, PARA]));
+		claim_queue.insert(CoreIndex(2), VecDeque::from([PARA, PARA]));
+		ClaimQueueSnapshot::from(claim_queue)
+	}
+
+	#[test]
+	fn runtime_core_selection_at_claim_queue_offset_zero() {
+		let scheduled = scheduled_at(&claim_queue(), 0);
+		assert_eq!(scheduled, cores(&[0, 2]));
+
+		let policy = RuntimeCoreSelection;
+		assert_eq!(
+			policy.select_core(&CoreSelector(0), &scheduled, &claimed(&[])),
+			Some(CoreIndex(0))
+		);
+		assert_eq!(
+			policy.select_core(&CoreSelector(1), &scheduled, &claimed(&[])),
+			Some(CoreIndex(2))
+		);
+		// The selected core was already claimed, the policy must not fall back to another one.
+		assert_eq!(policy.select_core(&CoreSelector(0), &scheduled, &claimed(&[0])), None);
+
+		// A different offset yields a different assignment.
+		let scheduled = scheduled_at(&claim_queue(), 1);
+		assert_eq!(scheduled, cores(&[1, 2]));
+		assert_eq!(
+			policy.select_core(&CoreSelector(0), &scheduled, &claimed(&[])),
+			Some(CoreIndex(1))
+		);
+	}
+
+	#[test]
+	fn runtime_core_selection_wraps_around() {
+		let scheduled = cores(&[3, 5, 7]);
+		let policy = RuntimeCoreSelection;
+
+		assert_eq!(
+			policy.select_core(&CoreSelector(2), &scheduled, &claimed(&[])),
+			Some(CoreIndex(7))
+		);
+		assert_eq!(
+			policy.select_core(&CoreSelector(3), &scheduled, &claimed(&[])),
+			Some(CoreIndex(3))
+		);
+		assert_eq!(
+			policy.select_core(&CoreSelector(4), &scheduled, &claimed(&[])),
+			Some(CoreIndex(5))
+		);
+		// 255 % 3 == 0
+		assert_eq!(
+			policy.select_core(&CoreSelector(u8::MAX), &scheduled, &claimed(&[])),
+			Some(CoreIndex(3))
+		);
+		assert_eq!(policy.select_core(&CoreSelector(u8::MAX), &scheduled, &claimed(&[3])), None);
+	}
+
+	#[test]
+	fn runtime_core_selection_without_assignment() {
+		let policy = RuntimeCoreSelection;
+
+		assert_eq!(policy.select_core(&CoreSelector(0), &[], &claimed(&[])), None);
+		assert_eq!(policy.select_core(&CoreSelector(u8::MAX), &[], &claimed(&[])), None);
+
+		let empty = ClaimQueueSnapshot::from(BTreeMap::new());
+		assert_eq!(
+			policy.select_core(&CoreSelector(0), &scheduled_at(&empty, 0), &claimed(&[])),
+			None
+		);
+	}
+
+	#[test]
+	fn first_unclaimed_core_at_claim_queue_offset_zero() {
+		let scheduled = scheduled_at(&claim_queue(), 0);
+		let policy = FirstUnclaimedCore;
+
+		assert_eq!(
+			policy.select_core(&CoreSelector(1), &scheduled, &claimed(&[])),
+			Some(CoreIndex(0))
+		);
+		assert_eq!(
+			policy.select_core(&CoreSelector(0), &scheduled, &claimed(&[0])),
+			Some(CoreIndex(2))
+		);
+		assert_eq!(policy.select_core(&CoreSelector(0), &scheduled, &claimed(&[0, 2])), None);
+	}
+
+	#[test]
+	fn first_unclaimed_core_ignores_selector_wrap_around() {
+		let scheduled = cores(&[3, 5, 7]);
+		let policy = FirstUnclaimedCore;
+
+		assert_eq!(
+			policy.select_core(&CoreSelector(u8::MAX), &scheduled, &claimed(&[])),
+			Some(CoreIndex(3))
+		);
+		assert_eq!(
+			policy.select_core(&CoreSelector(4), &scheduled, &claimed(&[3, 5])),
+			Some(CoreIndex(7))
+		);
+	}
+
+	#[test]
+	fn first_unclaimed_core_without_assignment() {
+		let policy = FirstUnclaimedCore;
+
+		assert_eq!(policy.select_core(&CoreSelector(0), &[], &claimed(&[])), None);
+
+		let empty = ClaimQueueSnapshot::from(BTreeMap::new());
+		assert_eq!(
+			policy.select_core(&CoreSelector(0), &scheduled_at(&empty, 0), &claimed(&[])),
+			None
+		);
+	}
+}
@@ -0,0 +1,77 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Prometheus metrics of the slot-based collator.
+
+use polkadot_primitives::CoreIndex;
+use prometheus_endpoint::{register, CounterVec, Gauge, Opts, PrometheusError, Registry, U64};
+
+/// Metrics of the slot-based block-builder task.
+#[derive(Clone)]
+pub(crate) struct Metrics {
+	/// Number of cores the para is scheduled on at the latest relay parent.
+	scheduled_cores: Gauge<U64>,
+	/// Number of blocks built, per core.
+	blocks_built: CounterVec<U64>,
+	/// Number of blocks that failed to be built, per core.
+	block_build_failures: CounterVec<U64>,
+}
+
+impl Metrics {
+	pub(crate) fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			scheduled_cores: register(
+				Gauge::new(
+					"cumulus_collator_scheduled_cores",
+					"Number of cores the parachain is scheduled on at the latest relay parent",
+				)?,
+				registry,
+			)?,
+			blocks_built: register(
+				CounterVec::new(
+					Opts::new(
+						"cumulus_collator_blocks_built",
+						"Number of parachain blocks built per core",
+					),
+					&["core_index"],
+				)?,
+				registry,
+			)?,
+			block_build_failures: register(
+				CounterVec::new(
+					Opts::new(
+						"cumulus_collator_block_build_failures",
+						"Number of parachain blocks that failed to be built per core",
+					),
+					&["core_index"],
+				)?,
+				registry,
+			)?,
+		})
+	}
+
+	pub(crate) fn on_scheduled_cores(&self, num_cores: usize) {
+		self.scheduled_cores.set(num_cores as u64);
+	}
+
+	pub(crate) fn on_block_built(&self, core_index: CoreIndex) {
+		self.blocks_built.with_label_values(&[&core_index.0.to_string()]).inc();
+	}
+
+	pub(crate) fn on_block_build_failure(&self, core_index: CoreIndex) {
+		self.block_build_failures.with_label_values(&[&core_index.0.to_string()]).inc();
+	}
+}
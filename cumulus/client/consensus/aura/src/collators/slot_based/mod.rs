@@ -27,6 +27,10 @@
 //! chain block. The collator implementation then expects that we have that many cores scheduled
 //! during the relay chain block. After the block is built, the block builder task sends it to
 //! the collation task which compresses it and submits it to the collation-generation subsystem.
+//!
+//! When the para is scheduled on multiple cores at a relay parent (elastic scaling), one block is
+//! built per core. The core for each block is picked by a [`CoreSelectionPolicy`], by default
+//! [`RuntimeCoreSelection`].
 
 use codec::Codec;
 use consensus_common::ParachainCandidate;
@@ -34,12 +38,14 @@ use cumulus_client_collator::service::ServiceInterface as CollatorServiceInterfa
 use cumulus_client_consensus_common::{self as consensus_common, ParachainBlockImportMarker};
 use cumulus_client_consensus_proposer::ProposerInterface;
 use cumulus_primitives_aura::AuraUnincludedSegmentApi;
-use cumulus_primitives_core::GetCoreSelectorApi;
+use cumulus_primitives_core::{GetCoreSelectorApi, RelayStateKeysApi};
 use cumulus_relay_chain_interface::RelayChainInterface;
 use polkadot_primitives::{
-	CollatorPair, CoreIndex, Hash as RelayHash, Id as ParaId, ValidationCodeHash,
+	BlockNumber as RelayBlockNumber, CollatorPair, CoreIndex, Hash as RelayHash, Id as ParaId,
+	ValidationCodeHash,
 };
 
+use prometheus_endpoint::Registry;
 use sc_client_api::{backend::AuxStore, BlockBackend, BlockOf, UsageProvider};
 use sc_consensus::BlockImport;
 use sc_utils::mpsc::tracing_unbounded;
@@ -57,8 +63,12 @@ use std::{sync::Arc, time::Duration};
 
 use self::{block_builder_task::run_block_builder, collation_task::run_collation_task};
 
+pub use core_selection::{CoreSelectionPolicy, FirstUnclaimedCore, RuntimeCoreSelection};
+
 mod block_builder_task;
 mod collation_task;
+mod core_selection;
+mod metrics;
 
 /// Parameters for [`run`].
 pub struct Params<BI, CIDP, Client, Backend, RClient, CHP, Proposer, CS> {
@@ -93,6 +103,13 @@ pub struct Params<BI, CIDP, Client, Backend, RClient, CHP, Proposer, CS> {
 	/// Drift slots by a fixed duration. This can be used to create more preferrable authoring
 	/// timings.
 	pub slot_drift: Duration,
+	/// Decides on which of the scheduled cores blocks are built.
+	pub core_selection_policy: Arc<dyn CoreSelectionPolicy>,
+	/// The slot duration of the relay chain, used to check that enough blocks can be built to
+	/// make use of all scheduled cores.
+	pub relay_chain_slot_duration: Duration,
+	/// Prometheus registry to register the per-core metrics with.
+	pub prometheus_registry: Option<Registry>,
 }
 
 /// Run aura-based block building and collation task.
@@ -110,8 +127,10 @@ where
 		+ Send
 		+ Sync
 		+ 'static,
-	Client::Api:
-		AuraApi<Block, P::Public> + GetCoreSelectorApi<Block> + AuraUnincludedSegmentApi<Block>,
+	Client::Api: AuraApi<Block, P::Public>
+		+ GetCoreSelectorApi<Block>
+		+ AuraUnincludedSegmentApi<Block>
+		+ RelayStateKeysApi<Block>,
 	Backend: sc_client_api::Backend<Block> + 'static,
 	RClient: RelayChainInterface + Clone + 'static,
 	CIDP: CreateInherentDataProviders<Block, ()> + 'static,
@@ -150,6 +169,9 @@ where
 		authoring_duration: params.authoring_duration,
 		collator_sender: tx,
 		slot_drift: params.slot_drift,
+		core_selection_policy: params.core_selection_policy,
+		relay_chain_slot_duration: params.relay_chain_slot_duration,
+		prometheus_registry: params.prometheus_registry,
 	};
 
 	let block_builder_fut =
@@ -164,6 +186,10 @@ where
 struct CollatorMessage<Block: BlockT> {
 	/// The hash of the relay chain block that provides the context for the parachain block.
 	pub relay_parent: RelayHash,
+	/// The number of the relay parent.
+	pub relay_parent_number: RelayBlockNumber,
+	/// The maximum size of the proof of validity accepted at the relay parent.
+	pub max_pov_size: u32,
 	/// The header of the parent block.
 	pub parent_header: Block::Header,
 	/// The parachain block candidate.
@@ -0,0 +1,447 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Diagnostics explaining why parachain blocks were not backed or included by the relay chain.
+//!
+//! Collators report the blocks they built via [`InclusionDiagnostics::note_collation`]. The task
+//! returned by [`run_inclusion_diagnostics`] follows the best relay chain blocks and records for
+//! every reported block whether it got backed and included, or the most likely reason why it was
+//! not. The records are exposed to node operators via the `parachain_diagnostics` RPC, see
+//! [`rpc`].
+
+use std::{collections::VecDeque, sync::Arc};
+
+use codec::Decode;
+use futures::StreamExt;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use cumulus_relay_chain_interface::{
+	BlockNumber as RelayBlockNumber, CoreIndex, OccupiedCoreAssumption, PHash, PHeader, ParaId,
+	RelayChainInterface, RelayChainResult,
+};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, UniqueSaturatedInto};
+
+pub mod rpc;
+
+const LOG_TARGET: &str = "parachain::inclusion-diagnostics";
+
+/// The reason why a parachain block was not included by the relay chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FailureReason {
+	/// No core was assigned to the parachain when the block was built.
+	NoCoreAssigned,
+	/// The proof of validity exceeded the maximum size accepted by the relay chain.
+	PovTooLarge,
+	/// The block was not backed before its relay parent left the allowed ancestry.
+	RelayParentTooOld,
+	/// The block was not backed, although its relay parent was still recent enough.
+	NotBacked,
+	/// The block was backed, but never became available, e.g. because of missing bitfields.
+	NotAvailable,
+}
+
+/// The inclusion status of a parachain block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum InclusionStatus {
+	/// The block was built, but not seen on the relay chain yet.
+	Pending,
+	/// The block was backed on the relay chain.
+	Backed {
+		/// The number of the relay chain block the candidate was first seen backed in.
+		relay_block_number: RelayBlockNumber,
+	},
+	/// The block was included by the relay chain.
+	Included {
+		/// The number of the relay chain block the inclusion was first seen in.
+		relay_block_number: RelayBlockNumber,
+	},
+	/// The block will not be included.
+	Failed {
+		/// The most likely reason for the failure.
+		reason: FailureReason,
+	},
+}
+
+impl InclusionStatus {
+	/// Whether the status can still change.
+	fn is_final(&self) -> bool {
+		matches!(self, Self::Included { .. } | Self::Failed { .. })
+	}
+}
+
+/// Diagnostics of a single parachain block built by this node.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockDiagnostics<Hash> {
+	/// Hash of the parachain block.
+	pub block_hash: Hash,
+	/// Number of the parachain block.
+	pub block_number: u64,
+	/// The relay parent the block was built on.
+	pub relay_parent: PHash,
+	/// The number of the relay parent.
+	pub relay_parent_number: RelayBlockNumber,
+	/// Size of the proof of validity in bytes.
+	pub pov_size: u32,
+	/// Maximum size of the proof of validity accepted at the relay parent.
+	pub max_pov_size: u32,
+	/// The core the block was built for, if any.
+	pub core_index: Option<u32>,
+	/// The inclusion status of the block.
+	pub status: InclusionStatus,
+}
+
+/// Configuration of the [`InclusionDiagnostics`].
+#[derive(Clone, Copy, Debug)]
+pub struct DiagnosticsConfig {
+	/// Maximum number of blocks to keep diagnostics for.
+	pub max_records: usize,
+	/// Number of relay chain blocks a relay parent can be used for backing.
+	pub allowed_ancestry_len: RelayBlockNumber,
+	/// Number of relay chain blocks a backed candidate may wait to become available.
+	pub availability_timeout: RelayBlockNumber,
+}
+
+impl Default for DiagnosticsConfig {
+	fn default() -> Self {
+		Self { max_records: 256, allowed_ancestry_len: 3, availability_timeout: 10 }
+	}
+}
+
+struct Records<Hash> {
+	config: DiagnosticsConfig,
+	blocks: VecDeque<BlockDiagnostics<Hash>>,
+}
+
+/// A shared handle to the inclusion diagnostics of the blocks built by this node.
+pub struct InclusionDiagnostics<Hash> {
+	records: Arc<Mutex<Records<Hash>>>,
+}
+
+impl<Hash> Clone for InclusionDiagnostics<Hash> {
+	fn clone(&self) -> Self {
+		Self { records: self.records.clone() }
+	}
+}
+
+impl<Hash: Clone + PartialEq> InclusionDiagnostics<Hash> {
+	/// Create new, empty diagnostics.
+	pub fn new(config: DiagnosticsConfig) -> Self {
+		Self {
+			records: Arc::new(Mutex::new(Records {
+				config,
+				blocks: VecDeque::with_capacity(config.max_records),
+			})),
+		}
+	}
+
+	/// Note a block built by this node.
+	///
+	/// `core_index` is `None` if no core was assigned to the parachain at the relay parent.
+	pub fn note_collation(
+		&self,
+		block_hash: Hash,
+		block_number: u64,
+		relay_parent: PHash,
+		relay_parent_number: RelayBlockNumber,
+		pov_size: usize,
+		max_pov_size: u32,
+		core_index: Option<CoreIndex>,
+	) {
+		let pov_size = pov_size.try_into().unwrap_or(u32::MAX);
+		let status = if core_index.is_none() {
+			InclusionStatus::Failed { reason: FailureReason::NoCoreAssigned }
+		} else if pov_size > max_pov_size {
+			InclusionStatus::Failed { reason: FailureReason::PovTooLarge }
+		} else {
+			InclusionStatus::Pending
+		};
+
+		let mut records = self.records.lock();
+		if records.blocks.iter().any(|block| block.block_hash == block_hash) {
+			return
+		}
+		if records.blocks.len() >= records.config.max_records {
+			records.blocks.pop_front();
+		}
+
+		records.blocks.push_back(BlockDiagnostics {
+			block_hash,
+			block_number,
+			relay_parent,
+			relay_parent_number,
+			pov_size,
+			max_pov_size,
+			core_index: core_index.map(|core_index| core_index.0),
+			status,
+		});
+	}
+
+	/// The diagnostics of the `count` most recently built blocks, newest first.
+	pub fn recent(&self, count: usize) -> Vec<BlockDiagnostics<Hash>> {
+		self.records.lock().blocks.iter().rev().take(count).cloned().collect()
+	}
+
+	/// The diagnostics of a specific block, if it is still known.
+	pub fn block(&self, block_hash: &Hash) -> Option<BlockDiagnostics<Hash>> {
+		self.records
+			.lock()
+			.blocks
+			.iter()
+			.find(|block| &block.block_hash == block_hash)
+			.cloned()
+	}
+
+	/// Update the status of all pending blocks with the state of a new best relay chain block.
+	///
+	/// `backed` are the parachain blocks pending availability and `included` is the most recent
+	/// included parachain block, if known.
+	fn note_relay_block(
+		&self,
+		relay_block_number: RelayBlockNumber,
+		backed: &[Hash],
+		included: Option<(Hash, u64)>,
+	) {
+		let mut records = self.records.lock();
+		let config = records.config;
+
+		for block in records.blocks.iter_mut().filter(|block| !block.status.is_final()) {
+			let relay_parent_expired = relay_block_number >
+				block.relay_parent_number.saturating_add(config.allowed_ancestry_len);
+
+			block.status = match (block.status, &included) {
+				(_, Some((hash, _))) if hash == &block.block_hash =>
+					InclusionStatus::Included { relay_block_number },
+				// Another block at the same height or above was included, so this one never will.
+				(InclusionStatus::Backed { .. }, Some((_, number)))
+					if *number >= block.block_number =>
+					InclusionStatus::Failed { reason: FailureReason::NotAvailable },
+				(InclusionStatus::Pending, Some((_, number))) if *number >= block.block_number =>
+					InclusionStatus::Failed {
+						reason: if relay_parent_expired {
+							FailureReason::RelayParentTooOld
+						} else {
+							FailureReason::NotBacked
+						},
+					},
+				(InclusionStatus::Pending, _) if backed.contains(&block.block_hash) =>
+					InclusionStatus::Backed { relay_block_number },
+				(InclusionStatus::Pending, _) if relay_parent_expired =>
+					InclusionStatus::Failed { reason: FailureReason::RelayParentTooOld },
+				(InclusionStatus::Backed { relay_block_number: backed_at }, _)
+					if relay_block_number >
+						backed_at.saturating_add(config.availability_timeout) =>
+					InclusionStatus::Failed { reason: FailureReason::NotAvailable },
+				(status, _) => status,
+			};
+		}
+	}
+}
+
+/// Follow the best relay chain blocks and update the status of the blocks noted in
+/// `diagnostics`.
+pub async fn run_inclusion_diagnostics<Block: BlockT>(
+	para_id: ParaId,
+	relay_chain_interface: impl RelayChainInterface,
+	diagnostics: InclusionDiagnostics<Block::Hash>,
+) {
+	let mut best_relay_blocks = match relay_chain_interface.new_best_notification_stream().await {
+		Ok(stream) => stream,
+		Err(err) => {
+			tracing::error!(
+				target: LOG_TARGET,
+				?err,
+				"Failed to subscribe to best relay chain blocks, inclusion diagnostics disabled.",
+			);
+			return
+		},
+	};
+
+	while let Some(relay_header) = best_relay_blocks.next().await {
+		if let Err(err) = follow_relay_block::<Block>(
+			para_id,
+			&relay_chain_interface,
+			&diagnostics,
+			&relay_header,
+		)
+		.await
+		{
+			tracing::debug!(
+				target: LOG_TARGET,
+				?err,
+				relay_block = ?relay_header.hash(),
+				"Failed to fetch inclusion state from the relay chain.",
+			);
+		}
+	}
+}
+
+async fn follow_relay_block<Block: BlockT>(
+	para_id: ParaId,
+	relay_chain_interface: &impl RelayChainInterface,
+	diagnostics: &InclusionDiagnostics<Block::Hash>,
+	relay_header: &PHeader,
+) -> RelayChainResult<()> {
+	let relay_hash = relay_header.hash();
+
+	let backed = relay_chain_interface
+		.candidates_pending_availability(relay_hash, para_id)
+		.await?
+		.into_iter()
+		.filter_map(|candidate| {
+			Block::Header::decode(&mut &candidate.commitments.head_data.0[..])
+				.ok()
+				.map(|header| header.hash())
+		})
+		.collect::<Vec<_>>();
+
+	// Assuming that all candidates pending availability time out yields the included head.
+	let included = relay_chain_interface
+		.persisted_validation_data(relay_hash, para_id, OccupiedCoreAssumption::TimedOut)
+		.await?
+		.and_then(|validation_data| {
+			Block::Header::decode(&mut &validation_data.parent_head.0[..]).ok()
+		})
+		.map(|header| (header.hash(), (*header.number()).unique_saturated_into()));
+
+	diagnostics.note_relay_block(relay_header.number, &backed, included);
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::H256;
+
+	fn diagnostics() -> InclusionDiagnostics<H256> {
+		InclusionDiagnostics::new(DiagnosticsConfig {
+			max_records: 4,
+			allowed_ancestry_len: 2,
+			availability_timeout: 3,
+		})
+	}
+
+	fn note(diagnostics: &InclusionDiagnostics<H256>, block: u8, relay_parent_number: u32) {
+		diagnostics.note_collation(
+			H256::repeat_byte(block),
+			block as u64,
+			PHash::repeat_byte(relay_parent_number as u8),
+			relay_parent_number,
+			1024,
+			2048,
+			Some(CoreIndex(0)),
+		);
+	}
+
+	fn status(diagnostics: &InclusionDiagnostics<H256>, block: u8) -> InclusionStatus {
+		diagnostics.block(&H256::repeat_byte(block)).unwrap().status
+	}
+
+	#[test]
+	fn blocks_failing_at_collation_are_reported() {
+		let diagnostics = diagnostics();
+		diagnostics.note_collation(H256::repeat_byte(1), 1, PHash::zero(), 10, 1024, 2048, None);
+		diagnostics.note_collation(
+			H256::repeat_byte(2),
+			2,
+			PHash::zero(),
+			10,
+			4096,
+			2048,
+			Some(CoreIndex(0)),
+		);
+
+		assert_eq!(
+			status(&diagnostics, 1),
+			InclusionStatus::Failed { reason: FailureReason::NoCoreAssigned }
+		);
+		assert_eq!(
+			status(&diagnostics, 2),
+			InclusionStatus::Failed { reason: FailureReason::PovTooLarge }
+		);
+	}
+
+	#[test]
+	fn block_is_backed_then_included() {
+		let diagnostics = diagnostics();
+		note(&diagnostics, 1, 10);
+
+		diagnostics.note_relay_block(11, &[H256::repeat_byte(1)], Some((H256::zero(), 0)));
+		assert_eq!(status(&diagnostics, 1), InclusionStatus::Backed { relay_block_number: 11 });
+
+		diagnostics.note_relay_block(12, &[], Some((H256::repeat_byte(1), 1)));
+		assert_eq!(status(&diagnostics, 1), InclusionStatus::Included { relay_block_number: 12 });
+	}
+
+	#[test]
+	fn unbacked_block_fails_once_relay_parent_is_too_old() {
+		let diagnostics = diagnostics();
+		note(&diagnostics, 1, 10);
+
+		diagnostics.note_relay_block(12, &[], None);
+		assert_eq!(status(&diagnostics, 1), InclusionStatus::Pending);
+
+		diagnostics.note_relay_block(13, &[], None);
+		assert_eq!(
+			status(&diagnostics, 1),
+			InclusionStatus::Failed { reason: FailureReason::RelayParentTooOld }
+		);
+	}
+
+	#[test]
+	fn backed_block_fails_if_never_available() {
+		let diagnostics = diagnostics();
+		note(&diagnostics, 1, 10);
+
+		diagnostics.note_relay_block(11, &[H256::repeat_byte(1)], None);
+		diagnostics.note_relay_block(14, &[H256::repeat_byte(1)], None);
+		assert_eq!(status(&diagnostics, 1), InclusionStatus::Backed { relay_block_number: 11 });
+
+		diagnostics.note_relay_block(15, &[], None);
+		assert_eq!(
+			status(&diagnostics, 1),
+			InclusionStatus::Failed { reason: FailureReason::NotAvailable }
+		);
+	}
+
+	#[test]
+	fn competing_block_inclusion_fails_unbacked_block() {
+		let diagnostics = diagnostics();
+		note(&diagnostics, 1, 10);
+
+		diagnostics.note_relay_block(11, &[], Some((H256::repeat_byte(0xff), 1)));
+		assert_eq!(
+			status(&diagnostics, 1),
+			InclusionStatus::Failed { reason: FailureReason::NotBacked }
+		);
+	}
+
+	#[test]
+	fn only_recent_blocks_are_kept() {
+		let diagnostics = diagnostics();
+		for block in 1..=6 {
+			note(&diagnostics, block, 10);
+		}
+
+		let recent = diagnostics.recent(10);
+		assert_eq!(recent.len(), 4);
+		assert_eq!(recent[0].block_hash, H256::repeat_byte(6));
+		assert_eq!(recent[3].block_hash, H256::repeat_byte(3));
+	}
+}
@@ -0,0 +1,55 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC exposing the [`InclusionDiagnostics`] of a parachain node.
+
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{BlockDiagnostics, InclusionDiagnostics};
+
+/// Number of blocks returned if the caller doesn't specify a count.
+const DEFAULT_COUNT: u32 = 16;
+
+/// Parachain inclusion diagnostics RPC methods.
+#[rpc(client, server)]
+pub trait ParachainDiagnosticsApi<Hash> {
+	/// Returns the inclusion diagnostics of the most recent parachain blocks built by this node,
+	/// newest first.
+	#[method(name = "parachain_diagnostics")]
+	fn diagnostics(&self, count: Option<u32>) -> RpcResult<Vec<BlockDiagnostics<Hash>>>;
+}
+
+/// Implements the [`ParachainDiagnosticsApiServer`] RPC trait.
+pub struct ParachainDiagnostics<Hash> {
+	diagnostics: InclusionDiagnostics<Hash>,
+}
+
+impl<Hash> ParachainDiagnostics<Hash> {
+	/// Create a new instance serving the given `diagnostics`.
+	pub fn new(diagnostics: InclusionDiagnostics<Hash>) -> Self {
+		Self { diagnostics }
+	}
+}
+
+impl<Hash> ParachainDiagnosticsApiServer<Hash> for ParachainDiagnostics<Hash>
+where
+	Hash: Clone + PartialEq + Send + Sync + Serialize + DeserializeOwned + 'static,
+{
+	fn diagnostics(&self, count: Option<u32>) -> RpcResult<Vec<BlockDiagnostics<Hash>>> {
+		Ok(self.diagnostics.recent(count.unwrap_or(DEFAULT_COUNT) as usize))
+	}
+}
@@ -36,6 +36,7 @@ async fn collect_relay_storage_proof(
 	relay_chain_interface: &impl RelayChainInterface,
 	para_id: ParaId,
 	relay_parent: PHash,
+	additional_relay_keys: Vec<Vec<u8>>,
 ) -> Option<sp_state_machine::StorageProof> {
 	use relay_chain::well_known_keys as relay_well_known_keys;
 
@@ -121,6 +122,7 @@ async fn collect_relay_storage_proof(
 	relevant_keys.extend(egress_channels.into_iter().map(|recipient| {
 		relay_well_known_keys::hrmp_channels(HrmpChannelId { sender: para_id, recipient })
 	}));
+	relevant_keys.extend(additional_relay_keys);
 
 	relay_chain_interface
 		.prove_read(relay_parent, &relevant_keys)
@@ -141,15 +143,25 @@ pub struct ParachainInherentDataProvider;
 impl ParachainInherentDataProvider {
 	/// Create the [`ParachainInherentData`] at the given `relay_parent`.
 	///
+	/// The relay chain state proof contains the keys required by `parachain-system` and the
+	/// given `additional_relay_keys`, usually requested by the runtime through the
+	/// [`RelayStateKeysApi`](cumulus_primitives_core::RelayStateKeysApi).
+	///
 	/// Returns `None` if the creation failed.
 	pub async fn create_at(
 		relay_parent: PHash,
 		relay_chain_interface: &impl RelayChainInterface,
 		validation_data: &PersistedValidationData,
 		para_id: ParaId,
+		additional_relay_keys: Vec<Vec<u8>>,
 	) -> Option<ParachainInherentData> {
-		let relay_chain_state =
-			collect_relay_storage_proof(relay_chain_interface, para_id, relay_parent).await?;
+		let relay_chain_state = collect_relay_storage_proof(
+			relay_chain_interface,
+			para_id,
+			relay_parent,
+			additional_relay_keys,
+		)
+		.await?;
 
 		let downward_messages = relay_chain_interface
 			.retrieve_dmq_contents(para_id, relay_parent)
@@ -23,7 +23,7 @@ use polkadot_network_bridge::{peer_sets_info, IsAuthority};
 use polkadot_node_network_protocol::{
 	peer_set::{PeerSet, PeerSetProtocolNames},
 	request_response::{
-		v1, v2, IncomingRequest, IncomingRequestReceiver, Protocol, ReqProtocolNames,
+		v1, v2, v3, IncomingRequest, IncomingRequestReceiver, Protocol, ReqProtocolNames,
 	},
 };
 
@@ -217,8 +217,12 @@ async fn new_minimal_relay_chain<Block: BlockT, Network: NetworkBackend<RelayBlo
 	.collect::<std::collections::HashMap<PeerSet, Box<dyn sc_network::NotificationService>>>();
 
 	let request_protocol_names = ReqProtocolNames::new(genesis_hash, config.chain_spec.fork_id());
-	let (collation_req_v1_receiver, collation_req_v2_receiver, available_data_req_receiver) =
-		build_request_response_protocol_receivers(&request_protocol_names, &mut net_config);
+	let (
+		collation_req_v1_receiver,
+		collation_req_v2_receiver,
+		collation_req_v3_receiver,
+		available_data_req_receiver,
+	) = build_request_response_protocol_receivers(&request_protocol_names, &mut net_config);
 
 	let best_header = relay_chain_rpc_client
 		.chain_get_header(None)
@@ -249,6 +253,7 @@ async fn new_minimal_relay_chain<Block: BlockT, Network: NetworkBackend<RelayBlo
 		authority_discovery_service,
 		collation_req_v1_receiver,
 		collation_req_v2_receiver,
+		collation_req_v3_receiver,
 		available_data_req_receiver,
 		registry: prometheus_registry,
 		spawner: task_manager.spawn_handle(),
@@ -274,6 +279,7 @@ fn build_request_response_protocol_receivers<
 ) -> (
 	IncomingRequestReceiver<v1::CollationFetchingRequest>,
 	IncomingRequestReceiver<v2::CollationFetchingRequest>,
+	IncomingRequestReceiver<v3::CollationFetchingRequest>,
 	IncomingRequestReceiver<v1::AvailableDataFetchingRequest>,
 ) {
 	let (collation_req_v1_receiver, cfg) =
@@ -282,6 +288,9 @@ fn build_request_response_protocol_receivers<
 	let (collation_req_v2_receiver, cfg) =
 		IncomingRequest::get_config_receiver::<_, Network>(request_protocol_names);
 	config.add_request_response_protocol(cfg);
+	let (collation_req_v3_receiver, cfg) =
+		IncomingRequest::get_config_receiver::<_, Network>(request_protocol_names);
+	config.add_request_response_protocol(cfg);
 	let (available_data_req_receiver, cfg) =
 		IncomingRequest::get_config_receiver::<_, Network>(request_protocol_names);
 	config.add_request_response_protocol(cfg);
@@ -291,5 +300,10 @@ fn build_request_response_protocol_receivers<
 	let cfg =
 		Protocol::ChunkFetchingV2.get_outbound_only_config::<_, Network>(request_protocol_names);
 	config.add_request_response_protocol(cfg);
-	(collation_req_v1_receiver, collation_req_v2_receiver, available_data_req_receiver)
+	(
+		collation_req_v1_receiver,
+		collation_req_v2_receiver,
+		collation_req_v3_receiver,
+		available_data_req_receiver,
+	)
 }
@@ -14,8 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
 
-//! The definition of a [`FixedVelocityConsensusHook`] for consensus logic to manage
-//! block velocity.
+//! The definition of a [`FixedVelocityConsensusHook`] and a [`RelayDrivenVelocityConsensusHook`]
+//! for consensus logic to manage block velocity.
 //!
 //! The velocity `V` refers to the rate of block processing by the relay chain.
 
@@ -23,7 +23,10 @@ use super::{pallet, Aura};
 use core::{marker::PhantomData, num::NonZeroU32};
 use cumulus_pallet_parachain_system::{
 	self as parachain_system,
-	consensus_hook::{ConsensusHook, UnincludedSegmentCapacity},
+	consensus_hook::{
+		capacity_from_async_backing_params, ConsensusHook, RelayDrivenUnincludedSegment,
+		UnincludedSegmentCapacity,
+	},
 	relay_state_snapshot::RelayChainStateProof,
 };
 use frame_support::pallet_prelude::*;
@@ -50,36 +53,7 @@ where
 {
 	// Validates the number of authored blocks within the slot with respect to the `V + 1` limit.
 	fn on_state_proof(state_proof: &RelayChainStateProof) -> (Weight, UnincludedSegmentCapacity) {
-		// Ensure velocity is non-zero.
-		let velocity = V.max(1);
-		let relay_chain_slot = state_proof.read_slot().expect("failed to read relay chain slot");
-
-		let (slot, authored) =
-			pallet::SlotInfo::<T>::get().expect("slot info is inserted on block initialization");
-
-		// Convert relay chain timestamp.
-		let relay_chain_timestamp =
-			u64::from(RELAY_CHAIN_SLOT_DURATION_MILLIS).saturating_mul(*relay_chain_slot);
-
-		let para_slot_duration = SlotDuration::from_millis(Aura::<T>::slot_duration().into());
-		let para_slot_from_relay =
-			Slot::from_timestamp(relay_chain_timestamp.into(), para_slot_duration);
-
-		// Check that we are not too far in the future. Since we expect `V` parachain blocks
-		// during the relay chain slot, we can allow for `V` parachain slots into the future.
-		if *slot > *para_slot_from_relay + u64::from(velocity) {
-			panic!(
-				"Parachain slot is too far in the future: parachain_slot: {:?}, derived_from_relay_slot: {:?} velocity: {:?}",
-				slot,
-				para_slot_from_relay,
-				velocity
-			);
-		}
-
-		// We need to allow authoring multiple blocks in the same slot.
-		if slot != para_slot_from_relay && authored > velocity {
-			panic!("authored blocks limit is reached for the slot")
-		}
+		check_velocity::<T>(state_proof, RELAY_CHAIN_SLOT_DURATION_MILLIS, V);
 		let weight = T::DbWeight::get().reads(1);
 
 		(
@@ -109,30 +83,137 @@ impl<
 	/// whose state we are querying against, this must always return `true` as long as the slot
 	/// is more recent than the included block itself.
 	pub fn can_build_upon(included_hash: T::Hash, new_slot: Slot) -> bool {
-		let velocity = V.max(1);
-		let (last_slot, authored_so_far) = match pallet::SlotInfo::<T>::get() {
-			None => return true,
-			Some(x) => x,
-		};
-
-		let size_after_included =
-			parachain_system::Pallet::<T>::unincluded_segment_size_after(included_hash);
-
-		// can never author when the unincluded segment is full.
-		if size_after_included >= C {
-			return false
-		}
-
-		// TODO: This logic needs to be adjusted.
-		// It checks that we have not authored more than `V + 1` blocks in the slot.
-		// As a slot however, we take the parachain slot here. Velocity should
-		// be measured in relation to the relay chain slot.
-		// https://github.com/paritytech/polkadot-sdk/issues/3967
-		if last_slot == new_slot {
-			authored_so_far < velocity + 1
-		} else {
-			// disallow slot from moving backwards.
-			last_slot < new_slot
-		}
+		can_build_upon_with::<T>(included_hash, new_slot, V, C)
+	}
+}
+
+/// A consensus hook for a fixed block processing velocity, whose unincluded segment capacity
+/// follows the asynchronous backing parameters of the relay chain.
+///
+/// The capacity is re-derived from the relay chain configuration proven with every block, so
+/// it adapts automatically when the relay chain is reconfigured. `MAX_C` bounds the capacity
+/// regardless of what the relay chain allows.
+///
+/// Relay chain slot duration must be provided in milliseconds.
+pub struct RelayDrivenVelocityConsensusHook<
+	T,
+	const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32,
+	const V: u32,
+	const MAX_C: u32,
+>(PhantomData<T>);
+
+impl<
+		T: pallet::Config,
+		const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32,
+		const V: u32,
+		const MAX_C: u32,
+	> ConsensusHook for RelayDrivenVelocityConsensusHook<T, RELAY_CHAIN_SLOT_DURATION_MILLIS, V, MAX_C>
+where
+	<T as pallet_timestamp::Config>::Moment: Into<u64>,
+{
+	fn on_state_proof(state_proof: &RelayChainStateProof) -> (Weight, UnincludedSegmentCapacity) {
+		check_velocity::<T>(state_proof, RELAY_CHAIN_SLOT_DURATION_MILLIS, V);
+		let (weight, capacity) = RelayDrivenUnincludedSegment::<MAX_C>::on_state_proof(state_proof);
+
+		(weight.saturating_add(T::DbWeight::get().reads(1)), capacity)
+	}
+}
+
+impl<
+		T: pallet::Config + parachain_system::Config,
+		const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32,
+		const V: u32,
+		const MAX_C: u32,
+	> RelayDrivenVelocityConsensusHook<T, RELAY_CHAIN_SLOT_DURATION_MILLIS, V, MAX_C>
+{
+	/// Whether it is legal to extend the chain, assuming the given block is the most
+	/// recently included one as-of the relay parent that will be built against, and
+	/// the given slot.
+	///
+	/// The capacity is taken from the asynchronous backing parameters stored with the last
+	/// relay parent. Before any are known, the parent block is required to be included.
+	pub fn can_build_upon(included_hash: T::Hash, new_slot: Slot) -> bool {
+		let capacity = parachain_system::Pallet::<T>::async_backing_params()
+			.map_or(1, |params| capacity_from_async_backing_params(&params, MAX_C).get());
+
+		can_build_upon_with::<T>(included_hash, new_slot, V, capacity)
+	}
+}
+
+/// Ensures the parachain slot is not ahead of the relay chain slot by more than the velocity
+/// allows and that no more than `velocity + 1` blocks were authored in the current slot.
+///
+/// Panics if either condition is violated.
+fn check_velocity<T: pallet::Config>(
+	state_proof: &RelayChainStateProof,
+	relay_chain_slot_duration_millis: u32,
+	velocity: u32,
+) where
+	<T as pallet_timestamp::Config>::Moment: Into<u64>,
+{
+	// Ensure velocity is non-zero.
+	let velocity = velocity.max(1);
+	let relay_chain_slot = state_proof.read_slot().expect("failed to read relay chain slot");
+
+	let (slot, authored) =
+		pallet::SlotInfo::<T>::get().expect("slot info is inserted on block initialization");
+
+	// Convert relay chain timestamp.
+	let relay_chain_timestamp =
+		u64::from(relay_chain_slot_duration_millis).saturating_mul(*relay_chain_slot);
+
+	let para_slot_duration = SlotDuration::from_millis(Aura::<T>::slot_duration().into());
+	let para_slot_from_relay =
+		Slot::from_timestamp(relay_chain_timestamp.into(), para_slot_duration);
+
+	// Check that we are not too far in the future. Since we expect `V` parachain blocks
+	// during the relay chain slot, we can allow for `V` parachain slots into the future.
+	if *slot > *para_slot_from_relay + u64::from(velocity) {
+		panic!(
+			"Parachain slot is too far in the future: parachain_slot: {:?}, derived_from_relay_slot: {:?} velocity: {:?}",
+			slot,
+			para_slot_from_relay,
+			velocity
+		);
+	}
+
+	// We need to allow authoring multiple blocks in the same slot.
+	if slot != para_slot_from_relay && authored > velocity {
+		panic!("authored blocks limit is reached for the slot")
+	}
+}
+
+/// Whether it is legal to extend the chain given velocity `velocity` and unincluded segment
+/// capacity `capacity`. See [`FixedVelocityConsensusHook::can_build_upon`].
+fn can_build_upon_with<T: pallet::Config + parachain_system::Config>(
+	included_hash: T::Hash,
+	new_slot: Slot,
+	velocity: u32,
+	capacity: u32,
+) -> bool {
+	let velocity = velocity.max(1);
+	let (last_slot, authored_so_far) = match pallet::SlotInfo::<T>::get() {
+		None => return true,
+		Some(x) => x,
+	};
+
+	let size_after_included =
+		parachain_system::Pallet::<T>::unincluded_segment_size_after(included_hash);
+
+	// can never author when the unincluded segment is full.
+	if size_after_included >= capacity {
+		return false
+	}
+
+	// TODO: This logic needs to be adjusted.
+	// It checks that we have not authored more than `V + 1` blocks in the slot.
+	// As a slot however, we take the parachain slot here. Velocity should
+	// be measured in relation to the relay chain slot.
+	// https://github.com/paritytech/polkadot-sdk/issues/3967
+	if last_slot == new_slot {
+		authored_so_far < velocity + 1
+	} else {
+		// disallow slot from moving backwards.
+		last_slot < new_slot
 	}
 }
@@ -40,7 +40,7 @@ use sp_consensus_aura::{digests::CompatibleDigestItem, Slot};
 use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
 
 pub mod consensus_hook;
-pub use consensus_hook::FixedVelocityConsensusHook;
+pub use consensus_hook::{FixedVelocityConsensusHook, RelayDrivenVelocityConsensusHook};
 
 type Aura<T> = pallet_aura::Pallet<T>;
 
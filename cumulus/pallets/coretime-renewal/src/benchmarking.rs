@@ -0,0 +1,85 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarking setup for cumulus-pallet-coretime-renewal
+
+use super::*;
+
+use frame_benchmarking::v2::*;
+
+fn settings() -> RenewalSettings {
+	RenewalSettings { core: 0, max_price: 1_000_000, lead_time: 100 }
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_settings() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, Some(settings()));
+
+		assert_eq!(Settings::<T>::get(), Some(settings()));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn increase_budget() -> Result<(), BenchmarkError> {
+		let origin =
+			T::BudgetOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Budget::<T>::put(1_000);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 1_000);
+
+		assert_eq!(Budget::<T>::get(), 2_000);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn settle_renewal() -> Result<(), BenchmarkError> {
+		let origin =
+			T::SettlementOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		PendingSettlement::<T>::put((1_000, 1_000));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 1_000, 400);
+
+		assert_eq!(Budget::<T>::get(), 600);
+		assert!(PendingSettlement::<T>::get().is_none());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn renew() {
+		let settings = settings();
+		Budget::<T>::put(settings.max_price.saturating_add(T::RenewalFee::get()));
+
+		#[block]
+		{
+			Pallet::<T>::renew(settings.clone(), 1_000);
+		}
+
+		assert_eq!(LastAttempt::<T>::get(), Some(1_000));
+		assert_eq!(PendingSettlement::<T>::get(), Some((1_000, settings.max_price)));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,431 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Pallet to automatically renew the bulk coretime of a parachain.
+//!
+//! The pallet follows the assignment of the parachain's core on the relay chain through the relay
+//! chain state proof. Once the current assignment is about to end and no follow-up schedule is
+//! queued, it sends an XCM to the broker chain which renews the core on behalf of the sovereign
+//! account of the parachain.
+//!
+//! Renewals are bounded by a configured maximum price and by a budget which has to be topped up
+//! by the [`Config::BudgetOrigin`], e.g. the treasury. Sending a renewal reserves the maximum price
+//! from the budget. Once the [`Config::SettlementOrigin`] reports the price actually paid on the
+//! broker chain, the rest is returned to the budget. Failed renewal attempts are reported via
+//! [`Event::RenewalFailed`] and retried in the following blocks.
+//!
+//! The runtime has to include [`Pallet`] in the keys returned from its
+//! [`RelayStateKeysApi`](cumulus_primitives_core::RelayStateKeysApi), so that the core descriptor
+//! is part of the relay chain state proof.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use alloc::{vec, vec::Vec};
+use codec::{Decode, Encode, MaxEncodedLen};
+use cumulus_pallet_parachain_system::{RelayStateKeys, RelayStateReader};
+use cumulus_primitives_core::{relay_chain::BlockNumber as RelayBlockNumber, ParaId};
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+use scale_info::TypeInfo;
+use sp_runtime::traits::BlockNumberProvider;
+use xcm::latest::prelude::*;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+const LOG_TARGET: &str = "runtime::coretime-renewal";
+
+/// Balance type used for coretime prices on the broker chain.
+pub type BrokerBalance = u128;
+
+/// Index of a core as used by the broker chain.
+pub type BrokerCoreIndex = u16;
+
+/// Mirrors of the relay chain's coretime assignment types, as far as needed to find out when the
+/// assignment of a core ends.
+pub mod relay {
+	use super::*;
+
+	/// Key of the `CoreDescriptors` entry of `core` in the relay chain state.
+	pub fn core_descriptor_key(core: BrokerCoreIndex) -> Vec<u8> {
+		let core = cumulus_primitives_core::relay_chain::CoreIndex(core.into());
+		let mut key = sp_io::hashing::twox_128(b"CoretimeAssignmentProvider").to_vec();
+		key.extend(sp_io::hashing::twox_128(b"CoreDescriptors"));
+		key.extend(sp_io::hashing::twox_256(&core.encode()));
+		key
+	}
+
+	/// What a core is assigned to.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub enum CoreAssignment {
+		/// The core is idle.
+		Idle,
+		/// The core is used for on-demand coretime.
+		Pool,
+		/// The core is assigned to the given task, i.e. parachain.
+		Task(u32),
+	}
+
+	/// The relay chain's bookkeeping of an assignment.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct AssignmentState {
+		/// Ratio of the core in parts of 57600.
+		pub ratio: u16,
+		/// Parts remaining in the current round.
+		pub remaining: u16,
+	}
+
+	/// The currently active assignments of a core.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct WorkState {
+		/// The assignments with their current state.
+		pub assignments: Vec<(CoreAssignment, AssignmentState)>,
+		/// The relay chain block at which the assignments end, if at all.
+		pub end_hint: Option<RelayBlockNumber>,
+		/// Position in the assignments.
+		pub pos: u16,
+		/// Step width in parts of 57600.
+		pub step: u16,
+	}
+
+	/// The queued schedules of a core.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+	pub struct QueueDescriptor {
+		/// First queued schedule.
+		pub first: RelayBlockNumber,
+		/// Last queued schedule.
+		pub last: RelayBlockNumber,
+	}
+
+	/// The state of a core as tracked by the relay chain.
+	#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug)]
+	pub struct CoreDescriptor {
+		/// Schedules which are queued, but not active yet.
+		pub queue: Option<QueueDescriptor>,
+		/// The currently active work.
+		pub current_work: Option<WorkState>,
+	}
+}
+
+/// Encoding of the broker pallet calls used by this pallet.
+#[derive(Encode, Decode)]
+enum BrokerCall {
+	#[codec(index = 6)]
+	Renew(BrokerCoreIndex),
+}
+
+/// Settings of the automatic renewal.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RenewalSettings {
+	/// The core to renew.
+	pub core: BrokerCoreIndex,
+	/// The maximum price to pay for a renewal.
+	pub max_price: BrokerBalance,
+	/// How many relay chain blocks before the end of the assignment the renewal is sent.
+	pub lead_time: RelayBlockNumber,
+}
+
+/// The reason why a renewal could not be sent.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum RenewalFailure {
+	/// The remaining budget doesn't cover the maximum price.
+	InsufficientBudget,
+	/// The XCM could not be sent to the broker chain.
+	SendFailed,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Reads the core descriptor from the relay chain state proof.
+		type RelayState: RelayStateReader;
+
+		/// Provides the number of the current relay parent.
+		type RelayChainBlockNumberProvider: BlockNumberProvider<BlockNumber = RelayBlockNumber>;
+
+		/// Sends the renewal messages.
+		type XcmSender: SendXcm;
+
+		/// Location of the broker chain, relative to this parachain.
+		#[pallet::constant]
+		type BrokerLocation: Get<Location>;
+
+		/// Index of the broker pallet in the runtime of the broker chain.
+		#[pallet::constant]
+		type BrokerPalletIndex: Get<u8>;
+
+		/// Amount of the relay chain's native asset paid for executing a renewal on the broker
+		/// chain.
+		#[pallet::constant]
+		type RenewalFee: Get<BrokerBalance>;
+
+		/// The id of this parachain, used to refund surplus fees to its sovereign account.
+		type SelfParaId: Get<ParaId>;
+
+		/// Origin allowed to configure the renewal.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin allowed to top up the renewal budget.
+		type BudgetOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Origin reporting the price paid on the broker chain for the last renewal.
+		type SettlementOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics and hooks in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// The renewal settings. No renewals are sent if unset.
+	#[pallet::storage]
+	pub type Settings<T: Config> = StorageValue<_, RenewalSettings, OptionQuery>;
+
+	/// The remaining budget for renewals.
+	#[pallet::storage]
+	pub type Budget<T: Config> = StorageValue<_, BrokerBalance, ValueQuery>;
+
+	/// The end of the assignment for which a renewal was last sent.
+	///
+	/// Ensures that at most one renewal is sent per assignment.
+	#[pallet::storage]
+	pub type LastAttempt<T: Config> = StorageValue<_, RelayBlockNumber, OptionQuery>;
+
+	/// The end of the assignment of the last renewal sent and the price reserved for it, until the
+	/// price actually paid is reported.
+	#[pallet::storage]
+	pub type PendingSettlement<T: Config> =
+		StorageValue<_, (RelayBlockNumber, BrokerBalance), OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The renewal settings were changed.
+		SettingsChanged { settings: Option<RenewalSettings> },
+		/// The renewal budget was topped up.
+		BudgetIncreased { amount: BrokerBalance, budget: BrokerBalance },
+		/// A renewal of the core was sent to the broker chain.
+		RenewalSent { core: BrokerCoreIndex, end: RelayBlockNumber, max_price: BrokerBalance },
+		/// The core assignment ends soon, but no renewal could be sent.
+		RenewalFailed { core: BrokerCoreIndex, end: RelayBlockNumber, reason: RenewalFailure },
+		/// The price paid for the renewal of the assignment ending at `end` was reported and the
+		/// rest of the reserved price was returned to the budget.
+		RenewalSettled { end: RelayBlockNumber, price: BrokerBalance, budget: BrokerBalance },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The lead time must not be zero.
+		ZeroLeadTime,
+		/// No renewal of the given assignment awaits its price.
+		NoPendingSettlement,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			// Reserve the weight of sending a renewal in `on_finalize`. The core descriptor is
+			// read from the relay chain state proof, which is already accounted for.
+			if Settings::<T>::exists() {
+				T::WeightInfo::renew().saturating_add(T::DbWeight::get().reads(2))
+			} else {
+				T::DbWeight::get().reads(1)
+			}
+		}
+
+		fn on_finalize(_n: BlockNumberFor<T>) {
+			// The relay chain state proof is only available after `set_validation_data`.
+			if let Some(settings) = Settings::<T>::get() {
+				Self::check_renewal(settings);
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the renewal settings, or disable automatic renewals with `None`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_settings())]
+		pub fn set_settings(
+			origin: OriginFor<T>,
+			settings: Option<RenewalSettings>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			if let Some(ref settings) = settings {
+				ensure!(settings.lead_time > 0, Error::<T>::ZeroLeadTime);
+			}
+
+			Settings::<T>::set(settings.clone());
+			Self::deposit_event(Event::SettingsChanged { settings });
+			Ok(())
+		}
+
+		/// Increase the renewal budget by `amount`.
+		///
+		/// The sovereign account of the parachain on the broker chain has to hold the funds.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::increase_budget())]
+		pub fn increase_budget(origin: OriginFor<T>, amount: BrokerBalance) -> DispatchResult {
+			T::BudgetOrigin::ensure_origin(origin)?;
+
+			let budget = Budget::<T>::mutate(|budget| {
+				*budget = budget.saturating_add(amount);
+				*budget
+			});
+			Self::deposit_event(Event::BudgetIncreased { amount, budget });
+			Ok(())
+		}
+
+		/// Report the `price` paid on the broker chain for the renewal of the assignment ending at
+		/// `end`.
+		///
+		/// The price reserved when sending the renewal is returned to the budget and `price` is
+		/// deducted instead.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::settle_renewal())]
+		pub fn settle_renewal(
+			origin: OriginFor<T>,
+			end: RelayBlockNumber,
+			price: BrokerBalance,
+		) -> DispatchResult {
+			T::SettlementOrigin::ensure_origin(origin)?;
+			let (pending_end, reserved) =
+				PendingSettlement::<T>::get().ok_or(Error::<T>::NoPendingSettlement)?;
+			ensure!(pending_end == end, Error::<T>::NoPendingSettlement);
+
+			PendingSettlement::<T>::kill();
+			let budget = Budget::<T>::mutate(|budget| {
+				*budget = budget.saturating_add(reserved).saturating_sub(price);
+				*budget
+			});
+			Self::deposit_event(Event::RenewalSettled { end, price, budget });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Send a renewal if the assignment of the core ends within the lead time.
+		pub(crate) fn check_renewal(settings: RenewalSettings) {
+			let core = settings.core;
+			let descriptor = match T::RelayState::read_relay_state::<relay::CoreDescriptor>(
+				&relay::core_descriptor_key(core),
+			) {
+				Ok(descriptor) => descriptor.unwrap_or_default(),
+				Err(err) => {
+					log::warn!(
+						target: LOG_TARGET,
+						"Failed to read the descriptor of core {core}: {err:?}",
+					);
+					return
+				},
+			};
+
+			// A queued schedule means the core was already renewed.
+			if descriptor.queue.is_some() {
+				return
+			}
+			let Some(end) = descriptor.current_work.and_then(|work| work.end_hint) else { return };
+
+			let now = T::RelayChainBlockNumberProvider::current_block_number();
+			if now.saturating_add(settings.lead_time) < end || LastAttempt::<T>::get() == Some(end)
+			{
+				return
+			}
+			Self::renew(settings, end);
+		}
+
+		/// Send a renewal of the assignment ending at `end`, reserving its maximum price from the
+		/// budget.
+		///
+		/// Nothing is recorded if the renewal can't be sent, so that it is retried in the next
+		/// block.
+		pub(crate) fn renew(settings: RenewalSettings, end: RelayBlockNumber) {
+			let core = settings.core;
+			let cost = settings.max_price.saturating_add(T::RenewalFee::get());
+			let budget = Budget::<T>::get();
+			if budget < cost {
+				Self::deposit_event(Event::RenewalFailed {
+					core,
+					end,
+					reason: RenewalFailure::InsufficientBudget,
+				});
+				return
+			}
+
+			match send_xcm::<T::XcmSender>(T::BrokerLocation::get(), Self::renewal_message(core)) {
+				Ok(_) => {
+					Budget::<T>::put(budget - cost);
+					LastAttempt::<T>::put(end);
+					PendingSettlement::<T>::put((end, settings.max_price));
+					Self::deposit_event(Event::RenewalSent {
+						core,
+						end,
+						max_price: settings.max_price,
+					});
+				},
+				Err(err) => {
+					log::warn!(target: LOG_TARGET, "Failed to send renewal: {err:?}");
+					Self::deposit_event(Event::RenewalFailed {
+						core,
+						end,
+						reason: RenewalFailure::SendFailed,
+					});
+				},
+			}
+		}
+
+		/// The XCM renewing `core` from the sovereign account of this parachain.
+		pub fn renewal_message(core: BrokerCoreIndex) -> Xcm<()> {
+			let fee: Asset = (Location::parent(), T::RenewalFee::get()).into();
+			let call = (T::BrokerPalletIndex::get(), BrokerCall::Renew(core)).encode();
+			let sovereign_account = Location::new(1, [Parachain(T::SelfParaId::get().into())]);
+
+			Xcm(vec![
+				WithdrawAsset(fee.clone().into()),
+				BuyExecution { fees: fee, weight_limit: Unlimited },
+				Transact { origin_kind: OriginKind::SovereignAccount, call: call.into() },
+				RefundSurplus,
+				DepositAsset { assets: AllCounted(1).into(), beneficiary: sovereign_account },
+			])
+		}
+	}
+}
+
+/// Requests the descriptor of the configured core.
+impl<T: Config> RelayStateKeys for Pallet<T> {
+	fn relay_state_keys() -> Vec<Vec<u8>> {
+		Settings::<T>::get()
+			.map(|settings| vec![relay::core_descriptor_key(settings.core)])
+			.unwrap_or_default()
+	}
+}
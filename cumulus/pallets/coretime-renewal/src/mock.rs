@@ -0,0 +1,137 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate as coretime_renewal;
+use cumulus_pallet_parachain_system::RelayStateReadError;
+use frame_support::{derive_impl, parameter_types};
+use frame_system::EnsureRoot;
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		CoretimeRenewal: coretime_renewal,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+}
+
+parameter_types! {
+	pub static RelayNumber: RelayBlockNumber = 0;
+	pub static CoreDescriptor: Option<relay::CoreDescriptor> = None;
+	pub static ProofAvailable: bool = true;
+	pub static SentXcm: Vec<(Location, Xcm<()>)> = Vec::new();
+	pub static FailSending: bool = false;
+	pub BrokerLocation: Location = Location::new(1, [Parachain(1005)]);
+	pub const BrokerPalletIndex: u8 = 50;
+	pub const RenewalFee: BrokerBalance = 10;
+	pub const SelfParaId: ParaId = ParaId::new(2000);
+}
+
+/// Reads the core descriptor set in [`CoreDescriptor`].
+pub struct MockRelayState;
+
+impl RelayStateReader for MockRelayState {
+	fn read_relay_state<V: Decode>(key: &[u8]) -> Result<Option<V>, RelayStateReadError> {
+		assert_eq!(key, relay::core_descriptor_key(CORE));
+		if !ProofAvailable::get() {
+			return Err(RelayStateReadError::NotProven)
+		}
+		CoreDescriptor::get()
+			.map(|descriptor| V::decode(&mut &descriptor.encode()[..]))
+			.transpose()
+			.map_err(|_| RelayStateReadError::Decode)
+	}
+}
+
+pub struct MockRelayBlockNumberProvider;
+
+impl BlockNumberProvider for MockRelayBlockNumberProvider {
+	type BlockNumber = RelayBlockNumber;
+
+	fn current_block_number() -> Self::BlockNumber {
+		RelayNumber::get()
+	}
+}
+
+/// Records all sent messages in [`SentXcm`].
+pub struct MockXcmSender;
+
+impl SendXcm for MockXcmSender {
+	type Ticket = (Location, Xcm<()>);
+
+	fn validate(
+		dest: &mut Option<Location>,
+		msg: &mut Option<Xcm<()>>,
+	) -> SendResult<Self::Ticket> {
+		if FailSending::get() {
+			return Err(SendError::Transport("sending failed"))
+		}
+		Ok(((dest.take().unwrap(), msg.take().unwrap()), Assets::new()))
+	}
+
+	fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+		SentXcm::mutate(|sent| sent.push(ticket));
+		Ok([0; 32])
+	}
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RelayState = MockRelayState;
+	type RelayChainBlockNumberProvider = MockRelayBlockNumberProvider;
+	type XcmSender = MockXcmSender;
+	type BrokerLocation = BrokerLocation;
+	type BrokerPalletIndex = BrokerPalletIndex;
+	type RenewalFee = RenewalFee;
+	type SelfParaId = SelfParaId;
+	type AdminOrigin = EnsureRoot<u64>;
+	type BudgetOrigin = EnsureRoot<u64>;
+	type SettlementOrigin = EnsureRoot<u64>;
+	type WeightInfo = ();
+}
+
+/// The core renewed in the tests.
+pub const CORE: BrokerCoreIndex = 3;
+
+/// A descriptor of a core assigned to this parachain until `end`.
+pub fn assigned_until(end: RelayBlockNumber) -> relay::CoreDescriptor {
+	relay::CoreDescriptor {
+		queue: None,
+		current_work: Some(relay::WorkState {
+			assignments: vec![(
+				relay::CoreAssignment::Task(SelfParaId::get().into()),
+				relay::AssignmentState { ratio: 57600, remaining: 57600 },
+			)],
+			end_hint: Some(end),
+			pos: 0,
+			step: 57600,
+		}),
+	}
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext: sp_io::TestExternalities =
+		frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,222 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+const MAX_PRICE: BrokerBalance = 1_000;
+const LEAD_TIME: RelayBlockNumber = 100;
+
+fn configure(budget: BrokerBalance) {
+	assert_ok!(CoretimeRenewal::set_settings(
+		RuntimeOrigin::root(),
+		Some(RenewalSettings { core: CORE, max_price: MAX_PRICE, lead_time: LEAD_TIME }),
+	));
+	assert_ok!(CoretimeRenewal::increase_budget(RuntimeOrigin::root(), budget));
+}
+
+fn finalize_at(relay_number: RelayBlockNumber) {
+	RelayNumber::set(relay_number);
+	CoretimeRenewal::on_finalize(System::block_number());
+}
+
+#[test]
+fn settings_and_budget_require_origins() {
+	new_test_ext().execute_with(|| {
+		let settings = RenewalSettings { core: CORE, max_price: MAX_PRICE, lead_time: LEAD_TIME };
+		assert_noop!(
+			CoretimeRenewal::set_settings(RuntimeOrigin::signed(1), Some(settings.clone())),
+			DispatchError::BadOrigin,
+		);
+		assert_noop!(
+			CoretimeRenewal::increase_budget(RuntimeOrigin::signed(1), 10),
+			DispatchError::BadOrigin,
+		);
+		assert_noop!(
+			CoretimeRenewal::set_settings(
+				RuntimeOrigin::root(),
+				Some(RenewalSettings { lead_time: 0, ..settings }),
+			),
+			Error::<Test>::ZeroLeadTime,
+		);
+
+		configure(500);
+		configure(600);
+		assert_eq!(Budget::<Test>::get(), 1_100);
+		System::assert_last_event(Event::BudgetIncreased { amount: 600, budget: 1_100 }.into());
+	});
+}
+
+#[test]
+fn renewal_is_sent_within_lead_time() {
+	new_test_ext().execute_with(|| {
+		configure(2_000);
+		CoreDescriptor::set(Some(assigned_until(1_000)));
+
+		finalize_at(899);
+		assert!(SentXcm::get().is_empty());
+
+		finalize_at(900);
+		assert_eq!(
+			SentXcm::get(),
+			vec![(BrokerLocation::get(), CoretimeRenewal::renewal_message(CORE))],
+		);
+		assert_eq!(Budget::<Test>::get(), 2_000 - MAX_PRICE - RenewalFee::get());
+		System::assert_last_event(
+			Event::RenewalSent { core: CORE, end: 1_000, max_price: MAX_PRICE }.into(),
+		);
+
+		// Only one renewal is sent per assignment.
+		finalize_at(901);
+		assert_eq!(SentXcm::get().len(), 1);
+	});
+}
+
+#[test]
+fn renewal_message_renews_from_sovereign_account() {
+	new_test_ext().execute_with(|| {
+		let message = CoretimeRenewal::renewal_message(CORE);
+		let Transact { origin_kind, call } = &message.0[2] else { panic!("Expected `Transact`") };
+
+		assert_eq!(*origin_kind, OriginKind::SovereignAccount);
+		assert_eq!(call.clone().into_encoded(), vec![BrokerPalletIndex::get(), 6, 3, 0]);
+		assert_eq!(
+			message.0[4],
+			DepositAsset {
+				assets: AllCounted(1).into(),
+				beneficiary: Location::new(1, [Parachain(2000)]),
+			},
+		);
+	});
+}
+
+#[test]
+fn no_renewal_if_already_renewed_or_unconfigured() {
+	new_test_ext().execute_with(|| {
+		let mut descriptor = assigned_until(1_000);
+		descriptor.queue = Some(relay::QueueDescriptor { first: 1_000, last: 1_000 });
+		CoreDescriptor::set(Some(descriptor));
+
+		// Not configured.
+		finalize_at(950);
+		assert!(SentXcm::get().is_empty());
+
+		// The queue shows that the core was renewed already.
+		configure(2_000);
+		finalize_at(950);
+		assert!(SentXcm::get().is_empty());
+
+		// Indefinite assignments don't need to be renewed.
+		CoreDescriptor::set(Some(relay::CoreDescriptor {
+			queue: None,
+			current_work: Some(relay::WorkState {
+				end_hint: None,
+				..assigned_until(0).current_work.unwrap()
+			}),
+		}));
+		finalize_at(950);
+		assert!(SentXcm::get().is_empty());
+
+		// The descriptor is not part of the proof.
+		CoreDescriptor::set(Some(assigned_until(1_000)));
+		ProofAvailable::set(false);
+		finalize_at(950);
+		assert!(SentXcm::get().is_empty());
+		assert_eq!(Budget::<Test>::get(), 2_000);
+	});
+}
+
+#[test]
+fn failures_are_reported_and_retried() {
+	new_test_ext().execute_with(|| {
+		configure(MAX_PRICE);
+		CoreDescriptor::set(Some(assigned_until(1_000)));
+
+		finalize_at(950);
+		assert!(SentXcm::get().is_empty());
+		System::assert_last_event(
+			Event::RenewalFailed {
+				core: CORE,
+				end: 1_000,
+				reason: RenewalFailure::InsufficientBudget,
+			}
+			.into(),
+		);
+		assert_eq!(LastAttempt::<Test>::get(), None);
+
+		// The renewal is sent once the budget was topped up.
+		assert_ok!(CoretimeRenewal::increase_budget(RuntimeOrigin::root(), RenewalFee::get()));
+		finalize_at(951);
+		assert_eq!(SentXcm::get().len(), 1);
+		assert_eq!(LastAttempt::<Test>::get(), Some(1_000));
+		assert_eq!(Budget::<Test>::get(), 0);
+
+		CoreDescriptor::set(Some(assigned_until(2_000)));
+		assert_ok!(CoretimeRenewal::increase_budget(
+			RuntimeOrigin::root(),
+			MAX_PRICE + RenewalFee::get()
+		));
+		FailSending::set(true);
+		finalize_at(1_950);
+		System::assert_last_event(
+			Event::RenewalFailed { core: CORE, end: 2_000, reason: RenewalFailure::SendFailed }
+				.into(),
+		);
+		assert_eq!(Budget::<Test>::get(), MAX_PRICE + RenewalFee::get());
+		assert_eq!(LastAttempt::<Test>::get(), Some(1_000));
+
+		// Sending is retried in the next block.
+		FailSending::set(false);
+		finalize_at(1_951);
+		assert_eq!(SentXcm::get().len(), 2);
+		assert_eq!(LastAttempt::<Test>::get(), Some(2_000));
+		assert_eq!(Budget::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn settlement_deducts_the_price_paid() {
+	new_test_ext().execute_with(|| {
+		configure(2_000);
+		CoreDescriptor::set(Some(assigned_until(1_000)));
+		finalize_at(950);
+		assert_eq!(PendingSettlement::<Test>::get(), Some((1_000, MAX_PRICE)));
+		assert_eq!(Budget::<Test>::get(), 2_000 - MAX_PRICE - RenewalFee::get());
+
+		assert_noop!(
+			CoretimeRenewal::settle_renewal(RuntimeOrigin::signed(1), 1_000, 400),
+			DispatchError::BadOrigin,
+		);
+		assert_noop!(
+			CoretimeRenewal::settle_renewal(RuntimeOrigin::root(), 2_000, 400),
+			Error::<Test>::NoPendingSettlement,
+		);
+
+		assert_ok!(CoretimeRenewal::settle_renewal(RuntimeOrigin::root(), 1_000, 400));
+		let budget = 2_000 - 400 - RenewalFee::get();
+		assert_eq!(Budget::<Test>::get(), budget);
+		assert_eq!(PendingSettlement::<Test>::get(), None);
+		System::assert_last_event(Event::RenewalSettled { end: 1_000, price: 400, budget }.into());
+
+		// A renewal is settled only once.
+		assert_noop!(
+			CoretimeRenewal::settle_renewal(RuntimeOrigin::root(), 1_000, 400),
+			Error::<Test>::NoPendingSettlement,
+		);
+	});
+}
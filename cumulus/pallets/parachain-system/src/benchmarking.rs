@@ -43,7 +43,7 @@ mod benchmarks {
 
 		#[block]
 		{
-			Pallet::<T>::enqueue_inbound_downward_messages(head, msgs);
+			Pallet::<T>::enqueue_inbound_downward_messages(head, msgs, Weight::MAX);
 		}
 
 		assert_eq!(ProcessedDownwardMessages::<T>::get(), n);
@@ -19,6 +19,7 @@
 
 use super::relay_state_snapshot::RelayChainStateProof;
 use core::num::NonZeroU32;
+use cumulus_primitives_core::relay_chain::AsyncBackingParams;
 use frame_support::weights::Weight;
 
 /// The possible capacity of the unincluded segment.
@@ -107,3 +108,33 @@ impl<const N: u32> ConsensusHook for FixedCapacityUnincludedSegment<N> {
 ///
 /// This is a simple type alias around a fixed-capacity unincluded segment with a size of 1.
 pub type RequireParentIncluded = FixedCapacityUnincludedSegment<1>;
+
+/// The unincluded segment capacity implied by the relay chain's asynchronous backing parameters.
+///
+/// The relay chain accepts candidates up to `max_candidate_depth` blocks on top of the most
+/// recently included one, so the segment may hold `max_candidate_depth + 1` blocks. The result is
+/// capped at `max` and never less than 1.
+pub fn capacity_from_async_backing_params(params: &AsyncBackingParams, max: u32) -> NonZeroU32 {
+	let capacity = params.max_candidate_depth.saturating_add(1).min(max);
+	NonZeroU32::new(core::cmp::max(capacity, 1)).expect("1 is the minimum value and non-zero; qed")
+}
+
+/// A consensus hook which derives the unincluded segment capacity from the asynchronous backing
+/// parameters of the relay chain configuration, instead of a constant.
+///
+/// When the relay chain is reconfigured, the capacity follows at the next relay parent. `MAX`
+/// bounds the capacity regardless of what the relay chain allows.
+pub struct RelayDrivenUnincludedSegment<const MAX: u32>;
+
+impl<const MAX: u32> ConsensusHook for RelayDrivenUnincludedSegment<MAX> {
+	fn on_state_proof(state_proof: &RelayChainStateProof) -> (Weight, UnincludedSegmentCapacity) {
+		let host_config = state_proof
+			.read_abridged_host_configuration()
+			.expect("host configuration is always part of the relay chain state proof; qed");
+
+		(
+			Weight::zero(),
+			capacity_from_async_backing_params(&host_config.async_backing_params, MAX).into(),
+		)
+	}
+}
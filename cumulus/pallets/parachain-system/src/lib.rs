@@ -29,8 +29,12 @@
 
 extern crate alloc;
 
-use alloc::{collections::btree_map::BTreeMap, vec, vec::Vec};
-use codec::{Decode, Encode};
+use alloc::{
+	collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+	vec,
+	vec::Vec,
+};
+use codec::{Decode, Encode, MaxEncodedLen};
 use core::{cmp, marker::PhantomData};
 use cumulus_primitives_core::{
 	relay_chain::{
@@ -49,7 +53,7 @@ use frame_support::{
 	ensure,
 	inherent::{InherentData, InherentIdentifier, ProvideInherent},
 	traits::{Get, HandleMessage},
-	weights::Weight,
+	weights::{Weight, WeightMeter},
 };
 use frame_system::{ensure_none, ensure_root, pallet_prelude::HeaderFor};
 use polkadot_parachain_primitives::primitives::RelayChainBlockNumber;
@@ -57,7 +61,7 @@ use polkadot_runtime_parachains::FeeTracker;
 use scale_info::TypeInfo;
 use sp_core::U256;
 use sp_runtime::{
-	traits::{Block as BlockT, BlockNumberProvider, Hash, One},
+	traits::{BlakeTwo256, Block as BlockT, BlockNumberProvider, Hash, One},
 	BoundedSlice, FixedU128, RuntimeDebug, Saturating,
 };
 use xcm::{latest::XcmHash, VersionedLocation, VersionedXcm};
@@ -78,13 +82,14 @@ pub mod consensus_hook;
 pub mod relay_state_snapshot;
 #[macro_use]
 pub mod validate_block;
+pub mod weight_reclaim;
 
 use unincluded_segment::{
 	Ancestor, HrmpChannelUpdate, HrmpWatermarkUpdate, OutboundBandwidthLimits, SegmentTracker,
 	UsedBandwidth,
 };
 
-pub use consensus_hook::{ConsensusHook, ExpectParentIncluded};
+pub use consensus_hook::{ConsensusHook, ExpectParentIncluded, RelayDrivenUnincludedSegment};
 /// Register the `validate_block` function that is used by parachains to validate blocks on a
 /// validator.
 ///
@@ -109,6 +114,7 @@ pub use consensus_hook::{ConsensusHook, ExpectParentIncluded};
 /// ```
 pub use cumulus_pallet_parachain_system_proc_macro::register_validate_block;
 pub use relay_state_snapshot::{MessagingStateSnapshot, RelayChainStateProof};
+pub use weight_reclaim::StorageWeightReclaim;
 
 pub use pallet::*;
 
@@ -174,6 +180,245 @@ impl CheckAssociatedRelayNumber for RelayNumberMonotonicallyIncreases {
 	}
 }
 
+const LOG_TARGET: &str = "runtime::parachain-system";
+
+/// The amount of proof size weight in bytes that needs to be wasted in a block before a warning
+/// about the missing storage weight reclaim is logged.
+const RECLAIM_WARNING_THRESHOLD: u64 = 16 * 1024;
+
+/// The number of relay chain blocks after its scheduled relay chain block within which a code
+/// upgrade scheduled with [`Pallet::schedule_code_upgrade_at`] must be submitted to the relay
+/// chain, before it is dropped.
+pub const SCHEDULED_CODE_UPGRADE_VALIDITY: RelayChainBlockNumber = 14_400;
+
+/// The progress of a code upgrade scheduled with [`Pallet::schedule_code_upgrade_at`].
+#[derive(Clone, Copy, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug, PartialEq, Eq)]
+pub enum ScheduledCodeUpgradeStatus {
+	/// The upgrade is authorized, but the validation code was not provided yet.
+	Authorized,
+	/// The validation code was provided and is submitted to the relay chain once the scheduled
+	/// relay chain block is reached.
+	Ready,
+	/// The validation code was submitted to the relay chain.
+	Submitted,
+	/// The relay chain is pre-checking the validation code.
+	PreChecking,
+	/// The relay chain accepted the validation code and will signal the go-ahead for the
+	/// upgrade.
+	PreCheckPassed,
+}
+
+/// A code upgrade scheduled with [`Pallet::schedule_code_upgrade_at`].
+#[derive(Clone, Encode, Decode, TypeInfo, RuntimeDebug, PartialEq, Eq)]
+pub struct ScheduledCodeUpgrade<Hash> {
+	/// The hash of the authorized code.
+	pub code_hash: Hash,
+	/// The relay chain block number from which on the code is submitted to the relay chain.
+	pub relay_block: RelayChainBlockNumber,
+	/// The hash of the validation code as seen by the relay chain, once the code was provided.
+	pub validation_code_hash: Option<relay_chain::ValidationCodeHash>,
+	/// The length of the validation code in bytes, once the code was provided.
+	pub code_len: u32,
+	/// The progress of the upgrade.
+	pub status: ScheduledCodeUpgradeStatus,
+}
+
+/// The sender of inbound messages.
+///
+/// Processing of inbound messages can be paused per sender, see
+/// [`Pallet::pause_inbound_messages`].
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	RuntimeDebug,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+)]
+pub enum InboundMessageSender {
+	/// The relay chain, sending downward messages.
+	Parent,
+	/// A sibling parachain, sending horizontal messages.
+	Sibling(ParaId),
+}
+
+/// An inbound message that was held back instead of being processed.
+#[derive(Clone, Encode, Decode, TypeInfo, RuntimeDebug, PartialEq, Eq)]
+pub struct HeldMessage {
+	/// The relay chain block number at which the message was sent.
+	pub sent_at: RelayChainBlockNumber,
+	/// The message itself.
+	pub data: Vec<u8>,
+}
+
+/// The indices of the held messages of a sender.
+///
+/// Messages are stored at the indices `head..tail`.
+#[derive(
+	Clone, Copy, Default, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug, PartialEq, Eq,
+)]
+pub struct HeldMessagesQueue {
+	/// The index of the oldest held message.
+	pub head: u32,
+	/// The index at which the next held message will be stored.
+	pub tail: u32,
+}
+
+impl HeldMessagesQueue {
+	/// The number of messages in the queue.
+	pub fn len(&self) -> u32 {
+		self.tail.saturating_sub(self.head)
+	}
+
+	/// Whether the queue is empty.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+/// The deferred messages of a sender tracked by [`MessageAdmission`].
+#[derive(Default)]
+struct DeferredMessagesState {
+	/// The queue of deferred messages of the sender.
+	queue: HeldMessagesQueue,
+	/// The number of deferred messages that were admitted in this block.
+	processed: u32,
+	/// The number of messages that were deferred in this block.
+	deferred: u32,
+}
+
+/// Admits inbound messages for processing as long as they fit into the weight limit, deferring
+/// all others to later blocks.
+///
+/// Messages of a sender are processed in order, so once a message of a sender was deferred all
+/// following messages of the sender are deferred as well. At least one message is admitted per
+/// block, so that a single message exceeding the weight of a whole block can not stall the
+/// processing of inbound messages forever.
+struct MessageAdmission<T> {
+	meter: WeightMeter,
+	admitted_any: bool,
+	senders: BTreeMap<InboundMessageSender, DeferredMessagesState>,
+	_config: PhantomData<T>,
+}
+
+impl<T: Config> MessageAdmission<T> {
+	fn new(weight_limit: Weight) -> Self {
+		Self {
+			meter: WeightMeter::with_limit(weight_limit),
+			admitted_any: false,
+			senders: BTreeMap::new(),
+			_config: PhantomData,
+		}
+	}
+
+	fn state(&mut self, sender: InboundMessageSender) -> &mut DeferredMessagesState {
+		self.senders.entry(sender).or_insert_with(|| DeferredMessagesState {
+			queue: DeferredMessagesQueues::<T>::get(sender).unwrap_or_default(),
+			..Default::default()
+		})
+	}
+
+	fn try_admit(&mut self, weight: Weight) -> bool {
+		if self.meter.try_consume(weight).is_err() {
+			if self.admitted_any {
+				return false
+			}
+			self.meter.consume(weight);
+		}
+		self.admitted_any = true;
+		true
+	}
+
+	/// Take the oldest deferred messages of `sender` that fit into the weight limit.
+	fn admit_deferred(
+		&mut self,
+		sender: InboundMessageSender,
+		message_weight: impl Fn(&HeldMessage) -> Weight,
+	) -> Vec<HeldMessage> {
+		let mut admitted = Vec::new();
+		loop {
+			let queue = self.state(sender).queue;
+			if queue.is_empty() {
+				break
+			}
+			match DeferredMessages::<T>::get(sender, queue.head) {
+				Some(message) => {
+					if !self.try_admit(message_weight(&message)) {
+						break
+					}
+					DeferredMessages::<T>::remove(sender, queue.head);
+					admitted.push(message);
+				},
+				None => defensive!("Deferred message is missing; skipping"),
+			}
+			let state = self.state(sender);
+			state.queue.head.saturating_inc();
+			state.processed.saturating_inc();
+		}
+		admitted
+	}
+
+	/// Admit a new message of `sender` with the given `weight`.
+	///
+	/// Returns `false` if the message was deferred instead.
+	fn admit(
+		&mut self,
+		sender: InboundMessageSender,
+		weight: Weight,
+		message: impl FnOnce() -> HeldMessage,
+	) -> bool {
+		if self.state(sender).queue.is_empty() && self.try_admit(weight) {
+			return true
+		}
+
+		let state = self.state(sender);
+		DeferredMessages::<T>::insert(sender, state.queue.tail, message());
+		state.queue.tail.saturating_inc();
+		state.deferred.saturating_inc();
+		false
+	}
+
+	/// Write back the queues of deferred messages and return the weight of the storage accesses.
+	fn finish(self) -> Weight {
+		let mut weight = Weight::zero();
+		for (sender, state) in self.senders {
+			let DeferredMessagesState { queue, processed, deferred } = state;
+			weight.saturating_accrue(
+				T::DbWeight::get()
+					.reads_writes(processed as u64 + 1, processed as u64 + deferred as u64 + 1),
+			);
+			if processed == 0 && deferred == 0 {
+				continue
+			}
+
+			if queue.is_empty() {
+				DeferredMessagesQueues::<T>::remove(sender);
+			} else {
+				DeferredMessagesQueues::<T>::insert(sender, queue);
+			}
+			if processed != 0 {
+				Pallet::<T>::deposit_event(Event::DeferredMessagesProcessed {
+					sender,
+					count: processed,
+					remaining: queue.len(),
+				});
+			}
+			if deferred != 0 {
+				Pallet::<T>::deposit_event(Event::InboundMessagesDeferred {
+					sender,
+					count: deferred,
+				});
+			}
+		}
+		weight
+	}
+}
+
 /// The max length of a DMP message.
 pub type MaxDmpMessageLenOf<T> = <<T as Config>::DmpQueue as HandleMessage>::MaxMessageLen;
 
@@ -472,6 +717,8 @@ pub mod pallet {
 				UnincludedSegment::<T>::append(ancestor);
 			}
 			HrmpOutboundMessages::<T>::put(outbound_messages);
+
+			Self::check_proof_size_reclaim();
 		}
 
 		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
@@ -508,8 +755,9 @@ pub mod pallet {
 			UpwardMessages::<T>::kill();
 			HrmpOutboundMessages::<T>::kill();
 			CustomValidationHeadData::<T>::kill();
+			ReclaimedProofSize::<T>::kill();
 
-			weight += T::DbWeight::get().writes(6);
+			weight += T::DbWeight::get().writes(7);
 
 			// Here, in `on_initialize` we must report the weight for both `on_initialize` and
 			// `on_finalize`.
@@ -653,10 +901,12 @@ pub mod pallet {
 					Self::deposit_event(Event::ValidationFunctionApplied {
 						relay_chain_block_num: vfp.relay_parent_number,
 					});
+					Self::conclude_scheduled_code_upgrade(true);
 				},
 				Some(relay_chain::UpgradeGoAhead::Abort) => {
 					<PendingValidationCode<T>>::kill();
 					Self::deposit_event(Event::ValidationFunctionDiscarded);
+					Self::conclude_scheduled_code_upgrade(false);
 				},
 				None => {},
 			}
@@ -680,16 +930,23 @@ pub mod pallet {
 			<RelevantMessagingState<T>>::put(relevant_messaging_state.clone());
 			<HostConfiguration<T>>::put(host_config);
 
+			total_weight.saturating_accrue(Self::progress_scheduled_code_upgrade(
+				&relay_state_proof,
+				vfp.relay_parent_number,
+			));
+
 			<T::OnSystemEvent as OnSystemEvent>::on_validation_data(&vfp);
 
 			total_weight.saturating_accrue(Self::enqueue_inbound_downward_messages(
 				relevant_messaging_state.dmq_mqc_head,
 				downward_messages,
+				Self::remaining_block_weight(total_weight),
 			));
 			total_weight.saturating_accrue(Self::enqueue_inbound_horizontal_messages(
 				&relevant_messaging_state.ingress_channels,
 				horizontal_messages,
 				vfp.relay_parent_number,
+				Self::remaining_block_weight(total_weight),
 			));
 
 			Ok(PostDispatchInfo { actual_weight: Some(total_weight), pays_fee: Pays::No })
@@ -708,6 +965,193 @@ pub mod pallet {
 
 		// WARNING: call indices 2 and 3 were used in a former version of this pallet. Using them
 		// again will require to bump the transaction version of runtimes using this pallet.
+
+		/// Schedule a code upgrade to the code with the given `code_hash`.
+		///
+		/// The upgrade is authorized right away, see
+		/// [`frame_system::Pallet::apply_authorized_upgrade`]. The provided code is held back
+		/// until the relay chain block `relay_block` is reached, only then it is submitted to the
+		/// relay chain. The progress of the PVF pre-checking on the relay chain is tracked and
+		/// reported with events. This requires the keys of [`RelayStateKeys`] of this pallet to
+		/// be part of the relay chain state proof.
+		///
+		/// Submitting the code is retried while the relay chain prohibits the upgrade, but the
+		/// upgrade is dropped if the code can never be submitted, or if it was not submitted
+		/// within [`SCHEDULED_CODE_UPGRADE_VALIDITY`] relay chain blocks after `relay_block`.
+		/// It can be cancelled with [`Pallet::cancel_scheduled_upgrade`] until it is submitted.
+		///
+		/// The dispatch origin for this call must be `Root`.
+		#[pallet::call_index(7)]
+		#[pallet::weight((T::DbWeight::get().reads_writes(2, 2), DispatchClass::Operational))]
+		pub fn schedule_code_upgrade_at(
+			origin: OriginFor<T>,
+			code_hash: T::Hash,
+			relay_block: RelayChainBlockNumber,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(
+				!ScheduledCodeUpgradeOf::<T>::exists(),
+				Error::<T>::CodeUpgradeAlreadyScheduled
+			);
+			ensure!(
+				relay_block > RelaychainDataProvider::<T>::current_block_number(),
+				Error::<T>::RelayBlockInThePast
+			);
+
+			frame_system::Pallet::<T>::do_authorize_upgrade(code_hash, true);
+			ScheduledCodeUpgradeOf::<T>::put(ScheduledCodeUpgrade {
+				code_hash,
+				relay_block,
+				validation_code_hash: None,
+				code_len: 0,
+				status: ScheduledCodeUpgradeStatus::Authorized,
+			});
+
+			Self::deposit_event(Event::CodeUpgradeScheduled { code_hash, relay_block });
+			Ok(())
+		}
+
+		/// Cancel the code upgrade scheduled with [`Pallet::schedule_code_upgrade_at`].
+		///
+		/// The upgrade authorization and the held back code are removed. An upgrade which was
+		/// already submitted to the relay chain can not be cancelled anymore.
+		///
+		/// The dispatch origin for this call must be `Root`.
+		#[pallet::call_index(8)]
+		#[pallet::weight((T::DbWeight::get().reads_writes(2, 3), DispatchClass::Operational))]
+		pub fn cancel_scheduled_upgrade(origin: OriginFor<T>) -> DispatchResult {
+			ensure_root(origin)?;
+			let scheduled =
+				ScheduledCodeUpgradeOf::<T>::get().ok_or(Error::<T>::NoScheduledCodeUpgrade)?;
+			ensure!(
+				matches!(
+					scheduled.status,
+					ScheduledCodeUpgradeStatus::Authorized | ScheduledCodeUpgradeStatus::Ready
+				),
+				Error::<T>::ScheduledCodeUpgradeSubmitted
+			);
+
+			Self::drop_scheduled_code_upgrade(&scheduled);
+			Self::deposit_event(Event::ScheduledCodeUpgradeCancelled);
+			Ok(())
+		}
+
+		/// Pause processing of inbound messages sent by `sender`.
+		///
+		/// Messages received from `sender` while it is paused are held back in the order they
+		/// were received, until they are replayed with [`Pallet::replay_held_messages`].
+		///
+		/// The dispatch origin for this call must be `Root`.
+		#[pallet::call_index(4)]
+		#[pallet::weight((T::DbWeight::get().reads_writes(1, 1), DispatchClass::Operational))]
+		pub fn pause_inbound_messages(
+			origin: OriginFor<T>,
+			sender: InboundMessageSender,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			PausedInboundSenders::<T>::try_mutate(|paused| {
+				ensure!(paused.insert(sender), Error::<T>::AlreadyPaused);
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::InboundMessagesPaused { sender });
+			Ok(())
+		}
+
+		/// Resume processing of inbound messages sent by `sender`.
+		///
+		/// Messages held back while `sender` was paused need to be replayed with
+		/// [`Pallet::replay_held_messages`]. Until all of them are replayed, new messages of
+		/// `sender` are held back as well, so that they are processed in the order they were
+		/// received.
+		///
+		/// The dispatch origin for this call must be `Root`.
+		#[pallet::call_index(5)]
+		#[pallet::weight((T::DbWeight::get().reads_writes(1, 1), DispatchClass::Operational))]
+		pub fn resume_inbound_messages(
+			origin: OriginFor<T>,
+			sender: InboundMessageSender,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			PausedInboundSenders::<T>::try_mutate(|paused| {
+				ensure!(paused.remove(&sender), Error::<T>::NotPaused);
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::InboundMessagesResumed { sender });
+			Ok(())
+		}
+
+		/// Process up to `max_count` of the messages held back for `sender`, oldest first.
+		///
+		/// Fails while there are deferred messages of `sender`, since these are older than the
+		/// held messages.
+		///
+		/// The dispatch origin for this call must be `Root`.
+		#[pallet::call_index(6)]
+		#[pallet::weight((
+			T::DbWeight::get()
+				.reads_writes(3, 1)
+				.saturating_add(
+					T::DbWeight::get().reads_writes((*max_count).into(), (*max_count).into()),
+				)
+				.saturating_add(T::WeightInfo::enqueue_inbound_downward_messages(*max_count))
+				.saturating_add(T::ReservedXcmpWeight::get()),
+			DispatchClass::Operational,
+		))]
+		pub fn replay_held_messages(
+			origin: OriginFor<T>,
+			sender: InboundMessageSender,
+			max_count: u32,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			ensure!(!PausedInboundSenders::<T>::get().contains(&sender), Error::<T>::SenderPaused);
+			ensure!(
+				!DeferredMessagesQueues::<T>::contains_key(sender),
+				Error::<T>::DeferredMessagesPending
+			);
+			let mut queue =
+				HeldMessagesQueues::<T>::get(sender).ok_or(Error::<T>::NoHeldMessages)?;
+
+			let end = queue.tail.min(queue.head.saturating_add(max_count));
+			let messages = (queue.head..end)
+				.filter_map(|index| HeldMessages::<T>::take(sender, index))
+				.collect::<Vec<_>>();
+			queue.head = end;
+
+			match sender {
+				InboundMessageSender::Parent => {
+					let bounded =
+						messages.iter().filter_map(|m| match BoundedSlice::try_from(&m.data[..]) {
+							Ok(bounded) => Some(bounded),
+							Err(_) => {
+								defensive!("Held downward message was too long; dropping");
+								None
+							},
+						});
+					T::DmpQueue::handle_messages(bounded);
+				},
+				InboundMessageSender::Sibling(para_id) => {
+					let message_iter = messages.iter().map(|m| (para_id, m.sent_at, &m.data[..]));
+					let max_weight = <ReservedXcmpWeightOverride<T>>::get()
+						.unwrap_or_else(T::ReservedXcmpWeight::get);
+					T::XcmpMessageHandler::handle_xcmp_messages(message_iter, max_weight);
+				},
+			}
+
+			if queue.is_empty() {
+				HeldMessagesQueues::<T>::remove(sender);
+			} else {
+				HeldMessagesQueues::<T>::insert(sender, queue);
+			}
+
+			Self::deposit_event(Event::HeldMessagesReplayed {
+				sender,
+				count: messages.len() as u32,
+				remaining: queue.len(),
+			});
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -725,6 +1169,38 @@ pub mod pallet {
 		DownwardMessagesProcessed { weight_used: Weight, dmq_head: relay_chain::Hash },
 		/// An upward message was sent to the relay chain.
 		UpwardMessageSent { message_hash: Option<XcmHash> },
+		/// Proof size weight was reclaimed after dispatching an extrinsic.
+		///
+		/// Only deposited with the `reclaim-diagnostics` feature enabled.
+		ProofSizeReclaimed { extrinsic_index: u32, reclaimed: u64 },
+		/// Processing of inbound messages of the given sender was paused.
+		InboundMessagesPaused { sender: InboundMessageSender },
+		/// Processing of inbound messages of the given sender was resumed.
+		InboundMessagesResumed { sender: InboundMessageSender },
+		/// Inbound messages of the given sender were held back instead of being processed.
+		InboundMessagesHeld { sender: InboundMessageSender, count: u32 },
+		/// Held back messages of the given sender were processed.
+		HeldMessagesReplayed { sender: InboundMessageSender, count: u32, remaining: u32 },
+		/// Inbound messages of the given sender were deferred, since processing them would have
+		/// exceeded the weight of the block.
+		InboundMessagesDeferred { sender: InboundMessageSender, count: u32 },
+		/// Deferred messages of the given sender were processed.
+		DeferredMessagesProcessed { sender: InboundMessageSender, count: u32, remaining: u32 },
+		/// A code upgrade was scheduled to be submitted at the given relay chain block.
+		CodeUpgradeScheduled { code_hash: T::Hash, relay_block: RelayChainBlockNumber },
+		/// The status of the scheduled code upgrade changed.
+		ScheduledCodeUpgradeStatusChanged { status: ScheduledCodeUpgradeStatus },
+		/// The scheduled code upgrade concluded, either by being applied or by being aborted by
+		/// the relay chain.
+		ScheduledCodeUpgradeConcluded { applied: bool },
+		/// The scheduled code upgrade was cancelled before being submitted to the relay chain.
+		ScheduledCodeUpgradeCancelled,
+		/// The scheduled code upgrade was dropped, since its code can not be submitted to the
+		/// relay chain.
+		ScheduledCodeUpgradeFailed { error: DispatchError },
+		/// The scheduled code upgrade was dropped, since it was not submitted to the relay chain
+		/// within [`SCHEDULED_CODE_UPGRADE_VALIDITY`] relay chain blocks.
+		ScheduledCodeUpgradeExpired,
 	}
 
 	#[pallet::error]
@@ -746,6 +1222,24 @@ pub mod pallet {
 		NothingAuthorized,
 		/// The given code upgrade has not been authorized.
 		Unauthorized,
+		/// Inbound messages of the given sender are already paused.
+		AlreadyPaused,
+		/// Inbound messages of the given sender are not paused.
+		NotPaused,
+		/// Held messages can not be replayed while the sender is paused.
+		SenderPaused,
+		/// There are no held messages for the given sender.
+		NoHeldMessages,
+		/// Held messages can not be replayed while older messages of the sender are deferred.
+		DeferredMessagesPending,
+		/// A code upgrade is already scheduled.
+		CodeUpgradeAlreadyScheduled,
+		/// The given relay chain block number is not in the future.
+		RelayBlockInThePast,
+		/// There is no scheduled code upgrade.
+		NoScheduledCodeUpgrade,
+		/// The scheduled code upgrade was already submitted to the relay chain.
+		ScheduledCodeUpgradeSubmitted,
 	}
 
 	/// Latest included block descendants the runtime accepted. In other words, these are
@@ -923,6 +1417,71 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type CustomValidationHeadData<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
 
+	/// The proof size in bytes reclaimed by [`StorageWeightReclaim`] in the current block.
+	///
+	/// `None` if the extension did not run in the current block. This will be cleared in
+	/// `on_initialize` of each new block.
+	#[pallet::storage]
+	#[pallet::whitelist_storage]
+	pub type ReclaimedProofSize<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+	/// The code upgrade scheduled with [`Pallet::schedule_code_upgrade_at`].
+	#[pallet::storage]
+	pub type ScheduledCodeUpgradeOf<T: Config> =
+		StorageValue<_, ScheduledCodeUpgrade<T::Hash>, OptionQuery>;
+
+	/// The validation code of the scheduled code upgrade, held back until it is submitted to the
+	/// relay chain.
+	#[pallet::storage]
+	pub type ScheduledValidationCode<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+	/// Senders whose inbound messages are held back instead of being processed.
+	#[pallet::storage]
+	pub type PausedInboundSenders<T: Config> =
+		StorageValue<_, BTreeSet<InboundMessageSender>, ValueQuery>;
+
+	/// The queue of held messages per sender.
+	///
+	/// The entry is removed once all held messages of the sender were replayed.
+	#[pallet::storage]
+	pub type HeldMessagesQueues<T: Config> =
+		StorageMap<_, Twox64Concat, InboundMessageSender, HeldMessagesQueue, OptionQuery>;
+
+	/// Inbound messages that were held back, indexed by their sender and their position in the
+	/// queue of the sender.
+	#[pallet::storage]
+	pub type HeldMessages<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		InboundMessageSender,
+		Twox64Concat,
+		u32,
+		HeldMessage,
+		OptionQuery,
+	>;
+
+	/// The queue of deferred messages per sender.
+	///
+	/// Inbound messages are deferred to later blocks if processing them would exceed the weight
+	/// of the block. The entry is removed once all deferred messages of the sender were
+	/// processed.
+	#[pallet::storage]
+	pub type DeferredMessagesQueues<T: Config> =
+		StorageMap<_, Twox64Concat, InboundMessageSender, HeldMessagesQueue, OptionQuery>;
+
+	/// Inbound messages that were deferred to later blocks, indexed by their sender and their
+	/// position in the queue of the sender.
+	#[pallet::storage]
+	pub type DeferredMessages<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		InboundMessageSender,
+		Twox64Concat,
+		u32,
+		HeldMessage,
+		OptionQuery,
+	>;
+
 	#[pallet::inherent]
 	impl<T: Config> ProvideInherent for Pallet<T> {
 		type Call = Call<T>;
@@ -974,6 +1533,60 @@ impl<T: Config> Pallet<T> {
 		let segment = UnincludedSegment::<T>::get();
 		crate::unincluded_segment::size_after_included(included_hash, &segment)
 	}
+
+	/// Returns the proof size in bytes reclaimed by [`StorageWeightReclaim`] in the current
+	/// block.
+	///
+	/// Returns `None` if the extension did not run in the current block.
+	pub fn reclaimed_proof_size() -> Option<u64> {
+		ReclaimedProofSize::<T>::get()
+	}
+
+	/// Note that `reclaimed` bytes of proof size weight were reclaimed after dispatching the
+	/// current extrinsic.
+	pub(crate) fn note_reclaimed_proof_size(reclaimed: u64) {
+		ReclaimedProofSize::<T>::mutate(|total| {
+			*total = Some(total.unwrap_or_default().saturating_add(reclaimed))
+		});
+
+		#[cfg(feature = "reclaim-diagnostics")]
+		Self::deposit_event(Event::ProofSizeReclaimed {
+			extrinsic_index: frame_system::Pallet::<T>::extrinsic_index().unwrap_or_default(),
+			reclaimed,
+		});
+	}
+
+	/// Warn if the proof size weight of the block is way above the proof size recorded by the
+	/// node.
+	///
+	/// This usually means that the runtime is not using [`StorageWeightReclaim`] or that the
+	/// extension is placed before other extensions that consume proof size weight.
+	fn check_proof_size_reclaim() {
+		let Some(recorded) = cumulus_primitives_storage_weight_reclaim::get_proof_size() else {
+			return
+		};
+		let weighed = frame_system::BlockWeight::<T>::get().total().proof_size();
+		let wasted = weighed.saturating_sub(recorded);
+
+		if wasted <= recorded || wasted <= RECLAIM_WARNING_THRESHOLD {
+			return
+		}
+
+		match ReclaimedProofSize::<T>::get() {
+			None => log::warn!(
+				target: LOG_TARGET,
+				"Block proof size weight is {weighed} bytes, but only {recorded} bytes were \
+				 recorded and no storage weight was reclaimed. Is `StorageWeightReclaim` part of \
+				 the transaction extensions of the runtime?",
+			),
+			Some(reclaimed) => log::warn!(
+				target: LOG_TARGET,
+				"Block proof size weight is {weighed} bytes, but only {recorded} bytes were \
+				 recorded although {reclaimed} bytes were reclaimed. Is `StorageWeightReclaim` \
+				 the last of the transaction extensions of the runtime?",
+			),
+		}
+	}
 }
 
 impl<T: Config> FeeTracker for Pallet<T> {
@@ -1102,6 +1715,9 @@ impl<T: Config> Pallet<T> {
 	/// Checks if the sequence of the messages is valid, dispatches them and communicates the
 	/// number of processed messages to the collator via a storage update.
 	///
+	/// Messages that don't fit into `weight_limit` are deferred to later blocks, see
+	/// [`MessageAdmission`].
+	///
 	/// # Panics
 	///
 	/// If it turns out that after processing all messages the Message Queue Chain
@@ -1109,11 +1725,12 @@ impl<T: Config> Pallet<T> {
 	fn enqueue_inbound_downward_messages(
 		expected_dmq_mqc_head: relay_chain::Hash,
 		downward_messages: Vec<InboundDownwardMessage>,
+		weight_limit: Weight,
 	) -> Weight {
 		let dm_count = downward_messages.len() as u32;
 		let mut dmq_head = <LastDmqMqcHead<T>>::get();
+		let sender = InboundMessageSender::Parent;
 
-		let weight_used = T::WeightInfo::enqueue_inbound_downward_messages(dm_count);
 		if dm_count != 0 {
 			Self::deposit_event(Event::DownwardMessagesReceived { count: dm_count });
 
@@ -1121,11 +1738,53 @@ impl<T: Config> Pallet<T> {
 			for m in &downward_messages {
 				dmq_head.extend_downward(m);
 			}
-			let bounded = downward_messages
+		}
+
+		let paused = PausedInboundSenders::<T>::get();
+		let mut weight_used = T::DbWeight::get().reads(2);
+		let base_weight = T::WeightInfo::enqueue_inbound_downward_messages(0);
+		let message_weight =
+			T::WeightInfo::enqueue_inbound_downward_messages(1).saturating_sub(base_weight);
+		let mut admission = MessageAdmission::<T>::new(
+			weight_limit.saturating_sub(base_weight).saturating_sub(weight_used),
+		);
+
+		// Deferred messages are older than the held messages, thus they are processed as long as
+		// the sender is not paused.
+		let deferred = if paused.contains(&sender) {
+			Vec::new()
+		} else {
+			admission.admit_deferred(sender, |_| message_weight)
+		};
+		let mut admitted = Vec::new();
+		if Self::must_hold_messages(&paused, sender) {
+			weight_used.saturating_accrue(Self::hold_messages(
+				sender,
+				downward_messages
+					.iter()
+					.map(|m| HeldMessage { sent_at: m.sent_at, data: m.msg.clone() }),
+			));
+		} else {
+			for m in &downward_messages {
+				if admission.admit(sender, message_weight, || HeldMessage {
+					sent_at: m.sent_at,
+					data: m.msg.clone(),
+				}) {
+					admitted.push(&m.msg[..]);
+				}
+			}
+		}
+
+		let processed = (deferred.len() + admitted.len()) as u32;
+		if processed != 0 {
+			let bounded = deferred
 				.iter()
-				// Note: we are not using `.defensive()` here since that prints the whole value to
-				// console. In case that the message is too long, this clogs up the log quite badly.
-				.filter_map(|m| match BoundedSlice::try_from(&m.msg[..]) {
+				.map(|m| &m.data[..])
+				.chain(admitted)
+				// Note: we are not using `.defensive()` here since that prints the whole value
+				// to console. In case that the message is too long, this clogs up the log
+				// quite badly.
+				.filter_map(|m| match BoundedSlice::try_from(m) {
 					Ok(bounded) => Some(bounded),
 					Err(_) => {
 						defensive!("Inbound Downward message was too long; dropping");
@@ -1133,6 +1792,11 @@ impl<T: Config> Pallet<T> {
 					},
 				});
 			T::DmpQueue::handle_messages(bounded);
+		}
+		weight_used.saturating_accrue(T::WeightInfo::enqueue_inbound_downward_messages(processed));
+		weight_used.saturating_accrue(admission.finish());
+
+		if dm_count != 0 {
 			<LastDmqMqcHead<T>>::put(&dmq_head);
 
 			Self::deposit_event(Event::DownwardMessagesProcessed {
@@ -1159,6 +1823,9 @@ impl<T: Config> Pallet<T> {
 	/// channels. It immediately dispatches signals and queues all other XCMs. Blob messages are
 	/// ignored.
 	///
+	/// Messages that don't fit into `weight_limit` are deferred to later blocks, see
+	/// [`MessageAdmission`].
+	///
 	/// **Panics** if either any of horizontal messages submitted by the collator was sent from
 	///            a para which has no open channel to this parachain or if after processing
 	///            messages across all inbound channels MQCs were obtained which do not
@@ -1167,6 +1834,7 @@ impl<T: Config> Pallet<T> {
 		ingress_channels: &[(ParaId, cumulus_primitives_core::AbridgedHrmpChannel)],
 		horizontal_messages: BTreeMap<ParaId, Vec<InboundHrmpMessage>>,
 		relay_parent_number: relay_chain::BlockNumber,
+		weight_limit: Weight,
 	) -> Weight {
 		// First, check that all submitted messages are sent from channels that exist. The
 		// channel exists if its MQC head is present in `vfp.hrmp_mqc_heads`.
@@ -1215,13 +1883,72 @@ impl<T: Config> Pallet<T> {
 					.extend_hrmp(horizontal_message);
 			}
 		}
-		let message_iter = horizontal_messages
+
+		// Hold back the messages of paused senders. The MQC heads above are still computed over
+		// all messages, since the relay chain considers them delivered.
+		let paused = PausedInboundSenders::<T>::get();
+		let mut must_hold = BTreeMap::new();
+		let (held_messages, horizontal_messages): (Vec<_>, Vec<_>) =
+			horizontal_messages.into_iter().partition(|(sender, _)| {
+				*must_hold.entry(*sender).or_insert_with(|| {
+					Self::must_hold_messages(&paused, InboundMessageSender::Sibling(*sender))
+				})
+			});
+		let mut weight_used = T::DbWeight::get().reads(must_hold.len() as u64 + 1);
+
+		let mut held_per_sender = BTreeMap::<ParaId, Vec<HeldMessage>>::new();
+		for (sender, message) in held_messages {
+			held_per_sender
+				.entry(sender)
+				.or_default()
+				.push(HeldMessage { sent_at: message.sent_at, data: message.data });
+		}
+		for (sender, messages) in held_per_sender {
+			weight_used.saturating_accrue(Self::hold_messages(
+				InboundMessageSender::Sibling(sender),
+				messages,
+			));
+		}
+
+		// Process the deferred messages of senders that are not paused first, since they are
+		// older than the messages received in this block.
+		let mut admission = MessageAdmission::<T>::new(weight_limit.saturating_sub(weight_used));
+		let mut deferred = Vec::new();
+		for deferred_sender in DeferredMessagesQueues::<T>::iter_keys() {
+			weight_used.saturating_accrue(T::DbWeight::get().reads(1));
+			let InboundMessageSender::Sibling(para_id) = deferred_sender else { continue };
+			if paused.contains(&deferred_sender) {
+				continue
+			}
+			let messages = admission.admit_deferred(deferred_sender, |m| {
+				T::XcmpMessageHandler::handle_xcmp_message_weight(para_id, &m.data)
+			});
+			deferred.extend(messages.into_iter().map(|m| (para_id, m)));
+		}
+
+		let mut admitted = Vec::new();
+		for (sender, message) in &horizontal_messages {
+			let weight = T::XcmpMessageHandler::handle_xcmp_message_weight(*sender, &message.data);
+			if admission.admit(InboundMessageSender::Sibling(*sender), weight, || HeldMessage {
+				sent_at: message.sent_at,
+				data: message.data.clone(),
+			}) {
+				admitted.push((*sender, message.sent_at, &message.data[..]));
+			}
+		}
+		weight_used.saturating_accrue(admission.finish());
+
+		let message_iter = deferred
 			.iter()
-			.map(|&(sender, ref message)| (sender, message.sent_at, &message.data[..]));
+			.map(|(sender, message)| (*sender, message.sent_at, &message.data[..]))
+			.chain(admitted);
 
 		let max_weight =
 			<ReservedXcmpWeightOverride<T>>::get().unwrap_or_else(T::ReservedXcmpWeight::get);
-		let weight_used = T::XcmpMessageHandler::handle_xcmp_messages(message_iter, max_weight);
+		weight_used.saturating_accrue(T::XcmpMessageHandler::handle_xcmp_messages(
+			message_iter,
+			max_weight,
+		));
 
 		// Check that the MQC heads for each channel provided by the relay chain match the MQC
 		// heads we have after processing all incoming messages.
@@ -1249,6 +1976,47 @@ impl<T: Config> Pallet<T> {
 		weight_used
 	}
 
+	/// Returns whether inbound messages of `sender` need to be held back.
+	///
+	/// This is the case while `sender` is paused and as long as there are held messages of
+	/// `sender` that were not replayed yet, so that messages are processed in order.
+	fn must_hold_messages(
+		paused: &BTreeSet<InboundMessageSender>,
+		sender: InboundMessageSender,
+	) -> bool {
+		paused.contains(&sender) || HeldMessagesQueues::<T>::contains_key(sender)
+	}
+
+	/// Append `messages` to the held messages of `sender`.
+	fn hold_messages(
+		sender: InboundMessageSender,
+		messages: impl IntoIterator<Item = HeldMessage>,
+	) -> Weight {
+		let mut queue = HeldMessagesQueues::<T>::get(sender).unwrap_or_default();
+		let mut count = 0u32;
+		for message in messages {
+			HeldMessages::<T>::insert(sender, queue.tail, message);
+			queue.tail.saturating_inc();
+			count.saturating_inc();
+		}
+
+		if count == 0 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		HeldMessagesQueues::<T>::insert(sender, queue);
+		Self::deposit_event(Event::InboundMessagesHeld { sender, count });
+
+		T::DbWeight::get().reads_writes(1, count as u64 + 1)
+	}
+
+	/// The weight that is left in the current block, given that `used` weight was consumed on
+	/// top of the already registered block weight.
+	fn remaining_block_weight(used: Weight) -> Weight {
+		let consumed = frame_system::Pallet::<T>::block_weight().total().saturating_add(used);
+		T::BlockWeights::get().max_block.saturating_sub(consumed)
+	}
+
 	/// Drop blocks from the unincluded segment with respect to the latest parachain head.
 	fn maybe_drop_included_ancestors(
 		relay_state_proof: &RelayChainStateProof,
@@ -1380,8 +2148,17 @@ impl<T: Config> Pallet<T> {
 		<HostConfiguration<T>>::get().map(|cfg| cfg.max_code_size)
 	}
 
-	/// The implementation of the runtime upgrade functionality for parachains.
-	pub fn schedule_code_upgrade(validation_function: Vec<u8>) -> DispatchResult {
+	/// The asynchronous backing parameters of the relay chain, as of the last relay parent.
+	///
+	/// Returns `None` if the relay chain parachain host configuration hasn't been submitted yet.
+	pub fn async_backing_params() -> Option<relay_chain::AsyncBackingParams> {
+		<HostConfiguration<T>>::get().map(|cfg| cfg.async_backing_params)
+	}
+
+	/// Ensure that a code upgrade with code of `code_len` bytes can be scheduled right now.
+	///
+	/// Reads four storage items.
+	fn ensure_can_schedule_code_upgrade(code_len: usize) -> DispatchResult {
 		// Ensure that `ValidationData` exists. We do not care about the validation data per se,
 		// but we do care about the [`UpgradeRestrictionSignal`] which arrives with the same
 		// inherent.
@@ -1390,7 +2167,14 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(!<PendingValidationCode<T>>::exists(), Error::<T>::OverlappingUpgrades);
 		let cfg = HostConfiguration::<T>::get().ok_or(Error::<T>::HostConfigurationNotAvailable)?;
-		ensure!(validation_function.len() <= cfg.max_code_size as usize, Error::<T>::TooBig);
+		ensure!(code_len <= cfg.max_code_size as usize, Error::<T>::TooBig);
+
+		Ok(())
+	}
+
+	/// The implementation of the runtime upgrade functionality for parachains.
+	pub fn schedule_code_upgrade(validation_function: Vec<u8>) -> DispatchResult {
+		Self::ensure_can_schedule_code_upgrade(validation_function.len())?;
 
 		// When a code upgrade is scheduled, it has to be applied in two
 		// places, synchronized: both polkadot and the individual parachain
@@ -1406,6 +2190,157 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Hold back `code` if it is the code of the upgrade scheduled with
+	/// [`Pallet::schedule_code_upgrade_at`].
+	///
+	/// Returns `false` if `code` does not belong to the scheduled code upgrade.
+	fn hold_scheduled_validation_code(code: &[u8]) -> bool {
+		let Some(mut scheduled) = ScheduledCodeUpgradeOf::<T>::get() else { return false };
+		if scheduled.status != ScheduledCodeUpgradeStatus::Authorized ||
+			scheduled.code_hash != T::Hashing::hash(code)
+		{
+			return false
+		}
+
+		scheduled.validation_code_hash = Some(BlakeTwo256::hash(code).into());
+		scheduled.code_len = code.len() as u32;
+		scheduled.status = ScheduledCodeUpgradeStatus::Ready;
+		ScheduledCodeUpgradeOf::<T>::put(scheduled);
+		ScheduledValidationCode::<T>::put(code.to_vec());
+		Self::deposit_event(Event::ScheduledCodeUpgradeStatusChanged {
+			status: ScheduledCodeUpgradeStatus::Ready,
+		});
+
+		true
+	}
+
+	/// Submit the scheduled validation code once the scheduled relay chain block is reached and
+	/// track the PVF pre-checking of the relay chain afterwards.
+	fn progress_scheduled_code_upgrade(
+		relay_state_proof: &RelayChainStateProof,
+		relay_parent_number: RelayChainBlockNumber,
+	) -> Weight {
+		let Some(mut scheduled) = ScheduledCodeUpgradeOf::<T>::get() else {
+			return T::DbWeight::get().reads(1)
+		};
+		let mut weight = T::DbWeight::get().reads(1);
+		let expired = relay_parent_number >=
+			scheduled.relay_block.saturating_add(SCHEDULED_CODE_UPGRADE_VALIDITY);
+
+		let new_status = match scheduled.status {
+			ScheduledCodeUpgradeStatus::Authorized if expired => {
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 3));
+				Self::drop_scheduled_code_upgrade(&scheduled);
+				Self::deposit_event(Event::ScheduledCodeUpgradeExpired);
+				return weight
+			},
+			ScheduledCodeUpgradeStatus::Ready if relay_parent_number >= scheduled.relay_block => {
+				weight.saturating_accrue(T::DbWeight::get().reads(4));
+				// Only read the code once it can actually be submitted, failed attempts are
+				// retried in the next block, e.g. once the upgrade restriction is lifted.
+				let submitted = Self::ensure_can_schedule_code_upgrade(scheduled.code_len as usize)
+					.and_then(|()| {
+						weight.saturating_accrue(
+							T::DbWeight::get()
+								.reads_writes(1, 4)
+								.saturating_add(Weight::from_parts(0, scheduled.code_len.into())),
+						);
+						let code = ScheduledValidationCode::<T>::get().unwrap_or_default();
+						Self::schedule_code_upgrade(code)
+					});
+				match submitted {
+					Ok(()) => {
+						ScheduledValidationCode::<T>::kill();
+						Some(ScheduledCodeUpgradeStatus::Submitted)
+					},
+					// The code can not shrink, so retrying is pointless.
+					Err(error) if error == Error::<T>::TooBig.into() => {
+						weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 3));
+						Self::drop_scheduled_code_upgrade(&scheduled);
+						Self::deposit_event(Event::ScheduledCodeUpgradeFailed { error });
+						return weight
+					},
+					Err(_) if expired => {
+						weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 3));
+						Self::drop_scheduled_code_upgrade(&scheduled);
+						Self::deposit_event(Event::ScheduledCodeUpgradeExpired);
+						return weight
+					},
+					Err(error) => {
+						log::debug!(
+							target: LOG_TARGET,
+							"Failed to submit the scheduled code upgrade: {error:?}",
+						);
+						None
+					},
+				}
+			},
+			ScheduledCodeUpgradeStatus::Submitted | ScheduledCodeUpgradeStatus::PreChecking => {
+				let para_id = T::SelfParaId::get();
+				let future_code_hash = relay_state_proof
+					.read_optional_entry::<relay_chain::ValidationCodeHash>(
+						&relay_chain::well_known_keys::future_code_hash(para_id),
+					)
+					.ok()
+					.flatten();
+				let pre_checking = scheduled.validation_code_hash.map_or(false, |code_hash| {
+					matches!(
+						relay_state_proof.read_optional_entry::<()>(
+							&relay_chain::well_known_keys::pvf_active_vote(code_hash),
+						),
+						Ok(Some(()))
+					)
+				});
+
+				if future_code_hash.is_some() && future_code_hash == scheduled.validation_code_hash
+				{
+					Some(ScheduledCodeUpgradeStatus::PreCheckPassed)
+				} else if pre_checking && scheduled.status == ScheduledCodeUpgradeStatus::Submitted
+				{
+					Some(ScheduledCodeUpgradeStatus::PreChecking)
+				} else {
+					None
+				}
+			},
+			_ => None,
+		};
+
+		if let Some(status) = new_status {
+			scheduled.status = status;
+			ScheduledCodeUpgradeOf::<T>::put(scheduled);
+			weight.saturating_accrue(T::DbWeight::get().writes(1));
+			Self::deposit_event(Event::ScheduledCodeUpgradeStatusChanged { status });
+		}
+
+		weight
+	}
+
+	/// Remove the code upgrade scheduled with [`Pallet::schedule_code_upgrade_at`] before it was
+	/// submitted to the relay chain, together with its authorization and held back code.
+	fn drop_scheduled_code_upgrade(scheduled: &ScheduledCodeUpgrade<T::Hash>) {
+		if scheduled.status == ScheduledCodeUpgradeStatus::Authorized {
+			frame_system::Pallet::<T>::do_revoke_authorized_upgrade(scheduled.code_hash);
+		}
+		ScheduledValidationCode::<T>::kill();
+		ScheduledCodeUpgradeOf::<T>::kill();
+	}
+
+	/// Remove the code upgrade scheduled with [`Pallet::schedule_code_upgrade_at`] once the relay
+	/// chain signaled whether it is `applied`.
+	fn conclude_scheduled_code_upgrade(applied: bool) {
+		let submitted = ScheduledCodeUpgradeOf::<T>::get().map_or(false, |scheduled| {
+			!matches!(
+				scheduled.status,
+				ScheduledCodeUpgradeStatus::Authorized | ScheduledCodeUpgradeStatus::Ready
+			)
+		});
+
+		if submitted {
+			ScheduledCodeUpgradeOf::<T>::kill();
+			Self::deposit_event(Event::ScheduledCodeUpgradeConcluded { applied });
+		}
+	}
+
 	/// Returns the [`CollationInfo`] of the current active block.
 	///
 	/// The given `header` is the header of the built block we are collecting the collation info
@@ -1540,6 +2475,10 @@ impl<T: Config> Pallet<T> {
 pub struct ParachainSetCode<T>(core::marker::PhantomData<T>);
 impl<T: Config> frame_system::SetCode<T> for ParachainSetCode<T> {
 	fn set_code(code: Vec<u8>) -> DispatchResult {
+		if Pallet::<T>::hold_scheduled_validation_code(&code) {
+			return Ok(())
+		}
+
 		Pallet::<T>::schedule_code_upgrade(code)
 	}
 }
@@ -1714,6 +2653,68 @@ pub trait RelaychainStateProvider {
 	fn set_current_relay_chain_state(_state: RelayChainState) {}
 }
 
+/// Something that needs to read relay chain storage in every block.
+///
+/// The keys of all implementations that are combined in the
+/// [`RelayStateKeysApi`](cumulus_primitives_core::RelayStateKeysApi) of the runtime are proven
+/// by the collator as part of the relay chain state proof. Their values can then be read with a
+/// [`RelayStateReader`].
+pub trait RelayStateKeys {
+	/// The relay chain storage keys to include in the relay chain state proof.
+	fn relay_state_keys() -> Vec<Vec<u8>>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl RelayStateKeys for Tuple {
+	fn relay_state_keys() -> Vec<Vec<u8>> {
+		let mut keys = Vec::new();
+		for_tuples!( #( keys.extend(Tuple::relay_state_keys()); )* );
+		keys
+	}
+}
+
+/// Requests the relay chain storage keys required to track the PVF pre-checking of a code upgrade
+/// scheduled with [`Pallet::schedule_code_upgrade_at`].
+impl<T: Config> RelayStateKeys for Pallet<T> {
+	fn relay_state_keys() -> Vec<Vec<u8>> {
+		let Some(scheduled) = ScheduledCodeUpgradeOf::<T>::get() else { return Vec::new() };
+
+		match (scheduled.status, scheduled.validation_code_hash) {
+			(
+				ScheduledCodeUpgradeStatus::Submitted | ScheduledCodeUpgradeStatus::PreChecking,
+				Some(code_hash),
+			) => vec![
+				relay_chain::well_known_keys::future_code_hash(T::SelfParaId::get()),
+				relay_chain::well_known_keys::pvf_active_vote(code_hash),
+			],
+			_ => Vec::new(),
+		}
+	}
+}
+
+/// Error returned by [`RelayStateReader::read_relay_state`].
+#[derive(RuntimeDebug, PartialEq, Eq)]
+pub enum RelayStateReadError {
+	/// The relay chain state proof of the current block is not available, e.g. in
+	/// `on_initialize`.
+	NotAvailable,
+	/// The key is not part of the relay chain state proof.
+	NotProven,
+	/// The value can not be decoded.
+	Decode,
+}
+
+/// Reads relay chain storage from the relay chain state proof of the current block.
+///
+/// Only keys that are always proven for `parachain-system` and keys declared through
+/// [`RelayStateKeys`] are part of the proof.
+pub trait RelayStateReader {
+	/// Read and decode the value stored under `key` in the relay chain state.
+	///
+	/// Returns `Ok(None)` if no value is stored under `key`.
+	fn read_relay_state<V: Decode>(key: &[u8]) -> Result<Option<V>, RelayStateReadError>;
+}
+
 /// Implements [`BlockNumberProvider`] that returns relay chain block number fetched from validation
 /// data.
 ///
@@ -1784,3 +2785,27 @@ impl<T: Config> RelaychainStateProvider for RelaychainDataProvider<T> {
 		ValidationData::<T>::put(validation_data)
 	}
 }
+
+impl<T: Config> RelayStateReader for RelaychainDataProvider<T> {
+	fn read_relay_state<V: Decode>(key: &[u8]) -> Result<Option<V>, RelayStateReadError> {
+		let (Some(validation_data), Some(relay_state_proof)) =
+			(ValidationData::<T>::get(), RelayStateProof::<T>::get())
+		else {
+			return Err(RelayStateReadError::NotAvailable)
+		};
+
+		let relay_state_proof = RelayChainStateProof::new(
+			T::SelfParaId::get(),
+			validation_data.relay_parent_storage_root,
+			relay_state_proof,
+		)
+		.map_err(|_| RelayStateReadError::NotProven)?;
+
+		relay_state_proof.read_optional_entry(key).map_err(|err| match err {
+			relay_state_snapshot::Error::ReadOptionalEntry(
+				relay_state_snapshot::ReadEntryErr::Decode,
+			) => RelayStateReadError::Decode,
+			_ => RelayStateReadError::NotProven,
+		})
+	}
+}
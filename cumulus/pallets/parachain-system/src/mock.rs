@@ -122,6 +122,7 @@ impl pallet_message_queue::Config for Test {
 	type Size = u32;
 	type QueueChangeHandler = ();
 	type QueuePausedQuery = ();
+	type ServiceWeightShare = ();
 	type HeapSize = sp_core::ConstU32<{ 103 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MaxWeight;
@@ -139,6 +140,7 @@ std::thread_local! {
 	pub static HANDLED_DMP_MESSAGES: RefCell<Vec<Vec<u8>>> = RefCell::new(Vec::new());
 	pub static HANDLED_XCMP_MESSAGES: RefCell<Vec<(ParaId, relay_chain::BlockNumber, Vec<u8>)>> = RefCell::new(Vec::new());
 	pub static SENT_MESSAGES: RefCell<Vec<(ParaId, Vec<u8>)>> = RefCell::new(Vec::new());
+	pub static XCMP_MESSAGE_WEIGHT: RefCell<Weight> = RefCell::new(Weight::zero());
 }
 
 pub fn send_message(dest: ParaId, message: Vec<u8>) {
@@ -212,6 +214,10 @@ impl XcmpMessageHandler for SaveIntoThreadLocal {
 			Weight::zero()
 		})
 	}
+
+	fn handle_xcmp_message_weight(_sender: ParaId, _data: &[u8]) -> Weight {
+		XCMP_MESSAGE_WEIGHT.with(|w| *w.borrow())
+	}
 }
 
 // This function basically just builds a genesis storage key/value store according to
@@ -219,6 +225,7 @@ impl XcmpMessageHandler for SaveIntoThreadLocal {
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	HANDLED_DMP_MESSAGES.with(|m| m.borrow_mut().clear());
 	HANDLED_XCMP_MESSAGES.with(|m| m.borrow_mut().clear());
+	XCMP_MESSAGE_WEIGHT.with(|w| *w.borrow_mut() = Weight::zero());
 
 	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
 }
@@ -17,11 +17,11 @@
 #![cfg(test)]
 
 use super::*;
-use crate::mock::*;
+use crate::{consensus_hook::capacity_from_async_backing_params, mock::*};
 
 use core::num::NonZeroU32;
 use cumulus_primitives_core::{AbridgedHrmpChannel, InboundDownwardMessage, InboundHrmpMessage};
-use frame_support::{assert_ok, parameter_types, weights::Weight};
+use frame_support::{assert_noop, assert_ok, parameter_types, weights::Weight};
 use frame_system::RawOrigin;
 use hex_literal::hex;
 use rand::Rng;
@@ -134,6 +134,47 @@ fn unincluded_segment_is_limited() {
 		.add(124, || {}); // The previous block wasn't included yet, should panic in `create_inherent`.
 }
 
+#[test]
+fn relay_driven_unincluded_segment_follows_async_backing_params() {
+	CONSENSUS_HOOK.with(|c| {
+		*c.borrow_mut() = Box::new(|s| RelayDrivenUnincludedSegment::<10>::on_state_proof(s))
+	});
+
+	BlockTests::new()
+		.with_inclusion_delay(1)
+		.with_relay_sproof_builder(|_, _, builder| {
+			builder.host_config.async_backing_params.max_candidate_depth = 1;
+		})
+		.add_with_post_test(
+			123,
+			|| {},
+			|| {
+				assert_eq!(
+					ParachainSystem::async_backing_params().map(|p| p.max_candidate_depth),
+					Some(1)
+				);
+				assert_eq!(<UnincludedSegment<Test>>::get().len(), 1);
+			},
+		)
+		.add_with_post_test(
+			124,
+			|| {},
+			|| {
+				// Capacity of `max_candidate_depth + 1` allows an unincluded parent.
+				assert_eq!(<UnincludedSegment<Test>>::get().len(), 2);
+			},
+		);
+
+	assert_eq!(
+		capacity_from_async_backing_params(
+			&relay_chain::AsyncBackingParams { max_candidate_depth: 5, allowed_ancestry_len: 2 },
+			3,
+		)
+		.get(),
+		3
+	);
+}
+
 #[test]
 fn unincluded_code_upgrade_handles_signal() {
 	CONSENSUS_HOOK.with(|c| {
@@ -1304,3 +1345,475 @@ fn ump_fee_factor_increases_and_decreases() {
 			},
 		);
 }
+
+#[test]
+fn reclaimed_proof_size_is_tracked_per_block() {
+	BlockTests::new()
+		.add_with_post_test(
+			1,
+			|| {
+				assert_eq!(ParachainSystem::reclaimed_proof_size(), None);
+
+				ParachainSystem::note_reclaimed_proof_size(100);
+				ParachainSystem::note_reclaimed_proof_size(0);
+				ParachainSystem::note_reclaimed_proof_size(50);
+			},
+			|| {
+				assert_eq!(ParachainSystem::reclaimed_proof_size(), Some(150));
+			},
+		)
+		.add_with_post_test(
+			2,
+			|| {},
+			|| {
+				assert_eq!(ParachainSystem::reclaimed_proof_size(), None);
+			},
+		);
+}
+
+#[test]
+fn paused_hrmp_messages_are_held_and_replayed_in_order() {
+	const ALICE: ParaId = ParaId::new(300);
+	const SENDER: InboundMessageSender = InboundMessageSender::Sibling(ALICE);
+
+	fn mqc_head(up_to: u32) -> Option<relay_chain::Hash> {
+		let mut mqc = MessageQueueChain::default();
+		for sent_at in 1..=up_to {
+			mqc.extend_hrmp(&mk_hrmp(sent_at));
+		}
+		Some(mqc.head())
+	}
+
+	fn take_handled() -> Vec<(ParaId, relay_chain::BlockNumber, Vec<u8>)> {
+		HANDLED_XCMP_MESSAGES.with(|m| core::mem::take(&mut *m.borrow_mut()))
+	}
+
+	BlockTests::new()
+		.with_relay_sproof_builder(|_, relay_block_num, sproof| {
+			sproof.upsert_inbound_channel(ALICE).mqc_head = mqc_head(relay_block_num);
+		})
+		.with_inherent_data(|_, relay_block_num, data| {
+			data.horizontal_messages.insert(ALICE, vec![mk_hrmp(relay_block_num)]);
+		})
+		.add(1, || {
+			assert_eq!(take_handled(), vec![(ALICE, 1, b"1".to_vec())]);
+
+			assert_ok!(ParachainSystem::pause_inbound_messages(RawOrigin::Root.into(), SENDER));
+			assert_noop!(
+				ParachainSystem::pause_inbound_messages(RawOrigin::Root.into(), SENDER),
+				Error::<Test>::AlreadyPaused
+			);
+		})
+		.add(2, || {
+			// Held back, since the sender is paused.
+			assert!(take_handled().is_empty());
+			assert_eq!(
+				HeldMessagesQueues::<Test>::get(SENDER),
+				Some(HeldMessagesQueue { head: 0, tail: 1 })
+			);
+			assert_noop!(
+				ParachainSystem::replay_held_messages(RawOrigin::Root.into(), SENDER, 10),
+				Error::<Test>::SenderPaused
+			);
+
+			assert_ok!(ParachainSystem::resume_inbound_messages(RawOrigin::Root.into(), SENDER));
+			assert_noop!(
+				ParachainSystem::resume_inbound_messages(RawOrigin::Root.into(), SENDER),
+				Error::<Test>::NotPaused
+			);
+		})
+		.add(3, || {
+			// Still held back, since the messages of block 2 were not replayed yet.
+			assert!(take_handled().is_empty());
+
+			assert_ok!(ParachainSystem::replay_held_messages(RawOrigin::Root.into(), SENDER, 1));
+			assert_eq!(take_handled(), vec![(ALICE, 2, b"2".to_vec())]);
+			System::assert_last_event(
+				crate::Event::HeldMessagesReplayed { sender: SENDER, count: 1, remaining: 1 }
+					.into(),
+			);
+
+			assert_ok!(ParachainSystem::replay_held_messages(RawOrigin::Root.into(), SENDER, 10));
+			assert_eq!(take_handled(), vec![(ALICE, 3, b"3".to_vec())]);
+			assert!(!HeldMessagesQueues::<Test>::contains_key(SENDER));
+			assert_noop!(
+				ParachainSystem::replay_held_messages(RawOrigin::Root.into(), SENDER, 10),
+				Error::<Test>::NoHeldMessages
+			);
+		})
+		.add(4, || {
+			assert_eq!(take_handled(), vec![(ALICE, 4, b"4".to_vec())]);
+		});
+}
+
+#[test]
+#[cfg(not(feature = "runtime-benchmarks"))]
+fn paused_dmp_messages_are_held_and_replayed() {
+	BlockTests::new()
+		.with_relay_sproof_builder(|_, relay_block_num, sproof| {
+			let mut mqc = MessageQueueChain::default();
+			for sent_at in 1..=relay_block_num.min(2) {
+				mqc.extend_downward(&mk_dmp(sent_at));
+			}
+			sproof.dmq_mqc_head = Some(mqc.head());
+		})
+		.with_inherent_data(|_, relay_block_num, data| {
+			if relay_block_num <= 2 {
+				data.downward_messages.push(mk_dmp(relay_block_num));
+			}
+		})
+		.add(1, || {
+			HANDLED_DMP_MESSAGES.with(|m| {
+				assert_eq!(&*m.borrow(), &[mk_dmp(1).msg]);
+				m.borrow_mut().clear();
+			});
+
+			assert_ok!(ParachainSystem::pause_inbound_messages(
+				RawOrigin::Root.into(),
+				InboundMessageSender::Parent
+			));
+		})
+		.add(2, || {
+			HANDLED_DMP_MESSAGES.with(|m| assert!(m.borrow().is_empty()));
+			assert_eq!(
+				HeldMessages::<Test>::get(InboundMessageSender::Parent, 0),
+				Some(HeldMessage { sent_at: 2, data: mk_dmp(2).msg })
+			);
+
+			assert_ok!(ParachainSystem::resume_inbound_messages(
+				RawOrigin::Root.into(),
+				InboundMessageSender::Parent
+			));
+			assert_ok!(ParachainSystem::replay_held_messages(
+				RawOrigin::Root.into(),
+				InboundMessageSender::Parent,
+				10
+			));
+		})
+		.add(3, || {
+			// The replayed message is processed by the message queue in the next block.
+			HANDLED_DMP_MESSAGES.with(|m| assert_eq!(&*m.borrow(), &[mk_dmp(2).msg]));
+			assert!(!HeldMessagesQueues::<Test>::contains_key(InboundMessageSender::Parent));
+		});
+}
+
+#[test]
+fn hrmp_messages_exceeding_the_block_weight_are_deferred() {
+	const ALICE: ParaId = ParaId::new(300);
+	const SENDER: InboundMessageSender = InboundMessageSender::Sibling(ALICE);
+
+	fn take_handled() -> Vec<(ParaId, relay_chain::BlockNumber, Vec<u8>)> {
+		HANDLED_XCMP_MESSAGES.with(|m| core::mem::take(&mut *m.borrow_mut()))
+	}
+
+	BlockTests::new()
+		.with_relay_sproof_builder(|_, _, sproof| {
+			let mut mqc = MessageQueueChain::default();
+			for _ in 0..3 {
+				mqc.extend_hrmp(&mk_hrmp(1));
+			}
+			sproof.upsert_inbound_channel(ALICE).mqc_head = Some(mqc.head());
+		})
+		.with_inherent_data(|_, relay_block_num, data| {
+			// Only two of the three messages fit into the block.
+			let max_block = <Test as frame_system::Config>::BlockWeights::get().max_block;
+			XCMP_MESSAGE_WEIGHT
+				.with(|w| *w.borrow_mut() = Weight::from_parts(max_block.ref_time() / 3, 0));
+			if relay_block_num == 1 {
+				data.horizontal_messages.insert(ALICE, vec![mk_hrmp(1); 3]);
+			}
+		})
+		.add(1, || {
+			assert_eq!(take_handled(), vec![(ALICE, 1, b"1".to_vec()); 2]);
+			assert_eq!(
+				DeferredMessagesQueues::<Test>::get(SENDER),
+				Some(HeldMessagesQueue { head: 0, tail: 1 })
+			);
+			System::assert_has_event(
+				crate::Event::InboundMessagesDeferred { sender: SENDER, count: 1 }.into(),
+			);
+		})
+		.add(2, || {
+			assert_eq!(take_handled(), vec![(ALICE, 1, b"1".to_vec())]);
+			assert!(!DeferredMessagesQueues::<Test>::contains_key(SENDER));
+			assert!(DeferredMessages::<Test>::get(SENDER, 0).is_none());
+			System::assert_has_event(
+				crate::Event::DeferredMessagesProcessed { sender: SENDER, count: 1, remaining: 0 }
+					.into(),
+			);
+		});
+}
+
+#[test]
+#[cfg(not(feature = "runtime-benchmarks"))]
+fn dmp_messages_are_processed_one_per_block_if_the_block_is_full() {
+	const SENDER: InboundMessageSender = InboundMessageSender::Parent;
+
+	BlockTests::new()
+		.with_relay_sproof_builder(|_, _, sproof| {
+			let mut mqc = MessageQueueChain::default();
+			for sent_at in 1..=3 {
+				mqc.extend_downward(&mk_dmp(sent_at));
+			}
+			sproof.dmq_mqc_head = Some(mqc.head());
+		})
+		.with_inherent_data(|_, relay_block_num, data| {
+			// Nothing fits into the block, but at least one message is processed per block.
+			let max_block = <Test as frame_system::Config>::BlockWeights::get().max_block;
+			System::register_extra_weight_unchecked(
+				max_block,
+				frame_support::dispatch::DispatchClass::Mandatory,
+			);
+			if relay_block_num == 1 {
+				data.downward_messages.extend((1..=3).map(mk_dmp));
+			}
+		})
+		.add(1, || {
+			HANDLED_DMP_MESSAGES.with(|m| {
+				assert_eq!(&*m.borrow(), &[mk_dmp(1).msg]);
+				m.borrow_mut().clear();
+			});
+			assert_eq!(
+				DeferredMessagesQueues::<Test>::get(SENDER),
+				Some(HeldMessagesQueue { head: 0, tail: 2 })
+			);
+			assert_noop!(
+				ParachainSystem::replay_held_messages(RawOrigin::Root.into(), SENDER, 10),
+				Error::<Test>::DeferredMessagesPending
+			);
+		})
+		.add(2, || {
+			HANDLED_DMP_MESSAGES.with(|m| {
+				assert_eq!(&*m.borrow(), &[mk_dmp(2).msg]);
+				m.borrow_mut().clear();
+			});
+			assert_eq!(
+				DeferredMessagesQueues::<Test>::get(SENDER),
+				Some(HeldMessagesQueue { head: 1, tail: 2 })
+			);
+		})
+		.add(3, || {
+			HANDLED_DMP_MESSAGES.with(|m| assert_eq!(&*m.borrow(), &[mk_dmp(3).msg]));
+			assert!(!DeferredMessagesQueues::<Test>::contains_key(SENDER));
+		});
+}
+
+#[test]
+fn relay_state_reader_reads_proven_keys() {
+	const KEY: &[u8] = b"relay_state_key";
+
+	BlockTests::new()
+		.with_relay_sproof_builder(|_, _, sproof| {
+			sproof.additional_key_values.push((KEY.to_vec(), 42u32.encode()));
+		})
+		.add(1, || {
+			type Reader = RelaychainDataProvider<Test>;
+
+			assert_eq!(Reader::read_relay_state::<u32>(KEY), Ok(Some(42)));
+			assert_eq!(Reader::read_relay_state::<u64>(KEY), Err(RelayStateReadError::Decode));
+			// Proven to be absent, as the proof contains the node of `KEY`.
+			assert_eq!(Reader::read_relay_state::<u32>(b"relay_state_key_absent"), Ok(None));
+		});
+
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			RelaychainDataProvider::<Test>::read_relay_state::<u32>(KEY),
+			Err(RelayStateReadError::NotAvailable)
+		);
+	});
+}
+
+#[test]
+fn scheduled_code_upgrade_is_submitted_at_relay_block_and_tracks_pre_checking() {
+	const CODE: &[u8] = b"scheduled code";
+
+	fn validation_code_hash() -> relay_chain::ValidationCodeHash {
+		relay_chain::ValidationCode(CODE.to_vec()).hash()
+	}
+
+	fn status() -> Option<ScheduledCodeUpgradeStatus> {
+		ScheduledCodeUpgradeOf::<Test>::get().map(|scheduled| scheduled.status)
+	}
+
+	BlockTests::new()
+		.with_relay_sproof_builder(|_, relay_block_num, sproof| match relay_block_num {
+			4 => sproof.additional_key_values.push((
+				relay_chain::well_known_keys::pvf_active_vote(validation_code_hash()),
+				vec![0],
+			)),
+			5 => sproof.additional_key_values.push((
+				relay_chain::well_known_keys::future_code_hash(ParachainId::get()),
+				validation_code_hash().encode(),
+			)),
+			6 => sproof.upgrade_go_ahead = Some(relay_chain::UpgradeGoAhead::GoAhead),
+			_ => {},
+		})
+		.add(1, || {
+			let code_hash = <Test as frame_system::Config>::Hashing::hash(CODE);
+			assert_noop!(
+				ParachainSystem::schedule_code_upgrade_at(RawOrigin::Root.into(), code_hash, 1),
+				Error::<Test>::RelayBlockInThePast
+			);
+			assert_ok!(ParachainSystem::schedule_code_upgrade_at(
+				RawOrigin::Root.into(),
+				code_hash,
+				3
+			));
+			assert_noop!(
+				ParachainSystem::schedule_code_upgrade_at(RawOrigin::Root.into(), code_hash, 3),
+				Error::<Test>::CodeUpgradeAlreadyScheduled
+			);
+
+			assert_ok!(System::apply_authorized_upgrade(RawOrigin::None.into(), CODE.to_vec()));
+			assert_eq!(status(), Some(ScheduledCodeUpgradeStatus::Ready));
+			assert_eq!(
+				ScheduledCodeUpgradeOf::<Test>::get().map(|scheduled| scheduled.code_len),
+				Some(CODE.len() as u32)
+			);
+			assert!(!PendingValidationCode::<Test>::exists());
+		})
+		.add(2, || {
+			assert_eq!(status(), Some(ScheduledCodeUpgradeStatus::Ready));
+			assert!(!PendingValidationCode::<Test>::exists());
+		})
+		.add(3, || {
+			assert_eq!(status(), Some(ScheduledCodeUpgradeStatus::Submitted));
+			assert_eq!(PendingValidationCode::<Test>::get(), CODE.to_vec());
+			assert!(!ScheduledValidationCode::<Test>::exists());
+		})
+		.add(4, || {
+			assert_eq!(status(), Some(ScheduledCodeUpgradeStatus::PreChecking));
+			assert_eq!(
+				<ParachainSystem as RelayStateKeys>::relay_state_keys(),
+				vec![
+					relay_chain::well_known_keys::future_code_hash(ParachainId::get()),
+					relay_chain::well_known_keys::pvf_active_vote(validation_code_hash()),
+				]
+			);
+		})
+		.add(5, || {
+			assert_eq!(status(), Some(ScheduledCodeUpgradeStatus::PreCheckPassed));
+		})
+		.add_with_post_test(
+			6,
+			|| {},
+			|| {
+				assert_eq!(status(), None);
+				assert!(System::events().iter().any(|record| record.event ==
+					crate::Event::ScheduledCodeUpgradeConcluded { applied: true }.into()));
+			},
+		);
+}
+
+#[test]
+fn scheduled_code_upgrade_can_be_cancelled_until_submitted() {
+	const CODE: &[u8] = b"scheduled code";
+
+	BlockTests::new()
+		.add(1, || {
+			let code_hash = <Test as frame_system::Config>::Hashing::hash(CODE);
+			assert_noop!(
+				ParachainSystem::cancel_scheduled_upgrade(RawOrigin::Root.into()),
+				Error::<Test>::NoScheduledCodeUpgrade
+			);
+
+			// the authorization is revoked along with the schedule.
+			assert_ok!(ParachainSystem::schedule_code_upgrade_at(
+				RawOrigin::Root.into(),
+				code_hash,
+				3
+			));
+			assert_ok!(ParachainSystem::cancel_scheduled_upgrade(RawOrigin::Root.into()));
+			System::assert_last_event(crate::Event::ScheduledCodeUpgradeCancelled.into());
+			assert!(!ScheduledCodeUpgradeOf::<Test>::exists());
+			assert!(System::authorized_upgrade().is_none());
+
+			// as is the held back code.
+			assert_ok!(ParachainSystem::schedule_code_upgrade_at(
+				RawOrigin::Root.into(),
+				code_hash,
+				3
+			));
+			assert_ok!(System::apply_authorized_upgrade(RawOrigin::None.into(), CODE.to_vec()));
+			assert_ok!(ParachainSystem::cancel_scheduled_upgrade(RawOrigin::Root.into()));
+			assert!(!ScheduledValidationCode::<Test>::exists());
+
+			assert_ok!(ParachainSystem::schedule_code_upgrade_at(
+				RawOrigin::Root.into(),
+				code_hash,
+				3
+			));
+			assert_ok!(System::apply_authorized_upgrade(RawOrigin::None.into(), CODE.to_vec()));
+		})
+		.add(3, || {
+			assert_eq!(
+				ScheduledCodeUpgradeOf::<Test>::get().map(|scheduled| scheduled.status),
+				Some(ScheduledCodeUpgradeStatus::Submitted)
+			);
+			assert_noop!(
+				ParachainSystem::cancel_scheduled_upgrade(RawOrigin::Root.into()),
+				Error::<Test>::ScheduledCodeUpgradeSubmitted
+			);
+		});
+}
+
+#[test]
+fn scheduled_code_upgrade_is_dropped_if_it_can_not_be_submitted() {
+	const CODE: &[u8] = b"scheduled code";
+
+	BlockTests::new()
+		.with_relay_sproof_builder(|_, relay_block_num, sproof| {
+			if relay_block_num == 3 {
+				sproof.host_config.max_code_size = CODE.len() as u32 - 1;
+			}
+		})
+		.add(1, || {
+			let code_hash = <Test as frame_system::Config>::Hashing::hash(CODE);
+			assert_ok!(ParachainSystem::schedule_code_upgrade_at(
+				RawOrigin::Root.into(),
+				code_hash,
+				3
+			));
+			assert_ok!(System::apply_authorized_upgrade(RawOrigin::None.into(), CODE.to_vec()));
+		})
+		.add_with_post_test(
+			3,
+			|| {
+				System::assert_has_event(
+					crate::Event::ScheduledCodeUpgradeFailed {
+						error: Error::<Test>::TooBig.into(),
+					}
+					.into(),
+				);
+			},
+			|| {
+				assert!(!ScheduledCodeUpgradeOf::<Test>::exists());
+				assert!(!ScheduledValidationCode::<Test>::exists());
+				assert!(!PendingValidationCode::<Test>::exists());
+			},
+		);
+}
+
+#[test]
+fn scheduled_code_upgrade_expires() {
+	const CODE: &[u8] = b"scheduled code";
+
+	BlockTests::new()
+		.add(1, || {
+			let code_hash = <Test as frame_system::Config>::Hashing::hash(CODE);
+			assert_ok!(ParachainSystem::schedule_code_upgrade_at(
+				RawOrigin::Root.into(),
+				code_hash,
+				3
+			));
+		})
+		.add((2 + SCHEDULED_CODE_UPGRADE_VALIDITY).into(), || {
+			assert!(ScheduledCodeUpgradeOf::<Test>::exists());
+		})
+		.add((3 + SCHEDULED_CODE_UPGRADE_VALIDITY).into(), || {
+			// the code was never provided.
+			System::assert_has_event(crate::Event::ScheduledCodeUpgradeExpired.into());
+			assert!(!ScheduledCodeUpgradeOf::<Test>::exists());
+			assert!(System::authorized_upgrade().is_none());
+		});
+}
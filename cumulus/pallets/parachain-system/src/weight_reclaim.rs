@@ -0,0 +1,110 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage weight reclaim integrated with the parachain system pallet.
+//!
+//! Runtimes should use [`StorageWeightReclaim`] instead of
+//! [`cumulus_primitives_storage_weight_reclaim::StorageWeightReclaim`]. It reclaims the same
+//! amount of weight, but keeps track of the proof size reclaimed per block. The total can be
+//! queried with [`Pallet::reclaimed_proof_size`], e.g. to implement the
+//! [`cumulus_primitives_core::StorageWeightReclaimApi`] runtime api. When the extension does not
+//! run in a block while the proof size weight of the block is way above the proof size recorded
+//! by the node, the pallet logs a warning at the end of the block.
+//!
+//! With the `reclaim-diagnostics` feature enabled, an [`Event::ProofSizeReclaimed`] is deposited
+//! for every dispatch.
+
+use crate::{Config, Pallet};
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
+use cumulus_primitives_core::Weight;
+use cumulus_primitives_storage_weight_reclaim::StorageWeightReclaim as ReclaimPrimitive;
+use frame_support::dispatch::{DispatchInfo, PostDispatchInfo};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	impl_tx_ext_default,
+	traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, TransactionExtension},
+	transaction_validity::TransactionValidityError,
+	DispatchResult,
+};
+
+/// Storage weight reclaim mechanism that reports the reclaimed proof size to the parachain
+/// system pallet.
+///
+/// This extension checks the size of the node-side storage proof before and after executing a
+/// given extrinsic. The difference between benchmarked and spent weight is reclaimed.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, Default, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct StorageWeightReclaim<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> StorageWeightReclaim<T> {
+	/// Create a new `StorageWeightReclaim` instance.
+	pub fn new() -> Self {
+		Self(Default::default())
+	}
+}
+
+impl<T: Config + Send + Sync> core::fmt::Debug for StorageWeightReclaim<T> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+		let _ = write!(f, "StorageWeightReclaim");
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> TransactionExtension<T::RuntimeCall> for StorageWeightReclaim<T>
+where
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	const IDENTIFIER: &'static str = "StorageWeightReclaim";
+	type Implicit = ();
+	type Val = ();
+	type Pre = <ReclaimPrimitive<T> as TransactionExtension<T::RuntimeCall>>::Pre;
+
+	fn prepare(
+		self,
+		val: Self::Val,
+		origin: &T::RuntimeOrigin,
+		call: &T::RuntimeCall,
+		info: &DispatchInfoOf<T::RuntimeCall>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		ReclaimPrimitive::<T>::new().prepare(val, origin, call, info, len)
+	}
+
+	fn post_dispatch_details(
+		pre: Self::Pre,
+		info: &DispatchInfoOf<T::RuntimeCall>,
+		post_info: &PostDispatchInfoOf<T::RuntimeCall>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<Weight, TransactionValidityError> {
+		let proof_size_before = block_proof_size::<T>();
+		let refund =
+			ReclaimPrimitive::<T>::post_dispatch_details(pre, info, post_info, len, result)?;
+		let reclaimed = proof_size_before.saturating_sub(block_proof_size::<T>());
+
+		Pallet::<T>::note_reclaimed_proof_size(reclaimed);
+
+		Ok(refund)
+	}
+
+	impl_tx_ext_default!(T::RuntimeCall; weight validate);
+}
+
+/// The total proof size weight consumed by the current block.
+fn block_proof_size<T: Config>() -> u64 {
+	frame_system::BlockWeight::<T>::get().total().proof_size()
+}
@@ -52,17 +52,22 @@ pub use weights::WeightInfo;
 
 extern crate alloc;
 
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 use bounded_collections::BoundedBTreeSet;
 use codec::{Decode, DecodeLimit, Encode, MaxEncodedLen};
+use core::{cmp::Reverse, marker::PhantomData};
 use cumulus_primitives_core::{
-	relay_chain::BlockNumber as RelayBlockNumber, ChannelStatus, GetChannelInfo, MessageSendError,
-	ParaId, XcmpMessageFormat, XcmpMessageHandler, XcmpMessageSource,
+	relay_chain::BlockNumber as RelayBlockNumber, AggregateMessageOrigin, ChannelStatus,
+	GetChannelInfo, IsSystem, MessageSendError, ParaId, XcmpMessageFormat, XcmpMessageHandler,
+	XcmpMessageSource,
 };
 
 use frame_support::{
 	defensive, defensive_assert,
-	traits::{Defensive, EnqueueMessage, EnsureOrigin, Get, QueueFootprint, QueuePausedQuery},
+	traits::{
+		Defensive, EnqueueMessage, EnsureOrigin, Get, ProcessMessage, ProcessMessageError,
+		QueueFootprint, QueuePausedQuery, QueueServiceWeightShare,
+	},
 	weights::{Weight, WeightMeter},
 	BoundedVec,
 };
@@ -70,8 +75,9 @@ use pallet_message_queue::OnQueueChanged;
 use polkadot_runtime_common::xcm_sender::PriceForMessageDelivery;
 use polkadot_runtime_parachains::FeeTracker;
 use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
 use sp_core::MAX_POSSIBLE_ALLOCATION;
-use sp_runtime::{FixedU128, RuntimeDebug, Saturating, WeakBoundedVec};
+use sp_runtime::{FixedU128, Perbill, RuntimeDebug, Saturating, WeakBoundedVec};
 use xcm::{latest::prelude::*, VersionedLocation, VersionedXcm, WrapVersion, MAX_XCM_DECODE_DEPTH};
 use xcm_builder::InspectMessageQueues;
 use xcm_executor::traits::ConvertOrigin;
@@ -254,6 +260,30 @@ pub mod pallet {
 				data.validate::<T>()
 			})
 		}
+
+		/// Overrides the settings of the inbound channel from `sibling`, or removes the override
+		/// if `config` is `None`.
+		///
+		/// - `origin`: Must pass `Root`.
+		/// - `sibling`: The sibling parachain sending on the channel.
+		/// - `config`: The queue thresholds and weight share to use for the channel.
+		#[pallet::call_index(6)]
+		#[pallet::weight((T::DbWeight::get().writes(1), DispatchClass::Operational,))]
+		pub fn set_inbound_channel_config(
+			origin: OriginFor<T>,
+			sibling: ParaId,
+			config: Option<InboundChannelConfig>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			if let Some(queue_config) = config.and_then(|c| c.queue_config) {
+				queue_config.validate::<T>()?;
+			}
+			InboundChannelConfigs::<T>::set(sibling, config);
+			Self::deposit_event(Event::InboundChannelConfigSet { sibling, config });
+
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -287,6 +317,8 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// An HRMP message was sent to a sibling parachain.
 		XcmpMessageSent { message_hash: XcmHash },
+		/// The settings of the inbound channel from a sibling were overridden or reset.
+		InboundChannelConfigSet { sibling: ParaId, config: Option<InboundChannelConfig> },
 	}
 
 	#[pallet::error]
@@ -363,6 +395,38 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type DeliveryFeeFactor<T: Config> =
 		StorageMap<_, Twox64Concat, ParaId, FixedU128, ValueQuery, InitialFactor>;
+
+	/// Governance overrides of the inbound XCMP channels, keyed by the sending sibling.
+	#[pallet::storage]
+	pub type InboundChannelConfigs<T: Config> =
+		StorageMap<_, Twox64Concat, ParaId, InboundChannelConfig, OptionQuery>;
+
+	/// The weight consumed by processing the messages of a sibling with a weight share, together
+	/// with the block in which it was consumed.
+	#[pallet::storage]
+	pub(super) type InboundWeightConsumed<T: Config> =
+		StorageMap<_, Twox64Concat, ParaId, (BlockNumberFor<T>, Weight), OptionQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Initial overrides of the inbound XCMP channels, keyed by the sending sibling.
+		pub inbound_channel_configs: Vec<(ParaId, InboundChannelConfig)>,
+		#[serde(skip)]
+		pub _config: PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (sibling, config) in &self.inbound_channel_configs {
+				if let Some(queue_config) = config.queue_config {
+					queue_config.validate::<T>().expect("Invalid inbound channel queue config");
+				}
+				InboundChannelConfigs::<T>::insert(sibling, config);
+			}
+		}
+	}
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -408,7 +472,19 @@ impl OutboundChannelDetails {
 	}
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[derive(
+	Copy,
+	Clone,
+	Eq,
+	PartialEq,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
 pub struct QueueConfigData {
 	/// The number of pages which must be in the queue for the other side to be told to suspend
 	/// their sending.
@@ -450,6 +526,31 @@ impl QueueConfigData {
 	}
 }
 
+/// Governance-controlled settings of an inbound XCMP channel.
+#[derive(
+	Copy,
+	Clone,
+	Default,
+	Eq,
+	PartialEq,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
+pub struct InboundChannelConfig {
+	/// Queue thresholds to use for this channel instead of the global [`QueueConfigData`].
+	pub queue_config: Option<QueueConfigData>,
+	/// The share of the message processing weight per block which the messages of this sibling
+	/// may consume, enforced by [`InboundWeightQuota`]. `None` means unlimited.
+	///
+	/// System parachains are never limited.
+	pub weight_share: Option<Perbill>,
+}
+
 #[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, TypeInfo)]
 pub enum ChannelSignal {
 	Suspend,
@@ -648,7 +749,7 @@ impl<T: Config> Pallet<T> {
 			return Err(())
 		}
 
-		let QueueConfigData { drop_threshold, .. } = <QueueConfig<T>>::get();
+		let QueueConfigData { drop_threshold, .. } = Self::inbound_queue_config(sender);
 		let fp = T::XcmpQueue::footprint(sender);
 		// Assume that it will not fit into the current page:
 		let new_pages = fp.ready_pages.saturating_add(1);
@@ -685,6 +786,29 @@ impl<T: Config> Pallet<T> {
 		xcm.encode().try_into().map_err(|_| ())
 	}
 
+	/// The queue thresholds of the inbound channel from `sender`.
+	pub fn inbound_queue_config(sender: ParaId) -> QueueConfigData {
+		InboundChannelConfigs::<T>::get(sender)
+			.and_then(|config| config.queue_config)
+			.unwrap_or_else(<QueueConfig<T>>::get)
+	}
+
+	/// The weight share of the inbound channel from `sender`, or `None` if it is unlimited.
+	pub fn inbound_weight_share(sender: ParaId) -> Option<Perbill> {
+		if sender.is_system() {
+			return None
+		}
+
+		InboundChannelConfigs::<T>::get(sender).and_then(|config| config.weight_share)
+	}
+
+	/// The priority of inbound messages from `sender`, the highest being enqueued first: system
+	/// parachains, then siblings by weight share, counting unlimited siblings as a full share.
+	fn inbound_priority(sender: ParaId) -> (bool, Perbill) {
+		let share = Self::inbound_weight_share(sender).unwrap_or_else(Perbill::one);
+		(sender.is_system(), share)
+	}
+
 	/// The worst-case weight of `on_idle`.
 	pub fn on_idle_weight() -> Weight {
 		<T as crate::Config>::WeightInfo::on_idle_good_msg()
@@ -709,7 +833,8 @@ impl<T: Config> Pallet<T> {
 impl<T: Config> OnQueueChanged<ParaId> for Pallet<T> {
 	// Suspends/Resumes the queue when certain thresholds are reached.
 	fn on_queue_changed(para: ParaId, fp: QueueFootprint) {
-		let QueueConfigData { resume_threshold, suspend_threshold, .. } = <QueueConfig<T>>::get();
+		let QueueConfigData { resume_threshold, suspend_threshold, .. } =
+			Self::inbound_queue_config(para);
 
 		let mut suspended_channels = <InboundXcmpSuspended<T>>::get();
 		let suspended = suspended_channels.contains(&para);
@@ -738,6 +863,15 @@ impl<T: Config> OnQueueChanged<ParaId> for Pallet<T> {
 	}
 }
 
+/// Limits the queues of siblings to their weight share of a servicing round of the message queue
+/// pallet, while other queues are waiting. Unlimited siblings and system parachains keep servicing
+/// until they run out of messages.
+impl<T: Config> QueueServiceWeightShare<ParaId> for Pallet<T> {
+	fn weight_share(sender: &ParaId) -> Option<Perbill> {
+		Self::inbound_weight_share(*sender)
+	}
+}
+
 impl<T: Config> QueuePausedQuery<ParaId> for Pallet<T> {
 	fn is_paused(para: &ParaId) -> bool {
 		if !QueueSuspended::<T>::get() {
@@ -763,7 +897,19 @@ impl<T: Config> XcmpMessageHandler for Pallet<T> {
 	) -> Weight {
 		let mut meter = WeightMeter::with_limit(max_weight);
 
-		for (sender, _sent_at, mut data) in iter {
+		// Enqueue in descending priority order, so that the queues of prioritized siblings become
+		// ready first. The sort is stable, which keeps the messages of each sibling in order.
+		let mut messages: Vec<_> = iter.collect();
+		let mut priorities = BTreeMap::new();
+		for (sender, ..) in &messages {
+			priorities.entry(*sender).or_insert_with(|| Self::inbound_priority(*sender));
+		}
+		meter.consume(T::DbWeight::get().reads(priorities.len() as u64));
+		messages.sort_by_key(|(sender, ..)| {
+			Reverse(priorities.get(sender).copied().unwrap_or_default())
+		});
+
+		for (sender, _sent_at, mut data) in messages {
 			let format = match XcmpMessageFormat::decode(&mut data) {
 				Ok(f) => f,
 				Err(_) => {
@@ -820,6 +966,85 @@ impl<T: Config> XcmpMessageHandler for Pallet<T> {
 
 		meter.consumed()
 	}
+
+	fn handle_xcmp_message_weight(_sender: ParaId, message: &[u8]) -> Weight {
+		let mut data = message;
+		let weight = match XcmpMessageFormat::decode(&mut data) {
+			Ok(XcmpMessageFormat::Signals) => T::WeightInfo::suspend_channel()
+				.max(T::WeightInfo::resume_channel())
+				.saturating_mul(data.len() as u64),
+			Ok(XcmpMessageFormat::ConcatenatedVersionedXcm) => {
+				// The XCMs are decoded to count them, which is accounted for by charging their
+				// decoding twice.
+				let mut count = 0u64;
+				while !data.is_empty() &&
+					VersionedXcm::<()>::decode_with_depth_limit(MAX_XCM_DECODE_DEPTH, &mut data)
+						.is_ok()
+				{
+					count.saturating_inc();
+				}
+				T::WeightInfo::take_first_concatenated_xcm()
+					.saturating_mul(2)
+					.saturating_add(T::WeightInfo::enqueue_xcmp_message())
+					.saturating_mul(count)
+			},
+			_ => Weight::zero(),
+		};
+
+		// Reading the priority of the sender.
+		weight.saturating_add(T::DbWeight::get().reads(1))
+	}
+}
+
+/// Enforces the weight shares of [`InboundChannelConfig`] on top of the `Inner` message processor.
+///
+/// Once the messages of a sibling consumed its share of `ServiceWeight` in a block, its queue
+/// yields until the next block so that the queues of other siblings are still serviced. The last
+/// message started within the share may exceed it. `ServiceWeight` should be the service weight
+/// configured for the message queue pallet.
+pub struct InboundWeightQuota<T, Inner, ServiceWeight>(PhantomData<(T, Inner, ServiceWeight)>);
+
+impl<T, Inner, ServiceWeight> ProcessMessage for InboundWeightQuota<T, Inner, ServiceWeight>
+where
+	T: Config,
+	Inner: ProcessMessage<Origin = AggregateMessageOrigin>,
+	ServiceWeight: Get<Weight>,
+{
+	type Origin = AggregateMessageOrigin;
+
+	fn process_message(
+		message: &[u8],
+		origin: Self::Origin,
+		meter: &mut WeightMeter,
+		id: &mut [u8; 32],
+	) -> Result<bool, ProcessMessageError> {
+		let AggregateMessageOrigin::Sibling(sender) = origin else {
+			return Inner::process_message(message, origin, meter, id)
+		};
+		let Some(share) = Pallet::<T>::inbound_weight_share(sender) else {
+			return Inner::process_message(message, origin, meter, id)
+		};
+		if meter.try_consume(T::DbWeight::get().reads_writes(2, 1)).is_err() {
+			return Err(ProcessMessageError::Yield)
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let consumed = match InboundWeightConsumed::<T>::get(sender) {
+			Some((block, consumed)) if block == now => consumed,
+			_ => Weight::zero(),
+		};
+		if consumed.any_gte(share * ServiceWeight::get()) {
+			log::debug!(target: LOG_TARGET, "Sibling {:?} used up its weight share", sender);
+			return Err(ProcessMessageError::Yield)
+		}
+
+		let before = meter.consumed();
+		let result = Inner::process_message(message, origin, meter, id);
+		let used = meter.consumed().saturating_sub(before);
+		InboundWeightConsumed::<T>::insert(sender, (now, consumed.saturating_add(used)));
+
+		result
+	}
 }
 
 impl<T: Config> XcmpMessageSource for Pallet<T> {
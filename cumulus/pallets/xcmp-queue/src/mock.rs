@@ -43,7 +43,8 @@ frame_support::construct_runtime!(
 		ParachainSystem: cumulus_pallet_parachain_system::{
 			Pallet, Call, Config<T>, Storage, Inherent, Event<T>,
 		},
-		XcmpQueue: xcmp_queue::{Pallet, Call, Storage, Event<T>},
+		XcmpQueue: xcmp_queue::{Pallet, Call, Storage, Config<T>, Event<T>},
+		MessageQueue: pallet_message_queue::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -185,6 +186,60 @@ impl<T: OnQueueChanged<ParaId>> EnqueueMessage<ParaId> for EnqueueToLocalStorage
 	}
 }
 
+parameter_types! {
+	pub static ProcessedMessages: Vec<AggregateMessageOrigin> = Default::default();
+	pub const MessageQueueServiceWeight: Weight = Weight::from_parts(100_000_000_000, 1 << 30);
+}
+
+/// A `ProcessMessage` implementation that records the origin of every processed message and
+/// consumes a tenth of the `MessageQueueServiceWeight` for it.
+pub struct RecordProcessedMessages;
+
+impl ProcessMessage for RecordProcessedMessages {
+	type Origin = AggregateMessageOrigin;
+
+	fn process_message(
+		_message: &[u8],
+		origin: Self::Origin,
+		meter: &mut WeightMeter,
+		_id: &mut [u8; 32],
+	) -> Result<bool, ProcessMessageError> {
+		let service_weight: Weight = MessageQueueServiceWeight::get();
+		let weight = Weight::from_parts(service_weight.ref_time() / 10, 0);
+		if meter.try_consume(weight).is_err() {
+			return Err(ProcessMessageError::Overweight(weight))
+		}
+		ProcessedMessages::mutate(|processed| processed.push(origin));
+		Ok(true)
+	}
+}
+
+/// Narrows the weight shares of the XCMP queue to the `Sibling` origin.
+pub struct SiblingWeightShare;
+
+impl QueueServiceWeightShare<AggregateMessageOrigin> for SiblingWeightShare {
+	fn weight_share(origin: &AggregateMessageOrigin) -> Option<Perbill> {
+		match origin {
+			AggregateMessageOrigin::Sibling(sender) => XcmpQueue::weight_share(sender),
+			_ => None,
+		}
+	}
+}
+
+impl pallet_message_queue::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type MessageProcessor = RecordProcessedMessages;
+	type Size = u32;
+	type QueueChangeHandler = ();
+	type QueuePausedQuery = ();
+	type ServiceWeightShare = SiblingWeightShare;
+	type HeapSize = sp_core::ConstU32<{ 64 * 1024 }>;
+	type MaxStale = sp_core::ConstU32<8>;
+	type ServiceWeight = MessageQueueServiceWeight;
+	type IdleMaxServiceWeight = ();
+	type WeightInfo = ();
+}
+
 parameter_types! {
 	/// The asset ID for the asset that we use to pay for message delivery fees.
 	pub FeeAssetId: AssetId = AssetId(RelayChain::get());
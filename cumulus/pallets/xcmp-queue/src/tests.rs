@@ -25,7 +25,7 @@ use frame_support::{
 	assert_err, assert_noop, assert_ok, assert_storage_noop, hypothetically, traits::Hooks,
 	StorageNoopGuard,
 };
-use mock::{new_test_ext, ParachainSystem, RuntimeOrigin as Origin, Test, XcmpQueue};
+use mock::{new_test_ext, ParachainSystem, RuntimeOrigin as Origin, System, Test, XcmpQueue};
 use sp_runtime::traits::{BadOrigin, Zero};
 use std::iter::{once, repeat};
 use xcm_builder::InspectMessageQueues;
@@ -920,3 +920,256 @@ fn page_not_modified_when_fragment_does_not_fit() {
 		}
 	});
 }
+
+#[test]
+fn set_inbound_channel_config_works() {
+	new_test_ext().execute_with(|| {
+		let sibling: ParaId = 2000.into();
+		let config = InboundChannelConfig {
+			queue_config: Some(QueueConfigData {
+				suspend_threshold: 2,
+				drop_threshold: 3,
+				resume_threshold: 1,
+			}),
+			weight_share: Some(Perbill::from_percent(10)),
+		};
+
+		assert_noop!(
+			XcmpQueue::set_inbound_channel_config(Origin::signed(2), sibling, Some(config)),
+			BadOrigin
+		);
+		let bad = InboundChannelConfig {
+			queue_config: Some(QueueConfigData {
+				suspend_threshold: 2,
+				drop_threshold: 1,
+				resume_threshold: 1,
+			}),
+			weight_share: None,
+		};
+		assert_noop!(
+			XcmpQueue::set_inbound_channel_config(Origin::root(), sibling, Some(bad)),
+			Error::<Test>::BadQueueConfig
+		);
+
+		assert_ok!(XcmpQueue::set_inbound_channel_config(Origin::root(), sibling, Some(config)));
+		assert_eq!(XcmpQueue::inbound_queue_config(sibling).suspend_threshold, 2);
+		assert_eq!(XcmpQueue::inbound_weight_share(sibling), Some(Perbill::from_percent(10)));
+		// Other channels keep the global config.
+		assert_eq!(XcmpQueue::inbound_queue_config(2001.into()), QueueConfig::<Test>::get());
+
+		assert_ok!(XcmpQueue::set_inbound_channel_config(Origin::root(), sibling, None));
+		assert_eq!(XcmpQueue::inbound_queue_config(sibling), QueueConfig::<Test>::get());
+	});
+}
+
+#[test]
+fn inbound_channel_thresholds_are_per_channel() {
+	new_test_ext().execute_with(|| {
+		let config = InboundChannelConfig {
+			queue_config: Some(QueueConfigData {
+				suspend_threshold: 2,
+				drop_threshold: 3,
+				resume_threshold: 1,
+			}),
+			weight_share: None,
+		};
+		assert_ok!(XcmpQueue::set_inbound_channel_config(
+			Origin::root(),
+			2000.into(),
+			Some(config)
+		));
+
+		let footprint = QueueFootprint { ready_pages: 2, ..Default::default() };
+		XcmpQueue::on_queue_changed(2000.into(), footprint);
+		XcmpQueue::on_queue_changed(2001.into(), footprint);
+
+		// Only the channel with the lowered threshold is suspended.
+		assert_eq!(
+			InboundXcmpSuspended::<Test>::get().into_iter().collect::<Vec<_>>(),
+			vec![ParaId::from(2000)]
+		);
+	});
+}
+
+#[test]
+fn inbound_messages_are_enqueued_in_priority_order() {
+	new_test_ext().execute_with(|| {
+		let limited = InboundChannelConfig {
+			queue_config: None,
+			weight_share: Some(Perbill::from_percent(10)),
+		};
+		assert_ok!(XcmpQueue::set_inbound_channel_config(
+			Origin::root(),
+			2001.into(),
+			Some(limited)
+		));
+
+		let xcm = VersionedXcm::<Test>::from(Xcm::<Test>(vec![ClearOrigin])).encode();
+		let data = [ConcatenatedVersionedXcm.encode(), xcm.clone()].concat();
+		let messages =
+			[2001, 2000, 1000, 2001].map(|para| (ParaId::from(para), 1, data.as_slice()));
+
+		XcmpQueue::handle_xcmp_messages(messages.into_iter(), Weight::MAX);
+
+		assert_eq!(
+			EnqueuedMessages::get(),
+			[1000, 2000, 2001, 2001].map(|para| (ParaId::from(para), xcm.clone())).to_vec()
+		);
+	});
+}
+
+#[test]
+fn limited_siblings_yield_to_other_queues_when_serviced() {
+	use frame_support::{traits::ServiceQueues, BoundedSlice};
+	use mock::{MessageQueue, MessageQueueServiceWeight, ProcessedMessages};
+
+	new_test_ext().execute_with(|| {
+		let limited = InboundChannelConfig {
+			queue_config: None,
+			weight_share: Some(Perbill::from_percent(25)),
+		};
+		assert_ok!(XcmpQueue::set_inbound_channel_config(
+			Origin::root(),
+			2001.into(),
+			Some(limited)
+		));
+
+		// The limited sibling is the first queue to be serviced.
+		let enqueue = |para: u32, count: usize| {
+			<MessageQueue as EnqueueMessage<AggregateMessageOrigin>>::enqueue_messages(
+				repeat(BoundedSlice::truncate_from(&b"message"[..])).take(count),
+				AggregateMessageOrigin::Sibling(para.into()),
+			)
+		};
+		enqueue(2001, 4);
+		enqueue(1000, 3);
+
+		MessageQueue::service_queues(MessageQueueServiceWeight::get());
+
+		// Every message takes a tenth of the service weight, so the limited sibling yields to the
+		// system parachain after two messages and only continues once it is the only queue left.
+		assert_eq!(
+			ProcessedMessages::get(),
+			[2001, 2001, 1000, 1000, 1000, 2001, 2001]
+				.map(|para| AggregateMessageOrigin::Sibling(para.into()))
+				.to_vec()
+		);
+	});
+}
+
+/// Consumes a fixed weight for every message.
+pub struct ConsumeTenRefTime;
+
+impl ProcessMessage for ConsumeTenRefTime {
+	type Origin = AggregateMessageOrigin;
+
+	fn process_message(
+		_message: &[u8],
+		_origin: Self::Origin,
+		meter: &mut WeightMeter,
+		_id: &mut [u8; 32],
+	) -> Result<bool, ProcessMessageError> {
+		meter.consume(Weight::from_parts(10, 0));
+		Ok(true)
+	}
+}
+
+frame_support::parameter_types! {
+	pub const TestServiceWeight: Weight = Weight::from_parts(100, 100);
+}
+
+#[test]
+fn inbound_weight_quota_yields_when_share_is_used_up() {
+	type Quota = InboundWeightQuota<Test, ConsumeTenRefTime, TestServiceWeight>;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let config = InboundChannelConfig {
+			queue_config: None,
+			weight_share: Some(Perbill::from_percent(25)),
+		};
+		for para in [1000, 2000] {
+			assert_ok!(XcmpQueue::set_inbound_channel_config(
+				Origin::root(),
+				para.into(),
+				Some(config)
+			));
+		}
+
+		let process = |para: u32| {
+			let mut meter = WeightMeter::new();
+			Quota::process_message(
+				&[],
+				AggregateMessageOrigin::Sibling(para.into()),
+				&mut meter,
+				&mut [0; 32],
+			)
+		};
+
+		// The third message starts within the share of 25 and exceeds it.
+		for _ in 0..3 {
+			assert_eq!(process(2000), Ok(true));
+		}
+		assert_eq!(process(2000), Err(ProcessMessageError::Yield));
+
+		// System parachains are never limited.
+		for _ in 0..10 {
+			assert_eq!(process(1000), Ok(true));
+		}
+
+		// The share is available again in the next block.
+		System::set_block_number(2);
+		assert_eq!(process(2000), Ok(true));
+	});
+}
+
+#[test]
+fn genesis_inbound_channel_configs_are_applied() {
+	use sp_runtime::BuildStorage;
+
+	let config =
+		InboundChannelConfig { queue_config: None, weight_share: Some(Perbill::from_percent(10)) };
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	GenesisConfig::<Test> {
+		inbound_channel_configs: vec![(2000.into(), config)],
+		..Default::default()
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	sp_io::TestExternalities::new(storage).execute_with(|| {
+		assert_eq!(InboundChannelConfigs::<Test>::get(ParaId::from(2000)), Some(config));
+		assert_eq!(XcmpQueue::inbound_weight_share(2000.into()), Some(Perbill::from_percent(10)));
+	});
+}
+
+#[test]
+fn handle_xcmp_message_weight_counts_xcms() {
+	use crate::weights::WeightInfo;
+	type Weights = <Test as Config>::WeightInfo;
+
+	new_test_ext().execute_with(|| {
+		let xcm = versioned_xcm(xcm::prelude::XCM_VERSION).encode();
+		let mut page = ConcatenatedVersionedXcm.encode();
+		page.extend(&xcm);
+		page.extend(&xcm);
+
+		let per_xcm = Weights::take_first_concatenated_xcm()
+			.saturating_mul(2)
+			.saturating_add(Weights::enqueue_xcmp_message());
+		assert_eq!(
+			XcmpQueue::handle_xcmp_message_weight(1000.into(), &page),
+			per_xcm
+				.saturating_mul(2)
+				.saturating_add(<Test as frame_system::Config>::DbWeight::get().reads(1)),
+		);
+
+		let signals = (Signals, ChannelSignal::Suspend).encode();
+		assert_eq!(
+			XcmpQueue::handle_xcmp_message_weight(1000.into(), &signals),
+			Weights::suspend_channel()
+				.max(Weights::resume_channel())
+				.saturating_add(<Test as frame_system::Config>::DbWeight::get().reads(1)),
+		);
+	});
+}
@@ -0,0 +1,237 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers to build the genesis config presets of parachain runtimes.
+//!
+//! Runtimes expose the presets through the `GenesisBuilder` runtime API, which allows to create a
+//! chain spec of the runtime, e.g. for a devnet, without writing the genesis config by hand.
+//!
+//! ```ignore
+//! fn get_preset(id: &PresetId) -> Option<Vec<u8>> {
+//! 	let patch = match id.as_ref() {
+//! 		DEV_RUNTIME_PRESET => GenesisConfigBuilder::development(1000.into(), UNITS * 1_000_000),
+//! 		LOCAL_TESTNET_RUNTIME_PRESET =>
+//! 			GenesisConfigBuilder::local_testnet(1000.into(), UNITS * 1_000_000),
+//! 		_ => return None,
+//! 	};
+//! 	Some(patch.build_bytes())
+//! }
+//! ```
+
+use crate::{AccountId, AuraId, Balance};
+use alloc::vec::Vec;
+use cumulus_pallet_xcmp_queue::InboundChannelConfig;
+use cumulus_primitives_core::ParaId;
+use serde_json::{json, Map, Value};
+use sp_keyring::Sr25519Keyring;
+
+/// Builds the JSON patch of the genesis config of a parachain runtime.
+///
+/// Only the pallets which are configured are part of the patch, so the builder can be used with
+/// any runtime which names its pallets like the system parachains: `Balances`, `ParachainInfo`,
+/// `CollatorSelection`, `Session`, `Sudo`, `PolkadotXcm` and `XcmpQueue`.
+#[derive(Clone, Debug)]
+pub struct GenesisConfigBuilder {
+	para_id: ParaId,
+	invulnerables: Vec<(AccountId, AuraId)>,
+	candidacy_bond: Option<Balance>,
+	endowed_accounts: Vec<(AccountId, Balance)>,
+	sudo: Option<AccountId>,
+	safe_xcm_version: Option<u32>,
+	inbound_channel_configs: Vec<(ParaId, InboundChannelConfig)>,
+}
+
+impl GenesisConfigBuilder {
+	/// Create a new builder for the parachain `para_id`.
+	pub fn new(para_id: ParaId) -> Self {
+		Self {
+			para_id,
+			invulnerables: Vec::new(),
+			candidacy_bond: None,
+			endowed_accounts: Vec::new(),
+			sudo: None,
+			safe_xcm_version: Some(xcm::prelude::XCM_VERSION),
+			inbound_channel_configs: Vec::new(),
+		}
+	}
+
+	/// Preset of a development chain with `Alice` as the only collator and sudo, endowing `Alice`,
+	/// `Bob` and their stash accounts with `endowment` each.
+	pub fn development(para_id: ParaId, endowment: Balance) -> Self {
+		Self::new(para_id)
+			.with_invulnerables([Sr25519Keyring::Alice])
+			.with_endowed_accounts(
+				[
+					Sr25519Keyring::Alice,
+					Sr25519Keyring::Bob,
+					Sr25519Keyring::AliceStash,
+					Sr25519Keyring::BobStash,
+				]
+				.map(|k| k.to_account_id()),
+				endowment,
+			)
+			.with_sudo(Sr25519Keyring::Alice.to_account_id())
+	}
+
+	/// Preset of a local testnet with `Alice` and `Bob` as collators and `Alice` as sudo,
+	/// endowing all well-known accounts with `endowment` each.
+	pub fn local_testnet(para_id: ParaId, endowment: Balance) -> Self {
+		Self::new(para_id)
+			.with_invulnerables([Sr25519Keyring::Alice, Sr25519Keyring::Bob])
+			.with_endowed_accounts(
+				Sr25519Keyring::well_known().map(|k| k.to_account_id()),
+				endowment,
+			)
+			.with_sudo(Sr25519Keyring::Alice.to_account_id())
+	}
+
+	/// Use the given development keys as invulnerable collators.
+	pub fn with_invulnerables(self, keys: impl IntoIterator<Item = Sr25519Keyring>) -> Self {
+		self.with_invulnerable_keys(
+			keys.into_iter().map(|k| (k.to_account_id(), k.public().into())).collect(),
+		)
+	}
+
+	/// Use the given accounts and aura keys as invulnerable collators.
+	pub fn with_invulnerable_keys(mut self, invulnerables: Vec<(AccountId, AuraId)>) -> Self {
+		self.invulnerables = invulnerables;
+		self
+	}
+
+	/// Set the bond of collator candidates.
+	pub fn with_candidacy_bond(mut self, bond: Balance) -> Self {
+		self.candidacy_bond = Some(bond);
+		self
+	}
+
+	/// Endow each of `accounts` with `endowment`.
+	pub fn with_endowed_accounts(
+		mut self,
+		accounts: impl IntoIterator<Item = AccountId>,
+		endowment: Balance,
+	) -> Self {
+		self.endowed_accounts
+			.extend(accounts.into_iter().map(|account| (account, endowment)));
+		self
+	}
+
+	/// Set the sudo key, or leave it out for runtimes without the sudo pallet.
+	pub fn with_sudo(mut self, key: impl Into<Option<AccountId>>) -> Self {
+		self.sudo = key.into();
+		self
+	}
+
+	/// Set the safe XCM version, defaults to the latest version.
+	pub fn with_safe_xcm_version(mut self, version: impl Into<Option<u32>>) -> Self {
+		self.safe_xcm_version = version.into();
+		self
+	}
+
+	/// Configure the inbound XCMP channel from `sibling`.
+	pub fn with_inbound_channel_config(
+		mut self,
+		sibling: ParaId,
+		config: InboundChannelConfig,
+	) -> Self {
+		self.inbound_channel_configs.push((sibling, config));
+		self
+	}
+
+	/// Build the genesis config patch.
+	pub fn build(self) -> Value {
+		let mut patch = Map::new();
+		patch.insert("parachainInfo".into(), json!({ "parachainId": self.para_id }));
+
+		if !self.endowed_accounts.is_empty() {
+			patch.insert("balances".into(), json!({ "balances": self.endowed_accounts }));
+		}
+
+		if !self.invulnerables.is_empty() {
+			let accounts: Vec<_> = self.invulnerables.iter().map(|(acc, _)| acc.clone()).collect();
+			let mut collator_selection = json!({ "invulnerables": accounts });
+			if let Some(bond) = self.candidacy_bond {
+				collator_selection["candidacyBond"] = json!(bond);
+			}
+			patch.insert("collatorSelection".into(), collator_selection);
+
+			let keys: Vec<_> = self
+				.invulnerables
+				.into_iter()
+				.map(|(acc, aura)| json!([acc.clone(), acc, { "aura": aura }]))
+				.collect();
+			patch.insert("session".into(), json!({ "keys": keys }));
+		}
+
+		if let Some(key) = self.sudo {
+			patch.insert("sudo".into(), json!({ "key": key }));
+		}
+
+		if let Some(version) = self.safe_xcm_version {
+			patch.insert("polkadotXcm".into(), json!({ "safeXcmVersion": version }));
+		}
+
+		if !self.inbound_channel_configs.is_empty() {
+			patch.insert(
+				"xcmpQueue".into(),
+				json!({ "inboundChannelConfigs": self.inbound_channel_configs }),
+			);
+		}
+
+		Value::Object(patch)
+	}
+
+	/// Build the genesis config patch as returned by `GenesisBuilder::get_preset`.
+	pub fn build_bytes(self) -> Vec<u8> {
+		serde_json::to_string(&self.build())
+			.expect("serialization to json is expected to work. qed.")
+			.into_bytes()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_runtime::Perbill;
+
+	#[test]
+	fn development_preset_contains_configured_pallets() {
+		let patch = GenesisConfigBuilder::development(2000.into(), 100).build();
+
+		assert_eq!(patch["parachainInfo"]["parachainId"], json!(2000));
+		assert_eq!(patch["balances"]["balances"].as_array().unwrap().len(), 4);
+		assert_eq!(patch["collatorSelection"]["invulnerables"].as_array().unwrap().len(), 1);
+		assert_eq!(patch["session"]["keys"].as_array().unwrap().len(), 1);
+		assert_eq!(patch["sudo"]["key"], json!(Sr25519Keyring::Alice.to_account_id()));
+		assert!(patch.get("xcmpQueue").is_none());
+	}
+
+	#[test]
+	fn optional_pallets_can_be_left_out() {
+		let config = InboundChannelConfig {
+			queue_config: None,
+			weight_share: Some(Perbill::from_percent(10)),
+		};
+		let patch = GenesisConfigBuilder::local_testnet(2000.into(), 100)
+			.with_sudo(None)
+			.with_safe_xcm_version(None)
+			.with_inbound_channel_config(2001.into(), config)
+			.build();
+
+		assert!(patch.get("sudo").is_none());
+		assert!(patch.get("polkadotXcm").is_none());
+		assert_eq!(patch["session"]["keys"].as_array().unwrap().len(), 2);
+		assert_eq!(patch["xcmpQueue"]["inboundChannelConfigs"], json!([[2001, config]]));
+	}
+}
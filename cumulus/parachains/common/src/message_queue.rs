@@ -18,8 +18,9 @@
 
 use core::marker::PhantomData;
 use cumulus_primitives_core::{AggregateMessageOrigin, ParaId};
-use frame_support::traits::{QueueFootprint, QueuePausedQuery};
+use frame_support::traits::{QueueFootprint, QueuePausedQuery, QueueServiceWeightShare};
 use pallet_message_queue::OnQueueChanged;
+use sp_runtime::Perbill;
 
 /// Narrow the scope of the `Inner` query from `AggregateMessageOrigin` to `ParaId`.
 ///
@@ -46,6 +47,17 @@ impl<Inner: OnQueueChanged<ParaId>> OnQueueChanged<AggregateMessageOrigin>
 	}
 }
 
+impl<Inner: QueueServiceWeightShare<ParaId>> QueueServiceWeightShare<AggregateMessageOrigin>
+	for NarrowOriginToSibling<Inner>
+{
+	fn weight_share(origin: &AggregateMessageOrigin) -> Option<Perbill> {
+		match origin {
+			AggregateMessageOrigin::Sibling(id) => Inner::weight_share(id),
+			_ => None,
+		}
+	}
+}
+
 /// Convert a sibling `ParaId` to an `AggregateMessageOrigin`.
 pub struct ParaIdToSibling;
 impl sp_runtime::traits::Convert<ParaId, AggregateMessageOrigin> for ParaIdToSibling {
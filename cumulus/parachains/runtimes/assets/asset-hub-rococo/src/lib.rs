@@ -266,6 +266,8 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = AssetsStringLimit;
 	type Freezer = AssetsFreezer;
+	type MaxFreezes = ConstU32<8>;
+	type UriLimit = ConstU32<256>;
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_local::WeightInfo<Runtime>;
 	type CallbackHandle = pallet_assets::AutoIncAssetId<Runtime, TrustBackedAssetsInstance>;
@@ -285,6 +287,7 @@ impl pallet_assets_freezer::Config<AssetsFreezerInstance> for Runtime {
 parameter_types! {
 	pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
 	pub const LiquidityWithdrawalFee: Permill = Permill::from_percent(0);
+	pub const OrderDeposit: Balance = deposit(1, 128);
 }
 
 ord_parameter_types! {
@@ -311,6 +314,8 @@ impl pallet_assets::Config<PoolAssetsInstance> for Runtime {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = ConstU32<50>;
 	type Freezer = PoolAssetsFreezer;
+	type MaxFreezes = ConstU32<8>;
+	type UriLimit = ConstU32<256>;
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_pool::WeightInfo<Runtime>;
 	type CallbackHandle = ();
@@ -374,6 +379,9 @@ impl pallet_asset_conversion::Config for Runtime {
 	type LPFee = ConstU32<3>;
 	type PalletId = AssetConversionPalletId;
 	type MaxSwapPathLength = ConstU32<3>;
+	type MaxOrdersPerPool = ConstU32<8>;
+	type MaxOrderFillsPerBlock = ConstU32<8>;
+	type OrderDeposit = OrderDeposit;
 	type MintMinLiquidity = ConstU128<100>;
 	type WeightInfo = weights::pallet_asset_conversion::WeightInfo<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -435,6 +443,8 @@ impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
 	type ApprovalDeposit = ForeignAssetsApprovalDeposit;
 	type StringLimit = ForeignAssetsAssetsStringLimit;
 	type Freezer = ForeignAssetsFreezer;
+	type MaxFreezes = ConstU32<8>;
+	type UriLimit = ConstU32<256>;
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_foreign::WeightInfo<Runtime>;
 	type CallbackHandle = ();
@@ -466,6 +476,7 @@ impl pallet_multisig::Config for Runtime {
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = MaxSignatories;
+	type MaxCallSize = ConstU32<4096>;
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 }
 
@@ -485,6 +496,7 @@ parameter_types! {
 	// One storage item; key size 32, value size 16
 	pub const AnnouncementDepositBase: Balance = deposit(1, 48);
 	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	pub const CallPreviewDepositPerByte: Balance = deposit(0, 1);
 	pub const MaxPending: u16 = 32;
 }
 
@@ -652,6 +664,8 @@ impl pallet_proxy::Config for Runtime {
 	type CallHasher = BlakeTwo256;
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+	type MaxCallPreviewLen = ConstU32<1024>;
+	type CallPreviewDepositPerByte = CallPreviewDepositPerByte;
 }
 
 parameter_types! {
@@ -702,6 +716,7 @@ impl pallet_message_queue::Config for Runtime {
 	// The XCMP queue pallet is only ever able to handle the `Sibling(ParaId)` origin:
 	type QueueChangeHandler = NarrowOriginToSibling<XcmpQueue>;
 	type QueuePausedQuery = NarrowOriginToSibling<XcmpQueue>;
+	type ServiceWeightShare = ();
 	type HeapSize = sp_core::ConstU32<{ 103 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
@@ -831,6 +846,7 @@ parameter_types! {
 	pub const UniquesMetadataDepositBase: Balance = deposit(1, 129);
 	pub const UniquesAttributeDepositBase: Balance = deposit(1, 0);
 	pub const UniquesDepositPerByte: Balance = deposit(0, 1);
+	pub const UniquesMaxRoyalty: Permill = Permill::from_percent(25);
 }
 
 impl pallet_uniques::Config for Runtime {
@@ -847,6 +863,10 @@ impl pallet_uniques::Config for Runtime {
 	type StringLimit = ConstU32<128>;
 	type KeyLimit = ConstU32<32>;
 	type ValueLimit = ConstU32<64>;
+	type MaxRoyalty = UniquesMaxRoyalty;
+	type MaxBatchSize = ConstU32<100>;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
 	type WeightInfo = weights::pallet_uniques::WeightInfo<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = ();
@@ -1305,6 +1325,30 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_uniques_runtime_api::CollectionApi<Block, AccountId, CollectionId, ItemId, Balance>
+		for Runtime
+	{
+		fn items_of_collection(
+			collection: CollectionId,
+			start: Option<ItemId>,
+			limit: u32,
+		) -> Vec<pallet_uniques::ItemSnapshot<ItemId, AccountId>> {
+			Uniques::items_of_collection(collection, start, limit)
+		}
+
+		fn owner(collection: CollectionId, item: ItemId) -> Option<AccountId> {
+			Uniques::owner(collection, item)
+		}
+
+		fn user_of(collection: CollectionId, item: ItemId) -> Option<AccountId> {
+			Uniques::user_of(collection, item)
+		}
+
+		fn price_of(collection: CollectionId, item: ItemId) -> Option<(Balance, Option<AccountId>)> {
+			Uniques::price_of(collection, item)
+		}
+	}
+
 	impl pallet_asset_conversion::AssetConversionApi<
 		Block,
 		Balance,
@@ -21,6 +21,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `cob`, CPU: `<UNKNOWN>`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("asset-hub-rococo-dev")`, DB CACHE: 1024
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `place_order`, `cancel_order` and `fill_order`. Re-run the benchmarks before relying on
+//! them.
 
 // Executed Command:
 // ./target/debug/polkadot-parachain
@@ -176,4 +180,56 @@ impl<T: frame_system::Config> pallet_asset_conversion::WeightInfo for WeightInfo
 			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
+	/// Storage: `AssetConversion::Pools` (r:1 w:0)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(1224), added: 3699, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::Orders` (r:1 w:1)
+	/// Proof: `AssetConversion::Orders` (`max_values`: None, `max_size`: Some(11397), added: 13872, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::NextOrderId` (r:1 w:1)
+	/// Proof: `AssetConversion::NextOrderId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	fn place_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1023`
+		//  Estimated: `14862`
+		// Minimum execution time: 58_317_000 picoseconds.
+		Weight::from_parts(58_317_000, 0)
+			.saturating_add(Weight::from_parts(0, 14862))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `AssetConversion::Orders` (r:1 w:1)
+	/// Proof: `AssetConversion::Orders` (`max_values`: None, `max_size`: Some(11397), added: 13872, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:1 w:1)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:2 w:2)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	fn cancel_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1188`
+		//  Estimated: `14862`
+		// Minimum execution time: 47_902_000 picoseconds.
+		Weight::from_parts(47_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 14862))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `AssetConversion::Orders` (r:1 w:1)
+	/// Proof: `AssetConversion::Orders` (`max_values`: None, `max_size`: Some(11397), added: 13872, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Asset` (r:2 w:2)
+	/// Proof: `ForeignAssets::Asset` (`max_values`: None, `max_size`: Some(808), added: 3283, mode: `MaxEncodedLen`)
+	/// Storage: `ForeignAssets::Account` (r:4 w:4)
+	/// Proof: `ForeignAssets::Account` (`max_values`: None, `max_size`: Some(732), added: 3207, mode: `MaxEncodedLen`)
+	fn fill_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1372`
+		//  Estimated: `14862`
+		// Minimum execution time: 81_655_000 picoseconds.
+		Weight::from_parts(81_655_000, 0)
+			.saturating_add(Weight::from_parts(0, 14862))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
 }
@@ -20,6 +20,11 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-ynta1nyy-project-238-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: ``, WASM-EXECUTION: `Compiled`, CHAIN: `Some("bridge-hub-rococo-dev")`, DB CACHE: 1024
+//!
+//! NOTE: The weights of `note_call`, `register_multisig`, `as_registered`, `rotate_registered`,
+//! `unregister_multisig`, `set_expiry` and `close_expired`, and the storage accesses of
+//! `as_multi_complete` and `cancel_as_multi`, were written by hand after this file was generated
+//! and were not measured. Regenerate this file with the command below before relying on them.
 
 // Executed Command:
 // ./target/production/polkadot-parachain
@@ -100,21 +105,25 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Calls` (r:1 w:1)
+	/// Proof: `Multisig::Calls` (`max_values`: None, `max_size`: Some(4234), added: 6709, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Expiries` (r:0 w:1)
+	/// Proof: `Multisig::Expiries` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	/// The range of component `z` is `[0, 10000]`.
 	fn as_multi_complete(s: u32, z: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `388 + s * (33 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `7699`
 		// Minimum execution time: 49_724_000 picoseconds.
 		Weight::from_parts(34_153_321, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 7699))
 			// Standard Error: 1_376
 			.saturating_add(Weight::from_parts(174_634, 0).saturating_mul(s.into()))
 			// Standard Error: 13
 			.saturating_add(Weight::from_parts(1_753, 0).saturating_mul(z.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
@@ -148,17 +157,133 @@ impl<T: frame_system::Config> pallet_multisig::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: `Multisig::Multisigs` (r:1 w:1)
 	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Calls` (r:1 w:1)
+	/// Proof: `Multisig::Calls` (`max_values`: None, `max_size`: Some(4234), added: 6709, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Expiries` (r:0 w:1)
+	/// Proof: `Multisig::Expiries` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[2, 100]`.
 	fn cancel_as_multi(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `454 + s * (1 ±0)`
-		//  Estimated: `6811`
+		//  Estimated: `7699`
 		// Minimum execution time: 32_421_000 picoseconds.
 		Weight::from_parts(32_554_061, 0)
-			.saturating_add(Weight::from_parts(0, 6811))
+			.saturating_add(Weight::from_parts(0, 7699))
 			// Standard Error: 1_157
 			.saturating_add(Weight::from_parts(141_221, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:0)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Calls` (r:1 w:1)
+	/// Proof: `Multisig::Calls` (`max_values`: None, `max_size`: Some(4234), added: 6709, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	/// The range of component `z` is `[0, 4088]`.
+	fn note_call(s: u32, z: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `492 + s * (1 ±0)`
+		//  Estimated: `7699`
+		// Minimum execution time: 32_114_000 picoseconds.
+		Weight::from_parts(31_271_000, 0)
+			.saturating_add(Weight::from_parts(0, 7699))
+			// Standard Error: 1_104
+			.saturating_add(Weight::from_parts(109_214, 0).saturating_mul(s.into()))
+			// Standard Error: 11
+			.saturating_add(Weight::from_parts(1_402, 0).saturating_mul(z.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::Registered` (r:1 w:1)
+	/// Proof: `Multisig::Registered` (`max_values`: None, `max_size`: Some(3292), added: 5767, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn register_multisig(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `6757`
+		// Minimum execution time: 27_480_000 picoseconds.
+		Weight::from_parts(27_480_000, 0)
+			.saturating_add(Weight::from_parts(0, 6757))
+			// Standard Error: 712
+			.saturating_add(Weight::from_parts(104_102, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Multisig::Registered` (r:1 w:0)
+	/// Proof: `Multisig::Registered` (`max_values`: None, `max_size`: Some(3292), added: 5767, mode: `MaxEncodedLen`)
+	fn as_registered() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3395`
+		//  Estimated: `6757`
+		// Minimum execution time: 14_016_000 picoseconds.
+		Weight::from_parts(14_016_000, 0)
+			.saturating_add(Weight::from_parts(0, 6757))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `Multisig::Registered` (r:1 w:1)
+	/// Proof: `Multisig::Registered` (`max_values`: None, `max_size`: Some(3292), added: 5767, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn rotate_registered(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3395`
+		//  Estimated: `6757`
+		// Minimum execution time: 20_917_000 picoseconds.
+		Weight::from_parts(20_917_000, 0)
+			.saturating_add(Weight::from_parts(0, 6757))
+			// Standard Error: 688
+			.saturating_add(Weight::from_parts(96_540, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Multisig::Registered` (r:1 w:1)
+	/// Proof: `Multisig::Registered` (`max_values`: None, `max_size`: Some(3292), added: 5767, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn unregister_multisig() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3395`
+		//  Estimated: `6757`
+		// Minimum execution time: 31_562_000 picoseconds.
+		Weight::from_parts(31_562_000, 0)
+			.saturating_add(Weight::from_parts(0, 6757))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Multisig::Multisigs` (r:1 w:0)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Expiries` (r:0 w:1)
+	/// Proof: `Multisig::Expiries` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[2, 100]`.
+	fn set_expiry(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `492 + s * (1 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 17_226_000 picoseconds.
+		Weight::from_parts(17_226_000, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 655
+			.saturating_add(Weight::from_parts(102_871, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Multisig::Expiries` (r:1 w:1)
+	/// Proof: `Multisig::Expiries` (`max_values`: None, `max_size`: Some(92), added: 2567, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Multisigs` (r:1 w:1)
+	/// Proof: `Multisig::Multisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Multisig::Calls` (r:1 w:1)
+	/// Proof: `Multisig::Calls` (`max_values`: None, `max_size`: Some(4234), added: 6709, mode: `MaxEncodedLen`)
+	fn close_expired() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4747`
+		//  Estimated: `7699`
+		// Minimum execution time: 48_904_000 picoseconds.
+		Weight::from_parts(48_904_000, 0)
+			.saturating_add(Weight::from_parts(0, 7699))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
 }
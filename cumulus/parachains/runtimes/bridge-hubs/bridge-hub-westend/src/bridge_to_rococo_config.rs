@@ -76,8 +76,6 @@ parameter_types! {
 			Parachain(<bp_bridge_hub_rococo::BridgeHubRococo as bp_runtime::Parachain>::PARACHAIN_ID)
 		]
 	);
-
-	pub storage BridgeDeposit: Balance = 10 * WND;
 }
 
 /// Proof of messages, coming from Rococo.
@@ -178,7 +176,7 @@ impl pallet_xcm_bridge_hub::Config<XcmOverBridgeHubRococoInstance> for Runtime {
 	type BridgeOriginAccountIdConverter =
 		(ParentIsPreset<AccountId>, SiblingParachainConvertsVia<Sibling, AccountId>);
 
-	type BridgeDeposit = BridgeDeposit;
+	type BridgeDeposit = crate::dynamic_params::bridges::BridgeDeposit;
 	type Currency = Balances;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	// Do not require deposit from system parachains or relay chain
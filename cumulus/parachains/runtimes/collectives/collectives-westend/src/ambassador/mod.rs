@@ -270,4 +270,10 @@ impl pallet_salary::Config<AmbassadorSalaryInstance> for Runtime {
 	type PayoutPeriod = ConstU32<{ 15 * DAYS }>;
 	// Total monthly salary budget.
 	type Budget = ConstU128<{ 10_000 * DOLLARS }>;
+	// Monthly budget for discretionary sub-spends assigned by senior Ambassadors.
+	type SubSpendBudget = ConstU128<{ 1_000 * DOLLARS }>;
+	// Only Senior Ambassadors and above may assign sub-spends.
+	type MinSubSpendRank = ConstU16<{ ranks::SENIOR_AMBASSADOR_TIER_3 }>;
+	// 30 days to claim a sub-spend after it is assigned.
+	type SubSpendClaimPeriod = ConstU32<{ 30 * DAYS }>;
 }
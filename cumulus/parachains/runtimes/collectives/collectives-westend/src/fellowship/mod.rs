@@ -257,6 +257,12 @@ impl pallet_salary::Config<FellowshipSalaryInstance> for Runtime {
 	type PayoutPeriod = ConstU32<{ 15 * DAYS }>;
 	// Total monthly salary budget.
 	type Budget = ConstU128<{ 100_000 * USDT_UNITS }>;
+	// Monthly budget for discretionary sub-spends assigned by Fellows.
+	type SubSpendBudget = ConstU128<{ 10_000 * USDT_UNITS }>;
+	// Only Fellows and above may assign sub-spends.
+	type MinSubSpendRank = ConstU16<{ ranks::DAN_3 }>;
+	// 30 days to claim a sub-spend after it is assigned.
+	type SubSpendClaimPeriod = ConstU32<{ 30 * DAYS }>;
 }
 
 parameter_types! {
@@ -328,6 +334,11 @@ impl pallet_treasury::Config<FellowshipTreasuryInstance> for Runtime {
 		AssetRate,
 	>;
 	type PayoutPeriod = ConstU32<{ 30 * DAYS }>;
+	type MilestoneOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		EitherOfDiverse<EnsureXcm<IsVoiceOfBody<GovernanceLocation, TreasurerBodyId>>, Fellows>,
+	>;
+	type MaxMilestones = ConstU32<16>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments<
 		sp_core::ConstU8<1>,
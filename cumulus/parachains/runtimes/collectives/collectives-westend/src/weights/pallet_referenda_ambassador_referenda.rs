@@ -21,6 +21,19 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `cob`, CPU: `<UNKNOWN>`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("collectives-polkadot-dev")`, DB CACHE: 1024
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `submit`, `place_decision_deposit_preparing`, `place_decision_deposit_queued`,
+//! `place_decision_deposit_not_queued`, `place_decision_deposit_passing`,
+//! `place_decision_deposit_failing`, `cancel`, `kill`, `one_fewer_deciding_failing`,
+//! `one_fewer_deciding_passing`, `nudge_referendum_requeued_insertion`,
+//! `nudge_referendum_requeued_slide`, `nudge_referendum_queued`, `nudge_referendum_not_queued`,
+//! `nudge_referendum_no_deposit`, `nudge_referendum_preparing`, `nudge_referendum_timed_out`,
+//! `nudge_referendum_begin_deciding_failing`, `nudge_referendum_begin_deciding_passing`,
+//! `nudge_referendum_begin_confirming`, `nudge_referendum_end_confirming`,
+//! `nudge_referendum_continue_not_confirming`, `nudge_referendum_continue_confirming`,
+//! `nudge_referendum_approved`, `nudge_referendum_rejected`. Re-run the benchmarks before relying
+//! on them.
 
 // Executed Command:
 // target/release/polkadot-parachain
@@ -55,6 +68,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:0 w:1)
 	/// Proof: `AmbassadorReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:0 w:1)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn submit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `255`
@@ -63,12 +78,14 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(34_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
 			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `AmbassadorReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_preparing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `366`
@@ -76,7 +93,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 63_000_000 picoseconds.
 		Weight::from_parts(68_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -87,6 +104,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_queued() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1165`
@@ -94,7 +113,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 97_000_000 picoseconds.
 		Weight::from_parts(123_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -105,6 +124,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_not_queued() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1173`
@@ -112,7 +133,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 104_000_000 picoseconds.
 		Weight::from_parts(111_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -123,6 +144,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_passing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `702`
@@ -130,7 +153,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 140_000_000 picoseconds.
 		Weight::from_parts(150_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -141,6 +164,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_failing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `601`
@@ -148,7 +173,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 81_000_000 picoseconds.
 		Weight::from_parts(82_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -179,6 +204,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:0 w:1)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn cancel() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `311`
@@ -187,7 +214,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(45_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
 			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `AmbassadorReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
@@ -209,6 +236,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `AmbassadorReferenda::MetadataOf` (r:1 w:0)
 	/// Proof: `AmbassadorReferenda::MetadataOf` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:0 w:1)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn kill() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `626`
@@ -217,7 +246,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(187_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
 			.saturating_add(T::DbWeight::get().reads(11))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `AmbassadorReferenda::TrackQueue` (r:1 w:0)
 	/// Proof: `AmbassadorReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
@@ -241,6 +270,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1412`
@@ -248,7 +279,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 88_000_000 picoseconds.
 		Weight::from_parts(97_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `AmbassadorReferenda::TrackQueue` (r:1 w:1)
@@ -259,6 +290,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1412`
@@ -266,13 +299,15 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 87_000_000 picoseconds.
 		Weight::from_parts(92_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:0)
 	/// Proof: `AmbassadorReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `AmbassadorReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_requeued_insertion() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `935`
@@ -280,13 +315,15 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 43_000_000 picoseconds.
 		Weight::from_parts(46_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:0)
 	/// Proof: `AmbassadorReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `AmbassadorReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_requeued_slide() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `935`
@@ -294,7 +331,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 39_000_000 picoseconds.
 		Weight::from_parts(43_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -303,6 +340,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorReferenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `AmbassadorReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_queued() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `951`
@@ -310,7 +349,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 48_000_000 picoseconds.
 		Weight::from_parts(50_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -319,6 +358,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorReferenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `AmbassadorReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `AmbassadorReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(171), added: 2646, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_not_queued() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `959`
@@ -326,13 +367,15 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 42_000_000 picoseconds.
 		Weight::from_parts(48_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `AmbassadorReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_no_deposit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `263`
@@ -340,13 +383,15 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 28_000_000 picoseconds.
 		Weight::from_parts(30_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `AmbassadorReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_preparing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `311`
@@ -354,11 +399,13 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 26_000_000 picoseconds.
 		Weight::from_parts(28_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `AmbassadorReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:1)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_timed_out() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `208`
@@ -366,8 +413,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 19_000_000 picoseconds.
 		Weight::from_parts(20_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `AmbassadorReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
@@ -377,6 +424,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `546`
@@ -384,7 +433,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 42_000_000 picoseconds.
 		Weight::from_parts(46_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -395,6 +444,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `647`
@@ -402,7 +453,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 87_000_000 picoseconds.
 		Weight::from_parts(93_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -411,6 +462,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_confirming() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `700`
@@ -418,7 +471,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 100_000_000 picoseconds.
 		Weight::from_parts(120_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -427,6 +480,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_end_confirming() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `683`
@@ -434,7 +489,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 90_000_000 picoseconds.
 		Weight::from_parts(100_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -443,6 +498,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_continue_not_confirming() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `700`
@@ -450,7 +507,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 77_000_000 picoseconds.
 		Weight::from_parts(82_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -459,6 +516,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_continue_confirming() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `704`
@@ -466,7 +525,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 68_000_000 picoseconds.
 		Weight::from_parts(77_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -477,6 +536,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Lookup` (r:1 w:1)
 	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:1)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_approved() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `704`
@@ -484,8 +545,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 99_000_000 picoseconds.
 		Weight::from_parts(104_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `AmbassadorReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
@@ -493,6 +554,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `AmbassadorCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `AmbassadorReferenda::ReferendumTrackParams` (r:1 w:1)
+	/// Proof: `AmbassadorReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_rejected() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `700`
@@ -500,8 +563,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 87_000_000 picoseconds.
 		Weight::from_parts(100_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `AmbassadorReferenda::ReferendumInfoFor` (r:1 w:0)
 	/// Proof: `AmbassadorReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
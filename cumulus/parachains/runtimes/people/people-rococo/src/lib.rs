@@ -112,6 +112,7 @@ pub type UncheckedExtrinsic =
 pub type Migrations = (
 	pallet_collator_selection::migration::v2::MigrationToV2<Runtime>,
 	cumulus_pallet_xcmp_queue::migration::v5::MigrateV4ToV5<Runtime>,
+	pallet_identity::migration::versioned::V2ToV3<Runtime>,
 	// permanent
 	pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 );
@@ -295,6 +296,7 @@ impl pallet_message_queue::Config for Runtime {
 	// The XCMP queue pallet is only ever able to handle the `Sibling(ParaId)` origin:
 	type QueueChangeHandler = NarrowOriginToSibling<XcmpQueue>;
 	type QueuePausedQuery = NarrowOriginToSibling<XcmpQueue>;
+	type ServiceWeightShare = ();
 	type HeapSize = sp_core::ConstU32<{ 103 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
@@ -406,6 +408,7 @@ impl pallet_multisig::Config for Runtime {
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = ConstU32<100>;
+	type MaxCallSize = ConstU32<4096>;
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 }
 
@@ -504,6 +507,7 @@ parameter_types! {
 	// One storage item; key size 32, value size 16.
 	pub const AnnouncementDepositBase: Balance = deposit(1, 48);
 	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	pub const CallPreviewDepositPerByte: Balance = deposit(0, 1);
 	pub const MaxPending: u16 = 32;
 }
 
@@ -520,6 +524,8 @@ impl pallet_proxy::Config for Runtime {
 	type CallHasher = BlakeTwo256;
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+	type MaxCallPreviewLen = ConstU32<1024>;
+	type CallPreviewDepositPerByte = CallPreviewDepositPerByte;
 }
 
 impl pallet_utility::Config for Runtime {
@@ -534,6 +540,8 @@ impl identity_migrator::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Reaper = EnsureRoot<AccountId>;
 	type ReapIdentityHandler = ();
+	type MigrateIdentityHandler = ();
+	type RestoreOrigin = EnsureRoot<AccountId>;
 	type WeightInfo = weights::polkadot_runtime_common_identity_migrator::WeightInfo<Runtime>;
 }
 
@@ -544,7 +552,10 @@ parameter_types! {
 impl pallet_migrations::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type Migrations = pallet_identity::migration::v2::LazyMigrationV1ToV2<Runtime>;
+	type Migrations = (
+		pallet_identity::migration::v2::LazyMigrationV1ToV2<Runtime>,
+		pallet_identity::migration::v4::LazyMigrationV3ToV4<Runtime>,
+	);
 	// Benchmarks need mocked migrations to guarantee that they succeed.
 	#[cfg(feature = "runtime-benchmarks")]
 	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
@@ -730,6 +741,23 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_identity_runtime_api::IdentityApi<Block, AccountId, pallet_identity::RegistrationOf<Runtime>> for Runtime {
+		fn identities(
+			start_key: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(AccountId, pallet_identity::RegistrationOf<Runtime>)> {
+			Identity::identities(start_key, limit)
+		}
+
+		fn identity_of(account: AccountId) -> Option<pallet_identity::RegistrationOf<Runtime>> {
+			pallet_identity::IdentityOf::<Runtime>::get(account)
+		}
+
+		fn super_of(account: AccountId) -> Option<(AccountId, pallet_identity::Data)> {
+			pallet_identity::SuperOf::<Runtime>::get(account)
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,
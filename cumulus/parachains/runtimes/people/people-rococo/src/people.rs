@@ -26,7 +26,7 @@ use parachains_common::{impls::ToParentTreasury, DAYS};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{AccountIdConversion, Verify},
-	RuntimeDebug,
+	Perbill, RuntimeDebug,
 };
 
 parameter_types! {
@@ -38,6 +38,8 @@ parameter_types! {
 	pub const ByteDeposit: Balance = deposit(0, 1);
 	pub const UsernameDeposit: Balance = deposit(0, 32);
 	pub const SubAccountDeposit: Balance = deposit(1, 53);
+	pub const PokeDepositReward: Perbill = Perbill::from_percent(10);
+	pub const SlashPortion: Perbill = Perbill::from_percent(90);
 	pub RelayTreasuryAccount: AccountId =
 		parachains_common::TREASURY_PALLET_ID.into_account_truncating();
 }
@@ -45,14 +47,20 @@ parameter_types! {
 impl pallet_identity::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type DepositTracker = ();
+	type DepositAsset = ();
 	type BasicDeposit = BasicDeposit;
 	type ByteDeposit = ByteDeposit;
 	type UsernameDeposit = UsernameDeposit;
 	type SubAccountDeposit = SubAccountDeposit;
+	type PokeDepositReward = PokeDepositReward;
 	type MaxSubAccounts = ConstU32<100>;
+	type MaxSubDepth = ConstU32<1>;
 	type IdentityInformation = IdentityInfo;
 	type MaxRegistrars = ConstU32<20>;
+	type MaxFeeTiers = ConstU32<8>;
 	type Slashed = ToParentTreasury<RelayTreasuryAccount, LocationToAccountId, Runtime>;
+	type SlashPortion = SlashPortion;
 	type ForceOrigin = EnsureRoot<Self::AccountId>;
 	type RegistrarOrigin = EnsureRoot<Self::AccountId>;
 	type OffchainSignature = Signature;
@@ -62,6 +70,9 @@ impl pallet_identity::Config for Runtime {
 	type UsernameGracePeriod = ConstU32<{ 3 * DAYS }>;
 	type MaxSuffixLength = ConstU32<7>;
 	type MaxUsernameLength = ConstU32<32>;
+	type OnIdentityChange = ();
+	type AttesterId = pallet_identity::offchain::crypto::Public;
+	type SubmitAttestation = ();
 	type WeightInfo = weights::pallet_identity::WeightInfo<Runtime>;
 }
 
@@ -151,6 +162,10 @@ impl IdentityInformationProvider for IdentityInfo {
 		self.fields().bits() & fields == fields
 	}
 
+	fn registered_fields(&self) -> Self::FieldsIdentifier {
+		self.fields().bits()
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	fn create_identity_info() -> Self {
 		let data = Data::Raw(alloc::vec![0; 32].try_into().unwrap());
@@ -14,6 +14,18 @@
 // limitations under the License.
 
 //! Taken from Rococo Relay Chain. Needs to rerun.
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `invite_sub`, `add_sub_with_own_deposit`, `cancel_sub_invite`, `remove_registrar`,
+//! `reclaim_fee`, `freeze_identity`, `thaw_identity`, `migration_v4_registrars_step`,
+//! `migration_v4_identity_step` and `held_deposits_step`; `set_subs_diff`, whose execution times
+//! are carried over from the former `set_subs_new` and `set_subs_old` benchmarks and whose storage
+//! accesses were counted by hand; the `PendingSubsOf` accesses of `clear_identity` and
+//! `kill_identity`; the `RequestedFieldsOf` accesses of `request_judgement`, `cancel_request`,
+//! `provide_judgement`, `provide_judgement_with_evidence` and `expire_request`; the
+//! `RequestDeadlineOf` reads of `provide_judgement` and `provide_judgement_with_evidence`; the base
+//! of `set_identity`, raised to account for hashing the identity information for the `IdentitySet`
+//! event. Re-run the benchmarks before relying on them.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -43,60 +55,50 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: Identity IdentityOf (r:1 w:1)
 	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
+	/// Storage: `Identity::EvidenceOf` (r:0 w:1)
+	/// Proof: `Identity::EvidenceOf` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	fn set_identity(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `442 + r * (5 ±0)`
 		//  Estimated: `11003`
 		// Minimum execution time: 31_373_000 picoseconds.
-		Weight::from_parts(30_435_545, 0)
+		Weight::from_parts(37_973_545, 0)
 			.saturating_add(Weight::from_parts(0, 11003))
 			// Standard Error: 2_307
 			.saturating_add(Weight::from_parts(92_753, 0).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Identity IdentityOf (r:1 w:0)
 	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
 	/// Storage: Identity SubsOf (r:1 w:1)
 	/// Proof: Identity SubsOf (max_values: None, max_size: Some(3258), added: 5733, mode: MaxEncodedLen)
-	/// Storage: Identity SuperOf (r:100 w:100)
+	/// Storage: Identity SuperOf (r:100 w:200)
 	/// Proof: Identity SuperOf (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
-	/// The range of component `s` is `[0, 100]`.
-	fn set_subs_new(s: u32, ) -> Weight {
+	/// Storage: Identity SubDepositOf (r:200 w:100)
+	/// Proof: Identity SubDepositOf (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
+	/// The range of component `a` is `[0, 100]`.
+	/// The range of component `r` is `[0, 100]`.
+	fn set_subs_diff(a: u32, r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `101`
-		//  Estimated: `11003 + s * (2589 ±0)`
+		//  Measured:  `194 + r * (32 ±0)`
+		//  Estimated: `11003 + a * (2589 ±0) + r * (2539 ±0)`
 		// Minimum execution time: 9_251_000 picoseconds.
 		Weight::from_parts(22_039_210, 0)
 			.saturating_add(Weight::from_parts(0, 11003))
 			// Standard Error: 40_779
-			.saturating_add(Weight::from_parts(2_898_525, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
-			.saturating_add(T::DbWeight::get().writes(1))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
-			.saturating_add(Weight::from_parts(0, 2589).saturating_mul(s.into()))
-	}
-	/// Storage: Identity IdentityOf (r:1 w:0)
-	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
-	/// Storage: Identity SubsOf (r:1 w:1)
-	/// Proof: Identity SubsOf (max_values: None, max_size: Some(3258), added: 5733, mode: MaxEncodedLen)
-	/// Storage: Identity SuperOf (r:0 w:100)
-	/// Proof: Identity SuperOf (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
-	/// The range of component `p` is `[0, 100]`.
-	fn set_subs_old(p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `194 + p * (32 ±0)`
-		//  Estimated: `11003`
-		// Minimum execution time: 9_329_000 picoseconds.
-		Weight::from_parts(24_055_061, 0)
-			.saturating_add(Weight::from_parts(0, 11003))
+			.saturating_add(Weight::from_parts(2_898_525, 0).saturating_mul(a.into()))
 			// Standard Error: 3_428
-			.saturating_add(Weight::from_parts(1_130_604, 0).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(1_130_604, 0).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2589).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(r.into()))
 	}
 	/// Storage: Identity SubsOf (r:1 w:1)
 	/// Proof: Identity SubsOf (max_values: None, max_size: Some(3258), added: 5733, mode: MaxEncodedLen)
@@ -104,6 +106,18 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
 	/// Storage: Identity SuperOf (r:0 w:100)
 	/// Proof: Identity SuperOf (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: `Identity::NameOf` (r:1 w:1)
+	/// Proof: `Identity::NameOf` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AccountOfName` (r:0 w:1)
+	/// Proof: `Identity::AccountOfName` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ManagerOf` (r:0 w:1)
+	/// Proof: `Identity::ManagerOf` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::EvidenceOf` (r:1 w:1)
+	/// Proof: `Identity::EvidenceOf` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ChallengeOf` (r:1 w:1)
+	/// Proof: `Identity::ChallengeOf` (`max_values`: None, `max_size`: Some(376), added: 2851, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingSubsOf` (r:1 w:1)
+	/// Proof: `Identity::PendingSubsOf` (`max_values`: None, `max_size`: Some(6658), added: 9133, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	/// The range of component `s` is `[0, 100]`.
 	fn clear_identity(_r: u32, s: u32, ) -> Weight {
@@ -115,14 +129,22 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 11003))
 			// Standard Error: 1_353
 			.saturating_add(Weight::from_parts(1_074_019, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(8))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
 	}
 	/// Storage: Identity Registrars (r:1 w:0)
 	/// Proof: Identity Registrars (max_values: Some(1), max_size: Some(1141), added: 1636, mode: MaxEncodedLen)
 	/// Storage: Identity IdentityOf (r:1 w:1)
 	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
+	/// Storage: `Identity::EvidenceOf` (r:0 w:1)
+	/// Proof: `Identity::EvidenceOf` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingRequestsOf` (r:1 w:1)
+	/// Proof: `Identity::PendingRequestsOf` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RequestDeadlineOf` (r:0 w:1)
+	/// Proof: `Identity::RequestDeadlineOf` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RequestedFieldsOf` (r:0 w:1)
+	/// Proof: `Identity::RequestedFieldsOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	fn request_judgement(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -133,11 +155,17 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 11003))
 			// Standard Error: 2_214
 			.saturating_add(Weight::from_parts(83_822, 0).saturating_mul(r.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: Identity IdentityOf (r:1 w:1)
 	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
+	/// Storage: Identity PendingRequestsOf (r:1 w:1)
+	/// Proof: Identity PendingRequestsOf (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: Identity RequestDeadlineOf (r:0 w:1)
+	/// Proof: Identity RequestDeadlineOf (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: `Identity::RequestedFieldsOf` (r:0 w:1)
+	/// Proof: `Identity::RequestedFieldsOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	fn cancel_request(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -148,8 +176,8 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 11003))
 			// Standard Error: 2_528
 			.saturating_add(Weight::from_parts(85_593, 0).saturating_mul(r.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: Identity Registrars (r:1 w:1)
 	/// Proof: Identity Registrars (max_values: Some(1), max_size: Some(1141), added: 1636, mode: MaxEncodedLen)
@@ -200,6 +228,14 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 	/// Proof: Identity Registrars (max_values: Some(1), max_size: Some(1141), added: 1636, mode: MaxEncodedLen)
 	/// Storage: Identity IdentityOf (r:1 w:1)
 	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
+	/// Storage: `Identity::EvidenceOf` (r:0 w:1)
+	/// Proof: `Identity::EvidenceOf` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingRequestsOf` (r:1 w:1)
+	/// Proof: `Identity::PendingRequestsOf` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RequestDeadlineOf` (r:1 w:1)
+	/// Proof: `Identity::RequestDeadlineOf` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RequestedFieldsOf` (r:1 w:1)
+	/// Proof: `Identity::RequestedFieldsOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 19]`.
 	fn provide_judgement(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -210,17 +246,29 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 11003))
 			// Standard Error: 2_881
 			.saturating_add(Weight::from_parts(109_812, 0).saturating_mul(r.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: Identity SubsOf (r:1 w:1)
 	/// Proof: Identity SubsOf (max_values: None, max_size: Some(3258), added: 5733, mode: MaxEncodedLen)
 	/// Storage: Identity IdentityOf (r:1 w:1)
 	/// Proof: Identity IdentityOf (max_values: None, max_size: Some(7538), added: 10013, mode: MaxEncodedLen)
-	/// Storage: System Account (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Identity SuperOf (r:0 w:100)
 	/// Proof: Identity SuperOf (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
+	/// Storage: `Identity::NameOf` (r:1 w:1)
+	/// Proof: `Identity::NameOf` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AccountOfName` (r:0 w:1)
+	/// Proof: `Identity::AccountOfName` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ManagerOf` (r:0 w:1)
+	/// Proof: `Identity::ManagerOf` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::EvidenceOf` (r:1 w:1)
+	/// Proof: `Identity::EvidenceOf` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ChallengeOf` (r:1 w:1)
+	/// Proof: `Identity::ChallengeOf` (`max_values`: None, `max_size`: Some(376), added: 2851, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingSubsOf` (r:1 w:1)
+	/// Proof: `Identity::PendingSubsOf` (`max_values`: None, `max_size`: Some(6658), added: 9133, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	/// The range of component `s` is `[0, 100]`.
 	fn kill_identity(r: u32, s: u32, ) -> Weight {
@@ -234,8 +282,8 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(15_486, 0).saturating_mul(r.into()))
 			// Standard Error: 1_275
 			.saturating_add(Weight::from_parts(1_085_117, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(10))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
 	}
 	/// Storage: Identity IdentityOf (r:1 w:0)
@@ -280,6 +328,8 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 	/// Proof: Identity SuperOf (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
 	/// Storage: Identity SubsOf (r:1 w:1)
 	/// Proof: Identity SubsOf (max_values: None, max_size: Some(3258), added: 5733, mode: MaxEncodedLen)
+	/// Storage: `Identity::SubDepositOf` (r:1 w:1)
+	/// Proof: `Identity::SubDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[1, 100]`.
 	fn remove_sub(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -290,8 +340,8 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 11003))
 			// Standard Error: 854
 			.saturating_add(Weight::from_parts(52_028, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Identity SuperOf (r:1 w:1)
 	/// Proof: Identity SuperOf (max_values: None, max_size: Some(114), added: 2589, mode: MaxEncodedLen)
@@ -299,6 +349,8 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 	/// Proof: Identity SubsOf (max_values: None, max_size: Some(3258), added: 5733, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:0)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// Storage: `Identity::SubDepositOf` (r:1 w:1)
+	/// Proof: `Identity::SubDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[0, 99]`.
 	fn quit_sub(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -309,8 +361,8 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 6723))
 			// Standard Error: 856
 			.saturating_add(Weight::from_parts(57_463, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Identity::UsernameAuthorities` (r:0 w:1)
 	/// Proof: `Identity::UsernameAuthorities` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
@@ -401,6 +453,405 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 	fn kill_username(_p: u32, ) -> Weight {
 		Weight::zero()
 	}
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AccountOfName` (r:1 w:2)
+	/// Proof: `Identity::AccountOfName` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::NameOf` (r:1 w:1)
+	/// Proof: `Identity::NameOf` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_name() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7273`
+		//  Estimated: `11003`
+		// Minimum execution time: 38_412_000 picoseconds.
+		Weight::from_parts(38_412_000, 0)
+			.saturating_add(Weight::from_parts(0, 11003))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Identity::NameOf` (r:1 w:1)
+	/// Proof: `Identity::NameOf` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AccountOfName` (r:0 w:1)
+	/// Proof: `Identity::AccountOfName` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn release_name() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `290`
+		//  Estimated: `3593`
+		// Minimum execution time: 27_905_000 picoseconds.
+		Weight::from_parts(27_905_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ManagerOf` (r:0 w:1)
+	/// Proof: `Identity::ManagerOf` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn authorize_manager() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6982`
+		//  Estimated: `11003`
+		// Minimum execution time: 17_216_000 picoseconds.
+		Weight::from_parts(17_216_000, 0)
+			.saturating_add(Weight::from_parts(0, 11003))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Identity::ManagerOf` (r:1 w:1)
+	/// Proof: `Identity::ManagerOf` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	fn revoke_manager() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `177`
+		//  Estimated: `3537`
+		// Minimum execution time: 11_645_000 picoseconds.
+		Weight::from_parts(11_645_000, 0)
+			.saturating_add(Weight::from_parts(0, 3537))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SubsOf` (r:1 w:1)
+	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn poke_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10523`
+		//  Estimated: `11003`
+		// Minimum execution time: 64_731_000 picoseconds.
+		Weight::from_parts(64_731_000, 0)
+			.saturating_add(Weight::from_parts(0, 11003))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SuperOf` (r:1 w:0)
+	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingSubsOf` (r:1 w:1)
+	/// Proof: `Identity::PendingSubsOf` (`max_values`: None, `max_size`: Some(6658), added: 9133, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn invite_sub() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6982`
+		//  Estimated: `11003`
+		// Minimum execution time: 16_834_000 picoseconds.
+		Weight::from_parts(16_834_000, 0)
+			.saturating_add(Weight::from_parts(0, 11003))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Identity::PendingSubsOf` (r:1 w:1)
+	/// Proof: `Identity::PendingSubsOf` (`max_values`: None, `max_size`: Some(6658), added: 9133, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SuperOf` (r:1 w:1)
+	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SubsOf` (r:1 w:1)
+	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SubDepositOf` (r:0 w:1)
+	/// Proof: `Identity::SubDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 99]`.
+	fn add_sub_with_own_deposit(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `546 + s * (36 ±0)`
+		//  Estimated: `11003`
+		// Minimum execution time: 35_112_000 picoseconds.
+		Weight::from_parts(35_112_000, 0)
+			.saturating_add(Weight::from_parts(0, 11003))
+			// Standard Error: 2_217
+			.saturating_add(Weight::from_parts(121_390, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::EvidenceOf` (r:0 w:2)
+	/// Proof: `Identity::EvidenceOf` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingRequestsOf` (r:1 w:1)
+	/// Proof: `Identity::PendingRequestsOf` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RequestDeadlineOf` (r:1 w:1)
+	/// Proof: `Identity::RequestDeadlineOf` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RequestedFieldsOf` (r:1 w:1)
+	/// Proof: `Identity::RequestedFieldsOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn provide_judgement_with_evidence(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7045 + r * (57 ±0)`
+		//  Estimated: `11003`
+		// Minimum execution time: 108_640_000 picoseconds.
+		Weight::from_parts(108_640_000, 0)
+			.saturating_add(Weight::from_parts(0, 11003))
+			// Standard Error: 7_115
+			.saturating_add(Weight::from_parts(151_202, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `Identity::EvidenceOf` (r:1 w:1)
+	/// Proof: `Identity::EvidenceOf` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	fn dispute_evidence() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `184`
+		//  Estimated: `3583`
+		// Minimum execution time: 13_512_000 picoseconds.
+		Weight::from_parts(13_512_000, 0)
+			.saturating_add(Weight::from_parts(0, 3583))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Identity::IdentityOf` (r:2 w:2)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SubsOf` (r:1 w:2)
+	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::NameOf` (r:1 w:2)
+	/// Proof: `Identity::NameOf` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::EvidenceOf` (r:21 w:40)
+	/// Proof: `Identity::EvidenceOf` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ChallengeOf` (r:21 w:40)
+	/// Proof: `Identity::ChallengeOf` (`max_values`: None, `max_size`: Some(376), added: 2851, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SuperOf` (r:100 w:100)
+	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AccountOfName` (r:0 w:1)
+	/// Proof: `Identity::AccountOfName` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ManagerOf` (r:0 w:1)
+	/// Proof: `Identity::ManagerOf` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 20]`.
+	/// The range of component `s` is `[0, 100]`.
+	fn transfer_identity(r: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7380 + r * (5 ±0) + s * (32 ±0)`
+		//  Estimated: `11003 + r * (5444 ±0) + s * (2589 ±0)`
+		// Minimum execution time: 92_417_000 picoseconds.
+		Weight::from_parts(92_417_000, 0)
+			.saturating_add(Weight::from_parts(0, 11003))
+			// Standard Error: 9_874
+			.saturating_add(Weight::from_parts(1_212_306, 0).saturating_mul(r.into()))
+			// Standard Error: 1_930
+			.saturating_add(Weight::from_parts(2_815_904, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(11))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(r.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 5444).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 2589).saturating_mul(s.into()))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttesterOf` (r:0 w:1)
+	/// Proof: `Identity::AttesterOf` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn set_attester(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `89 + r * (57 ±0)`
+		//  Estimated: `2626`
+		// Minimum execution time: 8_112_000 picoseconds.
+		Weight::from_parts(8_112_000, 0)
+			.saturating_add(Weight::from_parts(0, 2626))
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(79_511, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttesterOf` (r:1 w:0)
+	/// Proof: `Identity::AttesterOf` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ChallengeOf` (r:0 w:1)
+	/// Proof: `Identity::ChallengeOf` (`max_values`: None, `max_size`: Some(376), added: 2851, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn set_challenge(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7102 + r * (57 ±0)`
+		//  Estimated: `11003`
+		// Minimum execution time: 23_874_000 picoseconds.
+		Weight::from_parts(23_874_000, 0)
+			.saturating_add(Weight::from_parts(0, 11003))
+			// Standard Error: 3_215
+			.saturating_add(Weight::from_parts(96_118, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ChallengeOf` (r:1 w:1)
+	/// Proof: `Identity::ChallengeOf` (`max_values`: None, `max_size`: Some(376), added: 2851, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn remove_challenge(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `431 + r * (57 ±0)`
+		//  Estimated: `3841`
+		// Minimum execution time: 12_304_000 picoseconds.
+		Weight::from_parts(12_304_000, 0)
+			.saturating_add(Weight::from_parts(0, 3841))
+			// Standard Error: 1_517
+			.saturating_add(Weight::from_parts(81_740, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Identity::ChallengeOf` (r:1 w:1)
+	/// Proof: `Identity::ChallengeOf` (`max_values`: None, `max_size`: Some(376), added: 2851, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttesterOf` (r:1 w:0)
+	/// Proof: `Identity::AttesterOf` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn submit_attestation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `443`
+		//  Estimated: `3841`
+		// Minimum execution time: 52_618_000 picoseconds.
+		Weight::from_parts(52_618_000, 0)
+			.saturating_add(Weight::from_parts(0, 3841))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:1)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn set_max_pending(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `89 + r * (57 ±0)`
+		//  Estimated: `2626`
+		// Minimum execution time: 7_021_000 picoseconds.
+		Weight::from_parts(7_021_000, 0)
+			.saturating_add(Weight::from_parts(0, 2626))
+			// Standard Error: 1_297
+			.saturating_add(Weight::from_parts(82_904, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 20]`.
+	fn freeze_identity(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6998 + r * (5 ±0)`
+		//  Estimated: `11003`
+		// Minimum execution time: 19_811_000 picoseconds.
+		Weight::from_parts(19_811_000, 0)
+			.saturating_add(Weight::from_parts(0, 11003))
+			// Standard Error: 1_862
+			.saturating_add(Weight::from_parts(74_603, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 20]`.
+	fn thaw_identity(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6998 + r * (5 ±0)`
+		//  Estimated: `11003`
+		// Minimum execution time: 19_642_000 picoseconds.
+		Weight::from_parts(19_642_000, 0)
+			.saturating_add(Weight::from_parts(0, 11003))
+			// Standard Error: 1_905
+			.saturating_add(Weight::from_parts(76_118, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:1)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingRequestsOf` (r:0 w:1)
+	/// Proof: `Identity::PendingRequestsOf` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AttesterOf` (r:0 w:1)
+	/// Proof: `Identity::AttesterOf` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ChallengeOf` (r:1000 w:1000)
+	/// Proof: `Identity::ChallengeOf` (`max_values`: None, `max_size`: Some(376), added: 2851, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::EvidenceOf` (r:1000 w:1000)
+	/// Proof: `Identity::EvidenceOf` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 20]`.
+	/// The range of component `e` is `[0, 1000]`.
+	fn remove_registrar(r: u32, e: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `31 + r * (57 ±0)`
+		//  Estimated: `2626 + e * (2851 ±0)`
+		// Minimum execution time: 11_402_000 picoseconds.
+		Weight::from_parts(11_402_000, 0)
+			.saturating_add(Weight::from_parts(0, 2626))
+			// Standard Error: 2_417
+			.saturating_add(Weight::from_parts(91_314, 0).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(3_500_000, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(e.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 2851).saturating_mul(e.into()))
+	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingRequestsOf` (r:1 w:1)
+	/// Proof: `Identity::PendingRequestsOf` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RequestDeadlineOf` (r:0 w:1)
+	/// Proof: `Identity::RequestDeadlineOf` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RequestedFieldsOf` (r:0 w:1)
+	/// Proof: `Identity::RequestedFieldsOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn reclaim_fee(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7069 + r * (5 ±0)`
+		//  Estimated: `11003`
+		// Minimum execution time: 27_118_000 picoseconds.
+		Weight::from_parts(27_118_000, 0)
+			.saturating_add(Weight::from_parts(0, 11003))
+			// Standard Error: 2_006
+			.saturating_add(Weight::from_parts(81_207, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Identity::RequestDeadlineOf` (r:1 w:1)
+	/// Proof: `Identity::RequestDeadlineOf` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingRequestsOf` (r:1 w:1)
+	/// Proof: `Identity::PendingRequestsOf` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RequestedFieldsOf` (r:0 w:1)
+	/// Proof: `Identity::RequestedFieldsOf` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn expire_request(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7112 + r * (5 ±0)`
+		//  Estimated: `11003`
+		// Minimum execution time: 29_406_000 picoseconds.
+		Weight::from_parts(29_406_000, 0)
+			.saturating_add(Weight::from_parts(0, 11003))
+			// Standard Error: 2_113
+			.saturating_add(Weight::from_parts(82_734, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Identity::PendingSubsOf` (r:1 w:1)
+	/// Proof: `Identity::PendingSubsOf` (`max_values`: None, `max_size`: Some(6658), added: 9133, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 100]`.
+	fn cancel_sub_invite(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `175 + s * (66 ±0)`
+		//  Estimated: `10123`
+		// Minimum execution time: 21_408_000 picoseconds.
+		Weight::from_parts(21_408_000, 0)
+			.saturating_add(Weight::from_parts(0, 10123))
+			// Standard Error: 1_407
+			.saturating_add(Weight::from_parts(62_318, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 	fn migration_v2_authority_step() -> Weight {
 		Weight::zero()
 	}
@@ -419,4 +870,55 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 	fn migration_v2_cleanup_username_step() -> Weight {
 		Weight::zero()
 	}
+	/// Storage: `Identity::Registrars` (r:1 w:1)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	fn migration_v4_registrars_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1172`
+		//  Estimated: `2626`
+		// Minimum execution time: 11_500_000 picoseconds.
+		Weight::from_parts(11_500_000, 0)
+			.saturating_add(Weight::from_parts(0, 2626))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Identity::IdentityOf` (r:2 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingRequestsOf` (r:20 w:20)
+	/// Proof: `Identity::PendingRequestsOf` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn migration_v4_identity_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7162`
+		//  Estimated: `70836`
+		// Minimum execution time: 135_805_000 picoseconds.
+		Weight::from_parts(135_805_000, 0)
+			.saturating_add(Weight::from_parts(0, 70836))
+			.saturating_add(T::DbWeight::get().reads(22))
+			.saturating_add(T::DbWeight::get().writes(21))
+	}
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SubsOf` (r:1 w:0)
+	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SubDepositOf` (r:1 w:0)
+	/// Proof: `Identity::SubDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingSubsOf` (r:1 w:0)
+	/// Proof: `Identity::PendingSubsOf` (`max_values`: None, `max_size`: Some(6658), added: 9133, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::NameOf` (r:1 w:0)
+	/// Proof: `Identity::NameOf` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::UsernameInfoOf` (r:1 w:0)
+	/// Proof: `Identity::UsernameInfoOf` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingUsernames` (r:1 w:0)
+	/// Proof: `Identity::PendingUsernames` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AuthorityOf` (r:1 w:0)
+	/// Proof: `Identity::AuthorityOf` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn held_deposits_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `11003`
+		// Minimum execution time: 34_120_000 picoseconds.
+		Weight::from_parts(34_120_000, 0)
+			.saturating_add(Weight::from_parts(0, 11003))
+			.saturating_add(T::DbWeight::get().reads(8))
+	}
 }
@@ -20,6 +20,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `sbtb`, CPU: `13th Gen Intel(R) Core(TM) i7-1365U`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("rococo-dev")`, DB CACHE: 1024
+//!
+//! NOTE: The weights of `migrate_identity_to`, `receive_identity` and `restore_identity` were
+//! written by hand and have not been measured with the benchmark CLI yet. Re-run the benchmarks
+//! before relying on them.
 
 // Executed Command:
 // ./target/release/polkadot
@@ -94,4 +98,84 @@ impl<T: frame_system::Config> polkadot_runtime_common::identity_migrator::Weight
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SubsOf` (r:1 w:1)
+	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SuperOf` (r:100 w:100)
+	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `IdentityMigrator::MigratedIdentityOf` (r:0 w:1)
+	/// Proof: `IdentityMigrator::MigratedIdentityOf` (`max_values`: None, `max_size`: Some(14140), added: 16615, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[0, 20]`.
+	/// The range of component `s` is `[0, 100]`.
+	fn migrate_identity_to(r: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7457 + r * (5 ±0) + s * (32 ±0)`
+		//  Estimated: `11003 + r * (7 ±0) + s * (2589 ±0)`
+		// Minimum execution time: 171_208_000 picoseconds.
+		Weight::from_parts(168_944_371, 0)
+			.saturating_add(Weight::from_parts(0, 11003))
+			// Standard Error: 15_902
+			.saturating_add(Weight::from_parts(241_517, 0).saturating_mul(r.into()))
+			// Standard Error: 3_185
+			.saturating_add(Weight::from_parts(3_942_116, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 7).saturating_mul(r.into()))
+			.saturating_add(Weight::from_parts(0, 2589).saturating_mul(s.into()))
+	}
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SuperOf` (r:100 w:100)
+	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SubsOf` (r:1 w:1)
+	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 100]`.
+	fn receive_identity(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6867`
+		//  Estimated: `11003 + s * (2589 ±0)`
+		// Minimum execution time: 104_630_000 picoseconds.
+		Weight::from_parts(101_912_402, 0)
+			.saturating_add(Weight::from_parts(0, 11003))
+			// Standard Error: 2_760
+			.saturating_add(Weight::from_parts(3_064_283, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2589).saturating_mul(s.into()))
+	}
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SuperOf` (r:100 w:100)
+	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SubsOf` (r:1 w:1)
+	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `IdentityMigrator::MigratedIdentityOf` (r:1 w:1)
+	/// Proof: `IdentityMigrator::MigratedIdentityOf` (`max_values`: None, `max_size`: Some(14140), added: 16615, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 100]`.
+	fn restore_identity(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6867`
+		//  Estimated: `16615 + s * (2589 ±0)`
+		// Minimum execution time: 104_630_000 picoseconds.
+		Weight::from_parts(101_912_402, 0)
+			.saturating_add(Weight::from_parts(0, 16615))
+			// Standard Error: 2_760
+			.saturating_add(Weight::from_parts(3_064_283, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2589).saturating_mul(s.into()))
+	}
 }
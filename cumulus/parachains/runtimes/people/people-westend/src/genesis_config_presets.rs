@@ -0,0 +1,53 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # People Westend Runtime genesis config presets
+
+use crate::*;
+use alloc::{vec, vec::Vec};
+use parachains_common::genesis_config_helpers::GenesisConfigBuilder;
+use sp_genesis_builder::PresetId;
+
+const PEOPLE_WESTEND_ED: Balance = ExistentialDeposit::get();
+const PEOPLE_WESTEND_PARA_ID: u32 = 1004;
+
+fn people_westend_genesis(builder: GenesisConfigBuilder) -> Vec<u8> {
+	builder
+		.with_candidacy_bond(PEOPLE_WESTEND_ED * 16)
+		.with_sudo(None)
+		.build_bytes()
+}
+
+/// Provides the JSON representation of predefined genesis config for given `id`.
+pub fn get_preset(id: &PresetId) -> Option<Vec<u8>> {
+	let endowment = PEOPLE_WESTEND_ED * 524_288;
+	let builder = match id.as_ref() {
+		sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET =>
+			GenesisConfigBuilder::local_testnet(PEOPLE_WESTEND_PARA_ID.into(), endowment),
+		sp_genesis_builder::DEV_RUNTIME_PRESET =>
+			GenesisConfigBuilder::development(PEOPLE_WESTEND_PARA_ID.into(), endowment),
+		_ => return None,
+	};
+
+	Some(people_westend_genesis(builder))
+}
+
+/// List of supported presets.
+pub fn preset_names() -> Vec<PresetId> {
+	vec![
+		PresetId::from(sp_genesis_builder::DEV_RUNTIME_PRESET),
+		PresetId::from(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET),
+	]
+}
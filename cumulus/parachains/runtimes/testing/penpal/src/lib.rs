@@ -468,6 +468,8 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = AssetsStringLimit;
 	type Freezer = ();
+	type MaxFreezes = ConstU32<8>;
+	type UriLimit = ConstU32<256>;
 	type Extra = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type CallbackHandle = ();
@@ -506,6 +508,8 @@ impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
 	type ApprovalDeposit = ForeignAssetsApprovalDeposit;
 	type StringLimit = ForeignAssetsAssetsStringLimit;
 	type Freezer = ();
+	type MaxFreezes = ConstU32<8>;
+	type UriLimit = ConstU32<256>;
 	type Extra = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type CallbackHandle = ();
@@ -518,6 +522,7 @@ impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
 parameter_types! {
 	pub const AssetConversionPalletId: PalletId = PalletId(*b"py/ascon");
 	pub const LiquidityWithdrawalFee: Permill = Permill::from_percent(0);
+	pub const OrderDeposit: Balance = deposit(1, 128);
 }
 
 ord_parameter_types! {
@@ -545,6 +550,8 @@ impl pallet_assets::Config<PoolAssetsInstance> for Runtime {
 	type ApprovalDeposit = ConstU128<0>;
 	type StringLimit = ConstU32<50>;
 	type Freezer = ();
+	type MaxFreezes = ConstU32<8>;
+	type UriLimit = ConstU32<256>;
 	type Extra = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type CallbackHandle = ();
@@ -604,6 +611,9 @@ impl pallet_asset_conversion::Config for Runtime {
 	type LPFee = ConstU32<3>;
 	type PalletId = AssetConversionPalletId;
 	type MaxSwapPathLength = ConstU32<3>;
+	type MaxOrdersPerPool = ConstU32<8>;
+	type MaxOrderFillsPerBlock = ConstU32<8>;
+	type OrderDeposit = OrderDeposit;
 	type MintMinLiquidity = ConstU128<100>;
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
@@ -659,6 +669,7 @@ impl pallet_message_queue::Config for Runtime {
 	// The XCMP queue pallet is only ever able to handle the `Sibling(ParaId)` origin:
 	type QueueChangeHandler = NarrowOriginToSibling<XcmpQueue>;
 	type QueuePausedQuery = NarrowOriginToSibling<XcmpQueue>;
+	type ServiceWeightShare = ();
 	type HeapSize = sp_core::ConstU32<{ 103 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
@@ -784,6 +795,9 @@ impl pallet_sudo::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
+	type SensitiveCalls = frame_support::traits::Nothing;
+	type NestedCalls = ();
+	type MaxLogLength = ConstU32<0>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -20,6 +20,7 @@ use crate::{
 	chain_spec::DiskChainSpecLoader,
 	common::{
 		chain_spec::{Extensions, LoadSpec},
+		inherents::InherentProviderRegistry,
 		NodeExtraArgs,
 	},
 };
@@ -30,7 +31,7 @@ use sc_cli::{
 	RpcEndpoint, SharedParams, SubstrateCli,
 };
 use sc_service::{config::PrometheusConfig, BasePath};
-use std::{fmt::Debug, marker::PhantomData, path::PathBuf};
+use std::{fmt::Debug, marker::PhantomData, path::PathBuf, sync::Arc};
 
 /// Trait that can be used to customize some of the customer-facing info related to the node binary
 /// that is being built using this library.
@@ -155,6 +156,17 @@ pub struct Cli<Config: CliConfig> {
 	#[arg(long)]
 	pub export_pov_to_path: Option<PathBuf>,
 
+	/// Path to the configuration file of the providers of the inherents required by the runtime
+	/// which the node doesn't provide by default.
+	///
+	/// The node refuses to author blocks if the runtime declares required inherents without a
+	/// configured provider.
+	#[arg(long, value_name = "PATH")]
+	pub inherent_providers: Option<PathBuf>,
+
+	#[arg(skip)]
+	pub(crate) inherent_provider_plugins: Arc<InherentProviderRegistry>,
+
 	/// Relay chain arguments
 	#[arg(raw = true)]
 	pub relay_chain_args: Vec<String>,
@@ -168,6 +180,8 @@ impl<Config: CliConfig> Cli<Config> {
 		NodeExtraArgs {
 			use_slot_based_consensus: self.experimental_use_slot_based,
 			export_pov: self.export_pov_to_path.clone(),
+			inherent_providers: self.inherent_providers.clone(),
+			inherent_provider_plugins: self.inherent_provider_plugins.clone(),
 		}
 	}
 }
@@ -18,6 +18,7 @@ use crate::{
 	cli::{Cli, RelayChainCli, Subcommand},
 	common::{
 		chain_spec::{Extensions, LoadSpec},
+		inherents::InherentProviderRegistry,
 		runtime::{
 			AuraConsensusId, Consensus, Runtime, RuntimeResolver as RuntimeResolverT,
 			RuntimeResolver,
@@ -46,6 +47,8 @@ pub struct RunConfig {
 	pub chain_spec_loader: Box<dyn LoadSpec>,
 	/// A custom runtime resolver.
 	pub runtime_resolver: Box<dyn RuntimeResolver>,
+	/// The plugins which can provide the inherents required by the runtime.
+	pub inherent_provider_plugins: InherentProviderRegistry,
 }
 
 impl RunConfig {
@@ -54,7 +57,18 @@ impl RunConfig {
 		runtime_resolver: Box<dyn RuntimeResolver>,
 		chain_spec_loader: Box<dyn LoadSpec>,
 	) -> Self {
-		RunConfig { chain_spec_loader, runtime_resolver }
+		RunConfig {
+			chain_spec_loader,
+			runtime_resolver,
+			inherent_provider_plugins: Default::default(),
+		}
+	}
+
+	/// Use the given plugins to provide the inherents required by the runtime, in addition to
+	/// the built-in ones.
+	pub fn with_inherent_provider_plugins(mut self, plugins: InherentProviderRegistry) -> Self {
+		self.inherent_provider_plugins = plugins;
+		self
 	}
 }
 
@@ -100,6 +114,7 @@ fn new_node_spec(
 pub fn run<CliConfig: crate::cli::CliConfig>(cmd_config: RunConfig) -> Result<()> {
 	let mut cli = Cli::<CliConfig>::from_args();
 	cli.chain_spec_loader = Some(cmd_config.chain_spec_loader);
+	cli.inherent_provider_plugins = cmd_config.inherent_provider_plugins.into();
 
 	match &cli.subcommand {
 		Some(Subcommand::BuildSpec(cmd)) => {
@@ -232,7 +247,12 @@ pub fn run<CliConfig: crate::cli::CliConfig>(cmd_config: RunConfig) -> Result<()
 
 				if let Some(dev_block_time) = cli.dev_block_time {
 					return node_spec
-						.start_manual_seal_node(config, para_id, dev_block_time)
+						.start_manual_seal_node(
+							config,
+							para_id,
+							dev_block_time,
+							cli.node_extra_args(),
+						)
 						.map_err(Into::into)
 				}
 
@@ -0,0 +1,364 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Providers of the inherents required by a runtime which the node doesn't provide by default.
+//!
+//! Runtimes declare the additional inherents they require through the [`RequiredInherentsApi`].
+//! A configuration file, passed with `--inherent-providers`, names the plugin providing the data
+//! of each of them:
+//!
+//! ```json
+//! {
+//!   "inherents": [
+//!     { "identifier": "pricefed", "plugin": "file", "config": { "path": "/run/oracle/price" } },
+//!     { "identifier": "0x72616e646f6d6e73", "plugin": "static", "config": { "value": "0x00" } }
+//!   ]
+//! }
+//! ```
+//!
+//! The built-in plugins are:
+//! - `static`: provides the SCALE encoded `value` of its configuration for every block.
+//! - `file`: provides the SCALE encoded, hex formatted, content of the file at `path`, read anew
+//!   for every block, e.g. as written by an external price oracle.
+//!
+//! Node binaries built with this library may register further plugins in the
+//! [`InherentProviderRegistry`] of their [`crate::RunConfig`].
+
+use super::NodeExtraArgs;
+use codec::{Encode, Output};
+use cumulus_primitives_core::RequiredInherentsApi;
+use serde::Deserialize;
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_inherents::{InherentData, InherentIdentifier};
+use sp_runtime::traits::Block as BlockT;
+use std::{collections::HashMap, path::Path, sync::Arc};
+
+/// A plugin providing the data of an inherent.
+pub trait InherentProviderPlugin: Send + Sync {
+	/// Returns the SCALE encoded data of the inherent for the block being built.
+	fn provide(&self) -> Result<Vec<u8>, String>;
+}
+
+/// A function building a plugin from its configuration.
+pub type InherentProviderFactory = Box<
+	dyn Fn(&serde_json::Value) -> Result<Box<dyn InherentProviderPlugin>, String> + Send + Sync,
+>;
+
+/// The inherent provider plugins which can be referred to by name in the configuration file.
+pub struct InherentProviderRegistry {
+	factories: HashMap<String, InherentProviderFactory>,
+}
+
+impl Default for InherentProviderRegistry {
+	fn default() -> Self {
+		Self { factories: HashMap::new() }
+			.with_plugin("static", |config| {
+				let value = hex_field(config, "value")?;
+				Ok(Box::new(StaticProvider(value)))
+			})
+			.with_plugin("file", |config| {
+				let path = config
+					.get("path")
+					.and_then(|path| path.as_str())
+					.ok_or("missing `path` in the `file` plugin configuration")?;
+				Ok(Box::new(FileProvider(path.into())))
+			})
+	}
+}
+
+impl InherentProviderRegistry {
+	/// Register the plugin `name`, replacing any plugin previously registered under that name.
+	pub fn with_plugin(
+		mut self,
+		name: impl Into<String>,
+		factory: impl Fn(&serde_json::Value) -> Result<Box<dyn InherentProviderPlugin>, String>
+			+ Send
+			+ Sync
+			+ 'static,
+	) -> Self {
+		self.factories.insert(name.into(), Box::new(factory));
+		self
+	}
+
+	fn build(
+		&self,
+		name: &str,
+		config: &serde_json::Value,
+	) -> Result<Box<dyn InherentProviderPlugin>, String> {
+		let factory = self
+			.factories
+			.get(name)
+			.ok_or_else(|| format!("unknown inherent provider plugin `{name}`"))?;
+		factory(config).map_err(|e| format!("invalid configuration of plugin `{name}`: {e}"))
+	}
+}
+
+/// Provides a fixed value.
+struct StaticProvider(Vec<u8>);
+
+impl InherentProviderPlugin for StaticProvider {
+	fn provide(&self) -> Result<Vec<u8>, String> {
+		Ok(self.0.clone())
+	}
+}
+
+/// Provides the hex formatted content of a file.
+struct FileProvider(std::path::PathBuf);
+
+impl InherentProviderPlugin for FileProvider {
+	fn provide(&self) -> Result<Vec<u8>, String> {
+		let content = std::fs::read_to_string(&self.0)
+			.map_err(|e| format!("failed to read {}: {e}", self.0.display()))?;
+		parse_hex(content.trim())
+	}
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct InherentProvidersConfig {
+	inherents: Vec<InherentProviderConfig>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct InherentProviderConfig {
+	/// The identifier of the inherent, as 8 ASCII characters or 8 hex encoded bytes.
+	identifier: String,
+	/// The name of the plugin providing the inherent.
+	plugin: String,
+	/// The configuration of the plugin.
+	#[serde(default)]
+	config: serde_json::Value,
+}
+
+/// The configured providers of additional inherents.
+///
+/// It is used as an inherent data provider, putting the data of every configured inherent.
+#[derive(Clone, Default)]
+pub struct ExtraInherentProviders {
+	providers: Arc<Vec<(InherentIdentifier, Box<dyn InherentProviderPlugin>)>>,
+}
+
+impl ExtraInherentProviders {
+	/// Load the providers from the configuration file at `path`.
+	pub fn load(path: &Path, registry: &InherentProviderRegistry) -> Result<Self, String> {
+		let content = std::fs::read_to_string(path)
+			.map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+		Self::from_json(&content, registry)
+	}
+
+	fn from_json(content: &str, registry: &InherentProviderRegistry) -> Result<Self, String> {
+		let config: InherentProvidersConfig = serde_json::from_str(content)
+			.map_err(|e| format!("invalid inherent providers configuration: {e}"))?;
+
+		let mut providers: Vec<(InherentIdentifier, Box<dyn InherentProviderPlugin>)> = Vec::new();
+		for provider in config.inherents {
+			let identifier = parse_identifier(&provider.identifier)?;
+			if providers.iter().any(|(id, _)| *id == identifier) {
+				return Err(format!("inherent `{}` is configured twice", provider.identifier))
+			}
+			providers.push((identifier, registry.build(&provider.plugin, &provider.config)?));
+		}
+
+		Ok(Self { providers: Arc::new(providers) })
+	}
+
+	/// Ensure that every inherent required by the runtime at `at` has a provider.
+	///
+	/// Runtimes not supporting the [`RequiredInherentsApi`] are assumed not to require any.
+	pub fn ensure_required<Block, Client>(
+		&self,
+		client: &Client,
+		at: Block::Hash,
+	) -> Result<(), String>
+	where
+		Block: BlockT,
+		Client: ProvideRuntimeApi<Block>,
+		Client::Api: RequiredInherentsApi<Block>,
+	{
+		let runtime_api = client.runtime_api();
+		if !runtime_api
+			.has_api::<dyn RequiredInherentsApi<Block>>(at)
+			.map_err(|e| format!("failed to check for the required inherents API: {e}"))?
+		{
+			return Ok(())
+		}
+
+		let missing = runtime_api
+			.required_inherents(at)
+			.map_err(|e| format!("failed to fetch the inherents required by the runtime: {e}"))?
+			.into_iter()
+			.filter(|required| !self.providers.iter().any(|(id, _)| id == required))
+			.map(|required| display_identifier(&required))
+			.collect::<Vec<_>>();
+
+		if missing.is_empty() {
+			Ok(())
+		} else {
+			Err(format!(
+				"the runtime requires inherents without configured provider: {}. \
+				Configure them with `--inherent-providers`.",
+				missing.join(", ")
+			))
+		}
+	}
+}
+
+impl NodeExtraArgs {
+	/// Load the configured providers of additional inherents and ensure they cover the inherents
+	/// required by the runtime at `at`.
+	pub(crate) fn extra_inherent_providers<Block, Client>(
+		&self,
+		client: &Client,
+		at: Block::Hash,
+	) -> Result<ExtraInherentProviders, String>
+	where
+		Block: BlockT,
+		Client: ProvideRuntimeApi<Block>,
+		Client::Api: RequiredInherentsApi<Block>,
+	{
+		let providers = match &self.inherent_providers {
+			Some(path) => ExtraInherentProviders::load(path, &self.inherent_provider_plugins)?,
+			None => ExtraInherentProviders::default(),
+		};
+		providers.ensure_required(client, at)?;
+		Ok(providers)
+	}
+}
+
+#[async_trait::async_trait]
+impl sp_inherents::InherentDataProvider for ExtraInherentProviders {
+	async fn provide_inherent_data(
+		&self,
+		inherent_data: &mut InherentData,
+	) -> Result<(), sp_inherents::Error> {
+		for (identifier, provider) in self.providers.iter() {
+			let data = provider.provide().map_err(|e| {
+				sp_inherents::Error::Application(
+					format!("inherent `{}`: {e}", display_identifier(identifier)).into(),
+				)
+			})?;
+			inherent_data.put_data(*identifier, &PreEncoded(data))?;
+		}
+		Ok(())
+	}
+
+	async fn try_handle_error(
+		&self,
+		_: &InherentIdentifier,
+		_: &[u8],
+	) -> Option<Result<(), sp_inherents::Error>> {
+		None
+	}
+}
+
+/// Data which is already SCALE encoded.
+struct PreEncoded(Vec<u8>);
+
+impl Encode for PreEncoded {
+	fn size_hint(&self) -> usize {
+		self.0.len()
+	}
+
+	fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+		dest.write(&self.0)
+	}
+}
+
+fn parse_hex(value: &str) -> Result<Vec<u8>, String> {
+	value
+		.parse::<sp_core::Bytes>()
+		.map(|bytes| bytes.0)
+		.map_err(|e| format!("invalid hex value `{value}`: {e}"))
+}
+
+fn hex_field(config: &serde_json::Value, field: &str) -> Result<Vec<u8>, String> {
+	let value = config
+		.get(field)
+		.and_then(|value| value.as_str())
+		.ok_or_else(|| format!("missing `{field}` in the plugin configuration"))?;
+	parse_hex(value)
+}
+
+fn parse_identifier(identifier: &str) -> Result<InherentIdentifier, String> {
+	let bytes = if identifier.starts_with("0x") {
+		parse_hex(identifier)?
+	} else {
+		identifier.as_bytes().to_vec()
+	};
+	bytes
+		.try_into()
+		.map_err(|_| format!("inherent identifier `{identifier}` is not 8 bytes long"))
+}
+
+fn display_identifier(identifier: &InherentIdentifier) -> String {
+	match std::str::from_utf8(identifier) {
+		Ok(identifier) if identifier.chars().all(|c| c.is_ascii_graphic()) => identifier.into(),
+		_ => sp_core::bytes::to_hex(identifier, false),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_inherents::InherentDataProvider;
+
+	#[test]
+	fn can_parse_and_provide_configured_inherents() {
+		let price_file = std::env::temp_dir().join("omni-node-inherent-providers-test-price");
+		std::fs::write(&price_file, "0x2a000000\n").unwrap();
+		let config = format!(
+			r#"{{"inherents": [
+				{{"identifier": "pricefed", "plugin": "file", "config": {{"path": {:?}}}}},
+				{{"identifier": "0x72616e646f6d6e73", "plugin": "static", "config": {{"value": "0x0102"}}}}
+			]}}"#,
+			price_file.display().to_string(),
+		);
+		let providers =
+			ExtraInherentProviders::from_json(&config, &InherentProviderRegistry::default())
+				.unwrap();
+
+		let inherent_data = futures::executor::block_on(providers.create_inherent_data()).unwrap();
+		assert_eq!(inherent_data.get_data::<u32>(b"pricefed").unwrap(), Some(42));
+		assert_eq!(inherent_data.get_data::<[u8; 2]>(b"randomns").unwrap(), Some([1, 2]));
+
+		std::fs::remove_file(price_file).unwrap();
+	}
+
+	#[test]
+	fn rejects_invalid_configurations() {
+		let registry = InherentProviderRegistry::default();
+		let parse = |config: &str| ExtraInherentProviders::from_json(config, &registry).err();
+
+		assert!(parse(r#"{"inherents": [{"identifier": "short", "plugin": "static"}]}"#)
+			.unwrap()
+			.contains("not 8 bytes long"));
+		assert!(parse(r#"{"inherents": [{"identifier": "pricefed", "plugin": "oracle"}]}"#)
+			.unwrap()
+			.contains("unknown inherent provider plugin `oracle`"));
+		assert!(parse(r#"{"inherents": [{"identifier": "pricefed", "plugin": "static"}]}"#)
+			.unwrap()
+			.contains("missing `value`"));
+		assert!(parse(
+			r#"{"inherents": [
+				{"identifier": "pricefed", "plugin": "static", "config": {"value": "0x00"}},
+				{"identifier": "pricefed", "plugin": "static", "config": {"value": "0x01"}}
+			]}"#
+		)
+		.unwrap()
+		.contains("configured twice"));
+	}
+}
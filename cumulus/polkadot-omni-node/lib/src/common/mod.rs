@@ -21,12 +21,16 @@
 pub(crate) mod aura;
 pub mod chain_spec;
 pub mod command;
+pub mod inherents;
 pub mod rpc;
 pub mod runtime;
 pub mod spec;
 pub mod types;
 
-use cumulus_primitives_core::{CollectCollationInfo, GetCoreSelectorApi};
+use cumulus_primitives_core::{
+	CollectCollationInfo, GetCoreSelectorApi, RelayStateKeysApi, RequiredInherentsApi,
+};
+use inherents::InherentProviderRegistry;
 use sc_client_db::DbHash;
 use serde::de::DeserializeOwned;
 use sp_api::{ApiExt, CallApiAt, ConstructRuntimeApi, Metadata};
@@ -37,7 +41,7 @@ use sp_runtime::{
 };
 use sp_session::SessionKeys;
 use sp_transaction_pool::runtime_api::TaggedTransactionQueue;
-use std::{fmt::Debug, path::PathBuf, str::FromStr};
+use std::{fmt::Debug, path::PathBuf, str::FromStr, sync::Arc};
 
 pub trait NodeBlock:
 	BlockT<Extrinsic = OpaqueExtrinsic, Header = Self::BoundedHeader, Hash = DbHash> + DeserializeOwned
@@ -67,6 +71,8 @@ pub trait NodeRuntimeApi<Block: BlockT>:
 	+ TaggedTransactionQueue<Block>
 	+ CollectCollationInfo<Block>
 	+ GetCoreSelectorApi<Block>
+	+ RelayStateKeysApi<Block>
+	+ RequiredInherentsApi<Block>
 	+ Sized
 {
 }
@@ -78,6 +84,8 @@ impl<T, Block: BlockT> NodeRuntimeApi<Block> for T where
 		+ BlockBuilder<Block>
 		+ TaggedTransactionQueue<Block>
 		+ GetCoreSelectorApi<Block>
+		+ RelayStateKeysApi<Block>
+		+ RequiredInherentsApi<Block>
 		+ CollectCollationInfo<Block>
 {
 }
@@ -105,4 +113,11 @@ pub struct NodeExtraArgs {
 
 	/// If set, each `PoV` build by the node will be exported to this folder.
 	pub export_pov: Option<PathBuf>,
+
+	/// If set, the configuration file of the providers of the inherents required by the runtime
+	/// which the node doesn't provide by default.
+	pub inherent_providers: Option<PathBuf>,
+
+	/// The plugins which can be referred to in the `inherent_providers` configuration file.
+	pub inherent_provider_plugins: Arc<InherentProviderRegistry>,
 }
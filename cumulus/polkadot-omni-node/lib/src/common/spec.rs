@@ -24,6 +24,10 @@ use crate::common::{
 	ConstructNodeRuntimeApi, NodeBlock, NodeExtraArgs,
 };
 use cumulus_client_cli::CollatorOptions;
+use cumulus_client_diagnostics::{
+	rpc::{ParachainDiagnostics, ParachainDiagnosticsApiServer},
+	run_inclusion_diagnostics, InclusionDiagnostics,
+};
 use cumulus_client_service::{
 	build_network, build_relay_chain_interface, prepare_node_config, start_relay_chain_tasks,
 	BuildNetworkParams, CollatorSybilResistance, DARecoveryProfile, StartRelayChainTasksParams,
@@ -74,6 +78,7 @@ where
 		announce_block: Arc<dyn Fn(Hash, Option<Vec<u8>>) + Send + Sync>,
 		backend: Arc<ParachainBackend<Block>>,
 		node_extra_args: NodeExtraArgs,
+		inclusion_diagnostics: InclusionDiagnostics<Block::Hash>,
 	) -> Result<(), sc_service::Error>;
 }
 
@@ -253,17 +258,31 @@ pub(crate) trait NodeSpec: BaseNodeSpec {
 					})
 					.await?;
 
+				// Only collators build blocks whose inclusion can be diagnosed.
+				let inclusion_diagnostics =
+					validator.then(|| InclusionDiagnostics::new(Default::default()));
+
 				let rpc_builder = {
 					let client = client.clone();
 					let transaction_pool = transaction_pool.clone();
 					let backend_for_rpc = backend.clone();
+					let inclusion_diagnostics = inclusion_diagnostics.clone();
 
 					Box::new(move |_| {
-						Self::BuildRpcExtensions::build_rpc_extensions(
+						let mut module = Self::BuildRpcExtensions::build_rpc_extensions(
 							client.clone(),
 							backend_for_rpc.clone(),
 							transaction_pool.clone(),
-						)
+						)?;
+						if let Some(inclusion_diagnostics) = &inclusion_diagnostics {
+							module
+								.merge(
+									ParachainDiagnostics::new(inclusion_diagnostics.clone())
+										.into_rpc(),
+								)
+								.map_err(|e| sc_service::Error::Application(e.into()))?;
+						}
+						Ok(module)
 					})
 				};
 
@@ -326,7 +345,17 @@ pub(crate) trait NodeSpec: BaseNodeSpec {
 					sync_service,
 				})?;
 
-				if validator {
+				if let Some(inclusion_diagnostics) = inclusion_diagnostics {
+					task_manager.spawn_handle().spawn(
+						"inclusion-diagnostics",
+						None,
+						run_inclusion_diagnostics::<Self::Block>(
+							para_id,
+							relay_chain_interface.clone(),
+							inclusion_diagnostics.clone(),
+						),
+					);
+
 					Self::StartConsensus::start_consensus(
 						client.clone(),
 						block_import,
@@ -343,6 +372,7 @@ pub(crate) trait NodeSpec: BaseNodeSpec {
 						announce_block,
 						backend.clone(),
 						node_extra_args,
+						inclusion_diagnostics,
 					)?;
 				}
 
@@ -163,6 +163,18 @@ macro_rules! impl_node_runtime_apis {
 				}
 			}
 
+			impl cumulus_primitives_core::RelayStateKeysApi<$block> for $runtime {
+				fn relay_state_keys() -> Vec<Vec<u8>> {
+					unimplemented!()
+				}
+			}
+
+			impl cumulus_primitives_core::RequiredInherentsApi<$block> for $runtime {
+				fn required_inherents() -> Vec<[u8; 8]> {
+					unimplemented!()
+				}
+			}
+
 			#[cfg(feature = "try-runtime")]
 			impl frame_try_runtime::TryRuntime<$block> for $runtime {
 				fn on_runtime_upgrade(
@@ -37,6 +37,7 @@ use cumulus_client_consensus_aura::collators::slot_based::{
 };
 use cumulus_client_consensus_proposer::{Proposer, ProposerInterface};
 use cumulus_client_consensus_relay_chain::Verifier as RelayChainVerifier;
+use cumulus_client_diagnostics::InclusionDiagnostics;
 #[allow(deprecated)]
 use cumulus_client_service::CollatorSybilResistance;
 use cumulus_primitives_core::{relay_chain::ValidationCode, ParaId};
@@ -293,21 +294,26 @@ where
 		relay_chain_interface: Arc<dyn RelayChainInterface>,
 		transaction_pool: Arc<TransactionPoolHandle<Block, ParachainClient<Block, RuntimeApi>>>,
 		keystore: KeystorePtr,
-		_relay_chain_slot_duration: Duration,
+		relay_chain_slot_duration: Duration,
 		para_id: ParaId,
 		collator_key: CollatorPair,
 		_overseer_handle: OverseerHandle,
 		announce_block: Arc<dyn Fn(Hash, Option<Vec<u8>>) + Send + Sync>,
 		backend: Arc<ParachainBackend<Block>>,
-		_node_extra_args: NodeExtraArgs,
+		node_extra_args: NodeExtraArgs,
+		inclusion_diagnostics: InclusionDiagnostics<Block::Hash>,
 	) -> Result<(), Error> {
-		let proposer_factory = sc_basic_authorship::ProposerFactory::with_proof_recording(
+		let extra_inherents =
+			node_extra_args.extra_inherent_providers(&*client, client.chain_info().best_hash)?;
+
+		let mut proposer_factory = sc_basic_authorship::ProposerFactory::with_proof_recording(
 			task_manager.spawn_handle(),
 			client.clone(),
 			transaction_pool,
 			prometheus_registry,
 			telemetry.clone(),
 		);
+		proposer_factory.enable_proof_growth_reservation();
 
 		let proposer = Proposer::new(proposer_factory);
 		let collator_service = CollatorService::new(
@@ -315,11 +321,15 @@ where
 			Arc::new(task_manager.spawn_handle()),
 			announce_block,
 			client.clone(),
-		);
+		)
+		.with_inclusion_diagnostics(inclusion_diagnostics);
 
 		let client_for_aura = client.clone();
 		let params = SlotBasedParams {
-			create_inherent_data_providers: move |_, ()| async move { Ok(()) },
+			create_inherent_data_providers: move |_, ()| {
+				let extra_inherents = extra_inherents.clone();
+				async move { Ok(extra_inherents) }
+			},
 			block_import,
 			para_client: client.clone(),
 			para_backend: backend.clone(),
@@ -335,6 +345,9 @@ where
 			authoring_duration: Duration::from_millis(2000),
 			reinitialize: false,
 			slot_drift: Duration::from_secs(1),
+			core_selection_policy: Arc::new(slot_based::RuntimeCoreSelection),
+			relay_chain_slot_duration,
+			prometheus_registry: prometheus_registry.cloned(),
 		};
 
 		// We have a separate function only to be able to use `docify::export` on this piece of
@@ -396,26 +409,35 @@ where
 		announce_block: Arc<dyn Fn(Hash, Option<Vec<u8>>) + Send + Sync>,
 		backend: Arc<ParachainBackend<Block>>,
 		node_extra_args: NodeExtraArgs,
+		inclusion_diagnostics: InclusionDiagnostics<Block::Hash>,
 	) -> Result<(), Error> {
-		let proposer_factory = sc_basic_authorship::ProposerFactory::with_proof_recording(
+		let extra_inherents =
+			node_extra_args.extra_inherent_providers(&*client, client.chain_info().best_hash)?;
+
+		let mut proposer_factory = sc_basic_authorship::ProposerFactory::with_proof_recording(
 			task_manager.spawn_handle(),
 			client.clone(),
 			transaction_pool,
 			prometheus_registry,
 			telemetry.clone(),
 		);
+		proposer_factory.enable_proof_growth_reservation();
 
 		let collator_service = CollatorService::new(
 			client.clone(),
 			Arc::new(task_manager.spawn_handle()),
 			announce_block,
 			client.clone(),
-		);
+		)
+		.with_inclusion_diagnostics(inclusion_diagnostics);
 
 		let params = aura::ParamsWithExport {
 			export_pov: node_extra_args.export_pov,
 			params: AuraParams {
-				create_inherent_data_providers: move |_, ()| async move { Ok(()) },
+				create_inherent_data_providers: move |_, ()| {
+					let extra_inherents = extra_inherents.clone();
+					async move { Ok(extra_inherents) }
+				},
 				block_import,
 				para_client: client.clone(),
 				para_backend: backend,
@@ -18,6 +18,7 @@ use crate::common::{
 	rpc::BuildRpcExtensions as BuildRpcExtensionsT,
 	spec::{BaseNodeSpec, BuildImportQueue, NodeSpec as NodeSpecT},
 	types::{Hash, ParachainBlockImport, ParachainClient},
+	NodeExtraArgs,
 };
 use codec::Encode;
 use cumulus_client_parachain_inherent::{MockValidationDataInherentDataProvider, MockXcmConfig};
@@ -66,6 +67,7 @@ impl<NodeSpec: NodeSpecT> ManualSealNode<NodeSpec> {
 		mut config: Configuration,
 		para_id: ParaId,
 		block_time: u64,
+		node_extra_args: NodeExtraArgs,
 	) -> sc_service::error::Result<TaskManager>
 	where
 		Net: NetworkBackend<NodeSpec::Block, Hash>,
@@ -133,6 +135,8 @@ impl<NodeSpec: NodeSpecT> ManualSealNode<NodeSpec> {
 				}
 			});
 
+		let extra_inherents =
+			node_extra_args.extra_inherent_providers(&*client, client.chain_info().best_hash)?;
 		let client_for_cidp = client.clone();
 		let params = sc_consensus_manual_seal::ManualSealParams {
 			block_import: client.clone(),
@@ -150,6 +154,7 @@ impl<NodeSpec: NodeSpecT> ManualSealNode<NodeSpec> {
 				let current_para_block_head =
 					Some(polkadot_primitives::HeadData(current_para_head.encode()));
 				let client_for_xcm = client_for_cidp.clone();
+				let extra_inherents = extra_inherents.clone();
 				async move {
 					use sp_runtime::traits::UniqueSaturatedInto;
 
@@ -176,6 +181,7 @@ impl<NodeSpec: NodeSpecT> ManualSealNode<NodeSpec> {
 						// real timestamps would cause aura <> timestamp checking to fail.
 						sp_timestamp::InherentDataProvider::new(sp_timestamp::Timestamp::new(0)),
 						mocked_parachain,
+						extra_inherents,
 					))
 				}
 			},
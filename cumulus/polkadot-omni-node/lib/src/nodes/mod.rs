@@ -17,7 +17,10 @@
 pub mod aura;
 mod manual_seal;
 
-use crate::common::spec::{DynNodeSpec, NodeSpec as NodeSpecT};
+use crate::common::{
+	spec::{DynNodeSpec, NodeSpec as NodeSpecT},
+	NodeExtraArgs,
+};
 use cumulus_primitives_core::ParaId;
 use manual_seal::ManualSealNode;
 use sc_service::{Configuration, TaskManager};
@@ -32,6 +35,7 @@ pub trait DynNodeSpecExt: DynNodeSpec {
 		config: Configuration,
 		para_id: ParaId,
 		block_time: u64,
+		node_extra_args: NodeExtraArgs,
 	) -> sc_service::error::Result<TaskManager>;
 }
 
@@ -45,13 +49,24 @@ where
 		config: Configuration,
 		para_id: ParaId,
 		block_time: u64,
+		node_extra_args: NodeExtraArgs,
 	) -> sc_service::error::Result<TaskManager> {
 		let node = ManualSealNode::<T>::new();
 		match config.network.network_backend {
-			sc_network::config::NetworkBackendType::Libp2p =>
-				node.start_node::<sc_network::NetworkWorker<_, _>>(config, para_id, block_time),
-			sc_network::config::NetworkBackendType::Litep2p =>
-				node.start_node::<sc_network::Litep2pNetworkBackend>(config, para_id, block_time),
+			sc_network::config::NetworkBackendType::Libp2p => node
+				.start_node::<sc_network::NetworkWorker<_, _>>(
+					config,
+					para_id,
+					block_time,
+					node_extra_args,
+				),
+			sc_network::config::NetworkBackendType::Litep2p => node
+				.start_node::<sc_network::Litep2pNetworkBackend>(
+				config,
+				para_id,
+				block_time,
+				node_extra_args,
+			),
 		}
 	}
 }
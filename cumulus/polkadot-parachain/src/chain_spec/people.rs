@@ -209,11 +209,9 @@ pub mod rococo {
 /// Sub-module for Westend setup.
 pub mod westend {
 	use super::{ParaId, PeopleBalance};
-	use crate::chain_spec::SAFE_XCM_VERSION;
-	use parachains_common::{AccountId, AuraId};
+	use parachains_common::genesis_config_helpers::GenesisConfigBuilder;
 	use polkadot_omni_node_lib::chain_spec::{Extensions, GenericChainSpec};
 	use sc_chain_spec::ChainType;
-	use sp_keyring::Sr25519Keyring;
 
 	pub(crate) const PEOPLE_WESTEND: &str = "people-westend";
 	pub(crate) const PEOPLE_WESTEND_LOCAL: &str = "people-westend-local";
@@ -239,60 +237,15 @@ pub mod westend {
 		.with_name(chain_name)
 		.with_id(super::ensure_id(id).expect("invalid id"))
 		.with_chain_type(ChainType::Local)
-		.with_genesis_config_patch(genesis(
-			// initial collators.
-			vec![
-				(Sr25519Keyring::Alice.to_account_id(), Sr25519Keyring::Alice.public().into()),
-				(Sr25519Keyring::Bob.to_account_id(), Sr25519Keyring::Bob.public().into()),
-			],
-			Sr25519Keyring::well_known().map(|k| k.to_account_id()).collect(),
-			para_id,
-		))
+		.with_genesis_config_patch(
+			GenesisConfigBuilder::local_testnet(para_id, PEOPLE_WESTEND_ED * 524_288)
+				.with_candidacy_bond(PEOPLE_WESTEND_ED * 16)
+				.with_sudo(None)
+				.build(),
+		)
 		.with_properties(properties)
 		.build()
 	}
-
-	fn genesis(
-		invulnerables: Vec<(AccountId, AuraId)>,
-		endowed_accounts: Vec<AccountId>,
-		id: ParaId,
-	) -> serde_json::Value {
-		serde_json::json!({
-			"balances": {
-				"balances": endowed_accounts
-					.iter()
-					.cloned()
-					.map(|k| (k, PEOPLE_WESTEND_ED * 524_288))
-					.collect::<Vec<_>>(),
-			},
-			"parachainInfo": {
-				"parachainId": id,
-			},
-			"collatorSelection": {
-				"invulnerables": invulnerables
-					.iter()
-					.cloned()
-					.map(|(acc, _)| acc)
-					.collect::<Vec<_>>(),
-				"candidacyBond": PEOPLE_WESTEND_ED * 16,
-			},
-			"session": {
-				"keys": invulnerables
-					.into_iter()
-					.map(|(acc, aura)| {
-						(
-							acc.clone(),                                  // account id
-							acc,                                          // validator id
-							people_westend_runtime::SessionKeys { aura }, // session keys
-						)
-					})
-					.collect::<Vec<_>>(),
-			},
-			"polkadotXcm": {
-				"safeXcmVersion": Some(SAFE_XCM_VERSION),
-			}
-		})
-	}
 }
 
 pub mod kusama {
@@ -402,4 +402,31 @@ sp_api::decl_runtime_apis! {
 		/// Retrieve core selector and claim queue offset for the next block.
 		fn core_selector() -> (CoreSelector, ClaimQueueOffset);
 	}
+
+	/// Runtime api to query the storage weight reclaimed in the current block.
+	pub trait StorageWeightReclaimApi {
+		/// Returns the proof size in bytes reclaimed by the `StorageWeightReclaim` transaction
+		/// extension in the current block.
+		///
+		/// Returns `None` if the extension did not run in the current block.
+		fn reclaimed_proof_size() -> Option<u64>;
+	}
+
+	/// Runtime api to collect the relay chain storage keys the runtime wants to read.
+	///
+	/// The collator includes proofs for the returned keys in the relay chain state proof of the
+	/// next block, in addition to the keys that are always required.
+	pub trait RelayStateKeysApi {
+		/// Returns the relay chain storage keys to include in the relay chain state proof.
+		fn relay_state_keys() -> Vec<Vec<u8>>;
+	}
+
+	/// Runtime api to declare the inherents the runtime requires besides the standard ones.
+	///
+	/// Nodes which do not know how to provide these inherents themselves use it to make sure they
+	/// have been configured with a provider for each of them.
+	pub trait RequiredInherentsApi {
+		/// Returns the identifiers of the additional inherents required by the runtime.
+		fn required_inherents() -> Vec<[u8; 8]>;
+	}
 }
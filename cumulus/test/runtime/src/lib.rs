@@ -285,6 +285,9 @@ impl pallet_sudo::Config for Runtime {
 	type RuntimeCall = RuntimeCall;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
+	type SensitiveCalls = frame_support::traits::Nothing;
+	type NestedCalls = ();
+	type MaxLogLength = ConstU32<0>;
 }
 
 impl pallet_glutton::Config for Runtime {
@@ -380,7 +383,7 @@ pub type TxExtension = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
-	cumulus_primitives_storage_weight_reclaim::StorageWeightReclaim<Runtime>,
+	cumulus_pallet_parachain_system::StorageWeightReclaim<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -537,6 +540,18 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl cumulus_primitives_core::StorageWeightReclaimApi<Block> for Runtime {
+		fn reclaimed_proof_size() -> Option<u64> {
+			ParachainSystem::reclaimed_proof_size()
+		}
+	}
+
+	impl cumulus_primitives_core::RelayStateKeysApi<Block> for Runtime {
+		fn relay_state_keys() -> Vec<Vec<u8>> {
+			<ParachainSystem as cumulus_pallet_parachain_system::RelayStateKeys>::relay_state_keys()
+		}
+	}
+
 	impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
 		fn build_state(config: Vec<u8>) -> sp_genesis_builder::Result {
 			build_state::<RuntimeGenesisConfig>(config)
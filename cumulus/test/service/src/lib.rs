@@ -497,6 +497,9 @@ where
 					authoring_duration: Duration::from_millis(2000),
 					reinitialize: false,
 					slot_drift: Duration::from_secs(1),
+					core_selection_policy: Arc::new(slot_based::RuntimeCoreSelection),
+					relay_chain_slot_duration,
+					prometheus_registry: prometheus_registry.clone(),
 				};
 
 				let (collation_future, block_builder_future) =
@@ -978,7 +981,7 @@ pub fn construct_extrinsic(
 		frame_system::CheckNonce::<runtime::Runtime>::from(nonce),
 		frame_system::CheckWeight::<runtime::Runtime>::new(),
 		pallet_transaction_payment::ChargeTransactionPayment::<runtime::Runtime>::from(tip),
-		cumulus_primitives_storage_weight_reclaim::StorageWeightReclaim::<runtime::Runtime>::new(),
+		cumulus_pallet_parachain_system::StorageWeightReclaim::<runtime::Runtime>::new(),
 	)
 		.into();
 	let raw_payload = runtime::SignedPayload::from_raw(
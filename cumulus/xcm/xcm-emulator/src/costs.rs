@@ -0,0 +1,197 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Helpers to capture and assert the exact costs of emulated XCM programs.
+//!
+//! A [`CostsTracker`] records the balances of a set of accounts on a single chain. Once the
+//! XCM program has been executed, [`CostsTracker::costs`] collects the weight used and the fees
+//! paid (as reported by the events deposited on the chain), together with the balance changes of
+//! the tracked accounts. The collected [`ChainCosts`] of all the involved chains can be gathered
+//! in a [`CostsSnapshot`] and compared against a previously recorded snapshot, so that any change
+//! in fees or weights is detected by the test.
+
+use crate::{AccountIdOf, Assets, Balance, Chain, Weight};
+use std::{collections::BTreeMap, fmt, marker::PhantomData};
+
+/// Costs of an XCM program observed on a single chain.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ChainCosts {
+	/// Weight used by XCM programs executed locally via `pallet-xcm`.
+	pub execution_weight: Weight,
+	/// Weight used by the message queue to process inbound messages.
+	pub processing_weight: Weight,
+	/// Delivery fees paid for the messages sent from the chain.
+	pub delivery_fees: Vec<Assets>,
+	/// Free balance change of each tracked account, keyed by the account label.
+	pub balance_deltas: BTreeMap<String, i128>,
+}
+
+impl fmt::Display for ChainCosts {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(
+			f,
+			"execution_weight: ({}, {})",
+			self.execution_weight.ref_time(),
+			self.execution_weight.proof_size()
+		)?;
+		writeln!(
+			f,
+			"processing_weight: ({}, {})",
+			self.processing_weight.ref_time(),
+			self.processing_weight.proof_size()
+		)?;
+		for fees in &self.delivery_fees {
+			writeln!(f, "delivery_fees: {fees:?}")?;
+		}
+		for (label, delta) in &self.balance_deltas {
+			writeln!(f, "balance {label}: {delta:+}")?;
+		}
+		Ok(())
+	}
+}
+
+/// Tracks the costs of an XCM program on the chain `C`.
+///
+/// The tracker must be created before the XCM program is executed. Neither [`Self::track`] nor
+/// [`Self::costs`] may be called from within `execute_with`, as they access the chain externalities
+/// on their own.
+pub struct CostsTracker<C: Chain> {
+	accounts: Vec<(String, AccountIdOf<C::Runtime>, Balance)>,
+	_marker: PhantomData<C>,
+}
+
+impl<C: Chain> Default for CostsTracker<C> {
+	fn default() -> Self {
+		Self { accounts: Vec::new(), _marker: PhantomData }
+	}
+}
+
+impl<C> CostsTracker<C>
+where
+	C: Chain,
+	C::Runtime: pallet_xcm::Config + pallet_message_queue::Config,
+	C::RuntimeEvent: Clone
+		+ TryInto<pallet_xcm::Event<C::Runtime>>
+		+ TryInto<pallet_message_queue::Event<C::Runtime>>,
+{
+	/// Creates a new tracker, not tracking any account yet.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Starts tracking the balance of `account`, reported under the given `label`.
+	pub fn track(mut self, label: impl Into<String>, account: AccountIdOf<C::Runtime>) -> Self {
+		let balance = C::account_data_of(account.clone()).free;
+		self.accounts.push((label.into(), account, balance));
+		self
+	}
+
+	/// Collects the costs observed on the chain since the tracker was created.
+	///
+	/// Weights and fees are collected from the events deposited in the current block of the chain.
+	pub fn costs(&self) -> ChainCosts {
+		let mut costs = ChainCosts::default();
+
+		for event in C::ext_wrapper(C::events) {
+			match TryInto::<pallet_xcm::Event<C::Runtime>>::try_into(event.clone()) {
+				Ok(pallet_xcm::Event::<C::Runtime>::Attempted { outcome }) =>
+					costs.execution_weight.saturating_accrue(outcome.weight_used()),
+				Ok(pallet_xcm::Event::<C::Runtime>::FeesPaid { fees, .. }) =>
+					costs.delivery_fees.push(fees),
+				_ => {},
+			}
+			if let Ok(pallet_message_queue::Event::<C::Runtime>::Processed {
+				weight_used, ..
+			}) = TryInto::<pallet_message_queue::Event<C::Runtime>>::try_into(event)
+			{
+				costs.processing_weight.saturating_accrue(weight_used);
+			}
+		}
+
+		for (label, account, initial) in &self.accounts {
+			let current = C::account_data_of(account.clone()).free;
+			let delta = if current >= *initial {
+				i128::try_from(current - initial).unwrap_or(i128::MAX)
+			} else {
+				i128::try_from(initial - current).map(|delta| -delta).unwrap_or(i128::MIN)
+			};
+			costs.balance_deltas.insert(label.clone(), delta);
+		}
+
+		costs
+	}
+}
+
+/// Costs of an XCM program observed on all the involved chains.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CostsSnapshot {
+	/// Costs observed on each chain, keyed by the chain name.
+	pub chains: BTreeMap<String, ChainCosts>,
+}
+
+impl CostsSnapshot {
+	/// Creates an empty snapshot.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds the costs observed on the chain `C`.
+	pub fn with<C: Chain>(mut self, costs: ChainCosts) -> Self {
+		self.chains.insert(chain_name::<C>(), costs);
+		self
+	}
+
+	/// Asserts that the snapshot matches the `expected` one, as rendered by its `Display`
+	/// implementation.
+	///
+	/// Leading and trailing whitespace of every line is ignored, so that the expected snapshot can
+	/// be conveniently indented in the test code.
+	pub fn assert_matches(&self, expected: &str) {
+		let normalize = |snapshot: &str| {
+			snapshot
+				.lines()
+				.map(str::trim)
+				.filter(|line| !line.is_empty())
+				.collect::<Vec<_>>()
+				.join("\n")
+		};
+		let actual = self.to_string();
+		if normalize(&actual) != normalize(expected) {
+			panic!(
+				"XCM costs do not match the expected snapshot.\n\nExpected:\n{}\n\nActual:\n{}",
+				expected.trim(),
+				actual.trim()
+			);
+		}
+	}
+}
+
+impl fmt::Display for CostsSnapshot {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for (chain, costs) in &self.chains {
+			writeln!(f, "[{chain}]")?;
+			write!(f, "{costs}")?;
+		}
+		Ok(())
+	}
+}
+
+/// Returns the chain name without the generic network parameter and module path.
+fn chain_name<C>() -> String {
+	let name = std::any::type_name::<C>();
+	let name = name.split('<').next().unwrap_or(name);
+	name.rsplit("::").next().unwrap_or(name).to_string()
+}
@@ -16,6 +16,8 @@
 
 extern crate alloc;
 
+pub mod costs;
+
 pub use array_bytes;
 pub use codec::{Decode, Encode, EncodeLike, MaxEncodedLen};
 pub use log;
@@ -82,6 +84,8 @@ pub use xcm::latest::prelude::{
 };
 pub use xcm_executor::traits::ConvertLocation;
 
+pub use costs::{ChainCosts, CostsSnapshot, CostsTracker};
+
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
 thread_local! {
@@ -93,6 +93,9 @@
 //!     `OverseerHandle` type import if it’s not used elsewhere).
 //!     - Rename `AuraParams` to `SlotBasedParams`, remove the `overseer_handle` field and add a
 //!     `slot_drift` field with a   value of `Duration::from_secs(1)`.
+//!     - Add a `core_selection_policy` field with a value of
+//!     `Arc::new(slot_based::RuntimeCoreSelection)`, a `relay_chain_slot_duration` field and a
+//!     `prometheus_registry` field to enable the per-core collator metrics.
 //!     - Replace the single future returned by `aura::run` with the two futures returned by it and
 //!     spawn them as separate tasks:
 #![doc = docify::embed!("../../cumulus/polkadot-omni-node/lib/src/nodes/aura.rs", launch_slot_based_collator)]
@@ -73,8 +73,10 @@ use polkadot_primitives::{
 mod error;
 mod futures_undead;
 mod metrics;
+mod served_data;
 mod task;
 pub use metrics::Metrics;
+use served_data::ServedData;
 
 #[cfg(test)]
 mod tests;
@@ -93,6 +95,10 @@ pub(crate) const CONSERVATIVE_FETCH_CHUNKS_THRESHOLD: usize = 1 * 1024 * 1024;
 /// PoV size limit in bytes for which prefer fetching from backers. (Kusama and all testnets)
 pub const FETCH_CHUNKS_THRESHOLD: usize = 4 * 1024 * 1024;
 
+/// The default storage budget, in bytes, for recovered available data served to peers by nodes
+/// which don't run an availability store, such as collators.
+pub const DEFAULT_SERVED_DATA_BUDGET: usize = 64 * 1024 * 1024;
+
 #[derive(Clone, PartialEq)]
 /// The strategy we use to recover the PoV.
 pub enum RecoveryStrategyKind {
@@ -130,6 +136,9 @@ pub struct AvailabilityRecoverySubsystem {
 	bypass_availability_store: bool,
 	/// Receiver for available data requests.
 	req_receiver: IncomingRequestReceiver<request_v1::AvailableDataFetchingRequest>,
+	/// Storage budget, in bytes, for recovered data served to peers when the availability store
+	/// is bypassed.
+	served_data_budget: usize,
 	/// Metrics for this subsystem.
 	metrics: Metrics,
 	/// The type of check to perform after available data was recovered.
@@ -297,6 +306,9 @@ struct State {
 	/// An LRU cache of recently recovered data.
 	availability_lru: LruMap<CandidateHash, CachedRecovery>,
 
+	/// Recovered data served to peers if the availability store is bypassed.
+	served_data: ServedData,
+
 	/// Cached runtime info.
 	runtime_info: RuntimeInfo,
 }
@@ -307,6 +319,7 @@ impl Default for State {
 			ongoing_recoveries: FuturesUnordered::new(),
 			live_block: (0, Hash::default()),
 			availability_lru: LruMap::new(ByLength::new(LRU_SIZE)),
+			served_data: ServedData::new(0),
 			runtime_info: RuntimeInfo::new(None),
 		}
 	}
@@ -607,6 +620,7 @@ impl AvailabilityRecoverySubsystem {
 			bypass_availability_store: true,
 			post_recovery_check: PostRecoveryCheck::PovHash,
 			req_receiver,
+			served_data_budget: DEFAULT_SERVED_DATA_BUDGET,
 			metrics,
 			req_v1_protocol_name: req_protocol_names
 				.get_name(request_v1::ChunkFetchingRequest::PROTOCOL),
@@ -637,6 +651,7 @@ impl AvailabilityRecoverySubsystem {
 			bypass_availability_store: false,
 			post_recovery_check: PostRecoveryCheck::Reencode,
 			req_receiver,
+			served_data_budget: 0,
 			metrics,
 			req_v1_protocol_name: req_protocol_names
 				.get_name(request_v1::ChunkFetchingRequest::PROTOCOL),
@@ -645,6 +660,15 @@ impl AvailabilityRecoverySubsystem {
 		}
 	}
 
+	/// Set the storage budget, in bytes, for recovered data served to peers.
+	///
+	/// Only used by nodes which bypass the availability store, such as collators, which otherwise
+	/// can't answer full data requests. A budget of zero disables serving.
+	pub fn with_served_data_budget(mut self, served_data_budget: usize) -> Self {
+		self.served_data_budget = served_data_budget;
+		self
+	}
+
 	/// Customise the recovery strategy kind
 	/// Currently only useful for tests.
 	#[cfg(any(test, feature = "subsystem-benchmarks"))]
@@ -659,6 +683,7 @@ impl AvailabilityRecoverySubsystem {
 			bypass_availability_store: false,
 			post_recovery_check: PostRecoveryCheck::Reencode,
 			req_receiver,
+			served_data_budget: 0,
 			metrics,
 			req_v1_protocol_name: req_protocol_names
 				.get_name(request_v1::ChunkFetchingRequest::PROTOCOL),
@@ -669,9 +694,9 @@ impl AvailabilityRecoverySubsystem {
 
 	/// Starts the inner subsystem loop.
 	pub async fn run<Context>(self, mut ctx: Context) -> std::result::Result<(), FatalError> {
-		let mut state = State::default();
 		let Self {
 			mut req_receiver,
+			served_data_budget,
 			metrics,
 			recovery_strategy_kind,
 			bypass_availability_store,
@@ -679,6 +704,14 @@ impl AvailabilityRecoverySubsystem {
 			req_v1_protocol_name,
 			req_v2_protocol_name,
 		} = self;
+		let mut state = State {
+			served_data: ServedData::new(if bypass_availability_store {
+				served_data_budget
+			} else {
+				0
+			}),
+			..Default::default()
+		};
 
 		let (erasure_task_tx, erasure_task_rx) = futures::channel::mpsc::channel(16);
 		let mut erasure_task_rx = erasure_task_rx.fuse();
@@ -777,9 +810,11 @@ impl AvailabilityRecoverySubsystem {
 							if bypass_availability_store {
 								gum::debug!(
 									target: LOG_TARGET,
-									"Skipping request to availability-store.",
+									"Skipping request to availability-store, serving recovered data.",
 								);
-								let _ = req.send_response(None.into());
+								let data = state.served_data.get(&req.payload.candidate_hash);
+								metrics.on_served_data_request(data.is_some());
+								let _ = req.send_response(data.into());
 								Ok(())
 							} else {
 								match query_full_data(&mut ctx, req.payload.candidate_hash).await {
@@ -800,8 +835,13 @@ impl AvailabilityRecoverySubsystem {
 				output = state.ongoing_recoveries.select_next_some() => {
 					let mut res = Ok(());
 					if let Some((candidate_hash, result)) = output {
-						if let Err(ref e) = result {
-							res = Err(Error::Recovery(e.clone()));
+						match result {
+							Ok(ref data) if bypass_availability_store => {
+								state.served_data.insert(candidate_hash, data.clone());
+								metrics.note_served_data_bytes(state.served_data.used_bytes());
+							},
+							Ok(_) => {},
+							Err(ref e) => res = Err(Error::Recovery(e.clone())),
 						}
 
 						if let Ok(recovery) = CachedRecovery::try_from(result) {
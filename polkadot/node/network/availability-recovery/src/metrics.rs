@@ -18,8 +18,8 @@ use polkadot_node_subsystem::prometheus::HistogramVec;
 use polkadot_node_subsystem_util::metrics::{
 	self,
 	prometheus::{
-		self, prometheus::HistogramTimer, Counter, CounterVec, Histogram, Opts, PrometheusError,
-		Registry, U64,
+		self, prometheus::HistogramTimer, Counter, CounterVec, Gauge, Histogram, Opts,
+		PrometheusError, Registry, U64,
 	},
 };
 
@@ -108,6 +108,15 @@ struct MetricsInner {
 	/// Note: Those are only recoveries which could not get served locally already - so in other
 	/// words: Only real recoveries.
 	full_recoveries_started: Counter<U64>,
+
+	/// Number of full data requests answered from recovered data when bypassing the availability
+	/// store.
+	///
+	/// Split by result (`served` or `not_found`).
+	served_data_requests: CounterVec<U64>,
+
+	/// Total encoded size of the recovered data kept to be served to peers.
+	served_data_bytes: Gauge<U64>,
 }
 
 impl Metrics {
@@ -294,6 +303,21 @@ impl Metrics {
 			metrics.full_recoveries_started.inc()
 		}
 	}
+
+	/// A full data request was answered from the recovered data.
+	pub fn on_served_data_request(&self, found: bool) {
+		if let Some(metrics) = &self.0 {
+			let result = if found { "served" } else { "not_found" };
+			metrics.served_data_requests.with_label_values(&[result]).inc()
+		}
+	}
+
+	/// Set the total size of the recovered data kept to be served to peers.
+	pub fn note_served_data_bytes(&self, bytes: usize) {
+		if let Some(metrics) = &self.0 {
+			metrics.served_data_bytes.set(bytes as u64)
+		}
+	}
 }
 
 impl metrics::Metrics for Metrics {
@@ -403,6 +427,23 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			served_data_requests: prometheus::register(
+				CounterVec::new(
+					Opts::new(
+						"polkadot_parachain_availability_recovery_served_data_requests",
+						"Total number of full data requests answered from recovered data.",
+					),
+					&["result"],
+				)?,
+				registry,
+			)?,
+			served_data_bytes: prometheus::register(
+				Gauge::new(
+					"polkadot_parachain_availability_recovery_served_data_bytes",
+					"Total size of the recovered data kept to be served to peers.",
+				)?,
+				registry,
+			)?,
 		};
 		Ok(Metrics(Some(metrics)))
 	}
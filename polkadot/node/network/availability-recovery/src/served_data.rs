@@ -0,0 +1,166 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage-budgeted set of recovered available data, used to answer full data requests on nodes
+//! which don't run an availability store.
+
+use codec::Encode;
+use schnellru::{Limiter, LruMap};
+
+use polkadot_node_primitives::AvailableData;
+use polkadot_primitives::CandidateHash;
+
+/// Limits the stored data by the total encoded size of the entries.
+struct ByEncodedSize {
+	max_bytes: usize,
+	used_bytes: usize,
+}
+
+impl Limiter<CandidateHash, AvailableData> for ByEncodedSize {
+	type KeyToInsert<'a> = CandidateHash;
+	type LinkType = u32;
+
+	fn is_over_the_limit(&self, _length: usize) -> bool {
+		self.used_bytes > self.max_bytes
+	}
+
+	fn on_insert(
+		&mut self,
+		_length: usize,
+		key: CandidateHash,
+		value: AvailableData,
+	) -> Option<(CandidateHash, AvailableData)> {
+		let size = value.encoded_size();
+		if size > self.max_bytes {
+			return None
+		}
+
+		self.used_bytes = self.used_bytes.saturating_add(size);
+		Some((key, value))
+	}
+
+	fn on_replace(
+		&mut self,
+		_length: usize,
+		_old_key: &mut CandidateHash,
+		_new_key: CandidateHash,
+		old_value: &mut AvailableData,
+		new_value: &mut AvailableData,
+	) -> bool {
+		let new_size = new_value.encoded_size();
+		if new_size > self.max_bytes {
+			return false
+		}
+
+		self.used_bytes = self.used_bytes.saturating_sub(old_value.encoded_size()) + new_size;
+		true
+	}
+
+	fn on_removed(&mut self, _key: &mut CandidateHash, value: &mut AvailableData) {
+		self.used_bytes = self.used_bytes.saturating_sub(value.encoded_size());
+	}
+
+	fn on_cleared(&mut self) {
+		self.used_bytes = 0;
+	}
+
+	fn on_grow(&mut self, _new_memory_usage: usize) -> bool {
+		true
+	}
+}
+
+/// Recently recovered available data kept around to be served to peers.
+///
+/// Once the storage budget is exceeded the least recently used entries are evicted first. A
+/// budget of zero disables serving altogether.
+pub struct ServedData {
+	entries: LruMap<CandidateHash, AvailableData, ByEncodedSize>,
+}
+
+impl ServedData {
+	/// Create a new instance bounded by `max_bytes` of encoded available data.
+	pub fn new(max_bytes: usize) -> Self {
+		Self { entries: LruMap::new(ByEncodedSize { max_bytes, used_bytes: 0 }) }
+	}
+
+	/// Store the available data of a candidate, evicting older entries if necessary.
+	///
+	/// Data larger than the whole budget is not stored.
+	pub fn insert(&mut self, candidate_hash: CandidateHash, data: AvailableData) {
+		self.entries.insert(candidate_hash, data);
+	}
+
+	/// Get the available data of a candidate if it is still stored.
+	pub fn get(&mut self, candidate_hash: &CandidateHash) -> Option<AvailableData> {
+		self.entries.get(candidate_hash).cloned()
+	}
+
+	/// The total encoded size of all stored entries.
+	pub fn used_bytes(&self) -> usize {
+		self.entries.limiter().used_bytes
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use polkadot_node_primitives::{BlockData, PoV};
+	use polkadot_primitives::{Hash, HeadData, PersistedValidationData};
+
+	fn available_data(pov_size: usize) -> AvailableData {
+		AvailableData {
+			pov: std::sync::Arc::new(PoV { block_data: BlockData(vec![0; pov_size]) }),
+			validation_data: PersistedValidationData {
+				parent_head: HeadData(vec![]),
+				relay_parent_number: 0,
+				relay_parent_storage_root: Hash::zero(),
+				max_pov_size: 1024,
+			},
+		}
+	}
+
+	#[test]
+	fn evicts_oldest_entries_when_over_budget() {
+		let entry_size = available_data(100).encoded_size();
+		let mut served = ServedData::new(entry_size * 2);
+
+		let (a, b, c) = (
+			CandidateHash(Hash::repeat_byte(1)),
+			CandidateHash(Hash::repeat_byte(2)),
+			CandidateHash(Hash::repeat_byte(3)),
+		);
+		served.insert(a, available_data(100));
+		served.insert(b, available_data(100));
+		assert_eq!(served.used_bytes(), entry_size * 2);
+
+		served.insert(c, available_data(100));
+		assert_eq!(served.used_bytes(), entry_size * 2);
+		assert!(served.get(&a).is_none());
+		assert!(served.get(&b).is_some());
+		assert!(served.get(&c).is_some());
+	}
+
+	#[test]
+	fn does_not_store_data_larger_than_budget() {
+		let mut served = ServedData::new(available_data(100).encoded_size());
+		served.insert(CandidateHash(Hash::repeat_byte(1)), available_data(1000));
+		assert_eq!(served.used_bytes(), 0);
+
+		let mut disabled = ServedData::new(0);
+		disabled.insert(CandidateHash(Hash::repeat_byte(1)), available_data(0));
+		assert_eq!(disabled.used_bytes(), 0);
+	}
+}
@@ -24,7 +24,7 @@ use futures::{executor, future};
 use futures_timer::Delay;
 use rstest::rstest;
 
-use codec::Encode;
+use codec::{Decode, Encode};
 use polkadot_node_network_protocol::request_response::{
 	self as req_res,
 	v1::{AvailableDataFetchingRequest, ChunkResponse},
@@ -1674,6 +1674,113 @@ fn fast_path_backing_group_recovers_if_pov_small(
 	});
 }
 
+#[test]
+fn collator_serves_recovered_data() {
+	let mut test_state = TestState::default();
+	let req_protocol_names = ReqProtocolNames::new(&GENESIS_HASH, None);
+	let (req_receiver, req_cfg) = IncomingRequest::get_config_receiver::<
+		Block,
+		sc_network::NetworkWorker<Block, Hash>,
+	>(&req_protocol_names);
+	let inbound_queue = req_cfg.inbound_queue.expect("Inbound queue is set");
+	// Don't close the sending end of the request protocol. Otherwise, the subsystem will terminate.
+	std::mem::forget(inbound_queue.clone());
+
+	test_state
+		.candidate
+		.descriptor
+		.set_pov_hash(test_state.available_data.pov.hash());
+	let subsystem = AvailabilityRecoverySubsystem::for_collator(
+		None,
+		req_receiver,
+		&req_protocol_names,
+		Metrics::new_dummy(),
+	);
+
+	test_harness(subsystem, |mut virtual_overseer| async move {
+		let candidate_hash = test_state.candidate.hash();
+		let request_data = |candidate_hash| {
+			let inbound_queue = inbound_queue.clone();
+			async move {
+				let (pending_response, rx) = oneshot::channel();
+				inbound_queue
+					.send(sc_network::config::IncomingRequest {
+						peer: sc_network::PeerId::random(),
+						payload: AvailableDataFetchingRequest { candidate_hash }.encode(),
+						pending_response,
+					})
+					.await
+					.unwrap();
+				let response = rx.await.unwrap().result.unwrap();
+				req_res::v1::AvailableDataFetchingResponse::decode(&mut &response[..]).unwrap()
+			}
+		};
+
+		// Nothing was recovered yet.
+		assert_matches!(
+			request_data(candidate_hash).await,
+			req_res::v1::AvailableDataFetchingResponse::NoSuchData
+		);
+
+		overseer_signal(
+			&mut virtual_overseer,
+			OverseerSignal::ActiveLeaves(ActiveLeavesUpdate::start_work(new_leaf(
+				test_state.current,
+				1,
+			))),
+		)
+		.await;
+
+		let (tx, rx) = oneshot::channel();
+
+		overseer_send(
+			&mut virtual_overseer,
+			AvailabilityRecoveryMessage::RecoverAvailableData(
+				test_state.candidate.clone(),
+				test_state.session_index,
+				Some(GroupIndex(0)),
+				Some(test_state.core_index),
+				tx,
+			),
+		)
+		.await;
+
+		test_state.test_runtime_api_session_info(&mut virtual_overseer).await;
+		test_state.test_runtime_api_node_features(&mut virtual_overseer).await;
+
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::AvailabilityStore(
+				AvailabilityStoreMessage::QueryChunkSize(_, tx)
+			) => {
+				let _ = tx.send(Some(100));
+			}
+		);
+
+		test_state
+			.test_full_data_requests(
+				&req_protocol_names,
+				candidate_hash,
+				&mut virtual_overseer,
+				|i| if i == 3 { Has::Yes } else { Has::No },
+				GroupIndex(0),
+			)
+			.await;
+
+		assert_eq!(rx.await.unwrap().unwrap(), test_state.available_data);
+
+		// The recovered data is now served to peers.
+		assert_matches!(
+			request_data(candidate_hash).await,
+			req_res::v1::AvailableDataFetchingResponse::AvailableData(data) => {
+				assert_eq!(data, test_state.available_data);
+			}
+		);
+
+		virtual_overseer
+	});
+}
+
 #[rstest]
 #[case(true)]
 #[case(false)]
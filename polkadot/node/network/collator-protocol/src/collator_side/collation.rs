@@ -22,7 +22,8 @@ use futures::{future::BoxFuture, stream::FuturesUnordered};
 
 use polkadot_node_network_protocol::{
 	request_response::{
-		incoming::OutgoingResponse, v1 as protocol_v1, v2 as protocol_v2, IncomingRequest,
+		incoming::OutgoingResponse, v1 as protocol_v1, v2 as protocol_v2, v3 as protocol_v3,
+		IncomingRequest,
 	},
 	PeerId,
 };
@@ -92,6 +93,7 @@ pub struct WaitingCollationFetches {
 pub enum VersionedCollationRequest {
 	V1(IncomingRequest<protocol_v1::CollationFetchingRequest>),
 	V2(IncomingRequest<protocol_v2::CollationFetchingRequest>),
+	V3(IncomingRequest<protocol_v3::CollationFetchingRequest>),
 }
 
 impl From<IncomingRequest<protocol_v1::CollationFetchingRequest>> for VersionedCollationRequest {
@@ -106,12 +108,19 @@ impl From<IncomingRequest<protocol_v2::CollationFetchingRequest>> for VersionedC
 	}
 }
 
+impl From<IncomingRequest<protocol_v3::CollationFetchingRequest>> for VersionedCollationRequest {
+	fn from(req: IncomingRequest<protocol_v3::CollationFetchingRequest>) -> Self {
+		Self::V3(req)
+	}
+}
+
 impl VersionedCollationRequest {
 	/// Returns parachain id from the request payload.
 	pub fn para_id(&self) -> ParaId {
 		match self {
 			VersionedCollationRequest::V1(req) => req.payload.para_id,
 			VersionedCollationRequest::V2(req) => req.payload.para_id,
+			VersionedCollationRequest::V3(req) => req.payload.para_id,
 		}
 	}
 
@@ -120,6 +129,7 @@ impl VersionedCollationRequest {
 		match self {
 			VersionedCollationRequest::V1(req) => req.payload.relay_parent,
 			VersionedCollationRequest::V2(req) => req.payload.relay_parent,
+			VersionedCollationRequest::V3(req) => req.payload.relay_parent,
 		}
 	}
 
@@ -128,10 +138,15 @@ impl VersionedCollationRequest {
 		match self {
 			VersionedCollationRequest::V1(req) => req.peer,
 			VersionedCollationRequest::V2(req) => req.peer,
+			VersionedCollationRequest::V3(req) => req.peer,
 		}
 	}
 
 	/// Sends the response back to requester.
+	///
+	/// Responses to V3 requests are sent uncompressed. Use
+	/// [`protocol_v3::CollationFetchingResponse::compress`] and send the response directly to
+	/// compress them.
 	pub fn send_outgoing_response(
 		self,
 		response: OutgoingResponse<protocol_v1::CollationFetchingResponse>,
@@ -139,6 +154,14 @@ impl VersionedCollationRequest {
 		match self {
 			VersionedCollationRequest::V1(req) => req.send_outgoing_response(response),
 			VersionedCollationRequest::V2(req) => req.send_outgoing_response(response),
+			VersionedCollationRequest::V3(req) => {
+				let OutgoingResponse { result, reputation_changes, sent_feedback } = response;
+				req.send_outgoing_response(OutgoingResponse {
+					result: result.map(Into::into),
+					reputation_changes,
+					sent_feedback,
+				})
+			},
 		}
 	}
 }
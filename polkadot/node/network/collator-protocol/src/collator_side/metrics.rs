@@ -38,6 +38,18 @@ impl Metrics {
 		}
 	}
 
+	/// Record the size of a compressed proof of validity sent to a validator.
+	pub fn on_pov_compressed(&self, uncompressed_size: usize, compressed_size: usize) {
+		if let Some(metrics) = &self.0 {
+			metrics.compressed_pov_size.observe(compressed_size as f64);
+			if uncompressed_size > 0 {
+				metrics
+					.pov_compression_ratio
+					.observe(compressed_size as f64 / uncompressed_size as f64);
+			}
+		}
+	}
+
 	/// Provide a timer for `process_msg` which observes on drop.
 	pub fn time_process_msg(&self) -> Option<prometheus::prometheus::HistogramTimer> {
 		self.0.as_ref().map(|metrics| metrics.process_msg.start_timer())
@@ -61,6 +73,8 @@ struct MetricsInner {
 	collations_send_requested: prometheus::Counter<prometheus::U64>,
 	process_msg: prometheus::Histogram,
 	collation_distribution_time: prometheus::HistogramVec,
+	compressed_pov_size: prometheus::Histogram,
+	pov_compression_ratio: prometheus::Histogram,
 }
 
 impl metrics::Metrics for Metrics {
@@ -116,6 +130,29 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			compressed_pov_size: prometheus::register(
+				prometheus::Histogram::with_opts(
+					prometheus::HistogramOpts::new(
+						"polkadot_parachain_collator_protocol_collator_compressed_pov_size",
+						"Size in bytes of the compressed proofs of validity sent to validators",
+					)
+					.buckets(
+						prometheus::exponential_buckets(16384.0, 2.0, 10)
+							.expect("arguments are always valid; qed"),
+					),
+				)?,
+				registry,
+			)?,
+			pov_compression_ratio: prometheus::register(
+				prometheus::Histogram::with_opts(
+					prometheus::HistogramOpts::new(
+						"polkadot_parachain_collator_protocol_collator_pov_compression_ratio",
+						"Compressed size of the proofs of validity sent to validators relative to their uncompressed size",
+					)
+					.buckets(vec![0.05, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0]),
+				)?,
+				registry,
+			)?,
 		};
 
 		Ok(Metrics(Some(metrics)))
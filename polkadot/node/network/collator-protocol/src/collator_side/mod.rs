@@ -31,7 +31,7 @@ use polkadot_node_network_protocol::{
 	peer_set::{CollationVersion, PeerSet},
 	request_response::{
 		incoming::{self, OutgoingResponse},
-		v1 as request_v1, v2 as request_v2, IncomingRequestReceiver,
+		v1 as request_v1, v2 as request_v2, v3 as request_v3, IncomingRequestReceiver,
 	},
 	v1 as protocol_v1, v2 as protocol_v2, OurView, PeerId, UnifiedReputationChange as Rep,
 	Versioned, View,
@@ -936,10 +936,27 @@ async fn send_collation(
 			Ok(request_v1::CollationFetchingResponse::Collation(receipt, pov)),
 	};
 
-	let response =
-		OutgoingResponse { result, reputation_changes: Vec::new(), sent_feedback: Some(tx) };
+	let sent = match request {
+		// The validator accepts a compressed proof of validity.
+		VersionedCollationRequest::V3(req) => {
+			let result = result.map(|response| {
+				let response = request_v3::CollationFetchingResponse::from(response);
+				compress_collation(&state.metrics, response)
+			});
+			req.send_outgoing_response(OutgoingResponse {
+				result,
+				reputation_changes: Vec::new(),
+				sent_feedback: Some(tx),
+			})
+		},
+		request => request.send_outgoing_response(OutgoingResponse {
+			result,
+			reputation_changes: Vec::new(),
+			sent_feedback: Some(tx),
+		}),
+	};
 
-	if let Err(_) = request.send_outgoing_response(response) {
+	if let Err(_) = sent {
 		gum::warn!(target: LOG_TARGET, "Sending collation response failed");
 	}
 
@@ -956,6 +973,34 @@ async fn send_collation(
 	state.metrics.on_collation_sent();
 }
 
+/// Compress the proof of validity of a collation response, if that makes it smaller.
+fn compress_collation(
+	metrics: &Metrics,
+	response: request_v3::CollationFetchingResponse,
+) -> request_v3::CollationFetchingResponse {
+	let uncompressed_size = match &response {
+		request_v3::CollationFetchingResponse::Collation(_, pov) |
+		request_v3::CollationFetchingResponse::CollationWithParentHeadData { pov, .. } =>
+			pov.block_data.0.len(),
+		request_v3::CollationFetchingResponse::CompressedCollation { .. } => return response,
+	};
+
+	let response = response.compress(request_v3::MAX_DECOMPRESSED_POV_SIZE);
+	if let request_v3::CollationFetchingResponse::CompressedCollation { compressed_pov, .. } =
+		&response
+	{
+		gum::trace!(
+			target: LOG_TARGET,
+			uncompressed_size,
+			compressed_size = compressed_pov.len(),
+			"Compressed collation",
+		);
+		metrics.on_pov_compressed(uncompressed_size, compressed_pov.len());
+	}
+
+	response
+}
+
 /// A networking messages switch.
 #[overseer::contextbounds(CollatorProtocol, prefix = self::overseer)]
 async fn handle_incoming_peer_message<Context>(
@@ -1099,6 +1144,8 @@ async fn handle_incoming_request<Context>(
 					per_relay_parent.collations.values_mut().next(),
 				VersionedCollationRequest::V2(req) =>
 					per_relay_parent.collations.get_mut(&req.payload.candidate_hash),
+				VersionedCollationRequest::V3(req) =>
+					per_relay_parent.collations.get_mut(&req.payload.candidate_hash),
 				_ => {
 					gum::warn!(
 						target: LOG_TARGET,
@@ -1456,6 +1503,7 @@ pub(crate) async fn run<Context>(
 	collator_pair: CollatorPair,
 	req_v1_receiver: IncomingRequestReceiver<request_v1::CollationFetchingRequest>,
 	req_v2_receiver: IncomingRequestReceiver<request_v2::CollationFetchingRequest>,
+	req_v3_receiver: IncomingRequestReceiver<request_v3::CollationFetchingRequest>,
 	metrics: Metrics,
 ) -> std::result::Result<(), FatalError> {
 	run_inner(
@@ -1464,6 +1512,7 @@ pub(crate) async fn run<Context>(
 		collator_pair,
 		req_v1_receiver,
 		req_v2_receiver,
+		req_v3_receiver,
 		metrics,
 		ReputationAggregator::default(),
 		REPUTATION_CHANGE_INTERVAL,
@@ -1478,6 +1527,7 @@ async fn run_inner<Context>(
 	collator_pair: CollatorPair,
 	mut req_v1_receiver: IncomingRequestReceiver<request_v1::CollationFetchingRequest>,
 	mut req_v2_receiver: IncomingRequestReceiver<request_v2::CollationFetchingRequest>,
+	mut req_v3_receiver: IncomingRequestReceiver<request_v3::CollationFetchingRequest>,
 	metrics: Metrics,
 	reputation: ReputationAggregator,
 	reputation_interval: Duration,
@@ -1494,8 +1544,10 @@ async fn run_inner<Context>(
 		let reputation_changes = || vec![COST_INVALID_REQUEST];
 		let recv_req_v1 = req_v1_receiver.recv(reputation_changes).fuse();
 		let recv_req_v2 = req_v2_receiver.recv(reputation_changes).fuse();
+		let recv_req_v3 = req_v3_receiver.recv(reputation_changes).fuse();
 		pin_mut!(recv_req_v1);
 		pin_mut!(recv_req_v2);
+		pin_mut!(recv_req_v3);
 
 		let mut reconnect_timeout = &mut state.reconnect_timeout;
 		select! {
@@ -1565,6 +1617,9 @@ async fn run_inner<Context>(
 						(ProspectiveParachainsMode::Enabled { .. }, VersionedCollationRequest::V2(req)) => {
 							per_relay_parent.collations.get(&req.payload.candidate_hash)
 						},
+						(ProspectiveParachainsMode::Enabled { .. }, VersionedCollationRequest::V3(req)) => {
+							per_relay_parent.collations.get(&req.payload.candidate_hash)
+						},
 						_ => {
 							// Request version is checked in `handle_incoming_request`.
 							continue
@@ -1617,6 +1672,14 @@ async fn run_inner<Context>(
 					"Handling incoming collation fetch request V2"
 				)?;
 			}
+			in_req = recv_req_v3 => {
+				let request = in_req.map(VersionedCollationRequest::from);
+
+				log_error(
+					handle_incoming_request(&mut ctx, &mut state, request).await,
+					"Handling incoming collation fetch request V3"
+				)?;
+			}
 		}
 	}
 }
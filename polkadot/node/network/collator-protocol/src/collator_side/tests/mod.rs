@@ -229,6 +229,7 @@ struct TestHarness {
 	virtual_overseer: VirtualOverseer,
 	req_v1_cfg: sc_network::config::RequestResponseConfig,
 	req_v2_cfg: sc_network::config::RequestResponseConfig,
+	req_v3_cfg: sc_network::config::RequestResponseConfig,
 }
 
 fn test_harness<T: Future<Output = TestHarness>>(
@@ -255,6 +256,10 @@ fn test_harness<T: Future<Output = TestHarness>>(
 		Block,
 		sc_network::NetworkWorker<Block, Hash>,
 	>(&req_protocol_names);
+	let (collation_req_v3_receiver, req_v3_cfg) = IncomingRequest::get_config_receiver::<
+		Block,
+		sc_network::NetworkWorker<Block, Hash>,
+	>(&req_protocol_names);
 	let subsystem = async {
 		run_inner(
 			context,
@@ -262,6 +267,7 @@ fn test_harness<T: Future<Output = TestHarness>>(
 			collator_pair,
 			collation_req_receiver,
 			collation_req_v2_receiver,
+			collation_req_v3_receiver,
 			Default::default(),
 			reputation,
 			REPUTATION_CHANGE_TEST_INTERVAL,
@@ -270,7 +276,7 @@ fn test_harness<T: Future<Output = TestHarness>>(
 		.unwrap();
 	};
 
-	let test_fut = test(TestHarness { virtual_overseer, req_v1_cfg, req_v2_cfg });
+	let test_fut = test(TestHarness { virtual_overseer, req_v1_cfg, req_v2_cfg, req_v3_cfg });
 
 	futures::pin_mut!(test_fut);
 	futures::pin_mut!(subsystem);
@@ -712,6 +718,7 @@ fn advertise_and_send_collation() {
 			let mut virtual_overseer = test_harness.virtual_overseer;
 			let mut req_v1_cfg = test_harness.req_v1_cfg;
 			let req_v2_cfg = test_harness.req_v2_cfg;
+			let req_v3_cfg = test_harness.req_v3_cfg;
 
 			setup_system(&mut virtual_overseer, &test_state).await;
 
@@ -864,7 +871,7 @@ fn advertise_and_send_collation() {
 				None,
 			)
 			.await;
-			TestHarness { virtual_overseer, req_v1_cfg, req_v2_cfg }
+			TestHarness { virtual_overseer, req_v1_cfg, req_v2_cfg, req_v3_cfg }
 		},
 	);
 }
@@ -883,6 +890,7 @@ fn delay_reputation_change() {
 			let mut virtual_overseer = test_harness.virtual_overseer;
 			let mut req_v1_cfg = test_harness.req_v1_cfg;
 			let req_v2_cfg = test_harness.req_v2_cfg;
+			let req_v3_cfg = test_harness.req_v3_cfg;
 
 			setup_system(&mut virtual_overseer, &test_state).await;
 
@@ -978,7 +986,7 @@ fn delay_reputation_change() {
 				);
 			}
 
-			TestHarness { virtual_overseer, req_v1_cfg, req_v2_cfg }
+			TestHarness { virtual_overseer, req_v1_cfg, req_v2_cfg, req_v3_cfg }
 		},
 	);
 }
@@ -1492,6 +1500,7 @@ fn connect_to_buffered_groups() {
 			let mut virtual_overseer = test_harness.virtual_overseer;
 			let mut req_cfg = test_harness.req_v1_cfg;
 			let req_v2_cfg = test_harness.req_v2_cfg;
+			let req_v3_cfg = test_harness.req_v3_cfg;
 
 			setup_system(&mut virtual_overseer, &test_state).await;
 
@@ -1596,7 +1605,7 @@ fn connect_to_buffered_groups() {
 				}
 			);
 
-			TestHarness { virtual_overseer, req_v1_cfg: req_cfg, req_v2_cfg }
+			TestHarness { virtual_overseer, req_v1_cfg: req_cfg, req_v2_cfg, req_v3_cfg }
 		},
 	);
 }
@@ -478,6 +478,7 @@ fn send_parent_head_data_for_elastic_scaling() {
 			let mut virtual_overseer = test_harness.virtual_overseer;
 			let req_v1_cfg = test_harness.req_v1_cfg;
 			let mut req_v2_cfg = test_harness.req_v2_cfg;
+			let req_v3_cfg = test_harness.req_v3_cfg;
 
 			let head_b = Hash::from_low_u64_be(129);
 			let head_b_num: u32 = 63;
@@ -568,7 +569,7 @@ fn send_parent_head_data_for_elastic_scaling() {
 				}
 			);
 
-			TestHarness { virtual_overseer, req_v1_cfg, req_v2_cfg }
+			TestHarness { virtual_overseer, req_v1_cfg, req_v2_cfg, req_v3_cfg }
 		},
 	)
 }
@@ -589,6 +590,7 @@ fn advertise_and_send_collation_by_hash() {
 			let mut virtual_overseer = test_harness.virtual_overseer;
 			let req_v1_cfg = test_harness.req_v1_cfg;
 			let mut req_v2_cfg = test_harness.req_v2_cfg;
+			let req_v3_cfg = test_harness.req_v3_cfg;
 
 			let head_a = Hash::from_low_u64_be(128);
 			let head_a_num: u32 = 64;
@@ -682,7 +684,128 @@ fn advertise_and_send_collation_by_hash() {
 				);
 			}
 
-			TestHarness { virtual_overseer, req_v1_cfg, req_v2_cfg }
+			TestHarness { virtual_overseer, req_v1_cfg, req_v2_cfg, req_v3_cfg }
+		},
+	)
+}
+
+/// Tests that collator compresses collations requested with V3 requests.
+#[test]
+fn send_compressed_collation_for_v3_request() {
+	let test_state = TestState::default();
+
+	let local_peer_id = test_state.local_peer_id;
+	let collator_pair = test_state.collator_pair.clone();
+
+	test_harness(
+		local_peer_id,
+		collator_pair,
+		ReputationAggregator::new(|_| true),
+		|test_harness| async move {
+			let mut virtual_overseer = test_harness.virtual_overseer;
+			let req_v1_cfg = test_harness.req_v1_cfg;
+			let req_v2_cfg = test_harness.req_v2_cfg;
+			let mut req_v3_cfg = test_harness.req_v3_cfg;
+
+			let head_b = Hash::from_low_u64_be(129);
+			let head_b_num: u32 = 63;
+
+			overseer_send(
+				&mut virtual_overseer,
+				CollatorProtocolMessage::CollateOn(test_state.para_id),
+			)
+			.await;
+			update_view(&mut virtual_overseer, vec![(head_b, head_b_num)], 1).await;
+
+			// Highly compressible block data.
+			let pov_block = PoV { block_data: BlockData(vec![42; 64 * 1024]) };
+			let candidate = TestCandidateBuilder {
+				para_id: test_state.para_id,
+				relay_parent: head_b,
+				pov_hash: pov_block.hash(),
+				..Default::default()
+			}
+			.build();
+			distribute_collation_with_receipt(
+				&mut virtual_overseer,
+				&test_state,
+				head_b,
+				true,
+				candidate.clone(),
+				pov_block.clone(),
+				Hash::zero(),
+			)
+			.await;
+
+			let peer = test_state.validator_peer_id[0];
+			let validator_id = test_state.current_group_validator_authority_ids()[0].clone();
+			connect_peer(
+				&mut virtual_overseer,
+				peer,
+				CollationVersion::V2,
+				Some(validator_id.clone()),
+			)
+			.await;
+			expect_declare_msg_v2(&mut virtual_overseer, &test_state, &peer).await;
+
+			send_peer_view_change(&mut virtual_overseer, &peer, vec![head_b]).await;
+			expect_advertise_collation_msg(
+				&mut virtual_overseer,
+				&[peer],
+				head_b,
+				Some(vec![candidate.hash()]),
+			)
+			.await;
+
+			let (pending_response, rx) = oneshot::channel();
+			req_v3_cfg
+				.inbound_queue
+				.as_mut()
+				.unwrap()
+				.send(RawIncomingRequest {
+					peer,
+					payload: request_v3::CollationFetchingRequest {
+						relay_parent: head_b,
+						para_id: test_state.para_id,
+						candidate_hash: candidate.hash(),
+					}
+					.encode(),
+					pending_response,
+				})
+				.await
+				.unwrap();
+
+			assert_matches!(
+				rx.await,
+				Ok(full_response) => {
+					let bytes = full_response.result.expect("We should have a proper answer");
+					let response = request_v3::CollationFetchingResponse::decode(&mut &bytes[..])
+						.expect("Decoding should work");
+					assert_matches!(
+						&response,
+						request_v3::CollationFetchingResponse::CompressedCollation {
+							compressed_pov,
+							..
+						} => assert!(compressed_pov.len() < pov_block.block_data.0.len())
+					);
+
+					let (receipt, pov) = match response
+						.decompress(request_v3::MAX_DECOMPRESSED_POV_SIZE)
+						.expect("Decompression should work")
+					{
+						request_v1::CollationFetchingResponse::Collation(receipt, pov) |
+						request_v1::CollationFetchingResponse::CollationWithParentHeadData {
+							receipt,
+							pov,
+							..
+						} => (receipt, pov),
+					};
+					assert_eq!(receipt, candidate);
+					assert_eq!(pov, pov_block);
+				}
+			);
+
+			TestHarness { virtual_overseer, req_v1_cfg, req_v2_cfg, req_v3_cfg }
 		},
 	)
 }
@@ -32,7 +32,9 @@ use polkadot_node_subsystem_util::reputation::ReputationAggregator;
 use sp_keystore::KeystorePtr;
 
 use polkadot_node_network_protocol::{
-	request_response::{v1 as request_v1, v2 as protocol_v2, IncomingRequestReceiver},
+	request_response::{
+		v1 as request_v1, v2 as protocol_v2, v3 as protocol_v3, IncomingRequestReceiver,
+	},
 	PeerId, UnifiedReputationChange as Rep,
 };
 use polkadot_primitives::CollatorPair;
@@ -85,6 +87,8 @@ pub enum ProtocolSide {
 		request_receiver_v1: IncomingRequestReceiver<request_v1::CollationFetchingRequest>,
 		/// Receiver for v2 collation fetching requests.
 		request_receiver_v2: IncomingRequestReceiver<protocol_v2::CollationFetchingRequest>,
+		/// Receiver for v3 collation fetching requests, which accept compressed collations.
+		request_receiver_v3: IncomingRequestReceiver<protocol_v3::CollationFetchingRequest>,
 		/// Metrics.
 		metrics: collator_side::Metrics,
 	},
@@ -121,6 +125,7 @@ impl<Context> CollatorProtocolSubsystem {
 				collator_pair,
 				request_receiver_v1,
 				request_receiver_v2,
+				request_receiver_v3,
 				metrics,
 			} => collator_side::run(
 				ctx,
@@ -128,6 +133,7 @@ impl<Context> CollatorProtocolSubsystem {
 				collator_pair,
 				request_receiver_v1,
 				request_receiver_v2,
+				request_receiver_v3,
 				metrics,
 			)
 			.map_err(|e| SubsystemError::with_origin("collator-protocol", e))
@@ -57,6 +57,18 @@ impl Metrics {
 		self.0.as_ref().map(|metrics| metrics.collation_request_duration.start_timer())
 	}
 
+	/// Note the sizes of a compressed PoV received from a collator.
+	pub fn on_compressed_collation(&self, compressed_size: usize, decompressed_size: usize) {
+		if let Some(metrics) = &self.0 {
+			metrics.decompressed_pov_size.observe(decompressed_size as f64);
+			if decompressed_size > 0 {
+				metrics
+					.pov_compression_ratio
+					.observe(compressed_size as f64 / decompressed_size as f64);
+			}
+		}
+	}
+
 	/// Provide a timer for `request_unblocked_collations` which observes on drop.
 	pub fn time_request_unblocked_collations(
 		&self,
@@ -75,6 +87,8 @@ struct MetricsInner {
 	collator_peer_count: prometheus::Gauge<prometheus::U64>,
 	collation_request_duration: prometheus::Histogram,
 	request_unblocked_collations: prometheus::Histogram,
+	decompressed_pov_size: prometheus::Histogram,
+	pov_compression_ratio: prometheus::Histogram,
 }
 
 impl metrics::Metrics for Metrics {
@@ -135,6 +149,29 @@ impl metrics::Metrics for Metrics {
 				)?,
 				registry,
 			)?,
+			decompressed_pov_size: prometheus::register(
+				prometheus::Histogram::with_opts(
+					prometheus::HistogramOpts::new(
+						"polkadot_parachain_collator_protocol_validator_decompressed_pov_size",
+						"Size in bytes of the compressed proofs of validity received from collators after decompression",
+					)
+					.buckets(
+						prometheus::exponential_buckets(16384.0, 2.0, 10)
+							.expect("arguments are always valid; qed"),
+					),
+				)?,
+				registry,
+			)?,
+			pov_compression_ratio: prometheus::register(
+				prometheus::Histogram::with_opts(
+					prometheus::HistogramOpts::new(
+						"polkadot_parachain_collator_protocol_validator_pov_compression_ratio",
+						"Compressed size of the proofs of validity received from collators relative to their decompressed size",
+					)
+					.buckets(vec![0.05, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0]),
+				)?,
+				registry,
+			)?,
 		};
 
 		Ok(Metrics(Some(metrics)))
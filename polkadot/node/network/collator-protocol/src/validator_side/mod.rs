@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
+use codec::Decode;
 use futures::{
 	channel::oneshot, future::BoxFuture, select, stream::FuturesUnordered, FutureExt, StreamExt,
 };
@@ -32,7 +33,8 @@ use polkadot_node_network_protocol::{
 	peer_set::{CollationVersion, PeerSet},
 	request_response::{
 		outgoing::{Recipient, RequestError},
-		v1 as request_v1, v2 as request_v2, OutgoingRequest, Requests,
+		v1 as request_v1, v2 as request_v2, v3 as request_v3, OutgoingRequest, OutgoingResult,
+		Requests,
 	},
 	v1 as protocol_v1, v2 as protocol_v2, OurView, PeerId, UnifiedReputationChange as Rep,
 	Versioned, View,
@@ -704,11 +706,18 @@ async fn request_collation(
 			(requests, response_recv.boxed())
 		},
 		(CollationVersion::V2, Some(ProspectiveCandidate { candidate_hash, .. })) => {
-			let (req, response_recv) = OutgoingRequest::new(
+			// Prefer the protocol which allows the collator to compress the PoV and fall back to
+			// the uncompressed one for collators which don't support it yet.
+			let (req, response_recv) = OutgoingRequest::new_with_fallback(
 				Recipient::Peer(peer_id),
+				request_v3::CollationFetchingRequest { relay_parent, para_id, candidate_hash },
 				request_v2::CollationFetchingRequest { relay_parent, para_id, candidate_hash },
 			);
-			let requests = Requests::CollationFetchingV2(req);
+			let requests = Requests::CollationFetchingV3(req);
+			let metrics = state.metrics.clone();
+			let response_recv = response_recv.map(move |response| {
+				response.and_then(|(raw, _protocol)| decode_collation_response(&raw, &metrics))
+			});
 			(requests, response_recv.boxed())
 		},
 		_ => return Err(FetchError::ProtocolMismatch),
@@ -754,6 +763,37 @@ async fn request_collation(
 	Ok(())
 }
 
+/// Decode a raw collation fetching response, decompressing the PoV if the collator compressed it.
+///
+/// Decompression is bounded by [`request_v3::MAX_DECOMPRESSED_POV_SIZE`], responses which fail
+/// to decompress are treated as invalid.
+fn decode_collation_response(
+	raw: &[u8],
+	metrics: &Metrics,
+) -> OutgoingResult<request_v1::CollationFetchingResponse> {
+	let response = request_v3::CollationFetchingResponse::decode(&mut &raw[..])?;
+	let compressed_size = match &response {
+		request_v3::CollationFetchingResponse::CompressedCollation { compressed_pov, .. } =>
+			Some(compressed_pov.len()),
+		_ => None,
+	};
+
+	let response = response.decompress(request_v3::MAX_DECOMPRESSED_POV_SIZE).map_err(|err| {
+		gum::debug!(target: LOG_TARGET, ?err, "Failed to decompress collation");
+		RequestError::InvalidResponse("Invalid compressed collation".into())
+	})?;
+
+	if let Some(compressed_size) = compressed_size {
+		let pov = match &response {
+			request_v1::CollationFetchingResponse::Collation(_, pov) |
+			request_v1::CollationFetchingResponse::CollationWithParentHeadData { pov, .. } => pov,
+		};
+		metrics.on_compressed_collation(compressed_size, pov.block_data.0.len());
+	}
+
+	Ok(response)
+}
+
 /// Networking message has been received.
 #[overseer::contextbounds(CollatorProtocol, prefix = overseer)]
 async fn process_incoming_peer_message<Context>(
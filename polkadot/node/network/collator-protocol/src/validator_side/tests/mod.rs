@@ -31,7 +31,7 @@ use std::{
 use polkadot_node_network_protocol::{
 	our_view,
 	peer_set::CollationVersion,
-	request_response::{Requests, ResponseSender},
+	request_response::{Protocol, Requests, ResponseSender},
 	ObservedRole,
 };
 use polkadot_node_primitives::{BlockData, PoV};
@@ -425,11 +425,13 @@ async fn assert_fetch_collation_request(
 			),
 			Some(candidate_hash) => assert_matches!(
 				req,
-				Requests::CollationFetchingV2(req) => {
+				Requests::CollationFetchingV3(req) => {
 					let payload = req.payload;
 					assert_eq!(payload.relay_parent, relay_parent);
 					assert_eq!(payload.para_id, para_id);
 					assert_eq!(payload.candidate_hash, candidate_hash);
+					let (_, fallback) = req.fallback_request.expect("V2 fallback is provided");
+					assert_eq!(fallback, Protocol::CollationFetchingV2);
 					req.pending_response
 				}
 			),
@@ -719,6 +719,107 @@ fn second_multiple_candidates_per_relay_parent() {
 	});
 }
 
+#[test]
+fn compressed_collation_is_decompressed_and_seconded() {
+	let test_state = TestState::default();
+
+	test_harness(ReputationAggregator::new(|_| true), |test_harness| async move {
+		let TestHarness { mut virtual_overseer, keystore } = test_harness;
+
+		let pair = CollatorPair::generate().0;
+
+		let head_b = Hash::from_low_u64_be(128);
+		let head_b_num: u32 = 2;
+		let head_c = Hash::from_low_u64_be(130);
+
+		update_view(&mut virtual_overseer, &test_state, vec![(head_b, head_b_num)], 1).await;
+
+		let peer_a = PeerId::random();
+
+		connect_and_declare_collator(
+			&mut virtual_overseer,
+			peer_a,
+			pair.clone(),
+			test_state.chain_ids[0],
+			CollationVersion::V2,
+		)
+		.await;
+
+		let mut candidate = dummy_candidate_receipt_bad_sig(head_c, Some(Default::default()));
+		candidate.descriptor.para_id = test_state.chain_ids[0];
+		candidate.descriptor.persisted_validation_data_hash = dummy_pvd().hash();
+		let commitments = CandidateCommitments {
+			head_data: HeadData(vec![1 as u8]),
+			horizontal_messages: Default::default(),
+			upward_messages: Default::default(),
+			new_validation_code: None,
+			processed_downward_messages: 0,
+			hrmp_watermark: 0,
+		};
+		candidate.commitments_hash = commitments.hash();
+		let candidate: CandidateReceipt = candidate.into();
+
+		let candidate_hash = candidate.hash();
+		let parent_head_data_hash = Hash::zero();
+
+		advertise_collation(
+			&mut virtual_overseer,
+			peer_a,
+			head_c,
+			Some((candidate_hash, parent_head_data_hash)),
+		)
+		.await;
+		assert_matches!(
+			overseer_recv(&mut virtual_overseer).await,
+			AllMessages::CandidateBacking(
+				CandidateBackingMessage::CanSecond(request, tx),
+			) => {
+				assert_eq!(request.candidate_hash, candidate_hash);
+				tx.send(true).expect("receiving side should be alive");
+			}
+		);
+
+		let response_channel = assert_fetch_collation_request(
+			&mut virtual_overseer,
+			head_c,
+			test_state.chain_ids[0],
+			Some(candidate_hash),
+		)
+		.await;
+
+		let pov = PoV { block_data: BlockData(vec![1; 16 * 1024]) };
+		let response: request_v3::CollationFetchingResponse =
+			request_v1::CollationFetchingResponse::Collation(candidate.clone(), pov.clone()).into();
+		let response = response.compress(request_v3::MAX_DECOMPRESSED_POV_SIZE);
+		assert_matches!(
+			response,
+			request_v3::CollationFetchingResponse::CompressedCollation { .. }
+		);
+
+		response_channel
+			.send(Ok((response.encode(), ProtocolName::from(""))))
+			.expect("Sending response should succeed");
+
+		assert_candidate_backing_second(
+			&mut virtual_overseer,
+			head_c,
+			test_state.chain_ids[0],
+			&pov,
+			CollationVersion::V2,
+		)
+		.await;
+
+		let candidate = CommittedCandidateReceipt { descriptor: candidate.descriptor, commitments };
+
+		send_seconded_statement(&mut virtual_overseer, keystore.clone(), &candidate).await;
+
+		assert_collation_seconded(&mut virtual_overseer, head_c, peer_a, CollationVersion::V2)
+			.await;
+
+		virtual_overseer
+	});
+}
+
 #[test]
 fn fetched_collation_sanity_check() {
 	let test_state = TestState::default();
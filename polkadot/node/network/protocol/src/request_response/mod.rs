@@ -76,6 +76,9 @@ pub mod v1;
 /// Actual versioned requests and responses that are sent over the wire.
 pub mod v2;
 
+/// Actual versioned requests and responses that are sent over the wire.
+pub mod v3;
+
 /// A protocol per subsystem seems to make the most sense, this way we don't need any dispatching
 /// within protocols.
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, EnumIter)]
@@ -102,6 +105,9 @@ pub enum Protocol {
 	/// Protocol for chunk fetching version 2, used by availability distribution and availability
 	/// recovery.
 	ChunkFetchingV2,
+
+	/// Protocol for fetching collations from collators, which may compress the proof of validity.
+	CollationFetchingV3,
 }
 
 /// Minimum bandwidth we expect for validators - 500Mbit/s is the recommendation, so approximately
@@ -221,16 +227,17 @@ impl Protocol {
 				CHUNK_REQUEST_TIMEOUT,
 				tx,
 			),
-			Protocol::CollationFetchingV1 | Protocol::CollationFetchingV2 =>
-				N::request_response_config(
-					name,
-					legacy_names,
-					1_000,
-					POV_RESPONSE_SIZE,
-					// Taken from initial implementation in collator protocol:
-					POV_REQUEST_TIMEOUT_CONNECTED,
-					tx,
-				),
+			Protocol::CollationFetchingV1 |
+			Protocol::CollationFetchingV2 |
+			Protocol::CollationFetchingV3 => N::request_response_config(
+				name,
+				legacy_names,
+				1_000,
+				POV_RESPONSE_SIZE,
+				// Taken from initial implementation in collator protocol:
+				POV_REQUEST_TIMEOUT_CONNECTED,
+				tx,
+			),
 			Protocol::PoVFetchingV1 => N::request_response_config(
 				name,
 				legacy_names,
@@ -297,7 +304,9 @@ impl Protocol {
 			// as well.
 			Protocol::ChunkFetchingV1 | Protocol::ChunkFetchingV2 => 100,
 			// 10 seems reasonable, considering group sizes of max 10 validators.
-			Protocol::CollationFetchingV1 | Protocol::CollationFetchingV2 => 10,
+			Protocol::CollationFetchingV1 |
+			Protocol::CollationFetchingV2 |
+			Protocol::CollationFetchingV3 => 10,
 			// 10 seems reasonable, considering group sizes of max 10 validators.
 			Protocol::PoVFetchingV1 => 10,
 			// Validators are constantly self-selecting to request available data which may lead
@@ -366,6 +375,7 @@ impl Protocol {
 			Protocol::AttestedCandidateV2 => None,
 			Protocol::CollationFetchingV2 => None,
 			Protocol::ChunkFetchingV2 => None,
+			Protocol::CollationFetchingV3 => None,
 		}
 	}
 }
@@ -428,6 +438,9 @@ impl ReqProtocolNames {
 			Protocol::CollationFetchingV2 => "/req_collation/2",
 			Protocol::AttestedCandidateV2 => "/req_attested_candidate/2",
 			Protocol::ChunkFetchingV2 => "/req_chunk/2",
+
+			// V3:
+			Protocol::CollationFetchingV3 => "/req_collation/3",
 		};
 
 		format!("{}{}", prefix, short_name).into()
@@ -24,7 +24,7 @@ use sc_network_types::PeerId;
 
 use polkadot_primitives::AuthorityDiscoveryId;
 
-use super::{v1, v2, IsRequest, Protocol};
+use super::{v1, v2, v3, IsRequest, Protocol};
 
 /// All requests that can be sent to the network bridge via `NetworkBridgeTxMessage::SendRequest`.
 #[derive(Debug)]
@@ -47,6 +47,11 @@ pub enum Requests {
 	/// Fetch a collation from a collator which previously announced it.
 	/// Compared to V1 it requires specifying which candidate is requested by its hash.
 	CollationFetchingV2(OutgoingRequest<v2::CollationFetchingRequest>),
+	/// Fetch a collation from a collator which previously announced it, allowing the collator to
+	/// compress it. Falls back to V2 if the collator doesn't support it.
+	CollationFetchingV3(
+		OutgoingRequest<v3::CollationFetchingRequest, v2::CollationFetchingRequest>,
+	),
 }
 
 impl Requests {
@@ -62,6 +67,7 @@ impl Requests {
 			Self::ChunkFetching(r) => r.encode_request(),
 			Self::CollationFetchingV1(r) => r.encode_request(),
 			Self::CollationFetchingV2(r) => r.encode_request(),
+			Self::CollationFetchingV3(r) => r.encode_request(),
 			Self::PoVFetchingV1(r) => r.encode_request(),
 			Self::AvailableDataFetchingV1(r) => r.encode_request(),
 			Self::StatementFetchingV1(r) => r.encode_request(),
@@ -0,0 +1,235 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Requests and responses as sent over the wire for the individual protocols.
+
+use codec::{Decode, Encode};
+
+use polkadot_node_primitives::{BlockData, PoV};
+use polkadot_primitives::{
+	vstaging::CandidateReceiptV2 as CandidateReceipt, CandidateHash, Hash, HeadData, Id as ParaId,
+	MAX_POV_SIZE,
+};
+
+use super::{v1, IsRequest, Protocol};
+
+/// The maximum size of a decompressed proof of validity.
+///
+/// Compressed collations that inflate beyond this are rejected, protecting validators from
+/// compression bombs.
+pub const MAX_DECOMPRESSED_POV_SIZE: usize = MAX_POV_SIZE as usize;
+
+/// Request the advertised collation at that relay-parent, accepting a compressed response.
+#[derive(Debug, Clone, Encode, Decode)]
+pub struct CollationFetchingRequest {
+	/// Relay parent collation is built on top of.
+	pub relay_parent: Hash,
+	/// The `ParaId` of the collation.
+	pub para_id: ParaId,
+	/// Candidate hash.
+	pub candidate_hash: CandidateHash,
+}
+
+/// Responses as sent by collators.
+///
+/// The first two variants are the same as for V1, so any V1 response is a valid V3 response.
+#[derive(Debug, Clone, Encode, Decode)]
+pub enum CollationFetchingResponse {
+	/// Deliver requested collation.
+	#[codec(index = 0)]
+	Collation(CandidateReceipt, PoV),
+
+	/// Deliver requested collation along with parent head data.
+	#[codec(index = 1)]
+	CollationWithParentHeadData {
+		/// The receipt of the candidate.
+		receipt: CandidateReceipt,
+		/// Candidate's proof of validity.
+		pov: PoV,
+		/// The head data of the candidate's parent.
+		/// This is needed for elastic scaling to work.
+		parent_head_data: HeadData,
+	},
+
+	/// Deliver requested collation with its proof of validity compressed.
+	#[codec(index = 2)]
+	CompressedCollation {
+		/// The receipt of the candidate.
+		receipt: CandidateReceipt,
+		/// The zstd-compressed block data of the candidate's proof of validity.
+		compressed_pov: Vec<u8>,
+		/// The head data of the candidate's parent, if the collator provided it.
+		parent_head_data: Option<HeadData>,
+	},
+}
+
+impl IsRequest for CollationFetchingRequest {
+	type Response = CollationFetchingResponse;
+	const PROTOCOL: Protocol = Protocol::CollationFetchingV3;
+}
+
+/// Errors that can occur when decompressing a [`CollationFetchingResponse`].
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum DecompressionError {
+	/// The decompressed proof of validity would exceed the size limit.
+	#[error("Decompressed proof of validity exceeds {0} bytes")]
+	TooLarge(usize),
+	/// The compressed proof of validity could not be decompressed.
+	#[error("Compressed proof of validity is invalid")]
+	Invalid,
+}
+
+impl From<v1::CollationFetchingResponse> for CollationFetchingResponse {
+	fn from(response: v1::CollationFetchingResponse) -> Self {
+		match response {
+			v1::CollationFetchingResponse::Collation(receipt, pov) => Self::Collation(receipt, pov),
+			v1::CollationFetchingResponse::CollationWithParentHeadData {
+				receipt,
+				pov,
+				parent_head_data,
+			} => Self::CollationWithParentHeadData { receipt, pov, parent_head_data },
+		}
+	}
+}
+
+impl CollationFetchingResponse {
+	/// Compress the proof of validity, unless that doesn't make the response any smaller or the
+	/// proof of validity exceeds `max_pov_size` bytes.
+	pub fn compress(self, max_pov_size: usize) -> Self {
+		let (receipt, pov, parent_head_data) = match self {
+			Self::Collation(receipt, pov) => (receipt, pov, None),
+			Self::CollationWithParentHeadData { receipt, pov, parent_head_data } =>
+				(receipt, pov, Some(parent_head_data)),
+			compressed @ Self::CompressedCollation { .. } => return compressed,
+		};
+
+		match sp_maybe_compressed_blob::compress(&pov.block_data.0, max_pov_size) {
+			Some(compressed_pov) if compressed_pov.len() < pov.block_data.0.len() =>
+				Self::CompressedCollation { receipt, compressed_pov, parent_head_data },
+			_ => match parent_head_data {
+				Some(parent_head_data) =>
+					Self::CollationWithParentHeadData { receipt, pov, parent_head_data },
+				None => Self::Collation(receipt, pov),
+			},
+		}
+	}
+
+	/// Decompress the proof of validity, rejecting it if it exceeds `max_pov_size` bytes.
+	pub fn decompress(
+		self,
+		max_pov_size: usize,
+	) -> Result<v1::CollationFetchingResponse, DecompressionError> {
+		match self {
+			Self::Collation(receipt, pov) =>
+				Ok(v1::CollationFetchingResponse::Collation(receipt, pov)),
+			Self::CollationWithParentHeadData { receipt, pov, parent_head_data } =>
+				Ok(v1::CollationFetchingResponse::CollationWithParentHeadData {
+					receipt,
+					pov,
+					parent_head_data,
+				}),
+			Self::CompressedCollation { receipt, compressed_pov, parent_head_data } => {
+				let block_data =
+					sp_maybe_compressed_blob::decompress(&compressed_pov, max_pov_size).map_err(
+						|err| match err {
+							sp_maybe_compressed_blob::Error::PossibleBomb =>
+								DecompressionError::TooLarge(max_pov_size),
+							sp_maybe_compressed_blob::Error::Invalid => DecompressionError::Invalid,
+						},
+					)?;
+				// Blobs without the compression prefix are passed through unchanged.
+				if block_data.len() > max_pov_size {
+					return Err(DecompressionError::TooLarge(max_pov_size))
+				}
+
+				let pov = PoV { block_data: BlockData(block_data.into_owned()) };
+				Ok(match parent_head_data {
+					Some(parent_head_data) =>
+						v1::CollationFetchingResponse::CollationWithParentHeadData {
+							receipt,
+							pov,
+							parent_head_data,
+						},
+					None => v1::CollationFetchingResponse::Collation(receipt, pov),
+				})
+			},
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use polkadot_primitives_test_helpers::dummy_candidate_receipt_v2;
+
+	fn collation(block_data: Vec<u8>) -> CollationFetchingResponse {
+		CollationFetchingResponse::CollationWithParentHeadData {
+			receipt: dummy_candidate_receipt_v2(Hash::zero()),
+			pov: PoV { block_data: BlockData(block_data) },
+			parent_head_data: HeadData(vec![1, 2, 3]),
+		}
+	}
+
+	#[test]
+	fn compressed_collation_roundtrips() {
+		let block_data = vec![7; 64 * 1024];
+		let compressed = collation(block_data.clone()).compress(MAX_DECOMPRESSED_POV_SIZE);
+		assert!(matches!(
+			compressed,
+			CollationFetchingResponse::CompressedCollation { ref compressed_pov, .. }
+				if compressed_pov.len() < block_data.len()
+		));
+
+		let decoded = CollationFetchingResponse::decode(&mut &compressed.encode()[..]).unwrap();
+		assert!(matches!(
+			decoded.decompress(MAX_DECOMPRESSED_POV_SIZE),
+			Ok(v1::CollationFetchingResponse::CollationWithParentHeadData { pov, .. })
+				if pov.block_data.0 == block_data
+		));
+	}
+
+	#[test]
+	fn incompressible_collation_is_sent_uncompressed() {
+		let block_data = (0..=255u8).collect::<Vec<_>>();
+		assert!(matches!(
+			collation(block_data).compress(MAX_DECOMPRESSED_POV_SIZE),
+			CollationFetchingResponse::CollationWithParentHeadData { .. }
+		));
+	}
+
+	#[test]
+	fn v1_responses_decode_as_v3() {
+		let v1_response = v1::CollationFetchingResponse::Collation(
+			dummy_candidate_receipt_v2(Hash::zero()),
+			PoV { block_data: BlockData(vec![1]) },
+		);
+
+		assert!(matches!(
+			CollationFetchingResponse::decode(&mut &v1_response.encode()[..]),
+			Ok(CollationFetchingResponse::Collation(..))
+		));
+	}
+
+	#[test]
+	fn decompression_is_capped() {
+		let compressed = collation(vec![0; 64 * 1024]).compress(MAX_DECOMPRESSED_POV_SIZE);
+
+		assert_eq!(
+			compressed.decompress(1024).map(|_| ()),
+			Err(DecompressionError::TooLarge(1024))
+		);
+	}
+}
@@ -28,7 +28,8 @@ use polkadot_node_core_dispute_coordinator::Config as DisputeCoordinatorConfig;
 use polkadot_node_network_protocol::{
 	peer_set::{PeerSet, PeerSetProtocolNames},
 	request_response::{
-		v1 as request_v1, v2 as request_v2, IncomingRequestReceiver, ReqProtocolNames,
+		v1 as request_v1, v2 as request_v2, v3 as request_v3, IncomingRequestReceiver,
+		ReqProtocolNames,
 	},
 };
 #[cfg(any(feature = "malus", test))]
@@ -92,6 +93,8 @@ where
 	pub collation_req_v1_receiver: IncomingRequestReceiver<request_v1::CollationFetchingRequest>,
 	/// Collations request receiver for network protocol v2.
 	pub collation_req_v2_receiver: IncomingRequestReceiver<request_v2::CollationFetchingRequest>,
+	/// Collations request receiver for network protocol v3.
+	pub collation_req_v3_receiver: IncomingRequestReceiver<request_v3::CollationFetchingRequest>,
 	/// Receiver for available data requests.
 	pub available_data_req_receiver:
 		IncomingRequestReceiver<request_v1::AvailableDataFetchingRequest>,
@@ -160,6 +163,7 @@ pub fn validator_overseer_builder<Spawner, RuntimeClient>(
 		authority_discovery_service,
 		collation_req_v1_receiver: _,
 		collation_req_v2_receiver: _,
+		collation_req_v3_receiver: _,
 		available_data_req_receiver,
 		registry,
 		spawner,
@@ -384,6 +388,7 @@ pub fn validator_with_parallel_overseer_builder<Spawner, RuntimeClient>(
 		authority_discovery_service,
 		collation_req_v1_receiver: _,
 		collation_req_v2_receiver: _,
+		collation_req_v3_receiver: _,
 		available_data_req_receiver,
 		registry,
 		spawner,
@@ -601,6 +606,7 @@ pub fn collator_overseer_builder<Spawner, RuntimeClient>(
 		authority_discovery_service,
 		collation_req_v1_receiver,
 		collation_req_v2_receiver,
+		collation_req_v3_receiver,
 		available_data_req_receiver,
 		registry,
 		spawner,
@@ -704,6 +710,7 @@ where
 					collator_pair,
 					request_receiver_v1: collation_req_v1_receiver,
 					request_receiver_v2: collation_req_v2_receiver,
+					request_receiver_v3: collation_req_v3_receiver,
 					metrics: Metrics::register(registry)?,
 				},
 				IsParachainNode::FullNode => ProtocolSide::None,
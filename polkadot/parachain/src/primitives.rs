@@ -366,6 +366,15 @@ pub trait XcmpMessageHandler {
 		iter: I,
 		max_weight: Weight,
 	) -> Weight;
+
+	/// The weight of handling the message `data` sent by `sender` with
+	/// [`Self::handle_xcmp_messages`].
+	///
+	/// Used to defer messages to later blocks instead of exceeding the weight of a block. Defaults
+	/// to zero.
+	fn handle_xcmp_message_weight(_sender: Id, _data: &[u8]) -> Weight {
+		Weight::zero()
+	}
 }
 impl XcmpMessageHandler for () {
 	fn handle_xcmp_messages<'a, I: Iterator<Item = (Id, RelayChainBlockNumber, &'a [u8])>>(
@@ -175,7 +175,7 @@ pub type ValidatorSignature = validator_app::Signature;
 
 /// A declarations of storage keys where an external observer can find some interesting data.
 pub mod well_known_keys {
-	use super::{HrmpChannelId, Id, WellKnownKey};
+	use super::{HrmpChannelId, Id, ValidationCodeHash, WellKnownKey};
 	use alloc::vec::Vec;
 	use codec::Encode as _;
 	use hex_literal::hex;
@@ -389,6 +389,41 @@ pub mod well_known_keys {
 				.collect()
 		})
 	}
+
+	/// The hash of the validation code the given para is going to upgrade to.
+	///
+	/// The entry is only present once the PVF pre-checking of the code passed and the upgrade was
+	/// scheduled. The storage entry stores a value of `ValidationCodeHash` type.
+	pub fn future_code_hash(para_id: Id) -> Vec<u8> {
+		let prefix = hex!["cd710b30bd2eab0352ddcc26417aa194cad3324113c312669f1ac3d50722524b"];
+
+		para_id.using_encoded(|para_id: &[u8]| {
+			prefix
+				.as_ref()
+				.iter()
+				.chain(twox_64(para_id).iter())
+				.chain(para_id.iter())
+				.cloned()
+				.collect()
+		})
+	}
+
+	/// The active PVF pre-checking vote for the given validation code.
+	///
+	/// The entry is present as long as the PVF pre-checking of the code is in progress.
+	pub fn pvf_active_vote(code_hash: ValidationCodeHash) -> Vec<u8> {
+		let prefix = hex!["cd710b30bd2eab0352ddcc26417aa194b2653c2bebd76bb707c1566780cefa08"];
+
+		code_hash.using_encoded(|code_hash: &[u8]| {
+			prefix
+				.as_ref()
+				.iter()
+				.chain(twox_64(code_hash).iter())
+				.chain(code_hash.iter())
+				.cloned()
+				.collect()
+		})
+	}
 }
 
 /// Unique identifier for the Parachains Inherent
@@ -781,6 +781,7 @@ mod tests {
 		type WeightInfo = ();
 		type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 		type BlockNumberProvider = System;
+		type RevokeOrigin = frame_system::EnsureRoot<Self::AccountId>;
 		const MAX_VESTING_SCHEDULES: u32 = 28;
 	}
 
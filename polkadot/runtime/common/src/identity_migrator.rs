@@ -36,6 +36,9 @@ use frame_benchmarking::{account, v2::*, BenchmarkError};
 pub trait WeightInfo {
 	fn reap_identity(r: u32, s: u32) -> Weight;
 	fn poke_deposit() -> Weight;
+	fn migrate_identity_to(r: u32, s: u32) -> Weight;
+	fn receive_identity(s: u32) -> Weight;
+	fn restore_identity(s: u32) -> Weight;
 }
 
 impl WeightInfo for () {
@@ -45,6 +48,15 @@ impl WeightInfo for () {
 	fn poke_deposit() -> Weight {
 		Weight::MAX
 	}
+	fn migrate_identity_to(_r: u32, _s: u32) -> Weight {
+		Weight::MAX
+	}
+	fn receive_identity(_s: u32) -> Weight {
+		Weight::MAX
+	}
+	fn restore_identity(_s: u32) -> Weight {
+		Weight::MAX
+	}
 }
 
 pub struct TestWeightInfo;
@@ -55,6 +67,15 @@ impl WeightInfo for TestWeightInfo {
 	fn poke_deposit() -> Weight {
 		Weight::zero()
 	}
+	fn migrate_identity_to(_r: u32, _s: u32) -> Weight {
+		Weight::zero()
+	}
+	fn receive_identity(_s: u32) -> Weight {
+		Weight::zero()
+	}
+	fn restore_identity(_s: u32) -> Weight {
+		Weight::zero()
+	}
 }
 
 // Must use the same `Balance` as `T`'s Identity pallet to handle deposits.
@@ -65,12 +86,14 @@ type BalanceOf<T> = <<T as pallet_identity::Config>::Currency as Currency<
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
+	use alloc::{boxed::Box, vec::Vec};
 	use frame_support::{
 		dispatch::{DispatchResultWithPostInfo, PostDispatchInfo},
 		pallet_prelude::*,
 		traits::EnsureOrigin,
 	};
 	use frame_system::pallet_prelude::*;
+	use pallet_identity::{Data, RegistrationOf};
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
@@ -87,10 +110,27 @@ pub mod pallet {
 		/// A handler for what to do when an identity is reaped.
 		type ReapIdentityHandler: OnReapIdentity<Self::AccountId>;
 
+		/// A handler for sending a migrated identity to the People Chain.
+		type MigrateIdentityHandler: OnMigrateIdentity<Self::AccountId, Call<Self>>;
+
+		/// The origin that can restore an identity whose migration failed on the People Chain.
+		type RestoreOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Weight information for the extrinsics in the pallet.
 		type WeightInfo: WeightInfo;
 	}
 
+	/// The identities and sub-accounts migrated away from this chain, kept so that they can be
+	/// restored with [`Call::restore_identity`] should storing them on the People Chain fail.
+	#[pallet::storage]
+	pub type MigratedIdentityOf<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		(RegistrationOf<T>, BoundedVec<(T::AccountId, Data), T::MaxSubAccounts>),
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -99,6 +139,20 @@ pub mod pallet {
 		/// The deposits held for `who` were updated. `identity` is the new deposit held for
 		/// identity info, and `subs` is the new deposit held for the sub-accounts.
 		DepositUpdated { who: T::AccountId, identity: BalanceOf<T>, subs: BalanceOf<T> },
+		/// The identity and all sub accounts of `who` were reaped and sent to the People Chain.
+		IdentityMigrated { who: T::AccountId },
+		/// The identity and sub accounts of `who` were received from another chain. `identity`
+		/// and `subs` are the deposits now held for them.
+		IdentityReceived { who: T::AccountId, identity: BalanceOf<T>, subs: BalanceOf<T> },
+		/// The migrated identity and sub accounts of `who` were restored on this chain.
+		/// `identity` and `subs` are the deposits now held for them.
+		IdentityRestored { who: T::AccountId, identity: BalanceOf<T>, subs: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account has no migrated identity to restore.
+		NotMigrated,
 	}
 
 	#[pallet::call]
@@ -136,7 +190,7 @@ pub mod pallet {
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::poke_deposit())]
 		pub fn poke_deposit(origin: OriginFor<T>, who: T::AccountId) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
-			let (id_deposit, subs_deposit) = pallet_identity::Pallet::<T>::poke_deposit(&who)?;
+			let (id_deposit, subs_deposit) = pallet_identity::Pallet::<T>::do_poke_deposit(&who)?;
 			Self::deposit_event(Event::DepositUpdated {
 				who,
 				identity: id_deposit,
@@ -144,6 +198,101 @@ pub mod pallet {
 			});
 			Ok(Pays::No.into())
 		}
+
+		/// Move the identity of the sender, along with its sub-accounts, to the People Chain.
+		///
+		/// The identity is reaped from this chain, releasing all deposits held for it, and handed
+		/// to the `MigrateIdentityHandler` as a `receive_identity` call to execute on the People
+		/// Chain. Judgements with a fee still paid are refunded here and dropped on the People
+		/// Chain. A copy of the identity is kept in [`MigratedIdentityOf`], from which
+		/// `RestoreOrigin` can restore it should the `receive_identity` call fail.
+		///
+		/// Frozen identities cannot be migrated.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::migrate_identity_to(
+				T::MaxRegistrars::get(),
+				T::MaxSubAccounts::get()
+		))]
+		pub fn migrate_identity_to(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let registration = pallet_identity::IdentityOf::<T>::get(&who)
+				.ok_or(pallet_identity::Error::<T>::NoIdentity)?;
+			ensure!(!registration.frozen, pallet_identity::Error::<T>::IdentityFrozen);
+			let subs = pallet_identity::Pallet::<T>::subs(&who);
+			let (registrars, bytes, subs_count) =
+				pallet_identity::Pallet::<T>::reap_identity(&who)?;
+			MigratedIdentityOf::<T>::insert(
+				&who,
+				(registration.clone(), BoundedVec::truncate_from(subs.clone())),
+			);
+			let call = Call::<T>::receive_identity {
+				who: who.clone(),
+				registration: Box::new(registration),
+				subs,
+			};
+			T::MigrateIdentityHandler::on_migrate_identity(&who, bytes, subs_count, call)?;
+			Self::deposit_event(Event::IdentityMigrated { who });
+			Ok(Some(<T as pallet::Config>::WeightInfo::migrate_identity_to(registrars, subs_count))
+				.into())
+		}
+
+		/// Store the identity and sub-accounts of `who` migrated from another chain, and reserve
+		/// the deposits for them. Meant to be called by the system with an XCM `Transact`
+		/// Instruction.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::receive_identity(
+				T::MaxSubAccounts::get()
+		))]
+		pub fn receive_identity(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			registration: Box<RegistrationOf<T>>,
+			subs: Vec<(T::AccountId, Data)>,
+		) -> DispatchResultWithPostInfo {
+			ensure_root(origin)?;
+			let subs_count = subs.len() as u32;
+			pallet_identity::Pallet::<T>::import_identity(&who, *registration, subs)?;
+			let (id_deposit, subs_deposit) = pallet_identity::Pallet::<T>::do_poke_deposit(&who)?;
+			Self::deposit_event(Event::IdentityReceived {
+				who,
+				identity: id_deposit,
+				subs: subs_deposit,
+			});
+			let post = PostDispatchInfo {
+				actual_weight: Some(<T as pallet::Config>::WeightInfo::receive_identity(
+					subs_count,
+				)),
+				pays_fee: Pays::No,
+			};
+			Ok(post)
+		}
+
+		/// Restore the identity and sub-accounts of `who` that were migrated with
+		/// [`Call::migrate_identity_to`] but could not be stored on the People Chain, and reserve
+		/// the deposits for them again.
+		///
+		/// The dispatch origin for this call must match `RestoreOrigin`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::restore_identity(
+				T::MaxSubAccounts::get()
+		))]
+		pub fn restore_identity(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::RestoreOrigin::ensure_origin(origin)?;
+			let (registration, subs) =
+				MigratedIdentityOf::<T>::take(&who).ok_or(Error::<T>::NotMigrated)?;
+			let subs_count = subs.len() as u32;
+			pallet_identity::Pallet::<T>::import_identity(&who, registration, subs.into_inner())?;
+			let (id_deposit, subs_deposit) = pallet_identity::Pallet::<T>::do_poke_deposit(&who)?;
+			Self::deposit_event(Event::IdentityRestored {
+				who,
+				identity: id_deposit,
+				subs: subs_deposit,
+			});
+			Ok(Some(<T as pallet::Config>::WeightInfo::restore_identity(subs_count)).into())
+		}
 	}
 }
 
@@ -168,6 +317,34 @@ impl<AccountId> OnReapIdentity<AccountId> for () {
 	}
 }
 
+/// Trait to handle sending a migrated identity to another chain.
+pub trait OnMigrateIdentity<AccountId, Call> {
+	/// What to do when an identity is migrated to the People Chain. The implementation is
+	/// expected to send the deposit needed to store the identity there, along with `call` to be
+	/// executed with a `Superuser` origin, e.g. through an XCM `Transact`.
+	///
+	/// `call` is encoded as a call of this pallet, so the destination must run this pallet with
+	/// identity types of the same encoding as this chain.
+	///
+	/// Inputs
+	/// - `who`: Whose identity was migrated.
+	/// - `bytes`: The byte size of `IdentityInfo`.
+	/// - `subs`: The number of sub-accounts they had.
+	/// - `call`: The `receive_identity` call storing the identity on the People Chain.
+	fn on_migrate_identity(who: &AccountId, bytes: u32, subs: u32, call: Call) -> DispatchResult;
+}
+
+impl<AccountId, Call> OnMigrateIdentity<AccountId, Call> for () {
+	fn on_migrate_identity(
+		_who: &AccountId,
+		_bytes: u32,
+		_subs: u32,
+		_call: Call,
+	) -> DispatchResult {
+		Ok(())
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 #[benchmarks]
 mod benchmarks {
@@ -176,9 +353,11 @@ mod benchmarks {
 	use codec::Encode;
 	use frame_support::traits::EnsureOrigin;
 	use frame_system::RawOrigin;
-	use pallet_identity::{Data, IdentityInformationProvider, Judgement, Pallet as Identity};
+	use pallet_identity::{
+		Data, IdentityInformationProvider, Judgement, Pallet as Identity, Registration,
+	};
 	use sp_runtime::{
-		traits::{Bounded, Hash, StaticLookup},
+		traits::{Bounded, Hash, StaticLookup, Zero},
 		Saturating,
 	};
 
@@ -191,11 +370,8 @@ mod benchmarks {
 		assert_eq!(event, &system_event);
 	}
 
-	#[benchmark]
-	fn reap_identity(
-		r: Linear<0, { T::MaxRegistrars::get() }>,
-		s: Linear<0, { T::MaxSubAccounts::get() }>,
-	) -> Result<(), BenchmarkError> {
+	/// Set up an identity with `s` sub accounts and judgements from `r` registrars.
+	fn create_identity<T: Config>(r: u32, s: u32) -> Result<T::AccountId, BenchmarkError> {
 		// set up target
 		let target: T::AccountId = account("target", 0, SEED);
 		let target_origin =
@@ -233,12 +409,17 @@ mod benchmarks {
 
 			// add registrar
 			Identity::<T>::add_registrar(registrar_origin.clone(), registrar_lookup)?;
-			Identity::<T>::set_fee(RawOrigin::Signed(registrar.clone()).into(), ii, 10u32.into())?;
+			Identity::<T>::set_fee(
+				RawOrigin::Signed(registrar.clone()).into(),
+				ii,
+				10u32.into(),
+				None,
+			)?;
 			let fields = <T as pallet_identity::Config>::IdentityInformation::all_fields();
 			Identity::<T>::set_fields(RawOrigin::Signed(registrar.clone()).into(), ii, fields)?;
 
 			// request and provide judgement
-			Identity::<T>::request_judgement(target_origin.clone(), ii, 10u32.into())?;
+			Identity::<T>::request_judgement(target_origin.clone(), ii, 10u32.into(), None, None)?;
 			Identity::<T>::provide_judgement(
 				RawOrigin::Signed(registrar).into(),
 				ii,
@@ -248,6 +429,15 @@ mod benchmarks {
 			)?;
 		}
 
+		Ok(target)
+	}
+
+	#[benchmark]
+	fn reap_identity(
+		r: Linear<0, { T::MaxRegistrars::get() }>,
+		s: Linear<0, { T::MaxSubAccounts::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let target = create_identity::<T>(r, s)?;
 		let origin = T::Reaper::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 
 		#[extrinsic_call]
@@ -298,6 +488,82 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn migrate_identity_to(
+		r: Linear<0, { T::MaxRegistrars::get() }>,
+		s: Linear<0, { T::MaxSubAccounts::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let target = create_identity::<T>(r, s)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(target.clone()));
+
+		assert_last_event::<T>(Event::<T>::IdentityMigrated { who: target.clone() }.into());
+		assert!(MigratedIdentityOf::<T>::contains_key(&target));
+
+		let fields = <T as pallet_identity::Config>::IdentityInformation::all_fields();
+		assert!(!Identity::<T>::has_identity(&target, fields));
+		assert_eq!(Identity::<T>::subs(&target).len(), 0);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn receive_identity(s: Linear<0, { T::MaxSubAccounts::get() }>) -> Result<(), BenchmarkError> {
+		let target: T::AccountId = account("target", 0, SEED);
+		let _ = T::Currency::make_free_balance_be(&target, BalanceOf::<T>::max_value());
+		let info = <T as pallet_identity::Config>::IdentityInformation::create_identity_info();
+		let registration = Registration {
+			judgements: Default::default(),
+			deposit: Zero::zero(),
+			frozen: false,
+			info: info.clone(),
+		};
+
+		let data = Data::Raw(vec![0; 32].try_into().unwrap());
+		let subs: Vec<_> = (0..s).map(|ii| (account("sub", ii, SEED), data.clone())).collect();
+
+		// expected deposits
+		let expected_id_deposit = <T as pallet_identity::Config>::BasicDeposit::get()
+			.saturating_add(
+				<T as pallet_identity::Config>::ByteDeposit::get()
+					.saturating_mul(<BalanceOf<T>>::from(info.encoded_size() as u32)),
+			);
+		let expected_sub_deposit = <T as pallet_identity::Config>::SubAccountDeposit::get()
+			.saturating_mul(<BalanceOf<T>>::from(s));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, target.clone(), Box::new(registration), subs);
+
+		assert_last_event::<T>(
+			Event::<T>::IdentityReceived {
+				who: target.clone(),
+				identity: expected_id_deposit,
+				subs: expected_sub_deposit,
+			}
+			.into(),
+		);
+		assert_eq!(Identity::<T>::subs(&target).len(), s as usize);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn restore_identity(s: Linear<0, { T::MaxSubAccounts::get() }>) -> Result<(), BenchmarkError> {
+		let target = create_identity::<T>(0, s)?;
+		Pallet::<T>::migrate_identity_to(RawOrigin::Signed(target.clone()).into())?;
+		let origin =
+			T::RestoreOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, target.clone());
+
+		assert!(!MigratedIdentityOf::<T>::contains_key(&target));
+		assert_eq!(Identity::<T>::subs(&target).len(), s as usize);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::integration_tests::new_test_ext(),
@@ -299,7 +299,7 @@ mod tests {
 	};
 	use frame_system::limits;
 	use polkadot_primitives::AccountId;
-	use sp_core::{ConstU64, H256};
+	use sp_core::{ConstU32, ConstU64, H256};
 	use sp_runtime::{
 		traits::{BlakeTwo256, IdentityLookup},
 		BuildStorage, Perbill,
@@ -387,6 +387,8 @@ mod tests {
 		type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
 		type BalanceConverter = UnityAssetBalanceConversion;
 		type PayoutPeriod = ConstU64<0>;
+		type MilestoneOrigin = frame_system::EnsureRoot<AccountId>;
+		type MaxMilestones = ConstU32<16>;
 		type BlockNumberProvider = System;
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper = ();
@@ -285,14 +285,20 @@ impl crowdloan::Config for Test {
 impl pallet_identity::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type DepositTracker = ();
+	type DepositAsset = ();
 	type Slashed = ();
+	type SlashPortion = ();
 	type BasicDeposit = ConstU32<100>;
 	type ByteDeposit = ConstU32<10>;
 	type UsernameDeposit = ConstU32<10>;
 	type SubAccountDeposit = ConstU32<100>;
+	type PokeDepositReward = ();
 	type MaxSubAccounts = ConstU32<2>;
+	type MaxSubDepth = ConstU32<1>;
 	type IdentityInformation = IdentityInfo<ConstU32<2>>;
 	type MaxRegistrars = ConstU32<20>;
+	type MaxFeeTiers = ConstU32<8>;
 	type RegistrarOrigin = EnsureRoot<AccountId>;
 	type ForceOrigin = EnsureRoot<AccountId>;
 	type OffchainSignature = MultiSignature;
@@ -302,6 +308,9 @@ impl pallet_identity::Config for Test {
 	type UsernameGracePeriod = ConstU32<10>;
 	type MaxSuffixLength = ConstU32<7>;
 	type MaxUsernameLength = ConstU32<32>;
+	type OnIdentityChange = ();
+	type AttesterId = pallet_identity::offchain::crypto::Public;
+	type SubmitAttestation = ();
 	type WeightInfo = ();
 }
 
@@ -309,6 +318,8 @@ impl identity_migrator::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Reaper = EnsureRoot<AccountId>;
 	type ReapIdentityHandler = ();
+	type MigrateIdentityHandler = ();
+	type RestoreOrigin = EnsureRoot<AccountId>;
 	type WeightInfo = crate::identity_migrator::TestWeightInfo;
 }
 
@@ -394,6 +394,7 @@ impl pallet_message_queue::Config for Test {
 	type MessageProcessor = TestProcessMessage;
 	type QueueChangeHandler = ParaInclusion;
 	type QueuePausedQuery = ();
+	type ServiceWeightShare = ();
 	type HeapSize = ConstU32<65536>;
 	type MaxStale = ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
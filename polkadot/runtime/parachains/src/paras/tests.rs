@@ -1843,6 +1843,35 @@ fn verify_upgrade_restriction_signal_is_externally_accessible() {
 	});
 }
 
+#[test]
+fn verify_future_code_hash_is_externally_accessible() {
+	use polkadot_primitives::well_known_keys;
+
+	let a = ParaId::from(2020);
+	let code_hash = ValidationCode(vec![1, 2, 3]).hash();
+
+	new_test_ext(Default::default()).execute_with(|| {
+		assert!(sp_io::storage::get(&well_known_keys::future_code_hash(a)).is_none());
+		FutureCodeHash::<Test>::insert(&a, code_hash);
+		assert_eq!(
+			sp_io::storage::get(&well_known_keys::future_code_hash(a)).unwrap(),
+			code_hash.encode(),
+		);
+	});
+}
+
+#[test]
+fn verify_pvf_active_vote_key_is_correct() {
+	use polkadot_primitives::well_known_keys;
+
+	let code_hash = ValidationCode(vec![1, 2, 3]).hash();
+
+	assert_eq!(
+		well_known_keys::pvf_active_vote(code_hash),
+		PvfActiveVoteMap::<Test>::hashed_key_for(&code_hash),
+	);
+}
+
 #[test]
 fn verify_para_head_is_externally_accessible() {
 	use polkadot_primitives::well_known_keys;
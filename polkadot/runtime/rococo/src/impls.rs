@@ -21,8 +21,8 @@ use core::marker::PhantomData;
 use frame_support::pallet_prelude::DispatchResult;
 use frame_system::RawOrigin;
 use polkadot_primitives::Balance;
-use polkadot_runtime_common::identity_migrator::OnReapIdentity;
-use rococo_runtime_constants::currency::*;
+use polkadot_runtime_common::identity_migrator::{OnMigrateIdentity, OnReapIdentity};
+use rococo_runtime_constants::{currency::*, system_parachain::PEOPLE_ID};
 use xcm::{latest::prelude::*, VersionedLocation, VersionedXcm};
 use xcm_executor::traits::TransactAsset;
 
@@ -30,9 +30,9 @@ use xcm_executor::traits::TransactAsset;
 /// remote calls. The codec index must correspond to the index of `IdentityMigrator` in the
 /// `construct_runtime` of the remote chain.
 #[derive(Encode, Decode)]
-enum PeopleRuntimePallets<AccountId: Encode> {
+enum PeopleRuntimePallets<Call: Encode> {
 	#[codec(index = 248)]
-	IdentityMigrator(IdentityMigratorCalls<AccountId>),
+	IdentityMigrator(Call),
 }
 
 /// Call encoding for the calls needed from the Identity Migrator pallet.
@@ -80,22 +80,24 @@ impl<Runtime, AccountId> ToParachainIdentityReaper<Runtime, AccountId> {
 	}
 }
 
-// Note / Warning: This implementation should only be used in a transactional context. If not, then
-// an error could result in assets being burned.
-impl<Runtime, AccountId> OnReapIdentity<AccountId> for ToParachainIdentityReaper<Runtime, AccountId>
+impl<Runtime, AccountId> ToParachainIdentityReaper<Runtime, AccountId>
 where
 	Runtime: frame_system::Config + pallet_xcm::Config,
 	AccountId: Into<[u8; 32]> + Clone + Encode,
 {
-	fn on_reap_identity(who: &AccountId, fields: u32, subs: u32) -> DispatchResult {
-		use crate::impls::IdentityMigratorCalls::PokeDeposit;
-
-		let total_to_send = Self::calculate_remote_deposit(fields, subs);
+	/// Teleport the deposit needed for an identity of `bytes` and `subs` from `who` to the People
+	/// Chain, and execute `call` there as `Superuser`.
+	fn send_with_deposit(
+		who: &AccountId,
+		bytes: u32,
+		subs: u32,
+		call: impl Encode,
+	) -> DispatchResult {
+		let total_to_send = Self::calculate_remote_deposit(bytes, subs);
 
 		// define asset / destination from relay perspective
 		let roc = Asset { id: AssetId(Here.into_location()), fun: Fungible(total_to_send) };
-		// People Chain: ParaId 1004
-		let destination: Location = Location::new(0, Parachain(1004));
+		let destination: Location = Location::new(0, Parachain(PEOPLE_ID));
 
 		// Do `check_out` accounting since the XCM Executor's `InitiateTeleport` doesn't support
 		// unpaid teleports.
@@ -140,8 +142,6 @@ where
 			vec![Asset { id: AssetId(Location::new(1, Here)), fun: Fungible(total_to_send) }]
 				.into();
 
-		let poke = PeopleRuntimePallets::<AccountId>::IdentityMigrator(PokeDeposit(who.clone()));
-
 		// Actual program to execute on People Chain.
 		let program: Xcm<()> = Xcm(vec![
 			// Unpaid as this is constructed by the system, once per user. The user shouldn't have
@@ -156,8 +156,8 @@ where
 					.into_location()
 					.into(),
 			},
-			// Poke the deposit to reserve the appropriate amount on the parachain.
-			Transact { origin_kind: OriginKind::Superuser, call: poke.encode().into() },
+			// Execute `call`, which reserves the appropriate deposit on the parachain.
+			Transact { origin_kind: OriginKind::Superuser, call: call.encode().into() },
 		]);
 
 		// send
@@ -169,3 +169,34 @@ where
 		Ok(())
 	}
 }
+
+// Note / Warning: This implementation should only be used in a transactional context. If not, then
+// an error could result in assets being burned.
+impl<Runtime, AccountId> OnReapIdentity<AccountId> for ToParachainIdentityReaper<Runtime, AccountId>
+where
+	Runtime: frame_system::Config + pallet_xcm::Config,
+	AccountId: Into<[u8; 32]> + Clone + Encode,
+{
+	fn on_reap_identity(who: &AccountId, fields: u32, subs: u32) -> DispatchResult {
+		use crate::impls::IdentityMigratorCalls::PokeDeposit;
+
+		let poke = PeopleRuntimePallets::IdentityMigrator(PokeDeposit(who.clone()));
+		Self::send_with_deposit(who, fields, subs, poke)
+	}
+}
+
+// Note / Warning: This implementation should only be used in a transactional context. If not, then
+// an error could result in assets being burned.
+impl<Runtime, AccountId, Call> OnMigrateIdentity<AccountId, Call>
+	for ToParachainIdentityReaper<Runtime, AccountId>
+where
+	Runtime: frame_system::Config + pallet_xcm::Config,
+	AccountId: Into<[u8; 32]> + Clone + Encode,
+	Call: Encode,
+{
+	fn on_migrate_identity(who: &AccountId, bytes: u32, subs: u32, call: Call) -> DispatchResult {
+		// Store the identity on the People Chain, and reserve its deposit there.
+		let receive = PeopleRuntimePallets::IdentityMigrator(call);
+		Self::send_with_deposit(who, bytes, subs, receive)
+	}
+}
@@ -232,6 +232,7 @@ parameter_types! {
 		BlockWeights::get().max_block;
 	pub const MaxScheduledPerBlock: u32 = 50;
 	pub const NoPreimagePostponement: Option<u32> = Some(10);
+	pub const TimestampBucketSize: Moment = 10 * MILLISECS_PER_BLOCK;
 }
 
 /// Used the compare the privilege of an origin inside the scheduler.
@@ -331,6 +332,9 @@ impl pallet_scheduler::Config for Runtime {
 	type WeightInfo = weights::pallet_scheduler::WeightInfo<Runtime>;
 	type OriginPrivilegeCmp = OriginPrivilegeCmp;
 	type Preimages = Preimage;
+	type TimeProvider = Timestamp;
+	type TimestampTolerance = ExpectedBlockTime;
+	type TimestampBucketSize = TimestampBucketSize;
 }
 
 parameter_types! {
@@ -543,6 +547,8 @@ impl pallet_treasury::Config for Runtime {
 		AssetRate,
 	>;
 	type PayoutPeriod = PayoutSpendPeriod;
+	type MilestoneOrigin = EitherOfDiverse<EnsureRoot<AccountId>, Treasurer>;
+	type MaxMilestones = ConstU32<16>;
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments;
@@ -584,6 +590,8 @@ impl pallet_child_bounties::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxActiveChildBountyCount = MaxActiveChildBountyCount;
 	type ChildBountyValueMinimum = ChildBountyValueMinimum;
+	type MaxSubChildBountyCount = ConstU32<10>;
+	type MaxPartialPayouts = ConstU32<10>;
 	type WeightInfo = weights::pallet_child_bounties::WeightInfo<Runtime>;
 }
 
@@ -591,6 +599,8 @@ impl pallet_offences::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
 	type OnOffenceHandler = ();
+	type SessionEra = ();
+	type MaxOffenceHistory = ConstU32<1_000>;
 }
 
 impl pallet_authority_discovery::Config for Runtime {
@@ -719,19 +729,26 @@ parameter_types! {
 	pub const MaxSubAccounts: u32 = 100;
 	pub const MaxAdditionalFields: u32 = 100;
 	pub const MaxRegistrars: u32 = 20;
+	pub const SlashPortion: Perbill = Perbill::from_percent(90);
 }
 
 impl pallet_identity::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type DepositTracker = ();
+	type DepositAsset = ();
 	type BasicDeposit = BasicDeposit;
 	type ByteDeposit = ByteDeposit;
 	type UsernameDeposit = UsernameDeposit;
 	type SubAccountDeposit = SubAccountDeposit;
+	type PokeDepositReward = ();
 	type MaxSubAccounts = MaxSubAccounts;
+	type MaxSubDepth = ConstU32<1>;
 	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
 	type MaxRegistrars = MaxRegistrars;
+	type MaxFeeTiers = ConstU32<8>;
 	type Slashed = Treasury;
+	type SlashPortion = SlashPortion;
 	type ForceOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
 	type RegistrarOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
 	type OffchainSignature = Signature;
@@ -741,6 +758,9 @@ impl pallet_identity::Config for Runtime {
 	type UsernameGracePeriod = ConstU32<{ 30 * DAYS }>;
 	type MaxSuffixLength = ConstU32<7>;
 	type MaxUsernameLength = ConstU32<32>;
+	type OnIdentityChange = ();
+	type AttesterId = pallet_identity::offchain::crypto::Public;
+	type SubmitAttestation = ();
 	type WeightInfo = weights::pallet_identity::WeightInfo<Runtime>;
 }
 
@@ -766,6 +786,7 @@ impl pallet_multisig::Config for Runtime {
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = MaxSignatories;
+	type MaxCallSize = ConstU32<4096>;
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 }
 
@@ -791,6 +812,22 @@ parameter_types! {
 	pub const SocietyPalletId: PalletId = PalletId(*b"py/socie");
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+pub struct SocietyBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_society::BenchmarkHelper<Signature, AccountId> for SocietyBenchmarkHelper {
+	fn create_attestation(entropy: &[u8], msg: &[u8]) -> (Signature, AccountId) {
+		use sp_core::hexdisplay::HexDisplay;
+		use sp_io::crypto::{sr25519_generate, sr25519_sign};
+		use sp_runtime::{traits::IdentifyAccount, MultiSignature, MultiSigner};
+		let seed = alloc::format!("//SocietyAttester{}", HexDisplay::from(&entropy));
+		let public = sr25519_generate(0.into(), Some(seed.into_bytes()));
+		let who: AccountId = MultiSigner::Sr25519(public).into_account();
+		let signature = MultiSignature::Sr25519(sr25519_sign(0.into(), &public, msg).unwrap());
+		(signature, who)
+	}
+}
+
 impl pallet_society::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -805,7 +842,14 @@ impl pallet_society::Config for Runtime {
 	type MaxPayouts = ConstU32<8>;
 	type MaxBids = ConstU32<512>;
 	type PalletId = SocietyPalletId;
+	type AttestationSignature = Signature;
+	type AttestationSigner = <Signature as Verify>::Signer;
+	type RequiredAttestations = ConstU32<3>;
+	type MaxAttestations = ConstU32<8>;
+	type MaxAttestedIntake = ConstU32<4>;
 	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = SocietyBenchmarkHelper;
 }
 
 parameter_types! {
@@ -822,6 +866,7 @@ impl pallet_vesting::Config for Runtime {
 	type WeightInfo = weights::pallet_vesting::WeightInfo<Runtime>;
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 	type BlockNumberProvider = System;
+	type RevokeOrigin = EnsureRoot<AccountId>;
 	const MAX_VESTING_SCHEDULES: u32 = 28;
 }
 
@@ -833,6 +878,7 @@ parameter_types! {
 	pub const MaxProxies: u16 = 32;
 	pub const AnnouncementDepositBase: Balance = deposit(1, 8);
 	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	pub const CallPreviewDepositPerByte: Balance = deposit(0, 1);
 	pub const MaxPending: u16 = 32;
 }
 
@@ -971,6 +1017,8 @@ impl pallet_proxy::Config for Runtime {
 	type CallHasher = BlakeTwo256;
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+	type MaxCallPreviewLen = ConstU32<1024>;
+	type CallPreviewDepositPerByte = CallPreviewDepositPerByte;
 }
 
 impl parachains_origin::Config for Runtime {}
@@ -1023,6 +1071,9 @@ parameter_types! {
 	///
 	/// This is not a good value for para-chains since the `Scheduler` already uses up to 80% block weight.
 	pub MessageQueueServiceWeight: Weight = Perbill::from_percent(20) * BlockWeights::get().max_block;
+	/// The share of the message queue service weight that a single para may use while others
+	/// are waiting.
+	pub const MessageQueueServiceShare: Perbill = Perbill::from_percent(25);
 	pub const MessageQueueHeapSize: u32 = 32 * 1024;
 	pub const MessageQueueMaxStale: u32 = 96;
 }
@@ -1063,6 +1114,8 @@ impl pallet_message_queue::Config for Runtime {
 		pallet_message_queue::mock_helpers::NoopMessageProcessor<AggregateMessageOrigin>;
 	type QueueChangeHandler = ParaInclusion;
 	type QueuePausedQuery = ();
+	type ServiceWeightShare =
+		frame_support::traits::ConstServiceWeightShare<MessageQueueServiceShare>;
 	type WeightInfo = weights::pallet_message_queue::WeightInfo<Runtime>;
 }
 
@@ -1244,6 +1297,8 @@ impl identity_migrator::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Reaper = EnsureSigned<AccountId>;
 	type ReapIdentityHandler = ToParachainIdentityReaper<Runtime, Self::AccountId>;
+	type MigrateIdentityHandler = ToParachainIdentityReaper<Runtime, Self::AccountId>;
+	type RestoreOrigin = EnsureRoot<AccountId>;
 	type WeightInfo = weights::polkadot_runtime_common_identity_migrator::WeightInfo<Runtime>;
 }
 
@@ -1403,7 +1458,12 @@ parameter_types! {
 impl pallet_migrations::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type Migrations = pallet_identity::migration::v2::LazyMigrationV1ToV2<Runtime>;
+	type Migrations = (
+		pallet_identity::migration::v2::LazyMigrationV1ToV2<Runtime>,
+		pallet_identity::migration::v4::LazyMigrationV3ToV4<Runtime>,
+		pallet_conviction_voting::migration::LazyMigrationV0ToV1<Runtime>,
+		pallet_vesting::migrations::v2::LazyMigrationV1ToV2<Runtime>,
+	);
 	// Benchmarks need mocked migrations to guarantee that they succeed.
 	#[cfg(feature = "runtime-benchmarks")]
 	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
@@ -1419,6 +1479,9 @@ impl pallet_sudo::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type WeightInfo = weights::pallet_sudo::WeightInfo<Runtime>;
+	type SensitiveCalls = frame_support::traits::Nothing;
+	type NestedCalls = (Utility, Proxy, Multisig);
+	type MaxLogLength = ConstU32<0>;
 }
 
 impl pallet_root_testing::Config for Runtime {
@@ -1742,6 +1805,7 @@ pub mod migrations {
         parachains_inclusion::migration::MigrateToV1<Runtime>,
 		parachains_shared::migration::MigrateToV1<Runtime>,
         parachains_scheduler::migration::MigrateV2ToV3<Runtime>,
+        pallet_identity::migration::versioned::V2ToV3<Runtime>,
     );
 }
 
@@ -2361,6 +2425,16 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_message_queue::MessageQueueApi<Block, AggregateMessageOrigin> for Runtime {
+		fn starvation_count(origin: AggregateMessageOrigin) -> u32 {
+			pallet_message_queue::StarvationCount::<Runtime>::get(origin)
+		}
+
+		fn starvation_counts() -> Vec<(AggregateMessageOrigin, u32)> {
+			MessageQueue::starvation_counts()
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
@@ -21,6 +21,12 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-bn-ce5rx-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("rococo-dev")`, DB CACHE: 1024
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `propose_curator`, `award_child_bounty`, `claim_child_bounty`,
+//! `close_child_bounty_added`, `close_child_bounty_active`, `add_sub_child_bounty`,
+//! `award_partial_payout` and `claim_partial_payouts`. Re-run the benchmarks before relying on
+//! them.
 
 // Executed Command:
 // ./target/production/polkadot
@@ -81,6 +87,8 @@ impl<T: frame_system::Config> pallet_child_bounties::WeightInfo for WeightInfo<T
 	/// Proof: `ChildBounties::ChildBounties` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
 	/// Storage: `ChildBounties::ChildrenCuratorFees` (r:1 w:1)
 	/// Proof: `ChildBounties::ChildrenCuratorFees` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBountyParents` (r:1 w:0)
+	/// Proof: `ChildBounties::ChildBountyParents` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	fn propose_curator() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `594`
@@ -88,7 +96,7 @@ impl<T: frame_system::Config> pallet_child_bounties::WeightInfo for WeightInfo<T
 		// Minimum execution time: 17_527_000 picoseconds.
 		Weight::from_parts(18_257_000, 0)
 			.saturating_add(Weight::from_parts(0, 3642))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Bounties::Bounties` (r:1 w:0)
@@ -127,6 +135,8 @@ impl<T: frame_system::Config> pallet_child_bounties::WeightInfo for WeightInfo<T
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
 	/// Storage: `ChildBounties::ChildBounties` (r:1 w:1)
 	/// Proof: `ChildBounties::ChildBounties` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::SubChildBounties` (r:1 w:0)
+	/// Proof: `ChildBounties::SubChildBounties` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
 	fn award_child_bounty() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `637`
@@ -134,7 +144,7 @@ impl<T: frame_system::Config> pallet_child_bounties::WeightInfo for WeightInfo<T
 		// Minimum execution time: 18_616_000 picoseconds.
 		Weight::from_parts(19_316_000, 0)
 			.saturating_add(Weight::from_parts(0, 3642))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `ChildBounties::ChildBounties` (r:1 w:1)
@@ -145,6 +155,10 @@ impl<T: frame_system::Config> pallet_child_bounties::WeightInfo for WeightInfo<T
 	/// Proof: `ChildBounties::ParentChildBounties` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
 	/// Storage: `ChildBounties::ChildBountyDescriptions` (r:0 w:1)
 	/// Proof: `ChildBounties::ChildBountyDescriptions` (`max_values`: None, `max_size`: Some(16400), added: 18875, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::PartialPayouts` (r:1 w:0)
+	/// Proof: `ChildBounties::PartialPayouts` (`max_values`: None, `max_size`: Some(545), added: 3020, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBountyParents` (r:1 w:1)
+	/// Proof: `ChildBounties::ChildBountyParents` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	fn claim_child_bounty() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `576`
@@ -152,8 +166,8 @@ impl<T: frame_system::Config> pallet_child_bounties::WeightInfo for WeightInfo<T
 		// Minimum execution time: 96_376_000 picoseconds.
 		Weight::from_parts(98_476_000, 0)
 			.saturating_add(Weight::from_parts(0, 8799))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Bounties::Bounties` (r:1 w:0)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
@@ -167,6 +181,12 @@ impl<T: frame_system::Config> pallet_child_bounties::WeightInfo for WeightInfo<T
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ChildBounties::ChildBountyDescriptions` (r:0 w:1)
 	/// Proof: `ChildBounties::ChildBountyDescriptions` (`max_values`: None, `max_size`: Some(16400), added: 18875, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::PartialPayouts` (r:1 w:0)
+	/// Proof: `ChildBounties::PartialPayouts` (`max_values`: None, `max_size`: Some(545), added: 3020, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::SubChildBounties` (r:1 w:0)
+	/// Proof: `ChildBounties::SubChildBounties` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBountyParents` (r:1 w:1)
+	/// Proof: `ChildBounties::ChildBountyParents` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	fn close_child_bounty_added() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `840`
@@ -174,8 +194,8 @@ impl<T: frame_system::Config> pallet_child_bounties::WeightInfo for WeightInfo<T
 		// Minimum execution time: 64_640_000 picoseconds.
 		Weight::from_parts(66_174_000, 0)
 			.saturating_add(Weight::from_parts(0, 6196))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Bounties::Bounties` (r:1 w:0)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
@@ -189,6 +209,12 @@ impl<T: frame_system::Config> pallet_child_bounties::WeightInfo for WeightInfo<T
 	/// Proof: `ChildBounties::ParentChildBounties` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
 	/// Storage: `ChildBounties::ChildBountyDescriptions` (r:0 w:1)
 	/// Proof: `ChildBounties::ChildBountyDescriptions` (`max_values`: None, `max_size`: Some(16400), added: 18875, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::PartialPayouts` (r:1 w:0)
+	/// Proof: `ChildBounties::PartialPayouts` (`max_values`: None, `max_size`: Some(545), added: 3020, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::SubChildBounties` (r:1 w:0)
+	/// Proof: `ChildBounties::SubChildBounties` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBountyParents` (r:1 w:1)
+	/// Proof: `ChildBounties::ChildBountyParents` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	fn close_child_bounty_active() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1027`
@@ -196,7 +222,76 @@ impl<T: frame_system::Config> pallet_child_bounties::WeightInfo for WeightInfo<T
 		// Minimum execution time: 78_159_000 picoseconds.
 		Weight::from_parts(79_820_000, 0)
 			.saturating_add(Weight::from_parts(0, 8799))
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `ChildBounties::ParentChildBounties` (r:1 w:1)
+	/// Proof: `ChildBounties::ParentChildBounties` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBountyParents` (r:1 w:1)
+	/// Proof: `ChildBounties::ChildBountyParents` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::Bounties` (r:1 w:0)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBounties` (r:1 w:1)
+	/// Proof: `ChildBounties::ChildBounties` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::PartialPayouts` (r:1 w:0)
+	/// Proof: `ChildBounties::PartialPayouts` (`max_values`: None, `max_size`: Some(545), added: 3020, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::SubChildBounties` (r:1 w:1)
+	/// Proof: `ChildBounties::SubChildBounties` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBountyCount` (r:1 w:1)
+	/// Proof: `ChildBounties::ChildBountyCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBountyDescriptions` (r:0 w:1)
+	/// Proof: `ChildBounties::ChildBountyDescriptions` (`max_values`: None, `max_size`: Some(16400), added: 18875, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[0, 300]`.
+	fn add_sub_child_bounty(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1291`
+		//  Estimated: `6196`
+		// Minimum execution time: 68_411_000 picoseconds.
+		Weight::from_parts(70_255_083, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 97
+			.saturating_add(Weight::from_parts(702, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:0)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBounties` (r:1 w:0)
+	/// Proof: `ChildBounties::ChildBounties` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::PartialPayouts` (r:1 w:1)
+	/// Proof: `ChildBounties::PartialPayouts` (`max_values`: None, `max_size`: Some(545), added: 3020, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn award_partial_payout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187`
+		//  Estimated: `4010`
+		// Minimum execution time: 23_980_000 picoseconds.
+		Weight::from_parts(24_871_000, 0)
+			.saturating_add(Weight::from_parts(0, 4010))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `ChildBounties::PartialPayouts` (r:1 w:1)
+	/// Proof: `ChildBounties::PartialPayouts` (`max_values`: None, `max_size`: Some(545), added: 3020, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:11 w:11)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 10]`.
+	fn claim_partial_payouts(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1018`
+		//  Estimated: `4010`
+		// Minimum execution time: 29_305_000 picoseconds.
+		Weight::from_parts(2_318_906, 0)
+			.saturating_add(Weight::from_parts(0, 4010))
+			// Standard Error: 19_034
+			.saturating_add(Weight::from_parts(28_772_016, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(p.into()))
 	}
 }
@@ -21,6 +21,13 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-bn-ce5rx-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("rococo-dev")`, DB CACHE: 1024
+//!
+//! NOTE: `set_subs_diff` was written by hand and has not been measured with the benchmark CLI yet.
+//! Its execution times are carried over from the former `set_subs_new` and `set_subs_old`
+//! benchmarks and its storage accesses were counted by hand. `migration_v4_registrars_step`,
+//! `migration_v4_identity_step` and `held_deposits_step` were written by hand as well. The weights
+//! that return `Weight::zero()` are placeholders for calls added since this file was generated. Re-
+//! run the benchmarks before relying on them.
 
 // Executed Command:
 // ./target/production/polkadot
@@ -84,43 +91,31 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::SubsOf` (r:1 w:1)
 	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
-	/// Storage: `Identity::SuperOf` (r:100 w:100)
+	/// Storage: `Identity::SuperOf` (r:100 w:200)
 	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
-	/// The range of component `s` is `[0, 100]`.
-	fn set_subs_new(s: u32, ) -> Weight {
+	/// Storage: `Identity::SubDepositOf` (r:200 w:100)
+	/// Proof: `Identity::SubDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[0, 100]`.
+	/// The range of component `r` is `[0, 100]`.
+	fn set_subs_diff(a: u32, r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `101`
-		//  Estimated: `11037 + s * (2589 ±0)`
+		//  Measured:  `194 + r * (32 ±0)`
+		//  Estimated: `11037 + a * (2589 ±0) + r * (2539 ±0)`
 		// Minimum execution time: 8_010_000 picoseconds.
 		Weight::from_parts(19_868_412, 0)
 			.saturating_add(Weight::from_parts(0, 11037))
 			// Standard Error: 5_018
-			.saturating_add(Weight::from_parts(3_115_007, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
-			.saturating_add(T::DbWeight::get().writes(1))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
-			.saturating_add(Weight::from_parts(0, 2589).saturating_mul(s.into()))
-	}
-	/// Storage: `Identity::IdentityOf` (r:1 w:0)
-	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
-	/// Storage: `Identity::SubsOf` (r:1 w:1)
-	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
-	/// Storage: `Identity::SuperOf` (r:0 w:100)
-	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
-	/// The range of component `p` is `[0, 100]`.
-	fn set_subs_old(p: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `194 + p * (32 ±0)`
-		//  Estimated: `11037`
-		// Minimum execution time: 8_111_000 picoseconds.
-		Weight::from_parts(19_482_392, 0)
-			.saturating_add(Weight::from_parts(0, 11037))
+			.saturating_add(Weight::from_parts(3_115_007, 0).saturating_mul(a.into()))
 			// Standard Error: 3_156
-			.saturating_add(Weight::from_parts(1_305_890, 0).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(1_305_890, 0).saturating_mul(r.into()))
 			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(r.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
-			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(r.into())))
+			.saturating_add(Weight::from_parts(0, 2589).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(r.into()))
 	}
 	/// Storage: `Identity::SubsOf` (r:1 w:1)
 	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
@@ -128,6 +123,12 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::SuperOf` (r:0 w:100)
 	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::NameOf` (r:1 w:1)
+	/// Proof: `Identity::NameOf` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AccountOfName` (r:0 w:1)
+	/// Proof: `Identity::AccountOfName` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ManagerOf` (r:0 w:1)
+	/// Proof: `Identity::ManagerOf` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	/// The range of component `s` is `[0, 100]`.
 	fn clear_identity(r: u32, s: u32, ) -> Weight {
@@ -141,14 +142,18 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(191_321, 0).saturating_mul(r.into()))
 			// Standard Error: 2_135
 			.saturating_add(Weight::from_parts(1_295_872, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
 	}
 	/// Storage: `Identity::Registrars` (r:1 w:0)
 	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::IdentityOf` (r:1 w:1)
 	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingRequestsOf` (r:1 w:1)
+	/// Proof: `Identity::PendingRequestsOf` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RequestDeadlineOf` (r:0 w:1)
+	/// Proof: `Identity::RequestDeadlineOf` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	fn request_judgement(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -159,11 +164,15 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 11037))
 			// Standard Error: 5_456
 			.saturating_add(Weight::from_parts(135_316, 0).saturating_mul(r.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Identity::IdentityOf` (r:1 w:1)
 	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingRequestsOf` (r:1 w:1)
+	/// Proof: `Identity::PendingRequestsOf` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RequestDeadlineOf` (r:0 w:1)
+	/// Proof: `Identity::RequestDeadlineOf` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	fn cancel_request(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -174,8 +183,8 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 11037))
 			// Standard Error: 3_598
 			.saturating_add(Weight::from_parts(84_593, 0).saturating_mul(r.into()))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Identity::Registrars` (r:1 w:1)
 	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
@@ -226,6 +235,10 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::IdentityOf` (r:1 w:1)
 	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7572), added: 10047, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingRequestsOf` (r:1 w:1)
+	/// Proof: `Identity::PendingRequestsOf` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RequestDeadlineOf` (r:0 w:1)
+	/// Proof: `Identity::RequestDeadlineOf` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 19]`.
 	fn provide_judgement(r: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -236,8 +249,8 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 11037))
 			// Standard Error: 6_152
 			.saturating_add(Weight::from_parts(58_906, 0).saturating_mul(r.into()))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Identity::SubsOf` (r:1 w:1)
 	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
@@ -247,6 +260,12 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Identity::SuperOf` (r:0 w:100)
 	/// Proof: `Identity::SuperOf` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::NameOf` (r:1 w:1)
+	/// Proof: `Identity::NameOf` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AccountOfName` (r:0 w:1)
+	/// Proof: `Identity::AccountOfName` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::ManagerOf` (r:0 w:1)
+	/// Proof: `Identity::ManagerOf` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
 	/// The range of component `r` is `[1, 20]`.
 	/// The range of component `s` is `[0, 100]`.
 	fn kill_identity(r: u32, s: u32, ) -> Weight {
@@ -260,8 +279,8 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(135_044, 0).saturating_mul(r.into()))
 			// Standard Error: 1_944
 			.saturating_add(Weight::from_parts(1_388_151, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(6))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
 	}
 	/// Storage: `Identity::IdentityOf` (r:1 w:0)
@@ -430,6 +449,69 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 	fn kill_username(_p: u32, ) -> Weight {
 		Weight::zero()
 	}
+	fn claim_name() -> Weight {
+		Weight::zero()
+	}
+	fn release_name() -> Weight {
+		Weight::zero()
+	}
+	fn authorize_manager() -> Weight {
+		Weight::zero()
+	}
+	fn revoke_manager() -> Weight {
+		Weight::zero()
+	}
+	fn poke_deposit() -> Weight {
+		Weight::zero()
+	}
+	fn invite_sub() -> Weight {
+		Weight::zero()
+	}
+	fn add_sub_with_own_deposit(_s: u32, ) -> Weight {
+		Weight::zero()
+	}
+	fn provide_judgement_with_evidence(_r: u32, ) -> Weight {
+		Weight::zero()
+	}
+	fn dispute_evidence() -> Weight {
+		Weight::zero()
+	}
+	fn transfer_identity(_r: u32, _s: u32, ) -> Weight {
+		Weight::zero()
+	}
+	fn set_attester(_r: u32, ) -> Weight {
+		Weight::zero()
+	}
+	fn set_challenge(_r: u32, ) -> Weight {
+		Weight::zero()
+	}
+	fn remove_challenge(_r: u32, ) -> Weight {
+		Weight::zero()
+	}
+	fn submit_attestation() -> Weight {
+		Weight::zero()
+	}
+	fn set_max_pending(_r: u32, ) -> Weight {
+		Weight::zero()
+	}
+	fn freeze_identity(_r: u32, ) -> Weight {
+		Weight::zero()
+	}
+	fn thaw_identity(_r: u32, ) -> Weight {
+		Weight::zero()
+	}
+	fn remove_registrar(_r: u32, _e: u32, ) -> Weight {
+		Weight::zero()
+	}
+	fn reclaim_fee(_r: u32, ) -> Weight {
+		Weight::zero()
+	}
+	fn expire_request(_r: u32, ) -> Weight {
+		Weight::zero()
+	}
+	fn cancel_sub_invite(_s: u32, ) -> Weight {
+		Weight::zero()
+	}
 	fn migration_v2_authority_step() -> Weight {
 		Weight::zero()
 	}
@@ -448,4 +530,55 @@ impl<T: frame_system::Config> pallet_identity::WeightInfo for WeightInfo<T> {
 	fn migration_v2_cleanup_username_step() -> Weight {
 		Weight::zero()
 	}
+	/// Storage: `Identity::Registrars` (r:1 w:1)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(1141), added: 1636, mode: `MaxEncodedLen`)
+	fn migration_v4_registrars_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1172`
+		//  Estimated: `2626`
+		// Minimum execution time: 11_500_000 picoseconds.
+		Weight::from_parts(11_500_000, 0)
+			.saturating_add(Weight::from_parts(0, 2626))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Identity::IdentityOf` (r:2 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingRequestsOf` (r:20 w:20)
+	/// Proof: `Identity::PendingRequestsOf` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn migration_v4_identity_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `7162`
+		//  Estimated: `70836`
+		// Minimum execution time: 135_805_000 picoseconds.
+		Weight::from_parts(135_805_000, 0)
+			.saturating_add(Weight::from_parts(0, 70836))
+			.saturating_add(T::DbWeight::get().reads(22))
+			.saturating_add(T::DbWeight::get().writes(21))
+	}
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(7538), added: 10013, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SubsOf` (r:1 w:0)
+	/// Proof: `Identity::SubsOf` (`max_values`: None, `max_size`: Some(3258), added: 5733, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::SubDepositOf` (r:1 w:0)
+	/// Proof: `Identity::SubDepositOf` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingSubsOf` (r:1 w:0)
+	/// Proof: `Identity::PendingSubsOf` (`max_values`: None, `max_size`: Some(6658), added: 9133, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::NameOf` (r:1 w:0)
+	/// Proof: `Identity::NameOf` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::UsernameInfoOf` (r:1 w:0)
+	/// Proof: `Identity::UsernameInfoOf` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingUsernames` (r:1 w:0)
+	/// Proof: `Identity::PendingUsernames` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AuthorityOf` (r:1 w:0)
+	/// Proof: `Identity::AuthorityOf` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	fn held_deposits_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `11003`
+		// Minimum execution time: 34_120_000 picoseconds.
+		Weight::from_parts(34_120_000, 0)
+			.saturating_add(Weight::from_parts(0, 11003))
+			.saturating_add(T::DbWeight::get().reads(8))
+	}
 }
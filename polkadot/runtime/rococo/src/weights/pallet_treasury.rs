@@ -21,6 +21,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-bn-ce5rx-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("rococo-dev")`, DB CACHE: 1024
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `spend_with_milestones`, `release_milestone`, `expire_milestone` and `void_escrow`. Re-
+//! run the benchmarks before relying on them.
 
 // Executed Command:
 // ./target/production/polkadot
@@ -170,4 +174,76 @@ impl<T: frame_system::Config> pallet_treasury::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:0)
+	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(1238), added: 3713, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::EscrowCount` (r:1 w:1)
+	/// Proof: `Treasury::EscrowCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Milestones` (r:0 w:16)
+	/// Proof: `Treasury::Milestones` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Escrows` (r:0 w:1)
+	/// Proof: `Treasury::Escrows` (`max_values`: None, `max_size`: Some(1824), added: 4299, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 16]`.
+	fn spend_with_milestones(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114`
+		//  Estimated: `4703`
+		// Minimum execution time: 15_772_000 picoseconds.
+		Weight::from_parts(15_772_000, 0)
+			.saturating_add(Weight::from_parts(0, 4703))
+			// Standard Error: 9_214
+			.saturating_add(Weight::from_parts(1_942_337, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+	}
+	/// Storage: `Treasury::Escrows` (r:1 w:1)
+	/// Proof: `Treasury::Escrows` (`max_values`: None, `max_size`: Some(1824), added: 4299, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Milestones` (r:1 w:1)
+	/// Proof: `Treasury::Milestones` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendCount` (r:1 w:1)
+	/// Proof: `Treasury::SpendCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Spends` (r:0 w:1)
+	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(1853), added: 4328, mode: `MaxEncodedLen`)
+	fn release_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `305`
+		//  Estimated: `5289`
+		// Minimum execution time: 21_455_000 picoseconds.
+		Weight::from_parts(21_455_000, 0)
+			.saturating_add(Weight::from_parts(0, 5289))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Treasury::Milestones` (r:1 w:1)
+	/// Proof: `Treasury::Milestones` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Escrows` (r:1 w:1)
+	/// Proof: `Treasury::Escrows` (`max_values`: None, `max_size`: Some(1824), added: 4299, mode: `MaxEncodedLen`)
+	fn expire_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `305`
+		//  Estimated: `5289`
+		// Minimum execution time: 16_113_000 picoseconds.
+		Weight::from_parts(16_113_000, 0)
+			.saturating_add(Weight::from_parts(0, 5289))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Treasury::Escrows` (r:1 w:1)
+	/// Proof: `Treasury::Escrows` (`max_values`: None, `max_size`: Some(1824), added: 4299, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Milestones` (r:0 w:16)
+	/// Proof: `Treasury::Milestones` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 16]`.
+	fn void_escrow(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `191`
+		//  Estimated: `5289`
+		// Minimum execution time: 13_807_000 picoseconds.
+		Weight::from_parts(13_807_000, 0)
+			.saturating_add(Weight::from_parts(0, 5289))
+			// Standard Error: 8_611
+			.saturating_add(Weight::from_parts(1_203_871, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+	}
 }
@@ -21,6 +21,12 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-bn-ce5rx-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("rococo-dev")`, DB CACHE: 1024
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `vest_locked`, `vest_unlocked`, `vest_other_locked`, `vest_other_unlocked`,
+//! `vested_transfer`, `force_vested_transfer`, `not_unlocking_merge_schedules`,
+//! `unlocking_merge_schedules`, `force_remove_vesting_schedule`, `revoke_vesting_schedule` and
+//! `migration_v2_step`. Re-run the benchmarks before relying on them.
 
 // Executed Command:
 // ./target/production/polkadot
@@ -51,7 +57,7 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -61,10 +67,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vest_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `277 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 29_288_000 picoseconds.
 		Weight::from_parts(29_095_507, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 5446))
 			// Standard Error: 1_679
 			.saturating_add(Weight::from_parts(33_164, 0).saturating_mul(l.into()))
 			// Standard Error: 2_988
@@ -73,7 +79,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -83,10 +89,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vest_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `277 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 31_003_000 picoseconds.
 		Weight::from_parts(30_528_438, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 5446))
 			// Standard Error: 1_586
 			.saturating_add(Weight::from_parts(35_429, 0).saturating_mul(l.into()))
 			// Standard Error: 2_823
@@ -95,7 +101,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -107,10 +113,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vest_other_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `380 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 31_269_000 picoseconds.
 		Weight::from_parts(30_661_898, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 5446))
 			// Standard Error: 1_394
 			.saturating_add(Weight::from_parts(39_300, 0).saturating_mul(l.into()))
 			// Standard Error: 2_480
@@ -119,7 +125,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -131,10 +137,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `380 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 33_040_000 picoseconds.
 		Weight::from_parts(32_469_674, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 5446))
 			// Standard Error: 1_418
 			.saturating_add(Weight::from_parts(44_206, 0).saturating_mul(l.into()))
 			// Standard Error: 2_523
@@ -143,7 +149,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -155,10 +161,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `451 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 62_032_000 picoseconds.
 		Weight::from_parts(63_305_621, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 5446))
 			// Standard Error: 2_277
 			.saturating_add(Weight::from_parts(42_767, 0).saturating_mul(l.into()))
 			// Standard Error: 4_051
@@ -167,7 +173,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -191,7 +197,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -203,10 +209,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `378 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 31_440_000 picoseconds.
 		Weight::from_parts(30_773_053, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 5446))
 			// Standard Error: 1_474
 			.saturating_add(Weight::from_parts(43_019, 0).saturating_mul(l.into()))
 			// Standard Error: 2_723
@@ -215,7 +221,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -227,10 +233,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `378 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 34_221_000 picoseconds.
 		Weight::from_parts(33_201_125, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 5446))
 			// Standard Error: 1_751
 			.saturating_add(Weight::from_parts(44_088, 0).saturating_mul(l.into()))
 			// Standard Error: 3_234
@@ -239,7 +245,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -251,10 +257,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn force_remove_vesting_schedule(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `451 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 35_553_000 picoseconds.
 		Weight::from_parts(34_974_083, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 5446))
 			// Standard Error: 1_560
 			.saturating_add(Weight::from_parts(34_615, 0).saturating_mul(l.into()))
 			// Standard Error: 2_882
@@ -262,4 +268,40 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn revoke_vesting_schedule(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `690 + l * (25 ±0) + s * (36 ±0)`
+		//  Estimated: `6196`
+		// Minimum execution time: 62_118_000 picoseconds.
+		Weight::from_parts(60_374_215, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			// Standard Error: 1_351
+			.saturating_add(Weight::from_parts(45_306, 0).saturating_mul(l.into()))
+			// Standard Error: 2_496
+			.saturating_add(Weight::from_parts(81_904, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Vesting::Vesting` (r:2 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
+	fn migration_v2_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1147`
+		//  Estimated: `9902`
+		// Minimum execution time: 21_304_000 picoseconds.
+		Weight::from_parts(22_015_000, 0)
+			.saturating_add(Weight::from_parts(0, 9902))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
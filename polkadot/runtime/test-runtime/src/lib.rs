@@ -467,6 +467,8 @@ impl pallet_offences::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
 	type OnOffenceHandler = Staking;
+	type SessionEra = pallet_staking::EraOfSession<Self>;
+	type MaxOffenceHistory = ConstU32<100>;
 }
 
 impl pallet_authority_discovery::Config for Runtime {
@@ -504,6 +506,7 @@ impl pallet_vesting::Config for Runtime {
 	type WeightInfo = ();
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 	type BlockNumberProvider = System;
+	type RevokeOrigin = frame_system::EnsureRoot<AccountId>;
 	const MAX_VESTING_SCHEDULES: u32 = 28;
 }
 
@@ -511,6 +514,9 @@ impl pallet_sudo::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type WeightInfo = ();
+	type SensitiveCalls = frame_support::traits::Nothing;
+	type NestedCalls = ();
+	type MaxLogLength = ConstU32<0>;
 }
 
 impl parachains_configuration::Config for Runtime {
@@ -233,6 +233,7 @@ parameter_types! {
 		BlockWeights::get().max_block;
 	pub const MaxScheduledPerBlock: u32 = 50;
 	pub const NoPreimagePostponement: Option<u32> = Some(10);
+	pub const TimestampBucketSize: Moment = 10 * MILLISECS_PER_BLOCK;
 }
 
 impl pallet_scheduler::Config for Runtime {
@@ -248,6 +249,9 @@ impl pallet_scheduler::Config for Runtime {
 	type WeightInfo = weights::pallet_scheduler::WeightInfo<Runtime>;
 	type OriginPrivilegeCmp = frame_support::traits::EqualPrivilegeOnly;
 	type Preimages = Preimage;
+	type TimeProvider = Timestamp;
+	type TimestampTolerance = ExpectedBlockTime;
+	type TimestampBucketSize = TimestampBucketSize;
 }
 
 parameter_types! {
@@ -766,6 +770,7 @@ impl pallet_fast_unstake::Config for Runtime {
 	type ControlOrigin = EnsureRoot<AccountId>;
 	type Staking = Staking;
 	type MaxErasToCheckPerBlock = ConstU32<1>;
+	type MaxExposedEras = ConstU32<0>;
 	type WeightInfo = weights::pallet_fast_unstake::WeightInfo<Runtime>;
 }
 
@@ -824,6 +829,8 @@ impl pallet_treasury::Config for Runtime {
 		AssetRate,
 	>;
 	type PayoutPeriod = PayoutSpendPeriod;
+	type MilestoneOrigin = EitherOfDiverse<EnsureRoot<AccountId>, Treasurer>;
+	type MaxMilestones = ConstU32<16>;
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments;
@@ -833,6 +840,8 @@ impl pallet_offences::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
 	type OnOffenceHandler = Staking;
+	type SessionEra = pallet_staking::EraOfSession<Self>;
+	type MaxOffenceHistory = ConstU32<1_000>;
 }
 
 impl pallet_authority_discovery::Config for Runtime {
@@ -956,19 +965,26 @@ parameter_types! {
 	pub const MaxSubAccounts: u32 = 100;
 	pub const MaxAdditionalFields: u32 = 100;
 	pub const MaxRegistrars: u32 = 20;
+	pub const SlashPortion: Perbill = Perbill::from_percent(90);
 }
 
 impl pallet_identity::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type DepositTracker = ();
+	type DepositAsset = ();
 	type Slashed = ();
+	type SlashPortion = SlashPortion;
 	type BasicDeposit = BasicDeposit;
 	type ByteDeposit = ByteDeposit;
 	type UsernameDeposit = UsernameDeposit;
 	type SubAccountDeposit = SubAccountDeposit;
+	type PokeDepositReward = ();
 	type MaxSubAccounts = MaxSubAccounts;
+	type MaxSubDepth = ConstU32<1>;
 	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
 	type MaxRegistrars = MaxRegistrars;
+	type MaxFeeTiers = ConstU32<8>;
 	type ForceOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
 	type RegistrarOrigin = EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>;
 	type OffchainSignature = Signature;
@@ -978,6 +994,9 @@ impl pallet_identity::Config for Runtime {
 	type UsernameGracePeriod = ConstU32<{ 30 * DAYS }>;
 	type MaxSuffixLength = ConstU32<7>;
 	type MaxUsernameLength = ConstU32<32>;
+	type OnIdentityChange = ();
+	type AttesterId = pallet_identity::offchain::crypto::Public;
+	type SubmitAttestation = ();
 	type WeightInfo = weights::pallet_identity::WeightInfo<Runtime>;
 }
 
@@ -1003,6 +1022,7 @@ impl pallet_multisig::Config for Runtime {
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = MaxSignatories;
+	type MaxCallSize = ConstU32<4096>;
 	type WeightInfo = weights::pallet_multisig::WeightInfo<Runtime>;
 }
 
@@ -1038,6 +1058,7 @@ impl pallet_vesting::Config for Runtime {
 	type WeightInfo = weights::pallet_vesting::WeightInfo<Runtime>;
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 	type BlockNumberProvider = System;
+	type RevokeOrigin = EnsureRoot<AccountId>;
 	const MAX_VESTING_SCHEDULES: u32 = 28;
 }
 
@@ -1045,6 +1066,9 @@ impl pallet_sudo::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type WeightInfo = weights::pallet_sudo::WeightInfo<Runtime>;
+	type SensitiveCalls = frame_support::traits::Nothing;
+	type NestedCalls = (Utility, Proxy, Multisig);
+	type MaxLogLength = ConstU32<0>;
 }
 
 parameter_types! {
@@ -1055,6 +1079,7 @@ parameter_types! {
 	pub const MaxProxies: u16 = 32;
 	pub const AnnouncementDepositBase: Balance = deposit(1, 8);
 	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	pub const CallPreviewDepositPerByte: Balance = deposit(0, 1);
 	pub const MaxPending: u16 = 32;
 }
 
@@ -1204,6 +1229,8 @@ impl pallet_proxy::Config for Runtime {
 	type CallHasher = BlakeTwo256;
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+	type MaxCallPreviewLen = ConstU32<1024>;
+	type CallPreviewDepositPerByte = CallPreviewDepositPerByte;
 }
 
 impl parachains_origin::Config for Runtime {}
@@ -1249,6 +1276,9 @@ parameter_types! {
 	///
 	/// This is not a good value for para-chains since the `Scheduler` already uses up to 80% block weight.
 	pub MessageQueueServiceWeight: Weight = Perbill::from_percent(20) * BlockWeights::get().max_block;
+	/// The share of the message queue service weight that a single para may use while others
+	/// are waiting.
+	pub const MessageQueueServiceShare: Perbill = Perbill::from_percent(25);
 	pub const MessageQueueHeapSize: u32 = 128 * 1024;
 	pub const MessageQueueMaxStale: u32 = 48;
 }
@@ -1289,6 +1319,8 @@ impl pallet_message_queue::Config for Runtime {
 		pallet_message_queue::mock_helpers::NoopMessageProcessor<AggregateMessageOrigin>;
 	type QueueChangeHandler = ParaInclusion;
 	type QueuePausedQuery = ();
+	type ServiceWeightShare =
+		frame_support::traits::ConstServiceWeightShare<MessageQueueServiceShare>;
 	type WeightInfo = weights::pallet_message_queue::WeightInfo<Runtime>;
 }
 
@@ -1489,6 +1521,8 @@ impl identity_migrator::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Reaper = EnsureSigned<AccountId>;
 	type ReapIdentityHandler = ToParachainIdentityReaper<Runtime, Self::AccountId>;
+	type MigrateIdentityHandler = ToParachainIdentityReaper<Runtime, Self::AccountId>;
+	type RestoreOrigin = EnsureRoot<AccountId>;
 	type WeightInfo = weights::polkadot_runtime_common_identity_migrator::WeightInfo<Runtime>;
 }
 
@@ -1542,7 +1576,12 @@ parameter_types! {
 impl pallet_migrations::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type Migrations = pallet_identity::migration::v2::LazyMigrationV1ToV2<Runtime>;
+	type Migrations = (
+		pallet_identity::migration::v2::LazyMigrationV1ToV2<Runtime>,
+		pallet_identity::migration::v4::LazyMigrationV3ToV4<Runtime>,
+		pallet_conviction_voting::migration::LazyMigrationV0ToV1<Runtime>,
+		pallet_vesting::migrations::v2::LazyMigrationV1ToV2<Runtime>,
+	);
 	// Benchmarks need mocked migrations to guarantee that they succeed.
 	#[cfg(feature = "runtime-benchmarks")]
 	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
@@ -1837,6 +1876,7 @@ pub mod migrations {
 		parachains_shared::migration::MigrateToV1<Runtime>,
 		parachains_scheduler::migration::MigrateV2ToV3<Runtime>,
 		pallet_staking::migrations::v16::MigrateV15ToV16<Runtime>,
+		pallet_identity::migration::versioned::V2ToV3<Runtime>,
 		// permanent
 		pallet_xcm::migration::MigrateToLatestXcmVersion<Runtime>,
 	);
@@ -2390,12 +2430,35 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl frame_support::view_functions::runtime_api::RuntimeViewFunction<Block> for Runtime {
+		fn execute_view_function(
+			query_id: frame_support::view_functions::ViewFunctionId,
+			input: Vec<u8>,
+		) -> Result<Vec<u8>, frame_support::view_functions::ViewFunctionDispatchError> {
+			frame_support::view_functions::execute_view_function::<(
+				Staking,
+				Identity,
+				Multisig,
+			)>(query_id, input)
+		}
+	}
+
 	impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce> for Runtime {
 		fn account_nonce(account: AccountId) -> Nonce {
 			System::account_nonce(account)
 		}
 	}
 
+	impl pallet_message_queue::MessageQueueApi<Block, AggregateMessageOrigin> for Runtime {
+		fn starvation_count(origin: AggregateMessageOrigin) -> u32 {
+			pallet_message_queue::StarvationCount::<Runtime>::get(origin)
+		}
+
+		fn starvation_counts() -> Vec<(AggregateMessageOrigin, u32)> {
+			MessageQueue::starvation_counts()
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
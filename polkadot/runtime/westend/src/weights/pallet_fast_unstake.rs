@@ -21,6 +21,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner--ss9ysm1-project-163-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("westend-dev"), DB CACHE: 1024
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `on_idle_unstake` and `on_idle_check`. Re-run the benchmarks before relying on them.
 
 // Executed Command:
 // ./target/production/polkadot
@@ -80,6 +83,8 @@ impl<T: frame_system::Config> pallet_fast_unstake::WeightInfo for WeightInfo<T>
 	/// Proof: Staking Ledger (max_values: None, max_size: Some(1091), added: 3566, mode: MaxEncodedLen)
 	/// Storage: Staking Payee (r:0 w:64)
 	/// Proof: Staking Payee (max_values: None, max_size: Some(73), added: 2548, mode: MaxEncodedLen)
+	/// Storage: FastUnstake ExposedEras (r:64 w:64)
+	/// Proof: FastUnstake ExposedEras (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
 	/// The range of component `b` is `[1, 64]`.
 	fn on_idle_unstake(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -91,9 +96,9 @@ impl<T: frame_system::Config> pallet_fast_unstake::WeightInfo for WeightInfo<T>
 			// Standard Error: 92_258
 			.saturating_add(Weight::from_parts(61_451_756, 0).saturating_mul(b.into()))
 			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(b.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
-			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(b.into())))
 			.saturating_add(Weight::from_parts(0, 3774).saturating_mul(b.into()))
 	}
 	/// Storage: FastUnstake ErasToCheckPerBlock (r:1 w:0)
@@ -110,6 +115,8 @@ impl<T: frame_system::Config> pallet_fast_unstake::WeightInfo for WeightInfo<T>
 	/// Proof: Staking CurrentEra (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
 	/// Storage: Staking ErasStakers (r:257 w:0)
 	/// Proof Skipped: Staking ErasStakers (max_values: None, max_size: None, mode: Measured)
+	/// Storage: FastUnstake ExposedEras (r:64 w:64)
+	/// Proof: FastUnstake ExposedEras (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
 	/// The range of component `v` is `[1, 256]`.
 	/// The range of component `b` is `[1, 64]`.
 	fn on_idle_check(v: u32, b: u32, ) -> Weight {
@@ -125,7 +132,9 @@ impl<T: frame_system::Config> pallet_fast_unstake::WeightInfo for WeightInfo<T>
 			.saturating_add(Weight::from_parts(525_457_699, 0).saturating_mul(b.into()))
 			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(b.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(b.into())))
 			.saturating_add(Weight::from_parts(0, 49).saturating_mul(b.into()))
 			.saturating_add(Weight::from_parts(0, 4961).saturating_mul(v.into()))
 	}
@@ -21,6 +21,19 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-e8ezs4ez-project-163-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("kusama-dev"), DB CACHE: 1024
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `submit`, `place_decision_deposit_preparing`, `place_decision_deposit_queued`,
+//! `place_decision_deposit_not_queued`, `place_decision_deposit_passing`,
+//! `place_decision_deposit_failing`, `cancel`, `kill`, `one_fewer_deciding_failing`,
+//! `one_fewer_deciding_passing`, `nudge_referendum_requeued_insertion`,
+//! `nudge_referendum_requeued_slide`, `nudge_referendum_queued`, `nudge_referendum_not_queued`,
+//! `nudge_referendum_no_deposit`, `nudge_referendum_preparing`, `nudge_referendum_timed_out`,
+//! `nudge_referendum_begin_deciding_failing`, `nudge_referendum_begin_deciding_passing`,
+//! `nudge_referendum_begin_confirming`, `nudge_referendum_end_confirming`,
+//! `nudge_referendum_continue_not_confirming`, `nudge_referendum_continue_confirming`,
+//! `nudge_referendum_approved`, `nudge_referendum_rejected`. Re-run the benchmarks before relying
+//! on them.
 
 // Executed Command:
 // ./target/production/polkadot
@@ -56,6 +69,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
 	/// Storage: Referenda ReferendumInfoFor (r:0 w:1)
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:0 w:1)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn submit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `186`
@@ -64,12 +79,14 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(40_383_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
 			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:2 w:2)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:0)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn place_decision_deposit_preparing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `439`
@@ -77,7 +94,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 51_385_000 picoseconds.
 		Weight::from_parts(52_701_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -88,6 +105,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Referenda TrackQueue (max_values: None, max_size: Some(2012), added: 4487, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:0)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn place_decision_deposit_queued() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3196`
@@ -95,7 +114,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 70_018_000 picoseconds.
 		Weight::from_parts(75_868_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -106,6 +125,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Referenda TrackQueue (max_values: None, max_size: Some(2012), added: 4487, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:0)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn place_decision_deposit_not_queued() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3216`
@@ -113,7 +134,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 69_311_000 picoseconds.
 		Weight::from_parts(72_425_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -124,6 +145,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:2 w:2)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:0)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn place_decision_deposit_passing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `439`
@@ -131,7 +154,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 64_385_000 picoseconds.
 		Weight::from_parts(66_178_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -142,6 +165,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:2 w:2)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:0)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn place_decision_deposit_failing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `439`
@@ -149,7 +174,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 62_200_000 picoseconds.
 		Weight::from_parts(63_782_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -180,6 +205,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:2 w:2)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:0 w:1)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn cancel() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `347`
@@ -188,7 +215,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(38_953_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
 			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
@@ -196,6 +223,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
 	/// Storage: Referenda MetadataOf (r:1 w:0)
 	/// Proof: Referenda MetadataOf (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:0 w:1)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn kill() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `588`
@@ -204,7 +233,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(107_102_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
 			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: Referenda TrackQueue (r:1 w:0)
 	/// Proof: Referenda TrackQueue (max_values: None, max_size: Some(2012), added: 4487, mode: MaxEncodedLen)
@@ -228,6 +257,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:0)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn one_fewer_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3116`
@@ -235,7 +266,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 55_344_000 picoseconds.
 		Weight::from_parts(58_026_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Referenda TrackQueue (r:1 w:1)
@@ -246,6 +277,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:0)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn one_fewer_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `3116`
@@ -253,13 +286,15 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 57_003_000 picoseconds.
 		Weight::from_parts(60_347_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:0)
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
 	/// Storage: Referenda TrackQueue (r:1 w:1)
 	/// Proof: Referenda TrackQueue (max_values: None, max_size: Some(2012), added: 4487, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:0)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn nudge_referendum_requeued_insertion() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2939`
@@ -267,13 +302,15 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 23_001_000 picoseconds.
 		Weight::from_parts(24_812_000, 0)
 			.saturating_add(Weight::from_parts(0, 5477))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:0)
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
 	/// Storage: Referenda TrackQueue (r:1 w:1)
 	/// Proof: Referenda TrackQueue (max_values: None, max_size: Some(2012), added: 4487, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:0)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn nudge_referendum_requeued_slide() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2939`
@@ -281,7 +318,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 23_299_000 picoseconds.
 		Weight::from_parts(24_465_000, 0)
 			.saturating_add(Weight::from_parts(0, 5477))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -290,6 +327,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Referenda DecidingCount (max_values: None, max_size: Some(14), added: 2489, mode: MaxEncodedLen)
 	/// Storage: Referenda TrackQueue (r:1 w:1)
 	/// Proof: Referenda TrackQueue (max_values: None, max_size: Some(2012), added: 4487, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:0)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn nudge_referendum_queued() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2943`
@@ -297,7 +336,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 28_223_000 picoseconds.
 		Weight::from_parts(29_664_000, 0)
 			.saturating_add(Weight::from_parts(0, 5477))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -306,6 +345,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Referenda DecidingCount (max_values: None, max_size: Some(14), added: 2489, mode: MaxEncodedLen)
 	/// Storage: Referenda TrackQueue (r:1 w:1)
 	/// Proof: Referenda TrackQueue (max_values: None, max_size: Some(2012), added: 4487, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:0)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn nudge_referendum_not_queued() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `2963`
@@ -313,13 +354,15 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 27_474_000 picoseconds.
 		Weight::from_parts(29_072_000, 0)
 			.saturating_add(Weight::from_parts(0, 5477))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:0)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn nudge_referendum_no_deposit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `299`
@@ -327,13 +370,15 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 24_405_000 picoseconds.
 		Weight::from_parts(25_184_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:0)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn nudge_referendum_preparing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `347`
@@ -341,11 +386,13 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 24_572_000 picoseconds.
 		Weight::from_parts(25_287_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:1)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn nudge_referendum_timed_out() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `206`
@@ -353,8 +400,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 16_042_000 picoseconds.
 		Weight::from_parts(16_610_000, 0)
 			.saturating_add(Weight::from_parts(0, 4401))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
@@ -364,6 +411,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:0)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn nudge_referendum_begin_deciding_failing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `347`
@@ -371,7 +420,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 33_639_000 picoseconds.
 		Weight::from_parts(34_749_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -382,6 +431,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:0)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn nudge_referendum_begin_deciding_passing() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `347`
@@ -389,7 +440,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 36_467_000 picoseconds.
 		Weight::from_parts(37_693_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -398,6 +449,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:0)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn nudge_referendum_begin_confirming() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `400`
@@ -405,7 +458,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 29_857_000 picoseconds.
 		Weight::from_parts(30_840_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -414,6 +467,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:0)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn nudge_referendum_end_confirming() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `383`
@@ -421,7 +476,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 31_028_000 picoseconds.
 		Weight::from_parts(32_154_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -430,6 +485,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:0)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn nudge_referendum_continue_not_confirming() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `400`
@@ -437,7 +494,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 28_594_000 picoseconds.
 		Weight::from_parts(29_092_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -446,6 +503,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:0)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn nudge_referendum_continue_confirming() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `404`
@@ -453,7 +512,7 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 27_246_000 picoseconds.
 		Weight::from_parts(28_003_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
@@ -464,6 +523,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
 	/// Storage: Scheduler Lookup (r:1 w:1)
 	/// Proof: Scheduler Lookup (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:1)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn nudge_referendum_approved() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `404`
@@ -471,8 +532,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 43_426_000 picoseconds.
 		Weight::from_parts(44_917_000, 0)
 			.saturating_add(Weight::from_parts(0, 83866))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:1)
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
@@ -480,6 +541,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 	/// Proof: Balances InactiveIssuance (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
 	/// Storage: Scheduler Agenda (r:1 w:1)
 	/// Proof: Scheduler Agenda (max_values: None, max_size: Some(38963), added: 41438, mode: MaxEncodedLen)
+	/// Storage: Referenda ReferendumTrackParams (r:1 w:1)
+	/// Proof: Referenda ReferendumTrackParams (max_values: None, max_size: Some(106), added: 2581, mode: MaxEncodedLen)
 	fn nudge_referendum_rejected() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `400`
@@ -487,8 +550,8 @@ impl<T: frame_system::Config> pallet_referenda::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 30_285_000 picoseconds.
 		Weight::from_parts(31_575_000, 0)
 			.saturating_add(Weight::from_parts(0, 42428))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Referenda ReferendumInfoFor (r:1 w:0)
 	/// Proof: Referenda ReferendumInfoFor (max_values: None, max_size: Some(936), added: 3411, mode: MaxEncodedLen)
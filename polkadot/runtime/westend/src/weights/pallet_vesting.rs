@@ -21,6 +21,12 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner--ss9ysm1-project-163-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("westend-dev"), DB CACHE: 1024
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `vest_locked`, `vest_unlocked`, `vest_other_locked`, `vest_other_unlocked`,
+//! `vested_transfer`, `force_vested_transfer`, `not_unlocking_merge_schedules`,
+//! `unlocking_merge_schedules`, `force_remove_vesting_schedule`, `revoke_vesting_schedule` and
+//! `migration_v2_step`. Re-run the benchmarks before relying on them.
 
 // Executed Command:
 // ./target/production/polkadot
@@ -51,7 +57,7 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1981), added: 4456, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:0)
@@ -61,10 +67,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vest_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `348 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 35_225_000 picoseconds.
 		Weight::from_parts(34_420_748, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 5446))
 			// Standard Error: 2_341
 			.saturating_add(Weight::from_parts(41_794, 0).saturating_mul(l.into()))
 			// Standard Error: 4_166
@@ -73,7 +79,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1981), added: 4456, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:0)
@@ -83,10 +89,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vest_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `348 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 38_507_000 picoseconds.
 		Weight::from_parts(38_552_717, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 5446))
 			// Standard Error: 2_406
 			.saturating_add(Weight::from_parts(42_332, 0).saturating_mul(l.into()))
 			// Standard Error: 4_282
@@ -95,7 +101,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1981), added: 4456, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:0)
@@ -107,10 +113,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vest_other_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `451 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 36_919_000 picoseconds.
 		Weight::from_parts(35_087_984, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 5446))
 			// Standard Error: 2_435
 			.saturating_add(Weight::from_parts(66_131, 0).saturating_mul(l.into()))
 			// Standard Error: 4_333
@@ -119,7 +125,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1981), added: 4456, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:0)
@@ -131,10 +137,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `451 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 40_393_000 picoseconds.
 		Weight::from_parts(39_522_987, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 5446))
 			// Standard Error: 1_958
 			.saturating_add(Weight::from_parts(46_626, 0).saturating_mul(l.into()))
 			// Standard Error: 3_484
@@ -143,7 +149,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1981), added: 4456, mode: MaxEncodedLen)
 	/// Storage: System Account (r:1 w:1)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
@@ -155,10 +161,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `522 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 72_925_000 picoseconds.
 		Weight::from_parts(75_858_529, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 5446))
 			// Standard Error: 3_995
 			.saturating_add(Weight::from_parts(70_032, 0).saturating_mul(l.into()))
 			// Standard Error: 7_108
@@ -167,7 +173,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1981), added: 4456, mode: MaxEncodedLen)
 	/// Storage: System Account (r:2 w:2)
 	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
@@ -191,7 +197,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1981), added: 4456, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:0)
@@ -203,10 +209,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `449 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 37_715_000 picoseconds.
 		Weight::from_parts(36_483_330, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 5446))
 			// Standard Error: 2_146
 			.saturating_add(Weight::from_parts(55_976, 0).saturating_mul(l.into()))
 			// Standard Error: 3_964
@@ -215,7 +221,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: Vesting Vesting (r:1 w:1)
-	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1057), added: 3532, mode: MaxEncodedLen)
+	/// Proof: Vesting Vesting (max_values: None, max_size: Some(1981), added: 4456, mode: MaxEncodedLen)
 	/// Storage: Balances Locks (r:1 w:1)
 	/// Proof: Balances Locks (max_values: None, max_size: Some(1299), added: 3774, mode: MaxEncodedLen)
 	/// Storage: Balances Freezes (r:1 w:0)
@@ -227,10 +233,10 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `449 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 42_102_000 picoseconds.
 		Weight::from_parts(41_671_515, 0)
-			.saturating_add(Weight::from_parts(0, 4764))
+			.saturating_add(Weight::from_parts(0, 5446))
 			// Standard Error: 2_743
 			.saturating_add(Weight::from_parts(47_496, 0).saturating_mul(l.into()))
 			// Standard Error: 5_065
@@ -240,7 +246,7 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	}
 
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -252,9 +258,9 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 	fn force_remove_vesting_schedule(l: u32, s: u32) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `555 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 41_497_000 picoseconds.
-		Weight::from_parts(38_763_834, 4764)
+		Weight::from_parts(38_763_834, 5446)
 			// Standard Error: 2_030
 			.saturating_add(Weight::from_parts(99_580, 0).saturating_mul(l.into()))
 			// Standard Error: 3_750
@@ -262,4 +268,38 @@ impl<T: frame_system::Config> pallet_vesting::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn revoke_vesting_schedule(l: u32, s: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `690 + l * (25 ±0) + s * (36 ±0)`
+		//  Estimated: `6196`
+		// Minimum execution time: 62_118_000 picoseconds.
+		Weight::from_parts(60_374_215, 6196)
+			// Standard Error: 1_351
+			.saturating_add(Weight::from_parts(45_306, 0).saturating_mul(l.into()))
+			// Standard Error: 2_496
+			.saturating_add(Weight::from_parts(81_904, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Vesting::Vesting` (r:2 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
+	fn migration_v2_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1147`
+		//  Estimated: `9902`
+		// Minimum execution time: 21_304_000 picoseconds.
+		Weight::from_parts(22_015_000, 9902)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
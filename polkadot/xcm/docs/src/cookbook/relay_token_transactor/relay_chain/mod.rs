@@ -89,6 +89,7 @@ impl pallet_message_queue::Config for Runtime {
 	type MessageProcessor = MessageProcessor;
 	type QueueChangeHandler = ();
 	type QueuePausedQuery = ();
+	type ServiceWeightShare = ();
 	type WeightInfo = ();
 	type IdleMaxServiceWeight = MessageQueueServiceWeight;
 }
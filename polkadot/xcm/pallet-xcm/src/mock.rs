@@ -300,6 +300,8 @@ impl pallet_assets::Config for Test {
 	type ApprovalDeposit = ConstU128<1>;
 	type StringLimit = ConstU32<50>;
 	type Freezer = ();
+	type MaxFreezes = ConstU32<8>;
+	type UriLimit = ConstU32<256>;
 	type WeightInfo = ();
 	type CallbackHandle = ();
 	type Extra = ();
@@ -142,6 +142,9 @@ impl pallet_asset_conversion::Config for Runtime {
 	type LPFee = ConstU32<3>;
 	type PalletId = AssetConversionPalletId;
 	type MaxSwapPathLength = ConstU32<3>;
+	type MaxOrdersPerPool = ConstU32<8>;
+	type MaxOrderFillsPerBlock = ConstU32<8>;
+	type OrderDeposit = ConstU128<100>;
 	type MintMinLiquidity = ConstU128<100>;
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
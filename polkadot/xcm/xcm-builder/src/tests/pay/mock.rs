@@ -112,6 +112,8 @@ impl pallet_assets::Config for Test {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = AssetsStringLimit;
 	type Freezer = ();
+	type MaxFreezes = ConstU32<8>;
+	type UriLimit = ConstU32<256>;
 	type Extra = ();
 	type WeightInfo = ();
 	type RemoveItemsLimit = RemoveItemsLimit;
@@ -95,6 +95,8 @@ parameter_types! {
 	pub const PayoutPeriod: BlockNumber = 2;
 	pub const FixedSalaryAmount: Balance = 10 * UNITS;
 	pub static Budget: Balance = FixedSalaryAmount::get();
+	pub const SubSpendBudget: Balance = 5 * UNITS;
+	pub const MinSubSpendRank: Rank = 2;
 }
 
 pub struct FixedSalary;
@@ -113,6 +115,9 @@ impl pallet_salary::Config for Test {
 	type RegistrationPeriod = RegistrationPeriod;
 	type PayoutPeriod = PayoutPeriod;
 	type Budget = Budget;
+	type SubSpendBudget = SubSpendBudget;
+	type MinSubSpendRank = MinSubSpendRank;
+	type SubSpendClaimPeriod = PayoutPeriod;
 }
 
 /// Scenario:
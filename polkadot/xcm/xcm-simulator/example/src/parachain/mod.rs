@@ -29,7 +29,7 @@ use frame_system::EnsureRoot;
 use sp_core::ConstU32;
 use sp_runtime::{
 	traits::{Get, IdentityLookup},
-	AccountId32,
+	AccountId32, MultiSignature, MultiSigner, Permill,
 };
 use xcm::latest::prelude::*;
 use xcm_builder::{EnsureXcmOrigin, SignedToAccountId32};
@@ -61,13 +61,28 @@ impl pallet_balances::Config for Runtime {
 #[cfg(feature = "runtime-benchmarks")]
 pub struct UniquesHelper;
 #[cfg(feature = "runtime-benchmarks")]
-impl pallet_uniques::BenchmarkHelper<Location, AssetInstance> for UniquesHelper {
+impl
+	pallet_uniques::BenchmarkHelper<Location, AssetInstance, MultiSigner, AccountId, MultiSignature>
+	for UniquesHelper
+{
 	fn collection(i: u16) -> Location {
 		GeneralIndex(i as u128).into()
 	}
 	fn item(i: u16) -> AssetInstance {
 		AssetInstance::Index(i as u128)
 	}
+	fn signer() -> (MultiSigner, AccountId) {
+		<() as pallet_uniques::BenchmarkHelper<u32, u32, MultiSigner, AccountId, MultiSignature>>::signer()
+	}
+	fn sign(signer: &MultiSigner, message: &[u8]) -> MultiSignature {
+		<() as pallet_uniques::BenchmarkHelper<u32, u32, MultiSigner, AccountId, MultiSignature>>::sign(
+			signer, message,
+		)
+	}
+}
+
+parameter_types! {
+	pub const UniquesMaxRoyalty: Permill = Permill::from_percent(50);
 }
 
 impl pallet_uniques::Config for Runtime {
@@ -85,7 +100,11 @@ impl pallet_uniques::Config for Runtime {
 	type StringLimit = ConstU32<64>;
 	type KeyLimit = ConstU32<64>;
 	type ValueLimit = ConstU32<128>;
+	type MaxRoyalty = UniquesMaxRoyalty;
+	type MaxBatchSize = ConstU32<10>;
 	type Locker = ();
+	type OffchainSignature = MultiSignature;
+	type OffchainPublic = MultiSigner;
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = UniquesHelper;
@@ -29,7 +29,7 @@ use frame_support::{
 
 use frame_system::EnsureRoot;
 use sp_core::ConstU32;
-use sp_runtime::{traits::IdentityLookup, AccountId32};
+use sp_runtime::{traits::IdentityLookup, AccountId32, MultiSignature, MultiSigner, Permill};
 
 use polkadot_runtime_parachains::{
 	configuration,
@@ -62,6 +62,10 @@ impl pallet_balances::Config for Runtime {
 	type AccountStore = System;
 }
 
+parameter_types! {
+	pub const UniquesMaxRoyalty: Permill = Permill::from_percent(50);
+}
+
 impl pallet_uniques::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type CollectionId = u32;
@@ -77,7 +81,11 @@ impl pallet_uniques::Config for Runtime {
 	type StringLimit = ConstU32<64>;
 	type KeyLimit = ConstU32<64>;
 	type ValueLimit = ConstU32<128>;
+	type MaxRoyalty = UniquesMaxRoyalty;
+	type MaxBatchSize = ConstU32<10>;
 	type Locker = ();
+	type OffchainSignature = MultiSignature;
+	type OffchainPublic = MultiSigner;
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = ();
@@ -164,6 +172,7 @@ impl pallet_message_queue::Config for Runtime {
 	type MessageProcessor = MessageProcessor;
 	type QueueChangeHandler = ();
 	type QueuePausedQuery = ();
+	type ServiceWeightShare = ();
 	type WeightInfo = ();
 }
 
@@ -226,6 +226,7 @@ impl pallet_message_queue::Config for Runtime {
 		pallet_message_queue::mock_helpers::NoopMessageProcessor<AggregateMessageOrigin>;
 	type QueueChangeHandler = ();
 	type QueuePausedQuery = ();
+	type ServiceWeightShare = ();
 	type WeightInfo = ();
 }
 
@@ -0,0 +1,439 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Programmatic provisioning of a Rococo <> Westend bridge topology.
+//!
+//! [`BridgedNetworksBuilder`] spawns two relay networks, each with a bridge hub and an asset hub
+//! collator, opens HRMP channels between the asset hub and the bridge hub, pre-opens the
+//! requested `pallet-xcm-bridge-hub` lanes at genesis and (optionally) starts a
+//! `substrate-relay` instance that relays headers and messages between both bridge hubs.
+//!
+//! This replaces the shell orchestration from
+//! `bridges/testing/environments/rococo-westend/bridges_rococo_westend.sh` for tests written
+//! in Rust:
+//!
+//! ```ignore
+//! let bridged = BridgedNetworksBuilder::new()
+//! 	.with_lane(BridgeLane::new([0, 0, 0, 2]))
+//! 	.spawn()
+//! 	.await?;
+//! let client: OnlineClient<PolkadotConfig> =
+//! 	bridged.westend.get_node("westend-bridge-hub-collator1")?.wait_client().await?;
+//! ```
+//!
+//! Required binaries (`polkadot`, `polkadot-parachain` and `substrate-relay`) must be in the
+//! `PATH`. The relayer binary can be overridden with the `SUBSTRATE_RELAY_BINARY` environment
+//! variable or [`BridgedNetworksBuilder::with_relayer_binary`].
+
+use anyhow::{anyhow, Context};
+use serde_json::{json, Value};
+use std::process::Stdio;
+use subxt::{OnlineClient, PolkadotConfig};
+use tokio::process::{Child, Command};
+use zombienet_sdk::{LocalFileSystem, Network, NetworkConfig, NetworkConfigBuilder};
+
+/// Environment variable that overrides the relayer binary.
+pub const RELAYER_BINARY_ENV: &str = "SUBSTRATE_RELAY_BINARY";
+
+/// Default relayer binary.
+pub const DEFAULT_RELAYER_BINARY: &str = "substrate-relay";
+
+/// Lane that is opened between both asset hubs by the `*-local` bridge hub presets.
+pub const DEFAULT_LANE: [u8; 4] = [0, 0, 0, 2];
+
+/// Genesis hash used by the Rococo runtimes to identify the Rococo network.
+pub const ROCOCO_GENESIS_HASH: [u8; 32] = [
+	0x64, 0x08, 0xde, 0x77, 0x37, 0xc5, 0x9c, 0x23, 0x88, 0x90, 0x53, 0x3a, 0xf2, 0x58, 0x96, 0xa2,
+	0xc2, 0x06, 0x08, 0xd8, 0xb3, 0x80, 0xbb, 0x01, 0x02, 0x9a, 0xcb, 0x39, 0x27, 0x81, 0x06, 0x3e,
+];
+
+/// Genesis hash used by the Westend runtimes to identify the Westend network.
+pub const WESTEND_GENESIS_HASH: [u8; 32] = [
+	0xe1, 0x43, 0xf2, 0x38, 0x03, 0xac, 0x50, 0xe8, 0xf6, 0xf8, 0xe6, 0x26, 0x95, 0xd1, 0xce, 0x9e,
+	0x4e, 0x1d, 0x68, 0xaa, 0x36, 0xc1, 0xcd, 0x2c, 0xfd, 0x15, 0x34, 0x02, 0x13, 0xf3, 0x42, 0x3e,
+];
+
+/// One side of the bridge: a relay chain with its bridge hub and asset hub.
+#[derive(Debug, Clone)]
+pub struct BridgeSide {
+	/// Relay chain spec name, e.g. `rococo-local`.
+	pub relay_chain: String,
+	/// Names of the relay chain validators. The first one is used by the relayer.
+	pub validators: Vec<String>,
+	/// Bridge hub chain spec name.
+	pub bridge_hub_chain: String,
+	/// Bridge hub parachain id.
+	pub bridge_hub_id: u32,
+	/// Names of the bridge hub collators. The first one is used by the relayer.
+	pub bridge_hub_collators: Vec<String>,
+	/// Asset hub chain spec name.
+	pub asset_hub_chain: String,
+	/// Asset hub parachain id.
+	pub asset_hub_id: u32,
+	/// Names of the asset hub collators.
+	pub asset_hub_collators: Vec<String>,
+	/// Genesis config key of the `pallet-xcm-bridge-hub` instance bridging to the other side.
+	pub bridge_pallet: String,
+	/// Genesis hash identifying this network in `GlobalConsensus(ByGenesis(..))`.
+	pub genesis_hash: [u8; 32],
+	/// Name of the relay chain in `substrate-relay` arguments, e.g. `rococo`.
+	pub relayer_chain: String,
+	/// Signer used by the relayer to submit transactions to the bridge hub.
+	pub relayer_signer: String,
+}
+
+impl BridgeSide {
+	/// Default Rococo side, matching `bridges/testing/environments/rococo-westend`.
+	pub fn rococo() -> Self {
+		Self::local("rococo", 1013, "xcmOverBridgeHubWestend", ROCOCO_GENESIS_HASH)
+	}
+
+	/// Default Westend side, matching `bridges/testing/environments/rococo-westend`.
+	pub fn westend() -> Self {
+		Self::local("westend", 1002, "xcmOverBridgeHubRococo", WESTEND_GENESIS_HASH)
+	}
+
+	fn local(
+		network: &str,
+		bridge_hub_id: u32,
+		bridge_pallet: &str,
+		genesis_hash: [u8; 32],
+	) -> Self {
+		Self {
+			relay_chain: format!("{network}-local"),
+			validators: ["alice", "bob", "charlie"]
+				.iter()
+				.map(|name| format!("{name}-{network}-validator"))
+				.collect(),
+			bridge_hub_chain: format!("bridge-hub-{network}-local"),
+			bridge_hub_id,
+			bridge_hub_collators: vec![format!("{network}-bridge-hub-collator1")],
+			asset_hub_chain: format!("asset-hub-{network}-local"),
+			asset_hub_id: 1000,
+			asset_hub_collators: vec![format!("{network}-asset-hub-collator1")],
+			bridge_pallet: bridge_pallet.into(),
+			genesis_hash,
+			relayer_chain: network.into(),
+			relayer_signer: "//Charlie".into(),
+		}
+	}
+
+	/// Name of the relay node the relayer connects to.
+	pub fn relay_node(&self) -> anyhow::Result<&str> {
+		self.validators
+			.first()
+			.map(String::as_str)
+			.ok_or_else(|| anyhow!("{} has no validators", self.relay_chain))
+	}
+
+	/// Name of the bridge hub node the relayer connects to.
+	pub fn bridge_hub_node(&self) -> anyhow::Result<&str> {
+		self.bridge_hub_collators
+			.first()
+			.map(String::as_str)
+			.ok_or_else(|| anyhow!("{} has no collators", self.bridge_hub_chain))
+	}
+}
+
+/// A `pallet-xcm-bridge-hub` lane opened at genesis on both bridge hubs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BridgeLane {
+	/// Lane identifier (`LegacyLaneId`).
+	pub id: [u8; 4],
+	/// Sibling parachain owning the bridge on the Rococo side.
+	pub rococo_para: u32,
+	/// Sibling parachain owning the bridge on the Westend side.
+	pub westend_para: u32,
+}
+
+impl BridgeLane {
+	/// Lane between both asset hubs.
+	pub fn new(id: [u8; 4]) -> Self {
+		Self { id, rococo_para: 1000, westend_para: 1000 }
+	}
+
+	/// Lane between arbitrary sibling parachains on each side.
+	pub fn between(id: [u8; 4], rococo_para: u32, westend_para: u32) -> Self {
+		Self { id, rococo_para, westend_para }
+	}
+
+	/// Hex encoded lane id, as expected by `substrate-relay --lane`.
+	pub fn hex_id(&self) -> String {
+		self.id.iter().map(|b| format!("{b:02x}")).collect()
+	}
+}
+
+/// Builder of a bridged Rococo <> Westend topology.
+#[derive(Debug, Clone)]
+pub struct BridgedNetworksBuilder {
+	rococo: BridgeSide,
+	westend: BridgeSide,
+	lanes: Vec<BridgeLane>,
+	relayer: Option<String>,
+}
+
+impl Default for BridgedNetworksBuilder {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl BridgedNetworksBuilder {
+	/// Default topology with the relayer enabled and no explicit lanes. If no lane is added,
+	/// [`DEFAULT_LANE`] is opened between both asset hubs.
+	pub fn new() -> Self {
+		Self {
+			rococo: BridgeSide::rococo(),
+			westend: BridgeSide::westend(),
+			lanes: Vec::new(),
+			relayer: Some(
+				std::env::var(RELAYER_BINARY_ENV).unwrap_or_else(|_| DEFAULT_RELAYER_BINARY.into()),
+			),
+		}
+	}
+
+	/// Override the Rococo side.
+	pub fn with_rococo(mut self, f: impl FnOnce(BridgeSide) -> BridgeSide) -> Self {
+		self.rococo = f(self.rococo);
+		self
+	}
+
+	/// Override the Westend side.
+	pub fn with_westend(mut self, f: impl FnOnce(BridgeSide) -> BridgeSide) -> Self {
+		self.westend = f(self.westend);
+		self
+	}
+
+	/// Open `lane` at genesis on both bridge hubs.
+	pub fn with_lane(mut self, lane: BridgeLane) -> Self {
+		self.lanes.push(lane);
+		self
+	}
+
+	/// Use `binary` to run the relayer.
+	pub fn with_relayer_binary(mut self, binary: impl Into<String>) -> Self {
+		self.relayer = Some(binary.into());
+		self
+	}
+
+	/// Do not start a relayer. Useful for tests that drive the relayer themselves.
+	pub fn without_relayer(mut self) -> Self {
+		self.relayer = None;
+		self
+	}
+
+	/// Lanes opened at genesis.
+	pub fn lanes(&self) -> Vec<BridgeLane> {
+		if self.lanes.is_empty() {
+			vec![BridgeLane::new(DEFAULT_LANE)]
+		} else {
+			self.lanes.clone()
+		}
+	}
+
+	/// Network configurations of the Rococo and the Westend side.
+	pub fn network_configs(&self) -> anyhow::Result<(NetworkConfig, NetworkConfig)> {
+		let lanes = self.lanes();
+		let rococo_bridges = lanes
+			.iter()
+			.map(|lane| opened_bridge(lane.rococo_para, &self.westend, lane.westend_para, lane.id))
+			.collect();
+		let westend_bridges = lanes
+			.iter()
+			.map(|lane| opened_bridge(lane.westend_para, &self.rococo, lane.rococo_para, lane.id))
+			.collect();
+
+		Ok((
+			network_config(&self.rococo, rococo_bridges)?,
+			network_config(&self.westend, westend_bridges)?,
+		))
+	}
+
+	/// Spawn both networks and, unless disabled, the relayer between their bridge hubs.
+	pub async fn spawn(self) -> anyhow::Result<BridgedNetworks> {
+		let (rococo_config, westend_config) = self.network_configs()?;
+
+		let spawn_fn = zombienet_sdk::environment::get_spawn_fn();
+		let rococo = spawn_fn(rococo_config).await?;
+		let westend = spawn_fn(westend_config).await?;
+
+		for (network, side) in [(&rococo, &self.rococo), (&westend, &self.westend)] {
+			for node in [side.relay_node()?, side.bridge_hub_node()?] {
+				let _: OnlineClient<PolkadotConfig> = network.get_node(node)?.wait_client().await?;
+			}
+		}
+
+		let relayer = match self.relayer {
+			Some(ref binary) => Some(
+				Relayer {
+					binary,
+					rococo: (&self.rococo, &rococo),
+					westend: (&self.westend, &westend),
+				}
+				.start(&self.lanes())
+				.await?,
+			),
+			None => None,
+		};
+
+		Ok(BridgedNetworks { rococo, westend, relayer })
+	}
+}
+
+/// Spawned bridged topology. The relayer is killed when this is dropped.
+pub struct BridgedNetworks {
+	/// Rococo relay network with its bridge hub and asset hub.
+	pub rococo: Network<LocalFileSystem>,
+	/// Westend relay network with its bridge hub and asset hub.
+	pub westend: Network<LocalFileSystem>,
+	/// Running `relay-headers-and-messages` process, if the relayer is enabled.
+	pub relayer: Option<Child>,
+}
+
+fn network_config(side: &BridgeSide, opened_bridges: Vec<Value>) -> anyhow::Result<NetworkConfig> {
+	let images = zombienet_sdk::environment::get_images_from_env();
+	let mut config = NetworkConfigBuilder::new().with_relaychain(|r| {
+		let r = r
+			.with_chain(side.relay_chain.as_str())
+			.with_default_command("polkadot")
+			.with_default_image(images.polkadot.as_str());
+		let mut validators = side.validators.iter();
+		let first = validators.next().map(String::as_str).unwrap_or("alice");
+		validators.fold(r.with_node(|node| node.with_name(first)), |r, name| {
+			r.with_node(|node| node.with_name(name.as_str()))
+		})
+	});
+
+	for (id, chain, collators, overrides) in [
+		(
+			side.bridge_hub_id,
+			&side.bridge_hub_chain,
+			&side.bridge_hub_collators,
+			Some(json!({ side.bridge_pallet.clone(): { "openedBridges": opened_bridges } })),
+		),
+		(side.asset_hub_id, &side.asset_hub_chain, &side.asset_hub_collators, None),
+	] {
+		config = config.with_parachain(|p| {
+			let p = p
+				.with_id(id)
+				.with_chain(chain.as_str())
+				.with_default_command("polkadot-parachain")
+				.with_default_image(images.cumulus.as_str());
+			let p = match overrides {
+				Some(overrides) => p.with_genesis_overrides(overrides),
+				None => p,
+			};
+			let mut collators = collators.iter();
+			let first = collators.next().map(String::as_str).unwrap_or("collator");
+			collators.fold(p.with_collator(|n| n.with_name(first)), |p, name| {
+				p.with_collator(|n| n.with_name(name.as_str()))
+			})
+		});
+	}
+
+	for (sender, recipient) in
+		[(side.asset_hub_id, side.bridge_hub_id), (side.bridge_hub_id, side.asset_hub_id)]
+	{
+		config = config.with_hrmp_channel(|h| {
+			h.with_sender(sender)
+				.with_recipient(recipient)
+				.with_max_capacity(4)
+				.with_max_message_size(524288)
+		});
+	}
+
+	config.build().map_err(|e| {
+		let errs = e.into_iter().map(|e| e.to_string()).collect::<Vec<_>>().join(" ");
+		anyhow!("{} config errs: {errs}", side.relay_chain)
+	})
+}
+
+/// Genesis entry of `pallet-xcm-bridge-hub::GenesisConfig::opened_bridges`.
+fn opened_bridge(local_para: u32, remote: &BridgeSide, remote_para: u32, lane: [u8; 4]) -> Value {
+	json!([
+		{ "parents": 1, "interior": { "X1": [{ "Parachain": local_para }] } },
+		{ "X2": [
+			{ "GlobalConsensus": { "ByGenesis": remote.genesis_hash } },
+			{ "Parachain": remote_para }
+		] },
+		lane
+	])
+}
+
+struct Relayer<'a> {
+	binary: &'a str,
+	rococo: (&'a BridgeSide, &'a Network<LocalFileSystem>),
+	westend: (&'a BridgeSide, &'a Network<LocalFileSystem>),
+}
+
+impl Relayer<'_> {
+	/// Initialize both bridges and start relaying headers and messages over `lanes`.
+	async fn start(&self, lanes: &[BridgeLane]) -> anyhow::Result<Child> {
+		for (source, target) in [(self.rococo, self.westend), (self.westend, self.rococo)] {
+			let (source_host, source_port) = node_endpoint(source.1, source.0.relay_node()?)?;
+			let (target_host, target_port) = node_endpoint(target.1, target.0.bridge_hub_node()?)?;
+			let bridge =
+				format!("{}-to-bridge-hub-{}", source.0.relayer_chain, target.0.relayer_chain);
+
+			log::info!("Initializing {bridge} bridge");
+			let status = Command::new(self.binary)
+				.arg("init-bridge")
+				.arg(&bridge)
+				.args(["--source-host", &source_host, "--source-port", &source_port])
+				.args(["--source-version-mode", "Auto"])
+				.args(["--target-host", &target_host, "--target-port", &target_port])
+				.args(["--target-version-mode", "Auto", "--target-signer", "//Bob"])
+				.status()
+				.await
+				.with_context(|| format!("failed to run {}", self.binary))?;
+			if !status.success() {
+				return Err(anyhow!("init-bridge {bridge} failed: {status}"));
+			}
+		}
+
+		let mut command = Command::new(self.binary);
+		command.arg("relay-headers-and-messages").arg(format!(
+			"bridge-hub-{}-bridge-hub-{}",
+			self.rococo.0.relayer_chain, self.westend.0.relayer_chain
+		));
+		for (side, network) in [self.rococo, self.westend] {
+			let chain = &side.relayer_chain;
+			let (host, port) = node_endpoint(network, side.relay_node()?)?;
+			command
+				.args([format!("--{chain}-host"), host, format!("--{chain}-port"), port])
+				.args([format!("--{chain}-version-mode"), "Auto".into()]);
+
+			let bridge_hub = format!("bridge-hub-{chain}");
+			let (host, port) = node_endpoint(network, side.bridge_hub_node()?)?;
+			command
+				.args([format!("--{bridge_hub}-host"), host, format!("--{bridge_hub}-port"), port])
+				.args([format!("--{bridge_hub}-version-mode"), "Auto".into()])
+				.args([format!("--{bridge_hub}-signer"), side.relayer_signer.clone()])
+				.args([format!("--{bridge_hub}-transactions-mortality"), "4".into()]);
+		}
+		for lane in lanes {
+			command.args(["--lane".to_string(), lane.hex_id()]);
+		}
+
+		log::info!("Starting relayer: {command:?}");
+		command
+			.stdout(Stdio::inherit())
+			.stderr(Stdio::inherit())
+			.kill_on_drop(true)
+			.spawn()
+			.with_context(|| format!("failed to spawn {}", self.binary))
+	}
+}
+
+/// Host and port of the websocket endpoint of `node`.
+fn node_endpoint(
+	network: &Network<LocalFileSystem>,
+	node: &str,
+) -> anyhow::Result<(String, String)> {
+	let uri = network.get_node(node)?.ws_uri();
+	let address = uri.split("://").last().unwrap_or(uri).trim_end_matches('/');
+	let (host, port) = address
+		.rsplit_once(':')
+		.ok_or_else(|| anyhow!("unexpected websocket uri of {node}: {uri}"))?;
+	Ok((host.to_string(), port.to_string()))
+}
@@ -1,2 +1,4 @@
 // Copyright (C) Parity Technologies (UK) Ltd.
 // SPDX-License-Identifier: Apache-2.0
+
+pub mod bridges;
@@ -0,0 +1,5 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(feature = "zombie-bridges")]
+mod rococo_westend;
@@ -0,0 +1,57 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Requires `polkadot`, `polkadot-parachain` and `substrate-relay` binaries in the `PATH`:
+//! `cargo build -r -p polkadot-parachain-bin --bin polkadot-parachain && \`
+//! `cargo build -r --bin polkadot --bin polkadot-execute-worker --bin polkadot-prepare-worker`
+
+use polkadot_zombienet_sdk_tests::bridges::{BridgeLane, BridgedNetworksBuilder, DEFAULT_LANE};
+use std::time::Duration;
+use subxt::{dynamic::Value, OnlineClient, PolkadotConfig};
+
+async fn best_finalized(
+	client: &OnlineClient<PolkadotConfig>,
+	pallet: &str,
+) -> Result<Option<String>, anyhow::Error> {
+	let best = client
+		.storage()
+		.at_latest()
+		.await?
+		.fetch(&subxt::dynamic::storage(pallet, "BestFinalized", Vec::<Value>::new()))
+		.await?;
+	Ok(best.map(|best| format!("{:?}", best.to_value())))
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn headers_are_relayed_between_bridge_hubs() -> Result<(), anyhow::Error> {
+	env_logger::init_from_env(
+		env_logger::Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "info"),
+	);
+
+	let builder = BridgedNetworksBuilder::new().with_lane(BridgeLane::new(DEFAULT_LANE));
+	let rococo_bridge_hub = builder.network_configs()?.0;
+	assert!(rococo_bridge_hub.parachains().iter().any(|p| p.id() == 1013));
+
+	let bridged = builder.spawn().await?;
+	let rococo_client: OnlineClient<PolkadotConfig> =
+		bridged.rococo.get_node("rococo-bridge-hub-collator1")?.wait_client().await?;
+	let westend_client: OnlineClient<PolkadotConfig> =
+		bridged.westend.get_node("westend-bridge-hub-collator1")?.wait_client().await?;
+
+	// Both bridges are initialized by the relayer and then kept up to date.
+	let mut relayed = (None, None);
+	for _ in 0..60 {
+		relayed = (
+			best_finalized(&rococo_client, "BridgeWestendGrandpa").await?,
+			best_finalized(&westend_client, "BridgeRococoGrandpa").await?,
+		);
+		if relayed.0.is_some() && relayed.1.is_some() {
+			break;
+		}
+		tokio::time::sleep(Duration::from_secs(6)).await;
+	}
+	log::info!("Best finalized bridged headers: {relayed:?}");
+	assert!(relayed.0.is_some() && relayed.1.is_some(), "headers were not relayed");
+
+	Ok(())
+}
@@ -1,4 +1,5 @@
 // Copyright (C) Parity Technologies (UK) Ltd.
 // SPDX-License-Identifier: Apache-2.0
 
+mod bridges;
 mod smoke;
@@ -74,8 +74,7 @@ impl IdentityVerifier<AccountId> for AllianceIdentityVerifier {
 	}
 
 	fn super_account_id(who: &AccountId) -> Option<AccountId> {
-		use pallet_identity::SuperOf;
-		SuperOf::<Runtime>::get(who).map(|parent| parent.0)
+		crate::Identity::identity_root(who).filter(|root| root != who)
 	}
 }
 
@@ -43,7 +43,7 @@ use frame_support::{
 	derive_impl,
 	dispatch::DispatchClass,
 	dynamic_params::{dynamic_pallet_params, dynamic_params},
-	genesis_builder_helper::{build_state, get_preset},
+	genesis_builder_helper::{build_state, get_preset, patchable_paths},
 	instances::{Instance1, Instance2},
 	ord_parameter_types,
 	pallet_prelude::Get,
@@ -92,7 +92,9 @@ use sp_core::U256;
 use pallet_broker::TaskId;
 #[allow(deprecated)]
 pub use pallet_transaction_payment::{CurrencyAdapter, Multiplier, TargetedFeeAdjustment};
-use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
+use pallet_transaction_payment::{
+	FeeBreakdown, FeeDetails, FeeMultiplierProjection, RuntimeDispatchInfo,
+};
 use pallet_tx_pause::RuntimeCallNameOf;
 use sp_api::impl_runtime_apis;
 use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
@@ -104,7 +106,6 @@ use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata, H160};
 use sp_inherents::{CheckInherentsResult, InherentData};
 use sp_runtime::{
-	curve::PiecewiseLinear,
 	generic, impl_opaque_keys,
 	traits::{
 		self, AccountIdConversion, BlakeTwo256, Block as BlockT, Bounded, ConvertInto,
@@ -391,6 +392,7 @@ impl pallet_multisig::Config for Runtime {
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = ConstU32<100>;
+	type MaxCallSize = ConstU32<4096>;
 	type WeightInfo = pallet_multisig::weights::SubstrateWeight<Runtime>;
 }
 
@@ -401,6 +403,7 @@ parameter_types! {
 	pub const ProxyDepositFactor: Balance = deposit(0, 33);
 	pub const AnnouncementDepositBase: Balance = deposit(1, 8);
 	pub const AnnouncementDepositFactor: Balance = deposit(0, 66);
+	pub const CallPreviewDepositPerByte: Balance = deposit(0, 1);
 }
 
 /// The type used to represent the kinds of proxying allowed.
@@ -479,6 +482,8 @@ impl pallet_proxy::Config for Runtime {
 	type CallHasher = BlakeTwo256;
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+	type MaxCallPreviewLen = ConstU32<1024>;
+	type CallPreviewDepositPerByte = CallPreviewDepositPerByte;
 }
 
 parameter_types! {
@@ -500,6 +505,9 @@ impl pallet_scheduler::Config for Runtime {
 	type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = Preimage;
+	type TimeProvider = Timestamp;
+	type TimestampTolerance = ExpectedBlockTime;
+	type TimestampBucketSize = ConstU64<{ 10 * MILLISECS_PER_BLOCK }>;
 }
 
 impl pallet_glutton::Config for Runtime {
@@ -678,22 +686,41 @@ impl pallet_session::historical::Config for Runtime {
 	type FullIdentificationOf = pallet_staking::ExposureOf<Runtime>;
 }
 
-pallet_staking_reward_curve::build! {
-	const REWARD_CURVE: PiecewiseLinear<'static> = curve!(
-		min_inflation: 0_025_000,
-		max_inflation: 0_100_000,
-		ideal_stake: 0_500_000,
-		falloff: 0_050_000,
-		max_piece_count: 40,
-		test_precision: 0_005_000,
-	);
+/// Computes the era payouts from the inflation bounds in [`dynamic_params::inflation`].
+pub struct EraPayout;
+impl pallet_staking::EraPayout<Balance> for EraPayout {
+	fn era_payout(
+		total_staked: Balance,
+		total_issuance: Balance,
+		era_duration_millis: u64,
+	) -> (Balance, Balance) {
+		use sp_runtime::traits::Saturating;
+
+		const MILLISECONDS_PER_YEAR: u64 = (1000 * 3600 * 24 * 36525) / 100;
+		let period_fraction =
+			Perquintill::from_rational(era_duration_millis, MILLISECONDS_PER_YEAR);
+
+		let min_inflation = dynamic_params::inflation::MinInflation::get();
+		let max_inflation = dynamic_params::inflation::MaxInflation::get();
+		let stake = Perquintill::from_rational(total_staked, total_issuance);
+		let adjustment = pallet_staking_reward_fn::compute_inflation(
+			stake,
+			dynamic_params::inflation::IdealStake::get(),
+			dynamic_params::inflation::Falloff::get(),
+		);
+		let staking_inflation =
+			min_inflation.saturating_add(max_inflation.saturating_sub(min_inflation) * adjustment);
+
+		let max_payout = period_fraction * max_inflation * total_issuance;
+		let staking_payout = (period_fraction * staking_inflation) * total_issuance;
+		(staking_payout, max_payout.saturating_sub(staking_payout))
+	}
 }
 
 parameter_types! {
 	pub const SessionsPerEra: sp_staking::SessionIndex = 6;
 	pub const BondingDuration: sp_staking::EraIndex = 24 * 28;
 	pub const SlashDeferDuration: sp_staking::EraIndex = 24 * 7; // 1/4 the bonding duration.
-	pub const RewardCurve: &'static PiecewiseLinear<'static> = &REWARD_CURVE;
 	pub const MaxNominators: u32 = 64;
 	pub const MaxControllersInDeprecationBatch: u32 = 5900;
 	pub OffchainRepeat: BlockNumber = 5;
@@ -727,7 +754,7 @@ impl pallet_staking::Config for Runtime {
 		pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 3, 4>,
 	>;
 	type SessionInterface = Self;
-	type EraPayout = pallet_staking::ConvertCurve<RewardCurve>;
+	type EraPayout = EraPayout;
 	type NextNewSession = Session;
 	type MaxExposurePageSize = ConstU32<256>;
 	type ElectionProvider = ElectionProviderMultiPhase;
@@ -753,6 +780,7 @@ impl pallet_fast_unstake::Config for Runtime {
 	type Currency = Balances;
 	type Staking = Staking;
 	type MaxErasToCheckPerBlock = ConstU32<1>;
+	type MaxExposedEras = ConstU32<2>;
 	type WeightInfo = ();
 }
 
@@ -972,6 +1000,7 @@ impl pallet_conviction_voting::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type VoteLockingPeriod = VoteLockingPeriod;
+	type DecayingLocks = ConstBool<true>;
 	type MaxVotes = ConstU32<512>;
 	type MaxTurnout = frame_support::traits::TotalIssuanceOf<Balances, Self::AccountId>;
 	type Polls = Referenda;
@@ -1022,6 +1051,14 @@ impl pallet_referenda::TracksInfo<Balance, BlockNumber> for TracksInfo {
 			Err(())
 		}
 	}
+	fn params_override(
+		id: Self::Id,
+	) -> Option<pallet_referenda::TrackParams<Balance, BlockNumber>> {
+		match id {
+			0 => dynamic_params::referenda::RootTrack::get(),
+			_ => None,
+		}
+	}
 }
 pallet_referenda::impl_tracksinfo_get!(TracksInfo, Balance, BlockNumber);
 
@@ -1082,9 +1119,21 @@ impl pallet_ranked_collective::Config for Runtime {
 	type BenchmarkSetup = (CoreFellowship, Salary);
 }
 
+parameter_types! {
+	pub const AnnotationDepositBase: Balance = deposit(1, 88);
+	pub const AnnotationDepositPerByte: Balance = deposit(0, 1);
+	pub const AnnotationRetention: BlockNumber = 30 * DAYS;
+}
+
 impl pallet_remark::Config for Runtime {
 	type WeightInfo = pallet_remark::weights::SubstrateWeight<Self>;
 	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type AnnotationDepositBase = AnnotationDepositBase;
+	type AnnotationDepositPerByte = AnnotationDepositPerByte;
+	type MaxTopicLength = ConstU32<32>;
+	type MaxAnnotationLength = ConstU32<1024>;
+	type AnnotationRetention = AnnotationRetention;
 }
 
 impl pallet_root_testing::Config for Runtime {
@@ -1304,6 +1353,11 @@ impl pallet_treasury::Config for Runtime {
 	type Paymaster = PayAssetFromAccount<NativeAndAssets, TreasuryAccount>;
 	type BalanceConverter = AssetRate;
 	type PayoutPeriod = SpendPayoutPeriod;
+	type MilestoneOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+	>;
+	type MaxMilestones = ConstU32<16>;
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = PalletTreasuryArguments;
@@ -1363,6 +1417,7 @@ impl pallet_message_queue::Config for Runtime {
 	type Size = u32;
 	type QueueChangeHandler = ();
 	type QueuePausedQuery = ();
+	type ServiceWeightShare = ();
 	type HeapSize = ConstU32<{ 64 * 1024 }>;
 	type MaxStale = ConstU32<128>;
 	type ServiceWeight = MessageQueueServiceWeight;
@@ -1377,6 +1432,8 @@ impl pallet_child_bounties::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxActiveChildBountyCount = ConstU32<5>;
 	type ChildBountyValueMinimum = ChildBountyValueMinimum;
+	type MaxSubChildBountyCount = ConstU32<16>;
+	type MaxPartialPayouts = ConstU32<16>;
 	type WeightInfo = pallet_child_bounties::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1468,10 +1525,28 @@ impl pallet_revive::Config for Runtime {
 	type NativeToEthRatio = ConstU32<1_000_000>; // 10^(18 - 12) Eth is 10^18, Native is 10^12.
 }
 
+/// Calls which the sudo key may only dispatch with the approval of the co-signer, if one is set.
+///
+/// The call dispatched by `dispatch_whitelisted_call` is only known by its hash, so it can't be
+/// inspected and is always sensitive. Balance transfers aren't listed: `sudo_as` can dispatch a
+/// plain transfer from any account but the co-signer, so the co-signer can't protect them.
+pub struct SudoSensitiveCalls;
+impl Contains<RuntimeCall> for SudoSensitiveCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::Whitelist(pallet_whitelist::Call::dispatch_whitelisted_call { .. })
+		)
+	}
+}
+
 impl pallet_sudo::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
+	type SensitiveCalls = SudoSensitiveCalls;
+	type NestedCalls = (Utility, Proxy, Multisig, Scheduler, Whitelist);
+	type MaxLogLength = ConstU32<100>;
 }
 
 parameter_types! {
@@ -1584,6 +1659,8 @@ impl pallet_offences::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
 	type OnOffenceHandler = Staking;
+	type SessionEra = pallet_staking::EraOfSession<Self>;
+	type MaxOffenceHistory = ConstU32<1_000>;
 }
 
 impl pallet_authority_discovery::Config for Runtime {
@@ -1606,28 +1683,63 @@ impl pallet_grandpa::Config for Runtime {
 }
 
 parameter_types! {
-	// difference of 26 bytes on-chain for the registration and 9 bytes on-chain for the identity
-	// information, already accounted for by the byte deposit
-	pub const BasicDeposit: Balance = deposit(1, 17);
-	pub const ByteDeposit: Balance = deposit(0, 1);
 	pub const UsernameDeposit: Balance = deposit(0, 32);
-	pub const SubAccountDeposit: Balance = 2 * DOLLARS;   // 53 bytes on-chain
+	pub const PokeDepositReward: Perbill = Perbill::from_percent(10);
+	pub const SlashPortion: Perbill = Perbill::from_percent(90);
 	pub const MaxSubAccounts: u32 = 100;
 	pub const MaxAdditionalFields: u32 = 100;
 	pub const MaxRegistrars: u32 = 20;
 }
 
+/// The reasons for which deposits are accounted by the deposits pallet.
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	scale_info::TypeInfo,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	RuntimeDebug,
+)]
+pub enum DepositReason {
+	/// Deposits of identities, sub-identities, judgement fees and usernames.
+	Identity,
+}
+
+parameter_types! {
+	pub const IdentityDepositReason: DepositReason = DepositReason::Identity;
+	pub const MaxDepositPerAccount: Balance = 1_000 * DOLLARS;
+}
+
+impl pallet_deposits::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Reason = DepositReason;
+	type MaxReasons = ConstU32<8>;
+	type MaxDepositPerAccount = MaxDepositPerAccount;
+}
+
 impl pallet_identity::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
-	type BasicDeposit = BasicDeposit;
-	type ByteDeposit = ByteDeposit;
+	type DepositTracker = pallet_deposits::TrackAs<Runtime, IdentityDepositReason>;
+	type DepositAsset = ();
+	type BasicDeposit = dynamic_params::identity::BasicDeposit;
+	type ByteDeposit = dynamic_params::identity::ByteDeposit;
 	type UsernameDeposit = UsernameDeposit;
-	type SubAccountDeposit = SubAccountDeposit;
+	type SubAccountDeposit = dynamic_params::identity::SubAccountDeposit;
+	type PokeDepositReward = PokeDepositReward;
 	type MaxSubAccounts = MaxSubAccounts;
+	type MaxSubDepth = ConstU32<3>;
 	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
 	type MaxRegistrars = MaxRegistrars;
+	type MaxFeeTiers = ConstU32<8>;
 	type Slashed = Treasury;
+	type SlashPortion = SlashPortion;
 	type ForceOrigin = EnsureRootOrHalfCouncil;
 	type RegistrarOrigin = EnsureRootOrHalfCouncil;
 	type OffchainSignature = Signature;
@@ -1637,6 +1749,9 @@ impl pallet_identity::Config for Runtime {
 	type UsernameGracePeriod = ConstU32<{ 30 * DAYS }>;
 	type MaxSuffixLength = ConstU32<7>;
 	type MaxUsernameLength = ConstU32<32>;
+	type OnIdentityChange = ();
+	type AttesterId = pallet_identity::offchain::crypto::Public;
+	type SubmitAttestation = ();
 	type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
 }
 
@@ -1670,6 +1785,22 @@ parameter_types! {
 	pub const SocietyPalletId: PalletId = PalletId(*b"py/socie");
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+pub struct SocietyBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_society::BenchmarkHelper<Signature, AccountId> for SocietyBenchmarkHelper {
+	fn create_attestation(entropy: &[u8], msg: &[u8]) -> (Signature, AccountId) {
+		use sp_core::hexdisplay::HexDisplay;
+		use sp_io::crypto::{sr25519_generate, sr25519_sign};
+		use sp_runtime::traits::IdentifyAccount;
+		let seed = alloc::format!("//SocietyAttester{}", HexDisplay::from(&entropy));
+		let public = sr25519_generate(0.into(), Some(seed.into_bytes()));
+		let who: AccountId = MultiSigner::Sr25519(public).into_account();
+		let signature = MultiSignature::Sr25519(sr25519_sign(0.into(), &public, msg).unwrap());
+		(signature, who)
+	}
+}
+
 impl pallet_society::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type PalletId = SocietyPalletId;
@@ -1685,7 +1816,14 @@ impl pallet_society::Config for Runtime {
 	type ChallengePeriod = ChallengePeriod;
 	type MaxPayouts = MaxPayouts;
 	type MaxBids = MaxBids;
+	type AttestationSignature = Signature;
+	type AttestationSigner = <Signature as traits::Verify>::Signer;
+	type RequiredAttestations = ConstU32<3>;
+	type MaxAttestations = ConstU32<8>;
+	type MaxAttestedIntake = ConstU32<4>;
 	type WeightInfo = pallet_society::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = SocietyBenchmarkHelper;
 }
 
 parameter_types! {
@@ -1702,8 +1840,10 @@ impl pallet_vesting::Config for Runtime {
 	type WeightInfo = pallet_vesting::weights::SubstrateWeight<Runtime>;
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 	type BlockNumberProvider = System;
-	// `VestingInfo` encode length is 36bytes. 28 schedules gets encoded as 1009 bytes, which is the
-	// highest number of schedules that encodes less than 2^10.
+	type RevokeOrigin = EnsureRoot<AccountId>;
+	// `VestingInfo` encode length is 37 bytes, or 69 bytes for revocable schedules. 28 schedules
+	// got encoded as 1009 bytes before they could be revocable, which was the highest number of
+	// schedules that encoded less than 2^10.
 	const MAX_VESTING_SCHEDULES: u32 = 28;
 }
 
@@ -1772,6 +1912,8 @@ impl pallet_assets::Config<Instance1> for Runtime {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = StringLimit;
 	type Freezer = ();
+	type MaxFreezes = ConstU32<8>;
+	type UriLimit = ConstU32<256>;
 	type Extra = ();
 	type CallbackHandle = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
@@ -1799,6 +1941,8 @@ impl pallet_assets::Config<Instance2> for Runtime {
 	type ApprovalDeposit = ApprovalDeposit;
 	type StringLimit = StringLimit;
 	type Freezer = ();
+	type MaxFreezes = ConstU32<8>;
+	type UriLimit = ConstU32<256>;
 	type Extra = ();
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type RemoveItemsLimit = ConstU32<1000>;
@@ -1813,6 +1957,7 @@ parameter_types! {
 	pub const MintMinLiquidity: Balance = 100;  // 100 is good enough when the main currency has 10-12 decimals.
 	pub const LiquidityWithdrawalFee: Permill = Permill::from_percent(0);
 	pub const Native: NativeOrWithId<u32> = NativeOrWithId::Native;
+	pub const OrderDeposit: Balance = deposit(1, 128);
 }
 
 pub type NativeAndAssets =
@@ -1848,6 +1993,9 @@ impl pallet_asset_conversion::Config for Runtime {
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
 	type WeightInfo = pallet_asset_conversion::weights::SubstrateWeight<Runtime>;
 	type MaxSwapPathLength = ConstU32<4>;
+	type MaxOrdersPerPool = ConstU32<8>;
+	type MaxOrderFillsPerBlock = ConstU32<8>;
+	type OrderDeposit = OrderDeposit;
 	type MintMinLiquidity = MintMinLiquidity;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -1930,6 +2078,7 @@ parameter_types! {
 	pub const ItemAttributesApprovalsLimit: u32 = 20;
 	pub const MaxTips: u32 = 10;
 	pub const MaxDeadlineDuration: BlockNumber = 12 * 30 * DAYS;
+	pub const UniquesMaxRoyalty: Permill = Permill::from_percent(25);
 }
 
 impl pallet_uniques::Config for Runtime {
@@ -1946,6 +2095,10 @@ impl pallet_uniques::Config for Runtime {
 	type StringLimit = ConstU32<128>;
 	type KeyLimit = ConstU32<32>;
 	type ValueLimit = ConstU32<64>;
+	type MaxRoyalty = UniquesMaxRoyalty;
+	type MaxBatchSize = ConstU32<100>;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as traits::Verify>::Signer;
 	type WeightInfo = pallet_uniques::weights::SubstrateWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = ();
@@ -1955,6 +2108,7 @@ impl pallet_uniques::Config for Runtime {
 
 parameter_types! {
 	pub const Budget: Balance = 10_000 * DOLLARS;
+	pub const SubSpendBudget: Balance = 1_000 * DOLLARS;
 	pub TreasuryAccount: AccountId = Treasury::account_id();
 }
 
@@ -1974,6 +2128,9 @@ impl pallet_salary::Config for Runtime {
 	type RegistrationPeriod = ConstU32<200>;
 	type PayoutPeriod = ConstU32<200>;
 	type Budget = Budget;
+	type SubSpendBudget = SubSpendBudget;
+	type MinSubSpendRank = ConstU16<3>;
+	type SubSpendClaimPeriod = ConstU32<400>;
 }
 
 impl pallet_core_fellowship::Config for Runtime {
@@ -2217,7 +2374,14 @@ parameter_types! {
 impl pallet_migrations::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type Migrations = ();
+	type Migrations = (
+		pallet_deposits::migrations::v1::SeedDeposits<
+			Runtime,
+			pallet_identity::migration::held_deposits::IdentityDeposits<Runtime>,
+			IdentityDepositReason,
+		>,
+		pallet_vesting::migrations::v2::LazyMigrationV1ToV2<Runtime>,
+	);
 	// Benchmarks need mocked migrations to guarantee that they succeed.
 	#[cfg(feature = "runtime-benchmarks")]
 	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
@@ -2325,6 +2489,53 @@ pub mod dynamic_params {
 		#[codec(index = 1)]
 		pub static ByteDeposit: Balance = 1 * CENTS;
 	}
+
+	#[dynamic_pallet_params]
+	#[codec(index = 1)]
+	pub mod identity {
+		/// The base deposit of registering an identity. The difference of 26 bytes on-chain for
+		/// the registration and 9 bytes on-chain for the identity information is already
+		/// accounted for by the byte deposit.
+		#[codec(index = 0)]
+		pub static BasicDeposit: Balance = deposit(1, 17);
+
+		/// The per-byte deposit of the identity information.
+		#[codec(index = 1)]
+		pub static ByteDeposit: Balance = deposit(0, 1);
+
+		/// The deposit of a sub-account, which takes 53 bytes on-chain.
+		#[codec(index = 2)]
+		pub static SubAccountDeposit: Balance = 2 * DOLLARS;
+	}
+
+	#[dynamic_pallet_params]
+	#[codec(index = 2)]
+	pub mod referenda {
+		/// Overrides the parameters of the root track for newly submitted referenda.
+		#[codec(index = 0)]
+		pub static RootTrack: Option<pallet_referenda::TrackParams<Balance, BlockNumber>> = None;
+	}
+
+	/// The bounds of the staking inflation, see [`EraPayout`].
+	#[dynamic_pallet_params]
+	#[codec(index = 3)]
+	pub mod inflation {
+		/// The inflation when nothing is staked.
+		#[codec(index = 0)]
+		pub static MinInflation: Perquintill = Perquintill::from_rational(25u64, 1000u64);
+
+		/// The inflation when the ideal stake is reached.
+		#[codec(index = 1)]
+		pub static MaxInflation: Perquintill = Perquintill::from_rational(10u64, 100u64);
+
+		/// The ideal fraction of the issuance which is staked.
+		#[codec(index = 2)]
+		pub static IdealStake: Perquintill = Perquintill::from_rational(50u64, 100u64);
+
+		/// How quickly the inflation falls off once more than the ideal stake is staked.
+		#[codec(index = 3)]
+		pub static Falloff: Perquintill = Perquintill::from_rational(50u64, 1000u64);
+	}
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -2346,7 +2557,10 @@ impl EnsureOriginWithArg<RuntimeOrigin, RuntimeParametersKey> for DynamicParamet
 		key: &RuntimeParametersKey,
 	) -> Result<Self::Success, RuntimeOrigin> {
 		match key {
-			RuntimeParametersKey::Storage(_) => {
+			RuntimeParametersKey::Storage(_) |
+			RuntimeParametersKey::Identity(_) |
+			RuntimeParametersKey::Referenda(_) |
+			RuntimeParametersKey::Inflation(_) => {
 				frame_system::ensure_root(origin.clone()).map_err(|_| origin)?;
 				return Ok(())
 			},
@@ -2630,6 +2844,9 @@ mod runtime {
 
 	#[runtime::pallet_index(81)]
 	pub type VerifySignature = pallet_verify_signature::Pallet<Runtime>;
+
+	#[runtime::pallet_index(82)]
+	pub type Deposits = pallet_deposits::Pallet<Runtime>;
 }
 
 impl TryFrom<RuntimeCall> for pallet_revive::Call<Runtime> {
@@ -2922,6 +3139,31 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl frame_support::origin_audit::runtime_api::OriginAuditApi<Block> for Runtime {
+		fn call_origin_requirements() -> Vec<frame_support::origin_audit::CallOriginRequirements> {
+			use frame_support::origin_audit::GetCallOriginRequirements;
+			AllPalletsWithSystem::call_origin_requirements()
+		}
+	}
+
+	impl frame_support::reason_registry::runtime_api::ReasonRegistryApi<Block> for Runtime {
+		fn hold_reasons() -> Vec<frame_support::reason_registry::ReasonInfo> {
+			use frame_support::reason_registry::GetReasons;
+			RuntimeHoldReason::reasons()
+		}
+
+		fn freeze_reasons() -> Vec<frame_support::reason_registry::ReasonInfo> {
+			use frame_support::reason_registry::GetReasons;
+			RuntimeFreezeReason::reasons()
+		}
+	}
+
+	impl pallet_balances::BalancesApi<Block, AccountId, Balance> for Runtime {
+		fn balance_details(who: AccountId) -> pallet_balances::AccountBalanceDetails<Balance> {
+			Balances::balance_details(&who)
+		}
+	}
+
 	impl sp_block_builder::BlockBuilder<Block> for Runtime {
 		fn apply_extrinsic(extrinsic: <Block as BlockT>::Extrinsic) -> ApplyExtrinsicResult {
 			Executive::apply_extrinsic(extrinsic)
@@ -3057,6 +3299,22 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_offences_runtime_api::OffencesApi<
+		Block,
+		AccountId,
+		pallet_session::historical::IdentificationTuple<Runtime>,
+	> for Runtime {
+		fn offences_in_era(
+			era: sp_staking::EraIndex,
+		) -> Vec<pallet_offences::OffenceRecordOf<Runtime>> {
+			Offences::offences_in_era(era)
+		}
+
+		fn offences_of(who: AccountId) -> Vec<pallet_offences::OffenceRecordOf<Runtime>> {
+			Offences::offences_where(|record| record.offender.0 == who)
+		}
+	}
+
 	impl sp_consensus_babe::BabeApi<Block> for Runtime {
 		fn configuration() -> sp_consensus_babe::BabeConfiguration {
 			let epoch_config = Babe::epoch_config().unwrap_or(BABE_GENESIS_EPOCH_CONFIG);
@@ -3112,6 +3370,21 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl frame_support::view_functions::runtime_api::RuntimeViewFunction<Block> for Runtime {
+		fn execute_view_function(
+			query_id: frame_support::view_functions::ViewFunctionId,
+			input: Vec<u8>,
+		) -> Result<Vec<u8>, frame_support::view_functions::ViewFunctionDispatchError> {
+			frame_support::view_functions::execute_view_function::<(
+				Identity,
+				Deposits,
+				Multisig,
+				Remark,
+				Staking,
+			)>(query_id, input)
+		}
+	}
+
 	impl frame_system_rpc_runtime_api::AccountNonceApi<Block, AccountId, Nonce> for Runtime {
 		fn account_nonce(account: AccountId) -> Nonce {
 			System::account_nonce(account)
@@ -3314,6 +3587,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(6)]
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
 		Block,
 		Balance,
@@ -3330,6 +3604,31 @@ impl_runtime_apis! {
 		fn query_length_to_fee(length: u32) -> Balance {
 			TransactionPayment::length_to_fee(length)
 		}
+		fn query_fee_multiplier_projection(
+			block_fullness: Vec<Perquintill>,
+			weight: Weight,
+			length: u32,
+			target_block_fullness: Perquintill,
+			adjustment_variable: Multiplier,
+			minimum_multiplier: Multiplier,
+		) -> Vec<FeeMultiplierProjection<Balance>> {
+			TransactionPayment::simulate_fee_multiplier(
+				block_fullness,
+				weight,
+				length,
+				target_block_fullness,
+				adjustment_variable,
+				minimum_multiplier,
+			)
+		}
+		fn query_fee_breakdown(
+			uxt: <Block as BlockT>::Extrinsic,
+			len: u32,
+			tip: Balance,
+			blocks_ahead: u32,
+		) -> FeeBreakdown<Balance> {
+			TransactionPayment::query_fee_breakdown(uxt, len, tip, blocks_ahead)
+		}
 	}
 
 	impl pallet_asset_conversion::AssetConversionApi<
@@ -3654,6 +3953,7 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
 		fn build_state(config: Vec<u8>) -> sp_genesis_builder::Result {
 			build_state::<RuntimeGenesisConfig>(config)
@@ -3666,6 +3966,10 @@ impl_runtime_apis! {
 		fn preset_names() -> Vec<sp_genesis_builder::PresetId> {
 			vec![]
 		}
+
+		fn patchable_paths() -> Vec<alloc::string::String> {
+			patchable_paths::<RuntimeGenesisConfig>()
+		}
 	}
 }
 
@@ -0,0 +1,147 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Structural comparison of chain spec JSON documents.
+//!
+//! The result of a comparison is both a machine readable [JSON merge
+//! patch](https://datatracker.ietf.org/doc/html/rfc7386), compatible with
+//! [`json_patch::merge`](sc_chain_spec::json_patch::merge), and a list of individual [`Change`]s
+//! which can be rendered as a human-readable report.
+
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// Values longer than this are shortened in the human-readable report.
+const MAX_DISPLAYED_VALUE_LEN: usize = 64;
+
+/// The kind of a single difference between two JSON documents.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangeKind {
+	/// The value is present only in the new document.
+	Added(Value),
+	/// The value is present only in the old document.
+	Removed(Value),
+	/// The value is present in both documents, but differs.
+	Modified { old: Value, new: Value },
+}
+
+/// A single difference between two JSON documents.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+	/// Location of the change, as a JSON pointer (RFC 6901).
+	pub path: String,
+	/// What has changed.
+	pub kind: ChangeKind,
+}
+
+/// The difference between two JSON documents.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonDiff {
+	/// JSON merge patch which turns the old document into the new one.
+	///
+	/// Note that merge patches cannot express setting a key to `null`, as `null` denotes the key
+	/// removal. Such changes are still listed in [`JsonDiff::changes`].
+	pub patch: Value,
+	/// The list of changes, in the order of traversal.
+	pub changes: Vec<Change>,
+}
+
+impl JsonDiff {
+	/// Returns `true` if both compared documents are equal.
+	pub fn is_empty(&self) -> bool {
+		self.changes.is_empty()
+	}
+}
+
+impl fmt::Display for JsonDiff {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.changes.is_empty() {
+			return writeln!(f, "No differences.")
+		}
+		for Change { path, kind } in &self.changes {
+			match kind {
+				ChangeKind::Added(value) => writeln!(f, "+ {path}: {}", shorten(value))?,
+				ChangeKind::Removed(value) => writeln!(f, "- {path}: {}", shorten(value))?,
+				ChangeKind::Modified { old, new } =>
+					writeln!(f, "~ {path}: {} -> {}", shorten(old), shorten(new))?,
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Computes the difference between the `old` and `new` JSON documents.
+///
+/// Objects are compared key by key. Any other values (including arrays) are compared as a whole
+/// and replaced in the resulting patch if they differ.
+pub fn diff_json(old: &Value, new: &Value) -> JsonDiff {
+	let mut changes = Vec::new();
+	let patch =
+		diff_values(String::new(), old, new, &mut changes).unwrap_or_else(|| Map::new().into());
+	JsonDiff { patch, changes }
+}
+
+/// Compares two values located at `path`. Returns the merge patch, or `None` if values are equal.
+fn diff_values(path: String, old: &Value, new: &Value, changes: &mut Vec<Change>) -> Option<Value> {
+	match (old, new) {
+		(Value::Object(old), Value::Object(new)) => {
+			let mut patch = Map::new();
+			for (key, old_value) in old {
+				let path = format!("{path}/{}", escape(key));
+				match new.get(key) {
+					Some(new_value) => {
+						if let Some(value) = diff_values(path, old_value, new_value, changes) {
+							patch.insert(key.clone(), value);
+						}
+					},
+					None => {
+						changes.push(Change { path, kind: ChangeKind::Removed(old_value.clone()) });
+						patch.insert(key.clone(), Value::Null);
+					},
+				}
+			}
+			for (key, new_value) in new.iter().filter(|(key, _)| !old.contains_key(*key)) {
+				let path = format!("{path}/{}", escape(key));
+				changes.push(Change { path, kind: ChangeKind::Added(new_value.clone()) });
+				patch.insert(key.clone(), new_value.clone());
+			}
+			(!patch.is_empty()).then(|| patch.into())
+		},
+		(old, new) if old == new => None,
+		(old, new) => {
+			changes.push(Change {
+				path,
+				kind: ChangeKind::Modified { old: old.clone(), new: new.clone() },
+			});
+			Some(new.clone())
+		},
+	}
+}
+
+/// Escapes a single JSON pointer reference token.
+fn escape(key: &str) -> String {
+	key.replace('~', "~0").replace('/', "~1")
+}
+
+fn shorten(value: &Value) -> String {
+	let value = value.to_string();
+	match value.char_indices().nth(MAX_DISPLAYED_VALUE_LEN) {
+		Some((index, _)) => format!("{}...", &value[..index]),
+		None => value,
+	}
+}
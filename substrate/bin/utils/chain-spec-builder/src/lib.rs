@@ -19,6 +19,10 @@
 #[cfg(feature = "generate-readme")]
 docify::compile_markdown!("README.docify.md", "README.md");
 
+mod diff;
+
+pub use diff::{diff_json, Change, ChangeKind, JsonDiff};
+
 use clap::{Parser, Subcommand};
 use sc_chain_spec::{
 	json_patch, set_code_substitute_in_json_chain_spec, update_code_in_json_chain_spec, ChainType,
@@ -53,6 +57,7 @@ pub enum ChainSpecBuilderCmd {
 	ListPresets(ListPresetsCmd),
 	DisplayPreset(DisplayPresetCmd),
 	AddCodeSubstitute(AddCodeSubstituteCmd),
+	Diff(DiffCmd),
 }
 
 /// Create a new chain spec by interacting with the provided runtime wasm blob.
@@ -200,6 +205,33 @@ pub struct VerifyCmd {
 	pub input_chain_spec: PathBuf,
 }
 
+/// Compares two chain specs, or a chain spec against the runtime's genesis config preset.
+///
+/// A human-readable report of the differences is printed to stdout. Optionally, the JSON merge
+/// patch turning the old document into the new one can be written to a file.
+///
+/// When compared against the runtime, the plain genesis of the chain spec is compared with the
+/// given preset (or with the default genesis config if no preset name is given). A patch-based
+/// genesis is compared with the preset itself, while a full genesis config is compared with the
+/// runtime's default config patched with the preset.
+#[derive(Parser, Debug, Clone)]
+pub struct DiffCmd {
+	/// The old (reference) chain spec.
+	pub old_chain_spec: PathBuf,
+	/// The new chain spec to be compared against the old one.
+	#[arg(required_unless_present = "runtime", conflicts_with = "runtime")]
+	pub new_chain_spec: Option<PathBuf>,
+	/// The path to runtime wasm blob whose preset shall be compared with the chain spec genesis.
+	#[arg(long, short, alias = "runtime-wasm-path")]
+	pub runtime: Option<PathBuf>,
+	/// Name of the preset to be compared. If none is given default will be used.
+	#[arg(long, short, requires = "runtime")]
+	pub preset_name: Option<String>,
+	/// The path where the JSON patch should be saved.
+	#[arg(long)]
+	pub patch_path: Option<PathBuf>,
+}
+
 #[derive(Deserialize, Serialize, Clone)]
 pub struct ParachainExtension {
 	/// The relay chain of the Parachain.
@@ -305,6 +337,35 @@ impl ChainSpecBuilder {
 					.map_err(|e| format!("getting default config from runtime should work: {e}"))?;
 				println!("{preset}");
 			},
+			ChainSpecBuilderCmd::Diff(DiffCmd {
+				ref old_chain_spec,
+				ref new_chain_spec,
+				ref runtime,
+				ref preset_name,
+				ref patch_path,
+			}) => {
+				let diff = match (new_chain_spec, runtime) {
+					(Some(new_chain_spec), _) =>
+						diff_chain_specs(old_chain_spec.as_path(), new_chain_spec.as_path())?,
+					(None, Some(runtime)) => {
+						let code = fs::read(runtime.as_path())
+							.map_err(|e| format!("wasm blob shall be readable {e}"))?;
+						diff_chain_spec_against_preset(
+							old_chain_spec.as_path(),
+							&code[..],
+							preset_name.as_ref(),
+						)?
+					},
+					(None, None) =>
+						return Err("Either new chain spec or runtime must be provided".into()),
+				};
+				print!("{diff}");
+				if let Some(patch_path) = patch_path {
+					let patch = serde_json::to_string_pretty(&diff.patch)
+						.map_err(|e| format!("to pretty failed: {e}"))?;
+					fs::write(patch_path, patch).map_err(|err| err.to_string())?;
+				}
+			},
 		}
 		Ok(())
 	}
@@ -418,6 +479,48 @@ pub fn generate_chain_spec_for_runtime(cmd: &CreateCmd) -> Result<String, String
 	}
 }
 
+/// Compares two chain spec files.
+///
+/// Both plain and raw chain specs are supported. The returned patch turns the old chain spec into
+/// the new one.
+pub fn diff_chain_specs(old_chain_spec: &Path, new_chain_spec: &Path) -> Result<JsonDiff, String> {
+	let old = extract_chain_spec_json(old_chain_spec)?;
+	let new = extract_chain_spec_json(new_chain_spec)?;
+	Ok(diff_json(&old, &new))
+}
+
+/// Compares the plain genesis of the chain spec with the runtime's genesis config preset.
+///
+/// If no preset name is given, the runtime's default genesis config is used. The returned patch
+/// turns the chain spec genesis into the preset.
+pub fn diff_chain_spec_against_preset(
+	chain_spec: &Path,
+	code: &[u8],
+	preset_name: Option<&String>,
+) -> Result<JsonDiff, String> {
+	let chain_spec_json = extract_chain_spec_json(chain_spec)?;
+	let runtime_genesis = chain_spec_json
+		.pointer("/genesis/runtimeGenesis")
+		.ok_or("Chain spec does not contain plain runtime genesis")?;
+
+	let caller: GenesisConfigBuilderRuntimeCaller = GenesisConfigBuilderRuntimeCaller::new(code);
+	let preset = caller
+		.get_named_preset(preset_name)
+		.map_err(|e| format!("getting preset from runtime should work: {e}"))?;
+
+	if let Some(patch) = runtime_genesis.get("patch") {
+		Ok(diff_json(patch, &preset))
+	} else if let Some(config) = runtime_genesis.get("config") {
+		let mut expected = caller
+			.get_default_config()
+			.map_err(|e| format!("getting default config from runtime should work: {e}"))?;
+		json_patch::merge(&mut expected, preset);
+		Ok(diff_json(config, &expected))
+	} else {
+		Err("Chain spec runtime genesis contains neither patch nor config".into())
+	}
+}
+
 /// Extract any chain spec and convert it to JSON
 fn extract_chain_spec_json(input_chain_spec: &Path) -> Result<serde_json::Value, String> {
 	let chain_spec = &fs::read(input_chain_spec)
@@ -16,14 +16,16 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use std::fs::File;
+use std::{fs::File, path::Path};
 
 use clap::Parser;
 
 use cmd_lib::spawn_with_output;
-use sc_chain_spec::update_code_in_json_chain_spec;
-use serde_json::{from_reader, from_str, Value};
-use staging_chain_spec_builder::ChainSpecBuilder;
+use sc_chain_spec::{json_patch, update_code_in_json_chain_spec};
+use serde_json::{from_reader, from_str, json, Value};
+use staging_chain_spec_builder::{
+	diff_chain_spec_against_preset, diff_chain_specs, diff_json, ChainSpecBuilder,
+};
 
 // note: the runtime path will not be read, runtime code will be set directly, to avoid hassle with
 // creating the wasm file or providing a valid existing path during test execution.
@@ -234,6 +236,76 @@ fn test_add_code_substitute() {
 	assert_output_eq_expected(true, SUFFIX, "tests/expected/add_code_substitute.json");
 }
 
+#[test]
+fn test_diff() {
+	const SUFFIX: &str = "11";
+	let patch_path = OUTPUT_FILE.to_string() + SUFFIX;
+	let builder = get_builder(
+		SUFFIX,
+		vec![
+			"diff",
+			"tests/input/chain_spec_plain.json",
+			"tests/input/chain_spec_plain_modified.json",
+			"--patch-path",
+			patch_path.as_str(),
+		],
+	);
+	builder.run().unwrap();
+	assert_output_eq_expected(false, SUFFIX, "tests/expected/diff.json");
+}
+
+#[test]
+fn test_diff_patch_applies() {
+	let diff = diff_chain_specs(
+		Path::new("tests/input/chain_spec_plain.json"),
+		Path::new("tests/input/chain_spec_plain_modified.json"),
+	)
+	.unwrap();
+	let mut old: Value =
+		from_reader(File::open("tests/input/chain_spec_plain.json").unwrap()).unwrap();
+	let new: Value =
+		from_reader(File::open("tests/input/chain_spec_plain_modified.json").unwrap()).unwrap();
+	json_patch::merge(&mut old, diff.patch);
+	assert_eq!(old, new);
+	assert_eq!(diff.changes.len(), 5);
+}
+
+#[test]
+fn test_diff_json_report() {
+	let old = json!({"a": {"b": 1, "c": 2}, "d/e": [1], "f": "g"});
+	let new = json!({"a": {"b": 1, "c": 3, "x": true}, "d/e": [1, 2]});
+	let diff = diff_json(&old, &new);
+	assert_eq!(diff.patch, json!({"a": {"c": 3, "x": true}, "d/e": [1, 2], "f": null}));
+	assert_eq!(
+		diff.to_string(),
+		"~ /a/c: 2 -> 3\n+ /a/x: true\n~ /d~1e: [1] -> [1,2]\n- /f: \"g\"\n"
+	);
+
+	let diff = diff_json(&old, &old);
+	assert!(diff.is_empty());
+	assert_eq!(diff.patch, json!({}));
+	assert_eq!(diff.to_string(), "No differences.\n");
+}
+
+#[test]
+fn test_diff_against_preset() {
+	let diff = diff_chain_spec_against_preset(
+		Path::new("tests/expected/create_with_named_preset.json"),
+		substrate_test_runtime::WASM_BINARY.unwrap(),
+		Some(&"staging".to_string()),
+	)
+	.unwrap();
+	assert!(diff.is_empty());
+
+	let diff = diff_chain_spec_against_preset(
+		Path::new("tests/expected/create_with_named_preset.json"),
+		substrate_test_runtime::WASM_BINARY.unwrap(),
+		None,
+	)
+	.unwrap();
+	assert!(!diff.is_empty());
+}
+
 #[docify::export_content]
 fn cmd_create_default(runtime_path: &str) -> String {
 	bash!(
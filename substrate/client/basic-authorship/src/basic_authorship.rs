@@ -53,6 +53,13 @@ use sc_proposer_metrics::{EndProposingReason, MetricsLink as PrometheusMetrics};
 /// transferred to other nodes.
 pub const DEFAULT_BLOCK_SIZE_LIMIT: usize = 4 * 1024 * 1024 + 512;
 
+/// Default storage proof growth in bytes reserved for the next transaction by [`Proposer`], until
+/// it has seen a transaction that grows the proof by more.
+///
+/// Only used if [`ProposerFactory::enable_proof_growth_reservation`] was called. Can be
+/// overwritten by [`ProposerFactory::set_default_proof_growth`].
+pub const DEFAULT_PROOF_GROWTH: usize = 4 * 1024;
+
 const DEFAULT_SOFT_DEADLINE_PERCENT: Percent = Percent::from_percent(50);
 
 const LOG_TARGET: &'static str = "basic-authorship";
@@ -82,6 +89,11 @@ pub struct ProposerFactory<A, C, PR> {
 	telemetry: Option<TelemetryHandle>,
 	/// When estimating the block size, should the proof be included?
 	include_proof_in_block_size_estimation: bool,
+	/// Should block space be reserved for the proof growth of the next extrinsic?
+	reserve_proof_growth: bool,
+	/// The proof growth reserved for the next extrinsic before any extrinsic grew the proof by
+	/// more.
+	default_proof_growth: usize,
 	/// phantom member to pin the `ProofRecording` type.
 	_phantom: PhantomData<PR>,
 }
@@ -97,6 +109,8 @@ impl<A, C, PR> Clone for ProposerFactory<A, C, PR> {
 			soft_deadline_percent: self.soft_deadline_percent,
 			telemetry: self.telemetry.clone(),
 			include_proof_in_block_size_estimation: self.include_proof_in_block_size_estimation,
+			reserve_proof_growth: self.reserve_proof_growth,
+			default_proof_growth: self.default_proof_growth,
 			_phantom: self._phantom,
 		}
 	}
@@ -123,6 +137,8 @@ impl<A, C> ProposerFactory<A, C, DisableProofRecording> {
 			telemetry,
 			client,
 			include_proof_in_block_size_estimation: false,
+			reserve_proof_growth: false,
+			default_proof_growth: DEFAULT_PROOF_GROWTH,
 			_phantom: PhantomData,
 		}
 	}
@@ -151,6 +167,8 @@ impl<A, C> ProposerFactory<A, C, EnableProofRecording> {
 			soft_deadline_percent: DEFAULT_SOFT_DEADLINE_PERCENT,
 			telemetry,
 			include_proof_in_block_size_estimation: true,
+			reserve_proof_growth: false,
+			default_proof_growth: DEFAULT_PROOF_GROWTH,
 			_phantom: PhantomData,
 		}
 	}
@@ -159,6 +177,20 @@ impl<A, C> ProposerFactory<A, C, EnableProofRecording> {
 	pub fn disable_proof_in_block_size_estimation(&mut self) {
 		self.include_proof_in_block_size_estimation = false;
 	}
+
+	/// Reserve block space for the storage proof growth of the next transaction.
+	///
+	/// The proposer tracks by how much each pushed transaction grows the storage proof. When
+	/// enabled, the largest growth seen so far in the block, but at least the default proof growth
+	/// set with [`Self::set_default_proof_growth`], is added to the size of every transaction
+	/// before checking it against the block size limit. This stops the proposer
+	/// from pushing transactions that would very likely overrun the proof size limit (e.g. the
+	/// PoV limit of a parachain), instead of only noticing this after the fact.
+	///
+	/// Only has an effect if the proof is included in the block size estimation.
+	pub fn enable_proof_growth_reservation(&mut self) {
+		self.reserve_proof_growth = true;
+	}
 }
 
 impl<A, C, PR> ProposerFactory<A, C, PR> {
@@ -173,6 +205,16 @@ impl<A, C, PR> ProposerFactory<A, C, PR> {
 		self.default_block_size_limit = limit;
 	}
 
+	/// Set the default storage proof growth in bytes that is reserved for the next transaction.
+	///
+	/// The default value for the proof growth is: [`DEFAULT_PROOF_GROWTH`].
+	///
+	/// It is reserved until a transaction of the block grew the storage proof by more, and only
+	/// if [`ProposerFactory::enable_proof_growth_reservation`] was called.
+	pub fn set_default_proof_growth(&mut self, proof_growth: usize) {
+		self.default_proof_growth = proof_growth;
+	}
+
 	/// Set soft deadline percentage.
 	///
 	/// The value is used to compute soft deadline during block production.
@@ -223,6 +265,8 @@ where
 			telemetry: self.telemetry.clone(),
 			_phantom: PhantomData,
 			include_proof_in_block_size_estimation: self.include_proof_in_block_size_estimation,
+			reserve_proof_growth: self.reserve_proof_growth,
+			default_proof_growth: self.default_proof_growth,
 		};
 
 		proposer
@@ -257,6 +301,8 @@ pub struct Proposer<Block: BlockT, C, A: TransactionPool, PR> {
 	metrics: PrometheusMetrics,
 	default_block_size_limit: usize,
 	include_proof_in_block_size_estimation: bool,
+	reserve_proof_growth: bool,
+	default_proof_growth: usize,
 	soft_deadline_percent: Percent,
 	telemetry: Option<TelemetryHandle>,
 	_phantom: PhantomData<PR>,
@@ -414,6 +460,9 @@ where
 			now + time::Duration::from_micros(self.soft_deadline_percent.mul_floor(left_micros));
 		let mut skipped = 0;
 		let mut unqueue_invalid = Vec::new();
+		// The largest storage proof growth caused by a single transaction in this block, seeded
+		// with the default until a transaction grew the proof by more.
+		let mut max_proof_growth = self.default_proof_growth;
 
 		let delay = deadline.saturating_duration_since((self.now)()) / 8;
 		let mut pending_iterator =
@@ -451,7 +500,13 @@ where
 
 			let block_size =
 				block_builder.estimate_block_size(self.include_proof_in_block_size_estimation);
-			if block_size + pending_tx_data.encoded_size() > block_size_limit {
+			let proof_reserve =
+				if self.include_proof_in_block_size_estimation && self.reserve_proof_growth {
+					max_proof_growth
+				} else {
+					0
+				};
+			if block_size + pending_tx_data.encoded_size() + proof_reserve > block_size_limit {
 				pending_iterator.report_invalid(&pending_tx);
 				if skipped < MAX_SKIPPED_TRANSACTIONS {
 					skipped += 1;
@@ -480,10 +535,28 @@ where
 			}
 
 			trace!(target: LOG_TARGET, "[{:?}] Pushing to the block.", pending_tx_hash);
+			let proof_size_before = block_builder.estimate_proof_size();
 			match sc_block_builder::BlockBuilder::push(block_builder, pending_tx_data) {
 				Ok(()) => {
 					transaction_pushed = true;
-					debug!(target: LOG_TARGET, "[{:?}] Pushed to the block.", pending_tx_hash);
+					let proof_growth = proof_size_before
+						.zip(block_builder.estimate_proof_size())
+						.map(|(before, after)| after.saturating_sub(before));
+
+					if let Some(proof_growth) = proof_growth {
+						max_proof_growth = max_proof_growth.max(proof_growth);
+						self.metrics.report(|metrics| {
+							metrics.extrinsic_proof_size.observe(proof_growth as f64);
+						});
+						debug!(
+							target: LOG_TARGET,
+							"[{:?}] Pushed to the block, storage proof grew by {} bytes.",
+							pending_tx_hash,
+							proof_growth,
+						);
+					} else {
+						debug!(target: LOG_TARGET, "[{:?}] Pushed to the block.", pending_tx_hash);
+					}
 				},
 				Err(ApplyExtrinsicFailed(Validity(e))) if e.exhausted_resources() => {
 					pending_iterator.report_invalid(&pending_tx);
@@ -993,6 +1066,32 @@ mod tests {
 		// block size and thus, only the `Transfer` will fit into the block. It reads more data
 		// than we have reserved in the block limit.
 		assert_eq!(block.extrinsics().len(), 1);
+
+		// The default proof growth is reserved for the first transaction, which then no longer
+		// fits into the block.
+		proposer_factory.enable_proof_growth_reservation();
+		let proposer = block_on(proposer_factory.init(&genesis_header)).unwrap();
+		let block = block_on(proposer.propose(
+			Default::default(),
+			Default::default(),
+			deadline,
+			Some(block_limit),
+		))
+		.map(|r| r.block)
+		.unwrap();
+		assert_eq!(block.extrinsics().len(), 0);
+
+		proposer_factory.set_default_proof_growth(0);
+		let proposer = block_on(proposer_factory.init(&genesis_header)).unwrap();
+		let block = block_on(proposer.propose(
+			Default::default(),
+			Default::default(),
+			deadline,
+			Some(block_limit),
+		))
+		.map(|r| r.block)
+		.unwrap();
+		assert_eq!(block.extrinsics().len(), 1);
 	}
 
 	#[test]
@@ -26,7 +26,7 @@ use sp_core::{
 	storage::Storage,
 	traits::{CallContext, CodeExecutor, Externalities, FetchRuntimeCode, RuntimeCode},
 };
-use sp_genesis_builder::{PresetId, Result as BuildResult};
+use sp_genesis_builder::{validation, PresetId, Result as BuildResult};
 pub use sp_genesis_builder::{DEV_RUNTIME_PRESET, LOCAL_TESTNET_RUNTIME_PRESET};
 use sp_state_machine::BasicExternalities;
 use std::borrow::Cow;
@@ -147,10 +147,20 @@ where
 	///    the resulting `RuntimeGenesisConfig`.
 	///
 	/// Please note that the patch may contain full `RuntimeGenesisConfig`.
+	///
+	/// If the patched `RuntimeGenesisConfig` can't be built, keys of the patch which are not part
+	/// of the default `RuntimeGenesisConfig` and values of the wrong JSON type are reported with
+	/// their path.
 	pub fn get_storage_for_patch(&self, patch: Value) -> core::result::Result<Storage, String> {
 		let mut config = self.get_default_config()?;
+		// The default config does not know the serde aliases of the fields, so it is only used to
+		// point at the offending field once building the state failed.
+		let path_error = validation::validate_patch(&config, &patch).err();
 		crate::json_patch::merge(&mut config, patch);
-		self.get_storage_for_config(config)
+		self.get_storage_for_config(config).map_err(|e| match path_error {
+			Some(path_error) => format!("Invalid patch: {path_error}"),
+			None => e,
+		})
 	}
 
 	pub fn get_storage_for_named_preset(
@@ -171,6 +181,19 @@ where
 
 		Ok(preset_names)
 	}
+
+	/// Returns the paths of all fields of the `RuntimeGenesisConfig` which can be set by a patch.
+	///
+	/// Calls [`GenesisBuilder::patchable_paths`](sp_genesis_builder::GenesisBuilder::patchable_paths)
+	/// provided by the `runtime`, which is only available from version 2 of the API.
+	pub fn patchable_paths(&self) -> core::result::Result<Vec<String>, String> {
+		let mut t = BasicExternalities::new_empty();
+		let call_result = self
+			.call(&mut t, "GenesisBuilder_patchable_paths", &vec![])
+			.map_err(|e| format!("wasm call error {e}"))?;
+
+		Vec::<String>::decode(&mut &call_result[..]).map_err(|e| format!("scale codec error: {e}"))
+	}
 }
 
 #[cfg(test)]
@@ -250,4 +273,21 @@ mod tests {
 			}
 		);
 	}
+
+	#[test]
+	fn get_storage_for_patch_reports_unknown_fields() {
+		let patch = json!({
+			"babe": {
+				"epochConfig": {
+					"d": [69, 696],
+				}
+			},
+		});
+
+		let error =
+			<GenesisConfigBuilderRuntimeCaller>::new(substrate_test_runtime::wasm_binary_unwrap())
+				.get_storage_for_patch(patch)
+				.unwrap_err();
+		assert_eq!(error, "Invalid patch: unknown field `babe.epochConfig.d`");
+	}
 }
@@ -35,7 +35,7 @@ use sc_service::{
 	BlocksPruning, ChainSpec, TracingReceiver,
 };
 use sc_tracing::logging::LoggerBuilder;
-use std::{num::NonZeroU32, path::PathBuf};
+use std::{collections::HashMap, num::NonZeroU32, path::PathBuf};
 
 /// The maximum number of characters for a node name.
 pub(crate) const NODE_NAME_MAX_LENGTH: usize = 64;
@@ -301,6 +301,13 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 		self.import_params().map(|x| x.wasm_runtime_overrides()).unwrap_or_default()
 	}
 
+	/// Get the heap pages overrides for specific runtime API calls.
+	///
+	/// By default this is retrieved from `ImportParams` if it is available. Otherwise it is empty.
+	fn runtime_call_heap_pages(&self) -> Result<HashMap<String, u64>> {
+		Ok(self.import_params().map(|x| x.runtime_call_heap_pages()).unwrap_or_default())
+	}
+
 	/// Get the RPC address.
 	fn rpc_addr(&self, _default_listen_port: u16) -> Result<Option<Vec<RpcEndpoint>>> {
 		Ok(None)
@@ -533,6 +540,7 @@ pub trait CliConfiguration<DCV: DefaultConfigurationValues = ()>: Sized {
 			executor: ExecutorConfiguration {
 				wasm_method: self.wasm_method()?,
 				default_heap_pages: self.default_heap_pages()?,
+				runtime_call_heap_pages: self.runtime_call_heap_pages()?,
 				max_runtime_instances,
 				runtime_cache_size,
 			},
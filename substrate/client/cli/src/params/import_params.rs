@@ -24,7 +24,7 @@ use crate::{
 	params::{DatabaseParams, PruningParams},
 };
 use clap::Args;
-use std::path::PathBuf;
+use std::{collections::HashMap, path::PathBuf};
 
 /// Parameters for block import.
 #[derive(Debug, Clone, Args)]
@@ -71,6 +71,15 @@ pub struct ImportParams {
 	#[arg(long, value_name = "PATH")]
 	pub wasm_runtime_overrides: Option<PathBuf>,
 
+	/// Override the number of heap pages used for a specific runtime API call.
+	///
+	/// Expects `<METHOD>=<PAGES>`, e.g. `TaggedTransactionQueue_validate_transaction=4096`.
+	/// Can be passed multiple times. Only the named runtime API calls made by the node are
+	/// affected; all other calls keep using the heap pages configured on chain.
+	/// This should only be used to mitigate runtime bugs until a fixed runtime is enacted.
+	#[arg(long, value_name = "METHOD=PAGES", value_parser = parse_runtime_call_heap_pages)]
+	pub runtime_call_heap_pages: Vec<(String, u64)>,
+
 	#[allow(missing_docs)]
 	#[clap(flatten)]
 	pub execution_strategies: ExecutionStrategiesParams,
@@ -112,6 +121,28 @@ impl ImportParams {
 	pub fn wasm_runtime_overrides(&self) -> Option<PathBuf> {
 		self.wasm_runtime_overrides.clone()
 	}
+
+	/// Get the heap pages overrides for specific runtime API calls.
+	pub fn runtime_call_heap_pages(&self) -> HashMap<String, u64> {
+		self.runtime_call_heap_pages.iter().cloned().collect()
+	}
+}
+
+/// Parse a `<METHOD>=<PAGES>` runtime call heap pages override.
+fn parse_runtime_call_heap_pages(s: &str) -> Result<(String, u64), String> {
+	let (method, pages) = s
+		.split_once('=')
+		.ok_or_else(|| format!("Invalid runtime call override `{s}`, expected `METHOD=PAGES`"))?;
+
+	if method.is_empty() {
+		return Err(format!("Missing runtime API method name in `{s}`"))
+	}
+
+	let pages = pages
+		.parse::<u64>()
+		.map_err(|e| format!("Invalid number of heap pages `{pages}`: {e}"))?;
+
+	Ok((method.to_string(), pages))
 }
 
 /// Execution strategies parameters.
@@ -173,3 +204,33 @@ impl ExecutionStrategiesParams {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use clap::Parser;
+
+	#[derive(Parser)]
+	struct Cli {
+		#[clap(flatten)]
+		import: ImportParams,
+	}
+
+	#[test]
+	fn runtime_call_heap_pages_parse_works() {
+		let Cli { import } = Cli::parse_from([
+			"",
+			"--runtime-call-heap-pages=TaggedTransactionQueue_validate_transaction=4096",
+			"--runtime-call-heap-pages=Core_version=64",
+		]);
+
+		let overrides = import.runtime_call_heap_pages();
+		assert_eq!(overrides.len(), 2);
+		assert_eq!(overrides.get("TaggedTransactionQueue_validate_transaction"), Some(&4096));
+		assert_eq!(overrides.get("Core_version"), Some(&64));
+
+		assert!(Cli::try_parse_from(["", "--runtime-call-heap-pages=Core_version"]).is_err());
+		assert!(Cli::try_parse_from(["", "--runtime-call-heap-pages==64"]).is_err());
+		assert!(Cli::try_parse_from(["", "--runtime-call-heap-pages=Core_version=x"]).is_err());
+	}
+}
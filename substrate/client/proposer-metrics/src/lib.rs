@@ -19,8 +19,8 @@
 //! Prometheus basic proposer metrics.
 
 use prometheus_endpoint::{
-	prometheus::CounterVec, register, Gauge, Histogram, HistogramOpts, Opts, PrometheusError,
-	Registry, U64,
+	exponential_buckets, prometheus::CounterVec, register, Gauge, Histogram, HistogramOpts, Opts,
+	PrometheusError, Registry, U64,
 };
 
 /// Optional shareable link to basic authorship metrics.
@@ -62,6 +62,7 @@ pub struct Metrics {
 	pub end_proposing_reason: CounterVec,
 	pub create_inherents_time: Histogram,
 	pub create_block_proposal_time: Histogram,
+	pub extrinsic_proof_size: Histogram,
 }
 
 impl Metrics {
@@ -95,6 +96,16 @@ impl Metrics {
 				))?,
 				registry,
 			)?,
+			extrinsic_proof_size: register(
+				Histogram::with_opts(
+					HistogramOpts::new(
+						"substrate_proposer_extrinsic_proof_size",
+						"Histogram of the storage proof size in bytes added by a single transaction",
+					)
+					.buckets(exponential_buckets(64.0, 4.0, 10)?),
+				)?,
+				registry,
+			)?,
 			end_proposing_reason: register(
 				CounterVec::new(
 					Opts::new(
@@ -48,6 +48,20 @@ pub struct BlockStats {
 	pub num_extrinsics: u64,
 }
 
+/// Storage proof sizes of a block attributed to the individual extrinsics, returned by the
+/// `dev_getExtrinsicProofSizes` RPC.
+#[derive(Eq, PartialEq, Clone, Encode, Decode, Debug, TypeInfo, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtrinsicProofSizes {
+	/// The estimated proof size in bytes recorded while initializing the block.
+	pub initialization: u64,
+	/// The estimated proof size in bytes each extrinsic added to the proof, in block order.
+	///
+	/// Storage that was already accessed by an earlier extrinsic or while initializing the block
+	/// is only attributed to the first access.
+	pub extrinsics: Vec<u64>,
+}
+
 /// Substrate dev API.
 ///
 /// This API contains unstable and unsafe methods only meant for development nodes. They
@@ -61,4 +75,14 @@ pub trait DevApi<Hash> {
 	/// this function will return `None`.
 	#[method(name = "dev_getBlockStats", with_extensions)]
 	fn block_stats(&self, block_hash: Hash) -> Result<Option<BlockStats>, Error>;
+
+	/// Reexecute the specified `block_hash` and attribute the growth of the storage proof to
+	/// the individual extrinsics.
+	///
+	/// This function requires the specified block and its parent to be available
+	/// at the queried node. If either the specified block or the parent is pruned,
+	/// this function will return `None`.
+	#[method(name = "dev_getExtrinsicProofSizes", with_extensions)]
+	fn extrinsic_proof_sizes(&self, block_hash: Hash)
+		-> Result<Option<ExtrinsicProofSizes>, Error>;
 }
@@ -38,6 +38,19 @@ pub trait StateApi<Hash> {
 	#[method(name = "state_call", aliases = ["state_callAt"], blocking)]
 	fn call(&self, name: String, bytes: Bytes, hash: Option<Hash>) -> Result<Bytes, Error>;
 
+	/// Execute a view function at a block's state.
+	///
+	/// `view_function_id` is the 32 byte identifier of the view function and `input` its SCALE
+	/// encoded arguments. Returns the SCALE encoded result of the `RuntimeViewFunction` runtime
+	/// API, i.e. either the encoded return value of the view function or the dispatch error.
+	#[method(name = "state_callView", blocking)]
+	fn call_view(
+		&self,
+		view_function_id: Bytes,
+		input: Bytes,
+		hash: Option<Hash>,
+	) -> Result<Bytes, Error>;
+
 	/// Returns the keys with prefix, leave empty to get all the keys.
 	#[method(name = "state_getKeys", blocking)]
 	#[deprecated(since = "2.0.0", note = "Please use `getKeysPaged` with proper paging support")]
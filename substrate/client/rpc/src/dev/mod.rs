@@ -23,9 +23,10 @@
 mod tests;
 
 use jsonrpsee::Extensions;
+use sc_block_builder::{BlockBuilder, BlockBuilderApi, BlockBuilderBuilder};
 use sc_client_api::{BlockBackend, HeaderBackend};
 use sc_rpc_api::{check_if_safe, dev::error::Error};
-use sp_api::{ApiExt, Core, ProvideRuntimeApi};
+use sp_api::{ApiExt, CallApiAt, Core, ProvideRuntimeApi};
 use sp_core::Encode;
 use sp_runtime::{
 	generic::DigestItem,
@@ -36,7 +37,7 @@ use std::{
 	sync::Arc,
 };
 
-pub use sc_rpc_api::dev::{BlockStats, DevApiServer};
+pub use sc_rpc_api::dev::{BlockStats, DevApiServer, ExtrinsicProofSizes};
 
 type HasherOf<Block> = <<Block as BlockT>::Header as Header>::Hashing;
 
@@ -59,10 +60,11 @@ where
 	Client: BlockBackend<Block>
 		+ HeaderBackend<Block>
 		+ ProvideRuntimeApi<Block>
+		+ CallApiAt<Block>
 		+ Send
 		+ Sync
 		+ 'static,
-	Client::Api: Core<Block>,
+	Client::Api: Core<Block> + BlockBuilderApi<Block>,
 {
 	fn block_stats(
 		&self,
@@ -71,6 +73,82 @@ where
 	) -> Result<Option<BlockStats>, Error> {
 		check_if_safe(ext)?;
 
+		let Some((block, parent_header)) = self.block_and_parent_header(hash)? else {
+			return Ok(None)
+		};
+		let block_len = block.encoded_size() as u64;
+		let num_extrinsics = block.extrinsics().len() as u64;
+		let pre_root = *parent_header.state_root();
+		let mut runtime_api = self.client.runtime_api();
+		runtime_api.record_proof();
+		runtime_api
+			.execute_block(parent_header.hash(), block)
+			.map_err(|_| Error::BlockExecutionFailed)?;
+		let witness = runtime_api
+			.extract_proof()
+			.expect("We enabled proof recording. A proof must be available; qed");
+		let witness_len = witness.encoded_size() as u64;
+		let witness_compact_len = witness
+			.into_compact_proof::<HasherOf<Block>>(pre_root)
+			.map_err(|_| Error::WitnessCompactionFailed)?
+			.encoded_size() as u64;
+		Ok(Some(BlockStats { witness_len, witness_compact_len, block_len, num_extrinsics }))
+	}
+
+	fn extrinsic_proof_sizes(
+		&self,
+		ext: &Extensions,
+		hash: Block::Hash,
+	) -> Result<Option<ExtrinsicProofSizes>, Error> {
+		check_if_safe(ext)?;
+
+		let Some((block, parent_header)) = self.block_and_parent_header(hash)? else {
+			return Ok(None)
+		};
+		let (header, body) = block.deconstruct();
+
+		let mut block_builder = BlockBuilderBuilder::new(&*self.client)
+			.on_parent_block(parent_header.hash())
+			.with_parent_block_number(*parent_header.number())
+			.enable_proof_recording()
+			.with_inherent_digests(header.digest().clone())
+			.build()
+			.map_err(|_| Error::BlockExecutionFailed)?;
+
+		let current_proof_size = |block_builder: &BlockBuilder<'_, Block, Client>| {
+			block_builder
+				.estimate_proof_size()
+				.expect("We enabled proof recording. A proof must be available; qed")
+		};
+
+		let initialization = current_proof_size(&block_builder);
+		let mut last_proof_size = initialization;
+		let mut extrinsics = Vec::with_capacity(body.len());
+		for extrinsic in body {
+			block_builder.push(extrinsic).map_err(|_| Error::BlockExecutionFailed)?;
+
+			let proof_size = current_proof_size(&block_builder);
+			extrinsics.push(proof_size.saturating_sub(last_proof_size) as u64);
+			last_proof_size = proof_size;
+		}
+
+		Ok(Some(ExtrinsicProofSizes { initialization: initialization as u64, extrinsics }))
+	}
+}
+
+impl<Block, Client> Dev<Block, Client>
+where
+	Block: BlockT,
+	Client: BlockBackend<Block> + HeaderBackend<Block>,
+{
+	/// Returns the block with the given `hash`, stripped of its `Seal`, and the header of its
+	/// parent.
+	///
+	/// Returns `None` if either of them is not available.
+	fn block_and_parent_header(
+		&self,
+		hash: Block::Hash,
+	) -> Result<Option<(Block, Block::Header)>, Error> {
 		let block = {
 			let block = self.client.block(hash).map_err(|e| Error::BlockQueryError(Box::new(e)))?;
 			if let Some(block) = block {
@@ -95,22 +173,7 @@ where
 				return Ok(None)
 			}
 		};
-		let block_len = block.encoded_size() as u64;
-		let num_extrinsics = block.extrinsics().len() as u64;
-		let pre_root = *parent_header.state_root();
-		let mut runtime_api = self.client.runtime_api();
-		runtime_api.record_proof();
-		runtime_api
-			.execute_block(parent_header.hash(), block)
-			.map_err(|_| Error::BlockExecutionFailed)?;
-		let witness = runtime_api
-			.extract_proof()
-			.expect("We enabled proof recording. A proof must be available; qed");
-		let witness_len = witness.encoded_size() as u64;
-		let witness_compact_len = witness
-			.into_compact_proof::<HasherOf<Block>>(pre_root)
-			.map_err(|_| Error::WitnessCompactionFailed)?
-			.encoded_size() as u64;
-		Ok(Some(BlockStats { witness_len, witness_compact_len, block_len, num_extrinsics }))
+
+		Ok(Some((block, parent_header)))
 	}
 }
@@ -21,7 +21,10 @@ use crate::DenyUnsafe;
 use sc_block_builder::BlockBuilderBuilder;
 use sp_blockchain::HeaderBackend;
 use sp_consensus::BlockOrigin;
-use substrate_test_runtime_client::{prelude::*, runtime::Block};
+use substrate_test_runtime_client::{
+	prelude::*,
+	runtime::{Block, Transfer},
+};
 
 #[tokio::test]
 async fn block_stats_work() {
@@ -76,6 +79,57 @@ async fn block_stats_work() {
 	);
 }
 
+#[tokio::test]
+async fn extrinsic_proof_sizes_work() {
+	let client = Arc::new(substrate_test_runtime_client::new());
+	let mut api = <Dev<Block, _>>::new(client.clone()).into_rpc();
+	api.extensions_mut().insert(DenyUnsafe::No);
+
+	let mut builder = BlockBuilderBuilder::new(&*client)
+		.on_parent_block(client.chain_info().genesis_hash)
+		.with_parent_block_number(0)
+		.build()
+		.unwrap();
+	for nonce in 0..2 {
+		builder
+			.push_transfer(Transfer {
+				from: AccountKeyring::Alice.into(),
+				to: AccountKeyring::Ferdie.into(),
+				amount: 42,
+				nonce,
+			})
+			.unwrap();
+	}
+	let block = builder.build().unwrap().block;
+	client.import(BlockOrigin::Own, block).await.unwrap();
+
+	// Can't gather proof sizes for a block without a parent.
+	assert_eq!(
+		api.call::<_, Option<ExtrinsicProofSizes>>(
+			"dev_getExtrinsicProofSizes",
+			[client.genesis_hash()]
+		)
+		.await
+		.unwrap(),
+		None
+	);
+
+	let sizes = api
+		.call::<_, Option<ExtrinsicProofSizes>>(
+			"dev_getExtrinsicProofSizes",
+			[client.info().best_hash],
+		)
+		.await
+		.unwrap()
+		.unwrap();
+
+	assert!(sizes.initialization > 0);
+	assert_eq!(sizes.extrinsics.len(), 2);
+	// The first transfer touches the accounts, the second one only re-reads them.
+	assert!(sizes.extrinsics[0] > 0);
+	assert!(sizes.extrinsics[1] <= sizes.extrinsics[0]);
+}
+
 #[tokio::test]
 async fn deny_unsafe_works() {
 	let client = Arc::new(substrate_test_runtime_client::new());
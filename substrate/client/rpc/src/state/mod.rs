@@ -25,6 +25,7 @@ mod utils;
 mod tests;
 
 use crate::SubscriptionTaskExecutor;
+use codec::Encode;
 use jsonrpsee::{core::async_trait, Extensions, PendingSubscriptionSink};
 use sc_client_api::{
 	Backend, BlockBackend, BlockchainEvents, ExecutorProvider, ProofProvider, StorageProvider,
@@ -209,6 +210,31 @@ where
 		self.backend.call(block, method, data).map_err(Into::into)
 	}
 
+	fn call_view(
+		&self,
+		view_function_id: Bytes,
+		input: Bytes,
+		block: Option<Block::Hash>,
+	) -> Result<Bytes, Error> {
+		if view_function_id.len() != 32 {
+			return Err(Error::Client(
+				format!(
+					"Invalid view function id length: expected 32 bytes, got {}",
+					view_function_id.len()
+				)
+				.into(),
+			))
+		}
+
+		// The view function id is a fixed size array, so it is encoded as is.
+		let mut call_data = view_function_id.0;
+		input.0.encode_to(&mut call_data);
+
+		self.backend
+			.call(block, "RuntimeViewFunction_execute_view_function".into(), call_data.into())
+			.map_err(Into::into)
+	}
+
 	fn storage_keys(
 		&self,
 		key_prefix: StorageKey,
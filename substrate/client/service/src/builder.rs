@@ -256,6 +256,7 @@ where
 				no_genesis: config.no_genesis(),
 				wasm_runtime_substitutes,
 				enable_import_proof_recording,
+				runtime_call_heap_pages: config.executor.runtime_call_heap_pages.clone(),
 			},
 		)?;
 
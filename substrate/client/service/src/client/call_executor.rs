@@ -22,14 +22,14 @@ use sc_client_api::{
 };
 use sc_executor::{RuntimeVersion, RuntimeVersionOf};
 use sp_api::ProofRecorder;
-use sp_core::traits::{CallContext, CodeExecutor};
+use sp_core::traits::{CallContext, CodeExecutor, RuntimeCode};
 use sp_externalities::Extensions;
 use sp_runtime::{
 	generic::BlockId,
 	traits::{Block as BlockT, HashingFor},
 };
 use sp_state_machine::{backend::AsTrieBackend, OverlayedChanges, StateMachine, StorageProof};
-use std::{cell::RefCell, sync::Arc};
+use std::{cell::RefCell, collections::HashMap, sync::Arc};
 
 /// Call executor that executes methods locally, querying all required
 /// data from local backend.
@@ -38,6 +38,7 @@ pub struct LocalCallExecutor<Block: BlockT, B, E> {
 	executor: E,
 	code_provider: CodeProvider<Block, B, E>,
 	execution_extensions: Arc<ExecutionExtensions<Block>>,
+	runtime_call_heap_pages: Arc<HashMap<String, u64>>,
 }
 
 impl<Block: BlockT, B, E> LocalCallExecutor<Block, B, E>
@@ -59,8 +60,20 @@ where
 			executor,
 			code_provider,
 			execution_extensions: Arc::new(execution_extensions),
+			runtime_call_heap_pages: Arc::new(client_config.runtime_call_heap_pages),
 		})
 	}
+
+	/// Apply the heap pages override configured for `method`, if there is any.
+	fn apply_heap_pages_override(&self, runtime_code: &mut RuntimeCode, method: &str) {
+		if let Some(heap_pages) = self.runtime_call_heap_pages.get(method) {
+			log::debug!(
+				target: "executor",
+				"Overriding heap pages for runtime call `{method}` with {heap_pages}",
+			);
+			runtime_code.heap_pages = Some(*heap_pages);
+		}
+	}
 }
 
 impl<Block: BlockT, B, E> Clone for LocalCallExecutor<Block, B, E>
@@ -73,6 +86,7 @@ where
 			executor: self.executor.clone(),
 			code_provider: self.code_provider.clone(),
 			execution_extensions: self.execution_extensions.clone(),
+			runtime_call_heap_pages: self.runtime_call_heap_pages.clone(),
 		}
 	}
 }
@@ -107,7 +121,9 @@ where
 		let runtime_code =
 			state_runtime_code.runtime_code().map_err(sp_blockchain::Error::RuntimeCode)?;
 
-		let runtime_code = self.code_provider.maybe_override_code(runtime_code, &state, at_hash)?.0;
+		let mut runtime_code =
+			self.code_provider.maybe_override_code(runtime_code, &state, at_hash)?.0;
+		self.apply_heap_pages_override(&mut runtime_code, method);
 
 		let mut extensions = self.execution_extensions.extensions(at_hash, at_number);
 
@@ -147,7 +163,9 @@ where
 
 		let runtime_code =
 			state_runtime_code.runtime_code().map_err(sp_blockchain::Error::RuntimeCode)?;
-		let runtime_code = self.code_provider.maybe_override_code(runtime_code, &state, at_hash)?.0;
+		let mut runtime_code =
+			self.code_provider.maybe_override_code(runtime_code, &state, at_hash)?.0;
+		self.apply_heap_pages_override(&mut runtime_code, method);
 		let mut extensions = extensions.borrow_mut();
 
 		match recorder {
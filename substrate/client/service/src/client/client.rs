@@ -201,6 +201,9 @@ pub struct ClientConfig<Block: BlockT> {
 	pub wasm_runtime_substitutes: HashMap<NumberFor<Block>, Vec<u8>>,
 	/// Enable recording of storage proofs during block import
 	pub enable_import_proof_recording: bool,
+	/// Map of runtime API method names to the number of heap pages that should be used when
+	/// calling them, overriding the on-chain value.
+	pub runtime_call_heap_pages: HashMap<String, u64>,
 }
 
 impl<Block: BlockT> Default for ClientConfig<Block> {
@@ -212,6 +215,7 @@ impl<Block: BlockT> Default for ClientConfig<Block> {
 			no_genesis: false,
 			wasm_runtime_substitutes: HashMap::new(),
 			enable_import_proof_recording: false,
+			runtime_call_heap_pages: HashMap::new(),
 		}
 	}
 }
@@ -40,6 +40,7 @@ pub use sc_telemetry::TelemetryEndpoints;
 pub use sc_transaction_pool::TransactionPoolOptions;
 use sp_core::crypto::SecretString;
 use std::{
+	collections::HashMap,
 	io, iter,
 	net::SocketAddr,
 	num::NonZeroU32,
@@ -334,6 +335,10 @@ pub struct ExecutorConfiguration {
 	pub max_runtime_instances: usize,
 	/// The default number of 64KB pages to allocate for Wasm execution
 	pub default_heap_pages: Option<u64>,
+	/// Number of 64KB pages to allocate for specific runtime API calls, keyed by the method name.
+	///
+	/// Takes precedence over the on-chain heap pages for the given calls.
+	pub runtime_call_heap_pages: HashMap<String, u64>,
 	/// Maximum number of different runtime versions that can be cached.
 	pub runtime_cache_size: u8,
 }
@@ -344,6 +349,7 @@ impl Default for ExecutorConfiguration {
 			wasm_method: WasmExecutionMethod::default(),
 			max_runtime_instances: 8,
 			default_heap_pages: None,
+			runtime_call_heap_pages: HashMap::new(),
 			runtime_cache_size: 2,
 		}
 	}
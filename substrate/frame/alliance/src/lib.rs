@@ -143,8 +143,9 @@ pub trait IdentityVerifier<AccountId> {
 	/// Whether an account has been deemed "good" by the provider.
 	fn has_good_judgement(who: &AccountId) -> bool;
 
-	/// If the identity provider allows sub-accounts, provide the super of an account. Should
-	/// return `None` if the provider does not allow sub-accounts or if the account is not a sub.
+	/// If the identity provider allows sub-accounts, provide the account whose identity a
+	/// sub-account is known by, which may be more than one level above it. Should return `None`
+	/// if the provider does not allow sub-accounts or if the account is not a sub.
 	fn super_account_id(who: &AccountId) -> Option<AccountId>;
 }
 
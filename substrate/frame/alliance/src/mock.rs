@@ -31,7 +31,7 @@ pub use frame_support::{
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use pallet_identity::{
 	legacy::{IdentityField, IdentityInfo},
-	Data, IdentityOf, Judgement, SuperOf,
+	Data, IdentityOf, Judgement,
 };
 
 pub use crate as pallet_alliance;
@@ -106,14 +106,20 @@ type EnsureTwoOrRoot = EitherOfDiverse<EnsureRoot<AccountId>, EnsureSignedBy<Two
 impl pallet_identity::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type DepositTracker = ();
+	type DepositAsset = ();
 	type BasicDeposit = BasicDeposit;
 	type ByteDeposit = ByteDeposit;
 	type UsernameDeposit = UsernameDeposit;
 	type SubAccountDeposit = SubAccountDeposit;
+	type PokeDepositReward = ();
 	type MaxSubAccounts = MaxSubAccounts;
+	type MaxSubDepth = ConstU32<1>;
 	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
 	type MaxRegistrars = MaxRegistrars;
+	type MaxFeeTiers = ConstU32<8>;
 	type Slashed = ();
+	type SlashPortion = ();
 	type RegistrarOrigin = EnsureOneOrRoot;
 	type ForceOrigin = EnsureTwoOrRoot;
 	type OffchainSignature = AccountU64;
@@ -123,6 +129,9 @@ impl pallet_identity::Config for Test {
 	type UsernameGracePeriod = UsernameGracePeriod;
 	type MaxSuffixLength = ConstU32<7>;
 	type MaxUsernameLength = ConstU32<32>;
+	type OnIdentityChange = ();
+	type AttesterId = pallet_identity::offchain::crypto::Public;
+	type SubmitAttestation = ();
 	type WeightInfo = ();
 }
 
@@ -164,7 +173,7 @@ impl IdentityVerifier<AccountId> for AllianceIdentityVerifier {
 	}
 
 	fn super_account_id(who: &AccountId) -> Option<AccountId> {
-		SuperOf::<Test>::get(who).map(|parent| parent.0)
+		Identity::identity_root(who).filter(|root| root != who)
 	}
 }
 
@@ -112,6 +112,9 @@ impl pallet_asset_conversion::Config for Test {
 	type LPFee = ConstU32<3>;
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
 	type MaxSwapPathLength = ConstU32<4>;
+	type MaxOrdersPerPool = ConstU32<8>;
+	type MaxOrderFillsPerBlock = ConstU32<8>;
+	type OrderDeposit = ConstU64<100>;
 	type MintMinLiquidity = ConstU64<100>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -166,6 +166,42 @@ where
 	(lp_token, liquidity1, liquidity2)
 }
 
+/// Creates a pool with `n` limit orders of the `caller` offering the first asset of the pool for
+/// the second one. It returns the assets of the pool along with the amount of each order.
+fn create_pool_with_orders<T: Config>(
+	caller: &T::AccountId,
+	n: u32,
+) -> (T::AssetKind, T::AssetKind, T::Balance)
+where
+	T::Assets: Create<T::AccountId> + Mutate<T::AccountId>,
+{
+	create_fee_asset::<T>(caller);
+	let (asset1, asset2) = T::BenchmarkHelper::create_pair(0, 1);
+	create_asset_and_pool::<T>(caller, &asset1, &asset2);
+
+	let amount = T::Assets::minimum_balance(asset1.clone())
+		.max(T::Assets::minimum_balance(asset2.clone()))
+		.saturating_add(100u32.into());
+	assert_ok!(T::Assets::mint_into(asset1.clone(), caller, amount * T::Balance::from(n + 1)));
+	assert_ok!(T::Assets::mint_into(
+		T::PoolSetupFeeAsset::get(),
+		caller,
+		T::Assets::deposit_required(asset1.clone()) +
+			T::OrderDeposit::get() * T::Balance::from(n + 1)
+	));
+	for _ in 0..n {
+		assert_ok!(AssetConversion::<T>::place_order(
+			SystemOrigin::Signed(caller.clone()).into(),
+			Box::new(asset1.clone()),
+			amount,
+			Box::new(asset2.clone()),
+			amount,
+		));
+	}
+
+	(asset1, asset2, amount)
+}
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	let events = frame_system::Pallet::<T>::events();
 	let system_event: <T as frame_system::Config>::RuntimeEvent = generic_event.into();
@@ -410,5 +446,73 @@ mod benchmarks {
 		assert_last_event::<T>(Event::Touched { pool_id, who: caller }.into());
 	}
 
+	#[benchmark]
+	fn place_order() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (asset1, asset2, amount) =
+			create_pool_with_orders::<T>(&caller, T::MaxOrdersPerPool::get() - 1);
+
+		#[extrinsic_call]
+		_(
+			SystemOrigin::Signed(caller.clone()),
+			Box::new(asset1.clone()),
+			amount,
+			Box::new(asset2.clone()),
+			amount,
+		);
+
+		let pool_id = T::PoolLocator::pool_id(&asset1, &asset2).unwrap();
+		assert_eq!(Orders::<T>::get(&pool_id).len() as u32, T::MaxOrdersPerPool::get());
+	}
+
+	#[benchmark]
+	fn cancel_order() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (asset1, asset2, _) = create_pool_with_orders::<T>(&caller, T::MaxOrdersPerPool::get());
+		let order_id = T::MaxOrdersPerPool::get() - 1;
+
+		#[extrinsic_call]
+		_(
+			SystemOrigin::Signed(caller.clone()),
+			Box::new(asset1.clone()),
+			Box::new(asset2.clone()),
+			order_id,
+		);
+
+		let pool_id = T::PoolLocator::pool_id(&asset1, &asset2).unwrap();
+		assert_last_event::<T>(Event::OrderCancelled { order_id, pool_id }.into());
+	}
+
+	#[benchmark]
+	fn fill_order() {
+		let caller: T::AccountId = whitelisted_caller();
+		let (asset1, asset2, amount) =
+			create_pool_with_orders::<T>(&caller, T::MaxOrdersPerPool::get());
+		let order_id = T::MaxOrdersPerPool::get() - 1;
+		let taker: T::AccountId = account("taker", 0, 0);
+		assert_ok!(T::Assets::mint_into(
+			asset2.clone(),
+			&taker,
+			amount + T::Assets::minimum_balance(asset2.clone())
+		));
+
+		#[extrinsic_call]
+		_(
+			SystemOrigin::Signed(taker.clone()),
+			Box::new(asset1.clone()),
+			Box::new(asset2.clone()),
+			order_id,
+			amount,
+			taker.clone(),
+			false,
+		);
+
+		let pool_id = T::PoolLocator::pool_id(&asset1, &asset2).unwrap();
+		assert_last_event::<T>(
+			Event::OrderFilled { order_id, pool_id, taker, amount_in: amount, amount_out: amount }
+				.into(),
+		);
+	}
+
 	impl_benchmark_test_suite!(AssetConversion, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -30,6 +30,10 @@
 //!    there is a pool created, or
 //!  - [swap some assets for a specific amount of
 //!    another](`Pallet::swap_tokens_for_exact_tokens()`).
+//!  - [place a limit order](`Pallet::place_order()`) in the order book of a pool, which is
+//!    [filled](`Pallet::fill_order()`) directly or by [swaps matching
+//!    orders](`Pallet::swap_exact_tokens_for_tokens_with_orders()`) whenever it offers a better
+//!    price than the pool, and can be [cancelled](`Pallet::cancel_order()`) by its owner.
 //!  - [query for an exchange price](`AssetConversionApi::quote_price_exact_tokens_for_tokens`) via
 //!    a runtime call endpoint
 //!  - [query the size of a liquidity pool](`AssetConversionApi::get_reserves`) via a runtime api
@@ -91,8 +95,8 @@ use frame_support::{
 use sp_core::Get;
 use sp_runtime::{
 	traits::{
-		CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Ensure, IntegerSquareRoot, MaybeDisplay,
-		One, TrailingZeroInput, Zero,
+		AccountIdConversion, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Ensure,
+		IntegerSquareRoot, MaybeDisplay, One, TrailingZeroInput, Zero,
 	},
 	DispatchError, Saturating, TokenError, TransactionOutcome,
 };
@@ -103,6 +107,7 @@ pub mod pallet {
 	use frame_support::{
 		pallet_prelude::{DispatchResult, *},
 		traits::fungibles::Refund,
+		StorageHasher,
 	};
 	use frame_system::pallet_prelude::*;
 	use sp_arithmetic::{traits::Unsigned, Permill};
@@ -189,6 +194,21 @@ pub mod pallet {
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
 
+		/// The max number of open limit orders in the order book of a pool.
+		#[pallet::constant]
+		type MaxOrdersPerPool: Get<u32>;
+
+		/// The max number of limit orders of a pool that swaps may fill in a single block.
+		#[pallet::constant]
+		type MaxOrderFillsPerBlock: Get<u32>;
+
+		/// The deposit in [`Config::PoolSetupFeeAsset`] taken from the owner of a limit order until
+		/// the order is filled or cancelled.
+		///
+		/// Should be no less than the minimum balance of [`Config::PoolSetupFeeAsset`].
+		#[pallet::constant]
+		type OrderDeposit: Get<Self::Balance>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -208,6 +228,29 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type NextPoolAssetId<T: Config> = StorageValue<_, T::PoolAssetId, OptionQuery>;
 
+	/// The open limit orders of a pool.
+	///
+	/// The orders offering the same asset are sorted by price, the best one for the taker first,
+	/// and the orders with the same price in the order they were placed.
+	#[pallet::storage]
+	pub type Orders<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		BoundedVec<LimitOrderOf<T>, T::MaxOrdersPerPool>,
+		ValueQuery,
+	>;
+
+	/// Stores the `OrderId` that is going to be used for the next limit order.
+	#[pallet::storage]
+	pub type NextOrderId<T: Config> = StorageValue<_, OrderId, ValueQuery>;
+
+	/// The block number and the number of limit orders of a pool filled by swaps within that
+	/// block.
+	#[pallet::storage]
+	pub type OrderFills<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, (BlockNumberFor<T>, u32), ValueQuery>;
+
 	// Pallet's events.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -295,6 +338,43 @@ pub mod pallet {
 			/// The account initiating the touch.
 			who: T::AccountId,
 		},
+		/// A limit order has been placed in the order book of a pool.
+		OrderPlaced {
+			/// The ID of the order.
+			order_id: OrderId,
+			/// The pool id of the order book.
+			pool_id: T::PoolId,
+			/// The account that placed the order.
+			who: T::AccountId,
+			/// The asset offered by the order.
+			offered: T::AssetKind,
+			/// The amount of the offered asset.
+			amount_offered: T::Balance,
+			/// The asset wanted in exchange.
+			wanted: T::AssetKind,
+			/// The amount of the wanted asset.
+			amount_wanted: T::Balance,
+		},
+		/// A limit order has been filled, completely or in part.
+		OrderFilled {
+			/// The ID of the order.
+			order_id: OrderId,
+			/// The pool id of the order book.
+			pool_id: T::PoolId,
+			/// The account that filled the order.
+			taker: T::AccountId,
+			/// The amount of the wanted asset paid to the owner of the order.
+			amount_in: T::Balance,
+			/// The amount of the offered asset paid out of the order.
+			amount_out: T::Balance,
+		},
+		/// A limit order has been cancelled and its remaining funds returned.
+		OrderCancelled {
+			/// The ID of the order.
+			order_id: OrderId,
+			/// The pool id of the order book.
+			pool_id: T::PoolId,
+		},
 	}
 
 	#[pallet::error]
@@ -348,6 +428,16 @@ pub mod pallet {
 		IncorrectPoolAssetId,
 		/// The destination account cannot exist with the swapped funds.
 		BelowMinimum,
+		/// The order book of the pool is full.
+		TooManyOrders,
+		/// The limit order doesn't exist.
+		OrderNotFound,
+		/// Only the owner of a limit order can cancel it.
+		NotOrderOwner,
+		/// The amount exceeds what is left of the limit order.
+		OrderAmountTooHigh,
+		/// The amount of the limit order or of the fill is below the minimum balance of the asset.
+		OrderAmountTooLow,
 	}
 
 	#[pallet::hooks]
@@ -648,6 +738,7 @@ pub mod pallet {
 				Some(amount_out_min),
 				send_to,
 				keep_alive,
+				false,
 			)?;
 			Ok(())
 		}
@@ -722,9 +813,225 @@ pub mod pallet {
 			Self::deposit_event(Event::Touched { pool_id, who });
 			Ok(Some(T::WeightInfo::touch(refunds_number)).into())
 		}
+
+		/// Place a limit order offering `amount_offered` of `offered` in exchange for
+		/// `amount_wanted` of `wanted` in the order book of the pool of the two assets.
+		///
+		/// The offered amount and the [`Config::OrderDeposit`] are moved to the pallet's account
+		/// until the order is filled or cancelled. This action may involve holding assets from the
+		/// caller as a deposit for creating the pallet's account for the offered asset.
+		///
+		/// Both amounts must be no less than the minimum balance of their asset.
+		///
+		/// Emits `OrderPlaced` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::place_order())]
+		pub fn place_order(
+			origin: OriginFor<T>,
+			offered: Box<T::AssetKind>,
+			amount_offered: T::Balance,
+			wanted: Box<T::AssetKind>,
+			amount_wanted: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(offered != wanted, Error::<T>::InvalidAssetPair);
+			ensure!(!amount_offered.is_zero() && !amount_wanted.is_zero(), Error::<T>::ZeroAmount);
+
+			let pool_id = T::PoolLocator::pool_id(&offered, &wanted)
+				.map_err(|_| Error::<T>::InvalidAssetPair)?;
+			ensure!(Pools::<T>::contains_key(&pool_id), Error::<T>::PoolNotFound);
+			ensure!(
+				amount_offered >= T::Assets::minimum_balance(*offered.clone()) &&
+					amount_wanted >= T::Assets::minimum_balance(*wanted.clone()),
+				Error::<T>::OrderAmountTooLow
+			);
+
+			let order_id = NextOrderId::<T>::get();
+			let deposit = T::OrderDeposit::get();
+			let order = LimitOrder {
+				id: order_id,
+				owner: who.clone(),
+				offered: *offered.clone(),
+				amount_offered,
+				wanted: *wanted.clone(),
+				amount_wanted,
+				deposit,
+			};
+			let mut orders = Orders::<T>::get(&pool_id);
+			let index = orders
+				.iter()
+				.position(|o| o.offered == order.offered && Self::is_better_price(&order, o))
+				.unwrap_or(orders.len());
+			orders.try_insert(index, order).map_err(|_| Error::<T>::TooManyOrders)?;
+			Self::set_orders(&pool_id, orders);
+			NextOrderId::<T>::put(order_id.checked_add(1).ok_or(Error::<T>::Overflow)?);
+
+			let order_account = Self::order_account();
+			if T::Assets::should_touch(*offered.clone(), &order_account) {
+				T::Assets::touch(*offered.clone(), &order_account, &who)?;
+			}
+			T::Assets::transfer(*offered.clone(), &who, &order_account, amount_offered, Preserve)?;
+			if !deposit.is_zero() {
+				T::Assets::transfer(
+					T::PoolSetupFeeAsset::get(),
+					&who,
+					&order_account,
+					deposit,
+					Preserve,
+				)?;
+			}
+
+			Self::deposit_event(Event::OrderPlaced {
+				order_id,
+				pool_id,
+				who,
+				offered: *offered,
+				amount_offered,
+				wanted: *wanted,
+				amount_wanted,
+			});
+			Ok(())
+		}
+
+		/// Cancel the limit order `order_id` in the order book of the pool of `asset1` and
+		/// `asset2`, returning the remaining offered amount and the deposit to its owner.
+		///
+		/// The origin must be Signed and the owner of the order.
+		///
+		/// Emits `OrderCancelled` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::cancel_order())]
+		pub fn cancel_order(
+			origin: OriginFor<T>,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			order_id: OrderId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
+				.map_err(|_| Error::<T>::InvalidAssetPair)?;
+
+			let mut orders = Orders::<T>::get(&pool_id);
+			let index =
+				orders.iter().position(|o| o.id == order_id).ok_or(Error::<T>::OrderNotFound)?;
+			ensure!(orders[index].owner == who, Error::<T>::NotOrderOwner);
+			let order = orders.remove(index);
+			Self::set_orders(&pool_id, orders);
+
+			T::Assets::transfer(
+				order.offered,
+				&Self::order_account(),
+				&order.owner,
+				order.amount_offered,
+				Expendable,
+			)?;
+			Self::refund_order_deposit(&order)?;
+
+			Self::deposit_event(Event::OrderCancelled { order_id, pool_id });
+			Ok(())
+		}
+
+		/// Fill the limit order `order_id` in the order book of the pool of `asset1` and `asset2`
+		/// by paying `amount_in` of the asset wanted by the order. The proportional amount of the
+		/// offered asset is sent to `send_to`.
+		///
+		/// The order may be filled in part, in which case the rest stays in the order book. Both
+		/// `amount_in` and the amount sent to `send_to` must be no less than the minimum balance of
+		/// their asset.
+		///
+		/// Emits `OrderFilled` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::fill_order())]
+		pub fn fill_order(
+			origin: OriginFor<T>,
+			asset1: Box<T::AssetKind>,
+			asset2: Box<T::AssetKind>,
+			order_id: OrderId,
+			amount_in: T::Balance,
+			send_to: T::AccountId,
+			keep_alive: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount_in.is_zero(), Error::<T>::ZeroAmount);
+			let pool_id = T::PoolLocator::pool_id(&asset1, &asset2)
+				.map_err(|_| Error::<T>::InvalidAssetPair)?;
+
+			let mut orders = Orders::<T>::get(&pool_id);
+			let index =
+				orders.iter().position(|o| o.id == order_id).ok_or(Error::<T>::OrderNotFound)?;
+			ensure!(amount_in <= orders[index].amount_wanted, Error::<T>::OrderAmountTooHigh);
+
+			Self::do_fill_order(
+				&pool_id,
+				&mut orders[index],
+				&who,
+				amount_in,
+				&send_to,
+				keep_alive,
+			)?;
+			if orders[index].amount_wanted.is_zero() {
+				orders.remove(index);
+			}
+			Self::set_orders(&pool_id, orders);
+			Ok(())
+		}
+
+		/// Swap the exact amount of `asset1` into `asset2`, filling the limit orders of the pool
+		/// which offer a better price than the pool itself, best price first, before swapping the
+		/// rest through the pool. `amount_out_min` param allows you to specify the min amount of
+		/// the `asset2` you're happy to receive.
+		///
+		/// Orders are only matched for a `path` of two assets, and at most
+		/// [`Config::MaxOrderFillsPerBlock`] orders of a pool are filled in a block. Orders whose
+		/// fill would move an amount below the minimum balance of its asset are skipped.
+		///
+		/// [`AssetConversionApi::quote_price_exact_tokens_for_tokens`] only quotes the pool, so
+		/// this swap may acquire more than quoted.
+		#[pallet::call_index(9)]
+		#[pallet::weight(Pallet::<T>::swap_with_orders_weight(
+			path.len() as u32,
+			T::MaxOrderFillsPerBlock::get()
+		))]
+		pub fn swap_exact_tokens_for_tokens_with_orders(
+			origin: OriginFor<T>,
+			path: Vec<Box<T::AssetKind>>,
+			amount_in: T::Balance,
+			amount_out_min: T::Balance,
+			send_to: T::AccountId,
+			keep_alive: bool,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let path_len = path.len() as u32;
+			let (_, fills) = Self::do_swap_exact_tokens_for_tokens(
+				sender,
+				path.into_iter().map(|a| *a).collect(),
+				amount_in,
+				Some(amount_out_min),
+				send_to,
+				keep_alive,
+				true,
+			)?;
+			Ok(Some(Self::swap_with_orders_weight(path_len, fills)).into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// The weight of a swap along a path of `path_len` assets which fills `fills` orders,
+		/// including the access to the order book of the pool.
+		pub(crate) fn swap_with_orders_weight(path_len: u32, fills: u32) -> Weight {
+			// The `Orders` and `OrderFills` entries of the pool.
+			let proof_size = Blake2_128Concat::max_len::<T::PoolId>()
+				.saturating_add(
+					BoundedVec::<LimitOrderOf<T>, T::MaxOrdersPerPool>::max_encoded_len(),
+				)
+				.saturating_add(Blake2_128Concat::max_len::<T::PoolId>())
+				.saturating_add(<(BlockNumberFor<T>, u32)>::max_encoded_len());
+			T::WeightInfo::swap_exact_tokens_for_tokens(path_len)
+				.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+				.saturating_add(Weight::from_parts(0, proof_size as u64))
+				.saturating_add(T::WeightInfo::fill_order().saturating_mul(fills.into()))
+		}
+
 		/// Swap exactly `amount_in` of asset `path[0]` for asset `path[1]`.
 		/// If an `amount_out_min` is specified, it will return an error if it is unable to acquire
 		/// the amount desired.
@@ -732,7 +1039,11 @@ pub mod pallet {
 		/// Withdraws the `path[0]` asset from `sender`, deposits the `path[1]` asset to `send_to`,
 		/// respecting `keep_alive`.
 		///
-		/// If successful, returns the amount of `path[1]` acquired for the `amount_in`.
+		/// If `match_orders` is set, limit orders offering a better price than the pool are filled
+		/// first.
+		///
+		/// If successful, returns the amount of `path[1]` acquired for the `amount_in` and the
+		/// number of limit orders filled.
 		///
 		/// WARNING: This may return an error after a partial storage mutation. It should be used
 		/// only inside a transactional storage context and an Err result must imply a storage
@@ -744,16 +1055,29 @@ pub mod pallet {
 			amount_out_min: Option<T::Balance>,
 			send_to: T::AccountId,
 			keep_alive: bool,
-		) -> Result<T::Balance, DispatchError> {
+			match_orders: bool,
+		) -> Result<(T::Balance, u32), DispatchError> {
 			ensure!(amount_in > Zero::zero(), Error::<T>::ZeroAmount);
 			if let Some(amount_out_min) = amount_out_min {
 				ensure!(amount_out_min > Zero::zero(), Error::<T>::ZeroAmount);
 			}
 
 			Self::validate_swap_path(&path)?;
-			let path = Self::balance_path_from_amount_in(amount_in, path)?;
+			let (orders_in, orders_out, fills) = if match_orders {
+				Self::match_orders(&sender, &path, amount_in, &send_to, keep_alive)?
+			} else {
+				(Zero::zero(), Zero::zero(), 0)
+			};
 
-			let amount_out = path.last().map(|(_, a)| *a).ok_or(Error::<T>::InvalidPath)?;
+			let pool_in = amount_in.saturating_sub(orders_in);
+			let mut path: BalancePath<T> = if pool_in.is_zero() {
+				path.into_iter().map(|asset| (asset, Zero::zero())).collect()
+			} else {
+				Self::balance_path_from_amount_in(pool_in, path)?
+			};
+
+			let pool_out = path.last().map(|(_, a)| *a).ok_or(Error::<T>::InvalidPath)?;
+			let amount_out = pool_out.checked_add(&orders_out).ok_or(Error::<T>::Overflow)?;
 			if let Some(amount_out_min) = amount_out_min {
 				ensure!(
 					amount_out >= amount_out_min,
@@ -761,7 +1085,14 @@ pub mod pallet {
 				);
 			}
 
-			Self::swap(&sender, &path, &send_to, keep_alive)?;
+			if !pool_in.is_zero() {
+				Self::swap(&sender, &path, &send_to, keep_alive)?;
+			}
+			if let [(_, path_in), (_, path_out)] = path.as_mut_slice() {
+				// Report the amounts including the filled orders.
+				*path_in = amount_in;
+				*path_out = amount_out;
+			}
 
 			Self::deposit_event(Event::SwapExecuted {
 				who: sender,
@@ -770,7 +1101,7 @@ pub mod pallet {
 				amount_out,
 				path,
 			});
-			Ok(amount_out)
+			Ok((amount_out, fills))
 		}
 
 		/// Take the `path[0]` asset and swap some amount for `amount_out` of the `path[1]`. If an
@@ -1002,6 +1333,224 @@ pub mod pallet {
 			Ok(credit_out)
 		}
 
+		/// Fill the limit orders in the order book of the pool of a single hop `path` which offer
+		/// a better price than the pool, paying at most `amount_in` of `path[0]` from `sender` and
+		/// sending the acquired `path[1]` to `send_to`.
+		///
+		/// The number of orders of the pool filled in a block is limited by
+		/// [`Config::MaxOrderFillsPerBlock`]. Orders whose fill would move an amount below the
+		/// minimum balance of its asset are skipped.
+		///
+		/// Returns the amounts paid and acquired, and the number of orders filled.
+		///
+		/// WARNING: This may return an error after a partial storage mutation. It should be used
+		/// only inside a transactional storage context and an Err result must imply a storage
+		/// rollback.
+		pub(crate) fn match_orders(
+			sender: &T::AccountId,
+			path: &[T::AssetKind],
+			amount_in: T::Balance,
+			send_to: &T::AccountId,
+			keep_alive: bool,
+		) -> Result<(T::Balance, T::Balance, u32), DispatchError> {
+			let (mut total_in, mut total_out, mut filled) = (Zero::zero(), Zero::zero(), 0);
+			let [asset_in, asset_out] = path else { return Ok((total_in, total_out, filled)) };
+			let pool_id = T::PoolLocator::pool_id(asset_in, asset_out)
+				.map_err(|_| Error::<T>::InvalidAssetPair)?;
+			let mut orders = Orders::<T>::get(&pool_id);
+			if orders.is_empty() {
+				return Ok((total_in, total_out, filled))
+			}
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut fills = match OrderFills::<T>::get(&pool_id) {
+				(block, fills) if block == now => fills,
+				_ => 0,
+			};
+			let reserves = Self::get_reserves(asset_in.clone(), asset_out.clone()).ok();
+
+			let mut index = 0;
+			while index < orders.len() && fills < T::MaxOrderFillsPerBlock::get() {
+				let remaining = amount_in.saturating_sub(total_in);
+				if remaining.is_zero() {
+					break
+				}
+				let order = &mut orders[index];
+				if &order.offered != asset_out || &order.wanted != asset_in {
+					index += 1;
+					continue
+				}
+
+				let fill_in = remaining.min(order.amount_wanted);
+				let fill_out =
+					Self::mul_div(&fill_in, &order.amount_offered, &order.amount_wanted)?;
+				let pool_out = reserves
+					.and_then(|(reserve_in, reserve_out)| {
+						Self::get_amount_out(&fill_in, &reserve_in, &reserve_out).ok()
+					})
+					.unwrap_or_else(Zero::zero);
+				if fill_out <= pool_out || !Self::is_fillable(order, fill_in, fill_out) {
+					index += 1;
+					continue
+				}
+
+				let fill_out =
+					Self::do_fill_order(&pool_id, order, sender, fill_in, send_to, keep_alive)?;
+				total_in = total_in.saturating_add(fill_in);
+				total_out = total_out.saturating_add(fill_out);
+				fills.saturating_inc();
+				filled.saturating_inc();
+				if orders[index].amount_wanted.is_zero() {
+					orders.remove(index);
+				} else {
+					index += 1;
+				}
+			}
+
+			OrderFills::<T>::insert(&pool_id, (now, fills));
+			Self::set_orders(&pool_id, orders);
+			Ok((total_in, total_out, filled))
+		}
+
+		/// Fill `order` in the order book of `pool_id` by paying `amount_in` of the wanted asset
+		/// from `taker` to the owner, and sending the proportional amount of the offered asset to
+		/// `send_to`.
+		///
+		/// Once what is left of the order can no longer be filled, the order is closed and its
+		/// deposit returned. Any leftover of the offered asset is returned to the owner, or sent to
+		/// `send_to` along with the fill if it is below the minimum balance of the asset.
+		///
+		/// Note: It's assumed that `amount_in` does not exceed the amount wanted by the order.
+		///
+		/// Returns the amount of the offered asset sent to `send_to`.
+		fn do_fill_order(
+			pool_id: &T::PoolId,
+			order: &mut LimitOrderOf<T>,
+			taker: &T::AccountId,
+			amount_in: T::Balance,
+			send_to: &T::AccountId,
+			keep_alive: bool,
+		) -> Result<T::Balance, DispatchError> {
+			let mut amount_out =
+				Self::mul_div(&amount_in, &order.amount_offered, &order.amount_wanted)?;
+			ensure!(!amount_out.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(Self::is_fillable(order, amount_in, amount_out), Error::<T>::OrderAmountTooLow);
+
+			let preservation = if keep_alive { Preserve } else { Expendable };
+			let order_account = Self::order_account();
+			T::Assets::transfer(
+				order.wanted.clone(),
+				taker,
+				&order.owner,
+				amount_in,
+				preservation,
+			)?;
+
+			order.amount_wanted = order.amount_wanted.saturating_sub(amount_in);
+			order.amount_offered = order.amount_offered.saturating_sub(amount_out);
+			if Self::is_exhausted(order) {
+				let leftover = core::mem::take(&mut order.amount_offered);
+				order.amount_wanted = Zero::zero();
+				if leftover < T::Assets::minimum_balance(order.offered.clone()) {
+					amount_out = amount_out.saturating_add(leftover);
+				} else {
+					T::Assets::transfer(
+						order.offered.clone(),
+						&order_account,
+						&order.owner,
+						leftover,
+						Expendable,
+					)?;
+				}
+				Self::refund_order_deposit(order)?;
+			}
+
+			T::Assets::transfer(
+				order.offered.clone(),
+				&order_account,
+				send_to,
+				amount_out,
+				Expendable,
+			)?;
+
+			Self::deposit_event(Event::OrderFilled {
+				order_id: order.id,
+				pool_id: pool_id.clone(),
+				taker: taker.clone(),
+				amount_in,
+				amount_out,
+			});
+			Ok(amount_out)
+		}
+
+		/// Whether `order` can be filled by paying `amount_in` for `amount_out`, without moving an
+		/// amount below the minimum balance of its asset.
+		fn is_fillable(
+			order: &LimitOrderOf<T>,
+			amount_in: T::Balance,
+			amount_out: T::Balance,
+		) -> bool {
+			!amount_out.is_zero() &&
+				amount_in >= T::Assets::minimum_balance(order.wanted.clone()) &&
+				amount_out >= T::Assets::minimum_balance(order.offered.clone())
+		}
+
+		/// Whether what is left of `order` can no longer be filled.
+		fn is_exhausted(order: &LimitOrderOf<T>) -> bool {
+			order.amount_wanted.is_zero() ||
+				order.amount_offered.is_zero() ||
+				order.amount_wanted < T::Assets::minimum_balance(order.wanted.clone()) ||
+				order.amount_offered < T::Assets::minimum_balance(order.offered.clone())
+		}
+
+		/// Return the deposit of `order` to its owner.
+		fn refund_order_deposit(order: &LimitOrderOf<T>) -> DispatchResult {
+			if order.deposit.is_zero() {
+				return Ok(())
+			}
+			T::Assets::transfer(
+				T::PoolSetupFeeAsset::get(),
+				&Self::order_account(),
+				&order.owner,
+				order.deposit,
+				Expendable,
+			)?;
+			Ok(())
+		}
+
+		/// Store the open limit orders of `pool_id`, removing the entry once there are none.
+		/// Whether `order` offers more of its offered asset for each unit of its wanted asset than
+		/// `other`.
+		fn is_better_price(order: &LimitOrderOf<T>, other: &LimitOrderOf<T>) -> bool {
+			let price = |offered, wanted| {
+				T::HigherPrecisionBalance::from(offered)
+					.checked_mul(&T::HigherPrecisionBalance::from(wanted))
+			};
+			match (
+				price(order.amount_offered, other.amount_wanted),
+				price(other.amount_offered, order.amount_wanted),
+			) {
+				(Some(order), Some(other)) => order > other,
+				_ => false,
+			}
+		}
+
+		fn set_orders(
+			pool_id: &T::PoolId,
+			orders: BoundedVec<LimitOrderOf<T>, T::MaxOrdersPerPool>,
+		) {
+			if orders.is_empty() {
+				Orders::<T>::remove(pool_id);
+			} else {
+				Orders::<T>::insert(pool_id, orders);
+			}
+		}
+
+		/// The account holding the offered assets and the deposits of the open limit orders.
+		pub fn order_account() -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(b"orders")
+		}
+
 		/// Removes `value` balance of `asset` from `who` account if possible.
 		fn withdraw(
 			asset: T::AssetKind,
@@ -1313,6 +1862,10 @@ sp_api::decl_runtime_apis! {
 		///
 		/// Note that the price may have changed by the time the transaction is executed.
 		/// (Use `amount_out_min` to control slippage.)
+		///
+		/// Only the pool is quoted. The limit orders filled by
+		/// [`Pallet::swap_exact_tokens_for_tokens_with_orders`] are not taken into account, so that
+		/// call may acquire more than quoted.
 		fn quote_price_exact_tokens_for_tokens(
 			asset1: AssetId,
 			asset2: AssetId,
@@ -84,6 +84,8 @@ impl pallet_assets::Config<Instance1> for Test {
 	type ApprovalDeposit = ConstU128<1>;
 	type StringLimit = ConstU32<50>;
 	type Freezer = ();
+	type MaxFreezes = ConstU32<8>;
+	type UriLimit = ConstU32<256>;
 	type Extra = ();
 	type WeightInfo = ();
 	type CallbackHandle = ();
@@ -109,6 +111,8 @@ impl pallet_assets::Config<Instance2> for Test {
 	type ApprovalDeposit = ConstU128<0>;
 	type StringLimit = ConstU32<50>;
 	type Freezer = ();
+	type MaxFreezes = ConstU32<8>;
+	type UriLimit = ConstU32<256>;
 	type Extra = ();
 	type WeightInfo = ();
 	type CallbackHandle = ();
@@ -152,6 +156,9 @@ impl Config for Test {
 	type LPFee = ConstU32<3>; // means 0.3%
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
 	type MaxSwapPathLength = ConstU32<4>;
+	type MaxOrdersPerPool = ConstU32<8>;
+	type MaxOrderFillsPerBlock = ConstU32<2>;
+	type OrderDeposit = ConstU128<100>;
 	type MintMinLiquidity = ConstU128<100>; // 100 is good enough when the main currency has 12 decimals.
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -159,7 +159,7 @@ impl<T: Config> Swap<T::AccountId> for Pallet<T> {
 		send_to: T::AccountId,
 		keep_alive: bool,
 	) -> Result<Self::Balance, DispatchError> {
-		let amount_out = with_storage_layer(|| {
+		let (amount_out, _) = with_storage_layer(|| {
 			Self::do_swap_exact_tokens_for_tokens(
 				sender,
 				path,
@@ -167,6 +167,7 @@ impl<T: Config> Swap<T::AccountId> for Pallet<T> {
 				amount_out_min,
 				send_to,
 				keep_alive,
+				false,
 			)
 		})?;
 		Ok(amount_out)
@@ -2493,3 +2493,441 @@ fn swap_credit_invalid_path() {
 		assert_eq!(error, (expected_credit_in, Error::<Test>::InvalidPath.into()));
 	});
 }
+
+fn setup_order_book_pool(user: u128, maker: u128) -> (NativeOrWithId<u32>, NativeOrWithId<u32>) {
+	let token_1 = NativeOrWithId::Native;
+	let token_2 = NativeOrWithId::WithId(2);
+
+	create_tokens(user, vec![token_2.clone()]);
+	assert_ok!(AssetConversion::create_pool(
+		RuntimeOrigin::signed(user),
+		Box::new(token_1.clone()),
+		Box::new(token_2.clone())
+	));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, user, 1000));
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(user), 2, maker, 1000));
+	assert_ok!(AssetConversion::add_liquidity(
+		RuntimeOrigin::signed(user),
+		Box::new(token_1.clone()),
+		Box::new(token_2.clone()),
+		5000,
+		100,
+		1,
+		1,
+		user,
+	));
+
+	(token_1, token_2)
+}
+
+#[test]
+fn can_place_fill_and_cancel_limit_orders() {
+	new_test_ext().execute_with(|| {
+		let (user, maker) = (1, 2);
+		let (token_1, token_2) = setup_order_book_pool(user, maker);
+		let pool_id = (token_1.clone(), token_2.clone());
+		let order_account = AssetConversion::order_account();
+
+		assert_noop!(
+			AssetConversion::place_order(
+				RuntimeOrigin::signed(maker),
+				Box::new(token_2.clone()),
+				0,
+				Box::new(token_1.clone()),
+				1000,
+			),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			AssetConversion::place_order(
+				RuntimeOrigin::signed(maker),
+				Box::new(token_2.clone()),
+				100,
+				Box::new(NativeOrWithId::WithId(3)),
+				1000,
+			),
+			Error::<Test>::PoolNotFound
+		);
+		assert_ok!(AssetConversion::place_order(
+			RuntimeOrigin::signed(maker),
+			Box::new(token_2.clone()),
+			100,
+			Box::new(token_1.clone()),
+			1000,
+		));
+		assert!(events().contains(&Event::<Test>::OrderPlaced {
+			order_id: 0,
+			pool_id: pool_id.clone(),
+			who: maker,
+			offered: token_2.clone(),
+			amount_offered: 100,
+			wanted: token_1.clone(),
+			amount_wanted: 1000,
+		}));
+		assert_eq!(balance(maker, token_2.clone()), 900);
+		assert_eq!(balance(order_account, token_2.clone()), 100);
+		// The deposit is held until the order is filled or cancelled.
+		let deposit = <Test as Config>::OrderDeposit::get();
+		assert_eq!(balance(order_account, token_1.clone()), deposit);
+
+		// Fill half of the order.
+		let maker_native = balance(maker, token_1.clone());
+		assert_noop!(
+			AssetConversion::fill_order(
+				RuntimeOrigin::signed(user),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone()),
+				0,
+				1001,
+				user,
+				true,
+			),
+			Error::<Test>::OrderAmountTooHigh
+		);
+		assert_ok!(AssetConversion::fill_order(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			0,
+			500,
+			user,
+			true,
+		));
+		assert_eq!(
+			events(),
+			[Event::<Test>::OrderFilled {
+				order_id: 0,
+				pool_id: pool_id.clone(),
+				taker: user,
+				amount_in: 500,
+				amount_out: 50,
+			}]
+		);
+		assert_eq!(balance(maker, token_1.clone()), maker_native + 500);
+		assert_eq!(balance(user, token_2.clone()), 1000 - 100 + 50);
+		let order = &Orders::<Test>::get(&pool_id)[0];
+		assert_eq!((order.amount_offered, order.amount_wanted), (50, 500));
+
+		// Only the owner can cancel the rest.
+		assert_noop!(
+			AssetConversion::cancel_order(
+				RuntimeOrigin::signed(user),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone()),
+				0,
+			),
+			Error::<Test>::NotOrderOwner
+		);
+		assert_ok!(AssetConversion::cancel_order(
+			RuntimeOrigin::signed(maker),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			0,
+		));
+		assert_eq!(
+			events(),
+			[Event::<Test>::OrderCancelled { order_id: 0, pool_id: pool_id.clone() }]
+		);
+		assert_eq!(balance(maker, token_2.clone()), 950);
+		assert_eq!(balance(maker, token_1.clone()), maker_native + 500 + deposit);
+		assert_eq!(balance(order_account, token_1.clone()), 0);
+		assert!(!Orders::<Test>::contains_key(&pool_id));
+		assert_noop!(
+			AssetConversion::cancel_order(
+				RuntimeOrigin::signed(maker),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone()),
+				0,
+			),
+			Error::<Test>::OrderNotFound
+		);
+	});
+}
+
+#[test]
+fn swap_fills_orders_better_than_pool() {
+	new_test_ext().execute_with(|| {
+		let (user, maker) = (1, 2);
+		let (token_1, token_2) = setup_order_book_pool(user, maker);
+		let pool_id = (token_1.clone(), token_2.clone());
+
+		// Better than the pool, which pays about 2 of `token_2` for 100 of `token_1`.
+		assert_ok!(AssetConversion::place_order(
+			RuntimeOrigin::signed(maker),
+			Box::new(token_2.clone()),
+			20,
+			Box::new(token_1.clone()),
+			400,
+		));
+		// Worse than the pool.
+		assert_ok!(AssetConversion::place_order(
+			RuntimeOrigin::signed(maker),
+			Box::new(token_2.clone()),
+			10,
+			Box::new(token_1.clone()),
+			1000,
+		));
+
+		let maker_native = balance(maker, token_1.clone());
+		let user_token_2 = balance(user, token_2.clone());
+		let (reserve_1, reserve_2) =
+			AssetConversion::get_reserves(token_1.clone(), token_2.clone()).unwrap();
+		let pool_out = AssetConversion::get_amount_out(&200, &reserve_1, &reserve_2).unwrap();
+		System::reset_events();
+
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens_with_orders(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone()],
+			600,
+			1,
+			user,
+			true,
+		));
+		assert_eq!(
+			events(),
+			[
+				Event::<Test>::OrderFilled {
+					order_id: 0,
+					pool_id: pool_id.clone(),
+					taker: user,
+					amount_in: 400,
+					amount_out: 20,
+				},
+				Event::<Test>::SwapExecuted {
+					who: user,
+					send_to: user,
+					amount_in: 600,
+					amount_out: 20 + pool_out,
+					path: vec![(token_1.clone(), 600), (token_2.clone(), 20 + pool_out)],
+				},
+			]
+		);
+		// The deposit of the filled order is returned.
+		assert_eq!(
+			balance(maker, token_1.clone()),
+			maker_native + 400 + <Test as Config>::OrderDeposit::get()
+		);
+		assert_eq!(balance(user, token_2.clone()), user_token_2 + 20 + pool_out);
+		let orders = Orders::<Test>::get(&pool_id);
+		assert_eq!(orders.len(), 1);
+		assert_eq!(orders[0].id, 1);
+	});
+}
+
+#[test]
+fn swaps_fill_best_priced_orders_first() {
+	new_test_ext().execute_with(|| {
+		let (user, maker) = (1, 2);
+		let (token_1, token_2) = setup_order_book_pool(user, maker);
+		let pool_id = (token_1.clone(), token_2.clone());
+
+		for (amount_offered, amount_wanted) in [(20, 400), (20, 200), (10, 100)] {
+			assert_ok!(AssetConversion::place_order(
+				RuntimeOrigin::signed(maker),
+				Box::new(token_2.clone()),
+				amount_offered,
+				Box::new(token_1.clone()),
+				amount_wanted,
+			));
+		}
+		// An order in the other direction doesn't take part in the ordering.
+		assert_ok!(AssetConversion::place_order(
+			RuntimeOrigin::signed(maker),
+			Box::new(token_1.clone()),
+			100,
+			Box::new(token_2.clone()),
+			10,
+		));
+		// Sorted by price, the best one first, and by age for the same price.
+		let ids = |orders: &[LimitOrderOf<Test>]| orders.iter().map(|o| o.id).collect::<Vec<_>>();
+		assert_eq!(ids(&Orders::<Test>::get(&pool_id)), vec![1, 2, 0, 3]);
+
+		System::reset_events();
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens_with_orders(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone()],
+			200,
+			1,
+			user,
+			true,
+		));
+		assert!(events().contains(&Event::<Test>::OrderFilled {
+			order_id: 1,
+			pool_id: pool_id.clone(),
+			taker: user,
+			amount_in: 200,
+			amount_out: 20,
+		}));
+		assert_eq!(ids(&Orders::<Test>::get(&pool_id)), vec![2, 0, 3]);
+	});
+}
+
+#[test]
+fn swaps_fill_limited_number_of_orders_per_block() {
+	new_test_ext().execute_with(|| {
+		let (user, maker) = (1, 2);
+		let (token_1, token_2) = setup_order_book_pool(user, maker);
+		let pool_id = (token_1.clone(), token_2.clone());
+
+		for _ in 0..3 {
+			assert_ok!(AssetConversion::place_order(
+				RuntimeOrigin::signed(maker),
+				Box::new(token_2.clone()),
+				10,
+				Box::new(token_1.clone()),
+				100,
+			));
+		}
+
+		// `MaxOrderFillsPerBlock` is 2, the weight of unused fills is refunded.
+		let post_info = AssetConversion::swap_exact_tokens_for_tokens_with_orders(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone()],
+			300,
+			1,
+			user,
+			true,
+		)
+		.unwrap();
+		assert_eq!(post_info.actual_weight, Some(AssetConversion::swap_with_orders_weight(2, 2)));
+		assert_eq!(OrderFills::<Test>::get(&pool_id), (1, 2));
+		assert_eq!(Orders::<Test>::get(&pool_id).len(), 1);
+
+		let post_info = AssetConversion::swap_exact_tokens_for_tokens_with_orders(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone()],
+			100,
+			1,
+			user,
+			true,
+		)
+		.unwrap();
+		assert_eq!(post_info.actual_weight, Some(AssetConversion::swap_with_orders_weight(2, 0)));
+		assert_eq!(Orders::<Test>::get(&pool_id).len(), 1);
+
+		System::set_block_number(2);
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens_with_orders(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone()],
+			100,
+			1,
+			user,
+			true,
+		));
+		assert_eq!(OrderFills::<Test>::get(&pool_id), (2, 1));
+		assert!(!Orders::<Test>::contains_key(&pool_id));
+	});
+}
+
+#[test]
+fn plain_swaps_do_not_fill_orders() {
+	new_test_ext().execute_with(|| {
+		let (user, maker) = (1, 2);
+		let (token_1, token_2) = setup_order_book_pool(user, maker);
+		let pool_id = (token_1.clone(), token_2.clone());
+
+		assert_ok!(AssetConversion::place_order(
+			RuntimeOrigin::signed(maker),
+			Box::new(token_2.clone()),
+			20,
+			Box::new(token_1.clone()),
+			400,
+		));
+		System::reset_events();
+
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens(
+			RuntimeOrigin::signed(user),
+			bvec![token_1.clone(), token_2.clone()],
+			600,
+			1,
+			user,
+			true,
+		));
+		assert!(!events().iter().any(|e| matches!(e, Event::<Test>::OrderFilled { .. })));
+		assert_eq!(Orders::<Test>::get(&pool_id).len(), 1);
+		assert!(!OrderFills::<Test>::contains_key(&pool_id));
+	});
+}
+
+#[test]
+fn order_amounts_below_minimum_balance_are_not_moved() {
+	new_test_ext().execute_with(|| {
+		let (user, maker) = (1, 2);
+		let (token_1, token_2) = setup_order_book_pool(user, maker);
+		let pool_id = (token_1.clone(), token_2.clone());
+		let deposit = <Test as Config>::OrderDeposit::get();
+
+		assert_noop!(
+			AssetConversion::place_order(
+				RuntimeOrigin::signed(maker),
+				Box::new(token_1.clone()),
+				get_native_ed() - 1,
+				Box::new(token_2.clone()),
+				3,
+			),
+			Error::<Test>::OrderAmountTooLow
+		);
+		// Better than the pool, which pays about 49 of `token_1` for 1 of `token_2`.
+		assert_ok!(AssetConversion::place_order(
+			RuntimeOrigin::signed(maker),
+			Box::new(token_1.clone()),
+			250,
+			Box::new(token_2.clone()),
+			3,
+		));
+
+		// A fill of 1 pays out 83, below the minimum balance of `token_1`.
+		assert_noop!(
+			AssetConversion::fill_order(
+				RuntimeOrigin::signed(user),
+				Box::new(token_1.clone()),
+				Box::new(token_2.clone()),
+				0,
+				1,
+				user,
+				true,
+			),
+			Error::<Test>::OrderAmountTooLow
+		);
+		// A swap skips the order instead of failing.
+		System::reset_events();
+		assert_ok!(AssetConversion::swap_exact_tokens_for_tokens_with_orders(
+			RuntimeOrigin::signed(user),
+			bvec![token_2.clone(), token_1.clone()],
+			1,
+			1,
+			user,
+			true,
+		));
+		assert!(!events().iter().any(|e| matches!(e, Event::<Test>::OrderFilled { .. })));
+		assert_eq!(Orders::<Test>::get(&pool_id)[0].amount_wanted, 3);
+
+		// A fill of 2 pays out 166 and leaves 84, which can neither be filled nor returned to
+		// the owner, so it goes to the taker.
+		let maker_native = balance(maker, token_1.clone());
+		let user_native = balance(user, token_1.clone());
+		assert_ok!(AssetConversion::fill_order(
+			RuntimeOrigin::signed(user),
+			Box::new(token_1.clone()),
+			Box::new(token_2.clone()),
+			0,
+			2,
+			user,
+			true,
+		));
+		assert_eq!(
+			events(),
+			[Event::<Test>::OrderFilled {
+				order_id: 0,
+				pool_id: pool_id.clone(),
+				taker: user,
+				amount_in: 2,
+				amount_out: 250,
+			}]
+		);
+		assert_eq!(balance(user, token_1.clone()), user_native + 250);
+		assert_eq!(balance(maker, token_1.clone()), maker_native + deposit);
+		assert_eq!(balance(AssetConversion::order_account(), token_1.clone()), 0);
+		assert!(!Orders::<Test>::contains_key(&pool_id));
+	});
+}
@@ -19,7 +19,7 @@ use super::*;
 use codec::{Decode, Encode, MaxEncodedLen};
 use core::marker::PhantomData;
 use scale_info::TypeInfo;
-use sp_runtime::traits::TryConvert;
+use sp_runtime::{traits::TryConvert, RuntimeDebug};
 
 /// Represents a swap path with associated asset amounts indicating how much of the asset needs to
 /// be deposited to get the following asset's amount withdrawn (this is inclusive of fees).
@@ -41,6 +41,38 @@ pub struct PoolInfo<PoolAssetId> {
 	pub lp_token: PoolAssetId,
 }
 
+/// A limit order resting in the order book of a pool.
+///
+/// The owner offers `amount_offered` of `offered` in exchange for `amount_wanted` of `wanted`.
+/// Partial fills reduce both amounts proportionally, so the limit price stays the same.
+#[derive(Decode, Encode, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct LimitOrder<AccountId, AssetKind, Balance> {
+	/// The identifier of the order.
+	pub id: OrderId,
+	/// The account that placed the order.
+	pub owner: AccountId,
+	/// The asset sold by the owner and held by the pallet until the order is filled.
+	pub offered: AssetKind,
+	/// The remaining amount of the `offered` asset.
+	pub amount_offered: Balance,
+	/// The asset the owner wants to receive.
+	pub wanted: AssetKind,
+	/// The remaining amount of the `wanted` asset.
+	pub amount_wanted: Balance,
+	/// The deposit held from the owner until the order is filled or cancelled.
+	pub deposit: Balance,
+}
+
+/// Identifier of a [`LimitOrder`].
+pub type OrderId = u32;
+
+/// [`LimitOrder`] as stored by the pallet.
+pub type LimitOrderOf<T> = LimitOrder<
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::AssetKind,
+	<T as Config>::Balance,
+>;
+
 /// Provides means to resolve the `PoolId` and `AccountId` from a pair of assets.
 ///
 /// Resulting `PoolId` remains consistent whether the asset pair is presented as (asset1, asset2)
@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-wiukf8gn-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `place_order`, `cancel_order` and `fill_order`. Re-run the benchmarks before relying on
+//! them.
 
 // Executed Command:
 // ./target/production/substrate-node
@@ -57,6 +61,9 @@ pub trait WeightInfo {
 	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight;
 	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight;
 	fn touch(n: u32, ) -> Weight;
+	fn place_order() -> Weight;
+	fn cancel_order() -> Weight;
+	fn fill_order() -> Weight;
 }
 
 /// Weights for `pallet_asset_conversion` using the Substrate node and recommended hardware.
@@ -183,6 +190,55 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
+	/// Storage: `AssetConversion::Pools` (r:1 w:0)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::Orders` (r:1 w:1)
+	/// Proof: `AssetConversion::Orders` (`max_values`: None, `max_size`: Some(1275), added: 3750, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::NextOrderId` (r:1 w:1)
+	/// Proof: `AssetConversion::NextOrderId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn place_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1023`
+		//  Estimated: `6208`
+		// Minimum execution time: 58_317_000 picoseconds.
+		Weight::from_parts(58_317_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AssetConversion::Orders` (r:1 w:1)
+	/// Proof: `AssetConversion::Orders` (`max_values`: None, `max_size`: Some(1275), added: 3750, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn cancel_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1188`
+		//  Estimated: `6208`
+		// Minimum execution time: 47_902_000 picoseconds.
+		Weight::from_parts(47_902_000, 6208)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AssetConversion::Orders` (r:1 w:1)
+	/// Proof: `AssetConversion::Orders` (`max_values`: None, `max_size`: Some(1275), added: 3750, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:2 w:2)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:4 w:4)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn fill_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1372`
+		//  Estimated: `11426`
+		// Minimum execution time: 81_655_000 picoseconds.
+		Weight::from_parts(81_655_000, 11426)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -308,4 +364,53 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
 	}
+	/// Storage: `AssetConversion::Pools` (r:1 w:0)
+	/// Proof: `AssetConversion::Pools` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::Orders` (r:1 w:1)
+	/// Proof: `AssetConversion::Orders` (`max_values`: None, `max_size`: Some(1275), added: 3750, mode: `MaxEncodedLen`)
+	/// Storage: `AssetConversion::NextOrderId` (r:1 w:1)
+	/// Proof: `AssetConversion::NextOrderId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn place_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1023`
+		//  Estimated: `6208`
+		// Minimum execution time: 58_317_000 picoseconds.
+		Weight::from_parts(58_317_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `AssetConversion::Orders` (r:1 w:1)
+	/// Proof: `AssetConversion::Orders` (`max_values`: None, `max_size`: Some(1275), added: 3750, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn cancel_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1188`
+		//  Estimated: `6208`
+		// Minimum execution time: 47_902_000 picoseconds.
+		Weight::from_parts(47_902_000, 6208)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `AssetConversion::Orders` (r:1 w:1)
+	/// Proof: `AssetConversion::Orders` (`max_values`: None, `max_size`: Some(1275), added: 3750, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:2 w:2)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:4 w:4)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn fill_order() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1372`
+		//  Estimated: `11426`
+		// Minimum execution time: 81_655_000 picoseconds.
+		Weight::from_parts(81_655_000, 11426)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
 }
@@ -107,6 +107,8 @@ impl pallet_assets::Config for Test {
 	type CallbackHandle = ();
 	type Currency = Balances;
 	type Freezer = AssetsFreezer;
+	type MaxFreezes = ConstU32<8>;
+	type UriLimit = ConstU32<256>;
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
@@ -355,6 +355,9 @@ benchmarks_instance_pallet! {
 		let dummy = vec![0u8; T::StringLimit::get() as usize];
 		let origin = SystemOrigin::Signed(caller.clone()).into();
 		Assets::<T, I>::set_metadata(origin, asset_id.clone(), dummy.clone(), dummy, 12)?;
+		let uri = Some(vec![0u8; T::UriLimit::get() as usize]);
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Assets::<T, I>::set_extended_metadata(origin, asset_id.clone(), uri, Some([0u8; 32]))?;
 	}: _(SystemOrigin::Signed(caller), asset_id.clone())
 	verify {
 		assert_last_event::<T, I>(Event::MetadataCleared { asset_id: asset_id.into() }.into());
@@ -388,8 +391,11 @@ benchmarks_instance_pallet! {
 		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
 		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
 		let dummy = vec![0u8; T::StringLimit::get() as usize];
-		let origin = SystemOrigin::Signed(caller).into();
+		let origin = SystemOrigin::Signed(caller.clone()).into();
 		Assets::<T, I>::set_metadata(origin, asset_id.clone(), dummy.clone(), dummy, 12)?;
+		let uri = Some(vec![0u8; T::UriLimit::get() as usize]);
+		let origin = SystemOrigin::Signed(caller).into();
+		Assets::<T, I>::set_extended_metadata(origin, asset_id.clone(), uri, Some([0u8; 32]))?;
 
 		let origin =
 			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
@@ -554,6 +560,55 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::Blocked { asset_id: asset_id.into(), who: caller }.into());
 	}
 
+	freeze_amount {
+		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		let amount = T::Balance::from(10u32);
+		for i in 1..T::MaxFreezes::get() {
+			Assets::<T, I>::freeze_amount(
+				SystemOrigin::Signed(caller.clone()).into(),
+				asset_id.clone(),
+				caller_lookup.clone(),
+				[i as u8; 8],
+				amount,
+			)?;
+		}
+	}: _(SystemOrigin::Signed(caller.clone()), asset_id.clone(), caller_lookup, [0; 8], amount)
+	verify {
+		assert_last_event::<T, I>(Event::FreezeSet { asset_id: asset_id.into(), who: caller, reason: [0; 8], amount }.into());
+	}
+
+	thaw_amount {
+		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, 100u32.into());
+		for i in 0..T::MaxFreezes::get() {
+			Assets::<T, I>::freeze_amount(
+				SystemOrigin::Signed(caller.clone()).into(),
+				asset_id.clone(),
+				caller_lookup.clone(),
+				[i as u8; 8],
+				10u32.into(),
+			)?;
+		}
+	}: _(SystemOrigin::Signed(caller.clone()), asset_id.clone(), caller_lookup, [0; 8])
+	verify {
+		assert_last_event::<T, I>(Event::FreezeRemoved { asset_id: asset_id.into(), who: caller, reason: [0; 8] }.into());
+	}
+
+	set_extended_metadata {
+		let u in 0 .. T::UriLimit::get();
+
+		let uri = Some(vec![0u8; u as usize]);
+		let icon_hash = Some([0u8; 32]);
+
+		let (asset_id, caller, _) = create_default_asset::<T, I>(true);
+		T::Currency::make_free_balance_be(&caller, DepositBalanceOf::<T, I>::max_value());
+		let dummy = vec![0u8; T::StringLimit::get() as usize];
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Assets::<T, I>::set_metadata(origin, asset_id.clone(), dummy.clone(), dummy, 12)?;
+	}: _(SystemOrigin::Signed(caller), asset_id.clone(), uri.clone(), icon_hash)
+	verify {
+		assert_last_event::<T, I>(Event::ExtendedMetadataSet { asset_id: asset_id.into(), uri, icon_hash }.into());
+	}
+
 	transfer_all {
 		let amount = T::Balance::from(2 * MIN_BALANCE);
 		let (asset_id, caller, caller_lookup) = create_default_minted_asset::<T, I>(true, amount);
@@ -193,7 +193,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			return Frozen
 		}
 		if let Some(rest) = account.balance.checked_sub(&amount) {
-			if let Some(frozen) = T::Freezer::frozen_balance(id.clone(), who) {
+			if let Some(frozen) = Self::frozen_balance(id.clone(), who) {
 				match frozen.checked_add(&details.min_balance) {
 					Some(required) if rest < required => return Frozen,
 					None => return Overflow,
@@ -215,6 +215,33 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 	}
 
+	/// The frozen balance of an account: the largest of the named freezes on it and of the
+	/// balance frozen by `T::Freezer`.
+	pub(super) fn frozen_balance(id: T::AssetId, who: &T::AccountId) -> Option<T::Balance> {
+		let named = Freezes::<T, I>::get(&id, who).into_iter().map(|f| f.amount).max();
+		match (T::Freezer::frozen_balance(id, who), named) {
+			(Some(frozen), Some(named)) => Some(frozen.max(named)),
+			(frozen, named) => frozen.or(named),
+		}
+	}
+
+	/// Remove the named freezes of an account which no longer exists and notify `T::Freezer`.
+	pub(super) fn account_died(id: T::AssetId, who: &T::AccountId) {
+		Freezes::<T, I>::remove(&id, who);
+		T::Freezer::died(id, who);
+	}
+
+	/// Ensure that `who` is the Freezer of the asset `id`, and that the asset is live or frozen.
+	pub(super) fn ensure_freezer(id: &T::AssetId, who: &T::AccountId) -> DispatchResult {
+		let d = Asset::<T, I>::get(id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(
+			d.status == AssetStatus::Live || d.status == AssetStatus::Frozen,
+			Error::<T, I>::IncorrectStatus
+		);
+		ensure!(*who == d.freezer, Error::<T, I>::NoPermission);
+		Ok(())
+	}
+
 	// Maximum `amount` that can be passed into `can_withdraw` to result in a `WithdrawConsequence`
 	// of `Success`.
 	pub(super) fn reducible_balance(
@@ -228,7 +255,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let account = Account::<T, I>::get(&id, who).ok_or(Error::<T, I>::NoAccount)?;
 		ensure!(!account.status.is_frozen(), Error::<T, I>::Frozen);
 
-		let amount = if let Some(frozen) = T::Freezer::frozen_balance(id, who) {
+		let amount = if let Some(frozen) = Self::frozen_balance(id, who) {
 			// Frozen balance: account CANNOT be deleted
 			let required =
 				frozen.checked_add(&details.min_balance).ok_or(ArithmeticError::Overflow)?;
@@ -371,7 +398,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 		Asset::<T, I>::insert(&id, details);
 		// Executing a hook here is safe, since it is not in a `mutate`.
-		T::Freezer::died(id, &who);
+		Self::account_died(id, &who);
 		Ok(())
 	}
 
@@ -407,7 +434,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		}
 		Asset::<T, I>::insert(&id, details);
 		// Executing a hook here is safe, since it is not in a `mutate`.
-		T::Freezer::died(id, &who);
+		Self::account_died(id, &who);
 		return Ok(())
 	}
 
@@ -575,7 +602,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		// Execute hook outside of `mutate`.
 		if let Some(Remove) = target_died {
-			T::Freezer::died(id, target);
+			Self::account_died(id, target);
 		}
 		Ok(actual)
 	}
@@ -599,7 +626,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let (balance, died) =
 			Self::transfer_and_die(id.clone(), source, dest, amount, maybe_need_admin, f)?;
 		if let Some(Remove) = died {
-			T::Freezer::died(id, source);
+			Self::account_died(id, source);
 		}
 		Ok(balance)
 	}
@@ -799,7 +826,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			})?;
 
 		for who in &dead_accounts {
-			T::Freezer::died(id.clone(), &who);
+			Self::account_died(id.clone(), &who);
 		}
 
 		Self::deposit_event(Event::AccountsDestroyed {
@@ -858,9 +885,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ensure!(T::CallbackHandle::destroyed(&id).is_ok(), Error::<T, I>::CallbackFailed);
 
 			let metadata = Metadata::<T, I>::take(&id);
+			let extended_deposit =
+				ExtendedMetadata::<T, I>::take(&id).map_or(Zero::zero(), |m| m.deposit);
 			T::Currency::unreserve(
 				&details.owner,
-				details.deposit.saturating_add(metadata.deposit),
+				details
+					.deposit
+					.saturating_add(metadata.deposit)
+					.saturating_add(extended_deposit),
 			);
 			Self::deposit_event(Event::Destroyed { asset_id: id });
 
@@ -960,7 +992,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		// Execute hook outside of `mutate`.
 		if let Some(Remove) = owner_died {
-			T::Freezer::died(id, owner);
+			Self::account_died(id, owner);
 		}
 		Ok(())
 	}
@@ -1020,6 +1052,65 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			.saturating_add(T::MetadataDepositBase::get())
 	}
 
+	/// Do set extended metadata
+	pub(super) fn do_set_extended_metadata(
+		id: T::AssetId,
+		from: &T::AccountId,
+		uri: Option<Vec<u8>>,
+		icon_hash: Option<[u8; 32]>,
+	) -> DispatchResult {
+		let bounded_uri: Option<BoundedVec<u8, T::UriLimit>> = uri
+			.clone()
+			.map(|u| u.try_into().map_err(|_| Error::<T, I>::BadMetadata))
+			.transpose()?;
+
+		let d = Asset::<T, I>::get(&id).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(d.status == AssetStatus::Live, Error::<T, I>::AssetNotLive);
+		ensure!(from == &d.owner, Error::<T, I>::NoPermission);
+		ensure!(Metadata::<T, I>::contains_key(&id), Error::<T, I>::Unknown);
+		ensure!(!Metadata::<T, I>::get(&id).is_frozen, Error::<T, I>::NoPermission);
+
+		if uri.is_none() && icon_hash.is_none() {
+			ensure!(ExtendedMetadata::<T, I>::contains_key(&id), Error::<T, I>::Unknown);
+			Self::do_clear_extended_metadata(id, from);
+			return Ok(())
+		}
+
+		ExtendedMetadata::<T, I>::try_mutate(id.clone(), |metadata| {
+			let old_deposit = metadata.take().map_or(Zero::zero(), |m| m.deposit);
+			let new_deposit = Self::calc_extended_metadata_deposit(uri.as_deref(), icon_hash);
+
+			if new_deposit > old_deposit {
+				T::Currency::reserve(from, new_deposit - old_deposit)?;
+			} else {
+				T::Currency::unreserve(from, old_deposit - new_deposit);
+			}
+
+			*metadata =
+				Some(ExtendedAssetMetadata { deposit: new_deposit, uri: bounded_uri, icon_hash });
+
+			Self::deposit_event(Event::ExtendedMetadataSet { asset_id: id, uri, icon_hash });
+			Ok(())
+		})
+	}
+
+	/// Remove any extended metadata of an asset and return its deposit to `owner`.
+	pub(super) fn do_clear_extended_metadata(id: T::AssetId, owner: &T::AccountId) {
+		if let Some(metadata) = ExtendedMetadata::<T, I>::take(&id) {
+			T::Currency::unreserve(owner, metadata.deposit);
+			Self::deposit_event(Event::ExtendedMetadataCleared { asset_id: id });
+		}
+	}
+
+	/// Calculate the extended metadata deposit for the provided data.
+	pub(super) fn calc_extended_metadata_deposit(
+		uri: Option<&[u8]>,
+		icon_hash: Option<[u8; 32]>,
+	) -> DepositBalanceOf<T, I> {
+		let len = uri.map_or(0, |u| u.len()) + icon_hash.map_or(0, |h| h.len());
+		T::MetadataDepositPerByte::get().saturating_mul((len as u32).into())
+	}
+
 	/// Returns all the non-zero balances for all assets of the given `account`.
 	pub fn account_balances(account: T::AccountId) -> Vec<(T::AssetId, T::Balance)> {
 		Asset::<T, I>::iter_keys()
@@ -357,3 +357,74 @@ impl<T: Config<I>, I: 'static> fungibles::Refund<T::AccountId> for Pallet<T, I>
 		}
 	}
 }
+
+impl<T: Config<I>, I: 'static> fungibles::InspectFreeze<T::AccountId> for Pallet<T, I> {
+	type Id = FreezeReason;
+
+	fn balance_frozen(asset: Self::AssetId, id: &Self::Id, who: &T::AccountId) -> Self::Balance {
+		Freezes::<T, I>::get(asset, who)
+			.into_iter()
+			.find(|f| &f.id == id)
+			.map_or(Zero::zero(), |f| f.amount)
+	}
+
+	fn can_freeze(asset: Self::AssetId, id: &Self::Id, who: &T::AccountId) -> bool {
+		let freezes = Freezes::<T, I>::get(asset, who);
+		!freezes.is_full() || freezes.into_iter().any(|f| &f.id == id)
+	}
+}
+
+impl<T: Config<I>, I: 'static> fungibles::MutateFreeze<T::AccountId> for Pallet<T, I> {
+	fn set_freeze(
+		asset: Self::AssetId,
+		id: &Self::Id,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Self::thaw(asset, id, who)
+		}
+		Freezes::<T, I>::try_mutate(asset, who, |freezes| {
+			if let Some(f) = freezes.iter_mut().find(|f| &f.id == id) {
+				f.amount = amount;
+			} else {
+				freezes
+					.try_push(IdAmount { id: *id, amount })
+					.map_err(|_| Error::<T, I>::TooManyFreezes)?;
+			}
+			Ok(())
+		})
+	}
+
+	fn extend_freeze(
+		asset: Self::AssetId,
+		id: &Self::Id,
+		who: &T::AccountId,
+		amount: Self::Balance,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		Freezes::<T, I>::try_mutate(asset, who, |freezes| {
+			if let Some(f) = freezes.iter_mut().find(|f| &f.id == id) {
+				f.amount = f.amount.max(amount);
+			} else {
+				freezes
+					.try_push(IdAmount { id: *id, amount })
+					.map_err(|_| Error::<T, I>::TooManyFreezes)?;
+			}
+			Ok(())
+		})
+	}
+
+	fn thaw(asset: Self::AssetId, id: &Self::Id, who: &T::AccountId) -> DispatchResult {
+		let mut freezes = Freezes::<T, I>::get(&asset, who);
+		freezes.retain(|f| &f.id != id);
+		if freezes.is_empty() {
+			Freezes::<T, I>::remove(asset, who);
+		} else {
+			Freezes::<T, I>::insert(asset, who, freezes);
+		}
+		Ok(())
+	}
+}
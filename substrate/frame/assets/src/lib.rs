@@ -116,8 +116,14 @@
 //!   Owner.
 //! * `set_metadata`: Set the metadata of an asset class; called by the asset class's Owner.
 //! * `clear_metadata`: Remove the metadata of an asset class; called by the asset class's Owner.
+//! * `set_extended_metadata`: Set the URI and icon hash of an asset class; called by the asset
+//!   class's Owner.
 //! * `touch_other`: Create an asset account for specified account. Caller must place a deposit;
 //!   called by the asset class's Freezer or Admin.
+//! * `freeze_amount`: Freezes part of the balance of an account under a named reason; called by the
+//!   asset class's Freezer.
+//! * `thaw_amount`: Removes a named freeze from the balance of an account; called by the asset
+//!   class's Freezer.
 //! * `block`: Disallows further `transfer`s to and from an account; called by the asset class's
 //!   Freezer.
 //!
@@ -184,7 +190,7 @@ use frame_support::{
 	storage::KeyPrefixIterator,
 	traits::{
 		tokens::{
-			fungibles, DepositConsequence, Fortitude,
+			fungibles, DepositConsequence, Fortitude, IdAmount,
 			Preservation::{Expendable, Preserve},
 			WithdrawConsequence,
 		},
@@ -295,6 +301,8 @@ pub mod pallet {
 			type MetadataDepositPerByte = ConstUint<1>;
 			type ApprovalDeposit = ConstUint<1>;
 			type StringLimit = ConstU32<50>;
+			type MaxFreezes = ConstU32<8>;
+			type UriLimit = ConstU32<256>;
 			type Extra = ();
 			type CallbackHandle = ();
 			type WeightInfo = ();
@@ -393,6 +401,14 @@ pub mod pallet {
 		#[pallet::no_default]
 		type Freezer: FrozenBalance<Self::AssetId, Self::AccountId, Self::Balance>;
 
+		/// The maximum number of named freezes on the balance of an account for an asset.
+		#[pallet::constant]
+		type MaxFreezes: Get<u32>;
+
+		/// The maximum length of an asset URI stored on-chain.
+		#[pallet::constant]
+		type UriLimit: Get<u32>;
+
 		/// Additional data to be stored with an account's asset balance.
 		type Extra: Member + Parameter + Default + MaxEncodedLen;
 
@@ -446,6 +462,18 @@ pub mod pallet {
 		Approval<T::Balance, DepositBalanceOf<T, I>>,
 	>;
 
+	#[pallet::storage]
+	/// The named freezes on the balance of an account for an asset.
+	pub type Freezes<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<IdAmount<FreezeReason, T::Balance>, T::MaxFreezes>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	/// Metadata of an asset.
 	pub(super) type Metadata<T: Config<I>, I: 'static = ()> = StorageMap<
@@ -456,6 +484,15 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	#[pallet::storage]
+	/// Extended metadata of an asset, such as the location of its logo.
+	pub(super) type ExtendedMetadata<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		ExtendedAssetMetadata<DepositBalanceOf<T, I>, BoundedVec<u8, T::UriLimit>>,
+	>;
+
 	/// The asset ID enforced for the next asset creation, if any present. Otherwise, this storage
 	/// item has no effect.
 	///
@@ -639,6 +676,23 @@ pub mod pallet {
 		Deposited { asset_id: T::AssetId, who: T::AccountId, amount: T::Balance },
 		/// Some assets were withdrawn from the account (e.g. for transaction fees).
 		Withdrawn { asset_id: T::AssetId, who: T::AccountId, amount: T::Balance },
+		/// Some part of the balance of account `who` was frozen under `reason`.
+		FreezeSet {
+			asset_id: T::AssetId,
+			who: T::AccountId,
+			reason: FreezeReason,
+			amount: T::Balance,
+		},
+		/// The freeze under `reason` on the balance of account `who` was removed.
+		FreezeRemoved { asset_id: T::AssetId, who: T::AccountId, reason: FreezeReason },
+		/// New extended metadata has been set for an asset.
+		ExtendedMetadataSet {
+			asset_id: T::AssetId,
+			uri: Option<Vec<u8>>,
+			icon_hash: Option<[u8; 32]>,
+		},
+		/// Extended metadata has been cleared for an asset.
+		ExtendedMetadataCleared { asset_id: T::AssetId },
 	}
 
 	#[pallet::error]
@@ -688,6 +742,10 @@ pub mod pallet {
 		CallbackFailed,
 		/// The asset ID must be equal to the [`NextAssetId`].
 		BadAssetId,
+		/// Number of named freezes on an account would exceed `MaxFreezes`.
+		TooManyFreezes,
+		/// The account has no freeze under the given reason.
+		FreezeNotFound,
 	}
 
 	#[pallet::call(weight(<T as Config<I>>::WeightInfo))]
@@ -1191,7 +1249,9 @@ pub mod pallet {
 					return Ok(())
 				}
 
-				let metadata_deposit = Metadata::<T, I>::get(&id).deposit;
+				let metadata_deposit = Metadata::<T, I>::get(&id).deposit.saturating_add(
+					ExtendedMetadata::<T, I>::get(&id).map_or(Zero::zero(), |m| m.deposit),
+				);
 				let deposit = details.deposit + metadata_deposit;
 
 				// Move the deposit to the new owner.
@@ -1297,6 +1357,7 @@ pub mod pallet {
 			Metadata::<T, I>::try_mutate_exists(id.clone(), |metadata| {
 				let deposit = metadata.take().ok_or(Error::<T, I>::Unknown)?.deposit;
 				T::Currency::unreserve(&d.owner, deposit);
+				Self::do_clear_extended_metadata(id.clone(), &d.owner);
 				Self::deposit_event(Event::MetadataCleared { asset_id: id });
 				Ok(())
 			})
@@ -1380,6 +1441,7 @@ pub mod pallet {
 			Metadata::<T, I>::try_mutate_exists(id.clone(), |metadata| {
 				let deposit = metadata.take().ok_or(Error::<T, I>::Unknown)?.deposit;
 				T::Currency::unreserve(&d.owner, deposit);
+				Self::do_clear_extended_metadata(id.clone(), &d.owner);
 				Self::deposit_event(Event::MetadataCleared { asset_id: id });
 				Ok(())
 			})
@@ -1798,6 +1860,107 @@ pub mod pallet {
 			)?;
 			Ok(())
 		}
+
+		/// Freeze `amount` of the balance of an account `who` under `reason`, replacing any
+		/// previous freeze under the same reason. The frozen part of the balance cannot be
+		/// transferred, while the account otherwise stays usable.
+		///
+		/// Freezes under different reasons overlap: the frozen balance of the account is the
+		/// largest of them.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account whose balance is frozen.
+		/// - `reason`: The name of the freeze.
+		/// - `amount`: The amount to freeze. A zero amount removes the freeze.
+		///
+		/// Emits `FreezeSet`.
+		///
+		/// Weight: `O(MaxFreezes)`
+		#[pallet::call_index(33)]
+		pub fn freeze_amount(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			who: AccountIdLookupOf<T>,
+			reason: FreezeReason,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+			let who = T::Lookup::lookup(who)?;
+			Self::ensure_freezer(&id, &origin)?;
+			ensure!(Account::<T, I>::contains_key(&id, &who), Error::<T, I>::NoAccount);
+
+			<Self as fungibles::MutateFreeze<_>>::set_freeze(id.clone(), &reason, &who, amount)?;
+			Self::deposit_event(Event::<T, I>::FreezeSet { asset_id: id, who, reason, amount });
+			Ok(())
+		}
+
+		/// Remove the freeze under `reason` on the balance of an account `who`.
+		///
+		/// Origin must be Signed and the sender should be the Freezer of the asset `id`.
+		///
+		/// - `id`: The identifier of the asset.
+		/// - `who`: The account whose balance is thawed.
+		/// - `reason`: The name of the freeze.
+		///
+		/// Emits `FreezeRemoved`.
+		///
+		/// Weight: `O(MaxFreezes)`
+		#[pallet::call_index(34)]
+		pub fn thaw_amount(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			who: AccountIdLookupOf<T>,
+			reason: FreezeReason,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+			let who = T::Lookup::lookup(who)?;
+			Self::ensure_freezer(&id, &origin)?;
+			ensure!(
+				Freezes::<T, I>::get(&id, &who).iter().any(|f| f.id == reason),
+				Error::<T, I>::FreezeNotFound
+			);
+
+			<Self as fungibles::MutateFreeze<_>>::thaw(id.clone(), &reason, &who)?;
+			Self::deposit_event(Event::<T, I>::FreezeRemoved { asset_id: id, who, reason });
+			Ok(())
+		}
+
+		/// Set the extended metadata for an asset.
+		///
+		/// Origin must be Signed and the sender should be the Owner of the asset `id`. The asset
+		/// must already have metadata, and that metadata must not be frozen.
+		///
+		/// Funds of sender are reserved according to the formula:
+		/// `MetadataDepositPerByte * (uri.len + icon_hash.len)` taking into account any already
+		/// reserved funds. Passing `None` for both fields clears the extended metadata and frees
+		/// the deposit.
+		///
+		/// - `id`: The identifier of the asset to update.
+		/// - `uri`: Where the asset's logo and further details can be resolved. Limited in length
+		///   by `UriLimit`.
+		/// - `icon_hash`: The hash of the asset's logo, so that it can be verified once fetched.
+		///
+		/// Emits `ExtendedMetadataSet` or `ExtendedMetadataCleared`.
+		///
+		/// Weight: `O(U)` where U is the length of the URI.
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::set_extended_metadata(
+			uri.as_ref().map_or(0, |u| u.len() as u32)
+		))]
+		pub fn set_extended_metadata(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			uri: Option<Vec<u8>>,
+			icon_hash: Option<[u8; 32]>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+			Self::do_set_extended_metadata(id, &origin, uri, icon_hash)
+		}
 	}
 
 	/// Implements [`AccountTouch`] trait.
@@ -1302,6 +1302,75 @@ fn set_metadata_should_work() {
 	});
 }
 
+#[test]
+fn set_extended_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		Balances::make_free_balance_be(&1, 100);
+		let uri = Some(vec![0u8; 20]);
+		let icon_hash = Some([1u8; 32]);
+
+		// Cannot extend metadata which does not exist
+		assert_noop!(
+			Assets::set_extended_metadata(RuntimeOrigin::signed(1), 0, uri.clone(), icon_hash),
+			Error::<Test>::Unknown,
+		);
+		assert_ok!(Assets::set_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			vec![0u8; 10],
+			vec![0u8; 10],
+			12
+		));
+		assert_eq!(Balances::free_balance(&1), 79);
+
+		// Cannot extend metadata of an unowned asset, or with an oversized URI
+		assert_noop!(
+			Assets::set_extended_metadata(RuntimeOrigin::signed(2), 0, uri.clone(), icon_hash),
+			Error::<Test>::NoPermission,
+		);
+		assert_noop!(
+			Assets::set_extended_metadata(RuntimeOrigin::signed(1), 0, Some(vec![0u8; 300]), None),
+			Error::<Test>::BadMetadata,
+		);
+
+		// Successfully extend metadata and take deposit
+		assert_ok!(Assets::set_extended_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			uri.clone(),
+			icon_hash
+		));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::ExtendedMetadataSet {
+			asset_id: 0,
+			uri,
+			icon_hash,
+		}));
+		assert_eq!(Balances::free_balance(&1), 27);
+
+		// Update deposit
+		assert_ok!(Assets::set_extended_metadata(RuntimeOrigin::signed(1), 0, None, icon_hash));
+		assert_eq!(Balances::free_balance(&1), 47);
+
+		// Clearing both fields frees the deposit
+		assert_ok!(Assets::set_extended_metadata(RuntimeOrigin::signed(1), 0, None, None));
+		assert!(!ExtendedMetadata::<Test>::contains_key(0));
+		assert_eq!(Balances::free_balance(&1), 79);
+
+		// Clearing the metadata also clears the extended metadata
+		assert_ok!(Assets::set_extended_metadata(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(vec![0u8; 10]),
+			None
+		));
+		assert_eq!(Balances::free_balance(&1), 69);
+		assert_ok!(Assets::clear_metadata(RuntimeOrigin::signed(1), 0));
+		assert!(!ExtendedMetadata::<Test>::contains_key(0));
+		assert_eq!(Balances::free_balance(&1), 100);
+	});
+}
+
 /// Destroying an asset calls the `FrozenBalance::died` hooks of all accounts.
 #[test]
 fn destroy_accounts_calls_died_hooks() {
@@ -1375,6 +1444,65 @@ fn freezer_should_work() {
 	});
 }
 
+#[test]
+fn named_freezes_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+
+		// Only the freezer can freeze part of a balance, and only of an existing account.
+		assert_noop!(
+			Assets::freeze_amount(RuntimeOrigin::signed(2), 0, 1, *b"staking_", 50),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Assets::freeze_amount(RuntimeOrigin::signed(1), 0, 2, *b"staking_", 50),
+			Error::<Test>::NoAccount
+		);
+		assert_ok!(Assets::freeze_amount(RuntimeOrigin::signed(1), 0, 1, *b"staking_", 50));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::FreezeSet {
+			asset_id: 0,
+			who: 1,
+			reason: *b"staking_",
+			amount: 50,
+		}));
+		assert_ok!(Assets::freeze_amount(RuntimeOrigin::signed(1), 0, 1, *b"lending_", 30));
+		assert_eq!(
+			<Assets as fungibles::InspectFreeze<u64>>::balance_frozen(0, b"lending_", &1),
+			30
+		);
+
+		// Freezes overlap, so only the largest one (plus the minimum balance) is locked.
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 40));
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 1),
+			Error::<Test>::BalanceLow
+		);
+
+		assert_noop!(
+			Assets::thaw_amount(RuntimeOrigin::signed(1), 0, 1, *b"unknown_"),
+			Error::<Test>::FreezeNotFound
+		);
+		assert_ok!(Assets::thaw_amount(RuntimeOrigin::signed(1), 0, 1, *b"staking_"));
+		System::assert_last_event(RuntimeEvent::Assets(crate::Event::FreezeRemoved {
+			asset_id: 0,
+			who: 1,
+			reason: *b"staking_",
+		}));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 20));
+		assert_noop!(
+			Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 1),
+			Error::<Test>::BalanceLow
+		);
+
+		// Once the last freeze is thawed, the account can be removed completely.
+		assert_ok!(Assets::thaw_amount(RuntimeOrigin::signed(1), 0, 1, *b"lending_"));
+		assert!(!Freezes::<Test>::contains_key(0, 1));
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 40));
+		assert_eq!(Assets::balance(0, 1), 0);
+	});
+}
+
 #[test]
 fn imbalances_should_work() {
 	use frame_support::traits::fungibles::Balanced;
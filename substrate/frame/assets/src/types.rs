@@ -35,6 +35,9 @@ pub(super) type AssetAccountOf<T, I> = AssetAccount<
 pub(super) type ExistenceReasonOf<T, I> =
 	ExistenceReason<DepositBalanceOf<T, I>, <T as SystemConfig>::AccountId>;
 
+/// The name of a freeze on part of the balance of an asset account.
+pub type FreezeReason = [u8; 8];
+
 /// AssetStatus holds the current state of the asset. It could either be Live and available for use,
 /// or in a Destroying state.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
@@ -200,6 +203,19 @@ pub struct AssetMetadata<DepositBalance, BoundedString> {
 	pub(super) is_frozen: bool,
 }
 
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Default, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct ExtendedAssetMetadata<DepositBalance, BoundedUri> {
+	/// The balance deposited for this metadata.
+	///
+	/// This pays for the data stored in this struct.
+	pub(super) deposit: DepositBalance,
+	/// Where the logo and further details of this asset can be resolved. Limited in length by
+	/// `UriLimit`.
+	pub(super) uri: Option<BoundedUri>,
+	/// The hash of the logo of this asset.
+	pub(super) icon_hash: Option<[u8; 32]>,
+}
+
 /// Trait for allowing a minimum balance on the account to be specified, beyond the
 /// `minimum_balance` of the asset. This is additive - the `minimum_balance` of the asset must be
 /// met *and then* anything here in addition.
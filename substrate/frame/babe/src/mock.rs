@@ -175,6 +175,8 @@ impl pallet_offences::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = pallet_session::historical::IdentificationTuple<Self>;
 	type OnOffenceHandler = Staking;
+	type SessionEra = pallet_staking::EraOfSession<Self>;
+	type MaxOffenceHistory = ConstU32<100>;
 }
 
 parameter_types! {
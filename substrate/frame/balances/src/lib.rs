@@ -158,6 +158,7 @@ use core::{cmp, fmt::Debug, mem, result};
 use frame_support::{
 	ensure,
 	pallet_prelude::DispatchResult,
+	reason_registry::GetReasons,
 	traits::{
 		tokens::{
 			fungible, BalanceStatus as Status, DepositConsequence,
@@ -181,7 +182,8 @@ use sp_runtime::{
 	ArithmeticError, DispatchError, FixedPointOperand, Perbill, RuntimeDebug, TokenError,
 };
 pub use types::{
-	AccountData, AdjustmentDirection, BalanceLock, DustCleaner, ExtraFlags, Reasons, ReserveData,
+	AccountBalanceDetails, AccountData, AdjustmentDirection, BalanceLock, DustCleaner, ExtraFlags,
+	ReasonedAmount, Reasons, ReserveData,
 };
 pub use weights::WeightInfo;
 
@@ -904,6 +906,32 @@ pub mod pallet {
 			<Self as fungible::Inspect<_>>::reducible_balance(who.borrow(), Protect, Polite)
 		}
 
+		/// Get the breakdown of the balance of an account, with its holds and freezes described
+		/// through the reasons of the runtime, along with its spendable balance.
+		pub fn balance_details(who: &T::AccountId) -> AccountBalanceDetails<T::Balance>
+		where
+			T::RuntimeHoldReason: GetReasons,
+			T::FreezeIdentifier: GetReasons,
+		{
+			let account = Self::account(who);
+			let hold_reasons = T::RuntimeHoldReason::reasons();
+			let freeze_reasons = T::FreezeIdentifier::reasons();
+			AccountBalanceDetails {
+				free: account.free,
+				reserved: account.reserved,
+				frozen: account.frozen,
+				holds: Holds::<T, I>::get(who)
+					.iter()
+					.map(|hold| ReasonedAmount::new(&hold.id, hold.amount, &hold_reasons))
+					.collect(),
+				freezes: Freezes::<T, I>::get(who)
+					.iter()
+					.map(|freeze| ReasonedAmount::new(&freeze.id, freeze.amount, &freeze_reasons))
+					.collect(),
+				spendable: Self::usable_balance(who),
+			}
+		}
+
 		/// Get the reserved balance of an account.
 		pub fn reserved_balance(who: impl core::borrow::Borrow<T::AccountId>) -> T::Balance {
 			Self::account(who.borrow()).reserved
@@ -1250,3 +1278,16 @@ pub mod pallet {
 		}
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// Runtime API for wallets to inspect the balance of an account.
+	pub trait BalancesApi<AccountId, Balance>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// The holds and freezes on the balance of `who` with their reasons, along with its
+		/// spendable balance.
+		fn balance_details(who: AccountId) -> AccountBalanceDetails<Balance>;
+	}
+}
@@ -236,6 +236,41 @@ fn freezing_and_holds_should_overlap() {
 		});
 }
 
+#[test]
+fn balance_details_should_describe_holds_and_freezes() {
+	ExtBuilder::default().existential_deposit(1).build_and_execute_with(|| {
+		Balances::set_balance(&1, 100);
+		assert_ok!(Balances::hold(&TestId::Foo, &1, 30));
+		assert_ok!(Balances::set_freeze(&TestId::Bar, &1, 50));
+
+		let details = Balances::balance_details(&1);
+		assert_eq!(details.free, 70);
+		assert_eq!(details.reserved, 30);
+		assert_eq!(details.frozen, 50);
+		assert_eq!(
+			details.holds,
+			vec![ReasonedAmount {
+				pallet: "Balances".into(),
+				reason: "Foo".into(),
+				encoded: TestId::Foo.encode(),
+				amount: 30,
+			}]
+		);
+		assert_eq!(
+			details.freezes,
+			vec![ReasonedAmount {
+				pallet: "Balances".into(),
+				reason: "Bar".into(),
+				encoded: TestId::Bar.encode(),
+				amount: 50,
+			}]
+		);
+		// The part of the freeze which isn't covered by the hold can't be spent.
+		assert_eq!(details.spendable, 50);
+		assert_eq!(details.spendable, Balances::reducible_balance(&1, Expendable, Polite));
+	});
+}
+
 #[test]
 fn frozen_hold_balance_cannot_be_moved_without_force() {
 	ExtBuilder::default()
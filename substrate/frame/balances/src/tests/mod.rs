@@ -19,12 +19,16 @@
 
 #![cfg(test)]
 
-use crate::{self as pallet_balances, AccountData, Config, CreditOf, Error, Pallet, TotalIssuance};
+use crate::{
+	self as pallet_balances, AccountData, Config, CreditOf, Error, Pallet, ReasonedAmount,
+	TotalIssuance,
+};
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	assert_err, assert_noop, assert_ok, assert_storage_noop, derive_impl,
 	dispatch::{DispatchInfo, GetDispatchInfo},
 	parameter_types,
+	reason_registry::{reasons_of, GetReasons, ReasonInfo},
 	traits::{
 		fungible, ConstU32, ConstU8, Imbalance as ImbalanceT, OnUnbalanced, StorageMapShim,
 		StoredMap, VariantCount, VariantCountOf, WhitelistedStorageKeys,
@@ -75,6 +79,19 @@ impl VariantCount for TestId {
 	const VARIANT_COUNT: u32 = 3;
 }
 
+impl GetReasons for TestId {
+	fn reasons() -> Vec<ReasonInfo> {
+		// `TestId` is used directly as the runtime reason, so its encoding has no pallet index.
+		reasons_of::<TestId>("Balances", 0)
+			.into_iter()
+			.map(|mut info| {
+				info.encoded.remove(0);
+				info
+			})
+			.collect()
+	}
+}
+
 frame_support::construct_runtime!(
 	pub enum Test {
 		System: frame_system,
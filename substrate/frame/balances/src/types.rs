@@ -18,9 +18,13 @@
 //! Types used in the pallet.
 
 use crate::{Config, CreditOf, Event, Pallet};
+use alloc::{string::String, vec::Vec};
 use codec::{Decode, Encode, MaxEncodedLen};
 use core::ops::BitOr;
-use frame_support::traits::{Imbalance, LockIdentifier, OnUnbalanced, WithdrawReasons};
+use frame_support::{
+	reason_registry::ReasonInfo,
+	traits::{Imbalance, LockIdentifier, OnUnbalanced, WithdrawReasons},
+};
 use scale_info::TypeInfo;
 use sp_runtime::{RuntimeDebug, Saturating};
 
@@ -131,6 +135,54 @@ impl<Balance: Saturating + Copy + Ord> AccountData<Balance> {
 	}
 }
 
+/// An amount held or frozen on the balance of an account, along with the description of its
+/// reason.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ReasonedAmount<Balance> {
+	/// The name of the pallet the reason belongs to, empty if the reason is not described by the
+	/// runtime.
+	pub pallet: String,
+	/// The name of the reason variant in the pallet enum, empty if the reason is not described by
+	/// the runtime.
+	pub reason: String,
+	/// The SCALE encoding of the reason.
+	pub encoded: Vec<u8>,
+	/// The amount held or frozen.
+	pub amount: Balance,
+}
+
+impl<Balance> ReasonedAmount<Balance> {
+	/// Describe `amount` held or frozen for the reason `id`, looking up its name in `reasons`.
+	pub(crate) fn new<Id: Encode>(id: &Id, amount: Balance, reasons: &[ReasonInfo]) -> Self {
+		let encoded = id.encode();
+		let (pallet, reason) = reasons
+			.iter()
+			.find(|info| encoded.starts_with(&info.encoded))
+			.map(|info| (info.pallet.clone(), info.reason.clone()))
+			.unwrap_or_default();
+		Self { pallet, reason, encoded, amount }
+	}
+}
+
+/// The breakdown of the balance of an account, as served to wallets by the
+/// [`BalancesApi`](crate::BalancesApi) runtime API.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AccountBalanceDetails<Balance> {
+	/// The free balance of the account.
+	pub free: Balance,
+	/// The balance of the account on hold or reserved.
+	pub reserved: Balance,
+	/// The amount that `free + reserved` may not drop below, accounting for freezes and locks.
+	pub frozen: Balance,
+	/// The individual holds on the balance of the account.
+	pub holds: Vec<ReasonedAmount<Balance>>,
+	/// The individual freezes on the balance of the account.
+	pub freezes: Vec<ReasonedAmount<Balance>>,
+	/// The balance the account can transfer, or use for any other non-fee payment, under the
+	/// current rules. This is the same as [`Pallet::usable_balance`].
+	pub spendable: Balance,
+}
+
 pub struct DustCleaner<T: Config<I>, I: 'static = ()>(
 	pub(crate) Option<(T::AccountId, CreditOf<T, I>)>,
 );
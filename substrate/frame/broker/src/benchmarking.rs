@@ -115,6 +115,35 @@ fn setup_and_start_sale<T: Config>() -> Result<u16, BenchmarkError> {
 		.unwrap())
 }
 
+/// Purchase a Region and fund an account which can make offers for it.
+fn setup_region_sale<T: Config>() -> Result<(RegionId, T::AccountId, T::AccountId), BenchmarkError>
+{
+	setup_and_start_sale::<T>()?;
+
+	advance_to::<T>(2);
+
+	let seller: T::AccountId = whitelisted_caller();
+	T::Currency::set_balance(
+		&seller.clone(),
+		T::Currency::minimum_balance().saturating_add(10_000_000u32.into()),
+	);
+	let region = Broker::<T>::do_purchase(seller.clone(), 10_000_000u32.into())
+		.map_err(|_| BenchmarkError::Weightless)?;
+
+	let buyer: T::AccountId = account("buyer", 0, SEED);
+	T::Currency::set_balance(
+		&buyer.clone(),
+		T::Currency::minimum_balance().saturating_add(10_000_000u32.into()),
+	);
+
+	Ok((region, seller, buyer))
+}
+
+/// The price of the Region offers, which must not be below the minimum balance.
+fn offer_price<T: Config>() -> BalanceOf<T> {
+	T::Currency::minimum_balance().max(1_000_000u32.into())
+}
+
 #[benchmarks]
 mod benches {
 	use super::*;
@@ -1136,6 +1165,84 @@ mod benches {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn place_offer() -> Result<(), BenchmarkError> {
+		let (region, _, buyer) = setup_region_sale::<T>()?;
+		let price = offer_price::<T>();
+		let expiry = Broker::<T>::current_timeslice().saturating_add(10);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(buyer.clone()), region, region.mask, price, expiry);
+
+		assert_last_event::<T>(
+			Event::OfferPlaced { region_id: region, buyer, mask: region.mask, price, expiry }
+				.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_offer() -> Result<(), BenchmarkError> {
+		let (region, seller, buyer) = setup_region_sale::<T>()?;
+		let price = offer_price::<T>();
+		let expiry = Broker::<T>::current_timeslice().saturating_add(10);
+		// Only sell a part of the Region, which requires it to be interlaced.
+		let mask: CoreMask = 0x00000_fffff_fffff_00000.into();
+		Broker::<T>::do_place_offer(region, buyer.clone(), mask, price, expiry)
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(seller.clone()), region, buyer.clone());
+
+		assert_last_event::<T>(
+			Event::OfferAccepted {
+				region_id: region,
+				sold_region_id: RegionId { mask, ..region },
+				seller,
+				buyer,
+				price,
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn withdraw_offer() -> Result<(), BenchmarkError> {
+		let (region, _, buyer) = setup_region_sale::<T>()?;
+		let expiry = Broker::<T>::current_timeslice().saturating_add(10);
+		Broker::<T>::do_place_offer(region, buyer.clone(), region.mask, offer_price::<T>(), expiry)
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(buyer.clone()), region);
+
+		assert_last_event::<T>(Event::OfferRemoved { region_id: region, buyer }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn drop_offer() -> Result<(), BenchmarkError> {
+		let (region, _, buyer) = setup_region_sale::<T>()?;
+		let expiry = Broker::<T>::current_timeslice().saturating_add(10);
+		Broker::<T>::do_place_offer(region, buyer.clone(), region.mask, offer_price::<T>(), expiry)
+			.map_err(|_| BenchmarkError::Weightless)?;
+		// The offer can be dropped since the Region no longer exists.
+		Regions::<T>::remove(&region);
+
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), region, buyer.clone());
+
+		assert_last_event::<T>(Event::OfferRemoved { region_id: region, buyer }.into());
+
+		Ok(())
+	}
+
 	// Implements a test for each benchmark. Execute with:
 	// `cargo test -p pallet-broker --features runtime-benchmarks`.
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
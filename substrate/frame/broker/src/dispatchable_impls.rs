@@ -18,7 +18,11 @@
 use super::*;
 use frame_support::{
 	pallet_prelude::*,
-	traits::{fungible::Mutate, tokens::Preservation::Expendable, DefensiveResult},
+	traits::{
+		fungible::{Inspect, Mutate},
+		tokens::Preservation::Expendable,
+		DefensiveResult,
+	},
 };
 use sp_arithmetic::traits::{CheckedDiv, Saturating, Zero};
 use sp_runtime::traits::{BlockNumberProvider, Convert};
@@ -552,6 +556,75 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	pub(crate) fn do_place_offer(
+		region_id: RegionId,
+		buyer: T::AccountId,
+		mask: CoreMask,
+		price: BalanceOf<T>,
+		expiry: Timeslice,
+	) -> DispatchResult {
+		let region = Regions::<T>::get(&region_id).ok_or(Error::<T>::UnknownRegion)?;
+		ensure!(region.owner.as_ref() != Some(&buyer), Error::<T>::AlreadyOwner);
+		ensure!((mask & !region_id.mask).is_void(), Error::<T>::ExteriorPivot);
+		ensure!(!mask.is_void(), Error::<T>::VoidPivot);
+		ensure!(expiry > Self::current_timeslice(), Error::<T>::AlreadyExpired);
+		ensure!(!RegionOffers::<T>::contains_key(&region_id, &buyer), Error::<T>::OfferExists);
+		// Every escrowed price being at least the minimum balance, paying one out never leaves
+		// dust in the escrow account.
+		ensure!(price >= T::Currency::minimum_balance(), Error::<T>::OfferTooLow);
+
+		T::Currency::transfer(&buyer, &Self::offer_escrow_account(), price, Expendable)?;
+		RegionOffers::<T>::insert(&region_id, &buyer, RegionOfferRecord { mask, price, expiry });
+
+		Self::deposit_event(Event::OfferPlaced { region_id, buyer, mask, price, expiry });
+		Ok(())
+	}
+
+	pub(crate) fn do_accept_offer(
+		region_id: RegionId,
+		seller: T::AccountId,
+		buyer: T::AccountId,
+	) -> DispatchResult {
+		let offer = RegionOffers::<T>::get(&region_id, &buyer).ok_or(Error::<T>::UnknownOffer)?;
+		ensure!(offer.expiry > Self::current_timeslice(), Error::<T>::AlreadyExpired);
+
+		let sold_region_id = if offer.mask == region_id.mask {
+			region_id
+		} else {
+			Self::do_interlace(region_id, Some(seller.clone()), offer.mask)?.0
+		};
+		Self::do_transfer(sold_region_id, Some(seller.clone()), buyer.clone())?;
+
+		RegionOffers::<T>::remove(&region_id, &buyer);
+		T::Currency::transfer(&Self::offer_escrow_account(), &seller, offer.price, Expendable)?;
+
+		Self::deposit_event(Event::OfferAccepted {
+			region_id,
+			sold_region_id,
+			seller,
+			buyer,
+			price: offer.price,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn do_drop_offer(region_id: RegionId, buyer: T::AccountId) -> DispatchResult {
+		let offer = RegionOffers::<T>::get(&region_id, &buyer).ok_or(Error::<T>::UnknownOffer)?;
+		ensure!(
+			offer.expiry <= Self::current_timeslice() || !Regions::<T>::contains_key(&region_id),
+			Error::<T>::StillValid
+		);
+		Self::do_remove_offer(region_id, buyer)
+	}
+
+	pub(crate) fn do_remove_offer(region_id: RegionId, buyer: T::AccountId) -> DispatchResult {
+		let offer = RegionOffers::<T>::take(&region_id, &buyer).ok_or(Error::<T>::UnknownOffer)?;
+		T::Currency::transfer(&Self::offer_escrow_account(), &buyer, offer.price, Expendable)?;
+
+		Self::deposit_event(Event::OfferRemoved { region_id, buyer });
+		Ok(())
+	}
+
 	pub(crate) fn ensure_cores_for_sale(
 		status: &StatusRecord,
 		sale: &SaleInfoRecordOf<T>,
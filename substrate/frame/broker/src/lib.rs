@@ -194,6 +194,18 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type RevenueInbox<T> = StorageValue<_, OnDemandRevenueRecordOf<T>, OptionQuery>;
 
+	/// Offers to buy (a part of) a Region, keyed by the Region and the buyer.
+	#[pallet::storage]
+	pub type RegionOffers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		RegionId,
+		Blake2_128Concat,
+		T::AccountId,
+		RegionOfferRecordOf<T>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -468,6 +480,39 @@ pub mod pallet {
 		/// This should never happen, given that enable_auto_renew checks for this before enabling
 		/// auto-renewal.
 		AutoRenewalLimitReached,
+		/// An offer to buy (a part of) a Region has been placed.
+		OfferPlaced {
+			/// The Region for which the offer was placed.
+			region_id: RegionId,
+			/// The account which placed the offer.
+			buyer: T::AccountId,
+			/// The part of the Region which is to be bought.
+			mask: CoreMask,
+			/// The price held in escrow.
+			price: BalanceOf<T>,
+			/// The timeslice from which on the offer can no longer be accepted.
+			expiry: Timeslice,
+		},
+		/// An offer has been accepted and (a part of) a Region has been sold.
+		OfferAccepted {
+			/// The Region for which the offer was placed.
+			region_id: RegionId,
+			/// The Region which was sold.
+			sold_region_id: RegionId,
+			/// The previous owner of the sold Region.
+			seller: T::AccountId,
+			/// The new owner of the sold Region.
+			buyer: T::AccountId,
+			/// The price paid to the seller.
+			price: BalanceOf<T>,
+		},
+		/// An offer has been removed and its escrow returned to the buyer.
+		OfferRemoved {
+			/// The Region for which the offer was placed.
+			region_id: RegionId,
+			/// The account which placed the offer.
+			buyer: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
@@ -544,6 +589,14 @@ pub mod pallet {
 		SovereignAccountNotFound,
 		/// Attempted to disable auto-renewal for a core that didn't have it enabled.
 		AutoRenewalNotEnabled,
+		/// The buyer already has an offer for this Region.
+		OfferExists,
+		/// The offer cannot be found.
+		UnknownOffer,
+		/// The owner of a Region cannot place an offer for it.
+		AlreadyOwner,
+		/// The offered price is below the minimum balance of the currency.
+		OfferTooLow,
 	}
 
 	#[derive(frame_support::DefaultNoBound)]
@@ -943,6 +996,72 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Offer to buy (a part of) a Bulk Coretime Region. The offered price is held in escrow
+		/// until the offer is accepted, withdrawn or dropped.
+		///
+		/// - `origin`: Must be a Signed origin of the buyer, which must not own the Region.
+		/// - `region_id`: The Region which should be bought.
+		/// - `mask`: The part of the Region's interlace mask which should be bought. The Region is
+		///   interlaced upon acceptance if this is not the whole of its mask.
+		/// - `price`: The price which will be paid to the owner of the Region.
+		/// - `expiry`: The timeslice from which on the offer can no longer be accepted.
+		#[pallet::call_index(23)]
+		pub fn place_offer(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			mask: CoreMask,
+			price: BalanceOf<T>,
+			expiry: Timeslice,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_place_offer(region_id, who, mask, price, expiry)?;
+			Ok(())
+		}
+
+		/// Accept an offer to buy (a part of) a Bulk Coretime Region. The escrowed price is paid
+		/// to the owner and the Region, or the offered part of it, is transferred to the buyer.
+		///
+		/// - `origin`: Must be a Signed origin of the account which owns the Region `region_id`.
+		/// - `region_id`: The Region for which the offer was placed.
+		/// - `buyer`: The account which placed the offer.
+		#[pallet::call_index(24)]
+		pub fn accept_offer(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			buyer: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_accept_offer(region_id, who, buyer)?;
+			Ok(())
+		}
+
+		/// Withdraw an offer to buy (a part of) a Bulk Coretime Region and return its escrow.
+		///
+		/// - `origin`: Must be a Signed origin of the account which placed the offer.
+		/// - `region_id`: The Region for which the offer was placed.
+		#[pallet::call_index(25)]
+		pub fn withdraw_offer(origin: OriginFor<T>, region_id: RegionId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_remove_offer(region_id, who)?;
+			Ok(())
+		}
+
+		/// Drop an offer which can no longer be accepted and return its escrow to the buyer.
+		///
+		/// - `origin`: Can be any kind of origin.
+		/// - `region_id`: The Region for which the offer was placed. The offer must have expired or
+		///   the Region must no longer exist.
+		/// - `buyer`: The account which placed the offer.
+		#[pallet::call_index(26)]
+		pub fn drop_offer(
+			_origin: OriginFor<T>,
+			region_id: RegionId,
+			buyer: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			Self::do_drop_offer(region_id, buyer)?;
+			Ok(Pays::No.into())
+		}
+
 		#[pallet::call_index(99)]
 		#[pallet::weight(T::WeightInfo::swap_leases())]
 		pub fn swap_leases(origin: OriginFor<T>, id: TaskId, other: TaskId) -> DispatchResult {
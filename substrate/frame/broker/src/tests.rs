@@ -770,6 +770,96 @@ fn interlace_works() {
 	});
 }
 
+#[test]
+fn offer_for_part_of_region_works() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		let mask = CoreMask::from_chunk(0, 40);
+		let expiry = Broker::current_timeslice() + 5;
+
+		assert_noop!(
+			Broker::place_offer(RuntimeOrigin::signed(1), region, mask, 200, expiry),
+			Error::<Test>::AlreadyOwner
+		);
+		assert_noop!(
+			Broker::place_offer(RuntimeOrigin::signed(2), region, CoreMask::void(), 200, expiry),
+			Error::<Test>::VoidPivot
+		);
+		assert_ok!(Broker::place_offer(RuntimeOrigin::signed(2), region, mask, 200, expiry));
+		assert_eq!(balance(2), 800);
+		// The price is escrowed apart from the revenue of the pallet.
+		let revenue = balance(Broker::account_id());
+		assert_eq!(balance(Broker::offer_escrow_account()), 200);
+		assert_noop!(
+			Broker::place_offer(RuntimeOrigin::signed(2), region, mask, 300, expiry),
+			Error::<Test>::OfferExists
+		);
+
+		let seller_balance = balance(1);
+		assert_noop!(
+			Broker::accept_offer(RuntimeOrigin::signed(3), region, 2),
+			Error::<Test>::NotOwner
+		);
+		assert_ok!(Broker::accept_offer(RuntimeOrigin::signed(1), region, 2));
+		assert_eq!(balance(1), seller_balance + 200);
+		assert_eq!(balance(Broker::offer_escrow_account()), 0);
+		assert_eq!(balance(Broker::account_id()), revenue);
+		assert_eq!(RegionOffers::<Test>::get(region, 2), None);
+
+		// Only the offered part of the Region has been sold.
+		let sold = RegionId { mask, ..region };
+		let kept = RegionId { mask: region.mask ^ mask, ..region };
+		assert_eq!(Regions::<Test>::get(sold).unwrap().owner, Some(2));
+		assert_eq!(Regions::<Test>::get(kept).unwrap().owner, Some(1));
+		System::assert_last_event(
+			Event::OfferAccepted {
+				region_id: region,
+				sold_region_id: sold,
+				seller: 1,
+				buyer: 2,
+				price: 200,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn offer_escrow_is_returned() {
+	TestExt::new().endow(1, 1000).endow(2, 1000).execute_with(|| {
+		assert_ok!(Broker::do_start_sales(100, 1));
+		advance_to(2);
+		let region = Broker::do_purchase(1, u64::max_value()).unwrap();
+		let expiry = Broker::current_timeslice() + 2;
+
+		assert_ok!(Broker::place_offer(RuntimeOrigin::signed(2), region, region.mask, 200, expiry));
+		assert_ok!(Broker::withdraw_offer(RuntimeOrigin::signed(2), region));
+		assert_eq!(balance(2), 1000);
+		assert_noop!(
+			Broker::withdraw_offer(RuntimeOrigin::signed(2), region),
+			Error::<Test>::UnknownOffer
+		);
+
+		assert_ok!(Broker::place_offer(RuntimeOrigin::signed(2), region, region.mask, 200, expiry));
+		assert_noop!(
+			Broker::drop_offer(RuntimeOrigin::signed(3), region, 2),
+			Error::<Test>::StillValid
+		);
+
+		// Expired offers can no longer be accepted, but dropped by anyone.
+		advance_to(expiry as u64 * 2);
+		assert_noop!(
+			Broker::accept_offer(RuntimeOrigin::signed(1), region, 2),
+			Error::<Test>::AlreadyExpired
+		);
+		assert_ok!(Broker::drop_offer(RuntimeOrigin::signed(3), region, 2));
+		assert_eq!(balance(2), 1000);
+		assert_eq!(Regions::<Test>::get(region).unwrap().owner, Some(1));
+	});
+}
+
 #[test]
 fn cant_assign_unowned_region() {
 	TestExt::new().endow(1, 1000).execute_with(|| {
@@ -111,6 +111,20 @@ pub struct ContributionRecord<AccountId> {
 }
 pub type ContributionRecordOf<T> = ContributionRecord<<T as SConfig>::AccountId>;
 
+/// An offer to buy (a part of) a Region, whose price is held in escrow until the offer is accepted,
+/// withdrawn or dropped after it expired.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RegionOfferRecord<Balance> {
+	/// The part of the Region which is to be bought. If it is not the whole of the Region's mask,
+	/// the Region is interlaced upon acceptance and only this part is sold.
+	pub mask: CoreMask,
+	/// The price offered and held in escrow.
+	pub price: Balance,
+	/// The timeslice from which on the offer can no longer be accepted.
+	pub expiry: Timeslice,
+}
+pub type RegionOfferRecordOf<T> = RegionOfferRecord<BalanceOf<T>>;
+
 /// A per-timeslice bookkeeping record for tracking Instantaneous Coretime Pool activity and
 /// making proper payments to contributors.
 #[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
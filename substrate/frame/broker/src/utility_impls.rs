@@ -59,6 +59,12 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account_truncating()
 	}
 
+	/// The account holding the prices escrowed by Region offers, kept apart from the revenue
+	/// held by [`Self::account_id`].
+	pub fn offer_escrow_account() -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(b"offers")
+	}
+
 	pub fn sale_price(sale: &SaleInfoRecordOf<T>, now: RelayBlockNumberOf<T>) -> BalanceOf<T> {
 		let num = now.saturating_sub(sale.sale_start).min(sale.leadin_length).saturated_into();
 		let through = FixedU64::from_rational(num, sale.leadin_length.saturated_into());
@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `sergej-B650-AORUS-ELITE-AX`, CPU: `AMD Ryzen 9 7900X3D 12-Core Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `place_offer`, `accept_offer`, `withdraw_offer` and `drop_offer`. Re-run the benchmarks
+//! before relying on them.
 
 // Executed Command:
 // ./target/release/substrate-node
@@ -83,6 +87,10 @@ pub trait WeightInfo {
 	fn on_new_timeslice() -> Weight;
 	fn enable_auto_renew() -> Weight;
 	fn disable_auto_renew() -> Weight;
+	fn place_offer() -> Weight;
+	fn accept_offer() -> Weight;
+	fn withdraw_offer() -> Weight;
+	fn drop_offer() -> Weight;
 }
 
 /// Weights for `pallet_broker` using the Substrate node and recommended hardware.
@@ -532,6 +540,64 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Broker::Regions` (r:1 w:0)
+	/// Proof: `Broker::Regions` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Broker::RegionOffers` (r:1 w:1)
+	/// Proof: `Broker::RegionOffers` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn place_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `620`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_318_000 picoseconds.
+		Weight::from_parts(52_318_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Broker::RegionOffers` (r:1 w:1)
+	/// Proof: `Broker::RegionOffers` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `Broker::Regions` (r:1 w:3)
+	/// Proof: `Broker::Regions` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn accept_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `745`
+		//  Estimated: `6196`
+		// Minimum execution time: 63_907_000 picoseconds.
+		Weight::from_parts(63_907_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Broker::RegionOffers` (r:1 w:1)
+	/// Proof: `Broker::RegionOffers` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `497`
+		//  Estimated: `6196`
+		// Minimum execution time: 45_261_000 picoseconds.
+		Weight::from_parts(45_261_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Broker::RegionOffers` (r:1 w:1)
+	/// Proof: `Broker::RegionOffers` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `Broker::Regions` (r:1 w:0)
+	/// Proof: `Broker::Regions` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn drop_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `497`
+		//  Estimated: `6196`
+		// Minimum execution time: 47_033_000 picoseconds.
+		Weight::from_parts(47_033_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -980,4 +1046,62 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Broker::Regions` (r:1 w:0)
+	/// Proof: `Broker::Regions` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `Broker::RegionOffers` (r:1 w:1)
+	/// Proof: `Broker::RegionOffers` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn place_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `620`
+		//  Estimated: `6196`
+		// Minimum execution time: 52_318_000 picoseconds.
+		Weight::from_parts(52_318_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Broker::RegionOffers` (r:1 w:1)
+	/// Proof: `Broker::RegionOffers` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `Broker::Regions` (r:1 w:3)
+	/// Proof: `Broker::Regions` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn accept_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `745`
+		//  Estimated: `6196`
+		// Minimum execution time: 63_907_000 picoseconds.
+		Weight::from_parts(63_907_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Broker::RegionOffers` (r:1 w:1)
+	/// Proof: `Broker::RegionOffers` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn withdraw_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `497`
+		//  Estimated: `6196`
+		// Minimum execution time: 45_261_000 picoseconds.
+		Weight::from_parts(45_261_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Broker::RegionOffers` (r:1 w:1)
+	/// Proof: `Broker::RegionOffers` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `Broker::Regions` (r:1 w:0)
+	/// Proof: `Broker::Regions` (`max_values`: None, `max_size`: Some(86), added: 2561, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn drop_offer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `497`
+		//  Estimated: `6196`
+		// Minimum execution time: 47_033_000 picoseconds.
+		Weight::from_parts(47_033_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
\ No newline at end of file
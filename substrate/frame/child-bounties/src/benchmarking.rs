@@ -396,6 +396,111 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn add_sub_child_bounty(
+		d: Linear<0, { T::MaximumReasonLength::get() }>,
+	) -> Result<(), BenchmarkError> {
+		setup_pot_account::<T>();
+		let bounty_setup = activate_child_bounty::<T>(0, d)?;
+		let sub_child_bounty_id = ParentTotalChildBounties::<T>::get(bounty_setup.bounty_id);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(bounty_setup.child_curator),
+			bounty_setup.bounty_id,
+			bounty_setup.child_bounty_id,
+			T::ChildBountyValueMinimum::get(),
+			bounty_setup.reason.clone(),
+		);
+
+		assert_last_event::<T>(
+			Event::SubChildBountyAdded {
+				index: bounty_setup.bounty_id,
+				parent_child_index: bounty_setup.child_bounty_id,
+				child_index: sub_child_bounty_id,
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	// Worst case when the child-bounty already has the maximum number of partial payouts but one.
+	#[benchmark]
+	fn award_partial_payout() -> Result<(), BenchmarkError> {
+		setup_pot_account::<T>();
+		let bounty_setup = activate_child_bounty::<T>(0, T::MaximumReasonLength::get())?;
+		let amount = T::Currency::minimum_balance();
+		for i in 1..T::MaxPartialPayouts::get() {
+			let beneficiary = T::Lookup::unlookup(account("beneficiary", i, SEED));
+			Pallet::<T>::award_partial_payout(
+				RawOrigin::Signed(bounty_setup.child_curator.clone()).into(),
+				bounty_setup.bounty_id,
+				bounty_setup.child_bounty_id,
+				beneficiary,
+				amount,
+			)?;
+		}
+		let beneficiary_account = account::<T::AccountId>("beneficiary", 0, SEED);
+		let beneficiary = T::Lookup::unlookup(beneficiary_account.clone());
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(bounty_setup.child_curator),
+			bounty_setup.bounty_id,
+			bounty_setup.child_bounty_id,
+			beneficiary,
+			amount,
+		);
+
+		assert_last_event::<T>(
+			Event::PartialPayoutAwarded {
+				index: bounty_setup.bounty_id,
+				child_index: bounty_setup.child_bounty_id,
+				beneficiary: beneficiary_account,
+				amount,
+			}
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claim_partial_payouts(
+		p: Linear<1, { T::MaxPartialPayouts::get() }>,
+	) -> Result<(), BenchmarkError> {
+		setup_pot_account::<T>();
+		let bounty_setup = activate_child_bounty::<T>(0, T::MaximumReasonLength::get())?;
+		let amount = T::Currency::minimum_balance();
+		for i in 0..p {
+			let beneficiary = T::Lookup::unlookup(account("beneficiary", i, SEED));
+			Pallet::<T>::award_partial_payout(
+				RawOrigin::Signed(bounty_setup.child_curator.clone()).into(),
+				bounty_setup.bounty_id,
+				bounty_setup.child_bounty_id,
+				beneficiary,
+				amount,
+			)?;
+		}
+
+		set_block_number::<T>(T::SpendPeriod::get() + T::BountyDepositPayoutDelay::get());
+		let caller = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), bounty_setup.bounty_id, bounty_setup.child_bounty_id);
+
+		ensure!(
+			!PartialPayouts::<T>::contains_key(
+				bounty_setup.bounty_id,
+				bounty_setup.child_bounty_id
+			),
+			"Partial payouts were not claimed."
+		);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite! {
 		Pallet,
 		tests::new_test_ext(),
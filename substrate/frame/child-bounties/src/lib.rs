@@ -28,6 +28,13 @@
 //! to be delegated with the responsibility of assigning a payout address once the specified
 //! set of tasks is completed.
 //!
+//! The curator of a child bounty can in turn carve sub-child bounties out of its budget, each with
+//! a curator of its own, whose fee is taken from the fee of the child bounty curator. Closing a
+//! child bounty closes its sub-child bounties as well, returning their unspent funds.
+//!
+//! While a child bounty is active, parts of its budget can be awarded to beneficiaries as partial
+//! payouts, which they can claim after the payout delay.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -43,6 +50,9 @@
 //! - `unassign_curator` - Unassign an accepted curator from a specific child bounty.
 //! - `close_child_bounty` - Cancel the child bounty for a specific treasury amount and close the
 //!   bounty.
+//! - `add_sub_child_bounty` - Add a sub-child bounty funded from the budget of a child bounty.
+//! - `award_partial_payout` - Award part of the budget of an active child bounty to a beneficiary.
+//! - `claim_partial_payouts` - Claim the unlocked partial payouts of a child bounty.
 
 // Most of the business logic in this pallet has been
 // originally contributed by "https://github.com/shamb0",
@@ -106,6 +116,17 @@ pub struct ChildBounty<AccountId, Balance, BlockNumber> {
 	status: ChildBountyStatus<AccountId, BlockNumber>,
 }
 
+/// A partial payout awarded from an active child-bounty.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PartialPayout<AccountId, Balance, BlockNumber> {
+	/// The beneficiary of the payout.
+	beneficiary: AccountId,
+	/// The amount to be paid out.
+	amount: Balance,
+	/// When the payout can be claimed.
+	unlock_at: BlockNumber,
+}
+
 /// The status of a child-bounty.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum ChildBountyStatus<AccountId, BlockNumber> {
@@ -157,6 +178,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type ChildBountyValueMinimum: Get<BalanceOf<Self>>;
 
+		/// Maximum number of sub-child-bounties that can be added to a child-bounty.
+		#[pallet::constant]
+		type MaxSubChildBountyCount: Get<u32>;
+
+		/// Maximum number of partial payouts of a child-bounty which can be pending at once.
+		#[pallet::constant]
+		type MaxPartialPayouts: Get<u32>;
+
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -172,6 +201,14 @@ pub mod pallet {
 		InsufficientBountyBalance,
 		/// Number of child bounties exceeds limit `MaxActiveChildBountyCount`.
 		TooManyChildBounties,
+		/// Number of sub-child-bounties exceeds limit `MaxSubChildBountyCount`.
+		TooManySubChildBounties,
+		/// A sub-child-bounty cannot have sub-child-bounties of its own.
+		TooDeep,
+		/// Number of pending partial payouts exceeds limit `MaxPartialPayouts`.
+		TooManyPartialPayouts,
+		/// The child-bounty still has partial payouts pending.
+		PartialPayoutsPending,
 	}
 
 	#[pallet::event]
@@ -190,6 +227,26 @@ pub mod pallet {
 		},
 		/// A child-bounty is cancelled.
 		Canceled { index: BountyIndex, child_index: BountyIndex },
+		/// A sub-child-bounty is added to a child-bounty.
+		SubChildBountyAdded {
+			index: BountyIndex,
+			parent_child_index: BountyIndex,
+			child_index: BountyIndex,
+		},
+		/// Part of a child-bounty is awarded to a beneficiary.
+		PartialPayoutAwarded {
+			index: BountyIndex,
+			child_index: BountyIndex,
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// A partial payout of a child-bounty is claimed by its beneficiary.
+		PartialPayoutClaimed {
+			index: BountyIndex,
+			child_index: BountyIndex,
+			payout: BalanceOf<T>,
+			beneficiary: T::AccountId,
+		},
 	}
 
 	/// DEPRECATED: Replaced with `ParentTotalChildBounties` storage item keeping dedicated counts
@@ -246,6 +303,40 @@ pub mod pallet {
 	pub type ChildrenCuratorFees<T: Config> =
 		StorageMap<_, Twox64Concat, BountyIndex, BalanceOf<T>, ValueQuery>;
 
+	/// The sub-child-bounties carved out of each child-bounty. Indexed by `(parent_id, child_id)`.
+	#[pallet::storage]
+	pub type SubChildBounties<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BountyIndex,
+		Twox64Concat,
+		BountyIndex,
+		BoundedVec<BountyIndex, T::MaxSubChildBountyCount>,
+		ValueQuery,
+	>;
+
+	/// The child-bounty each sub-child-bounty was carved out of. Indexed by
+	/// `(parent_id, sub_child_id)`.
+	#[pallet::storage]
+	pub type ChildBountyParents<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BountyIndex, Twox64Concat, BountyIndex, BountyIndex>;
+
+	/// The partial payouts of each child-bounty which were not claimed yet. Indexed by
+	/// `(parent_id, child_id)`.
+	#[pallet::storage]
+	pub type PartialPayouts<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BountyIndex,
+		Twox64Concat,
+		BountyIndex,
+		BoundedVec<
+			PartialPayout<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+			T::MaxPartialPayouts,
+		>,
+		ValueQuery,
+	>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Add a new child-bounty.
@@ -359,7 +450,10 @@ pub mod pallet {
 			let child_bounty_curator = T::Lookup::lookup(curator)?;
 
 			let (curator, _) = Self::ensure_bounty_active(parent_bounty_id)?;
-			ensure!(signer == curator, BountiesError::<T>::RequireCurator);
+			ensure!(
+				Self::is_parent_curator(parent_bounty_id, child_bounty_id, &signer, &curator),
+				BountiesError::<T>::RequireCurator
+			);
 
 			// Mutate the child-bounty instance.
 			ChildBounties::<T>::try_mutate_exists(
@@ -378,12 +472,35 @@ pub mod pallet {
 					// Ensure child-bounty curator fee is less than child-bounty value.
 					ensure!(fee < child_bounty.value, BountiesError::<T>::InvalidFee);
 
-					// Add child-bounty curator fee to the cumulative sum. To be
-					// subtracted from the parent bounty curator when claiming
-					// bounty.
-					ChildrenCuratorFees::<T>::mutate(parent_bounty_id, |value| {
-						*value = value.saturating_add(fee)
-					});
+					if let Some(parent_child_bounty_id) =
+						ChildBountyParents::<T>::get(parent_bounty_id, child_bounty_id)
+					{
+						// The fee of a sub-child-bounty curator is taken from the fee of the
+						// curator of the child-bounty it was carved out of, which gets back the
+						// fee of any previously proposed curator.
+						ChildBounties::<T>::try_mutate(
+							parent_bounty_id,
+							parent_child_bounty_id,
+							|maybe_parent_child_bounty| -> DispatchResult {
+								let parent_child_bounty = maybe_parent_child_bounty
+									.as_mut()
+									.ok_or(BountiesError::<T>::InvalidIndex)?;
+								parent_child_bounty.fee = parent_child_bounty
+									.fee
+									.saturating_add(child_bounty.fee)
+									.checked_sub(&fee)
+									.ok_or(BountiesError::<T>::InvalidFee)?;
+								Ok(())
+							},
+						)?;
+					} else {
+						// Add child-bounty curator fee to the cumulative sum. To be
+						// subtracted from the parent bounty curator when claiming
+						// bounty.
+						ChildrenCuratorFees::<T>::mutate(parent_bounty_id, |value| {
+							*value = value.saturating_add(fee)
+						});
+					}
 
 					// Update the child-bounty curator fee.
 					child_bounty.fee = fee;
@@ -531,10 +648,17 @@ pub mod pallet {
 							ensure!(
 								maybe_sender.map_or(true, |sender| {
 									sender == *curator ||
-										Self::ensure_bounty_active(parent_bounty_id)
-											.map_or(false, |(parent_curator, _)| {
-												sender == parent_curator
-											})
+										Self::ensure_bounty_active(parent_bounty_id).map_or(
+											false,
+											|(parent_curator, _)| {
+												Self::is_parent_curator(
+													parent_bounty_id,
+													child_bounty_id,
+													&sender,
+													&parent_curator,
+												)
+											},
+										)
 								}),
 								BadOrigin
 							);
@@ -560,8 +684,12 @@ pub mod pallet {
 								Some(sender) => {
 									let (parent_curator, update_due) =
 										Self::ensure_bounty_active(parent_bounty_id)?;
-									if sender == parent_curator ||
-										update_due < Self::treasury_block_number()
+									if Self::is_parent_curator(
+										parent_bounty_id,
+										child_bounty_id,
+										&sender,
+										&parent_curator,
+									) || update_due < Self::treasury_block_number()
 									{
 										// Slash the child-bounty curator if
 										// + the call is made by the parent bounty curator.
@@ -578,7 +706,12 @@ pub mod pallet {
 						ChildBountyStatus::PendingPayout { ref curator, .. } => {
 							let (parent_curator, _) = Self::ensure_bounty_active(parent_bounty_id)?;
 							ensure!(
-								maybe_sender.map_or(true, |sender| parent_curator == sender),
+								maybe_sender.map_or(true, |sender| Self::is_parent_curator(
+									parent_bounty_id,
+									child_bounty_id,
+									&sender,
+									&parent_curator,
+								)),
 								BadOrigin,
 							);
 							slash_curator(curator, &mut child_bounty.curator_deposit);
@@ -623,6 +756,12 @@ pub mod pallet {
 			// Ensure parent bounty exists, and is active.
 			let (parent_curator, _) = Self::ensure_bounty_active(parent_bounty_id)?;
 
+			// Ensure no active sub-child-bounties, whose funds are still to be spent.
+			ensure!(
+				!SubChildBounties::<T>::contains_key(parent_bounty_id, child_bounty_id),
+				BountiesError::<T>::HasActiveChildBounty
+			);
+
 			ChildBounties::<T>::try_mutate_exists(
 				parent_bounty_id,
 				child_bounty_id,
@@ -633,7 +772,13 @@ pub mod pallet {
 					// Ensure child-bounty is in active state.
 					if let ChildBountyStatus::Active { ref curator } = child_bounty.status {
 						ensure!(
-							signer == *curator || signer == parent_curator,
+							signer == *curator ||
+								Self::is_parent_curator(
+									parent_bounty_id,
+									child_bounty_id,
+									&signer,
+									&parent_curator,
+								),
 							BountiesError::<T>::RequireCurator,
 						);
 						// Move the child-bounty state to pending payout.
@@ -685,6 +830,12 @@ pub mod pallet {
 		) -> DispatchResult {
 			let _ = ensure_signed(origin)?;
 
+			// Ensure all partial payouts are claimed first.
+			ensure!(
+				!PartialPayouts::<T>::contains_key(parent_bounty_id, child_bounty_id),
+				Error::<T>::PartialPayoutsPending
+			);
+
 			// Ensure child-bounty is in expected state.
 			ChildBounties::<T>::try_mutate_exists(
 				parent_bounty_id,
@@ -754,6 +905,9 @@ pub mod pallet {
 						// Remove the child-bounty description.
 						ChildBountyDescriptionsV1::<T>::remove(parent_bounty_id, child_bounty_id);
 
+						// Remove the sub-child-bounty from its parent child-bounty, if any.
+						Self::remove_sub_child_bounty(parent_bounty_id, child_bounty_id);
+
 						// Remove the child-bounty instance from the state.
 						*maybe_child_bounty = None;
 
@@ -778,6 +932,10 @@ pub mod pallet {
 		/// If the state of child-bounty is `PendingPayout`, call fails &
 		/// returns `PendingPayout` error.
 		///
+		/// The sub-child-bounties of the child-bounty are closed first, their
+		/// funds being returned to the child-bounty. The call fails if any
+		/// partial payout of the child-bounty is still pending.
+		///
 		/// For the origin other than T::RejectOrigin, parent bounty must be in
 		/// active state, for this child-bounty call to work. For origin
 		/// T::RejectOrigin execution is forced.
@@ -788,13 +946,12 @@ pub mod pallet {
 		/// - `parent_bounty_id`: Index of parent bounty.
 		/// - `child_bounty_id`: Index of child bounty.
 		#[pallet::call_index(6)]
-		#[pallet::weight(<T as Config>::WeightInfo::close_child_bounty_added()
-			.max(<T as Config>::WeightInfo::close_child_bounty_active()))]
+		#[pallet::weight(Pallet::<T>::close_child_bounty_weight(T::MaxSubChildBountyCount::get()))]
 		pub fn close_child_bounty(
 			origin: OriginFor<T>,
 			#[pallet::compact] parent_bounty_id: BountyIndex,
 			#[pallet::compact] child_bounty_id: BountyIndex,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let maybe_sender = ensure_signed(origin.clone())
 				.map(Some)
 				.or_else(|_| T::RejectOrigin::ensure_origin(origin).map(|_| None))?;
@@ -802,11 +959,263 @@ pub mod pallet {
 			// Ensure parent bounty exist, get parent curator.
 			let (parent_curator, _) = Self::ensure_bounty_active(parent_bounty_id)?;
 
-			ensure!(maybe_sender.map_or(true, |sender| parent_curator == sender), BadOrigin);
+			ensure!(
+				maybe_sender.map_or(true, |sender| Self::is_parent_curator(
+					parent_bounty_id,
+					child_bounty_id,
+					&sender,
+					&parent_curator,
+				)),
+				BadOrigin
+			);
 
+			let sub_child_bounties =
+				SubChildBounties::<T>::decode_len(parent_bounty_id, child_bounty_id).unwrap_or(0);
 			Self::impl_close_child_bounty(parent_bounty_id, child_bounty_id)?;
+			Ok(Some(Self::close_child_bounty_weight(sub_child_bounties as u32)).into())
+		}
+
+		/// Add a new sub-child-bounty, carved out of an active child-bounty.
+		///
+		/// The dispatch origin for this call must be the curator of the child-bounty, and the
+		/// parent bounty must be in "active" state. A sub-child-bounty cannot have
+		/// sub-child-bounties of its own.
+		///
+		/// The sub-child-bounty is funded from the budget of the child-bounty, which must have
+		/// enough funds left besides its curator fee and pending partial payouts. It is otherwise
+		/// handled as any child-bounty of the parent bounty, except that the curator of the
+		/// child-bounty it was carved out of acts as its parent curator, and that the fee of its
+		/// curator is taken from the fee of the child-bounty curator.
+		///
+		/// Upper bound to maximum number of sub-child-bounties that can be added to a child-bounty
+		/// is managed via runtime trait config [`Config::MaxSubChildBountyCount`].
+		///
+		/// - `parent_bounty_id`: Index of parent bounty.
+		/// - `child_bounty_id`: Index of the child-bounty to carve the sub-child-bounty out of.
+		/// - `value`: Value for executing the proposal.
+		/// - `description`: Text description for the sub-child-bounty.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_sub_child_bounty(description.len() as u32))]
+		pub fn add_sub_child_bounty(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			#[pallet::compact] child_bounty_id: BountyIndex,
+			#[pallet::compact] value: BalanceOf<T>,
+			description: Vec<u8>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+
+			// Verify the arguments.
+			let bounded_description =
+				description.try_into().map_err(|_| BountiesError::<T>::ReasonTooBig)?;
+			ensure!(value >= T::ChildBountyValueMinimum::get(), BountiesError::<T>::InvalidValue);
+			ensure!(
+				ParentChildBounties::<T>::get(parent_bounty_id) <=
+					T::MaxActiveChildBountyCount::get() as u32,
+				Error::<T>::TooManyChildBounties,
+			);
+			ensure!(
+				!ChildBountyParents::<T>::contains_key(parent_bounty_id, child_bounty_id),
+				Error::<T>::TooDeep
+			);
+
+			Self::ensure_bounty_active(parent_bounty_id)?;
+			let child_bounty = ChildBounties::<T>::get(parent_bounty_id, child_bounty_id)
+				.ok_or(BountiesError::<T>::InvalidIndex)?;
+			match child_bounty.status {
+				ChildBountyStatus::Active { ref curator } =>
+					ensure!(signer == *curator, BountiesError::<T>::RequireCurator),
+				_ => return Err(BountiesError::<T>::UnexpectedStatus.into()),
+			}
+
+			// Ensure the child-bounty has enough funds left for the sub-child-bounty.
+			ensure!(
+				value <=
+					Self::available_child_bounty_balance(
+						parent_bounty_id,
+						child_bounty_id,
+						&child_bounty
+					),
+				Error::<T>::InsufficientBountyBalance
+			);
+
+			// Get sub-child-bounty ID.
+			let sub_child_bounty_id = ParentTotalChildBounties::<T>::get(parent_bounty_id);
+			SubChildBounties::<T>::try_append(
+				parent_bounty_id,
+				child_bounty_id,
+				sub_child_bounty_id,
+			)
+			.map_err(|_| Error::<T>::TooManySubChildBounties)?;
+			ChildBountyParents::<T>::insert(parent_bounty_id, sub_child_bounty_id, child_bounty_id);
+
+			// Transfer funds from the child-bounty to the sub-child-bounty.
+			T::Currency::transfer(
+				&Self::child_bounty_account_id(parent_bounty_id, child_bounty_id),
+				&Self::child_bounty_account_id(parent_bounty_id, sub_child_bounty_id),
+				value,
+				KeepAlive,
+			)?;
+
+			// Increment the active child-bounty count.
+			ParentChildBounties::<T>::mutate(parent_bounty_id, |count| count.saturating_inc());
+			ParentTotalChildBounties::<T>::insert(
+				parent_bounty_id,
+				sub_child_bounty_id.saturating_add(1),
+			);
+
+			// Create sub-child-bounty instance.
+			Self::create_child_bounty(
+				parent_bounty_id,
+				sub_child_bounty_id,
+				value,
+				bounded_description,
+			);
+			Self::deposit_event(Event::<T>::SubChildBountyAdded {
+				index: parent_bounty_id,
+				parent_child_index: child_bounty_id,
+				child_index: sub_child_bounty_id,
+			});
+			Ok(())
+		}
+
+		/// Award part of the budget of an active child-bounty to a beneficiary.
+		///
+		/// The beneficiary will be able to claim the funds after a delay, using
+		/// `claim_partial_payouts`.
+		///
+		/// The dispatch origin for this call must be the parent curator or
+		/// curator of this child-bounty.
+		///
+		/// Parent bounty must be in active state, for this child-bounty call to
+		/// work.
+		///
+		/// Child-bounty must be in active state and have enough funds left besides its curator fee
+		/// and other pending partial payouts. The child-bounty cannot be awarded, claimed nor
+		/// closed until all its partial payouts are claimed.
+		///
+		/// - `parent_bounty_id`: Index of parent bounty.
+		/// - `child_bounty_id`: Index of child bounty.
+		/// - `beneficiary`: Beneficiary account.
+		/// - `amount`: Amount of the partial payout.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::award_partial_payout())]
+		pub fn award_partial_payout(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			#[pallet::compact] child_bounty_id: BountyIndex,
+			beneficiary: AccountIdLookupOf<T>,
+			#[pallet::compact] amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+
+			// Ensure parent bounty exists, and is active.
+			let (parent_curator, _) = Self::ensure_bounty_active(parent_bounty_id)?;
+
+			let child_bounty = ChildBounties::<T>::get(parent_bounty_id, child_bounty_id)
+				.ok_or(BountiesError::<T>::InvalidIndex)?;
+			match child_bounty.status {
+				ChildBountyStatus::Active { ref curator } => ensure!(
+					signer == *curator ||
+						Self::is_parent_curator(
+							parent_bounty_id,
+							child_bounty_id,
+							&signer,
+							&parent_curator,
+						),
+					BountiesError::<T>::RequireCurator,
+				),
+				_ => return Err(BountiesError::<T>::UnexpectedStatus.into()),
+			}
+
+			ensure!(
+				amount <=
+					Self::available_child_bounty_balance(
+						parent_bounty_id,
+						child_bounty_id,
+						&child_bounty
+					),
+				Error::<T>::InsufficientBountyBalance
+			);
+
+			PartialPayouts::<T>::try_append(
+				parent_bounty_id,
+				child_bounty_id,
+				PartialPayout {
+					beneficiary: beneficiary.clone(),
+					amount,
+					unlock_at: Self::treasury_block_number() + T::BountyDepositPayoutDelay::get(),
+				},
+			)
+			.map_err(|_| Error::<T>::TooManyPartialPayouts)?;
+
+			Self::deposit_event(Event::<T>::PartialPayoutAwarded {
+				index: parent_bounty_id,
+				child_index: child_bounty_id,
+				beneficiary,
+				amount,
+			});
 			Ok(())
 		}
+
+		/// Claim the partial payouts of a child-bounty whose payout delay has elapsed.
+		///
+		/// The dispatch origin for this call may be any signed origin.
+		///
+		/// Call works independent of parent bounty and child-bounty state.
+		///
+		/// Each beneficiary is paid out with the amount of their partial payout. The call fails if
+		/// no partial payout can be claimed yet.
+		///
+		/// - `parent_bounty_id`: Index of parent bounty.
+		/// - `child_bounty_id`: Index of child bounty.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_partial_payouts(
+			T::MaxPartialPayouts::get()
+		))]
+		pub fn claim_partial_payouts(
+			origin: OriginFor<T>,
+			#[pallet::compact] parent_bounty_id: BountyIndex,
+			#[pallet::compact] child_bounty_id: BountyIndex,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+
+			let payouts = PartialPayouts::<T>::get(parent_bounty_id, child_bounty_id);
+			let payouts_len = payouts.len() as u32;
+			let now = Self::treasury_block_number();
+			let (claimable, pending): (Vec<_>, Vec<_>) =
+				payouts.into_iter().partition(|payout| now >= payout.unlock_at);
+			ensure!(!claimable.is_empty(), BountiesError::<T>::Premature);
+
+			let child_bounty_account =
+				Self::child_bounty_account_id(parent_bounty_id, child_bounty_id);
+			for PartialPayout { beneficiary, amount, .. } in claimable {
+				// Should not fail because the amount was left aside in the child-bounty
+				// account when awarding the partial payout.
+				let transfer_result =
+					T::Currency::transfer(&child_bounty_account, &beneficiary, amount, KeepAlive);
+				debug_assert!(transfer_result.is_ok());
+
+				Self::deposit_event(Event::<T>::PartialPayoutClaimed {
+					index: parent_bounty_id,
+					child_index: child_bounty_id,
+					payout: amount,
+					beneficiary,
+				});
+			}
+
+			if pending.is_empty() {
+				PartialPayouts::<T>::remove(parent_bounty_id, child_bounty_id);
+			} else {
+				PartialPayouts::<T>::insert(
+					parent_bounty_id,
+					child_bounty_id,
+					BoundedVec::truncate_from(pending),
+				);
+			}
+			Ok(Some(<T as Config>::WeightInfo::claim_partial_payouts(payouts_len)).into())
+		}
 	}
 
 	#[pallet::hooks]
@@ -874,6 +1283,74 @@ impl<T: Config> Pallet<T> {
 		Self::deposit_event(Event::Added { index: parent_bounty_id, child_index: child_bounty_id });
 	}
 
+	/// Whether `who` acts as parent curator of a child-bounty, i.e. is the curator of the parent
+	/// bounty `parent_curator` or, for a sub-child-bounty, the curator of the child-bounty it was
+	/// carved out of.
+	fn is_parent_curator(
+		parent_bounty_id: BountyIndex,
+		child_bounty_id: BountyIndex,
+		who: &T::AccountId,
+		parent_curator: &T::AccountId,
+	) -> bool {
+		who == parent_curator ||
+			ChildBountyParents::<T>::get(parent_bounty_id, child_bounty_id)
+				.and_then(|parent_child_bounty_id| {
+					ChildBounties::<T>::get(parent_bounty_id, parent_child_bounty_id)
+				})
+				.map_or(false, |parent_child_bounty| {
+					matches!(
+						parent_child_bounty.status,
+						ChildBountyStatus::Active { ref curator } if curator == who
+					)
+				})
+	}
+
+	/// The funds of a child-bounty which are neither reserved for its curator fee nor its
+	/// pending partial payouts.
+	fn available_child_bounty_balance(
+		parent_bounty_id: BountyIndex,
+		child_bounty_id: BountyIndex,
+		child_bounty: &ChildBounty<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+	) -> BalanceOf<T> {
+		let pending = PartialPayouts::<T>::get(parent_bounty_id, child_bounty_id)
+			.iter()
+			.fold(BalanceOf::<T>::zero(), |pending, payout| pending.saturating_add(payout.amount));
+		T::Currency::free_balance(&Self::child_bounty_account_id(parent_bounty_id, child_bounty_id))
+			.saturating_sub(T::Currency::minimum_balance())
+			.saturating_sub(child_bounty.fee)
+			.saturating_sub(pending)
+	}
+
+	/// Remove a sub-child-bounty from the child-bounty it was carved out of, returning the index
+	/// of the latter. Returns `None` if the child-bounty is not a sub-child-bounty.
+	fn remove_sub_child_bounty(
+		parent_bounty_id: BountyIndex,
+		child_bounty_id: BountyIndex,
+	) -> Option<BountyIndex> {
+		let parent_child_bounty_id =
+			ChildBountyParents::<T>::take(parent_bounty_id, child_bounty_id)?;
+		SubChildBounties::<T>::mutate_exists(
+			parent_bounty_id,
+			parent_child_bounty_id,
+			|maybe_sub_child_bounties| {
+				if let Some(sub_child_bounties) = maybe_sub_child_bounties {
+					sub_child_bounties.retain(|id| *id != child_bounty_id);
+					if sub_child_bounties.is_empty() {
+						*maybe_sub_child_bounties = None;
+					}
+				}
+			},
+		);
+		Some(parent_child_bounty_id)
+	}
+
+	/// The weight of closing a child-bounty along with `sub_child_bounties` sub-child-bounties.
+	fn close_child_bounty_weight(sub_child_bounties: u32) -> Weight {
+		let close = <T as Config>::WeightInfo::close_child_bounty_added()
+			.max(<T as Config>::WeightInfo::close_child_bounty_active());
+		close.saturating_add(close.saturating_mul(sub_child_bounties.into()))
+	}
+
 	fn ensure_bounty_active(
 		bounty_id: BountyIndex,
 	) -> Result<(T::AccountId, BlockNumberFor<T>), DispatchError> {
@@ -890,6 +1367,17 @@ impl<T: Config> Pallet<T> {
 		parent_bounty_id: BountyIndex,
 		child_bounty_id: BountyIndex,
 	) -> DispatchResult {
+		// Partial payouts were awarded already, and must be claimed first.
+		ensure!(
+			!PartialPayouts::<T>::contains_key(parent_bounty_id, child_bounty_id),
+			Error::<T>::PartialPayoutsPending
+		);
+
+		// Close the sub-child-bounties first, returning their funds to this child-bounty.
+		for sub_child_bounty_id in SubChildBounties::<T>::get(parent_bounty_id, child_bounty_id) {
+			Self::impl_close_child_bounty(parent_bounty_id, sub_child_bounty_id)?;
+		}
+
 		ChildBounties::<T>::try_mutate_exists(
 			parent_bounty_id,
 			child_bounty_id,
@@ -917,24 +1405,42 @@ impl<T: Config> Pallet<T> {
 					},
 				}
 
-				// Revert the curator fee back to parent bounty curator &
+				// Revert the curator fee back to the parent curator &
 				// reduce the active child-bounty count.
-				ChildrenCuratorFees::<T>::mutate(parent_bounty_id, |value| {
-					*value = value.saturating_sub(child_bounty.fee)
-				});
+				let parent_account =
+					match Self::remove_sub_child_bounty(parent_bounty_id, child_bounty_id) {
+						Some(parent_child_bounty_id) => {
+							ChildBounties::<T>::mutate(
+								parent_bounty_id,
+								parent_child_bounty_id,
+								|maybe_parent_child_bounty| {
+									if let Some(parent_child_bounty) = maybe_parent_child_bounty {
+										parent_child_bounty.fee = parent_child_bounty
+											.fee
+											.saturating_add(child_bounty.fee);
+									}
+								},
+							);
+							Self::child_bounty_account_id(parent_bounty_id, parent_child_bounty_id)
+						},
+						None => {
+							ChildrenCuratorFees::<T>::mutate(parent_bounty_id, |value| {
+								*value = value.saturating_sub(child_bounty.fee)
+							});
+							pallet_bounties::Pallet::<T>::bounty_account_id(parent_bounty_id)
+						},
+					};
 				ParentChildBounties::<T>::mutate(parent_bounty_id, |count| {
 					*count = count.saturating_sub(1)
 				});
 
-				// Transfer fund from child-bounty to parent bounty.
-				let parent_bounty_account =
-					pallet_bounties::Pallet::<T>::bounty_account_id(parent_bounty_id);
+				// Transfer fund from child-bounty to its parent.
 				let child_bounty_account =
 					Self::child_bounty_account_id(parent_bounty_id, child_bounty_id);
 				let balance = T::Currency::free_balance(&child_bounty_account);
 				let transfer_result = T::Currency::transfer(
 					&child_bounty_account,
-					&parent_bounty_account,
+					&parent_account,
 					balance,
 					AllowDeath,
 				); // Should not fail; child bounty account gets this balance during creation.
@@ -110,6 +110,8 @@ impl pallet_treasury::Config for Test {
 	type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
 	type BalanceConverter = UnityAssetBalanceConversion;
 	type PayoutPeriod = ConstU64<10>;
+	type MilestoneOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxMilestones = ConstU32<16>;
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -140,6 +142,8 @@ impl pallet_child_bounties::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxActiveChildBountyCount = ConstU32<2>;
 	type ChildBountyValueMinimum = ConstU64<1>;
+	type MaxSubChildBountyCount = ConstU32<2>;
+	type MaxPartialPayouts = ConstU32<2>;
 	type WeightInfo = ();
 }
 
@@ -1603,6 +1607,340 @@ fn accept_curator_handles_different_deposit_calculations() {
 	});
 }
 
+#[test]
+fn sub_child_bounty_hierarchy_works() {
+	new_test_ext().execute_with(|| {
+		// TestProcedure
+		// 1, Create bounty & move to active state with enough bounty fund & parent curator.
+		// 2, Parent curator adds child-bounty child-bounty-1, moves to "Active" state.
+		// 3, Child-bounty curator adds sub-child-bounty, test for errors.
+		// 4, Child-bounty curator assigns the sub-child-bounty curator with part of its fee.
+		// 5, Parent curator closes child-bounty-1, which closes the sub-child-bounty as well.
+		// 6, Test for DB state & fund transfers back to the parent bounty.
+
+		// Make the parent bounty.
+		go_to_block(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&account_id(4), 101);
+		Balances::make_free_balance_be(&account_id(8), 101);
+		Balances::make_free_balance_be(&account_id(9), 101);
+
+		assert_ok!(Bounties::propose_bounty(
+			RuntimeOrigin::signed(account_id(0)),
+			50,
+			b"12345".to_vec()
+		));
+
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		go_to_block(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, account_id(4), 4));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(account_id(4)), 0));
+
+		// Add child-bounty & assign its curator.
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(account_id(4)),
+			0,
+			10,
+			b"12345-p1".to_vec()
+		));
+		assert_ok!(ChildBounties::propose_curator(
+			RuntimeOrigin::signed(account_id(4)),
+			0,
+			0,
+			account_id(8),
+			6
+		));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(account_id(8)), 0, 0));
+
+		// Only the child-bounty curator can add sub-child-bounties.
+		assert_noop!(
+			ChildBounties::add_sub_child_bounty(
+				RuntimeOrigin::signed(account_id(4)),
+				0,
+				0,
+				3,
+				b"12345-p1-s1".to_vec()
+			),
+			BountiesError::RequireCurator,
+		);
+
+		// Child-bounty curator fee & existential deposit are kept in the child-bounty.
+		assert_noop!(
+			ChildBounties::add_sub_child_bounty(
+				RuntimeOrigin::signed(account_id(8)),
+				0,
+				0,
+				4,
+				b"12345-p1-s1".to_vec()
+			),
+			Error::<Test>::InsufficientBountyBalance,
+		);
+
+		assert_ok!(ChildBounties::add_sub_child_bounty(
+			RuntimeOrigin::signed(account_id(8)),
+			0,
+			0,
+			3,
+			b"12345-p1-s1".to_vec()
+		));
+
+		assert_eq!(
+			last_event(),
+			ChildBountiesEvent::SubChildBountyAdded {
+				index: 0,
+				parent_child_index: 0,
+				child_index: 1
+			}
+		);
+
+		// DB check.
+		assert_eq!(pallet_child_bounties::SubChildBounties::<Test>::get(0, 0).to_vec(), vec![1]);
+		assert_eq!(pallet_child_bounties::ChildBountyParents::<Test>::get(0, 1), Some(0));
+		assert_eq!(pallet_child_bounties::ParentChildBounties::<Test>::get(0), 2);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(0, 0)), 7);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(0, 1)), 3);
+
+		// Sub-child-bounties cannot have sub-child-bounties of their own.
+		assert_noop!(
+			ChildBounties::add_sub_child_bounty(
+				RuntimeOrigin::signed(account_id(8)),
+				0,
+				1,
+				1,
+				b"12345-p1-s1-s1".to_vec()
+			),
+			Error::<Test>::TooDeep,
+		);
+
+		// The child-bounty curator acts as the parent curator of the sub-child-bounty, whose
+		// curator fee is taken from its own.
+		assert_noop!(
+			ChildBounties::propose_curator(
+				RuntimeOrigin::signed(account_id(9)),
+				0,
+				1,
+				account_id(9),
+				2
+			),
+			BountiesError::RequireCurator,
+		);
+		assert_ok!(ChildBounties::propose_curator(
+			RuntimeOrigin::signed(account_id(8)),
+			0,
+			1,
+			account_id(9),
+			2
+		));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(account_id(9)), 0, 1));
+
+		assert_eq!(pallet_child_bounties::ChildBounties::<Test>::get(0, 0).unwrap().fee, 4);
+		assert_eq!(pallet_child_bounties::ChildrenCuratorFees::<Test>::get(0), 6);
+		assert_eq!(Balances::reserved_balance(account_id(9)), CuratorDepositMin::get());
+
+		// The child-bounty cannot be awarded while it has sub-child-bounties.
+		assert_noop!(
+			ChildBounties::award_child_bounty(
+				RuntimeOrigin::signed(account_id(8)),
+				0,
+				0,
+				account_id(7)
+			),
+			BountiesError::HasActiveChildBounty,
+		);
+
+		// Closing the child-bounty closes the sub-child-bounty first.
+		assert_ok!(ChildBounties::close_child_bounty(RuntimeOrigin::signed(account_id(4)), 0, 0));
+
+		// Sub-child-bounty curator deposit is refunded.
+		assert_eq!(Balances::free_balance(account_id(9)), 101);
+		assert_eq!(Balances::reserved_balance(account_id(9)), 0);
+
+		// All funds are back to the parent bounty.
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 50);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(0, 0)), 0);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(0, 1)), 0);
+
+		// DB check.
+		assert_eq!(pallet_child_bounties::ChildrenCuratorFees::<Test>::get(0), 0);
+		assert_eq!(pallet_child_bounties::ParentChildBounties::<Test>::get(0), 0);
+		assert!(!pallet_child_bounties::SubChildBounties::<Test>::contains_key(0, 0));
+		assert!(!pallet_child_bounties::ChildBountyParents::<Test>::contains_key(0, 1));
+		assert!(!pallet_child_bounties::ChildBounties::<Test>::contains_key(0, 1));
+	});
+}
+
+#[test]
+fn partial_payouts_work() {
+	new_test_ext().execute_with(|| {
+		// TestProcedure
+		// 1, Create bounty & move to active state with enough bounty fund & parent curator.
+		// 2, Parent curator adds child-bounty child-bounty-1, moves to "Active" state.
+		// 3, Child-bounty curator awards partial payouts, test for errors.
+		// 4, Beneficiaries claim the partial payouts after the payout delay.
+		// 5, Test for DB state & fund transfers.
+
+		// Make the parent bounty.
+		go_to_block(1);
+		Balances::make_free_balance_be(&Treasury::account_id(), 101);
+		Balances::make_free_balance_be(&account_id(4), 101);
+		Balances::make_free_balance_be(&account_id(8), 101);
+
+		assert_ok!(Bounties::propose_bounty(
+			RuntimeOrigin::signed(account_id(0)),
+			50,
+			b"12345".to_vec()
+		));
+
+		assert_ok!(Bounties::approve_bounty(RuntimeOrigin::root(), 0));
+
+		go_to_block(2);
+
+		assert_ok!(Bounties::propose_curator(RuntimeOrigin::root(), 0, account_id(4), 4));
+		assert_ok!(Bounties::accept_curator(RuntimeOrigin::signed(account_id(4)), 0));
+
+		// Add child-bounty & assign its curator.
+		assert_ok!(ChildBounties::add_child_bounty(
+			RuntimeOrigin::signed(account_id(4)),
+			0,
+			10,
+			b"12345-p1".to_vec()
+		));
+
+		// Child-bounty must be active.
+		assert_noop!(
+			ChildBounties::award_partial_payout(
+				RuntimeOrigin::signed(account_id(4)),
+				0,
+				0,
+				account_id(7),
+				2
+			),
+			BountiesError::UnexpectedStatus,
+		);
+
+		assert_ok!(ChildBounties::propose_curator(
+			RuntimeOrigin::signed(account_id(4)),
+			0,
+			0,
+			account_id(8),
+			4
+		));
+		assert_ok!(ChildBounties::accept_curator(RuntimeOrigin::signed(account_id(8)), 0, 0));
+
+		assert_noop!(
+			ChildBounties::award_partial_payout(
+				RuntimeOrigin::signed(account_id(7)),
+				0,
+				0,
+				account_id(7),
+				2
+			),
+			BountiesError::RequireCurator,
+		);
+
+		// Both the child-bounty curator and the parent curator can award partial payouts.
+		assert_ok!(ChildBounties::award_partial_payout(
+			RuntimeOrigin::signed(account_id(8)),
+			0,
+			0,
+			account_id(7),
+			2
+		));
+		assert_eq!(
+			last_event(),
+			ChildBountiesEvent::PartialPayoutAwarded {
+				index: 0,
+				child_index: 0,
+				beneficiary: account_id(7),
+				amount: 2
+			}
+		);
+
+		go_to_block(3);
+
+		// Child-bounty curator fee, existential deposit & pending partial payouts are kept.
+		assert_noop!(
+			ChildBounties::award_partial_payout(
+				RuntimeOrigin::signed(account_id(4)),
+				0,
+				0,
+				account_id(6),
+				4
+			),
+			Error::<Test>::InsufficientBountyBalance,
+		);
+		assert_ok!(ChildBounties::award_partial_payout(
+			RuntimeOrigin::signed(account_id(4)),
+			0,
+			0,
+			account_id(6),
+			3
+		));
+
+		// Number of pending partial payouts is limited.
+		assert_noop!(
+			ChildBounties::award_partial_payout(
+				RuntimeOrigin::signed(account_id(8)),
+				0,
+				0,
+				account_id(5),
+				0
+			),
+			Error::<Test>::TooManyPartialPayouts,
+		);
+
+		// The child-bounty cannot be claimed nor closed while partial payouts are pending.
+		assert_noop!(
+			ChildBounties::close_child_bounty(RuntimeOrigin::signed(account_id(4)), 0, 0),
+			Error::<Test>::PartialPayoutsPending,
+		);
+
+		// Partial payouts can only be claimed after the payout delay.
+		assert_noop!(
+			ChildBounties::claim_partial_payouts(RuntimeOrigin::signed(account_id(0)), 0, 0),
+			BountiesError::Premature,
+		);
+
+		go_to_block(5);
+
+		assert_ok!(ChildBounties::claim_partial_payouts(
+			RuntimeOrigin::signed(account_id(0)),
+			0,
+			0
+		));
+		assert_eq!(
+			last_event(),
+			ChildBountiesEvent::PartialPayoutClaimed {
+				index: 0,
+				child_index: 0,
+				payout: 2,
+				beneficiary: account_id(7)
+			}
+		);
+		assert_eq!(Balances::free_balance(account_id(7)), 2);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(0, 0)), 8);
+		assert_eq!(pallet_child_bounties::PartialPayouts::<Test>::get(0, 0).len(), 1);
+
+		go_to_block(6);
+
+		assert_ok!(ChildBounties::claim_partial_payouts(
+			RuntimeOrigin::signed(account_id(0)),
+			0,
+			0
+		));
+		assert_eq!(Balances::free_balance(account_id(6)), 3);
+		assert_eq!(Balances::free_balance(ChildBounties::child_bounty_account_id(0, 0)), 5);
+		assert!(!pallet_child_bounties::PartialPayouts::<Test>::contains_key(0, 0));
+
+		// Once all partial payouts are claimed, the child-bounty can be closed, returning the
+		// remaining funds to the parent bounty.
+		assert_ok!(ChildBounties::close_child_bounty(RuntimeOrigin::signed(account_id(4)), 0, 0));
+		assert_eq!(Balances::free_balance(Bounties::bounty_account_id(0)), 45);
+	});
+}
+
 #[test]
 fn integrity_test() {
 	new_test_ext().execute_with(|| {
@@ -22,6 +22,12 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-wiukf8gn-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `propose_curator`, `award_child_bounty`, `claim_child_bounty`,
+//! `close_child_bounty_added`, `close_child_bounty_active`, `add_sub_child_bounty`,
+//! `award_partial_payout` and `claim_partial_payouts`. Re-run the benchmarks before relying on
+//! them.
 
 // Executed Command:
 // ./target/production/substrate-node
@@ -59,6 +65,9 @@ pub trait WeightInfo {
 	fn claim_child_bounty() -> Weight;
 	fn close_child_bounty_added() -> Weight;
 	fn close_child_bounty_active() -> Weight;
+	fn add_sub_child_bounty(d: u32, ) -> Weight;
+	fn award_partial_payout() -> Weight;
+	fn claim_partial_payouts(p: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_child_bounties` using the Substrate node and recommended hardware.
@@ -94,13 +103,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChildBounties::ChildBounties` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
 	/// Storage: `ChildBounties::ChildrenCuratorFees` (r:1 w:1)
 	/// Proof: `ChildBounties::ChildrenCuratorFees` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBountyParents` (r:1 w:0)
+	/// Proof: `ChildBounties::ChildBountyParents` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	fn propose_curator() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `842`
 		//  Estimated: `3642`
 		// Minimum execution time: 24_835_000 picoseconds.
 		Weight::from_parts(26_049_000, 3642)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Bounties::Bounties` (r:1 w:0)
@@ -137,13 +148,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
 	/// Storage: `ChildBounties::ChildBounties` (r:1 w:1)
 	/// Proof: `ChildBounties::ChildBounties` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::SubChildBounties` (r:1 w:0)
+	/// Proof: `ChildBounties::SubChildBounties` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
 	fn award_child_bounty() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `908`
 		//  Estimated: `3642`
 		// Minimum execution time: 26_462_000 picoseconds.
 		Weight::from_parts(27_166_000, 3642)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `ChildBounties::ChildBounties` (r:1 w:1)
@@ -154,14 +167,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChildBounties::ParentChildBounties` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
 	/// Storage: `ChildBounties::ChildBountyDescriptionsV1` (r:0 w:1)
 	/// Proof: `ChildBounties::ChildBountyDescriptionsV1` (`max_values`: None, `max_size`: Some(326), added: 2801, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::PartialPayouts` (r:1 w:0)
+	/// Proof: `ChildBounties::PartialPayouts` (`max_values`: None, `max_size`: Some(857), added: 3332, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBountyParents` (r:1 w:1)
+	/// Proof: `ChildBounties::ChildBountyParents` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	fn claim_child_bounty() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `752`
 		//  Estimated: `8799`
 		// Minimum execution time: 110_207_000 picoseconds.
 		Weight::from_parts(111_918_000, 8799)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Bounties::Bounties` (r:1 w:0)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
@@ -175,14 +192,20 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ChildBounties::ChildBountyDescriptionsV1` (r:0 w:1)
 	/// Proof: `ChildBounties::ChildBountyDescriptionsV1` (`max_values`: None, `max_size`: Some(326), added: 2801, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::PartialPayouts` (r:1 w:0)
+	/// Proof: `ChildBounties::PartialPayouts` (`max_values`: None, `max_size`: Some(857), added: 3332, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::SubChildBounties` (r:1 w:0)
+	/// Proof: `ChildBounties::SubChildBounties` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBountyParents` (r:1 w:1)
+	/// Proof: `ChildBounties::ChildBountyParents` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	fn close_child_bounty_added() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1122`
 		//  Estimated: `6196`
 		// Minimum execution time: 78_217_000 picoseconds.
 		Weight::from_parts(79_799_000, 6196)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Bounties::Bounties` (r:1 w:0)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
@@ -196,14 +219,86 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChildBounties::ParentChildBounties` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
 	/// Storage: `ChildBounties::ChildBountyDescriptionsV1` (r:0 w:1)
 	/// Proof: `ChildBounties::ChildBountyDescriptionsV1` (`max_values`: None, `max_size`: Some(326), added: 2801, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::PartialPayouts` (r:1 w:0)
+	/// Proof: `ChildBounties::PartialPayouts` (`max_values`: None, `max_size`: Some(857), added: 3332, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::SubChildBounties` (r:1 w:0)
+	/// Proof: `ChildBounties::SubChildBounties` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBountyParents` (r:1 w:1)
+	/// Proof: `ChildBounties::ChildBountyParents` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	fn close_child_bounty_active() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1343`
 		//  Estimated: `8799`
 		// Minimum execution time: 93_624_000 picoseconds.
 		Weight::from_parts(96_697_000, 8799)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `ChildBounties::ParentChildBounties` (r:1 w:1)
+	/// Proof: `ChildBounties::ParentChildBounties` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBountyParents` (r:1 w:1)
+	/// Proof: `ChildBounties::ChildBountyParents` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::Bounties` (r:1 w:0)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBounties` (r:1 w:1)
+	/// Proof: `ChildBounties::ChildBounties` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::PartialPayouts` (r:1 w:0)
+	/// Proof: `ChildBounties::PartialPayouts` (`max_values`: None, `max_size`: Some(857), added: 3332, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::SubChildBounties` (r:1 w:1)
+	/// Proof: `ChildBounties::SubChildBounties` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ParentTotalChildBounties` (r:1 w:1)
+	/// Proof: `ChildBounties::ParentTotalChildBounties` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBountyDescriptionsV1` (r:0 w:1)
+	/// Proof: `ChildBounties::ChildBountyDescriptionsV1` (`max_values`: None, `max_size`: Some(326), added: 2801, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[0, 300]`.
+	fn add_sub_child_bounty(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1291`
+		//  Estimated: `6196`
+		// Minimum execution time: 82_934_000 picoseconds.
+		Weight::from_parts(85_102_317, 6196)
+			// Standard Error: 341
+			.saturating_add(Weight::from_parts(1_493, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:0)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBounties` (r:1 w:0)
+	/// Proof: `ChildBounties::ChildBounties` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::PartialPayouts` (r:1 w:1)
+	/// Proof: `ChildBounties::PartialPayouts` (`max_values`: None, `max_size`: Some(857), added: 3332, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn award_partial_payout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187`
+		//  Estimated: `4322`
+		// Minimum execution time: 31_207_000 picoseconds.
+		Weight::from_parts(32_418_000, 4322)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChildBounties::PartialPayouts` (r:1 w:1)
+	/// Proof: `ChildBounties::PartialPayouts` (`max_values`: None, `max_size`: Some(857), added: 3332, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:17 w:17)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 16]`.
+	fn claim_partial_payouts(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1018`
+		//  Estimated: `4322`
+		// Minimum execution time: 38_551_000 picoseconds.
+		Weight::from_parts(4_186_742, 4322)
+			// Standard Error: 27_416
+			.saturating_add(Weight::from_parts(36_128_522, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(p.into()))
 	}
 }
 
@@ -239,13 +334,15 @@ impl WeightInfo for () {
 	/// Proof: `ChildBounties::ChildBounties` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
 	/// Storage: `ChildBounties::ChildrenCuratorFees` (r:1 w:1)
 	/// Proof: `ChildBounties::ChildrenCuratorFees` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBountyParents` (r:1 w:0)
+	/// Proof: `ChildBounties::ChildBountyParents` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	fn propose_curator() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `842`
 		//  Estimated: `3642`
 		// Minimum execution time: 24_835_000 picoseconds.
 		Weight::from_parts(26_049_000, 3642)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Bounties::Bounties` (r:1 w:0)
@@ -282,13 +379,15 @@ impl WeightInfo for () {
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
 	/// Storage: `ChildBounties::ChildBounties` (r:1 w:1)
 	/// Proof: `ChildBounties::ChildBounties` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::SubChildBounties` (r:1 w:0)
+	/// Proof: `ChildBounties::SubChildBounties` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
 	fn award_child_bounty() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `908`
 		//  Estimated: `3642`
 		// Minimum execution time: 26_462_000 picoseconds.
 		Weight::from_parts(27_166_000, 3642)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `ChildBounties::ChildBounties` (r:1 w:1)
@@ -299,14 +398,18 @@ impl WeightInfo for () {
 	/// Proof: `ChildBounties::ParentChildBounties` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
 	/// Storage: `ChildBounties::ChildBountyDescriptionsV1` (r:0 w:1)
 	/// Proof: `ChildBounties::ChildBountyDescriptionsV1` (`max_values`: None, `max_size`: Some(326), added: 2801, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::PartialPayouts` (r:1 w:0)
+	/// Proof: `ChildBounties::PartialPayouts` (`max_values`: None, `max_size`: Some(857), added: 3332, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBountyParents` (r:1 w:1)
+	/// Proof: `ChildBounties::ChildBountyParents` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	fn claim_child_bounty() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `752`
 		//  Estimated: `8799`
 		// Minimum execution time: 110_207_000 picoseconds.
 		Weight::from_parts(111_918_000, 8799)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Bounties::Bounties` (r:1 w:0)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
@@ -320,14 +423,20 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `ChildBounties::ChildBountyDescriptionsV1` (r:0 w:1)
 	/// Proof: `ChildBounties::ChildBountyDescriptionsV1` (`max_values`: None, `max_size`: Some(326), added: 2801, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::PartialPayouts` (r:1 w:0)
+	/// Proof: `ChildBounties::PartialPayouts` (`max_values`: None, `max_size`: Some(857), added: 3332, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::SubChildBounties` (r:1 w:0)
+	/// Proof: `ChildBounties::SubChildBounties` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBountyParents` (r:1 w:1)
+	/// Proof: `ChildBounties::ChildBountyParents` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	fn close_child_bounty_added() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1122`
 		//  Estimated: `6196`
 		// Minimum execution time: 78_217_000 picoseconds.
 		Weight::from_parts(79_799_000, 6196)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Bounties::Bounties` (r:1 w:0)
 	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
@@ -341,13 +450,85 @@ impl WeightInfo for () {
 	/// Proof: `ChildBounties::ParentChildBounties` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
 	/// Storage: `ChildBounties::ChildBountyDescriptionsV1` (r:0 w:1)
 	/// Proof: `ChildBounties::ChildBountyDescriptionsV1` (`max_values`: None, `max_size`: Some(326), added: 2801, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::PartialPayouts` (r:1 w:0)
+	/// Proof: `ChildBounties::PartialPayouts` (`max_values`: None, `max_size`: Some(857), added: 3332, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::SubChildBounties` (r:1 w:0)
+	/// Proof: `ChildBounties::SubChildBounties` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBountyParents` (r:1 w:1)
+	/// Proof: `ChildBounties::ChildBountyParents` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
 	fn close_child_bounty_active() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1343`
 		//  Estimated: `8799`
 		// Minimum execution time: 93_624_000 picoseconds.
 		Weight::from_parts(96_697_000, 8799)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `ChildBounties::ParentChildBounties` (r:1 w:1)
+	/// Proof: `ChildBounties::ParentChildBounties` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBountyParents` (r:1 w:1)
+	/// Proof: `ChildBounties::ChildBountyParents` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `Bounties::Bounties` (r:1 w:0)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBounties` (r:1 w:1)
+	/// Proof: `ChildBounties::ChildBounties` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::PartialPayouts` (r:1 w:0)
+	/// Proof: `ChildBounties::PartialPayouts` (`max_values`: None, `max_size`: Some(857), added: 3332, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::SubChildBounties` (r:1 w:1)
+	/// Proof: `ChildBounties::SubChildBounties` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ParentTotalChildBounties` (r:1 w:1)
+	/// Proof: `ChildBounties::ParentTotalChildBounties` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBountyDescriptionsV1` (r:0 w:1)
+	/// Proof: `ChildBounties::ChildBountyDescriptionsV1` (`max_values`: None, `max_size`: Some(326), added: 2801, mode: `MaxEncodedLen`)
+	/// The range of component `d` is `[0, 300]`.
+	fn add_sub_child_bounty(d: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1291`
+		//  Estimated: `6196`
+		// Minimum execution time: 82_934_000 picoseconds.
+		Weight::from_parts(85_102_317, 6196)
+			// Standard Error: 341
+			.saturating_add(Weight::from_parts(1_493, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Bounties::Bounties` (r:1 w:0)
+	/// Proof: `Bounties::Bounties` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::ChildBounties` (r:1 w:0)
+	/// Proof: `ChildBounties::ChildBounties` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	/// Storage: `ChildBounties::PartialPayouts` (r:1 w:1)
+	/// Proof: `ChildBounties::PartialPayouts` (`max_values`: None, `max_size`: Some(857), added: 3332, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn award_partial_payout() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187`
+		//  Estimated: `4322`
+		// Minimum execution time: 31_207_000 picoseconds.
+		Weight::from_parts(32_418_000, 4322)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChildBounties::PartialPayouts` (r:1 w:1)
+	/// Proof: `ChildBounties::PartialPayouts` (`max_values`: None, `max_size`: Some(857), added: 3332, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:17 w:17)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 16]`.
+	fn claim_partial_payouts(p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1018`
+		//  Estimated: `4322`
+		// Minimum execution time: 38_551_000 picoseconds.
+		Weight::from_parts(4_186_742, 4322)
+			// Standard Error: 27_416
+			.saturating_add(Weight::from_parts(36_128_522, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(p.into()))
 	}
 }
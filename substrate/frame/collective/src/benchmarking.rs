@@ -269,6 +269,69 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn propose_with_dependency(
+		b: Linear<2, MAX_BYTES>,
+		m: Linear<2, { T::MaxMembers::get() }>,
+		p: Linear<2, { T::MaxProposals::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let bytes_in_storage = b + size_of::<u32>() as u32;
+
+		// Construct `members`.
+		let mut members = vec![];
+		for i in 0..m - 1 {
+			let member = account::<T::AccountId>("member", i, SEED);
+			members.push(member);
+		}
+		let caller: T::AccountId = whitelisted_caller();
+		members.push(caller.clone());
+		Collective::<T, I>::set_members(
+			SystemOrigin::Root.into(),
+			members,
+			None,
+			T::MaxMembers::get(),
+		)?;
+
+		let threshold = m.max(2);
+		// Add previous proposals, the first of which is the dependency.
+		for i in 0..p - 1 {
+			T::Consideration::ensure_successful(&caller, i);
+			// Proposals should be different so that different proposal hashes are generated
+			let proposal: T::Proposal =
+				SystemCall::<T>::remark { remark: id_to_remark_data(i, b as usize) }.into();
+			Collective::<T, I>::propose(
+				SystemOrigin::Signed(caller.clone()).into(),
+				threshold,
+				Box::new(proposal),
+				bytes_in_storage,
+			)?;
+		}
+		let dependency = Proposals::<T, I>::get()[0];
+
+		T::Consideration::ensure_successful(&caller, p);
+
+		let proposal: T::Proposal =
+			SystemCall::<T>::remark { remark: id_to_remark_data(p, b as usize) }.into();
+		#[extrinsic_call]
+		_(
+			SystemOrigin::Signed(caller.clone()),
+			threshold,
+			Box::new(proposal.clone()),
+			bytes_in_storage,
+			dependency,
+			0,
+		);
+
+		// New proposal is recorded along with its dependency.
+		assert_eq!(Proposals::<T, I>::get().len(), p as usize);
+		let proposal_hash = T::Hashing::hash_of(&proposal);
+		assert_eq!(DependencyOf::<T, I>::get(proposal_hash), Some((dependency, 0)));
+		assert_last_event::<T, I>(
+			Event::DependencyDeclared { proposal_hash, dependency, dependency_index: 0 }.into(),
+		);
+		Ok(())
+	}
+
 	#[benchmark]
 	// We choose 5 as a minimum so we always trigger a vote in the voting loop (`for j in ...`)
 	fn vote(m: Linear<5, { T::MaxMembers::get() }>) -> Result<(), BenchmarkError> {
@@ -38,6 +38,10 @@
 //! abstentions and the proposal is executed if there are enough approvals counting the new votes.
 //!
 //! If there are not, or if no prime is set, then the motion is dropped without being executed.
+//!
+//! A motion may be proposed with `propose_with_dependency` to depend on another active motion. It
+//! can then only be approved and executed once its dependency has been, and is disapproved when
+//! closed if its dependency was dropped without being executed instead.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -438,6 +442,21 @@ pub mod pallet {
 	pub type CostOf<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Identity, T::Hash, (T::AccountId, T::Consideration), OptionQuery>;
 
+	/// The motion that a given proposal depends on, by hash and index, if it has not been
+	/// approved yet.
+	///
+	/// Remains in place once the dependency has been dropped without being approved, which
+	/// prevents the dependent proposal from ever being approved, even if a motion with the same
+	/// hash is proposed again.
+	#[pallet::storage]
+	pub type DependencyOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, T::Hash, (T::Hash, ProposalIndex), OptionQuery>;
+
+	/// The proposals that depend on a given active motion.
+	#[pallet::storage]
+	pub type Dependents<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, T::Hash, BoundedVec<T::Hash, T::MaxProposals>, ValueQuery>;
+
 	/// Votes on a given proposal, if it is ongoing.
 	#[pallet::storage]
 	pub type Voting<T: Config<I>, I: 'static = ()> =
@@ -492,6 +511,13 @@ pub mod pallet {
 		ProposalCostBurned { proposal_hash: T::Hash, who: T::AccountId },
 		/// Some cost for storing a proposal was released.
 		ProposalCostReleased { proposal_hash: T::Hash, who: T::AccountId },
+		/// A motion (given hash) can only be executed after another motion (given hash and index)
+		/// has been.
+		DependencyDeclared {
+			proposal_hash: T::Hash,
+			dependency: T::Hash,
+			dependency_index: ProposalIndex,
+		},
 	}
 
 	#[pallet::error]
@@ -520,6 +546,12 @@ pub mod pallet {
 		PrimeAccountNotMember,
 		/// Proposal is still active.
 		ProposalActive,
+		/// The motion a proposal depends on does not exist.
+		DependencyMissing,
+		/// The motion a proposal depends on has not been approved yet.
+		DependencyNotMet,
+		/// A motion with a dependency must have a threshold of at least 2.
+		ThresholdTooLow,
 	}
 
 	#[pallet::hooks]
@@ -887,6 +919,68 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Add a new motion to be voted on, which may only be executed after the motion
+		/// `dependency` has been.
+		///
+		/// Requires the sender to be member. `dependency` must be an active motion with the index
+		/// `dependency_index`, and `threshold` must be at least 2 since the motion cannot be
+		/// executed directly.
+		///
+		/// If `dependency` is dropped without being executed, the motion is disapproved when
+		/// closed.
+		///
+		/// ## Complexity
+		/// - `O(B + M + P)` where:
+		///   - `B` is `proposal` size in bytes (length-fee-bounded)
+		///   - `M` is members-count (code- and governance-bounded)
+		///   - `P` is proposals-count (code-bounded)
+		#[pallet::call_index(9)]
+		#[pallet::weight((
+			T::WeightInfo::propose_with_dependency(
+				*length_bound, // B
+				T::MaxMembers::get(), // M
+				T::MaxProposals::get(), // P
+			),
+			DispatchClass::Operational
+		))]
+		pub fn propose_with_dependency(
+			origin: OriginFor<T>,
+			#[pallet::compact] threshold: MemberCount,
+			proposal: Box<<T as Config<I>>::Proposal>,
+			#[pallet::compact] length_bound: u32,
+			dependency: T::Hash,
+			#[pallet::compact] dependency_index: ProposalIndex,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let members = Members::<T, I>::get();
+			ensure!(members.contains(&who), Error::<T, I>::NotMember);
+			ensure!(threshold >= 2, Error::<T, I>::ThresholdTooLow);
+			let voting = Voting::<T, I>::get(dependency).ok_or(Error::<T, I>::DependencyMissing)?;
+			ensure!(voting.index == dependency_index, Error::<T, I>::WrongIndex);
+
+			let proposal_hash = T::Hashing::hash_of(&proposal);
+			let (proposal_len, active_proposals) =
+				Self::do_propose_proposed(who, threshold, proposal, length_bound)?;
+
+			Dependents::<T, I>::try_mutate(dependency, |dependents| {
+				dependents.try_push(proposal_hash)
+			})
+			.map_err(|_| Error::<T, I>::TooManyProposals)?;
+			DependencyOf::<T, I>::insert(proposal_hash, (dependency, dependency_index));
+			Self::deposit_event(Event::DependencyDeclared {
+				proposal_hash,
+				dependency,
+				dependency_index,
+			});
+
+			Ok(Some(T::WeightInfo::propose_with_dependency(
+				proposal_len as u32,  // B
+				members.len() as u32, // M
+				active_proposals,     // P
+			))
+			.into())
+		}
 	}
 }
 
@@ -1050,6 +1144,15 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let disapproved = seats.saturating_sub(no_votes) < voting.threshold;
 		// Allow (dis-)approving the proposal as soon as there are enough votes.
 		if approved {
+			if !Self::dependency_met(&proposal_hash)? {
+				Self::deposit_event(Event::Closed { proposal_hash, yes: yes_votes, no: no_votes });
+				let proposal_count = Self::do_disapprove_proposal(proposal_hash);
+				return Ok((
+					Some(T::WeightInfo::close_early_disapproved(seats, proposal_count)),
+					Pays::No,
+				)
+					.into())
+			}
 			let (proposal, len) = Self::validate_and_get_proposal(
 				&proposal_hash,
 				length_bound,
@@ -1089,7 +1192,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			true => yes_votes += abstentions,
 			false => no_votes += abstentions,
 		}
-		let approved = yes_votes >= voting.threshold;
+		let approved = yes_votes >= voting.threshold && Self::dependency_met(&proposal_hash)?;
 
 		if approved {
 			let (proposal, len) = Self::validate_and_get_proposal(
@@ -1167,10 +1270,33 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		// default to the dispatch info weight for safety
 		let proposal_weight = get_result_weight(result).unwrap_or(dispatch_weight); // P1
 
+		// the proposals depending on this one may now be approved.
+		for dependent in Dependents::<T, I>::take(proposal_hash) {
+			DependencyOf::<T, I>::remove(dependent);
+		}
+
 		let proposal_count = Self::remove_proposal(proposal_hash);
 		(proposal_weight, proposal_count)
 	}
 
+	/// Check whether the motion `proposal_hash` depends on, if any, has been approved.
+	///
+	/// Returns `false` if the dependency was dropped without being approved, and an error if it
+	/// is still being voted on. A motion with the same hash but another index is a new motion, so
+	/// the dependency was dropped.
+	fn dependency_met(proposal_hash: &T::Hash) -> Result<bool, DispatchError> {
+		match DependencyOf::<T, I>::get(proposal_hash) {
+			None => Ok(true),
+			Some((dependency, index)) => {
+				ensure!(
+					Voting::<T, I>::get(dependency).map_or(true, |voting| voting.index != index),
+					Error::<T, I>::DependencyNotMet
+				);
+				Ok(false)
+			},
+		}
+	}
+
 	/// Removes a proposal from the pallet, and deposit the `Disapproved` event.
 	pub fn do_disapprove_proposal(proposal_hash: T::Hash) -> u32 {
 		// disapproved
@@ -1183,6 +1309,18 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		// remove proposal and vote
 		ProposalOf::<T, I>::remove(&proposal_hash);
 		Voting::<T, I>::remove(&proposal_hash);
+		// the motions still depending on this one keep their dependency and can't be approved.
+		Dependents::<T, I>::remove(&proposal_hash);
+		if let Some((dependency, _)) = DependencyOf::<T, I>::take(&proposal_hash) {
+			Dependents::<T, I>::mutate_exists(dependency, |maybe_dependents| {
+				if let Some(dependents) = maybe_dependents {
+					dependents.retain(|h| h != &proposal_hash);
+					if dependents.is_empty() {
+						*maybe_dependents = None;
+					}
+				}
+			});
+		}
 		let num_proposals = Proposals::<T, I>::mutate(|proposals| {
 			proposals.retain(|h| h != &proposal_hash);
 			proposals.len() + 1 // calculate weight based on original length
@@ -379,6 +379,113 @@ fn close_works() {
 	});
 }
 
+#[test]
+fn motion_dependencies_work() {
+	ExtBuilder::default().build_and_execute(|| {
+		let proposals = (0..5u64).map(make_proposal).collect::<Vec<_>>();
+		let len = proposals[0].using_encoded(|p| p.len() as u32);
+		let weight = proposals[0].get_dispatch_info().call_weight;
+		let hashes = proposals.iter().map(BlakeTwo256::hash_of).collect::<Vec<_>>();
+		let propose = |i: usize, dependency: Option<usize>| match dependency {
+			Some(d) => Collective::propose_with_dependency(
+				RuntimeOrigin::signed(1),
+				2,
+				Box::new(proposals[i].clone()),
+				len,
+				hashes[d],
+				d as u32,
+			),
+			None => Collective::propose(
+				RuntimeOrigin::signed(1),
+				2,
+				Box::new(proposals[i].clone()),
+				len,
+			),
+		};
+		let vote = |i: usize, approve: bool| {
+			assert_ok!(Collective::vote(RuntimeOrigin::signed(1), hashes[i], i as u32, approve));
+			assert_ok!(Collective::vote(RuntimeOrigin::signed(2), hashes[i], i as u32, approve));
+		};
+		let close = |i: usize| {
+			Collective::close(RuntimeOrigin::signed(4), hashes[i], i as u32, weight, len)
+		};
+
+		// The dependency must be an active motion.
+		assert_noop!(propose(1, Some(0)), Error::<Test, Instance1>::DependencyMissing);
+		assert_ok!(propose(0, None));
+		// A dependent motion cannot be executed directly.
+		assert_noop!(
+			Collective::propose_with_dependency(
+				RuntimeOrigin::signed(1),
+				1,
+				Box::new(proposals[1].clone()),
+				len,
+				hashes[0],
+				0,
+			),
+			Error::<Test, Instance1>::ThresholdTooLow
+		);
+		// The dependency is bound to the index of the motion.
+		assert_noop!(
+			Collective::propose_with_dependency(
+				RuntimeOrigin::signed(1),
+				2,
+				Box::new(proposals[1].clone()),
+				len,
+				hashes[0],
+				1,
+			),
+			Error::<Test, Instance1>::WrongIndex
+		);
+		assert_ok!(propose(1, Some(0)));
+		assert_ok!(propose(2, Some(0)));
+		System::assert_last_event(RuntimeEvent::Collective(CollectiveEvent::DependencyDeclared {
+			proposal_hash: hashes[2],
+			dependency: hashes[0],
+			dependency_index: 0,
+		}));
+		assert_eq!(
+			Dependents::<Test, Instance1>::get(hashes[0]).to_vec(),
+			vec![hashes[1], hashes[2]]
+		);
+
+		// An approved motion cannot be executed before its dependency.
+		vote(1, true);
+		assert_noop!(close(1), Error::<Test, Instance1>::DependencyNotMet);
+
+		// Once the dependency is executed, so can the dependent motions be.
+		vote(0, true);
+		assert_ok!(close(0));
+		assert_eq!(DependencyOf::<Test, Instance1>::get(hashes[1]), None);
+		assert_eq!(DependencyOf::<Test, Instance1>::get(hashes[2]), None);
+		assert_ok!(close(1));
+		System::assert_last_event(RuntimeEvent::Collective(CollectiveEvent::Executed {
+			proposal_hash: hashes[1],
+			result: Ok(()),
+		}));
+
+		// A motion whose dependency is dropped is disapproved, even with enough approvals.
+		assert_ok!(propose(3, None));
+		assert_ok!(propose(4, Some(3)));
+		vote(3, false);
+		assert_ok!(close(3));
+		// Proposing the dropped dependency again does not revive the motions depending on it.
+		assert_ok!(propose(3, None));
+		assert_ok!(Collective::vote(RuntimeOrigin::signed(1), hashes[3], 5, true));
+		assert_ok!(Collective::vote(RuntimeOrigin::signed(2), hashes[3], 5, true));
+		assert_ok!(Collective::close(RuntimeOrigin::signed(4), hashes[3], 5, weight, len));
+		assert_eq!(DependencyOf::<Test, Instance1>::get(hashes[4]), Some((hashes[3], 3)));
+		vote(4, true);
+		assert_ok!(close(4));
+		System::assert_last_event(RuntimeEvent::Collective(CollectiveEvent::Disapproved {
+			proposal_hash: hashes[4],
+		}));
+		assert_eq!(DependencyOf::<Test, Instance1>::get(hashes[4]), None);
+		assert!(!Dependents::<Test, Instance1>::contains_key(hashes[3]));
+		assert_eq!(Proposals::<Test, Instance1>::get().to_vec(), vec![hashes[2]]);
+	});
+}
+
 #[test]
 fn proposal_weight_limit_works_on_approve() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -22,6 +22,11 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-wiukf8gn-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `propose_with_dependency`, `close_early_disapproved`, `close_early_approved`,
+//! `close_disapproved`, `close_approved`, `disapprove_proposal` and `kill`. Re-run the benchmarks
+//! before relying on them.
 
 // Executed Command:
 // ./target/production/substrate-node
@@ -55,6 +60,7 @@ pub trait WeightInfo {
 	fn execute(b: u32, m: u32, ) -> Weight;
 	fn propose_execute(b: u32, m: u32, ) -> Weight;
 	fn propose_proposed(b: u32, m: u32, p: u32, ) -> Weight;
+	fn propose_with_dependency(b: u32, m: u32, p: u32, ) -> Weight;
 	fn vote(m: u32, ) -> Weight;
 	fn close_early_disapproved(m: u32, p: u32, ) -> Weight;
 	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight;
@@ -176,6 +182,44 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `Council::Members` (r:1 w:0)
 	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalOf` (r:1 w:1)
+	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Proposals` (r:1 w:1)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(355), added: 2830, mode: `MaxEncodedLen`)
+	/// Storage: `Council::ProposalCount` (r:1 w:1)
+	/// Proof: `Council::ProposalCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Voting` (r:1 w:1)
+	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::CostOf` (r:0 w:1)
+	/// Proof: `Council::CostOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Dependents` (r:1 w:1)
+	/// Proof: `Council::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::DependencyOf` (r:0 w:1)
+	/// Proof: `Council::DependencyOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `b` is `[2, 1024]`.
+	/// The range of component `m` is `[2, 100]`.
+	/// The range of component `p` is `[2, 100]`.
+	fn propose_with_dependency(b: u32, m: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `651 + m * (32 ±0) + p * (36 ±0)`
+		//  Estimated: `4024 + m * (33 ±0) + p * (36 ±0)`
+		// Minimum execution time: 50_265_000 picoseconds.
+		Weight::from_parts(68_526_006, 4024)
+			// Standard Error: 330
+			.saturating_add(Weight::from_parts(4_211, 0).saturating_mul(b.into()))
+			// Standard Error: 3_443
+			.saturating_add(Weight::from_parts(43_705, 0).saturating_mul(m.into()))
+			// Standard Error: 3_399
+			.saturating_add(Weight::from_parts(235_928, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 33).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
+	}
+	/// Storage: `Council::Members` (r:1 w:0)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Council::Voting` (r:1 w:1)
 	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `m` is `[5, 100]`.
@@ -199,6 +243,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Council::ProposalOf` (r:0 w:1)
 	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::DependencyOf` (r:1 w:1)
+	/// Proof: `Council::DependencyOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Dependents` (r:1 w:1)
+	/// Proof: `Council::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_early_disapproved(m: u32, p: u32, ) -> Weight {
@@ -211,8 +259,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(28_190, 0).saturating_mul(m.into()))
 			// Standard Error: 1_994
 			.saturating_add(Weight::from_parts(185_801, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 			.saturating_add(Weight::from_parts(0, 65).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
 	}
@@ -228,6 +276,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
 	/// Storage: `Council::Proposals` (r:1 w:1)
 	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::DependencyOf` (r:1 w:1)
+	/// Proof: `Council::DependencyOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Dependents` (r:1 w:1)
+	/// Proof: `Council::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `b` is `[2, 1024]`.
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
@@ -243,8 +295,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(33_189, 0).saturating_mul(m.into()))
 			// Standard Error: 2_927
 			.saturating_add(Weight::from_parts(245_387, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 			.saturating_add(Weight::from_parts(0, 1).saturating_mul(b.into()))
 			.saturating_add(Weight::from_parts(0, 66).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 40).saturating_mul(p.into()))
@@ -259,6 +311,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Council::ProposalOf` (r:0 w:1)
 	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::DependencyOf` (r:1 w:1)
+	/// Proof: `Council::DependencyOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Dependents` (r:1 w:1)
+	/// Proof: `Council::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_disapproved(m: u32, p: u32, ) -> Weight {
@@ -271,8 +327,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(39_633, 0).saturating_mul(m.into()))
 			// Standard Error: 2_226
 			.saturating_add(Weight::from_parts(191_898, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 			.saturating_add(Weight::from_parts(0, 65).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
 	}
@@ -290,6 +346,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
 	/// Storage: `Council::Proposals` (r:1 w:1)
 	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::DependencyOf` (r:1 w:1)
+	/// Proof: `Council::DependencyOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Dependents` (r:1 w:1)
+	/// Proof: `Council::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `b` is `[2, 1024]`.
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
@@ -305,8 +365,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(19_609, 0).saturating_mul(m.into()))
 			// Standard Error: 3_269
 			.saturating_add(Weight::from_parts(236_964, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 			.saturating_add(Weight::from_parts(0, 1).saturating_mul(b.into()))
 			.saturating_add(Weight::from_parts(0, 66).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 40).saturating_mul(p.into()))
@@ -317,6 +377,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Council::ProposalOf` (r:0 w:1)
 	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::DependencyOf` (r:1 w:1)
+	/// Proof: `Council::DependencyOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Dependents` (r:1 w:1)
+	/// Proof: `Council::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `p` is `[1, 100]`.
 	fn disapprove_proposal(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -326,8 +390,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(16_823_844, 1910)
 			// Standard Error: 1_424
 			.saturating_add(Weight::from_parts(170_583, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 			.saturating_add(Weight::from_parts(0, 32).saturating_mul(p.into()))
 	}
 	/// Storage: `Council::ProposalOf` (r:1 w:1)
@@ -342,6 +406,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Council::Voting` (r:0 w:1)
 	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::DependencyOf` (r:1 w:1)
+	/// Proof: `Council::DependencyOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Dependents` (r:1 w:1)
+	/// Proof: `Council::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `d` is `[0, 1]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn kill(d: u32, p: u32, ) -> Weight {
@@ -354,9 +422,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(31_856_043, 0).saturating_mul(d.into()))
 			// Standard Error: 5_806
 			.saturating_add(Weight::from_parts(288_259, 0).saturating_mul(p.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(d.into())))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(d.into())))
 			.saturating_add(Weight::from_parts(0, 1910).saturating_mul(d.into()))
 			.saturating_add(Weight::from_parts(0, 43).saturating_mul(p.into()))
@@ -490,6 +558,44 @@ impl WeightInfo for () {
 	}
 	/// Storage: `Council::Members` (r:1 w:0)
 	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalOf` (r:1 w:1)
+	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Proposals` (r:1 w:1)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(355), added: 2830, mode: `MaxEncodedLen`)
+	/// Storage: `Council::ProposalCount` (r:1 w:1)
+	/// Proof: `Council::ProposalCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Voting` (r:1 w:1)
+	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::CostOf` (r:0 w:1)
+	/// Proof: `Council::CostOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Dependents` (r:1 w:1)
+	/// Proof: `Council::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::DependencyOf` (r:0 w:1)
+	/// Proof: `Council::DependencyOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `b` is `[2, 1024]`.
+	/// The range of component `m` is `[2, 100]`.
+	/// The range of component `p` is `[2, 100]`.
+	fn propose_with_dependency(b: u32, m: u32, p: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `651 + m * (32 ±0) + p * (36 ±0)`
+		//  Estimated: `4024 + m * (33 ±0) + p * (36 ±0)`
+		// Minimum execution time: 50_265_000 picoseconds.
+		Weight::from_parts(68_526_006, 4024)
+			// Standard Error: 330
+			.saturating_add(Weight::from_parts(4_211, 0).saturating_mul(b.into()))
+			// Standard Error: 3_443
+			.saturating_add(Weight::from_parts(43_705, 0).saturating_mul(m.into()))
+			// Standard Error: 3_399
+			.saturating_add(Weight::from_parts(235_928, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(Weight::from_parts(0, 33).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
+	}
+	/// Storage: `Council::Members` (r:1 w:0)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Council::Voting` (r:1 w:1)
 	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `m` is `[5, 100]`.
@@ -513,6 +619,10 @@ impl WeightInfo for () {
 	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Council::ProposalOf` (r:0 w:1)
 	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::DependencyOf` (r:1 w:1)
+	/// Proof: `Council::DependencyOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Dependents` (r:1 w:1)
+	/// Proof: `Council::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_early_disapproved(m: u32, p: u32, ) -> Weight {
@@ -525,8 +635,8 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(28_190, 0).saturating_mul(m.into()))
 			// Standard Error: 1_994
 			.saturating_add(Weight::from_parts(185_801, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 			.saturating_add(Weight::from_parts(0, 65).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
 	}
@@ -542,6 +652,10 @@ impl WeightInfo for () {
 	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
 	/// Storage: `Council::Proposals` (r:1 w:1)
 	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::DependencyOf` (r:1 w:1)
+	/// Proof: `Council::DependencyOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Dependents` (r:1 w:1)
+	/// Proof: `Council::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `b` is `[2, 1024]`.
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
@@ -557,8 +671,8 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(33_189, 0).saturating_mul(m.into()))
 			// Standard Error: 2_927
 			.saturating_add(Weight::from_parts(245_387, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 			.saturating_add(Weight::from_parts(0, 1).saturating_mul(b.into()))
 			.saturating_add(Weight::from_parts(0, 66).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 40).saturating_mul(p.into()))
@@ -573,6 +687,10 @@ impl WeightInfo for () {
 	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Council::ProposalOf` (r:0 w:1)
 	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::DependencyOf` (r:1 w:1)
+	/// Proof: `Council::DependencyOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Dependents` (r:1 w:1)
+	/// Proof: `Council::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn close_disapproved(m: u32, p: u32, ) -> Weight {
@@ -585,8 +703,8 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(39_633, 0).saturating_mul(m.into()))
 			// Standard Error: 2_226
 			.saturating_add(Weight::from_parts(191_898, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 			.saturating_add(Weight::from_parts(0, 65).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 36).saturating_mul(p.into()))
 	}
@@ -604,6 +722,10 @@ impl WeightInfo for () {
 	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
 	/// Storage: `Council::Proposals` (r:1 w:1)
 	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::DependencyOf` (r:1 w:1)
+	/// Proof: `Council::DependencyOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Dependents` (r:1 w:1)
+	/// Proof: `Council::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `b` is `[2, 1024]`.
 	/// The range of component `m` is `[4, 100]`.
 	/// The range of component `p` is `[1, 100]`.
@@ -619,8 +741,8 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(19_609, 0).saturating_mul(m.into()))
 			// Standard Error: 3_269
 			.saturating_add(Weight::from_parts(236_964, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 			.saturating_add(Weight::from_parts(0, 1).saturating_mul(b.into()))
 			.saturating_add(Weight::from_parts(0, 66).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 40).saturating_mul(p.into()))
@@ -631,6 +753,10 @@ impl WeightInfo for () {
 	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Council::ProposalOf` (r:0 w:1)
 	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::DependencyOf` (r:1 w:1)
+	/// Proof: `Council::DependencyOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Dependents` (r:1 w:1)
+	/// Proof: `Council::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `p` is `[1, 100]`.
 	fn disapprove_proposal(p: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -640,8 +766,8 @@ impl WeightInfo for () {
 		Weight::from_parts(16_823_844, 1910)
 			// Standard Error: 1_424
 			.saturating_add(Weight::from_parts(170_583, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 			.saturating_add(Weight::from_parts(0, 32).saturating_mul(p.into()))
 	}
 	/// Storage: `Council::ProposalOf` (r:1 w:1)
@@ -656,6 +782,10 @@ impl WeightInfo for () {
 	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Council::Voting` (r:0 w:1)
 	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::DependencyOf` (r:1 w:1)
+	/// Proof: `Council::DependencyOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Dependents` (r:1 w:1)
+	/// Proof: `Council::Dependents` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// The range of component `d` is `[0, 1]`.
 	/// The range of component `p` is `[1, 100]`.
 	fn kill(d: u32, p: u32, ) -> Weight {
@@ -668,9 +798,9 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(31_856_043, 0).saturating_mul(d.into()))
 			// Standard Error: 5_806
 			.saturating_add(Weight::from_parts(288_259, 0).saturating_mul(p.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(d.into())))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(d.into())))
 			.saturating_add(Weight::from_parts(0, 1910).saturating_mul(d.into()))
 			.saturating_add(Weight::from_parts(0, 43).saturating_mul(p.into()))
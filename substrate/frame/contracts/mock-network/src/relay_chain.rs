@@ -227,6 +227,7 @@ impl pallet_message_queue::Config for Runtime {
 	type QueueChangeHandler = ();
 	type WeightInfo = ();
 	type QueuePausedQuery = ();
+	type ServiceWeightShare = ();
 }
 
 construct_runtime!(
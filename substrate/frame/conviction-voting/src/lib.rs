@@ -23,6 +23,10 @@
 //! ## Overview
 //!
 //! Pallet for managing actual voting in polls.
+//!
+//! Once a poll ends, or a delegation is removed, the balance used to vote stays locked for a
+//! period depending on the conviction. Depending on [`Config::DecayingLocks`], this lock is either
+//! released all at once when the period ends, or decays linearly over the period.
 
 #![recursion_limit = "256"]
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -44,6 +48,7 @@ use sp_runtime::{
 };
 
 mod conviction;
+pub mod migration;
 mod types;
 mod vote;
 pub mod weights;
@@ -89,7 +94,8 @@ pub mod pallet {
 	use super::*;
 	use frame_support::{
 		pallet_prelude::{
-			DispatchResultWithPostInfo, IsType, StorageDoubleMap, StorageMap, ValueQuery,
+			DispatchResultWithPostInfo, IsType, StorageDoubleMap, StorageMap, StorageVersion,
+			ValueQuery,
 		},
 		traits::ClassCountOf,
 		Twox64Concat,
@@ -97,7 +103,11 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 	use sp_runtime::BoundedVec;
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(_);
 
 	#[pallet::config]
@@ -137,6 +147,14 @@ pub mod pallet {
 		/// those successful voters are locked into the consequences that their votes entail.
 		#[pallet::constant]
 		type VoteLockingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Whether the locks left over by votes and delegations decay linearly over their period,
+		/// rather than being released all at once when it ends.
+		///
+		/// A lock left over by a vote decays from the end of the poll, and one left over by a
+		/// delegation from the time it is removed.
+		#[pallet::constant]
+		type DecayingLocks: Get<bool>;
 	}
 
 	/// All voting for a particular voter in a particular voting class. We store the balance for the
@@ -299,6 +317,8 @@ pub mod pallet {
 		/// Remove the lock caused by prior voting/delegating which has expired within a particular
 		/// class.
 		///
+		/// If locks decay, this also releases the part of the lock which has decayed so far.
+		///
 		/// The dispatch origin of this call must be _Signed_.
 		///
 		/// - `class`: The class of polls to unlock.
@@ -482,7 +502,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 									matches!(scope, UnvoteScope::Any),
 									Error::<T, I>::NoPermissionYet
 								);
-								prior.accumulate(unlock_at, balance)
+								if T::DecayingLocks::get() {
+									prior.accumulate_decaying(end, unlock_at, balance, now)
+								} else {
+									prior.accumulate(unlock_at, balance)
+								}
 							}
 						}
 						Ok(())
@@ -619,12 +643,14 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						);
 						let now = frame_system::Pallet::<T>::block_number();
 						let lock_periods = conviction.lock_periods().into();
-						prior.accumulate(
-							now.saturating_add(
-								T::VoteLockingPeriod::get().saturating_mul(lock_periods),
-							),
-							balance,
+						let unlock_at = now.saturating_add(
+							T::VoteLockingPeriod::get().saturating_mul(lock_periods),
 						);
+						if T::DecayingLocks::get() {
+							prior.accumulate_decaying(now, unlock_at, balance, now);
+						} else {
+							prior.accumulate(unlock_at, balance);
+						}
 						voting.set_common(delegations, prior);
 
 						Ok(votes)
@@ -663,7 +689,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// a security hole) but may be reduced from what they are currently.
 	fn update_lock(class: &ClassOf<T, I>, who: &T::AccountId) {
 		let class_lock_needed = VotingFor::<T, I>::mutate(who, class, |voting| {
-			voting.rejig(frame_system::Pallet::<T>::block_number());
+			let now = frame_system::Pallet::<T>::block_number();
+			if T::DecayingLocks::get() {
+				voting.rejig_decaying(now);
+			} else {
+				voting.rejig(now);
+			}
 			voting.locked_balance()
 		});
 		let lock_needed = ClassLocksFor::<T, I>::mutate(who, |locks| {
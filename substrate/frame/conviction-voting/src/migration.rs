@@ -0,0 +1,266 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the conviction-voting pallet.
+
+use super::*;
+use crate::vote::PriorLock;
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
+use frame_support::{
+	defensive,
+	migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+	pallet_prelude::*,
+	storage::unhashed,
+	traits::{Get, PalletInfoAccess},
+	weights::WeightMeter,
+	BoundedVec, StorageHasher,
+};
+
+#[cfg(feature = "try-runtime")]
+use alloc::vec::Vec;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// The storage types of version 0, before prior locks recorded when they start.
+pub mod v0 {
+	use super::*;
+
+	/// V0 type for the prior lock of a voter, without the block since which it applies.
+	#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+	pub struct PriorLock<BlockNumber, Balance>(pub BlockNumber, pub Balance);
+
+	/// V0 type for [`crate::Delegating`].
+	#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+	pub struct Delegating<Balance, AccountId, BlockNumber> {
+		pub balance: Balance,
+		pub target: AccountId,
+		pub conviction: Conviction,
+		pub delegations: Delegations<Balance>,
+		pub prior: PriorLock<BlockNumber, Balance>,
+	}
+
+	/// V0 type for [`crate::Casting`].
+	#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+	pub struct Casting<Balance, BlockNumber, PollIndex, MaxVotes>
+	where
+		MaxVotes: Get<u32>,
+	{
+		pub votes: BoundedVec<(PollIndex, AccountVote<Balance>), MaxVotes>,
+		pub delegations: Delegations<Balance>,
+		pub prior: PriorLock<BlockNumber, Balance>,
+	}
+
+	/// V0 type for [`crate::Voting`].
+	#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+	pub enum Voting<Balance, AccountId, BlockNumber, PollIndex, MaxVotes>
+	where
+		MaxVotes: Get<u32>,
+	{
+		Casting(Casting<Balance, BlockNumber, PollIndex, MaxVotes>),
+		Delegating(Delegating<Balance, AccountId, BlockNumber>),
+	}
+
+	pub type VotingOf<T, I> = Voting<
+		BalanceOf<T, I>,
+		<T as frame_system::Config>::AccountId,
+		BlockNumberFor<T>,
+		PollIndexOf<T, I>,
+		<T as Config<I>>::MaxVotes,
+	>;
+}
+
+/// Migrates the prior locks of all voters to record the block since which they apply, one
+/// voting record per step.
+///
+/// Existing prior locks are considered to apply in full since the block in which they are
+/// migrated, such that they only start decaying from then on if [`Config::DecayingLocks`] is
+/// enabled. Transactions must not change the voting records while the migration runs, which the
+/// multi-block migration framework ensures.
+///
+/// The migration is identified by the hashed name of the pallet instance, so every instance of
+/// the pallet is migrated on its own.
+pub struct LazyMigrationV0ToV1<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> LazyMigrationV0ToV1<T, I> {
+	/// The weight of migrating a single voting record.
+	pub fn step_weight() -> Weight {
+		// The `VotingFor` entry of a voter in a class.
+		let proof_size = Twox64Concat::max_len::<T::AccountId>()
+			.saturating_add(Twox64Concat::max_len::<ClassOf<T, I>>())
+			.saturating_add(VotingOf::<T, I>::max_encoded_len());
+		T::DbWeight::get()
+			.reads_writes(1, 1)
+			.saturating_add(Weight::from_parts(0, proof_size as u64))
+	}
+
+	/// Migrate the voting record of `who` in `class`.
+	fn migrate(who: &T::AccountId, class: &ClassOf<T, I>) {
+		let key = VotingFor::<T, I>::hashed_key_for(who, class);
+		let Some(voting) = unhashed::get::<v0::VotingOf<T, I>>(&key) else {
+			defensive!("voting record of version 0 must decode");
+			return
+		};
+		let now = frame_system::Pallet::<T>::block_number();
+		let migrate_prior = |prior: v0::PriorLock<BlockNumberFor<T>, BalanceOf<T, I>>| {
+			PriorLock::new(prior.0, prior.1, now)
+		};
+		let voting = match voting {
+			v0::Voting::Casting(v0::Casting { votes, delegations, prior }) =>
+				Voting::Casting(Casting { votes, delegations, prior: migrate_prior(prior) }),
+			v0::Voting::Delegating(v0::Delegating {
+				balance,
+				target,
+				conviction,
+				delegations,
+				prior,
+			}) => Voting::Delegating(Delegating {
+				balance,
+				target,
+				conviction,
+				delegations,
+				prior: migrate_prior(prior),
+			}),
+		};
+		VotingFor::<T, I>::insert(who, class, voting);
+	}
+}
+
+impl<T: Config<I>, I: 'static> SteppedMigration for LazyMigrationV0ToV1<T, I> {
+	type Cursor = (T::AccountId, ClassOf<T, I>);
+	type Identifier = MigrationId<16>;
+
+	fn id() -> Self::Identifier {
+		MigrationId {
+			pallet_id: <Pallet<T, I> as PalletInfoAccess>::name_hash(),
+			version_from: 0,
+			version_to: 1,
+		}
+	}
+
+	fn step(
+		mut cursor: Option<Self::Cursor>,
+		meter: &mut WeightMeter,
+	) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+		if Pallet::<T, I>::on_chain_storage_version() != Self::id().version_from as u16 {
+			return Ok(None);
+		}
+
+		let required = Self::step_weight();
+		// If there's not enough weight left in the block for a step, return an error.
+		if meter.remaining().any_lt(required) {
+			return Err(SteppedMigrationError::InsufficientWeight { required });
+		}
+
+		while meter.try_consume(required).is_ok() {
+			let mut keys = match &cursor {
+				Some((who, class)) =>
+					VotingFor::<T, I>::iter_keys_from(VotingFor::<T, I>::hashed_key_for(who, class)),
+				None => VotingFor::<T, I>::iter_keys(),
+			};
+			let Some((who, class)) = keys.next() else {
+				StorageVersion::new(Self::id().version_to as u16).put::<Pallet<T, I>>();
+				return Ok(None);
+			};
+			Self::migrate(&who, &class);
+			cursor = Some((who, class));
+		}
+		Ok(cursor)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		let count = VotingFor::<T, I>::iter_keys().count() as u32;
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let count: u32 = Decode::decode(&mut &state[..]).expect("decoded in pre_upgrade; qed");
+		ensure!(
+			VotingFor::<T, I>::iter().count() as u32 == count,
+			"pallet-conviction-voting: voting records lost in the migration"
+		);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::{new_test_ext, System, Test};
+
+	#[test]
+	fn migration_records_the_start_of_prior_locks() {
+		new_test_ext().execute_with(|| {
+			StorageVersion::new(0).put::<Pallet<Test>>();
+			let old = |balance| {
+				v0::VotingOf::<Test, ()>::Delegating(v0::Delegating {
+					balance,
+					target: 2,
+					conviction: Conviction::Locked1x,
+					delegations: Default::default(),
+					prior: v0::PriorLock(10, 3),
+				})
+			};
+			for who in [1, 3, 4] {
+				unhashed::put_raw(&VotingFor::<Test>::hashed_key_for(who, 0), &old(who).encode());
+			}
+			System::set_block_number(4);
+
+			// Not enough weight for a single step.
+			let mut meter = WeightMeter::with_limit(Weight::zero());
+			assert!(matches!(
+				LazyMigrationV0ToV1::<Test>::step(None, &mut meter),
+				Err(SteppedMigrationError::InsufficientWeight { .. })
+			));
+
+			// Two records are migrated per step.
+			let step_weight = LazyMigrationV0ToV1::<Test>::step_weight();
+			let mut cursor = None;
+			let mut steps = 0;
+			loop {
+				let mut meter = WeightMeter::with_limit(step_weight.saturating_mul(2));
+				cursor = LazyMigrationV0ToV1::<Test>::step(cursor, &mut meter).unwrap();
+				steps += 1;
+				if cursor.is_none() {
+					break
+				}
+			}
+			assert_eq!(steps, 2);
+			assert_eq!(Pallet::<Test>::on_chain_storage_version(), 1);
+
+			for who in [1, 3, 4] {
+				assert_eq!(
+					VotingFor::<Test>::get(who, 0),
+					Voting::Delegating(Delegating {
+						balance: who,
+						target: 2,
+						conviction: Conviction::Locked1x,
+						delegations: Default::default(),
+						prior: PriorLock::new(10, 3, 4),
+					})
+				);
+			}
+
+			// The migration doesn't run again.
+			let mut meter = WeightMeter::new();
+			assert!(matches!(LazyMigrationV0ToV1::<Test>::step(None, &mut meter), Ok(None)));
+			assert_eq!(meter.consumed(), Weight::zero());
+		});
+	}
+}
@@ -72,6 +72,7 @@ parameter_types! {
 		(2, Completed(2, false)),
 		(3, Ongoing(Tally::from_parts(0, 0, 0), 0)),
 	].into_iter().collect();
+	pub static DecayingLocks: bool = false;
 }
 
 pub struct TestPolls;
@@ -150,6 +151,7 @@ impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = pallet_balances::Pallet<Self>;
 	type VoteLockingPeriod = ConstU64<3>;
+	type DecayingLocks = DecayingLocks;
 	type MaxVotes = ConstU32<3>;
 	type WeightInfo = ();
 	type MaxTurnout = frame_support::traits::TotalIssuanceOf<Balances, Self::AccountId>;
@@ -383,6 +385,50 @@ fn successful_conviction_vote_balance_stays_locked_for_correct_time() {
 	});
 }
 
+#[test]
+fn successful_conviction_vote_balance_decays_over_lock_period() {
+	new_test_ext().execute_with(|| {
+		DecayingLocks::set(true);
+		assert_ok!(Voting::vote(RuntimeOrigin::signed(5), 3, aye(50, 2)));
+		let c = class(3);
+		Polls::set(vec![(3, Completed(3, true))].into_iter().collect());
+		run_to(4);
+		assert_ok!(Voting::remove_vote(RuntimeOrigin::signed(5), Some(c), 3));
+
+		// The lock decays linearly from the end of the poll at block 3 until block 3 + 2 * 3.
+		for (block, locked) in [(4, 42), (6, 26), (8, 9), (9, 0)] {
+			run_to(block);
+			assert_ok!(Voting::unlock(RuntimeOrigin::signed(5), c, 5));
+			assert_eq!(Balances::usable_balance(5), 50 - locked);
+		}
+	});
+}
+
+#[test]
+fn undelegated_balance_decays_over_lock_period() {
+	new_test_ext().execute_with(|| {
+		DecayingLocks::set(true);
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::Locked1x, 10));
+		assert_ok!(Voting::undelegate(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Balances::usable_balance(1), 0);
+
+		// Delegating again keeps the larger of both locks.
+		run_to(2);
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 3);
+		assert_ok!(Voting::delegate(RuntimeOrigin::signed(1), 0, 2, Conviction::Locked1x, 5));
+		assert_ok!(Voting::undelegate(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+		assert_eq!(Balances::usable_balance(1), 3);
+
+		for (block, usable) in [(3, 5), (4, 7), (5, 10)] {
+			run_to(block);
+			assert_ok!(Voting::unlock(RuntimeOrigin::signed(1), 0, 1));
+			assert_eq!(Balances::usable_balance(1), usable);
+		}
+	});
+}
+
 #[test]
 fn classwise_delegation_works() {
 	new_test_ext().execute_with(|| {
@@ -22,8 +22,8 @@ use codec::{Decode, Encode, EncodeLike, Input, MaxEncodedLen, Output};
 use frame_support::{pallet_prelude::Get, BoundedVec};
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{Saturating, Zero},
-	RuntimeDebug,
+	traits::{AtLeast32BitUnsigned, Saturating, Zero},
+	Perbill, RuntimeDebug,
 };
 
 /// A number of lock periods, plus a vote, one way or the other.
@@ -113,6 +113,9 @@ impl<Balance: Saturating> AccountVote<Balance> {
 }
 
 /// A "prior" lock, i.e. a lock for some now-forgotten reason.
+///
+/// It locks an amount until some block, along with the block since which this amount is locked,
+/// from which the lock decays linearly when locks are configured to decay.
 #[derive(
 	Encode,
 	Decode,
@@ -127,9 +130,14 @@ impl<Balance: Saturating> AccountVote<Balance> {
 	TypeInfo,
 	MaxEncodedLen,
 )]
-pub struct PriorLock<BlockNumber, Balance>(BlockNumber, Balance);
+pub struct PriorLock<BlockNumber, Balance>(BlockNumber, Balance, BlockNumber);
 
 impl<BlockNumber: Ord + Copy + Zero, Balance: Ord + Copy + Zero> PriorLock<BlockNumber, Balance> {
+	/// A lock of `amount` until `until`, locked in full since `since`.
+	pub(crate) fn new(until: BlockNumber, amount: Balance, since: BlockNumber) -> Self {
+		Self(until, amount, since)
+	}
+
 	/// Accumulates an additional lock.
 	pub fn accumulate(&mut self, until: BlockNumber, amount: Balance) {
 		self.0 = self.0.max(until);
@@ -144,6 +152,55 @@ impl<BlockNumber: Ord + Copy + Zero, Balance: Ord + Copy + Zero> PriorLock<Block
 		if now >= self.0 {
 			self.0 = Zero::zero();
 			self.1 = Zero::zero();
+			self.2 = Zero::zero();
+		}
+	}
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy, Balance: AtLeast32BitUnsigned + Copy>
+	PriorLock<BlockNumber, Balance>
+{
+	/// The amount locked at `now`, decaying linearly from the full amount since the start of the
+	/// lock down to zero when it expires.
+	pub fn decaying_locked_at(&self, now: BlockNumber) -> Balance {
+		if now >= self.0 {
+			Zero::zero()
+		} else if now <= self.2 {
+			self.1
+		} else {
+			Perbill::from_rational(self.0 - now, self.0 - self.2).mul_ceil(self.1)
+		}
+	}
+
+	/// Accumulates an additional lock of `amount`, decaying from `since` until `until`.
+	///
+	/// Both locks are rebased at `now`, such that the resulting lock never locks less than either
+	/// of them would have.
+	pub fn accumulate_decaying(
+		&mut self,
+		since: BlockNumber,
+		until: BlockNumber,
+		amount: Balance,
+		now: BlockNumber,
+	) {
+		let additional = Self(until, amount, since).decaying_locked_at(now);
+		self.rejig_decaying(now);
+		if additional.is_zero() {
+			return
+		}
+		self.0 = self.0.max(until);
+		self.1 = self.1.max(additional);
+		self.2 = now;
+	}
+
+	/// Rebases the decaying lock at `now`, releasing the part of it which has decayed so far.
+	pub fn rejig_decaying(&mut self, now: BlockNumber) {
+		self.1 = self.decaying_locked_at(now);
+		if self.1.is_zero() {
+			self.0 = Zero::zero();
+			self.2 = Zero::zero();
+		} else {
+			self.2 = self.2.max(now);
 		}
 	}
 }
@@ -206,7 +263,7 @@ where
 		Voting::Casting(Casting {
 			votes: Default::default(),
 			delegations: Default::default(),
-			prior: PriorLock(Zero::zero(), Default::default()),
+			prior: PriorLock(Zero::zero(), Default::default(), Zero::zero()),
 		})
 	}
 }
@@ -238,6 +295,15 @@ where
 		AsMut::<PriorLock<BlockNumber, Balance>>::as_mut(self).rejig(now);
 	}
 
+	/// Same as [`Self::rejig`], for a prior lock decaying until it expires.
+	pub fn rejig_decaying(&mut self, now: BlockNumber)
+	where
+		Balance: AtLeast32BitUnsigned,
+		BlockNumber: AtLeast32BitUnsigned,
+	{
+		AsMut::<PriorLock<BlockNumber, Balance>>::as_mut(self).rejig_decaying(now);
+	}
+
 	/// The amount of this account's balance that must currently be locked due to voting.
 	pub fn locked_balance(&self) -> Balance {
 		match self {
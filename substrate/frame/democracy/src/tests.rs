@@ -106,6 +106,19 @@ impl pallet_scheduler::Config for Test {
 	type WeightInfo = ();
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = ();
+	type TimeProvider = NoTime;
+	type TimestampTolerance = ConstU64<0>;
+	type TimestampBucketSize = ConstU64<1>;
+}
+
+/// A wall-clock time which is always zero, tasks are never scheduled at a timestamp.
+pub struct NoTime;
+impl frame_support::traits::Time for NoTime {
+	type Moment = u64;
+
+	fn now() -> u64 {
+		0
+	}
 }
 
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
@@ -0,0 +1,229 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Deposits Pallet
+//!
+//! - [`Config`]
+//!
+//! ## Overview
+//!
+//! Accounts for the storage deposits accounts hold in the pallets configured to report them.
+//!
+//! Pallets holding their deposits through [`ManagedDeposit`] report every held and released
+//! deposit to this pallet when [`TrackAs`] is configured as their deposit tracker. Deposits held
+//! by other pallets, or held in another currency, are not accounted for. The deposits are recorded
+//! per account and per reason, so that:
+//! - the deposits of an account can be queried with a per-reason breakdown through the
+//!   [`view_functions::DepositsOf`] view function, and
+//! - the total deposit of an account can be capped with [`Config::MaxDepositPerAccount`]. Holds
+//!   which would exceed the cap fail with [`Error::CapExceeded`].
+//!
+//! The deposits a pallet held before it was tracked are recorded by the
+//! [`migrations::v1::SeedDeposits`] migration.
+//!
+//! [`ManagedDeposit`]: frame_support::traits::ManagedDeposit
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod migrations;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod view_functions;
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use frame_support::{
+	defensive,
+	dispatch::DispatchResult,
+	traits::{tokens::Balance, Get, TrackDeposit},
+};
+use sp_runtime::traits::{Saturating, Zero};
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, Parameter};
+	use sp_runtime::traits::Member;
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The balance type of the deposits.
+		type Balance: Balance;
+
+		/// The reason for which a deposit is held, usually one per tracked pallet.
+		type Reason: Parameter + Member + MaxEncodedLen + Ord + Copy;
+
+		/// The maximum number of different reasons an account can hold deposits for.
+		#[pallet::constant]
+		type MaxReasons: Get<u32>;
+
+		/// The maximum total deposit an account can hold across all reasons.
+		#[pallet::constant]
+		type MaxDepositPerAccount: Get<Self::Balance>;
+	}
+
+	/// The deposits held by an account, sorted by reason.
+	#[pallet::storage]
+	pub type Deposits<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<(T::Reason, T::Balance), T::MaxReasons>,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A deposit was held.
+		DepositHeld { who: T::AccountId, reason: T::Reason, amount: T::Balance },
+		/// A deposit was released, slashed or repatriated.
+		DepositReleased { who: T::AccountId, reason: T::Reason, amount: T::Balance },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The deposit would exceed the maximum total deposit of the account.
+		CapExceeded,
+		/// The account holds deposits for too many reasons.
+		TooManyReasons,
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The deposits held by `who`, per reason.
+	pub fn deposits_of(who: &T::AccountId) -> Vec<(T::Reason, T::Balance)> {
+		Deposits::<T>::get(who).into_inner()
+	}
+
+	/// The total deposit held by `who` across all reasons.
+	pub fn total_deposit_of(who: &T::AccountId) -> T::Balance {
+		Deposits::<T>::get(who)
+			.iter()
+			.fold(Zero::zero(), |total: T::Balance, (_, amount)| total.saturating_add(*amount))
+	}
+
+	/// Record that `amount` was held as deposit of `who` for `reason`.
+	///
+	/// Fails if the total deposit of `who` would exceed [`Config::MaxDepositPerAccount`].
+	pub fn note_hold(who: &T::AccountId, reason: T::Reason, amount: T::Balance) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		let new_total = Self::total_deposit_of(who).saturating_add(amount);
+		frame_support::ensure!(
+			new_total <= T::MaxDepositPerAccount::get(),
+			Error::<T>::CapExceeded
+		);
+
+		Self::accrue(who, reason, amount)?;
+
+		Self::deposit_event(Event::DepositHeld { who: who.clone(), reason, amount });
+		Ok(())
+	}
+
+	/// Record `amount` which was held as deposit of `who` for `reason` before it was tracked.
+	///
+	/// The deposit is held already, so [`Config::MaxDepositPerAccount`] is not enforced.
+	pub(crate) fn note_existing_hold(who: &T::AccountId, reason: T::Reason, amount: T::Balance) {
+		if amount.is_zero() {
+			return
+		}
+		if Self::accrue(who, reason, amount).is_err() {
+			defensive!("Existing deposit held for more reasons than `MaxReasons`");
+		}
+	}
+
+	/// Add `amount` to the deposit of `who` for `reason`.
+	fn accrue(who: &T::AccountId, reason: T::Reason, amount: T::Balance) -> DispatchResult {
+		Deposits::<T>::try_mutate(who, |deposits| -> DispatchResult {
+			match deposits.binary_search_by_key(&reason, |(r, _)| *r) {
+				Ok(index) => deposits[index].1.saturating_accrue(amount),
+				Err(index) => deposits
+					.try_insert(index, (reason, amount))
+					.map_err(|_| Error::<T>::TooManyReasons)?,
+			}
+			Ok(())
+		})
+	}
+
+	/// Record that `amount` of the deposit of `who` for `reason` was released.
+	pub fn note_release(who: &T::AccountId, reason: T::Reason, amount: T::Balance) {
+		if amount.is_zero() {
+			return
+		}
+
+		let released = Deposits::<T>::mutate_exists(who, |maybe_deposits| {
+			let deposits = maybe_deposits.as_mut()?;
+			let index = deposits.binary_search_by_key(&reason, |(r, _)| *r).ok()?;
+			let held = deposits[index].1;
+			let released = amount.min(held);
+			if released == held {
+				deposits.remove(index);
+			} else {
+				deposits[index].1 = held - released;
+			}
+			if deposits.is_empty() {
+				*maybe_deposits = None;
+			}
+			Some(released)
+		})
+		.unwrap_or_else(Zero::zero);
+
+		if released < amount {
+			defensive!("Released deposit is greater than the tracked deposit");
+		}
+		if !released.is_zero() {
+			Self::deposit_event(Event::DepositReleased {
+				who: who.clone(),
+				reason,
+				amount: released,
+			});
+		}
+	}
+}
+
+/// Tracks the deposits held through a [`ManagedDeposit`] under the reason `R`.
+///
+/// [`ManagedDeposit`]: frame_support::traits::ManagedDeposit
+pub struct TrackAs<T, R>(PhantomData<(T, R)>);
+
+impl<T: Config, R: Get<T::Reason>> TrackDeposit<T::AccountId, T::Balance> for TrackAs<T, R> {
+	fn on_hold(who: &T::AccountId, amount: T::Balance) -> DispatchResult {
+		Pallet::<T>::note_hold(who, R::get(), amount)
+	}
+
+	fn on_release(who: &T::AccountId, amount: T::Balance) {
+		Pallet::<T>::note_release(who, R::get(), amount)
+	}
+}
@@ -0,0 +1,104 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage migrations for the deposits pallet.
+
+use super::*;
+
+pub const PALLET_MIGRATIONS_ID: &[u8; 15] = b"pallet-deposits";
+
+pub mod v1 {
+	use super::*;
+	use codec::MaxEncodedLen;
+	use frame_support::{
+		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+		pallet_prelude::*,
+		traits::HeldDeposits,
+		weights::WeightMeter,
+	};
+
+	/// Records the deposits `Source` held before it was tracked under the reason `R`.
+	///
+	/// Pallets which already hold deposits when they are configured to report them to this pallet
+	/// would otherwise release deposits that were never recorded. The migration only runs on
+	/// chains where this pallet was added before the storage version 1, so that a chain tracking
+	/// the deposits from genesis does not record them twice. All deposits tracked at that point
+	/// must thus be seeded by this single migration.
+	///
+	/// Deposits are recorded as they are held when the migration visits them. Transactions must
+	/// not change them while it runs, which the multi-block migration framework ensures.
+	pub struct SeedDeposits<T, Source, R>(PhantomData<(T, Source, R)>);
+
+	impl<T, Source, R> SeedDeposits<T, Source, R>
+	where
+		T: Config,
+		Source: HeldDeposits<T::AccountId, T::Balance>,
+	{
+		/// The weight of visiting a single deposit.
+		pub fn step_weight() -> Weight {
+			// The `Deposits` entry of an account: the hashed key, the account and its deposits.
+			let proof_size =
+				16u64.saturating_add(T::AccountId::max_encoded_len() as u64).saturating_add(
+					BoundedVec::<(T::Reason, T::Balance), T::MaxReasons>::max_encoded_len() as u64,
+				);
+			Source::next_deposit_weight()
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(Weight::from_parts(0, proof_size))
+		}
+	}
+
+	impl<T, Source, R> SteppedMigration for SeedDeposits<T, Source, R>
+	where
+		T: Config,
+		Source: HeldDeposits<T::AccountId, T::Balance>,
+		R: Get<T::Reason>,
+	{
+		type Cursor = Source::Cursor;
+		type Identifier = MigrationId<15>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 0, version_to: 1 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			if Pallet::<T>::on_chain_storage_version() != Self::id().version_from as u16 {
+				return Ok(None);
+			}
+
+			let required = Self::step_weight();
+			// If there's not enough weight left in the block for a step, return an error.
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			while meter.try_consume(required).is_ok() {
+				let Some((next, deposit)) = Source::next_deposit(cursor) else {
+					StorageVersion::new(Self::id().version_to as u16).put::<Pallet<T>>();
+					return Ok(None);
+				};
+				if let Some((who, amount)) = deposit {
+					Pallet::<T>::note_existing_hold(&who, R::get(), amount);
+				}
+				cursor = Some(next);
+			}
+			Ok(cursor)
+		}
+	}
+}
@@ -0,0 +1,119 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for the deposits pallet.
+
+use super::*;
+use crate as pallet_deposits;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64, HeldDeposits, ManagedDeposit},
+	weights::Weight,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{BuildStorage, RuntimeDebug};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Deposits: pallet_deposits,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+/// The pallets holding deposits in the tests.
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	MaxEncodedLen,
+	TypeInfo,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	RuntimeDebug,
+)]
+pub enum TestReason {
+	Identity,
+	Multisig,
+	Uniques,
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type Reason = TestReason;
+	type MaxReasons = ConstU32<2>;
+	type MaxDepositPerAccount = ConstU64<50>;
+}
+
+parameter_types! {
+	pub const IdentityReason: TestReason = TestReason::Identity;
+	pub const MultisigReason: TestReason = TestReason::Multisig;
+	pub const UniquesReason: TestReason = TestReason::Uniques;
+}
+
+pub type IdentityDeposit = ManagedDeposit<u64, Balances, TrackAs<Test, IdentityReason>>;
+pub type MultisigDeposit = ManagedDeposit<u64, Balances, TrackAs<Test, MultisigReason>>;
+pub type UniquesDeposit = ManagedDeposit<u64, Balances, TrackAs<Test, UniquesReason>>;
+
+std::thread_local! {
+	pub static HELD: core::cell::RefCell<Vec<Option<(u64, u64)>>> = Default::default();
+}
+
+/// The deposits held before they were tracked, `None` for deposits without an account.
+pub struct HeldBefore;
+
+impl HeldDeposits<u64, u64> for HeldBefore {
+	type Cursor = u32;
+
+	fn next_deposit(cursor: Option<u32>) -> Option<(u32, Option<(u64, u64)>)> {
+		let next = cursor.map_or(0, |index| index + 1);
+		HELD.with(|held| held.borrow().get(next as usize).map(|deposit| (next, *deposit)))
+	}
+
+	fn next_deposit_weight() -> Weight {
+		Weight::from_parts(1_000, 100)
+	}
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 100), (2, 100)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
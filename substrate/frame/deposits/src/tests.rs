@@ -0,0 +1,139 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the deposits pallet.
+
+use crate::{migrations::v1::SeedDeposits, mock::*, view_functions::DepositsOf, Error, Event};
+use frame_support::{
+	assert_err, assert_ok,
+	migrations::SteppedMigration,
+	traits::{Currency, GetStorageVersion, ReservableCurrency, StorageVersion},
+	view_functions::ViewFunction,
+	weights::WeightMeter,
+};
+
+#[test]
+fn deposits_are_accounted_per_reason() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(IdentityDeposit::hold(&1, 10));
+		assert_ok!(MultisigDeposit::hold(&1, 5));
+		assert_ok!(IdentityDeposit::hold(&1, 3));
+		assert_eq!(
+			Deposits::deposits_of(&1),
+			vec![(TestReason::Identity, 13), (TestReason::Multisig, 5)]
+		);
+		assert_eq!(Deposits::total_deposit_of(&1), 18);
+		assert_eq!(Balances::reserved_balance(1), 18);
+		System::assert_last_event(
+			Event::DepositHeld { who: 1, reason: TestReason::Identity, amount: 3 }.into(),
+		);
+
+		assert_eq!(IdentityDeposit::release(&1, 13), 13);
+		assert_eq!(Deposits::deposits_of(&1), vec![(TestReason::Multisig, 5)]);
+		System::assert_last_event(
+			Event::DepositReleased { who: 1, reason: TestReason::Identity, amount: 13 }.into(),
+		);
+
+		// Slashing and repatriating release the deposit as well.
+		assert_eq!(MultisigDeposit::slash(&1, 2).1, 0);
+		assert_eq!(MultisigDeposit::repatriate(&1, &2, 3), Ok(0));
+		assert_eq!(Balances::free_balance(2), 103);
+		assert!(!crate::Deposits::<Test>::contains_key(1));
+		assert!(Deposits::deposits_of(&1).is_empty());
+	});
+}
+
+#[test]
+fn deposits_are_capped_per_account() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(IdentityDeposit::hold(&1, 40));
+		assert_err!(MultisigDeposit::hold(&1, 11), Error::<Test>::CapExceeded);
+		assert_eq!(Balances::reserved_balance(1), 40);
+
+		assert_eq!(
+			MultisigDeposit::rejig(&1, 0, 10),
+			Ok(frame_support::traits::DepositChange::Increased { old: 0, new: 10 })
+		);
+		assert_err!(IdentityDeposit::rejig(&1, 40, 41), Error::<Test>::CapExceeded);
+
+		// The cap is per account.
+		assert_ok!(IdentityDeposit::hold(&2, 50));
+	});
+}
+
+#[test]
+fn deposits_are_limited_to_max_reasons() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(IdentityDeposit::hold(&1, 1));
+		assert_ok!(MultisigDeposit::hold(&1, 1));
+		assert_err!(UniquesDeposit::hold(&1, 1), Error::<Test>::TooManyReasons);
+		assert_eq!(Balances::reserved_balance(1), 2);
+	});
+}
+
+#[test]
+fn deposits_of_view_function_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(IdentityDeposit::hold(&1, 7));
+		assert_ok!(MultisigDeposit::hold(&1, 2));
+
+		assert_eq!(
+			DepositsOf::<Test>(1).invoke(),
+			vec![(TestReason::Identity, 7), (TestReason::Multisig, 2)]
+		);
+		assert_eq!(DepositsOf::<Test>(2).invoke(), vec![]);
+	});
+}
+
+#[test]
+fn existing_deposits_are_seeded() {
+	new_test_ext().execute_with(|| {
+		type Seed = SeedDeposits<Test, HeldBefore, IdentityReason>;
+		StorageVersion::new(0).put::<Deposits>();
+		// Deposits above the cap are recorded, as they are held already.
+		HELD.with(|held| {
+			*held.borrow_mut() =
+				vec![Some((1, 30)), None, Some((2, 60)), Some((1, 0)), Some((1, 5))]
+		});
+
+		// Two deposits per step.
+		let limit = Seed::step_weight().saturating_mul(2);
+		let mut cursor = None;
+		let mut steps = 0;
+		loop {
+			cursor = Seed::step(cursor, &mut WeightMeter::with_limit(limit)).unwrap();
+			steps += 1;
+			if cursor.is_none() {
+				break
+			}
+			assert_eq!(Deposits::on_chain_storage_version(), 0);
+		}
+		// The last step finds the end of the deposits.
+		assert_eq!(steps, 3);
+		assert_eq!(Deposits::on_chain_storage_version(), 1);
+		assert_eq!(Deposits::deposits_of(&1), vec![(TestReason::Identity, 35)]);
+		assert_eq!(Deposits::deposits_of(&2), vec![(TestReason::Identity, 60)]);
+
+		// Seeding again does not record the deposits twice.
+		assert!(matches!(Seed::step(None, &mut WeightMeter::new()), Ok(None)));
+		assert_eq!(Deposits::total_deposit_of(&1), 35);
+
+		// Too little weight for a single deposit.
+		StorageVersion::new(0).put::<Deposits>();
+		assert!(Seed::step(None, &mut WeightMeter::with_limit(Weight::zero())).is_err());
+	});
+}
@@ -0,0 +1,54 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! View functions of the deposits pallet.
+
+use super::*;
+use codec::{Decode, Encode};
+use frame_support::view_functions::{
+	DispatchViewFunction, ViewFunction, ViewFunctionDispatchError, ViewFunctionId,
+};
+
+/// The deposits held by an account, per reason.
+#[derive(Encode, Decode)]
+pub struct DepositsOf<T: Config>(pub T::AccountId);
+
+impl<T: Config> ViewFunction for DepositsOf<T> {
+	type ReturnType = Vec<(T::Reason, T::Balance)>;
+
+	fn id() -> ViewFunctionId {
+		ViewFunctionId::new::<Pallet<T>>("deposits_of")
+	}
+
+	fn invoke(self) -> Self::ReturnType {
+		Pallet::<T>::deposits_of(&self.0)
+	}
+}
+
+impl<T: Config> DispatchViewFunction for Pallet<T> {
+	fn dispatch_view_function<O: codec::Output>(
+		id: &ViewFunctionId,
+		input: &mut &[u8],
+		output: &mut O,
+	) -> Result<(), ViewFunctionDispatchError> {
+		if *id == DepositsOf::<T>::id() {
+			DepositsOf::<T>::execute(input, output)
+		} else {
+			Err(ViewFunctionDispatchError::NotFound(*id))
+		}
+	}
+}
@@ -66,6 +66,10 @@
 //! If unsuccessful, meaning that the staker was exposed, the aforementioned deposit will be slashed
 //! for the amount of wasted work they have inflicted on the chain.
 //!
+//! Optionally, stakers that were exposed in only a few of the checked eras, up to
+//! [`Config::MaxExposedEras`], are still unstaked. Instead of losing their entire deposit, they are
+//! charged a part of it, proportional to the number of eras they were exposed in.
+//!
 //! All in all, this pallet is meant to provide an easy off-ramp for some stakers.
 //!
 //! ### Example
@@ -158,7 +162,7 @@ pub mod pallet {
 		traits::{Defensive, ReservableCurrency, StorageVersion},
 	};
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::{traits::Zero, DispatchResult};
+	use sp_runtime::{traits::Zero, DispatchResult, Perbill};
 	use sp_staking::{EraIndex, StakingInterface};
 	pub use weights::WeightInfo;
 
@@ -203,6 +207,16 @@ pub mod pallet {
 		/// benchmarks.
 		type MaxErasToCheckPerBlock: Get<u32>;
 
+		/// Maximum number of eras in which a staker may be found exposed and still be fast
+		/// unstaked.
+		///
+		/// A staker exposed in up to this many of the checked eras is unstaked at the end of the
+		/// check, but is charged a part of their deposit proportional to the number of eras they
+		/// were exposed in, out of the [`MaxChecking`] eras checked. A staker exposed in more eras
+		/// has their entire deposit slashed, as if this was zero.
+		#[pallet::constant]
+		type MaxExposedEras: Get<u32>;
+
 		/// The weight information of this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -231,6 +245,14 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type ErasToCheckPerBlock<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+	/// The eras in which the stashes being checked in [`Head`] have been found exposed so far.
+	///
+	/// Only ever populated if [`Config::MaxExposedEras`] is non-zero.
+	// Hasher: Twox safe since `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type ExposedEras<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<EraIndex, T::MaxExposedEras>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -247,6 +269,9 @@ pub mod pallet {
 		BatchFinished { size: u32 },
 		/// An internal error happened. Operations will be paused now.
 		InternalError,
+		/// A staker exposed in a tolerated number of eras was charged a part of their deposit
+		/// before being unstaked.
+		ExposureCharged { stash: T::AccountId, eras: u32, amount: BalanceOf<T> },
 	}
 
 	#[pallet::error]
@@ -555,9 +580,25 @@ pub mod pallet {
 				unchecked_eras_to_check
 			);
 
-			let unstake_stash = |stash: T::AccountId, deposit| {
+			let unstake_stash = |stash: T::AccountId, deposit: BalanceOf<T>| {
+				// charge stashes that were exposed in a tolerated number of eras pro-rata.
+				let exposed = ExposedEras::<T>::take(&stash).map_or(0, |mut eras| {
+					eras.retain(|e| *e >= current_era.saturating_sub(bonding_duration));
+					eras.len() as u32
+				});
+				let charge = Perbill::from_rational(exposed, MaxChecking::<T>::get()) * deposit;
+				if !charge.is_zero() {
+					let _ = T::Currency::slash_reserved(&stash, charge);
+					log!(info, "charged {:?} by {:?} for {} exposed eras", stash, charge, exposed);
+					Self::deposit_event(Event::<T>::ExposureCharged {
+						stash: stash.clone(),
+						eras: exposed,
+						amount: charge,
+					});
+				}
+
 				let result = T::Staking::force_unstake(stash.clone());
-				let remaining = T::Currency::unreserve(&stash, deposit);
+				let remaining = T::Currency::unreserve(&stash, deposit.saturating_sub(charge));
 				if !remaining.is_zero() {
 					Self::halt("not enough balance to unreserve");
 				} else {
@@ -567,17 +608,28 @@ pub mod pallet {
 			};
 
 			let check_stash = |stash, deposit| {
-				let is_exposed = unchecked_eras_to_check
+				let exposed_in = unchecked_eras_to_check
 					.iter()
-					.any(|e| T::Staking::is_exposed_in_era(&stash, e));
+					.filter(|e| T::Staking::is_exposed_in_era(&stash, e))
+					.copied()
+					.collect::<Vec<_>>();
+				if exposed_in.is_empty() {
+					return true
+				}
 
-				if is_exposed {
+				// the stash is still eligible as long as it has not been exposed in more eras than
+				// tolerated.
+				let mut exposed = ExposedEras::<T>::get(&stash).unwrap_or_default();
+				exposed.retain(|e| *e >= current_era.saturating_sub(bonding_duration));
+				if exposed.try_extend(exposed_in.into_iter()).is_ok() {
+					ExposedEras::<T>::insert(&stash, exposed);
+					true
+				} else {
+					ExposedEras::<T>::remove(&stash);
 					let _ = T::Currency::slash_reserved(&stash, deposit);
 					log!(info, "slashed {:?} by {:?}", stash, deposit);
 					Self::deposit_event(Event::<T>::Slashed { stash, amount: deposit });
 					false
-				} else {
-					true
 				}
 			};
 
@@ -119,6 +119,7 @@ impl pallet_staking::Config for Runtime {
 parameter_types! {
 	pub static Deposit: u128 = 7;
 	pub static BatchSize: u32 = 1;
+	pub static MaxExposedEras: u32 = 0;
 }
 
 impl fast_unstake::Config for Runtime {
@@ -130,6 +131,7 @@ impl fast_unstake::Config for Runtime {
 	type BatchSize = BatchSize;
 	type WeightInfo = ();
 	type MaxErasToCheckPerBlock = ConstU32<16>;
+	type MaxExposedEras = MaxExposedEras;
 }
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
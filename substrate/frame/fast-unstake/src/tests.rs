@@ -782,6 +782,89 @@ mod on_idle {
 		});
 	}
 
+	#[test]
+	fn partially_exposed_nominator_is_charged() {
+		ExtBuilder::default().build_and_execute(|| {
+			MaxExposedEras::set(1);
+			Deposit::set(8);
+			ErasToCheckPerBlock::<T>::put(1);
+			CurrentEra::<T>::put(BondingDuration::get());
+
+			// create a nominator exposed in era 1
+			let exposed = 666;
+			create_exposed_nominator(exposed, 1);
+
+			// the exposure is tolerated, so the checking carries on.
+			next_block(true);
+			next_block(true);
+			next_block(true);
+			assert_eq!(
+				Head::<T>::get(),
+				Some(UnstakeRequest {
+					stashes: bounded_vec![(exposed, Deposit::get())],
+					checked: bounded_vec![3, 2, 1]
+				})
+			);
+			assert_eq!(ExposedEras::<T>::get(exposed), Some(bounded_vec![1]));
+
+			next_block(true);
+			next_block(true);
+			assert_eq!(Head::<T>::get(), None);
+
+			// they are unstaked, but charged a quarter of their deposit for one out of four eras.
+			assert_eq!(
+				fast_unstake_events_since_last_call(),
+				vec![
+					Event::BatchChecked { eras: vec![3] },
+					Event::BatchChecked { eras: vec![2] },
+					Event::BatchChecked { eras: vec![1] },
+					Event::BatchChecked { eras: vec![0] },
+					Event::ExposureCharged { stash: exposed, eras: 1, amount: 2 },
+					Event::Unstaked { stash: exposed, result: Ok(()) },
+					Event::BatchFinished { size: 1 }
+				]
+			);
+			assert_eq!(ExposedEras::<T>::get(exposed), None);
+			assert_eq!(Balances::reserved_balance(exposed), 0);
+			assert_eq!(Balances::free_balance(exposed), 100 - 2);
+			assert!(!pallet_staking::Bonded::<T>::contains_key(exposed));
+		});
+	}
+
+	#[test]
+	fn nominator_exposed_beyond_tolerance_is_slashed() {
+		ExtBuilder::default().build_and_execute(|| {
+			MaxExposedEras::set(1);
+			ErasToCheckPerBlock::<T>::put(2);
+			CurrentEra::<T>::put(BondingDuration::get());
+
+			// create a nominator exposed in eras 2 and 1
+			let exposed = 666;
+			create_exposed_nominator(exposed, 1);
+			let mut exposure =
+				pallet_staking::EraInfo::<T>::get_full_exposure(2, &VALIDATORS_PER_ERA);
+			exposure
+				.others
+				.push(pallet_staking::IndividualExposure { who: exposed, value: 0 as Balance });
+			pallet_staking::EraInfo::<T>::set_exposure(2, &VALIDATORS_PER_ERA, exposure);
+
+			next_block(true);
+			assert_eq!(ExposedEras::<T>::get(exposed), Some(bounded_vec![2]));
+			next_block(true);
+			assert_eq!(Head::<T>::get(), None);
+
+			assert_eq!(
+				fast_unstake_events_since_last_call(),
+				vec![
+					Event::BatchChecked { eras: vec![3, 2] },
+					Event::Slashed { stash: exposed, amount: Deposit::get() },
+					Event::BatchFinished { size: 0 }
+				]
+			);
+			assert_eq!(ExposedEras::<T>::get(exposed), None);
+		});
+	}
+
 	#[test]
 	fn validators_cannot_bail() {
 		ExtBuilder::default().build_and_execute(|| {
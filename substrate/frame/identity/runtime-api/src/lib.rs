@@ -0,0 +1,49 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the FRAME identity pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+use pallet_identity::Data;
+
+sp_api::decl_runtime_apis! {
+	/// Runtime API for reading the identities registered in `pallet-identity`.
+	pub trait IdentityApi<AccountId, Registration>
+	where
+		AccountId: Codec,
+		Registration: Codec,
+	{
+		/// Returns up to `limit` registered identities in storage order.
+		///
+		/// Enumeration starts right after `start_key`, or at the beginning when it is `None`. Pass
+		/// the last account of a page as `start_key` to get the next page; an empty result means
+		/// there are no more identities.
+		fn identities(start_key: Option<AccountId>, limit: u32) -> Vec<(AccountId, Registration)>;
+
+		/// Returns the identity registered for `account`, if any.
+		fn identity_of(account: AccountId) -> Option<Registration>;
+
+		/// Returns the super-identity of `account` and the name of the sub-account within it, if
+		/// `account` is a sub-account.
+		fn super_of(account: AccountId) -> Option<(AccountId, Data)>;
+	}
+}
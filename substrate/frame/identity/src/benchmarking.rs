@@ -21,12 +21,17 @@
 
 use super::*;
 
-use crate::{migration::v2::LazyMigrationV1ToV2, Pallet as Identity};
+use crate::{
+	migration::{
+		held_deposits::IdentityDeposits, v2::LazyMigrationV1ToV2, v4::LazyMigrationV3ToV4,
+	},
+	Pallet as Identity,
+};
 use alloc::{vec, vec::Vec};
 use frame_benchmarking::{account, v2::*, whitelisted_caller, BenchmarkError};
 use frame_support::{
 	assert_ok, ensure,
-	traits::{EnsureOrigin, Get, OnFinalize, OnInitialize},
+	traits::{EnsureOrigin, Get, HeldDeposits, OnFinalize, OnInitialize},
 };
 use frame_system::RawOrigin;
 use sp_io::crypto::{sr25519_generate, sr25519_sign};
@@ -66,7 +71,7 @@ fn add_registrars<T: Config>(r: u32) -> Result<(), &'static str> {
 		let registrar_origin = T::RegistrarOrigin::try_successful_origin()
 			.expect("RegistrarOrigin has no successful origin required for the benchmark");
 		Identity::<T>::add_registrar(registrar_origin, registrar_lookup)?;
-		Identity::<T>::set_fee(RawOrigin::Signed(registrar.clone()).into(), i, 10u32.into())?;
+		Identity::<T>::set_fee(RawOrigin::Signed(registrar.clone()).into(), i, 10u32.into(), None)?;
 		let fields = T::IdentityInformation::all_fields();
 		Identity::<T>::set_fields(RawOrigin::Signed(registrar.clone()).into(), i, fields)?;
 	}
@@ -114,6 +119,26 @@ fn add_sub_accounts<T: Config>(
 	Ok(subs)
 }
 
+// Invites `n` accounts to become self-funded sub-accounts of `who`, which must be able to hold
+// sub-accounts. Returns the invited accounts.
+fn add_sub_invites<T: Config>(
+	who: &T::AccountId,
+	n: u32,
+) -> Result<Vec<T::AccountId>, &'static str> {
+	let data = Data::Raw(vec![0; 32].try_into().unwrap());
+	let mut invited = Vec::new();
+	for i in 0..n {
+		let sub: T::AccountId = account("invited", i, SEED);
+		Identity::<T>::invite_sub(
+			RawOrigin::Signed(who.clone()).into(),
+			T::Lookup::unlookup(sub.clone()),
+			data.clone(),
+		)?;
+		invited.push(sub);
+	}
+	Ok(invited)
+}
+
 fn bench_suffix() -> Vec<u8> {
 	b"bench".to_vec()
 }
@@ -123,6 +148,59 @@ fn bench_username() -> Vec<u8> {
 	b"veryfastbenchmarkmachine".to_vec()
 }
 
+// Adds `registrar` as the registrar at index `index`, with an attester, and returns an account
+// with an identity for it to challenge.
+fn challenged_identity<T: Config>(
+	registrar: &T::AccountId,
+	index: RegistrarIndex,
+) -> Result<T::AccountId, &'static str> {
+	let registrar_origin = T::RegistrarOrigin::try_successful_origin()
+		.expect("RegistrarOrigin has no successful origin required for the benchmark");
+	Identity::<T>::add_registrar(registrar_origin, T::Lookup::unlookup(registrar.clone()))?;
+	Identity::<T>::set_attester(
+		RawOrigin::Signed(registrar.clone()).into(),
+		index,
+		Some(T::AttesterId::generate_pair(None)),
+	)?;
+
+	let target: T::AccountId = account("target", 0, SEED);
+	let _ = T::Currency::make_free_balance_be(&target, BalanceOf::<T>::max_value() / 2u32.into());
+	let info = T::IdentityInformation::create_identity_info();
+	Identity::<T>::set_identity(RawOrigin::Signed(target.clone()).into(), Box::new(info))?;
+	Ok(target)
+}
+
+// Returns an account with an identity judged by each of the `r` registrars added by
+// `add_registrars`.
+fn judged_identity<T: Config>(r: u32) -> Result<T::AccountId, &'static str> {
+	let target: T::AccountId = account("target", 0, SEED);
+	let target_origin: <T as frame_system::Config>::RuntimeOrigin =
+		RawOrigin::Signed(target.clone()).into();
+	let _ = T::Currency::make_free_balance_be(&target, BalanceOf::<T>::max_value() / 2u32.into());
+
+	let info = T::IdentityInformation::create_identity_info();
+	Identity::<T>::set_identity(target_origin.clone(), Box::new(info.clone()))?;
+	for i in 0..r {
+		let registrar: T::AccountId = account("registrar", i, SEED);
+		Identity::<T>::request_judgement(target_origin.clone(), i, 10u32.into(), None, None)?;
+		Identity::<T>::provide_judgement(
+			RawOrigin::Signed(registrar).into(),
+			i,
+			T::Lookup::unlookup(target.clone()),
+			Judgement::Reasonable,
+			T::Hashing::hash_of(&info),
+		)?;
+	}
+	Ok(target)
+}
+
+// A display name of maximum length, distinct for each `i`.
+fn bench_name(i: u8) -> DisplayName {
+	let mut name = vec![b'n'; 32];
+	name[31] = i;
+	name.try_into().expect("name is 32 bytes; qed")
+}
+
 fn bounded_username<T: Config>(username: Vec<u8>, suffix: Vec<u8>) -> Username<T> {
 	let mut full_username = Vec::with_capacity(username.len() + suffix.len() + 1);
 	full_username.extend(username);
@@ -175,7 +253,7 @@ mod benchmarks {
 			let balance_to_use = T::Currency::minimum_balance() * 10u32.into();
 			let _ = T::Currency::make_free_balance_be(&registrar, balance_to_use);
 
-			Identity::<T>::request_judgement(caller_origin.clone(), i, 10u32.into())?;
+			Identity::<T>::request_judgement(caller_origin.clone(), i, 10u32.into(), None, None)?;
 			Identity::<T>::provide_judgement(
 				RawOrigin::Signed(registrar).into(),
 				i,
@@ -185,49 +263,39 @@ mod benchmarks {
 			)?;
 		}
 
-		#[extrinsic_call]
-		_(
-			RawOrigin::Signed(caller.clone()),
-			Box::new(T::IdentityInformation::create_identity_info()),
-		);
-
-		assert_last_event::<T>(Event::<T>::IdentitySet { who: caller }.into());
-		Ok(())
-	}
-
-	// We need to split `set_subs` into two benchmarks to accurately isolate the potential
-	// writes caused by new or old sub accounts. The actual weight should simply be
-	// the sum of these two weights.
-	#[benchmark]
-	fn set_subs_new(s: Linear<0, { T::MaxSubAccounts::get() }>) -> Result<(), BenchmarkError> {
-		let caller: T::AccountId = whitelisted_caller();
-
-		// Create a new subs vec with sub accounts
-		let subs = create_sub_accounts::<T>(&caller, s)?;
-		ensure!(SubsOf::<T>::get(&caller).1.len() == 0, "Caller already has subs");
+		let info = T::IdentityInformation::create_identity_info();
+		let identity = T::Hashing::hash_of(&info);
+		let fields = info.registered_fields();
 
 		#[extrinsic_call]
-		set_subs(RawOrigin::Signed(caller.clone()), subs);
+		_(RawOrigin::Signed(caller.clone()), Box::new(info));
 
-		ensure!(SubsOf::<T>::get(&caller).1.len() as u32 == s, "Subs not added");
+		assert_last_event::<T>(Event::<T>::IdentitySet { who: caller, identity, fields }.into());
 		Ok(())
 	}
 
+	// Replaces `r` sub-accounts with `a` others, so that every sub-account is written. Kept
+	// sub-accounts are only read, which `set_subs` accounts for separately.
 	#[benchmark]
-	fn set_subs_old(p: Linear<0, { T::MaxSubAccounts::get() }>) -> Result<(), BenchmarkError> {
+	fn set_subs_diff(
+		a: Linear<0, { T::MaxSubAccounts::get() }>,
+		r: Linear<0, { T::MaxSubAccounts::get() }>,
+	) -> Result<(), BenchmarkError> {
 		let caller: T::AccountId = whitelisted_caller();
 
-		// Give them p many previous sub accounts.
-		let _ = add_sub_accounts::<T>(&caller, p)?;
+		// Give them r many previous sub accounts.
+		let _ = add_sub_accounts::<T>(&caller, r)?;
+		ensure!(SubsOf::<T>::get(&caller).1.len() as u32 == r, "Caller does not have subs");
 
-		// Remove all subs.
-		let subs = create_sub_accounts::<T>(&caller, 0)?;
-		ensure!(SubsOf::<T>::get(&caller).1.len() as u32 == p, "Caller does have subs",);
+		// And replace them with a many others.
+		let data = Data::Raw(vec![1; 32].try_into().unwrap());
+		let subs: Vec<(T::AccountId, Data)> =
+			(0..a).map(|i| (account("new_sub", i, SEED), data.clone())).collect();
 
 		#[extrinsic_call]
 		set_subs(RawOrigin::Signed(caller.clone()), subs);
 
-		ensure!(SubsOf::<T>::get(&caller).1.len() == 0, "Subs not removed");
+		ensure!(SubsOf::<T>::get(&caller).1.len() as u32 == a, "Subs not replaced");
 		Ok(())
 	}
 
@@ -245,12 +313,16 @@ mod benchmarks {
 		// Register the registrars
 		add_registrars::<T>(r)?;
 
-		// Add sub accounts
+		// Add sub accounts and the worst case of pending invitations
 		let _ = add_sub_accounts::<T>(&caller, s)?;
+		let _ = add_sub_invites::<T>(&caller, T::MaxSubAccounts::get())?;
 
 		// Create their main identity with x additional fields
 		let info = T::IdentityInformation::create_identity_info();
 		Identity::<T>::set_identity(caller_origin.clone(), Box::new(info.clone()))?;
+		Identity::<T>::claim_name(caller_origin.clone(), bench_name(0))?;
+		let manager = T::Lookup::unlookup(account("manager", 0, SEED));
+		Identity::<T>::authorize_manager(caller_origin.clone(), manager)?;
 
 		// User requests judgement from all the registrars, and they approve
 		for i in 0..r {
@@ -258,7 +330,7 @@ mod benchmarks {
 			let balance_to_use = T::Currency::minimum_balance() * 10u32.into();
 			let _ = T::Currency::make_free_balance_be(&registrar, balance_to_use);
 
-			Identity::<T>::request_judgement(caller_origin.clone(), i, 10u32.into())?;
+			Identity::<T>::request_judgement(caller_origin.clone(), i, 10u32.into(), None, None)?;
 			Identity::<T>::provide_judgement(
 				RawOrigin::Signed(registrar).into(),
 				i,
@@ -274,6 +346,7 @@ mod benchmarks {
 		_(RawOrigin::Signed(caller.clone()));
 
 		ensure!(!IdentityOf::<T>::contains_key(&caller), "Identity not cleared.");
+		ensure!(!PendingSubsOf::<T>::contains_key(&caller), "Invitations not cleared.");
 		Ok(())
 	}
 
@@ -291,8 +364,19 @@ mod benchmarks {
 			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
 		Identity::<T>::set_identity(caller_origin.clone(), Box::new(info))?;
 
+		// Request a tier of the fee schedule, so that the requested fields are recorded.
+		let registrar: T::AccountId = account("registrar", r - 1, SEED);
+		let tier =
+			<T::IdentityInformation as IdentityInformationProvider>::FieldsIdentifier::default();
+		Identity::<T>::set_fee(
+			RawOrigin::Signed(registrar).into(),
+			r - 1,
+			10u32.into(),
+			Some(tier.clone()),
+		)?;
+
 		#[extrinsic_call]
-		_(RawOrigin::Signed(caller.clone()), r - 1, 10u32.into());
+		_(RawOrigin::Signed(caller.clone()), r - 1, 10u32.into(), Some(tier), None);
 
 		assert_last_event::<T>(
 			Event::<T>::JudgementRequested { who: caller, registrar_index: r - 1 }.into(),
@@ -315,7 +399,7 @@ mod benchmarks {
 			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
 		Identity::<T>::set_identity(caller_origin.clone(), Box::new(info))?;
 
-		Identity::<T>::request_judgement(caller_origin.clone(), r - 1, 10u32.into())?;
+		Identity::<T>::request_judgement(caller_origin.clone(), r - 1, 10u32.into(), None, None)?;
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()), r - 1);
@@ -342,7 +426,7 @@ mod benchmarks {
 		ensure!(registrars[r as usize].as_ref().unwrap().fee == 0u32.into(), "Fee already set.");
 
 		#[extrinsic_call]
-		_(RawOrigin::Signed(caller), r, 100u32.into());
+		_(RawOrigin::Signed(caller), r, 100u32.into(), None);
 
 		let updated_registrars = Registrars::<T>::get();
 		ensure!(
@@ -432,18 +516,32 @@ mod benchmarks {
 
 		let info = T::IdentityInformation::create_identity_info();
 		let info_hash = T::Hashing::hash_of(&info);
+		let fields = info.registered_fields();
 		Identity::<T>::set_identity(user_origin.clone(), Box::new(info))?;
 
 		let registrar_origin = T::RegistrarOrigin::try_successful_origin()
 			.expect("RegistrarOrigin has no successful origin required for the benchmark");
 		Identity::<T>::add_registrar(registrar_origin, caller_lookup)?;
-		Identity::<T>::request_judgement(user_origin, r, 10u32.into())?;
+		// Request a tier of the fee schedule, so that the requested fields are checked.
+		Identity::<T>::set_fee(
+			RawOrigin::Signed(caller.clone()).into(),
+			r,
+			10u32.into(),
+			Some(fields.clone()),
+		)?;
+		Identity::<T>::request_judgement(user_origin, r, 10u32.into(), Some(fields.clone()), None)?;
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller), r, user_lookup, Judgement::Reasonable, info_hash);
 
 		assert_last_event::<T>(
-			Event::<T>::JudgementGiven { target: user, registrar_index: r }.into(),
+			Event::<T>::JudgementGiven {
+				target: user,
+				registrar_index: r,
+				identity: info_hash,
+				fields,
+			}
+			.into(),
 		);
 
 		Ok(())
@@ -464,7 +562,11 @@ mod benchmarks {
 
 		let info = T::IdentityInformation::create_identity_info();
 		Identity::<T>::set_identity(target_origin.clone(), Box::new(info.clone()))?;
+		Identity::<T>::claim_name(target_origin.clone(), bench_name(0))?;
+		let manager = T::Lookup::unlookup(account("manager", 0, SEED));
+		Identity::<T>::authorize_manager(target_origin.clone(), manager)?;
 		let _ = add_sub_accounts::<T>(&target, s)?;
+		let _ = add_sub_invites::<T>(&target, T::MaxSubAccounts::get())?;
 
 		// User requests judgement from all the registrars, and they approve
 		for i in 0..r {
@@ -472,7 +574,7 @@ mod benchmarks {
 			let balance_to_use = T::Currency::minimum_balance() * 10u32.into();
 			let _ = T::Currency::make_free_balance_be(&registrar, balance_to_use);
 
-			Identity::<T>::request_judgement(target_origin.clone(), i, 10u32.into())?;
+			Identity::<T>::request_judgement(target_origin.clone(), i, 10u32.into(), None, None)?;
 			Identity::<T>::provide_judgement(
 				RawOrigin::Signed(registrar).into(),
 				i,
@@ -487,10 +589,14 @@ mod benchmarks {
 		let origin =
 			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 
+		// Paying a reporter is the more expensive way to kill an identity.
+		let reporter = T::Lookup::unlookup(account("reporter", 0, SEED));
+
 		#[extrinsic_call]
-		_(origin as T::RuntimeOrigin, target_lookup);
+		kill_identity_with_reporter(origin as T::RuntimeOrigin, target_lookup, reporter);
 
 		ensure!(!IdentityOf::<T>::contains_key(&target), "Identity not removed");
+		ensure!(!PendingSubsOf::<T>::contains_key(&target), "Invitations not cleared.");
 
 		Ok(())
 	}
@@ -904,7 +1010,7 @@ mod benchmarks {
 		{
 			LazyMigrationV1ToV2::<T>::identity_step(None);
 		}
-		assert!(IdentityOf::<T>::get(&setup.account).is_some());
+		assert!(IdentityOf::<T>::contains_key(&setup.account));
 		Ok(())
 	}
 
@@ -941,5 +1047,594 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn migration_v4_registrars_step() -> Result<(), BenchmarkError> {
+		LazyMigrationV3ToV4::<T>::setup_benchmark_env_for_migration();
+		#[block]
+		{
+			LazyMigrationV3ToV4::<T>::registrars_step();
+		}
+		assert_eq!(Registrars::<T>::get().len() as u32, T::MaxRegistrars::get());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn migration_v4_identity_step() -> Result<(), BenchmarkError> {
+		let account = LazyMigrationV3ToV4::<T>::setup_benchmark_env_for_migration();
+		#[block]
+		{
+			LazyMigrationV3ToV4::<T>::identity_step(None);
+		}
+		assert!(IdentityOf::<T>::get(&account).is_some_and(|id| !id.frozen));
+		assert_eq!(PendingRequestsOf::<T>::get(0), 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn held_deposits_step() -> Result<(), BenchmarkError> {
+		// Worst case: the only deposit is the one of a pending username, which is held by the
+		// authority of its suffix.
+		let authority: T::AccountId = account("authority", 0, SEED);
+		let suffix: Suffix<T> = bench_suffix().try_into().unwrap();
+		AuthorityOf::<T>::insert(
+			&suffix,
+			AuthorityProperties { account_id: authority.clone(), allocation: 0 },
+		);
+		let username: Username<T> =
+			[bench_username(), b".".to_vec(), bench_suffix()].concat().try_into().unwrap();
+		let deposit: BalanceOf<T> = 10u32.into();
+		let target: T::AccountId = account("target", 0, SEED);
+		PendingUsernames::<T>::insert(
+			&username,
+			(target, Zero::zero(), Provider::AuthorityDeposit(deposit)),
+		);
+
+		let next;
+		#[block]
+		{
+			next = IdentityDeposits::<T>::next_deposit(None);
+		}
+
+		assert!(next.is_some());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn claim_name() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_origin: <T as frame_system::Config>::RuntimeOrigin =
+			RawOrigin::Signed(caller.clone()).into();
+		let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+		let info = T::IdentityInformation::create_identity_info();
+		Identity::<T>::set_identity(caller_origin.clone(), Box::new(info))?;
+		// Worst case: the previously claimed name must be released.
+		Identity::<T>::claim_name(caller_origin, bench_name(0))?;
+		let name = bench_name(1);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), name.clone());
+
+		assert_eq!(Identity::<T>::account_of_name(&name), Some(caller));
+		assert_eq!(Identity::<T>::account_of_name(&bench_name(0)), None);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn release_name() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_origin: <T as frame_system::Config>::RuntimeOrigin =
+			RawOrigin::Signed(caller.clone()).into();
+		let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+		let info = T::IdentityInformation::create_identity_info();
+		Identity::<T>::set_identity(caller_origin.clone(), Box::new(info))?;
+		Identity::<T>::claim_name(caller_origin, bench_name(0))?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		ensure!(!NameOf::<T>::contains_key(&caller), "Name not released.");
+		Ok(())
+	}
+
+	#[benchmark]
+	fn authorize_manager() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_origin: <T as frame_system::Config>::RuntimeOrigin =
+			RawOrigin::Signed(caller.clone()).into();
+		let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let info = T::IdentityInformation::create_identity_info();
+		Identity::<T>::set_identity(caller_origin, Box::new(info))?;
+		let manager: T::AccountId = account("manager", 0, SEED);
+		let manager_lookup = T::Lookup::unlookup(manager.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), manager_lookup);
+
+		assert_last_event::<T>(Event::<T>::ManagerAuthorized { main: caller, manager }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn revoke_manager() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_origin: <T as frame_system::Config>::RuntimeOrigin =
+			RawOrigin::Signed(caller.clone()).into();
+		let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let info = T::IdentityInformation::create_identity_info();
+		Identity::<T>::set_identity(caller_origin.clone(), Box::new(info))?;
+		let manager: T::AccountId = account("manager", 0, SEED);
+		Identity::<T>::authorize_manager(caller_origin, T::Lookup::unlookup(manager.clone()))?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+
+		assert_last_event::<T>(Event::<T>::ManagerRevoked { main: caller, manager }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn poke_deposit() -> Result<(), BenchmarkError> {
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_origin: <T as frame_system::Config>::RuntimeOrigin =
+			RawOrigin::Signed(target.clone()).into();
+		let _ =
+			T::Currency::make_free_balance_be(&target, BalanceOf::<T>::max_value() / 2u32.into());
+		let info = T::IdentityInformation::create_identity_info();
+		Identity::<T>::set_identity(target_origin, Box::new(info))?;
+		let _ = add_sub_accounts::<T>(&target, T::MaxSubAccounts::get())?;
+
+		// Worst case: the deposit decreases and the caller is rewarded.
+		let excess = T::Currency::minimum_balance() * 100u32.into();
+		T::Currency::reserve(&target, excess)?;
+		IdentityOf::<T>::mutate(&target, |id| {
+			if let Some(id) = id {
+				id.deposit = id.deposit.saturating_add(excess);
+			}
+		});
+		let caller: T::AccountId = whitelisted_caller();
+		let _ = T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), T::Lookup::unlookup(target.clone()));
+
+		ensure!(
+			IdentityOf::<T>::get(&target).map(|id| id.deposit) ==
+				Some(Identity::<T>::calculate_identity_deposit(
+					&T::IdentityInformation::create_identity_info()
+				)),
+			"Deposit not poked."
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn invite_sub() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let _ = add_sub_accounts::<T>(&caller, 0)?;
+		// Worst case: the invitation is appended to a list that is one short of full.
+		let _ = add_sub_invites::<T>(&caller, T::MaxSubAccounts::get() - 1)?;
+		let sub: T::AccountId = account("new_sub", 0, SEED);
+		let data = Data::Raw(vec![0; 32].try_into().unwrap());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(sub.clone()), data);
+
+		ensure!(
+			PendingSubsOf::<T>::get(&caller).1.iter().any(|(invited, _)| *invited == sub),
+			"Sub not invited."
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_sub_with_own_deposit(
+		s: Linear<0, { T::MaxSubAccounts::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let main: T::AccountId = account("super", 0, SEED);
+		let _ = add_sub_accounts::<T>(&main, s)?;
+		let caller: T::AccountId = whitelisted_caller();
+		let _ =
+			T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		Identity::<T>::invite_sub(
+			RawOrigin::Signed(main.clone()).into(),
+			T::Lookup::unlookup(caller.clone()),
+			Data::Raw(vec![0; 32].try_into().unwrap()),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(main.clone()));
+
+		ensure!(SubsOf::<T>::get(&main).1.len() as u32 == s + 1, "Sub not added.");
+		ensure!(SubDepositOf::<T>::contains_key(&caller), "Deposit not recorded.");
+		ensure!(!PendingSubsOf::<T>::contains_key(&main), "Invitation not removed.");
+		Ok(())
+	}
+
+	#[benchmark]
+	fn provide_judgement_with_evidence(
+		r: Linear<1, { T::MaxRegistrars::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let user: T::AccountId = account("user", r, SEED);
+		let user_origin =
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(user.clone()));
+		let user_lookup = <T::Lookup as StaticLookup>::unlookup(user.clone());
+		let _ = T::Currency::make_free_balance_be(&user, BalanceOf::<T>::max_value());
+
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+		add_registrars::<T>(r)?;
+
+		let info = T::IdentityInformation::create_identity_info();
+		let info_hash = T::Hashing::hash_of(&info);
+		let fields = info.registered_fields();
+		Identity::<T>::set_identity(user_origin.clone(), Box::new(info))?;
+
+		let registrar_origin = T::RegistrarOrigin::try_successful_origin()
+			.expect("RegistrarOrigin has no successful origin required for the benchmark");
+		Identity::<T>::add_registrar(registrar_origin, caller_lookup)?;
+		// Request a tier of the fee schedule, so that the requested fields are checked.
+		Identity::<T>::set_fee(
+			RawOrigin::Signed(caller.clone()).into(),
+			r,
+			10u32.into(),
+			Some(fields.clone()),
+		)?;
+		Identity::<T>::request_judgement(user_origin, r, 10u32.into(), Some(fields), None)?;
+		let evidence: Evidence = vec![1; 64].try_into().unwrap();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			r,
+			user_lookup,
+			Judgement::Reasonable,
+			info_hash,
+			evidence.clone(),
+		);
+
+		assert_last_event::<T>(
+			Event::<T>::EvidenceProvided { target: user, registrar_index: r, evidence }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn dispute_evidence() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_origin =
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+		let _ = T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+		let info = T::IdentityInformation::create_identity_info();
+		let info_hash = T::Hashing::hash_of(&info);
+		Identity::<T>::set_identity(caller_origin, Box::new(info))?;
+
+		add_registrars::<T>(1)?;
+		let registrar: T::AccountId = account("registrar", 0, SEED);
+		Identity::<T>::provide_judgement_with_evidence(
+			RawOrigin::Signed(registrar).into(),
+			0,
+			T::Lookup::unlookup(caller.clone()),
+			Judgement::Reasonable,
+			info_hash,
+			vec![1; 64].try_into().unwrap(),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), 0);
+
+		assert_last_event::<T>(
+			Event::<T>::EvidenceDisputed { who: caller, registrar_index: 0 }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_identity(
+		r: Linear<1, { T::MaxRegistrars::get() }>,
+		s: Linear<0, { T::MaxSubAccounts::get() }>,
+	) -> Result<(), BenchmarkError> {
+		add_registrars::<T>(r)?;
+
+		let old: T::AccountId = account("target", 0, SEED);
+		let old_origin: <T as frame_system::Config>::RuntimeOrigin =
+			RawOrigin::Signed(old.clone()).into();
+		let old_lookup = T::Lookup::unlookup(old.clone());
+		let _ = T::Currency::make_free_balance_be(&old, BalanceOf::<T>::max_value() / 2u32.into());
+		let new: T::AccountId = account("new", 0, SEED);
+		let _ = T::Currency::make_free_balance_be(&new, T::Currency::minimum_balance());
+
+		let info = T::IdentityInformation::create_identity_info();
+		Identity::<T>::set_identity(old_origin.clone(), Box::new(info.clone()))?;
+		Identity::<T>::claim_name(old_origin.clone(), bench_name(0))?;
+		let manager = T::Lookup::unlookup(account("manager", 0, SEED));
+		Identity::<T>::authorize_manager(old_origin.clone(), manager)?;
+		let _ = add_sub_accounts::<T>(&old, s)?;
+
+		// Every registrar gives a judgement with evidence.
+		for i in 0..r {
+			let registrar: T::AccountId = account("registrar", i, SEED);
+			let balance_to_use = T::Currency::minimum_balance() * 10u32.into();
+			let _ = T::Currency::make_free_balance_be(&registrar, balance_to_use);
+
+			Identity::<T>::request_judgement(old_origin.clone(), i, 10u32.into(), None, None)?;
+			Identity::<T>::provide_judgement_with_evidence(
+				RawOrigin::Signed(registrar).into(),
+				i,
+				old_lookup.clone(),
+				Judgement::Reasonable,
+				T::Hashing::hash_of(&info),
+				vec![1; 64].try_into().unwrap(),
+			)?;
+		}
+
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, old_lookup, T::Lookup::unlookup(new.clone()));
+
+		ensure!(!IdentityOf::<T>::contains_key(&old), "Identity not removed");
+		ensure!(IdentityOf::<T>::contains_key(&new), "Identity not transferred");
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_attester(r: Linear<1, { T::MaxRegistrars::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		add_registrars::<T>(r)?;
+		let registrar_origin = T::RegistrarOrigin::try_successful_origin()
+			.expect("RegistrarOrigin has no successful origin required for the benchmark");
+		Identity::<T>::add_registrar(registrar_origin, T::Lookup::unlookup(caller.clone()))?;
+		let attester = T::AttesterId::generate_pair(None);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), r, Some(attester.clone()));
+
+		assert_last_event::<T>(
+			Event::<T>::AttesterSet { registrar_index: r, attester: Some(attester) }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_challenge(r: Linear<1, { T::MaxRegistrars::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		add_registrars::<T>(r)?;
+		let target = challenged_identity::<T>(&caller, r)?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			r,
+			T::Lookup::unlookup(target.clone()),
+			vec![b'u'; 256].try_into().unwrap(),
+			vec![b'n'; 64].try_into().unwrap(),
+		);
+
+		assert_last_event::<T>(Event::<T>::ChallengeSet { target, registrar_index: r }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_challenge(
+		r: Linear<1, { T::MaxRegistrars::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		add_registrars::<T>(r)?;
+		let target = challenged_identity::<T>(&caller, r)?;
+		Identity::<T>::set_challenge(
+			RawOrigin::Signed(caller.clone()).into(),
+			r,
+			T::Lookup::unlookup(target.clone()),
+			vec![b'u'; 256].try_into().unwrap(),
+			vec![b'n'; 64].try_into().unwrap(),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), r, T::Lookup::unlookup(target.clone()));
+
+		assert_last_event::<T>(Event::<T>::ChallengeRemoved { target, registrar_index: r }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn submit_attestation() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let target = challenged_identity::<T>(&caller, 0)?;
+		let nonce: offchain::ChallengeNonce = vec![b'n'; 64].try_into().unwrap();
+		Identity::<T>::set_challenge(
+			RawOrigin::Signed(caller).into(),
+			0,
+			T::Lookup::unlookup(target.clone()),
+			vec![b'u'; 256].try_into().unwrap(),
+			nonce.clone(),
+		)?;
+		let attestation =
+			offchain::Attestation { target: target.clone(), registrar_index: 0, nonce };
+		let signature = AttesterOf::<T>::get(0)
+			.and_then(|attester| attester.sign(&attestation.encode()))
+			.ok_or("Failed to sign the attestation")?;
+
+		#[extrinsic_call]
+		_(RawOrigin::None, attestation, signature);
+
+		assert_last_event::<T>(Event::<T>::ChallengeVerified { target, registrar_index: 0 }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_max_pending(
+		r: Linear<1, { T::MaxRegistrars::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+
+		add_registrars::<T>(r)?;
+
+		let registrar_origin = T::RegistrarOrigin::try_successful_origin()
+			.expect("RegistrarOrigin has no successful origin required for the benchmark");
+		Identity::<T>::add_registrar(registrar_origin, caller_lookup)?;
+
+		let registrars = Registrars::<T>::get();
+		ensure!(
+			registrars[r as usize].as_ref().unwrap().max_pending.is_none(),
+			"Limit already set."
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), r, Some(10));
+
+		let updated_registrars = Registrars::<T>::get();
+		ensure!(
+			updated_registrars[r as usize].as_ref().unwrap().max_pending == Some(10),
+			"Limit not changed."
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn freeze_identity(r: Linear<1, { T::MaxRegistrars::get() }>) -> Result<(), BenchmarkError> {
+		add_registrars::<T>(r)?;
+		let target = judged_identity::<T>(r)?;
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, T::Lookup::unlookup(target.clone()));
+
+		assert_last_event::<T>(Event::<T>::IdentityFrozen { who: target }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn thaw_identity(r: Linear<1, { T::MaxRegistrars::get() }>) -> Result<(), BenchmarkError> {
+		add_registrars::<T>(r)?;
+		let target = judged_identity::<T>(r)?;
+		let origin =
+			T::ForceOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Identity::<T>::freeze_identity(origin.clone(), T::Lookup::unlookup(target.clone()))?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, T::Lookup::unlookup(target.clone()));
+
+		assert_last_event::<T>(Event::<T>::IdentityThawed { who: target }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_registrar(
+		r: Linear<1, { T::MaxRegistrars::get() }>,
+		e: Linear<0, 1000>,
+	) -> Result<(), BenchmarkError> {
+		add_registrars::<T>(r)?;
+		// Worst case: every entry belongs to the removed registrar, half of them challenges and
+		// half of them evidences.
+		for i in 0..e {
+			let who: T::AccountId = account("judged", i, SEED);
+			if i % 2 == 0 {
+				ChallengeOf::<T>::insert(
+					&who,
+					r - 1,
+					offchain::Challenge {
+						url: vec![b'u'; 256].try_into().unwrap(),
+						nonce: vec![b'n'; 64].try_into().unwrap(),
+						verified: false,
+					},
+				);
+			} else {
+				EvidenceOf::<T>::insert(
+					&who,
+					r - 1,
+					JudgementEvidence {
+						evidence: vec![b'e'; 64].try_into().unwrap(),
+						disputed: false,
+					},
+				);
+			}
+		}
+		let origin =
+			T::RegistrarOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, r - 1, e);
+
+		assert_last_event::<T>(Event::<T>::RegistrarRemoved { registrar_index: r - 1 }.into());
+		assert_eq!(ChallengeOf::<T>::iter().count() + EvidenceOf::<T>::iter().count(), 0);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reclaim_fee(r: Linear<1, { T::MaxRegistrars::get() - 1 }>) -> Result<(), BenchmarkError> {
+		add_registrars::<T>(r + 1)?;
+		let target = judged_identity::<T>(r)?;
+		Identity::<T>::request_judgement(
+			RawOrigin::Signed(target.clone()).into(),
+			r,
+			10u32.into(),
+			None,
+			None,
+		)?;
+		let origin =
+			T::RegistrarOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Identity::<T>::remove_registrar(origin, r, 0)?;
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), T::Lookup::unlookup(target.clone()), r);
+
+		assert_last_event::<T>(
+			Event::<T>::FeeReclaimed { target, registrar_index: r, fee: 10u32.into() }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn expire_request(r: Linear<1, { T::MaxRegistrars::get() - 1 }>) -> Result<(), BenchmarkError> {
+		add_registrars::<T>(r + 1)?;
+		let target = judged_identity::<T>(r)?;
+		let valid_until = frame_system::Pallet::<T>::block_number() + One::one();
+		Identity::<T>::request_judgement(
+			RawOrigin::Signed(target.clone()).into(),
+			r,
+			10u32.into(),
+			None,
+			Some(valid_until),
+		)?;
+		frame_system::Pallet::<T>::set_block_number(valid_until + One::one());
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), T::Lookup::unlookup(target.clone()), r);
+
+		assert_last_event::<T>(
+			Event::<T>::JudgementRequestExpired { target, registrar_index: r, fee: 10u32.into() }
+				.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_sub_invite(s: Linear<1, { T::MaxSubAccounts::get() }>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let _ = add_sub_accounts::<T>(&caller, 0)?;
+		let invited = add_sub_invites::<T>(&caller, s)?;
+		// Cancel the last invitation, which has to be searched for through the whole list.
+		let sub = invited.last().expect("at least one account is invited; qed").clone();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), T::Lookup::unlookup(sub.clone()));
+
+		ensure!(
+			!PendingSubsOf::<T>::get(&caller).1.iter().any(|(invited, _)| *invited == sub),
+			"Invitation not cancelled."
+		);
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Identity, crate::tests::new_test_ext(), crate::tests::Test);
 }
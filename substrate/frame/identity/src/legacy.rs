@@ -21,11 +21,14 @@ use codec::{Decode, Encode, MaxEncodedLen};
 #[cfg(feature = "runtime-benchmarks")]
 use enumflags2::BitFlag;
 use enumflags2::{bitflags, BitFlags};
-use frame_support::{traits::Get, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound};
+use frame_support::{
+	traits::{ConstU32, Get},
+	CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
 use scale_info::{build::Variants, Path, Type, TypeInfo};
 use sp_runtime::{BoundedVec, RuntimeDebug};
 
-use crate::types::{Data, IdentityInformationProvider};
+use crate::types::{BoundedData, IdentityInformationProvider, INLINE_RAW_DATA_LENGTH};
 
 /// The fields that we use to identify the owner of an account with. Each corresponds to a field
 /// in the `IdentityInfo` struct.
@@ -65,6 +68,9 @@ impl TypeInfo for IdentityField {
 ///
 /// NOTE: This should be stored at the end of the storage item to facilitate the addition of extra
 /// fields in a backwards compatible way through a specialized `Decode` impl.
+///
+/// `MaxRawLength` bounds the raw data of each field. Raising it from the default of 32 bytes keeps
+/// the encoding of all stored information, so it needs no storage migration.
 #[derive(
 	CloneNoBound,
 	Encode,
@@ -76,63 +82,72 @@ impl TypeInfo for IdentityField {
 	TypeInfo,
 )]
 #[codec(mel_bound())]
-#[scale_info(skip_type_params(FieldLimit))]
-pub struct IdentityInfo<FieldLimit: Get<u32>> {
+#[scale_info(skip_type_params(FieldLimit, MaxRawLength))]
+pub struct IdentityInfo<
+	FieldLimit: Get<u32>,
+	MaxRawLength: Get<u32> = ConstU32<INLINE_RAW_DATA_LENGTH>,
+> {
 	/// Additional fields of the identity that are not catered for with the struct's explicit
 	/// fields.
-	pub additional: BoundedVec<(Data, Data), FieldLimit>,
+	pub additional: BoundedVec<(BoundedData<MaxRawLength>, BoundedData<MaxRawLength>), FieldLimit>,
 
 	/// A reasonable display name for the controller of the account. This should be whatever it is
 	/// that it is typically known as and should not be confusable with other entities, given
 	/// reasonable context.
 	///
 	/// Stored as UTF-8.
-	pub display: Data,
+	pub display: BoundedData<MaxRawLength>,
 
 	/// The full legal name in the local jurisdiction of the entity. This might be a bit
 	/// long-winded.
 	///
 	/// Stored as UTF-8.
-	pub legal: Data,
+	pub legal: BoundedData<MaxRawLength>,
 
 	/// A representative website held by the controller of the account.
 	///
 	/// NOTE: `https://` is automatically prepended.
 	///
 	/// Stored as UTF-8.
-	pub web: Data,
+	pub web: BoundedData<MaxRawLength>,
 
 	/// The Riot/Matrix handle held by the controller of the account.
 	///
 	/// Stored as UTF-8.
-	pub riot: Data,
+	pub riot: BoundedData<MaxRawLength>,
 
 	/// The email address of the controller of the account.
 	///
 	/// Stored as UTF-8.
-	pub email: Data,
+	pub email: BoundedData<MaxRawLength>,
 
 	/// The PGP/GPG public key of the controller of the account.
 	pub pgp_fingerprint: Option<[u8; 20]>,
 
 	/// A graphic image representing the controller of the account. Should be a company,
 	/// organization or project logo or a headshot in the case of a human.
-	pub image: Data,
+	pub image: BoundedData<MaxRawLength>,
 
 	/// The Twitter identity. The leading `@` character may be elided.
-	pub twitter: Data,
+	pub twitter: BoundedData<MaxRawLength>,
 }
 
-impl<FieldLimit: Get<u32> + 'static> IdentityInformationProvider for IdentityInfo<FieldLimit> {
+impl<FieldLimit: Get<u32> + 'static, MaxRawLength: Get<u32> + 'static> IdentityInformationProvider
+	for IdentityInfo<FieldLimit, MaxRawLength>
+{
 	type FieldsIdentifier = u64;
 
 	fn has_identity(&self, fields: Self::FieldsIdentifier) -> bool {
 		self.fields().bits() & fields == fields
 	}
 
+	fn registered_fields(&self) -> Self::FieldsIdentifier {
+		self.fields().bits()
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	fn create_identity_info() -> Self {
-		let data = Data::Raw(vec![0; 32].try_into().unwrap());
+		let data = BoundedData::Raw(vec![0; MaxRawLength::get() as usize].try_into().unwrap());
 
 		IdentityInfo {
 			additional: vec![(data.clone(), data.clone()); FieldLimit::get().try_into().unwrap()]
@@ -155,23 +170,25 @@ impl<FieldLimit: Get<u32> + 'static> IdentityInformationProvider for IdentityInf
 	}
 }
 
-impl<FieldLimit: Get<u32>> Default for IdentityInfo<FieldLimit> {
+impl<FieldLimit: Get<u32>, MaxRawLength: Get<u32>> Default
+	for IdentityInfo<FieldLimit, MaxRawLength>
+{
 	fn default() -> Self {
 		IdentityInfo {
 			additional: BoundedVec::default(),
-			display: Data::None,
-			legal: Data::None,
-			web: Data::None,
-			riot: Data::None,
-			email: Data::None,
+			display: BoundedData::None,
+			legal: BoundedData::None,
+			web: BoundedData::None,
+			riot: BoundedData::None,
+			email: BoundedData::None,
 			pgp_fingerprint: None,
-			image: Data::None,
-			twitter: Data::None,
+			image: BoundedData::None,
+			twitter: BoundedData::None,
 		}
 	}
 }
 
-impl<FieldLimit: Get<u32>> IdentityInfo<FieldLimit> {
+impl<FieldLimit: Get<u32>, MaxRawLength: Get<u32>> IdentityInfo<FieldLimit, MaxRawLength> {
 	pub(crate) fn fields(&self) -> BitFlags<IdentityField> {
 		let mut res = BitFlags::<IdentityField>::empty();
 		if !self.display.is_none() {
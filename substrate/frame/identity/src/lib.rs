@@ -34,6 +34,11 @@
 //!
 //! A super-user can remove accounts and in doing so, slash the deposit.
 //!
+//! Deposits and judgement fees are held in the native `Currency`, unless the runtime configures a
+//! [`Config::DepositAsset`], e.g. a stablecoin held through [`HeldAsset`]. Changing
+//! `DepositAsset` on a live chain only takes effect through
+//! [`migration::deposit_asset::SwitchDepositAsset`], once the pallet holds no deposits anymore.
+//!
 //! All accounts may also have a limited number of sub-accounts which may be specified by the owner;
 //! by definition, these have equivalent ownership and each has an individual name.
 //!
@@ -63,6 +68,18 @@
 //! only map to a single username, known as the _primary_. This primary username will be the result
 //! of a lookup in the [UsernameOf] map for any given account.
 //!
+//! ### Display Names
+//!
+//! Accounts with an identity can also claim a unique display name, charged a deposit per byte of
+//! the name. The hash of a claimed name resolves to the claiming account through the
+//! [AccountOfName] map. The name is released when the identity is cleared or killed.
+//!
+//! ### Verification by Other Pallets
+//!
+//! The pallet implements [`frame_support::traits::IdentityVerifier`], so that other pallets can
+//! require an account to have at least some judgement from one of a set of registrars without
+//! reading the storage of this pallet.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -77,6 +94,15 @@
 //! * `remove_expired_approval` - Remove a username that was issued but never accepted.
 //! * `set_primary_username` - Set a given username as an account's primary.
 //! * `remove_username` - Remove a username after its grace period has ended.
+//! * `claim_name` - Claim a unique display name for an account with an identity.
+//! * `release_name` - Release the display name of an account; the deposit is returned.
+//! * `authorize_manager` - Authorize an account to update the identity info and subs.
+//! * `revoke_manager` - Revoke the authorization of the identity manager.
+//! * `dispute_evidence` - Dispute the evidence a registrar attached to its judgement.
+//!
+//! #### For Identity Managers
+//! * `set_identity_for` - Set the identity info of the managed account.
+//! * `set_subs_for` - Set the sub-accounts of the managed account.
 //!
 //! #### For General Users with Sub-Identities
 //! * `set_subs` - Set the sub-accounts of an identity.
@@ -84,11 +110,28 @@
 //! * `remove_sub` - Remove a sub-identity of an identity.
 //! * `rename_sub` - Rename a sub-identity of an identity.
 //! * `quit_sub` - Remove a sub-identity of an identity (called by the sub-identity).
+//! * `invite_sub` - Invite an account to become a sub-identity that holds its own deposit.
+//! * `add_sub_with_own_deposit` - Accept an invitation to become a sub-identity, holding the
+//!   deposit (called by the sub-identity).
+//!
+//! #### For Anyone
+//! * `poke_deposit` - Update the deposits of an identity to the current deposit configuration.
+//! * `reclaim_fee` - Return the fee paid for a judgement request to a removed registrar.
+//! * `expire_request` - Return the fee paid for a judgement request whose deadline has passed.
 //!
 //! #### For Registrars
 //! * `set_fee` - Set the fee required to be paid for a judgement to be given by the registrar.
 //! * `set_fields` - Set the fields that a registrar cares about in their judgements.
 //! * `provide_judgement` - Provide a judgement to an identity.
+//! * `provide_judgement_with_evidence` - Provide a judgement to an identity along with a reference
+//!   to its off-chain evidence.
+//! * `set_attester` - Set the key with which off-chain workers attest the registrar's challenges.
+//! * `set_challenge` - Post a verification challenge for an identity.
+//! * `remove_challenge` - Remove a verification challenge.
+//!
+//! #### Unsigned
+//! * `submit_attestation` - Mark a verification challenge as verified, as attested by the
+//!   registrar's attester.
 //!
 //! #### For Username Authorities
 //! * `set_username_for` - Set a username for a given account. The account must approve it.
@@ -96,7 +139,12 @@
 //!
 //! #### For Superusers
 //! * `add_registrar` - Add a new registrar to the system.
+//! * `remove_registrar` - Remove a registrar from the system.
 //! * `kill_identity` - Forcibly remove the associated identity; the deposit is lost.
+//! * `kill_identity_with_reporter` - Forcibly remove the associated identity, paying part of the
+//!   deposit to the account that reported it.
+//! * `transfer_identity` - Forcibly move an identity, its sub-accounts and deposits to another
+//!   account.
 //! * `add_username_authority` - Add an account with the ability to issue usernames.
 //! * `remove_username_authority` - Remove an account with the ability to issue usernames.
 //! * `kill_username` - Forcibly remove a username.
@@ -109,9 +157,11 @@
 mod benchmarking;
 pub mod legacy;
 pub mod migration;
+pub mod offchain;
 #[cfg(test)]
 mod tests;
 mod types;
+pub mod view_functions;
 pub mod weights;
 
 extern crate alloc;
@@ -123,17 +173,22 @@ use frame_support::{
 	ensure,
 	pallet_prelude::{DispatchError, DispatchResult},
 	traits::{
-		BalanceStatus, Currency, Defensive, Get, OnUnbalanced, ReservableCurrency, StorageVersion,
+		Currency, Defensive, ExistenceRequirement::KeepAlive, Get, IdentityVerifier, Imbalance,
+		ManagedDeposit, OnUnbalanced, ReservableCurrency, StorageVersion, TrackDeposit,
 	},
+	weights::Weight,
 	BoundedVec,
 };
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
-use sp_runtime::traits::{
-	AppendZerosInput, Hash, IdentifyAccount, Saturating, StaticLookup, Verify, Zero,
+use sp_runtime::{
+	traits::{AppendZerosInput, Hash, IdentifyAccount, Saturating, StaticLookup, Verify, Zero},
+	Perbill, RuntimeAppPublic,
 };
 pub use types::{
-	Data, IdentityInformationProvider, Judgement, RegistrarIndex, RegistrarInfo, Registration,
+	BoundedData, Data, DepositAsset, DisplayName, Evidence, FeeSchedule, HeldAsset,
+	IdentityInformationProvider, Judgement, JudgementEvidence, OnIdentityChange, RegistrarIndex,
+	RegistrarInfo, Registration, INLINE_RAW_DATA_LENGTH,
 };
 pub use weights::WeightInfo;
 
@@ -143,7 +198,15 @@ type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
 	<T as frame_system::Config>::AccountId,
 >>::NegativeImbalance;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+/// The identity registration of an account, as kept in [`IdentityOf`].
+pub type RegistrationOf<T> =
+	Registration<BalanceOf<T>, <T as Config>::MaxRegistrars, <T as Config>::IdentityInformation>;
 type ProviderOf<T> = Provider<BalanceOf<T>>;
+type NativeDeposit<T> = ManagedDeposit<
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::Currency,
+	<T as Config>::DepositTracker,
+>;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -158,6 +221,19 @@ pub mod pallet {
 		/// The currency trait.
 		type Currency: ReservableCurrency<Self::AccountId>;
 
+		/// Tracks the deposits held by this pallet, e.g. to account for them across pallets.
+		/// Deposits held in `DepositAsset` are not tracked.
+		type DepositTracker: TrackDeposit<Self::AccountId, BalanceOf<Self>>;
+
+		/// The asset in which deposits and judgement fees are held instead of `Currency`, or `()`
+		/// to hold them in `Currency`. Slashed deposits held in an asset are burned rather than
+		/// handled by `Slashed`.
+		///
+		/// Deposits are held in this asset from genesis. A live chain keeps holding them where it
+		/// did until [`migration::deposit_asset::SwitchDepositAsset`] runs, see
+		/// [`DepositsInAsset`].
+		type DepositAsset: DepositAsset<Self::AccountId, BalanceOf<Self>>;
+
 		/// The amount held on deposit for a registered identity.
 		#[pallet::constant]
 		type BasicDeposit: Get<BalanceOf<Self>>;
@@ -177,10 +253,24 @@ pub mod pallet {
 		#[pallet::constant]
 		type SubAccountDeposit: Get<BalanceOf<Self>>;
 
+		/// The portion of the deposit excess released by [`Call::poke_deposit`] that is paid to
+		/// the caller, as an incentive to rebalance outdated deposits.
+		#[pallet::constant]
+		type PokeDepositReward: Get<Perbill>;
+
 		/// The maximum number of sub-accounts allowed per identified account.
 		#[pallet::constant]
 		type MaxSubAccounts: Get<u32>;
 
+		/// The maximum depth of a tree of sub-accounts below an identity. A sub-account less than
+		/// this many levels below an identity may have sub-accounts of its own, so `1` only
+		/// allows direct sub-accounts of identities.
+		///
+		/// Below its direct sub-accounts, the tree of an identity holds at most `MaxSubAccounts`
+		/// sub-accounts per further level, so that it can be removed along with the identity.
+		#[pallet::constant]
+		type MaxSubDepth: Get<u32>;
+
 		/// Structure holding information about an identity.
 		type IdentityInformation: IdentityInformationProvider;
 
@@ -189,9 +279,19 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxRegistrars: Get<u32>;
 
-		/// What to do with slashed funds.
+		/// Maximum number of sets of fields a registrar can publish a separate fee for, besides
+		/// the fee for all of its fields.
+		#[pallet::constant]
+		type MaxFeeTiers: Get<u32>;
+
+		/// What to do with slashed funds held in `Currency`.
 		type Slashed: OnUnbalanced<NegativeImbalanceOf<Self>>;
 
+		/// The portion of the deposit slashed by [`Call::kill_identity_with_reporter`] that is
+		/// handled by `Slashed`. The remainder is paid to the reporter.
+		#[pallet::constant]
+		type SlashPortion: Get<Perbill>;
+
 		/// The origin which may forcibly set or remove a name. Root can always do this.
 		type ForceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
@@ -226,11 +326,21 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxUsernameLength: Get<u32>;
 
+		/// Handler notified when an identity is set, cleared, killed or judged.
+		type OnIdentityChange: OnIdentityChange<Self::AccountId, BalanceOf<Self>>;
+
+		/// The key with which off-chain workers attest the verification challenges of a registrar.
+		type AttesterId: Member + Parameter + RuntimeAppPublic + MaxEncodedLen;
+
+		/// Submits the attestations made by off-chain workers. Set to `()` to disable the
+		/// off-chain verification of challenges.
+		type SubmitAttestation: offchain::SubmitAttestation<Self>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -241,13 +351,8 @@ pub mod pallet {
 	///
 	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
 	#[pallet::storage]
-	pub type IdentityOf<T: Config> = StorageMap<
-		_,
-		Twox64Concat,
-		T::AccountId,
-		Registration<BalanceOf<T>, T::MaxRegistrars, T::IdentityInformation>,
-		OptionQuery,
-	>;
+	pub type IdentityOf<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, RegistrationOf<T>, OptionQuery>;
 
 	/// Identifies the primary username of an account.
 	#[pallet::storage]
@@ -287,6 +392,7 @@ pub mod pallet {
 					BalanceOf<T>,
 					T::AccountId,
 					<T::IdentityInformation as IdentityInformationProvider>::FieldsIdentifier,
+					T::MaxFeeTiers,
 				>,
 			>,
 			T::MaxRegistrars,
@@ -294,6 +400,15 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The number of judgement requests with a fee paid that are open on each registrar, counted
+	/// against its `max_pending`.
+	///
+	/// Requests opened before this count was introduced are counted by the migration to storage
+	/// version 4.
+	#[pallet::storage]
+	pub type PendingRequestsOf<T: Config> =
+		StorageMap<_, Twox64Concat, RegistrarIndex, u32, ValueQuery>;
+
 	/// A map of the accounts who are authorized to grant usernames.
 	#[pallet::storage]
 	pub type AuthorityOf<T: Config> =
@@ -337,6 +452,137 @@ pub mod pallet {
 	pub type UnbindingUsernames<T: Config> =
 		StorageMap<_, Blake2_128Concat, Username<T>, BlockNumberFor<T>, OptionQuery>;
 
+	/// Reverse lookup from the hash of a claimed display name to the account that claimed it.
+	///
+	/// IDENTITY-NOTE: OK ― the key is the output of `T::Hashing`.
+	#[pallet::storage]
+	pub type AccountOfName<T: Config> = StorageMap<_, Identity, T::Hash, T::AccountId, OptionQuery>;
+
+	/// The display name claimed by an account. First item is the hash of the name, second is the
+	/// deposit held for it.
+	///
+	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type NameOf<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (T::Hash, BalanceOf<T>), OptionQuery>;
+
+	/// The account authorized by an identity to update its information and sub-accounts.
+	///
+	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type ManagerOf<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+	/// The deposit held by a sub-account for itself, if it was added through
+	/// [`Call::add_sub_with_own_deposit`] rather than funded by its super-identity.
+	#[pallet::storage]
+	pub type SubDepositOf<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, OptionQuery>;
+
+	/// The number of sub-accounts in the tree of an identity that are not its direct
+	/// sub-accounts. At most `MaxSubAccounts` times one less than `MaxSubDepth`.
+	///
+	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type NestedSubsOf<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Invitations to become a self-funded sub-account, keyed by the inviting account. The first
+	/// item is the deposit held from the inviter for the invitations, the second is the invited
+	/// accounts along with the names they are invited under.
+	///
+	/// TWOX-NOTE: OK ― `AccountId` is a secure hash.
+	#[pallet::storage]
+	pub type PendingSubsOf<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		(BalanceOf<T>, BoundedVec<(T::AccountId, Data), T::MaxSubAccounts>),
+		ValueQuery,
+	>;
+
+	/// Off-chain evidence attached to a judgement, keyed by the judged account and the index of the
+	/// registrar that gave the judgement.
+	#[pallet::storage]
+	pub type EvidenceOf<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		RegistrarIndex,
+		JudgementEvidence,
+		OptionQuery,
+	>;
+
+	/// The block until which a judgement request with a fee paid is valid, keyed by the requesting
+	/// account and the index of the registrar. Requests without a deadline have no entry.
+	#[pallet::storage]
+	pub type RequestDeadlineOf<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		RegistrarIndex,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// The set of fields a judgement request with a fee paid was priced for, keyed by the
+	/// requesting account and the index of the registrar. Requests for all of the registrar's
+	/// fields have no entry.
+	#[pallet::storage]
+	pub type RequestedFieldsOf<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		RegistrarIndex,
+		<T::IdentityInformation as IdentityInformationProvider>::FieldsIdentifier,
+		OptionQuery,
+	>;
+
+	/// The key that attests the verification challenges of a registrar.
+	#[pallet::storage]
+	pub type AttesterOf<T: Config> =
+		StorageMap<_, Twox64Concat, RegistrarIndex, T::AttesterId, OptionQuery>;
+
+	/// Verification challenges, keyed by the challenged account and the index of the registrar
+	/// that posted the challenge.
+	#[pallet::storage]
+	pub type ChallengeOf<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		RegistrarIndex,
+		offchain::Challenge,
+		OptionQuery,
+	>;
+
+	/// Whether the deposits and judgement fees of the pallet are held in [`Config::DepositAsset`]
+	/// rather than in [`Config::Currency`].
+	///
+	/// Only switched by [`migration::deposit_asset::SwitchDepositAsset`], and only if the pallet
+	/// holds no deposits, so that changing `DepositAsset` can't strand the deposits already held.
+	#[pallet::storage]
+	pub type DepositsInAsset<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		#[serde(skip)]
+		pub _config: core::marker::PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			// No deposits are held yet, so they can be held in `DepositAsset` from the start.
+			if T::DepositAsset::ENABLED {
+				DepositsInAsset::<T>::put(true);
+			}
+		}
+	}
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Too many subs-accounts.
@@ -400,23 +646,95 @@ pub mod pallet {
 		/// The action cannot be performed because of insufficient privileges (e.g. authority
 		/// trying to unbind a username provided by the system).
 		InsufficientPrivileges,
+		/// The display name is empty.
+		InvalidName,
+		/// The display name is already claimed.
+		NameTaken,
+		/// The account has not claimed a display name.
+		NoName,
+		/// The sender is not the manager of the identity.
+		NotManager,
+		/// The identity has no manager.
+		NoManager,
+		/// The account was not invited to become a sub-account.
+		NotInvited,
+		/// The registrar does not publish a fee for the requested set of fields.
+		NoFeeForFields,
+		/// The registrar already publishes fees for the maximum number of field sets.
+		TooManyFeeTiers,
+		/// No evidence was attached to the judgement.
+		NoEvidence,
+		/// The evidence is already disputed.
+		AlreadyDisputed,
+		/// The account already has an identity.
+		AlreadyHasIdentity,
+		/// The registrar has no attester.
+		NoAttester,
+		/// No matching verification challenge found.
+		NoChallenge,
+		/// The verification challenge was already verified.
+		AlreadyVerified,
+		/// The attestation is not signed by the attester of the registrar.
+		InvalidAttestation,
+		/// The URL or the nonce of the verification challenge is empty.
+		InvalidChallenge,
+		/// The sub-account tree would be deeper than `MaxSubDepth`.
+		SubDepthExceeded,
+		/// The registrar has as many open judgement requests as it accepts.
+		TooManyPendingRequests,
+		/// The identity is frozen by governance and cannot be changed or cleared.
+		IdentityFrozen,
+		/// The identity is not frozen.
+		NotFrozen,
+		/// The registrar has not been removed.
+		RegistrarActive,
+		/// The deadline of a judgement request is not in the future.
+		InvalidDeadline,
+		/// The judgement request has no deadline or its deadline has not passed.
+		RequestNotExpired,
+		/// The identity does not register all of the fields that the judgement was requested for.
+		RequestedFieldsNotSet,
+		/// The account holds sub-accounts in the tree of another identity.
+		NestedSubAccounts,
+		/// The witness data given does not match the current state.
+		BadWitness,
+		/// The deadline of the judgement request has passed.
+		RequestExpired,
 	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// A name was set or reset (which will remove all judgements).
-		IdentitySet { who: T::AccountId },
+		/// A name was set or reset (which will remove all judgements). `identity` is the hash of
+		/// the new identity information and `fields` the fields it registers.
+		IdentitySet {
+			who: T::AccountId,
+			identity: T::Hash,
+			fields: <T::IdentityInformation as IdentityInformationProvider>::FieldsIdentifier,
+		},
 		/// A name was cleared, and the given balance returned.
 		IdentityCleared { who: T::AccountId, deposit: BalanceOf<T> },
-		/// A name was removed and the given balance slashed.
-		IdentityKilled { who: T::AccountId, deposit: BalanceOf<T> },
+		/// A name was removed and the given balance slashed. Of the slashed deposit, `slashed` was
+		/// handled by `Slashed` and `reward` was paid to the `reporter`, if any.
+		IdentityKilled {
+			who: T::AccountId,
+			deposit: BalanceOf<T>,
+			slashed: BalanceOf<T>,
+			reporter: Option<T::AccountId>,
+			reward: BalanceOf<T>,
+		},
 		/// A judgement was asked from a registrar.
 		JudgementRequested { who: T::AccountId, registrar_index: RegistrarIndex },
 		/// A judgement request was retracted.
 		JudgementUnrequested { who: T::AccountId, registrar_index: RegistrarIndex },
-		/// A judgement was given by a registrar.
-		JudgementGiven { target: T::AccountId, registrar_index: RegistrarIndex },
+		/// A judgement was given by a registrar on the identity information of hash `identity`,
+		/// which registers `fields`.
+		JudgementGiven {
+			target: T::AccountId,
+			registrar_index: RegistrarIndex,
+			identity: T::Hash,
+			fields: <T::IdentityInformation as IdentityInformationProvider>::FieldsIdentifier,
+		},
 		/// A registrar was added.
 		RegistrarAdded { registrar_index: RegistrarIndex },
 		/// A sub-identity was added to an identity and the deposit paid.
@@ -451,6 +769,71 @@ pub mod pallet {
 		UsernameRemoved { username: Username<T> },
 		/// A username has been killed.
 		UsernameKilled { username: Username<T> },
+		/// A display name was claimed by `who` and the given deposit held.
+		NameClaimed { who: T::AccountId, name_hash: T::Hash, deposit: BalanceOf<T> },
+		/// A display name was released by `who` and the given deposit returned.
+		NameReleased { who: T::AccountId, name_hash: T::Hash, deposit: BalanceOf<T> },
+		/// `manager` was authorized to manage the identity of `main`.
+		ManagerAuthorized { main: T::AccountId, manager: T::AccountId },
+		/// The authorization of `manager` to manage the identity of `main` was revoked.
+		ManagerRevoked { main: T::AccountId, manager: T::AccountId },
+		/// The identity and sub-account deposits of `who` were updated from `old_deposit` to
+		/// `new_deposit`, and `reward` was paid out of the released excess to `caller`.
+		DepositPoked {
+			who: T::AccountId,
+			caller: T::AccountId,
+			old_deposit: BalanceOf<T>,
+			new_deposit: BalanceOf<T>,
+			reward: BalanceOf<T>,
+		},
+		/// `sub` was invited to become a self-funded sub-identity of `main`.
+		SubInvited { sub: T::AccountId, main: T::AccountId },
+		/// `main` withdrew its invitation of `sub` and the deposit held for it was returned.
+		SubInviteCancelled { sub: T::AccountId, main: T::AccountId, deposit: BalanceOf<T> },
+		/// A registrar attached evidence to its judgement of an identity.
+		EvidenceProvided {
+			target: T::AccountId,
+			registrar_index: RegistrarIndex,
+			evidence: Evidence,
+		},
+		/// The owner of an identity disputed the evidence attached to a judgement.
+		EvidenceDisputed { who: T::AccountId, registrar_index: RegistrarIndex },
+		/// The identity of `old`, along with its sub-accounts and deposits, was moved to `new`.
+		IdentityTransferred { old: T::AccountId, new: T::AccountId, deposit: BalanceOf<T> },
+		/// The attester of a registrar was set or removed.
+		AttesterSet { registrar_index: RegistrarIndex, attester: Option<T::AttesterId> },
+		/// A registrar posted a verification challenge for an identity.
+		ChallengeSet { target: T::AccountId, registrar_index: RegistrarIndex },
+		/// A registrar removed a verification challenge.
+		ChallengeRemoved { target: T::AccountId, registrar_index: RegistrarIndex },
+		/// The attester of a registrar verified a challenge.
+		ChallengeVerified { target: T::AccountId, registrar_index: RegistrarIndex },
+		/// An identity was frozen.
+		IdentityFrozen { who: T::AccountId },
+		/// An identity was thawed.
+		IdentityThawed { who: T::AccountId },
+		/// A registrar was removed.
+		RegistrarRemoved { registrar_index: RegistrarIndex },
+		/// The fee paid for a judgement request to a removed registrar was returned.
+		FeeReclaimed { target: T::AccountId, registrar_index: RegistrarIndex, fee: BalanceOf<T> },
+		/// A judgement request expired unanswered and its fee was returned.
+		JudgementRequestExpired {
+			target: T::AccountId,
+			registrar_index: RegistrarIndex,
+			fee: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn offchain_worker(now: BlockNumberFor<T>) {
+			Self::attest_challenges(now);
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+			Self::do_try_state()
+		}
 	}
 
 	#[pallet::call]
@@ -479,6 +862,8 @@ pub mod pallet {
 							account,
 							fee: Zero::zero(),
 							fields: Default::default(),
+							fee_schedule: Default::default(),
+							max_pending: None,
 						}))
 						.map_err(|_| Error::<T>::TooManyRegistrars)?;
 					Ok(((registrars.len() - 1) as RegistrarIndex, registrars.len()))
@@ -495,7 +880,8 @@ pub mod pallet {
 		/// If the account already has identity information, the deposit is taken as part payment
 		/// for the new deposit.
 		///
-		/// The dispatch origin for this call must be _Signed_.
+		/// The dispatch origin for this call must be _Signed_, and the sender's identity must not
+		/// be frozen.
 		///
 		/// - `info`: The identity information.
 		///
@@ -507,141 +893,88 @@ pub mod pallet {
 			info: Box<T::IdentityInformation>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
-
-			let mut id = match IdentityOf::<T>::get(&sender) {
-				Some(mut id) => {
-					// Only keep non-positive judgements.
-					id.judgements.retain(|j| j.1.is_sticky());
-					id.info = *info;
-					id
-				},
-				None => Registration {
-					info: *info,
-					judgements: BoundedVec::default(),
-					deposit: Zero::zero(),
-				},
-			};
-
-			let new_deposit = Self::calculate_identity_deposit(&id.info);
-			let old_deposit = id.deposit;
-			Self::rejig_deposit(&sender, old_deposit, new_deposit)?;
-
-			id.deposit = new_deposit;
-			let judgements = id.judgements.len();
-			IdentityOf::<T>::insert(&sender, id);
-			Self::deposit_event(Event::IdentitySet { who: sender });
-
-			Ok(Some(T::WeightInfo::set_identity(judgements as u32)).into())
+			let judgements = Self::do_set_identity(&sender, *info)?;
+			Ok(Some(T::WeightInfo::set_identity(judgements)).into())
 		}
 
 		/// Set the sub-accounts of the sender.
 		///
 		/// Payment: Any aggregate balance reserved by previous `set_subs` calls will be returned
-		/// and an amount `SubAccountDeposit` will be reserved for each item in `subs`.
+		/// and an amount `SubAccountDeposit` will be reserved for each item in `subs`, except for
+		/// kept sub-accounts that hold their own deposit.
 		///
 		/// The dispatch origin for this call must be _Signed_ and the sender must have a registered
-		/// identity.
+		/// identity that is not frozen, or be a sub-account less than `MaxSubDepth` levels below
+		/// one.
 		///
 		/// - `subs`: The identity's (new) sub-accounts.
-		// TODO: This whole extrinsic screams "not optimized". For example we could
-		// filter any overlap between new and old subs, and avoid reading/writing
-		// to those values... We could also ideally avoid needing to write to
-		// N storage items for N sub accounts. Right now the weight on this function
-		// is a large overestimate due to the fact that it could potentially write
-		// to 2 x T::MaxSubAccounts::get().
+		///
+		/// Sub-accounts that are kept with the same name are not written, and the weight of the
+		/// call is refunded down to the sub-accounts actually added, renamed and removed.
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::set_subs_old(T::MaxSubAccounts::get())
-			.saturating_add(T::WeightInfo::set_subs_new(subs.len() as u32))
-		)]
+		#[pallet::weight(Pallet::<T>::set_subs_weight(
+			subs.len() as u32,
+			T::MaxSubAccounts::get(),
+			0,
+			Pallet::<T>::max_nested_subs(),
+		))]
 		pub fn set_subs(
 			origin: OriginFor<T>,
 			subs: Vec<(T::AccountId, Data)>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
-			ensure!(IdentityOf::<T>::contains_key(&sender), Error::<T>::NotFound);
-			ensure!(
-				subs.len() <= T::MaxSubAccounts::get() as usize,
-				Error::<T>::TooManySubAccounts
-			);
-
-			let (old_deposit, old_ids) = SubsOf::<T>::get(&sender);
-			let new_deposit = Self::subs_deposit(subs.len() as u32);
-
-			let not_other_sub =
-				subs.iter().filter_map(|i| SuperOf::<T>::get(&i.0)).all(|i| i.0 == sender);
-			ensure!(not_other_sub, Error::<T>::AlreadyClaimed);
-
-			if old_deposit < new_deposit {
-				T::Currency::reserve(&sender, new_deposit - old_deposit)?;
-			} else if old_deposit > new_deposit {
-				let err_amount = T::Currency::unreserve(&sender, old_deposit - new_deposit);
-				debug_assert!(err_amount.is_zero());
-			}
-			// do nothing if they're equal.
-
-			for s in old_ids.iter() {
-				SuperOf::<T>::remove(s);
-			}
-			let mut ids = BoundedVec::<T::AccountId, T::MaxSubAccounts>::default();
-			for (id, name) in subs {
-				SuperOf::<T>::insert(&id, (sender.clone(), name));
-				ids.try_push(id).expect("subs length is less than T::MaxSubAccounts; qed");
-			}
-			let new_subs = ids.len();
-
-			if ids.is_empty() {
-				SubsOf::<T>::remove(&sender);
-			} else {
-				SubsOf::<T>::insert(&sender, (new_deposit, ids));
-			}
-
-			Self::deposit_event(Event::SubIdentitiesSet {
-				main: sender,
-				number_of_subs: new_subs as u32,
-				new_deposit,
-			});
+			let (written, removed, kept, nested) = Self::do_set_subs(&sender, subs)?;
 
-			Ok(Some(
-				T::WeightInfo::set_subs_old(old_ids.len() as u32) // P: Real number of old accounts removed.
-					// S: New subs added
-					.saturating_add(T::WeightInfo::set_subs_new(new_subs as u32)),
-			)
-			.into())
+			Ok(Some(Self::set_subs_weight(written, removed, kept, nested)).into())
 		}
 
-		/// Clear an account's identity info and all sub-accounts and return all deposits.
+		/// Clear an account's identity info, all sub-accounts and its display name and return all
+		/// deposits.
 		///
-		/// Payment: All reserved balances on the account are returned.
+		/// Payment: All reserved balances on the account are returned, including the deposits held
+		/// for pending sub-account invitations. Sub-accounts of sub-accounts are removed as well,
+		/// and their deposits returned to the sub-accounts that hold them.
 		///
 		/// The dispatch origin for this call must be _Signed_ and the sender must have a registered
-		/// identity.
+		/// identity that is not frozen.
 		///
 		/// Emits `IdentityCleared` if successful.
 		#[pallet::call_index(3)]
 		#[pallet::weight(T::WeightInfo::clear_identity(
 			T::MaxRegistrars::get(),
 			T::MaxSubAccounts::get(),
-		))]
+		)
+		.saturating_add(Pallet::<T>::sub_tree_removal_weight(
+			T::MaxSubAccounts::get(),
+			Pallet::<T>::max_nested_subs(),
+		)))]
 		pub fn clear_identity(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
+			Self::ensure_not_frozen(&sender)?;
 
 			let (subs_deposit, sub_ids) = SubsOf::<T>::take(&sender);
 			let id = IdentityOf::<T>::take(&sender).ok_or(Error::<T>::NoIdentity)?;
-			let deposit = id.total_deposit().saturating_add(subs_deposit);
-			for sub in sub_ids.iter() {
-				SuperOf::<T>::remove(sub);
-			}
-
-			let err_amount = T::Currency::unreserve(&sender, deposit);
-			debug_assert!(err_amount.is_zero());
+			let deposit = id
+				.total_deposit()
+				.saturating_add(subs_deposit)
+				.saturating_add(Self::take_name(&sender));
+			let nested = Self::remove_sub_tree(&sender, &sub_ids);
+			ManagerOf::<T>::remove(&sender);
+			Self::clear_invites(&sender);
+			let _ = EvidenceOf::<T>::clear_prefix(&sender, T::MaxRegistrars::get(), None);
+			let _ = ChallengeOf::<T>::clear_prefix(&sender, T::MaxRegistrars::get(), None);
+			Self::close_requests(&sender, &id.judgements);
+
+			Deposit::<T>::release(&sender, deposit);
+			T::OnIdentityChange::on_identity_removed(&sender);
 
 			Self::deposit_event(Event::IdentityCleared { who: sender, deposit });
 
 			#[allow(deprecated)]
-			Ok(Some(T::WeightInfo::clear_identity(
-				id.judgements.len() as u32,
-				sub_ids.len() as u32,
-			))
+			Ok(Some(
+				T::WeightInfo::clear_identity(id.judgements.len() as u32, sub_ids.len() as u32)
+					.saturating_add(Self::sub_tree_removal_weight(sub_ids.len() as u32, nested)),
+			)
 			.into())
 		}
 
@@ -657,8 +990,15 @@ pub mod pallet {
 		/// - `max_fee`: The maximum fee that may be paid. This should just be auto-populated as:
 		///
 		/// ```nocompile
-		/// Registrars::<T>::get().get(reg_index).unwrap().fee
+		/// Registrars::<T>::get().get(reg_index).unwrap().fee_for(fields.as_ref()).unwrap()
 		/// ```
+		/// - `fields`: The set of fields to be judged, which must be priced by the registrar. If
+		///   `None`, all of the fields the registrar concerns themselves with are judged.
+		/// - `valid_until`: The last block in which the request is valid, if any. Once it has
+		///   passed without a judgement, the registrar can no longer judge the request and anybody
+		///   may return the fee with [`Call::expire_request`].
+		///
+		/// Fails if the registrar already has its `max_pending` requests open.
 		///
 		/// Emits `JudgementRequested` if successful.
 		#[pallet::call_index(4)]
@@ -667,32 +1007,56 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			#[pallet::compact] reg_index: RegistrarIndex,
 			#[pallet::compact] max_fee: BalanceOf<T>,
+			fields: Option<
+				<T::IdentityInformation as IdentityInformationProvider>::FieldsIdentifier,
+			>,
+			valid_until: Option<BlockNumberFor<T>>,
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
+			ensure!(
+				valid_until.map_or(true, |b| b > frame_system::Pallet::<T>::block_number()),
+				Error::<T>::InvalidDeadline
+			);
 			let registrars = Registrars::<T>::get();
 			let registrar = registrars
 				.get(reg_index as usize)
 				.and_then(Option::as_ref)
 				.ok_or(Error::<T>::EmptyIndex)?;
-			ensure!(max_fee >= registrar.fee, Error::<T>::FeeChanged);
+			let fee = registrar.fee_for(fields.as_ref()).ok_or(Error::<T>::NoFeeForFields)?;
+			ensure!(max_fee >= fee, Error::<T>::FeeChanged);
+			let pending = PendingRequestsOf::<T>::get(reg_index);
+			ensure!(
+				registrar.max_pending.map_or(true, |max| pending < max),
+				Error::<T>::TooManyPendingRequests
+			);
 			let mut id = IdentityOf::<T>::get(&sender).ok_or(Error::<T>::NoIdentity)?;
 
-			let item = (reg_index, Judgement::FeePaid(registrar.fee));
+			let item = (reg_index, Judgement::FeePaid(fee));
 			match id.judgements.binary_search_by_key(&reg_index, |x| x.0) {
 				Ok(i) =>
 					if id.judgements[i].1.is_sticky() {
 						return Err(Error::<T>::StickyJudgement.into())
 					} else {
-						id.judgements[i] = item
+						id.judgements[i] = item;
+						EvidenceOf::<T>::remove(&sender, reg_index);
 					},
 				Err(i) =>
 					id.judgements.try_insert(i, item).map_err(|_| Error::<T>::TooManyRegistrars)?,
 			}
 
-			T::Currency::reserve(&sender, registrar.fee)?;
+			Deposit::<T>::hold(&sender, fee)?;
 
 			let judgements = id.judgements.len();
 			IdentityOf::<T>::insert(&sender, id);
+			PendingRequestsOf::<T>::insert(reg_index, pending.saturating_add(1));
+			if let Some(valid_until) = valid_until {
+				RequestDeadlineOf::<T>::insert(&sender, reg_index, valid_until);
+			}
+			match fields {
+				Some(fields) if fields != registrar.fields =>
+					RequestedFieldsOf::<T>::insert(&sender, reg_index, fields),
+				_ => RequestedFieldsOf::<T>::remove(&sender, reg_index),
+			}
 
 			Self::deposit_event(Event::JudgementRequested {
 				who: sender,
@@ -731,10 +1095,10 @@ pub mod pallet {
 				return Err(Error::<T>::JudgementGiven.into())
 			};
 
-			let err_amount = T::Currency::unreserve(&sender, fee);
-			debug_assert!(err_amount.is_zero());
+			Deposit::<T>::release(&sender, fee);
 			let judgements = id.judgements.len();
 			IdentityOf::<T>::insert(&sender, id);
+			Self::close_requests(&sender, &[(reg_index, Judgement::FeePaid(fee))]);
 
 			Self::deposit_event(Event::JudgementUnrequested {
 				who: sender,
@@ -751,27 +1115,39 @@ pub mod pallet {
 		///
 		/// - `index`: the index of the registrar whose fee is to be set.
 		/// - `fee`: the new fee.
+		/// - `fields`: the set of fields that `fee` applies to. If `None` or equal to the
+		///   registrar's fields, the fee for judging all of the registrar's fields is set.
+		///   Otherwise, the fee for this set of fields is added to the fee schedule or updated.
 		#[pallet::call_index(6)]
 		#[pallet::weight(T::WeightInfo::set_fee(T::MaxRegistrars::get()))]
 		pub fn set_fee(
 			origin: OriginFor<T>,
 			#[pallet::compact] index: RegistrarIndex,
 			#[pallet::compact] fee: BalanceOf<T>,
+			fields: Option<
+				<T::IdentityInformation as IdentityInformationProvider>::FieldsIdentifier,
+			>,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
 			let registrars = Registrars::<T>::mutate(|rs| -> Result<usize, DispatchError> {
-				rs.get_mut(index as usize)
+				let registrar = rs
+					.get_mut(index as usize)
 					.and_then(|x| x.as_mut())
-					.and_then(|r| {
-						if r.account == who {
-							r.fee = fee;
-							Some(())
-						} else {
-							None
-						}
-					})
+					.filter(|r| r.account == who)
 					.ok_or_else(|| DispatchError::from(Error::<T>::InvalidIndex))?;
+				match fields {
+					Some(fields) if fields != registrar.fields => {
+						let schedule = &mut registrar.fee_schedule;
+						match schedule.iter_mut().find(|(tier_fields, _)| tier_fields == &fields) {
+							Some((_, tier_fee)) => *tier_fee = fee,
+							None => schedule
+								.try_push((fields, fee))
+								.map_err(|_| Error::<T>::TooManyFeeTiers)?,
+						}
+					},
+					_ => registrar.fee = fee,
+				}
 				Ok(rs.len())
 			})?;
 			Ok(Some(T::WeightInfo::set_fee(registrars as u32)).into())
@@ -818,6 +1194,9 @@ pub mod pallet {
 		///
 		/// - `index`: the index of the registrar whose fee is to be set.
 		/// - `fields`: the fields that the registrar concerns themselves with.
+		///
+		/// If the fields change, the fee schedule of the registrar is cleared, as it was priced for
+		/// the previous fields.
 		#[pallet::call_index(8)]
 		#[pallet::weight(T::WeightInfo::set_fields(T::MaxRegistrars::get()))]
 		pub fn set_fields(
@@ -834,7 +1213,10 @@ pub mod pallet {
 						.and_then(|r| r.as_mut())
 						.filter(|r| r.account == who)
 						.ok_or_else(|| DispatchError::from(Error::<T>::InvalidIndex))?;
-					registrar.fields = fields;
+					if registrar.fields != fields {
+						registrar.fields = fields;
+						registrar.fee_schedule = FeeSchedule::default();
+					}
 
 					Ok(registrars.len())
 				})?;
@@ -853,6 +1235,8 @@ pub mod pallet {
 		/// - `identity`: The hash of the [`IdentityInformationProvider`] for that the judgement is
 		///   provided.
 		///
+		/// Fails if `target` requested the judgement with a deadline that has passed.
+		///
 		/// Note: Judgements do not apply to a username.
 		///
 		/// Emits `JudgementGiven` if successful.
@@ -867,50 +1251,20 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			let target = T::Lookup::lookup(target)?;
-			ensure!(!judgement.has_deposit(), Error::<T>::InvalidJudgement);
-			Registrars::<T>::get()
-				.get(reg_index as usize)
-				.and_then(Option::as_ref)
-				.filter(|r| r.account == sender)
-				.ok_or(Error::<T>::InvalidIndex)?;
-			let mut id = IdentityOf::<T>::get(&target).ok_or(Error::<T>::InvalidTarget)?;
-
-			if T::Hashing::hash_of(&id.info) != identity {
-				return Err(Error::<T>::JudgementForDifferentIdentity.into())
-			}
-
-			let item = (reg_index, judgement);
-			match id.judgements.binary_search_by_key(&reg_index, |x| x.0) {
-				Ok(position) => {
-					if let Judgement::FeePaid(fee) = id.judgements[position].1 {
-						T::Currency::repatriate_reserved(
-							&target,
-							&sender,
-							fee,
-							BalanceStatus::Free,
-						)
-						.map_err(|_| Error::<T>::JudgementPaymentFailed)?;
-					}
-					id.judgements[position] = item
-				},
-				Err(position) => id
-					.judgements
-					.try_insert(position, item)
-					.map_err(|_| Error::<T>::TooManyRegistrars)?,
-			}
+			let judgements =
+				Self::do_provide_judgement(&sender, reg_index, &target, judgement, identity)?;
 
-			let judgements = id.judgements.len();
-			IdentityOf::<T>::insert(&target, id);
-			Self::deposit_event(Event::JudgementGiven { target, registrar_index: reg_index });
-
-			Ok(Some(T::WeightInfo::provide_judgement(judgements as u32)).into())
+			Ok(Some(T::WeightInfo::provide_judgement(judgements)).into())
 		}
 
-		/// Remove an account's identity and sub-account information and slash the deposits.
+		/// Remove an account's identity, sub-account information and display name and slash the
+		/// deposits.
 		///
-		/// Payment: Reserved balances from `set_subs` and `set_identity` are slashed and handled by
-		/// `Slash`. Verification request deposits are not returned; they should be cancelled
-		/// manually using `cancel_request`.
+		/// Payment: Reserved balances from `set_subs`, `set_identity` and `claim_name` are slashed
+		/// and handled by `Slash`. Verification request deposits are not returned; they should be
+		/// cancelled manually using `cancel_request`. The deposits held for pending sub-account
+		/// invitations are returned. Sub-accounts of sub-accounts are removed as well, and their
+		/// deposits returned to the sub-accounts that hold them.
 		///
 		/// The dispatch origin for this call must match `T::ForceOrigin`.
 		///
@@ -922,30 +1276,18 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::kill_identity(
 			T::MaxRegistrars::get(),
 			T::MaxSubAccounts::get(),
-		))]
+		)
+		.saturating_add(Pallet::<T>::sub_tree_removal_weight(
+			T::MaxSubAccounts::get(),
+			Pallet::<T>::max_nested_subs(),
+		)))]
 		pub fn kill_identity(
 			origin: OriginFor<T>,
 			target: AccountIdLookupOf<T>,
 		) -> DispatchResultWithPostInfo {
 			T::ForceOrigin::ensure_origin(origin)?;
-
-			// Figure out who we're meant to be clearing.
 			let target = T::Lookup::lookup(target)?;
-			// Grab their deposit (and check that they have one).
-			let (subs_deposit, sub_ids) = SubsOf::<T>::take(&target);
-			let id = IdentityOf::<T>::take(&target).ok_or(Error::<T>::NoIdentity)?;
-			let deposit = id.total_deposit().saturating_add(subs_deposit);
-			for sub in sub_ids.iter() {
-				SuperOf::<T>::remove(sub);
-			}
-			// Slash their deposit from them.
-			T::Slashed::on_unbalanced(T::Currency::slash_reserved(&target, deposit).0);
-
-			Self::deposit_event(Event::IdentityKilled { who: target, deposit });
-
-			#[allow(deprecated)]
-			Ok(Some(T::WeightInfo::kill_identity(id.judgements.len() as u32, sub_ids.len() as u32))
-				.into())
+			Self::do_kill_identity(target, None)
 		}
 
 		/// Add the given account to the sender's subs.
@@ -954,9 +1296,12 @@ pub mod pallet {
 		/// to the sender.
 		///
 		/// The dispatch origin for this call must be _Signed_ and the sender must have a registered
-		/// sub identity of `sub`.
+		/// identity, or be a sub-account less than `MaxSubDepth` levels below one. The identity
+		/// must not be frozen.
 		#[pallet::call_index(11)]
-		#[pallet::weight(T::WeightInfo::add_sub(T::MaxSubAccounts::get()))]
+		#[pallet::weight(T::WeightInfo::add_sub(T::MaxSubAccounts::get())
+			.saturating_add(Pallet::<T>::sub_tree_weight(1))
+		)]
 		pub fn add_sub(
 			origin: OriginFor<T>,
 			sub: AccountIdLookupOf<T>,
@@ -964,10 +1309,10 @@ pub mod pallet {
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			let sub = T::Lookup::lookup(sub)?;
-			ensure!(IdentityOf::<T>::contains_key(&sender), Error::<T>::NoIdentity);
-
-			// Check if it's already claimed as sub-identity.
-			ensure!(!SuperOf::<T>::contains_key(&sub), Error::<T>::AlreadyClaimed);
+			let path = Self::ensure_can_hold_subs(&sender, Error::<T>::NoIdentity)?;
+			Self::ensure_not_frozen(&sender)?;
+			Self::ensure_can_become_sub(&sub, &path)?;
+			Self::note_sub_tree(&sender, &path, 1, 0, 0)?;
 
 			SubsOf::<T>::try_mutate(&sender, |(ref mut subs_deposit, ref mut sub_ids)| {
 				// Ensure there is space and that the deposit is paid.
@@ -976,7 +1321,7 @@ pub mod pallet {
 					Error::<T>::TooManySubAccounts
 				);
 				let deposit = T::SubAccountDeposit::get();
-				T::Currency::reserve(&sender, deposit)?;
+				Deposit::<T>::hold(&sender, deposit)?;
 
 				SuperOf::<T>::insert(&sub, (sender.clone(), data));
 				sub_ids.try_push(sub.clone()).expect("sub ids length checked above; qed");
@@ -990,9 +1335,11 @@ pub mod pallet {
 		/// Alter the associated name of the given sub-account.
 		///
 		/// The dispatch origin for this call must be _Signed_ and the sender must have a registered
-		/// sub identity of `sub`.
+		/// sub identity of `sub`. The identity must not be frozen.
 		#[pallet::call_index(12)]
-		#[pallet::weight(T::WeightInfo::rename_sub(T::MaxSubAccounts::get()))]
+		#[pallet::weight(T::WeightInfo::rename_sub(T::MaxSubAccounts::get())
+			.saturating_add(Pallet::<T>::sub_tree_weight(0))
+		)]
 		pub fn rename_sub(
 			origin: OriginFor<T>,
 			sub: AccountIdLookupOf<T>,
@@ -1000,7 +1347,8 @@ pub mod pallet {
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			let sub = T::Lookup::lookup(sub)?;
-			ensure!(IdentityOf::<T>::contains_key(&sender), Error::<T>::NoIdentity);
+			ensure!(Self::holds_subs(&sender), Error::<T>::NoIdentity);
+			Self::ensure_not_frozen(&sender)?;
 			ensure!(SuperOf::<T>::get(&sub).map_or(false, |x| x.0 == sender), Error::<T>::NotOwned);
 			SuperOf::<T>::insert(&sub, (&sender, data));
 
@@ -1011,58 +1359,113 @@ pub mod pallet {
 		/// Remove the given account from the sender's subs.
 		///
 		/// Payment: Balance reserved by a previous `set_subs` call for one sub will be repatriated
-		/// to the sender.
+		/// to the sender. The deposit of a self-funded sub is returned to the sub. Unless it has an
+		/// identity, the sub-accounts of `sub` are removed along with it.
 		///
 		/// The dispatch origin for this call must be _Signed_ and the sender must have a registered
-		/// sub identity of `sub`.
+		/// sub identity of `sub`. The identity must not be frozen.
 		#[pallet::call_index(13)]
-		#[pallet::weight(T::WeightInfo::remove_sub(T::MaxSubAccounts::get()))]
-		pub fn remove_sub(origin: OriginFor<T>, sub: AccountIdLookupOf<T>) -> DispatchResult {
+		#[pallet::weight(T::WeightInfo::remove_sub(T::MaxSubAccounts::get())
+			.saturating_add(Pallet::<T>::sub_tree_removal_weight(1, Pallet::<T>::max_nested_subs()))
+			.saturating_add(Pallet::<T>::sub_tree_weight(0))
+		)]
+		pub fn remove_sub(
+			origin: OriginFor<T>,
+			sub: AccountIdLookupOf<T>,
+		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
-			ensure!(IdentityOf::<T>::contains_key(&sender), Error::<T>::NoIdentity);
+			ensure!(Self::holds_subs(&sender), Error::<T>::NoIdentity);
+			Self::ensure_not_frozen(&sender)?;
 			let sub = T::Lookup::lookup(sub)?;
 			let (sup, _) = SuperOf::<T>::get(&sub).ok_or(Error::<T>::NotSub)?;
 			ensure!(sup == sender, Error::<T>::NotOwned);
 			SuperOf::<T>::remove(&sub);
-			SubsOf::<T>::mutate(&sup, |(ref mut subs_deposit, ref mut sub_ids)| {
+			let emptied = SubsOf::<T>::mutate(&sup, |(ref mut subs_deposit, ref mut sub_ids)| {
 				sub_ids.retain(|x| x != &sub);
-				let deposit = T::SubAccountDeposit::get().min(*subs_deposit);
-				*subs_deposit -= deposit;
-				let err_amount = T::Currency::unreserve(&sender, deposit);
-				debug_assert!(err_amount.is_zero());
+				let deposit = match SubDepositOf::<T>::take(&sub) {
+					Some(deposit) => {
+						Deposit::<T>::release(&sub, deposit);
+						deposit
+					},
+					None => {
+						let deposit = T::SubAccountDeposit::get().min(*subs_deposit);
+						*subs_deposit -= deposit;
+						Deposit::<T>::release(&sender, deposit);
+						deposit
+					},
+				};
 				Self::deposit_event(Event::SubIdentityRemoved { sub, main: sender, deposit });
+				sub_ids.is_empty() && subs_deposit.is_zero()
 			});
-			Ok(())
+			// A sub-account without an identity must not keep an empty tree.
+			if emptied {
+				SubsOf::<T>::remove(&sup);
+			}
+			let nested = Self::remove_subs_of_sub(&sub);
+			Self::note_sub_tree(&sup, &Self::super_path(&sup), 0, 1, nested)?;
+
+			Ok(Some(
+				T::WeightInfo::remove_sub(T::MaxSubAccounts::get())
+					.saturating_add(Self::sub_tree_removal_weight(1, nested))
+					.saturating_add(Self::sub_tree_weight(0)),
+			)
+			.into())
 		}
 
 		/// Remove the sender as a sub-account.
 		///
 		/// Payment: Balance reserved by a previous `set_subs` call for one sub will be repatriated
-		/// to the sender (*not* the original depositor).
+		/// to the sender (*not* the original depositor). The deposit of a self-funded sub is
+		/// returned to it. Unless it has an identity, the sub-accounts of the sender are removed
+		/// along with it.
 		///
 		/// The dispatch origin for this call must be _Signed_ and the sender must have a registered
-		/// super-identity.
+		/// super-identity that is not frozen.
 		///
 		/// NOTE: This should not normally be used, but is provided in the case that the non-
 		/// controller of an account is maliciously registered as a sub-account.
 		#[pallet::call_index(14)]
-		#[pallet::weight(T::WeightInfo::quit_sub(T::MaxSubAccounts::get()))]
-		pub fn quit_sub(origin: OriginFor<T>) -> DispatchResult {
+		#[pallet::weight(T::WeightInfo::quit_sub(T::MaxSubAccounts::get())
+			.saturating_add(Pallet::<T>::sub_tree_removal_weight(1, Pallet::<T>::max_nested_subs()))
+			.saturating_add(Pallet::<T>::sub_tree_weight(0))
+		)]
+		pub fn quit_sub(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 			let (sup, _) = SuperOf::<T>::take(&sender).ok_or(Error::<T>::NotSub)?;
-			SubsOf::<T>::mutate(&sup, |(ref mut subs_deposit, ref mut sub_ids)| {
+			Self::ensure_not_frozen(&sup)?;
+			let emptied = SubsOf::<T>::mutate(&sup, |(ref mut subs_deposit, ref mut sub_ids)| {
 				sub_ids.retain(|x| x != &sender);
-				let deposit = T::SubAccountDeposit::get().min(*subs_deposit);
-				*subs_deposit -= deposit;
-				let _ =
-					T::Currency::repatriate_reserved(&sup, &sender, deposit, BalanceStatus::Free);
+				let deposit = match SubDepositOf::<T>::take(&sender) {
+					Some(deposit) => {
+						Deposit::<T>::release(&sender, deposit);
+						deposit
+					},
+					None => {
+						let deposit = T::SubAccountDeposit::get().min(*subs_deposit);
+						*subs_deposit -= deposit;
+						let _ = Deposit::<T>::repatriate(&sup, &sender, deposit);
+						deposit
+					},
+				};
 				Self::deposit_event(Event::SubIdentityRevoked {
 					sub: sender,
 					main: sup.clone(),
 					deposit,
 				});
+				sub_ids.is_empty() && subs_deposit.is_zero()
 			});
-			Ok(())
+			if emptied {
+				SubsOf::<T>::remove(&sup);
+			}
+			let nested = Self::remove_subs_of_sub(&sender);
+			Self::note_sub_tree(&sup, &Self::super_path(&sup), 0, 1, nested)?;
+
+			Ok(Some(
+				T::WeightInfo::quit_sub(T::MaxSubAccounts::get())
+					.saturating_add(Self::sub_tree_removal_weight(1, nested))
+					.saturating_add(Self::sub_tree_weight(0)),
+			)
+			.into())
 		}
 
 		/// Add an `AccountId` with permission to grant usernames with a given `suffix` appended.
@@ -1149,7 +1552,7 @@ pub mod pallet {
 						Ok(Provider::new_with_allocation())
 					} else {
 						let deposit = T::UsernameDeposit::get();
-						T::Currency::reserve(&sender, deposit)?;
+						Deposit::<T>::hold(&sender, deposit)?;
 						Ok(Provider::new_with_deposit(deposit))
 					}
 				},
@@ -1219,8 +1622,7 @@ pub mod pallet {
 						let authority_account = AuthorityOf::<T>::get(&suffix)
 							.map(|auth_info| auth_info.account_id)
 							.ok_or(Error::<T>::NotUsernameAuthority)?;
-						let err_amount = T::Currency::unreserve(&authority_account, deposit);
-						debug_assert!(err_amount.is_zero());
+						Deposit::<T>::release(&authority_account, deposit);
 						T::WeightInfo::remove_expired_approval(0)
 					},
 					Provider::Allocation => {
@@ -1315,9 +1717,7 @@ pub mod pallet {
 					if let Some(authority_account) =
 						AuthorityOf::<T>::get(&suffix).map(|auth_info| auth_info.account_id)
 					{
-						let err_amount =
-							T::Currency::unreserve(&authority_account, username_deposit);
-						debug_assert!(err_amount.is_zero());
+						Deposit::<T>::release(&authority_account, username_deposit);
 					}
 				},
 				Provider::Allocation => {
@@ -1357,9 +1757,7 @@ pub mod pallet {
 					if let Some(authority_account) =
 						AuthorityOf::<T>::get(&suffix).map(|auth_info| auth_info.account_id)
 					{
-						T::Slashed::on_unbalanced(
-							T::Currency::slash_reserved(&authority_account, username_deposit).0,
-						);
+						Deposit::<T>::slash(&authority_account, username_deposit, None);
 					}
 					T::WeightInfo::kill_username(0)
 				},
@@ -1375,21 +1773,1291 @@ pub mod pallet {
 			Self::deposit_event(Event::UsernameKilled { username });
 			Ok((Some(actual_weight), Pays::No).into())
 		}
-	}
-}
 
-impl<T: Config> Pallet<T> {
-	/// Get the subs of an account.
-	pub fn subs(who: &T::AccountId) -> Vec<(T::AccountId, Data)> {
-		SubsOf::<T>::get(who)
-			.1
-			.into_iter()
-			.filter_map(|a| SuperOf::<T>::get(&a).map(|x| (a, x.1)))
-			.collect()
-	}
+		/// Claim `name` as the display name of the sender, which dapps can resolve back to the
+		/// sender through [`AccountOfName`].
+		///
+		/// Payment: `ByteDeposit` is reserved for each byte of `name`. If the sender already
+		/// claimed a name, it is released and its deposit is taken as part payment for the new one.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must have a registered
+		/// identity.
+		///
+		/// - `name`: The display name. Must not be empty nor claimed by any account.
+		///
+		/// Emits `NameClaimed` if successful.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::claim_name())]
+		pub fn claim_name(origin: OriginFor<T>, name: DisplayName) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(IdentityOf::<T>::contains_key(&sender), Error::<T>::NoIdentity);
+			ensure!(!name.is_empty(), Error::<T>::InvalidName);
 
-	/// Calculate the deposit required for a number of `sub` accounts.
-	fn subs_deposit(subs: u32) -> BalanceOf<T> {
+			let name_hash = T::Hashing::hash(&name[..]);
+			ensure!(!AccountOfName::<T>::contains_key(&name_hash), Error::<T>::NameTaken);
+
+			let deposit = Self::name_deposit(&name);
+			let old_deposit = match NameOf::<T>::get(&sender) {
+				Some((old_hash, old_deposit)) => {
+					AccountOfName::<T>::remove(&old_hash);
+					old_deposit
+				},
+				None => Zero::zero(),
+			};
+			Self::rejig_deposit(&sender, old_deposit, deposit)?;
+
+			AccountOfName::<T>::insert(&name_hash, &sender);
+			NameOf::<T>::insert(&sender, (name_hash, deposit));
+			Self::deposit_event(Event::NameClaimed { who: sender, name_hash, deposit });
+			Ok(())
+		}
+
+		/// Release the display name of the sender.
+		///
+		/// Payment: The deposit reserved by `claim_name` is returned.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must have claimed a
+		/// display name.
+		///
+		/// Emits `NameReleased` if successful.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::release_name())]
+		pub fn release_name(origin: OriginFor<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let (name_hash, deposit) = NameOf::<T>::take(&sender).ok_or(Error::<T>::NoName)?;
+			AccountOfName::<T>::remove(&name_hash);
+			Deposit::<T>::release(&sender, deposit);
+			Self::deposit_event(Event::NameReleased { who: sender, name_hash, deposit });
+			Ok(())
+		}
+
+		/// Authorize `manager` to update the identity information and sub-accounts of the sender,
+		/// replacing any previously authorized manager.
+		///
+		/// The manager cannot clear the identity, nor move any of its deposits; any deposit
+		/// changes caused by its updates are held from or returned to the sender.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must have a registered
+		/// identity.
+		///
+		/// Emits `ManagerAuthorized` if successful.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::authorize_manager())]
+		pub fn authorize_manager(
+			origin: OriginFor<T>,
+			manager: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let manager = T::Lookup::lookup(manager)?;
+			ensure!(IdentityOf::<T>::contains_key(&sender), Error::<T>::NoIdentity);
+			ensure!(manager != sender, Error::<T>::InvalidTarget);
+
+			ManagerOf::<T>::insert(&sender, &manager);
+			Self::deposit_event(Event::ManagerAuthorized { main: sender, manager });
+			Ok(())
+		}
+
+		/// Revoke the authorization of the manager of the sender's identity.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must have authorized
+		/// a manager.
+		///
+		/// Emits `ManagerRevoked` if successful.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::revoke_manager())]
+		pub fn revoke_manager(origin: OriginFor<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let manager = ManagerOf::<T>::take(&sender).ok_or(Error::<T>::NoManager)?;
+			Self::deposit_event(Event::ManagerRevoked { main: sender, manager });
+			Ok(())
+		}
+
+		/// Set the identity information of `main` on its behalf, as [`Call::set_identity`] does.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the manager authorized by `main`.
+		///
+		/// Emits `IdentitySet` if successful.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::set_identity(T::MaxRegistrars::get())
+			.saturating_add(T::DbWeight::get().reads(1))
+		)]
+		pub fn set_identity_for(
+			origin: OriginFor<T>,
+			main: AccountIdLookupOf<T>,
+			info: Box<T::IdentityInformation>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let main = T::Lookup::lookup(main)?;
+			Self::ensure_manager(&main, &sender)?;
+			ensure!(IdentityOf::<T>::contains_key(&main), Error::<T>::NoIdentity);
+
+			let judgements = Self::do_set_identity(&main, *info)?;
+			Ok(Some(
+				T::WeightInfo::set_identity(judgements).saturating_add(T::DbWeight::get().reads(1)),
+			)
+			.into())
+		}
+
+		/// Set the sub-accounts of `main` on its behalf, as [`Call::set_subs`] does.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the manager authorized by `main`.
+		///
+		/// Emits `SubIdentitiesSet` if successful.
+		#[pallet::call_index(29)]
+		#[pallet::weight(Pallet::<T>::set_subs_weight(
+			subs.len() as u32,
+			T::MaxSubAccounts::get(),
+			0,
+			Pallet::<T>::max_nested_subs(),
+		)
+		.saturating_add(T::DbWeight::get().reads(1)))]
+		pub fn set_subs_for(
+			origin: OriginFor<T>,
+			main: AccountIdLookupOf<T>,
+			subs: Vec<(T::AccountId, Data)>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let main = T::Lookup::lookup(main)?;
+			Self::ensure_manager(&main, &sender)?;
+
+			let (written, removed, kept, nested) = Self::do_set_subs(&main, subs)?;
+			Ok(Some(
+				Self::set_subs_weight(written, removed, kept, nested)
+					.saturating_add(T::DbWeight::get().reads(1)),
+			)
+			.into())
+		}
+
+		/// Update the identity and sub-account deposits held by `target` to the ones required by
+		/// the current deposit configuration, e.g. after a runtime changed `BasicDeposit` or
+		/// `ByteDeposit`.
+		///
+		/// Payment: If the deposits decrease, `PokeDepositReward` of the released excess is paid
+		/// by `target` to the caller. The call is free if the deposits changed.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `target`: The account whose deposits to update. Must have a registered identity.
+		///
+		/// Emits `DepositPoked` if successful.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::poke_deposit())]
+		pub fn poke_deposit(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let caller = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+
+			let old_deposit = IdentityOf::<T>::get(&target)
+				.ok_or(Error::<T>::NoIdentity)?
+				.deposit
+				.saturating_add(SubsOf::<T>::get(&target).0);
+			let (new_id_deposit, new_subs_deposit) = Self::do_poke_deposit(&target)?;
+			let new_deposit = new_id_deposit.saturating_add(new_subs_deposit);
+
+			let mut reward = Zero::zero();
+			if new_deposit < old_deposit && caller != target {
+				let excess = old_deposit.saturating_sub(new_deposit);
+				// The reward is best-effort; it must not prevent the deposits from being updated.
+				let maybe_reward = T::PokeDepositReward::get() * excess;
+				if Deposit::<T>::pay(&target, &caller, maybe_reward).is_ok() {
+					reward = maybe_reward;
+				}
+			}
+
+			Self::deposit_event(Event::DepositPoked {
+				who: target,
+				caller,
+				old_deposit,
+				new_deposit,
+				reward,
+			});
+
+			if old_deposit == new_deposit {
+				Ok(Pays::Yes.into())
+			} else {
+				Ok(Pays::No.into())
+			}
+		}
+
+		/// Invite `sub` to become a sub-account of the sender that holds its own deposit. The
+		/// invitation is accepted by `sub` through [`Call::add_sub_with_own_deposit`].
+		///
+		/// Payment: `SubAccountDeposit` is reserved from the sender for each pending invitation. It
+		/// is returned when the invitation is accepted or cancelled, or when the sender's identity
+		/// is removed. Inviting an account again only updates the name it is invited under.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must have a registered
+		/// identity, or be a sub-account less than `MaxSubDepth` levels below one.
+		///
+		/// - `sub`: The invited account. Must not be a sub-account already.
+		/// - `data`: The name of the sub-account.
+		///
+		/// Emits `SubInvited` if successful.
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::invite_sub().saturating_add(Pallet::<T>::sub_tree_weight(0)))]
+		pub fn invite_sub(
+			origin: OriginFor<T>,
+			sub: AccountIdLookupOf<T>,
+			data: Data,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let sub = T::Lookup::lookup(sub)?;
+			Self::ensure_can_hold_subs(&sender, Error::<T>::NoIdentity)?;
+			ensure!(!SuperOf::<T>::contains_key(&sub), Error::<T>::AlreadyClaimed);
+
+			PendingSubsOf::<T>::try_mutate(&sender, |(invites_deposit, invites)| {
+				if let Some(invite) = invites.iter_mut().find(|(invited, _)| *invited == sub) {
+					invite.1 = data;
+				} else {
+					invites
+						.try_push((sub.clone(), data))
+						.map_err(|_| Error::<T>::TooManySubAccounts)?;
+					let deposit = T::SubAccountDeposit::get();
+					Deposit::<T>::hold(&sender, deposit)?;
+					*invites_deposit = invites_deposit.saturating_add(deposit);
+				}
+				Ok::<_, DispatchError>(())
+			})?;
+			Self::deposit_event(Event::SubInvited { sub, main: sender });
+			Ok(())
+		}
+
+		/// Accept the invitation of `main` and become its sub-account, holding
+		/// `SubAccountDeposit` from the sender rather than from `main`.
+		///
+		/// Payment: `SubAccountDeposit` is reserved from the sender. It is returned to the sender
+		/// when it is removed as a sub-account. The deposit `main` held for the invitation is
+		/// returned to `main`.
+		///
+		/// The dispatch origin for this call must be _Signed_ by an account invited by `main`
+		/// through [`Call::invite_sub`]. The identity of `main` must not be frozen.
+		///
+		/// Emits `SubIdentityAdded` if successful.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::add_sub_with_own_deposit(T::MaxSubAccounts::get())
+			.saturating_add(Pallet::<T>::sub_tree_weight(1))
+		)]
+		pub fn add_sub_with_own_deposit(
+			origin: OriginFor<T>,
+			main: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let main = T::Lookup::lookup(main)?;
+			let (_, data) = PendingSubsOf::<T>::get(&main)
+				.1
+				.into_iter()
+				.find(|(invited, _)| *invited == sender)
+				.ok_or(Error::<T>::NotInvited)?;
+			let path = Self::ensure_can_hold_subs(&main, Error::<T>::NoIdentity)?;
+			Self::ensure_not_frozen(&main)?;
+			Self::ensure_can_become_sub(&sender, &path)?;
+			Self::note_sub_tree(&main, &path, 1, 0, 0)?;
+
+			SubsOf::<T>::try_mutate(&main, |(_, ref mut sub_ids)| {
+				ensure!(
+					sub_ids.len() < T::MaxSubAccounts::get() as usize,
+					Error::<T>::TooManySubAccounts
+				);
+				let deposit = T::SubAccountDeposit::get();
+				Deposit::<T>::hold(&sender, deposit)?;
+
+				Self::take_invite(&main, &sender);
+				SuperOf::<T>::insert(&sender, (main.clone(), data));
+				SubDepositOf::<T>::insert(&sender, deposit);
+				sub_ids.try_push(sender.clone()).expect("sub ids length checked above; qed");
+
+				Self::deposit_event(Event::SubIdentityAdded {
+					sub: sender,
+					main: main.clone(),
+					deposit,
+				});
+				Ok(())
+			})
+		}
+
+		/// Provide a judgement for an account's identity along with a reference to the off-chain
+		/// evidence it is based on.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the account
+		/// of the registrar whose index is `reg_index`.
+		///
+		/// - `reg_index`: the index of the registrar whose judgement is being made.
+		/// - `target`: the account whose identity the judgement is upon. This must be an account
+		///   with a registered identity.
+		/// - `judgement`: the judgement of the registrar of index `reg_index` about `target`.
+		/// - `identity`: The hash of the [`IdentityInformationProvider`] for that the judgement is
+		///   provided.
+		/// - `evidence`: a reference to the evidence, such as an IPFS CID or a document hash.
+		///
+		/// Emits `JudgementGiven` and `EvidenceProvided` if successful.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::provide_judgement_with_evidence(T::MaxRegistrars::get()))]
+		pub fn provide_judgement_with_evidence(
+			origin: OriginFor<T>,
+			#[pallet::compact] reg_index: RegistrarIndex,
+			target: AccountIdLookupOf<T>,
+			judgement: Judgement<BalanceOf<T>>,
+			identity: T::Hash,
+			evidence: Evidence,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let judgements =
+				Self::do_provide_judgement(&sender, reg_index, &target, judgement, identity)?;
+
+			EvidenceOf::<T>::insert(
+				&target,
+				reg_index,
+				JudgementEvidence { evidence: evidence.clone(), disputed: false },
+			);
+			Self::deposit_event(Event::EvidenceProvided {
+				target,
+				registrar_index: reg_index,
+				evidence,
+			});
+
+			Ok(Some(T::WeightInfo::provide_judgement_with_evidence(judgements)).into())
+		}
+
+		/// Dispute the evidence attached to a registrar's judgement of the sender's identity.
+		///
+		/// The dispute is only recorded; the judgement stands until the registrar provides a new
+		/// one.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must have a
+		/// registered identity.
+		///
+		/// - `reg_index`: the index of the registrar whose evidence is disputed.
+		///
+		/// Emits `EvidenceDisputed` if successful.
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::dispute_evidence())]
+		pub fn dispute_evidence(
+			origin: OriginFor<T>,
+			#[pallet::compact] reg_index: RegistrarIndex,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			EvidenceOf::<T>::try_mutate(&sender, reg_index, |maybe_evidence| {
+				let evidence = maybe_evidence.as_mut().ok_or(Error::<T>::NoEvidence)?;
+				ensure!(!evidence.disputed, Error::<T>::AlreadyDisputed);
+				evidence.disputed = true;
+				Ok::<(), DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::EvidenceDisputed {
+				who: sender,
+				registrar_index: reg_index,
+			});
+			Ok(())
+		}
+
+		/// Move the identity of `old`, e.g. a compromised account, to `new`, along with its
+		/// judgements, sub-accounts, pending sub-account invitations, display name and all of their
+		/// deposits. An invitation of `new` itself is withdrawn.
+		///
+		/// Payment: The deposits held by `old` are moved to `new` and stay reserved. Deposits held
+		/// by self-funded sub-accounts are not affected.
+		///
+		/// The dispatch origin for this call must match `T::ForceOrigin`.
+		///
+		/// - `old`: the account whose identity is moved. This must be an account with a registered
+		///   identity.
+		/// - `new`: the account receiving the identity. This must be an account without an identity
+		///   or sub-accounts of its own, and not in the tree of sub-accounts of `old`.
+		///
+		/// Any identity manager authorized by `old` is revoked.
+		///
+		/// Emits `IdentityTransferred` if successful.
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::transfer_identity(
+			T::MaxRegistrars::get(),
+			T::MaxSubAccounts::get(),
+		)
+		.saturating_add(Pallet::<T>::sub_tree_weight(0))
+		.saturating_add(T::DbWeight::get().reads_writes(2, 4)))]
+		pub fn transfer_identity(
+			origin: OriginFor<T>,
+			old: AccountIdLookupOf<T>,
+			new: AccountIdLookupOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let old = T::Lookup::lookup(old)?;
+			let new = T::Lookup::lookup(new)?;
+			ensure!(!IdentityOf::<T>::contains_key(&new), Error::<T>::AlreadyHasIdentity);
+			ensure!(!SubsOf::<T>::contains_key(&new), Error::<T>::NestedSubAccounts);
+			ensure!(!Self::super_path(&new).contains(&old), Error::<T>::AlreadyClaimed);
+			let id = IdentityOf::<T>::take(&old).ok_or(Error::<T>::NoIdentity)?;
+			let (subs_deposit, sub_ids) = SubsOf::<T>::take(&old);
+			let name = NameOf::<T>::take(&old);
+			// `new` can't become a sub-account of itself.
+			Self::take_invite(&old, &new);
+			let (invites_deposit, invites) = PendingSubsOf::<T>::take(&old);
+
+			let deposit = id
+				.total_deposit()
+				.saturating_add(subs_deposit)
+				.saturating_add(invites_deposit)
+				.saturating_add(name.as_ref().map_or(Zero::zero(), |(_, deposit)| *deposit));
+			Deposit::<T>::transfer(&old, &new, deposit)?;
+
+			for sub in sub_ids.iter() {
+				SuperOf::<T>::mutate(sub, |sup| {
+					if let Some((main, _)) = sup {
+						*main = new.clone();
+					}
+				});
+			}
+			if let Some((name_hash, name_deposit)) = name {
+				AccountOfName::<T>::insert(&name_hash, &new);
+				NameOf::<T>::insert(&new, (name_hash, name_deposit));
+			}
+			for (reg_index, evidence) in EvidenceOf::<T>::drain_prefix(&old) {
+				EvidenceOf::<T>::insert(&new, reg_index, evidence);
+			}
+			for (reg_index, challenge) in ChallengeOf::<T>::drain_prefix(&old) {
+				ChallengeOf::<T>::insert(&new, reg_index, challenge);
+			}
+			for (reg_index, valid_until) in RequestDeadlineOf::<T>::drain_prefix(&old) {
+				RequestDeadlineOf::<T>::insert(&new, reg_index, valid_until);
+			}
+			for (reg_index, fields) in RequestedFieldsOf::<T>::drain_prefix(&old) {
+				RequestedFieldsOf::<T>::insert(&new, reg_index, fields);
+			}
+			ManagerOf::<T>::remove(&old);
+
+			let judgements = id.judgements.len() as u32;
+			let subs = sub_ids.len() as u32;
+			IdentityOf::<T>::insert(&new, id);
+			if !sub_ids.is_empty() {
+				SubsOf::<T>::insert(&new, (subs_deposit, sub_ids));
+			}
+			if !invites.is_empty() {
+				PendingSubsOf::<T>::insert(&new, (invites_deposit, invites));
+			}
+			let nested = NestedSubsOf::<T>::take(&old);
+			if !nested.is_zero() {
+				NestedSubsOf::<T>::insert(&new, nested);
+			}
+			T::OnIdentityChange::on_identity_removed(&old);
+			T::OnIdentityChange::on_identity_set(&new);
+
+			Self::deposit_event(Event::IdentityTransferred { old, new, deposit });
+
+			Ok(Some(
+				T::WeightInfo::transfer_identity(judgements, subs)
+					.saturating_add(Self::sub_tree_weight(0))
+					.saturating_add(T::DbWeight::get().reads_writes(2, 4)),
+			)
+			.into())
+		}
+
+		/// Set the key with which off-chain workers attest the verification challenges of a
+		/// registrar, or remove it.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the account
+		/// of the registrar whose index is `reg_index`.
+		///
+		/// - `reg_index`: the index of the registrar.
+		/// - `attester`: the attester key, or `None` to stop verifying challenges off-chain.
+		///
+		/// Emits `AttesterSet` if successful.
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::set_attester(T::MaxRegistrars::get()))]
+		pub fn set_attester(
+			origin: OriginFor<T>,
+			#[pallet::compact] reg_index: RegistrarIndex,
+			attester: Option<T::AttesterId>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::ensure_registrar(&sender, reg_index)?;
+
+			AttesterOf::<T>::set(reg_index, attester.clone());
+			Self::deposit_event(Event::AttesterSet { registrar_index: reg_index, attester });
+			Ok(())
+		}
+
+		/// Post a verification challenge for the identity of `target`, asking for `nonce` to be
+		/// published at `url`. Any previous challenge of the registrar for `target` is replaced.
+		///
+		/// The challenge is checked by the off-chain workers holding the registrar's attester key.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the account
+		/// of the registrar whose index is `reg_index`. The registrar must have an attester.
+		///
+		/// - `reg_index`: the index of the registrar.
+		/// - `target`: the account whose identity is challenged. This must have an identity.
+		/// - `url`: where the nonce must be published.
+		/// - `nonce`: the nonce to publish.
+		///
+		/// Emits `ChallengeSet` if successful.
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::set_challenge(T::MaxRegistrars::get()))]
+		pub fn set_challenge(
+			origin: OriginFor<T>,
+			#[pallet::compact] reg_index: RegistrarIndex,
+			target: AccountIdLookupOf<T>,
+			url: offchain::ChallengeUrl,
+			nonce: offchain::ChallengeNonce,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			Self::ensure_registrar(&sender, reg_index)?;
+			ensure!(!url.is_empty() && !nonce.is_empty(), Error::<T>::InvalidChallenge);
+			ensure!(IdentityOf::<T>::contains_key(&target), Error::<T>::NoIdentity);
+			ensure!(AttesterOf::<T>::contains_key(reg_index), Error::<T>::NoAttester);
+
+			ChallengeOf::<T>::insert(
+				&target,
+				reg_index,
+				offchain::Challenge { url, nonce, verified: false },
+			);
+			Self::deposit_event(Event::ChallengeSet { target, registrar_index: reg_index });
+			Ok(())
+		}
+
+		/// Remove a verification challenge posted by a registrar.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the account
+		/// of the registrar whose index is `reg_index`.
+		///
+		/// - `reg_index`: the index of the registrar.
+		/// - `target`: the account whose challenge is removed.
+		///
+		/// Emits `ChallengeRemoved` if successful.
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::remove_challenge(T::MaxRegistrars::get()))]
+		pub fn remove_challenge(
+			origin: OriginFor<T>,
+			#[pallet::compact] reg_index: RegistrarIndex,
+			target: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			Self::ensure_registrar(&sender, reg_index)?;
+			ChallengeOf::<T>::take(&target, reg_index).ok_or(Error::<T>::NoChallenge)?;
+
+			Self::deposit_event(Event::ChallengeRemoved { target, registrar_index: reg_index });
+			Ok(())
+		}
+
+		/// Mark a verification challenge as verified.
+		///
+		/// The dispatch origin for this call must be _None_. The attestation must answer a
+		/// pending challenge and be signed by the attester of the registrar that posted it.
+		///
+		/// - `attestation`: the challenge that was verified.
+		/// - `signature`: the signature of the encoded `attestation` by the attester.
+		///
+		/// Emits `ChallengeVerified` if successful.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::submit_attestation())]
+		pub fn submit_attestation(
+			origin: OriginFor<T>,
+			attestation: offchain::Attestation<T::AccountId>,
+			signature: <T::AttesterId as RuntimeAppPublic>::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			Self::check_attestation(&attestation, &signature)?;
+
+			let offchain::Attestation { target, registrar_index, .. } = attestation;
+			ChallengeOf::<T>::mutate(&target, registrar_index, |challenge| {
+				if let Some(challenge) = challenge {
+					challenge.verified = true;
+				}
+			});
+			Self::deposit_event(Event::ChallengeVerified { target, registrar_index });
+			Ok(())
+		}
+
+		/// Set the maximum number of judgement requests with a fee paid that may be open on a
+		/// registrar at once.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender must be the account
+		/// of the registrar whose index is `index`.
+		///
+		/// - `index`: the index of the registrar whose limit is to be set.
+		/// - `max_pending`: the new limit, or `None` for no limit. Requests already open are kept,
+		///   even beyond the new limit.
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::set_max_pending(T::MaxRegistrars::get()))]
+		pub fn set_max_pending(
+			origin: OriginFor<T>,
+			#[pallet::compact] index: RegistrarIndex,
+			max_pending: Option<u32>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let registrars = Registrars::<T>::mutate(|rs| -> Result<usize, DispatchError> {
+				rs.get_mut(index as usize)
+					.and_then(|x| x.as_mut())
+					.filter(|r| r.account == who)
+					.ok_or_else(|| DispatchError::from(Error::<T>::InvalidIndex))?
+					.max_pending = max_pending;
+				Ok(rs.len())
+			})?;
+			Ok(Some(T::WeightInfo::set_max_pending(registrars as u32)).into())
+		}
+
+		/// Freeze the identity of `target`, e.g. to preserve it as evidence during an
+		/// investigation. While frozen, `target` can neither change its identity information or
+		/// sub-accounts, nor clear its identity. Its sub-accounts can't change their own
+		/// sub-accounts or quit either.
+		///
+		/// The dispatch origin for this call must match `T::ForceOrigin`.
+		///
+		/// - `target`: the account whose identity to freeze. This must be an account with a
+		///   registered identity that is not frozen already.
+		///
+		/// Emits `IdentityFrozen` if successful.
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::freeze_identity(T::MaxRegistrars::get()))]
+		pub fn freeze_identity(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let judgements = Self::set_frozen(&target, true)?;
+			Self::deposit_event(Event::IdentityFrozen { who: target });
+			Ok(Some(T::WeightInfo::freeze_identity(judgements)).into())
+		}
+
+		/// Thaw the identity of `target`, frozen by [`Call::freeze_identity`].
+		///
+		/// The dispatch origin for this call must match `T::ForceOrigin`.
+		///
+		/// - `target`: the account whose identity to thaw. This must be an account with a frozen
+		///   identity.
+		///
+		/// Emits `IdentityThawed` if successful.
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::thaw_identity(T::MaxRegistrars::get()))]
+		pub fn thaw_identity(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let judgements = Self::set_frozen(&target, false)?;
+			Self::deposit_event(Event::IdentityThawed { who: target });
+			Ok(Some(T::WeightInfo::thaw_identity(judgements)).into())
+		}
+
+		/// Remove a registrar from the system. Its index is not reused.
+		///
+		/// The verification challenges posted by the registrar and the evidence attached to its
+		/// judgements are removed. Neither carries a deposit, so nothing is returned for them.
+		///
+		/// Fees paid for judgement requests that the registrar did not answer stay reserved until
+		/// they are returned with [`Call::reclaim_fee`].
+		///
+		/// The dispatch origin for this call must be `T::RegistrarOrigin`.
+		///
+		/// - `index`: the index of the registrar to remove.
+		/// - `entries`: the number of verification challenges and judgement evidences stored, of
+		///   all registrars. Both are scanned to find the ones of the registrar, so this bounds the
+		///   weight of the call.
+		///
+		/// Emits `RegistrarRemoved` if successful.
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::remove_registrar(T::MaxRegistrars::get(), *entries))]
+		pub fn remove_registrar(
+			origin: OriginFor<T>,
+			#[pallet::compact] index: RegistrarIndex,
+			#[pallet::compact] entries: u32,
+		) -> DispatchResultWithPostInfo {
+			T::RegistrarOrigin::ensure_origin(origin)?;
+
+			let registrars = Registrars::<T>::try_mutate(|rs| -> Result<usize, DispatchError> {
+				rs.get_mut(index as usize)
+					.and_then(Option::take)
+					.ok_or(Error::<T>::InvalidIndex)?;
+				Ok(rs.len())
+			})?;
+			PendingRequestsOf::<T>::remove(index);
+			AttesterOf::<T>::remove(index);
+			let scanned = Self::clear_registrar_entries(index, entries)?;
+
+			Self::deposit_event(Event::RegistrarRemoved { registrar_index: index });
+
+			Ok(Some(T::WeightInfo::remove_registrar(registrars as u32, scanned)).into())
+		}
+
+		/// Return the fee that `target` paid for a judgement request to a removed registrar.
+		///
+		/// Payment: The fee reserved by [`Call::request_judgement`] is returned to `target`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `target`: the account that requested the judgement.
+		/// - `reg_index`: the index of the removed registrar.
+		///
+		/// Emits `FeeReclaimed` if successful.
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::reclaim_fee(T::MaxRegistrars::get()))]
+		pub fn reclaim_fee(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			#[pallet::compact] reg_index: RegistrarIndex,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let removed = Registrars::<T>::get()
+				.get(reg_index as usize)
+				.ok_or(Error::<T>::InvalidIndex)?
+				.is_none();
+			ensure!(removed, Error::<T>::RegistrarActive);
+			let mut id = IdentityOf::<T>::get(&target).ok_or(Error::<T>::InvalidTarget)?;
+
+			let pos = id
+				.judgements
+				.binary_search_by_key(&reg_index, |x| x.0)
+				.map_err(|_| Error::<T>::NotFound)?;
+			let fee = if let Judgement::FeePaid(fee) = id.judgements.remove(pos).1 {
+				fee
+			} else {
+				return Err(Error::<T>::JudgementGiven.into())
+			};
+
+			Deposit::<T>::release(&target, fee);
+			let judgements = id.judgements.len();
+			IdentityOf::<T>::insert(&target, id);
+			Self::close_requests(&target, &[(reg_index, Judgement::FeePaid(fee))]);
+
+			Self::deposit_event(Event::FeeReclaimed { target, registrar_index: reg_index, fee });
+
+			Ok(Some(T::WeightInfo::reclaim_fee(judgements as u32)).into())
+		}
+
+		/// Return the fee that `target` paid for a judgement request whose deadline has passed
+		/// without a judgement.
+		///
+		/// Payment: The fee reserved by [`Call::request_judgement`] is returned to `target`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `target`: the account that requested the judgement.
+		/// - `reg_index`: the index of the registrar whose judgement was requested.
+		///
+		/// Emits `JudgementRequestExpired` if successful.
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::expire_request(T::MaxRegistrars::get()))]
+		pub fn expire_request(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			#[pallet::compact] reg_index: RegistrarIndex,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			ensure!(Self::request_expired(&target, reg_index), Error::<T>::RequestNotExpired);
+			let mut id = IdentityOf::<T>::get(&target).ok_or(Error::<T>::InvalidTarget)?;
+
+			let pos = id
+				.judgements
+				.binary_search_by_key(&reg_index, |x| x.0)
+				.map_err(|_| Error::<T>::NotFound)?;
+			let fee = if let Judgement::FeePaid(fee) = id.judgements.remove(pos).1 {
+				fee
+			} else {
+				return Err(Error::<T>::JudgementGiven.into())
+			};
+
+			Deposit::<T>::release(&target, fee);
+			let judgements = id.judgements.len();
+			IdentityOf::<T>::insert(&target, id);
+			Self::close_requests(&target, &[(reg_index, Judgement::FeePaid(fee))]);
+
+			Self::deposit_event(Event::JudgementRequestExpired {
+				target,
+				registrar_index: reg_index,
+				fee,
+			});
+
+			Ok(Some(T::WeightInfo::expire_request(judgements as u32)).into())
+		}
+
+		/// Withdraw an invitation made through [`Call::invite_sub`] that `sub` has not accepted.
+		///
+		/// Payment: The deposit held for the invitation is returned to the sender.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the account that invited `sub`.
+		///
+		/// - `sub`: The invited account.
+		///
+		/// Emits `SubInviteCancelled` if successful.
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::cancel_sub_invite(T::MaxSubAccounts::get()))]
+		pub fn cancel_sub_invite(
+			origin: OriginFor<T>,
+			sub: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let sub = T::Lookup::lookup(sub)?;
+			let deposit = Self::take_invite(&sender, &sub).ok_or(Error::<T>::NotInvited)?;
+
+			Self::deposit_event(Event::SubInviteCancelled { sub, main: sender, deposit });
+			Ok(())
+		}
+
+		/// Remove an account's identity like [`Call::kill_identity`], rewarding the account that
+		/// reported it.
+		///
+		/// Payment: Only the `T::SlashPortion` of the slashed deposits is handled by `Slash`; the
+		/// remainder is paid to `reporter`.
+		///
+		/// The dispatch origin for this call must match `T::ForceOrigin`.
+		///
+		/// - `target`: the account whose identity the judgement is upon. This must be an account
+		///   with a registered identity.
+		/// - `reporter`: the account to reward for reporting the identity.
+		///
+		/// Emits `IdentityKilled` if successful.
+		#[pallet::call_index(47)]
+		#[pallet::weight(T::WeightInfo::kill_identity(
+			T::MaxRegistrars::get(),
+			T::MaxSubAccounts::get(),
+		)
+		.saturating_add(Pallet::<T>::sub_tree_removal_weight(
+			T::MaxSubAccounts::get(),
+			Pallet::<T>::max_nested_subs(),
+		)))]
+		pub fn kill_identity_with_reporter(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			reporter: AccountIdLookupOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ForceOrigin::ensure_origin(origin)?;
+			let target = T::Lookup::lookup(target)?;
+			let reporter = T::Lookup::lookup(reporter)?;
+			Self::do_kill_identity(target, Some(reporter))
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			if let Call::submit_attestation { attestation, signature } = call {
+				Self::validate_attestation(attestation, signature)
+			} else {
+				InvalidTransaction::Call.into()
+			}
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Remove the identity of `target` and slash its deposits, paying the part beyond the
+	/// `T::SlashPortion` to `reporter` if one is given.
+	fn do_kill_identity(
+		target: T::AccountId,
+		reporter: Option<T::AccountId>,
+	) -> DispatchResultWithPostInfo {
+		// Grab their deposit (and check that they have one).
+		let (subs_deposit, sub_ids) = SubsOf::<T>::take(&target);
+		let id = IdentityOf::<T>::take(&target).ok_or(Error::<T>::NoIdentity)?;
+		let deposit = id
+			.total_deposit()
+			.saturating_add(subs_deposit)
+			.saturating_add(Self::take_name(&target));
+		let nested = Self::remove_sub_tree(&target, &sub_ids);
+		ManagerOf::<T>::remove(&target);
+		Self::clear_invites(&target);
+		let _ = EvidenceOf::<T>::clear_prefix(&target, T::MaxRegistrars::get(), None);
+		let _ = ChallengeOf::<T>::clear_prefix(&target, T::MaxRegistrars::get(), None);
+		Self::close_requests(&target, &id.judgements);
+		// Slash their deposit from them, rewarding the reporter with the remainder of the slash
+		// portion.
+		let (slashed, reward) = Deposit::<T>::slash(&target, deposit, reporter.as_ref());
+		T::OnIdentityChange::on_identity_removed(&target);
+
+		Self::deposit_event(Event::IdentityKilled {
+			who: target,
+			deposit,
+			slashed,
+			reporter,
+			reward,
+		});
+
+		#[allow(deprecated)]
+		Ok(Some(
+			T::WeightInfo::kill_identity(id.judgements.len() as u32, sub_ids.len() as u32)
+				.saturating_add(Self::sub_tree_removal_weight(sub_ids.len() as u32, nested)),
+		)
+		.into())
+	}
+
+	/// Freeze or thaw the identity of `who`. Returns the number of judgements on it.
+	fn set_frozen(who: &T::AccountId, frozen: bool) -> Result<u32, DispatchError> {
+		IdentityOf::<T>::try_mutate(who, |id| {
+			let id = id.as_mut().ok_or(Error::<T>::NoIdentity)?;
+			let error = if frozen { Error::<T>::IdentityFrozen } else { Error::<T>::NotFrozen };
+			ensure!(id.frozen != frozen, error);
+			id.frozen = frozen;
+			Ok(id.judgements.len() as u32)
+		})
+	}
+
+	/// Stop counting the requests of `who` with a fee paid among `judgements` as open on their
+	/// registrars, and drop their deadlines.
+	fn close_requests(
+		who: &T::AccountId,
+		judgements: &[(RegistrarIndex, Judgement<BalanceOf<T>>)],
+	) {
+		for (reg_index, _) in judgements.iter().filter(|(_, judgement)| judgement.has_deposit()) {
+			PendingRequestsOf::<T>::mutate_exists(reg_index, |pending| {
+				*pending = pending.and_then(|p| p.checked_sub(1)).filter(|p| *p > 0)
+			});
+			RequestDeadlineOf::<T>::remove(who, reg_index);
+			RequestedFieldsOf::<T>::remove(who, reg_index);
+		}
+	}
+
+	/// Whether the judgement request of `who` to the registrar at `reg_index` has a deadline that
+	/// has passed.
+	fn request_expired(who: &T::AccountId, reg_index: RegistrarIndex) -> bool {
+		RequestDeadlineOf::<T>::get(who, reg_index)
+			.is_some_and(|valid_until| valid_until < frame_system::Pallet::<T>::block_number())
+	}
+
+	/// Remove the verification challenges and judgement evidences of the registrar at `index`.
+	///
+	/// Both maps are keyed by account first, so all of their entries are scanned. Fails with
+	/// `BadWitness` if there are more than `entries`. Returns the number of entries scanned.
+	fn clear_registrar_entries(index: RegistrarIndex, entries: u32) -> Result<u32, DispatchError> {
+		let mut scanned = 0u32;
+		let mut scan = |reg_index: RegistrarIndex| -> Result<bool, DispatchError> {
+			scanned += 1;
+			ensure!(scanned <= entries, Error::<T>::BadWitness);
+			Ok(reg_index == index)
+		};
+
+		let mut challenged = Vec::new();
+		for (who, reg_index) in ChallengeOf::<T>::iter_keys() {
+			if scan(reg_index)? {
+				challenged.push(who);
+			}
+		}
+		let mut judged = Vec::new();
+		for (who, reg_index) in EvidenceOf::<T>::iter_keys() {
+			if scan(reg_index)? {
+				judged.push(who);
+			}
+		}
+
+		for who in challenged {
+			ChallengeOf::<T>::remove(&who, index);
+		}
+		for who in judged {
+			EvidenceOf::<T>::remove(&who, index);
+		}
+		Ok(scanned)
+	}
+
+	/// Get the subs of an account.
+	pub fn subs(who: &T::AccountId) -> Vec<(T::AccountId, Data)> {
+		SubsOf::<T>::get(who)
+			.1
+			.into_iter()
+			.filter_map(|a| SuperOf::<T>::get(&a).map(|x| (a, x.1)))
+			.collect()
+	}
+
+	/// Set the identity information of `who`, keeping only its sticky judgements and updating its
+	/// deposit. Fails if the identity is frozen. Returns the number of judgements kept.
+	fn do_set_identity(
+		who: &T::AccountId,
+		info: T::IdentityInformation,
+	) -> Result<u32, DispatchError> {
+		Self::ensure_not_frozen(who)?;
+		let mut id = match IdentityOf::<T>::get(who) {
+			Some(mut id) => {
+				// Only keep non-positive judgements.
+				id.judgements.retain(|j| {
+					let sticky = j.1.is_sticky();
+					if !sticky {
+						EvidenceOf::<T>::remove(who, j.0);
+					}
+					sticky
+				});
+				id.info = info;
+				id
+			},
+			None => {
+				// The sub-accounts of a sub-account belong to the tree of its super-identity.
+				ensure!(!SubsOf::<T>::contains_key(who), Error::<T>::NestedSubAccounts);
+				Registration {
+					info,
+					judgements: BoundedVec::default(),
+					deposit: Zero::zero(),
+					frozen: false,
+				}
+			},
+		};
+
+		let new_deposit = Self::calculate_identity_deposit(&id.info);
+		let old_deposit = id.deposit;
+		Self::rejig_deposit(who, old_deposit, new_deposit)?;
+
+		id.deposit = new_deposit;
+		let judgements = id.judgements.len() as u32;
+		let identity = T::Hashing::hash_of(&id.info);
+		let fields = id.info.registered_fields();
+		IdentityOf::<T>::insert(who, id);
+		T::OnIdentityChange::on_identity_set(who);
+		Self::deposit_event(Event::IdentitySet { who: who.clone(), identity, fields });
+
+		Ok(judgements)
+	}
+
+	/// Replace the sub-accounts of `who`, which must have an identity that is not frozen, and
+	/// update its deposit.
+	///
+	/// Only the sub-accounts that are added, renamed or removed are written; kept sub-accounts
+	/// that hold their own deposit keep holding it. Removed sub-accounts without an identity are
+	/// removed along with their own sub-accounts. Returns the number of sub-accounts written,
+	/// removed, kept unchanged and removed below the removed ones, respectively.
+	fn do_set_subs(
+		who: &T::AccountId,
+		subs: Vec<(T::AccountId, Data)>,
+	) -> Result<(u32, u32, u32, u32), DispatchError> {
+		let path = Self::ensure_can_hold_subs(who, Error::<T>::NotFound)?;
+		ensure!(subs.len() <= T::MaxSubAccounts::get() as usize, Error::<T>::TooManySubAccounts);
+		Self::ensure_not_frozen(who)?;
+
+		let (old_deposit, old_ids) = SubsOf::<T>::get(who);
+
+		let mut written = Vec::new();
+		let mut kept = 0u32;
+		for (sub, name) in subs.iter() {
+			let current = SuperOf::<T>::get(sub);
+			ensure!(
+				current.as_ref().map_or(true, |(main, _)| main == who),
+				Error::<T>::AlreadyClaimed
+			);
+			if !old_ids.contains(sub) {
+				Self::ensure_can_become_sub(sub, &path)?;
+			}
+			match current {
+				Some((_, current_name)) if &current_name == name => kept.saturating_inc(),
+				_ => written.push((sub, name)),
+			}
+		}
+		let removed: Vec<_> =
+			old_ids.iter().filter(|old| !subs.iter().any(|(sub, _)| sub == *old)).collect();
+
+		let ids: BoundedVec<T::AccountId, T::MaxSubAccounts> = subs
+			.iter()
+			.map(|(sub, _)| sub.clone())
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("subs length is less than T::MaxSubAccounts; qed");
+		let new_deposit = Self::subs_deposit(Self::super_funded_subs(&ids));
+		Self::rejig_deposit(who, old_deposit, new_deposit)?;
+
+		let nested = removed
+			.iter()
+			.fold(0u32, |nested, sub| nested.saturating_add(Self::remove_sub_account(sub)));
+		Self::note_sub_tree(who, &path, ids.len() as u32, old_ids.len() as u32, nested)?;
+		for (sub, name) in written.iter() {
+			SuperOf::<T>::insert(*sub, (who.clone(), (*name).clone()));
+		}
+		let new_subs = ids.len() as u32;
+
+		if ids.is_empty() {
+			SubsOf::<T>::remove(who);
+		} else {
+			SubsOf::<T>::insert(who, (new_deposit, ids));
+		}
+
+		Self::deposit_event(Event::SubIdentitiesSet {
+			main: who.clone(),
+			number_of_subs: new_subs,
+			new_deposit,
+		});
+
+		Ok((written.len() as u32, removed.len() as u32, kept, nested))
+	}
+
+	/// Remove the invitation of `sub` by `main`, returning the deposit held for it to `main`.
+	/// Returns the returned deposit, or `None` if `main` did not invite `sub`.
+	fn take_invite(main: &T::AccountId, sub: &T::AccountId) -> Option<BalanceOf<T>> {
+		PendingSubsOf::<T>::mutate_exists(main, |maybe_invites| {
+			let (invites_deposit, invites) = maybe_invites.as_mut()?;
+			let pos = invites.iter().position(|(invited, _)| invited == sub)?;
+			invites.remove(pos);
+			let deposit = if invites.is_empty() {
+				let deposit = *invites_deposit;
+				*maybe_invites = None;
+				deposit
+			} else {
+				let deposit = T::SubAccountDeposit::get().min(*invites_deposit);
+				*invites_deposit = invites_deposit.saturating_sub(deposit);
+				deposit
+			};
+			Deposit::<T>::release(main, deposit);
+			Some(deposit)
+		})
+	}
+
+	/// Remove all invitations pending from `main`, returning the deposit held for them to `main`.
+	fn clear_invites(main: &T::AccountId) {
+		let (invites_deposit, _) = PendingSubsOf::<T>::take(main);
+		Deposit::<T>::release(main, invites_deposit);
+	}
+
+	/// Remove `sub` from its super-identity, returning its own deposit if it is self-funded, along
+	/// with its own sub-accounts. Returns the number of sub-accounts removed below `sub`.
+	fn remove_sub_account(sub: &T::AccountId) -> u32 {
+		SuperOf::<T>::remove(sub);
+		if let Some(deposit) = SubDepositOf::<T>::take(sub) {
+			Deposit::<T>::release(sub, deposit);
+		}
+		Self::remove_subs_of_sub(sub)
+	}
+
+	/// Remove the sub-accounts and invitations of `sub`, which is removed from its
+	/// super-identity, returning their deposits. A sub-account with an identity keeps them.
+	/// Returns the number of sub-accounts removed.
+	fn remove_subs_of_sub(sub: &T::AccountId) -> u32 {
+		if IdentityOf::<T>::contains_key(sub) {
+			return 0
+		}
+		Self::clear_invites(sub);
+		let (subs_deposit, sub_ids) = SubsOf::<T>::take(sub);
+		Deposit::<T>::release(sub, subs_deposit);
+		sub_ids.iter().fold(sub_ids.len() as u32, |removed, nested| {
+			removed.saturating_add(Self::remove_sub_account(nested))
+		})
+	}
+
+	/// Remove the sub-accounts `sub_ids` of the removed identity of `who`, along with the rest of
+	/// its tree. Returns the number of sub-accounts removed below `sub_ids`.
+	fn remove_sub_tree(who: &T::AccountId, sub_ids: &[T::AccountId]) -> u32 {
+		NestedSubsOf::<T>::remove(who);
+		sub_ids
+			.iter()
+			.fold(0u32, |removed, sub| removed.saturating_add(Self::remove_sub_account(sub)))
+	}
+
+	/// The maximum number of sub-accounts in the tree of an identity below its direct
+	/// sub-accounts.
+	fn max_nested_subs() -> u32 {
+		T::MaxSubAccounts::get().saturating_mul(T::MaxSubDepth::get().saturating_sub(1))
+	}
+
+	/// Account for `added` sub-accounts added to and `removed` removed from the sub-accounts of
+	/// `who`, whose [`Self::super_path`] is `path`, with `nested` more removed below those, in
+	/// the [`NestedSubsOf`] of the tree of `who`.
+	///
+	/// Fails if the tree would hold more than [`Self::max_nested_subs`] nested sub-accounts.
+	fn note_sub_tree(
+		who: &T::AccountId,
+		path: &[T::AccountId],
+		added: u32,
+		removed: u32,
+		nested: u32,
+	) -> DispatchResult {
+		// The direct sub-accounts of an identity are not nested.
+		let (root, added, removed) = match path.last() {
+			Some(root) => (root, added, removed.saturating_add(nested)),
+			None => (who, 0, nested),
+		};
+		if added.is_zero() && removed.is_zero() {
+			return Ok(())
+		}
+		NestedSubsOf::<T>::try_mutate_exists(root, |maybe_nested| {
+			let count = maybe_nested.unwrap_or(0).saturating_sub(removed).saturating_add(added);
+			ensure!(
+				added.is_zero() || count <= Self::max_nested_subs(),
+				Error::<T>::TooManySubAccounts
+			);
+			*maybe_nested = Some(count).filter(|n| !n.is_zero());
+			Ok(())
+		})
+	}
+
+	/// The number of `subs` whose deposit is held by their super-identity.
+	fn super_funded_subs(subs: &[T::AccountId]) -> u32 {
+		subs.iter().filter(|sub| !SubDepositOf::<T>::contains_key(sub)).count() as u32
+	}
+
+	/// Record the judgement of the registrar `sender`, of index `reg_index`, on the identity of
+	/// `target` with hash `identity`, dropping any evidence of a previous judgement. Returns the
+	/// number of judgements on the identity.
+	fn do_provide_judgement(
+		sender: &T::AccountId,
+		reg_index: RegistrarIndex,
+		target: &T::AccountId,
+		judgement: Judgement<BalanceOf<T>>,
+		identity: T::Hash,
+	) -> Result<u32, DispatchError> {
+		ensure!(!judgement.has_deposit(), Error::<T>::InvalidJudgement);
+		Registrars::<T>::get()
+			.get(reg_index as usize)
+			.and_then(Option::as_ref)
+			.filter(|r| &r.account == sender)
+			.ok_or(Error::<T>::InvalidIndex)?;
+		let mut id = IdentityOf::<T>::get(target).ok_or(Error::<T>::InvalidTarget)?;
+
+		if T::Hashing::hash_of(&id.info) != identity {
+			return Err(Error::<T>::JudgementForDifferentIdentity.into())
+		}
+
+		let item = (reg_index, judgement);
+		match id.judgements.binary_search_by_key(&reg_index, |x| x.0) {
+			Ok(position) => {
+				if let Judgement::FeePaid(fee) = id.judgements[position].1 {
+					// The fee of an expired request can only be returned to the requester, with
+					// `expire_request`.
+					ensure!(!Self::request_expired(target, reg_index), Error::<T>::RequestExpired);
+					// A request priced for a subset of the registrar's fields may only be judged
+					// favourably if the identity registers those fields.
+					if let Some(fields) = RequestedFieldsOf::<T>::get(target, reg_index) {
+						ensure!(
+							!matches!(judgement, Judgement::Reasonable | Judgement::KnownGood) ||
+								id.info.has_identity(fields),
+							Error::<T>::RequestedFieldsNotSet
+						);
+					}
+					Deposit::<T>::repatriate(target, sender, fee)
+						.map_err(|_| Error::<T>::JudgementPaymentFailed)?;
+					Self::close_requests(target, &id.judgements[position..=position]);
+				}
+				id.judgements[position] = item
+			},
+			Err(position) => id
+				.judgements
+				.try_insert(position, item)
+				.map_err(|_| Error::<T>::TooManyRegistrars)?,
+		}
+
+		let judgements = id.judgements.len() as u32;
+		let fields = id.info.registered_fields();
+		IdentityOf::<T>::insert(target, id);
+		EvidenceOf::<T>::remove(target, reg_index);
+		T::OnIdentityChange::on_judgement_given(target, reg_index, &judgement);
+		Self::deposit_event(Event::JudgementGiven {
+			target: target.clone(),
+			registrar_index: reg_index,
+			identity,
+			fields,
+		});
+
+		Ok(judgements)
+	}
+
+	/// Ensure that `manager` is the identity manager authorized by `main`.
+	fn ensure_manager(main: &T::AccountId, manager: &T::AccountId) -> DispatchResult {
+		ensure!(ManagerOf::<T>::get(main).as_ref() == Some(manager), Error::<T>::NotManager);
+		Ok(())
+	}
+
+	/// Calculate the deposit required for a number of `sub` accounts.
+	fn subs_deposit(subs: u32) -> BalanceOf<T> {
 		T::SubAccountDeposit::get().saturating_mul(BalanceOf::<T>::from(subs))
 	}
 
@@ -1399,13 +3067,41 @@ impl<T: Config> Pallet<T> {
 		current: BalanceOf<T>,
 		new: BalanceOf<T>,
 	) -> DispatchResult {
-		if new > current {
-			T::Currency::reserve(who, new - current)?;
-		} else if new < current {
-			let err_amount = T::Currency::unreserve(who, current - new);
-			debug_assert!(err_amount.is_zero());
+		Deposit::<T>::rejig(who, current, new)
+	}
+
+	/// Get up to `limit` registered identities in storage order, starting right after the identity
+	/// of `start_key` if given.
+	pub fn identities(
+		start_key: Option<T::AccountId>,
+		limit: u32,
+	) -> Vec<(T::AccountId, RegistrationOf<T>)> {
+		let iter = match start_key {
+			Some(key) => IdentityOf::<T>::iter_from(IdentityOf::<T>::hashed_key_for(key)),
+			None => IdentityOf::<T>::iter(),
+		};
+		iter.take(limit as usize).collect()
+	}
+
+	/// Resolve a display name to the account that claimed it.
+	pub fn account_of_name(name: &[u8]) -> Option<T::AccountId> {
+		AccountOfName::<T>::get(T::Hashing::hash(name))
+	}
+
+	/// Calculate the deposit required for a display name.
+	fn name_deposit(name: &DisplayName) -> BalanceOf<T> {
+		T::ByteDeposit::get().saturating_mul(BalanceOf::<T>::from(name.len() as u32))
+	}
+
+	/// Remove the display name claimed by `who`, if any, and return the deposit held for it.
+	fn take_name(who: &T::AccountId) -> BalanceOf<T> {
+		match NameOf::<T>::take(who) {
+			Some((name_hash, deposit)) => {
+				AccountOfName::<T>::remove(&name_hash);
+				deposit
+			},
+			None => Zero::zero(),
 		}
-		Ok(())
 	}
 
 	/// Check if the account has corresponding identity information by the identity field.
@@ -1417,6 +3113,120 @@ impl<T: Config> Pallet<T> {
 			.map_or(false, |registration| (registration.info.has_identity(fields)))
 	}
 
+	/// The account whose identity `who` is known by: `who` itself if it has an identity, or the
+	/// identity at the root of its tree if it is a sub-account.
+	pub fn identity_root(who: &T::AccountId) -> Option<T::AccountId> {
+		if IdentityOf::<T>::contains_key(who) {
+			return Some(who.clone())
+		}
+		Self::super_path(who).pop().filter(|root| IdentityOf::<T>::contains_key(root))
+	}
+
+	/// Check if the identity that `who` is known by, see [`Self::identity_root`], has
+	/// corresponding identity information by the identity field.
+	pub fn has_resolved_identity(
+		who: &T::AccountId,
+		fields: <T::IdentityInformation as IdentityInformationProvider>::FieldsIdentifier,
+	) -> bool {
+		Self::identity_root(who).map_or(false, |root| Self::has_identity(&root, fields))
+	}
+
+	/// The super accounts of `who`, from its direct super account up to the first account with
+	/// an identity. Empty if `who` has an identity or is not a sub-account.
+	///
+	/// At most `MaxSubDepth` accounts are returned. Sub-accounts are removed along with the
+	/// identity of their tree, so the last one, if any, has an identity.
+	pub fn super_path(who: &T::AccountId) -> Vec<T::AccountId> {
+		let mut path = Vec::new();
+		let mut current = who.clone();
+		while (path.len() as u32) < T::MaxSubDepth::get() &&
+			!IdentityOf::<T>::contains_key(&current)
+		{
+			let Some((sup, _)) = SuperOf::<T>::get(&current) else { break };
+			path.push(sup.clone());
+			current = sup;
+		}
+		path
+	}
+
+	/// Ensure that the identity `who` is known by, see [`Self::identity_root`], is not frozen.
+	///
+	/// A frozen identity keeps its information and its whole tree of sub-accounts.
+	fn ensure_not_frozen(who: &T::AccountId) -> DispatchResult {
+		let frozen = Self::identity_root(who)
+			.and_then(IdentityOf::<T>::get)
+			.is_some_and(|id| id.frozen);
+		ensure!(!frozen, Error::<T>::IdentityFrozen);
+		Ok(())
+	}
+
+	/// Whether `who` currently holds any sub-accounts, or has an identity to hold them.
+	fn holds_subs(who: &T::AccountId) -> bool {
+		IdentityOf::<T>::contains_key(who) || SubsOf::<T>::contains_key(who)
+	}
+
+	/// Ensure `who` may add sub-accounts, returning its [`Self::super_path`].
+	///
+	/// It must have an identity, or be a sub-account less than `MaxSubDepth` levels below one.
+	/// Fails with `no_identity` otherwise.
+	fn ensure_can_hold_subs(
+		who: &T::AccountId,
+		no_identity: Error<T>,
+	) -> Result<Vec<T::AccountId>, DispatchError> {
+		if IdentityOf::<T>::contains_key(who) {
+			return Ok(Vec::new())
+		}
+		let path = Self::super_path(who);
+		ensure!((path.len() as u32) < T::MaxSubDepth::get(), Error::<T>::SubDepthExceeded);
+		match path.last() {
+			Some(root) if IdentityOf::<T>::contains_key(root) => Ok(path),
+			_ => Err(no_identity.into()),
+		}
+	}
+
+	/// Ensure `sub` may become a sub-account of the account whose [`Self::super_path`] is `path`.
+	///
+	/// It must not be a sub-account already, nor an account of `path`. Its own sub-accounts
+	/// would be moved down the tree, so it must have an identity to keep them, or none.
+	fn ensure_can_become_sub(sub: &T::AccountId, path: &[T::AccountId]) -> DispatchResult {
+		ensure!(!SuperOf::<T>::contains_key(sub), Error::<T>::AlreadyClaimed);
+		ensure!(!path.contains(sub), Error::<T>::AlreadyClaimed);
+		ensure!(
+			IdentityOf::<T>::contains_key(sub) || !SubsOf::<T>::contains_key(sub),
+			Error::<T>::SubDepthExceeded
+		);
+		Ok(())
+	}
+
+	/// The weight of checking the sub-account tree when adding `subs` sub-accounts: reading the
+	/// [`Self::super_path`] of the super account, and the identity and sub-accounts of each sub.
+	fn sub_tree_weight(subs: u32) -> Weight {
+		T::DbWeight::get()
+			.reads(2u64.saturating_mul(T::MaxSubDepth::get().saturating_add(subs) as u64))
+	}
+
+	/// The weight of removing `removed` sub-accounts along with their own sub-accounts, `nested`
+	/// in total: updating [`NestedSubsOf`], checking the identity, invitations and sub-accounts of
+	/// each removed sub-account, and removing each nested one with its deposit.
+	fn sub_tree_removal_weight(removed: u32, nested: u32) -> Weight {
+		let db = T::DbWeight::get();
+		db.reads_writes(1, 1)
+			.saturating_add(
+				db.reads_writes(3, 2).saturating_mul(removed.saturating_add(nested).into()),
+			)
+			.saturating_add(db.reads_writes(5, 5).saturating_mul(nested.into()))
+	}
+
+	/// The weight of setting the sub-accounts of an account, where `written` sub-accounts are
+	/// added or renamed, `removed` are removed along with `nested` sub-accounts below them and
+	/// `kept` are kept unchanged. Kept sub-accounts are only read.
+	fn set_subs_weight(written: u32, removed: u32, kept: u32, nested: u32) -> Weight {
+		T::WeightInfo::set_subs_diff(written, removed)
+			.saturating_add(Self::sub_tree_weight(written))
+			.saturating_add(Self::sub_tree_removal_weight(removed, nested))
+			.saturating_add(T::DbWeight::get().reads(2u64.saturating_mul(kept as u64)))
+	}
+
 	/// Calculate the deposit required for an identity.
 	fn calculate_identity_deposit(info: &T::IdentityInformation) -> BalanceOf<T> {
 		let bytes = info.encoded_size() as u32;
@@ -1557,17 +3367,64 @@ impl<T: Config> Pallet<T> {
 		// subs
 		let (subs_deposit, sub_ids) = SubsOf::<T>::take(&who);
 		let actual_subs = sub_ids.len() as u32;
-		for sub in sub_ids.iter() {
-			SuperOf::<T>::remove(sub);
-		}
+		Self::remove_sub_tree(who, &sub_ids);
+		ManagerOf::<T>::remove(who);
+		let _ = EvidenceOf::<T>::clear_prefix(who, T::MaxRegistrars::get(), None);
+		let _ = ChallengeOf::<T>::clear_prefix(who, T::MaxRegistrars::get(), None);
+		Self::close_requests(who, &id.judgements);
 
 		// unreserve any deposits
-		let deposit = id.total_deposit().saturating_add(subs_deposit);
-		let err_amount = T::Currency::unreserve(&who, deposit);
-		debug_assert!(err_amount.is_zero());
+		let deposit = id
+			.total_deposit()
+			.saturating_add(subs_deposit)
+			.saturating_add(Self::take_name(who));
+		Deposit::<T>::release(&who, deposit);
+		T::OnIdentityChange::on_identity_removed(who);
 		Ok((registrars, encoded_byte_size, actual_subs))
 	}
 
+	/// Store an identity and sub-accounts of `who` migrated from another chain, holding no
+	/// deposit for either.
+	///
+	/// Judgements with a fee still paid are dropped, as that fee was returned on the source chain
+	/// when the identity was reaped. The deposits are expected to be taken right after with
+	/// [`Self::do_poke_deposit`].
+	///
+	/// Used for migration of Identity info from the Polkadot Relay Chain into a system parachain.
+	pub fn import_identity(
+		who: &T::AccountId,
+		mut registration: RegistrationOf<T>,
+		subs: Vec<(T::AccountId, Data)>,
+	) -> DispatchResult {
+		ensure!(!IdentityOf::<T>::contains_key(who), Error::<T>::AlreadyClaimed);
+		ensure!(!SuperOf::<T>::contains_key(who), Error::<T>::AlreadyClaimed);
+		ensure!(subs.len() <= T::MaxSubAccounts::get() as usize, Error::<T>::TooManySubAccounts);
+		ensure!(
+			subs.iter().all(|(sub, _)| {
+				sub != who &&
+					!SuperOf::<T>::contains_key(sub) &&
+					!IdentityOf::<T>::contains_key(sub)
+			}),
+			Error::<T>::AlreadyClaimed
+		);
+
+		registration.judgements.retain(|(_, judgement)| !judgement.has_deposit());
+		registration.deposit = Zero::zero();
+		IdentityOf::<T>::insert(who, registration);
+
+		if !subs.is_empty() {
+			let mut ids = BoundedVec::<T::AccountId, T::MaxSubAccounts>::default();
+			for (id, name) in subs {
+				SuperOf::<T>::insert(&id, (who.clone(), name));
+				ids.try_push(id).expect("subs length is less than T::MaxSubAccounts; qed");
+			}
+			SubsOf::<T>::insert(who, (BalanceOf::<T>::zero(), ids));
+		}
+
+		T::OnIdentityChange::on_identity_set(who);
+		Ok(())
+	}
+
 	/// Update the deposits held by `target` for its identity info.
 	///
 	/// Parameters:
@@ -1575,9 +3432,9 @@ impl<T: Config> Pallet<T> {
 	///
 	/// Return type is a tuple of the new Identity and Subs deposits, respectively.
 	///
-	/// NOTE: This function is here temporarily for migration of Identity info from the Polkadot
-	/// Relay Chain into a system parachain. It will be removed after the migration.
-	pub fn poke_deposit(
+	/// Used by [`Call::poke_deposit`] and for migration of Identity info from the Polkadot Relay
+	/// Chain into a system parachain.
+	pub fn do_poke_deposit(
 		target: &T::AccountId,
 	) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
 		// Identity Deposit
@@ -1603,7 +3460,7 @@ impl<T: Config> Pallet<T> {
 			SubsOf::<T>::try_mutate(
 				&target,
 				|(current_subs_deposit, subs_of)| -> Result<BalanceOf<T>, DispatchError> {
-					let new_subs_deposit = Self::subs_deposit(subs_of.len() as u32);
+					let new_subs_deposit = Self::subs_deposit(Self::super_funded_subs(subs_of));
 					Self::rejig_deposit(&target, *current_subs_deposit, new_subs_deposit)?;
 					*current_subs_deposit = new_subs_deposit;
 					Ok(new_subs_deposit)
@@ -1629,6 +3486,7 @@ impl<T: Config> Pallet<T> {
 			Registration {
 				judgements: Default::default(),
 				deposit: Zero::zero(),
+				frozen: false,
 				info: info.clone(),
 			},
 		);
@@ -1655,4 +3513,353 @@ impl<T: Config> Pallet<T> {
 		>(&who, (Zero::zero(), sub_accounts));
 		Ok(())
 	}
+
+	/// Ensure the correctness of the state of this pallet.
+	///
+	/// Checks that:
+	/// - every `SuperOf` entry appears in exactly one `SubsOf` entry, namely the one of its super
+	///   account, and is at most `MaxSubDepth` levels below an identity;
+	/// - `NestedSubsOf` counts the sub-accounts of each tree below its direct sub-accounts, and at
+	///   most `MaxSubAccounts` per level;
+	/// - every account in `SubsOf` has a `SuperOf` entry pointing back to its super account;
+	/// - every claimed display name is owned by an account with an identity and resolves back to
+	///   it;
+	/// - every account that authorized an identity manager has an identity;
+	/// - every self-funded sub-account is a sub-account;
+	/// - all evidence is attached to an existing judgement;
+	/// - every account holds at least the identity, judgement fee, sub-account, display name and
+	///   username deposits recorded for it;
+	/// - deposits are held in `DepositAsset` if and only if it is configured.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+		use alloc::collections::btree_map::BTreeMap;
+
+		ensure!(
+			DepositsInAsset::<T>::get() == T::DepositAsset::ENABLED,
+			"`DepositAsset` changed without switching the deposits held by the pallet"
+		);
+
+		let mut nested_subs = BTreeMap::<T::AccountId, u32>::new();
+		for (sub, (super_account, _)) in SuperOf::<T>::iter() {
+			ensure!(
+				SubsOf::<T>::get(&super_account).1.contains(&sub),
+				"`SuperOf` entry is missing from the `SubsOf` of its super account"
+			);
+			let path = Self::super_path(&super_account);
+			ensure!(
+				Self::identity_root(&super_account).is_some() &&
+					(path.len() as u32) < T::MaxSubDepth::get(),
+				"Sub-account is not in the tree of an identity, or more than `MaxSubDepth` levels \
+				below it"
+			);
+			if let Some(root) = path.last() {
+				nested_subs.entry(root.clone()).or_default().saturating_inc();
+			}
+		}
+		ensure!(
+			NestedSubsOf::<T>::iter().collect::<BTreeMap<_, _>>() == nested_subs,
+			"`NestedSubsOf` does not match the sub-accounts below the direct sub-accounts"
+		);
+		ensure!(
+			nested_subs.values().all(|nested| *nested <= Self::max_nested_subs()),
+			"Tree of sub-accounts holds more than `max_nested_subs` nested sub-accounts"
+		);
+
+		let mut deposits = BTreeMap::<T::AccountId, BalanceOf<T>>::new();
+		let mut add_deposit = |who: T::AccountId, amount: BalanceOf<T>| {
+			let deposit = deposits.entry(who).or_insert_with(Zero::zero);
+			*deposit = deposit.saturating_add(amount);
+		};
+
+		let mut subs_count = 0usize;
+		for (super_account, (subs_deposit, subs)) in SubsOf::<T>::iter() {
+			ensure!(!subs.is_empty(), "`SubsOf` entry without sub-accounts");
+			for sub in subs.iter() {
+				ensure!(
+					SuperOf::<T>::get(sub).map(|(s, _)| s) == Some(super_account.clone()),
+					"`SubsOf` entry is missing a `SuperOf` entry pointing back to it"
+				);
+			}
+			subs_count = subs_count.saturating_add(subs.len());
+			add_deposit(super_account, subs_deposit);
+		}
+		// Combined with the checks above, this ensures no sub-account appears in two `SubsOf`.
+		ensure!(
+			subs_count == SuperOf::<T>::iter_keys().count(),
+			"Number of sub-accounts in `SubsOf` does not match `SuperOf`"
+		);
+
+		for (who, (name_hash, deposit)) in NameOf::<T>::iter() {
+			ensure!(
+				AccountOfName::<T>::get(&name_hash).as_ref() == Some(&who),
+				"`NameOf` entry does not resolve back to its account"
+			);
+			ensure!(IdentityOf::<T>::contains_key(&who), "Display name owner has no identity");
+			add_deposit(who, deposit);
+		}
+		ensure!(
+			AccountOfName::<T>::iter_keys().count() == NameOf::<T>::iter_keys().count(),
+			"Number of claimed names in `AccountOfName` does not match `NameOf`"
+		);
+
+		for (sub, deposit) in SubDepositOf::<T>::iter() {
+			ensure!(
+				SuperOf::<T>::contains_key(&sub),
+				"Self-funded sub-account has no super account"
+			);
+			add_deposit(sub, deposit);
+		}
+
+		for (main, (invites_deposit, invites)) in PendingSubsOf::<T>::iter() {
+			ensure!(!invites.is_empty(), "`PendingSubsOf` entry without invitations");
+			add_deposit(main, invites_deposit);
+		}
+
+		for (who, reg_index) in EvidenceOf::<T>::iter_keys() {
+			ensure!(
+				IdentityOf::<T>::get(&who)
+					.is_some_and(|id| id.judgements.iter().any(|j| j.0 == reg_index)),
+				"Evidence without a judgement"
+			);
+		}
+
+		for (who, reg_index) in RequestDeadlineOf::<T>::iter_keys() {
+			ensure!(
+				IdentityOf::<T>::get(&who).is_some_and(|id| id
+					.judgements
+					.iter()
+					.any(|j| j.0 == reg_index && j.1.has_deposit())),
+				"Deadline without a judgement request"
+			);
+		}
+
+		for (who, reg_index) in RequestedFieldsOf::<T>::iter_keys() {
+			ensure!(
+				IdentityOf::<T>::get(&who).is_some_and(|id| id
+					.judgements
+					.iter()
+					.any(|j| j.0 == reg_index && j.1.has_deposit())),
+				"Requested fields without a judgement request"
+			);
+		}
+
+		for (who, reg_index) in ChallengeOf::<T>::iter_keys() {
+			ensure!(IdentityOf::<T>::contains_key(&who), "Challenge without an identity");
+			ensure!(
+				(reg_index as usize) < Registrars::<T>::decode_len().unwrap_or_default(),
+				"Challenge of an unknown registrar"
+			);
+		}
+
+		for main in ManagerOf::<T>::iter_keys() {
+			ensure!(IdentityOf::<T>::contains_key(&main), "Managed account has no identity");
+		}
+
+		let mut pending = BTreeMap::<RegistrarIndex, u32>::new();
+		for (who, registration) in IdentityOf::<T>::iter() {
+			let fees = registration.judgements.iter().fold(
+				BalanceOf::<T>::zero(),
+				|fees, (reg_index, judgement)| match judgement {
+					Judgement::FeePaid(fee) => {
+						pending.entry(*reg_index).or_default().saturating_inc();
+						fees.saturating_add(*fee)
+					},
+					_ => fees,
+				},
+			);
+			add_deposit(who, registration.deposit.saturating_add(fees));
+		}
+		// Requests made before the limit was introduced are not counted.
+		for (reg_index, count) in PendingRequestsOf::<T>::iter() {
+			ensure!(
+				count <= pending.get(&reg_index).copied().unwrap_or_default(),
+				"More pending requests counted than are open on the registrar"
+			);
+		}
+
+		let username_deposits = UsernameInfoOf::<T>::iter()
+			.map(|(username, info)| (username, info.provider))
+			.chain(
+				PendingUsernames::<T>::iter()
+					.map(|(username, (_, _, provider))| (username, provider)),
+			);
+		for (username, provider) in username_deposits {
+			let Provider::AuthorityDeposit(deposit) = provider else { continue };
+			// The deposit of a removed authority can't be attributed to an account anymore.
+			if let Some(authority) = Self::suffix_of_username(&username)
+				.and_then(|suffix| AuthorityOf::<T>::get(&suffix))
+			{
+				add_deposit(authority.account_id, deposit);
+			}
+		}
+
+		for (who, deposit) in deposits {
+			ensure!(
+				Deposit::<T>::held(&who) >= deposit,
+				"Account holds less than its identity deposits"
+			);
+		}
+
+		Ok(())
+	}
+}
+
+/// Verification by the judgements of registrars. A sub-account is checked against the identity at
+/// the root of its tree, and judgements of removed registrars do not count.
+impl<T: Config> IdentityVerifier<T::AccountId> for Pallet<T> {
+	type Level = Judgement<BalanceOf<T>>;
+	type Verifier = RegistrarIndex;
+
+	fn has_identity_level(
+		who: &T::AccountId,
+		level: &Judgement<BalanceOf<T>>,
+		verifiers: &[RegistrarIndex],
+	) -> bool {
+		let Some(registration) = Self::identity_root(who).and_then(IdentityOf::<T>::get) else {
+			return false
+		};
+		let registrars = Registrars::<T>::get();
+		registration.judgements.iter().any(|(reg_index, judgement)| {
+			verifiers.contains(reg_index) &&
+				judgement.is_at_least(level) &&
+				registrars.get(*reg_index as usize).is_some_and(Option::is_some)
+		})
+	}
+}
+
+/// Holds the deposits and judgement fees of the pallet, in [`Config::DepositAsset`] if it is
+/// enabled and in [`Config::Currency`] otherwise.
+struct Deposit<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> Deposit<T> {
+	/// Whether deposits are held in `DepositAsset`. Deposits stay in `Currency` until
+	/// [`DepositsInAsset`] is switched, and storage is only read if `DepositAsset` is enabled.
+	fn in_asset() -> bool {
+		T::DepositAsset::ENABLED && DepositsInAsset::<T>::get()
+	}
+
+	/// Hold `amount` as deposit of `who`.
+	fn hold(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		if !Self::in_asset() {
+			return NativeDeposit::<T>::hold(who, amount)
+		}
+		if amount.is_zero() {
+			return Ok(())
+		}
+		T::DepositAsset::hold(who, amount)
+	}
+
+	/// Release `amount` of the deposit of `who`. Returns the amount actually released.
+	fn release(who: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+		if !Self::in_asset() {
+			return NativeDeposit::<T>::release(who, amount)
+		}
+		if amount.is_zero() {
+			return amount
+		}
+		let released = T::DepositAsset::release(who, amount);
+		if released < amount {
+			frame_support::defensive!("Released deposit is greater than the held balance");
+		}
+		released
+	}
+
+	/// Slash `amount` of the deposit of `who`. If a `reporter` is given, only `T::SlashPortion` of
+	/// it is slashed and the remainder is paid to the reporter.
+	///
+	/// Returns the amounts slashed and paid to the reporter, respectively.
+	fn slash(
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+		reporter: Option<&T::AccountId>,
+	) -> (BalanceOf<T>, BalanceOf<T>) {
+		if !Self::in_asset() {
+			let (imbalance, _) = NativeDeposit::<T>::slash(who, amount);
+			let (slashed, reward) = match reporter {
+				Some(reporter) => {
+					let portion = T::SlashPortion::get() * imbalance.peek();
+					let (slashed, reward) = imbalance.split(portion);
+					let reward_amount = reward.peek();
+					T::Currency::resolve_creating(reporter, reward);
+					(slashed, reward_amount)
+				},
+				None => (imbalance, Zero::zero()),
+			};
+			let slashed_amount = slashed.peek();
+			T::Slashed::on_unbalanced(slashed);
+			return (slashed_amount, reward)
+		}
+
+		let reward = match reporter {
+			Some(reporter) => {
+				let reward = amount.saturating_sub(T::SlashPortion::get() * amount);
+				T::DepositAsset::transfer_held(who, reporter, reward, false)
+					.unwrap_or_else(|_| Zero::zero())
+			},
+			None => Zero::zero(),
+		};
+		(T::DepositAsset::burn_held(who, amount.saturating_sub(reward)), reward)
+	}
+
+	/// Move `amount` of the deposit of `who` to the free balance of `to`. Returns the amount that
+	/// could not be moved.
+	fn repatriate(
+		who: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		if !Self::in_asset() {
+			return NativeDeposit::<T>::repatriate(who, to, amount)
+		}
+		let moved = T::DepositAsset::transfer_held(who, to, amount, false)?;
+		Ok(amount.saturating_sub(moved))
+	}
+
+	/// Move `amount` of the deposit of `who` to the deposit of `to`. Returns the amount that could
+	/// not be moved.
+	fn transfer(
+		who: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		if !Self::in_asset() {
+			return NativeDeposit::<T>::transfer(who, to, amount)
+		}
+		if amount.is_zero() {
+			return Ok(amount)
+		}
+		let moved = T::DepositAsset::transfer_held(who, to, amount, true)?;
+		Ok(amount.saturating_sub(moved))
+	}
+
+	/// Update the deposit of `who` from `old` to `new`, only holding or releasing the difference.
+	/// Fails without any change if `who` cannot afford an increase.
+	fn rejig(who: &T::AccountId, old: BalanceOf<T>, new: BalanceOf<T>) -> DispatchResult {
+		if !Self::in_asset() {
+			return NativeDeposit::<T>::rejig(who, old, new).map(|_| ())
+		}
+		if new > old {
+			Self::hold(who, new - old)?;
+		} else if new < old {
+			Self::release(who, old - new);
+		}
+		Ok(())
+	}
+
+	/// Pay `amount` from the free balance of `who` to `to`, in the currency deposits are held in.
+	fn pay(who: &T::AccountId, to: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		if !Self::in_asset() {
+			return T::Currency::transfer(who, to, amount, KeepAlive)
+		}
+		T::DepositAsset::transfer(who, to, amount)
+	}
+
+	/// The balance held by `who`, including any deposits held by other pallets.
+	#[cfg(any(feature = "try-runtime", test))]
+	fn held(who: &T::AccountId) -> BalanceOf<T> {
+		if !Self::in_asset() {
+			return T::Currency::reserved_balance(who)
+		}
+		T::DepositAsset::balance_on_hold(who)
+	}
 }
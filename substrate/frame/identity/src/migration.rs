@@ -42,6 +42,14 @@ pub mod versioned {
 		crate::pallet::Pallet<T>,
 		<T as frame_system::Config>::DbWeight,
 	>;
+
+	pub type V2ToV3<T> = VersionedMigration<
+		2,
+		3,
+		v3::VersionUncheckedMigrateV2ToV3<T>,
+		crate::pallet::Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
 }
 
 /// The old identity types in v0.
@@ -53,11 +61,7 @@ mod types_v0 {
 		Pallet<T>,
 		Twox64Concat,
 		<T as frame_system::Config>::AccountId,
-		Registration<
-			BalanceOf<T>,
-			<T as pallet::Config>::MaxRegistrars,
-			<T as pallet::Config>::IdentityInformation,
-		>,
+		types_v3::RegistrationOf<T>,
 		OptionQuery,
 	>;
 }
@@ -71,14 +75,7 @@ mod types_v1 {
 		Pallet<T>,
 		Twox64Concat,
 		<T as frame_system::Config>::AccountId,
-		(
-			Registration<
-				BalanceOf<T>,
-				<T as pallet::Config>::MaxRegistrars,
-				<T as pallet::Config>::IdentityInformation,
-			>,
-			Option<Username<T>>,
-		),
+		(types_v3::RegistrationOf<T>, Option<Username<T>>),
 		OptionQuery,
 	>;
 
@@ -111,6 +108,80 @@ mod types_v1 {
 	>;
 }
 
+/// The old registrar types in v2.
+mod types_v2 {
+	use super::*;
+
+	/// Information concerning a registrar, before fee schedules were introduced.
+	#[derive(Encode, Decode)]
+	pub struct RegistrarInfo<Balance, AccountId, IdField> {
+		pub account: AccountId,
+		pub fee: Balance,
+		pub fields: IdField,
+	}
+
+	pub type RegistrarInfoOf<T> = RegistrarInfo<
+		BalanceOf<T>,
+		<T as frame_system::Config>::AccountId,
+		<<T as Config>::IdentityInformation as IdentityInformationProvider>::FieldsIdentifier,
+	>;
+
+	#[storage_alias]
+	pub type Registrars<T: Config> =
+		StorageValue<Pallet<T>, Vec<Option<RegistrarInfoOf<T>>>, ValueQuery>;
+}
+
+/// The old registrar and identity types in v3.
+mod types_v3 {
+	use super::*;
+
+	/// Information on an identity, before identities could be frozen.
+	#[derive(CloneNoBound, Encode, Decode, PartialEqNoBound, RuntimeDebugNoBound)]
+	pub struct Registration<
+		Balance: Encode + Decode + MaxEncodedLen + Copy + Clone + core::fmt::Debug + Eq + PartialEq,
+		MaxJudgements: Get<u32>,
+		IdentityInfo: IdentityInformationProvider,
+	> {
+		pub judgements: BoundedVec<(RegistrarIndex, Judgement<Balance>), MaxJudgements>,
+		pub deposit: Balance,
+		pub info: IdentityInfo,
+	}
+
+	pub type RegistrationOf<T> = Registration<
+		BalanceOf<T>,
+		<T as Config>::MaxRegistrars,
+		<T as Config>::IdentityInformation,
+	>;
+
+	#[storage_alias]
+	pub type IdentityOf<T: Config> = StorageMap<
+		Pallet<T>,
+		Twox64Concat,
+		<T as frame_system::Config>::AccountId,
+		RegistrationOf<T>,
+		OptionQuery,
+	>;
+
+	/// Information concerning a registrar, before judgement requests could be limited.
+	#[derive(Encode, Decode, PartialEq, RuntimeDebug)]
+	pub struct RegistrarInfo<Balance, AccountId, IdField> {
+		pub account: AccountId,
+		pub fee: Balance,
+		pub fields: IdField,
+		pub fee_schedule: FeeSchedule<IdField, Balance, ConstU32<8>>,
+	}
+
+	pub type RegistrarInfoOf<T> = RegistrarInfo<
+		BalanceOf<T>,
+		<T as frame_system::Config>::AccountId,
+		<<T as Config>::IdentityInformation as IdentityInformationProvider>::FieldsIdentifier,
+	>;
+
+	#[storage_alias]
+	pub type Registrars<T: Config> =
+		StorageValue<Pallet<T>, Vec<Option<RegistrarInfoOf<T>>>, ValueQuery>;
+}
+
 pub mod v1 {
 	use super::*;
 
@@ -217,14 +288,7 @@ pub mod v2 {
 	#[derive(Encode, Decode)]
 	struct TryRuntimeState<T: Config> {
 		authorities: BTreeMap<Suffix<T>, (T::AccountId, u32)>,
-		identities: BTreeMap<
-			T::AccountId,
-			Registration<
-				BalanceOf<T>,
-				<T as Config>::MaxRegistrars,
-				<T as Config>::IdentityInformation,
-			>,
-		>,
+		identities: BTreeMap<T::AccountId, types_v3::RegistrationOf<T>>,
 		primary_usernames: BTreeMap<T::AccountId, Username<T>>,
 		usernames: BTreeMap<Username<T>, T::AccountId>,
 		pending_usernames: BTreeMap<Username<T>, (T::AccountId, BlockNumberFor<T>)>,
@@ -374,7 +438,7 @@ pub mod v2 {
 			}
 			assert!(prev_state.authorities.is_empty());
 
-			for (account, identity) in IdentityOf::<T>::iter() {
+			for (account, identity) in types_v3::IdentityOf::<T>::iter() {
 				assert!(identity.deposit > 0u32.into());
 				let prev_identity = prev_state
 					.identities
@@ -490,15 +554,8 @@ pub mod v2 {
 		// it. Remove the entry if there was no real identity associated with the account.
 		pub(crate) fn identity_step(maybe_last_key: Option<HashedKey>) -> StepResultOf<T> {
 			if let Some(mut last_key) =
-				IdentityOf::<T>::translate_next::<
-					(
-						Registration<
-							BalanceOf<T>,
-							<T as pallet::Config>::MaxRegistrars,
-							<T as pallet::Config>::IdentityInformation,
-						>,
-						Option<Username<T>>,
-					),
+				types_v3::IdentityOf::<T>::translate_next::<
+					(types_v3::RegistrationOf<T>, Option<Username<T>>),
 					_,
 				>(maybe_last_key.map(|b| b.to_vec()), |account, (identity, maybe_username)| {
 					if let Some(primary_username) = maybe_username {
@@ -598,11 +655,11 @@ pub mod v2 {
 
 			let username: Username<T> = b"account.bench".to_vec().try_into().unwrap();
 			let info = T::IdentityInformation::create_identity_info();
-			let registration: Registration<
-				BalanceOf<T>,
-				<T as Config>::MaxRegistrars,
-				<T as Config>::IdentityInformation,
-			> = Registration { judgements: Default::default(), deposit: 10u32.into(), info };
+			let registration = types_v3::RegistrationOf::<T> {
+				judgements: Default::default(),
+				deposit: 10u32.into(),
+				info,
+			};
 			frame_support::migration::put_storage_value(
 				b"Identity",
 				b"IdentityOf",
@@ -634,12 +691,12 @@ pub mod v2 {
 
 			let username: Username<T> = b"account.bench".to_vec().try_into().unwrap();
 			let info = T::IdentityInformation::create_identity_info();
-			let registration: Registration<
-				BalanceOf<T>,
-				<T as Config>::MaxRegistrars,
-				<T as Config>::IdentityInformation,
-			> = Registration { judgements: Default::default(), deposit: 10u32.into(), info };
-			IdentityOf::<T>::insert(&account_id, &registration);
+			let registration = types_v3::RegistrationOf::<T> {
+				judgements: Default::default(),
+				deposit: 10u32.into(),
+				info,
+			};
+			types_v3::IdentityOf::<T>::insert(&account_id, &registration);
 			UsernameOf::<T>::insert(&account_id, &username);
 			let username_info = UsernameInformation {
 				owner: account_id.clone(),
@@ -676,14 +733,8 @@ pub mod v2 {
 		use super::*;
 		use crate::tests::{new_test_ext, Test};
 
-		fn registration(
-			with_deposit: bool,
-		) -> Registration<
-			BalanceOf<Test>,
-			<Test as Config>::MaxRegistrars,
-			<Test as Config>::IdentityInformation,
-		> {
-			Registration {
+		fn registration(with_deposit: bool) -> types_v3::RegistrationOf<Test> {
+			types_v3::RegistrationOf::<Test> {
 				judgements: Default::default(),
 				deposit: if with_deposit { 10u32.into() } else { 0u32.into() },
 				info: Default::default(),
@@ -811,7 +862,7 @@ pub mod v2 {
 					assert_eq!(&username_info.owner, owner);
 					let actual_primary = UsernameOf::<Test>::get(owner).unwrap();
 					assert_eq!(primary, &actual_primary);
-					assert_eq!(IdentityOf::<Test>::contains_key(owner), *has_identity);
+					assert_eq!(types_v3::IdentityOf::<Test>::contains_key(owner), *has_identity);
 					if let Some(secondary) = maybe_secondary {
 						let expected_info = UsernameInformation {
 							owner: owner.clone(),
@@ -824,10 +875,10 @@ pub mod v2 {
 				// Check that existing identities were preserved.
 				for id in identity_only.iter() {
 					let expected_reg = registration(true);
-					assert_eq!(IdentityOf::<Test>::get(id), Some(expected_reg));
+					assert_eq!(types_v3::IdentityOf::<Test>::get(id), Some(expected_reg));
 					assert!(!UsernameOf::<Test>::contains_key(id));
 				}
-				let identity_count = IdentityOf::<Test>::iter().count();
+				let identity_count = types_v3::IdentityOf::<Test>::iter().count();
 				assert_eq!(
 					identity_count,
 					count_of_usernames_without_identities + identity_only.len()
@@ -848,3 +899,759 @@ pub mod v2 {
 		}
 	}
 }
+
+pub mod v3 {
+	use super::*;
+
+	/// The log target.
+	const TARGET: &'static str = "runtime::identity::migration::v3";
+
+	/// Migration to add an empty fee schedule to every registrar.
+	pub struct VersionUncheckedMigrateV2ToV3<T>(PhantomData<T>);
+	impl<T: Config> UncheckedOnRuntimeUpgrade for VersionUncheckedMigrateV2ToV3<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let fees = types_v2::Registrars::<T>::get()
+				.into_iter()
+				.map(|r| r.map(|r| (r.account, r.fee, r.fields)))
+				.collect::<Vec<_>>();
+			Ok(fees.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			log::info!(
+				target: TARGET,
+				"running storage migration from version 2 to version 3."
+			);
+
+			let translated = types_v3::Registrars::<T>::translate::<
+				Vec<Option<types_v2::RegistrarInfoOf<T>>>,
+				_,
+			>(|registrars| {
+				let registrars = registrars?
+					.into_iter()
+					.map(|r| {
+						r.map(|r| types_v3::RegistrarInfo {
+							account: r.account,
+							fee: r.fee,
+							fields: r.fields,
+							fee_schedule: Default::default(),
+						})
+					})
+					.collect::<Vec<_>>();
+				Some(registrars)
+			});
+			if translated.is_err() {
+				log::error!(target: TARGET, "failed to decode the registrars.");
+			}
+
+			T::DbWeight::get().reads_writes(1, 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let expected: Vec<
+				Option<(
+					T::AccountId,
+					BalanceOf<T>,
+					<T::IdentityInformation as IdentityInformationProvider>::FieldsIdentifier,
+				)>,
+			> = Decode::decode(&mut &state[..]).expect("failed to decode the state from pre-upgrade.");
+			let registrars = types_v3::Registrars::<T>::get();
+			ensure!(registrars.len() == expected.len(), "must migrate all registrars.");
+			ensure!(
+				registrars.iter().flatten().all(|r| r.fee_schedule.is_empty()),
+				"fee schedules must be empty."
+			);
+			let migrated = registrars
+				.into_iter()
+				.map(|r| r.map(|r| (r.account, r.fee, r.fields)))
+				.collect::<Vec<_>>();
+			ensure!(migrated == expected, "must migrate all registrars.");
+			log::info!(target: TARGET, "migrated all registrars.");
+			Ok(())
+		}
+	}
+
+	#[cfg(test)]
+	mod tests {
+		use super::*;
+		use crate::tests::{new_test_ext, Test};
+
+		#[test]
+		fn migrate_to_v3() {
+			new_test_ext().execute_with(|| {
+				let account: <Test as frame_system::Config>::AccountId = [3u8; 32].into();
+				let old = vec![
+					Some(types_v2::RegistrarInfoOf::<Test> {
+						account: account.clone(),
+						fee: 10,
+						fields: 1,
+					}),
+					None,
+				];
+				types_v2::Registrars::<Test>::put(old);
+
+				VersionUncheckedMigrateV2ToV3::<Test>::on_runtime_upgrade();
+
+				assert_eq!(
+					types_v3::Registrars::<Test>::get(),
+					vec![
+						Some(types_v3::RegistrarInfoOf::<Test> {
+							account,
+							fee: 10,
+							fields: 1,
+							fee_schedule: Default::default(),
+						}),
+						None,
+					]
+				);
+			});
+		}
+	}
+}
+
+pub mod v4 {
+	use super::*;
+	use frame_support::{
+		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+		weights::WeightMeter,
+	};
+
+	/// The log target.
+	const TARGET: &'static str = "runtime::identity::migration::v4";
+
+	type HashedKey = BoundedVec<u8, ConstU32<256>>;
+
+	/// Progressive states of a migration. The migration starts with the first variant and ends with
+	/// the last.
+	#[derive(Decode, Encode, MaxEncodedLen, Eq, PartialEq)]
+	pub enum MigrationState {
+		FinishedRegistrars,
+		Identity(HashedKey),
+		Finished,
+	}
+
+	#[cfg(feature = "try-runtime")]
+	type TryRuntimeStateOf<T> = (
+		Vec<Option<types_v3::RegistrarInfoOf<T>>>,
+		BTreeMap<RegistrarIndex, u32>,
+		BTreeMap<<T as frame_system::Config>::AccountId, types_v3::RegistrationOf<T>>,
+	);
+
+	/// Migration to leave judgement requests unlimited on every registrar, to count the judgement
+	/// requests already open on each in [`PendingRequestsOf`], and to add the `frozen` flag to
+	/// every identity.
+	///
+	/// The registrars are migrated in the first step, then one identity is migrated per step.
+	pub struct LazyMigrationV3ToV4<T: Config>(PhantomData<T>);
+	impl<T: Config> SteppedMigration for LazyMigrationV3ToV4<T> {
+		type Cursor = MigrationState;
+		type Identifier = MigrationId<15>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 3, version_to: 4 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			if Pallet::<T>::on_chain_storage_version() != Self::id().version_from as u16 {
+				return Ok(None);
+			}
+
+			// Check that we have enough weight for at least the next step. If we don't, then the
+			// migration cannot be complete.
+			let required = Self::required_weight(cursor.as_ref());
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			loop {
+				// Check that we would have enough weight to perform this step in the worst case
+				// scenario.
+				let required_weight = Self::required_weight(cursor.as_ref());
+				if !meter.can_consume(required_weight) {
+					break;
+				}
+
+				let next = match &cursor {
+					// At first, migrate the registrars.
+					None => Self::registrars_step(),
+					// Then migrate the identities, counting their open judgement requests.
+					Some(MigrationState::FinishedRegistrars) => Self::identity_step(None),
+					// Keep migrating identities.
+					Some(MigrationState::Identity(last_key)) =>
+						Self::identity_step(Some(last_key.clone())),
+					// After the last identity was migrated, the migration is done.
+					Some(MigrationState::Finished) => {
+						StorageVersion::new(Self::id().version_to as u16).put::<Pallet<T>>();
+						log::info!(target: TARGET, "migrated all registrars and identities.");
+						return Ok(None)
+					},
+				};
+
+				cursor = Some(next);
+				meter.consume(required_weight);
+			}
+
+			Ok(cursor)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let mut pending = BTreeMap::new();
+			let identities = types_v3::IdentityOf::<T>::iter()
+				.map(|(account, registration)| {
+					for (reg_index, judgement) in registration.judgements.iter() {
+						if judgement.has_deposit() {
+							pending.entry(*reg_index).or_insert(0u32).saturating_inc();
+						}
+					}
+					(account, registration)
+				})
+				.collect::<BTreeMap<_, _>>();
+			let state: TryRuntimeStateOf<T> =
+				(types_v3::Registrars::<T>::get(), pending, identities);
+			Ok(state.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let (expected, pending, identities): TryRuntimeStateOf<T> =
+				Decode::decode(&mut &state[..])
+					.expect("failed to decode the state from pre-upgrade.");
+			ensure!(
+				PendingRequestsOf::<T>::iter().collect::<BTreeMap<_, _>>() == pending,
+				"must count all open judgement requests."
+			);
+			let migrated = IdentityOf::<T>::iter()
+				.map(|(account, r)| {
+					ensure!(!r.frozen, "identities must not be frozen.");
+					Ok((
+						account,
+						types_v3::Registration {
+							judgements: r.judgements,
+							deposit: r.deposit,
+							info: r.info,
+						},
+					))
+				})
+				.collect::<Result<BTreeMap<_, _>, TryRuntimeError>>()?;
+			ensure!(migrated == identities, "must migrate all identities.");
+			let registrars = Registrars::<T>::get();
+			ensure!(registrars.len() == expected.len(), "must migrate all registrars.");
+			ensure!(
+				registrars.iter().flatten().all(|r| r.max_pending.is_none()),
+				"judgement requests must be unlimited."
+			);
+			let migrated = registrars
+				.into_iter()
+				.map(|r| {
+					r.map(|r| types_v3::RegistrarInfo {
+						account: r.account,
+						fee: r.fee,
+						fields: r.fields,
+						fee_schedule: BoundedVec::truncate_from(r.fee_schedule.into_inner()),
+					})
+				})
+				.collect::<Vec<_>>();
+			ensure!(migrated == expected, "must migrate all registrars.");
+			Ok(())
+		}
+	}
+
+	impl<T: Config> LazyMigrationV3ToV4<T> {
+		pub(crate) fn required_weight(step: Option<&MigrationState>) -> Weight {
+			match step {
+				None => T::WeightInfo::migration_v4_registrars_step(),
+				Some(MigrationState::FinishedRegistrars) | Some(MigrationState::Identity(_)) =>
+					T::WeightInfo::migration_v4_identity_step(),
+				Some(MigrationState::Finished) => Weight::zero(),
+			}
+		}
+
+		// Migrate all registrars, leaving their judgement requests unlimited.
+		pub(crate) fn registrars_step() -> MigrationState {
+			let translated = Registrars::<T>::translate::<
+				Vec<Option<types_v3::RegistrarInfoOf<T>>>,
+				_,
+			>(|registrars| {
+				let registrars = registrars?
+					.into_iter()
+					.map(|r| {
+						r.map(|r| RegistrarInfo {
+							account: r.account,
+							fee: r.fee,
+							fields: r.fields,
+							// Truncated if the runtime allows fewer tiers than the old limit of 8.
+							fee_schedule: BoundedVec::truncate_from(r.fee_schedule.into_inner()),
+							max_pending: None,
+						})
+					})
+					.collect::<Vec<_>>();
+				Some(BoundedVec::truncate_from(registrars))
+			});
+			if translated.is_err() {
+				log::error!(target: TARGET, "failed to decode the registrars.");
+			}
+			MigrationState::FinishedRegistrars
+		}
+
+		// Migrate one entry of `IdentityOf` and count its judgement requests with a fee paid in
+		// `PendingRequestsOf`.
+		pub(crate) fn identity_step(maybe_last_key: Option<HashedKey>) -> MigrationState {
+			if let Some(mut last_key) =
+				IdentityOf::<T>::translate_next::<types_v3::RegistrationOf<T>, _>(
+					maybe_last_key.map(|b| b.to_vec()),
+					|_, registration| {
+						for (reg_index, judgement) in registration.judgements.iter() {
+							if judgement.has_deposit() {
+								PendingRequestsOf::<T>::mutate(reg_index, |pending| {
+									pending.saturating_inc()
+								});
+							}
+						}
+						Some(Registration {
+							judgements: registration.judgements,
+							deposit: registration.deposit,
+							frozen: false,
+							info: registration.info,
+						})
+					},
+				) {
+				last_key.truncate(HashedKey::bound());
+				MigrationState::Identity(
+					HashedKey::try_from(last_key)
+						.expect("truncated to bound so the conversion must succeed; qed"),
+				)
+			} else {
+				MigrationState::Finished
+			}
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	impl<T: Config> LazyMigrationV3ToV4<T> {
+		/// Store an identity in the old format with a judgement request open on every registrar,
+		/// returning its owner.
+		pub(crate) fn setup_benchmark_env_for_migration() -> T::AccountId {
+			let account_id: T::AccountId = frame_benchmarking::account("account", 1, 0);
+			let registrar: T::AccountId = frame_benchmarking::account("registrar", 0, 0);
+			let registrars = (0..T::MaxRegistrars::get())
+				.map(|_| {
+					Some(types_v3::RegistrarInfoOf::<T> {
+						account: registrar.clone(),
+						fee: 10u32.into(),
+						fields: Default::default(),
+						fee_schedule: Default::default(),
+					})
+				})
+				.collect::<Vec<_>>();
+			types_v3::Registrars::<T>::put(registrars);
+			let judgements = (0..T::MaxRegistrars::get())
+				.map(|reg_index| (reg_index, Judgement::FeePaid(10u32.into())))
+				.collect::<Vec<_>>();
+			let registration = types_v3::RegistrationOf::<T> {
+				judgements: BoundedVec::truncate_from(judgements),
+				deposit: 10u32.into(),
+				info: T::IdentityInformation::create_identity_info(),
+			};
+			types_v3::IdentityOf::<T>::insert(&account_id, &registration);
+			account_id
+		}
+	}
+
+	#[cfg(test)]
+	mod tests {
+		use super::*;
+		use crate::tests::{infoof_ten, new_test_ext, Test};
+
+		fn account_from_u8(byte: u8) -> <Test as frame_system::Config>::AccountId {
+			[byte; 32].into()
+		}
+
+		#[test]
+		fn migrate_to_v4() {
+			new_test_ext().execute_with(|| {
+				StorageVersion::new(3).put::<Pallet<Test>>();
+				let account = account_from_u8(3);
+				let fee_schedule = vec![(1, 5)];
+				let old = vec![
+					None,
+					Some(types_v3::RegistrarInfoOf::<Test> {
+						account: account.clone(),
+						fee: 10,
+						fields: 3,
+						fee_schedule: BoundedVec::truncate_from(fee_schedule.clone()),
+					}),
+				];
+				types_v3::Registrars::<Test>::put(old);
+				let judgements = BoundedVec::truncate_from(vec![
+					(0, Judgement::Reasonable),
+					(1, Judgement::FeePaid(10)),
+				]);
+				for i in 10u8..20u8 {
+					types_v3::IdentityOf::<Test>::insert(
+						&account_from_u8(i),
+						types_v3::RegistrationOf::<Test> {
+							judgements: judgements.clone(),
+							deposit: 10,
+							info: infoof_ten(),
+						},
+					);
+				}
+
+				// Run the migration with room for the registrars and two identities per step.
+				let limit = LazyMigrationV3ToV4::<Test>::required_weight(None).saturating_add(
+					LazyMigrationV3ToV4::<Test>::required_weight(Some(
+						&MigrationState::FinishedRegistrars,
+					))
+					.saturating_mul(2),
+				);
+				let mut cursor = None;
+				let mut steps = 0;
+				loop {
+					let mut weight_meter = WeightMeter::with_limit(limit);
+					cursor = LazyMigrationV3ToV4::<Test>::step(cursor, &mut weight_meter).unwrap();
+					steps += 1;
+					if cursor.is_none() {
+						break;
+					}
+					assert_eq!(Pallet::<Test>::on_chain_storage_version(), 3);
+				}
+				// The registrars and two identities in the first step, then two identities per
+				// step until the cursor reaches the end.
+				assert_eq!(steps, 6);
+				assert_eq!(Pallet::<Test>::on_chain_storage_version(), 4);
+
+				assert_eq!(PendingRequestsOf::<Test>::iter().collect::<Vec<_>>(), vec![(1, 10)]);
+				for i in 10u8..20u8 {
+					assert_eq!(
+						IdentityOf::<Test>::get(&account_from_u8(i)),
+						Some(Registration {
+							judgements: judgements.clone(),
+							deposit: 10,
+							frozen: false,
+							info: infoof_ten()
+						})
+					);
+				}
+
+				assert_eq!(
+					Registrars::<Test>::get().into_inner(),
+					vec![
+						None,
+						Some(RegistrarInfo {
+							account,
+							fee: 10,
+							fields: 3,
+							fee_schedule: BoundedVec::truncate_from(fee_schedule),
+							max_pending: None,
+						}),
+					]
+				);
+
+				// The migration does nothing once the storage version was bumped.
+				assert!(LazyMigrationV3ToV4::<Test>::step(None, &mut WeightMeter::new())
+					.unwrap()
+					.is_none());
+				assert_eq!(PendingRequestsOf::<Test>::get(1), 10);
+			});
+		}
+	}
+}
+
+pub mod deposit_asset {
+	use super::*;
+	use frame_support::traits::OnRuntimeUpgrade;
+
+	/// The log target.
+	const TARGET: &'static str = "runtime::identity::migration::deposit_asset";
+
+	/// Migration to hold deposits and judgement fees in [`Config::DepositAsset`] if it is
+	/// configured, or in [`Config::Currency`] otherwise, after the runtime changed it.
+	///
+	/// Deposits can't be moved from one to the other, as accounts may not even own the new one.
+	/// The switch is thus only made once the pallet holds no deposits. Until then, the deposits
+	/// stay where they are held and the migration must be run again.
+	pub struct SwitchDepositAsset<T>(PhantomData<T>);
+
+	impl<T: Config> SwitchDepositAsset<T> {
+		/// Whether the pallet holds any deposit or judgement fee, along with the number of reads.
+		fn holds_deposits() -> (bool, u64) {
+			let username_deposit = |provider: ProviderOf<T>| match provider {
+				Provider::AuthorityDeposit(deposit) => deposit,
+				_ => Zero::zero(),
+			};
+			let mut deposits = IdentityOf::<T>::iter_values()
+				.map(|id| id.total_deposit())
+				.chain(SubsOf::<T>::iter_values().map(|(deposit, _)| deposit))
+				.chain(SubDepositOf::<T>::iter_values())
+				.chain(PendingSubsOf::<T>::iter_values().map(|(deposit, _)| deposit))
+				.chain(NameOf::<T>::iter_values().map(|(_, deposit)| deposit))
+				.chain(
+					UsernameInfoOf::<T>::iter_values().map(|info| username_deposit(info.provider)),
+				)
+				.chain(
+					PendingUsernames::<T>::iter_values()
+						.map(|(_, _, provider)| username_deposit(provider)),
+				);
+			// Every map is read once more to find its end.
+			let mut reads = 7u64;
+			let held = deposits.any(|deposit: BalanceOf<T>| {
+				reads.saturating_inc();
+				!deposit.is_zero()
+			});
+			(held, reads)
+		}
+	}
+
+	impl<T: Config> OnRuntimeUpgrade for SwitchDepositAsset<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let in_asset = T::DepositAsset::ENABLED;
+			if DepositsInAsset::<T>::get() == in_asset {
+				log::info!(target: TARGET, "deposits are already held where configured.");
+				return T::DbWeight::get().reads(1)
+			}
+			let (held, reads) = Self::holds_deposits();
+			if held {
+				log::error!(
+					target: TARGET,
+					"deposits are still held, they must all be released before switching."
+				);
+				return T::DbWeight::get().reads(reads.saturating_add(1))
+			}
+			DepositsInAsset::<T>::put(in_asset);
+			log::info!(
+				target: TARGET,
+				"deposits are now held in {}.",
+				if in_asset { "`DepositAsset`" } else { "`Currency`" }
+			);
+			T::DbWeight::get().reads_writes(reads.saturating_add(1), 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(
+				DepositsInAsset::<T>::get() == T::DepositAsset::ENABLED,
+				"deposits must be released before `DepositAsset` can be changed."
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod held_deposits {
+	use super::*;
+	use frame_support::traits::HeldDeposits;
+
+	/// The position in the maps holding deposits, in the order they are visited, along with the
+	/// last key visited in the map.
+	#[derive(Decode, Encode, MaxEncodedLen, Eq, PartialEq, Clone, RuntimeDebug)]
+	pub enum DepositCursor<A, U> {
+		Identity(Option<A>),
+		Subs(Option<A>),
+		SubDeposit(Option<A>),
+		PendingSubs(Option<A>),
+		Name(Option<A>),
+		Username(Option<U>),
+		PendingUsername(Option<U>),
+	}
+
+	impl<A, U> DepositCursor<A, U> {
+		/// The start of the map visited after the map of `self`.
+		fn next_map(&self) -> Option<Self> {
+			Some(match self {
+				Self::Identity(_) => Self::Subs(None),
+				Self::Subs(_) => Self::SubDeposit(None),
+				Self::SubDeposit(_) => Self::PendingSubs(None),
+				Self::PendingSubs(_) => Self::Name(None),
+				Self::Name(_) => Self::Username(None),
+				Self::Username(_) => Self::PendingUsername(None),
+				Self::PendingUsername(_) => return None,
+			})
+		}
+	}
+
+	type DepositCursorOf<T> = DepositCursor<<T as frame_system::Config>::AccountId, Username<T>>;
+	type NextDepositOf<T> =
+		(DepositCursorOf<T>, Option<(<T as frame_system::Config>::AccountId, BalanceOf<T>)>);
+
+	/// Enumerates the deposits and judgement fees held by the pallet in [`Config::Currency`], e.g.
+	/// to seed the [`Config::DepositTracker`] of a pallet which held deposits before it was
+	/// tracked.
+	///
+	/// Username deposits of removed authorities cannot be attributed to an account anymore. No
+	/// deposits are enumerated while they are held in [`Config::DepositAsset`].
+	pub struct IdentityDeposits<T>(PhantomData<T>);
+
+	impl<T: Config> IdentityDeposits<T> {
+		/// The deposit of the username `username` granted by an authority, if any.
+		fn username_deposit(
+			username: &Username<T>,
+			provider: ProviderOf<T>,
+		) -> Option<(T::AccountId, BalanceOf<T>)> {
+			let Provider::AuthorityDeposit(deposit) = provider else { return None };
+			let suffix = Pallet::<T>::suffix_of_username(username)?;
+			AuthorityOf::<T>::get(&suffix).map(|authority| (authority.account_id, deposit))
+		}
+
+		/// The first entry after `cursor` in the map of `cursor`.
+		fn next_in_map(cursor: &DepositCursorOf<T>) -> Option<NextDepositOf<T>> {
+			match cursor {
+				DepositCursor::Identity(last) => last
+					.as_ref()
+					.map_or_else(IdentityOf::<T>::iter, |last| {
+						IdentityOf::<T>::iter_from(IdentityOf::<T>::hashed_key_for(last))
+					})
+					.next()
+					.map(|(who, id)| {
+						(
+							DepositCursor::Identity(Some(who.clone())),
+							Some((who, id.total_deposit())),
+						)
+					}),
+				DepositCursor::Subs(last) => last
+					.as_ref()
+					.map_or_else(SubsOf::<T>::iter, |last| {
+						SubsOf::<T>::iter_from(SubsOf::<T>::hashed_key_for(last))
+					})
+					.next()
+					.map(|(who, (deposit, _))| {
+						(DepositCursor::Subs(Some(who.clone())), Some((who, deposit)))
+					}),
+				DepositCursor::SubDeposit(last) => last
+					.as_ref()
+					.map_or_else(SubDepositOf::<T>::iter, |last| {
+						SubDepositOf::<T>::iter_from(SubDepositOf::<T>::hashed_key_for(last))
+					})
+					.next()
+					.map(|(who, deposit)| {
+						(DepositCursor::SubDeposit(Some(who.clone())), Some((who, deposit)))
+					}),
+				DepositCursor::PendingSubs(last) => last
+					.as_ref()
+					.map_or_else(PendingSubsOf::<T>::iter, |last| {
+						PendingSubsOf::<T>::iter_from(PendingSubsOf::<T>::hashed_key_for(last))
+					})
+					.next()
+					.map(|(who, (deposit, _))| {
+						(DepositCursor::PendingSubs(Some(who.clone())), Some((who, deposit)))
+					}),
+				DepositCursor::Name(last) => last
+					.as_ref()
+					.map_or_else(NameOf::<T>::iter, |last| {
+						NameOf::<T>::iter_from(NameOf::<T>::hashed_key_for(last))
+					})
+					.next()
+					.map(|(who, (_, deposit))| {
+						(DepositCursor::Name(Some(who.clone())), Some((who, deposit)))
+					}),
+				DepositCursor::Username(last) => last
+					.as_ref()
+					.map_or_else(UsernameInfoOf::<T>::iter, |last| {
+						UsernameInfoOf::<T>::iter_from(UsernameInfoOf::<T>::hashed_key_for(last))
+					})
+					.next()
+					.map(|(username, info)| {
+						let deposit = Self::username_deposit(&username, info.provider);
+						(DepositCursor::Username(Some(username)), deposit)
+					}),
+				DepositCursor::PendingUsername(last) => last
+					.as_ref()
+					.map_or_else(PendingUsernames::<T>::iter, |last| {
+						PendingUsernames::<T>::iter_from(PendingUsernames::<T>::hashed_key_for(
+							last,
+						))
+					})
+					.next()
+					.map(|(username, (_, _, provider))| {
+						let deposit = Self::username_deposit(&username, provider);
+						(DepositCursor::PendingUsername(Some(username)), deposit)
+					}),
+			}
+		}
+	}
+
+	impl<T: Config> HeldDeposits<T::AccountId, BalanceOf<T>> for IdentityDeposits<T> {
+		type Cursor = DepositCursorOf<T>;
+
+		fn next_deposit(cursor: Option<Self::Cursor>) -> Option<NextDepositOf<T>> {
+			if Deposit::<T>::in_asset() {
+				return None;
+			}
+			let mut cursor = cursor.unwrap_or(DepositCursor::Identity(None));
+			loop {
+				if let Some(next) = Self::next_in_map(&cursor) {
+					return Some(next);
+				}
+				cursor = cursor.next_map()?;
+			}
+		}
+
+		fn next_deposit_weight() -> Weight {
+			T::WeightInfo::held_deposits_step()
+		}
+	}
+
+	#[cfg(test)]
+	mod tests {
+		use super::*;
+		use crate::tests::{account, infoof_ten, new_test_ext, Test};
+
+		#[test]
+		fn held_deposits_are_enumerated() {
+			new_test_ext().execute_with(|| {
+				let (alice, bob) = (account(1), account(2));
+				let registration = RegistrationOf::<Test> {
+					judgements: BoundedVec::truncate_from(vec![(0, Judgement::FeePaid(5))]),
+					deposit: 10,
+					info: infoof_ten(),
+				};
+				IdentityOf::<Test>::insert(&alice, registration);
+				SubDepositOf::<Test>::insert(&bob, 3);
+				NameOf::<Test>::insert(&bob, (Default::default(), 4));
+
+				let authority = account(3);
+				let suffix: Suffix<Test> = b"test".to_vec().try_into().unwrap();
+				AuthorityOf::<Test>::insert(
+					&suffix,
+					AuthorityProperties { account_id: authority.clone(), allocation: 0 },
+				);
+				let username: Username<Test> = b"alice.test".to_vec().try_into().unwrap();
+				UsernameInfoOf::<Test>::insert(
+					&username,
+					UsernameInformation {
+						owner: alice.clone(),
+						provider: Provider::AuthorityDeposit(7),
+					},
+				);
+				// The authority of the suffix was removed.
+				let orphan: Username<Test> = b"bob.gone".to_vec().try_into().unwrap();
+				PendingUsernames::<Test>::insert(
+					&orphan,
+					(bob.clone(), 0, Provider::AuthorityDeposit(8)),
+				);
+
+				let mut cursor = None;
+				let mut deposits = vec![];
+				while let Some((next, deposit)) = IdentityDeposits::<Test>::next_deposit(cursor) {
+					deposits.push(deposit);
+					cursor = Some(next);
+				}
+				assert_eq!(
+					deposits,
+					vec![
+						Some((alice, 15)),
+						Some((bob.clone(), 3)),
+						Some((bob, 4)),
+						Some((authority, 7)),
+						None
+					]
+				);
+			});
+		}
+	}
+}
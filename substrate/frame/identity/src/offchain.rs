@@ -0,0 +1,244 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Social-proof verification through off-chain workers.
+//!
+//! A registrar authorizes an attester key with [`Call::set_attester`] and posts verification
+//! challenges for identities with [`Call::set_challenge`]. A challenge asks the owner of the
+//! identity to publish a nonce at a given URL, e.g. a web page or a social media post linked from
+//! one of the identity fields.
+//!
+//! Nodes holding the attester key in their keystore fetch the URL of each pending challenge of the
+//! registrar from their off-chain worker. If the response contains the nonce, they sign an
+//! [`Attestation`] and submit it through [`Config::SubmitAttestation`], usually as an unsigned
+//! transaction calling [`Call::submit_attestation`]. The challenge is then marked as verified,
+//! which the registrar can rely on when giving its judgement.
+//!
+//! The subsystem is inactive unless a node holds an attester key and the runtime configures a
+//! submitter, e.g. [`UnsignedAttestations`].
+
+use super::*;
+use frame_support::pallet_prelude::*;
+use frame_system::offchain::{CreateInherent, SubmitTransaction};
+use sp_runtime::{
+	offchain::{http, storage::StorageValueRef, Duration},
+	KeyTypeId, RuntimeAppPublic,
+};
+
+const LOG_TARGET: &str = "runtime::identity::offchain";
+
+/// Key type of the keys used by off-chain workers to sign attestations.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"idty");
+
+/// The attester key, used to sign [`Attestation`]s.
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_application_crypto::{app_crypto, sr25519};
+	app_crypto!(sr25519, KEY_TYPE);
+}
+
+/// The URL that a verification challenge is published at.
+pub type ChallengeUrl = BoundedVec<u8, ConstU32<256>>;
+
+/// The nonce that must be published to pass a verification challenge.
+pub type ChallengeNonce = BoundedVec<u8, ConstU32<64>>;
+
+/// The maximum number of bytes of a response read when checking a challenge.
+const MAX_RESPONSE_LEN: usize = 64 * 1024;
+
+/// The time allowed for fetching the URL of a challenge, in milliseconds.
+const FETCH_TIMEOUT_MS: u64 = 5_000;
+
+/// The number of blocks to wait before checking a failed challenge again.
+const RECHECK_INTERVAL: u32 = 10;
+
+/// The priority of attestation transactions.
+const ATTESTATION_PRIORITY: TransactionPriority = TransactionPriority::MAX / 2;
+
+/// A verification challenge posted by a registrar for an identity.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Challenge {
+	/// Where the nonce must be published.
+	pub url: ChallengeUrl,
+	/// The nonce to publish.
+	pub nonce: ChallengeNonce,
+	/// Whether an attester found the nonce at the URL.
+	pub verified: bool,
+}
+
+/// A statement by the attester of a registrar that the nonce of a challenge was found at its URL.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct Attestation<AccountId> {
+	/// The account whose identity was challenged.
+	pub target: AccountId,
+	/// The index of the registrar that posted the challenge.
+	pub registrar_index: RegistrarIndex,
+	/// The nonce that was found.
+	pub nonce: ChallengeNonce,
+}
+
+/// Submits the attestations produced by the off-chain worker to the chain.
+pub trait SubmitAttestation<T: Config> {
+	/// Submit a [`Call::submit_attestation`] call.
+	fn submit(call: Call<T>) -> Result<(), ()>;
+}
+
+/// Drops attestations, disabling the verification of challenges by the off-chain worker.
+impl<T: Config> SubmitAttestation<T> for () {
+	fn submit(_call: Call<T>) -> Result<(), ()> {
+		Err(())
+	}
+}
+
+/// Submits attestations as unsigned transactions.
+pub struct UnsignedAttestations<T>(core::marker::PhantomData<T>);
+
+impl<T: Config + CreateInherent<Call<T>>> SubmitAttestation<T> for UnsignedAttestations<T> {
+	fn submit(call: Call<T>) -> Result<(), ()> {
+		let xt = T::create_inherent(call.into());
+		SubmitTransaction::<T, Call<T>>::submit_transaction(xt)
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Ensure that `who` is the account of the registrar at `reg_index`.
+	pub(crate) fn ensure_registrar(
+		who: &T::AccountId,
+		reg_index: RegistrarIndex,
+	) -> DispatchResult {
+		Registrars::<T>::get()
+			.get(reg_index as usize)
+			.and_then(Option::as_ref)
+			.filter(|r| &r.account == who)
+			.ok_or(Error::<T>::InvalidIndex)?;
+		Ok(())
+	}
+
+	/// Check that `attestation` answers a pending challenge and is signed by the attester of the
+	/// registrar that posted it.
+	pub(crate) fn check_attestation(
+		attestation: &Attestation<T::AccountId>,
+		signature: &<T::AttesterId as RuntimeAppPublic>::Signature,
+	) -> Result<(), Error<T>> {
+		let challenge = ChallengeOf::<T>::get(&attestation.target, attestation.registrar_index)
+			.ok_or(Error::<T>::NoChallenge)?;
+		ensure!(challenge.nonce == attestation.nonce, Error::<T>::NoChallenge);
+		ensure!(!challenge.verified, Error::<T>::AlreadyVerified);
+		let attester =
+			AttesterOf::<T>::get(attestation.registrar_index).ok_or(Error::<T>::NoAttester)?;
+		ensure!(attester.verify(&attestation.encode(), signature), Error::<T>::InvalidAttestation);
+		Ok(())
+	}
+
+	/// Validate an unsigned [`Call::submit_attestation`] transaction.
+	pub(crate) fn validate_attestation(
+		attestation: &Attestation<T::AccountId>,
+		signature: &<T::AttesterId as RuntimeAppPublic>::Signature,
+	) -> TransactionValidity {
+		Self::check_attestation(attestation, signature).map_err(|e| match e {
+			Error::<T>::NoAttester | Error::<T>::InvalidAttestation => InvalidTransaction::BadProof,
+			_ => InvalidTransaction::Stale,
+		})?;
+
+		ValidTransaction::with_tag_prefix("IdentityAttestation")
+			.priority(ATTESTATION_PRIORITY)
+			.and_provides((&attestation.target, attestation.registrar_index))
+			.longevity(RECHECK_INTERVAL.into())
+			.propagate(true)
+			.build()
+	}
+
+	/// Check the pending challenges of the registrars whose attester keys are held by this node,
+	/// submitting an attestation for each challenge whose nonce is found at its URL.
+	pub(crate) fn attest_challenges(now: BlockNumberFor<T>) {
+		let local_keys = T::AttesterId::all();
+		if local_keys.is_empty() {
+			return
+		}
+
+		for (reg_index, attester) in AttesterOf::<T>::iter() {
+			let Some(key) = local_keys.iter().find(|k| **k == attester) else { continue };
+			for (target, challenge_index, challenge) in ChallengeOf::<T>::iter() {
+				if challenge_index != reg_index ||
+					challenge.verified ||
+					!Self::should_check(&target, reg_index, now)
+				{
+					continue
+				}
+
+				match Self::fetch(&challenge.url) {
+					Ok(body) if contains(&body, &challenge.nonce) => {},
+					Ok(_) => continue,
+					Err(e) => {
+						log::debug!(
+							target: LOG_TARGET,
+							"Failed to fetch the challenge of {:?}: {:?}",
+							target,
+							e,
+						);
+						continue
+					},
+				}
+
+				let attestation =
+					Attestation { target, registrar_index: reg_index, nonce: challenge.nonce };
+				let Some(signature) = key.sign(&attestation.encode()) else { continue };
+				let call = Call::submit_attestation { attestation, signature };
+				if T::SubmitAttestation::submit(call).is_err() {
+					log::debug!(target: LOG_TARGET, "Failed to submit an attestation");
+				}
+			}
+		}
+	}
+
+	/// Whether the challenge of `reg_index` for `target` is due to be checked at `now`, recording
+	/// the check in the local storage of the node if so.
+	fn should_check(
+		target: &T::AccountId,
+		reg_index: RegistrarIndex,
+		now: BlockNumberFor<T>,
+	) -> bool {
+		let key = (b"identity::challenge-check", target, reg_index).encode();
+		StorageValueRef::persistent(&key)
+			.mutate(|last: Result<Option<BlockNumberFor<T>>, _>| match last {
+				Ok(Some(last)) if now < last.saturating_add(RECHECK_INTERVAL.into()) => Err(()),
+				_ => Ok(now),
+			})
+			.is_ok()
+	}
+
+	/// Fetch the body of the response at `url`, up to [`MAX_RESPONSE_LEN`] bytes.
+	fn fetch(url: &[u8]) -> Result<Vec<u8>, http::Error> {
+		let url = core::str::from_utf8(url).map_err(|_| http::Error::Unknown)?;
+		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT_MS));
+		let response = http::Request::get(url)
+			.deadline(deadline)
+			.send()
+			.map_err(|_| http::Error::IoError)?
+			.try_wait(deadline)
+			.map_err(|_| http::Error::DeadlineReached)??;
+		if response.code != 200 {
+			return Err(http::Error::Unknown)
+		}
+		Ok(response.body().take(MAX_RESPONSE_LEN).collect())
+	}
+}
+
+/// Whether `needle` occurs in `haystack`.
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+	!needle.is_empty() && haystack.windows(needle.len()).any(|w| w == needle)
+}
@@ -25,7 +25,9 @@ use crate::{
 
 use codec::{Decode, Encode};
 use frame_support::{
-	assert_err, assert_noop, assert_ok, derive_impl, parameter_types,
+	assert_err, assert_noop, assert_ok, derive_impl,
+	dispatch::Pays,
+	parameter_types,
 	traits::{ConstU32, ConstU64, Get, OnFinalize, OnInitialize},
 	BoundedVec,
 };
@@ -69,19 +71,54 @@ impl pallet_balances::Config for Test {
 parameter_types! {
 	pub const MaxAdditionalFields: u32 = 2;
 	pub const MaxRegistrars: u32 = 20;
+	pub const PokeDepositReward: Perbill = Perbill::from_percent(10);
+	pub const SlashPortion: Perbill = Perbill::from_percent(80);
+	pub static IdentityChanges: Vec<IdentityChange> = vec![];
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum IdentityChange {
+	Set(AccountId),
+	Removed(AccountId),
+	Judged(AccountId, RegistrarIndex, Judgement<u64>),
+}
+
+pub struct RecordIdentityChanges;
+impl OnIdentityChange<AccountId, u64> for RecordIdentityChanges {
+	fn on_identity_set(who: &AccountId) {
+		IdentityChanges::mutate(|c| c.push(IdentityChange::Set(who.clone())));
+	}
+	fn on_identity_removed(who: &AccountId) {
+		IdentityChanges::mutate(|c| c.push(IdentityChange::Removed(who.clone())));
+	}
+	fn on_judgement_given(
+		who: &AccountId,
+		registrar_index: RegistrarIndex,
+		judgement: &Judgement<u64>,
+	) {
+		IdentityChanges::mutate(|c| {
+			c.push(IdentityChange::Judged(who.clone(), registrar_index, *judgement))
+		});
+	}
 }
 
 impl pallet_identity::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
+	type DepositTracker = ();
+	type DepositAsset = ();
 	type Slashed = ();
+	type SlashPortion = SlashPortion;
 	type BasicDeposit = ConstU64<100>;
 	type ByteDeposit = ConstU64<10>;
 	type UsernameDeposit = ConstU64<10>;
 	type SubAccountDeposit = ConstU64<100>;
+	type PokeDepositReward = PokeDepositReward;
 	type MaxSubAccounts = ConstU32<2>;
+	type MaxSubDepth = ConstU32<3>;
 	type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
 	type MaxRegistrars = MaxRegistrars;
+	type MaxFeeTiers = ConstU32<8>;
 	type RegistrarOrigin = EnsureRoot<Self::AccountId>;
 	type ForceOrigin = EnsureRoot<Self::AccountId>;
 	type OffchainSignature = MultiSignature;
@@ -91,9 +128,31 @@ impl pallet_identity::Config for Test {
 	type UsernameGracePeriod = ConstU64<2>;
 	type MaxSuffixLength = ConstU32<7>;
 	type MaxUsernameLength = ConstU32<32>;
+	type OnIdentityChange = RecordIdentityChanges;
+	type AttesterId = offchain::crypto::Public;
+	type SubmitAttestation = offchain::UnsignedAttestations<Self>;
 	type WeightInfo = ();
 }
 
+type Extrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type RuntimeCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateInherent<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_inherent(call: Self::RuntimeCall) -> Self::Extrinsic {
+		Extrinsic::new_bare(call)
+	}
+}
+
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 	pallet_balances::GenesisConfig::<Test> {
@@ -126,7 +185,7 @@ fn run_to_block(n: u64) {
 	}
 }
 
-fn account(id: u8) -> AccountIdOf<Test> {
+pub fn account(id: u8) -> AccountIdOf<Test> {
 	[id; 32].into()
 }
 
@@ -176,7 +235,7 @@ fn test_username_of(int: Vec<u8>, suffix: Vec<u8>) -> Username<Test> {
 	bounded_username
 }
 
-fn infoof_ten() -> IdentityInfo<MaxAdditionalFields> {
+pub fn infoof_ten() -> IdentityInfo<MaxAdditionalFields> {
 	IdentityInfo {
 		display: Data::Raw(b"ten".to_vec().try_into().unwrap()),
 		legal: Data::Raw(b"The Right Ordinal Ten, Esq.".to_vec().try_into().unwrap()),
@@ -296,6 +355,158 @@ fn editing_subaccounts_should_work() {
 	});
 }
 
+#[test]
+fn self_funded_subaccounts_should_work() {
+	new_test_ext().execute_with(|| {
+		let data = |x| Data::Raw(vec![x; 1].try_into().unwrap());
+		let [_, two, _, _, ten, twenty, thirty, _] = accounts();
+		let sub_deposit: u64 = <<Test as Config>::SubAccountDeposit as Get<u64>>::get();
+
+		assert_noop!(
+			Identity::invite_sub(RuntimeOrigin::signed(ten.clone()), twenty.clone(), data(1)),
+			Error::<Test>::NoIdentity
+		);
+		let ten_info = infoof_ten();
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(ten_info.clone())
+		));
+		let id_deposit = id_deposit(&ten_info);
+
+		// only invited accounts may join
+		assert_noop!(
+			Identity::add_sub_with_own_deposit(RuntimeOrigin::signed(twenty.clone()), ten.clone()),
+			Error::<Test>::NotInvited
+		);
+		assert_ok!(Identity::invite_sub(
+			RuntimeOrigin::signed(ten.clone()),
+			twenty.clone(),
+			data(1)
+		));
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::SubInvited {
+			sub: twenty.clone(),
+			main: ten.clone(),
+		}));
+		// the invitation is paid for by the inviter until it is accepted
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - sub_deposit);
+		assert_ok!(Identity::add_sub_with_own_deposit(
+			RuntimeOrigin::signed(twenty.clone()),
+			ten.clone()
+		));
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::SubIdentityAdded {
+			sub: twenty.clone(),
+			main: ten.clone(),
+			deposit: sub_deposit,
+		}));
+		assert_eq!(SuperOf::<Test>::get(twenty.clone()), Some((ten.clone(), data(1))));
+		assert!(!PendingSubsOf::<Test>::contains_key(ten.clone()));
+		assert_eq!(Balances::free_balance(twenty.clone()), 1000 - sub_deposit);
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit);
+		assert_eq!(SubsOf::<Test>::get(ten.clone()).0, 0);
+
+		// a self-funded sub still counts towards the limit
+		assert_ok!(Identity::add_sub(RuntimeOrigin::signed(ten.clone()), two.clone(), data(2)));
+		assert_ok!(Identity::invite_sub(
+			RuntimeOrigin::signed(ten.clone()),
+			thirty.clone(),
+			data(3)
+		));
+		assert_noop!(
+			Identity::add_sub_with_own_deposit(RuntimeOrigin::signed(thirty.clone()), ten.clone()),
+			Error::<Test>::TooManySubAccounts
+		);
+		assert_ok!(Identity::do_try_state());
+
+		// poking leaves the deposit of the self-funded sub with the sub
+		assert_ok!(Identity::poke_deposit(RuntimeOrigin::signed(ten.clone()), ten.clone()));
+		assert_eq!(SubsOf::<Test>::get(ten.clone()).0, sub_deposit);
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 2 * sub_deposit);
+
+		// quitting returns the deposit to the sub, not the super
+		assert_ok!(Identity::quit_sub(RuntimeOrigin::signed(twenty.clone())));
+		assert_eq!(SuperOf::<Test>::get(twenty.clone()), None);
+		assert_eq!(Balances::free_balance(twenty.clone()), 1000);
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 2 * sub_deposit);
+
+		// removal by the super also returns the deposit to the sub
+		assert_ok!(Identity::add_sub_with_own_deposit(
+			RuntimeOrigin::signed(thirty.clone()),
+			ten.clone()
+		));
+		assert_eq!(Balances::free_balance(thirty.clone()), 1000 - sub_deposit);
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - sub_deposit);
+		assert_ok!(Identity::remove_sub(RuntimeOrigin::signed(ten.clone()), thirty.clone()));
+		assert_eq!(Balances::free_balance(thirty), 1000);
+		assert_eq!(Balances::free_balance(ten), 1000 - id_deposit - sub_deposit);
+		assert_ok!(Identity::do_try_state());
+	});
+}
+
+#[test]
+fn sub_invitations_hold_a_deposit_until_resolved() {
+	new_test_ext().execute_with(|| {
+		let data = |x| Data::Raw(vec![x; 1].try_into().unwrap());
+		let [one, two, three, _, ten, twenty, _, _] = accounts();
+		let sub_deposit: u64 = <<Test as Config>::SubAccountDeposit as Get<u64>>::get();
+
+		let ten_info = infoof_ten();
+		let id_deposit = id_deposit(&ten_info);
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(ten.clone()), Box::new(ten_info)));
+
+		// inviting the same account again only renames the invitation
+		assert_ok!(Identity::invite_sub(RuntimeOrigin::signed(ten.clone()), one.clone(), data(1)));
+		assert_ok!(Identity::invite_sub(RuntimeOrigin::signed(ten.clone()), one.clone(), data(9)));
+		assert_eq!(
+			PendingSubsOf::<Test>::get(ten.clone()),
+			(sub_deposit, BoundedVec::truncate_from(vec![(one.clone(), data(9))]))
+		);
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - sub_deposit);
+
+		// the number of pending invitations is bounded
+		assert_ok!(Identity::invite_sub(RuntimeOrigin::signed(ten.clone()), two.clone(), data(2)));
+		assert_noop!(
+			Identity::invite_sub(RuntimeOrigin::signed(ten.clone()), three.clone(), data(3)),
+			Error::<Test>::TooManySubAccounts
+		);
+
+		// cancelling returns the deposit of the invitation
+		assert_noop!(
+			Identity::cancel_sub_invite(RuntimeOrigin::signed(twenty.clone()), one.clone()),
+			Error::<Test>::NotInvited
+		);
+		assert_ok!(Identity::cancel_sub_invite(RuntimeOrigin::signed(ten.clone()), one.clone()));
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::SubInviteCancelled {
+			sub: one.clone(),
+			main: ten.clone(),
+			deposit: sub_deposit,
+		}));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - sub_deposit);
+		assert_noop!(
+			Identity::add_sub_with_own_deposit(RuntimeOrigin::signed(one.clone()), ten.clone()),
+			Error::<Test>::NotInvited
+		);
+		assert_ok!(Identity::do_try_state());
+
+		// clearing the identity drops the remaining invitations and returns their deposit
+		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(ten.clone())));
+		assert!(!PendingSubsOf::<Test>::contains_key(ten.clone()));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000);
+		assert_noop!(
+			Identity::add_sub_with_own_deposit(RuntimeOrigin::signed(two.clone()), ten.clone()),
+			Error::<Test>::NotInvited
+		);
+
+		// so does killing it
+		let ten_info = infoof_ten();
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(ten.clone()), Box::new(ten_info)));
+		assert_ok!(Identity::invite_sub(RuntimeOrigin::signed(ten.clone()), two.clone(), data(2)));
+		assert_ok!(Identity::kill_identity(RuntimeOrigin::root(), ten.clone()));
+		assert!(!PendingSubsOf::<Test>::contains_key(ten.clone()));
+		assert_eq!(Balances::free_balance(ten), 1000 - id_deposit);
+		assert_ok!(Identity::do_try_state());
+	});
+}
+
 #[test]
 fn resolving_subaccount_ownership_works() {
 	new_test_ext().execute_with(|| {
@@ -336,6 +547,114 @@ fn resolving_subaccount_ownership_works() {
 	});
 }
 
+#[test]
+fn nested_subaccounts_should_work() {
+	new_test_ext().execute_with(|| {
+		let data = |x| Data::Raw(vec![x; 1].try_into().unwrap());
+		let [one, _, _, _, ten, twenty, thirty, forty] = accounts();
+		let display = IdentityField::Display as u64;
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+
+		// 10 -> 20 -> 30 -> 40, where 40 is `MaxSubDepth` levels below 10.
+		assert_ok!(Identity::add_sub(RuntimeOrigin::signed(ten.clone()), twenty.clone(), data(1)));
+		assert_ok!(Identity::add_sub(
+			RuntimeOrigin::signed(twenty.clone()),
+			thirty.clone(),
+			data(2)
+		));
+		assert_ok!(Identity::set_subs(
+			RuntimeOrigin::signed(thirty.clone()),
+			vec![(forty.clone(), data(3))]
+		));
+		assert_noop!(
+			Identity::add_sub(RuntimeOrigin::signed(forty.clone()), one.clone(), data(4)),
+			Error::<Test>::SubDepthExceeded
+		);
+		assert_eq!(Identity::super_path(&forty), vec![thirty.clone(), twenty.clone(), ten.clone()]);
+		assert_eq!(Identity::identity_root(&forty), Some(ten.clone()));
+		assert!(Identity::has_resolved_identity(&forty, display));
+		assert!(!Identity::has_identity(&forty, display));
+		assert!(!Identity::has_resolved_identity(&one, display));
+		assert_eq!(NestedSubsOf::<Test>::get(&ten), 2);
+
+		// No cycles: an account of the path can't become a sub-account below it.
+		assert_noop!(
+			Identity::add_sub(RuntimeOrigin::signed(thirty.clone()), ten.clone(), data(4)),
+			Error::<Test>::AlreadyClaimed
+		);
+		// Its sub-accounts belong to the tree of 10, so 30 can't have an identity of its own.
+		assert_noop!(
+			Identity::set_identity(RuntimeOrigin::signed(thirty.clone()), Box::new(infoof_ten())),
+			Error::<Test>::NestedSubAccounts
+		);
+		assert_ok!(Identity::do_try_state());
+
+		// Removing 20 removes its sub-accounts along with it and returns their deposits.
+		assert_ok!(Identity::remove_sub(RuntimeOrigin::signed(ten.clone()), twenty.clone()));
+		assert!(SuperOf::<Test>::get(&thirty).is_none());
+		assert!(SuperOf::<Test>::get(&forty).is_none());
+		assert_eq!(Balances::reserved_balance(twenty.clone()), 0);
+		assert_eq!(Balances::reserved_balance(thirty.clone()), 0);
+		assert_eq!(NestedSubsOf::<Test>::get(&ten), 0);
+		assert_eq!(Identity::identity_root(&forty), None);
+		assert_noop!(
+			Identity::add_sub(RuntimeOrigin::signed(twenty.clone()), one.clone(), data(4)),
+			Error::<Test>::NoIdentity
+		);
+		assert_ok!(Identity::do_try_state());
+
+		// It can come back below 10 without them.
+		assert_ok!(Identity::add_sub(RuntimeOrigin::signed(ten.clone()), twenty, data(1)));
+		assert_ok!(Identity::do_try_state());
+	});
+}
+
+#[test]
+fn sub_trees_are_bounded_and_removed_with_their_identity() {
+	new_test_ext().execute_with(|| {
+		let data = |x| Data::Raw(vec![x; 1].try_into().unwrap());
+		let [one, two, three, four, ten, twenty, thirty, _] = accounts();
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+
+		// 10 -> 20 -> (30 -> (2, 3), 1), filling the `MaxSubAccounts` sub-accounts allowed on
+		// each level below the direct sub-accounts of 10.
+		assert_ok!(Identity::add_sub(RuntimeOrigin::signed(ten.clone()), twenty.clone(), data(1)));
+		assert_ok!(Identity::set_subs(
+			RuntimeOrigin::signed(twenty.clone()),
+			vec![(thirty.clone(), data(2)), (one.clone(), data(3))]
+		));
+		assert_ok!(Identity::set_subs(
+			RuntimeOrigin::signed(thirty.clone()),
+			vec![(two.clone(), data(4)), (three.clone(), data(5))]
+		));
+		assert_eq!(NestedSubsOf::<Test>::get(&ten), 4);
+		assert_noop!(
+			Identity::add_sub(RuntimeOrigin::signed(one.clone()), four.clone(), data(6)),
+			Error::<Test>::TooManySubAccounts
+		);
+		assert_ok!(Identity::do_try_state());
+
+		// Clearing the identity removes the whole tree and returns all deposits.
+		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(ten.clone())));
+		for sub in [&twenty, &thirty, &one, &two, &three] {
+			assert!(SuperOf::<Test>::get(sub).is_none());
+		}
+		assert!(!SubsOf::<Test>::contains_key(&twenty));
+		assert!(!SubsOf::<Test>::contains_key(&thirty));
+		assert_eq!(Balances::reserved_balance(ten.clone()), 0);
+		assert_eq!(Balances::reserved_balance(twenty.clone()), 0);
+		assert_eq!(Balances::reserved_balance(thirty.clone()), 0);
+		assert!(!NestedSubsOf::<Test>::contains_key(&ten));
+		assert_ok!(Identity::do_try_state());
+	});
+}
+
 #[test]
 fn trailing_zeros_decodes_into_default_data() {
 	let encoded = Data::Raw(b"Hello".to_vec().try_into().unwrap()).encode();
@@ -346,12 +665,78 @@ fn trailing_zeros_decodes_into_default_data() {
 	assert_eq!(b, Data::None);
 }
 
+#[test]
+fn view_functions_return_identity_and_super() {
+	use frame_support::view_functions::{execute_view_function, ViewFunction};
+	use view_functions::{IdentityOfAccount, SuperOfAccount};
+
+	new_test_ext().execute_with(|| {
+		let [one, _, _, _, ten, _, _, _] = accounts();
+		let data = Data::Raw(vec![1; 1].try_into().unwrap());
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+		assert_ok!(Identity::add_sub(
+			RuntimeOrigin::signed(ten.clone()),
+			one.clone(),
+			data.clone()
+		));
+
+		assert_eq!(
+			IdentityOfAccount::<Test>(ten.clone()).invoke().map(|r| r.info),
+			Some(infoof_ten())
+		);
+		assert_eq!(IdentityOfAccount::<Test>(one.clone()).invoke(), None);
+
+		let output =
+			execute_view_function::<Identity>(SuperOfAccount::<Test>::id(), one.encode()).unwrap();
+		assert_eq!(
+			Option::<(AccountIdOf<Test>, Data)>::decode(&mut &output[..]),
+			Ok(Some((ten, data)))
+		);
+	});
+}
+
+#[test]
+fn identities_can_be_paged() {
+	new_test_ext().execute_with(|| {
+		let [_, _, _, _, ten, twenty, thirty, _] = accounts();
+		for who in [&ten, &twenty, &thirty] {
+			assert_ok!(Identity::set_identity(
+				RuntimeOrigin::signed(who.clone()),
+				Box::new(infoof_ten())
+			));
+		}
+
+		let mut seen = Vec::new();
+		let mut start_key = None;
+		loop {
+			let page = Identity::identities(start_key.clone(), 2);
+			assert!(page.len() <= 2);
+			match page.last() {
+				Some((who, _)) => start_key = Some(who.clone()),
+				None => break,
+			}
+			seen.extend(page.into_iter().map(|(who, reg)| {
+				assert_eq!(IdentityOf::<Test>::get(&who), Some(reg));
+				who
+			}));
+		}
+		seen.sort();
+		let mut expected = vec![ten, twenty, thirty];
+		expected.sort();
+		assert_eq!(seen, expected);
+		assert!(Identity::identities(None, 0).is_empty());
+	});
+}
+
 #[test]
 fn adding_registrar_invalid_index() {
 	new_test_ext().execute_with(|| {
 		let [_, _, three, _, _, _, _, _] = accounts();
 		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
-		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10, None));
 		let fields = IdentityField::Display | IdentityField::Legal;
 		assert_noop!(
 			Identity::set_fields(RuntimeOrigin::signed(three), 100, fields.bits()),
@@ -365,102 +750,337 @@ fn adding_registrar_should_work() {
 	new_test_ext().execute_with(|| {
 		let [_, _, three, _, _, _, _, _] = accounts();
 		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
-		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10, None));
 		let fields = IdentityField::Display | IdentityField::Legal;
 		assert_ok!(Identity::set_fields(RuntimeOrigin::signed(three.clone()), 0, fields.bits()));
 		assert_eq!(
 			Registrars::<Test>::get(),
-			vec![Some(RegistrarInfo { account: three, fee: 10, fields: fields.bits() })]
-		);
-	});
-}
-
-#[test]
-fn amount_of_registrars_is_limited() {
-	new_test_ext().execute_with(|| {
-		for ii in 1..MaxRegistrars::get() + 1 {
-			assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), account_from_u32(ii)));
-		}
-		let last_registrar = MaxRegistrars::get() + 1;
-		assert_noop!(
-			Identity::add_registrar(RuntimeOrigin::root(), account_from_u32(last_registrar)),
-			Error::<Test>::TooManyRegistrars
+			vec![Some(RegistrarInfo {
+				account: three,
+				fee: 10,
+				fields: fields.bits(),
+				fee_schedule: Default::default(),
+				max_pending: None,
+			})]
 		);
 	});
 }
 
 #[test]
-fn registration_should_work() {
+fn identity_changes_are_reported() {
 	new_test_ext().execute_with(|| {
-		let [_, _, three, _, ten, _, _, _] = accounts();
+		let [_, _, three, _, ten, twenty, _, _] = accounts();
+		IdentityChanges::set(vec![]);
 		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
-		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10));
-		let mut three_fields = infoof_ten();
-		three_fields.additional.try_push(Default::default()).unwrap();
-		three_fields.additional.try_push(Default::default()).unwrap();
-		assert!(three_fields.additional.try_push(Default::default()).is_err());
 		let ten_info = infoof_ten();
-		let id_deposit = id_deposit(&ten_info);
 		assert_ok!(Identity::set_identity(
 			RuntimeOrigin::signed(ten.clone()),
 			Box::new(ten_info.clone())
 		));
-		assert_eq!(IdentityOf::<Test>::get(ten.clone()).unwrap().info, ten_info);
-		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit);
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(three),
+			0,
+			ten.clone(),
+			Judgement::Reasonable,
+			BlakeTwo256::hash_of(&ten_info)
+		));
 		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(ten.clone())));
-		assert_eq!(Balances::free_balance(ten.clone()), 1000);
-		assert_noop!(
-			Identity::clear_identity(RuntimeOrigin::signed(ten)),
-			Error::<Test>::NoIdentity
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(twenty.clone()),
+			Box::new(ten_info)
+		));
+		assert_ok!(Identity::kill_identity(RuntimeOrigin::root(), twenty.clone()));
+
+		assert_eq!(
+			IdentityChanges::get(),
+			vec![
+				IdentityChange::Set(ten.clone()),
+				IdentityChange::Judged(ten.clone(), 0, Judgement::Reasonable),
+				IdentityChange::Removed(ten),
+				IdentityChange::Set(twenty.clone()),
+				IdentityChange::Removed(twenty),
+			]
 		);
 	});
 }
 
 #[test]
-fn uninvited_judgement_should_work() {
+fn requesting_judgement_for_fee_tier_should_work() {
 	new_test_ext().execute_with(|| {
 		let [_, _, three, _, ten, _, _, _] = accounts();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		let all = (IdentityField::Display | IdentityField::Legal | IdentityField::Web).bits();
+		let display = IdentityField::Display.bits();
+		assert_ok!(Identity::set_fields(RuntimeOrigin::signed(three.clone()), 0, all));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 30, None));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 5, Some(display)));
+		assert_eq!(
+			Registrars::<Test>::get()[0].as_ref().unwrap().fee_schedule.to_vec(),
+			vec![(display, 5)]
+		);
+
+		let ten_info = infoof_ten();
+		let id_deposit = id_deposit(&ten_info);
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(ten.clone()), Box::new(ten_info)));
+
+		// only published field sets can be requested
 		assert_noop!(
-			Identity::provide_judgement(
-				RuntimeOrigin::signed(three.clone()),
+			Identity::request_judgement(
+				RuntimeOrigin::signed(ten.clone()),
 				0,
-				ten.clone(),
-				Judgement::Reasonable,
-				H256::random()
+				30,
+				Some(IdentityField::Legal.bits()),
+				None
 			),
-			Error::<Test>::InvalidIndex
+			Error::<Test>::NoFeeForFields
 		);
-
-		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
 		assert_noop!(
-			Identity::provide_judgement(
-				RuntimeOrigin::signed(three.clone()),
+			Identity::request_judgement(
+				RuntimeOrigin::signed(ten.clone()),
 				0,
-				ten.clone(),
-				Judgement::Reasonable,
-				H256::random()
+				4,
+				Some(display),
+				None
 			),
-			Error::<Test>::InvalidTarget
+			Error::<Test>::FeeChanged
 		);
-
-		assert_ok!(Identity::set_identity(
+		assert_ok!(Identity::request_judgement(
 			RuntimeOrigin::signed(ten.clone()),
-			Box::new(infoof_ten())
+			0,
+			5,
+			Some(display),
+			None
 		));
-		assert_noop!(
-			Identity::provide_judgement(
-				RuntimeOrigin::signed(three.clone()),
-				0,
-				ten.clone(),
-				Judgement::Reasonable,
-				H256::random()
-			),
-			Error::<Test>::JudgementForDifferentIdentity
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 5);
+		assert_eq!(
+			IdentityOf::<Test>::get(ten.clone()).unwrap().judgements[0].1,
+			Judgement::FeePaid(5)
 		);
+		assert_eq!(RequestedFieldsOf::<Test>::get(ten.clone(), 0), Some(display));
 
-		let identity_hash = BlakeTwo256::hash_of(&infoof_ten());
-
-		assert_noop!(
+		// requesting the registrar's own fields costs the base fee
+		assert_ok!(Identity::cancel_request(RuntimeOrigin::signed(ten.clone()), 0));
+		assert_eq!(RequestedFieldsOf::<Test>::get(ten.clone(), 0), None);
+		assert_ok!(Identity::request_judgement(
+			RuntimeOrigin::signed(ten.clone()),
+			0,
+			30,
+			Some(all),
+			None
+		));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 30);
+		assert_eq!(RequestedFieldsOf::<Test>::get(ten.clone(), 0), None);
+
+		// changing the fields clears the fee schedule
+		assert_ok!(Identity::set_fields(RuntimeOrigin::signed(three), 0, display));
+		assert!(Registrars::<Test>::get()[0].as_ref().unwrap().fee_schedule.is_empty());
+	});
+}
+
+#[test]
+fn fee_schedule_is_bounded_by_max_fee_tiers() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, _, _, _, _] = accounts();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		let max = <<Test as Config>::MaxFeeTiers as Get<u32>>::get();
+		for i in 0..max {
+			assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 5, Some(1 << i)));
+		}
+		// updating a published tier is still allowed
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 6, Some(1)));
+		assert_noop!(
+			Identity::set_fee(RuntimeOrigin::signed(three), 0, 5, Some(1 << max)),
+			Error::<Test>::TooManyFeeTiers
+		);
+	});
+}
+
+#[test]
+fn judging_a_fee_tier_checks_the_requested_fields() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, _, _, _] = accounts();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		let display = IdentityField::Display.bits();
+		let web = IdentityField::Web.bits();
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 5, Some(display)));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 7, Some(web)));
+
+		// ten registers a display name and legal name, but no web address
+		let ten_info = infoof_ten();
+		let id_deposit = id_deposit(&ten_info);
+		let ten_hash = BlakeTwo256::hash_of(&ten_info);
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(ten.clone()), Box::new(ten_info)));
+		assert_ok!(Identity::request_judgement(
+			RuntimeOrigin::signed(ten.clone()),
+			0,
+			7,
+			Some(web),
+			None
+		));
+
+		// the web address cannot be judged favourably, but may be judged otherwise
+		assert_noop!(
+			Identity::provide_judgement(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				ten.clone(),
+				Judgement::Reasonable,
+				ten_hash
+			),
+			Error::<Test>::RequestedFieldsNotSet
+		);
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			ten.clone(),
+			Judgement::LowQuality,
+			ten_hash
+		));
+		assert_eq!(RequestedFieldsOf::<Test>::get(ten.clone(), 0), None);
+
+		// the display name can
+		assert_ok!(Identity::request_judgement(
+			RuntimeOrigin::signed(ten.clone()),
+			0,
+			5,
+			Some(display),
+			None
+		));
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			ten.clone(),
+			Judgement::Reasonable,
+			ten_hash
+		));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 7 - 5);
+		assert_eq!(Balances::free_balance(three), 100 + 7 + 5);
+		assert_ok!(Identity::do_try_state());
+	});
+}
+
+#[test]
+fn amount_of_registrars_is_limited() {
+	new_test_ext().execute_with(|| {
+		for ii in 1..MaxRegistrars::get() + 1 {
+			assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), account_from_u32(ii)));
+		}
+		let last_registrar = MaxRegistrars::get() + 1;
+		assert_noop!(
+			Identity::add_registrar(RuntimeOrigin::root(), account_from_u32(last_registrar)),
+			Error::<Test>::TooManyRegistrars
+		);
+	});
+}
+
+#[test]
+fn registration_should_work() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, _, _, _] = accounts();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10, None));
+		let mut three_fields = infoof_ten();
+		three_fields.additional.try_push(Default::default()).unwrap();
+		three_fields.additional.try_push(Default::default()).unwrap();
+		assert!(three_fields.additional.try_push(Default::default()).is_err());
+		let ten_info = infoof_ten();
+		let id_deposit = id_deposit(&ten_info);
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(ten_info.clone())
+		));
+		assert_eq!(IdentityOf::<Test>::get(ten.clone()).unwrap().info, ten_info);
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit);
+		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(ten.clone())));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000);
+		assert_noop!(
+			Identity::clear_identity(RuntimeOrigin::signed(ten)),
+			Error::<Test>::NoIdentity
+		);
+	});
+}
+
+#[test]
+fn identity_events_carry_hash_and_fields() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, _, _, _] = accounts();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		let ten_info = infoof_ten();
+		let identity = BlakeTwo256::hash_of(&ten_info);
+		let fields = (IdentityField::Display | IdentityField::Legal).bits();
+		assert_eq!(ten_info.registered_fields(), fields);
+
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(ten_info.clone())
+		));
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::IdentitySet {
+			who: ten.clone(),
+			identity,
+			fields,
+		}));
+
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(three),
+			0,
+			ten.clone(),
+			Judgement::Reasonable,
+			identity
+		));
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::JudgementGiven {
+			target: ten,
+			registrar_index: 0,
+			identity,
+			fields,
+		}));
+	});
+}
+
+#[test]
+fn uninvited_judgement_should_work() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, _, _, _] = accounts();
+		assert_noop!(
+			Identity::provide_judgement(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				ten.clone(),
+				Judgement::Reasonable,
+				H256::random()
+			),
+			Error::<Test>::InvalidIndex
+		);
+
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_noop!(
+			Identity::provide_judgement(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				ten.clone(),
+				Judgement::Reasonable,
+				H256::random()
+			),
+			Error::<Test>::InvalidTarget
+		);
+
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+		assert_noop!(
+			Identity::provide_judgement(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				ten.clone(),
+				Judgement::Reasonable,
+				H256::random()
+			),
+			Error::<Test>::JudgementForDifferentIdentity
+		);
+
+		let identity_hash = BlakeTwo256::hash_of(&infoof_ten());
+
+		assert_noop!(
 			Identity::provide_judgement(
 				RuntimeOrigin::signed(ten.clone()),
 				0,
@@ -535,223 +1155,1335 @@ fn killing_slashing_should_work() {
 }
 
 #[test]
-fn setting_subaccounts_should_work() {
+fn killing_rewards_the_reporter() {
 	new_test_ext().execute_with(|| {
-		let [_, _, _, _, ten, twenty, thirty, forty] = accounts();
+		let [_, _, _, four, ten, _, _, _] = accounts();
 		let ten_info = infoof_ten();
 		let id_deposit = id_deposit(&ten_info);
-		let sub_deposit: u64 = <<Test as Config>::SubAccountDeposit as Get<u64>>::get();
-		let mut subs = vec![(twenty.clone(), Data::Raw(vec![40; 1].try_into().unwrap()))];
-		assert_noop!(
-			Identity::set_subs(RuntimeOrigin::signed(ten.clone()), subs.clone()),
-			Error::<Test>::NotFound
-		);
-
+		let slashed = SlashPortion::get() * id_deposit;
+		let reward = id_deposit - slashed;
+		assert!(reward > 0);
 		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(ten.clone()), Box::new(ten_info)));
-		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit);
-		assert_ok!(Identity::set_subs(RuntimeOrigin::signed(ten.clone()), subs.clone()));
+		let issuance = Balances::total_issuance();
 
-		System::assert_last_event(tests::RuntimeEvent::Identity(Event::SubIdentitiesSet {
-			main: ten.clone(),
-			number_of_subs: 1,
-			new_deposit: sub_deposit,
+		assert_ok!(Identity::kill_identity_with_reporter(
+			RuntimeOrigin::root(),
+			ten.clone(),
+			four.clone()
+		));
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::IdentityKilled {
+			who: ten.clone(),
+			deposit: id_deposit,
+			slashed,
+			reporter: Some(four.clone()),
+			reward,
 		}));
+		assert_eq!(Balances::free_balance(ten), 1000 - id_deposit);
+		assert_eq!(Balances::free_balance(four), reward);
+		assert_eq!(Balances::total_issuance(), issuance - slashed);
+	});
+}
 
-		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - sub_deposit);
-		assert_eq!(
-			SubsOf::<Test>::get(ten.clone()),
-			(sub_deposit, vec![twenty.clone()].try_into().unwrap())
+#[test]
+fn frozen_identities_cannot_be_changed() {
+	new_test_ext().execute_with(|| {
+		let [_, _, _, _, ten, twenty, _, _] = accounts();
+		let ten_info = infoof_ten();
+		assert_noop!(
+			Identity::freeze_identity(RuntimeOrigin::root(), ten.clone()),
+			Error::<Test>::NoIdentity
 		);
-		assert_eq!(
-			SuperOf::<Test>::get(twenty.clone()),
-			Some((ten.clone(), Data::Raw(vec![40; 1].try_into().unwrap())))
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(ten_info.clone())
+		));
+		assert_noop!(
+			Identity::freeze_identity(RuntimeOrigin::signed(ten.clone()), ten.clone()),
+			BadOrigin
+		);
+		assert_noop!(
+			Identity::thaw_identity(RuntimeOrigin::root(), ten.clone()),
+			Error::<Test>::NotFrozen
 		);
 
-		// push another item and re-set it.
-		subs.push((thirty.clone(), Data::Raw(vec![50; 1].try_into().unwrap())));
-		assert_ok!(Identity::set_subs(RuntimeOrigin::signed(ten.clone()), subs.clone()));
-		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 2 * sub_deposit);
-		assert_eq!(
-			SubsOf::<Test>::get(ten.clone()),
-			(2 * sub_deposit, vec![twenty.clone(), thirty.clone()].try_into().unwrap())
+		assert_ok!(Identity::freeze_identity(RuntimeOrigin::root(), ten.clone()));
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::IdentityFrozen {
+			who: ten.clone(),
+		}));
+		assert!(IdentityOf::<Test>::get(&ten).unwrap().frozen);
+		assert_noop!(
+			Identity::freeze_identity(RuntimeOrigin::root(), ten.clone()),
+			Error::<Test>::IdentityFrozen
 		);
-		assert_eq!(
-			SuperOf::<Test>::get(twenty.clone()),
-			Some((ten.clone(), Data::Raw(vec![40; 1].try_into().unwrap())))
+		assert_noop!(
+			Identity::set_identity(RuntimeOrigin::signed(ten.clone()), Box::new(infoof_twenty())),
+			Error::<Test>::IdentityFrozen
 		);
-		assert_eq!(
-			SuperOf::<Test>::get(thirty.clone()),
-			Some((ten.clone(), Data::Raw(vec![50; 1].try_into().unwrap())))
+		assert_noop!(
+			Identity::set_subs(
+				RuntimeOrigin::signed(ten.clone()),
+				vec![(twenty.clone(), Data::Raw(vec![40; 1].try_into().unwrap()))]
+			),
+			Error::<Test>::IdentityFrozen
+		);
+		assert_noop!(
+			Identity::clear_identity(RuntimeOrigin::signed(ten.clone())),
+			Error::<Test>::IdentityFrozen
 		);
 
-		// switch out one of the items and re-set.
-		subs[0] = (forty.clone(), Data::Raw(vec![60; 1].try_into().unwrap()));
-		assert_ok!(Identity::set_subs(RuntimeOrigin::signed(ten.clone()), subs.clone()));
-		// no change in the balance
-		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 2 * sub_deposit);
-		assert_eq!(
-			SubsOf::<Test>::get(ten.clone()),
-			(2 * sub_deposit, vec![forty.clone(), thirty.clone()].try_into().unwrap())
+		// once thawed, the owner is in control of the identity again
+		assert_ok!(Identity::thaw_identity(RuntimeOrigin::root(), ten.clone()));
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::IdentityThawed {
+			who: ten.clone(),
+		}));
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(ten.clone()), Box::new(ten_info)));
+		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(ten)));
+	});
+}
+
+#[test]
+fn frozen_identities_keep_their_sub_accounts() {
+	new_test_ext().execute_with(|| {
+		let data = |x| Data::Raw(vec![x; 1].try_into().unwrap());
+		let [_, _, _, _, ten, twenty, thirty, _] = accounts();
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+		assert_ok!(Identity::add_sub(RuntimeOrigin::signed(ten.clone()), twenty.clone(), data(1)));
+		assert_ok!(Identity::invite_sub(
+			RuntimeOrigin::signed(ten.clone()),
+			thirty.clone(),
+			data(2)
+		));
+		assert_ok!(Identity::freeze_identity(RuntimeOrigin::root(), ten.clone()));
+
+		assert_noop!(
+			Identity::add_sub(RuntimeOrigin::signed(ten.clone()), thirty.clone(), data(2)),
+			Error::<Test>::IdentityFrozen
 		);
-		assert_eq!(SuperOf::<Test>::get(twenty.clone()), None);
-		assert_eq!(
-			SuperOf::<Test>::get(thirty.clone()),
-			Some((ten.clone(), Data::Raw(vec![50; 1].try_into().unwrap())))
+		assert_noop!(
+			Identity::rename_sub(RuntimeOrigin::signed(ten.clone()), twenty.clone(), data(3)),
+			Error::<Test>::IdentityFrozen
 		);
-		assert_eq!(
+		assert_noop!(
+			Identity::remove_sub(RuntimeOrigin::signed(ten.clone()), twenty.clone()),
+			Error::<Test>::IdentityFrozen
+		);
+		assert_noop!(
+			Identity::quit_sub(RuntimeOrigin::signed(twenty.clone())),
+			Error::<Test>::IdentityFrozen
+		);
+		assert_noop!(
+			Identity::add_sub_with_own_deposit(RuntimeOrigin::signed(thirty.clone()), ten.clone()),
+			Error::<Test>::IdentityFrozen
+		);
+		assert_eq!(SuperOf::<Test>::get(&twenty), Some((ten.clone(), data(1))));
+
+		assert_ok!(Identity::thaw_identity(RuntimeOrigin::root(), ten.clone()));
+		assert_ok!(Identity::quit_sub(RuntimeOrigin::signed(twenty)));
+		assert_ok!(Identity::add_sub_with_own_deposit(RuntimeOrigin::signed(thirty), ten));
+	});
+}
+
+fn display_name(name: &[u8]) -> DisplayName {
+	name.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn claiming_names_should_work() {
+	new_test_ext().execute_with(|| {
+		let [_, _, _, _, ten, twenty, _, _] = accounts();
+		let ten_info = infoof_ten();
+		let id_deposit = id_deposit(&ten_info);
+		let byte_deposit: u64 = <<Test as Config>::ByteDeposit as Get<u64>>::get();
+		let name = display_name(b"ten");
+
+		assert_noop!(
+			Identity::claim_name(RuntimeOrigin::signed(ten.clone()), name.clone()),
+			Error::<Test>::NoIdentity
+		);
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(ten.clone()), Box::new(ten_info)));
+		assert_noop!(
+			Identity::claim_name(RuntimeOrigin::signed(ten.clone()), display_name(b"")),
+			Error::<Test>::InvalidName
+		);
+
+		assert_ok!(Identity::claim_name(RuntimeOrigin::signed(ten.clone()), name.clone()));
+		assert_eq!(Identity::account_of_name(b"ten"), Some(ten.clone()));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 3 * byte_deposit);
+		System::assert_last_event(
+			Event::NameClaimed {
+				who: ten.clone(),
+				name_hash: BlakeTwo256::hash(b"ten"),
+				deposit: 3 * byte_deposit,
+			}
+			.into(),
+		);
+
+		// Names are unique.
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(twenty.clone()),
+			Box::new(infoof_twenty())
+		));
+		assert_noop!(
+			Identity::claim_name(RuntimeOrigin::signed(twenty.clone()), name.clone()),
+			Error::<Test>::NameTaken
+		);
+
+		// Claiming another name releases the previous one and adjusts the deposit.
+		assert_ok!(Identity::claim_name(RuntimeOrigin::signed(ten.clone()), display_name(b"x")));
+		assert_eq!(Identity::account_of_name(b"ten"), None);
+		assert_eq!(Identity::account_of_name(b"x"), Some(ten.clone()));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - byte_deposit);
+		assert_ok!(Identity::claim_name(RuntimeOrigin::signed(twenty), name));
+		assert_ok!(Identity::do_try_state());
+	});
+}
+
+#[test]
+fn releasing_names_should_work() {
+	new_test_ext().execute_with(|| {
+		let [_, _, _, _, ten, _, _, _] = accounts();
+		let ten_info = infoof_ten();
+		let id_deposit = id_deposit(&ten_info);
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(ten.clone()), Box::new(ten_info)));
+		assert_noop!(
+			Identity::release_name(RuntimeOrigin::signed(ten.clone())),
+			Error::<Test>::NoName
+		);
+
+		assert_ok!(Identity::claim_name(RuntimeOrigin::signed(ten.clone()), display_name(b"ten")));
+		assert_ok!(Identity::release_name(RuntimeOrigin::signed(ten.clone())));
+		assert_eq!(Identity::account_of_name(b"ten"), None);
+		assert_eq!(NameOf::<Test>::get(&ten), None);
+		assert_eq!(Balances::free_balance(ten), 1000 - id_deposit);
+	});
+}
+
+#[test]
+fn clearing_and_killing_identity_releases_name() {
+	new_test_ext().execute_with(|| {
+		let [_, _, _, _, ten, twenty, _, _] = accounts();
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+		assert_ok!(Identity::claim_name(RuntimeOrigin::signed(ten.clone()), display_name(b"ten")));
+		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(ten.clone())));
+		assert_eq!(Identity::account_of_name(b"ten"), None);
+		assert_eq!(NameOf::<Test>::get(&ten), None);
+		assert_eq!(Balances::free_balance(ten), 1000);
+
+		let twenty_info = infoof_twenty();
+		let id_deposit = id_deposit(&twenty_info);
+		let byte_deposit: u64 = <<Test as Config>::ByteDeposit as Get<u64>>::get();
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(twenty.clone()),
+			Box::new(twenty_info)
+		));
+		assert_ok!(Identity::claim_name(
+			RuntimeOrigin::signed(twenty.clone()),
+			display_name(b"ten")
+		));
+		assert_ok!(Identity::kill_identity(RuntimeOrigin::root(), twenty.clone()));
+		assert_eq!(Identity::account_of_name(b"ten"), None);
+		assert_eq!(Balances::free_balance(twenty), 1000 - id_deposit - 3 * byte_deposit);
+		assert_ok!(Identity::do_try_state());
+	});
+}
+
+#[test]
+fn identity_managers_should_work() {
+	new_test_ext().execute_with(|| {
+		let [one, _, _, _, ten, twenty, thirty, _] = accounts();
+		let ten_info = infoof_ten();
+		assert_noop!(
+			Identity::authorize_manager(RuntimeOrigin::signed(ten.clone()), twenty.clone()),
+			Error::<Test>::NoIdentity
+		);
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(ten.clone()), Box::new(ten_info)));
+		assert_noop!(
+			Identity::set_identity_for(
+				RuntimeOrigin::signed(twenty.clone()),
+				ten.clone(),
+				Box::new(infoof_twenty())
+			),
+			Error::<Test>::NotManager
+		);
+
+		assert_ok!(Identity::authorize_manager(RuntimeOrigin::signed(ten.clone()), twenty.clone()));
+		System::assert_last_event(
+			Event::ManagerAuthorized { main: ten.clone(), manager: twenty.clone() }.into(),
+		);
+
+		// The manager updates the identity and subs; deposits are held from the main account.
+		let twenty_free = Balances::free_balance(twenty.clone());
+		assert_ok!(Identity::set_identity_for(
+			RuntimeOrigin::signed(twenty.clone()),
+			ten.clone(),
+			Box::new(infoof_twenty())
+		));
+		assert_eq!(IdentityOf::<Test>::get(&ten).unwrap().info, infoof_twenty());
+		let subs = vec![(thirty.clone(), Data::Raw(vec![40; 1].try_into().unwrap()))];
+		assert_ok!(Identity::set_subs_for(
+			RuntimeOrigin::signed(twenty.clone()),
+			ten.clone(),
+			subs
+		));
+		assert_eq!(SuperOf::<Test>::get(&thirty).map(|(main, _)| main), Some(ten.clone()));
+		let sub_deposit: u64 = <<Test as Config>::SubAccountDeposit as Get<u64>>::get();
+		assert_eq!(
+			Balances::free_balance(ten.clone()),
+			1000 - id_deposit(&infoof_twenty()) - sub_deposit
+		);
+		assert_eq!(Balances::free_balance(twenty.clone()), twenty_free);
+
+		// Revoking the manager removes its rights.
+		assert_noop!(
+			Identity::revoke_manager(RuntimeOrigin::signed(one)),
+			Error::<Test>::NoManager
+		);
+		assert_ok!(Identity::revoke_manager(RuntimeOrigin::signed(ten.clone())));
+		assert_noop!(
+			Identity::set_subs_for(RuntimeOrigin::signed(twenty.clone()), ten.clone(), vec![]),
+			Error::<Test>::NotManager
+		);
+
+		// Clearing the identity removes the manager.
+		assert_ok!(Identity::authorize_manager(RuntimeOrigin::signed(ten.clone()), twenty));
+		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(ten.clone())));
+		assert_eq!(ManagerOf::<Test>::get(&ten), None);
+		assert_ok!(Identity::do_try_state());
+	});
+}
+
+#[test]
+fn setting_subaccounts_should_work() {
+	new_test_ext().execute_with(|| {
+		let [_, _, _, _, ten, twenty, thirty, forty] = accounts();
+		let ten_info = infoof_ten();
+		let id_deposit = id_deposit(&ten_info);
+		let sub_deposit: u64 = <<Test as Config>::SubAccountDeposit as Get<u64>>::get();
+		let mut subs = vec![(twenty.clone(), Data::Raw(vec![40; 1].try_into().unwrap()))];
+		assert_noop!(
+			Identity::set_subs(RuntimeOrigin::signed(ten.clone()), subs.clone()),
+			Error::<Test>::NotFound
+		);
+
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(ten.clone()), Box::new(ten_info)));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit);
+		assert_ok!(Identity::set_subs(RuntimeOrigin::signed(ten.clone()), subs.clone()));
+
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::SubIdentitiesSet {
+			main: ten.clone(),
+			number_of_subs: 1,
+			new_deposit: sub_deposit,
+		}));
+
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - sub_deposit);
+		assert_eq!(
+			SubsOf::<Test>::get(ten.clone()),
+			(sub_deposit, vec![twenty.clone()].try_into().unwrap())
+		);
+		assert_eq!(
+			SuperOf::<Test>::get(twenty.clone()),
+			Some((ten.clone(), Data::Raw(vec![40; 1].try_into().unwrap())))
+		);
+
+		// push another item and re-set it.
+		subs.push((thirty.clone(), Data::Raw(vec![50; 1].try_into().unwrap())));
+		assert_ok!(Identity::set_subs(RuntimeOrigin::signed(ten.clone()), subs.clone()));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 2 * sub_deposit);
+		assert_eq!(
+			SubsOf::<Test>::get(ten.clone()),
+			(2 * sub_deposit, vec![twenty.clone(), thirty.clone()].try_into().unwrap())
+		);
+		assert_eq!(
+			SuperOf::<Test>::get(twenty.clone()),
+			Some((ten.clone(), Data::Raw(vec![40; 1].try_into().unwrap())))
+		);
+		assert_eq!(
+			SuperOf::<Test>::get(thirty.clone()),
+			Some((ten.clone(), Data::Raw(vec![50; 1].try_into().unwrap())))
+		);
+
+		// switch out one of the items and re-set.
+		subs[0] = (forty.clone(), Data::Raw(vec![60; 1].try_into().unwrap()));
+		assert_ok!(Identity::set_subs(RuntimeOrigin::signed(ten.clone()), subs.clone()));
+		// no change in the balance
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 2 * sub_deposit);
+		assert_eq!(
+			SubsOf::<Test>::get(ten.clone()),
+			(2 * sub_deposit, vec![forty.clone(), thirty.clone()].try_into().unwrap())
+		);
+		assert_eq!(SuperOf::<Test>::get(twenty.clone()), None);
+		assert_eq!(
+			SuperOf::<Test>::get(thirty.clone()),
+			Some((ten.clone(), Data::Raw(vec![50; 1].try_into().unwrap())))
+		);
+		assert_eq!(
 			SuperOf::<Test>::get(forty.clone()),
 			Some((ten.clone(), Data::Raw(vec![60; 1].try_into().unwrap())))
 		);
-
-		// clear
-		assert_ok!(Identity::set_subs(RuntimeOrigin::signed(ten.clone()), vec![]));
+
+		// clear
+		assert_ok!(Identity::set_subs(RuntimeOrigin::signed(ten.clone()), vec![]));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit);
+		assert_eq!(SubsOf::<Test>::get(ten.clone()), (0, BoundedVec::default()));
+		assert_eq!(SuperOf::<Test>::get(thirty.clone()), None);
+		assert_eq!(SuperOf::<Test>::get(forty), None);
+
+		subs.push((twenty, Data::Raw(vec![40; 1].try_into().unwrap())));
+		assert_noop!(
+			Identity::set_subs(RuntimeOrigin::signed(ten), subs.clone()),
+			Error::<Test>::TooManySubAccounts
+		);
+	});
+}
+
+#[test]
+fn try_state_detects_inconsistent_subs_and_deposits() {
+	new_test_ext().execute_with(|| {
+		let [_, _, _, _, ten, twenty, thirty, _] = accounts();
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+		assert_ok!(Identity::set_subs(
+			RuntimeOrigin::signed(ten.clone()),
+			vec![(twenty.clone(), Data::Raw(vec![40; 1].try_into().unwrap()))]
+		));
+		assert_ok!(Identity::do_try_state());
+
+		// a `SuperOf` entry that is not part of the subs of its super account.
+		SuperOf::<Test>::insert(&thirty, (ten.clone(), Data::None));
+		assert!(Identity::do_try_state().is_err());
+		SuperOf::<Test>::remove(&thirty);
+
+		// a sub-account without a `SuperOf` entry.
+		let super_of_twenty = SuperOf::<Test>::take(&twenty).unwrap();
+		assert!(Identity::do_try_state().is_err());
+		SuperOf::<Test>::insert(&twenty, super_of_twenty);
+		assert_ok!(Identity::do_try_state());
+
+		// deposits that are not held anymore.
+		assert_eq!(<Balances as ReservableCurrency<_>>::unreserve(&ten, 1), 0);
+		assert!(Identity::do_try_state().is_err());
+	});
+}
+
+#[test]
+fn setting_subaccounts_storage_accesses_are_bounded_by_subs() {
+	use frame_support::storage::{
+		storage_access_recorder::record_storage_accesses, StoragePrefixedMap,
+	};
+
+	new_test_ext().execute_with(|| {
+		let [_, _, _, _, ten, twenty, thirty, forty] = accounts();
+		let data = |byte| Data::Raw(vec![byte; 1].try_into().unwrap());
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+		assert_ok!(Identity::set_subs(
+			RuntimeOrigin::signed(ten.clone()),
+			vec![(twenty.clone(), data(1)), (thirty.clone(), data(2))]
+		));
+
+		let (result, log) = record_storage_accesses(|| {
+			Identity::set_subs(
+				RuntimeOrigin::signed(ten.clone()),
+				vec![(thirty.clone(), data(3)), (forty.clone(), data(4))],
+			)
+		});
+		assert_ok!(result);
+
+		// The old subs are removed and the new ones inserted, without scanning `SuperOf`.
+		log.assert_max_iterations(0);
+		log.assert_max_accesses_with_prefix(&SuperOf::<Test>::final_prefix(), 2 + 2 + 2);
+		assert_eq!(SuperOf::<Test>::get(twenty), None);
+		assert_eq!(SuperOf::<Test>::get(forty), Some((ten, data(4))));
+	});
+}
+
+#[test]
+fn clearing_account_should_remove_subaccounts_and_refund() {
+	new_test_ext().execute_with(|| {
+		let [_, _, _, _, ten, twenty, _, _] = accounts();
+		let ten_info = infoof_ten();
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(ten_info.clone())
+		));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit(&ten_info));
+		assert_ok!(Identity::set_subs(
+			RuntimeOrigin::signed(ten.clone()),
+			vec![(twenty.clone(), Data::Raw(vec![40; 1].try_into().unwrap()))]
+		));
+		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(ten.clone())));
+		assert_eq!(Balances::free_balance(ten), 1000);
+		assert!(SuperOf::<Test>::get(twenty).is_none());
+	});
+}
+
+#[test]
+fn killing_account_should_remove_subaccounts_and_not_refund() {
+	new_test_ext().execute_with(|| {
+		let [_, _, _, _, ten, twenty, _, _] = accounts();
+		let ten_info = infoof_ten();
+		let id_deposit = id_deposit(&ten_info);
+		let sub_deposit: u64 = <<Test as Config>::SubAccountDeposit as Get<u64>>::get();
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(ten.clone()), Box::new(ten_info)));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit);
+		assert_ok!(Identity::set_subs(
+			RuntimeOrigin::signed(ten.clone()),
+			vec![(twenty.clone(), Data::Raw(vec![40; 1].try_into().unwrap()))]
+		));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - sub_deposit);
+		assert_ok!(Identity::kill_identity(RuntimeOrigin::root(), ten.clone()));
+		assert_eq!(Balances::free_balance(ten), 1000 - id_deposit - sub_deposit);
+		assert!(SuperOf::<Test>::get(twenty).is_none());
+	});
+}
+
+#[test]
+fn set_subs_only_writes_changed_subs() {
+	new_test_ext().execute_with(|| {
+		let data = |x| Data::Raw(vec![x; 1].try_into().unwrap());
+		let [one, _, _, _, ten, twenty, _, _] = accounts();
+		let sub_deposit: u64 = <<Test as Config>::SubAccountDeposit as Get<u64>>::get();
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+		assert_ok!(Identity::invite_sub(
+			RuntimeOrigin::signed(ten.clone()),
+			twenty.clone(),
+			data(1)
+		));
+		assert_ok!(Identity::add_sub_with_own_deposit(
+			RuntimeOrigin::signed(twenty.clone()),
+			ten.clone()
+		));
+
+		// the self-funded sub is kept as is
+		let info = Identity::set_subs(
+			RuntimeOrigin::signed(ten.clone()),
+			vec![(twenty.clone(), data(1)), (one.clone(), data(2))],
+		)
+		.unwrap();
+		assert_eq!(info.actual_weight, Some(Identity::set_subs_weight(1, 0, 1, 0)));
+		assert_eq!(SubDepositOf::<Test>::get(twenty.clone()), Some(sub_deposit));
+		assert_eq!(Balances::free_balance(twenty.clone()), 1000 - sub_deposit);
+		assert_eq!(SubsOf::<Test>::get(ten.clone()).0, sub_deposit);
+		assert_ok!(Identity::do_try_state());
+
+		// renaming writes the sub, removing releases the deposit held for it
+		let info =
+			Identity::set_subs(RuntimeOrigin::signed(ten.clone()), vec![(twenty.clone(), data(3))])
+				.unwrap();
+		assert_eq!(info.actual_weight, Some(Identity::set_subs_weight(1, 1, 0, 0)));
+		assert_eq!(SuperOf::<Test>::get(twenty.clone()), Some((ten.clone(), data(3))));
+		assert_eq!(SuperOf::<Test>::get(one), None);
+		assert_eq!(SubDepositOf::<Test>::get(twenty), Some(sub_deposit));
+		assert_eq!(SubsOf::<Test>::get(ten).0, 0);
+		assert_ok!(Identity::do_try_state());
+	});
+}
+
+#[test]
+fn killing_account_should_refund_self_funded_subaccounts() {
+	new_test_ext().execute_with(|| {
+		let [_, _, _, _, ten, twenty, _, _] = accounts();
+		let ten_info = infoof_ten();
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(ten.clone()), Box::new(ten_info)));
+		assert_ok!(Identity::invite_sub(
+			RuntimeOrigin::signed(ten.clone()),
+			twenty.clone(),
+			Data::Raw(vec![40; 1].try_into().unwrap())
+		));
+		assert_ok!(Identity::add_sub_with_own_deposit(
+			RuntimeOrigin::signed(twenty.clone()),
+			ten.clone()
+		));
+		assert_ok!(Identity::kill_identity(RuntimeOrigin::root(), ten));
+		assert_eq!(Balances::free_balance(twenty.clone()), 1000);
+		assert!(SuperOf::<Test>::get(twenty.clone()).is_none());
+		assert!(SubDepositOf::<Test>::get(twenty).is_none());
+	});
+}
+
+#[test]
+fn transferring_identity_should_work() {
+	new_test_ext().execute_with(|| {
+		let data = |x| Data::Raw(vec![x; 1].try_into().unwrap());
+		let [one, two, three, _, ten, twenty, thirty, _] = accounts();
+		let sub_deposit: u64 = <<Test as Config>::SubAccountDeposit as Get<u64>>::get();
+		let ten_info = infoof_ten();
+		let id_deposit = id_deposit(&ten_info);
+		let name: DisplayName = b"ten".to_vec().try_into().unwrap();
+		let name_deposit = 30;
+
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10, None));
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(ten_info.clone())
+		));
+		assert_ok!(Identity::set_subs(
+			RuntimeOrigin::signed(ten.clone()),
+			vec![(one.clone(), data(1))]
+		));
+		assert_ok!(Identity::claim_name(RuntimeOrigin::signed(ten.clone()), name.clone()));
+		assert_ok!(Identity::authorize_manager(RuntimeOrigin::signed(ten.clone()), thirty.clone()));
+		assert_ok!(Identity::request_judgement(
+			RuntimeOrigin::signed(ten.clone()),
+			0,
+			10,
+			None,
+			None
+		));
+		assert_ok!(Identity::invite_sub(RuntimeOrigin::signed(ten.clone()), two.clone(), data(2)));
+		assert_ok!(Identity::invite_sub(
+			RuntimeOrigin::signed(ten.clone()),
+			twenty.clone(),
+			data(20)
+		));
+		// the invitation of `twenty` is withdrawn when it receives the identity
+		let deposit = id_deposit + sub_deposit + name_deposit + 10 + sub_deposit;
+		assert_eq!(Balances::reserved_balance(ten.clone()), deposit + sub_deposit);
+
+		assert_noop!(
+			Identity::transfer_identity(
+				RuntimeOrigin::signed(ten.clone()),
+				ten.clone(),
+				twenty.clone()
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			Identity::transfer_identity(RuntimeOrigin::root(), ten.clone(), one.clone()),
+			Error::<Test>::AlreadyClaimed
+		);
+		assert_noop!(
+			Identity::transfer_identity(RuntimeOrigin::root(), twenty.clone(), thirty.clone()),
+			Error::<Test>::NoIdentity
+		);
+
+		assert_ok!(Identity::transfer_identity(RuntimeOrigin::root(), ten.clone(), twenty.clone()));
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::IdentityTransferred {
+			old: ten.clone(),
+			new: twenty.clone(),
+			deposit,
+		}));
+		assert!(IdentityOf::<Test>::get(ten.clone()).is_none());
+		assert_eq!(IdentityOf::<Test>::get(twenty.clone()).unwrap().info, ten_info);
+		assert_eq!(SuperOf::<Test>::get(one), Some((twenty.clone(), data(1))));
+		assert_eq!(Identity::account_of_name(&name), Some(twenty.clone()));
+		assert!(ManagerOf::<Test>::get(ten.clone()).is_none());
+		assert!(!PendingSubsOf::<Test>::contains_key(ten.clone()));
+		assert_eq!(
+			PendingSubsOf::<Test>::get(twenty.clone()),
+			(sub_deposit, vec![(two, data(2))].try_into().unwrap())
+		);
+		assert_eq!(Balances::reserved_balance(ten.clone()), 0);
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - deposit);
+		assert_eq!(Balances::reserved_balance(twenty.clone()), deposit);
+
+		// the pending judgement is paid from the new account
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			twenty.clone(),
+			Judgement::Reasonable,
+			BlakeTwo256::hash_of(&ten_info)
+		));
+		assert_eq!(Balances::free_balance(three), 100 + 10);
+		assert_eq!(Balances::reserved_balance(twenty.clone()), deposit - 10);
+
+		assert_noop!(
+			Identity::transfer_identity(RuntimeOrigin::root(), ten, twenty),
+			Error::<Test>::NoIdentity
+		);
+		assert_ok!(Identity::do_try_state());
+	});
+}
+
+#[test]
+fn verification_challenges_should_work() {
+	use frame_support::{
+		pallet_prelude::TransactionSource, traits::Hooks, unsigned::ValidateUnsigned,
+	};
+	use offchain::{Attestation, ChallengeNonce, ChallengeUrl};
+	use sp_core::offchain::{
+		testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	};
+	use sp_runtime::RuntimeAppPublic;
+
+	let mut ext = new_test_ext();
+	let (offchain, offchain_state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		let [_, _, three, _, ten, twenty, _, _] = accounts();
+		let attester = offchain::crypto::Public::generate_pair(None);
+		let url: ChallengeUrl = b"https://example.com/ten".to_vec().try_into().unwrap();
+		let nonce: ChallengeNonce = b"nonce-of-ten".to_vec().try_into().unwrap();
+		let expect_request = |response: &[u8]| {
+			offchain_state.write().expect_request(PendingRequest {
+				method: "GET".into(),
+				uri: "https://example.com/ten".into(),
+				response: Some(response.to_vec()),
+				sent: true,
+				..Default::default()
+			})
+		};
+
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+		assert_noop!(
+			Identity::set_challenge(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				ten.clone(),
+				url.clone(),
+				nonce.clone()
+			),
+			Error::<Test>::NoAttester
+		);
+		assert_noop!(
+			Identity::set_attester(RuntimeOrigin::signed(ten.clone()), 0, Some(attester.clone())),
+			Error::<Test>::InvalidIndex
+		);
+		assert_ok!(Identity::set_attester(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			Some(attester.clone())
+		));
+		assert_noop!(
+			Identity::set_challenge(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				twenty.clone(),
+				url.clone(),
+				nonce.clone()
+			),
+			Error::<Test>::NoIdentity
+		);
+		assert_noop!(
+			Identity::set_challenge(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				ten.clone(),
+				url.clone(),
+				Default::default()
+			),
+			Error::<Test>::InvalidChallenge
+		);
+		assert_ok!(Identity::set_challenge(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			ten.clone(),
+			url.clone(),
+			nonce.clone()
+		));
+
+		// the nonce is not published yet
+		expect_request(b"hello");
+		Identity::offchain_worker(1);
+		assert!(pool_state.read().transactions.is_empty());
+		// and the challenge is not checked again right away
+		Identity::offchain_worker(2);
+
+		expect_request(b"<p>nonce-of-ten</p>");
+		Identity::offchain_worker(11);
+		let transaction = pool_state.write().transactions.pop().unwrap();
+		let (attestation, signature) = match Extrinsic::decode(&mut &*transaction).unwrap().function
+		{
+			RuntimeCall::Identity(Call::submit_attestation { attestation, signature }) =>
+				(attestation, signature),
+			e => panic!("Unexpected call: {:?}", e),
+		};
+		assert_eq!(
+			attestation,
+			Attestation { target: ten.clone(), registrar_index: 0, nonce: nonce.clone() }
+		);
+		let call = Call::submit_attestation {
+			attestation: attestation.clone(),
+			signature: signature.clone(),
+		};
+		assert_ok!(Identity::validate_unsigned(TransactionSource::Local, &call));
+
+		// attestations must be signed by the attester of the registrar
+		let forged = offchain::crypto::Public::generate_pair(None)
+			.sign(&attestation.encode())
+			.unwrap();
+		assert_noop!(
+			Identity::submit_attestation(RuntimeOrigin::none(), attestation.clone(), forged),
+			Error::<Test>::InvalidAttestation
+		);
+		assert_noop!(
+			Identity::submit_attestation(
+				RuntimeOrigin::signed(ten.clone()),
+				attestation.clone(),
+				signature.clone()
+			),
+			BadOrigin
+		);
+
+		assert_ok!(Identity::submit_attestation(
+			RuntimeOrigin::none(),
+			attestation.clone(),
+			signature.clone()
+		));
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::ChallengeVerified {
+			target: ten.clone(),
+			registrar_index: 0,
+		}));
+		assert!(ChallengeOf::<Test>::get(&ten, 0).unwrap().verified);
+		assert!(Identity::validate_unsigned(TransactionSource::Local, &call).is_err());
+		assert_noop!(
+			Identity::submit_attestation(RuntimeOrigin::none(), attestation, signature),
+			Error::<Test>::AlreadyVerified
+		);
+
+		// verified challenges are not checked again
+		Identity::offchain_worker(30);
+		assert!(pool_state.read().transactions.is_empty());
+		assert_ok!(Identity::do_try_state());
+
+		assert_ok!(Identity::remove_challenge(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			ten.clone()
+		));
+		assert_noop!(
+			Identity::remove_challenge(RuntimeOrigin::signed(three), 0, ten),
+			Error::<Test>::NoChallenge
+		);
+	});
+}
+
+#[test]
+fn cancelling_requested_judgement_should_work() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, _, _, _] = accounts();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10, None));
+		assert_noop!(
+			Identity::cancel_request(RuntimeOrigin::signed(ten.clone()), 0),
+			Error::<Test>::NoIdentity
+		);
+		let ten_info = infoof_ten();
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(ten_info.clone())
+		));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit(&ten_info));
+		assert_ok!(Identity::request_judgement(
+			RuntimeOrigin::signed(ten.clone()),
+			0,
+			10,
+			None,
+			None
+		));
+		assert_ok!(Identity::cancel_request(RuntimeOrigin::signed(ten.clone()), 0));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit(&ten_info));
+		assert_noop!(
+			Identity::cancel_request(RuntimeOrigin::signed(ten.clone()), 0),
+			Error::<Test>::NotFound
+		);
+
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(three),
+			0,
+			ten.clone(),
+			Judgement::Reasonable,
+			BlakeTwo256::hash_of(&ten_info)
+		));
+		assert_noop!(
+			Identity::cancel_request(RuntimeOrigin::signed(ten), 0),
+			Error::<Test>::JudgementGiven
+		);
+	});
+}
+
+#[test]
+fn requesting_judgement_should_work() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, four, ten, _, _, _] = accounts();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10, None));
+		let ten_info = infoof_ten();
+		let id_deposit = id_deposit(&ten_info);
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(ten_info.clone())
+		));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit);
+		assert_noop!(
+			Identity::request_judgement(RuntimeOrigin::signed(ten.clone()), 0, 9, None, None),
+			Error::<Test>::FeeChanged
+		);
+		assert_ok!(Identity::request_judgement(
+			RuntimeOrigin::signed(ten.clone()),
+			0,
+			10,
+			None,
+			None
+		));
+		// 10 for the judgement request and the deposit for the identity.
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 10);
+
+		// Re-requesting won't work as we already paid.
+		assert_noop!(
+			Identity::request_judgement(RuntimeOrigin::signed(ten.clone()), 0, 10, None, None),
+			Error::<Test>::StickyJudgement
+		);
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			ten.clone(),
+			Judgement::Erroneous,
+			BlakeTwo256::hash_of(&ten_info)
+		));
+		// Registrar got their payment now.
+		// 100 initial balance and 10 for the judgement.
+		assert_eq!(Balances::free_balance(three.clone()), 100 + 10);
+
+		// Re-requesting still won't work as it's erroneous.
+		assert_noop!(
+			Identity::request_judgement(RuntimeOrigin::signed(ten.clone()), 0, 10, None, None),
+			Error::<Test>::StickyJudgement
+		);
+
+		// Requesting from a second registrar still works.
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), four));
+		assert_ok!(Identity::request_judgement(
+			RuntimeOrigin::signed(ten.clone()),
+			1,
+			10,
+			None,
+			None
+		));
+
+		// Re-requesting after the judgement has been reduced works.
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(three),
+			0,
+			ten.clone(),
+			Judgement::OutOfDate,
+			BlakeTwo256::hash_of(&ten_info)
+		));
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(ten), 0, 10, None, None));
+	});
+}
+
+#[test]
+fn judgement_requests_are_limited_per_registrar() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, twenty, _, _] = accounts();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10, None));
+		assert_noop!(
+			Identity::set_max_pending(RuntimeOrigin::signed(ten.clone()), 0, Some(1)),
+			Error::<Test>::InvalidIndex
+		);
+		assert_ok!(Identity::set_max_pending(RuntimeOrigin::signed(three.clone()), 0, Some(1)));
+		assert_eq!(Registrars::<Test>::get()[0].as_ref().unwrap().max_pending, Some(1));
+
+		let ten_info = infoof_ten();
+		let twenty_info = infoof_twenty();
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(ten_info.clone())
+		));
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(twenty.clone()),
+			Box::new(twenty_info.clone())
+		));
+
+		assert_ok!(Identity::request_judgement(
+			RuntimeOrigin::signed(ten.clone()),
+			0,
+			10,
+			None,
+			None
+		));
+		assert_eq!(PendingRequestsOf::<Test>::get(0), 1);
+		assert_noop!(
+			Identity::request_judgement(RuntimeOrigin::signed(twenty.clone()), 0, 10, None, None),
+			Error::<Test>::TooManyPendingRequests
+		);
+
+		// cancelling frees the slot
+		assert_ok!(Identity::cancel_request(RuntimeOrigin::signed(ten.clone()), 0));
+		assert_eq!(PendingRequestsOf::<Test>::get(0), 0);
+		assert_ok!(Identity::request_judgement(
+			RuntimeOrigin::signed(twenty.clone()),
+			0,
+			10,
+			None,
+			None
+		));
+		assert_noop!(
+			Identity::request_judgement(RuntimeOrigin::signed(ten.clone()), 0, 10, None, None),
+			Error::<Test>::TooManyPendingRequests
+		);
+
+		// and so does a judgement
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			twenty.clone(),
+			Judgement::Reasonable,
+			BlakeTwo256::hash_of(&twenty_info)
+		));
+		assert_eq!(PendingRequestsOf::<Test>::get(0), 0);
+		assert_ok!(Identity::request_judgement(
+			RuntimeOrigin::signed(ten.clone()),
+			0,
+			10,
+			None,
+			None
+		));
+		assert_ok!(Identity::do_try_state());
+
+		// clearing the identity closes its open requests
+		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(ten)));
+		assert_eq!(PendingRequestsOf::<Test>::get(0), 0);
+		assert_ok!(Identity::set_max_pending(RuntimeOrigin::signed(three), 0, None));
+		assert_ok!(Identity::do_try_state());
+	});
+}
+
+#[test]
+fn fees_of_removed_registrars_can_be_reclaimed() {
+	new_test_ext().execute_with(|| {
+		let [one, _, three, _, ten, _, _, _] = accounts();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10, None));
+		let ten_info = infoof_ten();
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(ten_info.clone())
+		));
+		let id_deposit = IdentityOf::<Test>::get(&ten).unwrap().deposit;
+		assert_ok!(Identity::request_judgement(
+			RuntimeOrigin::signed(ten.clone()),
+			0,
+			10,
+			None,
+			None
+		));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 10);
+
+		assert_noop!(
+			Identity::reclaim_fee(RuntimeOrigin::signed(one.clone()), ten.clone(), 0),
+			Error::<Test>::RegistrarActive
+		);
+		assert_noop!(
+			Identity::remove_registrar(RuntimeOrigin::signed(three.clone()), 0, 0),
+			BadOrigin
+		);
+		assert_ok!(Identity::remove_registrar(RuntimeOrigin::root(), 0, 0));
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::RegistrarRemoved {
+			registrar_index: 0,
+		}));
+		assert!(Registrars::<Test>::get()[0].is_none());
+		assert!(!PendingRequestsOf::<Test>::contains_key(0));
+		assert_noop!(
+			Identity::remove_registrar(RuntimeOrigin::root(), 0, 0),
+			Error::<Test>::InvalidIndex
+		);
+		assert_noop!(
+			Identity::request_judgement(RuntimeOrigin::signed(ten.clone()), 0, 10, None, None),
+			Error::<Test>::EmptyIndex
+		);
+		assert_ok!(Identity::do_try_state());
+
+		// anyone can return the fee to the requester
+		assert_ok!(Identity::reclaim_fee(RuntimeOrigin::signed(one.clone()), ten.clone(), 0));
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::FeeReclaimed {
+			target: ten.clone(),
+			registrar_index: 0,
+			fee: 10,
+		}));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit);
+		assert!(IdentityOf::<Test>::get(&ten).unwrap().judgements.is_empty());
+		assert_noop!(
+			Identity::reclaim_fee(RuntimeOrigin::signed(one), ten, 0),
+			Error::<Test>::NotFound
+		);
+		assert!(!PendingRequestsOf::<Test>::contains_key(0));
+		assert_ok!(Identity::do_try_state());
+	});
+}
+
+#[test]
+fn judgement_requests_expire_after_their_deadline() {
+	new_test_ext().execute_with(|| {
+		let [one, _, three, _, ten, _, _, _] = accounts();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10, None));
+		let ten_info = infoof_ten();
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(ten_info.clone())
+		));
+		let id_deposit = IdentityOf::<Test>::get(&ten).unwrap().deposit;
+
+		assert_noop!(
+			Identity::request_judgement(RuntimeOrigin::signed(ten.clone()), 0, 10, None, Some(1)),
+			Error::<Test>::InvalidDeadline
+		);
+		assert_ok!(Identity::request_judgement(
+			RuntimeOrigin::signed(ten.clone()),
+			0,
+			10,
+			None,
+			Some(3)
+		));
+		assert_eq!(RequestDeadlineOf::<Test>::get(&ten, 0), Some(3));
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 10);
+
+		// the request is valid up to and including its deadline
+		run_to_block(3);
+		assert_noop!(
+			Identity::expire_request(RuntimeOrigin::signed(one.clone()), ten.clone(), 0),
+			Error::<Test>::RequestNotExpired
+		);
+		run_to_block(4);
+		// the registrar can no longer be paid for it
+		assert_noop!(
+			Identity::provide_judgement(
+				RuntimeOrigin::signed(three.clone()),
+				0,
+				ten.clone(),
+				Judgement::Reasonable,
+				BlakeTwo256::hash_of(&ten_info)
+			),
+			Error::<Test>::RequestExpired
+		);
+		assert_ok!(Identity::expire_request(RuntimeOrigin::signed(one.clone()), ten.clone(), 0));
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::JudgementRequestExpired {
+			target: ten.clone(),
+			registrar_index: 0,
+			fee: 10,
+		}));
 		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit);
-		assert_eq!(SubsOf::<Test>::get(ten.clone()), (0, BoundedVec::default()));
-		assert_eq!(SuperOf::<Test>::get(thirty.clone()), None);
-		assert_eq!(SuperOf::<Test>::get(forty), None);
+		assert!(IdentityOf::<Test>::get(&ten).unwrap().judgements.is_empty());
+		assert!(!RequestDeadlineOf::<Test>::contains_key(&ten, 0));
+		assert_eq!(PendingRequestsOf::<Test>::get(0), 0);
+		assert_ok!(Identity::do_try_state());
 
-		subs.push((twenty, Data::Raw(vec![40; 1].try_into().unwrap())));
+		// a judgement given in time drops the deadline
+		assert_ok!(Identity::request_judgement(
+			RuntimeOrigin::signed(ten.clone()),
+			0,
+			10,
+			None,
+			Some(10)
+		));
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(three),
+			0,
+			ten.clone(),
+			Judgement::Reasonable,
+			BlakeTwo256::hash_of(&ten_info)
+		));
+		assert!(!RequestDeadlineOf::<Test>::contains_key(&ten, 0));
+		run_to_block(11);
 		assert_noop!(
-			Identity::set_subs(RuntimeOrigin::signed(ten), subs.clone()),
-			Error::<Test>::TooManySubAccounts
+			Identity::expire_request(RuntimeOrigin::signed(one), ten, 0),
+			Error::<Test>::RequestNotExpired
 		);
+		assert_ok!(Identity::do_try_state());
 	});
 }
 
 #[test]
-fn clearing_account_should_remove_subaccounts_and_refund() {
+fn identity_levels_are_verified() {
 	new_test_ext().execute_with(|| {
-		let [_, _, _, _, ten, twenty, _, _] = accounts();
+		let [one, two, _, _, ten, twenty, _, _] = accounts();
+		let reasonable = Judgement::Reasonable;
+		let known_good = Judgement::KnownGood;
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), one.clone()));
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), two.clone()));
 		let ten_info = infoof_ten();
 		assert_ok!(Identity::set_identity(
 			RuntimeOrigin::signed(ten.clone()),
 			Box::new(ten_info.clone())
 		));
-		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit(&ten_info));
 		assert_ok!(Identity::set_subs(
 			RuntimeOrigin::signed(ten.clone()),
-			vec![(twenty.clone(), Data::Raw(vec![40; 1].try_into().unwrap()))]
+			vec![(twenty.clone(), Data::Raw(vec![1; 1].try_into().unwrap()))]
 		));
-		assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(ten.clone())));
-		assert_eq!(Balances::free_balance(ten), 1000);
-		assert!(SuperOf::<Test>::get(twenty).is_none());
-	});
-}
+		assert!(!Identity::has_identity_level(&ten, &reasonable, &[0, 1]));
 
-#[test]
-fn killing_account_should_remove_subaccounts_and_not_refund() {
-	new_test_ext().execute_with(|| {
-		let [_, _, _, _, ten, twenty, _, _] = accounts();
-		let ten_info = infoof_ten();
-		let id_deposit = id_deposit(&ten_info);
-		let sub_deposit: u64 = <<Test as Config>::SubAccountDeposit as Get<u64>>::get();
-		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(ten.clone()), Box::new(ten_info)));
-		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit);
-		assert_ok!(Identity::set_subs(
-			RuntimeOrigin::signed(ten.clone()),
-			vec![(twenty.clone(), Data::Raw(vec![40; 1].try_into().unwrap()))]
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(one),
+			0,
+			ten.clone(),
+			Judgement::Reasonable,
+			BlakeTwo256::hash_of(&ten_info)
 		));
-		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - sub_deposit);
-		assert_ok!(Identity::kill_identity(RuntimeOrigin::root(), ten.clone()));
-		assert_eq!(Balances::free_balance(ten), 1000 - id_deposit - sub_deposit);
-		assert!(SuperOf::<Test>::get(twenty).is_none());
+		assert!(Identity::has_identity_level(&ten, &reasonable, &[0, 1]));
+		assert!(!Identity::has_identity_level(&ten, &known_good, &[0, 1]));
+		assert!(!Identity::has_identity_level(&ten, &reasonable, &[1]));
+		assert!(!Identity::has_identity_level(&ten, &reasonable, &[]));
+		// sub-accounts are verified by the identity of their super account
+		assert!(Identity::has_identity_level(&twenty, &reasonable, &[0]));
+
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(two),
+			1,
+			ten.clone(),
+			Judgement::KnownGood,
+			BlakeTwo256::hash_of(&ten_info)
+		));
+		assert!(Identity::has_identity_level(&ten, &known_good, &[0, 1]));
+		assert!(Identity::has_identity_level(&ten, &reasonable, &[1]));
+
+		// judgements of removed registrars do not count
+		assert_ok!(Identity::remove_registrar(RuntimeOrigin::root(), 1, 0));
+		assert!(!Identity::has_identity_level(&ten, &known_good, &[0, 1]));
+		assert!(Identity::has_identity_level(&ten, &reasonable, &[0, 1]));
 	});
 }
 
 #[test]
-fn cancelling_requested_judgement_should_work() {
+fn judgement_evidence_should_work() {
 	new_test_ext().execute_with(|| {
 		let [_, _, three, _, ten, _, _, _] = accounts();
+		let evidence: Evidence = b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"
+			.to_vec()
+			.try_into()
+			.unwrap();
 		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
-		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10));
-		assert_noop!(
-			Identity::cancel_request(RuntimeOrigin::signed(ten.clone()), 0),
-			Error::<Test>::NoIdentity
-		);
 		let ten_info = infoof_ten();
 		assert_ok!(Identity::set_identity(
 			RuntimeOrigin::signed(ten.clone()),
 			Box::new(ten_info.clone())
 		));
-		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit(&ten_info));
-		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(ten.clone()), 0, 10));
-		assert_ok!(Identity::cancel_request(RuntimeOrigin::signed(ten.clone()), 0));
-		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit(&ten_info));
 		assert_noop!(
-			Identity::cancel_request(RuntimeOrigin::signed(ten.clone()), 0),
-			Error::<Test>::NotFound
+			Identity::dispute_evidence(RuntimeOrigin::signed(ten.clone()), 0),
+			Error::<Test>::NoEvidence
+		);
+
+		assert_ok!(Identity::provide_judgement_with_evidence(
+			RuntimeOrigin::signed(three.clone()),
+			0,
+			ten.clone(),
+			Judgement::KnownGood,
+			BlakeTwo256::hash_of(&ten_info),
+			evidence.clone()
+		));
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::EvidenceProvided {
+			target: ten.clone(),
+			registrar_index: 0,
+			evidence: evidence.clone(),
+		}));
+		assert_eq!(
+			EvidenceOf::<Test>::get(ten.clone(), 0),
+			Some(JudgementEvidence { evidence: evidence.clone(), disputed: false })
+		);
+
+		// the owner of the identity may dispute the evidence once
+		assert_ok!(Identity::dispute_evidence(RuntimeOrigin::signed(ten.clone()), 0));
+		System::assert_last_event(tests::RuntimeEvent::Identity(Event::EvidenceDisputed {
+			who: ten.clone(),
+			registrar_index: 0,
+		}));
+		assert!(EvidenceOf::<Test>::get(ten.clone(), 0).unwrap().disputed);
+		assert_noop!(
+			Identity::dispute_evidence(RuntimeOrigin::signed(ten.clone()), 0),
+			Error::<Test>::AlreadyDisputed
 		);
+		assert_ok!(Identity::do_try_state());
 
+		// a new judgement without evidence drops the old evidence
 		assert_ok!(Identity::provide_judgement(
-			RuntimeOrigin::signed(three),
+			RuntimeOrigin::signed(three.clone()),
 			0,
 			ten.clone(),
 			Judgement::Reasonable,
 			BlakeTwo256::hash_of(&ten_info)
 		));
-		assert_noop!(
-			Identity::cancel_request(RuntimeOrigin::signed(ten), 0),
-			Error::<Test>::JudgementGiven
-		);
+		assert!(EvidenceOf::<Test>::get(ten.clone(), 0).is_none());
+
+		// so does changing the identity
+		assert_ok!(Identity::provide_judgement_with_evidence(
+			RuntimeOrigin::signed(three),
+			0,
+			ten.clone(),
+			Judgement::KnownGood,
+			BlakeTwo256::hash_of(&ten_info),
+			evidence
+		));
+		let mut new_info = ten_info;
+		new_info.legal = Data::Raw(b"Ten".to_vec().try_into().unwrap());
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(ten.clone()), Box::new(new_info)));
+		assert!(EvidenceOf::<Test>::get(ten, 0).is_none());
+		assert_ok!(Identity::do_try_state());
 	});
 }
 
 #[test]
-fn requesting_judgement_should_work() {
+fn remove_registrar_clears_its_challenges_and_evidence() {
+	use sp_runtime::RuntimeAppPublic;
+
 	new_test_ext().execute_with(|| {
-		let [_, _, three, four, ten, _, _, _] = accounts();
-		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
-		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10));
+		let [one, _, three, _, ten, _, _, _] = accounts();
+		let evidence: Evidence = b"evidence".to_vec().try_into().unwrap();
 		let ten_info = infoof_ten();
-		let id_deposit = id_deposit(&ten_info);
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), one.clone()));
 		assert_ok!(Identity::set_identity(
 			RuntimeOrigin::signed(ten.clone()),
 			Box::new(ten_info.clone())
 		));
-		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit);
-		assert_noop!(
-			Identity::request_judgement(RuntimeOrigin::signed(ten.clone()), 0, 9),
-			Error::<Test>::FeeChanged
-		);
-		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(ten.clone()), 0, 10));
-		// 10 for the judgement request and the deposit for the identity.
-		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 10);
-
-		// Re-requesting won't work as we already paid.
-		assert_noop!(
-			Identity::request_judgement(RuntimeOrigin::signed(ten.clone()), 0, 10),
-			Error::<Test>::StickyJudgement
-		);
-		assert_ok!(Identity::provide_judgement(
+		for (registrar, reg_index) in [(three.clone(), 0), (one, 1)] {
+			assert_ok!(Identity::provide_judgement_with_evidence(
+				RuntimeOrigin::signed(registrar),
+				reg_index,
+				ten.clone(),
+				Judgement::KnownGood,
+				BlakeTwo256::hash_of(&ten_info),
+				evidence.clone()
+			));
+		}
+		assert_ok!(Identity::set_attester(
 			RuntimeOrigin::signed(three.clone()),
 			0,
-			ten.clone(),
-			Judgement::Erroneous,
-			BlakeTwo256::hash_of(&ten_info)
+			Some(offchain::crypto::Public::generate_pair(None))
 		));
-		// Registrar got their payment now.
-		// 100 initial balance and 10 for the judgement.
-		assert_eq!(Balances::free_balance(three.clone()), 100 + 10);
-
-		// Re-requesting still won't work as it's erroneous.
-		assert_noop!(
-			Identity::request_judgement(RuntimeOrigin::signed(ten.clone()), 0, 10),
-			Error::<Test>::StickyJudgement
-		);
-
-		// Requesting from a second registrar still works.
-		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), four));
-		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(ten.clone()), 1, 10));
-
-		// Re-requesting after the judgement has been reduced works.
-		assert_ok!(Identity::provide_judgement(
+		assert_ok!(Identity::set_challenge(
 			RuntimeOrigin::signed(three),
 			0,
 			ten.clone(),
-			Judgement::OutOfDate,
-			BlakeTwo256::hash_of(&ten_info)
+			b"https://example.com/ten".to_vec().try_into().unwrap(),
+			b"nonce-of-ten".to_vec().try_into().unwrap()
 		));
-		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(ten), 0, 10));
+
+		// all the challenges and evidences are scanned, not only those of the registrar
+		assert_noop!(
+			Identity::remove_registrar(RuntimeOrigin::root(), 0, 2),
+			Error::<Test>::BadWitness
+		);
+		assert_ok!(Identity::remove_registrar(RuntimeOrigin::root(), 0, 3));
+		assert!(ChallengeOf::<Test>::get(ten.clone(), 0).is_none());
+		assert!(EvidenceOf::<Test>::get(ten.clone(), 0).is_none());
+		assert!(EvidenceOf::<Test>::get(ten, 1).is_some());
+		assert_ok!(Identity::do_try_state());
 	});
 }
 
@@ -762,12 +2494,18 @@ fn provide_judgement_should_return_judgement_payment_failed_error() {
 		let ten_info = infoof_ten();
 		let id_deposit = id_deposit(&ten_info);
 		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
-		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), 0, 10, None));
 		assert_ok!(Identity::set_identity(
 			RuntimeOrigin::signed(ten.clone()),
 			Box::new(ten_info.clone())
 		));
-		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(ten.clone()), 0, 10));
+		assert_ok!(Identity::request_judgement(
+			RuntimeOrigin::signed(ten.clone()),
+			0,
+			10,
+			None,
+			None
+		));
 		// 10 for the judgement request and the deposit for the identity.
 		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 10);
 
@@ -791,7 +2529,7 @@ fn field_deposit_should_work() {
 	new_test_ext().execute_with(|| {
 		let [_, _, three, _, ten, _, _, _] = accounts();
 		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
-		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three), 0, 10));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three), 0, 10, None));
 		let id = IdentityInfo {
 			additional: vec![
 				(
@@ -892,6 +2630,7 @@ fn poke_deposit_works() {
 			Registration {
 				judgements: Default::default(),
 				deposit: Zero::zero(),
+				frozen: false,
 				info: ten_info.clone(),
 			},
 		);
@@ -906,7 +2645,7 @@ fn poke_deposit_works() {
 		assert_eq!(Balances::free_balance(ten.clone()), 1000);
 
 		// poke
-		assert_ok!(Identity::poke_deposit(&ten));
+		assert_ok!(Identity::do_poke_deposit(&ten));
 
 		// free balance reduced correctly
 		let id_deposit = id_deposit(&ten_info);
@@ -918,7 +2657,8 @@ fn poke_deposit_works() {
 			Some(Registration {
 				judgements: Default::default(),
 				deposit: id_deposit,
-				info: infoof_ten()
+				frozen: false,
+				info: infoof_ten(),
 			},)
 		);
 		// new subs deposit is 10           vvvvvvvvvvvv
@@ -926,6 +2666,117 @@ fn poke_deposit_works() {
 	});
 }
 
+#[test]
+fn import_identity_works() {
+	new_test_ext().execute_with(|| {
+		let [one, _, _, _, ten, twenty, thirty, _] = accounts();
+		let ten_info = infoof_ten();
+		let registration = Registration {
+			judgements: vec![(0, Judgement::FeePaid(10)), (1, Judgement::Reasonable)]
+				.try_into()
+				.unwrap(),
+			deposit: 50,
+			frozen: false,
+			info: ten_info.clone(),
+		};
+		let subs = vec![(twenty.clone(), Data::Raw(vec![40; 1].try_into().unwrap()))];
+
+		// Subs must be unclaimed.
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(thirty.clone()),
+			Box::new(ten_info.clone())
+		));
+		assert_noop!(
+			Identity::import_identity(
+				&ten,
+				registration.clone(),
+				vec![(thirty.clone(), Data::None)]
+			),
+			Error::<Test>::AlreadyClaimed
+		);
+		assert_noop!(
+			Identity::import_identity(
+				&ten,
+				registration.clone(),
+				vec![(one.clone(), Data::None), (twenty.clone(), Data::None), (thirty, Data::None)]
+			),
+			Error::<Test>::TooManySubAccounts
+		);
+
+		assert_ok!(Identity::import_identity(&ten, registration.clone(), subs));
+		// Nothing is held, and judgements with a fee paid are dropped.
+		assert_eq!(Balances::free_balance(ten.clone()), 1000);
+		assert_eq!(
+			IdentityOf::<Test>::get(&ten),
+			Some(Registration {
+				judgements: vec![(1, Judgement::Reasonable)].try_into().unwrap(),
+				deposit: 0,
+				frozen: false,
+				info: ten_info.clone(),
+			})
+		);
+		assert_eq!(SubsOf::<Test>::get(&ten), (0, vec![twenty.clone()].try_into().unwrap()));
+		assert_noop!(
+			Identity::import_identity(&ten, registration, vec![]),
+			Error::<Test>::AlreadyClaimed
+		);
+
+		// The deposits are taken on poke.
+		assert_ok!(Identity::do_poke_deposit(&ten));
+		let subs_deposit: u64 = <<Test as Config>::SubAccountDeposit as Get<u64>>::get();
+		assert_eq!(
+			Balances::free_balance(ten.clone()),
+			1000 - id_deposit(&ten_info) - subs_deposit
+		);
+		assert_eq!(SuperOf::<Test>::get(&twenty).map(|(main, _)| main), Some(ten));
+	});
+}
+
+#[test]
+fn poke_deposit_call_rewards_caller() {
+	new_test_ext().execute_with(|| {
+		let [one, _, _, _, ten, _, _, _] = accounts();
+		let ten_info = infoof_ten();
+		let id_deposit = id_deposit(&ten_info);
+		assert_noop!(
+			Identity::poke_deposit(RuntimeOrigin::signed(one.clone()), ten.clone()),
+			Error::<Test>::NoIdentity
+		);
+		assert_ok!(Identity::set_identity(RuntimeOrigin::signed(ten.clone()), Box::new(ten_info)));
+
+		// Nothing to update, the caller pays.
+		let info = Identity::poke_deposit(RuntimeOrigin::signed(one.clone()), ten.clone()).unwrap();
+		assert_eq!(info.pays_fee, Pays::Yes);
+
+		// Simulate a decrease of the deposit configuration.
+		assert_ok!(Balances::reserve(&ten, 100));
+		IdentityOf::<Test>::mutate(&ten, |id| id.as_mut().unwrap().deposit += 100);
+		let one_free = Balances::free_balance(one.clone());
+
+		let info = Identity::poke_deposit(RuntimeOrigin::signed(one.clone()), ten.clone()).unwrap();
+		assert_eq!(info.pays_fee, Pays::No);
+		System::assert_last_event(
+			Event::DepositPoked {
+				who: ten.clone(),
+				caller: one.clone(),
+				old_deposit: id_deposit + 100,
+				new_deposit: id_deposit,
+				reward: 10,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(ten.clone()), id_deposit);
+		assert_eq!(Balances::free_balance(ten.clone()), 1000 - id_deposit - 10);
+		assert_eq!(Balances::free_balance(one), one_free + 10);
+
+		// Poking one's own deposit is not rewarded.
+		assert_ok!(Balances::reserve(&ten, 100));
+		IdentityOf::<Test>::mutate(&ten, |id| id.as_mut().unwrap().deposit += 100);
+		assert_ok!(Identity::poke_deposit(RuntimeOrigin::signed(ten.clone()), ten.clone()));
+		assert_eq!(Balances::free_balance(ten), 1000 - id_deposit - 10);
+	});
+}
+
 #[test]
 fn poke_deposit_does_not_insert_new_subs_storage() {
 	new_test_ext().execute_with(|| {
@@ -940,6 +2791,7 @@ fn poke_deposit_does_not_insert_new_subs_storage() {
 			Registration {
 				judgements: Default::default(),
 				deposit: Zero::zero(),
+				frozen: false,
 				info: ten_info.clone(),
 			},
 		);
@@ -949,7 +2801,7 @@ fn poke_deposit_does_not_insert_new_subs_storage() {
 		assert_eq!(Balances::free_balance(ten.clone()), 1000);
 
 		// poke
-		assert_ok!(Identity::poke_deposit(&ten));
+		assert_ok!(Identity::do_poke_deposit(&ten));
 
 		// free balance reduced correctly
 		let id_deposit = id_deposit(&ten_info);
@@ -960,7 +2812,8 @@ fn poke_deposit_does_not_insert_new_subs_storage() {
 			Some(Registration {
 				judgements: Default::default(),
 				deposit: id_deposit,
-				info: infoof_ten()
+				frozen: false,
+				info: infoof_ten(),
 			})
 		);
 		// No new subs storage item.
@@ -2246,3 +4099,336 @@ fn unbind_dangling_username_defensive_should_panic() {
 		);
 	});
 }
+
+/// A runtime holding deposits in an asset through [`HeldAsset`].
+mod held_asset {
+	use super::*;
+	use frame_support::{
+		storage_alias,
+		traits::{
+			fungibles,
+			tokens::{
+				DepositConsequence, Fortitude, Preservation, Provenance, WithdrawConsequence,
+			},
+			OnRuntimeUpgrade,
+		},
+		Blake2_128Concat, Twox64Concat,
+	};
+	use migration::deposit_asset::SwitchDepositAsset;
+	use sp_runtime::TokenError;
+
+	type Block = frame_system::mocking::MockBlock<AssetTest>;
+
+	frame_support::construct_runtime!(
+		pub enum AssetTest
+		{
+			System: frame_system,
+			Balances: pallet_balances,
+			Identity: pallet_identity,
+		}
+	);
+
+	#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+	impl frame_system::Config for AssetTest {
+		type AccountId = AccountId;
+		type Lookup = IdentityLookup<Self::AccountId>;
+		type Block = Block;
+		type AccountData = pallet_balances::AccountData<u64>;
+	}
+
+	#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+	impl pallet_balances::Config for AssetTest {
+		type AccountStore = System;
+	}
+
+	/// The asset deposits are held in.
+	const ASSET: u32 = 7;
+
+	/// The free and held balances of an account, by asset.
+	#[storage_alias]
+	type AssetAccounts =
+		StorageMap<MockAssets, Blake2_128Concat, (u32, AccountId), (u64, u64), ValueQuery>;
+
+	#[storage_alias]
+	type AssetIssuance = StorageMap<MockAssets, Twox64Concat, u32, u64, ValueQuery>;
+
+	/// Assets with a minimum balance of 1 and a single hold reason.
+	pub struct MockAssets;
+
+	impl fungibles::Inspect<AccountId> for MockAssets {
+		type AssetId = u32;
+		type Balance = u64;
+
+		fn total_issuance(asset: u32) -> u64 {
+			AssetIssuance::get(asset)
+		}
+		fn minimum_balance(_: u32) -> u64 {
+			1
+		}
+		fn total_balance(asset: u32, who: &AccountId) -> u64 {
+			let (free, held) = AssetAccounts::get((asset, who));
+			free + held
+		}
+		fn balance(asset: u32, who: &AccountId) -> u64 {
+			AssetAccounts::get((asset, who)).0
+		}
+		fn reducible_balance(
+			asset: u32,
+			who: &AccountId,
+			preservation: Preservation,
+			_: Fortitude,
+		) -> u64 {
+			let free = Self::balance(asset, who);
+			match preservation {
+				Preservation::Expendable => free,
+				_ => free.saturating_sub(1),
+			}
+		}
+		fn can_deposit(_: u32, _: &AccountId, _: u64, _: Provenance) -> DepositConsequence {
+			DepositConsequence::Success
+		}
+		fn can_withdraw(asset: u32, who: &AccountId, amount: u64) -> WithdrawConsequence<u64> {
+			match Self::balance(asset, who).checked_sub(amount) {
+				None => WithdrawConsequence::BalanceLow,
+				Some(0) if amount > 0 => WithdrawConsequence::ReducedToZero(0),
+				Some(_) => WithdrawConsequence::Success,
+			}
+		}
+		fn asset_exists(asset: u32) -> bool {
+			asset == ASSET
+		}
+	}
+
+	impl fungibles::Unbalanced<AccountId> for MockAssets {
+		fn handle_dust(_: fungibles::Dust<AccountId, Self>) {}
+		fn write_balance(
+			asset: u32,
+			who: &AccountId,
+			amount: u64,
+		) -> Result<Option<u64>, DispatchError> {
+			AssetAccounts::mutate((asset, who), |(free, _)| *free = amount);
+			Ok(None)
+		}
+		fn set_total_issuance(asset: u32, amount: u64) {
+			AssetIssuance::insert(asset, amount);
+		}
+	}
+
+	impl fungibles::InspectHold<AccountId> for MockAssets {
+		type Reason = ();
+
+		fn total_balance_on_hold(asset: u32, who: &AccountId) -> u64 {
+			AssetAccounts::get((asset, who)).1
+		}
+		fn balance_on_hold(asset: u32, _: &(), who: &AccountId) -> u64 {
+			Self::total_balance_on_hold(asset, who)
+		}
+	}
+
+	impl fungibles::UnbalancedHold<AccountId> for MockAssets {
+		fn set_balance_on_hold(asset: u32, _: &(), who: &AccountId, amount: u64) -> DispatchResult {
+			AssetAccounts::mutate((asset, who), |(_, held)| *held = amount);
+			Ok(())
+		}
+	}
+
+	impl fungibles::Mutate<AccountId> for MockAssets {}
+	impl fungibles::MutateHold<AccountId> for MockAssets {}
+
+	impl pallet_identity::Config for AssetTest {
+		type RuntimeEvent = RuntimeEvent;
+		type Currency = Balances;
+		type DepositTracker = ();
+		type DepositAsset = HeldAsset<MockAssets, ConstU32<ASSET>, ()>;
+		type Slashed = ();
+		type SlashPortion = SlashPortion;
+		type BasicDeposit = ConstU64<100>;
+		type ByteDeposit = ConstU64<10>;
+		type UsernameDeposit = ConstU64<10>;
+		type SubAccountDeposit = ConstU64<100>;
+		type PokeDepositReward = PokeDepositReward;
+		type MaxSubAccounts = ConstU32<2>;
+		type MaxSubDepth = ConstU32<3>;
+		type IdentityInformation = IdentityInfo<MaxAdditionalFields>;
+		type MaxRegistrars = MaxRegistrars;
+		type MaxFeeTiers = ConstU32<8>;
+		type RegistrarOrigin = EnsureRoot<Self::AccountId>;
+		type ForceOrigin = EnsureRoot<Self::AccountId>;
+		type OffchainSignature = MultiSignature;
+		type SigningPublicKey = AccountPublic;
+		type UsernameAuthorityOrigin = EnsureRoot<Self::AccountId>;
+		type PendingUsernameExpiration = ConstU64<100>;
+		type UsernameGracePeriod = ConstU64<2>;
+		type MaxSuffixLength = ConstU32<7>;
+		type MaxUsernameLength = ConstU32<32>;
+		type OnIdentityChange = ();
+		type AttesterId = offchain::crypto::Public;
+		type SubmitAttestation = offchain::UnsignedAttestations<Self>;
+		type WeightInfo = ();
+	}
+
+	type Extrinsic = frame_system::mocking::MockUncheckedExtrinsic<AssetTest>;
+
+	impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for AssetTest
+	where
+		RuntimeCall: From<LocalCall>,
+	{
+		type RuntimeCall = RuntimeCall;
+		type Extrinsic = Extrinsic;
+	}
+
+	impl<LocalCall> frame_system::offchain::CreateInherent<LocalCall> for AssetTest
+	where
+		RuntimeCall: From<LocalCall>,
+	{
+		fn create_inherent(call: Self::RuntimeCall) -> Self::Extrinsic {
+			Extrinsic::new_bare(call)
+		}
+	}
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<AssetTest>::default().build_storage().unwrap();
+		pallet_balances::GenesisConfig::<AssetTest> {
+			balances: vec![(account(10), 1000), (account(20), 1000)],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+		pallet_identity::GenesisConfig::<AssetTest>::default()
+			.assimilate_storage(&mut t)
+			.unwrap();
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			for who in [account(10), account(20)] {
+				AssetAccounts::insert((ASSET, who), (1000, 0));
+			}
+			AssetIssuance::insert(ASSET, 2000);
+		});
+		ext
+	}
+
+	fn asset_of(who: &AccountId) -> (u64, u64) {
+		AssetAccounts::get((ASSET, who))
+	}
+
+	#[test]
+	fn deposits_are_held_and_released_in_the_asset() {
+		new_test_ext().execute_with(|| {
+			let (ten, twenty) = (account(10), account(20));
+			let ten_info = infoof_ten();
+			let deposit = id_deposit(&ten_info);
+			assert!(DepositsInAsset::<AssetTest>::get());
+
+			assert_ok!(Identity::set_identity(
+				RuntimeOrigin::signed(ten.clone()),
+				Box::new(ten_info)
+			));
+			assert_eq!(asset_of(&ten), (1000 - deposit, deposit));
+			assert_eq!(Balances::free_balance(&ten), 1000);
+			assert_eq!(Balances::reserved_balance(&ten), 0);
+
+			// more than the free asset can't be held
+			assert_noop!(Deposit::<AssetTest>::hold(&twenty, 1000), TokenError::FundsUnavailable);
+
+			assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(ten.clone())));
+			assert_eq!(asset_of(&ten), (1000, 0));
+			assert_ok!(Identity::do_try_state());
+		});
+	}
+
+	#[test]
+	fn slashed_deposits_are_burned_and_paid_to_the_reporter() {
+		new_test_ext().execute_with(|| {
+			let (ten, four) = (account(10), account(4));
+			let ten_info = infoof_ten();
+			let deposit = id_deposit(&ten_info);
+			let slashed = SlashPortion::get() * deposit;
+			let reward = deposit - slashed;
+			assert_ok!(Identity::set_identity(
+				RuntimeOrigin::signed(ten.clone()),
+				Box::new(ten_info)
+			));
+
+			assert_ok!(Identity::kill_identity_with_reporter(
+				RuntimeOrigin::root(),
+				ten.clone(),
+				four.clone()
+			));
+			System::assert_last_event(RuntimeEvent::Identity(Event::IdentityKilled {
+				who: ten.clone(),
+				deposit,
+				slashed,
+				reporter: Some(four.clone()),
+				reward,
+			}));
+			assert_eq!(asset_of(&ten), (1000 - deposit, 0));
+			assert_eq!(asset_of(&four), (reward, 0));
+			assert_eq!(AssetIssuance::get(ASSET), 2000 - slashed);
+			// the native currency is untouched
+			assert_eq!(Balances::free_balance(&ten), 1000);
+
+			// without a reporter, everything is burned
+			assert_ok!(Deposit::<AssetTest>::hold(&ten, 100));
+			assert_eq!(Deposit::<AssetTest>::slash(&ten, 100, None), (100, 0));
+			assert_eq!(AssetIssuance::get(ASSET), 2000 - slashed - 100);
+		});
+	}
+
+	#[test]
+	fn deposits_are_moved_in_the_asset() {
+		new_test_ext().execute_with(|| {
+			let (ten, twenty, thirty) = (account(10), account(20), account(30));
+			assert_ok!(Deposit::<AssetTest>::hold(&ten, 300));
+
+			// repatriated deposits are free on the receiving account
+			assert_eq!(Deposit::<AssetTest>::repatriate(&ten, &twenty, 100), Ok(0));
+			assert_eq!(asset_of(&ten), (700, 200));
+			assert_eq!(asset_of(&twenty), (1100, 0));
+
+			// transferred deposits stay held, no more than held is moved
+			assert_eq!(Deposit::<AssetTest>::transfer(&ten, &thirty, 300), Ok(100));
+			assert_eq!(asset_of(&ten), (700, 0));
+			assert_eq!(asset_of(&thirty), (0, 200));
+
+			// payments are made from the free asset and keep the payer alive
+			assert_ok!(Deposit::<AssetTest>::pay(&twenty, &ten, 100));
+			assert_eq!(asset_of(&twenty), (1000, 0));
+			assert_eq!(asset_of(&ten), (800, 0));
+			assert_noop!(Deposit::<AssetTest>::pay(&twenty, &ten, 1000), TokenError::NotExpendable);
+			assert_eq!(AssetIssuance::get(ASSET), 2000);
+		});
+	}
+
+	#[test]
+	fn deposit_asset_is_switched_once_no_deposits_are_held() {
+		new_test_ext().execute_with(|| {
+			let ten = account(10);
+			// A chain that held its deposits in `Currency` before configuring `DepositAsset`.
+			DepositsInAsset::<AssetTest>::kill();
+			assert_ok!(Identity::set_identity(
+				RuntimeOrigin::signed(ten.clone()),
+				Box::new(infoof_ten())
+			));
+			let deposit = id_deposit(&infoof_ten());
+			assert_eq!(Balances::reserved_balance(&ten), deposit);
+			assert_eq!(asset_of(&ten), (1000, 0));
+			assert!(Identity::do_try_state().is_err());
+
+			// the deposits stay in `Currency` while any is held
+			SwitchDepositAsset::<AssetTest>::on_runtime_upgrade();
+			assert!(!DepositsInAsset::<AssetTest>::get());
+
+			assert_ok!(Identity::clear_identity(RuntimeOrigin::signed(ten.clone())));
+			assert_eq!(Balances::reserved_balance(&ten), 0);
+			SwitchDepositAsset::<AssetTest>::on_runtime_upgrade();
+			assert!(DepositsInAsset::<AssetTest>::get());
+			assert_ok!(Identity::do_try_state());
+
+			assert_ok!(Identity::set_identity(
+				RuntimeOrigin::signed(ten.clone()),
+				Box::new(infoof_ten())
+			));
+			assert_eq!(asset_of(&ten), (1000 - deposit, deposit));
+		});
+	}
+}
@@ -18,10 +18,14 @@
 use super::*;
 use alloc::{vec, vec::Vec};
 use codec::{Decode, Encode, MaxEncodedLen};
-use core::{fmt::Debug, iter::once, ops::Add};
+use core::{fmt::Debug, iter::once, marker::PhantomData, ops::Add};
 use frame_support::{
-	traits::{ConstU32, Get},
-	BoundedVec, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+	traits::{
+		fungibles,
+		tokens::{Fortitude, Precision, Preservation, Restriction},
+		ConstU32, Get,
+	},
+	BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
 };
 use scale_info::{
 	build::{Fields, Variants},
@@ -35,16 +39,22 @@ use sp_runtime::{
 /// An identifier for a single name registrar/identity verification service.
 pub type RegistrarIndex = u32;
 
-/// Either underlying data blob if it is at most 32 bytes, or a hash of it. If the data is greater
-/// than 32-bytes then it will be truncated when encoding.
+/// The byte length up to which raw [`BoundedData`] is encoded with the length in its leading byte.
+/// Longer raw data is encoded with a leading byte of `39` and a compact length prefix.
+pub const INLINE_RAW_DATA_LENGTH: u32 = 32;
+
+/// Either underlying data blob if it is at most `MaxRawLength` bytes, or a hash of it.
 ///
 /// Can also be `None`.
-#[derive(Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
-pub enum Data {
+///
+/// Raw data of at most [`INLINE_RAW_DATA_LENGTH`] bytes is encoded exactly as [`Data`] always has
+/// been, so widening `MaxRawLength` of a stored type requires no storage migration.
+#[derive(CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound)]
+pub enum BoundedData<MaxRawLength: Get<u32>> {
 	/// No data here.
 	None,
 	/// The data is stored directly.
-	Raw(BoundedVec<u8, ConstU32<32>>),
+	Raw(BoundedVec<u8, MaxRawLength>),
 	/// Only the Blake2 hash of the data is stored. The preimage of the hash may be retrieved
 	/// through some hash-lookup service.
 	BlakeTwo256([u8; 32]),
@@ -57,53 +67,78 @@ pub enum Data {
 	/// Only the SHA3-256 hash of the data is stored. The preimage of the hash may be retrieved
 	/// through some hash-lookup service.
 	ShaThree256([u8; 32]),
+	/// Only a 256-bit hash of the data is stored, of a scheme not covered by the other variants,
+	/// such as the digest of a content identifier. The scheme is left to off-chain conventions.
+	Hash256([u8; 32]),
 }
 
-impl Data {
+/// Either underlying data blob if it is at most 32 bytes, or a hash of it.
+///
+/// Can also be `None`.
+pub type Data = BoundedData<ConstU32<INLINE_RAW_DATA_LENGTH>>;
+
+impl<MaxRawLength: Get<u32>> BoundedData<MaxRawLength> {
 	pub fn is_none(&self) -> bool {
-		self == &Data::None
+		matches!(self, BoundedData::None)
 	}
 }
 
-impl Decode for Data {
+impl<MaxRawLength: Get<u32>> Decode for BoundedData<MaxRawLength> {
 	fn decode<I: codec::Input>(input: &mut I) -> core::result::Result<Self, codec::Error> {
 		let b = input.read_byte()?;
 		Ok(match b {
-			0 => Data::None,
+			0 => BoundedData::None,
 			n @ 1..=33 => {
 				let mut r: BoundedVec<_, _> = vec![0u8; n as usize - 1]
 					.try_into()
-					.expect("bound checked in match arm condition; qed");
+					.map_err(|_| codec::Error::from("raw data longer than bound"))?;
 				input.read(&mut r[..])?;
-				Data::Raw(r)
+				BoundedData::Raw(r)
+			},
+			34 => BoundedData::BlakeTwo256(<[u8; 32]>::decode(input)?),
+			35 => BoundedData::Sha256(<[u8; 32]>::decode(input)?),
+			36 => BoundedData::Keccak256(<[u8; 32]>::decode(input)?),
+			37 => BoundedData::ShaThree256(<[u8; 32]>::decode(input)?),
+			38 => BoundedData::Hash256(<[u8; 32]>::decode(input)?),
+			39 => {
+				let r = BoundedVec::<u8, MaxRawLength>::decode(input)?;
+				if r.len() <= INLINE_RAW_DATA_LENGTH as usize {
+					return Err(codec::Error::from("non-canonical raw data length"))
+				}
+				BoundedData::Raw(r)
 			},
-			34 => Data::BlakeTwo256(<[u8; 32]>::decode(input)?),
-			35 => Data::Sha256(<[u8; 32]>::decode(input)?),
-			36 => Data::Keccak256(<[u8; 32]>::decode(input)?),
-			37 => Data::ShaThree256(<[u8; 32]>::decode(input)?),
 			_ => return Err(codec::Error::from("invalid leading byte")),
 		})
 	}
 }
 
-impl Encode for Data {
+impl<MaxRawLength: Get<u32>> Encode for BoundedData<MaxRawLength> {
 	fn encode(&self) -> Vec<u8> {
 		match self {
-			Data::None => vec![0u8; 1],
-			Data::Raw(ref x) => {
-				let l = x.len().min(32);
+			BoundedData::None => vec![0u8; 1],
+			BoundedData::Raw(ref x) if x.len() <= INLINE_RAW_DATA_LENGTH as usize => {
+				let l = x.len();
 				let mut r = vec![l as u8 + 1; l + 1];
-				r[1..].copy_from_slice(&x[..l as usize]);
+				r[1..].copy_from_slice(&x[..]);
 				r
 			},
-			Data::BlakeTwo256(ref h) => once(34u8).chain(h.iter().cloned()).collect(),
-			Data::Sha256(ref h) => once(35u8).chain(h.iter().cloned()).collect(),
-			Data::Keccak256(ref h) => once(36u8).chain(h.iter().cloned()).collect(),
-			Data::ShaThree256(ref h) => once(37u8).chain(h.iter().cloned()).collect(),
+			BoundedData::Raw(ref x) => once(39u8).chain(x.encode()).collect(),
+			BoundedData::BlakeTwo256(ref h) => once(34u8).chain(h.iter().cloned()).collect(),
+			BoundedData::Sha256(ref h) => once(35u8).chain(h.iter().cloned()).collect(),
+			BoundedData::Keccak256(ref h) => once(36u8).chain(h.iter().cloned()).collect(),
+			BoundedData::ShaThree256(ref h) => once(37u8).chain(h.iter().cloned()).collect(),
+			BoundedData::Hash256(ref h) => once(38u8).chain(h.iter().cloned()).collect(),
 		}
 	}
 }
-impl codec::EncodeLike for Data {}
+impl<MaxRawLength: Get<u32>> codec::EncodeLike for BoundedData<MaxRawLength> {}
+
+impl<MaxRawLength: Get<u32>> MaxEncodedLen for BoundedData<MaxRawLength> {
+	fn max_encoded_len() -> usize {
+		// The leading byte, followed by either a hash or the raw data with its length.
+		BoundedVec::<u8, MaxRawLength>::max_encoded_len().max(32).saturating_add(1)
+	}
+}
 
 /// Add a Raw variant with the given index and a fixed sized byte array
 macro_rules! data_raw_variants {
@@ -118,7 +153,7 @@ macro_rules! data_raw_variants {
     }
 }
 
-impl TypeInfo for Data {
+impl<MaxRawLength: Get<u32> + 'static> TypeInfo for BoundedData<MaxRawLength> {
 	type Identity = Self;
 
 	fn type_info() -> Type {
@@ -174,13 +209,25 @@ impl TypeInfo for Data {
 			})
 			.variant("ShaThree256", |v| {
 				v.index(37).fields(Fields::unnamed().field(|f| f.ty::<[u8; 32]>()))
+			})
+			.variant("Hash256", |v| {
+				v.index(38).fields(Fields::unnamed().field(|f| f.ty::<[u8; 32]>()))
 			});
 
+		// raw data longer than the inline variants, only if the bound allows any
+		let variants = if MaxRawLength::get() > INLINE_RAW_DATA_LENGTH {
+			variants.variant("RawLong", |v| {
+				v.index(39).fields(Fields::unnamed().field(|f| f.ty::<Vec<u8>>()))
+			})
+		} else {
+			variants
+		};
+
 		Type::builder().path(Path::new("Data", module_path!())).variant(variants)
 	}
 }
 
-impl Default for Data {
+impl<MaxRawLength: Get<u32>> Default for BoundedData<MaxRawLength> {
 	fn default() -> Self {
 		Self::None
 	}
@@ -217,6 +264,16 @@ pub enum Judgement<Balance: Encode + Decode + MaxEncodedLen + Copy + Clone + Deb
 impl<Balance: Encode + Decode + MaxEncodedLen + Copy + Clone + Debug + Eq + PartialEq>
 	Judgement<Balance>
 {
+	/// Returns `true` if this judgement is a positive judgement at least as good as `level`, where
+	/// `KnownGood` is better than `Reasonable`. Other judgements are never at least any level.
+	pub fn is_at_least(&self, level: &Self) -> bool {
+		match (self, level) {
+			(Judgement::KnownGood, Judgement::KnownGood | Judgement::Reasonable) => true,
+			(Judgement::Reasonable, Judgement::Reasonable) => true,
+			_ => false,
+		}
+	}
+
 	/// Returns `true` if this judgement is indicative of a deposit being currently held. This means
 	/// it should not be cleared or replaced except by an operation which utilizes the deposit.
 	pub(crate) fn has_deposit(&self) -> bool {
@@ -231,6 +288,165 @@ impl<Balance: Encode + Decode + MaxEncodedLen + Copy + Clone + Debug + Eq + Part
 	}
 }
 
+/// Handler for changes to identities, e.g. to invalidate verification cached by other pallets.
+///
+/// The implementation for `()` does nothing.
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+pub trait OnIdentityChange<
+	AccountId,
+	Balance: Encode + Decode + MaxEncodedLen + Copy + Clone + Debug + Eq + PartialEq,
+>
+{
+	/// The identity information of `who` was set or replaced.
+	fn on_identity_set(who: &AccountId);
+
+	/// The identity of `who` was cleared, killed or reaped.
+	fn on_identity_removed(who: &AccountId);
+
+	/// The registrar of index `registrar_index` gave `judgement` on the identity of `who`.
+	fn on_judgement_given(
+		who: &AccountId,
+		registrar_index: RegistrarIndex,
+		judgement: &Judgement<Balance>,
+	);
+}
+
+/// An asset in which the deposits and judgement fees of the pallet are held instead of
+/// [`Config::Currency`], e.g. a stablecoin on a chain whose native token is not the deposit asset.
+///
+/// The implementation for `()` holds nothing, so that everything is held in `Currency`.
+/// [`HeldAsset`] holds an asset of a `fungibles::MutateHold` implementation.
+pub trait DepositAsset<AccountId, Balance> {
+	/// Whether deposits are held in this asset rather than in `Currency`.
+	const ENABLED: bool;
+
+	/// Hold `amount` of the asset of `who`.
+	fn hold(who: &AccountId, amount: Balance) -> DispatchResult;
+
+	/// Release up to `amount` of the asset held by `who`. Returns the amount released.
+	fn release(who: &AccountId, amount: Balance) -> Balance;
+
+	/// Burn up to `amount` of the asset held by `who`. Returns the amount burned.
+	fn burn_held(who: &AccountId, amount: Balance) -> Balance;
+
+	/// Move up to `amount` of the asset held by `who` to `dest`, where it stays held if `on_hold`
+	/// or is free otherwise. Returns the amount moved.
+	fn transfer_held(
+		who: &AccountId,
+		dest: &AccountId,
+		amount: Balance,
+		on_hold: bool,
+	) -> Result<Balance, DispatchError>;
+
+	/// Transfer `amount` of the free asset of `who` to `dest`, keeping `who` alive.
+	fn transfer(who: &AccountId, dest: &AccountId, amount: Balance) -> DispatchResult;
+
+	/// The amount of the asset held by `who`.
+	fn balance_on_hold(who: &AccountId) -> Balance;
+}
+
+impl<AccountId, Balance: Zero> DepositAsset<AccountId, Balance> for () {
+	const ENABLED: bool = false;
+
+	fn hold(_: &AccountId, _: Balance) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn release(_: &AccountId, _: Balance) -> Balance {
+		Zero::zero()
+	}
+
+	fn burn_held(_: &AccountId, _: Balance) -> Balance {
+		Zero::zero()
+	}
+
+	fn transfer_held(
+		_: &AccountId,
+		_: &AccountId,
+		_: Balance,
+		_: bool,
+	) -> Result<Balance, DispatchError> {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn transfer(_: &AccountId, _: &AccountId, _: Balance) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn balance_on_hold(_: &AccountId) -> Balance {
+		Zero::zero()
+	}
+}
+
+/// Holds the asset `Asset` of `Assets` for the hold reason `Reason`.
+pub struct HeldAsset<Assets, Asset, Reason>(PhantomData<(Assets, Asset, Reason)>);
+
+impl<AccountId, Assets, Asset, Reason> DepositAsset<AccountId, Assets::Balance>
+	for HeldAsset<Assets, Asset, Reason>
+where
+	AccountId: Eq,
+	Assets: fungibles::MutateHold<AccountId> + fungibles::Mutate<AccountId>,
+	Asset: Get<Assets::AssetId>,
+	Reason: Get<Assets::Reason>,
+{
+	const ENABLED: bool = true;
+
+	fn hold(who: &AccountId, amount: Assets::Balance) -> DispatchResult {
+		Assets::hold(Asset::get(), &Reason::get(), who, amount)
+	}
+
+	fn release(who: &AccountId, amount: Assets::Balance) -> Assets::Balance {
+		Assets::release(Asset::get(), &Reason::get(), who, amount, Precision::BestEffort)
+			.unwrap_or_else(|_| Zero::zero())
+	}
+
+	fn burn_held(who: &AccountId, amount: Assets::Balance) -> Assets::Balance {
+		Assets::burn_held(
+			Asset::get(),
+			&Reason::get(),
+			who,
+			amount,
+			Precision::BestEffort,
+			Fortitude::Force,
+		)
+		.unwrap_or_else(|_| Zero::zero())
+	}
+
+	fn transfer_held(
+		who: &AccountId,
+		dest: &AccountId,
+		amount: Assets::Balance,
+		on_hold: bool,
+	) -> Result<Assets::Balance, DispatchError> {
+		let mode = if on_hold { Restriction::OnHold } else { Restriction::Free };
+		Assets::transfer_on_hold(
+			Asset::get(),
+			&Reason::get(),
+			who,
+			dest,
+			amount,
+			Precision::BestEffort,
+			mode,
+			Fortitude::Force,
+		)
+	}
+
+	fn transfer(who: &AccountId, dest: &AccountId, amount: Assets::Balance) -> DispatchResult {
+		<Assets as fungibles::Mutate<AccountId>>::transfer(
+			Asset::get(),
+			who,
+			dest,
+			amount,
+			Preservation::Preserve,
+		)
+		.map(|_| ())
+	}
+
+	fn balance_on_hold(who: &AccountId) -> Assets::Balance {
+		Assets::balance_on_hold(Asset::get(), &Reason::get(), who)
+	}
+}
+
 /// Information concerning the identity of the controller of an account.
 pub trait IdentityInformationProvider:
 	Encode + Decode + MaxEncodedLen + Clone + Debug + Eq + PartialEq + TypeInfo + Default
@@ -241,6 +457,13 @@ pub trait IdentityInformationProvider:
 	/// Check if an identity registered information for some given `fields`.
 	fn has_identity(&self, fields: Self::FieldsIdentifier) -> bool;
 
+	/// The fields for which the identity registered information.
+	///
+	/// Defaults to no fields; implementations that track their fields should override it.
+	fn registered_fields(&self) -> Self::FieldsIdentifier {
+		Self::FieldsIdentifier::default()
+	}
+
 	/// Create a basic instance of the identity information.
 	#[cfg(feature = "runtime-benchmarks")]
 	fn create_identity_info() -> Self;
@@ -271,6 +494,10 @@ pub struct Registration<
 	/// Amount held on deposit for this information.
 	pub deposit: Balance,
 
+	/// Whether the identity is frozen by governance, in which case its owner can neither change
+	/// nor clear it.
+	pub frozen: bool,
+
 	/// Information on the identity.
 	pub info: IdentityInfo,
 }
@@ -297,17 +524,30 @@ impl<
 	> Decode for Registration<Balance, MaxJudgements, IdentityInfo>
 {
 	fn decode<I: codec::Input>(input: &mut I) -> core::result::Result<Self, codec::Error> {
-		let (judgements, deposit, info) = Decode::decode(&mut AppendZerosInput::new(input))?;
-		Ok(Self { judgements, deposit, info })
+		let (judgements, deposit, frozen, info) =
+			Decode::decode(&mut AppendZerosInput::new(input))?;
+		Ok(Self { judgements, deposit, frozen, info })
 	}
 }
 
 /// Information concerning a registrar.
-#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	EqNoBound,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[codec(mel_bound(Balance: MaxEncodedLen, AccountId: MaxEncodedLen))]
+#[scale_info(skip_type_params(MaxFeeTiers))]
 pub struct RegistrarInfo<
 	Balance: Encode + Decode + Clone + Debug + Eq + PartialEq,
 	AccountId: Encode + Decode + Clone + Debug + Eq + PartialEq,
 	IdField: Encode + Decode + Clone + Debug + Default + Eq + PartialEq + TypeInfo + MaxEncodedLen,
+	MaxFeeTiers: Get<u32>,
 > {
 	/// The account of the registrar.
 	pub account: AccountId,
@@ -318,8 +558,55 @@ pub struct RegistrarInfo<
 	/// Relevant fields for this registrar. Registrar judgements are limited to attestations on
 	/// these fields.
 	pub fields: IdField,
+
+	/// Fees for judgements limited to other sets of fields, e.g. a cheaper fee for verifying the
+	/// display name only. Judgements on `fields` always cost `fee`.
+	pub fee_schedule: FeeSchedule<IdField, Balance, MaxFeeTiers>,
+
+	/// The maximum number of judgement requests with a fee paid that may be open on this
+	/// registrar at once, or `None` for no limit.
+	pub max_pending: Option<u32>,
+}
+
+impl<
+		Balance: Encode + Decode + Clone + Debug + Eq + PartialEq,
+		AccountId: Encode + Decode + Clone + Debug + Eq + PartialEq,
+		IdField: Encode + Decode + Clone + Debug + Default + Eq + PartialEq + TypeInfo + MaxEncodedLen,
+		MaxFeeTiers: Get<u32>,
+	> RegistrarInfo<Balance, AccountId, IdField, MaxFeeTiers>
+{
+	/// The fee for a judgement on `fields`, or on all of the registrar's fields if `None`. Returns
+	/// `None` if the registrar does not publish a fee for `fields`.
+	pub fn fee_for(&self, fields: Option<&IdField>) -> Option<Balance> {
+		match fields {
+			None => Some(self.fee.clone()),
+			Some(fields) if fields == &self.fields => Some(self.fee.clone()),
+			Some(fields) => self
+				.fee_schedule
+				.iter()
+				.find(|(tier_fields, _)| tier_fields == fields)
+				.map(|(_, fee)| fee.clone()),
+		}
+	}
 }
 
+/// A bounded reference to off-chain evidence, such as an IPFS CID or a document hash.
+pub type Evidence = BoundedVec<u8, ConstU32<64>>;
+
+/// Off-chain evidence attached by a registrar to its judgement of an identity.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct JudgementEvidence {
+	/// The reference to the evidence.
+	pub evidence: Evidence,
+
+	/// Whether the owner of the identity disputes the evidence.
+	pub disputed: bool,
+}
+
+/// Fees a registrar charges for judgements on specific sets of fields, at most `MaxFeeTiers` of
+/// them.
+pub type FeeSchedule<IdField, Balance, MaxFeeTiers> = BoundedVec<(IdField, Balance), MaxFeeTiers>;
+
 /// The number of usernames that an authority may allocate.
 type Allocation = u32;
 /// A byte vec used to represent a username.
@@ -337,6 +624,9 @@ pub struct AuthorityProperties<Account> {
 /// A byte vec used to represent a username.
 pub(crate) type Username<T> = BoundedVec<u8, <T as Config>::MaxUsernameLength>;
 
+/// A byte vec used to represent a display name. Bounded like the raw `Data` of an identity field.
+pub type DisplayName = BoundedVec<u8, ConstU32<32>>;
+
 #[derive(Clone, Encode, Decode, MaxEncodedLen, TypeInfo, PartialEq, Debug)]
 pub enum Provider<Balance> {
 	Allocation,
@@ -383,6 +673,7 @@ mod tests {
 				Data::Sha256(_) => "Sha256".to_string(),
 				Data::Keccak256(_) => "Keccak256".to_string(),
 				Data::ShaThree256(_) => "ShaThree256".to_string(),
+				Data::Hash256(_) => "Hash256".to_string(),
 				Data::Raw(bytes) => format!("Raw{}", bytes.len()),
 			};
 			if let scale_info::TypeDef::Variant(variant) = &type_info.type_def {
@@ -419,6 +710,7 @@ mod tests {
 			Data::Sha256(Default::default()),
 			Data::Keccak256(Default::default()),
 			Data::ShaThree256(Default::default()),
+			Data::Hash256(Default::default()),
 		];
 
 		// A Raw instance for all possible sizes of the Raw data
@@ -430,4 +722,30 @@ mod tests {
 			check_type_info(d);
 		}
 	}
+
+	#[test]
+	fn long_raw_data_codec_works() {
+		type LongData = BoundedData<ConstU32<64>>;
+
+		// Up to 32 bytes, raw data is encoded as it has always been.
+		let short = LongData::Raw(vec![1u8; 32].try_into().unwrap());
+		let legacy = Data::Raw(vec![1u8; 32].try_into().unwrap());
+		assert_eq!(short.encode(), legacy.encode());
+		assert_eq!(LongData::decode(&mut &legacy.encode()[..]), Ok(short));
+
+		// Longer raw data has its own leading byte and a length prefix.
+		let long = LongData::Raw(vec![1u8; 64].try_into().unwrap());
+		let encoded = long.encode();
+		assert_eq!(encoded[0], 39);
+		assert_eq!(encoded.len(), LongData::max_encoded_len());
+		assert_eq!(LongData::decode(&mut &encoded[..]), Ok(long));
+		assert!(Data::decode(&mut &encoded[..]).is_err());
+
+		// Short raw data behind the long leading byte is not canonical.
+		let non_canonical = once(39u8).chain(vec![1u8; 32].encode()).collect::<Vec<_>>();
+		assert!(LongData::decode(&mut &non_canonical[..]).is_err());
+
+		// The bound is unchanged for `Data`.
+		assert_eq!(Data::max_encoded_len(), 34);
+	}
 }
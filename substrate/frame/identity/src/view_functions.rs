@@ -0,0 +1,98 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! View functions of the identity pallet.
+
+use super::*;
+use codec::Decode;
+use frame_support::view_functions::{
+	DispatchViewFunction, ViewFunction, ViewFunctionDispatchError, ViewFunctionId,
+};
+
+/// The identity registration of an account, if any.
+#[derive(Encode, Decode)]
+pub struct IdentityOfAccount<T: Config>(pub T::AccountId);
+
+impl<T: Config> ViewFunction for IdentityOfAccount<T> {
+	type ReturnType = Option<RegistrationOf<T>>;
+
+	fn id() -> ViewFunctionId {
+		ViewFunctionId::new::<Pallet<T>>("identity_of")
+	}
+
+	fn invoke(self) -> Self::ReturnType {
+		IdentityOf::<T>::get(self.0)
+	}
+}
+
+/// The super-identity of an account together with the name of the account as sub-identity, if
+/// the account is a sub-identity.
+#[derive(Encode, Decode)]
+pub struct SuperOfAccount<T: Config>(pub T::AccountId);
+
+impl<T: Config> ViewFunction for SuperOfAccount<T> {
+	type ReturnType = Option<(T::AccountId, Data)>;
+
+	fn id() -> ViewFunctionId {
+		ViewFunctionId::new::<Pallet<T>>("super_of")
+	}
+
+	fn invoke(self) -> Self::ReturnType {
+		SuperOf::<T>::get(self.0)
+	}
+}
+
+/// The account that claimed a display name, if any.
+#[derive(Encode, Decode)]
+pub struct AccountOfDisplayName<T: Config>(pub DisplayName, core::marker::PhantomData<T>);
+
+impl<T: Config> AccountOfDisplayName<T> {
+	/// Resolve `name`.
+	pub fn new(name: DisplayName) -> Self {
+		Self(name, Default::default())
+	}
+}
+
+impl<T: Config> ViewFunction for AccountOfDisplayName<T> {
+	type ReturnType = Option<T::AccountId>;
+
+	fn id() -> ViewFunctionId {
+		ViewFunctionId::new::<Pallet<T>>("account_of_name")
+	}
+
+	fn invoke(self) -> Self::ReturnType {
+		Pallet::<T>::account_of_name(&self.0)
+	}
+}
+
+impl<T: Config> DispatchViewFunction for Pallet<T> {
+	fn dispatch_view_function<O: codec::Output>(
+		id: &ViewFunctionId,
+		input: &mut &[u8],
+		output: &mut O,
+	) -> Result<(), ViewFunctionDispatchError> {
+		if *id == IdentityOfAccount::<T>::id() {
+			IdentityOfAccount::<T>::execute(input, output)
+		} else if *id == SuperOfAccount::<T>::id() {
+			SuperOfAccount::<T>::execute(input, output)
+		} else if *id == AccountOfDisplayName::<T>::id() {
+			AccountOfDisplayName::<T>::execute(input, output)
+		} else {
+			Err(ViewFunctionDispatchError::NotFound(*id))
+		}
+	}
+}
@@ -0,0 +1,57 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Benchmarks for Legacy Indices Pallet
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::*;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn force_set_claim() {
+		let account_index = T::AccountIndex::from(SEED);
+		let who: T::AccountId = account("who", 0, SEED);
+		let who_lookup = T::Lookup::unlookup(who.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, account_index, who_lookup);
+
+		assert_eq!(Claims::<T>::get(account_index), Some(who));
+	}
+
+	#[benchmark]
+	fn force_remove_claim() {
+		let account_index = T::AccountIndex::from(SEED);
+		let who: T::AccountId = account("who", 0, SEED);
+		Claims::<T>::insert(account_index, who);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, account_index);
+
+		assert_eq!(Claims::<T>::get(account_index), None);
+	}
+
+	impl_benchmark_test_suite!(Pallet, mock::new_test_ext(), mock::Test);
+}
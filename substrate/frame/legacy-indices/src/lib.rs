@@ -0,0 +1,198 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Legacy Indices Pallet
+//!
+//! A registry of the accounts that legacy `pallet-indices` indices resolved to.
+//!
+//! Runtimes that want to remove `pallet-indices` may still hold calls embedding short
+//! `MultiAddress::Index` addresses, e.g. in the scheduler, multisigs or proxy announcements. This
+//! pallet keeps those addresses resolvable: the [`migration::MigrateFromIndices`] migration moves
+//! every index of the removed pallet into [`Claims`] and refunds its deposit, and the pallet is
+//! then used as the runtime's `frame_system::Config::Lookup` in place of `pallet-indices`.
+//!
+//! No new indices can be claimed. Root may record or remove a claim to correct the registry.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod benchmarking;
+pub mod migration;
+mod mock;
+mod tests;
+pub mod weights;
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+use frame_support::traits::{Currency, ReservableCurrency};
+use sp_runtime::{
+	traits::{AtLeast32Bit, LookupError, StaticLookup},
+	MultiAddress,
+};
+pub use weights::WeightInfo;
+
+type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The module's config trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Type used for an account's index; must match the one of the removed `pallet-indices`.
+		type AccountIndex: Parameter
+			+ Member
+			+ MaybeSerializeDeserialize
+			+ Codec
+			+ Default
+			+ AtLeast32Bit
+			+ Copy
+			+ MaxEncodedLen;
+
+		/// The currency in which the deposits of the removed `pallet-indices` were reserved.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Record the account a legacy index resolves to, replacing any previous claim.
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// - `index`: the legacy index.
+		/// - `who`: the account the index resolves to.
+		///
+		/// Emits `ClaimRecorded` if successful.
+		///
+		/// ## Complexity
+		/// - `O(1)`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::force_set_claim())]
+		pub fn force_set_claim(
+			origin: OriginFor<T>,
+			index: T::AccountIndex,
+			who: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+			let who = T::Lookup::lookup(who)?;
+
+			Claims::<T>::insert(index, &who);
+			Self::deposit_event(Event::ClaimRecorded { index, who });
+			Ok(())
+		}
+
+		/// Remove the claim of a legacy index, so that it no longer resolves.
+		///
+		/// The dispatch origin for this call must be _Root_.
+		///
+		/// - `index`: the legacy index. It must have been claimed.
+		///
+		/// Emits `ClaimRemoved` if successful.
+		///
+		/// ## Complexity
+		/// - `O(1)`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::force_remove_claim())]
+		pub fn force_remove_claim(origin: OriginFor<T>, index: T::AccountIndex) -> DispatchResult {
+			ensure_root(origin)?;
+
+			Claims::<T>::take(index).ok_or(Error::<T>::NotClaimed)?;
+			Self::deposit_event(Event::ClaimRemoved { index });
+			Ok(())
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A legacy index was recorded as resolving to an account.
+		ClaimRecorded { index: T::AccountIndex, who: T::AccountId },
+		/// A legacy index no longer resolves.
+		ClaimRemoved { index: T::AccountIndex },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The index has not been claimed.
+		NotClaimed,
+	}
+
+	/// The lookup from legacy index to account.
+	#[pallet::storage]
+	pub type Claims<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountIndex, T::AccountId>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		pub claims: Vec<(T::AccountIndex, T::AccountId)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (index, who) in &self.claims {
+				Claims::<T>::insert(index, who);
+			}
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Lookup a legacy index to get an Id, if it has been claimed.
+	pub fn lookup_index(index: T::AccountIndex) -> Option<T::AccountId> {
+		Claims::<T>::get(index)
+	}
+
+	/// Lookup an address to get an Id, if there's one there.
+	pub fn lookup_address(a: MultiAddress<T::AccountId, T::AccountIndex>) -> Option<T::AccountId> {
+		match a {
+			MultiAddress::Id(i) => Some(i),
+			MultiAddress::Index(i) => Self::lookup_index(i),
+			_ => None,
+		}
+	}
+}
+
+impl<T: Config> StaticLookup for Pallet<T> {
+	type Source = MultiAddress<T::AccountId, T::AccountIndex>;
+	type Target = T::AccountId;
+
+	fn lookup(a: Self::Source) -> Result<Self::Target, LookupError> {
+		Self::lookup_address(a).ok_or(LookupError)
+	}
+
+	fn unlookup(a: Self::Target) -> Self::Source {
+		MultiAddress::Id(a)
+	}
+}
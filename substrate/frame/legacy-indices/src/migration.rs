@@ -0,0 +1,80 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A migration moving the indices of a removed `pallet-indices` into this pallet.
+
+use super::*;
+use frame_support::{
+	pallet_prelude::*, storage_alias, traits::OnRuntimeUpgrade, weights::Weight, Blake2_128Concat,
+};
+use sp_runtime::traits::{Saturating, Zero};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+const LOG_TARGET: &str = "runtime::legacy-indices::migration";
+
+/// The lookup from index to account of the removed `pallet-indices`, stored under the name the
+/// pallet had in [`construct_runtime!`](frame_support::construct_runtime).
+#[storage_alias(dynamic)]
+pub(crate) type Accounts<P: Get<&'static str>, T: Config> = StorageMap<
+	P,
+	Blake2_128Concat,
+	<T as Config>::AccountIndex,
+	(<T as frame_system::Config>::AccountId, BalanceOf<T>, bool),
+	OptionQuery,
+>;
+
+/// Moves every index of the removed `pallet-indices`, named `P` in the runtime, into [`Claims`].
+///
+/// The deposits still reserved for the indices are unreserved, and the storage of the removed
+/// pallet is cleared as it is read. Running the migration again is a no-op, but `P` must not be
+/// in the runtime anymore, as its indices could be claimed again otherwise.
+///
+/// The number of indices is expected to be small enough to be migrated in a single block.
+pub struct MigrateFromIndices<T, P>(core::marker::PhantomData<(T, P)>);
+
+impl<T: Config, P: Get<&'static str>> OnRuntimeUpgrade for MigrateFromIndices<T, P> {
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		Ok((Accounts::<P, T>::iter().count() as u32).encode())
+	}
+
+	fn on_runtime_upgrade() -> Weight {
+		let mut migrated = 0u64;
+		for (index, (who, deposit, _)) in Accounts::<P, T>::drain() {
+			if !deposit.is_zero() {
+				T::Currency::unreserve(&who, deposit);
+			}
+			Claims::<T>::insert(index, who);
+			migrated.saturating_inc();
+		}
+
+		log::info!(target: LOG_TARGET, "migrated {} legacy indices", migrated);
+		// one read and one write to the old storage, the account and the claim each.
+		T::DbWeight::get().reads_writes(migrated * 2 + 1, migrated * 3)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let migrated: u32 =
+			Decode::decode(&mut &state[..]).map_err(|_| "cannot decode the pre-upgrade state")?;
+		ensure!(Accounts::<P, T>::iter().next().is_none(), "legacy indices remain");
+		ensure!(Claims::<T>::iter().count() as u32 >= migrated, "legacy indices were lost");
+		Ok(())
+	}
+}
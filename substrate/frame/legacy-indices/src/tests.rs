@@ -0,0 +1,85 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the module.
+
+#![cfg(test)]
+
+use super::{migration::*, mock::*, *};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{OnRuntimeUpgrade, ReservableCurrency},
+};
+use sp_runtime::{traits::BadOrigin, MultiAddress::*};
+
+#[test]
+fn lookup_resolves_claims() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(LegacyIndices::lookup(Index(0)), Ok(1));
+		assert_eq!(LegacyIndices::lookup(Index(1)), Err(LookupError));
+		assert_eq!(LegacyIndices::lookup(Id(2)), Ok(2));
+		assert_eq!(LegacyIndices::lookup(Address20([0; 20])), Err(LookupError));
+		assert_eq!(LegacyIndices::unlookup(2), Id(2));
+	});
+}
+
+#[test]
+fn force_set_and_remove_claim_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(LegacyIndices::force_set_claim(Some(1).into(), 1, Id(2)), BadOrigin);
+		assert_ok!(LegacyIndices::force_set_claim(RuntimeOrigin::root(), 1, Id(2)));
+		System::assert_last_event(Event::ClaimRecorded { index: 1, who: 2 }.into());
+		assert_eq!(LegacyIndices::lookup_index(1), Some(2));
+
+		// an existing claim is replaced.
+		assert_ok!(LegacyIndices::force_set_claim(RuntimeOrigin::root(), 0, Index(1)));
+		assert_eq!(LegacyIndices::lookup_index(0), Some(2));
+
+		assert_noop!(LegacyIndices::force_remove_claim(Some(1).into(), 0), BadOrigin);
+		assert_ok!(LegacyIndices::force_remove_claim(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::ClaimRemoved { index: 0 }.into());
+		assert_eq!(LegacyIndices::lookup(Index(0)), Err(LookupError));
+		assert_noop!(
+			LegacyIndices::force_remove_claim(RuntimeOrigin::root(), 0),
+			Error::<Test>::NotClaimed
+		);
+	});
+}
+
+#[test]
+fn migration_from_indices_works() {
+	new_test_ext().execute_with(|| {
+		// indices claimed with a deposit, forced and frozen in the removed pallet.
+		assert_ok!(Balances::reserve(&3, 2));
+		Accounts::<IndicesPalletName, Test>::insert(1, (3, 2, false));
+		Accounts::<IndicesPalletName, Test>::insert(2, (4, 0, false));
+		Accounts::<IndicesPalletName, Test>::insert(3, (5, 0, true));
+
+		MigrateFromIndices::<Test, IndicesPalletName>::on_runtime_upgrade();
+
+		assert_eq!(LegacyIndices::lookup(Index(0)), Ok(1));
+		assert_eq!(LegacyIndices::lookup(Index(1)), Ok(3));
+		assert_eq!(LegacyIndices::lookup(Index(2)), Ok(4));
+		assert_eq!(LegacyIndices::lookup(Index(3)), Ok(5));
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Accounts::<IndicesPalletName, Test>::iter().count(), 0);
+
+		// running it again is a no-op.
+		MigrateFromIndices::<Test, IndicesPalletName>::on_runtime_upgrade();
+		assert_eq!(Claims::<Test>::iter().count(), 4);
+	});
+}
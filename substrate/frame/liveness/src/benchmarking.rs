@@ -0,0 +1,92 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Liveness pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use frame_benchmarking::v2::*;
+use frame_support::{traits::UnfilteredDispatchable, WeakBoundedVec};
+use frame_system::RawOrigin;
+use sp_runtime::{traits::ValidateUnsigned, transaction_validity::TransactionSource};
+
+use crate::*;
+
+const MAX_KEYS: u32 = 1000;
+
+/// Create `k` keys, at least `s`, and an attestation signed by the first `s` of them.
+pub fn create_attestation<T: Config>(
+	k: u32,
+	s: u32,
+) -> Result<(Attestation<BlockNumberFor<T>>, SignaturesOf<T>), &'static str> {
+	let mut keys = Vec::new();
+	for _ in 0..k.max(s) {
+		keys.push(T::AuthorityId::generate_pair(None));
+	}
+	let bounded_keys = WeakBoundedVec::<_, T::MaxKeys>::try_from(keys.clone())
+		.map_err(|()| "More than the maximum number of keys provided")?;
+	Keys::<T>::put(bounded_keys);
+
+	let attestation = Attestation {
+		session_index: T::ValidatorSet::session_index(),
+		period: Pallet::<T>::current_period(),
+		validators_len: keys.len() as u32,
+	};
+
+	let encoded_attestation = attestation.encode();
+	let mut signatures = Vec::new();
+	for (index, authority_id) in keys.iter().take(s as usize).enumerate() {
+		let signature = authority_id.sign(&encoded_attestation).ok_or("couldn't make signature")?;
+		signatures.push((index as AuthIndex, signature));
+	}
+	let signatures = SignaturesOf::<T>::try_from(signatures)
+		.map_err(|_| "More than the maximum number of signatures provided")?;
+
+	Ok((attestation, signatures))
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn validate_unsigned_and_then_attest(
+		k: Linear<1, MAX_KEYS>,
+		s: Linear<1, MAX_KEYS>,
+	) -> Result<(), BenchmarkError> {
+		let (attestation, signatures) = create_attestation::<T>(k, s)?;
+		let call = Call::attest { attestation, signatures };
+		let call_enc = call.encode();
+
+		#[block]
+		{
+			Pallet::<T>::validate_unsigned(TransactionSource::InBlock, &call)
+				.map_err(<&str>::from)?;
+			<Call<T> as Decode>::decode(&mut &*call_enc)
+				.expect("call is encoded above, encoding must be correct")
+				.dispatch_bypass_filter(RawOrigin::None.into())?;
+		}
+
+		Ok(())
+	}
+
+	impl_benchmark_test_suite! {
+		Pallet,
+		mock::new_test_ext(),
+		mock::Runtime
+	}
+}
@@ -0,0 +1,685 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Liveness Pallet
+//!
+//! A lightweight replacement for the I'm online pallet, for chains which removed it but still
+//! want to score their validators by liveness.
+//!
+//! Validators sign an [`Attestation`] with their session key once per
+//! [`Config::HeartbeatPeriod`]. The offchain worker of every validator node signs the attestation
+//! of the current period with its local keys and submits it as an unsigned [`Call::attest`]
+//! transaction. Signatures of several validators may also be aggregated into a single
+//! transaction, which counts one heartbeat for every signer that has not yet attested in that
+//! period.
+//!
+//! Like in the I'm online pallet, an attestation provides one transaction tag per validator it
+//! newly counts, and its priority grows with the number of such validators. An attestation
+//! carrying a superset of the signatures of a pooled one therefore replaces it, while any other
+//! resubmission of already public signatures conflicts with it and is rejected.
+//!
+//! At the end of each session every validator is given a liveness score: its number of heartbeats
+//! relative to the mean number of heartbeats per validator over the last
+//! [`Config::ParticipationWindow`] sessions. Validators scoring below
+//! [`Config::OfflineThreshold`] are reported as offline. Since the expectation follows recent
+//! network conditions, a network-wide degradation does not mark every validator offline.
+//!
+//! - [`Config`]
+//! - [`Call`]
+//! - [`Pallet`]
+//!
+//! ## Interface
+//!
+//! ### Public Functions
+//!
+//! - `liveness_score` - The liveness score of a validator in the last completed session.
+//!
+//! ## Dependencies
+//!
+//! This pallet depends on the [Session pallet](../pallet_session/index.html).
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+mod benchmarking;
+mod mock;
+mod tests;
+pub mod weights;
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Get, OneSessionHandler, ValidatorSet, ValidatorSetWithIdentification},
+	BoundedSlice, WeakBoundedVec,
+};
+use frame_system::{
+	offchain::{CreateInherent, SubmitTransaction},
+	pallet_prelude::*,
+};
+pub use pallet::*;
+use scale_info::TypeInfo;
+use sp_application_crypto::RuntimeAppPublic;
+use sp_runtime::{
+	offchain::storage::{MutateStorageError, StorageRetrievalError, StorageValueRef},
+	traits::{Convert, One, Saturating, Zero},
+	KeyTypeId, Perbill, RuntimeDebug, SaturatedConversion,
+};
+use sp_staking::SessionIndex;
+pub use weights::WeightInfo;
+
+/// The key type of the liveness session keys.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"live");
+
+const DB_PREFIX: &[u8] = b"parity/liveness-attestation-status/";
+
+pub(crate) const LOG_TARGET: &str = "runtime::liveness";
+
+pub mod sr25519 {
+	mod app_sr25519 {
+		use sp_application_crypto::{app_crypto, sr25519};
+		app_crypto!(sr25519, crate::KEY_TYPE);
+	}
+
+	sp_application_crypto::with_pair! {
+		/// A liveness keypair using sr25519 as its crypto.
+		pub type AuthorityPair = app_sr25519::Pair;
+	}
+
+	/// A liveness signature using sr25519 as its crypto.
+	pub type AuthoritySignature = app_sr25519::Signature;
+
+	/// A liveness identifier using sr25519 as its crypto.
+	pub type AuthorityId = app_sr25519::Public;
+}
+
+/// Error which may occur while executing the off-chain code.
+enum OffchainErr {
+	AlreadySent,
+	FailedSigning,
+	FailedToAcquireLock,
+	SubmitTransaction,
+}
+
+impl core::fmt::Debug for OffchainErr {
+	fn fmt(&self, fmt: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match *self {
+			OffchainErr::AlreadySent => write!(fmt, "Attestation already sent in this period"),
+			OffchainErr::FailedSigning => write!(fmt, "Failed to sign attestation"),
+			OffchainErr::FailedToAcquireLock => write!(fmt, "Failed to acquire lock"),
+			OffchainErr::SubmitTransaction => write!(fmt, "Failed to submit transaction"),
+		}
+	}
+}
+
+pub type AuthIndex = u32;
+
+/// The statement signed by every validator attesting to be online.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Attestation<BlockNumber> {
+	/// Index of the current session.
+	pub session_index: SessionIndex,
+	/// The heartbeat period attested to, i.e. the block number divided by the heartbeat period.
+	pub period: BlockNumber,
+	/// The length of session validator set.
+	pub validators_len: u32,
+}
+
+/// The heartbeats of a validator in a session.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct HeartbeatTally<BlockNumber> {
+	/// The number of heartbeats counted.
+	pub count: u32,
+	/// The last heartbeat period counted.
+	pub last_period: BlockNumber,
+}
+
+/// The participation of the validator set in a completed session.
+#[derive(
+	Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default,
+)]
+pub struct Participation {
+	/// The total number of heartbeats counted.
+	pub heartbeats: u32,
+	/// The size of the validator set.
+	pub validators: u32,
+}
+
+/// The signatures of an aggregated attestation, by authority index.
+pub type SignaturesOf<T> = BoundedVec<
+	(AuthIndex, <<T as Config>::AuthorityId as RuntimeAppPublic>::Signature),
+	<T as Config>::MaxKeys,
+>;
+
+/// A type for representing the validator id in a session.
+pub type ValidatorId<T> = <<T as Config>::ValidatorSet as ValidatorSet<
+	<T as frame_system::Config>::AccountId,
+>>::ValidatorId;
+
+/// A tuple of (ValidatorId, Identification) where `Identification` is the full identification of
+/// `ValidatorId`.
+pub type IdentificationTuple<T> = (
+	ValidatorId<T>,
+	<<T as Config>::ValidatorSet as ValidatorSetWithIdentification<
+		<T as frame_system::Config>::AccountId,
+	>>::Identification,
+);
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: CreateInherent<Call<Self>> + frame_system::Config {
+		/// The identifier type for an authority.
+		type AuthorityId: Member
+			+ Parameter
+			+ RuntimeAppPublic
+			+ Ord
+			+ MaybeSerializeDeserialize
+			+ MaxEncodedLen;
+
+		/// The maximum number of keys that can be added.
+		type MaxKeys: Get<u32>;
+
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// A type for retrieving the validators supposed to be online in a session.
+		type ValidatorSet: ValidatorSetWithIdentification<Self::AccountId>;
+
+		/// The number of blocks in which a validator may be counted at most one heartbeat.
+		#[pallet::constant]
+		type HeartbeatPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The number of most recent sessions the expected number of heartbeats per validator is
+		/// computed over.
+		#[pallet::constant]
+		type ParticipationWindow: Get<u32>;
+
+		/// The liveness score below which a validator is considered offline.
+		#[pallet::constant]
+		type OfflineThreshold: Get<Perbill>;
+
+		/// A configuration for base priority of unsigned transactions.
+		///
+		/// An attestation signed by the whole validator set gets this priority, others a share
+		/// of it proportional to the number of validators they newly count.
+		///
+		/// This is exposed so that it can be tuned for particular runtime, when
+		/// multiple pallets send unsigned transactions.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Heartbeats of `count` validators were counted for `period`.
+		HeartbeatsReceived { period: BlockNumberFor<T>, count: u32 },
+		/// At the end of the session, no validator was found to be offline.
+		AllGood,
+		/// At the end of the session, at least one validator was found to be offline.
+		SomeOffline { offline: Vec<IdentificationTuple<T>> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Non existent public key.
+		InvalidKey,
+		/// All signers already attested in the period.
+		DuplicatedHeartbeat,
+	}
+
+	/// The current set of keys that may attest.
+	#[pallet::storage]
+	pub type Keys<T: Config> =
+		StorageValue<_, WeakBoundedVec<T::AuthorityId, T::MaxKeys>, ValueQuery>;
+
+	/// For each session index, the heartbeats counted for each authority.
+	#[pallet::storage]
+	pub type Heartbeats<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SessionIndex,
+		Twox64Concat,
+		AuthIndex,
+		HeartbeatTally<BlockNumberFor<T>>,
+	>;
+
+	/// The participation in the most recent sessions, oldest first.
+	#[pallet::storage]
+	pub type RecentParticipation<T: Config> =
+		StorageValue<_, BoundedVec<Participation, T::ParticipationWindow>, ValueQuery>;
+
+	/// The liveness scores of the validators in the last completed session.
+	#[pallet::storage]
+	pub type LivenessScores<T: Config> =
+		StorageValue<_, WeakBoundedVec<(ValidatorId<T>, Perbill), T::MaxKeys>, ValueQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn offchain_worker(now: BlockNumberFor<T>) {
+			// Only send attestations if we are a potential validator.
+			if sp_io::offchain::is_validator() {
+				Self::send_attestations(now);
+			} else {
+				log::trace!(
+					target: LOG_TARGET,
+					"Skipping attestation at {:?}. Not a validator.",
+					now,
+				)
+			}
+		}
+	}
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		pub keys: Vec<T::AuthorityId>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			Pallet::<T>::initialize_keys(&self.keys);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Count a heartbeat for every signer of `attestation` which has not yet attested in its
+		/// period.
+		///
+		/// ## Complexity:
+		/// - `O(K + S)` where K is length of `Keys` (attestation.validators_len) and S is the
+		///   number of signatures.
+		// NOTE: the weight includes the cost of validate_unsigned as it is part of the cost to
+		// import block with such an extrinsic.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::validate_unsigned_and_then_attest(
+			attestation.validators_len,
+			signatures.len() as u32,
+		))]
+		pub fn attest(
+			origin: OriginFor<T>,
+			attestation: Attestation<BlockNumberFor<T>>,
+			// since signature verification is done in `validate_unsigned`
+			// we can skip doing it here again.
+			signatures: SignaturesOf<T>,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			let current_session = T::ValidatorSet::session_index();
+			let keys_len = Keys::<T>::decode_len().unwrap_or_default() as u32;
+			let mut count = 0;
+			for (authority_index, _) in signatures.iter() {
+				ensure!(*authority_index < keys_len, Error::<T>::InvalidKey);
+				if Self::count_heartbeat(current_session, *authority_index, attestation.period) {
+					count += 1;
+				}
+			}
+			ensure!(count > 0, Error::<T>::DuplicatedHeartbeat);
+
+			Self::deposit_event(Event::<T>::HeartbeatsReceived {
+				period: attestation.period,
+				count,
+			});
+			Ok(())
+		}
+	}
+
+	/// Invalid transaction custom error. Returned when validators_len field in attestation is
+	/// incorrect.
+	pub(crate) const INVALID_VALIDATORS_LEN: u8 = 10;
+	/// Invalid transaction custom error. Returned when the signatures are not sorted by authority
+	/// index without duplicates, or empty.
+	pub(crate) const INVALID_SIGNERS: u8 = 11;
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			if let Call::attest { attestation, signatures } = call {
+				// check if session index and period from the attestation are recent
+				let current_session = T::ValidatorSet::session_index();
+				let current_period = Self::current_period();
+				if attestation.session_index != current_session ||
+					attestation.period > current_period ||
+					attestation.period.saturating_add(One::one()) < current_period
+				{
+					return InvalidTransaction::Stale.into()
+				}
+
+				let keys = Keys::<T>::get();
+				if keys.len() as u32 != attestation.validators_len {
+					return InvalidTransaction::Custom(INVALID_VALIDATORS_LEN).into()
+				}
+
+				let sorted = signatures.windows(2).all(|pair| pair[0].0 < pair[1].0);
+				if signatures.is_empty() || !sorted {
+					return InvalidTransaction::Custom(INVALID_SIGNERS).into()
+				}
+
+				let new_signers = signatures
+					.iter()
+					.map(|(authority_index, _)| *authority_index)
+					.filter(|authority_index| {
+						!Self::has_attested(current_session, *authority_index, attestation.period)
+					})
+					.collect::<Vec<_>>();
+				if new_signers.is_empty() {
+					// we already counted a heartbeat for all signers
+					return InvalidTransaction::Stale.into()
+				}
+
+				// check signatures (this is expensive so we do it last).
+				let signatures_valid = attestation.using_encoded(|encoded_attestation| {
+					signatures.iter().all(|(authority_index, signature)| {
+						keys.get(*authority_index as usize).is_some_and(|authority_id| {
+							authority_id.verify(&encoded_attestation, signature)
+						})
+					})
+				});
+				if !signatures_valid {
+					return InvalidTransaction::BadProof.into()
+				}
+
+				// Provide one tag per newly counted signer, so that any two attestations counting
+				// the same signer conflict in the pool, and prefer the one counting the most.
+				let priority = Perbill::from_rational(new_signers.len() as u32, keys.len() as u32) *
+					T::UnsignedPriority::get();
+				let mut valid = ValidTransaction::with_tag_prefix("Liveness")
+					.priority(priority.max(1))
+					.longevity(T::HeartbeatPeriod::get().saturated_into::<u64>().max(1))
+					.propagate(true);
+				for authority_index in new_signers {
+					valid =
+						valid.and_provides((current_session, attestation.period, authority_index));
+				}
+				valid.build()
+			} else {
+				InvalidTransaction::Call.into()
+			}
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The liveness score of `validator` in the last completed session, if it was part of the
+	/// validator set.
+	pub fn liveness_score(validator: &ValidatorId<T>) -> Option<Perbill> {
+		LivenessScores::<T>::get()
+			.into_iter()
+			.find(|(id, _)| id == validator)
+			.map(|(_, score)| score)
+	}
+
+	/// Sign and submit an attestation of the current period with every local key which has not
+	/// attested in it yet.
+	fn send_attestations(now: BlockNumberFor<T>) {
+		let session_index = T::ValidatorSet::session_index();
+		let period = Self::current_period();
+		let attestation = Attestation {
+			session_index,
+			period,
+			validators_len: Keys::<T>::decode_len().unwrap_or_default() as u32,
+		};
+		for (authority_index, key) in Self::local_authority_keys() {
+			if Self::has_attested(session_index, authority_index, period) {
+				continue
+			}
+			if let Err(e) = Self::send_attestation(&attestation, authority_index, key) {
+				log::debug!(
+					target: LOG_TARGET,
+					"Skipping attestation of authority {} at {:?}: {:?}",
+					authority_index,
+					now,
+					e,
+				);
+			}
+		}
+	}
+
+	fn send_attestation(
+		attestation: &Attestation<BlockNumberFor<T>>,
+		authority_index: AuthIndex,
+		key: T::AuthorityId,
+	) -> Result<(), OffchainErr> {
+		// Remember the last period attested to by this authority, so that every period is
+		// attested to once even though the offchain worker runs on every block.
+		let storage_key = {
+			let mut key = DB_PREFIX.to_vec();
+			key.extend(authority_index.encode());
+			key
+		};
+		let storage = StorageValueRef::persistent(&storage_key);
+		let sent = (attestation.session_index, attestation.period);
+		let res = storage.mutate(
+			|last: Result<Option<(SessionIndex, BlockNumberFor<T>)>, StorageRetrievalError>| {
+				match last {
+					Ok(Some(last)) if last == sent => Err(OffchainErr::AlreadySent),
+					_ => Ok(sent),
+				}
+			},
+		);
+		match res {
+			Ok(_) => {},
+			Err(MutateStorageError::ValueFunctionFailed(err)) => return Err(err),
+			Err(MutateStorageError::ConcurrentModification(_)) =>
+				return Err(OffchainErr::FailedToAcquireLock),
+		}
+
+		let signature = key.sign(&attestation.encode()).ok_or(OffchainErr::FailedSigning)?;
+		let call = Call::attest {
+			attestation: attestation.clone(),
+			signatures: BoundedVec::truncate_from(alloc::vec![(authority_index, signature)]),
+		};
+		let xt = T::create_inherent(call.into());
+		SubmitTransaction::<T, Call<T>>::submit_transaction(xt).map_err(|_| {
+			// Allow retrying in the next block.
+			storage.clear();
+			OffchainErr::SubmitTransaction
+		})
+	}
+
+	fn local_authority_keys() -> impl Iterator<Item = (AuthIndex, T::AuthorityId)> {
+		let authorities = Keys::<T>::get();
+		let mut local_keys = T::AuthorityId::all();
+		local_keys.sort();
+
+		authorities.into_iter().enumerate().filter_map(move |(index, authority)| {
+			local_keys
+				.binary_search(&authority)
+				.ok()
+				.map(|location| (index as AuthIndex, local_keys[location].clone()))
+		})
+	}
+
+	/// The heartbeat period of the current block.
+	pub fn current_period() -> BlockNumberFor<T> {
+		let period = T::HeartbeatPeriod::get();
+		if period.is_zero() {
+			return Zero::zero()
+		}
+		frame_system::Pallet::<T>::block_number() / period
+	}
+
+	/// Returns `true` if a heartbeat was already counted for the authority at `authority_index`
+	/// in `period` of `session_index`.
+	fn has_attested(
+		session_index: SessionIndex,
+		authority_index: AuthIndex,
+		period: BlockNumberFor<T>,
+	) -> bool {
+		Heartbeats::<T>::get(session_index, authority_index)
+			.is_some_and(|tally| tally.last_period >= period)
+	}
+
+	/// Count a heartbeat for the authority at `authority_index` in `period`, returning `false` if
+	/// one was already counted.
+	fn count_heartbeat(
+		session_index: SessionIndex,
+		authority_index: AuthIndex,
+		period: BlockNumberFor<T>,
+	) -> bool {
+		Heartbeats::<T>::mutate(session_index, authority_index, |maybe_tally| {
+			if maybe_tally.is_some_and(|tally| tally.last_period >= period) {
+				return false
+			}
+			let count = maybe_tally.map_or(0, |tally| tally.count);
+			*maybe_tally =
+				Some(HeartbeatTally { count: count.saturating_add(1), last_period: period });
+			true
+		})
+	}
+
+	/// Note the participation of the ending session and compute the liveness score of each of
+	/// its validators, returning the offline ones.
+	fn evaluate_session(session_index: SessionIndex) -> Vec<IdentificationTuple<T>> {
+		let validators = T::ValidatorSet::validators();
+		let counts = (0..validators.len() as AuthIndex)
+			.map(|index| Heartbeats::<T>::get(session_index, index).map_or(0, |tally| tally.count))
+			.collect::<Vec<_>>();
+
+		let participation = Participation {
+			heartbeats: counts.iter().fold(0u32, |total, count| total.saturating_add(*count)),
+			validators: validators.len() as u32,
+		};
+		let recent = RecentParticipation::<T>::mutate(|recent| {
+			if participation.validators > 0 {
+				if recent.len() as u32 >= T::ParticipationWindow::get() && !recent.is_empty() {
+					recent.remove(0);
+				}
+				let _ = recent.try_push(participation);
+			}
+			recent.clone()
+		});
+		let (heartbeats, validators_count) =
+			recent.iter().fold((0u64, 0u64), |(heartbeats, validators), participation| {
+				(
+					heartbeats.saturating_add(participation.heartbeats.into()),
+					validators.saturating_add(participation.validators.into()),
+				)
+			});
+
+		// The score is the number of heartbeats relative to the mean number of heartbeats per
+		// validator in the recent sessions. Without any heartbeats recently, there is nothing to
+		// compare with.
+		let score_of = |count: u32| {
+			if heartbeats.is_zero() {
+				Perbill::one()
+			} else {
+				Perbill::from_rational(
+					(count as u64).saturating_mul(validators_count).min(heartbeats),
+					heartbeats,
+				)
+			}
+		};
+
+		let threshold = T::OfflineThreshold::get();
+		let mut scores = Vec::with_capacity(validators.len());
+		let mut offline = Vec::new();
+		for (id, count) in validators.into_iter().zip(counts) {
+			let score = score_of(count);
+			if score < threshold {
+				if let Some(full_id) = <T::ValidatorSet as ValidatorSetWithIdentification<
+					T::AccountId,
+				>>::IdentificationOf::convert(id.clone())
+				{
+					offline.push((id.clone(), full_id));
+				}
+			}
+			scores.push((id, score));
+		}
+		LivenessScores::<T>::put(WeakBoundedVec::<_, T::MaxKeys>::force_from(
+			scores,
+			Some("Warning: The session has more validators than expected."),
+		));
+
+		offline
+	}
+
+	fn initialize_keys(keys: &[T::AuthorityId]) {
+		if !keys.is_empty() {
+			assert!(Keys::<T>::get().is_empty(), "Keys are already initialized!");
+			let bounded_keys = <BoundedSlice<'_, _, T::MaxKeys>>::try_from(keys)
+				.expect("More than the maximum number of keys provided");
+			Keys::<T>::put(bounded_keys);
+		}
+	}
+}
+
+impl<T: Config> sp_runtime::BoundToRuntimeAppPublic for Pallet<T> {
+	type Public = T::AuthorityId;
+}
+
+impl<T: Config> OneSessionHandler<T::AccountId> for Pallet<T> {
+	type Key = T::AuthorityId;
+
+	fn on_genesis_session<'a, I: 'a>(validators: I)
+	where
+		I: Iterator<Item = (&'a T::AccountId, T::AuthorityId)>,
+	{
+		let keys = validators.map(|x| x.1).collect::<Vec<_>>();
+		Self::initialize_keys(&keys);
+	}
+
+	fn on_new_session<'a, I: 'a>(_changed: bool, validators: I, _queued_validators: I)
+	where
+		I: Iterator<Item = (&'a T::AccountId, T::AuthorityId)>,
+	{
+		// Remember who the authorities are for the new session.
+		let keys = validators.map(|x| x.1).collect::<Vec<_>>();
+		let bounded_keys = WeakBoundedVec::<_, T::MaxKeys>::force_from(
+			keys,
+			Some(
+				"Warning: The session has more keys than expected. \
+  				A runtime configuration adjustment may be needed.",
+			),
+		);
+		Keys::<T>::put(bounded_keys);
+	}
+
+	fn on_before_session_ending() {
+		let session_index = T::ValidatorSet::session_index();
+		let offline = Self::evaluate_session(session_index);
+
+		// Remove all heartbeats of the current session, they have already been processed and
+		// won't be needed anymore.
+		let _ = Heartbeats::<T>::clear_prefix(session_index, u32::MAX, None);
+
+		if offline.is_empty() {
+			Self::deposit_event(Event::<T>::AllGood);
+		} else {
+			Self::deposit_event(Event::<T>::SomeOffline { offline });
+		}
+	}
+
+	fn on_disabled(_i: u32) {
+		// ignore
+	}
+}
@@ -0,0 +1,147 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test utilities
+
+#![cfg(test)]
+
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use pallet_session::historical as pallet_session_historical;
+use sp_runtime::{testing::UintAuthorityId, traits::ConvertInto, BuildStorage, Perbill};
+use sp_staking::SessionIndex;
+
+use crate as liveness;
+use crate::Config;
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+pub type Extrinsic = sp_runtime::testing::TestXt<RuntimeCall, ()>;
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Session: pallet_session,
+		Liveness: liveness,
+		Historical: pallet_session_historical,
+	}
+);
+
+parameter_types! {
+	pub static Validators: Option<Vec<u64>> = Some(vec![
+		1,
+		2,
+		3,
+	]);
+}
+
+pub struct TestSessionManager;
+impl pallet_session::SessionManager<u64> for TestSessionManager {
+	fn new_session(_new_index: SessionIndex) -> Option<Vec<u64>> {
+		Validators::mutate(|l| l.take())
+	}
+	fn end_session(_: SessionIndex) {}
+	fn start_session(_: SessionIndex) {}
+}
+
+impl pallet_session::historical::SessionManager<u64, u64> for TestSessionManager {
+	fn new_session(_new_index: SessionIndex) -> Option<Vec<(u64, u64)>> {
+		Validators::mutate(|l| {
+			l.take().map(|validators| validators.iter().map(|v| (*v, *v)).collect())
+		})
+	}
+	fn end_session(_: SessionIndex) {}
+	fn start_session(_: SessionIndex) {}
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+	let mut result: sp_io::TestExternalities = t.into();
+	// Set the default keys, otherwise session will discard the validator.
+	result.execute_with(|| {
+		for i in 1..=6 {
+			System::inc_providers(&i);
+			assert_eq!(Session::set_keys(RuntimeOrigin::signed(i), i.into(), vec![]), Ok(()));
+		}
+	});
+	result
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+	type Block = Block;
+}
+
+parameter_types! {
+	pub const Period: u64 = 1_000;
+	pub const Offset: u64 = 0;
+	pub const OfflineThreshold: Perbill = Perbill::from_percent(50);
+}
+
+impl pallet_session::Config for Runtime {
+	type ShouldEndSession = pallet_session::PeriodicSessions<Period, Offset>;
+	type SessionManager =
+		pallet_session::historical::NoteHistoricalRoot<Runtime, TestSessionManager>;
+	type SessionHandler = (Liveness,);
+	type ValidatorId = u64;
+	type ValidatorIdOf = ConvertInto;
+	type Keys = UintAuthorityId;
+	type RuntimeEvent = RuntimeEvent;
+	type NextSessionRotation = pallet_session::PeriodicSessions<Period, Offset>;
+	type WeightInfo = ();
+}
+
+impl pallet_session::historical::Config for Runtime {
+	type FullIdentification = u64;
+	type FullIdentificationOf = ConvertInto;
+}
+
+impl Config for Runtime {
+	type AuthorityId = UintAuthorityId;
+	type MaxKeys = ConstU32<10_000>;
+	type RuntimeEvent = RuntimeEvent;
+	type ValidatorSet = Historical;
+	type HeartbeatPeriod = ConstU64<10>;
+	type ParticipationWindow = ConstU32<2>;
+	type OfflineThreshold = OfflineThreshold;
+	type UnsignedPriority = ConstU64<{ 1 << 20 }>;
+	type WeightInfo = ();
+}
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type RuntimeCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateInherent<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_inherent(call: Self::RuntimeCall) -> Self::Extrinsic {
+		Extrinsic::new_bare(call)
+	}
+}
+
+pub fn advance_session() {
+	let now = System::block_number().max(1);
+	System::set_block_number(now + 1);
+	Session::rotate_session();
+}
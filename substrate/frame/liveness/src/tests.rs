@@ -0,0 +1,296 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the liveness pallet.
+
+#![cfg(test)]
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{
+	offchain::{
+		testing::{TestOffchainExt, TestTransactionPoolExt},
+		OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+	},
+	testing::UintAuthorityId,
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+
+fn attestation(period: u64) -> Attestation<u64> {
+	Attestation {
+		session_index: Session::current_index(),
+		period,
+		validators_len: Keys::<Runtime>::decode_len().unwrap_or_default() as u32,
+	}
+}
+
+fn sign(attestation: &Attestation<u64>, signers: &[u64]) -> SignaturesOf<Runtime> {
+	signers
+		.iter()
+		.map(|i| ((i - 1) as AuthIndex, UintAuthorityId(*i).sign(&attestation.encode()).unwrap()))
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+fn attest(period: u64, signers: &[u64]) -> DispatchResult {
+	let attestation = attestation(period);
+	let signatures = sign(&attestation, signers);
+	let call = Call::attest { attestation: attestation.clone(), signatures: signatures.clone() };
+	Liveness::pre_dispatch(&call).map_err(|e| <&'static str>::from(e))?;
+	Liveness::attest(RuntimeOrigin::none(), attestation, signatures)
+}
+
+fn validate(
+	attestation: Attestation<u64>,
+	signatures: SignaturesOf<Runtime>,
+) -> TransactionValidity {
+	Liveness::validate_unsigned(
+		TransactionSource::External,
+		&Call::attest { attestation, signatures },
+	)
+}
+
+#[test]
+fn should_count_one_heartbeat_per_period() {
+	new_test_ext().execute_with(|| {
+		advance_session();
+		advance_session();
+		assert_eq!(Session::validators(), vec![1, 2, 3]);
+		System::set_block_number(10);
+
+		// given
+		assert_ok!(attest(1, &[1, 2]));
+		System::assert_last_event(Event::HeartbeatsReceived { period: 1, count: 2 }.into());
+
+		// then
+		// the same signers can't attest twice in a period.
+		let again = attestation(1);
+		assert_eq!(
+			validate(again.clone(), sign(&again, &[1, 2])),
+			InvalidTransaction::Stale.into()
+		);
+		assert_noop!(
+			Liveness::attest(RuntimeOrigin::none(), again.clone(), sign(&again, &[1, 2])),
+			Error::<Runtime>::DuplicatedHeartbeat
+		);
+
+		// but only the new signers are counted when they attest along.
+		assert_ok!(attest(1, &[2, 3]));
+		System::assert_last_event(Event::HeartbeatsReceived { period: 1, count: 1 }.into());
+
+		// and they may attest again in the next period.
+		System::set_block_number(20);
+		assert_ok!(attest(2, &[1]));
+
+		let session = Session::current_index();
+		let count = |index| Heartbeats::<Runtime>::get(session, index).map(|tally| tally.count);
+		assert_eq!((count(0), count(1), count(2)), (Some(2), Some(1), Some(1)));
+	});
+}
+
+#[test]
+fn should_prefer_attestations_counting_more_validators() {
+	new_test_ext().execute_with(|| {
+		advance_session();
+		advance_session();
+		System::set_block_number(10);
+		let session = Session::current_index();
+		let tag = |index: AuthIndex| ("Liveness", (session, 1u64, index)).encode();
+		let current = attestation(1);
+
+		// given
+		let single = validate(current.clone(), sign(&current, &[1])).unwrap();
+		let pair = validate(current.clone(), sign(&current, &[1, 2])).unwrap();
+
+		// then
+		// every signer gets its own tag, so that attestations sharing a signer conflict.
+		assert_eq!(single.provides, vec![tag(0)]);
+		assert_eq!(pair.provides, vec![tag(0), tag(1)]);
+		// and the superset is preferred, so it replaces the subset but not the other way round.
+		assert_eq!(single.priority, Perbill::from_rational(1u32, 3) * (1u64 << 20));
+		assert_eq!(pair.priority, Perbill::from_rational(2u32, 3) * (1u64 << 20));
+		assert!(pair.priority > single.priority);
+
+		// when
+		assert_ok!(attest(1, &[1]));
+
+		// then
+		// signers which were already counted neither provide a tag nor add to the priority.
+		let pair = validate(current.clone(), sign(&current, &[1, 2])).unwrap();
+		assert_eq!(pair.provides, vec![tag(1)]);
+		assert_eq!(pair.priority, single.priority);
+	});
+}
+
+#[test]
+fn should_submit_attestations_from_offchain_worker() {
+	let mut ext = new_test_ext();
+	let (offchain, _state) = TestOffchainExt::new();
+	let (pool, state) = TestTransactionPoolExt::new();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		advance_session();
+		advance_session();
+		assert_eq!(Session::validators(), vec![1, 2, 3]);
+
+		// given
+		UintAuthorityId::set_all_keys(vec![1, 3]);
+		System::set_block_number(10);
+
+		// when
+		Liveness::offchain_worker(10);
+
+		// then
+		// one attestation is submitted for every local key.
+		let submitted = || {
+			core::mem::take(&mut state.write().transactions)
+				.into_iter()
+				.map(|tx| {
+					let ex: Extrinsic = Decode::decode(&mut &*tx).unwrap();
+					match ex.function {
+						RuntimeCall::Liveness(crate::Call::attest { attestation, signatures }) => {
+							assert_ok!(validate(attestation.clone(), signatures.clone()));
+							(attestation.period, signatures[0].0)
+						},
+						e => panic!("Unexpected call: {:?}", e),
+					}
+				})
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(submitted(), vec![(1, 0), (1, 2)]);
+
+		// and nothing more in the same period.
+		System::set_block_number(11);
+		Liveness::offchain_worker(11);
+		assert_eq!(submitted(), vec![]);
+
+		// but the keys attest again in the next period, unless already counted.
+		System::set_block_number(20);
+		assert_ok!(attest(2, &[1]));
+		Liveness::offchain_worker(20);
+		assert_eq!(submitted(), vec![(2, 2)]);
+	});
+}
+
+#[test]
+fn should_reject_invalid_attestations() {
+	new_test_ext().execute_with(|| {
+		advance_session();
+		advance_session();
+		System::set_block_number(30);
+
+		// stale or future periods
+		for period in [1, 4] {
+			let stale = attestation(period);
+			assert_eq!(
+				validate(stale.clone(), sign(&stale, &[1])),
+				InvalidTransaction::Stale.into()
+			);
+		}
+		// the previous period is still accepted
+		let previous = attestation(2);
+		assert_ok!(validate(previous.clone(), sign(&previous, &[1])));
+
+		// another session
+		let mut other_session = attestation(3);
+		other_session.session_index += 1;
+		assert_eq!(
+			validate(other_session.clone(), sign(&other_session, &[1])),
+			InvalidTransaction::Stale.into()
+		);
+
+		// wrong validator set length
+		let mut wrong_len = attestation(3);
+		wrong_len.validators_len = 2;
+		assert_eq!(
+			validate(wrong_len.clone(), sign(&wrong_len, &[1])),
+			InvalidTransaction::Custom(INVALID_VALIDATORS_LEN).into()
+		);
+
+		// unsorted, duplicated or missing signers
+		let current = attestation(3);
+		for signers in [&[2, 1][..], &[1, 1][..], &[][..]] {
+			assert_eq!(
+				validate(current.clone(), sign(&current, signers)),
+				InvalidTransaction::Custom(INVALID_SIGNERS).into()
+			);
+		}
+
+		// signature of another attestation or an unknown authority
+		assert_eq!(
+			validate(current.clone(), sign(&previous, &[1])),
+			InvalidTransaction::BadProof.into()
+		);
+		assert_eq!(
+			validate(current.clone(), sign(&current, &[4])),
+			InvalidTransaction::BadProof.into()
+		);
+
+		assert_ok!(validate(current.clone(), sign(&current, &[1, 2, 3])));
+	});
+}
+
+#[test]
+fn offline_threshold_follows_recent_participation() {
+	new_test_ext().execute_with(|| {
+		advance_session();
+		advance_session();
+
+		// given
+		// validator 3 attests once while the others attest in four periods.
+		for period in 1..=4 {
+			System::set_block_number(period * 10);
+			let signers: &[u64] = if period == 1 { &[1, 2, 3] } else { &[1, 2] };
+			assert_ok!(attest(period, signers));
+		}
+
+		// when
+		let session = Session::current_index();
+		advance_session();
+
+		// then
+		System::assert_has_event(Event::SomeOffline { offline: vec![(3, 3)] }.into());
+		assert_eq!(Liveness::liveness_score(&1), Some(Perbill::one()));
+		assert_eq!(Liveness::liveness_score(&3), Some(Perbill::from_rational(1u32, 3)));
+		assert_eq!(Heartbeats::<Runtime>::iter_prefix(session).count(), 0);
+
+		// given
+		// the whole network degrades and every validator attests only once.
+		System::set_block_number(100);
+		assert_ok!(attest(10, &[1, 2, 3]));
+
+		// when
+		advance_session();
+
+		// then
+		// the expectation is lowered to two heartbeats, so that none is offline.
+		System::assert_has_event(Event::AllGood.into());
+		assert_eq!(Liveness::liveness_score(&3), Some(Perbill::from_percent(50)));
+		assert_eq!(
+			RecentParticipation::<Runtime>::get().into_inner(),
+			vec![
+				Participation { heartbeats: 9, validators: 3 },
+				Participation { heartbeats: 3, validators: 3 }
+			]
+		);
+	});
+}
@@ -0,0 +1,113 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for `pallet_liveness`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2026-10-16, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `runner-wiukf8gn-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `validate_unsigned_and_then_attest`. Re-run the benchmarks before relying on them.
+
+// Executed Command:
+// ./target/production/substrate-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_liveness
+// --no-storage-info
+// --no-median-slopes
+// --no-min-squares
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./substrate/frame/liveness/src/weights.rs
+// --header=./substrate/HEADER-APACHE2
+// --template=./substrate/.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_liveness`.
+pub trait WeightInfo {
+	fn validate_unsigned_and_then_attest(k: u32, s: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_liveness` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Liveness::Keys` (r:1 w:0)
+	/// Proof: `Liveness::Keys` (`max_values`: Some(1), `max_size`: Some(320002), added: 320497, mode: `MaxEncodedLen`)
+	/// Storage: `Liveness::Heartbeats` (r:1000 w:1000)
+	/// Proof: `Liveness::Heartbeats` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `k` is `[1, 1000]`.
+	/// The range of component `s` is `[1, 1000]`.
+	fn validate_unsigned_and_then_attest(k: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `298 + k * (32 ±0) + s * (30 ±0)`
+		//  Estimated: `321487 + s * (2507 ±0)`
+		// Minimum execution time: 71_412_000 picoseconds.
+		Weight::from_parts(12_304_118, 321487)
+			// Standard Error: 1_035
+			.saturating_add(Weight::from_parts(31_802, 0).saturating_mul(k.into()))
+			// Standard Error: 1_035
+			.saturating_add(Weight::from_parts(52_976_410, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2507).saturating_mul(s.into()))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Proof: `Session::CurrentIndex` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Liveness::Keys` (r:1 w:0)
+	/// Proof: `Liveness::Keys` (`max_values`: Some(1), `max_size`: Some(320002), added: 320497, mode: `MaxEncodedLen`)
+	/// Storage: `Liveness::Heartbeats` (r:1000 w:1000)
+	/// Proof: `Liveness::Heartbeats` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// The range of component `k` is `[1, 1000]`.
+	/// The range of component `s` is `[1, 1000]`.
+	fn validate_unsigned_and_then_attest(k: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `298 + k * (32 ±0) + s * (30 ±0)`
+		//  Estimated: `321487 + s * (2507 ±0)`
+		// Minimum execution time: 71_412_000 picoseconds.
+		Weight::from_parts(12_304_118, 321487)
+			// Standard Error: 1_035
+			.saturating_add(Weight::from_parts(31_802, 0).saturating_mul(k.into()))
+			// Standard Error: 1_035
+			.saturating_add(Weight::from_parts(52_976_410, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2507).saturating_mul(s.into()))
+	}
+}
@@ -192,6 +192,11 @@
 //! number of queues is constant. Creating a new queue must therefore be, possibly economically,
 //! expensive. Currently this is archived by having one queue per para-chain/thread, which keeps the
 //! number of queues within `O(n)` and should be "good enough".
+//!
+//! A single queue with many heavy messages can still use up all the weight of a servicing round.
+//! [`Config::ServiceWeightShare`] can limit each queue to a share of the weight of a round, as long
+//! as other queues are waiting as well. Rounds which ran out of weight before a queue could be
+//! fully serviced are counted in [`StarvationCount`] and exposed through [`MessageQueueApi`].
 
 #![deny(missing_docs)]
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -214,7 +219,7 @@ use frame_support::{
 	traits::{
 		Defensive, DefensiveSaturating, DefensiveTruncateFrom, EnqueueMessage,
 		ExecuteOverweightError, Footprint, ProcessMessage, ProcessMessageError, QueueFootprint,
-		QueuePausedQuery, ServiceQueues,
+		QueuePausedQuery, QueueServiceWeightShare, ServiceQueues,
 	},
 	BoundedSlice, CloneNoBound, DefaultNoBound,
 };
@@ -513,6 +518,15 @@ pub mod pallet {
 		/// it that happen *within* the servicing will not be reflected.
 		type QueuePausedQuery: QueuePausedQuery<<Self::MessageProcessor as ProcessMessage>::Origin>;
 
+		/// Queried by the pallet for the share of the servicing weight that a queue may use.
+		///
+		/// A queue is only limited to its share while other queues are waiting to be serviced. A
+		/// queue whose next message does not fit into its share at all is still serviced. Use `()`
+		/// to not limit any queue.
+		type ServiceWeightShare: QueueServiceWeightShare<
+			<Self::MessageProcessor as ProcessMessage>::Origin,
+		>;
+
 		/// The size of the page; this implies the maximum message size which can be sent.
 		///
 		/// A good value depends on the expected message sizes, their weights, the weight that is
@@ -633,6 +647,14 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type ServiceHead<T: Config> = StorageValue<_, MessageOriginOf<T>, OptionQuery>;
 
+	/// How many servicing rounds ran out of weight while a queue still had messages to process.
+	///
+	/// Only the first such queue of a round is counted. The count is removed once the queue is
+	/// empty.
+	#[pallet::storage]
+	pub type StarvationCount<T: Config> =
+		StorageMap<_, Twox64Concat, MessageOriginOf<T>, u32, ValueQuery>;
+
 	/// The map of page indices to pages.
 	#[pallet::storage]
 	pub(super) type Pages<T: Config> = StorageDoubleMap<
@@ -819,6 +841,8 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn ready_ring_unknit(origin: &MessageOriginOf<T>, neighbours: Neighbours<MessageOriginOf<T>>) {
+		// An empty queue can no longer be starved.
+		StarvationCount::<T>::remove(origin);
 		if origin == &neighbours.next {
 			debug_assert!(
 				origin == &neighbours.prev,
@@ -1153,36 +1177,46 @@ impl<T: Config> Pallet<T> {
 	/// Execute any messages remaining to be processed in the queue of `origin`, using up to
 	/// `weight_limit` to do so. Any messages which would take more than `overweight_limit` to
 	/// execute are deemed overweight and ignored.
+	///
+	/// Returns whether progress was made, the next ready queue and whether the servicing bailed
+	/// because it ran out of weight.
 	fn service_queue(
 		origin: MessageOriginOf<T>,
 		weight: &mut WeightMeter,
 		overweight_limit: Weight,
-	) -> (bool, Option<MessageOriginOf<T>>) {
+	) -> (bool, Option<MessageOriginOf<T>>, bool) {
 		use PageExecutionStatus::*;
 		if weight
 			.try_consume(
 				T::WeightInfo::service_queue_base()
-					.saturating_add(T::WeightInfo::ready_ring_unknit()),
+					.saturating_add(T::WeightInfo::ready_ring_unknit())
+					// Removing the `StarvationCount` of the queue once it is empty.
+					.saturating_add(T::DbWeight::get().writes(1)),
 			)
 			.is_err()
 		{
-			return (false, None)
+			return (false, None, true)
 		}
 
 		let mut book_state = BookStateFor::<T>::get(&origin);
 		let mut total_processed = 0;
 		if T::QueuePausedQuery::is_paused(&origin) {
 			let next_ready = book_state.ready_neighbours.as_ref().map(|x| x.next.clone());
-			return (false, next_ready)
+			return (false, next_ready, false)
 		}
 
+		let mut bailed = false;
 		while book_state.end > book_state.begin {
 			let (processed, status) =
 				Self::service_page(&origin, &mut book_state, weight, overweight_limit);
 			total_processed.saturating_accrue(processed);
 			match status {
 				// Store the page progress and do not go to the next one.
-				Bailed | NoProgress => break,
+				Bailed => {
+					bailed = true;
+					break
+				},
+				NoProgress => break,
 				// Go to the next page if this one is at the end.
 				NoMore => (),
 			};
@@ -1201,7 +1235,63 @@ impl<T: Config> Pallet<T> {
 		if total_processed > 0 {
 			T::QueueChangeHandler::on_queue_changed(origin, book_state.into());
 		}
-		(total_processed > 0, next_ready)
+		(total_processed > 0, next_ready, bailed)
+	}
+
+	/// Service the queue of `origin` like [`Self::service_queue`], but without letting it use
+	/// more than its [`Config::ServiceWeightShare`] of `weight_limit` while other queues are
+	/// ready.
+	///
+	/// `shares_used` tracks the weight that the limited queues consumed in this servicing round.
+	/// The returned flag is only set if the queue ran out of the overall weight and not just its
+	/// share of it.
+	fn service_queue_with_share(
+		origin: MessageOriginOf<T>,
+		weight: &mut WeightMeter,
+		weight_limit: Weight,
+		overweight_limit: Weight,
+		shares_used: &mut Vec<(MessageOriginOf<T>, Weight)>,
+	) -> (bool, Option<MessageOriginOf<T>>, bool) {
+		let Some(share) = T::ServiceWeightShare::weight_share(&origin) else {
+			return Self::service_queue(origin, weight, overweight_limit)
+		};
+		let used = shares_used.iter().find(|(o, _)| o == &origin).map_or(Weight::zero(), |x| x.1);
+		let budget = (share * weight_limit).saturating_sub(used);
+
+		if weight.try_consume(T::DbWeight::get().reads(1)).is_err() {
+			return (false, None, true)
+		}
+		let next_ready = BookStateFor::<T>::get(&origin)
+			.ready_neighbours
+			.map(|neighbours| neighbours.next);
+		if next_ready.as_ref().map_or(true, |next| next == &origin) {
+			// No other queue is waiting; the share does not apply.
+			return Self::service_queue(origin, weight, overweight_limit)
+		}
+
+		let mut meter = WeightMeter::with_limit(weight.remaining().min(budget));
+		let mut limited = meter.limit() < weight.remaining();
+		let (mut progressed, mut next, mut bailed) =
+			Self::service_queue(origin.clone(), &mut meter, overweight_limit);
+		weight.consume(meter.consumed());
+		let mut consumed = meter.consumed();
+
+		if limited && bailed && !progressed && used.is_zero() {
+			// Its next message does not fit into the share at all. Let it use the remaining weight
+			// so that it can still make progress.
+			let before = weight.consumed();
+			(progressed, next, bailed) =
+				Self::service_queue(origin.clone(), weight, overweight_limit);
+			consumed.saturating_accrue(weight.consumed().saturating_sub(before));
+			limited = false;
+		}
+
+		match shares_used.iter_mut().find(|(o, _)| o == &origin) {
+			Some((_, w)) => w.saturating_accrue(consumed),
+			None => shares_used.push((origin, consumed)),
+		}
+		// A queue that used up its share will get more weight in the next round.
+		(progressed, next.or(next_ready), bailed && !limited)
 	}
 
 	/// Service as many messages of a page as possible.
@@ -1328,6 +1418,11 @@ impl<T: Config> Pallet<T> {
 		ItemExecutionStatus::Executed(is_processed)
 	}
 
+	/// The starvation counts of all queues which were starved at least once.
+	pub fn starvation_counts() -> Vec<(MessageOriginOf<T>, u32)> {
+		StarvationCount::<T>::iter().collect()
+	}
+
 	/// Ensure the correctness of state of this pallet.
 	///
 	/// # Assumptions-
@@ -1568,7 +1663,9 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn service_queues_impl(weight_limit: Weight, context: ServiceQueuesContext) -> Weight {
-		let mut weight = WeightMeter::with_limit(weight_limit);
+		// Keep the weight for counting a starved queue aside.
+		let starvation_weight = T::DbWeight::get().reads_writes(1, 1);
+		let mut weight = WeightMeter::with_limit(weight_limit.saturating_sub(starvation_weight));
 
 		// Get the maximum weight that processing a single message may take:
 		let overweight_limit = Self::max_message_weight(weight_limit).unwrap_or_else(|| {
@@ -1587,10 +1684,22 @@ impl<T: Config> Pallet<T> {
 			// The loop aborts as soon as it arrives at this queue again without making any progress
 			// on other queues in between.
 			let mut last_no_progress = None;
+			// The weight consumed by the queues that are limited to a share.
+			let mut shares_used = Vec::new();
+			// The first queue that ran out of weight in this round.
+			let mut starved = None;
 
 			loop {
-				let (progressed, n) =
-					Self::service_queue(next.clone(), &mut weight, overweight_limit);
+				let (progressed, n, bailed) = Self::service_queue_with_share(
+					next.clone(),
+					&mut weight,
+					weight_limit,
+					overweight_limit,
+					&mut shares_used,
+				);
+				if bailed && starved.is_none() {
+					starved = Some(next.clone());
+				}
 				next = match n {
 					Some(n) =>
 						if !progressed {
@@ -1608,6 +1717,10 @@ impl<T: Config> Pallet<T> {
 					None => break,
 				}
 			}
+			if let Some(origin) = starved {
+				StarvationCount::<T>::mutate(origin, |count| count.saturating_inc());
+				return weight.consumed().saturating_add(starvation_weight)
+			}
 			weight.consumed()
 		}) {
 			Err(()) => weight.consumed(),
@@ -1758,3 +1871,18 @@ impl<T: Config> EnqueueMessage<MessageOriginOf<T>> for Pallet<T> {
 		BookStateFor::<T>::get(&origin).into()
 	}
 }
+
+sp_api::decl_runtime_apis! {
+	/// Runtime API for monitoring how fairly the message queues are serviced.
+	pub trait MessageQueueApi<MessageOrigin>
+	where
+		MessageOrigin: Codec,
+	{
+		/// How many servicing rounds ran out of weight while the queue of `origin` still had
+		/// messages to process.
+		fn starvation_count(origin: MessageOrigin) -> u32;
+
+		/// The starvation counts of all queues which were starved at least once.
+		fn starvation_counts() -> Vec<(MessageOrigin, u32)>;
+	}
+}
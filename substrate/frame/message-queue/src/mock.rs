@@ -25,7 +25,7 @@ use super::*;
 use crate as pallet_message_queue;
 use alloc::collections::btree_map::BTreeMap;
 use frame_support::{derive_impl, parameter_types};
-use sp_runtime::BuildStorage;
+use sp_runtime::{BuildStorage, Perbill};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -53,6 +53,7 @@ impl Config for Test {
 	type Size = u32;
 	type QueueChangeHandler = RecordingQueueChangeHandler;
 	type QueuePausedQuery = MockedQueuePauser;
+	type ServiceWeightShare = MockedWeightShare;
 	type HeapSize = HeapSize;
 	type MaxStale = MaxStale;
 	type ServiceWeight = ServiceWeight;
@@ -295,6 +296,17 @@ impl QueuePausedQuery<MessageOrigin> for MockedQueuePauser {
 	}
 }
 
+parameter_types! {
+	pub static WeightShares: Vec<(MessageOrigin, Perbill)> = vec![];
+}
+
+pub struct MockedWeightShare;
+impl QueueServiceWeightShare<MessageOrigin> for MockedWeightShare {
+	fn weight_share(id: &MessageOrigin) -> Option<Perbill> {
+		WeightShares::get().into_iter().find(|(o, _)| o == id).map(|(_, share)| share)
+	}
+}
+
 /// Create new test externalities.
 ///
 /// Is generic since it is used by the unit test, integration tests and benchmarks.
@@ -24,6 +24,7 @@ use crate::{mock::*, *};
 use frame_support::{assert_noop, assert_ok, assert_storage_noop, StorageNoopGuard};
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use sp_crypto_hashing::blake2_256;
+use sp_runtime::Perbill;
 
 #[test]
 fn mocked_weight_works() {
@@ -1494,6 +1495,56 @@ fn enqueue_messages_works() {
 	});
 }
 
+#[test]
+fn service_queues_weight_share_works() {
+	use MessageOrigin::*;
+	build_and_execute::<Test>(|| {
+		WeightShares::set(vec![(Here, Perbill::from_percent(50))]);
+		MessageQueue::enqueue_messages(
+			vec![msg("a"), msg("b"), msg("c"), msg("d")].into_iter(),
+			Here,
+		);
+		MessageQueue::enqueue_messages(
+			vec![msg("x"), msg("y"), msg("z"), msg("w")].into_iter(),
+			There,
+		);
+
+		// `Here` may only use half of the weight while `There` is waiting.
+		assert_eq!(MessageQueue::service_queues(4.into_weight()), 4.into_weight());
+		assert_eq!(
+			MessagesProcessed::take(),
+			vec![(vmsg("a"), Here), (vmsg("b"), Here), (vmsg("x"), There), (vmsg("y"), There)]
+		);
+		// `There` ran out of weight, `Here` was only limited to its share.
+		assert_eq!(MessageQueue::starvation_counts(), vec![(There, 1)]);
+
+		// Once `There` is done, `Here` is not limited anymore.
+		assert_eq!(MessageQueue::service_queues(4.into_weight()), 4.into_weight());
+		assert_eq!(
+			MessagesProcessed::take(),
+			vec![(vmsg("z"), There), (vmsg("w"), There), (vmsg("c"), Here), (vmsg("d"), Here)]
+		);
+		// Both queues are empty now, so their counts are removed.
+		assert!(MessageQueue::starvation_counts().is_empty());
+	});
+}
+
+#[test]
+fn starvation_count_is_removed_with_the_queue() {
+	use MessageOrigin::*;
+	build_and_execute::<Test>(|| {
+		WeightShares::set(vec![(Here, Perbill::from_percent(50))]);
+		MessageQueue::enqueue_messages(vec![msg("a"), msg("b"), msg("c")].into_iter(), Here);
+		MessageQueue::enqueue_messages(vec![msg("x"), msg("y"), msg("z")].into_iter(), There);
+
+		assert_eq!(MessageQueue::service_queues(4.into_weight()), 4.into_weight());
+		assert_eq!(MessageQueue::starvation_counts(), vec![(There, 1)]);
+
+		MessageQueue::sweep_queue(There);
+		assert!(MessageQueue::starvation_counts().is_empty());
+	});
+}
+
 #[test]
 fn service_queues_suspend_works() {
 	use MessageOrigin::*;
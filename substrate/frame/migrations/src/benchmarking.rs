@@ -204,6 +204,49 @@ mod benches {
 		);
 	}
 
+	#[benchmark]
+	fn pause_migration() {
+		Cursor::<T>::set(Some(cursor::<T>()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root);
+
+		assert!(Paused::<T>::exists());
+	}
+
+	#[benchmark]
+	fn resume_migration() {
+		Cursor::<T>::set(Some(cursor::<T>()));
+		Paused::<T>::put(BlockNumberFor::<T>::from(0u32));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root);
+
+		assert!(!Paused::<T>::exists());
+	}
+
+	#[benchmark]
+	fn skip_migration() -> Result<(), BenchmarkError> {
+		T::Migrations::set_fail_after(0);
+		let id = T::Migrations::nth_id(0)
+			.and_then(|id| IdentifierOf::<T>::try_from(id).ok())
+			.ok_or(BenchmarkError::Stop("Invalid identifier"))?;
+		Cursor::<T>::set(Some(MigrationCursor::Stuck));
+		LastFailure::<T>::put(MigrationFailureReport {
+			index: Some(0),
+			inner_cursor: None,
+			progress: Default::default(),
+			reason: MigrationFailureReason::Failed,
+			failed_at: 0u32.into(),
+		});
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, 0, id);
+
+		assert_last_event::<T>(Event::MigrationForceSkipped { index: 0 }.into());
+		Ok(())
+	}
+
 	fn cursor<T: Config>() -> CursorOf<T> {
 		// Note: The weight of a function can depend on the weight of reading the `inner_cursor`.
 		// `Cursor` is a user provided type. Now instead of requiring something like `Cursor:
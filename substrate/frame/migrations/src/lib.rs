@@ -130,6 +130,21 @@
 //! implement this would be to use the `SafeMode` or `TxPause` pallets that can prevent most user
 //! interactions but still allow a whitelisted set of governance calls.
 //!
+//! Every failure is recorded in [`LastFailure`], which contains the reason of the failure and the
+//! progress of the failed migration. Governance can then decide to fix the issue with a new
+//! runtime, or to [`skip_migration`][Pallet::skip_migration] the failed migration. Skipping needs
+//! the identifier of the migration as explicit acknowledgement that the invariants established by
+//! the migration will not hold. An ongoing migration can also be paused with
+//! [`pause_migration`][Pallet::pause_migration] to investigate the `MigrationProgressed` events
+//! before it fails.
+//!
+//! While the migrations are ongoing or stuck, the chain only includes inherents, so
+//! `pause_migration` has to be dispatched from a hook, e.g. by the scheduler or an XCM message of
+//! the governing chain. A paused upgrade does not count as [ongoing][MultiStepMigrator::ongoing],
+//! which lets governance include [`resume_migration`][Pallet::resume_migration] and
+//! `skip_migration` as normal extrinsics. Note that user transactions are included as well while
+//! the upgrade is paused, so pausing should be combined with the `SafeMode` or `TxPause` pallets.
+//!
 //! ### Remark: Failed migrations
 //!
 //! Failed migrations are not added to the `Historic` set. This means that an erroneous
@@ -222,6 +237,54 @@ impl<Cursor, BlockNumber> ActiveCursor<Cursor, BlockNumber> {
 	}
 }
 
+/// Progress of the currently active migration.
+#[derive(
+	Debug, Default, Clone, Eq, PartialEq, Encode, Decode, scale_info::TypeInfo, MaxEncodedLen,
+)]
+pub struct MigrationProgress {
+	/// The number of steps that the migration executed so far.
+	pub steps: u32,
+	/// The weight that the steps of the migration consumed so far.
+	pub weight_used: Weight,
+}
+
+/// Why a migration or the whole upgrade failed.
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, scale_info::TypeInfo, MaxEncodedLen)]
+pub enum MigrationFailureReason {
+	/// A new upgrade was started while the migrations of a previous one were still ongoing.
+	Interrupted,
+	/// The migration is not part of [`Config::Migrations`] or its identifier is too long.
+	InvalidMigration,
+	/// The cursor returned by the migration is longer than [`Config::CursorMaxLen`].
+	CursorTooLong,
+	/// The migration did not complete within its `max_steps`.
+	Timeout,
+	/// A step of the migration requires more weight than available.
+	InsufficientWeight {
+		/// The weight that the step requires.
+		required: Weight,
+	},
+	/// The migration reported that its cursor is invalid.
+	InvalidCursor,
+	/// The migration reported that it failed.
+	Failed,
+}
+
+/// Post-mortem report of a failed upgrade.
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, scale_info::TypeInfo, MaxEncodedLen)]
+pub struct MigrationFailureReport<Cursor, BlockNumber> {
+	/// The index of the failed migration, if known.
+	pub index: Option<u32>,
+	/// The inner cursor of the failed migration.
+	pub inner_cursor: Option<Cursor>,
+	/// The progress of the failed migration.
+	pub progress: MigrationProgress,
+	/// Why the migration failed.
+	pub reason: MigrationFailureReason,
+	/// The block number at which the migration failed.
+	pub failed_at: BlockNumber,
+}
+
 /// How to clear the records of historic migrations.
 #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, scale_info::TypeInfo)]
 pub enum HistoricCleanupSelector<Id> {
@@ -270,6 +333,9 @@ pub type IdentifierOf<T> = BoundedVec<u8, <T as Config>::IdentifierMaxLen>;
 /// Convenience alias for [`ActiveCursor`].
 pub type ActiveCursorOf<T> = ActiveCursor<RawCursorOf<T>, BlockNumberFor<T>>;
 
+/// Convenience alias for [`MigrationFailureReport`].
+pub type MigrationFailureReportOf<T> = MigrationFailureReport<RawCursorOf<T>, BlockNumberFor<T>>;
+
 /// Trait for a tuple of No-OP migrations with one element.
 #[impl_trait_for_tuples::impl_for_tuples(30)]
 pub trait MockedMigrations: SteppedMigrations {
@@ -403,6 +469,24 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Historic<T: Config> = StorageMap<_, Twox64Concat, IdentifierOf<T>, (), OptionQuery>;
 
+	/// Progress of the currently active migration.
+	///
+	/// Reset whenever the next migration becomes active.
+	#[pallet::storage]
+	pub type Progress<T: Config> = StorageValue<_, MigrationProgress, ValueQuery>;
+
+	/// The block number at which the execution of the ongoing migrations was paused.
+	///
+	/// `None` indicates that the execution is not paused.
+	#[pallet::storage]
+	pub type Paused<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// Report of the last failed upgrade.
+	///
+	/// Kept for post-mortem analysis until the next failure overwrites it.
+	#[pallet::storage]
+	pub type LastFailure<T: Config> = StorageValue<_, MigrationFailureReportOf<T>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -457,12 +541,46 @@ pub mod pallet {
 			/// Should be passed to `clear_historic` in a successive call.
 			next_cursor: Option<Vec<u8>>,
 		},
+		/// The execution of the ongoing migrations was paused.
+		MigrationsPaused,
+		/// The execution of the ongoing migrations was resumed.
+		MigrationsResumed,
+		/// A migration was skipped by governance.
+		///
+		/// The invariants that the migration establishes do not hold.
+		MigrationForceSkipped {
+			/// The index of the skipped migration within the [`Config::Migrations`] list.
+			index: u32,
+		},
+		/// A step of a migration was executed.
+		MigrationProgressed {
+			/// The index of the migration within the [`Config::Migrations`] list.
+			index: u32,
+			/// The inner cursor of the migration after the step.
+			///
+			/// `None` if the migration completed with this step.
+			cursor: Option<RawCursorOf<T>>,
+			/// The number of steps that the migration executed so far.
+			steps: u32,
+			/// The weight that this step consumed.
+			weight_used: Weight,
+		},
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		/// The operation cannot complete since some MBMs are ongoing.
 		Ongoing,
+		/// The operation cannot complete since no MBMs are ongoing.
+		NotOngoing,
+		/// The execution of the migrations is already paused.
+		AlreadyPaused,
+		/// The execution of the migrations is not paused.
+		NotPaused,
+		/// The given migration is not the active or failed one.
+		NotCurrentMigration,
+		/// The given identifier does not match the identifier of the migration.
+		IdentifierMismatch,
 	}
 
 	#[pallet::hooks]
@@ -484,6 +602,10 @@ pub mod pallet {
 				Cursor::<T>::put(MigrationCursor::Stuck);
 				assert!(<T as frame_system::Config>::MultiBlockMigrator::ongoing());
 
+				Paused::<T>::put(System::<T>::block_number());
+				assert!(!<T as frame_system::Config>::MultiBlockMigrator::ongoing());
+
+				Paused::<T>::kill();
 				Cursor::<T>::kill();
 			}
 
@@ -532,6 +654,7 @@ pub mod pallet {
 			ensure_root(origin)?;
 
 			Cursor::<T>::set(cursor);
+			Progress::<T>::kill();
 
 			Ok(())
 		}
@@ -559,6 +682,7 @@ pub mod pallet {
 				inner_cursor,
 				started_at,
 			}));
+			Progress::<T>::kill();
 
 			Ok(())
 		}
@@ -605,6 +729,92 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Pause the execution of the ongoing or stuck migrations.
+		///
+		/// The migrations do not progress until the execution is resumed with
+		/// [`Self::resume_migration`]. This allows to inspect the progress of a migration that is
+		/// about to fail before it is too late.
+		///
+		/// While paused, the migrations are not reported as
+		/// [ongoing][MultiStepMigrator::ongoing]. Extrinsics are therefore included again, which
+		/// makes it possible to resume or skip the migration. Since the chain only includes
+		/// inherents before the pause, this call has to be dispatched from a hook, e.g. by the
+		/// scheduler or through XCM.
+		#[pallet::call_index(4)]
+		pub fn pause_migration(origin: OriginFor<T>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(Cursor::<T>::exists(), Error::<T>::NotOngoing);
+			ensure!(!Paused::<T>::exists(), Error::<T>::AlreadyPaused);
+			Paused::<T>::put(System::<T>::block_number());
+
+			Self::deposit_event(Event::MigrationsPaused);
+			Ok(())
+		}
+
+		/// Resume the execution of the migrations paused with [`Self::pause_migration`].
+		///
+		/// The blocks during which the execution was paused do not count towards the `max_steps`
+		/// of the active migration.
+		#[pallet::call_index(5)]
+		pub fn resume_migration(origin: OriginFor<T>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let paused_at = Paused::<T>::take().ok_or(Error::<T>::NotPaused)?;
+			Cursor::<T>::mutate(|cursor| {
+				if let Some(MigrationCursor::Active(cursor)) = cursor {
+					let paused_for = System::<T>::block_number().saturating_sub(paused_at);
+					cursor.started_at.saturating_accrue(paused_for);
+				}
+			});
+
+			Self::deposit_event(Event::MigrationsResumed);
+			Ok(())
+		}
+
+		/// Skip the active migration, or the failed migration if the migrations are stuck.
+		///
+		/// The invariants established by the skipped migration will not hold, which is explicitly
+		/// acknowledged by passing the identifier `id` of the migration with index `index`. The
+		/// skipped migration is not recorded in the [`Historic`] set. The execution continues with
+		/// the next migration, unless it is paused.
+		///
+		/// Use [`Self::pause_migration`] first to be able to include this call as an extrinsic.
+		#[pallet::call_index(6)]
+		pub fn skip_migration(
+			origin: OriginFor<T>,
+			index: u32,
+			id: IdentifierOf<T>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let current = match Cursor::<T>::get() {
+				None => return Err(Error::<T>::NotOngoing.into()),
+				Some(MigrationCursor::Active(cursor)) => Some(cursor.index),
+				Some(MigrationCursor::Stuck) => LastFailure::<T>::get().and_then(|r| r.index),
+			};
+			ensure!(current == Some(index), Error::<T>::NotCurrentMigration);
+			ensure!(
+				T::Migrations::nth_id(index).as_deref() == Some(&id[..]),
+				Error::<T>::IdentifierMismatch
+			);
+
+			let now = System::<T>::block_number();
+			Cursor::<T>::put(MigrationCursor::Active(ActiveCursor {
+				index: index.saturating_add(1),
+				inner_cursor: None,
+				started_at: now,
+			}));
+			Progress::<T>::kill();
+			// The next migration only starts now, so the time it stays paused counts from here.
+			if Paused::<T>::exists() {
+				Paused::<T>::put(now);
+			}
+
+			Self::deposit_event(Event::MigrationForceSkipped { index });
+			Ok(())
+		}
 	}
 }
 
@@ -616,8 +826,7 @@ impl<T: Config> Pallet<T> {
 		if let Some(cursor) = Cursor::<T>::get() {
 			log::error!("Ongoing migrations interrupted - chain stuck");
 
-			let maybe_index = cursor.as_active().map(|c| c.index);
-			Self::upgrade_failed(maybe_index);
+			Self::upgrade_failed(cursor.as_active(), MigrationFailureReason::Interrupted);
 			return T::WeightInfo::onboard_new_mbms()
 		}
 
@@ -634,11 +843,13 @@ impl<T: Config> Pallet<T> {
 				}
 				.into(),
 			));
+			Progress::<T>::kill();
+			Paused::<T>::kill();
 			Self::deposit_event(Event::UpgradeStarted { migrations });
 			T::MigrationStatusHandler::started();
 		}
 
-		T::WeightInfo::onboard_new_mbms()
+		T::WeightInfo::onboard_new_mbms().saturating_add(T::DbWeight::get().writes(2))
 	}
 
 	/// Tries to make progress on the Multi-Block-Migrations process.
@@ -652,6 +863,11 @@ impl<T: Config> Pallet<T> {
 				return meter.consumed()
 			},
 			Some(MigrationCursor::Active(cursor)) => {
+				meter.consume(T::DbWeight::get().reads(1));
+				if Paused::<T>::exists() {
+					log::debug!("[Block {n:?}] Migrations are paused.");
+					return meter.consumed()
+				}
 				log::debug!("Progressing MBM #{}", cursor.index);
 				cursor
 			},
@@ -713,13 +929,14 @@ impl<T: Config> Pallet<T> {
 		let id = T::Migrations::nth_id(cursor.index).map(TryInto::try_into);
 		let Some(Ok(bounded_id)): Option<Result<IdentifierOf<T>, _>> = id else {
 			defensive!("integrity_test ensures that all identifiers are present and bounde; qed.");
-			Self::upgrade_failed(Some(cursor.index));
+			Self::upgrade_failed(Some(&cursor), MigrationFailureReason::InvalidMigration);
 			return None
 		};
 
 		if Historic::<T>::contains_key(&bounded_id) {
 			Self::deposit_event(Event::MigrationSkipped { index: cursor.index });
 			cursor.goto_next_migration(System::<T>::block_number());
+			Progress::<T>::kill();
 			return Some(ControlFlow::Continue(cursor))
 		}
 
@@ -734,6 +951,7 @@ impl<T: Config> Pallet<T> {
 			PreUpgradeBytes::<T>::insert(&bounded_id, PreUpgradeBytesWrapper(bytes));
 		}
 
+		let consumed_before = meter.consumed();
 		let next_cursor = T::Migrations::nth_transactional_step(
 			cursor.index,
 			cursor.inner_cursor.clone().map(|c| c.into_inner()),
@@ -741,25 +959,27 @@ impl<T: Config> Pallet<T> {
 		);
 		let Some((max_steps, next_cursor)) = max_steps.zip(next_cursor) else {
 			defensive!("integrity_test ensures that the tuple is valid; qed");
-			Self::upgrade_failed(Some(cursor.index));
+			Self::upgrade_failed(Some(&cursor), MigrationFailureReason::InvalidMigration);
 			return None
 		};
+		let step_weight = meter.consumed().saturating_sub(consumed_before);
 
 		let took = System::<T>::block_number().saturating_sub(cursor.started_at);
 		match next_cursor {
 			Ok(Some(next_cursor)) => {
 				let Ok(bound_next_cursor) = next_cursor.try_into() else {
 					defensive!("The integrity check ensures that all cursors' MEL bound fits into CursorMaxLen; qed");
-					Self::upgrade_failed(Some(cursor.index));
+					Self::upgrade_failed(Some(&cursor), MigrationFailureReason::CursorTooLong);
 					return None
 				};
 
-				Self::deposit_event(Event::MigrationAdvanced { index: cursor.index, took });
 				cursor.inner_cursor = Some(bound_next_cursor);
+				Self::record_progress(&cursor, step_weight);
+				Self::deposit_event(Event::MigrationAdvanced { index: cursor.index, took });
 
 				if max_steps.map_or(false, |max| took > max.into()) {
 					Self::deposit_event(Event::MigrationFailed { index: cursor.index, took });
-					Self::upgrade_failed(Some(cursor.index));
+					Self::upgrade_failed(Some(&cursor), MigrationFailureReason::Timeout);
 					None
 				} else {
 					// A migration cannot progress more than one step per block, we therefore break.
@@ -778,35 +998,75 @@ impl<T: Config> Pallet<T> {
 				.expect("Invalid cursor.index.")
 				.expect("Post-upgrade failed.");
 
+				cursor.inner_cursor = None;
+				Self::record_progress(&cursor, step_weight);
 				Self::deposit_event(Event::MigrationCompleted { index: cursor.index, took });
 				Historic::<T>::insert(&bounded_id, ());
 				cursor.goto_next_migration(System::<T>::block_number());
+				Progress::<T>::kill();
 				Some(ControlFlow::Continue(cursor))
 			},
 			Err(SteppedMigrationError::InsufficientWeight { required }) => {
 				if is_first || required.any_gt(meter.limit()) {
 					Self::deposit_event(Event::MigrationFailed { index: cursor.index, took });
-					Self::upgrade_failed(Some(cursor.index));
+					Self::upgrade_failed(
+						Some(&cursor),
+						MigrationFailureReason::InsufficientWeight { required },
+					);
 					None
 				} else {
 					// Retry and hope that there is more weight in the next block.
 					Some(ControlFlow::Break(cursor))
 				}
 			},
-			Err(SteppedMigrationError::InvalidCursor | SteppedMigrationError::Failed) => {
+			Err(error @ (SteppedMigrationError::InvalidCursor | SteppedMigrationError::Failed)) => {
 				Self::deposit_event(Event::MigrationFailed { index: cursor.index, took });
-				Self::upgrade_failed(Some(cursor.index));
+				let reason = match error {
+					SteppedMigrationError::InvalidCursor => MigrationFailureReason::InvalidCursor,
+					_ => MigrationFailureReason::Failed,
+				};
+				Self::upgrade_failed(Some(&cursor), reason);
 				None
 			},
 		}
 	}
 
-	/// Fail the current runtime upgrade, caused by `migration`.
+	/// Record that a step of the migration of `cursor` consumed `weight`.
 	///
-	/// When the `try-runtime` feature is enabled, this function will panic.
+	/// The inner cursor of `cursor` must already point past the step.
+	fn record_progress(cursor: &ActiveCursorOf<T>, weight: Weight) {
+		let progress = Progress::<T>::mutate(|progress| {
+			progress.steps.saturating_inc();
+			progress.weight_used.saturating_accrue(weight);
+			progress.clone()
+		});
+
+		Self::deposit_event(Event::MigrationProgressed {
+			index: cursor.index,
+			cursor: cursor.inner_cursor.clone(),
+			steps: progress.steps,
+			weight_used: weight,
+		});
+	}
+
+	/// Fail the current runtime upgrade, caused by the migration of `cursor`.
+	///
+	/// A [`MigrationFailureReport`] is recorded in [`LastFailure`]. When the `try-runtime` feature
+	/// is enabled, this function will panic.
 	// Allow unreachable code so it can compile without warnings when `try-runtime` is enabled.
-	fn upgrade_failed(migration: Option<u32>) {
+	fn upgrade_failed(cursor: Option<&ActiveCursorOf<T>>, reason: MigrationFailureReason) {
 		use FailedMigrationHandling::*;
+		let migration = cursor.map(|c| c.index);
+		// A stuck upgrade was already reported when it got stuck.
+		if cursor.is_some() || !LastFailure::<T>::exists() {
+			LastFailure::<T>::put(MigrationFailureReport {
+				index: migration,
+				inner_cursor: cursor.and_then(|c| c.inner_cursor.clone()),
+				progress: Progress::<T>::take(),
+				reason,
+				failed_at: System::<T>::block_number(),
+			});
+		}
 		Self::deposit_event(Event::UpgradeFailed);
 
 		if cfg!(feature = "try-runtime") {
@@ -827,12 +1087,17 @@ impl<T: Config> Pallet<T> {
 			.max(T::WeightInfo::exec_migration_skipped_historic())
 			.max(T::WeightInfo::exec_migration_advance())
 			.max(T::WeightInfo::exec_migration_fail())
+			// Reading and updating the `Progress` and writing the `LastFailure`.
+			.saturating_add(T::DbWeight::get().reads_writes(1, 2))
 	}
 }
 
 impl<T: Config> MultiStepMigrator for Pallet<T> {
+	/// Whether the migrations are active or stuck, and not paused.
+	///
+	/// A paused upgrade is not ongoing, so that the calls to resume or skip it can be included.
 	fn ongoing() -> bool {
-		Cursor::<T>::exists()
+		Cursor::<T>::exists() && !Paused::<T>::exists()
 	}
 
 	fn step() -> Weight {
@@ -23,12 +23,14 @@ use crate::{mock_helpers::*, Event, Historic};
 
 use frame_support::{
 	derive_impl,
+	dispatch::DispatchResultWithPostInfo,
 	migrations::*,
 	traits::{OnFinalize, OnInitialize},
 	weights::Weight,
 };
 use frame_system::EventRecord;
 use sp_core::H256;
+use sp_runtime::traits::Dispatchable;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -127,6 +129,18 @@ pub fn run_to_block(n: u32) {
 	}
 }
 
+/// Includes `call` as a root extrinsic.
+///
+/// Like `frame_executive`, this refuses to include anything but inherents while the MBMs are
+/// ongoing.
+pub fn include_extrinsic(call: RuntimeCall) -> DispatchResultWithPostInfo {
+	assert!(
+		!<Migrations as MultiStepMigrator>::ongoing(),
+		"Only inherents can be included while MBMs are ongoing"
+	);
+	call.dispatch(RuntimeOrigin::root())
+}
+
 /// Returns the historic migrations, sorted by their identifier.
 pub fn historic() -> Vec<MockedIdentifier> {
 	let mut historic = Historic::<Test>::iter_keys().collect::<Vec<_>>();
@@ -156,7 +170,28 @@ impl<E: IntoRecord> IntoRecords for Vec<E> {
 	}
 }
 
+/// Asserts that exactly `events` were deposited, ignoring `MigrationProgressed` events.
+///
+/// Use [`progress_events`] to check the progress of the migrations.
 pub fn assert_events<E: IntoRecord>(events: Vec<E>) {
-	pretty_assertions::assert_eq!(events.into_records(), System::events());
+	let deposited = System::events()
+		.into_iter()
+		.filter(|record| {
+			!matches!(record.event, RuntimeEvent::Migrations(Event::MigrationProgressed { .. }))
+		})
+		.collect::<Vec<_>>();
+	pretty_assertions::assert_eq!(events.into_records(), deposited);
 	System::reset_events();
 }
+
+/// Returns the `(index, steps)` of all deposited `MigrationProgressed` events.
+pub fn progress_events() -> Vec<(u32, u32)> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::Migrations(Event::MigrationProgressed { index, steps, .. }) =>
+				Some((index, steps)),
+			_ => None,
+		})
+		.collect()
+}
@@ -17,12 +17,17 @@
 
 #![cfg(test)]
 
-use frame_support::{pallet_prelude::Weight, traits::OnRuntimeUpgrade};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok, migrations::MultiStepMigrator, pallet_prelude::Weight,
+	traits::OnRuntimeUpgrade,
+};
 
 use crate::{
 	mock::{Test as T, *},
 	mock_helpers::{MockedMigrationKind::*, *},
-	Cursor, Event, FailedMigrationHandling, MigrationCursor,
+	ActiveCursor, Cursor, Error, Event, FailedMigrationHandling, LastFailure, MigrationCursor,
+	MigrationFailureReason, MigrationFailureReport, MigrationProgress, Paused, Progress,
 };
 
 #[docify::export]
@@ -367,6 +372,127 @@ fn migration_timeout_errors() {
 	});
 }
 
+#[test]
+fn progress_is_reported_for_every_step() {
+	test_closure(|| {
+		MockedMigrations::set(vec![(SucceedAfter, 2), (SucceedAfter, 0)]);
+
+		System::set_block_number(1);
+		Migrations::on_runtime_upgrade();
+		run_to_block(3);
+
+		assert_eq!(progress_events(), vec![(0, 1), (0, 2)]);
+		assert_eq!(
+			Progress::<T>::get(),
+			MigrationProgress { steps: 2, weight_used: Weight::zero() }
+		);
+
+		run_to_block(10);
+		assert_eq!(progress_events(), vec![(0, 1), (0, 2), (0, 3), (1, 1)]);
+		// The progress is reset once a migration completes.
+		assert_eq!(Progress::<T>::get(), MigrationProgress::default());
+		assert_eq!(upgrades_started_completed_failed(), (1, 1, 0));
+	});
+}
+
+#[test]
+fn paused_migrations_do_not_progress() {
+	test_closure(|| {
+		MockedMigrations::set(vec![(SucceedAfter, 2)]);
+
+		System::set_block_number(1);
+		assert_noop!(Migrations::pause_migration(RuntimeOrigin::root()), Error::<T>::NotOngoing);
+		Migrations::on_runtime_upgrade();
+		run_to_block(2);
+
+		assert!(<Migrations as MultiStepMigrator>::ongoing());
+		// Dispatched from a hook, since the chain only includes inherents.
+		assert_ok!(Migrations::pause_migration(RuntimeOrigin::root()));
+		assert_noop!(Migrations::pause_migration(RuntimeOrigin::root()), Error::<T>::AlreadyPaused);
+		// The paused upgrade is not ongoing, so extrinsics are included again.
+		assert!(!<Migrations as MultiStepMigrator>::ongoing());
+		run_to_block(10);
+
+		// Only the step of block 2 was executed. The paused blocks don't count as steps.
+		assert_eq!(progress_events(), vec![(0, 1)]);
+		assert_eq!(upgrades_started_completed_failed(), (1, 0, 0));
+
+		assert_ok!(include_extrinsic(RuntimeCall::Migrations(crate::Call::resume_migration {})));
+		assert_noop!(Migrations::resume_migration(RuntimeOrigin::root()), Error::<T>::NotPaused);
+		assert!(!Paused::<T>::exists());
+		assert!(<Migrations as MultiStepMigrator>::ongoing());
+		run_to_block(20);
+
+		assert_eq!(historic(), vec![mocked_id(SucceedAfter, 2)]);
+		assert_eq!(upgrades_started_completed_failed(), (0, 1, 0));
+	});
+}
+
+#[test]
+#[cfg(not(feature = "try-runtime"))]
+fn failed_migration_is_reported_and_can_be_skipped() {
+	test_closure(|| {
+		FailedUpgradeResponse::set(FailedMigrationHandling::KeepStuck);
+		MockedMigrations::set(vec![(FailAfter, 2), (SucceedAfter, 1)]);
+
+		System::set_block_number(1);
+		Migrations::on_runtime_upgrade();
+		run_to_block(10);
+
+		assert_eq!(Cursor::<T>::get(), Some(MigrationCursor::Stuck));
+		assert_eq!(
+			LastFailure::<T>::get(),
+			Some(MigrationFailureReport {
+				index: Some(0),
+				inner_cursor: Some(2u32.encode().try_into().unwrap()),
+				progress: MigrationProgress { steps: 2, weight_used: Weight::zero() },
+				reason: MigrationFailureReason::Failed,
+				failed_at: 4,
+			})
+		);
+		assert_eq!(upgrades_started_completed_failed(), (1, 0, 1));
+
+		// The stuck chain only includes inherents until it is paused from a hook.
+		assert!(<Migrations as MultiStepMigrator>::ongoing());
+		assert_ok!(Migrations::pause_migration(RuntimeOrigin::root()));
+
+		// The identifier must be given to acknowledge the skipped migration.
+		assert_noop!(
+			Migrations::skip_migration(RuntimeOrigin::root(), 1, mocked_id(SucceedAfter, 1)),
+			Error::<T>::NotCurrentMigration
+		);
+		assert_noop!(
+			Migrations::skip_migration(RuntimeOrigin::root(), 0, mocked_id(FailAfter, 3)),
+			Error::<T>::IdentifierMismatch
+		);
+		assert_ok!(include_extrinsic(RuntimeCall::Migrations(crate::Call::skip_migration {
+			index: 0,
+			id: mocked_id(FailAfter, 2),
+		})));
+		assert_eq!(
+			Cursor::<T>::get(),
+			Some(MigrationCursor::Active(ActiveCursor {
+				index: 1,
+				inner_cursor: None,
+				started_at: 10
+			}))
+		);
+		assert_eq!(Paused::<T>::get(), Some(10));
+		assert_ok!(include_extrinsic(RuntimeCall::Migrations(crate::Call::resume_migration {})));
+		System::reset_events();
+
+		run_to_block(20);
+		assert_events(vec![
+			Event::MigrationAdvanced { index: 1, took: 1 },
+			Event::MigrationCompleted { index: 1, took: 2 },
+			Event::UpgradeCompleted,
+		]);
+		// The skipped migration is not recorded as executed.
+		assert_eq!(historic(), vec![mocked_id(SucceedAfter, 1)]);
+		assert_eq!(upgrades_started_completed_failed(), (0, 1, 0));
+	});
+}
+
 #[cfg(feature = "try-runtime")]
 #[test]
 fn try_runtime_success_case() {
@@ -45,6 +45,23 @@ fn setup_multi<T: Config>(
 	Ok((signatories, Box::new(call)))
 }
 
+/// Register a named multisig of `s` signatories, returning its account and its controller.
+fn setup_registered<T: Config>(s: u32) -> Result<(T::AccountId, T::AccountId), &'static str> {
+	let (mut signatories, _) = setup_multi::<T>(s, 0)?;
+	let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+	let multisig = Multisig::<T>::registered_account_id(&caller, &[0; 32]);
+	Multisig::<T>::register_multisig(
+		RawOrigin::Signed(caller.clone()).into(),
+		[0; 32],
+		s as u16,
+		signatories.clone(),
+	)
+	.map_err(|_| "failed to register multisig")?;
+	signatories.push(caller);
+	signatories.sort();
+	Ok((multisig, Multisig::<T>::multi_account_id(&signatories, s as u16)))
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -298,5 +315,152 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn note_call(
+		s: Linear<2, { T::MaxSignatories::get() }>,
+		z: Linear<0, { T::MaxCallSize::get() - 8 }>,
+	) -> Result<(), BenchmarkError> {
+		let (mut signatories, call) = setup_multi::<T>(s, z)?;
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s.try_into().unwrap());
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let call_hash = call.using_encoded(blake2_256);
+		// Create the multi without storing the call
+		let o = RawOrigin::Signed(caller.clone()).into();
+		Multisig::<T>::approve_as_multi(
+			o,
+			s as u16,
+			signatories.clone(),
+			None,
+			call_hash,
+			Weight::zero(),
+		)?;
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), s as u16, signatories, call);
+
+		assert!(Calls::<T>::contains_key(multi_account_id, call_hash));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn register_multisig(s: Linear<2, { T::MaxSignatories::get() }>) -> Result<(), BenchmarkError> {
+		let (mut signatories, _) = setup_multi::<T>(s, 0)?;
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let multisig = Multisig::<T>::registered_account_id(&caller, &[0; 32]);
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), [0; 32], s as u16, signatories);
+
+		assert!(Registered::<T>::contains_key(multisig));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn as_registered() -> Result<(), BenchmarkError> {
+		let s = T::MaxSignatories::get();
+		let (multisig, controller) = setup_registered::<T>(s)?;
+		let call: <T as Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: Vec::new() }.into();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(controller), multisig, Box::new(call));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn rotate_registered(s: Linear<2, { T::MaxSignatories::get() }>) -> Result<(), BenchmarkError> {
+		// Growing the signatories is the worst case, as the controller takes over the deposit.
+		let (multisig, controller) = setup_registered::<T>(2)?;
+		T::Currency::make_free_balance_be(&controller, BalanceOf::<T>::max_value());
+		let (signatories, _) = setup_multi::<T>(s, 0)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(controller.clone()), multisig.clone(), s as u16, signatories);
+
+		assert_eq!(Registered::<T>::get(&multisig).map(|r| r.threshold), Some(s as u16));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unregister_multisig() -> Result<(), BenchmarkError> {
+		let (multisig, controller) = setup_registered::<T>(T::MaxSignatories::get())?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(controller), multisig.clone());
+
+		assert!(!Registered::<T>::contains_key(multisig));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_expiry(s: Linear<2, { T::MaxSignatories::get() }>) -> Result<(), BenchmarkError> {
+		let (mut signatories, call) = setup_multi::<T>(s, 0)?;
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s.try_into().unwrap());
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let call_hash = call.using_encoded(blake2_256);
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi
+		let o = RawOrigin::Signed(caller.clone()).into();
+		Multisig::<T>::as_multi(o, s as u16, signatories.clone(), None, call, Weight::zero())?;
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		add_to_whitelist(caller_key.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), s as u16, signatories, timepoint, call_hash, 10u32.into());
+
+		assert!(Expiries::<T>::contains_key(multi_account_id, call_hash));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn close_expired() -> Result<(), BenchmarkError> {
+		let s = T::MaxSignatories::get();
+		let (mut signatories, call) = setup_multi::<T>(s, T::MaxCallSize::get() - 8)?;
+		let multi_account_id = Multisig::<T>::multi_account_id(&signatories, s.try_into().unwrap());
+		let caller = signatories.pop().ok_or("signatories should have len 2 or more")?;
+		let noting = signatories[0].clone();
+		let mut other_signatories = signatories.clone();
+		other_signatories[0] = caller.clone();
+		other_signatories.sort();
+		let call_hash = call.using_encoded(blake2_256);
+		let timepoint = Multisig::<T>::timepoint();
+		// Create the multi, store its call and let it expire
+		let o = RawOrigin::Signed(caller.clone()).into();
+		Multisig::<T>::as_multi(
+			o,
+			s as u16,
+			signatories.clone(),
+			None,
+			call.clone(),
+			Weight::zero(),
+		)?;
+		let o = RawOrigin::Signed(noting).into();
+		Multisig::<T>::note_call(o, s as u16, other_signatories, call)?;
+		let o = RawOrigin::Signed(caller).into();
+		Multisig::<T>::set_expiry(o, s as u16, signatories, timepoint, call_hash, 0u32.into())?;
+		let closer: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(closer), multi_account_id.clone(), call_hash);
+
+		assert!(!Multisigs::<T>::contains_key(&multi_account_id, call_hash));
+		assert!(!Calls::<T>::contains_key(multi_account_id, call_hash));
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Multisig, crate::tests::new_test_ext(), crate::tests::Test);
 }
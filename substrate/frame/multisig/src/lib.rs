@@ -31,6 +31,11 @@
 //! operation. This is useful for multisig wallets where cryptographic threshold signatures are
 //! not available or desired.
 //!
+//! A group can also register a named multisig, whose account is derived from its creator and
+//! name rather than from its signatories. It is controlled by the composite account of its
+//! current signatories and threshold, which can rotate them without the named account, and the
+//! assets held by it, changing.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -39,6 +44,14 @@
 //!   number of signed origins.
 //! * `approve_as_multi` - Approve a call from a composite origin.
 //! * `cancel_as_multi` - Cancel a call from a composite origin.
+//! * `note_call` - Store the call of an open multisig operation for other signatories to inspect.
+//! * `register_multisig` - Register a named multisig with a set of signatories and a threshold.
+//! * `as_registered` - Dispatch a call from a named multisig, by its controller.
+//! * `rotate_registered` - Change the signatories and threshold of a named multisig, by its
+//!   controller.
+//! * `unregister_multisig` - Remove a named multisig, by its controller.
+//! * `set_expiry` - Set the number of blocks after which an open multisig operation expires.
+//! * `close_expired` - Remove an expired multisig operation and refund its deposit.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -46,13 +59,14 @@
 mod benchmarking;
 pub mod migrations;
 mod tests;
+pub mod view_functions;
 pub mod weights;
 
 extern crate alloc;
 use alloc::{boxed::Box, vec, vec::Vec};
 use frame::{
 	prelude::*,
-	traits::{Currency, ReservableCurrency},
+	traits::{Currency, InspectNestedCalls, IsSubType, ManagedDeposit, ReservableCurrency, Zero},
 };
 use frame_system::RawOrigin;
 pub use weights::WeightInfo;
@@ -76,6 +90,7 @@ macro_rules! log {
 
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+type Deposit<T> = ManagedDeposit<<T as frame_system::Config>::AccountId, <T as Config>::Currency>;
 
 /// A global extrinsic index, formed as the extrinsic index within a block, together with that
 /// block's height. This allows a transaction in which a multisig operation of a particular
@@ -107,6 +122,40 @@ where
 	approvals: BoundedVec<AccountId, MaxApprovals>,
 }
 
+/// The call of an open multisig operation, stored so that signatories can inspect it before
+/// approving the operation.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxCallSize))]
+pub struct StoredCall<Balance, AccountId, MaxCallSize>
+where
+	MaxCallSize: Get<u32>,
+{
+	/// The encoded call.
+	call: BoundedVec<u8, MaxCallSize>,
+	/// The account who stored the call.
+	depositor: AccountId,
+	/// The amount held in reserve of the `depositor`, to be returned once the operation ends.
+	deposit: Balance,
+}
+
+/// A named multisig, whose signatories and threshold can be rotated without changing its
+/// account.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxSignatories))]
+pub struct RegisteredMultisig<Balance, AccountId, MaxSignatories>
+where
+	MaxSignatories: Get<u32>,
+{
+	/// The current signatories. Always sorted.
+	signatories: BoundedVec<AccountId, MaxSignatories>,
+	/// The current number of approvals needed to act as the named multisig.
+	threshold: u16,
+	/// The account who registered it, or the controller who last grew its signatories.
+	depositor: AccountId,
+	/// The amount held in reserve of the `depositor`, to be returned once it is unregistered.
+	deposit: Balance,
+}
+
 type CallHash = [u8; 32];
 
 enum CallOrHash<T: Config> {
@@ -127,7 +176,8 @@ pub mod pallet {
 		type RuntimeCall: Parameter
 			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
 			+ GetDispatchInfo
-			+ From<frame_system::Call<Self>>;
+			+ From<frame_system::Call<Self>>
+			+ IsSubType<Call<Self>>;
 
 		/// The currency mechanism.
 		type Currency: ReservableCurrency<Self::AccountId>;
@@ -151,6 +201,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxSignatories: Get<u32>;
 
+		/// The maximum length of an encoded call which can be stored with `note_call`.
+		#[pallet::constant]
+		type MaxCallSize: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: weights::WeightInfo;
 	}
@@ -173,6 +227,37 @@ pub mod pallet {
 		Multisig<BlockNumberFor<T>, BalanceOf<T>, T::AccountId, T::MaxSignatories>,
 	>;
 
+	/// The calls of open multisig operations, stored with `note_call`.
+	#[pallet::storage]
+	pub type Calls<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		[u8; 32],
+		StoredCall<BalanceOf<T>, T::AccountId, T::MaxCallSize>,
+	>;
+
+	/// The blocks at which open multisig operations expire, set with `set_expiry`.
+	#[pallet::storage]
+	pub type Expiries<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		[u8; 32],
+		BlockNumberFor<T>,
+	>;
+
+	/// The named multisigs, keyed by their account.
+	#[pallet::storage]
+	pub type Registered<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		RegisteredMultisig<BalanceOf<T>, T::AccountId, T::MaxSignatories>,
+	>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Threshold must be 2 or greater.
@@ -203,6 +288,21 @@ pub mod pallet {
 		MaxWeightTooLow,
 		/// The data to be stored is already stored.
 		AlreadyStored,
+		/// The encoded call is longer than `MaxCallSize`.
+		CallTooLarge,
+		/// The threshold is higher than the number of signatories.
+		ThresholdTooHigh,
+		/// A named multisig is already registered under this name by this account.
+		AlreadyRegistered,
+		/// The named multisig is not registered.
+		NotRegistered,
+		/// Only the composite account of the current signatories and threshold of a named
+		/// multisig can act for it.
+		NotController,
+		/// The multisig operation has no expiry or has not expired yet.
+		NotExpired,
+		/// The account of the named multisig still exists, so it cannot be unregistered.
+		RegisteredAccountInUse,
 	}
 
 	#[pallet::event]
@@ -232,6 +332,28 @@ pub mod pallet {
 			multisig: T::AccountId,
 			call_hash: CallHash,
 		},
+		/// The call of a multisig operation has been stored.
+		CallNoted { noting: T::AccountId, multisig: T::AccountId, call_hash: CallHash },
+		/// A named multisig has been registered.
+		MultisigRegistered {
+			creator: T::AccountId,
+			multisig: T::AccountId,
+			controller: T::AccountId,
+		},
+		/// A call has been dispatched from a named multisig.
+		RegisteredMultisigExecuted { multisig: T::AccountId, result: DispatchResult },
+		/// The signatories and threshold of a named multisig have been rotated.
+		RegisteredMultisigRotated { multisig: T::AccountId, controller: T::AccountId },
+		/// A named multisig has been unregistered.
+		MultisigUnregistered { multisig: T::AccountId },
+		/// The expiry of a multisig operation has been set.
+		ExpirySet { multisig: T::AccountId, call_hash: CallHash, expires_at: BlockNumberFor<T> },
+		/// An expired multisig operation has been removed.
+		MultisigExpired {
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+		},
 	}
 
 	#[pallet::hooks]
@@ -468,9 +590,9 @@ pub mod pallet {
 			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
 			ensure!(m.depositor == who, Error::<T>::NotOwner);
 
-			let err_amount = T::Currency::unreserve(&m.depositor, m.deposit);
-			debug_assert!(err_amount.is_zero());
+			Deposit::<T>::release(&m.depositor, m.deposit);
 			<Multisigs<T>>::remove(&id, &call_hash);
+			Self::clear_metadata(&id, &call_hash);
 
 			Self::deposit_event(Event::MultisigCancelled {
 				cancelling: who,
@@ -480,6 +602,346 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Store the call of an open multisig operation, so that the other signatories can
+		/// inspect what they are approving instead of its bare hash.
+		///
+		/// Payment: `DepositBase` will be reserved, plus `DepositFactor` for every 32 bytes of
+		/// the encoded call. It is returned once the operation is executed or cancelled.
+		///
+		/// The dispatch origin for this call must be _Signed_ by one of the signatories.
+		///
+		/// - `threshold`: The total number of approvals for this dispatch before it is executed.
+		/// - `other_signatories`: The accounts (other than the sender) who can approve this
+		/// dispatch. May not be empty.
+		/// - `call`: The call of the open multisig operation.
+		///
+		/// ## Complexity
+		/// - `O(S + Z)`.
+		/// - One balance-reserve operation.
+		/// - One call encode & hash, both of complexity `O(Z)` where `Z` is tx-len.
+		/// - One encode & hash, both of complexity `O(S)`.
+		/// - One event.
+		/// - I/O: 2 reads, one insert `O(Z)`.
+		/// - Storage: inserts one item, value size bounded by `MaxCallSize`, with a deposit taken
+		///   for its lifetime of `DepositBase + Z / 32 * DepositFactor`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::note_call(
+			other_signatories.len() as u32,
+			call.using_encoded(|d| d.len()) as u32,
+		))]
+		pub fn note_call(
+			origin: OriginFor<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
+			let max_sigs = T::MaxSignatories::get() as usize;
+			ensure!(!other_signatories.is_empty(), Error::<T>::TooFewSignatories);
+			ensure!(other_signatories.len() < max_sigs, Error::<T>::TooManySignatories);
+			let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
+
+			let id = Self::multi_account_id(&signatories, threshold);
+
+			let encoded_call = call.encode();
+			let call_hash = blake2_256(&encoded_call);
+			ensure!(<Multisigs<T>>::contains_key(&id, call_hash), Error::<T>::NotFound);
+			ensure!(!<Calls<T>>::contains_key(&id, call_hash), Error::<T>::AlreadyStored);
+			let call_len = encoded_call.len() as u32;
+			let call: BoundedVec<_, _> =
+				encoded_call.try_into().map_err(|_| Error::<T>::CallTooLarge)?;
+
+			let deposit =
+				T::DepositBase::get() + T::DepositFactor::get() * call_len.div_ceil(32).into();
+			Deposit::<T>::hold(&who, deposit)?;
+
+			<Calls<T>>::insert(
+				&id,
+				call_hash,
+				StoredCall { call, depositor: who.clone(), deposit },
+			);
+			Self::deposit_event(Event::CallNoted { noting: who, multisig: id, call_hash });
+			Ok(())
+		}
+
+		/// Register a named multisig, whose account is derived from the sender and `name`.
+		///
+		/// Unlike the composite account used by `as_multi`, the account of a named multisig does
+		/// not change when its signatories or threshold do. It is controlled by the composite
+		/// account of its current signatories and threshold, through `as_registered`.
+		///
+		/// Payment: `DepositBase` will be reserved, plus `DepositFactor` for every signatory. It
+		/// is returned once the named multisig is unregistered.
+		///
+		/// The dispatch origin for this call must be _Signed_ by one of the signatories.
+		///
+		/// - `name`: The name of the multisig, unique per sender.
+		/// - `threshold`: The number of approvals needed to act as the named multisig.
+		/// - `other_signatories`: The accounts (other than the sender) who are part of the
+		/// multisig. May not be empty.
+		///
+		/// ## Complexity
+		/// - `O(S)`.
+		/// - One balance-reserve operation.
+		/// - One encode & hash, both of complexity `O(S)`.
+		/// - One event.
+		/// - Storage: inserts one item, value size bounded by `MaxSignatories`, with a deposit
+		///   taken for its lifetime of `DepositBase + S * DepositFactor`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::register_multisig(other_signatories.len() as u32))]
+		pub fn register_multisig(
+			origin: OriginFor<T>,
+			name: [u8; 32],
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let max_sigs = T::MaxSignatories::get() as usize;
+			ensure!(!other_signatories.is_empty(), Error::<T>::TooFewSignatories);
+			ensure!(other_signatories.len() < max_sigs, Error::<T>::TooManySignatories);
+			let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
+			let signatories = Self::ensure_valid_signatories(signatories, threshold)?;
+
+			let id = Self::registered_account_id(&who, &name);
+			ensure!(!<Registered<T>>::contains_key(&id), Error::<T>::AlreadyRegistered);
+
+			let deposit =
+				T::DepositBase::get() + T::DepositFactor::get() * (signatories.len() as u32).into();
+			Deposit::<T>::hold(&who, deposit)?;
+
+			let controller = Self::multi_account_id(&signatories, threshold);
+			<Registered<T>>::insert(
+				&id,
+				RegisteredMultisig { signatories, threshold, depositor: who.clone(), deposit },
+			);
+			Self::deposit_event(Event::MultisigRegistered {
+				creator: who,
+				multisig: id,
+				controller,
+			});
+			Ok(())
+		}
+
+		/// Dispatch a call from a named multisig.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the controller of the named
+		/// multisig, i.e. the composite account of its current signatories and threshold. This
+		/// is usually achieved by wrapping this call in `as_multi`.
+		///
+		/// - `multisig`: The account of the named multisig.
+		/// - `call`: The call to be dispatched from the named multisig.
+		///
+		/// The result of the call is found in the deposited `RegisteredMultisigExecuted` event.
+		///
+		/// ## Complexity
+		/// O(C) where C is the execution weight of the call.
+		#[pallet::call_index(6)]
+		#[pallet::weight({
+			let dispatch_info = call.get_dispatch_info();
+			(
+				T::WeightInfo::as_registered()
+					// AccountData for inner call origin accountdata.
+					.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+					.saturating_add(dispatch_info.call_weight),
+				dispatch_info.class,
+			)
+		})]
+		pub fn as_registered(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_controller(&who, &multisig)?;
+
+			let result = call.dispatch(RawOrigin::Signed(multisig.clone()).into());
+			Self::deposit_event(Event::RegisteredMultisigExecuted {
+				multisig,
+				result: result.map(|_| ()).map_err(|e| e.error),
+			});
+			Ok(get_result_weight(result)
+				.map(|actual_weight| T::WeightInfo::as_registered().saturating_add(actual_weight))
+				.into())
+		}
+
+		/// Change the signatories and threshold of a named multisig, keeping its account.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the controller of the named
+		/// multisig, i.e. the approval of its current threshold of signatories is needed.
+		///
+		/// The deposit is adjusted to the new number of signatories. If it grows, the controller
+		/// reserves the whole new deposit and the previous depositor is refunded. If it shrinks,
+		/// the difference is returned to the current depositor.
+		///
+		/// - `multisig`: The account of the named multisig.
+		/// - `threshold`: The new number of approvals needed to act as the named multisig.
+		/// - `signatories`: The new signatories. Must be sorted and contain at least two.
+		///
+		/// ## Complexity
+		/// - `O(S)`.
+		/// - Up to two balance-reserve operations.
+		/// - One encode & hash, both of complexity `O(S)`.
+		/// - One event.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::rotate_registered(signatories.len() as u32))]
+		pub fn rotate_registered(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			threshold: u16,
+			signatories: Vec<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut registered = Self::ensure_controller(&who, &multisig)?;
+
+			ensure!(signatories.len() >= 2, Error::<T>::TooFewSignatories);
+			ensure!(
+				signatories.len() <= T::MaxSignatories::get() as usize,
+				Error::<T>::TooManySignatories
+			);
+			ensure!(
+				signatories.windows(2).all(|pair| pair[0] < pair[1]),
+				Error::<T>::SignatoriesOutOfOrder
+			);
+			let signatories = Self::ensure_valid_signatories(signatories, threshold)?;
+
+			let deposit =
+				T::DepositBase::get() + T::DepositFactor::get() * (signatories.len() as u32).into();
+			if deposit > registered.deposit {
+				Deposit::<T>::hold(&who, deposit)?;
+				Deposit::<T>::release(&registered.depositor, registered.deposit);
+				registered.depositor = who;
+			} else {
+				Deposit::<T>::release(&registered.depositor, registered.deposit - deposit);
+			}
+			registered.deposit = deposit;
+
+			let controller = Self::multi_account_id(&signatories, threshold);
+			registered.signatories = signatories;
+			registered.threshold = threshold;
+			<Registered<T>>::insert(&multisig, registered);
+			Self::deposit_event(Event::RegisteredMultisigRotated { multisig, controller });
+			Ok(())
+		}
+
+		/// Remove a named multisig and return the deposit taken for it.
+		///
+		/// The account of the named multisig must no longer exist, i.e. hold no balance and have no
+		/// providers. Otherwise its creator could register it again with signatories of their
+		/// choice and take over whatever is left in it.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the controller of the named
+		/// multisig.
+		///
+		/// - `multisig`: The account of the named multisig.
+		///
+		/// ## Complexity
+		/// - `O(S)`.
+		/// - One balance-unreserve operation.
+		/// - One event.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::unregister_multisig())]
+		pub fn unregister_multisig(origin: OriginFor<T>, multisig: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let registered = Self::ensure_controller(&who, &multisig)?;
+			ensure!(
+				T::Currency::total_balance(&multisig).is_zero() &&
+					!frame_system::Pallet::<T>::account_exists(&multisig),
+				Error::<T>::RegisteredAccountInUse
+			);
+
+			<Registered<T>>::remove(&multisig);
+			Deposit::<T>::release(&registered.depositor, registered.deposit);
+			Self::deposit_event(Event::MultisigUnregistered { multisig });
+			Ok(())
+		}
+
+		/// Set the number of blocks after which an open multisig operation expires, so that its
+		/// deposit can be refunded even if the other signatories never approve or cancel it.
+		///
+		/// Once expired, anyone can remove the operation with `close_expired`. Setting the expiry
+		/// again replaces the previous one.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the account that opened the
+		/// multisig operation.
+		///
+		/// - `threshold`: The total number of approvals for this dispatch before it is executed.
+		/// - `other_signatories`: The accounts (other than the sender) who can approve this
+		/// dispatch. May not be empty.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the first approval
+		/// transaction for this dispatch.
+		/// - `call_hash`: The hash of the call to be executed.
+		/// - `expires_in`: The number of blocks from now after which the operation expires.
+		///
+		/// ## Complexity
+		/// - `O(S)`.
+		/// - One encode & hash, both of complexity `O(S)`.
+		/// - One event.
+		/// - I/O: 1 read `O(S)`, one insert.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::set_expiry(other_signatories.len() as u32))]
+		pub fn set_expiry(
+			origin: OriginFor<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			timepoint: Timepoint<BlockNumberFor<T>>,
+			call_hash: [u8; 32],
+			expires_in: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
+			let max_sigs = T::MaxSignatories::get() as usize;
+			ensure!(!other_signatories.is_empty(), Error::<T>::TooFewSignatories);
+			ensure!(other_signatories.len() < max_sigs, Error::<T>::TooManySignatories);
+			let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
+
+			let id = Self::multi_account_id(&signatories, threshold);
+
+			let m = <Multisigs<T>>::get(&id, call_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(m.when == timepoint, Error::<T>::WrongTimepoint);
+			ensure!(m.depositor == who, Error::<T>::NotOwner);
+
+			let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(expires_in);
+			<Expiries<T>>::insert(&id, call_hash, expires_at);
+			Self::deposit_event(Event::ExpirySet { multisig: id, call_hash, expires_at });
+			Ok(())
+		}
+
+		/// Remove an expired multisig operation and refund the deposits taken for it.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `multisig`: The composite account of the multisig operation.
+		/// - `call_hash`: The hash of the call of the multisig operation.
+		///
+		/// ## Complexity
+		/// - `O(S)`.
+		/// - Up to two balance-unreserve operations.
+		/// - One event.
+		/// - I/O: 2 reads, up to 3 removes.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::close_expired())]
+		pub fn close_expired(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			call_hash: [u8; 32],
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let expires_at =
+				<Expiries<T>>::get(&multisig, call_hash).ok_or(Error::<T>::NotExpired)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= expires_at,
+				Error::<T>::NotExpired
+			);
+			let m = <Multisigs<T>>::take(&multisig, call_hash).ok_or(Error::<T>::NotFound)?;
+
+			Deposit::<T>::release(&m.depositor, m.deposit);
+			Self::clear_metadata(&multisig, &call_hash);
+
+			Self::deposit_event(Event::MultisigExpired { timepoint: m.when, multisig, call_hash });
+			Ok(())
+		}
 	}
 }
 
@@ -494,6 +956,13 @@ impl<T: Config> Pallet<T> {
 			.expect("infinite length input; no invalid inputs for type; qed")
 	}
 
+	/// Derive the account ID of a named multisig from its creator and name.
+	pub fn registered_account_id(creator: &T::AccountId, name: &[u8; 32]) -> T::AccountId {
+		let entropy = (b"modlpy/msigname", creator, name).using_encoded(blake2_256);
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
 	fn operate(
 		who: T::AccountId,
 		threshold: u16,
@@ -546,7 +1015,8 @@ impl<T: Config> Pallet<T> {
 				// Clean up storage before executing call to avoid an possibility of reentrancy
 				// attack.
 				<Multisigs<T>>::remove(&id, call_hash);
-				T::Currency::unreserve(&m.depositor, m.deposit);
+				Deposit::<T>::release(&m.depositor, m.deposit);
+				Self::clear_metadata(&id, &call_hash);
 
 				let result = call.dispatch(RawOrigin::Signed(id.clone()).into());
 				Self::deposit_event(Event::MultisigExecuted {
@@ -599,7 +1069,7 @@ impl<T: Config> Pallet<T> {
 			// Just start the operation by recording it in storage.
 			let deposit = T::DepositBase::get() + T::DepositFactor::get() * threshold.into();
 
-			T::Currency::reserve(&who, deposit)?;
+			Deposit::<T>::hold(&who, deposit)?;
 
 			let initial_approvals =
 				vec![who.clone()].try_into().map_err(|_| Error::<T>::TooManySignatories)?;
@@ -631,6 +1101,38 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Check that the signatories of a named multisig can reach `threshold`, and bound them.
+	fn ensure_valid_signatories(
+		signatories: Vec<T::AccountId>,
+		threshold: u16,
+	) -> Result<BoundedVec<T::AccountId, T::MaxSignatories>, DispatchError> {
+		ensure!(threshold >= 2, Error::<T>::MinimumThreshold);
+		ensure!(threshold as usize <= signatories.len(), Error::<T>::ThresholdTooHigh);
+		Ok(signatories.try_into().map_err(|_| Error::<T>::TooManySignatories)?)
+	}
+
+	/// Check that `who` is the controller of the named multisig `multisig`.
+	fn ensure_controller(
+		who: &T::AccountId,
+		multisig: &T::AccountId,
+	) -> Result<RegisteredMultisig<BalanceOf<T>, T::AccountId, T::MaxSignatories>, DispatchError> {
+		let registered = <Registered<T>>::get(multisig).ok_or(Error::<T>::NotRegistered)?;
+		ensure!(
+			*who == Self::multi_account_id(&registered.signatories, registered.threshold),
+			Error::<T>::NotController
+		);
+		Ok(registered)
+	}
+
+	/// Remove the stored call and the expiry of a multisig operation, if any, and release the
+	/// deposit of the stored call.
+	fn clear_metadata(id: &T::AccountId, call_hash: &CallHash) {
+		if let Some(stored) = <Calls<T>>::take(id, call_hash) {
+			Deposit::<T>::release(&stored.depositor, stored.deposit);
+		}
+		<Expiries<T>>::remove(id, call_hash);
+	}
+
 	/// Check that signatories is sorted and doesn't contain sender, then insert sender.
 	fn ensure_sorted_and_insert(
 		other_signatories: Vec<T::AccountId>,
@@ -663,3 +1165,14 @@ fn get_result_weight(result: DispatchResultWithPostInfo) -> Option<Weight> {
 		Err(err) => err.post_info.actual_weight,
 	}
 }
+
+impl<T: Config> InspectNestedCalls<<T as Config>::RuntimeCall> for Pallet<T> {
+	fn nested_calls(call: &<T as Config>::RuntimeCall) -> Vec<&<T as Config>::RuntimeCall> {
+		match call.is_sub_type() {
+			Some(Call::as_multi { call, .. }) |
+			Some(Call::as_multi_threshold_1 { call, .. }) |
+			Some(Call::as_registered { call, .. }) => vec![&**call],
+			_ => Vec::new(),
+		}
+	}
+}
@@ -52,6 +52,7 @@ impl Contains<RuntimeCall> for TestBaseCallFilter {
 	fn contains(c: &RuntimeCall) -> bool {
 		match *c {
 			RuntimeCall::Balances(_) => true,
+			RuntimeCall::Multisig(pallet_multisig::Call::as_registered { .. }) => true,
 			// Needed for benchmarking
 			RuntimeCall::System(frame_system::Call::remark { .. }) => true,
 			_ => false,
@@ -65,6 +66,7 @@ impl Config for Test {
 	type DepositBase = ConstU64<1>;
 	type DepositFactor = ConstU64<1>;
 	type MaxSignatories = ConstU32<3>;
+	type MaxCallSize = ConstU32<128>;
 	type WeightInfo = ();
 }
 
@@ -686,3 +688,321 @@ fn multisig_handles_no_preimage_after_all_approve() {
 		assert_eq!(Balances::free_balance(6), 15);
 	});
 }
+
+#[test]
+fn note_call_stores_call_until_executed() {
+	use frame::deps::frame_support::view_functions::{execute_view_function, ViewFunction};
+	use view_functions::{PendingMultisig, PendingMultisigs};
+
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(1), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multi, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), multi, 5));
+
+		let call = call_transfer(6, 15);
+		let call_weight = call.get_dispatch_info().call_weight;
+		let hash = blake2_256(&call.encode());
+		assert_noop!(
+			Multisig::note_call(RuntimeOrigin::signed(2), 2, vec![1, 3], call.clone()),
+			Error::<Test>::NotFound,
+		);
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_eq!(PendingMultisigs::<Test>(multi).invoke()[0].call, None);
+
+		assert_ok!(Multisig::note_call(RuntimeOrigin::signed(2), 2, vec![1, 3], call.clone()));
+		System::assert_last_event(
+			pallet_multisig::Event::CallNoted { noting: 2, multisig: multi, call_hash: hash }
+				.into(),
+		);
+		assert_eq!(Balances::reserved_balance(2), 2);
+		assert_noop!(
+			Multisig::note_call(RuntimeOrigin::signed(3), 2, vec![1, 2], call.clone()),
+			Error::<Test>::AlreadyStored,
+		);
+
+		let expected = vec![PendingMultisig {
+			call_hash: hash,
+			when: now(),
+			depositor: 1,
+			approvals: vec![1],
+			call: Some(*call.clone()),
+			expires_at: None,
+		}];
+		assert_eq!(PendingMultisigs::<Test>(multi).invoke(), expected);
+		let output =
+			execute_view_function::<Multisig>(PendingMultisigs::<Test>::id(), multi.encode())
+				.unwrap();
+		assert_eq!(
+			Vec::<PendingMultisig<_, _, RuntimeCall>>::decode(&mut &output[..]),
+			Ok(expected)
+		);
+
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(3),
+			2,
+			vec![1, 2],
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 15);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(PendingMultisigs::<Test>(multi).invoke().is_empty());
+		assert!(!Calls::<Test>::contains_key(multi, hash));
+	});
+}
+
+#[test]
+fn note_call_deposit_is_returned_on_cancel() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		let call = call_transfer(6, 15);
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_ok!(Multisig::note_call(RuntimeOrigin::signed(3), 2, vec![1, 2], call));
+		assert_eq!(Balances::reserved_balance(3), 2);
+
+		assert_ok!(Multisig::cancel_as_multi(RuntimeOrigin::signed(1), 2, vec![2, 3], now(), hash));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert!(!Calls::<Test>::contains_key(multi, hash));
+	});
+}
+
+#[test]
+fn note_call_rejects_large_calls() {
+	new_test_ext().execute_with(|| {
+		let call =
+			Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![0; 128] }));
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_noop!(
+			Multisig::note_call(RuntimeOrigin::signed(2), 2, vec![1, 3], call),
+			Error::<Test>::CallTooLarge,
+		);
+	});
+}
+
+#[test]
+fn registered_multisig_keeps_account_across_rotation() {
+	new_test_ext().execute_with(|| {
+		let name = [1; 32];
+		let multisig = Multisig::registered_account_id(&1, &name);
+		assert_noop!(
+			Multisig::register_multisig(RuntimeOrigin::signed(1), name, 3, vec![2]),
+			Error::<Test>::ThresholdTooHigh,
+		);
+		assert_ok!(Multisig::register_multisig(RuntimeOrigin::signed(1), name, 2, vec![2, 3]));
+		let controller = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		System::assert_last_event(
+			pallet_multisig::Event::MultisigRegistered { creator: 1, multisig, controller }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 4);
+		assert_noop!(
+			Multisig::register_multisig(RuntimeOrigin::signed(1), name, 2, vec![2, 3]),
+			Error::<Test>::AlreadyRegistered,
+		);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), multisig, 8));
+
+		// The current signatories act as the named multisig through its controller.
+		let call = Box::new(RuntimeCall::Multisig(pallet_multisig::Call::as_registered {
+			multisig,
+			call: call_transfer(6, 5),
+		}));
+		let call_weight = call.get_dispatch_info().call_weight;
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(1),
+			2,
+			vec![2, 3],
+			None,
+			call.clone(),
+			Weight::zero()
+		));
+		assert_ok!(Multisig::as_multi(
+			RuntimeOrigin::signed(2),
+			2,
+			vec![1, 3],
+			Some(now()),
+			call,
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 5);
+		assert_eq!(Balances::free_balance(multisig), 3);
+
+		// Rotation needs the approval of the current threshold of signatories.
+		assert_noop!(
+			Multisig::rotate_registered(RuntimeOrigin::signed(1), multisig, 2, vec![3, 4]),
+			Error::<Test>::NotController,
+		);
+		assert_noop!(
+			Multisig::rotate_registered(RuntimeOrigin::signed(controller), multisig, 2, vec![4, 3]),
+			Error::<Test>::SignatoriesOutOfOrder,
+		);
+		assert_ok!(Multisig::rotate_registered(
+			RuntimeOrigin::signed(controller),
+			multisig,
+			2,
+			vec![3, 4]
+		));
+		let new_controller = Multisig::multi_account_id(&[3, 4][..], 2);
+		System::assert_last_event(
+			pallet_multisig::Event::RegisteredMultisigRotated {
+				multisig,
+				controller: new_controller,
+			}
+			.into(),
+		);
+		// The deposit of the removed signatory is returned.
+		assert_eq!(Balances::reserved_balance(1), 3);
+
+		assert_noop!(
+			Multisig::as_registered(
+				RuntimeOrigin::signed(controller),
+				multisig,
+				call_transfer(6, 5)
+			),
+			Error::<Test>::NotController,
+		);
+		assert_ok!(Multisig::as_registered(
+			RuntimeOrigin::signed(new_controller),
+			multisig,
+			call_transfer(6, 2)
+		));
+		assert_eq!(Balances::free_balance(6), 7);
+
+		// The account has to be emptied before it can be unregistered, so that its creator cannot
+		// register it again and take over what is left in it.
+		assert_noop!(
+			Multisig::unregister_multisig(RuntimeOrigin::signed(new_controller), multisig),
+			Error::<Test>::RegisteredAccountInUse,
+		);
+		assert_ok!(Multisig::as_registered(
+			RuntimeOrigin::signed(new_controller),
+			multisig,
+			call_transfer(6, 1)
+		));
+		assert!(!System::account_exists(&multisig));
+		assert_ok!(Multisig::unregister_multisig(RuntimeOrigin::signed(new_controller), multisig));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_noop!(
+			Multisig::as_registered(
+				RuntimeOrigin::signed(new_controller),
+				multisig,
+				call_transfer(6, 5)
+			),
+			Error::<Test>::NotRegistered,
+		);
+	});
+}
+
+#[test]
+fn growing_a_registered_multisig_moves_its_deposit_to_the_controller() {
+	new_test_ext().execute_with(|| {
+		let name = [1; 32];
+		let multisig = Multisig::registered_account_id(&1, &name);
+		assert_ok!(Multisig::register_multisig(RuntimeOrigin::signed(1), name, 2, vec![2]));
+		assert_eq!(Balances::reserved_balance(1), 3);
+		let controller = Multisig::multi_account_id(&[1, 2][..], 2);
+
+		// The controller cannot afford the larger deposit.
+		assert_noop!(
+			Multisig::rotate_registered(
+				RuntimeOrigin::signed(controller),
+				multisig,
+				2,
+				vec![1, 2, 3]
+			),
+			pallet_balances::Error::<Test, _>::InsufficientBalance,
+		);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(4), controller, 6));
+		assert_ok!(Multisig::rotate_registered(
+			RuntimeOrigin::signed(controller),
+			multisig,
+			2,
+			vec![1, 2, 3]
+		));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(controller), 4);
+
+		// Shrinking it again refunds the controller who paid for it.
+		let new_controller = Multisig::multi_account_id(&[1, 2, 3][..], 2);
+		assert_ok!(Multisig::rotate_registered(
+			RuntimeOrigin::signed(new_controller),
+			multisig,
+			2,
+			vec![2, 3]
+		));
+		assert_eq!(Balances::reserved_balance(controller), 3);
+		assert_eq!(Balances::reserved_balance(new_controller), 0);
+	});
+}
+
+#[test]
+fn expired_multisig_can_be_closed_by_anyone() {
+	new_test_ext().execute_with(|| {
+		let multi = Multisig::multi_account_id(&[1, 2, 3][..], 3);
+		let call = call_transfer(6, 15);
+		let hash = blake2_256(&call.encode());
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(1),
+			3,
+			vec![2, 3],
+			None,
+			hash,
+			Weight::zero()
+		));
+		assert_ok!(Multisig::note_call(RuntimeOrigin::signed(2), 3, vec![1, 3], call));
+		assert_eq!(Balances::reserved_balance(1), 4);
+		assert_eq!(Balances::reserved_balance(2), 2);
+
+		assert_noop!(
+			Multisig::close_expired(RuntimeOrigin::signed(4), multi, hash),
+			Error::<Test>::NotExpired,
+		);
+		assert_noop!(
+			Multisig::set_expiry(RuntimeOrigin::signed(2), 3, vec![1, 3], now(), hash, 10),
+			Error::<Test>::NotOwner,
+		);
+		assert_ok!(Multisig::set_expiry(RuntimeOrigin::signed(1), 3, vec![2, 3], now(), hash, 10));
+		System::assert_last_event(
+			pallet_multisig::Event::ExpirySet { multisig: multi, call_hash: hash, expires_at: 11 }
+				.into(),
+		);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Multisig::close_expired(RuntimeOrigin::signed(4), multi, hash),
+			Error::<Test>::NotExpired,
+		);
+
+		System::set_block_number(11);
+		assert_ok!(Multisig::close_expired(RuntimeOrigin::signed(4), multi, hash));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(!Multisigs::<Test>::contains_key(multi, hash));
+		assert!(!Calls::<Test>::contains_key(multi, hash));
+		assert!(!Expiries::<Test>::contains_key(multi, hash));
+	});
+}
@@ -0,0 +1,82 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! View functions of the multisig pallet.
+
+use super::*;
+use frame::deps::frame_support::view_functions::{
+	DispatchViewFunction, ViewFunction, ViewFunctionDispatchError, ViewFunctionId,
+};
+
+/// An open multisig operation as returned by [`PendingMultisigs`].
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct PendingMultisig<BlockNumber, AccountId, Call> {
+	/// The hash of the call to be dispatched.
+	pub call_hash: CallHash,
+	/// The extrinsic when the multisig operation was opened.
+	pub when: Timepoint<BlockNumber>,
+	/// The account who opened it.
+	pub depositor: AccountId,
+	/// The approvals achieved so far, including the depositor.
+	pub approvals: Vec<AccountId>,
+	/// The call to be dispatched, if it was stored with `note_call`.
+	pub call: Option<Call>,
+	/// The block at which the operation expires, if it was set with `set_expiry`.
+	pub expires_at: Option<BlockNumber>,
+}
+
+/// All open multisig operations of a multisig account.
+#[derive(Encode, Decode)]
+pub struct PendingMultisigs<T: Config>(pub T::AccountId);
+
+impl<T: Config> ViewFunction for PendingMultisigs<T> {
+	type ReturnType =
+		Vec<PendingMultisig<BlockNumberFor<T>, T::AccountId, <T as Config>::RuntimeCall>>;
+
+	fn id() -> ViewFunctionId {
+		ViewFunctionId::new::<Pallet<T>>("pending_multisigs")
+	}
+
+	fn invoke(self) -> Self::ReturnType {
+		Multisigs::<T>::iter_prefix(&self.0)
+			.map(|(call_hash, m)| PendingMultisig {
+				call_hash,
+				when: m.when,
+				depositor: m.depositor,
+				approvals: m.approvals.into_inner(),
+				call: Calls::<T>::get(&self.0, call_hash).and_then(|stored| {
+					<T as Config>::RuntimeCall::decode(&mut &stored.call[..]).ok()
+				}),
+				expires_at: Expiries::<T>::get(&self.0, call_hash),
+			})
+			.collect()
+	}
+}
+
+impl<T: Config> DispatchViewFunction for Pallet<T> {
+	fn dispatch_view_function<O: codec::Output>(
+		id: &ViewFunctionId,
+		input: &mut &[u8],
+		output: &mut O,
+	) -> Result<(), ViewFunctionDispatchError> {
+		if *id == PendingMultisigs::<T>::id() {
+			PendingMultisigs::<T>::execute(input, output)
+		} else {
+			Err(ViewFunctionDispatchError::NotFound(*id))
+		}
+	}
+}
@@ -30,7 +30,7 @@ use frame_support::{
 	BoundedVec,
 };
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin as SystemOrigin};
-use sp_runtime::traits::{Bounded, One};
+use sp_runtime::traits::{BlakeTwo256, Bounded, Hash, One};
 
 use crate::Pallet as Nfts;
 
@@ -64,6 +64,21 @@ fn add_collection_metadata<T: Config<I>, I: 'static>() -> (T::AccountId, Account
 	(caller, caller_lookup)
 }
 
+fn add_collection_royalty<T: Config<I>, I: 'static>() -> T::AccountId {
+	let caller = Collection::<T, I>::get(T::Helper::collection(0)).unwrap().owner;
+	if caller != whitelisted_caller() {
+		whitelist_account!(caller);
+	}
+	let recipient: T::AccountId = account("recipient", 0, SEED);
+	assert_ok!(Nfts::<T, I>::set_collection_royalty(
+		SystemOrigin::Signed(caller).into(),
+		T::Helper::collection(0),
+		T::Lookup::unlookup(recipient.clone()),
+		Permill::from_percent(10),
+	));
+	recipient
+}
+
 fn mint_item<T: Config<I>, I: 'static>(
 	index: u16,
 ) -> (T::ItemId, T::AccountId, AccountIdLookupOf<T>) {
@@ -669,6 +684,7 @@ benchmarks_instance_pallet! {
 		let price = ItemPrice::<T, I>::from(0u32);
 		let origin = SystemOrigin::Signed(seller.clone()).into();
 		Nfts::<T, I>::set_price(origin, collection, item, Some(price), Some(buyer_lookup))?;
+		add_collection_royalty::<T, I>();
 		T::Currency::make_free_balance_be(&buyer, DepositBalanceOf::<T, I>::max_value());
 	}: _(SystemOrigin::Signed(buyer.clone()), collection, item, price)
 	verify {
@@ -763,6 +779,7 @@ benchmarks_instance_pallet! {
 		let origin = SystemOrigin::Signed(caller.clone());
 		frame_system::Pallet::<T>::set_block_number(One::one());
 		Nfts::<T, I>::transfer(origin.clone().into(), collection, item2, target_lookup)?;
+		add_collection_royalty::<T, I>();
 		Nfts::<T, I>::create_swap(
 			origin.clone().into(),
 			collection,
@@ -878,5 +895,102 @@ benchmarks_instance_pallet! {
 		);
 	}
 
+	set_collection_royalty {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
+		let royalty = Permill::from_percent(10);
+	}: _(SystemOrigin::Signed(caller), collection, recipient_lookup, royalty)
+	verify {
+		assert_last_event::<T, I>(Event::CollectionRoyaltySet { collection, recipient, royalty }.into());
+	}
+
+	clear_collection_royalty {
+		let (collection, caller, _) = create_collection::<T, I>();
+		add_collection_royalty::<T, I>();
+	}: _(SystemOrigin::Signed(caller), collection)
+	verify {
+		assert_last_event::<T, I>(Event::CollectionRoyaltyCleared { collection }.into());
+	}
+
+	lock_collection_royalty {
+		let (collection, caller, _) = create_collection::<T, I>();
+	}: _(SystemOrigin::Signed(caller), collection)
+	verify {
+		assert_last_event::<T, I>(Event::CollectionRoyaltyLocked { collection }.into());
+	}
+
+	set_lazy_mint_root {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let root = H256::repeat_byte(1);
+		let items = u32::MAX;
+		let duration = T::MaxDeadlineDuration::get();
+		let deadline = frame_system::Pallet::<T>::block_number() + duration;
+	}: _(SystemOrigin::Signed(caller), collection, root, items, duration)
+	verify {
+		assert_last_event::<T, I>(Event::LazyMintRootSet { collection, root, items, deadline }.into());
+	}
+
+	clear_lazy_mint_root {
+		let (collection, caller, _) = create_collection::<T, I>();
+		assert_ok!(Nfts::<T, I>::set_lazy_mint_root(
+			SystemOrigin::Signed(caller.clone()).into(),
+			collection,
+			H256::repeat_byte(1),
+			u32::MAX,
+			T::MaxDeadlineDuration::get(),
+		));
+	}: _(SystemOrigin::Signed(caller), collection)
+	verify {
+		assert_last_event::<T, I>(Event::LazyMintRootCleared { collection }.into());
+	}
+
+	claim_lazy_mint {
+		let n in 1 .. 32;
+		let (collection, caller, _) = create_collection::<T, I>();
+		let item = T::Helper::item(0);
+		let target: T::AccountId = account("target", 0, SEED);
+		T::Currency::make_free_balance_be(&target, DepositBalanceOf::<T, I>::max_value());
+
+		let metadata = vec![0u8; T::StringLimit::get() as usize];
+		let mint_data = LazyMintItem {
+			item,
+			metadata: metadata.clone(),
+			only_account: Some(target.clone()),
+			mint_price: Some(DepositBalanceOf::<T, I>::min_value()),
+		};
+		// The leftmost leaf of a tree with `u32::MAX` leaves is concatenated with its right sibling
+		// on every level, which allows building a proof of any depth up to 32.
+		let proof: Vec<H256> = (0..n).map(|i| H256::repeat_byte(i as u8)).collect();
+		let root = proof.iter().fold(BlakeTwo256::hash(&mint_data.encode()), |node, sibling| {
+			BlakeTwo256::hash(&[node.as_bytes(), sibling.as_bytes()].concat())
+		});
+		assert_ok!(Nfts::<T, I>::set_lazy_mint_root(
+			SystemOrigin::Signed(caller).into(),
+			collection,
+			root,
+			u32::MAX,
+			T::MaxDeadlineDuration::get(),
+		));
+		let proof = LazyMintProof { leaf_index: 0, proof: proof.try_into().unwrap() };
+	}: _(SystemOrigin::Signed(target), collection, mint_data, proof)
+	verify {
+		let metadata: BoundedVec<_, _> = metadata.try_into().unwrap();
+		assert_last_event::<T, I>(Event::ItemMetadataSet { collection, item, data: metadata }.into());
+	}
+
+	transfer_with_unlock_at {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+		let unlock_at = frame_system::Pallet::<T>::block_number() + One::one();
+	}: _(SystemOrigin::Signed(caller), collection, item, target_lookup, unlock_at)
+	verify {
+		assert_last_event::<T, I>(Event::ItemTimeLocked { collection, item, unlock_at }.into());
+	}
+
 	impl_benchmark_test_suite!(Nfts, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -21,10 +21,7 @@
 //! to have the functionality defined in this module.
 
 use crate::*;
-use frame_support::{
-	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement::KeepAlive},
-};
+use frame_support::pallet_prelude::*;
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Creates a new swap offer for the specified item.
@@ -146,7 +143,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// `send_item_id`, `receive_collection_id`, and `receive_item_id`. The `caller` account must be
 	/// the owner of the item specified by `send_collection_id` and `send_item_id`. If the claimed
 	/// swap has an associated `price`, it will be transferred between the owners of the two items
-	/// based on the `price.direction`. After the swap is completed, the function emits the
+	/// based on the `price.direction`, less the royalty of the collection of the item given up by
+	/// the receiver of the price. After the swap is completed, the function emits the
 	/// `SwapClaimed` event.
 	///
 	/// - `caller`: The account claiming the swap offer, which must be the owner of the sent item.
@@ -192,17 +190,20 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		if let Some(ref price) = swap.price {
 			match price.direction {
-				PriceDirection::Send => T::Currency::transfer(
+				// The royalty is paid on the item given up by the receiver of the price.
+				PriceDirection::Send => Self::do_pay_price(
+					send_collection_id,
+					send_item_id,
 					&receive_item.owner,
 					&send_item.owner,
 					price.amount,
-					KeepAlive,
 				)?,
-				PriceDirection::Receive => T::Currency::transfer(
+				PriceDirection::Receive => Self::do_pay_price(
+					receive_collection_id,
+					receive_item_id,
 					&send_item.owner,
 					&receive_item.owner,
 					price.amount,
-					KeepAlive,
 				)?,
 			};
 		}
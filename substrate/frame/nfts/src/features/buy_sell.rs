@@ -23,7 +23,7 @@
 use crate::*;
 use frame_support::{
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement, ExistenceRequirement::KeepAlive},
+	traits::{Currency, ExistenceRequirement::KeepAlive},
 };
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -116,10 +116,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	///
 	/// This function is used to buy an item from the specified `collection`. The `buyer` account
 	/// will attempt to buy the item with the provided `bid_price`. The item's current owner will
-	/// receive the bid price if it is equal to or higher than the item's set price. If
-	/// `whitelisted_buyer` is specified in the item's price information, only that account is
-	/// allowed to buy the item. If the item is not for sale, or the bid price is too low, the
-	/// function will return an error.
+	/// receive the bid price if it is equal to or higher than the item's set price, less the
+	/// royalty of the collection if it has one. If `whitelisted_buyer` is specified in the item's
+	/// price information, only that account is allowed to buy the item. If the item is not for
+	/// sale, or the bid price is too low, the function will return an error.
 	///
 	/// - `collection`: The identifier of the collection containing the item to be bought.
 	/// - `item`: The identifier of the item to be bought.
@@ -148,12 +148,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ensure!(only_buyer == buyer, Error::<T, I>::NoPermission);
 		}
 
-		T::Currency::transfer(
-			&buyer,
-			&details.owner,
-			price_info.0,
-			ExistenceRequirement::KeepAlive,
-		)?;
+		Self::do_pay_price(collection, item, &buyer, &details.owner, price_info.0)?;
 
 		let old_owner = details.owner.clone();
 
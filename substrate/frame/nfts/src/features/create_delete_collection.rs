@@ -126,6 +126,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			}
 
 			CollectionMetadataOf::<T, I>::remove(&collection);
+			CollectionRoyaltyOf::<T, I>::remove(&collection);
+			LazyMintRootOf::<T, I>::remove(&collection);
 			Self::clear_roles(&collection)?;
 
 			for (_, (_, deposit)) in Attribute::<T, I>::drain_prefix((&collection,)) {
@@ -205,12 +205,15 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// This function returns a dispatch error in the following cases:
 	/// - If the collection ID is invalid ([`UnknownCollection`](crate::Error::UnknownCollection)).
 	/// - If the item is locked ([`ItemLocked`](crate::Error::ItemLocked)).
+	/// - If the item's unlock block hasn't been reached yet
+	///   ([`ItemTimeLocked`](crate::Error::ItemTimeLocked)).
 	pub fn do_burn(
 		collection: T::CollectionId,
 		item: T::ItemId,
 		with_details: impl FnOnce(&ItemDetailsFor<T, I>) -> DispatchResult,
 	) -> DispatchResult {
 		ensure!(!T::Locker::is_locked(collection, item), Error::<T, I>::ItemLocked);
+		Self::ensure_not_time_locked(&collection, &item)?;
 		ensure!(
 			!Self::has_system_attribute(&collection, &item, PalletAttributes::TransferDisabled)?,
 			Error::<T, I>::ItemLocked
@@ -262,6 +265,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		ItemPriceOf::<T, I>::remove(&collection, &item);
 		PendingSwapOf::<T, I>::remove(&collection, &item);
 		ItemAttributesApprovalsOf::<T, I>::remove(&collection, &item);
+		ItemUnlockAt::<T, I>::remove(&collection, &item);
 
 		if remove_config {
 			ItemConfigOf::<T, I>::remove(&collection, &item);
@@ -0,0 +1,190 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module contains helper functions to commit to and lazily mint the items of a collection in
+//! the NFTs pallet.
+
+use crate::*;
+use frame_support::{pallet_prelude::*, traits::ExistenceRequirement};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Sets the lazy mint root of the specified collection.
+	///
+	/// - `maybe_check_origin`: An optional account ID that is allowed to set the root. If `None`,
+	///   it's considered the root account.
+	/// - `collection`: The ID of the collection for which to set the root.
+	/// - `root`: The root of the tree of items which can be lazily minted.
+	/// - `items`: The number of leaves of the tree.
+	/// - `duration`: The number of blocks after which the root expires.
+	///
+	/// Emits `LazyMintRootSet` event upon successful setting of the root.
+	/// Returns `Ok(())` on success, or one of the following dispatch errors:
+	/// - `NoPermission`: The caller is not the Issuer of the collection.
+	/// - `UnknownCollection`: The specified collection does not exist.
+	/// - `WrongDuration`: The `duration` is greater than `MaxDeadlineDuration`.
+	pub(crate) fn do_set_lazy_mint_root(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		root: H256,
+		items: u32,
+		duration: frame_system::pallet_prelude::BlockNumberFor<T>,
+	) -> DispatchResult {
+		if let Some(check_origin) = &maybe_check_origin {
+			ensure!(
+				Self::has_role(&collection, check_origin, CollectionRole::Issuer),
+				Error::<T, I>::NoPermission
+			);
+		}
+		ensure!(Collection::<T, I>::contains_key(&collection), Error::<T, I>::UnknownCollection);
+		ensure!(duration <= T::MaxDeadlineDuration::get(), Error::<T, I>::WrongDuration);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let deadline = duration.saturating_add(now);
+		LazyMintRootOf::<T, I>::insert(&collection, LazyMintRoot { root, items, deadline });
+		Self::deposit_event(Event::LazyMintRootSet { collection, root, items, deadline });
+		Ok(())
+	}
+
+	/// Clears the lazy mint root of the specified collection.
+	///
+	/// - `maybe_check_origin`: An optional account ID that is allowed to clear the root. If `None`,
+	///   it's considered the root account.
+	/// - `collection`: The ID of the collection for which to clear the root.
+	///
+	/// Emits `LazyMintRootCleared` event upon successful clearing of the root.
+	/// Returns `Ok(())` on success, or one of the following dispatch errors:
+	/// - `NoPermission`: The caller is not the Issuer of the collection.
+	/// - `LazyMintRootNotFound`: The collection has no lazy mint root.
+	pub(crate) fn do_clear_lazy_mint_root(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+	) -> DispatchResult {
+		if let Some(check_origin) = &maybe_check_origin {
+			ensure!(
+				Self::has_role(&collection, check_origin, CollectionRole::Issuer),
+				Error::<T, I>::NoPermission
+			);
+		}
+
+		LazyMintRootOf::<T, I>::take(&collection).ok_or(Error::<T, I>::LazyMintRootNotFound)?;
+		Self::deposit_event(Event::LazyMintRootCleared { collection });
+		Ok(())
+	}
+
+	/// Mints an item committed to by the lazy mint root of a collection.
+	///
+	/// The `mint_to` account receives the newly minted item and pays the deposits of the item and
+	/// of its metadata, as well as the `mint_price` of the item, if any. The minting window of the
+	/// collection's mint settings is respected. Each leaf can only be claimed once, so a burned
+	/// item can't be minted again from the same definition.
+	///
+	/// - `mint_to`: The account that receives the newly minted item.
+	/// - `collection`: The ID of the collection of the item.
+	/// - `mint_data`: The definition of the item, committed to by the root.
+	/// - `proof`: The proof that `mint_data` is a leaf of the root.
+	///
+	/// Returns `Ok(())` on success, or one of the following dispatch errors, besides the errors of
+	/// minting and setting the metadata of an item:
+	/// - `LazyMintRootNotFound`: The collection has no lazy mint root.
+	/// - `DeadlineExpired`: The lazy mint root of the collection has expired.
+	/// - `AlreadyClaimed`: The leaf was already claimed.
+	/// - `BadLazyMintProof`: The proof does not match the root.
+	/// - `NoAdmin`: The item has metadata, but the collection has no Admin to set it.
+	pub(crate) fn do_claim_lazy_mint(
+		mint_to: T::AccountId,
+		collection: T::CollectionId,
+		mint_data: LazyMintItemOf<T, I>,
+		proof: LazyMintProof,
+	) -> DispatchResult {
+		let LazyMintRoot { root, items, deadline } =
+			LazyMintRootOf::<T, I>::get(&collection).ok_or(Error::<T, I>::LazyMintRootNotFound)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(now <= deadline, Error::<T, I>::DeadlineExpired);
+
+		let leaf = mint_data.encode();
+		let leaf_hash = BlakeTwo256::hash(&leaf);
+		ensure!(
+			!LazyMintClaimed::<T, I>::contains_key(&collection, &leaf_hash),
+			Error::<T, I>::AlreadyClaimed
+		);
+		ensure!(
+			binary_merkle_tree::verify_proof::<BlakeTwo256, _, _>(
+				&root,
+				proof.proof,
+				items,
+				proof.leaf_index,
+				&leaf,
+			),
+			Error::<T, I>::BadLazyMintProof
+		);
+
+		let LazyMintItem { item, metadata, only_account, mint_price } = mint_data;
+		let metadata = Self::construct_metadata(metadata)?;
+		if let Some(account) = only_account {
+			ensure!(account == mint_to, Error::<T, I>::WrongOrigin);
+		}
+		let maybe_admin = if metadata.is_empty() {
+			None
+		} else {
+			Some(
+				Self::find_account_by_role(&collection, CollectionRole::Admin)
+					.ok_or(Error::<T, I>::NoAdmin)?,
+			)
+		};
+
+		let item_config = ItemConfig { settings: Self::get_default_item_settings(&collection)? };
+		Self::do_mint(
+			collection,
+			item,
+			Some(mint_to.clone()),
+			mint_to.clone(),
+			item_config,
+			|collection_details, collection_config| {
+				let mint_settings = collection_config.mint_settings;
+				if let Some(start_block) = mint_settings.start_block {
+					ensure!(start_block <= now, Error::<T, I>::MintNotStarted);
+				}
+				if let Some(end_block) = mint_settings.end_block {
+					ensure!(end_block >= now, Error::<T, I>::MintEnded);
+				}
+
+				if let Some(price) = mint_price {
+					T::Currency::transfer(
+						&mint_to,
+						&collection_details.owner,
+						price,
+						ExistenceRequirement::KeepAlive,
+					)?;
+				}
+				Ok(())
+			},
+		)?;
+
+		if let Some(admin_account) = maybe_admin {
+			Self::do_set_item_metadata(
+				Some(admin_account),
+				collection,
+				item,
+				metadata,
+				Some(mint_to),
+			)?;
+		}
+		LazyMintClaimed::<T, I>::insert(&collection, leaf_hash, ());
+		Ok(())
+	}
+}
@@ -0,0 +1,159 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module contains helper functions to configure and pay the royalties of collections in
+//! the NFTs pallet.
+
+use crate::*;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{Currency, ExistenceRequirement::KeepAlive},
+};
+use sp_runtime::PerThing;
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Sets the royalty of the specified collection.
+	///
+	/// - `maybe_check_origin`: An optional account ID that is allowed to set the royalty. If
+	///   `None`, it's considered the root account.
+	/// - `collection`: The ID of the collection for which to set the royalty.
+	/// - `royalty`: The royalty to set.
+	///
+	/// Emits `CollectionRoyaltySet` event upon successful setting of the royalty.
+	/// Returns `Ok(())` on success, or one of the following dispatch errors:
+	/// - `NoPermission`: The caller is not the Admin of the collection.
+	/// - `NoConfig`: The specified collection does not exist.
+	/// - `LockedCollectionRoyalty`: The royalty of the collection is locked.
+	pub(crate) fn do_set_collection_royalty(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+		royalty: CollectionRoyalty<T::AccountId>,
+	) -> DispatchResult {
+		Self::ensure_royalty_unlocked(maybe_check_origin, &collection)?;
+
+		CollectionRoyaltyOf::<T, I>::insert(&collection, &royalty);
+		Self::deposit_event(Event::CollectionRoyaltySet {
+			collection,
+			recipient: royalty.recipient,
+			royalty: royalty.royalty,
+		});
+		Ok(())
+	}
+
+	/// Clears the royalty of the specified collection.
+	///
+	/// - `maybe_check_origin`: An optional account ID that is allowed to clear the royalty. If
+	///   `None`, it's considered the root account.
+	/// - `collection`: The ID of the collection for which to clear the royalty.
+	///
+	/// Emits `CollectionRoyaltyCleared` event upon successful clearing of the royalty.
+	/// Returns `Ok(())` on success, or one of the following dispatch errors:
+	/// - `NoPermission`: The caller is not the Admin of the collection.
+	/// - `NoConfig`: The specified collection does not exist.
+	/// - `LockedCollectionRoyalty`: The royalty of the collection is locked.
+	/// - `RoyaltyNotFound`: The collection has no royalty.
+	pub(crate) fn do_clear_collection_royalty(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: T::CollectionId,
+	) -> DispatchResult {
+		Self::ensure_royalty_unlocked(maybe_check_origin, &collection)?;
+
+		CollectionRoyaltyOf::<T, I>::take(&collection).ok_or(Error::<T, I>::RoyaltyNotFound)?;
+		Self::deposit_event(Event::CollectionRoyaltyCleared { collection });
+		Ok(())
+	}
+
+	/// Locks the royalty of the specified collection.
+	///
+	/// The origin must have the `Admin` role within the collection. This function disables the
+	/// `UnlockedRoyalty` setting of the collection, preventing any further change to its royalty.
+	///
+	/// - `origin`: The account attempting to lock the royalty.
+	/// - `collection`: The ID of the collection for which to lock the royalty.
+	pub(crate) fn do_lock_collection_royalty(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+	) -> DispatchResult {
+		ensure!(
+			Self::has_role(&collection, &origin, CollectionRole::Admin),
+			Error::<T, I>::NoPermission
+		);
+		CollectionConfigOf::<T, I>::try_mutate(collection, |maybe_config| {
+			let config = maybe_config.as_mut().ok_or(Error::<T, I>::NoConfig)?;
+			config.disable_setting(CollectionSetting::UnlockedRoyalty);
+
+			Self::deposit_event(Event::<T, I>::CollectionRoyaltyLocked { collection });
+			Ok(())
+		})
+	}
+
+	/// Pays the `price` of an item from the `buyer` to the `seller`.
+	///
+	/// If the item's collection has a royalty, the royalty is deducted from the price and paid
+	/// to its recipient, emitting a `RoyaltyPaid` event. The rest of the price is paid to the
+	/// `seller`. A royalty too small to create the account of its recipient is paid to the
+	/// `seller` as well, so that it doesn't fail the sale.
+	///
+	/// - `collection`: The identifier of the collection containing the sold item.
+	/// - `item`: The identifier of the sold item.
+	/// - `buyer`: The account paying the price.
+	/// - `seller`: The account receiving the price.
+	/// - `price`: The price of the item.
+	pub(crate) fn do_pay_price(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		buyer: &T::AccountId,
+		seller: &T::AccountId,
+		price: ItemPrice<T, I>,
+	) -> DispatchResult {
+		let mut remaining = price;
+		if let Some(CollectionRoyalty { recipient, royalty }) =
+			CollectionRoyaltyOf::<T, I>::get(&collection)
+		{
+			let amount = royalty.mul_floor(price);
+			// A royalty which can't create the recipient's account stays with the seller.
+			let below_minimum = T::Currency::total_balance(&recipient).saturating_add(amount) <
+				T::Currency::minimum_balance();
+			if !amount.is_zero() && !below_minimum && recipient != *seller {
+				T::Currency::transfer(buyer, &recipient, amount, KeepAlive)?;
+				remaining.saturating_reduce(amount);
+				Self::deposit_event(Event::RoyaltyPaid { collection, item, recipient, amount });
+			}
+		}
+		T::Currency::transfer(buyer, seller, remaining, KeepAlive)
+	}
+
+	fn ensure_royalty_unlocked(
+		maybe_check_origin: Option<T::AccountId>,
+		collection: &T::CollectionId,
+	) -> DispatchResult {
+		if let Some(check_origin) = &maybe_check_origin {
+			ensure!(
+				Self::has_role(collection, check_origin, CollectionRole::Admin),
+				Error::<T, I>::NoPermission
+			);
+		}
+
+		let is_root = maybe_check_origin.is_none();
+		let collection_config = Self::get_collection_config(collection)?;
+		ensure!(
+			is_root || collection_config.is_setting_enabled(CollectionSetting::UnlockedRoyalty),
+			Error::<T, I>::LockedCollectionRoyalty
+		);
+		Ok(())
+	}
+}
@@ -20,6 +20,7 @@
 
 use crate::*;
 use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::BlockNumberFor;
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Transfer an NFT to the specified destination account.
@@ -41,6 +42,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// - If the item ID is invalid ([`UnknownItem`](crate::Error::UnknownItem)).
 	/// - If the item is locked or transferring it is disabled
 	///   ([`ItemLocked`](crate::Error::ItemLocked)).
+	/// - If the item's unlock block hasn't been reached yet
+	///   ([`ItemTimeLocked`](crate::Error::ItemTimeLocked)).
 	/// - If the collection or item is non-transferable
 	///   ([`ItemsNonTransferable`](crate::Error::ItemsNonTransferable)).
 	pub fn do_transfer(
@@ -59,6 +62,9 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		// Ensure the item is not locked.
 		ensure!(!T::Locker::is_locked(collection, item), Error::<T, I>::ItemLocked);
 
+		// Ensure the item's unlock block, if any, has been reached.
+		Self::ensure_not_time_locked(&collection, &item)?;
+
 		// Ensure the item is not transfer disabled on the system level attribute.
 		ensure!(
 			!Self::has_system_attribute(&collection, &item, PalletAttributes::TransferDisabled)?,
@@ -101,6 +107,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Item::<T, I>::insert(&collection, &item, &details);
 		ItemPriceOf::<T, I>::remove(&collection, &item);
 		PendingSwapOf::<T, I>::remove(&collection, &item);
+		ItemUnlockAt::<T, I>::remove(&collection, &item);
 
 		// Emit `Transferred` event.
 		Self::deposit_event(Event::Transferred {
@@ -112,6 +119,61 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	/// Transfer an NFT to the specified destination account and keep it locked until the
+	/// `unlock_at` block.
+	///
+	/// - `origin`: The account requesting the transfer. Must be either the owner of the item or its
+	///   approved delegate.
+	/// - `collection`: The ID of the collection to which the NFT belongs.
+	/// - `item`: The ID of the NFT to transfer.
+	/// - `dest`: The destination account to which the NFT will be transferred.
+	/// - `unlock_at`: The block from which the NFT can be transferred or burned again. Must be at
+	///   most `MaxDeadlineDuration` blocks in the future.
+	///
+	/// Until the unlock block is reached, the NFT can be neither transferred nor burned, which
+	/// makes it possible to build escrow and rental arrangements on top of it. Bounding the lock
+	/// duration keeps a sender from pushing a permanently locked item onto the recipient.
+	pub(crate) fn do_transfer_with_unlock_at(
+		origin: T::AccountId,
+		collection: T::CollectionId,
+		item: T::ItemId,
+		dest: T::AccountId,
+		unlock_at: BlockNumberFor<T>,
+	) -> DispatchResult {
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(unlock_at > now, Error::<T, I>::WrongDuration);
+		ensure!(
+			unlock_at.saturating_sub(now) <= T::MaxDeadlineDuration::get(),
+			Error::<T, I>::WrongDuration
+		);
+
+		Self::do_transfer(collection, item, dest, |_, details| {
+			if details.owner != origin {
+				let deadline = details.approvals.get(&origin).ok_or(Error::<T, I>::NoPermission)?;
+				if let Some(d) = deadline {
+					ensure!(now <= *d, Error::<T, I>::ApprovalExpired);
+				}
+			}
+			Ok(())
+		})?;
+
+		ItemUnlockAt::<T, I>::insert(&collection, &item, unlock_at);
+		Self::deposit_event(Event::ItemTimeLocked { collection, item, unlock_at });
+		Ok(())
+	}
+
+	/// Ensures that the unlock block of an NFT, if any, has been reached.
+	pub(crate) fn ensure_not_time_locked(
+		collection: &T::CollectionId,
+		item: &T::ItemId,
+	) -> DispatchResult {
+		if let Some(unlock_at) = ItemUnlockAt::<T, I>::get(collection, item) {
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now >= unlock_at, Error::<T, I>::ItemTimeLocked);
+		}
+		Ok(())
+	}
+
 	/// Transfer ownership of a collection to another account.
 	///
 	/// - `origin`: The account requesting the transfer.
@@ -123,6 +123,9 @@ impl<T: Config<I>, I: 'static> Inspect<<T as SystemConfig>::AccountId> for Palle
 			Ok(transfer_disabled) if transfer_disabled => return false,
 			_ => (),
 		}
+		if Self::ensure_not_time_locked(collection, item).is_err() {
+			return false
+		}
 		match (
 			CollectionConfigOf::<T, I>::get(collection),
 			ItemConfigOf::<T, I>::get(collection, item),
@@ -57,9 +57,10 @@ use frame_support::traits::{
 	ReservableCurrency,
 };
 use frame_system::Config as SystemConfig;
+use sp_core::H256;
 use sp_runtime::{
 	traits::{IdentifyAccount, Saturating, StaticLookup, Verify, Zero},
-	RuntimeDebug,
+	Permill, RuntimeDebug,
 };
 
 pub use pallet::*;
@@ -410,6 +411,46 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The royalty paid on the sales of the items of a collection.
+	#[pallet::storage]
+	pub type CollectionRoyaltyOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		CollectionRoyalty<T::AccountId>,
+		OptionQuery,
+	>;
+
+	/// The block until which an item can't be transferred or burned.
+	#[pallet::storage]
+	pub type ItemUnlockAt<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// The commitment to the items of a collection which can be lazily minted.
+	#[pallet::storage]
+	pub type LazyMintRootOf<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		LazyMintRoot<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// The hashes of the lazy mint leaves of a collection which were already claimed.
+	///
+	/// Kept across lazy mint roots, so that an item definition can only be claimed once, even if
+	/// the item is burned or a later root commits to it again.
+	#[pallet::storage]
+	pub type LazyMintClaimed<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Blake2_128Concat, T::CollectionId, Identity, H256, (), OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -590,6 +631,38 @@ pub mod pallet {
 			attribute: PalletAttributes<T::CollectionId>,
 			value: BoundedVec<u8, T::ValueLimit>,
 		},
+		/// The royalty of a `collection` was set.
+		CollectionRoyaltySet {
+			collection: T::CollectionId,
+			recipient: T::AccountId,
+			royalty: Permill,
+		},
+		/// The royalty of a `collection` was cleared.
+		CollectionRoyaltyCleared { collection: T::CollectionId },
+		/// The royalty of a `collection` was locked.
+		CollectionRoyaltyLocked { collection: T::CollectionId },
+		/// A royalty was paid on the sale of an `item`.
+		RoyaltyPaid {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			recipient: T::AccountId,
+			amount: ItemPrice<T, I>,
+		},
+		/// The lazy mint root of a `collection` was set.
+		LazyMintRootSet {
+			collection: T::CollectionId,
+			root: H256,
+			items: u32,
+			deadline: BlockNumberFor<T>,
+		},
+		/// The lazy mint root of a `collection` was cleared.
+		LazyMintRootCleared { collection: T::CollectionId },
+		/// An `item` was locked until the `unlock_at` block.
+		ItemTimeLocked {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			unlock_at: BlockNumberFor<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -684,6 +757,18 @@ pub mod pallet {
 		CollectionNotEmpty,
 		/// The witness data should be provided.
 		WitnessRequired,
+		/// The royalty of the collection is locked.
+		LockedCollectionRoyalty,
+		/// The collection has no royalty.
+		RoyaltyNotFound,
+		/// The collection has no lazy mint root.
+		LazyMintRootNotFound,
+		/// The provided lazy mint proof is incorrect.
+		BadLazyMintProof,
+		/// The item can't be transferred or burned before its unlock block.
+		ItemTimeLocked,
+		/// The collection has no Admin to set the metadata of the item.
+		NoAdmin,
 	}
 
 	#[pallet::call]
@@ -1927,6 +2012,187 @@ pub mod pallet {
 			Self::validate_signature(&Encode::encode(&data), &signature, &signer)?;
 			Self::do_set_attributes_pre_signed(origin, data, signer)
 		}
+
+		/// Set the royalty paid on every sale of an item of a collection.
+		///
+		/// The royalty is deducted from the price paid for an item in `buy_item` and
+		/// `claim_swap`, and paid to the `recipient`.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Admin of
+		/// the `collection`.
+		///
+		/// - `collection`: The identifier of the collection.
+		/// - `recipient`: The account receiving the royalty.
+		/// - `royalty`: The part of the sale price paid as royalty.
+		///
+		/// Emits `CollectionRoyaltySet`.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::set_collection_royalty())]
+		pub fn set_collection_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			recipient: AccountIdLookupOf<T>,
+			royalty: Permill,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			let recipient = T::Lookup::lookup(recipient)?;
+			Self::do_set_collection_royalty(
+				maybe_check_origin,
+				collection,
+				CollectionRoyalty { recipient, royalty },
+			)
+		}
+
+		/// Clear the royalty of a collection.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Admin of
+		/// the `collection`.
+		///
+		/// - `collection`: The identifier of the collection.
+		///
+		/// Emits `CollectionRoyaltyCleared`.
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::clear_collection_royalty())]
+		pub fn clear_collection_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_clear_collection_royalty(maybe_check_origin, collection)
+		}
+
+		/// Disallow further changes to the royalty of a collection.
+		///
+		/// Origin must be Signed and the sender should be the Admin of the `collection`.
+		///
+		/// Note: it's possible only to lock the royalty; it can only be unlocked by
+		/// `force_collection_config`.
+		///
+		/// - `collection`: The identifier of the collection.
+		///
+		/// Emits `CollectionRoyaltyLocked`.
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::lock_collection_royalty())]
+		pub fn lock_collection_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_lock_collection_royalty(origin, collection)
+		}
+
+		/// Commit to a set of items of a collection which can later be minted by anyone
+		/// presenting a proof of their definition.
+		///
+		/// The `root` is the root of a binary merkle tree, hashed with `BlakeTwo256`, whose leaves
+		/// are the encoded `LazyMintItem`s. Replaces the previous root of the collection, if any.
+		/// Items can be claimed until the root expires after `duration` blocks.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Issuer of
+		/// the `collection`.
+		///
+		/// - `collection`: The identifier of the collection.
+		/// - `root`: The root of the tree of items.
+		/// - `items`: The number of leaves of the tree.
+		/// - `duration`: The number of blocks the items can be claimed for. Must be at most
+		///   `MaxDeadlineDuration`.
+		///
+		/// Emits `LazyMintRootSet`.
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::set_lazy_mint_root())]
+		pub fn set_lazy_mint_root(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			root: H256,
+			items: u32,
+			duration: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_set_lazy_mint_root(maybe_check_origin, collection, root, items, duration)
+		}
+
+		/// Clear the lazy mint root of a collection.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Issuer of
+		/// the `collection`.
+		///
+		/// - `collection`: The identifier of the collection.
+		///
+		/// Emits `LazyMintRootCleared`.
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::clear_lazy_mint_root())]
+		pub fn clear_lazy_mint_root(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+		) -> DispatchResult {
+			let maybe_check_origin = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some).map_err(DispatchError::from))?;
+			Self::do_clear_lazy_mint_root(maybe_check_origin, collection)
+		}
+
+		/// Mint an item committed to by the lazy mint root of a collection.
+		///
+		/// Origin must be Signed. The sender receives the item and pays the deposits of the item
+		/// and its metadata, as well as the `mint_price` of the item, if any. Each item definition
+		/// can only be claimed once, and only until the root expires. An item with metadata can
+		/// only be claimed while the collection has an Admin.
+		///
+		/// - `collection`: The identifier of the collection.
+		/// - `mint_data`: The definition of the item to mint.
+		/// - `proof`: The proof that `mint_data` is a leaf of the collection's lazy mint root.
+		///
+		/// Emits `Issued` on success.
+		/// Emits `ItemMetadataSet` if the item has metadata.
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::claim_lazy_mint(proof.proof.len() as u32))]
+		pub fn claim_lazy_mint(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			mint_data: LazyMintItemOf<T, I>,
+			proof: LazyMintProof,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_claim_lazy_mint(origin, collection, mint_data, proof)
+		}
+
+		/// Move an item from the sender account to another and lock it until a given block.
+		///
+		/// Until the `unlock_at` block is reached, the item can be neither transferred nor burned.
+		///
+		/// Origin must be Signed and the signing account must be either:
+		/// - the Owner of the `item`;
+		/// - the approved delegate for the `item` (in this case, the approval is reset).
+		///
+		/// Arguments:
+		/// - `collection`: The collection of the item to be transferred.
+		/// - `item`: The item to be transferred.
+		/// - `dest`: The account to receive ownership of the item.
+		/// - `unlock_at`: The block from which the item can be transferred or burned again. Must be
+		///   in the future and at most `MaxDeadlineDuration` blocks away.
+		///
+		/// Emits `Transferred` and `ItemTimeLocked`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::transfer_with_unlock_at())]
+		pub fn transfer_with_unlock_at(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			dest: AccountIdLookupOf<T>,
+			unlock_at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let dest = T::Lookup::lookup(dest)?;
+			Self::do_transfer_with_unlock_at(origin, collection, item, dest, unlock_at)
+		}
 	}
 }
 
@@ -29,8 +29,8 @@ use frame_support::{
 use pallet_balances::Error as BalancesError;
 use sp_core::{bounded::BoundedVec, Pair};
 use sp_runtime::{
-	traits::{Dispatchable, IdentifyAccount},
-	MultiSignature, MultiSigner,
+	traits::{BlakeTwo256, Dispatchable, IdentifyAccount},
+	MultiSignature, MultiSigner, Permill,
 };
 
 type AccountIdOf<Test> = <Test as frame_system::Config>::AccountId;
@@ -3875,3 +3875,613 @@ fn clear_collection_metadata_works() {
 		assert_eq!(Balances::reserved_balance(&account(1)), 10);
 	});
 }
+
+#[test]
+fn collection_royalty_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let collection_id = 0;
+		let item_1 = 1;
+		let item_2 = 2;
+		let royalty = Permill::from_percent(10);
+		let initial_balance = 100;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+		Balances::make_free_balance_be(&user_3, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+
+		// only the collection's admin can set the royalty
+		assert_noop!(
+			Nfts::set_collection_royalty(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				user_3.clone(),
+				royalty,
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			user_3.clone(),
+			royalty,
+		));
+		assert_eq!(
+			CollectionRoyaltyOf::<Test>::get(collection_id),
+			Some(CollectionRoyalty { recipient: user_3.clone(), royalty })
+		);
+		assert!(events().contains(&Event::<Test>::CollectionRoyaltySet {
+			collection: collection_id,
+			recipient: user_3.clone(),
+			royalty,
+		}));
+
+		// the royalty is deducted from the price of a sold item
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_1,
+			user_1.clone(),
+			None
+		));
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_1,
+			Some(50),
+			None,
+		));
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_1,
+			50,
+		));
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + 45);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance - 50);
+		assert_eq!(Balances::total_balance(&user_3), initial_balance + 5);
+		assert!(events().contains(&Event::<Test>::RoyaltyPaid {
+			collection: collection_id,
+			item: item_1,
+			recipient: user_3.clone(),
+			amount: 5,
+		}));
+
+		// the royalty is deducted from the price of a swap
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_2,
+			user_1.clone(),
+			None
+		));
+		let price_with_direction =
+			PriceWithDirection { amount: 20, direction: PriceDirection::Receive };
+		assert_ok!(Nfts::create_swap(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_2,
+			collection_id,
+			Some(item_1),
+			Some(price_with_direction.clone()),
+			2,
+		));
+		assert_ok!(Nfts::claim_swap(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_1,
+			collection_id,
+			item_2,
+			Some(price_with_direction),
+		));
+		assert_eq!(Balances::total_balance(&user_1), initial_balance + 45 + 18);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance - 50 - 20);
+		assert_eq!(Balances::total_balance(&user_3), initial_balance + 5 + 2);
+		assert!(events().contains(&Event::<Test>::RoyaltyPaid {
+			collection: collection_id,
+			item: item_2,
+			recipient: user_3.clone(),
+			amount: 2,
+		}));
+
+		// clear the royalty
+		assert_ok!(Nfts::clear_collection_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id
+		));
+		assert!(!CollectionRoyaltyOf::<Test>::contains_key(collection_id));
+		assert!(events()
+			.contains(&Event::<Test>::CollectionRoyaltyCleared { collection: collection_id }));
+		assert_noop!(
+			Nfts::clear_collection_royalty(RuntimeOrigin::signed(user_1.clone()), collection_id),
+			Error::<Test>::RoyaltyNotFound
+		);
+
+		// lock the royalty
+		assert_noop!(
+			Nfts::lock_collection_royalty(RuntimeOrigin::signed(user_2.clone()), collection_id),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::lock_collection_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id
+		));
+		assert!(events()
+			.contains(&Event::<Test>::CollectionRoyaltyLocked { collection: collection_id }));
+		assert_noop!(
+			Nfts::set_collection_royalty(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				user_3.clone(),
+				royalty,
+			),
+			Error::<Test>::LockedCollectionRoyalty
+		);
+
+		// the force origin can still change a locked royalty
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::root(),
+			collection_id,
+			user_3.clone(),
+			royalty,
+		));
+		assert_ok!(Nfts::clear_collection_royalty(RuntimeOrigin::root(), collection_id));
+	});
+}
+
+#[test]
+fn royalty_of_existing_collections_is_unlocked() {
+	// The settings of the collections created before royalties existed lack the royalty bit.
+	let legacy_settings =
+		CollectionSetting::TransferableItems | CollectionSetting::UnlockedMetadata;
+	let settings = CollectionSettings::decode(&mut &legacy_settings.bits().encode()[..]).unwrap();
+	assert!(!settings.is_disabled(CollectionSetting::UnlockedRoyalty));
+}
+
+#[test]
+fn royalty_below_minimum_balance_is_paid_to_seller() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		ExistentialDeposit::set(10);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let collection_id = 0;
+		let item_id = 1;
+		let price = 50;
+		let initial_balance = 100;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		// user_3 has no account, and 10% of the price is below the existential deposit
+		assert_ok!(Nfts::set_collection_royalty(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			user_3.clone(),
+			Permill::from_percent(10),
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_1.clone(),
+			None
+		));
+		assert_ok!(Nfts::set_price(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			Some(price),
+			None,
+		));
+		let user_1_balance = Balances::total_balance(&user_1);
+
+		assert_ok!(Nfts::buy_item(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id,
+			price
+		));
+		assert_eq!(Balances::total_balance(&user_1), user_1_balance + price);
+		assert_eq!(Balances::total_balance(&user_2), initial_balance - price);
+		assert_eq!(Balances::total_balance(&user_3), 0);
+		assert!(!events().iter().any(|e| matches!(e, Event::<Test>::RoyaltyPaid { .. })));
+	});
+}
+
+#[test]
+fn lazy_mint_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let collection_id = 0;
+		let initial_balance = 100;
+
+		Balances::make_free_balance_be(&user_1, initial_balance);
+		Balances::make_free_balance_be(&user_2, initial_balance);
+		Balances::make_free_balance_be(&user_3, initial_balance);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			collection_config_with_all_settings_enabled()
+		));
+
+		let mint_items = vec![
+			LazyMintItem { item: 0, metadata: vec![], only_account: None, mint_price: None },
+			LazyMintItem {
+				item: 1,
+				metadata: vec![0, 1],
+				only_account: Some(user_2.clone()),
+				mint_price: Some(10),
+			},
+			LazyMintItem { item: 2, metadata: vec![], only_account: None, mint_price: None },
+		];
+		let leaves: Vec<_> = mint_items.iter().map(|item| item.encode()).collect();
+		let make_proof = |index: u32| {
+			let proof =
+				binary_merkle_tree::merkle_proof::<BlakeTwo256, _, _>(leaves.clone(), index);
+			LazyMintProof { leaf_index: index, proof: proof.proof.try_into().unwrap() }
+		};
+		let root = binary_merkle_tree::merkle_root::<BlakeTwo256, _>(leaves.clone());
+
+		// nothing can be claimed without a root
+		assert_noop!(
+			Nfts::claim_lazy_mint(
+				RuntimeOrigin::signed(user_3.clone()),
+				collection_id,
+				mint_items[0].clone(),
+				make_proof(0),
+			),
+			Error::<Test>::LazyMintRootNotFound
+		);
+
+		// only the collection's issuer can set the root
+		assert_noop!(
+			Nfts::set_lazy_mint_root(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				root,
+				mint_items.len() as u32,
+				10,
+			),
+			Error::<Test>::NoPermission
+		);
+		// the root can't outlive the max deadline duration
+		assert_noop!(
+			Nfts::set_lazy_mint_root(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				root,
+				mint_items.len() as u32,
+				10_001,
+			),
+			Error::<Test>::WrongDuration
+		);
+		assert_ok!(Nfts::set_lazy_mint_root(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			root,
+			mint_items.len() as u32,
+			10,
+		));
+		assert!(events().contains(&Event::<Test>::LazyMintRootSet {
+			collection: collection_id,
+			root,
+			items: mint_items.len() as u32,
+			deadline: 11,
+		}));
+
+		// the claimer pays the item deposit
+		assert_ok!(Nfts::claim_lazy_mint(
+			RuntimeOrigin::signed(user_3.clone()),
+			collection_id,
+			mint_items[0].clone(),
+			make_proof(0),
+		));
+		assert_eq!(items(), vec![(user_3.clone(), 0, 0)]);
+		assert_eq!(Balances::reserved_balance(&user_3), 1);
+		assert_noop!(
+			Nfts::claim_lazy_mint(
+				RuntimeOrigin::signed(user_3.clone()),
+				collection_id,
+				mint_items[0].clone(),
+				make_proof(0),
+			),
+			Error::<Test>::AlreadyClaimed
+		);
+		// a burned item can't be claimed again
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(user_3.clone()), collection_id, 0));
+		assert_noop!(
+			Nfts::claim_lazy_mint(
+				RuntimeOrigin::signed(user_3.clone()),
+				collection_id,
+				mint_items[0].clone(),
+				make_proof(0),
+			),
+			Error::<Test>::AlreadyClaimed
+		);
+
+		// the item definition must match the proof
+		let mut forged_item = mint_items[2].clone();
+		forged_item.item = 3;
+		assert_noop!(
+			Nfts::claim_lazy_mint(
+				RuntimeOrigin::signed(user_3.clone()),
+				collection_id,
+				forged_item,
+				make_proof(2),
+			),
+			Error::<Test>::BadLazyMintProof
+		);
+		assert_noop!(
+			Nfts::claim_lazy_mint(
+				RuntimeOrigin::signed(user_3.clone()),
+				collection_id,
+				mint_items[2].clone(),
+				make_proof(1),
+			),
+			Error::<Test>::BadLazyMintProof
+		);
+
+		// an item restricted to an account can only be claimed by that account
+		assert_noop!(
+			Nfts::claim_lazy_mint(
+				RuntimeOrigin::signed(user_3.clone()),
+				collection_id,
+				mint_items[1].clone(),
+				make_proof(1),
+			),
+			Error::<Test>::WrongOrigin
+		);
+		assert_ok!(Nfts::claim_lazy_mint(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			mint_items[1].clone(),
+			make_proof(1),
+		));
+		assert_eq!(Item::<Test>::get(collection_id, 1).unwrap().owner, user_2);
+		assert_eq!(ItemMetadataOf::<Test>::get(collection_id, 1).unwrap().data, bvec![0, 1]);
+		// the item deposit of 1 and the metadata deposit of 1 + 2 bytes
+		assert_eq!(Balances::reserved_balance(&user_2), 4);
+		assert_eq!(Balances::free_balance(&user_2), initial_balance - 10 - 4);
+		assert_eq!(Balances::free_balance(&user_1), initial_balance + 10);
+
+		// clear the root
+		assert_noop!(
+			Nfts::clear_lazy_mint_root(RuntimeOrigin::signed(user_2.clone()), collection_id),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Nfts::clear_lazy_mint_root(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id
+		));
+		assert!(
+			events().contains(&Event::<Test>::LazyMintRootCleared { collection: collection_id })
+		);
+		assert_noop!(
+			Nfts::claim_lazy_mint(
+				RuntimeOrigin::signed(user_3.clone()),
+				collection_id,
+				mint_items[2].clone(),
+				make_proof(2),
+			),
+			Error::<Test>::LazyMintRootNotFound
+		);
+		assert_noop!(
+			Nfts::clear_lazy_mint_root(RuntimeOrigin::root(), collection_id),
+			Error::<Test>::LazyMintRootNotFound
+		);
+	});
+}
+
+#[test]
+fn lazy_mint_root_expires() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let collection_id = 0;
+
+		Balances::make_free_balance_be(&user_1, 100);
+		Balances::make_free_balance_be(&user_2, 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			collection_config_with_all_settings_enabled()
+		));
+
+		let mint_item =
+			LazyMintItem { item: 0, metadata: vec![0, 1], only_account: None, mint_price: None };
+		let leaves = vec![mint_item.encode()];
+		let root = binary_merkle_tree::merkle_root::<BlakeTwo256, _>(leaves.clone());
+		let proof = binary_merkle_tree::merkle_proof::<BlakeTwo256, _, _>(leaves, 0);
+		let proof = LazyMintProof { leaf_index: 0, proof: proof.proof.try_into().unwrap() };
+		assert_ok!(Nfts::set_lazy_mint_root(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			root,
+			1,
+			10,
+		));
+
+		// the metadata can't be set without an admin
+		assert_ok!(Nfts::set_team(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			Some(user_1.clone()),
+			None,
+			Some(user_1.clone()),
+		));
+		assert_noop!(
+			Nfts::claim_lazy_mint(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				mint_item.clone(),
+				proof.clone(),
+			),
+			Error::<Test>::NoAdmin
+		);
+		assert_ok!(Nfts::set_team(
+			RuntimeOrigin::root(),
+			collection_id,
+			Some(user_1.clone()),
+			Some(user_1.clone()),
+			Some(user_1.clone()),
+		));
+
+		// the items can't be claimed after the deadline
+		System::set_block_number(12);
+		assert_noop!(
+			Nfts::claim_lazy_mint(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				mint_item.clone(),
+				proof.clone(),
+			),
+			Error::<Test>::DeadlineExpired
+		);
+		System::set_block_number(11);
+		assert_ok!(Nfts::claim_lazy_mint(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			mint_item,
+			proof,
+		));
+		assert_eq!(ItemMetadataOf::<Test>::get(collection_id, 0).unwrap().data, bvec![0, 1]);
+	});
+}
+
+#[test]
+fn transfer_with_unlock_at_should_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let user_1 = account(1);
+		let user_2 = account(2);
+		let user_3 = account(3);
+		let collection_id = 0;
+		let item_id = 42;
+
+		Balances::make_free_balance_be(&user_1, 100);
+
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			user_1.clone(),
+			default_collection_config()
+		));
+		assert_ok!(Nfts::force_mint(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_1.clone(),
+			default_item_config()
+		));
+
+		// the unlock block must be in the future
+		assert_noop!(
+			Nfts::transfer_with_unlock_at(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item_id,
+				user_2.clone(),
+				1,
+			),
+			Error::<Test>::WrongDuration
+		);
+		// the lock can't last longer than `MaxDeadlineDuration`
+		let max_duration: u64 = <Test as Config>::MaxDeadlineDuration::get();
+		assert_noop!(
+			Nfts::transfer_with_unlock_at(
+				RuntimeOrigin::signed(user_1.clone()),
+				collection_id,
+				item_id,
+				user_2.clone(),
+				1 + max_duration + 1,
+			),
+			Error::<Test>::WrongDuration
+		);
+		// only the owner or a delegate can transfer the item
+		assert_noop!(
+			Nfts::transfer_with_unlock_at(
+				RuntimeOrigin::signed(user_3.clone()),
+				collection_id,
+				item_id,
+				user_2.clone(),
+				5,
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Nfts::transfer_with_unlock_at(
+			RuntimeOrigin::signed(user_1.clone()),
+			collection_id,
+			item_id,
+			user_2.clone(),
+			5,
+		));
+		assert_eq!(items(), vec![(user_2.clone(), collection_id, item_id)]);
+		assert_eq!(ItemUnlockAt::<Test>::get(collection_id, item_id), Some(5));
+		assert!(events().contains(&Event::<Test>::ItemTimeLocked {
+			collection: collection_id,
+			item: item_id,
+			unlock_at: 5,
+		}));
+		assert!(!<Nfts as Inspect<AccountIdOf<Test>>>::can_transfer(&collection_id, &item_id));
+
+		// the item can be neither transferred nor burned before the unlock block
+		assert_noop!(
+			Nfts::transfer(
+				RuntimeOrigin::signed(user_2.clone()),
+				collection_id,
+				item_id,
+				user_3.clone()
+			),
+			Error::<Test>::ItemTimeLocked
+		);
+		assert_noop!(
+			Nfts::burn(RuntimeOrigin::signed(user_2.clone()), collection_id, item_id),
+			Error::<Test>::ItemTimeLocked
+		);
+
+		System::set_block_number(5);
+		assert!(<Nfts as Inspect<AccountIdOf<Test>>>::can_transfer(&collection_id, &item_id));
+		assert_ok!(Nfts::transfer(
+			RuntimeOrigin::signed(user_2.clone()),
+			collection_id,
+			item_id,
+			user_3.clone()
+		));
+		assert_eq!(items(), vec![(user_3.clone(), collection_id, item_id)]);
+		assert!(!ItemUnlockAt::<Test>::contains_key(collection_id, item_id));
+
+		// burning clears the lock
+		assert_ok!(Nfts::transfer_with_unlock_at(
+			RuntimeOrigin::signed(user_3.clone()),
+			collection_id,
+			item_id,
+			user_1.clone(),
+			10,
+		));
+		System::set_block_number(10);
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(user_1.clone()), collection_id, item_id));
+		assert!(!ItemUnlockAt::<Test>::contains_key(collection_id, item_id));
+	});
+}
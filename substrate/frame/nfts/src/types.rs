@@ -24,7 +24,7 @@ use codec::EncodeLike;
 use enumflags2::{bitflags, BitFlags};
 use frame_support::{
 	pallet_prelude::{BoundedVec, MaxEncodedLen},
-	traits::Get,
+	traits::{ConstU32, Get},
 	BoundedBTreeMap, BoundedBTreeSet,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
@@ -79,6 +79,9 @@ pub type PreSignedMintOf<T, I = ()> = PreSignedMint<
 	BlockNumberFor<T>,
 	BalanceOf<T, I>,
 >;
+/// A type alias for the definition of an item which can be lazily minted.
+pub type LazyMintItemOf<T, I = ()> =
+	LazyMintItem<<T as Config<I>>::ItemId, <T as SystemConfig>::AccountId, BalanceOf<T, I>>;
 /// A type alias for the pre-signed minting configuration on the attribute level of an item.
 pub type PreSignedAttributesOf<T, I = ()> = PreSignedAttributes<
 	<T as Config<I>>::CollectionId,
@@ -232,6 +235,15 @@ pub struct ItemMetadataDeposit<DepositBalance, AccountId> {
 	pub amount: DepositBalance,
 }
 
+/// The royalty paid on every sale of an item of a collection.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CollectionRoyalty<AccountId> {
+	/// The account receiving the royalty.
+	pub recipient: AccountId,
+	/// The part of the sale price paid as royalty.
+	pub royalty: Permill,
+}
+
 /// Specifies whether the tokens will be sent or received.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum PriceDirection {
@@ -265,6 +277,11 @@ pub enum CollectionSetting {
 	UnlockedMaxSupply,
 	/// When this isn't set then the deposit is required to hold the items of this collection.
 	DepositRequired,
+	/// The royalty of this collection can be modified.
+	///
+	/// Like the other settings, it is stored as disabled, so the collections created before this
+	/// setting existed have an unlocked royalty.
+	UnlockedRoyalty,
 }
 
 /// Wrapper type for `BitFlags<CollectionSetting>` that implements `Codec`.
@@ -545,3 +562,37 @@ pub struct PreSignedAttributes<CollectionId, ItemId, AccountId, Deadline> {
 	/// A deadline for the signature.
 	pub deadline: Deadline,
 }
+
+/// A commitment of a collection's issuer to a set of items which can be lazily minted.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct LazyMintRoot<Deadline> {
+	/// The root of the binary merkle tree built over the encoded [`LazyMintItem`]s.
+	pub root: H256,
+	/// The number of leaves of the tree.
+	pub items: u32,
+	/// The last block at which items can be claimed.
+	pub deadline: Deadline,
+}
+
+/// The definition of an item which can be lazily minted.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct LazyMintItem<ItemId, AccountId, Balance> {
+	/// Item's ID.
+	pub item: ItemId,
+	/// Additional item's metadata.
+	pub metadata: Vec<u8>,
+	/// Restrict the claim to a particular account.
+	pub only_account: Option<AccountId>,
+	/// An optional price the claimer would need to pay for the mint.
+	pub mint_price: Option<Balance>,
+}
+
+/// A proof that a [`LazyMintItem`] is a leaf of a [`LazyMintRoot`].
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct LazyMintProof {
+	/// The index of the leaf in the tree.
+	pub leaf_index: u32,
+	/// The hashes of the sibling nodes from the leaf up to the root. A tree of at most
+	/// `u32::MAX` leaves is at most 32 levels deep.
+	pub proof: BoundedVec<H256, ConstU32<32>>,
+}
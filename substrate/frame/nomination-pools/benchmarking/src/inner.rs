@@ -33,8 +33,9 @@ use pallet_nomination_pools::{
 	adapter::{Member, Pool, StakeStrategy, StakeStrategyType},
 	BalanceOf, BondExtra, BondedPoolInner, BondedPools, ClaimPermission, ClaimPermissions,
 	Commission, CommissionChangeRate, CommissionClaimPermission, ConfigOp, GlobalMaxCommission,
-	MaxPoolMembers, MaxPoolMembersPerPool, MaxPools, Metadata, MinCreateBond, MinJoinBond,
-	Pallet as Pools, PoolId, PoolMembers, PoolRoles, PoolState, RewardPools, SubPoolsStorage,
+	LoyaltyRebates, MaxPoolMembers, MaxPoolMembersPerPool, MaxPools, MemberLoyalties, Metadata,
+	MinCreateBond, MinJoinBond, Pallet as Pools, PoolId, PoolMembers, PoolRoles, PoolState,
+	RewardPools, SubPoolsStorage,
 };
 use pallet_staking::MaxNominationsOf;
 use sp_runtime::{
@@ -961,6 +962,49 @@ mod benchmarks {
 		assert_eq!(CurrencyOf::<T>::balance(&reward_account), ed + commission * origin_weight);
 	}
 
+	#[benchmark]
+	fn set_loyalty_rebate() {
+		let (depositor, _pool_account) =
+			create_pool_account::<T>(0, Pools::<T>::depositor_min_bond() * 2u32.into(), None);
+		whitelist_account!(depositor);
+
+		#[extrinsic_call]
+		_(RuntimeOrigin::Signed(depositor), 1u32.into(), Perbill::from_percent(50), 28);
+
+		let rebate = LoyaltyRebates::<T>::get(1).unwrap();
+		assert_eq!((rebate.share, rebate.min_eras), (Perbill::from_percent(50), 28));
+	}
+
+	#[benchmark]
+	fn claim_loyalty_rebate() {
+		let origin_weight = Pools::<T>::depositor_min_bond() * 2u32.into();
+		let ed = CurrencyOf::<T>::minimum_balance();
+		let (depositor, _pool_account) =
+			create_pool_account::<T>(0, origin_weight, Some(Perbill::from_percent(50)));
+		let reward_account = Pools::<T>::generate_reward_account(1);
+		CurrencyOf::<T>::set_balance(&reward_account, ed + origin_weight);
+
+		// half of the commission is set aside for the members when it is claimed.
+		assert_ok!(Pools::<T>::set_loyalty_rebate(
+			RuntimeOrigin::Signed(depositor.clone()).into(),
+			1u32.into(),
+			Perbill::from_percent(50),
+			0,
+		));
+		assert_ok!(Pools::<T>::claim_commission(
+			RuntimeOrigin::Signed(depositor.clone()).into(),
+			1u32.into()
+		));
+		let balance_before = CurrencyOf::<T>::balance(&depositor);
+		whitelist_account!(depositor);
+
+		#[extrinsic_call]
+		_(RuntimeOrigin::Signed(depositor.clone()));
+
+		assert!(CurrencyOf::<T>::balance(&depositor) > balance_before);
+		assert!(MemberLoyalties::<T>::get(&depositor).unwrap().accrued.is_zero());
+	}
+
 	#[benchmark]
 	fn adjust_pool_deposit() {
 		// Create a pool
@@ -158,6 +158,14 @@
 //! changing the commission rate to a very high value after rewards are accumulated, and thus claim
 //! an unexpectedly high chunk of the reward.
 //!
+//! #### Loyalty rebates
+//!
+//! The `root` role can opt a pool in to loyalty rebates with [`Call::set_loyalty_rebate`]. A share
+//! of the commission is then set aside every time it is claimed, and credited to the members
+//! pro-rata to their active points. Members claim their rebate with
+//! [`Call::claim_loyalty_rebate`] once they have been part of the pool for the configured number
+//! of eras. The rebate of a member that leaves the pool is credited to the remaining members.
+//!
 //! ### Dismantling
 //!
 //! As noted, a pool is destroyed once
@@ -1495,6 +1503,44 @@ impl<T: Config> UnbondPool<T> {
 	}
 }
 
+/// The loyalty rebate settings and accounting of a pool.
+///
+/// A pool which opts in redirects `share` of its commission back to its members whenever the
+/// commission is claimed. The rebate is credited to members pro-rata to their active points at
+/// that time, and stays in the pool's reward account until claimed. Only members that have been
+/// part of the pool for at least `min_eras` eras may claim it.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, DefaultNoBound, RuntimeDebugNoBound)]
+#[cfg_attr(feature = "std", derive(Clone, PartialEq))]
+#[codec(mel_bound(T: Config))]
+#[scale_info(skip_type_params(T))]
+pub struct LoyaltyRebate<T: Config> {
+	/// The portion of each commission claim that is redirected to members.
+	pub share: Perbill,
+	/// The number of eras a member has to be part of the pool before they may claim.
+	pub min_eras: EraIndex,
+	/// The rebate credited so far to each point of the pool.
+	pub counter: T::RewardCounter,
+	/// The amount of rebate that has been set aside but not yet claimed by members.
+	pub pending: BalanceOf<T>,
+}
+
+/// The loyalty rebate accounting of a pool member.
+///
+/// Members that joined before their pool opted in to loyalty rebates have no record, which is
+/// treated as having been part of the pool since era zero.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, DefaultNoBound, RuntimeDebugNoBound)]
+#[cfg_attr(feature = "std", derive(Clone, PartialEq))]
+#[codec(mel_bound(T: Config))]
+#[scale_info(skip_type_params(T))]
+pub struct MemberLoyalty<T: Config> {
+	/// The era at which the member joined the pool.
+	pub since: EraIndex,
+	/// The value of [`LoyaltyRebate::counter`] when the member's rebate was last settled.
+	pub last_recorded_counter: T::RewardCounter,
+	/// The rebate accrued by the member and not yet claimed.
+	pub accrued: BalanceOf<T>,
+}
+
 #[derive(Encode, Decode, MaxEncodedLen, TypeInfo, DefaultNoBound, RuntimeDebugNoBound)]
 #[cfg_attr(feature = "std", derive(Clone, PartialEq))]
 #[codec(mel_bound(T: Config))]
@@ -1740,6 +1786,15 @@ pub mod pallet {
 	pub type ClaimPermissions<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, ClaimPermission, ValueQuery>;
 
+	/// The loyalty rebate settings and accounting of the pools that have opted in.
+	#[pallet::storage]
+	pub type LoyaltyRebates<T: Config> = StorageMap<_, Twox64Concat, PoolId, LoyaltyRebate<T>>;
+
+	/// The loyalty rebate accounting of the members of pools that have opted in.
+	#[pallet::storage]
+	pub type MemberLoyalties<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, MemberLoyalty<T>>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub min_join_bond: BalanceOf<T>,
@@ -1865,6 +1920,12 @@ pub mod pallet {
 		MinBalanceDeficitAdjusted { pool_id: PoolId, amount: BalanceOf<T> },
 		/// Claimed excess frozen ED of af the reward pool.
 		MinBalanceExcessAdjusted { pool_id: PoolId, amount: BalanceOf<T> },
+		/// A pool's loyalty rebate settings have been changed.
+		LoyaltyRebateUpdated { pool_id: PoolId, share: Perbill, min_eras: EraIndex },
+		/// A part of a pool's claimed commission has been set aside as loyalty rebate.
+		LoyaltyRebateAccrued { pool_id: PoolId, amount: BalanceOf<T> },
+		/// A member has claimed their loyalty rebate.
+		LoyaltyRebateClaimed { member: T::AccountId, pool_id: PoolId, amount: BalanceOf<T> },
 	}
 
 	#[pallet::error]
@@ -1950,6 +2011,10 @@ pub mod pallet {
 		NotMigrated,
 		/// This call is not allowed in the current state of the pallet.
 		NotSupported,
+		/// The member has not been part of the pool for long enough to claim loyalty rebates.
+		NotLongStanding,
+		/// The member has no loyalty rebate to claim.
+		NoPendingLoyaltyRebate,
 	}
 
 	#[derive(Encode, Decode, PartialEq, TypeInfo, PalletError, RuntimeDebug)]
@@ -2043,6 +2108,16 @@ pub mod pallet {
 					unbonding_eras: Default::default(),
 				},
 			);
+			if let Some(rebate) = LoyaltyRebates::<T>::get(pool_id) {
+				MemberLoyalties::<T>::insert(
+					&who,
+					MemberLoyalty::<T> {
+						since: T::StakeAdapter::current_era(),
+						last_recorded_counter: rebate.counter,
+						accrued: Zero::zero(),
+					},
+				);
+			}
 
 			Self::deposit_event(Event::<T>::Bonded {
 				member: who,
@@ -2170,6 +2245,7 @@ pub mod pallet {
 				&mut bonded_pool,
 				&mut reward_pool,
 			)?;
+			Self::settle_loyalty_rebate(&member_account, &member);
 
 			let current_era = T::StakeAdapter::current_era();
 			let unbond_era = T::StakeAdapter::bonding_duration().saturating_add(current_era);
@@ -2401,6 +2477,8 @@ pub mod pallet {
 
 				// member being reaped.
 				PoolMembers::<T>::remove(&member_account);
+				// any unclaimed loyalty rebate goes to the remaining members.
+				Self::forfeit_loyalty_rebate(&member_account, member.pool_id, bonded_pool.points);
 
 				// Ensure any dangling delegation is withdrawn.
 				let dangling_withdrawal = match T::StakeAdapter::member_delegation_balance(
@@ -3085,6 +3163,81 @@ pub mod pallet {
 			Self::migrate_to_delegate_stake(pool_id)?;
 			Ok(Pays::No.into())
 		}
+
+		/// Set a pool's loyalty rebate settings.
+		///
+		/// When the pool's commission is claimed, `share` of it is redirected to the members of
+		/// the pool pro-rata to their points, instead of being paid out to the commission payee.
+		/// Members may claim their rebate with [`Call::claim_loyalty_rebate`] once they have been
+		/// part of the pool for at least `min_eras` eras. A `share` of zero stops any further
+		/// rebate from being set aside, while letting members claim what has already accrued.
+		///
+		/// The dispatch origin of this call must be signed by the `root` role of the pool.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::set_loyalty_rebate())]
+		pub fn set_loyalty_rebate(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			share: Perbill,
+			min_eras: EraIndex,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let bonded_pool = BondedPool::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(bonded_pool.can_manage_commission(&who), Error::<T>::DoesNotHavePermission);
+
+			LoyaltyRebates::<T>::mutate(pool_id, |maybe_rebate| {
+				let rebate = maybe_rebate.get_or_insert_with(Default::default);
+				rebate.share = share;
+				rebate.min_eras = min_eras;
+			});
+
+			Self::deposit_event(Event::<T>::LoyaltyRebateUpdated { pool_id, share, min_eras });
+			Ok(())
+		}
+
+		/// Claim the loyalty rebate accrued by the caller in their pool.
+		///
+		/// The caller must have been part of the pool for at least the pool's configured minimum
+		/// number of eras.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::claim_loyalty_rebate())]
+		pub fn claim_loyalty_rebate(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let member = PoolMembers::<T>::get(&who).ok_or(Error::<T>::PoolMemberNotFound)?;
+			let pool_id = member.pool_id;
+			let mut rebate =
+				LoyaltyRebates::<T>::get(pool_id).ok_or(Error::<T>::NoPendingLoyaltyRebate)?;
+			let mut loyalty = Self::settled_loyalty(&who, &member, &rebate);
+
+			ensure!(
+				T::StakeAdapter::current_era() >= loyalty.since.saturating_add(rebate.min_eras),
+				Error::<T>::NotLongStanding
+			);
+			let amount = loyalty.accrued.min(rebate.pending);
+			ensure!(!amount.is_zero(), Error::<T>::NoPendingLoyaltyRebate);
+
+			let mut reward_pool = RewardPools::<T>::get(pool_id)
+				.defensive_ok_or::<Error<T>>(DefensiveError::RewardPoolNotFound.into())?;
+			T::Currency::transfer(
+				&Self::generate_reward_account(pool_id),
+				&who,
+				amount,
+				Preservation::Preserve,
+			)?;
+
+			// the rebate was part of the pool's commission, so it is accounted for as claimed
+			// commission. This keeps the reward pool's payout tracking unaffected.
+			reward_pool.total_commission_claimed =
+				reward_pool.total_commission_claimed.saturating_add(amount);
+			loyalty.accrued.saturating_reduce(amount);
+			rebate.pending.saturating_reduce(amount);
+			RewardPools::<T>::insert(pool_id, reward_pool);
+			MemberLoyalties::<T>::insert(&who, loyalty);
+			LoyaltyRebates::<T>::insert(pool_id, rebate);
+
+			Self::deposit_event(Event::<T>::LoyaltyRebateClaimed { member: who, pool_id, amount });
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -3134,6 +3287,7 @@ impl<T: Config> Pallet<T> {
 		ReversePoolIdLookup::<T>::remove(&bonded_account);
 		RewardPools::<T>::remove(bonded_pool.id);
 		SubPoolsStorage::<T>::remove(bonded_pool.id);
+		LoyaltyRebates::<T>::remove(bonded_pool.id);
 
 		// remove the ED restriction from the pool reward account.
 		let _ = Self::unfreeze_pool_deposit(&bonded_pool.reward_account()).defensive();
@@ -3443,6 +3597,7 @@ impl<T: Config> Pallet<T> {
 			&mut bonded_pool,
 			&mut reward_pool,
 		)?;
+		Self::settle_loyalty_rebate(&member_account, &member);
 
 		let (points_issued, bonded) = match extra {
 			BondExtra::FreeBalance(amount) =>
@@ -3491,13 +3646,20 @@ impl<T: Config> Pallet<T> {
 			.map(|(_, p)| p.clone())
 			.ok_or(Error::<T>::NoCommissionCurrentSet)?;
 
+		// Set aside the loyalty rebate, if the pool has opted in. It stays in the reward account
+		// until claimed by members.
+		let rebate = Self::set_aside_loyalty_rebate(pool_id, commission, bonded_pool.points);
+		let commission = commission.saturating_sub(rebate);
+
 		// Payout claimed commission.
-		T::Currency::transfer(
-			&bonded_pool.reward_account(),
-			&payee,
-			commission,
-			Preservation::Preserve,
-		)?;
+		if !commission.is_zero() {
+			T::Currency::transfer(
+				&bonded_pool.reward_account(),
+				&payee,
+				commission,
+				Preservation::Preserve,
+			)?;
+		}
 
 		// Add pending commission to total claimed counter.
 		reward_pool.total_commission_claimed =
@@ -3506,10 +3668,88 @@ impl<T: Config> Pallet<T> {
 		reward_pool.total_commission_pending = Zero::zero();
 		RewardPools::<T>::insert(pool_id, reward_pool);
 
+		if !rebate.is_zero() {
+			Self::deposit_event(Event::<T>::LoyaltyRebateAccrued { pool_id, amount: rebate });
+		}
 		Self::deposit_event(Event::<T>::PoolCommissionClaimed { pool_id, commission });
 		Ok(())
 	}
 
+	/// Set aside the loyalty rebate share of `commission` for the members of `pool_id`, crediting
+	/// it to each of the pool's `bonded_points`.
+	///
+	/// Returns the amount set aside, which is zero if the pool has not opted in to loyalty
+	/// rebates or has no points left to credit.
+	fn set_aside_loyalty_rebate(
+		pool_id: PoolId,
+		commission: BalanceOf<T>,
+		bonded_points: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		LoyaltyRebates::<T>::mutate(pool_id, |maybe_rebate| {
+			let Some(rebate) = maybe_rebate else { return Zero::zero() };
+			let amount = rebate.share * commission;
+			// rounding down here ensures the rebate credited to members never exceeds `pending`.
+			match T::RewardCounter::checked_from_rational(amount, bonded_points) {
+				Some(increment) => {
+					rebate.counter = rebate.counter.saturating_add(increment);
+					rebate.pending.saturating_accrue(amount);
+					amount
+				},
+				None => Zero::zero(),
+			}
+		})
+	}
+
+	/// Returns `member`'s loyalty rebate record brought up to date with `rebate`.
+	fn settled_loyalty(
+		member_account: &T::AccountId,
+		member: &PoolMember<T>,
+		rebate: &LoyaltyRebate<T>,
+	) -> MemberLoyalty<T> {
+		let mut loyalty = MemberLoyalties::<T>::get(member_account).unwrap_or_default();
+		let accrued = rebate
+			.counter
+			.defensive_saturating_sub(loyalty.last_recorded_counter)
+			.saturating_mul_int(member.active_points());
+		loyalty.accrued.saturating_accrue(accrued);
+		loyalty.last_recorded_counter = rebate.counter;
+		loyalty
+	}
+
+	/// Settle `member`'s loyalty rebate. This must be called before their active points change.
+	///
+	/// Does nothing if the member's pool has not opted in to loyalty rebates.
+	fn settle_loyalty_rebate(member_account: &T::AccountId, member: &PoolMember<T>) {
+		if let Some(rebate) = LoyaltyRebates::<T>::get(member.pool_id) {
+			let loyalty = Self::settled_loyalty(member_account, member, &rebate);
+			MemberLoyalties::<T>::insert(member_account, loyalty);
+		}
+	}
+
+	/// Remove the loyalty rebate record of a member leaving `pool_id`, crediting any rebate they
+	/// did not claim to the remaining `bonded_points` of the pool.
+	fn forfeit_loyalty_rebate(
+		member_account: &T::AccountId,
+		pool_id: PoolId,
+		bonded_points: BalanceOf<T>,
+	) {
+		let Some(loyalty) = MemberLoyalties::<T>::take(member_account) else { return };
+		if loyalty.accrued.is_zero() {
+			return
+		}
+		LoyaltyRebates::<T>::mutate(pool_id, |maybe_rebate| {
+			// if no points remain, the rebate stays in the reward account and goes to the
+			// depositor when the pool is dissolved.
+			if let Some(rebate) = maybe_rebate {
+				if let Some(increment) =
+					T::RewardCounter::checked_from_rational(loyalty.accrued, bonded_points)
+				{
+					rebate.counter = rebate.counter.saturating_add(increment);
+				}
+			}
+		});
+	}
+
 	pub(crate) fn do_claim_payout(
 		signer: T::AccountId,
 		member_account: T::AccountId,
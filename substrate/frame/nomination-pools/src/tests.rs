@@ -7386,6 +7386,112 @@ mod commission {
 			);
 		})
 	}
+	#[test]
+	fn loyalty_rebate_works() {
+		ExtBuilder::default().build_and_execute(|| {
+			let pool_id = 1;
+			let _ = Currency::set_balance(&900, 5);
+			assert_ok!(Pools::set_commission(
+				RuntimeOrigin::signed(900),
+				pool_id,
+				Some((Perbill::from_percent(50), 900))
+			));
+
+			// Only the root role can configure loyalty rebates.
+			assert_noop!(
+				Pools::set_loyalty_rebate(
+					RuntimeOrigin::signed(10),
+					pool_id,
+					Perbill::from_percent(50),
+					3
+				),
+				Error::<Runtime>::DoesNotHavePermission
+			);
+			assert_ok!(Pools::set_loyalty_rebate(
+				RuntimeOrigin::signed(900),
+				pool_id,
+				Perbill::from_percent(50),
+				3
+			));
+
+			// 20 joins at era 1, while 10 has been a member since before the pool opted in.
+			CurrentEra::set(1);
+			Currency::set_balance(&20, ExistentialDeposit::get() + 10);
+			assert_ok!(Pools::join(RuntimeOrigin::signed(20), 10, pool_id));
+			assert_eq!(MemberLoyalties::<Runtime>::get(20).unwrap().since, 1);
+			assert_eq!(MemberLoyalties::<Runtime>::get(10), None);
+
+			// When: half of the claimed commission is set aside for the members.
+			deposit_rewards(100);
+			assert_ok!(Pools::claim_commission(RuntimeOrigin::signed(900), pool_id));
+
+			// Then:
+			assert_eq!(LoyaltyRebates::<Runtime>::get(pool_id).unwrap().pending, 25);
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![
+					Event::Created { depositor: 10, pool_id },
+					Event::Bonded { member: 10, pool_id, bonded: 10, joined: true },
+					Event::PoolCommissionUpdated {
+						pool_id,
+						current: Some((Perbill::from_percent(50), 900))
+					},
+					Event::LoyaltyRebateUpdated {
+						pool_id,
+						share: Perbill::from_percent(50),
+						min_eras: 3
+					},
+					Event::Bonded { member: 20, pool_id, bonded: 10, joined: true },
+					Event::LoyaltyRebateAccrued { pool_id, amount: 25 },
+					Event::PoolCommissionClaimed { pool_id, commission: 25 },
+				]
+			);
+
+			// Neither member has been part of the pool for long enough.
+			CurrentEra::set(2);
+			assert_noop!(
+				Pools::claim_loyalty_rebate(RuntimeOrigin::signed(10)),
+				Error::<Runtime>::NotLongStanding
+			);
+			assert_noop!(
+				Pools::claim_loyalty_rebate(RuntimeOrigin::signed(20)),
+				Error::<Runtime>::NotLongStanding
+			);
+
+			// When:
+			CurrentEra::set(3);
+			let balance_10 = Currency::free_balance(&10);
+			assert_ok!(Pools::claim_loyalty_rebate(RuntimeOrigin::signed(10)));
+
+			// Then: 10 gets half of the rebate, rounded down, while 20 still has to wait.
+			assert_eq!(Currency::free_balance(&10), balance_10 + 12);
+			assert_eq!(
+				pool_events_since_last_call(),
+				vec![Event::LoyaltyRebateClaimed { member: 10, pool_id, amount: 12 }]
+			);
+			assert_noop!(
+				Pools::claim_loyalty_rebate(RuntimeOrigin::signed(10)),
+				Error::<Runtime>::NoPendingLoyaltyRebate
+			);
+			assert_noop!(
+				Pools::claim_loyalty_rebate(RuntimeOrigin::signed(20)),
+				Error::<Runtime>::NotLongStanding
+			);
+
+			// When: 20 leaves the pool before being able to claim.
+			assert_ok!(fully_unbond_permissioned(20));
+			assert_eq!(MemberLoyalties::<Runtime>::get(20).unwrap().accrued, 12);
+			CurrentEra::set(6);
+			assert_ok!(Pools::withdraw_unbonded(RuntimeOrigin::signed(20), 20, 0));
+
+			// Then: its rebate goes to the remaining members.
+			assert_eq!(MemberLoyalties::<Runtime>::get(20), None);
+			let balance_10 = Currency::free_balance(&10);
+			assert_ok!(Pools::claim_loyalty_rebate(RuntimeOrigin::signed(10)));
+			assert_eq!(Currency::free_balance(&10), balance_10 + 12);
+			assert_eq!(LoyaltyRebates::<Runtime>::get(pool_id).unwrap().pending, 1);
+		})
+	}
 }
 mod slash {
 	use super::*;
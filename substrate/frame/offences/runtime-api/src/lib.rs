@@ -0,0 +1,40 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the offences pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+use pallet_offences::OffenceRecord;
+
+sp_api::decl_runtime_apis! {
+	pub trait OffencesApi<AccountId, Offender>
+		where
+			AccountId: Codec,
+			Offender: Codec,
+	{
+		/// Returns the recorded offences committed in the given era, oldest first.
+		fn offences_in_era(era: sp_staking::EraIndex) -> Vec<OffenceRecord<AccountId, Offender>>;
+
+		/// Returns the recorded offences of the validator `who`, oldest first.
+		fn offences_of(who: AccountId) -> Vec<OffenceRecord<AccountId, Offender>>;
+	}
+}
@@ -18,6 +18,10 @@
 //! # Offences Pallet
 //!
 //! Tracks reported offences
+//!
+//! The last [`Config::MaxOffenceHistory`] offenders reported are kept in the [`OffenceHistory`],
+//! along with the kind of their offence, the era it was committed in, the slash fraction applied
+//! and the reporters, so that it can be queried without replaying events.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -29,13 +33,17 @@ mod tests;
 extern crate alloc;
 
 use alloc::vec::Vec;
-use codec::Encode;
+use codec::{Decode, Encode};
 use core::marker::PhantomData;
-use frame_support::weights::Weight;
-use sp_runtime::{traits::Hash, Perbill};
+use frame_support::{dispatch::DispatchClass, traits::Get, weights::Weight};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Convert, Hash},
+	Perbill, RuntimeDebug,
+};
 use sp_staking::{
 	offence::{Kind, Offence, OffenceDetails, OffenceError, OnOffenceHandler, ReportOffence},
-	SessionIndex,
+	EraIndex, SessionIndex,
 };
 
 pub use pallet::*;
@@ -46,6 +54,40 @@ type OpaqueTimeSlot = Vec<u8>;
 /// A type alias for a report identifier.
 type ReportIdOf<T> = <T as frame_system::Config>::Hash;
 
+/// An offence record as kept in the [`OffenceHistory`].
+pub type OffenceRecordOf<T> =
+	OffenceRecord<<T as frame_system::Config>::AccountId, <T as Config>::IdentificationTuple>;
+
+/// What became of a reported offence.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum OffenceDisposition {
+	/// The offender was handed over to be slashed.
+	Slashed,
+	/// The offence carries no slash, e.g. as it is the only one of its kind in its time slot.
+	Unslashed,
+}
+
+/// A reported offence of a single offender.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct OffenceRecord<AccountId, Offender> {
+	/// The kind of the offence.
+	pub kind: Kind,
+	/// The (kind-specific) encoded time slot in which the offence was committed.
+	pub time_slot: OpaqueTimeSlot,
+	/// The session in which the offence was committed.
+	pub session_index: SessionIndex,
+	/// The era in which the offence was committed, if known.
+	pub era: Option<EraIndex>,
+	/// The offender.
+	pub offender: Offender,
+	/// The accounts which reported the offence.
+	pub reporters: Vec<AccountId>,
+	/// The slash fraction applied to the offender when the offence was reported.
+	pub slash_fraction: Perbill,
+	/// What became of the offence.
+	pub disposition: OffenceDisposition,
+}
+
 const LOG_TARGET: &str = "runtime::offences";
 
 #[frame_support::pallet]
@@ -69,6 +111,11 @@ pub mod pallet {
 		type IdentificationTuple: Parameter;
 		/// A handler called for every offence report.
 		type OnOffenceHandler: OnOffenceHandler<Self::AccountId, Self::IdentificationTuple, Weight>;
+		/// The era a session belongs to, if known.
+		type SessionEra: Convert<SessionIndex, Option<EraIndex>>;
+		/// The maximum number of offences kept in the [`OffenceHistory`].
+		#[pallet::constant]
+		type MaxOffenceHistory: Get<u32>;
 	}
 
 	/// The primary structure that holds all offence records keyed by report identifiers.
@@ -92,6 +139,15 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The most recently reported offences, by their index in the history.
+	#[pallet::storage]
+	pub type OffenceHistory<T: Config> = StorageMap<_, Twox64Concat, u32, OffenceRecordOf<T>>;
+
+	/// The index of the oldest offence kept in the [`OffenceHistory`] and the index of the next
+	/// offence to be recorded.
+	#[pallet::storage]
+	pub type OffenceHistoryBounds<T: Config> = StorageValue<_, (u32, u32), ValueQuery>;
+
 	/// Events type.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -114,8 +170,8 @@ where
 
 		// Go through all offenders in the offence report and find all offenders that were spotted
 		// in unique reports.
-		let TriageOutcome { concurrent_offenders } =
-			match Self::triage_offence_report::<O>(reporters, &time_slot, offenders) {
+		let TriageOutcome { concurrent_offenders, new_offenders } =
+			match Self::triage_offence_report::<O>(reporters.clone(), &time_slot, offenders) {
 				Some(triage) => triage,
 				// The report contained only duplicates, so there is no need to slash again.
 				None => return Err(OffenceError::DuplicateReport),
@@ -128,10 +184,33 @@ where
 
 		let slash_perbill: Vec<_> = (0..concurrent_offenders.len()).map(|_| new_fraction).collect();
 
-		T::OnOffenceHandler::on_offence(
-			&concurrent_offenders,
-			&slash_perbill,
-			offence.session_index(),
+		let session_index = offence.session_index();
+		T::OnOffenceHandler::on_offence(&concurrent_offenders, &slash_perbill, session_index);
+
+		let era = T::SessionEra::convert(session_index);
+		let disposition = if new_fraction.is_zero() {
+			OffenceDisposition::Unslashed
+		} else {
+			OffenceDisposition::Slashed
+		};
+		let mut history_weight = Weight::zero();
+		for offender in new_offenders {
+			history_weight.saturating_accrue(Self::record_offence(OffenceRecord {
+				kind: O::ID,
+				time_slot: time_slot.encode(),
+				session_index,
+				era,
+				offender,
+				reporters: reporters.clone(),
+				slash_fraction: new_fraction,
+				disposition,
+			}));
+		}
+		// The offences are reported from both transactions and hooks, whose weight does not cover
+		// the history.
+		frame_system::Pallet::<T>::register_extra_weight_unchecked(
+			history_weight,
+			DispatchClass::Mandatory,
 		);
 
 		// Deposit the event.
@@ -158,6 +237,40 @@ impl<T: Config> Pallet<T> {
 		Reports::<T>::get(report_id)
 	}
 
+	/// The offences kept in the [`OffenceHistory`] which were committed in `era`, oldest first.
+	pub fn offences_in_era(era: EraIndex) -> Vec<OffenceRecordOf<T>> {
+		Self::offences_where(|record| record.era == Some(era))
+	}
+
+	/// The offences kept in the [`OffenceHistory`] which match `filter`, oldest first.
+	pub fn offences_where(filter: impl Fn(&OffenceRecordOf<T>) -> bool) -> Vec<OffenceRecordOf<T>> {
+		let (first, next) = OffenceHistoryBounds::<T>::get();
+		// the indices wrap around, so `next` may be lower than `first`.
+		(0..next.wrapping_sub(first))
+			.map(|i| first.wrapping_add(i))
+			.filter_map(OffenceHistory::<T>::get)
+			.filter(|r| filter(r))
+			.collect()
+	}
+
+	/// Add an offence to the [`OffenceHistory`], pruning the oldest ones beyond
+	/// [`Config::MaxOffenceHistory`].
+	///
+	/// Returns the weight consumed.
+	fn record_offence(record: OffenceRecordOf<T>) -> Weight {
+		let (mut first, next) = OffenceHistoryBounds::<T>::get();
+		OffenceHistory::<T>::insert(next, record);
+		let next = next.wrapping_add(1);
+		let mut pruned = 0;
+		while next.wrapping_sub(first) > T::MaxOffenceHistory::get() {
+			OffenceHistory::<T>::remove(first);
+			first = first.wrapping_add(1);
+			pruned += 1;
+		}
+		OffenceHistoryBounds::<T>::put((first, next));
+		T::DbWeight::get().reads_writes(1, 2 + pruned)
+	}
+
 	/// Compute the ID for the given report properties.
 	///
 	/// The report id depends on the offence kind, time slot and the id of offender.
@@ -177,12 +290,12 @@ impl<T: Config> Pallet<T> {
 	) -> Option<TriageOutcome<T>> {
 		let mut storage = ReportIndexStorage::<T, O>::load(time_slot);
 
-		let mut any_new = false;
+		let mut new_offenders = Vec::new();
 		for offender in offenders {
 			let report_id = Self::report_id::<O>(time_slot, &offender);
 
 			if !<Reports<T>>::contains_key(&report_id) {
-				any_new = true;
+				new_offenders.push(offender.clone());
 				<Reports<T>>::insert(
 					&report_id,
 					OffenceDetails { offender, reporters: reporters.clone() },
@@ -192,7 +305,7 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 
-		if any_new {
+		if !new_offenders.is_empty() {
 			// Load report details for the all reports happened at the same time.
 			let concurrent_offenders = storage
 				.concurrent_reports
@@ -202,7 +315,7 @@ impl<T: Config> Pallet<T> {
 
 			storage.save();
 
-			Some(TriageOutcome { concurrent_offenders })
+			Some(TriageOutcome { concurrent_offenders, new_offenders })
 		} else {
 			None
 		}
@@ -212,6 +325,8 @@ impl<T: Config> Pallet<T> {
 struct TriageOutcome<T: Config> {
 	/// Other reports for the same report kinds.
 	concurrent_offenders: Vec<OffenceDetails<T::AccountId, T::IdentificationTuple>>,
+	/// The offenders which had not been reported before.
+	new_offenders: Vec<T::IdentificationTuple>,
 }
 
 /// An auxiliary struct for working with storage of indexes localized for a specific offence
@@ -27,10 +27,13 @@ use frame_support::{
 	traits::ConstU32,
 	weights::{constants::RocksDbWeight, Weight},
 };
-use sp_runtime::{traits::IdentityLookup, BuildStorage, Perbill};
+use sp_runtime::{
+	traits::{Convert, IdentityLookup},
+	BuildStorage, Perbill,
+};
 use sp_staking::{
 	offence::{self, Kind, OffenceDetails},
-	SessionIndex,
+	EraIndex, SessionIndex,
 };
 
 pub struct OnOffenceHandler;
@@ -60,6 +63,14 @@ pub fn with_on_offence_fractions<R, F: FnOnce(&mut Vec<Perbill>) -> R>(f: F) ->
 	OnOffencePerbill::mutate(|fractions| f(fractions))
 }
 
+/// Every session is its own era.
+pub struct SessionIsEra;
+impl Convert<SessionIndex, Option<EraIndex>> for SessionIsEra {
+	fn convert(session: SessionIndex) -> Option<EraIndex> {
+		Some(session)
+	}
+}
+
 type Block = frame_system::mocking::MockBlock<Runtime>;
 
 frame_support::construct_runtime!(
@@ -83,6 +94,8 @@ impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IdentificationTuple = u64;
 	type OnOffenceHandler = OnOffenceHandler;
+	type SessionEra = SessionIsEra;
+	type MaxOffenceHistory = ConstU32<3>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
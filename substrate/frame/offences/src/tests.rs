@@ -267,3 +267,79 @@ fn should_properly_count_offences() {
 		);
 	});
 }
+
+#[test]
+fn should_keep_a_bounded_history_of_offences() {
+	new_test_ext().execute_with(|| {
+		// given
+		let time_slot = 42;
+		let offence = Offence { validator_set_count: 5, time_slot, offenders: vec![5] };
+		Offences::report_offence(vec![1], offence).unwrap();
+
+		// then
+		let record = |offender, reporters, percent| OffenceRecord {
+			kind: KIND,
+			time_slot: time_slot.encode(),
+			session_index: 1,
+			era: Some(1),
+			offender,
+			reporters,
+			slash_fraction: Perbill::from_percent(percent),
+			disposition: OffenceDisposition::Slashed,
+		};
+		assert_eq!(Offences::offences_in_era(1), vec![record(5, vec![1], 25)]);
+		assert_eq!(Offences::offences_in_era(2), vec![]);
+
+		// when
+		// the same offender is reported again alongside new ones.
+		let offence = Offence { validator_set_count: 5, time_slot, offenders: vec![5, 4, 3, 2] };
+		Offences::report_offence(vec![2], offence).unwrap();
+
+		// then
+		// only new offenders are recorded, and the oldest record is pruned.
+		assert_eq!(OffenceHistoryBounds::<Runtime>::get(), (1, 4));
+		assert_eq!(
+			Offences::offences_in_era(1),
+			vec![record(4, vec![2], 85), record(3, vec![2], 85), record(2, vec![2], 85)]
+		);
+		assert_eq!(Offences::offences_where(|r| r.offender == 3), vec![record(3, vec![2], 85)]);
+	});
+}
+
+#[test]
+fn should_weigh_the_history_of_offences() {
+	new_test_ext().execute_with(|| {
+		// given
+		let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+		let history_weight = || *System::block_weight().get(DispatchClass::Mandatory);
+		let offence = Offence { validator_set_count: 5, time_slot: 42, offenders: vec![5] };
+		Offences::report_offence(vec![], offence).unwrap();
+		assert_eq!(history_weight(), db_weight.reads_writes(1, 2));
+
+		// when
+		// the history holds at most three offences, so the first one is pruned.
+		let offence = Offence { validator_set_count: 5, time_slot: 42, offenders: vec![4, 3, 2] };
+		Offences::report_offence(vec![], offence).unwrap();
+
+		// then
+		assert_eq!(history_weight(), db_weight.reads_writes(4, 9));
+	});
+}
+
+#[test]
+fn should_query_the_history_of_offences_across_the_index_wrap() {
+	new_test_ext().execute_with(|| {
+		// given
+		OffenceHistoryBounds::<Runtime>::put((u32::MAX - 1, u32::MAX - 1));
+
+		// when
+		let offence = Offence { validator_set_count: 5, time_slot: 42, offenders: vec![5, 4, 3] };
+		Offences::report_offence(vec![], offence).unwrap();
+
+		// then
+		assert_eq!(OffenceHistoryBounds::<Runtime>::get(), (u32::MAX - 1, 1));
+		let offenders: Vec<_> =
+			Offences::offences_in_era(1).into_iter().map(|r| r.offender).collect();
+		assert_eq!(offenders, vec![5, 4, 3]);
+	});
+}
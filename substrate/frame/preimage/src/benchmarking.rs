@@ -228,6 +228,37 @@ benchmarks! {
 		assert_eq!(c, 0);
 	}
 
+	// Note a chunk of a large preimage - will reserve.
+	note_preimage_chunk {
+		let s in 0 .. MAX_CHUNK_SIZE;
+		let caller = funded_account::<T>();
+		let (preimage, hash) = sized_preimage_and_hash::<T>(s);
+	}: _(RawOrigin::Signed(caller.clone()), hash, 0, preimage)
+	verify {
+		assert!(PreimageChunks::<T>::contains_key((hash, Some(caller), 0)));
+	}
+	// Assemble the chunks of a large preimage - will unreserve for each chunk and reserve again.
+	complete_preimage_chunks {
+		let n in 1 .. MAX_CHUNKS;
+		let s in 0 .. MAX_SIZE;
+		let caller = funded_account::<T>();
+		let (preimage, hash) = sized_preimage_and_hash::<T>(s.min(n * MAX_CHUNK_SIZE));
+		note_chunks::<T>(&caller, preimage, hash, n);
+	}: _(RawOrigin::Signed(caller), hash)
+	verify {
+		assert!(Preimage::<T>::have_preimage(&hash));
+	}
+	// Clear the chunks of a large preimage - will unreserve for each chunk.
+	clear_preimage_chunks {
+		let n in 1 .. MAX_CHUNKS;
+		let caller = funded_account::<T>();
+		let (preimage, hash) = sized_preimage_and_hash::<T>(n);
+		note_chunks::<T>(&caller, preimage, hash, n);
+	}: _(RawOrigin::Signed(caller.clone()), hash)
+	verify {
+		assert!(!ChunkedUploads::<T>::contains_key(&hash, Some(caller)));
+	}
+
 	impl_benchmark_test_suite!(Preimage, crate::mock::new_test_ext(), crate::mock::Test);
 }
 
@@ -246,3 +277,16 @@ fn insert_old_unrequested<T: Config>(s: u32) -> <T as frame_system::Config>::Has
 	);
 	hash
 }
+
+fn note_chunks<T: Config>(caller: &T::AccountId, preimage: Vec<u8>, hash: T::Hash, n: u32) {
+	let len = preimage.len();
+	for i in 0..n as usize {
+		let chunk = preimage[i * len / n as usize..(i + 1) * len / n as usize].to_vec();
+		assert_ok!(Preimage::<T>::note_preimage_chunk(
+			RawOrigin::Signed(caller.clone()).into(),
+			hash,
+			i as u32,
+			chunk,
+		));
+	}
+}
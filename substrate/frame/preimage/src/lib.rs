@@ -25,6 +25,12 @@
 //! The Preimage pallet allows for the users and the runtime to store the preimage
 //! of a hash on chain. This can be used by other pallets for storing and managing
 //! large byte-blobs.
+//!
+//! Preimages which are too large to fit into a single extrinsic can be uploaded in chunks with
+//! `note_preimage_chunk`. Once all chunks are uploaded, `complete_preimage_chunks` assembles them
+//! and notes the preimage if it matches the expected hash. A deposit is taken for each chunk until
+//! then. Each account uploads its own chunks, so several uploads of the same preimage do not
+//! interfere with each other.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -91,9 +97,23 @@ pub enum RequestStatus<AccountId, Ticket> {
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 type TicketOf<T> = <T as Config>::Consideration;
+type PreimageChunkOf<T> = (Option<TicketOf<T>>, BoundedVec<u8, ConstU32<MAX_CHUNK_SIZE>>);
+
+/// A preimage which is being uploaded in chunks.
+#[derive(Clone, Default, Eq, PartialEq, Encode, Decode, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct ChunkedUpload {
+	/// The number of chunks uploaded so far.
+	count: u32,
+	/// The total length of the chunks uploaded so far.
+	len: u32,
+}
 
 /// Maximum size of preimage we can store is 4mb.
 const MAX_SIZE: u32 = 4 * 1024 * 1024;
+/// Maximum size of a single chunk of a preimage uploaded in chunks is 1mb.
+const MAX_CHUNK_SIZE: u32 = 1024 * 1024;
+/// Maximum number of chunks a preimage can be uploaded in.
+pub const MAX_CHUNKS: u32 = 64;
 /// Hard-limit on the number of hashes that can be passed to `ensure_updated`.
 ///
 /// Exists only for benchmarking purposes.
@@ -140,6 +160,10 @@ pub mod pallet {
 		Requested { hash: T::Hash },
 		/// A preimage has ben cleared.
 		Cleared { hash: T::Hash },
+		/// A chunk of a preimage has been noted.
+		ChunkNoted { hash: T::Hash, index: u32 },
+		/// The uploaded chunks of a preimage have been cleared.
+		ChunksCleared { hash: T::Hash },
 	}
 
 	#[pallet::error]
@@ -160,6 +184,16 @@ pub mod pallet {
 		TooMany,
 		/// Too few hashes were requested to be upgraded (i.e. zero).
 		TooFew,
+		/// The chunk index is not lower than `MAX_CHUNKS`.
+		BadChunkIndex,
+		/// A chunk with this index has already been noted.
+		ChunkAlreadyNoted,
+		/// No chunks have been noted for this hash.
+		NoChunks,
+		/// Not all chunks of the preimage have been noted.
+		MissingChunk,
+		/// The assembled chunks do not hash to the expected hash.
+		HashMismatch,
 	}
 
 	/// A reason for this pallet placing a hold on funds.
@@ -184,6 +218,26 @@ pub mod pallet {
 	pub(super) type PreimageFor<T: Config> =
 		StorageMap<_, Identity, (T::Hash, u32), BoundedVec<u8, ConstU32<MAX_SIZE>>>;
 
+	/// Chunks of preimages being uploaded, along with the ticket of their deposit.
+	///
+	/// Keyed by the hash of the whole preimage, the uploader (`None` for the `ManagerOrigin`) and
+	/// the index of the chunk.
+	#[pallet::storage]
+	pub(super) type PreimageChunks<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Identity, T::Hash>,
+			NMapKey<Twox64Concat, Option<T::AccountId>>,
+			NMapKey<Twox64Concat, u32>,
+		),
+		PreimageChunkOf<T>,
+	>;
+
+	/// Preimages being uploaded in chunks, keyed by their hash and uploader.
+	#[pallet::storage]
+	pub(super) type ChunkedUploads<T: Config> =
+		StorageDoubleMap<_, Identity, T::Hash, Twox64Concat, Option<T::AccountId>, ChunkedUpload>;
+
 	#[pallet::call(weight = T::WeightInfo)]
 	impl<T: Config> Pallet<T> {
 		/// Register a preimage on-chain.
@@ -255,6 +309,71 @@ pub mod pallet {
 			let pays: Pays = (ratio < Perbill::from_percent(90)).into();
 			Ok(pays.into())
 		}
+
+		/// Note a chunk of a preimage which is too large to be noted at once.
+		///
+		/// A deposit is taken proportional to the size of the chunk, unless the origin is the
+		/// `ManagerOrigin`. The chunk is added to the upload of the sender, which is kept apart
+		/// from the uploads of other accounts until it is completed with
+		/// `complete_preimage_chunks` or cleared with `clear_preimage_chunks`.
+		///
+		/// - `hash`: The hash of the whole preimage.
+		/// - `index`: The position of this chunk in the preimage, lower than `MAX_CHUNKS`.
+		/// - `data`: The bytes of the chunk.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::note_preimage_chunk(data.len() as u32))]
+		pub fn note_preimage_chunk(
+			origin: OriginFor<T>,
+			hash: T::Hash,
+			index: u32,
+			data: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let maybe_sender = Self::ensure_signed_or_manager(origin)?;
+			Self::do_note_preimage_chunk(hash, index, data, maybe_sender.as_ref())?;
+			if maybe_sender.is_none() {
+				Ok(Pays::No.into())
+			} else {
+				Ok(().into())
+			}
+		}
+
+		/// Assemble the chunks of a preimage uploaded by the sender and note it.
+		///
+		/// The chunks must have been noted with consecutive indices starting at zero, and their
+		/// concatenation must hash to `hash`. The chunk deposits are released, and the preimage
+		/// is noted as if by `note_preimage` from the sender.
+		///
+		/// - `hash`: The hash of the whole preimage.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::complete_preimage_chunks(MAX_CHUNKS, MAX_SIZE))]
+		pub fn complete_preimage_chunks(
+			origin: OriginFor<T>,
+			hash: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let maybe_sender = Self::ensure_signed_or_manager(origin)?;
+			let upload =
+				ChunkedUploads::<T>::get(hash, &maybe_sender).ok_or(Error::<T>::NoChunks)?;
+			Self::do_complete_preimage_chunks(hash, maybe_sender.as_ref(), &upload)?;
+			Ok(Some(T::WeightInfo::complete_preimage_chunks(upload.count, upload.len)).into())
+		}
+
+		/// Clear the chunks of a preimage uploaded by the sender which has not been completed,
+		/// returning their deposits.
+		///
+		/// - `hash`: The hash of the whole preimage.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::clear_preimage_chunks(MAX_CHUNKS))]
+		pub fn clear_preimage_chunks(
+			origin: OriginFor<T>,
+			hash: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let maybe_sender = Self::ensure_signed_or_manager(origin)?;
+			let upload =
+				ChunkedUploads::<T>::take(hash, &maybe_sender).ok_or(Error::<T>::NoChunks)?;
+			Self::clear_chunks(hash, maybe_sender.as_ref());
+			Self::deposit_event(Event::ChunksCleared { hash });
+			Ok(Some(T::WeightInfo::clear_preimage_chunks(upload.count)).into())
+		}
 	}
 }
 
@@ -317,6 +436,72 @@ impl<T: Config> Pallet<T> {
 		Ok(Some(who))
 	}
 
+	/// Store a chunk of a preimage on chain, taking a deposit from `maybe_depositor` if `Some`.
+	fn do_note_preimage_chunk(
+		hash: T::Hash,
+		index: u32,
+		data: Vec<u8>,
+		maybe_depositor: Option<&T::AccountId>,
+	) -> DispatchResult {
+		ensure!(index < MAX_CHUNKS, Error::<T>::BadChunkIndex);
+		ensure!(Self::len(&hash).is_none(), Error::<T>::AlreadyNoted);
+		let owner = maybe_depositor.cloned();
+		ensure!(
+			!PreimageChunks::<T>::contains_key((hash, &owner, index)),
+			Error::<T>::ChunkAlreadyNoted
+		);
+		let mut upload = ChunkedUploads::<T>::get(hash, &owner).unwrap_or_default();
+		let len = data.len() as u32;
+		ensure!(upload.len.saturating_add(len) <= MAX_SIZE, Error::<T>::TooBig);
+		let data: BoundedVec<u8, ConstU32<MAX_CHUNK_SIZE>> =
+			data.try_into().map_err(|_| Error::<T>::TooBig)?;
+
+		let maybe_ticket = match maybe_depositor {
+			Some(depositor) =>
+				Some(T::Consideration::new(depositor, Footprint::from_parts(1, len as usize))?),
+			None => None,
+		};
+		PreimageChunks::<T>::insert((hash, &owner, index), (maybe_ticket, data));
+		upload.count.saturating_inc();
+		upload.len.saturating_accrue(len);
+		ChunkedUploads::<T>::insert(hash, owner, upload);
+
+		Self::deposit_event(Event::ChunkNoted { hash, index });
+		Ok(())
+	}
+
+	/// Assemble the chunks of `upload` by `maybe_owner` and note them as the preimage of `hash`.
+	fn do_complete_preimage_chunks(
+		hash: T::Hash,
+		maybe_owner: Option<&T::AccountId>,
+		upload: &ChunkedUpload,
+	) -> DispatchResult {
+		let mut preimage = Vec::with_capacity(upload.len as usize);
+		for index in 0..upload.count {
+			let (_, data) = PreimageChunks::<T>::get((hash, maybe_owner, index))
+				.ok_or(Error::<T>::MissingChunk)?;
+			preimage.extend_from_slice(&data);
+		}
+		ensure!(T::Hashing::hash(&preimage) == hash, Error::<T>::HashMismatch);
+
+		ChunkedUploads::<T>::remove(hash, maybe_owner);
+		Self::clear_chunks(hash, maybe_owner);
+		Self::note_bytes(preimage.into(), maybe_owner)?;
+		Ok(())
+	}
+
+	/// Remove the chunks of `hash` uploaded by `maybe_owner`, dropping the tickets of their
+	/// deposits.
+	fn clear_chunks(hash: T::Hash, maybe_owner: Option<&T::AccountId>) {
+		for (_, (maybe_ticket, _)) in
+			PreimageChunks::<T>::drain_prefix((hash, maybe_owner.cloned()))
+		{
+			if let (Some(owner), Some(ticket)) = (maybe_owner, maybe_ticket) {
+				let _ = ticket.drop(owner);
+			}
+		}
+	}
+
 	/// Store some preimage on chain.
 	///
 	/// If `maybe_depositor` is `None` then it is also requested. If `Some`, then it is not.
@@ -520,3 +520,129 @@ fn ensure_updated_works() {
 		}
 	});
 }
+
+#[test]
+fn chunked_preimage_works() {
+	new_test_ext().execute_with(|| {
+		let h = hashed([1, 2, 3]);
+		assert_ok!(Preimage::note_preimage_chunk(RuntimeOrigin::signed(2), h, 0, vec![1, 2]));
+		assert_noop!(
+			Preimage::note_preimage_chunk(RuntimeOrigin::signed(2), h, 0, vec![1, 2]),
+			Error::<Test>::ChunkAlreadyNoted,
+		);
+		assert_ok!(Preimage::note_preimage_chunk(RuntimeOrigin::signed(2), h, 1, vec![3]));
+		// A deposit is held for each chunk.
+		assert_eq!(Balances::balance_on_hold(&PreimageHoldReason::get(), &2), 7);
+		assert!(!Preimage::have_preimage(&h));
+
+		// Other accounts have no upload to complete.
+		assert_noop!(
+			Preimage::complete_preimage_chunks(RuntimeOrigin::signed(3), h),
+			Error::<Test>::NoChunks,
+		);
+		assert_ok!(Preimage::complete_preimage_chunks(RuntimeOrigin::signed(2), h));
+		// The chunk deposits are replaced by the deposit of the whole preimage.
+		assert_eq!(Balances::balance_on_hold(&PreimageHoldReason::get(), &2), 5);
+		assert_eq!(Preimage::get_preimage(&h), Some(vec![1, 2, 3]));
+		assert_eq!(ChunkedUploads::<Test>::iter().count(), 0);
+		assert_eq!(PreimageChunks::<Test>::iter().count(), 0);
+
+		// The preimage can not be uploaded again.
+		assert_noop!(
+			Preimage::note_preimage_chunk(RuntimeOrigin::signed(2), h, 0, vec![1]),
+			Error::<Test>::AlreadyNoted,
+		);
+	});
+}
+
+#[test]
+fn chunked_preimage_is_verified() {
+	new_test_ext().execute_with(|| {
+		let h = hashed([1, 2, 3]);
+		assert_noop!(
+			Preimage::note_preimage_chunk(RuntimeOrigin::signed(2), h, MAX_CHUNKS, vec![1]),
+			Error::<Test>::BadChunkIndex,
+		);
+		assert_noop!(
+			Preimage::complete_preimage_chunks(RuntimeOrigin::signed(2), h),
+			Error::<Test>::NoChunks,
+		);
+		assert_ok!(Preimage::note_preimage_chunk(RuntimeOrigin::signed(2), h, 0, vec![1]));
+		assert_ok!(Preimage::note_preimage_chunk(RuntimeOrigin::signed(2), h, 2, vec![3]));
+		assert_noop!(
+			Preimage::complete_preimage_chunks(RuntimeOrigin::signed(2), h),
+			Error::<Test>::MissingChunk,
+		);
+		assert_ok!(Preimage::note_preimage_chunk(RuntimeOrigin::signed(2), h, 1, vec![4]));
+		assert_noop!(
+			Preimage::complete_preimage_chunks(RuntimeOrigin::signed(2), h),
+			Error::<Test>::HashMismatch,
+		);
+
+		// The owner gets their deposits back when clearing the chunks.
+		assert_noop!(
+			Preimage::clear_preimage_chunks(RuntimeOrigin::signed(3), h),
+			Error::<Test>::NoChunks,
+		);
+		assert_ok!(Preimage::clear_preimage_chunks(RuntimeOrigin::signed(2), h));
+		assert_eq!(Balances::balance_on_hold(&PreimageHoldReason::get(), &2), 0);
+		assert_eq!(ChunkedUploads::<Test>::iter().count(), 0);
+		assert_eq!(PreimageChunks::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn chunked_uploads_of_other_accounts_do_not_block() {
+	new_test_ext().execute_with(|| {
+		let h = hashed([1, 2, 3]);
+		// Account 3 squats on the hash with a bogus chunk.
+		assert_ok!(Preimage::note_preimage_chunk(RuntimeOrigin::signed(3), h, 0, vec![9]));
+		assert_ok!(Preimage::note_preimage_chunk(RuntimeOrigin::signed(3), h, 1, vec![9]));
+
+		// Account 2 can still upload and complete the preimage.
+		assert_ok!(Preimage::note_preimage_chunk(RuntimeOrigin::signed(2), h, 0, vec![1]));
+		assert_ok!(Preimage::note_preimage_chunk(RuntimeOrigin::signed(2), h, 1, vec![2, 3]));
+		assert_noop!(
+			Preimage::complete_preimage_chunks(RuntimeOrigin::signed(3), h),
+			Error::<Test>::HashMismatch,
+		);
+		assert_ok!(Preimage::complete_preimage_chunks(RuntimeOrigin::signed(2), h));
+		assert_eq!(Preimage::get_preimage(&h), Some(vec![1, 2, 3]));
+		assert!(!ChunkedUploads::<Test>::contains_key(&h, Some(2)));
+
+		// The stale upload can no longer grow, but its owner can still clear it.
+		assert_noop!(
+			Preimage::note_preimage_chunk(RuntimeOrigin::signed(3), h, 2, vec![9]),
+			Error::<Test>::AlreadyNoted,
+		);
+		assert_ok!(Preimage::clear_preimage_chunks(RuntimeOrigin::signed(3), h));
+		assert_eq!(Balances::balance_on_hold(&PreimageHoldReason::get(), &3), 0);
+		assert_eq!(ChunkedUploads::<Test>::iter().count(), 0);
+		assert_eq!(PreimageChunks::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn manager_chunked_upload_is_kept_apart() {
+	new_test_ext().execute_with(|| {
+		let h = hashed([1, 2, 3]);
+		assert_ok!(Preimage::note_preimage_chunk(RuntimeOrigin::signed(2), h, 0, vec![1]));
+
+		// The `ManagerOrigin` uploads its own chunks without a deposit.
+		assert_ok!(Preimage::note_preimage_chunk(RuntimeOrigin::signed(1), h, 0, vec![1, 2]));
+		assert_ok!(Preimage::note_preimage_chunk(RuntimeOrigin::signed(1), h, 1, vec![3]));
+		assert_eq!(Balances::balance_on_hold(&PreimageHoldReason::get(), &1), 0);
+		assert_eq!(ChunkedUploads::<Test>::get(&h, None::<u64>).map(|u| u.count), Some(2));
+		assert_ok!(Preimage::complete_preimage_chunks(RuntimeOrigin::signed(1), h));
+		assert!(Preimage::have_preimage(&h));
+		assert!(!ChunkedUploads::<Test>::contains_key(&h, None::<u64>));
+
+		// The upload of account 2 is untouched.
+		assert_eq!(
+			PreimageChunks::<Test>::get((h, Some(2), 0)).map(|(_, data)| data.to_vec()),
+			Some(vec![1])
+		);
+		assert_ok!(Preimage::clear_preimage_chunks(RuntimeOrigin::signed(2), h));
+		assert_eq!(Balances::balance_on_hold(&PreimageHoldReason::get(), &2), 0);
+	});
+}
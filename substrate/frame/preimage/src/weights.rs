@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-wiukf8gn-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `note_preimage_chunk`, `complete_preimage_chunks` and `clear_preimage_chunks`. Re-run
+//! the benchmarks before relying on them.
 
 // Executed Command:
 // ./target/production/substrate-node
@@ -64,6 +68,9 @@ pub trait WeightInfo {
 	fn unrequest_unnoted_preimage() -> Weight;
 	fn unrequest_multi_referenced_preimage() -> Weight;
 	fn ensure_updated(n: u32, ) -> Weight;
+	fn note_preimage_chunk(s: u32, ) -> Weight;
+	fn complete_preimage_chunks(n: u32, s: u32, ) -> Weight;
+	fn clear_preimage_chunks(n: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_preimage` using the Substrate node and recommended hardware.
@@ -276,6 +283,79 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2830).saturating_mul(n.into()))
 	}
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:0)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::PreimageChunks` (r:1 w:1)
+	/// Proof: `Preimage::PreimageChunks` (`max_values`: None, `max_size`: Some(1048641), added: 1051116, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::ChunkedUploads` (r:1 w:1)
+	/// Proof: `Preimage::ChunkedUploads` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(355), added: 2830, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 1048576]`.
+	fn note_preimage_chunk(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `1052106`
+		// Minimum execution time: 48_305_000 picoseconds.
+		Weight::from_parts(48_305_000, 1052106)
+			// Standard Error: 6
+			.saturating_add(Weight::from_parts(2_418, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Preimage::ChunkedUploads` (r:1 w:1)
+	/// Proof: `Preimage::ChunkedUploads` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::PreimageChunks` (r:64 w:64)
+	/// Proof: `Preimage::PreimageChunks` (`max_values`: None, `max_size`: Some(1048641), added: 1051116, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(355), added: 2830, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::PreimageFor` (r:0 w:1)
+	/// Proof: `Preimage::PreimageFor` (`max_values`: None, `max_size`: Some(4194344), added: 4196819, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 64]`.
+	/// The range of component `s` is `[0, 4194304]`.
+	fn complete_preimage_chunks(n: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `221 + n * (1048611 ±0) + s * (1 ±0)`
+		//  Estimated: `3820 + n * (1051116 ±0)`
+		// Minimum execution time: 71_644_000 picoseconds.
+		Weight::from_parts(71_644_000, 3820)
+			// Standard Error: 104_116
+			.saturating_add(Weight::from_parts(8_311_027, 0).saturating_mul(n.into()))
+			// Standard Error: 12
+			.saturating_add(Weight::from_parts(4_109, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 1051116).saturating_mul(n.into()))
+	}
+	/// Storage: `Preimage::ChunkedUploads` (r:1 w:1)
+	/// Proof: `Preimage::ChunkedUploads` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::PreimageChunks` (r:64 w:64)
+	/// Proof: `Preimage::PreimageChunks` (`max_values`: None, `max_size`: Some(1048641), added: 1051116, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(355), added: 2830, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 64]`.
+	fn clear_preimage_chunks(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `221 + n * (1048611 ±0)`
+		//  Estimated: `3820 + n * (1051116 ±0)`
+		// Minimum execution time: 29_816_000 picoseconds.
+		Weight::from_parts(29_816_000, 3820)
+			// Standard Error: 61_877
+			.saturating_add(Weight::from_parts(3_925_442, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 1051116).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -487,4 +567,77 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2830).saturating_mul(n.into()))
 	}
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:0)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::PreimageChunks` (r:1 w:1)
+	/// Proof: `Preimage::PreimageChunks` (`max_values`: None, `max_size`: Some(1048641), added: 1051116, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::ChunkedUploads` (r:1 w:1)
+	/// Proof: `Preimage::ChunkedUploads` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(355), added: 2830, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[0, 1048576]`.
+	fn note_preimage_chunk(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `1052106`
+		// Minimum execution time: 48_305_000 picoseconds.
+		Weight::from_parts(48_305_000, 1052106)
+			// Standard Error: 6
+			.saturating_add(Weight::from_parts(2_418, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Preimage::ChunkedUploads` (r:1 w:1)
+	/// Proof: `Preimage::ChunkedUploads` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::PreimageChunks` (r:64 w:64)
+	/// Proof: `Preimage::PreimageChunks` (`max_values`: None, `max_size`: Some(1048641), added: 1051116, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::StatusFor` (r:1 w:0)
+	/// Proof: `Preimage::StatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::RequestStatusFor` (r:1 w:1)
+	/// Proof: `Preimage::RequestStatusFor` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(355), added: 2830, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::PreimageFor` (r:0 w:1)
+	/// Proof: `Preimage::PreimageFor` (`max_values`: None, `max_size`: Some(4194344), added: 4196819, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 64]`.
+	/// The range of component `s` is `[0, 4194304]`.
+	fn complete_preimage_chunks(n: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `221 + n * (1048611 ±0) + s * (1 ±0)`
+		//  Estimated: `3820 + n * (1051116 ±0)`
+		// Minimum execution time: 71_644_000 picoseconds.
+		Weight::from_parts(71_644_000, 3820)
+			// Standard Error: 104_116
+			.saturating_add(Weight::from_parts(8_311_027, 0).saturating_mul(n.into()))
+			// Standard Error: 12
+			.saturating_add(Weight::from_parts(4_109, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 1051116).saturating_mul(n.into()))
+	}
+	/// Storage: `Preimage::ChunkedUploads` (r:1 w:1)
+	/// Proof: `Preimage::ChunkedUploads` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Preimage::PreimageChunks` (r:64 w:64)
+	/// Proof: `Preimage::PreimageChunks` (`max_values`: None, `max_size`: Some(1048641), added: 1051116, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(355), added: 2830, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 64]`.
+	fn clear_preimage_chunks(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `221 + n * (1048611 ±0)`
+		//  Estimated: `3820 + n * (1051116 ±0)`
+		// Minimum execution time: 29_816_000 picoseconds.
+		Weight::from_parts(29_816_000, 3820)
+			// Standard Error: 61_877
+			.saturating_add(Weight::from_parts(3_925_442, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 1051116).saturating_mul(n.into()))
+	}
 }
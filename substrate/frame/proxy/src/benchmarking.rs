@@ -118,10 +118,10 @@ mod benchmarks {
 		let real_lookup = T::Lookup::unlookup(real);
 		let call: <T as Config>::RuntimeCall =
 			frame_system::Call::<T>::remark { remark: vec![] }.into();
-		Proxy::<T>::announce(
+		Proxy::<T>::announce_with_call(
 			RawOrigin::Signed(delegate.clone()).into(),
 			real_lookup.clone(),
-			T::CallHasher::hash_of(&call),
+			Box::new(call.clone()),
 		)?;
 		add_announcements::<T>(a, Some(delegate.clone()), None)?;
 
@@ -153,10 +153,10 @@ mod benchmarks {
 		let real_lookup = T::Lookup::unlookup(real);
 		let call: <T as Config>::RuntimeCall =
 			frame_system::Call::<T>::remark { remark: vec![] }.into();
-		Proxy::<T>::announce(
+		Proxy::<T>::announce_with_call(
 			RawOrigin::Signed(caller.clone()).into(),
 			real_lookup.clone(),
-			T::CallHasher::hash_of(&call),
+			Box::new(call.clone()),
 		)?;
 		add_announcements::<T>(a, Some(caller.clone()), None)?;
 
@@ -184,10 +184,10 @@ mod benchmarks {
 		let real_lookup = T::Lookup::unlookup(real.clone());
 		let call: <T as Config>::RuntimeCall =
 			frame_system::Call::<T>::remark { remark: vec![] }.into();
-		Proxy::<T>::announce(
+		Proxy::<T>::announce_with_call(
 			RawOrigin::Signed(caller.clone()).into(),
 			real_lookup,
-			T::CallHasher::hash_of(&call),
+			Box::new(call.clone()),
 		)?;
 		add_announcements::<T>(a, Some(caller.clone()), None)?;
 
@@ -225,6 +225,86 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn announce_with_call(
+		a: Linear<0, { T::MaxPending::get() - 1 }>,
+		p: Linear<1, { T::MaxProxies::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		add_proxies::<T>(p, None)?;
+		// In this case the caller is the "target" proxy
+		let caller: T::AccountId = account("target", p - 1, SEED);
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		// ... and "real" is the traditional caller. This is not a typo.
+		let real: T::AccountId = whitelisted_caller();
+		let real_lookup = T::Lookup::unlookup(real.clone());
+		add_announcements::<T>(a, Some(caller.clone()), None)?;
+		let call: <T as Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let call_hash = T::CallHasher::hash_of(&call);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), real_lookup, Box::new(call));
+
+		assert!(AnnouncedCalls::<T>::contains_key(&caller, call_hash));
+		assert_last_event::<T>(Event::Announced { real, proxy: caller, call_hash }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_watchdog() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let watchdog: T::AccountId = account("watchdog", 0, SEED);
+		let watchdog_lookup = T::Lookup::unlookup(watchdog.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), Some(watchdog_lookup));
+
+		assert_last_event::<T>(
+			Event::WatchdogSet { real: caller, watchdog: Some(watchdog) }.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn veto_announcement(
+		a: Linear<0, { T::MaxPending::get() - 1 }>,
+		p: Linear<1, { T::MaxProxies::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		add_proxies::<T>(p, None)?;
+		// In this case the caller is the "target" proxy
+		let delegate: T::AccountId = account("target", p - 1, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		T::Currency::make_free_balance_be(&delegate, BalanceOf::<T>::max_value() / 2u32.into());
+		// ... and "real" is the traditional caller. This is not a typo.
+		let real: T::AccountId = whitelisted_caller();
+		let real_lookup = T::Lookup::unlookup(real.clone());
+		let watchdog: T::AccountId = account("watchdog", 0, SEED);
+		Proxy::<T>::set_watchdog(
+			RawOrigin::Signed(real.clone()).into(),
+			Some(T::Lookup::unlookup(watchdog.clone())),
+		)?;
+		let call: <T as Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let call_hash = T::CallHasher::hash_of(&call);
+		Proxy::<T>::announce_with_call(
+			RawOrigin::Signed(delegate.clone()).into(),
+			real_lookup.clone(),
+			Box::new(call),
+		)?;
+		add_announcements::<T>(a, Some(delegate.clone()), None)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(watchdog), real_lookup, delegate_lookup, call_hash);
+
+		let (announcements, _) = Announcements::<T>::get(&delegate);
+		assert_eq!(announcements.len() as u32, a);
+
+		Ok(())
+	}
+
 	#[benchmark]
 	fn add_proxy(p: Linear<1, { T::MaxProxies::get() - 1 }>) -> Result<(), BenchmarkError> {
 		add_proxies::<T>(p, None)?;
@@ -23,6 +23,10 @@
 //! wish to execute some duration prior to execution happens. In this case, the target account may
 //! reject the announcement and in doing so, veto the execution.
 //!
+//! An announcement may carry the call itself rather than only its hash, so that it can be
+//! reviewed before it is executed. An account may also appoint a watchdog, which can veto the
+//! announcements of its proxies without being able to make any call on its behalf.
+//!
 //! - [`Config`]
 //! - [`Call`]
 
@@ -34,10 +38,10 @@ mod tests;
 pub mod weights;
 
 extern crate alloc;
-use alloc::{boxed::Box, vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 use frame::{
 	prelude::*,
-	traits::{Currency, ReservableCurrency},
+	traits::{Currency, InspectNestedCalls, ReservableCurrency},
 };
 pub use pallet::*;
 pub use weights::WeightInfo;
@@ -163,6 +167,15 @@ pub mod pallet {
 		/// into a pre-existing storage value.
 		#[pallet::constant]
 		type AnnouncementDepositFactor: Get<BalanceOf<Self>>;
+
+		/// The maximum length of the encoded call attached to an announcement.
+		#[pallet::constant]
+		type MaxCallPreviewLen: Get<u32>;
+
+		/// The amount of currency needed per byte of the encoded call attached to an
+		/// announcement.
+		#[pallet::constant]
+		type CallPreviewDepositPerByte: Get<BalanceOf<Self>>;
 	}
 
 	#[pallet::call]
@@ -370,35 +383,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let real = T::Lookup::lookup(real)?;
-			Proxies::<T>::get(&real)
-				.0
-				.into_iter()
-				.find(|x| x.delegate == who)
-				.ok_or(Error::<T>::NotProxy)?;
-
-			let announcement = Announcement {
-				real: real.clone(),
-				call_hash,
-				height: frame_system::Pallet::<T>::block_number(),
-			};
-
-			Announcements::<T>::try_mutate(&who, |(ref mut pending, ref mut deposit)| {
-				pending.try_push(announcement).map_err(|_| Error::<T>::TooMany)?;
-				Self::rejig_deposit(
-					&who,
-					*deposit,
-					T::AnnouncementDepositBase::get(),
-					T::AnnouncementDepositFactor::get(),
-					pending.len(),
-				)
-				.map(|d| {
-					d.expect("Just pushed; pending.len() > 0; rejig_deposit returns Some; qed")
-				})
-				.map(|d| *deposit = d)
-			})?;
-			Self::deposit_event(Event::Announced { real, proxy: who, call_hash });
-
-			Ok(())
+			Self::do_announce(who, real, call_hash)
 		}
 
 		/// Remove a given announcement.
@@ -502,6 +487,124 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Publish the announcement of a proxy-call that will be made in the future, along with
+		/// the call itself.
+		///
+		/// Same as [`Pallet::announce`], except that the encoded call is kept on chain so that it
+		/// can be reviewed by `real` and its watchdog before it is executed. Unless the sender
+		/// already announced the same call, this takes an additional deposit of
+		/// `CallPreviewDepositPerByte` per byte of the encoded call.
+		///
+		/// The dispatch origin for this call must be _Signed_ and a proxy of `real`.
+		///
+		/// Parameters:
+		/// - `real`: The account that the proxy will make a call on behalf of.
+		/// - `call`: The call to be made by the `real` account.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::announce_with_call(
+			T::MaxPending::get(),
+			T::MaxProxies::get()
+		))]
+		pub fn announce_with_call(
+			origin: OriginFor<T>,
+			real: AccountIdLookupOf<T>,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let real = T::Lookup::lookup(real)?;
+			let encoded_call: BoundedVec<u8, T::MaxCallPreviewLen> =
+				call.encode().try_into().map_err(|_| Error::<T>::CallTooLong)?;
+			let call_hash = T::CallHasher::hash_of(&call);
+			Self::do_announce(who.clone(), real, call_hash)?;
+
+			if !AnnouncedCalls::<T>::contains_key(&who, call_hash) {
+				let deposit =
+					T::CallPreviewDepositPerByte::get() * (encoded_call.len() as u32).into();
+				T::Currency::reserve(&who, deposit)?;
+				AnnouncedCalls::<T>::insert(&who, call_hash, (encoded_call, deposit));
+			}
+
+			Ok(())
+		}
+
+		/// Set or clear the watchdog of the sender.
+		///
+		/// The watchdog may veto the calls announced by the proxies of the sender through
+		/// [`Pallet::veto_announcement`], without being able to make any call on its behalf.
+		///
+		/// Setting a watchdog takes a deposit of `ProxyDepositFactor`, which is returned when it
+		/// is cleared.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `watchdog`: The new watchdog of the sender, or `None` to clear it.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::set_watchdog())]
+		pub fn set_watchdog(
+			origin: OriginFor<T>,
+			watchdog: Option<AccountIdLookupOf<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let watchdog = watchdog.map(T::Lookup::lookup).transpose()?;
+
+			let old_deposit =
+				Watchdogs::<T>::get(&who).map_or_else(Zero::zero, |(_, deposit)| deposit);
+			let new_deposit =
+				if watchdog.is_some() { T::ProxyDepositFactor::get() } else { Zero::zero() };
+			if new_deposit > old_deposit {
+				T::Currency::reserve(&who, new_deposit - old_deposit)?;
+			} else if new_deposit < old_deposit {
+				T::Currency::unreserve(&who, old_deposit - new_deposit);
+			}
+
+			match watchdog {
+				Some(ref watchdog) => Watchdogs::<T>::insert(&who, (watchdog.clone(), new_deposit)),
+				None => Watchdogs::<T>::remove(&who),
+			}
+			Self::deposit_event(Event::WatchdogSet { real: who, watchdog });
+
+			Ok(())
+		}
+
+		/// Remove the given announcement of a delegate of an account the sender is the watchdog
+		/// of.
+		///
+		/// Same as [`Pallet::reject_announcement`], but on behalf of `real`. The deposit is
+		/// returned to `delegate`.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the watchdog of `real`.
+		///
+		/// Parameters:
+		/// - `real`: The account the call was announced on behalf of.
+		/// - `delegate`: The account that previously announced the call.
+		/// - `call_hash`: The hash of the call to be made.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::veto_announcement(
+			T::MaxPending::get(),
+			T::MaxProxies::get()
+		))]
+		pub fn veto_announcement(
+			origin: OriginFor<T>,
+			real: AccountIdLookupOf<T>,
+			delegate: AccountIdLookupOf<T>,
+			call_hash: CallHashOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let real = T::Lookup::lookup(real)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			ensure!(
+				Watchdogs::<T>::get(&real).map_or(false, |(watchdog, _)| watchdog == who),
+				Error::<T>::NotWatchdog
+			);
+			Self::edit_announcements(&delegate, |ann| {
+				ann.real != real || ann.call_hash != call_hash
+			})?;
+			Self::deposit_event(Event::AnnouncementVetoed { real, delegate, call_hash });
+
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -533,6 +636,10 @@ pub mod pallet {
 			proxy_type: T::ProxyType,
 			delay: BlockNumberFor<T>,
 		},
+		/// The watchdog of an account was set or cleared.
+		WatchdogSet { real: T::AccountId, watchdog: Option<T::AccountId> },
+		/// An announcement was vetoed by the watchdog of the account it was made on behalf of.
+		AnnouncementVetoed { real: T::AccountId, delegate: T::AccountId, call_hash: CallHashOf<T> },
 	}
 
 	#[pallet::error]
@@ -553,6 +660,10 @@ pub mod pallet {
 		Unannounced,
 		/// Cannot add self as proxy.
 		NoSelfProxy,
+		/// The call attached to an announcement is too long.
+		CallTooLong,
+		/// Sender is not the watchdog of the account.
+		NotWatchdog,
 	}
 
 	/// The set of account proxies. Maps the account which has delegated to the accounts
@@ -584,6 +695,24 @@ pub mod pallet {
 		),
 		ValueQuery,
 	>;
+
+	/// The encoded calls attached to the announcements made by the proxy (first key), by call
+	/// hash, together with the amount held on deposit.
+	#[pallet::storage]
+	pub type AnnouncedCalls<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Identity,
+		CallHashOf<T>,
+		(BoundedVec<u8, T::MaxCallPreviewLen>, BalanceOf<T>),
+	>;
+
+	/// The watchdog of an account (key), allowed to veto the announcements of its proxies,
+	/// together with the amount held on deposit.
+	#[pallet::storage]
+	pub type Watchdogs<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (T::AccountId, BalanceOf<T>)>;
 }
 
 impl<T: Config> Pallet<T> {
@@ -743,17 +872,65 @@ impl<T: Config> Pallet<T> {
 		Ok(if len == 0 { None } else { Some(new_deposit) })
 	}
 
+	fn do_announce(
+		who: T::AccountId,
+		real: T::AccountId,
+		call_hash: CallHashOf<T>,
+	) -> DispatchResult {
+		Proxies::<T>::get(&real)
+			.0
+			.into_iter()
+			.find(|x| x.delegate == who)
+			.ok_or(Error::<T>::NotProxy)?;
+
+		let announcement = Announcement {
+			real: real.clone(),
+			call_hash,
+			height: frame_system::Pallet::<T>::block_number(),
+		};
+
+		Announcements::<T>::try_mutate(&who, |(ref mut pending, ref mut deposit)| {
+			pending.try_push(announcement).map_err(|_| Error::<T>::TooMany)?;
+			Self::rejig_deposit(
+				&who,
+				*deposit,
+				T::AnnouncementDepositBase::get(),
+				T::AnnouncementDepositFactor::get(),
+				pending.len(),
+			)
+			.map(|d| d.expect("Just pushed; pending.len() > 0; rejig_deposit returns Some; qed"))
+			.map(|d| *deposit = d)
+		})?;
+		Self::deposit_event(Event::Announced { real, proxy: who, call_hash });
+
+		Ok(())
+	}
+
 	fn edit_announcements<
 		F: FnMut(&Announcement<T::AccountId, CallHashOf<T>, BlockNumberFor<T>>) -> bool,
 	>(
 		delegate: &T::AccountId,
-		f: F,
+		mut f: F,
 	) -> DispatchResult {
 		Announcements::<T>::try_mutate_exists(delegate, |x| {
 			let (mut pending, old_deposit) = x.take().ok_or(Error::<T>::NotFound)?;
-			let orig_pending_len = pending.len();
-			pending.retain(f);
-			ensure!(orig_pending_len > pending.len(), Error::<T>::NotFound);
+			let mut removed = Vec::new();
+			pending.retain(|ann| {
+				let keep = f(ann);
+				if !keep {
+					removed.push(ann.call_hash);
+				}
+				keep
+			});
+			ensure!(!removed.is_empty(), Error::<T>::NotFound);
+			// The attached calls are only dropped once no pending announcement refers to them.
+			for call_hash in removed {
+				if !pending.iter().any(|ann| ann.call_hash == call_hash) {
+					if let Some((_, deposit)) = AnnouncedCalls::<T>::take(delegate, call_hash) {
+						T::Currency::unreserve(delegate, deposit);
+					}
+				}
+			}
 			*x = Self::rejig_deposit(
 				delegate,
 				old_deposit,
@@ -817,3 +994,13 @@ impl<T: Config> Pallet<T> {
 		T::Currency::unreserve(&delegator, old_deposit);
 	}
 }
+
+impl<T: Config> InspectNestedCalls<<T as Config>::RuntimeCall> for Pallet<T> {
+	fn nested_calls(call: &<T as Config>::RuntimeCall) -> Vec<&<T as Config>::RuntimeCall> {
+		match call.is_sub_type() {
+			Some(Call::proxy { call, .. }) | Some(Call::proxy_announced { call, .. }) =>
+				vec![&**call],
+			_ => Vec::new(),
+		}
+	}
+}
@@ -119,6 +119,8 @@ impl Config for Test {
 	type MaxPending = ConstU32<2>;
 	type AnnouncementDepositBase = ConstU64<1>;
 	type AnnouncementDepositFactor = ConstU64<1>;
+	type MaxCallPreviewLen = ConstU32<1024>;
+	type CallPreviewDepositPerByte = ConstU64<1>;
 }
 
 use super::{Call as ProxyCall, Event as ProxyEvent};
@@ -304,6 +306,75 @@ fn proxy_announced_removes_announcement_and_returns_deposit() {
 	});
 }
 
+#[test]
+fn announce_with_call_keeps_call_until_last_announcement_is_gone() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 1));
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(2), 3, ProxyType::Any, 1));
+		let call = Box::new(RuntimeCall::System(SystemCall::remark { remark: vec![] }));
+		let call_hash = BlakeTwo256::hash_of(&call);
+		let long_call = Box::new(RuntimeCall::System(SystemCall::remark { remark: vec![0; 1024] }));
+		assert_noop!(
+			Proxy::announce_with_call(RuntimeOrigin::signed(3), 1, long_call),
+			Error::<Test>::CallTooLong
+		);
+
+		assert_ok!(Proxy::announce_with_call(RuntimeOrigin::signed(3), 1, call.clone()));
+		System::assert_last_event(ProxyEvent::Announced { real: 1, proxy: 3, call_hash }.into());
+		assert_eq!(
+			AnnouncedCalls::<Test>::get(3, call_hash),
+			Some((call.encode().try_into().unwrap(), 3))
+		);
+		assert_eq!(Balances::reserved_balance(3), 2 + 3);
+
+		// Announcing the same call again doesn't take another deposit for it.
+		assert_ok!(Proxy::announce_with_call(RuntimeOrigin::signed(3), 2, call.clone()));
+		assert_eq!(Balances::reserved_balance(3), 3 + 3);
+
+		// The call is kept as long as some announcement refers to it.
+		assert_ok!(Proxy::remove_announcement(RuntimeOrigin::signed(3), 1, call_hash));
+		assert!(AnnouncedCalls::<Test>::contains_key(3, call_hash));
+		assert_eq!(Balances::reserved_balance(3), 2 + 3);
+
+		frame_system::Pallet::<Test>::set_block_number(2);
+		assert_ok!(Proxy::proxy_announced(RuntimeOrigin::signed(0), 3, 2, None, call));
+		assert!(!AnnouncedCalls::<Test>::contains_key(3, call_hash));
+		assert_eq!(Balances::reserved_balance(3), 0);
+	});
+}
+
+#[test]
+fn watchdog_can_veto_announcements() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(1), 3, ProxyType::Any, 1));
+		assert_ok!(Proxy::set_watchdog(RuntimeOrigin::signed(1), Some(4)));
+		System::assert_last_event(ProxyEvent::WatchdogSet { real: 1, watchdog: Some(4) }.into());
+		assert_eq!(Watchdogs::<Test>::get(1), Some((4, 1)));
+		assert_eq!(Balances::reserved_balance(1), 2 + 1);
+
+		assert_ok!(Proxy::announce(RuntimeOrigin::signed(3), 1, [1; 32].into()));
+		assert_noop!(
+			Proxy::veto_announcement(RuntimeOrigin::signed(2), 1, 3, [1; 32].into()),
+			Error::<Test>::NotWatchdog
+		);
+		assert_noop!(
+			Proxy::veto_announcement(RuntimeOrigin::signed(4), 1, 3, [2; 32].into()),
+			Error::<Test>::NotFound
+		);
+		assert_ok!(Proxy::veto_announcement(RuntimeOrigin::signed(4), 1, 3, [1; 32].into()));
+		System::assert_last_event(
+			ProxyEvent::AnnouncementVetoed { real: 1, delegate: 3, call_hash: [1; 32].into() }
+				.into(),
+		);
+		assert!(Announcements::<Test>::get(3).0.is_empty());
+		assert_eq!(Balances::reserved_balance(3), 0);
+
+		assert_ok!(Proxy::set_watchdog(RuntimeOrigin::signed(1), None));
+		assert_eq!(Watchdogs::<Test>::get(1), None);
+		assert_eq!(Balances::reserved_balance(1), 2);
+	});
+}
+
 #[test]
 fn filtering_works() {
 	new_test_ext().execute_with(|| {
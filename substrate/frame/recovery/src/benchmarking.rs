@@ -116,6 +116,8 @@ mod benchmarks {
 			frame_system::Call::<T>::remark { remark: vec![] }.into();
 
 		Proxy::<T>::insert(&caller, &recovered_account);
+		// The veto period has already passed.
+		VetoDeadlines::<T>::insert(&caller, BlockNumberFor::<T>::zero());
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller), recovered_account_lookup, Box::new(call))
@@ -238,6 +240,13 @@ mod benchmarks {
 		// Create the recovery config storage item
 		<Recoverable<T>>::insert(&lost_account, recovery_config.clone());
 
+		// Weigh every friend and set a veto period
+		let recovery_options = RecoveryOptions {
+			friend_weights: vec![1; n as usize].try_into().unwrap(),
+			veto_period: 1u32.into(),
+		};
+		RecoverableOptions::<T>::insert(&lost_account, recovery_options);
+
 		// Reserve deposit for recovery
 		T::Currency::reserve(&caller, total_deposit).unwrap();
 
@@ -297,8 +306,14 @@ mod benchmarks {
 		// Create the active recovery storage item
 		<ActiveRecoveries<T>>::insert(&caller, &rescuer_account, recovery_status);
 
+		// The rescuer claimed the recovery, and the veto period is ongoing
+		frame_system::Pallet::<T>::inc_consumers(&rescuer_account).unwrap();
+		Proxy::<T>::insert(&rescuer_account, &caller);
+		VetoDeadlines::<T>::insert(&rescuer_account, BlockNumberFor::<T>::max_value());
+
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller.clone()), rescuer_account_lookup);
+		assert!(!Proxy::<T>::contains_key(&rescuer_account));
 		assert_last_event::<T>(
 			Event::RecoveryClosed { lost_account: caller, rescuer_account }.into(),
 		);
@@ -353,5 +368,56 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn create_weighted_recovery(n: Linear<1, { T::MaxFriends::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+
+		// Create weighted friends
+		let friends = generate_friends::<T>(n).into_iter().map(|f| (f, 1)).collect::<Vec<_>>();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			friends,
+			n as u16,
+			DEFAULT_DELAY.into(),
+			DEFAULT_DELAY.into(),
+		);
+
+		assert_last_event::<T>(Event::RecoveryCreated { account: caller }.into());
+	}
+
+	#[benchmark]
+	fn veto_recovery() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let rescuer_account: T::AccountId = account("rescuer_account", 0, SEED);
+		let rescuer_account_lookup = T::Lookup::unlookup(rescuer_account.clone());
+
+		T::Currency::make_free_balance_be(&rescuer_account, BalanceOf::<T>::max_value());
+
+		// Reserve deposit for recovery
+		let deposit = T::RecoveryDeposit::get();
+		T::Currency::reserve(&rescuer_account, deposit).unwrap();
+
+		// Create an active recovery status
+		let recovery_status =
+			ActiveRecovery { created: DEFAULT_DELAY.into(), deposit, friends: Default::default() };
+		<ActiveRecoveries<T>>::insert(&caller, &rescuer_account, recovery_status);
+
+		// The rescuer claimed the recovery, and the veto period is ongoing
+		frame_system::Pallet::<T>::inc_consumers(&rescuer_account)?;
+		Proxy::<T>::insert(&rescuer_account, &caller);
+		VetoDeadlines::<T>::insert(&rescuer_account, BlockNumberFor::<T>::max_value());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), rescuer_account_lookup);
+
+		assert_last_event::<T>(
+			Event::RecoveryVetoed { lost_account: caller, rescuer_account }.into(),
+		);
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
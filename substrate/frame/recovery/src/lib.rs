@@ -40,6 +40,13 @@
 //! * `delay_period` - The minimum number of blocks after the beginning of the recovery process that
 //!   need to pass before the account can be successfully recovered.
 //!
+//! Using `create_weighted_recovery` instead, the account owner can additionally choose:
+//! * `friends` with weights - Each friend is given a weight, and `threshold` is then the total
+//!   weight of vouching friends needed instead of their number.
+//! * `veto_period` - The number of blocks after a successful `claim_recovery` during which the
+//!   rescuer cannot yet use the account and the account owner can still call `veto_recovery` with
+//!   their original key.
+//!
 //! There is a configurable deposit that all users need to pay to create a recovery
 //! configuration. This deposit is composed of a base deposit plus a multiplier for
 //! the number of friends chosen. This deposit is returned in full when the account
@@ -61,7 +68,8 @@
 //!    needs to wait until the delay period has passed, starting when they initiated the recovery
 //!    process.
 //! 6. Now the account owner is able to call `claim_recovery`, which subsequently allows them to
-//!    call `as_recovered` and directly make calls on-behalf-of the lost account.
+//!    call `as_recovered` and directly make calls on-behalf-of the lost account. If a veto period
+//!    was configured, `as_recovered` is only available once it has passed.
 //! 7. Using the now recovered account, the account owner can call `close_recovery` on the recovery
 //!    process they opened, reclaiming the recovery deposit they placed.
 //! 8. Then the account owner should then call `remove_recovery` to remove the recovery
@@ -91,6 +99,10 @@
 //! this pallet makes it near-zero cost to re-configure the recovery settings and
 //! remove/replace friends who are acting inappropriately.
 //!
+//! If the attacker manages to collect enough vouches and wait out the delay period, a configured
+//! veto period still gives the account owner a last chance to stop the recovery with
+//! `veto_recovery` before the attacker can act on-behalf-of the account.
+//!
 //! ### Safety Considerations
 //!
 //! It is important to note that this is a powerful pallet that can compromise the
@@ -122,6 +134,8 @@
 //! #### For General Users
 //!
 //! * `create_recovery` - Create a recovery configuration for your account and make it recoverable.
+//! * `create_weighted_recovery` - Create a recovery configuration with weighted friends and a veto
+//!   period.
 //! * `initiate_recovery` - Start the recovery process for a recoverable account.
 //!
 //! #### For Friends of a Recoverable Account
@@ -141,6 +155,7 @@
 //!   deposit.
 //! * `remove_recovery` - Remove the recovery configuration from the account, making it
 //!   un-recoverable.
+//! * `veto_recovery` - Cancel a claimed recovery of your account while its veto period is ongoing.
 //!
 //! #### For Super Users
 //!
@@ -156,15 +171,17 @@ use alloc::{boxed::Box, vec::Vec};
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{CheckedAdd, CheckedMul, Dispatchable, SaturatedConversion, StaticLookup},
-	RuntimeDebug,
+	traits::{CheckedAdd, CheckedMul, Dispatchable, SaturatedConversion, StaticLookup, Zero},
+	ArithmeticError, DispatchResult, RuntimeDebug,
 };
 
 use frame_support::{
 	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	ensure,
 	traits::{BalanceStatus, Currency, ReservableCurrency},
 	BoundedVec,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 
 pub use pallet::*;
 pub use weights::WeightInfo;
@@ -182,6 +199,7 @@ type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 type FriendsOf<T> = BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxFriends>;
+type FriendWeightsOf<T> = BoundedVec<u16, <T as Config>::MaxFriends>;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
 /// An active recovery process.
@@ -211,12 +229,23 @@ pub struct RecoveryConfig<BlockNumber, Balance, Friends> {
 	threshold: u16,
 }
 
+/// Additional options of a recovery configuration created with `create_weighted_recovery`.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RecoveryOptions<BlockNumber, FriendWeights> {
+	/// The weight of each friend, in the same order as the friends of the configuration.
+	///
+	/// The `threshold` of the configuration is the total weight of vouching friends needed.
+	friend_weights: FriendWeights,
+	/// The number of blocks after a successful claim during which the account owner can veto
+	/// the recovery.
+	veto_period: BlockNumber,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::ArithmeticError;
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
@@ -294,6 +323,8 @@ pub mod pallet {
 		AccountRecovered { lost_account: T::AccountId, rescuer_account: T::AccountId },
 		/// A recovery process has been removed for an account.
 		RecoveryRemoved { lost_account: T::AccountId },
+		/// A claimed recovery of lost account by rescuer account has been vetoed by its owner.
+		RecoveryVetoed { lost_account: T::AccountId, rescuer_account: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -330,6 +361,12 @@ pub mod pallet {
 		AlreadyProxy,
 		/// Some internal state is broken.
 		BadState,
+		/// Friends must have a weight greater than zero
+		ZeroWeight,
+		/// The rescuer must wait until the veto period has passed
+		VetoPeriod,
+		/// There is no recovery of this account by this rescuer which can be vetoed
+		NotVetoable,
 	}
 
 	/// The set of recoverable accounts and their recovery configuration.
@@ -364,6 +401,22 @@ pub mod pallet {
 	#[pallet::getter(fn proxy)]
 	pub type Proxy<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
 
+	/// Additional options of recoverable accounts set up with `create_weighted_recovery`.
+	#[pallet::storage]
+	pub type RecoverableOptions<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		RecoveryOptions<BlockNumberFor<T>, FriendWeightsOf<T>>,
+	>;
+
+	/// The block number until which a claimed recovery can be vetoed by the account owner.
+	///
+	/// Map from the user who claimed the recovery to the end of its veto period.
+	#[pallet::storage]
+	pub type VetoDeadlines<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Send a call through a recovered account.
@@ -391,6 +444,14 @@ pub mod pallet {
 			// Check `who` is allowed to make a call on behalf of `account`
 			let target = Self::proxy(&who).ok_or(Error::<T>::NotAllowed)?;
 			ensure!(target == account, Error::<T>::NotAllowed);
+			// Make sure the owner had the chance to veto the recovery
+			if let Some(deadline) = VetoDeadlines::<T>::get(&who) {
+				ensure!(
+					deadline <= <frame_system::Pallet<T>>::block_number(),
+					Error::<T>::VetoPeriod
+				);
+				VetoDeadlines::<T>::remove(&who);
+			}
 			call.dispatch(frame_system::RawOrigin::Signed(account).into())
 				.map(|_| ())
 				.map_err(|e| e.error)
@@ -448,36 +509,9 @@ pub mod pallet {
 			delay_period: BlockNumberFor<T>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			// Check account is not already set up for recovery
-			ensure!(!<Recoverable<T>>::contains_key(&who), Error::<T>::AlreadyRecoverable);
 			// Check user input is valid
-			ensure!(threshold >= 1, Error::<T>::ZeroThreshold);
-			ensure!(!friends.is_empty(), Error::<T>::NotEnoughFriends);
 			ensure!(threshold as usize <= friends.len(), Error::<T>::NotEnoughFriends);
-			let bounded_friends: FriendsOf<T> =
-				friends.try_into().map_err(|_| Error::<T>::MaxFriends)?;
-			ensure!(Self::is_sorted_and_unique(&bounded_friends), Error::<T>::NotSorted);
-			// Total deposit is base fee + number of friends * factor fee
-			let friend_deposit = T::FriendDepositFactor::get()
-				.checked_mul(&bounded_friends.len().saturated_into())
-				.ok_or(ArithmeticError::Overflow)?;
-			let total_deposit = T::ConfigDepositBase::get()
-				.checked_add(&friend_deposit)
-				.ok_or(ArithmeticError::Overflow)?;
-			// Reserve the deposit
-			T::Currency::reserve(&who, total_deposit)?;
-			// Create the recovery configuration
-			let recovery_config = RecoveryConfig {
-				delay_period,
-				deposit: total_deposit,
-				friends: bounded_friends,
-				threshold,
-			};
-			// Create the recovery configuration storage item
-			<Recoverable<T>>::insert(&who, recovery_config);
-
-			Self::deposit_event(Event::<T>::RecoveryCreated { account: who });
-			Ok(())
+			Self::do_create_recovery(who, friends, threshold, delay_period)
 		}
 
 		/// Initiate the process for recovering a recoverable account.
@@ -603,13 +637,26 @@ pub mod pallet {
 				.ok_or(ArithmeticError::Overflow)?;
 			ensure!(recoverable_block_number <= current_block_number, Error::<T>::DelayPeriod);
 			// Make sure the threshold is met
-			ensure!(
-				recovery_config.threshold as usize <= active_recovery.friends.len(),
-				Error::<T>::Threshold
-			);
+			let options = RecoverableOptions::<T>::get(&account);
+			let vouched_weight = match &options {
+				Some(options) => Self::vouched_weight(
+					&recovery_config.friends,
+					&options.friend_weights,
+					&active_recovery.friends,
+				),
+				None => active_recovery.friends.len().saturated_into(),
+			};
+			ensure!(recovery_config.threshold as u32 <= vouched_weight, Error::<T>::Threshold);
 			frame_system::Pallet::<T>::inc_consumers(&who).map_err(|_| Error::<T>::BadState)?;
 			// Create the recovery storage item
 			Proxy::<T>::insert(&who, &account);
+			// Give the account owner the chance to veto the recovery
+			if let Some(options) = options.filter(|o| !o.veto_period.is_zero()) {
+				let deadline = current_block_number
+					.checked_add(&options.veto_period)
+					.ok_or(ArithmeticError::Overflow)?;
+				VetoDeadlines::<T>::insert(&who, deadline);
+			}
 			Self::deposit_event(Event::<T>::AccountRecovered {
 				lost_account: account,
 				rescuer_account: who,
@@ -623,6 +670,9 @@ pub mod pallet {
 		/// Payment: By calling this function, the recoverable account will receive
 		/// the recovery deposit `RecoveryDeposit` placed by the rescuer.
 		///
+		/// If the rescuer already claimed the recovery and its veto period is ongoing, the claim
+		/// is revoked as well.
+		///
 		/// The dispatch origin for this call must be _Signed_ and must be a
 		/// recoverable account with an active recovery process for it.
 		///
@@ -648,6 +698,15 @@ pub mod pallet {
 				BalanceStatus::Free,
 			);
 			debug_assert!(res.is_ok());
+			// A claim which can still be vetoed is revoked along with the recovery process.
+			if Self::proxy(&rescuer).as_ref() == Some(&who) {
+				if let Some(deadline) = VetoDeadlines::<T>::take(&rescuer) {
+					if <frame_system::Pallet<T>>::block_number() < deadline {
+						Proxy::<T>::remove(&rescuer);
+						frame_system::Pallet::<T>::dec_consumers(&rescuer);
+					}
+				}
+			}
 			Self::deposit_event(Event::<T>::RecoveryClosed {
 				lost_account: who,
 				rescuer_account: rescuer,
@@ -675,6 +734,7 @@ pub mod pallet {
 			ensure!(active_recoveries.next().is_none(), Error::<T>::StillActive);
 			// Take the recovery configuration for this account.
 			let recovery_config = <Recoverable<T>>::take(&who).ok_or(Error::<T>::NotRecoverable)?;
+			RecoverableOptions::<T>::remove(&who);
 
 			// Unreserve the initial deposit for the recovery configuration.
 			T::Currency::unreserve(&who, recovery_config.deposit);
@@ -700,14 +760,153 @@ pub mod pallet {
 			// Check `who` is allowed to make a call on behalf of `account`
 			ensure!(Self::proxy(&who) == Some(account), Error::<T>::NotAllowed);
 			Proxy::<T>::remove(&who);
+			VetoDeadlines::<T>::remove(&who);
 
 			frame_system::Pallet::<T>::dec_consumers(&who);
 			Ok(())
 		}
+
+		/// Create a recovery configuration for your account where each friend has a weight.
+		///
+		/// This works like `create_recovery`, except that `threshold` is the total weight of
+		/// the friends who must vouch for a recovery attempt, and that the account owner can
+		/// veto a successful claim for `veto_period` blocks.
+		///
+		/// Payment: `ConfigDepositBase` + `FriendDepositFactor` * #_of_friends balance
+		/// will be reserved for storing the recovery configuration. This deposit is returned
+		/// in full when the user calls `remove_recovery`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `friends`: A list of friends you trust to vouch for recovery attempts, each with a
+		///   non-zero weight. Should be ordered by friend and contain no duplicate friends.
+		/// - `threshold`: The total weight of friends that must vouch for a recovery attempt before
+		///   the account can be recovered. Should be less than or equal to the sum of all weights.
+		/// - `delay_period`: The number of blocks after a recovery attempt is initialized that
+		///   needs to pass before the account can be recovered.
+		/// - `veto_period`: The number of blocks after a recovery is claimed during which you can
+		///   still veto it with `veto_recovery`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::create_weighted_recovery(friends.len() as u32))]
+		pub fn create_weighted_recovery(
+			origin: OriginFor<T>,
+			friends: Vec<(T::AccountId, u16)>,
+			threshold: u16,
+			delay_period: BlockNumberFor<T>,
+			veto_period: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (friends, weights): (Vec<_>, Vec<_>) = friends.into_iter().unzip();
+			// Check user input is valid
+			ensure!(weights.iter().all(|w| *w > 0), Error::<T>::ZeroWeight);
+			let total_weight: u32 = weights.iter().map(|w| *w as u32).sum();
+			ensure!(threshold as u32 <= total_weight, Error::<T>::NotEnoughFriends);
+			let friend_weights: FriendWeightsOf<T> =
+				weights.try_into().map_err(|_| Error::<T>::MaxFriends)?;
+			Self::do_create_recovery(who.clone(), friends, threshold, delay_period)?;
+			RecoverableOptions::<T>::insert(&who, RecoveryOptions { friend_weights, veto_period });
+			Ok(())
+		}
+
+		/// As the owner of a recovered account, veto the recovery claimed by `rescuer`.
+		///
+		/// This is only possible while the veto period of the claim is ongoing. The rescuer
+		/// loses the ability to call on-behalf-of the account, and the active recovery process
+		/// is closed, with its deposit going to the account.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be the recovered account.
+		///
+		/// Parameters:
+		/// - `rescuer`: The account which claimed the recovery of your account.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::veto_recovery())]
+		pub fn veto_recovery(
+			origin: OriginFor<T>,
+			rescuer: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let rescuer = T::Lookup::lookup(rescuer)?;
+			ensure!(Self::proxy(&rescuer) == Some(who.clone()), Error::<T>::NotVetoable);
+			let deadline = VetoDeadlines::<T>::get(&rescuer).ok_or(Error::<T>::NotVetoable)?;
+			ensure!(<frame_system::Pallet<T>>::block_number() < deadline, Error::<T>::NotVetoable);
+			Proxy::<T>::remove(&rescuer);
+			VetoDeadlines::<T>::remove(&rescuer);
+			frame_system::Pallet::<T>::dec_consumers(&rescuer);
+			// Close the active recovery process, punishing the rescuer like `close_recovery`.
+			if let Some(active_recovery) = <ActiveRecoveries<T>>::take(&who, &rescuer) {
+				let res = T::Currency::repatriate_reserved(
+					&rescuer,
+					&who,
+					active_recovery.deposit,
+					BalanceStatus::Free,
+				);
+				debug_assert!(res.is_ok());
+			}
+			Self::deposit_event(Event::<T>::RecoveryVetoed {
+				lost_account: who,
+				rescuer_account: rescuer,
+			});
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Create the recovery configuration of `who`, reserving its deposit.
+	///
+	/// The caller is responsible for checking `threshold` against the friends.
+	fn do_create_recovery(
+		who: T::AccountId,
+		friends: Vec<T::AccountId>,
+		threshold: u16,
+		delay_period: BlockNumberFor<T>,
+	) -> DispatchResult {
+		// Check account is not already set up for recovery
+		ensure!(!<Recoverable<T>>::contains_key(&who), Error::<T>::AlreadyRecoverable);
+		// Check user input is valid
+		ensure!(threshold >= 1, Error::<T>::ZeroThreshold);
+		ensure!(!friends.is_empty(), Error::<T>::NotEnoughFriends);
+		let bounded_friends: FriendsOf<T> =
+			friends.try_into().map_err(|_| Error::<T>::MaxFriends)?;
+		ensure!(Self::is_sorted_and_unique(&bounded_friends), Error::<T>::NotSorted);
+		// Total deposit is base fee + number of friends * factor fee
+		let friend_deposit = T::FriendDepositFactor::get()
+			.checked_mul(&bounded_friends.len().saturated_into())
+			.ok_or(ArithmeticError::Overflow)?;
+		let total_deposit = T::ConfigDepositBase::get()
+			.checked_add(&friend_deposit)
+			.ok_or(ArithmeticError::Overflow)?;
+		// Reserve the deposit
+		T::Currency::reserve(&who, total_deposit)?;
+		// Create the recovery configuration
+		let recovery_config = RecoveryConfig {
+			delay_period,
+			deposit: total_deposit,
+			friends: bounded_friends,
+			threshold,
+		};
+		// Create the recovery configuration storage item
+		<Recoverable<T>>::insert(&who, recovery_config);
+
+		Self::deposit_event(Event::<T>::RecoveryCreated { account: who });
+		Ok(())
+	}
+
+	/// Sum the weights of the friends who vouched, given the weights of all friends.
+	fn vouched_weight(
+		friends: &Vec<T::AccountId>,
+		friend_weights: &Vec<u16>,
+		vouched: &Vec<T::AccountId>,
+	) -> u32 {
+		friends
+			.iter()
+			.zip(friend_weights.iter())
+			.filter(|(friend, _)| Self::is_friend(vouched, friend))
+			.map(|(_, weight)| *weight as u32)
+			.sum()
+	}
+
 	/// Check that friends list is sorted and has no duplicates.
 	fn is_sorted_and_unique(friends: &Vec<T::AccountId>) -> bool {
 		friends.windows(2).all(|w| w[0] < w[1])
@@ -21,7 +21,7 @@ use super::*;
 use frame_support::{assert_noop, assert_ok, traits::Currency};
 use mock::{
 	new_test_ext, run_to_block, Balances, BalancesCall, MaxFriends, Recovery, RecoveryCall,
-	RuntimeCall, RuntimeOrigin, Test,
+	RuntimeCall, RuntimeOrigin, System, Test,
 };
 use sp_runtime::{bounded_vec, traits::BadOrigin};
 
@@ -495,3 +495,153 @@ fn remove_recovery_works() {
 		assert_ok!(Recovery::remove_recovery(RuntimeOrigin::signed(5)));
 	});
 }
+
+#[test]
+fn create_weighted_recovery_handles_basic_errors() {
+	new_test_ext().execute_with(|| {
+		// No friends with a zero weight
+		assert_noop!(
+			Recovery::create_weighted_recovery(
+				RuntimeOrigin::signed(5),
+				vec![(2, 1), (3, 0)],
+				1,
+				10,
+				5
+			),
+			Error::<Test>::ZeroWeight
+		);
+		// Threshold must be reachable by the total weight of friends
+		assert_noop!(
+			Recovery::create_weighted_recovery(
+				RuntimeOrigin::signed(5),
+				vec![(2, 2), (3, 1)],
+				4,
+				10,
+				5
+			),
+			Error::<Test>::NotEnoughFriends
+		);
+		// Friends must be sorted
+		assert_noop!(
+			Recovery::create_weighted_recovery(
+				RuntimeOrigin::signed(5),
+				vec![(3, 2), (2, 1)],
+				3,
+				10,
+				5
+			),
+			Error::<Test>::NotSorted
+		);
+	});
+}
+
+#[test]
+fn weighted_recovery_with_veto_works() {
+	new_test_ext().execute_with(|| {
+		// Account 2 weighs as much as accounts 3 and 4 together
+		let friends = vec![(2, 2), (3, 1), (4, 1)];
+		assert_ok!(Recovery::create_weighted_recovery(RuntimeOrigin::signed(5), friends, 3, 10, 5));
+		// Deposit is taken as for an unweighted configuration
+		assert_eq!(Balances::reserved_balance(5), 13);
+		assert_ok!(Recovery::initiate_recovery(RuntimeOrigin::signed(1), 5));
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(3), 5, 1));
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(4), 5, 1));
+		run_to_block(11);
+		// Two friends have vouched, but their weight is not enough
+		assert_noop!(
+			Recovery::claim_recovery(RuntimeOrigin::signed(1), 5),
+			Error::<Test>::Threshold
+		);
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(2), 5, 1));
+		assert_ok!(Recovery::claim_recovery(RuntimeOrigin::signed(1), 5));
+		assert_eq!(<VetoDeadlines<Test>>::get(&1), Some(16));
+		// The rescuer cannot use the account during the veto period
+		let call = Box::new(RuntimeCall::Balances(BalancesCall::transfer_allow_death {
+			dest: 1,
+			value: 50,
+		}));
+		assert_noop!(
+			Recovery::as_recovered(RuntimeOrigin::signed(1), 5, call),
+			Error::<Test>::VetoPeriod
+		);
+		// Only the recovered account can veto
+		assert_noop!(
+			Recovery::veto_recovery(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::NotVetoable
+		);
+		// The owner still has their key and vetoes the recovery, taking the rescuer's deposit
+		assert_ok!(Recovery::veto_recovery(RuntimeOrigin::signed(5), 1));
+		System::assert_last_event(
+			Event::<Test>::RecoveryVetoed { lost_account: 5, rescuer_account: 1 }.into(),
+		);
+		assert!(!<Proxy<Test>>::contains_key(&1));
+		assert_eq!(<VetoDeadlines<Test>>::iter().count(), 0);
+		assert!(!<ActiveRecoveries<Test>>::contains_key(&5, &1));
+		assert_eq!(Balances::free_balance(5), 97);
+		assert_eq!(Balances::total_balance(&1), 90);
+		// Options are removed along with the configuration
+		assert_ok!(Recovery::remove_recovery(RuntimeOrigin::signed(5)));
+		assert!(!<RecoverableOptions<Test>>::contains_key(&5));
+	});
+}
+
+#[test]
+fn veto_period_expires() {
+	new_test_ext().execute_with(|| {
+		let friends = vec![(2, 1), (3, 1)];
+		assert_ok!(Recovery::create_weighted_recovery(RuntimeOrigin::signed(5), friends, 2, 0, 5));
+		assert_ok!(Recovery::initiate_recovery(RuntimeOrigin::signed(1), 5));
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(2), 5, 1));
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(3), 5, 1));
+		assert_ok!(Recovery::claim_recovery(RuntimeOrigin::signed(1), 5));
+		run_to_block(5);
+		// Once the veto period is over, the owner can no longer veto
+		assert_noop!(
+			Recovery::veto_recovery(RuntimeOrigin::signed(5), 1),
+			Error::<Test>::NotVetoable
+		);
+		// And the rescuer can use the account
+		let call = Box::new(RuntimeCall::Balances(BalancesCall::transfer_allow_death {
+			dest: 1,
+			value: 50,
+		}));
+		assert_ok!(Recovery::as_recovered(RuntimeOrigin::signed(1), 5, call));
+		assert_eq!(Balances::free_balance(1), 140);
+		// The expired deadline is cleaned up
+		assert_eq!(<VetoDeadlines<Test>>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn close_recovery_during_veto_period_revokes_claim() {
+	new_test_ext().execute_with(|| {
+		let friends = vec![(2, 1), (3, 1)];
+		assert_ok!(Recovery::create_weighted_recovery(RuntimeOrigin::signed(5), friends, 2, 0, 5));
+		assert_ok!(Recovery::initiate_recovery(RuntimeOrigin::signed(1), 5));
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(2), 5, 1));
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(3), 5, 1));
+		assert_ok!(Recovery::claim_recovery(RuntimeOrigin::signed(1), 5));
+		// Closing the recovery while the claim can be vetoed also revokes the claim
+		assert_ok!(Recovery::close_recovery(RuntimeOrigin::signed(5), 1));
+		assert!(!<Proxy<Test>>::contains_key(&1));
+		assert_eq!(<VetoDeadlines<Test>>::iter().count(), 0);
+		assert_eq!(Balances::total_balance(&1), 90);
+	});
+}
+
+#[test]
+fn close_recovery_after_veto_period_keeps_claim() {
+	new_test_ext().execute_with(|| {
+		let friends = vec![(2, 1), (3, 1)];
+		assert_ok!(Recovery::create_weighted_recovery(RuntimeOrigin::signed(5), friends, 2, 0, 5));
+		assert_ok!(Recovery::initiate_recovery(RuntimeOrigin::signed(1), 5));
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(2), 5, 1));
+		assert_ok!(Recovery::vouch_recovery(RuntimeOrigin::signed(3), 5, 1));
+		assert_ok!(Recovery::claim_recovery(RuntimeOrigin::signed(1), 5));
+		run_to_block(10);
+		// The claim stands once the veto period is over, but its deadline is cleaned up
+		assert_ok!(Recovery::close_recovery(RuntimeOrigin::signed(5), 1));
+		assert_eq!(<Proxy<Test>>::get(&1), Some(5));
+		assert_eq!(<VetoDeadlines<Test>>::iter().count(), 0);
+	});
+}
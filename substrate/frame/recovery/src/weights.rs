@@ -22,6 +22,11 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-wiukf8gn-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `as_recovered`, `claim_recovery`, `close_recovery`, `remove_recovery`,
+//! `cancel_recovered`, `create_weighted_recovery` and `veto_recovery`. Re-run the benchmarks before
+//! relying on them.
 
 // Executed Command:
 // ./target/production/substrate-node
@@ -60,6 +65,8 @@ pub trait WeightInfo {
 	fn close_recovery(n: u32, ) -> Weight;
 	fn remove_recovery(n: u32, ) -> Weight;
 	fn cancel_recovered() -> Weight;
+	fn create_weighted_recovery(n: u32, ) -> Weight;
+	fn veto_recovery() -> Weight;
 }
 
 /// Weights for `pallet_recovery` using the Substrate node and recommended hardware.
@@ -71,13 +78,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `SafeMode::EnteredUntil` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `TxPause::PausedCalls` (r:1 w:0)
 	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::VetoDeadlines` (r:1 w:1)
+	/// Proof: `Recovery::VetoDeadlines` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn as_recovered() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `530`
 		//  Estimated: `3997`
 		// Minimum execution time: 21_063_000 picoseconds.
 		Weight::from_parts(21_784_000, 3997)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Recovery::Proxy` (r:0 w:1)
 	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
@@ -138,6 +148,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// Storage: `Recovery::Proxy` (r:1 w:1)
 	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::RecoverableOptions` (r:1 w:0)
+	/// Proof: `Recovery::RecoverableOptions` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::VetoDeadlines` (r:0 w:1)
+	/// Proof: `Recovery::VetoDeadlines` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
 	fn claim_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -147,13 +161,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(29_298_729, 3854)
 			// Standard Error: 5_392
 			.saturating_add(Weight::from_parts(162_096, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:1)
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Proxy` (r:1 w:1)
+	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::VetoDeadlines` (r:1 w:1)
+	/// Proof: `Recovery::VetoDeadlines` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
 	fn close_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -163,13 +181,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(40_675_824, 3854)
 			// Standard Error: 6_163
 			.saturating_add(Weight::from_parts(144_246, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:0)
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// Storage: `Recovery::Recoverable` (r:1 w:1)
 	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::RecoverableOptions` (r:0 w:1)
+	/// Proof: `Recovery::RecoverableOptions` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
 	fn remove_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -180,10 +200,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 7_758
 			.saturating_add(Weight::from_parts(194_601, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Recovery::Proxy` (r:1 w:1)
 	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::VetoDeadlines` (r:0 w:1)
+	/// Proof: `Recovery::VetoDeadlines` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn cancel_recovered() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `385`
@@ -191,7 +213,40 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 17_356_000 picoseconds.
 		Weight::from_parts(18_101_000, 3545)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:1)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::RecoverableOptions` (r:0 w:1)
+	/// Proof: `Recovery::RecoverableOptions` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn create_weighted_recovery(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `279`
+		//  Estimated: `3816`
+		// Minimum execution time: 30_118_000 picoseconds.
+		Weight::from_parts(31_473_512, 3816)
+			// Standard Error: 6_018
+			.saturating_add(Weight::from_parts(96_213, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Recovery::Proxy` (r:1 w:1)
+	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::VetoDeadlines` (r:1 w:1)
+	/// Proof: `Recovery::VetoDeadlines` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn veto_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `3854`
+		// Minimum execution time: 42_871_000 picoseconds.
+		Weight::from_parts(44_120_000, 3854)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 }
 
@@ -203,13 +258,16 @@ impl WeightInfo for () {
 	/// Proof: `SafeMode::EnteredUntil` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `TxPause::PausedCalls` (r:1 w:0)
 	/// Proof: `TxPause::PausedCalls` (`max_values`: None, `max_size`: Some(532), added: 3007, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::VetoDeadlines` (r:1 w:1)
+	/// Proof: `Recovery::VetoDeadlines` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn as_recovered() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `530`
 		//  Estimated: `3997`
 		// Minimum execution time: 21_063_000 picoseconds.
 		Weight::from_parts(21_784_000, 3997)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Recovery::Proxy` (r:0 w:1)
 	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
@@ -270,6 +328,10 @@ impl WeightInfo for () {
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// Storage: `Recovery::Proxy` (r:1 w:1)
 	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::RecoverableOptions` (r:1 w:0)
+	/// Proof: `Recovery::RecoverableOptions` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::VetoDeadlines` (r:0 w:1)
+	/// Proof: `Recovery::VetoDeadlines` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
 	fn claim_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -279,13 +341,17 @@ impl WeightInfo for () {
 		Weight::from_parts(29_298_729, 3854)
 			// Standard Error: 5_392
 			.saturating_add(Weight::from_parts(162_096, 0).saturating_mul(n.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:1)
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::Proxy` (r:1 w:1)
+	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::VetoDeadlines` (r:1 w:1)
+	/// Proof: `Recovery::VetoDeadlines` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
 	fn close_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -295,13 +361,15 @@ impl WeightInfo for () {
 		Weight::from_parts(40_675_824, 3854)
 			// Standard Error: 6_163
 			.saturating_add(Weight::from_parts(144_246, 0).saturating_mul(n.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:0)
 	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
 	/// Storage: `Recovery::Recoverable` (r:1 w:1)
 	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::RecoverableOptions` (r:0 w:1)
+	/// Proof: `Recovery::RecoverableOptions` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 9]`.
 	fn remove_recovery(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -312,10 +380,12 @@ impl WeightInfo for () {
 			// Standard Error: 7_758
 			.saturating_add(Weight::from_parts(194_601, 0).saturating_mul(n.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Recovery::Proxy` (r:1 w:1)
 	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::VetoDeadlines` (r:0 w:1)
+	/// Proof: `Recovery::VetoDeadlines` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn cancel_recovered() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `385`
@@ -323,6 +393,39 @@ impl WeightInfo for () {
 		// Minimum execution time: 17_356_000 picoseconds.
 		Weight::from_parts(18_101_000, 3545)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Recovery::Recoverable` (r:1 w:1)
+	/// Proof: `Recovery::Recoverable` (`max_values`: None, `max_size`: Some(351), added: 2826, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::RecoverableOptions` (r:0 w:1)
+	/// Proof: `Recovery::RecoverableOptions` (`max_values`: None, `max_size`: Some(63), added: 2538, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 9]`.
+	fn create_weighted_recovery(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `279`
+		//  Estimated: `3816`
+		// Minimum execution time: 30_118_000 picoseconds.
+		Weight::from_parts(31_473_512, 3816)
+			// Standard Error: 6_018
+			.saturating_add(Weight::from_parts(96_213, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Recovery::Proxy` (r:1 w:1)
+	/// Proof: `Recovery::Proxy` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::VetoDeadlines` (r:1 w:1)
+	/// Proof: `Recovery::VetoDeadlines` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Recovery::ActiveRecoveries` (r:1 w:1)
+	/// Proof: `Recovery::ActiveRecoveries` (`max_values`: None, `max_size`: Some(389), added: 2864, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn veto_recovery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `712`
+		//  Estimated: `3854`
+		// Minimum execution time: 42_871_000 picoseconds.
+		Weight::from_parts(44_120_000, 3854)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 }
@@ -101,7 +101,7 @@ pub use self::{
 		BalanceOf, BoundedCallOf, CallOf, Curve, DecidingStatus, DecidingStatusOf, Deposit,
 		InsertSorted, NegativeImbalanceOf, PalletsOriginOf, ReferendumIndex, ReferendumInfo,
 		ReferendumInfoOf, ReferendumStatus, ReferendumStatusOf, ScheduleAddressOf, TallyOf,
-		TrackIdOf, TrackInfo, TrackInfoOf, TracksInfo, VotesOf,
+		TrackIdOf, TrackInfo, TrackInfoOf, TrackParams, TrackParamsOf, TracksInfo, VotesOf,
 	},
 	weights::WeightInfo,
 };
@@ -268,6 +268,12 @@ pub mod pallet {
 	pub type DecidingCount<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Twox64Concat, TrackIdOf<T, I>, u32, ValueQuery>;
 
+	/// The parameters of the track of an ongoing referendum, if they were overridden by
+	/// [`TracksInfo::params_override`] at the time of its submission.
+	#[pallet::storage]
+	pub type ReferendumTrackParams<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ReferendumIndex, TrackParamsOf<T, I>>;
+
 	/// The metadata is a general information concerning the referendum.
 	/// The `Hash` refers to the preimage of the `Preimages` provider which can be a JSON
 	/// dump or IPFS hash of a JSON file.
@@ -502,6 +508,10 @@ pub mod pallet {
 				alarm: Self::set_alarm(nudge_call, now.saturating_add(T::UndecidingTimeout::get())),
 			};
 			ReferendumInfoFor::<T, I>::insert(index, ReferendumInfo::Ongoing(status));
+			// Keep the current parameters of the track for the lifetime of the referendum.
+			if let Some(params) = T::Tracks::params_override(track) {
+				ReferendumTrackParams::<T, I>::insert(index, params);
+			}
 
 			Self::deposit_event(Event::<T, I>::Submitted { index, track, proposal });
 			Ok(())
@@ -524,7 +534,8 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			let mut status = Self::ensure_ongoing(index)?;
 			ensure!(status.decision_deposit.is_none(), Error::<T, I>::HasDeposit);
-			let track = Self::track(status.track).ok_or(Error::<T, I>::NoTrack)?;
+			let track =
+				Self::referendum_track(index, status.track).ok_or(Error::<T, I>::NoTrack)?;
 			status.decision_deposit =
 				Some(Self::take_deposit(who.clone(), track.decision_deposit)?);
 			let now = frame_system::Pallet::<T>::block_number();
@@ -583,6 +594,7 @@ pub mod pallet {
 			}
 			Self::note_one_fewer_deciding(status.track);
 			Self::deposit_event(Event::<T, I>::Cancelled { index, tally: status.tally });
+			ReferendumTrackParams::<T, I>::remove(index);
 			let info = ReferendumInfo::Cancelled(
 				frame_system::Pallet::<T>::block_number(),
 				Some(status.submission_deposit),
@@ -611,6 +623,7 @@ pub mod pallet {
 			Self::slash_deposit(Some(status.submission_deposit.clone()));
 			Self::slash_deposit(status.decision_deposit.clone());
 			Self::do_clear_metadata(index);
+			ReferendumTrackParams::<T, I>::remove(index);
 			let info = ReferendumInfo::Killed(frame_system::Pallet::<T>::block_number());
 			ReferendumInfoFor::<T, I>::insert(index, info);
 			Ok(())
@@ -654,13 +667,15 @@ pub mod pallet {
 			track: TrackIdOf<T, I>,
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
-			let track_info = T::Tracks::info(track).ok_or(Error::<T, I>::BadTrack)?;
+			ensure!(T::Tracks::info(track).is_some(), Error::<T, I>::BadTrack);
 			let mut track_queue = TrackQueue::<T, I>::get(track);
 			let branch =
 				if let Some((index, mut status)) = Self::next_for_deciding(&mut track_queue) {
+					let track_info =
+						Self::referendum_track(index, track).ok_or(Error::<T, I>::BadTrack)?;
 					let now = frame_system::Pallet::<T>::block_number();
 					let (maybe_alarm, branch) =
-						Self::begin_deciding(&mut status, index, now, track_info);
+						Self::begin_deciding(&mut status, index, now, &track_info);
 					if let Some(set_alarm) = maybe_alarm {
 						Self::ensure_alarm_at(&mut status, index, set_alarm);
 					}
@@ -828,6 +843,7 @@ impl<T: Config<I>, I: 'static> Polling<T::Tally> for Pallet<T, I> {
 		let mut status = Self::ensure_ongoing(index).map_err(|_| ())?;
 		Self::ensure_no_alarm(&mut status);
 		Self::note_one_fewer_deciding(status.track);
+		ReferendumTrackParams::<T, I>::remove(index);
 		let now = frame_system::Pallet::<T>::block_number();
 		let info = if approved {
 			ReferendumInfo::Approved(now, Some(status.submission_deposit), status.decision_deposit)
@@ -866,7 +882,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let info = ReferendumInfoFor::<T, I>::get(ref_index).ok_or(Error::<T, I>::BadReferendum)?;
 		match info {
 			ReferendumInfo::Ongoing(status) => {
-				let track = Self::track(status.track).ok_or(Error::<T, I>::NoTrack)?;
+				let track = Self::referendum_track(ref_index, status.track)
+					.ok_or(Error::<T, I>::NoTrack)?;
 				let elapsed = if let Some(deciding) = status.deciding {
 					frame_system::Pallet::<T>::block_number().saturating_sub(deciding.since)
 				} else {
@@ -1096,7 +1113,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	) -> (ReferendumInfoOf<T, I>, bool, ServiceBranch) {
 		let mut dirty = false;
 		// Should it begin being decided?
-		let track = match Self::track(status.track) {
+		let track = match Self::referendum_track(index, status.track) {
 			Some(x) => x,
 			None => return (ReferendumInfo::Ongoing(status), false, ServiceBranch::Fail),
 		};
@@ -1132,7 +1149,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						let prepare_end = status.submitted.saturating_add(track.prepare_period);
 						if now >= prepare_end {
 							let (maybe_alarm, branch) =
-								Self::ready_for_deciding(now, track, index, &mut status);
+								Self::ready_for_deciding(now, &track, index, &mut status);
 							if let Some(set_alarm) = maybe_alarm {
 								alarm = alarm.min(set_alarm);
 							}
@@ -1152,6 +1169,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					// Too long without being decided - end it.
 					Self::ensure_no_alarm(&mut status);
 					Self::deposit_event(Event::<T, I>::TimedOut { index, tally: status.tally });
+					ReferendumTrackParams::<T, I>::remove(index);
 					return (
 						ReferendumInfo::TimedOut(
 							now,
@@ -1179,11 +1197,12 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 							Self::ensure_no_alarm(&mut status);
 							Self::note_one_fewer_deciding(status.track);
 							let (desired, call) = (status.enactment, status.proposal);
-							Self::schedule_enactment(index, track, desired, status.origin, call);
+							Self::schedule_enactment(index, &track, desired, status.origin, call);
 							Self::deposit_event(Event::<T, I>::Confirmed {
 								index,
 								tally: status.tally,
 							});
+							ReferendumTrackParams::<T, I>::remove(index);
 							return (
 								ReferendumInfo::Approved(
 									now,
@@ -1209,6 +1228,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						Self::ensure_no_alarm(&mut status);
 						Self::note_one_fewer_deciding(status.track);
 						Self::deposit_event(Event::<T, I>::Rejected { index, tally: status.tally });
+						ReferendumTrackParams::<T, I>::remove(index);
 						return (
 							ReferendumInfo::Rejected(
 								now,
@@ -1229,7 +1249,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 						ServiceBranch::ContinueNotConfirming
 					}
 				};
-				alarm = Self::decision_time(deciding, &status.tally, status.track, track);
+				alarm = Self::decision_time(deciding, &status.tally, status.track, &track);
 			},
 		}
 
@@ -1302,6 +1322,16 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Some(&tracks[index].1)
 	}
 
+	/// Get the track info value for the track `id` of referendum `index`, with the parameters
+	/// the track had when the referendum was submitted.
+	fn referendum_track(index: ReferendumIndex, id: TrackIdOf<T, I>) -> Option<TrackInfoOf<T, I>> {
+		let track = Self::track(id)?.clone();
+		Some(match ReferendumTrackParams::<T, I>::get(index) {
+			Some(params) => track.with_params(params),
+			None => track,
+		})
+	}
+
 	/// Determine whether the given `tally` would result in a referendum passing at `elapsed` blocks
 	/// into a total decision `period`, given the two curves for `support_needed` and
 	/// `approval_needed`.
@@ -1350,6 +1380,19 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Ok(())
 		})?;
 
+		ReferendumTrackParams::<T, I>::iter_keys().try_for_each(
+			|referendum_index| -> DispatchResult {
+				ensure!(
+					matches!(
+						ReferendumInfoFor::<T, I>::get(referendum_index),
+						Some(ReferendumInfo::Ongoing(_))
+					),
+					"Referendum indices in `ReferendumTrackParams` must be ongoing referenda"
+				);
+				Ok(())
+			},
+		)?;
+
 		Self::try_state_referenda_info()?;
 		Self::try_state_tracks()?;
 
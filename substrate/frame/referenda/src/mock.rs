@@ -81,6 +81,19 @@ impl pallet_scheduler::Config for Test {
 	type WeightInfo = ();
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = Preimage;
+	type TimeProvider = NoTime;
+	type TimestampTolerance = ConstU64<0>;
+	type TimestampBucketSize = ConstU64<1>;
+}
+
+/// A wall-clock time which is always zero, tasks are never scheduled at a timestamp.
+pub struct NoTime;
+impl frame_support::traits::Time for NoTime {
+	type Moment = u64;
+
+	fn now() -> u64 {
+		0
+	}
 }
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
 impl pallet_balances::Config for Test {
@@ -88,6 +101,7 @@ impl pallet_balances::Config for Test {
 }
 parameter_types! {
 	pub static AlarmInterval: u64 = 1;
+	pub static RootTrackOverride: Option<TrackParams<u64, u64>> = None;
 }
 ord_parameter_types! {
 	pub const One: u64 = 1;
@@ -185,6 +199,12 @@ impl TracksInfo<u64, u64> for TestTracksInfo {
 			Err(())
 		}
 	}
+	fn params_override(id: Self::Id) -> Option<TrackParams<u64, u64>> {
+		match id {
+			0 => RootTrackOverride::get(),
+			_ => None,
+		}
+	}
 }
 impl_tracksinfo_get!(TestTracksInfo, u64, u64);
 
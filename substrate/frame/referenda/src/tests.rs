@@ -706,3 +706,27 @@ fn zero_enactment_delay_executes_proposal_at_next_block() {
 		assert_eq!(Balances::free_balance(42), 20);
 	});
 }
+
+#[test]
+fn track_params_override_is_kept_for_ongoing_referenda() {
+	ExtBuilder::default().build_and_execute(|| {
+		let root_track = TestTracksInfo::info(0).unwrap();
+		RootTrackOverride::set(Some(TrackParams { decision_deposit: 20, ..root_track.params() }));
+		assert_ok!(propose_set_balance(1, 2, 1));
+		assert_eq!(ReferendumTrackParams::<Test>::get(0).map(|p| p.decision_deposit), Some(20));
+
+		// Lifting the override only affects referenda submitted afterwards.
+		RootTrackOverride::set(None);
+		assert_ok!(propose_set_balance(1, 2, 1));
+		assert!(!ReferendumTrackParams::<Test>::contains_key(1));
+
+		assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(2), 0));
+		assert_ok!(Referenda::place_decision_deposit(RuntimeOrigin::signed(3), 1));
+		assert_eq!(Balances::reserved_balance(2), 20);
+		assert_eq!(Balances::reserved_balance(3), 10);
+
+		// The snapshot is removed once the referendum is no longer ongoing.
+		assert_ok!(Referenda::kill(RuntimeOrigin::root(), 0));
+		assert!(!ReferendumTrackParams::<Test>::contains_key(0));
+	});
+}
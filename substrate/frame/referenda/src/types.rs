@@ -62,6 +62,7 @@ pub type ReferendumStatusOf<T, I> = ReferendumStatus<
 >;
 pub type DecidingStatusOf<T> = DecidingStatus<BlockNumberFor<T>>;
 pub type TrackInfoOf<T, I = ()> = TrackInfo<BalanceOf<T, I>, BlockNumberFor<T>>;
+pub type TrackParamsOf<T, I = ()> = TrackParams<BalanceOf<T, I>, BlockNumberFor<T>>;
 pub type TrackIdOf<T, I> =
 	<<T as Config<I>>::Tracks as TracksInfo<BalanceOf<T, I>, BlockNumberFor<T>>>::Id;
 pub type ScheduleAddressOf<T, I> = <<T as Config<I>>::Scheduler as Anon<
@@ -136,6 +137,50 @@ pub struct TrackInfo<Balance, Moment> {
 	pub min_support: Curve,
 }
 
+impl<Balance: Clone, Moment: Clone> TrackInfo<Balance, Moment> {
+	/// The parameters of this track which can be tuned.
+	pub fn params(&self) -> TrackParams<Balance, Moment> {
+		TrackParams {
+			decision_deposit: self.decision_deposit.clone(),
+			prepare_period: self.prepare_period.clone(),
+			decision_period: self.decision_period.clone(),
+			confirm_period: self.confirm_period.clone(),
+			min_enactment_period: self.min_enactment_period.clone(),
+			min_approval: self.min_approval.clone(),
+			min_support: self.min_support.clone(),
+		}
+	}
+
+	/// Replace the tunable parameters of this track with `params`.
+	pub fn with_params(self, params: TrackParams<Balance, Moment>) -> Self {
+		TrackInfo {
+			name: self.name,
+			max_deciding: self.max_deciding,
+			decision_deposit: params.decision_deposit,
+			prepare_period: params.prepare_period,
+			decision_period: params.decision_period,
+			confirm_period: params.confirm_period,
+			min_enactment_period: params.min_enactment_period,
+			min_approval: params.min_approval,
+			min_support: params.min_support,
+		}
+	}
+}
+
+/// The parameters of a track which can be tuned without a runtime upgrade.
+///
+/// See [`TrackInfo`] for the meaning of each field.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TrackParams<Balance, Moment> {
+	pub decision_deposit: Balance,
+	pub prepare_period: Moment,
+	pub decision_period: Moment,
+	pub confirm_period: Moment,
+	pub min_enactment_period: Moment,
+	pub min_approval: Curve,
+	pub min_support: Curve,
+}
+
 /// Information on the voting tracks.
 pub trait TracksInfo<Balance, Moment> {
 	/// The identifier for a track.
@@ -161,6 +206,15 @@ pub trait TracksInfo<Balance, Moment> {
 		tracks.get(maybe_index).map(|(_, info)| info)
 	}
 
+	/// Return the parameters which currently override the static parameters of track `id`.
+	///
+	/// By default no track is overridden. Implementations can read these from storage, for
+	/// example from `pallet-parameters`, so that tracks can be tuned without a runtime upgrade.
+	/// Referenda keep the parameters of their track at the time of their submission.
+	fn params_override(_id: Self::Id) -> Option<TrackParams<Balance, Moment>> {
+		None
+	}
+
 	/// Check assumptions about the static data that this trait provides.
 	fn check_integrity() -> Result<(), &'static str>
 	where
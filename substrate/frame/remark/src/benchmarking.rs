@@ -22,7 +22,9 @@
 use super::*;
 use alloc::vec;
 use frame_benchmarking::v2::*;
+use frame_support::traits::Get;
 use frame_system::{EventRecord, Pallet as System, RawOrigin};
+use sp_runtime::traits::Bounded;
 
 #[cfg(test)]
 use crate::Pallet as Remark;
@@ -34,6 +36,19 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	assert_eq!(event, &system_event);
 }
 
+fn max_topic<T: Config>() -> TopicOf<T> {
+	BoundedVec::truncate_from(vec![0u8; T::MaxTopicLength::get() as usize])
+}
+
+/// Make `who` keep an annotation of maximum size, returning its topic.
+fn annotated<T: Config>(who: &T::AccountId) -> Result<TopicOf<T>, BenchmarkError> {
+	T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+	let topic = max_topic::<T>();
+	let data = BoundedVec::truncate_from(vec![0u8; T::MaxAnnotationLength::get() as usize]);
+	Pallet::<T>::annotate(RawOrigin::Signed(who.clone()).into(), topic.clone(), data)?;
+	Ok(topic)
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -54,5 +69,46 @@ mod benchmarks {
 		);
 	}
 
+	#[benchmark]
+	fn annotate(l: Linear<1, { T::MaxAnnotationLength::get() }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		let topic = max_topic::<T>();
+		let data = BoundedVec::truncate_from(vec![0u8; l as usize]);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), topic.clone(), data);
+
+		assert!(Annotations::<T>::contains_key(&caller, &topic));
+	}
+
+	#[benchmark]
+	fn clear_annotation() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let topic = annotated::<T>(&caller)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), topic.clone());
+
+		assert!(!Annotations::<T>::contains_key(&caller, &topic));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn reap_annotation() -> Result<(), BenchmarkError> {
+		let annotator: T::AccountId = account("annotator", 0, 0);
+		let topic = annotated::<T>(&annotator)?;
+		System::<T>::set_block_number(
+			System::<T>::block_number().saturating_add(T::AnnotationRetention::get()),
+		);
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), T::Lookup::unlookup(annotator.clone()), topic.clone());
+
+		assert!(!Annotations::<T>::contains_key(&annotator, &topic));
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Remark, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -16,11 +16,18 @@
 // limitations under the License.
 
 //! Remark storage pallet. Indexes remarks and stores them off chain.
+//!
+//! Accounts may also keep annotations on chain, keyed by a topic of their choosing. An annotation
+//! is backed by a deposit for the bytes it occupies and records the index of the event announcing
+//! it. Once it has been kept for [`Config::AnnotationRetention`] blocks, anyone may reap it, which
+//! returns its deposit to the annotating account. Annotations can be read with the
+//! [`view_functions::AnnotationOfAccount`] view function.
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 mod benchmarking;
+pub mod view_functions;
 pub mod weights;
 
 #[cfg(test)]
@@ -31,6 +38,41 @@ mod tests;
 extern crate alloc;
 
 use alloc::vec::Vec;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	traits::{Currency, ReservableCurrency},
+	BoundedVec, RuntimeDebug,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Saturating, StaticLookup, Zero},
+	DispatchResult,
+};
+
+type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+/// The topic under which an account keeps an annotation.
+pub type TopicOf<T> = BoundedVec<u8, <T as Config>::MaxTopicLength>;
+/// An annotation as stored by the pallet.
+pub type AnnotationOf<T> =
+	Annotation<BalanceOf<T>, BlockNumberFor<T>, <T as Config>::MaxAnnotationLength>;
+
+/// An annotation kept on chain by an account.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+#[scale_info(skip_type_params(MaxLength))]
+#[codec(mel_bound(Balance: MaxEncodedLen, BlockNumber: MaxEncodedLen))]
+pub struct Annotation<Balance, BlockNumber, MaxLength: frame_support::traits::Get<u32>> {
+	/// The annotated data.
+	pub data: BoundedVec<u8, MaxLength>,
+	/// The amount held on deposit for the annotation.
+	pub deposit: Balance,
+	/// The block in which the annotation was made.
+	pub recorded_at: BlockNumber,
+	/// The index within that block of the event announcing the annotation.
+	pub event_index: u32,
+}
 
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
@@ -48,6 +90,29 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
+
+		/// The currency in which annotation deposits are held.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The base deposit held for an annotation.
+		#[pallet::constant]
+		type AnnotationDepositBase: Get<BalanceOf<Self>>;
+
+		/// The deposit held per byte of an annotation's topic and data.
+		#[pallet::constant]
+		type AnnotationDepositPerByte: Get<BalanceOf<Self>>;
+
+		/// The maximum length of an annotation's topic.
+		#[pallet::constant]
+		type MaxTopicLength: Get<u32>;
+
+		/// The maximum length of an annotation's data.
+		#[pallet::constant]
+		type MaxAnnotationLength: Get<u32>;
+
+		/// The number of blocks an annotation is kept before anyone may reap it.
+		#[pallet::constant]
+		type AnnotationRetention: Get<BlockNumberFor<Self>>;
 	}
 
 	#[pallet::error]
@@ -56,11 +121,27 @@ pub mod pallet {
 		Empty,
 		/// Attempted to call `store` outside of block execution.
 		BadContext,
+		/// No annotation is kept under the topic.
+		NotAnnotated,
+		/// The annotation's retention period has not passed yet.
+		StillRetained,
 	}
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
+	/// The annotations kept by an account, by topic.
+	#[pallet::storage]
+	pub type Annotations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		TopicOf<T>,
+		AnnotationOf<T>,
+		OptionQuery,
+	>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Index and store data off chain.
@@ -76,6 +157,74 @@ pub mod pallet {
 			Self::deposit_event(Event::Stored { sender, content_hash: content_hash.into() });
 			Ok(().into())
 		}
+
+		/// Keep `data` on chain under `topic`, replacing any annotation already kept there.
+		///
+		/// A deposit for the topic and data is held from the sender, adjusting the deposit of the
+		/// replaced annotation. The retention period starts anew.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::annotate(data.len() as u32))]
+		pub fn annotate(
+			origin: OriginFor<T>,
+			topic: TopicOf<T>,
+			data: BoundedVec<u8, T::MaxAnnotationLength>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!data.is_empty(), Error::<T>::Empty);
+
+			let bytes = topic.len().saturating_add(data.len()) as u32;
+			let deposit = T::AnnotationDepositPerByte::get()
+				.saturating_mul(bytes.into())
+				.saturating_add(T::AnnotationDepositBase::get());
+			let old_deposit =
+				Annotations::<T>::get(&who, &topic).map_or_else(Zero::zero, |a| a.deposit);
+			if deposit > old_deposit {
+				T::Currency::reserve(&who, deposit - old_deposit)?;
+			} else if deposit < old_deposit {
+				let err_amount = T::Currency::unreserve(&who, old_deposit - deposit);
+				debug_assert!(err_amount.is_zero());
+			}
+
+			let event_index = frame_system::Pallet::<T>::event_count();
+			let recorded_at = frame_system::Pallet::<T>::block_number();
+			Annotations::<T>::insert(
+				&who,
+				&topic,
+				Annotation { data, deposit, recorded_at, event_index },
+			);
+			Self::deposit_event(Event::Annotated { who, topic, deposit });
+			Ok(())
+		}
+
+		/// Remove the annotation the sender keeps under `topic`, releasing its deposit.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::clear_annotation())]
+		pub fn clear_annotation(origin: OriginFor<T>, topic: TopicOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::remove_annotation(who, topic)
+		}
+
+		/// Remove an annotation whose retention period has passed, releasing its deposit to the
+		/// account that made it.
+		///
+		/// May be called by any signed origin.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::reap_annotation())]
+		pub fn reap_annotation(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			topic: TopicOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			let annotation = Annotations::<T>::get(&who, &topic).ok_or(Error::<T>::NotAnnotated)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >=
+					annotation.recorded_at.saturating_add(T::AnnotationRetention::get()),
+				Error::<T>::StillRetained
+			);
+			Self::remove_annotation(who, topic)
+		}
 	}
 
 	#[pallet::event]
@@ -83,5 +232,20 @@ pub mod pallet {
 	pub enum Event<T: Config> {
 		/// Stored data off chain.
 		Stored { sender: T::AccountId, content_hash: sp_core::H256 },
+		/// An annotation was kept on chain.
+		Annotated { who: T::AccountId, topic: TopicOf<T>, deposit: BalanceOf<T> },
+		/// An annotation was removed and its deposit released.
+		AnnotationRemoved { who: T::AccountId, topic: TopicOf<T> },
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Remove an annotation and release its deposit.
+	fn remove_annotation(who: T::AccountId, topic: TopicOf<T>) -> DispatchResult {
+		let annotation = Annotations::<T>::take(&who, &topic).ok_or(Error::<T>::NotAnnotated)?;
+		let err_amount = T::Currency::unreserve(&who, annotation.deposit);
+		debug_assert!(err_amount.is_zero());
+		Self::deposit_event(Event::AnnotationRemoved { who, topic });
+		Ok(())
 	}
 }
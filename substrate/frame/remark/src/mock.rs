@@ -18,7 +18,10 @@
 //! Test environment for remarks pallet.
 
 use crate as pallet_remark;
-use frame_support::derive_impl;
+use frame_support::{
+	derive_impl,
+	traits::{ConstU32, ConstU64},
+};
 use sp_runtime::BuildStorage;
 
 pub type Block = frame_system::mocking::MockBlock<Test>;
@@ -28,6 +31,7 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
+		Balances: pallet_balances,
 		Remark: pallet_remark,
 	}
 );
@@ -35,14 +39,31 @@ frame_support::construct_runtime!(
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Test {
 	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
 }
 
 impl pallet_remark::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type Currency = Balances;
+	type AnnotationDepositBase = ConstU64<10>;
+	type AnnotationDepositPerByte = ConstU64<1>;
+	type MaxTopicLength = ConstU32<8>;
+	type MaxAnnotationLength = ConstU32<32>;
+	type AnnotationRetention = ConstU64<10>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	let t = RuntimeGenesisConfig { system: Default::default() }.build_storage().unwrap();
+	let t = RuntimeGenesisConfig {
+		system: Default::default(),
+		balances: pallet_balances::GenesisConfig { balances: vec![(1, 100), (2, 100)] },
+	}
+	.build_storage()
+	.unwrap();
 	t.into()
 }
@@ -17,9 +17,13 @@
 
 //! Tests for remarks pallet.
 
-use super::{Error, Event, Pallet as Remark};
+use super::{
+	view_functions::AnnotationOfAccount, Annotations, Error, Event, Pallet as Remark, TopicOf,
+};
 use crate::mock::*;
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{
+	assert_noop, assert_ok, traits::ReservableCurrency, view_functions::ViewFunction, BoundedVec,
+};
 use frame_system::RawOrigin;
 
 #[test]
@@ -56,3 +60,65 @@ fn does_not_store_empty() {
 		assert!(System::events().is_empty());
 	});
 }
+
+#[test]
+fn annotations_work() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let topic = TopicOf::<Test>::truncate_from(b"name".to_vec());
+		let data = |d: &[u8]| BoundedVec::truncate_from(d.to_vec());
+
+		assert_noop!(
+			Remark::<Test>::annotate(RawOrigin::Signed(1).into(), topic.clone(), data(b"")),
+			Error::<Test>::Empty
+		);
+		// 10 base deposit and 1 per byte of the topic and data.
+		assert_ok!(Remark::<Test>::annotate(
+			RawOrigin::Signed(1).into(),
+			topic.clone(),
+			data(b"alice")
+		));
+		System::assert_last_event(
+			Event::Annotated { who: 1, topic: topic.clone(), deposit: 19 }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 19);
+		let annotation = AnnotationOfAccount::<Test>(1, topic.clone()).invoke().unwrap();
+		assert_eq!(annotation.data, data(b"alice"));
+		assert_eq!(annotation.recorded_at, 1);
+		assert_eq!(annotation.event_index, System::event_count() - 1);
+
+		// replacing the annotation adjusts its deposit.
+		System::set_block_number(5);
+		assert_ok!(Remark::<Test>::annotate(
+			RawOrigin::Signed(1).into(),
+			topic.clone(),
+			data(b"al")
+		));
+		assert_eq!(Balances::reserved_balance(1), 16);
+		assert_eq!(Annotations::<Test>::get(1, &topic).unwrap().recorded_at, 5);
+
+		// the annotation can only be reaped once its retention period has passed.
+		assert_noop!(
+			Remark::<Test>::reap_annotation(RawOrigin::Signed(2).into(), 1, topic.clone()),
+			Error::<Test>::StillRetained
+		);
+		System::set_block_number(15);
+		assert_ok!(Remark::<Test>::reap_annotation(RawOrigin::Signed(2).into(), 1, topic.clone()));
+		System::assert_last_event(Event::AnnotationRemoved { who: 1, topic: topic.clone() }.into());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(AnnotationOfAccount::<Test>(1, topic.clone()).invoke(), None);
+
+		// or be cleared by its owner at any time.
+		assert_ok!(Remark::<Test>::annotate(
+			RawOrigin::Signed(2).into(),
+			topic.clone(),
+			data(b"bob")
+		));
+		assert_noop!(
+			Remark::<Test>::clear_annotation(RawOrigin::Signed(1).into(), topic.clone()),
+			Error::<Test>::NotAnnotated
+		);
+		assert_ok!(Remark::<Test>::clear_annotation(RawOrigin::Signed(2).into(), topic));
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
@@ -0,0 +1,53 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! View functions of the remark pallet.
+
+use super::*;
+use frame_support::view_functions::{
+	DispatchViewFunction, ViewFunction, ViewFunctionDispatchError, ViewFunctionId,
+};
+
+/// The annotation an account keeps under a topic, if any.
+#[derive(Encode, Decode)]
+pub struct AnnotationOfAccount<T: Config>(pub T::AccountId, pub TopicOf<T>);
+
+impl<T: Config> ViewFunction for AnnotationOfAccount<T> {
+	type ReturnType = Option<AnnotationOf<T>>;
+
+	fn id() -> ViewFunctionId {
+		ViewFunctionId::new::<Pallet<T>>("annotation_of")
+	}
+
+	fn invoke(self) -> Self::ReturnType {
+		Annotations::<T>::get(self.0, self.1)
+	}
+}
+
+impl<T: Config> DispatchViewFunction for Pallet<T> {
+	fn dispatch_view_function<O: codec::Output>(
+		id: &ViewFunctionId,
+		input: &mut &[u8],
+		output: &mut O,
+	) -> Result<(), ViewFunctionDispatchError> {
+		if *id == AnnotationOfAccount::<T>::id() {
+			AnnotationOfAccount::<T>::execute(input, output)
+		} else {
+			Err(ViewFunctionDispatchError::NotFound(*id))
+		}
+	}
+}
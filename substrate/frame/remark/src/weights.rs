@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-wiukf8gn-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `annotate`, `clear_annotation` and `reap_annotation`. Re-run the benchmarks before
+//! relying on them.
 
 // Executed Command:
 // ./target/production/substrate-node
@@ -52,6 +56,9 @@ use core::marker::PhantomData;
 /// Weight functions needed for `pallet_remark`.
 pub trait WeightInfo {
 	fn store(l: u32, ) -> Weight;
+	fn annotate(l: u32, ) -> Weight;
+	fn clear_annotation() -> Weight;
+	fn reap_annotation() -> Weight;
 }
 
 /// Weights for `pallet_remark` using the Substrate node and recommended hardware.
@@ -67,6 +74,48 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 0
 			.saturating_add(Weight::from_parts(1_643, 0).saturating_mul(l.into()))
 	}
+	/// Storage: Remark Annotations (r:1 w:1)
+	/// Proof: Remark Annotations (max_values: None, max_size: Some(1147), added: 3622, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 1024]`.
+	fn annotate(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `4612`
+		// Minimum execution time: 30_714_000 picoseconds.
+		Weight::from_parts(30_714_000, 4612)
+			// Standard Error: 11
+			.saturating_add(Weight::from_parts(1_108, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Remark Annotations (r:1 w:1)
+	/// Proof: Remark Annotations (max_values: None, max_size: Some(1147), added: 3622, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn clear_annotation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1317`
+		//  Estimated: `4612`
+		// Minimum execution time: 31_502_000 picoseconds.
+		Weight::from_parts(31_502_000, 4612)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Remark Annotations (r:1 w:1)
+	/// Proof: Remark Annotations (max_values: None, max_size: Some(1147), added: 3622, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn reap_annotation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1317`
+		//  Estimated: `4612`
+		// Minimum execution time: 33_086_000 picoseconds.
+		Weight::from_parts(33_086_000, 4612)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -81,4 +130,46 @@ impl WeightInfo for () {
 			// Standard Error: 0
 			.saturating_add(Weight::from_parts(1_643, 0).saturating_mul(l.into()))
 	}
+	/// Storage: Remark Annotations (r:1 w:1)
+	/// Proof: Remark Annotations (max_values: None, max_size: Some(1147), added: 3622, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `l` is `[1, 1024]`.
+	fn annotate(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `4612`
+		// Minimum execution time: 30_714_000 picoseconds.
+		Weight::from_parts(30_714_000, 4612)
+			// Standard Error: 11
+			.saturating_add(Weight::from_parts(1_108, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Remark Annotations (r:1 w:1)
+	/// Proof: Remark Annotations (max_values: None, max_size: Some(1147), added: 3622, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn clear_annotation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1317`
+		//  Estimated: `4612`
+		// Minimum execution time: 31_502_000 picoseconds.
+		Weight::from_parts(31_502_000, 4612)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: Remark Annotations (r:1 w:1)
+	/// Proof: Remark Annotations (max_values: None, max_size: Some(1147), added: 3622, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn reap_annotation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1317`
+		//  Estimated: `4612`
+		// Minimum execution time: 33_086_000 picoseconds.
+		Weight::from_parts(33_086_000, 4612)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
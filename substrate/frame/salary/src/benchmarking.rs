@@ -25,6 +25,7 @@ use crate::Pallet as Salary;
 use frame_benchmarking::v2::*;
 use frame_system::{Pallet as System, RawOrigin};
 use sp_core::Get;
+use sp_runtime::traits::One;
 
 const SEED: u32 = 0;
 
@@ -43,6 +44,30 @@ fn ensure_member_with_salary<T: Config<I>, I: 'static>(who: &T::AccountId) {
 	}
 }
 
+/// Set up a sub-spend of the full `SubSpendBudget` from a member of sufficient rank to a
+/// freshly inducted member, returning the beneficiary.
+fn setup_sub_spend<T: Config<I>, I: 'static>() -> T::AccountId {
+	let assigner: T::AccountId = account("assigner", 0, SEED);
+	let beneficiary: T::AccountId = whitelisted_caller();
+	T::Members::induct(&beneficiary).unwrap();
+	let rank = T::MinSubSpendRank::get().max(T::Members::min_rank() + One::one());
+	if T::Members::rank_of(&assigner).is_none() {
+		T::Members::induct(&assigner).unwrap();
+	}
+	while T::Members::rank_of(&assigner).unwrap() < rank {
+		T::Members::promote(&assigner).unwrap();
+	}
+	Salary::<T, I>::init(RawOrigin::Signed(assigner.clone()).into()).unwrap();
+	Salary::<T, I>::assign_sub_spend(
+		RawOrigin::Signed(assigner).into(),
+		beneficiary.clone(),
+		T::SubSpendBudget::get(),
+		Default::default(),
+	)
+	.unwrap();
+	beneficiary
+}
+
 #[instance_benchmarks]
 mod benchmarks {
 	use super::*;
@@ -175,6 +200,78 @@ mod benchmarks {
 		assert!(!matches!(Claimant::<T, I>::get(&caller).unwrap().status, Attempted { .. }));
 	}
 
+	#[benchmark]
+	fn assign_sub_spend() {
+		let caller: T::AccountId = whitelisted_caller();
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		T::Members::induct(&beneficiary).unwrap();
+		let rank = T::MinSubSpendRank::get().max(T::Members::min_rank() + One::one());
+		T::Members::induct(&caller).unwrap();
+		while T::Members::rank_of(&caller).unwrap() < rank {
+			T::Members::promote(&caller).unwrap();
+		}
+		Salary::<T, I>::init(RawOrigin::Signed(caller.clone()).into()).unwrap();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller.clone()),
+			beneficiary.clone(),
+			T::SubSpendBudget::get(),
+			Default::default(),
+		);
+
+		assert!(Salary::<T, I>::sub_spend(0).is_some());
+	}
+
+	#[benchmark]
+	fn claim_sub_spend() {
+		let beneficiary = setup_sub_spend::<T, I>();
+		T::Paymaster::ensure_successful(&beneficiary, (), T::SubSpendBudget::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(beneficiary.clone()), 0);
+
+		match Salary::<T, I>::sub_spend(0).map(|s| s.status) {
+			Some(SubSpendState::Attempted { id }) =>
+				assert_ne!(T::Paymaster::check_payment(id), PaymentStatus::Failure),
+			_ => panic!("No sub-spend payment made"),
+		}
+	}
+
+	#[benchmark]
+	fn check_sub_spend() {
+		let beneficiary = setup_sub_spend::<T, I>();
+		T::Paymaster::ensure_successful(&beneficiary, (), T::SubSpendBudget::get());
+		Salary::<T, I>::claim_sub_spend(RawOrigin::Signed(beneficiary.clone()).into(), 0).unwrap();
+		let id = match Salary::<T, I>::sub_spend(0).unwrap().status {
+			SubSpendState::Attempted { id } => id,
+			_ => panic!("No sub-spend payment made"),
+		};
+		T::Paymaster::ensure_concluded(id);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(beneficiary.clone()), 0);
+
+		assert!(!matches!(
+			Salary::<T, I>::sub_spend(0).map(|s| s.status),
+			Some(SubSpendState::Attempted { .. })
+		));
+	}
+
+	#[benchmark]
+	fn remove_sub_spend() {
+		setup_sub_spend::<T, I>();
+		System::<T>::set_block_number(
+			System::<T>::block_number() + T::SubSpendClaimPeriod::get() + One::one(),
+		);
+		let caller: T::AccountId = account("caller", 0, SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), 0);
+
+		assert!(Salary::<T, I>::sub_spend(0).is_none());
+	}
+
 	impl_benchmark_test_suite! {
 		Salary,
 		crate::tests::unit::new_test_ext(),
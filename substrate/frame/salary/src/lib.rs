@@ -48,6 +48,9 @@ pub use weights::WeightInfo;
 /// Payroll cycle.
 pub type Cycle = u32;
 
+/// Index of a sub-spend.
+pub type SubSpendIndex = u32;
+
 /// The status of the pallet instance.
 #[derive(Encode, Decode, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen, RuntimeDebug)]
 pub struct StatusType<CycleIndex, BlockNumber, Balance> {
@@ -85,6 +88,34 @@ pub struct ClaimantStatus<CycleIndex, Balance, Id> {
 	status: ClaimState<Balance, Id>,
 }
 
+/// The payment state of a sub-spend.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub enum SubSpendState<Id> {
+	/// Not yet claimed, or the last payment attempt failed.
+	Pending,
+	/// A payment has been attempted with the given identity.
+	Attempted { id: Id },
+}
+
+/// A discretionary payment assigned by a member to a lower-ranked member for some deliverable.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct SubSpend<AccountId, Balance, Hash, CycleIndex, BlockNumber, Id> {
+	/// The member who assigned the sub-spend.
+	assigner: AccountId,
+	/// The member who may claim the sub-spend.
+	beneficiary: AccountId,
+	/// The amount to be paid.
+	amount: Balance,
+	/// The hash of the deliverable's description.
+	deliverable: Hash,
+	/// The cycle out of whose sub-budget this is paid.
+	cycle: CycleIndex,
+	/// The block after which the sub-spend can no longer be claimed.
+	expires: BlockNumber,
+	/// The state of the payment.
+	status: SubSpendState<Id>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -139,6 +170,21 @@ pub mod pallet {
 		/// This may change over the course of a cycle without any problem.
 		#[pallet::constant]
 		type Budget: Get<BalanceOf<Self, I>>;
+
+		/// The total budget per cycle for sub-spends, on top of `Budget`.
+		///
+		/// The benchmarks require that this be non-zero.
+		#[pallet::constant]
+		type SubSpendBudget: Get<BalanceOf<Self, I>>;
+
+		/// The minimum rank a member must have to assign a sub-spend. Sub-spends may only be
+		/// assigned to members of a lower rank.
+		#[pallet::constant]
+		type MinSubSpendRank: Get<<Self::Members as RankedMembers>::Rank>;
+
+		/// The number of blocks after assignment for which a sub-spend may be claimed.
+		#[pallet::constant]
+		type SubSpendClaimPeriod: Get<BlockNumberFor<Self>>;
 	}
 
 	pub type CycleIndexOf<T> = BlockNumberFor<T>;
@@ -146,6 +192,14 @@ pub mod pallet {
 	pub type IdOf<T, I> = <<T as Config<I>>::Paymaster as Pay>::Id;
 	pub type StatusOf<T, I> = StatusType<CycleIndexOf<T>, BlockNumberFor<T>, BalanceOf<T, I>>;
 	pub type ClaimantStatusOf<T, I> = ClaimantStatus<CycleIndexOf<T>, BalanceOf<T, I>, IdOf<T, I>>;
+	pub type SubSpendOf<T, I> = SubSpend<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T, I>,
+		<T as frame_system::Config>::Hash,
+		CycleIndexOf<T>,
+		BlockNumberFor<T>,
+		IdOf<T, I>,
+	>;
 
 	/// The overall status of the system.
 	#[pallet::storage]
@@ -157,6 +211,22 @@ pub mod pallet {
 	pub(super) type Claimant<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Twox64Concat, T::AccountId, ClaimantStatusOf<T, I>, OptionQuery>;
 
+	/// The number of sub-spends which have been assigned.
+	#[pallet::storage]
+	pub(super) type SubSpendCount<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, SubSpendIndex, ValueQuery>;
+
+	/// The sub-spends which are yet to be paid out or removed.
+	#[pallet::storage]
+	pub(super) type SubSpends<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, SubSpendIndex, SubSpendOf<T, I>, OptionQuery>;
+
+	/// The cycle of the most recent sub-spend assignment and how much of that cycle's
+	/// `SubSpendBudget` has been allocated.
+	#[pallet::storage]
+	pub(super) type SubSpendAllocated<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, (CycleIndexOf<T>, BalanceOf<T, I>), OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -175,6 +245,23 @@ pub mod pallet {
 		CycleStarted { index: CycleIndexOf<T> },
 		/// A member swapped their account.
 		Swapped { who: T::AccountId, new_who: T::AccountId },
+		/// A sub-spend was assigned to a member for a deliverable.
+		SubSpendAssigned {
+			index: SubSpendIndex,
+			assigner: T::AccountId,
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T, I>,
+			deliverable: T::Hash,
+		},
+		/// A sub-spend payment happened.
+		SubSpendPaid {
+			index: SubSpendIndex,
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T, I>,
+			id: <T::Paymaster as Pay>::Id,
+		},
+		/// A sub-spend was removed, either because it was paid or it was cancelled.
+		SubSpendRemoved { index: SubSpendIndex },
 	}
 
 	#[pallet::error]
@@ -207,6 +294,16 @@ pub mod pallet {
 		Inconclusive,
 		/// The cycle is after that in which the payment was made.
 		NotCurrent,
+		/// The member's rank is too low to assign this sub-spend.
+		InsufficientRank,
+		/// The sub-spend does not exist.
+		UnknownSubSpend,
+		/// The account is not the beneficiary of the sub-spend.
+		NotBeneficiary,
+		/// The sub-spend's claim period is over.
+		SubSpendExpired,
+		/// The sub-spend may only be removed by its assigner before it expires.
+		NoPermission,
 	}
 
 	#[pallet::call]
@@ -380,6 +477,168 @@ pub mod pallet {
 
 			Ok(Pays::No.into())
 		}
+
+		/// Assign a sub-spend to a lower-ranked member for a specific deliverable.
+		///
+		/// The amount is allocated from the current cycle's `SubSpendBudget` and may be claimed
+		/// by the beneficiary within `SubSpendClaimPeriod` blocks.
+		///
+		/// - `origin`: A `Signed` origin of an account which is a member of `Members` of at least
+		///   `MinSubSpendRank`.
+		/// - `beneficiary`: A member of a lower rank than the origin.
+		/// - `amount`: The amount to be paid.
+		/// - `deliverable`: The hash of a description of the work to be delivered.
+		#[pallet::weight(T::WeightInfo::assign_sub_spend())]
+		#[pallet::call_index(7)]
+		pub fn assign_sub_spend(
+			origin: OriginFor<T>,
+			beneficiary: T::AccountId,
+			amount: BalanceOf<T, I>,
+			deliverable: T::Hash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let rank = T::Members::rank_of(&who).ok_or(Error::<T, I>::NotMember)?;
+			let beneficiary_rank =
+				T::Members::rank_of(&beneficiary).ok_or(Error::<T, I>::NotMember)?;
+			ensure!(
+				rank >= T::MinSubSpendRank::get() && rank > beneficiary_rank,
+				Error::<T, I>::InsufficientRank
+			);
+			ensure!(!amount.is_zero(), Error::<T, I>::ClaimZero);
+			let cycle = Status::<T, I>::get().ok_or(Error::<T, I>::NotStarted)?.cycle_index;
+
+			let allocated = match SubSpendAllocated::<T, I>::get() {
+				Some((c, allocated)) if c == cycle => allocated,
+				_ => Zero::zero(),
+			};
+			let allocated = allocated.saturating_add(amount);
+			ensure!(allocated <= T::SubSpendBudget::get(), Error::<T, I>::Bankrupt);
+
+			let index = SubSpendCount::<T, I>::get();
+			let expires = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::SubSpendClaimPeriod::get());
+			let sub_spend = SubSpend {
+				assigner: who.clone(),
+				beneficiary: beneficiary.clone(),
+				amount,
+				deliverable,
+				cycle,
+				expires,
+				status: SubSpendState::Pending,
+			};
+			SubSpends::<T, I>::insert(index, sub_spend);
+			SubSpendCount::<T, I>::put(index.saturating_add(1));
+			SubSpendAllocated::<T, I>::put((cycle, allocated));
+
+			Self::deposit_event(Event::<T, I>::SubSpendAssigned {
+				index,
+				assigner: who,
+				beneficiary,
+				amount,
+				deliverable,
+			});
+			Ok(())
+		}
+
+		/// Claim the payment of a sub-spend.
+		///
+		/// Will only work before the sub-spend's claim period is over.
+		///
+		/// - `origin`: A `Signed` origin of the sub-spend's beneficiary.
+		/// - `index`: The index of the sub-spend.
+		#[pallet::weight(T::WeightInfo::claim_sub_spend())]
+		#[pallet::call_index(8)]
+		pub fn claim_sub_spend(
+			origin: OriginFor<T>,
+			index: SubSpendIndex,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let mut sub_spend =
+				SubSpends::<T, I>::get(index).ok_or(Error::<T, I>::UnknownSubSpend)?;
+			ensure!(sub_spend.beneficiary == who, Error::<T, I>::NotBeneficiary);
+			ensure!(sub_spend.status == SubSpendState::Pending, Error::<T, I>::NoClaim);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now <= sub_spend.expires, Error::<T, I>::SubSpendExpired);
+
+			let id = T::Paymaster::pay(&who, (), sub_spend.amount)
+				.map_err(|_| Error::<T, I>::PayError)?;
+			sub_spend.status = SubSpendState::Attempted { id };
+			let amount = sub_spend.amount;
+			SubSpends::<T, I>::insert(index, sub_spend);
+
+			Self::deposit_event(Event::<T, I>::SubSpendPaid {
+				index,
+				beneficiary: who,
+				amount,
+				id,
+			});
+			Ok(Pays::No.into())
+		}
+
+		/// Update a sub-spend's payment status. If it succeeded the sub-spend is removed; if it
+		/// failed the sub-spend may be claimed again.
+		///
+		/// - `origin`: A `Signed` origin of an account.
+		/// - `index`: The index of the sub-spend.
+		#[pallet::weight(T::WeightInfo::check_sub_spend())]
+		#[pallet::call_index(9)]
+		pub fn check_sub_spend(
+			origin: OriginFor<T>,
+			index: SubSpendIndex,
+		) -> DispatchResultWithPostInfo {
+			let _ = ensure_signed(origin)?;
+			let mut sub_spend =
+				SubSpends::<T, I>::get(index).ok_or(Error::<T, I>::UnknownSubSpend)?;
+			let SubSpendState::Attempted { id } = sub_spend.status else {
+				return Err(Error::<T, I>::NoClaim.into())
+			};
+			match T::Paymaster::check_payment(id) {
+				PaymentStatus::Failure => {
+					sub_spend.status = SubSpendState::Pending;
+					SubSpends::<T, I>::insert(index, sub_spend);
+				},
+				PaymentStatus::Success => {
+					SubSpends::<T, I>::remove(index);
+					Self::deposit_event(Event::<T, I>::SubSpendRemoved { index });
+				},
+				_ => return Err(Error::<T, I>::Inconclusive.into()),
+			}
+			Ok(Pays::No.into())
+		}
+
+		/// Remove an unclaimed sub-spend.
+		///
+		/// If the sub-spend is still within its claim period, only its assigner may remove it. Once
+		/// expired, anyone may remove it. The amount is returned to the sub-budget if its cycle is
+		/// still current.
+		///
+		/// - `origin`: A `Signed` origin of an account.
+		/// - `index`: The index of the sub-spend.
+		#[pallet::weight(T::WeightInfo::remove_sub_spend())]
+		#[pallet::call_index(10)]
+		pub fn remove_sub_spend(
+			origin: OriginFor<T>,
+			index: SubSpendIndex,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let sub_spend = SubSpends::<T, I>::get(index).ok_or(Error::<T, I>::UnknownSubSpend)?;
+			ensure!(sub_spend.status == SubSpendState::Pending, Error::<T, I>::Inconclusive);
+			let now = frame_system::Pallet::<T>::block_number();
+			let expired = now > sub_spend.expires;
+			ensure!(expired || sub_spend.assigner == who, Error::<T, I>::NoPermission);
+
+			SubSpendAllocated::<T, I>::mutate(|maybe_allocated| {
+				if let Some((cycle, allocated)) = maybe_allocated {
+					if *cycle == sub_spend.cycle {
+						allocated.saturating_reduce(sub_spend.amount);
+					}
+				}
+			});
+			SubSpends::<T, I>::remove(index);
+
+			Self::deposit_event(Event::<T, I>::SubSpendRemoved { index });
+			Ok(if expired { Pays::No } else { Pays::Yes }.into())
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -389,6 +648,9 @@ pub mod pallet {
 		pub fn last_active(who: &T::AccountId) -> Result<CycleIndexOf<T>, DispatchError> {
 			Ok(Claimant::<T, I>::get(&who).ok_or(Error::<T, I>::NotInducted)?.last_active)
 		}
+		pub fn sub_spend(index: SubSpendIndex) -> Option<SubSpendOf<T, I>> {
+			SubSpends::<T, I>::get(index)
+		}
 		pub fn cycle_period() -> BlockNumberFor<T> {
 			T::RegistrationPeriod::get() + T::PayoutPeriod::get()
 		}
@@ -100,6 +100,9 @@ impl Config for Test {
 	type RegistrationPeriod = ConstU64<2>;
 	type PayoutPeriod = ConstU64<2>;
 	type Budget = Budget;
+	type SubSpendBudget = ConstU64<10>;
+	type MinSubSpendRank = ConstU16<2>;
+	type SubSpendClaimPeriod = ConstU64<5>;
 }
 
 pub struct FixedSalary;
@@ -26,6 +26,7 @@ use frame_support::{
 	parameter_types,
 	traits::{tokens::ConvertRank, ConstU64},
 };
+use sp_core::H256;
 use sp_runtime::{traits::Identity, BuildStorage, DispatchResult};
 
 use crate as pallet_salary;
@@ -154,6 +155,9 @@ impl Config for Test {
 	type RegistrationPeriod = ConstU64<2>;
 	type PayoutPeriod = ConstU64<2>;
 	type Budget = Budget;
+	type SubSpendBudget = ConstU64<10>;
+	type MinSubSpendRank = ConstU64<2>;
+	type SubSpendClaimPeriod = ConstU64<5>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -615,3 +619,79 @@ fn other_mixed_bankruptcy_fails_gracefully() {
 		assert_eq!(paid(3), 6);
 	});
 }
+
+#[test]
+fn sub_spends_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Salary::init(RuntimeOrigin::signed(1)));
+		set_rank(1, 1);
+		set_rank(2, 2);
+		set_rank(3, 3);
+		let hash = H256::repeat_byte(1);
+
+		// Only members of at least `MinSubSpendRank` may assign, and only to lower ranks.
+		assert_noop!(
+			Salary::assign_sub_spend(RuntimeOrigin::signed(1), 4, 1, hash),
+			Error::<Test>::NotMember
+		);
+		assert_noop!(
+			Salary::assign_sub_spend(RuntimeOrigin::signed(1), 1, 1, hash),
+			Error::<Test>::InsufficientRank
+		);
+		assert_noop!(
+			Salary::assign_sub_spend(RuntimeOrigin::signed(2), 3, 1, hash),
+			Error::<Test>::InsufficientRank
+		);
+		assert_noop!(
+			Salary::assign_sub_spend(RuntimeOrigin::signed(2), 1, 0, hash),
+			Error::<Test>::ClaimZero
+		);
+
+		// The sub-budget is shared between all assigners.
+		assert_ok!(Salary::assign_sub_spend(RuntimeOrigin::signed(3), 1, 6, hash));
+		assert_noop!(
+			Salary::assign_sub_spend(RuntimeOrigin::signed(2), 1, 5, hash),
+			Error::<Test>::Bankrupt
+		);
+		assert_ok!(Salary::assign_sub_spend(RuntimeOrigin::signed(2), 1, 4, hash));
+
+		// Only the beneficiary may claim, and only once.
+		assert_noop!(
+			Salary::claim_sub_spend(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NotBeneficiary
+		);
+		assert_ok!(Salary::claim_sub_spend(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(1), 6);
+		assert_noop!(Salary::claim_sub_spend(RuntimeOrigin::signed(1), 0), Error::<Test>::NoClaim);
+
+		// A successful payment clears the sub-spend.
+		assert_noop!(
+			Salary::check_sub_spend(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::Inconclusive
+		);
+		set_status(0, PaymentStatus::Success);
+		assert_ok!(Salary::check_sub_spend(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Salary::sub_spend(0), None);
+
+		// The assigner may cancel before expiry, returning the amount to the sub-budget.
+		assert_noop!(
+			Salary::remove_sub_spend(RuntimeOrigin::signed(3), 1),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Salary::remove_sub_spend(RuntimeOrigin::signed(2), 1));
+		assert_ok!(Salary::assign_sub_spend(RuntimeOrigin::signed(2), 1, 4, hash));
+
+		// Once expired, it can no longer be claimed but anyone may remove it.
+		run_to(7);
+		assert_noop!(
+			Salary::claim_sub_spend(RuntimeOrigin::signed(1), 2),
+			Error::<Test>::SubSpendExpired
+		);
+		assert_ok!(Salary::remove_sub_spend(RuntimeOrigin::signed(3), 2));
+		assert_eq!(paid(1), 6);
+
+		// The sub-budget is renewed with the cycle.
+		assert_ok!(Salary::bump(RuntimeOrigin::signed(1)));
+		assert_ok!(Salary::assign_sub_spend(RuntimeOrigin::signed(3), 2, 10, hash));
+	});
+}
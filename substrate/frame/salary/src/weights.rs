@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-wiukf8gn-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `assign_sub_spend`, `claim_sub_spend`, `check_sub_spend` and `remove_sub_spend`. Re-run
+//! the benchmarks before relying on them.
 
 // Executed Command:
 // ./target/production/substrate-node
@@ -58,6 +62,10 @@ pub trait WeightInfo {
 	fn payout() -> Weight;
 	fn payout_other() -> Weight;
 	fn check_payment() -> Weight;
+	fn assign_sub_spend() -> Weight;
+	fn claim_sub_spend() -> Weight;
+	fn check_sub_spend() -> Weight;
+	fn remove_sub_spend() -> Weight;
 }
 
 /// Weights for `pallet_salary` using the Substrate node and recommended hardware.
@@ -160,6 +168,62 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `RankedCollective::Members` (r:2 w:0)
+	/// Proof: `RankedCollective::Members` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `Salary::Status` (r:1 w:0)
+	/// Proof: `Salary::Status` (`max_values`: Some(1), `max_size`: Some(56), added: 551, mode: `MaxEncodedLen`)
+	/// Storage: `Salary::SubSpendAllocated` (r:1 w:1)
+	/// Proof: `Salary::SubSpendAllocated` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `Salary::SubSpendCount` (r:1 w:1)
+	/// Proof: `Salary::SubSpendCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Salary::SubSpends` (r:0 w:1)
+	/// Proof: `Salary::SubSpends` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn assign_sub_spend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `502`
+		//  Estimated: `6024`
+		// Minimum execution time: 24_318_000 picoseconds.
+		Weight::from_parts(25_104_000, 6024)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Salary::SubSpends` (r:1 w:1)
+	/// Proof: `Salary::SubSpends` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_sub_spend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `452`
+		//  Estimated: `6196`
+		// Minimum execution time: 51_327_000 picoseconds.
+		Weight::from_parts(52_860_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Salary::SubSpends` (r:1 w:1)
+	/// Proof: `Salary::SubSpends` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn check_sub_spend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `226`
+		//  Estimated: `3598`
+		// Minimum execution time: 11_874_000 picoseconds.
+		Weight::from_parts(12_391_000, 3598)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Salary::SubSpends` (r:1 w:1)
+	/// Proof: `Salary::SubSpends` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Salary::SubSpendAllocated` (r:1 w:1)
+	/// Proof: `Salary::SubSpendAllocated` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	fn remove_sub_spend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262`
+		//  Estimated: `3598`
+		// Minimum execution time: 14_092_000 picoseconds.
+		Weight::from_parts(14_718_000, 3598)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -261,4 +325,60 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `RankedCollective::Members` (r:2 w:0)
+	/// Proof: `RankedCollective::Members` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `Salary::Status` (r:1 w:0)
+	/// Proof: `Salary::Status` (`max_values`: Some(1), `max_size`: Some(56), added: 551, mode: `MaxEncodedLen`)
+	/// Storage: `Salary::SubSpendAllocated` (r:1 w:1)
+	/// Proof: `Salary::SubSpendAllocated` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `Salary::SubSpendCount` (r:1 w:1)
+	/// Proof: `Salary::SubSpendCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Salary::SubSpends` (r:0 w:1)
+	/// Proof: `Salary::SubSpends` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn assign_sub_spend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `502`
+		//  Estimated: `6024`
+		// Minimum execution time: 24_318_000 picoseconds.
+		Weight::from_parts(25_104_000, 6024)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Salary::SubSpends` (r:1 w:1)
+	/// Proof: `Salary::SubSpends` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_sub_spend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `452`
+		//  Estimated: `6196`
+		// Minimum execution time: 51_327_000 picoseconds.
+		Weight::from_parts(52_860_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Salary::SubSpends` (r:1 w:1)
+	/// Proof: `Salary::SubSpends` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn check_sub_spend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `226`
+		//  Estimated: `3598`
+		// Minimum execution time: 11_874_000 picoseconds.
+		Weight::from_parts(12_391_000, 3598)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Salary::SubSpends` (r:1 w:1)
+	/// Proof: `Salary::SubSpends` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Salary::SubSpendAllocated` (r:1 w:1)
+	/// Proof: `Salary::SubSpendAllocated` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	fn remove_sub_spend() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262`
+		//  Estimated: `3598`
+		// Minimum execution time: 14_092_000 picoseconds.
+		Weight::from_parts(14_718_000, 3598)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
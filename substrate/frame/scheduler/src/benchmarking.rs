@@ -67,6 +67,36 @@ fn fill_schedule<T: Config>(
 	Ok(())
 }
 
+/// A timestamp in the future, at which the tasks of the timestamp agenda are scheduled.
+fn future_timestamp<T: Config>() -> MomentOf<T> {
+	T::TimeProvider::now()
+		.saturating_add(T::TimestampBucketSize::get())
+		.saturating_add(One::one())
+}
+
+/// Add `n` periodic items to the bucket of the timestamp agenda holding `when`.
+fn fill_timestamp_schedule<T: Config>(when: MomentOf<T>, n: u32) -> Result<(), &'static str> {
+	let origin: <T as Config>::PalletsOrigin = frame_system::RawOrigin::Root.into();
+	for i in 0..n {
+		let call = make_call::<T>(None);
+		let period = Some(100u32.into());
+		let name = u32_to_name(i);
+		Scheduler::<T>::do_schedule_named_at_timestamp(
+			name,
+			when,
+			period,
+			0,
+			origin.clone(),
+			call,
+		)?;
+	}
+	ensure!(
+		TimestampAgenda::<T>::get(Scheduler::<T>::timestamp_bucket(when)).len() == n as usize,
+		"didn't fill timestamp schedule"
+	);
+	Ok(())
+}
+
 fn u32_to_name(i: u32) -> TaskName {
 	i.using_encoded(blake2_256)
 }
@@ -415,5 +445,70 @@ benchmarks! {
 		);
 	}
 
+	schedule_named_at_timestamp {
+		let s in 0 .. (T::MaxScheduledPerBlock::get() - 1);
+		let id = u32_to_name(s);
+		let when = future_timestamp::<T>();
+		let period = Some(100u32.into());
+		let priority = 0;
+		// Essentially a no-op call.
+		let call = Box::new(SystemCall::set_storage { items: vec![] }.into());
+
+		fill_timestamp_schedule::<T>(when, s)?;
+	}: _(RawOrigin::Root, id, when, period, priority, call)
+	verify {
+		ensure!(
+			TimestampAgenda::<T>::get(Scheduler::<T>::timestamp_bucket(when)).len() ==
+				(s + 1) as usize,
+			"didn't add to timestamp schedule"
+		);
+	}
+
+	cancel_named_at_timestamp {
+		let s in 1 .. T::MaxScheduledPerBlock::get();
+		let when = future_timestamp::<T>();
+
+		fill_timestamp_schedule::<T>(when, s)?;
+	}: _(RawOrigin::Root, u32_to_name(0))
+	verify {
+		ensure!(!TimestampLookup::<T>::contains_key(u32_to_name(0)), "didn't remove from lookup");
+		ensure!(
+			TimestampAgenda::<T>::get(Scheduler::<T>::timestamp_bucket(when)).len() ==
+				(s - 1) as usize,
+			"didn't remove from timestamp schedule"
+		);
+	}
+
+	// `enqueue_due_timestamp_tasks` when no bucket of the timestamp agenda is due.
+	service_timestamp_agenda_base {
+		let now = BLOCK_NUMBER.into();
+		let bucket = Scheduler::<T>::timestamp_bucket(future_timestamp::<T>());
+		TimestampAgendaRange::<T>::put((bucket, bucket));
+	}: {
+		Scheduler::<T>::enqueue_due_timestamp_tasks(&mut WeightMeter::new(), now);
+	} verify {
+		assert_eq!(TimestampAgendaRange::<T>::get(), Some((bucket, bucket)));
+	}
+
+	// `service_timestamp_bucket` when a single task of the bucket is placed and the others are
+	// written back.
+	service_timestamp_bucket {
+		let s in 1 .. T::MaxScheduledPerBlock::get();
+		let now = BLOCK_NUMBER.into();
+		let when = future_timestamp::<T>();
+		let bucket = Scheduler::<T>::timestamp_bucket(when);
+		fill_timestamp_schedule::<T>(when, s)?;
+		let max = T::MaxScheduledPerBlock::get();
+		let task_weight = T::WeightInfo::schedule(max)
+			.saturating_add(T::WeightInfo::schedule_named_at_timestamp(max));
+		let mut weight = WeightMeter::with_limit(task_weight);
+		let mut rescheduled = Vec::new();
+	}: {
+		Scheduler::<T>::service_timestamp_bucket(&mut weight, now, bucket, when, &mut rescheduled);
+	} verify {
+		assert_eq!(TimestampAgenda::<T>::get(bucket).len(), (s - 1) as usize);
+		assert_eq!(rescheduled.len(), 1);
+	}
+
 	impl_benchmark_test_suite!(Scheduler, crate::mock::new_test_ext(), crate::mock::Test);
 }
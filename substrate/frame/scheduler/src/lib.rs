@@ -95,8 +95,9 @@ use frame_support::{
 	ensure,
 	traits::{
 		schedule::{self, DispatchTime, MaybeHashed},
-		Bounded, CallerTrait, EnsureOrigin, Get, IsType, OriginTrait, PalletInfoAccess,
-		PrivilegeCmp, QueryPreimage, StorageVersion, StorePreimage,
+		Bounded, CallerTrait, EnsureOrigin, Get, InspectNestedCalls, IsSubType, IsType,
+		OriginTrait, PalletInfoAccess, PrivilegeCmp, QueryPreimage, StorageVersion, StorePreimage,
+		Time,
 	},
 	weights::{Weight, WeightMeter},
 };
@@ -125,6 +126,9 @@ pub type CallOrHashOf<T> =
 pub type BoundedCallOf<T> =
 	Bounded<<T as Config>::RuntimeCall, <T as frame_system::Config>::Hashing>;
 
+/// The wall-clock time type of the [`Config::TimeProvider`].
+pub type MomentOf<T> = <<T as Config>::TimeProvider as Time>::Moment;
+
 /// The configuration of the retry mechanism for a given task along with its current state.
 #[derive(Clone, Copy, RuntimeDebug, PartialEq, Eq, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub struct RetryConfig<Period> {
@@ -182,6 +186,22 @@ where
 	}
 }
 
+/// A named task scheduled at a wall-clock timestamp, waiting to be due.
+#[cfg_attr(any(feature = "std", test), derive(PartialEq, Eq))]
+#[derive(Clone, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo)]
+pub struct TimestampScheduled<Moment, Task> {
+	/// The unique identity of the task.
+	id: TaskName,
+	/// The timestamp from which the task is due.
+	when: Moment,
+	/// If the task is periodic, the time between two of its occurrences.
+	maybe_period: Option<Moment>,
+	/// The task placed into the agenda of the block in which it is due.
+	task: Task,
+}
+
+pub type TimestampScheduledOf<T> = TimestampScheduled<MomentOf<T>, ScheduledOf<T>>;
+
 use crate::{Scheduled as ScheduledV3, Scheduled as ScheduledV2};
 
 pub type ScheduledV2Of<T> = ScheduledV2<
@@ -292,6 +312,29 @@ pub mod pallet {
 
 		/// The preimage provider with which we look up call hashes to get the call.
 		type Preimages: QueryPreimage<H = Self::Hashing> + StorePreimage;
+
+		/// The wall-clock time against which tasks scheduled at a timestamp are resolved.
+		///
+		/// Usually `pallet_timestamp`. Note that it is read in `on_initialize`, hence before the
+		/// timestamp of the current block is set, so it reports the time of the parent block.
+		type TimeProvider: Time;
+
+		/// How early a task scheduled at a timestamp may be placed into the agenda.
+		///
+		/// A task is due in the first block that observes a time of at least its timestamp minus
+		/// this tolerance. Setting it to about one block time makes tasks run in the block closest
+		/// to their timestamp rather than in the block after it.
+		#[pallet::constant]
+		type TimestampTolerance: Get<MomentOf<Self>>;
+
+		/// The span of time covered by a bucket of the timestamp agenda.
+		///
+		/// Tasks scheduled at a timestamp are stored in buckets of this span, at most
+		/// `MaxScheduledPerBlock` tasks per bucket. Every block visits the buckets from the
+		/// earliest one holding tasks up to the current time, so this should be a few block times
+		/// at least.
+		#[pallet::constant]
+		type TimestampBucketSize: Get<MomentOf<Self>>;
 	}
 
 	#[pallet::storage]
@@ -325,6 +368,25 @@ pub mod pallet {
 	pub(crate) type Lookup<T: Config> =
 		StorageMap<_, Twox64Concat, TaskName, TaskAddress<BlockNumberFor<T>>>;
 
+	/// Named tasks scheduled at a timestamp which are not due yet, indexed by the bucket of their
+	/// timestamp, i.e. the timestamp divided by `T::TimestampBucketSize`, and ordered by timestamp.
+	#[pallet::storage]
+	pub type TimestampAgenda<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		MomentOf<T>,
+		BoundedVec<TimestampScheduledOf<T>, T::MaxScheduledPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Lookup from the name of a task scheduled at a timestamp to its timestamp.
+	#[pallet::storage]
+	pub type TimestampLookup<T: Config> = StorageMap<_, Twox64Concat, TaskName, MomentOf<T>>;
+
+	/// The earliest bucket of the timestamp agenda which may hold tasks, and the latest one.
+	#[pallet::storage]
+	pub type TimestampAgendaRange<T: Config> = StorageValue<_, (MomentOf<T>, MomentOf<T>)>;
+
 	/// Events type.
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -357,6 +419,12 @@ pub mod pallet {
 		RetryFailed { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
 		/// The given task can never be executed since it is overweight.
 		PermanentlyOverweight { task: TaskAddress<BlockNumberFor<T>>, id: Option<TaskName> },
+		/// Scheduled some named task at a timestamp.
+		ScheduledAtTimestamp { when: MomentOf<T>, id: TaskName },
+		/// Canceled some named task scheduled at a timestamp.
+		CanceledAtTimestamp { id: TaskName },
+		/// A task scheduled at a timestamp is due and was placed into the agenda.
+		DueAtTimestamp { when: MomentOf<T>, id: TaskName, task: TaskAddress<BlockNumberFor<T>> },
 	}
 
 	#[pallet::error]
@@ -371,6 +439,8 @@ pub mod pallet {
 		RescheduleNoChange,
 		/// Attempt to use a non-named function on a named task.
 		Named,
+		/// Given target timestamp is in the past.
+		TargetTimestampInPast,
 	}
 
 	#[pallet::hooks]
@@ -378,6 +448,7 @@ pub mod pallet {
 		/// Execute the scheduled calls
 		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
 			let mut weight_counter = WeightMeter::with_limit(T::MaximumWeight::get());
+			Self::enqueue_due_timestamp_tasks(&mut weight_counter, now);
 			Self::service_agendas(&mut weight_counter, now, u32::max_value());
 			weight_counter.consumed()
 		}
@@ -601,6 +672,56 @@ pub mod pallet {
 			Self::deposit_event(Event::RetryCancelled { task, id: Some(id) });
 			Ok(())
 		}
+
+		/// Schedule a named task at a wall-clock timestamp, as reported by `T::TimeProvider`.
+		///
+		/// The task is placed into the agenda of the first block observing a time of at least
+		/// `when` minus `T::TimestampTolerance`, and dispatched like any other task of that block.
+		/// If no block was produced around `when`, the task runs late rather than being skipped.
+		/// A task that cannot be placed because the agenda is full or the block has no weight
+		/// left is tried again in the next block.
+		///
+		/// A periodic task is scheduled again `period` after the occurrence that was due,
+		/// skipping the occurrences that were missed in between.
+		///
+		/// At most `T::MaxScheduledPerBlock` tasks can be scheduled within the same span of
+		/// `T::TimestampBucketSize`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_named_at_timestamp(
+			T::MaxScheduledPerBlock::get()
+		))]
+		pub fn schedule_named_at_timestamp(
+			origin: OriginFor<T>,
+			id: TaskName,
+			when: MomentOf<T>,
+			maybe_period: Option<MomentOf<T>>,
+			priority: schedule::Priority,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_schedule_named_at_timestamp(
+				id,
+				when,
+				maybe_period,
+				priority,
+				origin.caller().clone(),
+				T::Preimages::bound(*call)?,
+			)?;
+			Ok(())
+		}
+
+		/// Cancel a named task scheduled at a timestamp which is not due yet.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_named_at_timestamp(
+			T::MaxScheduledPerBlock::get()
+		))]
+		pub fn cancel_named_at_timestamp(origin: OriginFor<T>, id: TaskName) -> DispatchResult {
+			T::ScheduleOrigin::ensure_origin(origin.clone())?;
+			let origin = <T as Config>::RuntimeOrigin::from(origin);
+			Self::do_cancel_named_at_timestamp(Some(origin.caller().clone()), id)?;
+			Ok(())
+		}
 	}
 }
 
@@ -1127,6 +1248,99 @@ impl<T: Config> Pallet<T> {
 		Self::place_task(new_time, task).map_err(|x| x.0)
 	}
 
+	fn do_schedule_named_at_timestamp(
+		id: TaskName,
+		when: MomentOf<T>,
+		maybe_period: Option<MomentOf<T>>,
+		priority: schedule::Priority,
+		origin: T::PalletsOrigin,
+		call: BoundedCallOf<T>,
+	) -> DispatchResult {
+		// ensure id it is unique
+		if Lookup::<T>::contains_key(&id) || TimestampLookup::<T>::contains_key(&id) {
+			return Err(Error::<T>::FailedToSchedule.into())
+		}
+
+		if when <= T::TimeProvider::now() {
+			return Err(Error::<T>::TargetTimestampInPast.into())
+		}
+
+		let lookup_hash = call.lookup_hash();
+
+		let task = Scheduled {
+			maybe_id: None,
+			priority,
+			call,
+			maybe_periodic: None,
+			origin,
+			_phantom: Default::default(),
+		};
+		let maybe_period = maybe_period.filter(|period| !period.is_zero());
+		Self::insert_timestamp_task(TimestampScheduled { id, when, maybe_period, task })
+			.map_err(|_| Error::<T>::FailedToSchedule)?;
+
+		if let Some(hash) = lookup_hash {
+			// Request the call to be made available.
+			T::Preimages::request(&hash);
+		}
+
+		Self::deposit_event(Event::ScheduledAtTimestamp { when, id });
+		Ok(())
+	}
+
+	fn do_cancel_named_at_timestamp(
+		origin: Option<T::PalletsOrigin>,
+		id: TaskName,
+	) -> DispatchResult {
+		let when = TimestampLookup::<T>::get(&id).ok_or(Error::<T>::NotFound)?;
+		let bucket = Self::timestamp_bucket(when);
+		let scheduled = TimestampAgenda::<T>::try_mutate_exists(bucket, |maybe_agenda| {
+			let agenda = maybe_agenda.as_mut().ok_or(Error::<T>::NotFound)?;
+			let index = agenda
+				.iter()
+				.position(|scheduled| scheduled.id == id)
+				.ok_or(Error::<T>::NotFound)?;
+			if let Some(ref o) = origin {
+				Self::ensure_privilege(o, &agenda[index].task.origin)?;
+			}
+			let scheduled = agenda.remove(index);
+			if agenda.is_empty() {
+				*maybe_agenda = None;
+			}
+			Ok::<_, DispatchError>(scheduled)
+		})?;
+		TimestampLookup::<T>::remove(&id);
+		T::Preimages::drop(&scheduled.task.call);
+		Self::deposit_event(Event::CanceledAtTimestamp { id });
+		Ok(())
+	}
+
+	/// The bucket of the timestamp agenda holding the tasks scheduled at `when`.
+	fn timestamp_bucket(when: MomentOf<T>) -> MomentOf<T> {
+		when / T::TimestampBucketSize::get().max(One::one())
+	}
+
+	/// Insert `scheduled` into the bucket of its timestamp, keeping the bucket ordered by
+	/// timestamp.
+	fn insert_timestamp_task(
+		scheduled: TimestampScheduledOf<T>,
+	) -> Result<(), TimestampScheduledOf<T>> {
+		let (id, when) = (scheduled.id, scheduled.when);
+		let bucket = Self::timestamp_bucket(when);
+		TimestampAgenda::<T>::try_mutate(bucket, |agenda| {
+			let index = agenda.partition_point(|other| other.when <= scheduled.when);
+			agenda.try_insert(index, scheduled)
+		})?;
+		TimestampLookup::<T>::insert(id, when);
+		TimestampAgendaRange::<T>::mutate(|range| {
+			*range = Some(match *range {
+				Some((first, last)) => (first.min(bucket), last.max(bucket)),
+				None => (bucket, bucket),
+			});
+		});
+		Ok(())
+	}
+
 	fn do_cancel_retry(
 		origin: &T::PalletsOrigin,
 		(when, index): TaskAddress<BlockNumberFor<T>>,
@@ -1151,6 +1365,117 @@ enum ServiceTaskError {
 use ServiceTaskError::*;
 
 impl<T: Config> Pallet<T> {
+	/// Place the tasks scheduled at a timestamp which are due into the agenda of `now`.
+	///
+	/// The buckets of the timestamp agenda are visited from the earliest one holding tasks up to
+	/// the bucket of the current time. Tasks which cannot be placed, because the agenda is full or
+	/// there is not enough weight left, remain in their bucket and are tried again in the next
+	/// block.
+	fn enqueue_due_timestamp_tasks(weight: &mut WeightMeter, now: BlockNumberFor<T>) {
+		if weight.try_consume(T::WeightInfo::service_timestamp_agenda_base()).is_err() {
+			return
+		}
+		let Some((mut bucket, last)) = TimestampAgendaRange::<T>::get() else { return };
+
+		let threshold = T::TimeProvider::now().saturating_add(T::TimestampTolerance::get());
+		let due_bucket = Self::timestamp_bucket(threshold).min(last);
+		let bucket_weight = T::WeightInfo::service_timestamp_bucket(T::MaxScheduledPerBlock::get());
+		let mut rescheduled = Vec::new();
+		while bucket <= due_bucket && weight.try_consume(bucket_weight).is_ok() {
+			if !Self::service_timestamp_bucket(weight, now, bucket, threshold, &mut rescheduled) {
+				break
+			}
+			bucket.saturating_inc();
+		}
+
+		if bucket > last {
+			TimestampAgendaRange::<T>::kill();
+		} else {
+			TimestampAgendaRange::<T>::put((bucket, last));
+		}
+		for scheduled in rescheduled {
+			Self::reschedule_timestamp_task(scheduled);
+		}
+	}
+
+	/// Place the tasks of the timestamp agenda `bucket` which are due at `threshold` into the
+	/// agenda of `now`. The next occurrences of periodic tasks are pushed to `rescheduled`.
+	///
+	/// Returns whether the bucket is empty afterwards.
+	fn service_timestamp_bucket(
+		weight: &mut WeightMeter,
+		now: BlockNumberFor<T>,
+		bucket: MomentOf<T>,
+		threshold: MomentOf<T>,
+		rescheduled: &mut Vec<(TimestampScheduledOf<T>, TaskAddress<BlockNumberFor<T>>)>,
+	) -> bool {
+		let mut agenda = TimestampAgenda::<T>::get(bucket).into_inner();
+		let max_items = T::MaxScheduledPerBlock::get();
+		// A periodic task is inserted into the timestamp agenda again.
+		let task_weight = T::WeightInfo::schedule(max_items)
+			.saturating_add(T::WeightInfo::schedule_named_at_timestamp(max_items));
+
+		let mut placed = 0;
+		for scheduled in agenda.iter() {
+			if scheduled.when > threshold || weight.try_consume(task_weight).is_err() {
+				break
+			}
+			let Ok(address) = Self::place_task(now, scheduled.task.clone()) else {
+				// The agenda of this block is full, try again in the next one.
+				break
+			};
+			placed += 1;
+			Self::deposit_event(Event::DueAtTimestamp {
+				when: scheduled.when,
+				id: scheduled.id,
+				task: address,
+			});
+
+			if let Some(period) = scheduled.maybe_period {
+				// Skip the occurrences which were missed.
+				let missed = threshold.saturating_sub(scheduled.when) / period;
+				let next = scheduled
+					.when
+					.saturating_add(missed.saturating_add(One::one()).saturating_mul(period));
+				rescheduled.push((TimestampScheduled { when: next, ..scheduled.clone() }, address));
+			} else {
+				TimestampLookup::<T>::remove(&scheduled.id);
+			}
+		}
+
+		agenda.drain(..placed);
+		let is_empty = agenda.is_empty();
+		if placed > 0 {
+			if is_empty {
+				TimestampAgenda::<T>::remove(bucket);
+			} else {
+				TimestampAgenda::<T>::insert(bucket, BoundedVec::truncate_from(agenda));
+			}
+		}
+		is_empty
+	}
+
+	/// Insert the next occurrence of a periodic task scheduled at a timestamp, whose due
+	/// occurrence was placed at `address`.
+	fn reschedule_timestamp_task(
+		(scheduled, address): (TimestampScheduledOf<T>, TaskAddress<BlockNumberFor<T>>),
+	) {
+		let id = scheduled.id;
+		let maybe_hash = scheduled.task.call.lookup_hash();
+		match Self::insert_timestamp_task(scheduled) {
+			Ok(()) =>
+				if let Some(hash) = maybe_hash {
+					// The placed task consumes the preimage request, make one for the next
+					// occurrence.
+					T::Preimages::request(&hash);
+				},
+			Err(_) => {
+				TimestampLookup::<T>::remove(&id);
+				Self::deposit_event(Event::PeriodicFailed { task: address, id: Some(id) });
+			},
+		}
+	}
+
 	/// Service up to `max` agendas queue starting from earliest incompletely executed agenda.
 	fn service_agendas(weight: &mut WeightMeter, now: BlockNumberFor<T>, max: u32) {
 		if weight.try_consume(T::WeightInfo::service_agendas_base()).is_err() {
@@ -1449,6 +1774,22 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
+impl<T: Config> InspectNestedCalls<<T as Config>::RuntimeCall> for Pallet<T>
+where
+	<T as Config>::RuntimeCall: IsSubType<Call<T>>,
+{
+	fn nested_calls(call: &<T as Config>::RuntimeCall) -> Vec<&<T as Config>::RuntimeCall> {
+		match call.is_sub_type() {
+			Some(Call::schedule { call, .. }) |
+			Some(Call::schedule_named { call, .. }) |
+			Some(Call::schedule_after { call, .. }) |
+			Some(Call::schedule_named_after { call, .. }) |
+			Some(Call::schedule_named_at_timestamp { call, .. }) => Vec::from([&**call]),
+			_ => Vec::new(),
+		}
+	}
+}
+
 #[allow(deprecated)]
 impl<T: Config> schedule::v2::Anon<BlockNumberFor<T>, <T as Config>::RuntimeCall, T::PalletsOrigin>
 	for Pallet<T>
@@ -22,7 +22,9 @@ use super::*;
 use crate as scheduler;
 use frame_support::{
 	derive_impl, ord_parameter_types, parameter_types,
-	traits::{ConstU32, Contains, EitherOfDiverse, EqualPrivilegeOnly, OnFinalize, OnInitialize},
+	traits::{
+		ConstU32, ConstU64, Contains, EitherOfDiverse, EqualPrivilegeOnly, OnFinalize, OnInitialize,
+	},
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use sp_runtime::{BuildStorage, Perbill};
@@ -210,10 +212,33 @@ impl WeightInfo for TestWeightInfo {
 	fn cancel_retry_named() -> Weight {
 		Weight::from_parts(50, 0)
 	}
+	fn schedule_named_at_timestamp(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn cancel_named_at_timestamp(_s: u32) -> Weight {
+		Weight::from_parts(50, 0)
+	}
+	fn service_timestamp_agenda_base() -> Weight {
+		Weight::zero()
+	}
+	fn service_timestamp_bucket(_s: u32) -> Weight {
+		Weight::zero()
+	}
 }
 parameter_types! {
 	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
 		BlockWeights::get().max_block;
+	pub static Now: u64 = 0;
+}
+
+/// A wall-clock time which is set by the tests through [`Now`].
+pub struct MockTime;
+impl Time for MockTime {
+	type Moment = u64;
+
+	fn now() -> u64 {
+		Now::get()
+	}
 }
 
 impl Config for Test {
@@ -227,6 +252,9 @@ impl Config for Test {
 	type WeightInfo = TestWeightInfo;
 	type OriginPrivilegeCmp = EqualPrivilegeOnly;
 	type Preimages = Preimage;
+	type TimeProvider = MockTime;
+	type TimestampTolerance = ConstU64<1_000>;
+	type TimestampBucketSize = ConstU64<10_000>;
 }
 
 pub type LoggerCall = logger::Call<Test>;
@@ -3035,3 +3035,189 @@ fn unavailable_call_is_detected() {
 		assert!(!Preimage::is_requested(&hash));
 	});
 }
+
+#[test]
+fn timestamp_scheduling_works() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let name = [1u8; 32];
+
+		assert_noop!(
+			Scheduler::schedule_named_at_timestamp(
+				RuntimeOrigin::root(),
+				name,
+				0,
+				None,
+				127,
+				Box::new(call.clone())
+			),
+			Error::<Test>::TargetTimestampInPast
+		);
+		assert_ok!(Scheduler::schedule_named_at_timestamp(
+			RuntimeOrigin::root(),
+			name,
+			10_000,
+			None,
+			127,
+			Box::new(call.clone())
+		));
+		// the name must be unique
+		assert_noop!(
+			Scheduler::schedule_named_at_timestamp(
+				RuntimeOrigin::root(),
+				name,
+				20_000,
+				None,
+				127,
+				Box::new(call)
+			),
+			Error::<Test>::FailedToSchedule
+		);
+
+		Now::set(5_000);
+		run_to_block(2);
+		assert!(logger::log().is_empty());
+
+		// Due within the tolerance.
+		Now::set(9_000);
+		run_to_block(3);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+		System::assert_has_event(
+			crate::Event::DueAtTimestamp { when: 10_000, id: name, task: (3, 0) }.into(),
+		);
+		assert_eq!(TimestampAgenda::<Test>::iter().count(), 0);
+		assert_eq!(TimestampLookup::<Test>::get(name), None);
+		assert_eq!(TimestampAgendaRange::<Test>::get(), None);
+
+		Now::set(50_000);
+		run_to_block(4);
+		assert_eq!(logger::log(), vec![(root(), 42u32)]);
+	});
+}
+
+#[test]
+fn periodic_timestamp_scheduling_skips_missed_occurrences() {
+	new_test_ext().execute_with(|| {
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		assert_ok!(Scheduler::schedule_named_at_timestamp(
+			RuntimeOrigin::root(),
+			[1u8; 32],
+			10_000,
+			Some(10_000),
+			127,
+			Box::new(call)
+		));
+
+		Now::set(10_000);
+		run_to_block(2);
+		assert_eq!(logger::log().len(), 1);
+		assert!(!TimestampAgenda::<Test>::contains_key(1));
+		assert_eq!(TimestampAgenda::<Test>::get(2)[0].when, 20_000);
+		assert_eq!(TimestampLookup::<Test>::get([1u8; 32]), Some(20_000));
+
+		// The occurrences at 20_000 and 30_000 were missed, only one of them runs.
+		Now::set(35_000);
+		run_to_block(3);
+		assert_eq!(logger::log().len(), 2);
+		assert_eq!(TimestampAgenda::<Test>::get(4)[0].when, 40_000);
+		assert_eq!(TimestampAgendaRange::<Test>::get(), Some((4, 4)));
+
+		run_to_block(4);
+		assert_eq!(logger::log().len(), 2);
+	});
+}
+
+#[test]
+fn cancel_named_at_timestamp_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let call =
+			RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+		let name = [1u8; 32];
+		assert_ok!(Scheduler::schedule_named_at_timestamp(
+			RuntimeOrigin::root(),
+			name,
+			10_000,
+			None,
+			127,
+			Box::new(call)
+		));
+
+		assert_noop!(
+			Scheduler::cancel_named_at_timestamp(RuntimeOrigin::root(), [2u8; 32]),
+			Error::<Test>::NotFound
+		);
+		assert_ok!(Scheduler::cancel_named_at_timestamp(RuntimeOrigin::root(), name));
+		System::assert_last_event(crate::Event::CanceledAtTimestamp { id: name }.into());
+		assert!(!TimestampAgenda::<Test>::contains_key(1));
+		assert_eq!(TimestampLookup::<Test>::get(name), None);
+
+		Now::set(10_000);
+		run_to_block(2);
+		assert!(logger::log().is_empty());
+	});
+}
+
+#[test]
+fn timestamp_agenda_buckets_are_serviced_in_order() {
+	new_test_ext().execute_with(|| {
+		for (i, when) in [(1u32, 42_000u64), (2, 15_000), (3, 18_000)] {
+			let call =
+				RuntimeCall::Logger(LoggerCall::log { i, weight: Weight::from_parts(10, 0) });
+			assert_ok!(Scheduler::schedule_named_at_timestamp(
+				RuntimeOrigin::root(),
+				[i as u8; 32],
+				when,
+				None,
+				127,
+				Box::new(call)
+			));
+		}
+		// The tasks are kept in the buckets of their timestamp, ordered by timestamp.
+		assert_eq!(TimestampAgenda::<Test>::get(1).len(), 2);
+		assert_eq!(TimestampAgenda::<Test>::get(1)[0].when, 15_000);
+		assert_eq!(TimestampAgenda::<Test>::get(4).len(), 1);
+		assert_eq!(TimestampAgendaRange::<Test>::get(), Some((1, 4)));
+
+		Now::set(20_000);
+		run_to_block(2);
+		assert_eq!(logger::log(), vec![(root(), 2u32), (root(), 3u32)]);
+		assert!(!TimestampAgenda::<Test>::contains_key(1));
+		// The buckets up to the current time are done with.
+		assert_eq!(TimestampAgendaRange::<Test>::get(), Some((3, 4)));
+
+		Now::set(45_000);
+		run_to_block(3);
+		assert_eq!(logger::log(), vec![(root(), 2u32), (root(), 3u32), (root(), 1u32)]);
+		assert_eq!(TimestampAgenda::<Test>::iter().count(), 0);
+		assert_eq!(TimestampLookup::<Test>::iter().count(), 0);
+		assert_eq!(TimestampAgendaRange::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn scheduled_calls_are_nested_calls() {
+	use frame_support::traits::InspectNestedCalls;
+
+	let call = RuntimeCall::Logger(LoggerCall::log { i: 42, weight: Weight::from_parts(10, 0) });
+	assert!(Scheduler::nested_calls(&call).is_empty());
+
+	let schedule = RuntimeCall::Scheduler(crate::Call::schedule {
+		when: 4,
+		maybe_periodic: None,
+		priority: 127,
+		call: Box::new(call.clone()),
+	});
+	assert_eq!(Scheduler::nested_calls(&schedule), vec![&call]);
+
+	let schedule_named_after = RuntimeCall::Scheduler(crate::Call::schedule_named_after {
+		id: [1u8; 32],
+		after: 4,
+		maybe_periodic: None,
+		priority: 127,
+		call: Box::new(schedule),
+	});
+	assert_eq!(Scheduler::any_nested_call(&schedule_named_after, 2, |c| *c == call), Ok(true));
+}
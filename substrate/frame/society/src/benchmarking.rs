@@ -29,6 +29,13 @@ use sp_runtime::traits::Bounded;
 
 use crate::Pallet as Society;
 
+/// Helper to create attestations by (newly generated) members.
+pub trait BenchmarkHelper<Signature, AccountId> {
+	/// Sign `msg` with a key identified by `entropy`, returning the signature and the account
+	/// of the signer.
+	fn create_attestation(entropy: &[u8], msg: &[u8]) -> (Signature, AccountId);
+}
+
 fn mock_balance_deposit<T: Config<I>, I: 'static>() -> BalanceOf<T, I> {
 	T::Currency::minimum_balance().saturating_mul(1_000u32.into())
 }
@@ -508,6 +515,33 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn attested_bid(
+		n: Linear<{ T::RequiredAttestations::get() }, { T::MaxAttestations::get() }>,
+	) -> Result<(), BenchmarkError> {
+		setup_society::<T, I>()?;
+		let caller: T::AccountId = whitelisted_caller();
+		let value: BalanceOf<T, I> = 10u32.into();
+		let payload = (ATTESTATION_CONTEXT, &caller, &value, RoundCount::<T, I>::get()).encode();
+		let mut attestations = BoundedVec::new();
+		for i in 0..n {
+			let (signature, attester) =
+				T::BenchmarkHelper::create_attestation(&i.encode(), &payload[..]);
+			// Bypass `max_members` since `MaxAttestations` may exceed it.
+			let record = MemberRecord { rank: 0, strikes: 0, vouching: None, index: i + 1 };
+			Members::<T, I>::insert(&attester, record);
+			attestations
+				.try_push((attester, signature))
+				.map_err(|_| "too many attestations")?;
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), value, attestations);
+
+		assert!(Society::<T, I>::has_bid(&AttestedBids::<T, I>::get(), &caller));
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Society,
 		sp_io::TestExternalities::from(
@@ -138,6 +138,10 @@
 //! Bids that were not selected stay in the bidder pool until they are selected or
 //! a user chooses to "unbid".
 //!
+//! A user may instead skip the auction by presenting signed attestations from enough existing
+//! members. Up to `MaxAttestedIntake` such attested bids become candidates at the next rotation,
+//! ahead of the regular bids.
+//!
 //! #### Candidate Phase
 //!
 //! Once a bidder becomes a candidate, members vote whether to approve or reject
@@ -219,6 +223,7 @@
 //!
 //! * `bid` - A user can make a bid to join the membership society by reserving a deposit.
 //! * `unbid` - A user can withdraw their bid for entry, the deposit is returned.
+//! * `attested_bid` - A user can bid without a deposit by presenting attestations from members.
 //!
 //! #### For Members
 //!
@@ -279,13 +284,15 @@ use rand_chacha::{
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{
-		AccountIdConversion, CheckedAdd, CheckedSub, Hash, Saturating, StaticLookup,
-		TrailingZeroInput, Zero,
+		AccountIdConversion, CheckedAdd, CheckedSub, Hash, IdentifyAccount, Saturating,
+		StaticLookup, TrailingZeroInput, Verify, Zero,
 	},
 	ArithmeticError::Overflow,
 	Percent, RuntimeDebug,
 };
 
+#[cfg(feature = "runtime-benchmarks")]
+pub use benchmarking::BenchmarkHelper;
 pub use weights::WeightInfo;
 
 pub use pallet::*;
@@ -469,6 +476,9 @@ pub type GroupParamsFor<T, I> = GroupParams<BalanceOf<T, I>>;
 
 pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
+/// The context prefixed to the payload members sign when attesting to a candidate.
+pub const ATTESTATION_CONTEXT: &[u8] = b"society/attest";
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -530,8 +540,31 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxBids: Get<u32>;
 
+		/// The signature type members use to attest to a candidate off-chain.
+		type AttestationSignature: Verify<Signer = Self::AttestationSigner> + Parameter;
+
+		/// The public key corresponding to `AttestationSignature`, which must map onto the
+		/// attesting member's account.
+		type AttestationSigner: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// The minimum number of distinct member attestations needed for an attested bid.
+		#[pallet::constant]
+		type RequiredAttestations: Get<u32>;
+
+		/// The maximum number of attestations which may be submitted with an attested bid.
+		#[pallet::constant]
+		type MaxAttestations: Get<u32>;
+
+		/// The maximum number of attested bids which may become candidates in one rotation.
+		#[pallet::constant]
+		type MaxAttestedIntake: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
+
+		/// Helper to create member attestations in benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AttestationSignature, Self::AccountId>;
 	}
 
 	#[pallet::error]
@@ -600,6 +633,14 @@ pub mod pallet {
 		InsufficientFunds,
 		/// The candidate/defender has no stale votes to remove.
 		NoVotes,
+		/// Fewer attestations than `RequiredAttestations` were provided.
+		TooFewAttestations,
+		/// An attestation was not signed by its claimed member.
+		InvalidAttestation,
+		/// The same member attested more than once.
+		DuplicateAttester,
+		/// The attested intake for the next rotation is already full.
+		AttestedIntakeFull,
 	}
 
 	#[pallet::event]
@@ -642,6 +683,13 @@ pub mod pallet {
 		Deposit { value: BalanceOf<T, I> },
 		/// A \[member\] got elevated to \[rank\].
 		Elevated { member: T::AccountId, rank: Rank },
+		/// A membership bid backed by member attestations just happened. It will become a
+		/// candidate at the next rotation without going through the bid auction.
+		AttestedBid {
+			candidate_id: T::AccountId,
+			offer: BalanceOf<T, I>,
+			attesters: Vec<T::AccountId>,
+		},
 	}
 
 	/// Old name generated by `decl_event`.
@@ -704,6 +752,15 @@ pub mod pallet {
 	pub(super) type Bids<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, BoundedVec<Bid<T::AccountId, BalanceOf<T, I>>, T::MaxBids>, ValueQuery>;
 
+	/// The bids backed by member attestations, which become candidates at the next rotation
+	/// ahead of (and in addition to) the bid auction.
+	#[pallet::storage]
+	pub(super) type AttestedBids<T: Config<I>, I: 'static = ()> = StorageValue<
+		_,
+		BoundedVec<Bid<T::AccountId, BalanceOf<T, I>>, T::MaxAttestedIntake>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	pub type Candidates<T: Config<I>, I: 'static = ()> = StorageMap<
 		_,
@@ -822,6 +879,7 @@ pub mod pallet {
 
 			let mut bids = Bids::<T, I>::get();
 			ensure!(!Self::has_bid(&bids, &who), Error::<T, I>::AlreadyBid);
+			ensure!(!Self::has_bid(&AttestedBids::<T, I>::get(), &who), Error::<T, I>::AlreadyBid);
 			ensure!(!Candidates::<T, I>::contains_key(&who), Error::<T, I>::AlreadyCandidate);
 			ensure!(!Members::<T, I>::contains_key(&who), Error::<T, I>::AlreadyMember);
 			ensure!(!SuspendedMembers::<T, I>::contains_key(&who), Error::<T, I>::Suspended);
@@ -888,6 +946,7 @@ pub mod pallet {
 			// Get bids and check user is not bidding.
 			let mut bids = Bids::<T, I>::get();
 			ensure!(!Self::has_bid(&bids, &who), Error::<T, I>::AlreadyBid);
+			ensure!(!Self::has_bid(&AttestedBids::<T, I>::get(), &who), Error::<T, I>::AlreadyBid);
 
 			// Check user is not already a candidate, member or suspended member.
 			ensure!(!Candidates::<T, I>::contains_key(&who), Error::<T, I>::AlreadyCandidate);
@@ -1363,6 +1422,64 @@ pub mod pallet {
 			// if backend == 0 { return Err(Error::<T, I>::NoVotes.into()); };
 			Ok(Pays::No.into())
 		}
+
+		/// A user outside of the society can make a bid for entry which is backed by off-chain
+		/// attestations from existing members. Such a bid skips the bid auction and becomes a
+		/// candidate at the next rotation, up to `MaxAttestedIntake` per rotation.
+		///
+		/// Each attestation is a signature by a member over `(ATTESTATION_CONTEXT, who, value,
+		/// round)`, where `who` is the bidder and `round` is the current `RoundCount`, so
+		/// attestations expire at the next rotation. No deposit is reserved.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `value`: A one time payment the bid would like to receive when joining the society.
+		/// - `attestations`: At least `RequiredAttestations` pairs of distinct members and their
+		///   signatures.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::attested_bid(attestations.len() as u32))]
+		pub fn attested_bid(
+			origin: OriginFor<T>,
+			value: BalanceOf<T, I>,
+			attestations: BoundedVec<(T::AccountId, T::AttestationSignature), T::MaxAttestations>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut attested = AttestedBids::<T, I>::get();
+			ensure!(!Self::has_bid(&attested, &who), Error::<T, I>::AlreadyBid);
+			ensure!(!Self::has_bid(&Bids::<T, I>::get(), &who), Error::<T, I>::AlreadyBid);
+			ensure!(!Candidates::<T, I>::contains_key(&who), Error::<T, I>::AlreadyCandidate);
+			ensure!(!Members::<T, I>::contains_key(&who), Error::<T, I>::AlreadyMember);
+			ensure!(!SuspendedMembers::<T, I>::contains_key(&who), Error::<T, I>::Suspended);
+			ensure!(Parameters::<T, I>::exists(), Error::<T, I>::NotGroup);
+			ensure!(
+				attestations.len() as u32 >= T::RequiredAttestations::get(),
+				Error::<T, I>::TooFewAttestations
+			);
+
+			let payload = (ATTESTATION_CONTEXT, &who, &value, RoundCount::<T, I>::get()).encode();
+			let mut attesters = Vec::with_capacity(attestations.len());
+			for (attester, signature) in attestations.into_iter() {
+				ensure!(!attesters.contains(&attester), Error::<T, I>::DuplicateAttester);
+				ensure!(Members::<T, I>::contains_key(&attester), Error::<T, I>::NotMember);
+				ensure!(
+					signature.verify(&payload[..], &attester),
+					Error::<T, I>::InvalidAttestation
+				);
+				attesters.push(attester);
+			}
+
+			let bid = Bid { who: who.clone(), kind: BidKind::Deposit(Zero::zero()), value };
+			attested.try_push(bid).map_err(|_| Error::<T, I>::AttestedIntakeFull)?;
+			AttestedBids::<T, I>::put(attested);
+			Self::deposit_event(Event::<T, I>::AttestedBid {
+				candidate_id: who,
+				offer: value,
+				attesters,
+			});
+			Ok(())
+		}
 	}
 }
 
@@ -1550,8 +1667,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	}
 
 	/// Remove a selection of bidding accounts such that the total bids is no greater than `Pot` and
-	/// the number of bids would not surpass `MaxMembers` if all were accepted. At most one bid may
-	/// be zero.
+	/// the number of bids would not surpass `MaxMembers` if all were accepted. At most one auction
+	/// bid may be zero.
+	///
+	/// Attested bids are selected first and do not count towards the group's `max_intake`. Any
+	/// which cannot be afforded remain queued for the next rotation.
 	///
 	/// Candidates are inserted from each bidder.
 	///
@@ -1561,44 +1681,66 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		member_count: u32,
 		pot: BalanceOf<T, I>,
 	) -> u32 {
-		// Get the number of left-most bidders whose bids add up to less than `pot`.
-		let mut bids = Bids::<T, I>::get();
 		let params = match Parameters::<T, I>::get() {
 			Some(params) => params,
 			None => return 0,
 		};
-		let max_selections: u32 = params
-			.max_intake
-			.min(params.max_members.saturating_sub(member_count))
-			.min(bids.len() as u32);
+		let mut capacity = params.max_members.saturating_sub(member_count);
 
 		let mut selections = 0;
 		// A running total of the cost to onboard these bids
 		let mut total_cost: BalanceOf<T, I> = Zero::zero();
 
+		let mut attested = AttestedBids::<T, I>::get();
+		if !attested.is_empty() {
+			attested.retain(|bid| {
+				total_cost.saturating_accrue(bid.value);
+				let accept = selections < capacity && total_cost <= pot;
+				if accept {
+					Self::insert_candidacy(round, bid);
+					selections.saturating_inc();
+				} else {
+					total_cost.saturating_reduce(bid.value);
+				}
+				!accept
+			});
+			AttestedBids::<T, I>::put(&attested);
+			capacity.saturating_reduce(selections);
+		}
+
+		// Get the number of left-most bidders whose bids add up to less than `pot`.
+		let mut bids = Bids::<T, I>::get();
+		let max_selections: u32 = params.max_intake.min(capacity).min(bids.len() as u32);
+		let mut auction_selections = 0;
+
 		bids.retain(|bid| {
 			// We only accept a zero bid as the first selection.
 			total_cost.saturating_accrue(bid.value);
-			let accept = selections < max_selections &&
-				(!bid.value.is_zero() || selections == 0) &&
+			let accept = auction_selections < max_selections &&
+				(!bid.value.is_zero() || auction_selections == 0) &&
 				total_cost <= pot;
 			if accept {
-				let candidacy = Candidacy {
-					round,
-					kind: bid.kind.clone(),
-					bid: bid.value,
-					tally: Default::default(),
-					skeptic_struck: false,
-				};
-				Candidates::<T, I>::insert(&bid.who, candidacy);
-				selections.saturating_inc();
+				Self::insert_candidacy(round, bid);
+				auction_selections.saturating_inc();
 			}
 			!accept
 		});
 
 		// No need to reset Bids if we're not taking anything.
 		Bids::<T, I>::put(&bids);
-		selections
+		selections.saturating_add(auction_selections)
+	}
+
+	/// Turn a bid into a fresh candidacy for the given `round`.
+	fn insert_candidacy(round: RoundIndex, bid: &Bid<T::AccountId, BalanceOf<T, I>>) {
+		let candidacy = Candidacy {
+			round,
+			kind: bid.kind.clone(),
+			bid: bid.value,
+			tally: Default::default(),
+			skeptic_struck: false,
+		};
+		Candidates::<T, I>::insert(&bid.who, candidacy);
 	}
 
 	/// Puts a bid into storage ordered by smallest to largest value.
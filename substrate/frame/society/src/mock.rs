@@ -26,7 +26,10 @@ use frame_support::{
 };
 use frame_support_test::TestRandomness;
 use frame_system::EnsureSignedBy;
-use sp_runtime::{traits::IdentityLookup, BuildStorage};
+use sp_runtime::{
+	traits::{IdentifyAccount, IdentityLookup, Lazy, Verify},
+	BuildStorage,
+};
 
 use RuntimeOrigin as Origin;
 
@@ -54,6 +57,44 @@ ord_parameter_types! {
 	pub const MaxBids: u32 = 10;
 }
 
+/// A dummy attestation which is valid for `signer` over exactly the message it carries.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct TestAttestation(pub u128, pub Vec<u8>);
+
+impl Verify for TestAttestation {
+	type Signer = TestAttester;
+	fn verify<L: Lazy<[u8]>>(&self, mut msg: L, signer: &u128) -> bool {
+		self.0 == *signer && msg.get() == &self.1[..]
+	}
+}
+
+/// The signer of a `TestAttestation`, which is simply the member's account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct TestAttester(pub u128);
+
+impl IdentifyAccount for TestAttester {
+	type AccountId = u128;
+	fn into_account(self) -> u128 {
+		self.0
+	}
+}
+
+/// Create an attestation by `who` over the attested bid of `candidate` in the current round.
+pub fn attest(who: u128, candidate: u128, value: u64) -> (u128, TestAttestation) {
+	let round = RoundCount::<Test>::get();
+	(who, TestAttestation(who, (ATTESTATION_CONTEXT, candidate, value, round).encode()))
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AttestationHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<TestAttestation, u128> for AttestationHelper {
+	fn create_attestation(entropy: &[u8], msg: &[u8]) -> (TestAttestation, u128) {
+		let who = u128::decode(&mut TrailingZeroInput::new(entropy)).expect("infinite input; qed");
+		(TestAttestation(who, msg.to_vec()), who)
+	}
+}
+
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Test {
 	type AccountId = u128;
@@ -82,7 +123,14 @@ impl Config for Test {
 	type ChallengePeriod = ChallengePeriod;
 	type MaxPayouts = MaxPayouts;
 	type MaxBids = MaxBids;
+	type AttestationSignature = TestAttestation;
+	type AttestationSigner = TestAttester;
+	type RequiredAttestations = ConstU32<2>;
+	type MaxAttestations = ConstU32<3>;
+	type MaxAttestedIntake = ConstU32<2>;
 	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = AttestationHelper;
 }
 
 pub struct EnvBuilder {
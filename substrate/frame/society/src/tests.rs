@@ -303,6 +303,72 @@ fn bidding_works() {
 	});
 }
 
+#[test]
+fn attested_bidding_works() {
+	EnvBuilder::new().execute(|| {
+		place_members([20, 30]);
+		// Not enough attestations.
+		let attestations = vec![attest(10, 50, 100)].try_into().unwrap();
+		assert_noop!(
+			Society::attested_bid(Origin::signed(50), 100, attestations),
+			Error::<Test>::TooFewAttestations
+		);
+		// The same member cannot attest twice.
+		let attestations = vec![attest(10, 50, 100), attest(10, 50, 100)].try_into().unwrap();
+		assert_noop!(
+			Society::attested_bid(Origin::signed(50), 100, attestations),
+			Error::<Test>::DuplicateAttester
+		);
+		// Only members can attest.
+		let attestations = vec![attest(10, 50, 100), attest(99, 50, 100)].try_into().unwrap();
+		assert_noop!(
+			Society::attested_bid(Origin::signed(50), 100, attestations),
+			Error::<Test>::NotMember
+		);
+		// Attestations must be over the bid being made.
+		let attestations = vec![attest(10, 50, 100), attest(20, 50, 50)].try_into().unwrap();
+		assert_noop!(
+			Society::attested_bid(Origin::signed(50), 100, attestations),
+			Error::<Test>::InvalidAttestation
+		);
+
+		let attestations = vec![attest(10, 50, 100), attest(20, 50, 100)].try_into().unwrap();
+		assert_ok!(Society::attested_bid(Origin::signed(50), 100, attestations));
+		let attestations = vec![attest(20, 60, 0), attest(30, 60, 0)].try_into().unwrap();
+		assert_ok!(Society::attested_bid(Origin::signed(60), 0, attestations));
+		// The attested intake for this rotation is now full.
+		let attestations = vec![attest(10, 70, 0), attest(30, 70, 0)].try_into().unwrap();
+		assert_noop!(
+			Society::attested_bid(Origin::signed(70), 0, attestations),
+			Error::<Test>::AttestedIntakeFull
+		);
+		// An attested bidder cannot also bid normally.
+		assert_noop!(Society::bid(Origin::signed(50), 100), Error::<Test>::AlreadyBid);
+		assert_ok!(Society::bid(Origin::signed(40), 0));
+		assert_eq!(Balances::reserved_balance(50), 0);
+
+		next_intake();
+		// The attested bids become candidates alongside the auction's pick.
+		assert_eq!(
+			candidacies(),
+			vec![
+				(40, candidacy(1, 0, Deposit(25), 0, 0)),
+				(50, candidacy(1, 100, Deposit(0), 0, 0)),
+				(60, candidacy(1, 0, Deposit(0), 0, 0)),
+			]
+		);
+		assert!(AttestedBids::<Test>::get().is_empty());
+
+		// Attestations from a past round are no longer valid.
+		let attestations = vec![attest(10, 70, 0), attest(30, 70, 0)].try_into().unwrap();
+		next_intake();
+		assert_noop!(
+			Society::attested_bid(Origin::signed(70), 0, attestations),
+			Error::<Test>::InvalidAttestation
+		);
+	});
+}
+
 #[test]
 fn unbidding_works() {
 	EnvBuilder::new().execute(|| {
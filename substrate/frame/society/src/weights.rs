@@ -22,6 +22,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-wiukf8gn-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `bid`, `vouch` and `attested_bid`. Re-run the benchmarks before relying on them.
 
 // Executed Command:
 // ./target/production/substrate-node
@@ -71,6 +74,7 @@ pub trait WeightInfo {
 	fn drop_candidate() -> Weight;
 	fn cleanup_candidacy() -> Weight;
 	fn cleanup_challenge() -> Weight;
+	fn attested_bid(n: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_society` using the Substrate node and recommended hardware.
@@ -86,13 +90,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Society::SuspendedMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Society::Parameters` (r:1 w:0)
 	/// Proof: `Society::Parameters` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Society::AttestedBids` (r:1 w:0)
+	/// Proof: `Society::AttestedBids` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	fn bid() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `444`
 		//  Estimated: `3909`
 		// Minimum execution time: 37_812_000 picoseconds.
 		Weight::from_parts(38_375_000, 3909)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Society::Bids` (r:1 w:1)
@@ -114,13 +120,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Society::Members` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Society::SuspendedMembers` (r:1 w:0)
 	/// Proof: `Society::SuspendedMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Society::AttestedBids` (r:1 w:0)
+	/// Proof: `Society::AttestedBids` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	fn vouch() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `481`
 		//  Estimated: `6421`
 		// Minimum execution time: 28_051_000 picoseconds.
 		Weight::from_parts(29_088_000, 6421)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Society::Bids` (r:1 w:1)
@@ -440,6 +448,34 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Society::AttestedBids` (r:1 w:1)
+	/// Proof: `Society::AttestedBids` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Society::Bids` (r:1 w:0)
+	/// Proof: `Society::Bids` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Society::Candidates` (r:1 w:0)
+	/// Proof: `Society::Candidates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Society::Members` (r:3 w:0)
+	/// Proof: `Society::Members` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Society::SuspendedMembers` (r:1 w:0)
+	/// Proof: `Society::SuspendedMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Society::Parameters` (r:1 w:0)
+	/// Proof: `Society::Parameters` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Society::RoundCount` (r:1 w:0)
+	/// Proof: `Society::RoundCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `2..=3`.
+	fn attested_bid(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `450`
+		//  Estimated: `3915`
+		// Minimum execution time: 105_870_000 picoseconds.
+		Weight::from_parts(22_316_000, 3915)
+			// Standard Error: 7_408
+			.saturating_add(Weight::from_parts(41_392_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2526).saturating_mul(n.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -454,13 +490,15 @@ impl WeightInfo for () {
 	/// Proof: `Society::SuspendedMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Society::Parameters` (r:1 w:0)
 	/// Proof: `Society::Parameters` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Society::AttestedBids` (r:1 w:0)
+	/// Proof: `Society::AttestedBids` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	fn bid() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `444`
 		//  Estimated: `3909`
 		// Minimum execution time: 37_812_000 picoseconds.
 		Weight::from_parts(38_375_000, 3909)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Society::Bids` (r:1 w:1)
@@ -482,13 +520,15 @@ impl WeightInfo for () {
 	/// Proof: `Society::Members` (`max_values`: None, `max_size`: None, mode: `Measured`)
 	/// Storage: `Society::SuspendedMembers` (r:1 w:0)
 	/// Proof: `Society::SuspendedMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Society::AttestedBids` (r:1 w:0)
+	/// Proof: `Society::AttestedBids` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	fn vouch() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `481`
 		//  Estimated: `6421`
 		// Minimum execution time: 28_051_000 picoseconds.
 		Weight::from_parts(29_088_000, 6421)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Society::Bids` (r:1 w:1)
@@ -808,4 +848,32 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Society::AttestedBids` (r:1 w:1)
+	/// Proof: `Society::AttestedBids` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Society::Bids` (r:1 w:0)
+	/// Proof: `Society::Bids` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Society::Candidates` (r:1 w:0)
+	/// Proof: `Society::Candidates` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Society::Members` (r:3 w:0)
+	/// Proof: `Society::Members` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Society::SuspendedMembers` (r:1 w:0)
+	/// Proof: `Society::SuspendedMembers` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Society::Parameters` (r:1 w:0)
+	/// Proof: `Society::Parameters` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Society::RoundCount` (r:1 w:0)
+	/// Proof: `Society::RoundCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `n` is `2..=3`.
+	fn attested_bid(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `450`
+		//  Estimated: `3915`
+		// Minimum execution time: 105_870_000 picoseconds.
+		Weight::from_parts(22_316_000, 3915)
+			// Standard Error: 7_408
+			.saturating_add(Weight::from_parts(41_392_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2526).saturating_mul(n.into()))
+	}
 }
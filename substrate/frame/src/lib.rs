@@ -355,7 +355,7 @@ pub mod runtime {
 		pub use frame_support::{ord_parameter_types, parameter_types};
 
 		/// For building genesis config.
-		pub use frame_support::genesis_builder_helper::{build_state, get_preset};
+		pub use frame_support::genesis_builder_helper::{build_state, get_preset, patchable_paths};
 
 		/// Const types that can easily be used in conjuncture with `Get`.
 		pub use frame_support::traits::{
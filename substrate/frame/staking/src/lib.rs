@@ -301,6 +301,7 @@ pub mod inflation;
 pub mod ledger;
 pub mod migrations;
 pub mod slashing;
+pub mod view_functions;
 pub mod weights;
 
 mod pallet;
@@ -1011,6 +1012,19 @@ impl<T: Config> Convert<T::AccountId, Option<Exposure<T::AccountId, BalanceOf<T>
 	}
 }
 
+/// A converter from a session index to the era it belongs to, if that era is still bonded.
+pub struct EraOfSession<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> Convert<SessionIndex, Option<EraIndex>> for EraOfSession<T> {
+	fn convert(session_index: SessionIndex) -> Option<EraIndex> {
+		BondedEras::<T>::get()
+			.iter()
+			.rev()
+			.find(|&(_, start)| *start <= session_index)
+			.map(|(era, _)| *era)
+	}
+}
+
 /// Filter historical offences out and only allow those from the bonding period.
 pub struct FilterHistoricalOffences<T, R> {
 	_inner: core::marker::PhantomData<(T, R)>,
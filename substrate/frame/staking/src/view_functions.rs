@@ -0,0 +1,71 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! View functions of the staking pallet.
+
+use super::*;
+use frame_support::view_functions::{
+	DispatchViewFunction, ViewFunction, ViewFunctionDispatchError, ViewFunctionId,
+};
+
+/// The staking ledger of a stash account, if the account is bonded.
+#[derive(Encode, Decode)]
+pub struct LedgerOfStash<T: Config>(pub T::AccountId);
+
+impl<T: Config> ViewFunction for LedgerOfStash<T> {
+	type ReturnType = Option<StakingLedger<T>>;
+
+	fn id() -> ViewFunctionId {
+		ViewFunctionId::new::<Pallet<T>>("ledger_of_stash")
+	}
+
+	fn invoke(self) -> Self::ReturnType {
+		Pallet::<T>::ledger(StakingAccount::Stash(self.0)).ok()
+	}
+}
+
+/// The reward destination of a stash account, if the account is bonded.
+#[derive(Encode, Decode)]
+pub struct PayeeOfStash<T: Config>(pub T::AccountId);
+
+impl<T: Config> ViewFunction for PayeeOfStash<T> {
+	type ReturnType = Option<RewardDestination<T::AccountId>>;
+
+	fn id() -> ViewFunctionId {
+		ViewFunctionId::new::<Pallet<T>>("payee_of_stash")
+	}
+
+	fn invoke(self) -> Self::ReturnType {
+		Payee::<T>::get(self.0)
+	}
+}
+
+impl<T: Config> DispatchViewFunction for Pallet<T> {
+	fn dispatch_view_function<O: codec::Output>(
+		id: &ViewFunctionId,
+		input: &mut &[u8],
+		output: &mut O,
+	) -> Result<(), ViewFunctionDispatchError> {
+		if *id == LedgerOfStash::<T>::id() {
+			LedgerOfStash::<T>::execute(input, output)
+		} else if *id == PayeeOfStash::<T>::id() {
+			PayeeOfStash::<T>::execute(input, output)
+		} else {
+			Err(ViewFunctionDispatchError::NotFound(*id))
+		}
+	}
+}
@@ -21,10 +21,14 @@ use super::*;
 use crate::Pallet;
 use alloc::{boxed::Box, vec};
 use frame_benchmarking::v2::*;
-use frame_support::dispatch::{DispatchInfo, GetDispatchInfo};
+use frame_support::{
+	dispatch::{DispatchInfo, GetDispatchInfo},
+	traits::Get,
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 use sp_runtime::traits::{
-	AsSystemOriginSigner, AsTransactionAuthorizedOrigin, DispatchTransaction, Dispatchable,
+	AsSystemOriginSigner, AsTransactionAuthorizedOrigin, DispatchTransaction, Dispatchable, Zero,
 };
 
 fn assert_last_event<T: Config>(generic_event: crate::Event<T>) {
@@ -32,6 +36,12 @@ fn assert_last_event<T: Config>(generic_event: crate::Event<T>) {
 	frame_system::Pallet::<T>::assert_last_event(re.into());
 }
 
+/// Fill the sudo log, so that recording a call drops the oldest record.
+fn fill_log<T: Config>() {
+	let record = SudoRecord { call_hash: Default::default(), at: Zero::zero(), success: true };
+	SudoLog::<T>::put(BoundedVec::truncate_from(vec![record; T::MaxLogLength::get() as usize]));
+}
+
 #[benchmarks(where
 	T: Send + Sync,
 	<T as Config>::RuntimeCall: From<frame_system::Call<T>>,
@@ -63,6 +73,7 @@ mod benchmarks {
 		Key::<T>::put(&caller);
 
 		let call = frame_system::Call::remark { remark: vec![] }.into();
+		fill_log::<T>();
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller), Box::new(call));
@@ -79,6 +90,7 @@ mod benchmarks {
 
 		let who: T::AccountId = account("as", 0, 0);
 		let who_lookup = T::Lookup::unlookup(who);
+		fill_log::<T>();
 
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller), who_lookup, Box::new(call));
@@ -97,6 +109,32 @@ mod benchmarks {
 		assert_last_event::<T>(Event::KeyRemoved {});
 	}
 
+	#[benchmark]
+	fn set_co_signer() {
+		let caller: T::AccountId = whitelisted_caller();
+		Key::<T>::put(&caller);
+
+		let co_signer: T::AccountId = account("co_signer", 0, 0);
+		let co_signer_lookup = T::Lookup::unlookup(co_signer.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), Some(co_signer_lookup));
+
+		assert_last_event::<T>(Event::CoSignerChanged { new: Some(co_signer) });
+	}
+
+	#[benchmark]
+	fn co_sign() {
+		let caller: T::AccountId = whitelisted_caller();
+		CoSigner::<T>::put(&caller);
+		let call_hash = T::Hash::default();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), call_hash);
+
+		assert_last_event::<T>(Event::CallCoSigned { call_hash });
+	}
+
 	#[benchmark]
 	fn check_only_sudo_account() {
 		let caller: T::AccountId = whitelisted_caller();
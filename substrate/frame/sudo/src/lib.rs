@@ -94,6 +94,23 @@
 //! Learn more about the `Root` origin in the [`RawOrigin`](frame_system::RawOrigin) type
 //! documentation.
 //!
+//! ### Co-signer and action log
+//!
+//! A second key, the co-signer, may be set with [`set_co_signer`](Pallet::set_co_signer). While it
+//! is set, calls matched by [`Config::SensitiveCalls`] are only dispatched by the sudo key once the
+//! co-signer has approved their hash with [`co_sign`](Pallet::co_sign). The calls managing the
+//! co-signer, and the calls of `frame_system` that replace the runtime code, authorize a runtime
+//! upgrade or write the storage directly, are always sensitive. The calls nested in the wrapper
+//! calls of [`Config::NestedCalls`], e.g. batches, are checked as well. Only the co-signer can then
+//! change or remove itself, and [`sudo_as`](Pallet::sudo_as) can't impersonate it.
+//!
+//! The co-signer only protects calls which need the `Root` origin. [`sudo_as`](Pallet::sudo_as)
+//! still dispatches any call from any other account without its approval, so e.g. the funds of any
+//! account can be moved by the sudo key alone, whatever [`Config::SensitiveCalls`] contains.
+//!
+//! The hashes of the last [`Config::MaxLogLength`] calls dispatched through this pallet are kept
+//! in [`SudoLog`], together with the block in which they were dispatched and their outcome.
+//!
 //! ### Examples
 //!
 //! 1. You can make a privileged runtime call using `sudo` with an account that matches the sudo
@@ -127,7 +144,14 @@ use alloc::boxed::Box;
 
 use sp_runtime::{traits::StaticLookup, DispatchResult};
 
-use frame_support::{dispatch::GetDispatchInfo, traits::UnfilteredDispatchable};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	dispatch::GetDispatchInfo,
+	traits::{Contains, InspectNestedCalls, IsSubType, UnfilteredDispatchable},
+	RuntimeDebug,
+};
+use scale_info::TypeInfo;
+use sp_runtime::traits::Hash;
 
 mod extension;
 #[cfg(test)]
@@ -145,10 +169,26 @@ pub use pallet::*;
 
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
+/// The number of levels of wrapper calls inspected for sensitive calls.
+///
+/// Calls nested deeper than this are treated as sensitive, since they can't be inspected.
+pub const MAX_NESTED_CALL_DEPTH: u32 = 8;
+
+/// A call dispatched through the sudo pallet, as kept in the [`SudoLog`].
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct SudoRecord<Hash, BlockNumber> {
+	/// The hash of the dispatched call.
+	pub call_hash: Hash,
+	/// The block in which the call was dispatched.
+	pub at: BlockNumber,
+	/// Whether the call succeeded.
+	pub success: bool,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::{DispatchResult, *};
-	use frame_support::pallet_prelude::*;
+	use frame_support::{pallet_prelude::*, traits::Nothing};
 	use frame_system::{pallet_prelude::*, RawOrigin};
 
 	/// Default preludes for [`Config`].
@@ -169,6 +209,9 @@ pub mod pallet {
 			type RuntimeEvent = ();
 			#[inject_runtime_type]
 			type RuntimeCall = ();
+			type SensitiveCalls = Nothing;
+			type NestedCalls = ();
+			type MaxLogLength = ConstU32<0>;
 		}
 	}
 	#[pallet::config(with_default)]
@@ -181,10 +224,25 @@ pub mod pallet {
 		#[pallet::no_default_bounds]
 		type RuntimeCall: Parameter
 			+ UnfilteredDispatchable<RuntimeOrigin = Self::RuntimeOrigin>
-			+ GetDispatchInfo;
+			+ GetDispatchInfo
+			+ IsSubType<Call<Self>>
+			+ IsSubType<frame_system::Call<Self>>;
 
 		/// Type representing the weight of this pallet
 		type WeightInfo: WeightInfo;
+
+		/// The calls which require the approval of the co-signer, if one is set.
+		#[pallet::no_default_bounds]
+		type SensitiveCalls: Contains<<Self as Config>::RuntimeCall>;
+
+		/// The wrapper calls, e.g. batches, whose nested calls are checked for sensitive calls
+		/// as well.
+		#[pallet::no_default_bounds]
+		type NestedCalls: InspectNestedCalls<<Self as Config>::RuntimeCall>;
+
+		/// The maximum number of dispatched calls kept in the [`SudoLog`].
+		#[pallet::constant]
+		type MaxLogLength: Get<u32>;
 	}
 
 	#[pallet::pallet]
@@ -206,8 +264,10 @@ pub mod pallet {
 			call: Box<<T as Config>::RuntimeCall>,
 		) -> DispatchResultWithPostInfo {
 			Self::ensure_sudo(origin)?;
+			let call_hash = Self::ensure_co_signed(&call)?;
 
 			let res = call.dispatch_bypass_filter(RawOrigin::Root.into());
+			Self::log(call_hash, res.is_ok());
 			Self::deposit_event(Event::Sudid { sudo_result: res.map(|_| ()).map_err(|e| e.error) });
 
 			// Sudo user does not pay a fee.
@@ -228,8 +288,10 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			Self::ensure_sudo(origin)?;
 			let _ = weight; // We don't check the weight witness since it is a root call.
+			let call_hash = Self::ensure_co_signed(&call)?;
 
 			let res = call.dispatch_bypass_filter(RawOrigin::Root.into());
+			Self::log(call_hash, res.is_ok());
 			Self::deposit_event(Event::Sudid { sudo_result: res.map(|_| ()).map_err(|e| e.error) });
 
 			// Sudo user does not pay a fee.
@@ -274,7 +336,13 @@ pub mod pallet {
 			Self::ensure_sudo(origin)?;
 
 			let who = T::Lookup::lookup(who)?;
+			ensure!(
+				CoSigner::<T>::get().as_ref() != Some(&who),
+				Error::<T>::CannotImpersonateCoSigner
+			);
+			let call_hash = Self::ensure_co_signed(&call)?;
 			let res = call.dispatch_bypass_filter(RawOrigin::Signed(who).into());
+			Self::log(call_hash, res.is_ok());
 			Self::deposit_event(Event::SudoAsDone {
 				sudo_result: res.map(|_| ()).map_err(|e| e.error),
 			});
@@ -297,6 +365,50 @@ pub mod pallet {
 			// Sudo user does not pay a fee.
 			Ok(Pays::No.into())
 		}
+
+		/// Sets or removes the co-signer, which must approve sensitive calls before the sudo key
+		/// can dispatch them.
+		///
+		/// The dispatch origin for this call must be the sudo key if no co-signer is set, and the
+		/// co-signer itself otherwise.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_co_signer())]
+		pub fn set_co_signer(
+			origin: OriginFor<T>,
+			new: Option<AccountIdLookupOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			match CoSigner::<T>::get() {
+				Some(co_signer) => {
+					ensure!(ensure_signed(origin)? == co_signer, Error::<T>::RequireCoSigner)
+				},
+				None => Self::ensure_sudo(origin)?,
+			}
+
+			let new = new.map(T::Lookup::lookup).transpose()?;
+			CoSigned::<T>::kill();
+			CoSigner::<T>::set(new.clone());
+			Self::deposit_event(Event::CoSignerChanged { new });
+
+			// Sudo user does not pay a fee.
+			Ok(Pays::No.into())
+		}
+
+		/// Approves the sensitive call with the given hash, allowing the sudo key to dispatch it
+		/// once. Replaces any previous approval.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the co-signer.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::co_sign())]
+		pub fn co_sign(origin: OriginFor<T>, call_hash: T::Hash) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(CoSigner::<T>::get() == Some(who), Error::<T>::RequireCoSigner);
+
+			CoSigned::<T>::put(call_hash);
+			Self::deposit_event(Event::CallCoSigned { call_hash });
+
+			// Sudo user does not pay a fee.
+			Ok(Pays::No.into())
+		}
 	}
 
 	#[pallet::event]
@@ -321,6 +433,16 @@ pub mod pallet {
 			/// The result of the call made by the sudo user.
 			sudo_result: DispatchResult,
 		},
+		/// The co-signer has been updated.
+		CoSignerChanged {
+			/// The new co-signer (if one was set).
+			new: Option<T::AccountId>,
+		},
+		/// The co-signer approved a sensitive call.
+		CallCoSigned {
+			/// The hash of the approved call.
+			call_hash: T::Hash,
+		},
 	}
 
 	#[pallet::error]
@@ -328,12 +450,34 @@ pub mod pallet {
 	pub enum Error<T> {
 		/// Sender must be the Sudo account.
 		RequireSudo,
+		/// Sender must be the co-signer.
+		RequireCoSigner,
+		/// The call is sensitive and has not been approved by the co-signer.
+		RequireCoSignature,
+		/// The co-signer can't be the origin of a `sudo_as` call.
+		CannotImpersonateCoSigner,
 	}
 
 	/// The `AccountId` of the sudo key.
 	#[pallet::storage]
 	pub(super) type Key<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
+	/// The `AccountId` of the co-signer, if one is set.
+	#[pallet::storage]
+	pub type CoSigner<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+	/// The hash of the sensitive call last approved by the co-signer and not yet dispatched.
+	#[pallet::storage]
+	pub type CoSigned<T: Config> = StorageValue<_, T::Hash, OptionQuery>;
+
+	/// The most recent calls dispatched through this pallet, oldest first.
+	#[pallet::storage]
+	pub type SudoLog<T: Config> = StorageValue<
+		_,
+		BoundedVec<SudoRecord<T::Hash, BlockNumberFor<T>>, T::MaxLogLength>,
+		ValueQuery,
+	>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -363,5 +507,63 @@ pub mod pallet {
 				Ok(())
 			}
 		}
+
+		/// Ensure that a call has been approved by the co-signer if it needs to be, consuming the
+		/// approval. Returns the hash of the call.
+		fn ensure_co_signed(call: &<T as Config>::RuntimeCall) -> Result<T::Hash, DispatchError> {
+			let call_hash = T::Hashing::hash_of(call);
+			if CoSigner::<T>::exists() && Self::is_sensitive(call) {
+				ensure!(CoSigned::<T>::get() == Some(call_hash), Error::<T>::RequireCoSignature);
+				CoSigned::<T>::kill();
+			}
+			Ok(call_hash)
+		}
+
+		/// Whether `call` or any of the calls nested in it needs the approval of the co-signer.
+		///
+		/// Calls nested deeper than [`MAX_NESTED_CALL_DEPTH`] can't be inspected and make `call`
+		/// sensitive.
+		fn is_sensitive(call: &<T as Config>::RuntimeCall) -> bool {
+			T::NestedCalls::any_nested_call(call, MAX_NESTED_CALL_DEPTH, |call| {
+				T::SensitiveCalls::contains(call) || Self::is_always_sensitive(call)
+			})
+			.unwrap_or(true)
+		}
+
+		/// Whether `call` manages the co-signer, replaces the runtime code, authorizes a runtime
+		/// upgrade or writes the storage directly.
+		fn is_always_sensitive(call: &<T as Config>::RuntimeCall) -> bool {
+			matches!(
+				<_ as IsSubType<Call<T>>>::is_sub_type(call),
+				Some(Call::set_co_signer { .. } | Call::co_sign { .. })
+			) || matches!(
+				<_ as IsSubType<frame_system::Call<T>>>::is_sub_type(call),
+				Some(
+					frame_system::Call::set_code { .. } |
+						frame_system::Call::set_code_without_checks { .. } |
+						frame_system::Call::authorize_upgrade { .. } |
+						frame_system::Call::authorize_upgrade_without_checks { .. } |
+						frame_system::Call::apply_authorized_upgrade { .. } |
+						frame_system::Call::set_storage { .. } |
+						frame_system::Call::kill_storage { .. } |
+						frame_system::Call::kill_prefix { .. }
+				)
+			)
+		}
+
+		/// Record a dispatched call in the [`SudoLog`], dropping the oldest record if it is full.
+		fn log(call_hash: T::Hash, success: bool) {
+			if T::MaxLogLength::get() == 0 {
+				return
+			}
+			let record =
+				SudoRecord { call_hash, at: frame_system::Pallet::<T>::block_number(), success };
+			SudoLog::<T>::mutate(|log| {
+				if log.is_full() {
+					log.remove(0);
+				}
+				let _ = log.try_push(record);
+			});
+		}
 	}
 }
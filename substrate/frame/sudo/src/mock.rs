@@ -94,6 +94,7 @@ frame_support::construct_runtime!(
 		System: frame_system,
 		Sudo: sudo,
 		Logger: logger,
+		Utility: pallet_utility,
 	}
 );
 
@@ -115,16 +116,39 @@ impl logger::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 }
 
+impl pallet_utility::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type PalletsOrigin = OriginCaller;
+	type WeightInfo = ();
+}
+
+// Logger calls with a negative value need to be approved by the co-signer.
+pub struct NegativeLogs;
+impl Contains<RuntimeCall> for NegativeLogs {
+	fn contains(call: &RuntimeCall) -> bool {
+		match call {
+			RuntimeCall::Logger(LoggerCall::privileged_i32_log { i, .. }) |
+			RuntimeCall::Logger(LoggerCall::non_privileged_log { i, .. }) => *i < 0,
+			_ => false,
+		}
+	}
+}
+
 // Implement the sudo module's `Config` on the Test runtime.
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type WeightInfo = ();
+	type SensitiveCalls = NegativeLogs;
+	type NestedCalls = (Utility,);
+	type MaxLogLength = frame_support::traits::ConstU32<2>;
 }
 
 // New types for dispatchable functions.
 pub type SudoCall = sudo::Call<Test>;
 pub type LoggerCall = logger::Call<Test>;
+pub type UtilityCall = pallet_utility::Call<Test>;
 
 // Build test environment by setting the root `key` for the Genesis.
 pub fn new_test_ext(root_key: u64) -> sp_io::TestExternalities {
@@ -20,9 +20,10 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok, weights::Weight};
 use mock::{
-	new_test_ext, Logger, LoggerCall, RuntimeCall, RuntimeEvent as TestEvent, RuntimeOrigin, Sudo,
-	SudoCall, System, Test,
+	new_test_ext, Logger, LoggerCall, OriginCaller, RuntimeCall, RuntimeEvent as TestEvent,
+	RuntimeOrigin, Sudo, SudoCall, System, Test, UtilityCall,
 };
+use sp_runtime::{traits::BlakeTwo256, DispatchError};
 
 #[test]
 fn test_setup_works() {
@@ -225,3 +226,203 @@ fn sudo_as_emits_events_correctly() {
 		System::assert_has_event(TestEvent::Sudo(Event::SudoAsDone { sudo_result: Ok(()) }));
 	});
 }
+
+#[test]
+fn sensitive_calls_require_co_signature() {
+	new_test_ext(1).execute_with(|| {
+		let call = |i| {
+			Box::new(RuntimeCall::Logger(LoggerCall::privileged_i32_log {
+				i,
+				weight: Weight::from_parts(1, 0),
+			}))
+		};
+		// Without a co-signer, sensitive calls are dispatched as usual.
+		assert_ok!(Sudo::sudo(RuntimeOrigin::signed(1), call(-1)));
+
+		assert_noop!(
+			Sudo::set_co_signer(RuntimeOrigin::signed(2), Some(2)),
+			Error::<Test>::RequireSudo
+		);
+		assert_ok!(Sudo::set_co_signer(RuntimeOrigin::signed(1), Some(2)));
+		System::assert_last_event(TestEvent::Sudo(Event::CoSignerChanged { new: Some(2) }));
+		// Only the co-signer may now change itself.
+		assert_noop!(
+			Sudo::set_co_signer(RuntimeOrigin::signed(1), None),
+			Error::<Test>::RequireCoSigner
+		);
+		assert_noop!(Sudo::set_co_signer(RuntimeOrigin::root(), None), DispatchError::BadOrigin);
+
+		assert_noop!(
+			Sudo::sudo(RuntimeOrigin::signed(1), call(-2)),
+			Error::<Test>::RequireCoSignature
+		);
+		assert_ok!(Sudo::sudo(RuntimeOrigin::signed(1), call(2)));
+
+		assert_noop!(
+			Sudo::co_sign(RuntimeOrigin::signed(1), BlakeTwo256::hash_of(&call(-2))),
+			Error::<Test>::RequireCoSigner
+		);
+		assert_ok!(Sudo::co_sign(RuntimeOrigin::signed(2), BlakeTwo256::hash_of(&call(-2))));
+		assert_noop!(
+			Sudo::sudo(RuntimeOrigin::signed(1), call(-3)),
+			Error::<Test>::RequireCoSignature
+		);
+		assert_ok!(Sudo::sudo(RuntimeOrigin::signed(1), call(-2)));
+		assert_eq!(Logger::i32_log(), vec![-1, 2, -2]);
+		// The approval is consumed.
+		assert_noop!(
+			Sudo::sudo(RuntimeOrigin::signed(1), call(-2)),
+			Error::<Test>::RequireCoSignature
+		);
+
+		assert_ok!(Sudo::set_co_signer(RuntimeOrigin::signed(2), None));
+		assert_ok!(Sudo::sudo(RuntimeOrigin::signed(1), call(-3)));
+	});
+}
+
+#[test]
+fn nested_sensitive_calls_require_co_signature() {
+	new_test_ext(1).execute_with(|| {
+		let log = |i| {
+			RuntimeCall::Logger(LoggerCall::privileged_i32_log {
+				i,
+				weight: Weight::from_parts(1, 0),
+			})
+		};
+		let batch = |calls| Box::new(RuntimeCall::Utility(UtilityCall::batch_all { calls }));
+		assert_ok!(Sudo::set_co_signer(RuntimeOrigin::signed(1), Some(2)));
+
+		assert_noop!(
+			Sudo::sudo(RuntimeOrigin::signed(1), batch(vec![log(1), log(-1)])),
+			Error::<Test>::RequireCoSignature
+		);
+		assert_noop!(
+			Sudo::sudo_as(RuntimeOrigin::signed(1), 3, batch(vec![log(-1)])),
+			Error::<Test>::RequireCoSignature
+		);
+		assert_ok!(Sudo::sudo(RuntimeOrigin::signed(1), batch(vec![log(1), log(2)])));
+
+		assert_ok!(Sudo::co_sign(
+			RuntimeOrigin::signed(2),
+			BlakeTwo256::hash_of(&batch(vec![log(1), log(-1)]))
+		));
+		assert_ok!(Sudo::sudo(RuntimeOrigin::signed(1), batch(vec![log(1), log(-1)])));
+		assert_eq!(Logger::i32_log(), vec![1, 2, 1, -1]);
+
+		// Calls nested too deep to be inspected are sensitive.
+		let mut nested = batch(vec![log(3)]);
+		for _ in 0..MAX_NESTED_CALL_DEPTH {
+			nested = batch(vec![*nested]);
+		}
+		assert_noop!(
+			Sudo::sudo(RuntimeOrigin::signed(1), nested),
+			Error::<Test>::RequireCoSignature
+		);
+	});
+}
+
+#[test]
+fn co_signer_and_upgrade_authorization_are_always_sensitive() {
+	new_test_ext(1).execute_with(|| {
+		assert_ok!(Sudo::set_co_signer(RuntimeOrigin::signed(1), Some(2)));
+
+		let authorize = Box::new(RuntimeCall::System(frame_system::Call::authorize_upgrade {
+			code_hash: Default::default(),
+		}));
+		assert_noop!(
+			Sudo::sudo(RuntimeOrigin::signed(1), authorize.clone()),
+			Error::<Test>::RequireCoSignature
+		);
+		assert_ok!(Sudo::co_sign(RuntimeOrigin::signed(2), BlakeTwo256::hash_of(&authorize)));
+		assert_ok!(Sudo::sudo(RuntimeOrigin::signed(1), authorize));
+
+		// The sudo key can't act as the co-signer, neither directly nor through a wrapper call.
+		let remove_co_signer = Box::new(RuntimeCall::Sudo(SudoCall::set_co_signer { new: None }));
+		assert_noop!(
+			Sudo::sudo_as(RuntimeOrigin::signed(1), 2, remove_co_signer.clone()),
+			Error::<Test>::CannotImpersonateCoSigner
+		);
+		let approve =
+			Box::new(RuntimeCall::Sudo(SudoCall::co_sign { call_hash: Default::default() }));
+		assert_noop!(
+			Sudo::sudo_as(RuntimeOrigin::signed(1), 2, approve),
+			Error::<Test>::CannotImpersonateCoSigner
+		);
+		let dispatch_as = Box::new(RuntimeCall::Utility(UtilityCall::dispatch_as {
+			as_origin: Box::new(OriginCaller::system(frame_system::RawOrigin::Signed(2))),
+			call: remove_co_signer,
+		}));
+		assert_noop!(
+			Sudo::sudo(RuntimeOrigin::signed(1), dispatch_as),
+			Error::<Test>::RequireCoSignature
+		);
+		assert_eq!(CoSigner::<Test>::get(), Some(2));
+	});
+}
+
+#[test]
+fn code_and_storage_calls_are_always_sensitive() {
+	new_test_ext(1).execute_with(|| {
+		assert_ok!(Sudo::set_co_signer(RuntimeOrigin::signed(1), Some(2)));
+
+		let set_storage = Box::new(RuntimeCall::System(frame_system::Call::set_storage {
+			items: vec![(b"key".to_vec(), b"value".to_vec())],
+		}));
+		let kill_storage = Box::new(RuntimeCall::System(frame_system::Call::kill_storage {
+			keys: vec![b"key".to_vec()],
+		}));
+		let kill_prefix = Box::new(RuntimeCall::System(frame_system::Call::kill_prefix {
+			prefix: b"k".to_vec(),
+			subkeys: 1,
+		}));
+		let set_code = Box::new(RuntimeCall::System(frame_system::Call::set_code_without_checks {
+			code: vec![],
+		}));
+		for call in [&set_storage, &kill_storage, &kill_prefix, &set_code] {
+			assert_noop!(
+				Sudo::sudo(RuntimeOrigin::signed(1), call.clone()),
+				Error::<Test>::RequireCoSignature
+			);
+			assert_noop!(
+				Sudo::sudo_unchecked_weight(
+					RuntimeOrigin::signed(1),
+					call.clone(),
+					Weight::from_parts(1, 0)
+				),
+				Error::<Test>::RequireCoSignature
+			);
+		}
+
+		assert_ok!(Sudo::co_sign(RuntimeOrigin::signed(2), BlakeTwo256::hash_of(&set_storage)));
+		assert_ok!(Sudo::sudo(RuntimeOrigin::signed(1), set_storage));
+		assert_eq!(sp_io::storage::get(b"key").as_deref(), Some(&b"value"[..]));
+	});
+}
+
+#[test]
+fn dispatched_calls_are_logged() {
+	new_test_ext(1).execute_with(|| {
+		let first = Box::new(RuntimeCall::Logger(LoggerCall::privileged_i32_log {
+			i: 1,
+			weight: Weight::from_parts(1, 0),
+		}));
+		let second = Box::new(RuntimeCall::Logger(LoggerCall::non_privileged_log {
+			i: 2,
+			weight: Weight::from_parts(1, 0),
+		}));
+		assert_ok!(Sudo::sudo(RuntimeOrigin::signed(1), first.clone()));
+		// A failed call is logged too.
+		assert_ok!(Sudo::sudo(RuntimeOrigin::signed(1), second.clone()));
+		System::set_block_number(2);
+		assert_ok!(Sudo::sudo_as(RuntimeOrigin::signed(1), 2, second.clone()));
+
+		// The oldest record was dropped.
+		assert_eq!(
+			SudoLog::<Test>::get().into_inner(),
+			vec![
+				SudoRecord { call_hash: BlakeTwo256::hash_of(&second), at: 1, success: false },
+				SudoRecord { call_hash: BlakeTwo256::hash_of(&second), at: 2, success: true },
+			]
+		);
+	});
+}
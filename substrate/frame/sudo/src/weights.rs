@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-wiukf8gn-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `sudo`, `sudo_as`, `set_co_signer` and `co_sign`. Re-run the benchmarks before relying
+//! on them.
 
 // Executed Command:
 // ./target/production/substrate-node
@@ -55,6 +59,8 @@ pub trait WeightInfo {
 	fn sudo() -> Weight;
 	fn sudo_as() -> Weight;
 	fn remove_key() -> Weight;
+	fn set_co_signer() -> Weight;
+	fn co_sign() -> Weight;
 	fn check_only_sudo_account() -> Weight;
 }
 
@@ -74,23 +80,29 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `Sudo::Key` (r:1 w:0)
 	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::SudoLog` (r:1 w:1)
+	/// Proof: `Sudo::SudoLog` (`max_values`: Some(1), `max_size`: Some(3701), added: 4196, mode: `MaxEncodedLen`)
 	fn sudo() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `198`
-		//  Estimated: `1517`
+		//  Estimated: `5186`
 		// Minimum execution time: 11_218_000 picoseconds.
-		Weight::from_parts(11_501_000, 1517)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		Weight::from_parts(11_501_000, 5186)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Sudo::Key` (r:1 w:0)
 	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::SudoLog` (r:1 w:1)
+	/// Proof: `Sudo::SudoLog` (`max_values`: Some(1), `max_size`: Some(3701), added: 4196, mode: `MaxEncodedLen`)
 	fn sudo_as() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `198`
-		//  Estimated: `1517`
+		//  Estimated: `5186`
 		// Minimum execution time: 11_161_000 picoseconds.
-		Weight::from_parts(11_618_000, 1517)
-			.saturating_add(T::DbWeight::get().reads(1_u64))
+		Weight::from_parts(11_618_000, 5186)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Sudo::Key` (r:1 w:1)
 	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
@@ -103,6 +115,34 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Sudo::CoSigner` (r:1 w:1)
+	/// Proof: `Sudo::CoSigner` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::Key` (r:1 w:0)
+	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::CoSigned` (r:0 w:1)
+	/// Proof: `Sudo::CoSigned` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn set_co_signer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `165`
+		//  Estimated: `1517`
+		// Minimum execution time: 11_902_000 picoseconds.
+		Weight::from_parts(11_902_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Sudo::CoSigner` (r:1 w:0)
+	/// Proof: `Sudo::CoSigner` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::CoSigned` (r:0 w:1)
+	/// Proof: `Sudo::CoSigned` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn co_sign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `165`
+		//  Estimated: `1517`
+		// Minimum execution time: 9_847_000 picoseconds.
+		Weight::from_parts(9_847_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 	/// Storage: `Sudo::Key` (r:1 w:0)
 	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
 	fn check_only_sudo_account() -> Weight {
@@ -130,23 +170,29 @@ impl WeightInfo for () {
 	}
 	/// Storage: `Sudo::Key` (r:1 w:0)
 	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::SudoLog` (r:1 w:1)
+	/// Proof: `Sudo::SudoLog` (`max_values`: Some(1), `max_size`: Some(3701), added: 4196, mode: `MaxEncodedLen`)
 	fn sudo() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `198`
-		//  Estimated: `1517`
+		//  Estimated: `5186`
 		// Minimum execution time: 11_218_000 picoseconds.
-		Weight::from_parts(11_501_000, 1517)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+		Weight::from_parts(11_501_000, 5186)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Sudo::Key` (r:1 w:0)
 	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::SudoLog` (r:1 w:1)
+	/// Proof: `Sudo::SudoLog` (`max_values`: Some(1), `max_size`: Some(3701), added: 4196, mode: `MaxEncodedLen`)
 	fn sudo_as() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `198`
-		//  Estimated: `1517`
+		//  Estimated: `5186`
 		// Minimum execution time: 11_161_000 picoseconds.
-		Weight::from_parts(11_618_000, 1517)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+		Weight::from_parts(11_618_000, 5186)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Sudo::Key` (r:1 w:1)
 	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
@@ -159,6 +205,34 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Sudo::CoSigner` (r:1 w:1)
+	/// Proof: `Sudo::CoSigner` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::Key` (r:1 w:0)
+	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::CoSigned` (r:0 w:1)
+	/// Proof: `Sudo::CoSigned` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn set_co_signer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `165`
+		//  Estimated: `1517`
+		// Minimum execution time: 11_902_000 picoseconds.
+		Weight::from_parts(11_902_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Sudo::CoSigner` (r:1 w:0)
+	/// Proof: `Sudo::CoSigner` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Sudo::CoSigned` (r:0 w:1)
+	/// Proof: `Sudo::CoSigned` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn co_sign() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `165`
+		//  Estimated: `1517`
+		// Minimum execution time: 9_847_000 picoseconds.
+		Weight::from_parts(9_847_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 	/// Storage: `Sudo::Key` (r:1 w:0)
 	/// Proof: `Sudo::Key` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
 	fn check_only_sudo_account() -> Weight {
@@ -69,6 +69,28 @@ pub(crate) fn expand_variant(
 	}
 }
 
+pub(crate) fn expand_reasons(
+	composite_name: &str,
+	index: u8,
+	path: &PalletPath,
+	instance: Option<&Ident>,
+	variant_name: &Ident,
+	scrate: &TokenStream,
+) -> TokenStream {
+	let composite_name = quote::format_ident!("{}", composite_name);
+	let pallet_name = variant_name.to_string();
+
+	let ty = if let Some(inst) = instance {
+		quote!(#path::#composite_name<#path::#inst>)
+	} else {
+		quote!(#path::#composite_name)
+	};
+
+	quote! {
+		#scrate::reason_registry::reasons_of::<#ty>(#pallet_name, #index)
+	}
+}
+
 pub(crate) fn expand_variant_count(
 	composite_name: &str,
 	path: &PalletPath,
@@ -24,6 +24,7 @@ pub fn expand_outer_freeze_reason(pallet_decls: &[Pallet], scrate: &TokenStream)
 	let mut conversion_fns = Vec::new();
 	let mut freeze_reason_variants = Vec::new();
 	let mut freeze_reason_variants_count = Vec::new();
+	let mut freeze_reasons = Vec::new();
 	for decl in pallet_decls {
 		if let Some(_) = decl.find_part("FreezeReason") {
 			let variant_name = &decl.name;
@@ -51,6 +52,15 @@ pub fn expand_outer_freeze_reason(pallet_decls: &[Pallet], scrate: &TokenStream)
 				path,
 				instance,
 			));
+
+			freeze_reasons.push(composite_helper::expand_reasons(
+				"FreezeReason",
+				index,
+				path,
+				instance,
+				variant_name,
+				scrate,
+			));
 		}
 	}
 
@@ -70,6 +80,15 @@ pub fn expand_outer_freeze_reason(pallet_decls: &[Pallet], scrate: &TokenStream)
 			const VARIANT_COUNT: u32 = 0 #( + #freeze_reason_variants_count )*;
 		}
 
+		impl #scrate::reason_registry::GetReasons for RuntimeFreezeReason {
+			fn reasons() -> #scrate::__private::Vec<#scrate::reason_registry::ReasonInfo> {
+				#[allow(unused_mut)]
+				let mut reasons = #scrate::__private::Vec::new();
+				#( reasons.extend(#freeze_reasons); )*
+				reasons
+			}
+		}
+
 		#( #conversion_fns )*
 	}
 }
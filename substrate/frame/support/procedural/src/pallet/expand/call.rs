@@ -18,7 +18,10 @@
 use crate::{
 	pallet::{
 		expand::warnings::{weight_constant_warning, weight_witness_warning},
-		parse::{call::CallWeightDef, helper::CallReturnType},
+		parse::{
+			call::{CallWeightDef, OriginCheckDef},
+			helper::CallReturnType,
+		},
 		Def,
 	},
 	COUNTER,
@@ -114,6 +117,32 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 
 	let fn_doc = methods.iter().map(|method| &method.docs).collect::<Vec<_>>();
 
+	let origin_audit = &quote::quote!(#frame_support::origin_audit);
+	let fn_origin_requirements = methods
+		.iter()
+		.map(|method| {
+			method
+				.origin_checks
+				.iter()
+				.map(|check| match check {
+					OriginCheckDef::Root => quote!(#origin_audit::OriginRequirement::Root),
+					OriginCheckDef::Signed => quote!(#origin_audit::OriginRequirement::Signed),
+					OriginCheckDef::None => quote!(#origin_audit::OriginRequirement::None),
+					OriginCheckDef::SignedOrRoot =>
+						quote!(#origin_audit::OriginRequirement::SignedOrRoot),
+					OriginCheckDef::EnsureOrigin(ty) => {
+						let name = origin_type_name(ty);
+						quote!(#origin_audit::OriginRequirement::EnsureOrigin(#name.into()))
+					},
+					OriginCheckDef::EnsureOriginOrRoot(ty) => {
+						let name = origin_type_name(ty);
+						quote!(#origin_audit::OriginRequirement::EnsureOriginOrRoot(#name.into()))
+					},
+				})
+				.collect::<Vec<_>>()
+		})
+		.collect::<Vec<_>>();
+
 	let args_name = methods
 		.iter()
 		.map(|method| method.args.iter().map(|(_, name, _)| name.clone()).collect::<Vec<_>>())
@@ -433,6 +462,30 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 			}
 		}
 
+		impl<#type_impl_gen> #origin_audit::GetCallOriginRequirements
+			for #pallet_ident<#type_use_gen>
+			#where_clause
+		{
+			fn call_origin_requirements(
+			) -> #frame_support::__private::Vec<#origin_audit::CallOriginRequirements> {
+				#frame_support::__private::Vec::from([
+					#(
+						#cfg_attrs
+						#origin_audit::CallOriginRequirements {
+							pallet: <
+								Self as #frame_support::traits::PalletInfoAccess
+							>::name().into(),
+							call: stringify!(#fn_name).into(),
+							call_index: #call_index,
+							origins: #frame_support::__private::Vec::from([
+								#( #fn_origin_requirements, )*
+							]),
+						},
+					)*
+				])
+			}
+		}
+
 		impl<#type_impl_gen> #frame_support::traits::UnfilteredDispatchable
 			for #call_ident<#type_use_gen>
 			#where_clause
@@ -482,3 +535,14 @@ pub fn expand_call(def: &mut Def) -> proc_macro2::TokenStream {
 		}
 	)
 }
+
+/// The name of an `EnsureOrigin` type as it is written in the pallet, e.g. `T::AdminOrigin`.
+fn origin_type_name(ty: &syn::Type) -> String {
+	let mut name = quote::quote!(#ty).to_string();
+	for (spaced, compact) in
+		[(" :: ", "::"), (":: ", "::"), (" < ", "<"), ("< ", "<"), (" >", ">"), (" ,", ",")]
+	{
+		name = name.replace(spaced, compact);
+	}
+	name
+}
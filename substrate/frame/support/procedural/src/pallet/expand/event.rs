@@ -127,6 +127,65 @@ pub fn expand_event(def: &mut Def) -> proc_macro2::TokenStream {
 		)]
 	));
 
+	let indexed_fields_metadata = event.indexed_fields.iter().map(|field| {
+		let cfg_attrs = &field.cfg_attrs;
+		let variant = field.variant.to_string();
+		let index = field.index as u32;
+		let name = match &field.ident {
+			Some(ident) => {
+				let ident = ident.to_string();
+				quote::quote!(Some(#ident))
+			},
+			None => quote::quote!(None),
+		};
+
+		quote::quote!(
+			#( #cfg_attrs )*
+			#frame_support::__private::metadata_ir::EventIndexedFieldIR {
+				variant: #variant,
+				index: #index,
+				name: #name,
+			},
+		)
+	});
+
+	let mut indexed_variants: Vec<(&Ident, &Vec<syn::Attribute>, Vec<_>)> = Vec::new();
+	for field in &event.indexed_fields {
+		match indexed_variants.iter_mut().find(|(variant, ..)| **variant == field.variant) {
+			Some((.., fields)) => fields.push(field),
+			None => indexed_variants.push((&field.variant, &field.cfg_attrs, vec![field])),
+		}
+	}
+	let indexed_topics_arms = indexed_variants.iter().map(|(variant, cfg_attrs, fields)| {
+		let bindings = fields
+			.iter()
+			.map(|field| Ident::new(&format!("__indexed_{}", field.index), variant.span()))
+			.collect::<Vec<_>>();
+		let pattern = if fields.iter().all(|field| field.ident.is_some()) {
+			let names = fields.iter().map(|field| &field.ident);
+			quote::quote!(Self::#variant { #( #names: #bindings, )* .. })
+		} else {
+			let last = fields.iter().map(|field| field.index).max().unwrap_or_default();
+			let elems = (0..=last).map(|index| {
+				match fields.iter().position(|field| field.index == index) {
+					Some(pos) => {
+						let binding = &bindings[pos];
+						quote::quote!(#binding)
+					},
+					None => quote::quote!(_),
+				}
+			});
+			quote::quote!(Self::#variant( #( #elems, )* .. ))
+		};
+
+		quote::quote!(
+			#( #cfg_attrs )*
+			#pattern => #frame_support::__private::vec![
+				#( <H as #frame_support::sp_runtime::traits::Hash>::hash_of(#bindings), )*
+			],
+		)
+	});
+
 	let capture_docs = if cfg!(feature = "no-metadata-docs") { "never" } else { "always" };
 
 	// skip requirement for type params to implement `TypeInfo`, and set docs capture
@@ -143,9 +202,30 @@ pub fn expand_event(def: &mut Def) -> proc_macro2::TokenStream {
 
 		let PalletEventDepositAttr { fn_vis, fn_span, .. } = deposit_event;
 
+		let deposit = if event.indexed_fields.is_empty() {
+			quote::quote!(<#frame_system::Pallet<T>>::deposit_event(event))
+		} else {
+			quote::quote!(
+				if topics.is_empty() {
+					<#frame_system::Pallet<T>>::deposit_event(event)
+				} else {
+					<#frame_system::Pallet<T>>::deposit_event_indexed(&topics, event)
+				}
+			)
+		};
+		let topics = if event.indexed_fields.is_empty() {
+			quote::quote!()
+		} else {
+			quote::quote!(
+				let topics = event.indexed_topics::<<T as #frame_system::Config>::Hashing>();
+			)
+		};
+
 		quote::quote_spanned!(*fn_span =>
 			impl<#type_impl_gen> #pallet_ident<#type_use_gen> #completed_where_clause {
 				#fn_vis fn deposit_event(event: Event<#event_use_gen>) {
+					#topics
+
 					let event = <
 						<T as Config #trait_use_gen>::RuntimeEvent as
 						From<Event<#event_use_gen>>
@@ -156,7 +236,7 @@ pub fn expand_event(def: &mut Def) -> proc_macro2::TokenStream {
 						Into<<T as #frame_system::Config>::RuntimeEvent>
 					>::into(event);
 
-					<#frame_system::Pallet<T>>::deposit_event(event)
+					#deposit
 				}
 			}
 		)
@@ -190,6 +270,20 @@ pub fn expand_event(def: &mut Def) -> proc_macro2::TokenStream {
 				#frame_support::__private::metadata_ir::PalletEventMetadataIR {
 					ty: #frame_support::__private::scale_info::meta_type::<W>(),
 					deprecation_info: #deprecation,
+					indexed_fields: #frame_support::__private::Vec::from([
+						#( #indexed_fields_metadata )*
+					]),
+				}
+			}
+
+			/// The topics of the fields marked with `#[pallet::indexed]`, hashed with `H`.
+			#[allow(unreachable_patterns)]
+			pub fn indexed_topics<H: #frame_support::sp_runtime::traits::Hash>(
+				&self,
+			) -> #frame_support::__private::Vec<H::Output> {
+				match self {
+					#( #indexed_topics_arms )*
+					_ => #frame_support::__private::Vec::new(),
 				}
 			}
 		}
@@ -93,6 +93,103 @@ pub struct CallVariantDef {
 	pub feeless_check: Option<syn::ExprClosure>,
 	/// The return type of the call: `DispatchInfo` or `DispatchResultWithPostInfo`.
 	pub return_type: helper::CallReturnType,
+	/// The origin checks found in the body of the call, used for the origin audit.
+	pub origin_checks: Vec<OriginCheckDef>,
+}
+
+/// An origin check found in the body of a dispatchable.
+#[derive(Clone)]
+pub enum OriginCheckDef {
+	/// `ensure_root(origin)`.
+	Root,
+	/// `ensure_signed(origin)`.
+	Signed,
+	/// `ensure_none(origin)`.
+	None,
+	/// `ensure_signed_or_root(origin)`.
+	SignedOrRoot,
+	/// `Type::ensure_origin(origin)` or `Type::try_origin(origin)`.
+	EnsureOrigin(syn::Type),
+	/// `Type::ensure_origin_or_root(origin)`.
+	EnsureOriginOrRoot(syn::Type),
+}
+
+/// Collects the origin checks called in the body of a dispatchable.
+#[derive(Default)]
+struct OriginCheckCollector {
+	checks: Vec<OriginCheckDef>,
+}
+
+impl OriginCheckCollector {
+	/// The `EnsureOrigin` type that `path` calls a function of, if it can be named.
+	fn ensure_origin_type(path: &syn::ExprPath) -> Option<syn::Type> {
+		let mut segments = path.path.segments.clone();
+		segments.pop();
+		segments.pop_punct();
+
+		let ty = match &path.qself {
+			// `<Type as EnsureOrigin<_>>::ensure_origin`
+			Some(qself) if qself.position >= segments.len() => (*qself.ty).clone(),
+			Some(_) => syn::Type::Path(syn::TypePath {
+				qself: path.qself.clone(),
+				path: syn::Path { leading_colon: path.path.leading_colon, segments },
+			}),
+			None => {
+				// Only paths to types are supported, e.g. `T::AdminOrigin`. `Self` is the pallet,
+				// which doesn't implement `EnsureOrigin`.
+				let last = segments.last()?;
+				if last.ident == "Self" ||
+					!last.ident.to_string().starts_with(|c: char| c.is_ascii_uppercase())
+				{
+					return None
+				}
+				syn::Type::Path(syn::TypePath {
+					qself: None,
+					path: syn::Path { leading_colon: path.path.leading_colon, segments },
+				})
+			},
+		};
+
+		// Types that must be inferred can't be named outside of the call.
+		let mut infer = InferTypeFinder::default();
+		syn::visit::visit_type(&mut infer, &ty);
+		(!infer.found).then_some(ty)
+	}
+}
+
+impl<'ast> syn::visit::Visit<'ast> for OriginCheckCollector {
+	fn visit_expr_call(&mut self, call: &'ast syn::ExprCall) {
+		if let syn::Expr::Path(path) = &*call.func {
+			if let Some(last) = path.path.segments.last() {
+				let check = match last.ident.to_string().as_str() {
+					"ensure_root" => Some(OriginCheckDef::Root),
+					"ensure_signed" => Some(OriginCheckDef::Signed),
+					"ensure_none" => Some(OriginCheckDef::None),
+					"ensure_signed_or_root" => Some(OriginCheckDef::SignedOrRoot),
+					"ensure_origin" | "try_origin" =>
+						Self::ensure_origin_type(path).map(OriginCheckDef::EnsureOrigin),
+					"ensure_origin_or_root" =>
+						Self::ensure_origin_type(path).map(OriginCheckDef::EnsureOriginOrRoot),
+					_ => None,
+				};
+				self.checks.extend(check);
+			}
+		}
+
+		syn::visit::visit_expr_call(self, call);
+	}
+}
+
+/// Finds `_` in a type.
+#[derive(Default)]
+struct InferTypeFinder {
+	found: bool,
+}
+
+impl<'ast> syn::visit::Visit<'ast> for InferTypeFinder {
+	fn visit_type_infer(&mut self, _: &'ast syn::TypeInfer) {
+		self.found = true;
+	}
 }
 
 /// Attributes for functions in call impl block.
@@ -435,6 +532,9 @@ impl CallDef {
 					}
 				}
 
+				let mut origin_checks = OriginCheckCollector::default();
+				syn::visit::Visit::visit_block(&mut origin_checks, &method.block);
+
 				methods.push(CallVariantDef {
 					name: method.sig.ident.clone(),
 					weight,
@@ -446,6 +546,7 @@ impl CallDef {
 					cfg_attrs,
 					feeless_check,
 					return_type,
+					origin_checks: origin_checks.checks,
 				});
 			} else {
 				let msg = "Invalid pallet::call, only method accepted";
@@ -25,6 +25,7 @@ mod keyword {
 	syn::custom_keyword!(pallet);
 	syn::custom_keyword!(generate_deposit);
 	syn::custom_keyword!(deposit_event);
+	syn::custom_keyword!(indexed);
 }
 
 /// Definition for pallet event enum.
@@ -45,6 +46,39 @@ pub struct EventDef {
 	pub attr_span: proc_macro2::Span,
 	/// event attributes
 	pub attrs: Vec<syn::Attribute>,
+	/// The fields marked with `#[pallet::indexed]`, in declaration order.
+	pub indexed_fields: Vec<EventIndexedFieldDef>,
+}
+
+/// Definition of an event field marked with `#[pallet::indexed]`.
+pub struct EventIndexedFieldDef {
+	/// The variant the field belongs to.
+	pub variant: syn::Ident,
+	/// The cfg attributes of the variant.
+	pub cfg_attrs: Vec<syn::Attribute>,
+	/// The position of the field within the variant.
+	pub index: usize,
+	/// The name of the field, `None` for tuple variants.
+	pub ident: Option<syn::Ident>,
+}
+
+/// Attribute for fields of event variants.
+///
+/// Syntax is:
+/// * `#[pallet::indexed]`
+pub struct EventFieldAttrIsIndexed;
+
+impl syn::parse::Parse for EventFieldAttrIsIndexed {
+	fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+		input.parse::<syn::Token![#]>()?;
+		let content;
+		syn::bracketed!(content in input);
+		content.parse::<keyword::pallet>()?;
+		content.parse::<syn::Token![::]>()?;
+
+		content.parse::<keyword::indexed>()?;
+		Ok(EventFieldAttrIsIndexed)
+	}
 }
 
 /// Attribute for a pallet's Event.
@@ -138,6 +172,28 @@ impl EventDef {
 
 		let event = syn::parse2::<keyword::Event>(item.ident.to_token_stream())?;
 
+		let mut indexed_fields = vec![];
+		for variant in item.variants.iter_mut() {
+			let cfg_attrs = helper::get_item_cfg_attrs(&variant.attrs);
+			for (index, field) in variant.fields.iter_mut().enumerate() {
+				let field_attrs: Vec<EventFieldAttrIsIndexed> =
+					helper::take_item_pallet_attrs(&mut field.attrs)?;
+				match field_attrs.len() {
+					0 => (),
+					1 => indexed_fields.push(EventIndexedFieldDef {
+						variant: variant.ident.clone(),
+						cfg_attrs: cfg_attrs.clone(),
+						index,
+						ident: field.ident.clone(),
+					}),
+					_ => {
+						let msg = "Invalid pallet::indexed, duplicated attribute";
+						return Err(syn::Error::new(field.span(), msg))
+					},
+				}
+			}
+		}
+
 		Ok(EventDef {
 			attr_span,
 			index,
@@ -147,6 +203,7 @@ impl EventDef {
 			gen_kind,
 			where_clause,
 			attrs,
+			indexed_fields,
 		})
 	}
 }
@@ -21,19 +21,41 @@
 
 extern crate alloc;
 
-use alloc::{format, vec::Vec};
+use alloc::{format, string::String, vec::Vec};
 use frame_support::traits::BuildGenesisConfig;
-use sp_genesis_builder::{PresetId, Result as BuildResult};
+use sp_genesis_builder::{validation, PresetId, Result as BuildResult};
 
 /// Build `GenesisConfig` from a JSON blob not using any defaults and store it in the storage. For
 /// more info refer to [`sp_genesis_builder::GenesisBuilder::build_state`].
-pub fn build_state<GC: BuildGenesisConfig>(json: Vec<u8>) -> BuildResult {
-	let gc =
-		serde_json::from_slice::<GC>(&json).map_err(|e| format!("Invalid JSON blob: {}", e))?;
+///
+/// If the JSON blob can't be deserialized, it is validated against the default `GenesisConfig`,
+/// so that unknown, missing and mistyped fields are reported with their path.
+pub fn build_state<GC: BuildGenesisConfig + Default>(json: Vec<u8>) -> BuildResult {
+	let gc = serde_json::from_slice::<GC>(&json).map_err(|e| {
+		// The default config does not know the serde aliases of the fields, so it is only used to
+		// point at the offending field once the deserialization failed.
+		let path_error = serde_json::from_slice::<serde_json::Value>(&json)
+			.ok()
+			.and_then(|value| validation::validate_config(&default_config::<GC>(), &value).err());
+		match path_error {
+			Some(path_error) => format!("Invalid JSON blob: {}", path_error),
+			None => format!("Invalid JSON blob: {}", e),
+		}
+	})?;
 	<GC as BuildGenesisConfig>::build(&gc);
 	Ok(())
 }
 
+/// Get the paths of all fields of the `GenesisConfig` which can be set by a patch. For more info
+/// refer to [`sp_genesis_builder::GenesisBuilder::patchable_paths`].
+pub fn patchable_paths<GC: BuildGenesisConfig + Default>() -> Vec<String> {
+	validation::patchable_paths(&default_config::<GC>())
+}
+
+fn default_config<GC: BuildGenesisConfig + Default>() -> serde_json::Value {
+	serde_json::to_value(&GC::default()).expect("serialization to json is expected to work. qed.")
+}
+
 /// Get the default `GenesisConfig` as a JSON blob if `name` is None.
 ///
 /// Query of named presets is delegetaed to provided `preset_for_name` closure. For more info refer
@@ -54,3 +76,44 @@ where
 		preset_for_name,
 	)
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde::{Deserialize, Serialize};
+
+	#[derive(Default, Serialize, Deserialize)]
+	#[serde(rename_all = "camelCase", deny_unknown_fields)]
+	struct Config {
+		#[serde(alias = "validation_upgrade_frequency")]
+		validation_upgrade_cooldown: u32,
+		max_code_size: u32,
+	}
+
+	impl BuildGenesisConfig for Config {
+		fn build(&self) {}
+	}
+
+	#[test]
+	fn build_state_accepts_aliased_fields() {
+		let preset = br#"{ "validation_upgrade_frequency": 10, "maxCodeSize": 20 }"#.to_vec();
+		assert_eq!(build_state::<Config>(preset), Ok(()));
+	}
+
+	#[test]
+	fn build_state_reports_the_path_of_invalid_fields() {
+		let preset = br#"{ "validationUpgradeCooldown": 10, "maxCodeSize": "20" }"#.to_vec();
+		assert_eq!(
+			build_state::<Config>(preset),
+			Err("Invalid JSON blob: invalid type at `maxCodeSize`: expected number, found string"
+				.into())
+		);
+
+		let preset =
+			br#"{ "validationUpgradeCooldown": 10, "maxCodeSize": 20, "foo": 1 }"#.to_vec();
+		assert_eq!(
+			build_state::<Config>(preset),
+			Err("Invalid JSON blob: unknown field `foo`".into())
+		);
+	}
+}
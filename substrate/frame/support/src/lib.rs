@@ -82,10 +82,13 @@ mod hash;
 pub mod inherent;
 pub mod instances;
 pub mod migrations;
+pub mod origin_audit;
+pub mod reason_registry;
 pub mod storage;
 #[cfg(test)]
 mod tests;
 pub mod traits;
+pub mod view_functions;
 pub mod weights;
 #[doc(hidden)]
 pub mod unsigned {
@@ -1842,6 +1845,36 @@ pub mod pallet_macros {
 	/// deposit_event` on `Pallet`.
 	pub use frame_support_procedural::generate_deposit;
 
+	/// Marks a field of an [`event`] variant as indexed.
+	///
+	/// The generated `deposit_event` deposits the hash of the value of each indexed field as a
+	/// topic of the event. Nodes can then look up the events that refer to a given value, e.g. all
+	/// the events of an account, through `frame_system::EventTopics`.
+	///
+	/// Indexed fields are only listed in the metadata IR, in
+	/// [`PalletEventMetadataIR::indexed_fields`](crate::metadata_ir::PalletEventMetadataIR). The
+	/// V14 and V15 metadata served by the runtime have no place for them, so they are not exposed
+	/// to clients yet.
+	///
+	/// ```
+	/// #[frame_support::pallet(dev_mode)]
+	/// pub mod custom_pallet {
+	/// #   use frame_support::pallet_prelude::*;
+	/// #   #[pallet::config]
+	/// #   pub trait Config: frame_system::Config {
+	/// #       type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+	/// #   }
+	/// #   #[pallet::pallet]
+	/// #   pub struct Pallet<T>(_);
+	///     #[pallet::event]
+	///     #[pallet::generate_deposit(fn deposit_event)]
+	///     pub enum Event<T: Config> {
+	///         Transferred { #[pallet::indexed] from: T::AccountId, #[pallet::indexed] to: T::AccountId, amount: u32 },
+	///     }
+	/// }
+	/// ```
+	pub use frame_support_procedural::indexed;
+
 	/// Allows defining logic to make an extrinsic call feeless.
 	///
 	/// Each dispatchable may be annotated with the `#[pallet::feeless_if($closure)]`
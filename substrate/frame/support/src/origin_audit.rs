@@ -0,0 +1,102 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support for auditing the origins required by dispatchables.
+//!
+//! The [`pallet`](crate::pallet) macro records the origin checks it finds in the body of every
+//! dispatchable, such as `ensure_root`, `ensure_signed` or `T::AdminOrigin::ensure_origin`, and
+//! exposes them through [`GetCallOriginRequirements`]. A runtime can serve the table of all its
+//! pallets through the [`OriginAuditApi`](runtime_api::OriginAuditApi) runtime API, so that
+//! tooling can diff the privileges of dispatchables between runtime versions.
+//!
+//! The detection is syntactic: only origin checks that appear directly in the body of a
+//! dispatchable are found. Checks performed in helper functions are not reported.
+
+use alloc::{string::String, vec::Vec};
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// An origin check performed by a dispatchable.
+#[derive(Clone, Encode, Decode, TypeInfo, RuntimeDebug, PartialEq, Eq)]
+pub enum OriginRequirement {
+	/// The origin is checked with `ensure_root`.
+	Root,
+	/// The origin is checked with `ensure_signed`.
+	Signed,
+	/// The origin is checked with `ensure_none`.
+	None,
+	/// The origin is checked with `ensure_signed_or_root`.
+	SignedOrRoot,
+	/// The origin is checked with `ensure_origin` or `try_origin` of the given `EnsureOrigin`
+	/// type.
+	///
+	/// The type is identified by the path it is named with in the pallet, e.g.
+	/// `T::AdminOrigin`, which does not depend on the compiler.
+	EnsureOrigin(String),
+	/// The origin is checked with `ensure_origin_or_root` of the given `EnsureOrigin` type.
+	///
+	/// The type is identified like in [`Self::EnsureOrigin`].
+	EnsureOriginOrRoot(String),
+}
+
+/// The origin checks performed by a dispatchable.
+#[derive(Clone, Encode, Decode, TypeInfo, RuntimeDebug, PartialEq, Eq)]
+pub struct CallOriginRequirements {
+	/// The name of the pallet in the runtime.
+	pub pallet: String,
+	/// The name of the dispatchable.
+	pub call: String,
+	/// The call index of the dispatchable.
+	pub call_index: u8,
+	/// The origin checks found in the body of the dispatchable, in order of appearance.
+	///
+	/// Empty if the dispatchable does not check its origin directly.
+	pub origins: Vec<OriginRequirement>,
+}
+
+/// Provides the origin requirements of dispatchables.
+///
+/// Implemented by the [`pallet`](crate::pallet) macro for every pallet and by tuples of pallets,
+/// which concatenate the requirements of their members. The tuple of all pallets of a runtime is
+/// what it uses to implement the [`OriginAuditApi`](runtime_api::OriginAuditApi) runtime API.
+pub trait GetCallOriginRequirements {
+	/// The origin requirements of all dispatchables.
+	fn call_origin_requirements() -> Vec<CallOriginRequirements>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(64)]
+impl GetCallOriginRequirements for Tuple {
+	fn call_origin_requirements() -> Vec<CallOriginRequirements> {
+		let mut requirements = Vec::new();
+		for_tuples!( #( requirements.extend(Tuple::call_origin_requirements()); )* );
+		requirements
+	}
+}
+
+/// The runtime API for auditing origin requirements.
+pub mod runtime_api {
+	use super::*;
+
+	sp_api::decl_runtime_apis! {
+		/// Runtime API for querying the origins required by the dispatchables of the runtime.
+		pub trait OriginAuditApi {
+			/// The origin requirements of every dispatchable of the runtime.
+			fn call_origin_requirements() -> Vec<CallOriginRequirements>;
+		}
+	}
+}
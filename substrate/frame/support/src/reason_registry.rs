@@ -0,0 +1,85 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support for describing the reasons of holds and freezes of a runtime.
+//!
+//! The `construct_runtime` macro implements [`GetReasons`] for the `RuntimeHoldReason` and
+//! `RuntimeFreezeReason` enums it aggregates from the `#[pallet::composite_enum]`s of the pallets.
+//! A runtime can serve them through the [`ReasonRegistryApi`](runtime_api::ReasonRegistryApi)
+//! runtime API, so that wallets can display why funds are held or frozen instead of an opaque
+//! encoded reason.
+
+use alloc::{
+	string::{String, ToString},
+	vec,
+	vec::Vec,
+};
+use codec::{Decode, Encode};
+use scale_info::{TypeDef, TypeInfo};
+use sp_runtime::RuntimeDebug;
+
+/// Description of a variant of a runtime composite reason enum.
+#[derive(Clone, Encode, Decode, TypeInfo, RuntimeDebug, PartialEq, Eq)]
+pub struct ReasonInfo {
+	/// The name of the pallet in the runtime.
+	pub pallet: String,
+	/// The name of the reason variant in the pallet enum.
+	pub reason: String,
+	/// The SCALE encoding of the reason in the runtime enum.
+	///
+	/// For reasons carrying data, this is only the prefix preceding the encoded data.
+	pub encoded: Vec<u8>,
+	/// The documentation of the reason variant.
+	pub docs: Vec<String>,
+}
+
+/// Provides the description of all the variants of a runtime composite reason enum.
+pub trait GetReasons {
+	/// The description of all the variants, ordered by pallet.
+	fn reasons() -> Vec<ReasonInfo>;
+}
+
+/// The description of the variants of the pallet reason enum `R`, as part of the pallet at index
+/// `pallet_index` named `pallet` in the runtime.
+pub fn reasons_of<R: TypeInfo + 'static>(pallet: &str, pallet_index: u8) -> Vec<ReasonInfo> {
+	let TypeDef::Variant(def) = R::type_info().type_def else { return Vec::new() };
+
+	def.variants
+		.iter()
+		.map(|variant| ReasonInfo {
+			pallet: pallet.to_string(),
+			reason: variant.name.to_string(),
+			encoded: vec![pallet_index, variant.index],
+			docs: variant.docs.iter().map(|doc| doc.to_string()).collect(),
+		})
+		.collect()
+}
+
+/// The runtime API for describing hold and freeze reasons.
+pub mod runtime_api {
+	use super::*;
+
+	sp_api::decl_runtime_apis! {
+		/// Runtime API for querying the reasons of holds and freezes of the runtime.
+		pub trait ReasonRegistryApi {
+			/// The description of all the variants of `RuntimeHoldReason`.
+			fn hold_reasons() -> Vec<ReasonInfo>;
+			/// The description of all the variants of `RuntimeFreezeReason`.
+			fn freeze_reasons() -> Vec<ReasonInfo>;
+		}
+	}
+}
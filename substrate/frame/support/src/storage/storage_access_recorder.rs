@@ -0,0 +1,563 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Feature gated since it is only meant to be used in tests.
+#![cfg(feature = "std")]
+
+//! Introspection of the storage accesses done by a piece of code.
+//!
+//! [`record_storage_accesses`] runs a closure and records every storage read and write it does,
+//! together with the size of the value and the transactional layer it happened in. The returned
+//! [`StorageAccessLog`] can then be used to assert the expected access pattern, for example to
+//! catch a dispatchable that accidentally became `O(n)` in the number of storage items.
+//!
+//! # Example
+//!
+//! ```
+//! use frame_support::storage::{storage_access_recorder::record_storage_accesses, unhashed};
+//!
+//! sp_io::TestExternalities::default().execute_with(|| {
+//! 	let ((), log) = record_storage_accesses(|| {
+//! 		unhashed::put(b"key", &1u32);
+//! 		let _ = unhashed::get::<u32>(b"key");
+//! 	});
+//!
+//! 	log.assert_max_reads(1);
+//! 	log.assert_max_writes(1);
+//! 	assert_eq!(log.accesses_with_prefix(b"key").count(), 2);
+//! });
+//! ```
+
+use super::transactional::TRANSACTION_LEVEL_KEY;
+use alloc::{boxed::Box, vec::Vec};
+use core::any::{Any, TypeId};
+use sp_core::storage::{ChildInfo, StateVersion, TrackedStorageKey};
+use sp_externalities::{Extension, ExtensionStore, Externalities, MultiRemovalResults};
+
+/// The kind of a recorded storage access.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StorageAccessKind {
+	/// The value of the key was read, or checked for existence.
+	Read,
+	/// A value was written to, or appended to, the key.
+	Write,
+	/// The key was removed.
+	Remove,
+	/// The key following the accessed key was requested, as done when iterating the storage.
+	NextKey,
+	/// All keys starting with the accessed key were removed.
+	ClearPrefix,
+}
+
+/// A single recorded storage access.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StorageAccess {
+	/// The kind of the access.
+	pub kind: StorageAccessKind,
+	/// The accessed key, or the prefix for [`StorageAccessKind::ClearPrefix`].
+	pub key: Vec<u8>,
+	/// The storage key of the child trie, if the access was made to a child trie.
+	pub child: Option<Vec<u8>>,
+	/// The size of the read or written value, zero if there was none.
+	pub size: u32,
+	/// The transactional layer the access happened in, relative to the start of the recording.
+	pub layer: u32,
+}
+
+/// All the storage accesses recorded by [`record_storage_accesses`], in the order they happened.
+///
+/// Accesses to the [`TRANSACTION_LEVEL_KEY`] done by the transactional layers of FRAME are not
+/// recorded, only the layer they open is.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageAccessLog {
+	accesses: Vec<StorageAccess>,
+}
+
+impl StorageAccessLog {
+	/// All recorded accesses.
+	pub fn accesses(&self) -> &[StorageAccess] {
+		&self.accesses
+	}
+
+	/// The recorded accesses of the given kind.
+	pub fn accesses_of_kind(
+		&self,
+		kind: StorageAccessKind,
+	) -> impl Iterator<Item = &StorageAccess> + '_ {
+		self.accesses.iter().filter(move |access| access.kind == kind)
+	}
+
+	/// The recorded accesses to keys starting with `prefix`.
+	pub fn accesses_with_prefix<'a>(
+		&'a self,
+		prefix: &'a [u8],
+	) -> impl Iterator<Item = &'a StorageAccess> + 'a {
+		self.accesses.iter().filter(move |access| access.key.starts_with(prefix))
+	}
+
+	/// The number of reads, including the existence checks.
+	pub fn reads(&self) -> usize {
+		self.accesses_of_kind(StorageAccessKind::Read).count()
+	}
+
+	/// The number of writes, including the removals.
+	pub fn writes(&self) -> usize {
+		self.accesses
+			.iter()
+			.filter(|access| {
+				matches!(
+					access.kind,
+					StorageAccessKind::Write |
+						StorageAccessKind::Remove |
+						StorageAccessKind::ClearPrefix
+				)
+			})
+			.count()
+	}
+
+	/// The number of times the next key was requested, i.e. the number of iteration steps.
+	pub fn iterations(&self) -> usize {
+		self.accesses_of_kind(StorageAccessKind::NextKey).count()
+	}
+
+	/// The total size of all read values.
+	pub fn read_size(&self) -> u64 {
+		self.accesses_of_kind(StorageAccessKind::Read)
+			.map(|access| access.size as u64)
+			.sum()
+	}
+
+	/// The total size of all written values.
+	pub fn write_size(&self) -> u64 {
+		self.accesses_of_kind(StorageAccessKind::Write)
+			.map(|access| access.size as u64)
+			.sum()
+	}
+
+	/// Assert that at most `max` reads were recorded.
+	#[track_caller]
+	pub fn assert_max_reads(&self, max: usize) {
+		assert!(self.reads() <= max, "expected at most {max} reads, got: {:#?}", self.accesses);
+	}
+
+	/// Assert that at most `max` writes were recorded.
+	#[track_caller]
+	pub fn assert_max_writes(&self, max: usize) {
+		assert!(self.writes() <= max, "expected at most {max} writes, got: {:#?}", self.accesses);
+	}
+
+	/// Assert that at most `max` iteration steps were recorded.
+	#[track_caller]
+	pub fn assert_max_iterations(&self, max: usize) {
+		assert!(
+			self.iterations() <= max,
+			"expected at most {max} iteration steps, got: {:#?}",
+			self.accesses
+		);
+	}
+
+	/// Assert that at most `max` accesses of any kind to keys starting with `prefix` were
+	/// recorded.
+	#[track_caller]
+	pub fn assert_max_accesses_with_prefix(&self, prefix: &[u8], max: usize) {
+		let accesses = self.accesses_with_prefix(prefix).collect::<Vec<_>>();
+		assert!(
+			accesses.len() <= max,
+			"expected at most {max} accesses with prefix {}, got: {accesses:#?}",
+			array_bytes::bytes2hex("0x", prefix),
+		);
+	}
+
+	/// Assert that no key starting with `prefix` was accessed.
+	#[track_caller]
+	pub fn assert_no_access_with_prefix(&self, prefix: &[u8]) {
+		self.assert_max_accesses_with_prefix(prefix, 0)
+	}
+}
+
+/// Run `f` and record all the storage accesses it does.
+///
+/// Must be called within externalities, e.g. inside of `TestExternalities::execute_with`.
+///
+/// # Panics
+///
+/// Panics if called outside of externalities.
+pub fn record_storage_accesses<R>(f: impl FnOnce() -> R) -> (R, StorageAccessLog) {
+	sp_externalities::with_externalities(|inner| {
+		let mut recorder = RecordingExternalities { inner, log: Default::default(), layer: 0 };
+		let result = sp_externalities::set_and_run_with_externalities(&mut recorder, f);
+		(result, recorder.log)
+	})
+	.expect("`record_storage_accesses` must be called within externalities")
+}
+
+/// Externalities recording all storage accesses before forwarding them to `inner`.
+struct RecordingExternalities<'a> {
+	inner: &'a mut dyn Externalities,
+	log: StorageAccessLog,
+	layer: u32,
+}
+
+impl<'a> RecordingExternalities<'a> {
+	fn record(
+		&mut self,
+		kind: StorageAccessKind,
+		child_info: Option<&ChildInfo>,
+		key: &[u8],
+		size: usize,
+	) {
+		if child_info.is_none() && key == TRANSACTION_LEVEL_KEY {
+			return
+		}
+
+		self.log.accesses.push(StorageAccess {
+			kind,
+			key: key.to_vec(),
+			child: child_info.map(|info| info.storage_key().to_vec()),
+			size: size as u32,
+			layer: self.layer,
+		});
+	}
+}
+
+impl<'a> ExtensionStore for RecordingExternalities<'a> {
+	fn extension_by_type_id(&mut self, type_id: TypeId) -> Option<&mut dyn Any> {
+		self.inner.extension_by_type_id(type_id)
+	}
+
+	fn register_extension_with_type_id(
+		&mut self,
+		type_id: TypeId,
+		extension: Box<dyn Extension>,
+	) -> Result<(), sp_externalities::Error> {
+		self.inner.register_extension_with_type_id(type_id, extension)
+	}
+
+	fn deregister_extension_by_type_id(
+		&mut self,
+		type_id: TypeId,
+	) -> Result<(), sp_externalities::Error> {
+		self.inner.deregister_extension_by_type_id(type_id)
+	}
+}
+
+impl<'a> Externalities for RecordingExternalities<'a> {
+	fn set_offchain_storage(&mut self, key: &[u8], value: Option<&[u8]>) {
+		self.inner.set_offchain_storage(key, value)
+	}
+
+	fn storage(&mut self, key: &[u8]) -> Option<Vec<u8>> {
+		let value = self.inner.storage(key);
+		self.record(StorageAccessKind::Read, None, key, value.as_ref().map_or(0, |v| v.len()));
+		value
+	}
+
+	fn storage_hash(&mut self, key: &[u8]) -> Option<Vec<u8>> {
+		self.record(StorageAccessKind::Read, None, key, 0);
+		self.inner.storage_hash(key)
+	}
+
+	fn child_storage_hash(&mut self, child_info: &ChildInfo, key: &[u8]) -> Option<Vec<u8>> {
+		self.record(StorageAccessKind::Read, Some(child_info), key, 0);
+		self.inner.child_storage_hash(child_info, key)
+	}
+
+	fn child_storage(&mut self, child_info: &ChildInfo, key: &[u8]) -> Option<Vec<u8>> {
+		let value = self.inner.child_storage(child_info, key);
+		let size = value.as_ref().map_or(0, |v| v.len());
+		self.record(StorageAccessKind::Read, Some(child_info), key, size);
+		value
+	}
+
+	fn exists_storage(&mut self, key: &[u8]) -> bool {
+		self.record(StorageAccessKind::Read, None, key, 0);
+		self.inner.exists_storage(key)
+	}
+
+	fn exists_child_storage(&mut self, child_info: &ChildInfo, key: &[u8]) -> bool {
+		self.record(StorageAccessKind::Read, Some(child_info), key, 0);
+		self.inner.exists_child_storage(child_info, key)
+	}
+
+	fn next_storage_key(&mut self, key: &[u8]) -> Option<Vec<u8>> {
+		self.record(StorageAccessKind::NextKey, None, key, 0);
+		self.inner.next_storage_key(key)
+	}
+
+	fn next_child_storage_key(&mut self, child_info: &ChildInfo, key: &[u8]) -> Option<Vec<u8>> {
+		self.record(StorageAccessKind::NextKey, Some(child_info), key, 0);
+		self.inner.next_child_storage_key(child_info, key)
+	}
+
+	fn kill_child_storage(
+		&mut self,
+		child_info: &ChildInfo,
+		maybe_limit: Option<u32>,
+		maybe_cursor: Option<&[u8]>,
+	) -> MultiRemovalResults {
+		self.record(StorageAccessKind::ClearPrefix, Some(child_info), &[], 0);
+		self.inner.kill_child_storage(child_info, maybe_limit, maybe_cursor)
+	}
+
+	fn clear_prefix(
+		&mut self,
+		prefix: &[u8],
+		maybe_limit: Option<u32>,
+		maybe_cursor: Option<&[u8]>,
+	) -> MultiRemovalResults {
+		self.record(StorageAccessKind::ClearPrefix, None, prefix, 0);
+		self.inner.clear_prefix(prefix, maybe_limit, maybe_cursor)
+	}
+
+	fn clear_child_prefix(
+		&mut self,
+		child_info: &ChildInfo,
+		prefix: &[u8],
+		maybe_limit: Option<u32>,
+		maybe_cursor: Option<&[u8]>,
+	) -> MultiRemovalResults {
+		self.record(StorageAccessKind::ClearPrefix, Some(child_info), prefix, 0);
+		self.inner.clear_child_prefix(child_info, prefix, maybe_limit, maybe_cursor)
+	}
+
+	fn place_storage(&mut self, key: Vec<u8>, value: Option<Vec<u8>>) {
+		match value {
+			Some(ref value) => self.record(StorageAccessKind::Write, None, &key, value.len()),
+			None => self.record(StorageAccessKind::Remove, None, &key, 0),
+		}
+		self.inner.place_storage(key, value)
+	}
+
+	fn place_child_storage(
+		&mut self,
+		child_info: &ChildInfo,
+		key: Vec<u8>,
+		value: Option<Vec<u8>>,
+	) {
+		match value {
+			Some(ref value) =>
+				self.record(StorageAccessKind::Write, Some(child_info), &key, value.len()),
+			None => self.record(StorageAccessKind::Remove, Some(child_info), &key, 0),
+		}
+		self.inner.place_child_storage(child_info, key, value)
+	}
+
+	fn storage_root(&mut self, state_version: StateVersion) -> Vec<u8> {
+		self.inner.storage_root(state_version)
+	}
+
+	fn child_storage_root(
+		&mut self,
+		child_info: &ChildInfo,
+		state_version: StateVersion,
+	) -> Vec<u8> {
+		self.inner.child_storage_root(child_info, state_version)
+	}
+
+	fn storage_append(&mut self, key: Vec<u8>, value: Vec<u8>) {
+		self.record(StorageAccessKind::Write, None, &key, value.len());
+		self.inner.storage_append(key, value)
+	}
+
+	fn storage_start_transaction(&mut self) {
+		self.layer += 1;
+		self.inner.storage_start_transaction()
+	}
+
+	fn storage_rollback_transaction(&mut self) -> Result<(), ()> {
+		self.layer = self.layer.saturating_sub(1);
+		self.inner.storage_rollback_transaction()
+	}
+
+	fn storage_commit_transaction(&mut self) -> Result<(), ()> {
+		self.layer = self.layer.saturating_sub(1);
+		self.inner.storage_commit_transaction()
+	}
+
+	fn storage_index_transaction(&mut self, index: u32, hash: &[u8], size: u32) {
+		self.inner.storage_index_transaction(index, hash, size)
+	}
+
+	fn storage_renew_transaction_index(&mut self, index: u32, hash: &[u8]) {
+		self.inner.storage_renew_transaction_index(index, hash)
+	}
+
+	fn wipe(&mut self) {
+		self.inner.wipe()
+	}
+
+	fn commit(&mut self) {
+		self.inner.commit()
+	}
+
+	fn read_write_count(&self) -> (u32, u32, u32, u32) {
+		self.inner.read_write_count()
+	}
+
+	fn reset_read_write_count(&mut self) {
+		self.inner.reset_read_write_count()
+	}
+
+	fn get_whitelist(&self) -> Vec<TrackedStorageKey> {
+		self.inner.get_whitelist()
+	}
+
+	fn set_whitelist(&mut self, new: Vec<TrackedStorageKey>) {
+		self.inner.set_whitelist(new)
+	}
+
+	fn proof_size(&self) -> Option<u32> {
+		self.inner.proof_size()
+	}
+
+	fn get_read_and_written_keys(&self) -> Vec<(Vec<u8>, u32, u32, bool)> {
+		self.inner.get_read_and_written_keys()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::storage::{transactional::with_storage_layer, unhashed};
+	use sp_io::TestExternalities;
+	use sp_runtime::DispatchError;
+
+	#[test]
+	fn reads_and_writes_are_recorded() {
+		TestExternalities::default().execute_with(|| {
+			unhashed::put(b"a", &1u32);
+
+			let (value, log) = record_storage_accesses(|| {
+				unhashed::put(b"b", &2u64);
+				unhashed::kill(b"a");
+				unhashed::get::<u64>(b"b")
+			});
+
+			assert_eq!(value, Some(2));
+			assert_eq!(
+				log.accesses(),
+				&[
+					StorageAccess {
+						kind: StorageAccessKind::Write,
+						key: b"b".to_vec(),
+						child: None,
+						size: 8,
+						layer: 0,
+					},
+					StorageAccess {
+						kind: StorageAccessKind::Remove,
+						key: b"a".to_vec(),
+						child: None,
+						size: 0,
+						layer: 0,
+					},
+					StorageAccess {
+						kind: StorageAccessKind::Read,
+						key: b"b".to_vec(),
+						child: None,
+						size: 8,
+						layer: 0,
+					},
+				]
+			);
+			assert_eq!((log.reads(), log.writes()), (1, 2));
+			assert_eq!((log.read_size(), log.write_size()), (8, 8));
+
+			// The changes are applied to the recorded externalities.
+			assert_eq!(unhashed::get::<u32>(b"a"), None);
+			assert_eq!(unhashed::get::<u64>(b"b"), Some(2));
+		});
+	}
+
+	#[test]
+	fn transactional_layers_are_recorded() {
+		TestExternalities::default().execute_with(|| {
+			let ((), log) = record_storage_accesses(|| {
+				unhashed::put(b"a", &1u32);
+				let _ = with_storage_layer::<(), DispatchError, _>(|| {
+					unhashed::put(b"b", &1u32);
+					with_storage_layer::<(), DispatchError, _>(|| {
+						unhashed::put(b"c", &1u32);
+						Err(DispatchError::Other("rollback"))
+					})
+				});
+				unhashed::put(b"d", &1u32);
+			});
+
+			let layers =
+				log.accesses().iter().map(|a| (a.key.clone(), a.layer)).collect::<Vec<_>>();
+			assert_eq!(
+				layers,
+				vec![
+					(b"a".to_vec(), 0),
+					(b"b".to_vec(), 1),
+					(b"c".to_vec(), 2),
+					(b"d".to_vec(), 0)
+				]
+			);
+			// The rolled back writes are recorded as well.
+			assert_eq!(unhashed::get::<u32>(b"c"), None);
+		});
+	}
+
+	#[test]
+	fn iterations_are_recorded() {
+		TestExternalities::default().execute_with(|| {
+			for i in 0u8..10 {
+				unhashed::put(&[b'p', i], &i);
+			}
+
+			let (count, log) = record_storage_accesses(|| {
+				crate::storage::KeyPrefixIterator::new(b"p".to_vec(), b"p".to_vec(), |_| Ok(()))
+					.count()
+			});
+
+			assert_eq!(count, 10);
+			log.assert_max_iterations(11);
+			log.assert_max_reads(0);
+			log.assert_no_access_with_prefix(b"q");
+		});
+	}
+
+	#[test]
+	#[should_panic(expected = "expected at most 1 writes")]
+	fn assert_max_writes_panics_when_exceeded() {
+		TestExternalities::default().execute_with(|| {
+			let ((), log) = record_storage_accesses(|| {
+				unhashed::put(b"a", &1u32);
+				unhashed::put(b"b", &1u32);
+			});
+
+			log.assert_max_writes(1);
+		});
+	}
+
+	#[test]
+	#[should_panic(expected = "expected at most 1 accesses with prefix 0x61")]
+	fn assert_max_accesses_with_prefix_panics_when_exceeded() {
+		TestExternalities::default().execute_with(|| {
+			let ((), log) = record_storage_accesses(|| {
+				unhashed::put(b"a1", &1u32);
+				unhashed::put(b"a2", &1u32);
+				unhashed::put(b"b", &1u32);
+			});
+
+			log.assert_max_accesses_with_prefix(b"b", 1);
+			log.assert_max_accesses_with_prefix(b"a", 1);
+		});
+	}
+}
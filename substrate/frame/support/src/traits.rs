@@ -22,9 +22,9 @@
 pub mod tokens;
 pub use tokens::{
 	currency::{
-		ActiveIssuanceOf, Currency, InspectLockableCurrency, LockIdentifier, LockableCurrency,
-		NamedReservableCurrency, ReservableCurrency, TotalIssuanceOf, VestedTransfer,
-		VestingSchedule,
+		ActiveIssuanceOf, Currency, DepositChange, HeldDeposits, InspectLockableCurrency,
+		LockIdentifier, LockableCurrency, ManagedDeposit, NamedReservableCurrency,
+		ReservableCurrency, TotalIssuanceOf, TrackDeposit, VestedTransfer, VestingSchedule,
 	},
 	fungible, fungibles,
 	imbalance::{Imbalance, OnUnbalanced, SignedImbalance},
@@ -91,6 +91,9 @@ pub use hooks::{
 	PostTransactions, PreInherents, UncheckedOnRuntimeUpgrade,
 };
 
+mod idle_tasks;
+pub use idle_tasks::{IdleTask, IdleTasks};
+
 pub mod schedule;
 mod storage;
 #[cfg(feature = "experimental")]
@@ -105,8 +108,9 @@ mod dispatch;
 pub use dispatch::EnsureOneOf;
 pub use dispatch::{
 	AsEnsureOriginWithArg, CallerTrait, EitherOf, EitherOfDiverse, EnsureOrigin,
-	EnsureOriginEqualOrHigherPrivilege, EnsureOriginWithArg, MapSuccess, NeverEnsureOrigin,
-	OriginTrait, TryMapSuccess, TryWithMorphedArg, UnfilteredDispatchable,
+	EnsureOriginEqualOrHigherPrivilege, EnsureOriginWithArg, InspectNestedCalls, MapSuccess,
+	NestingTooDeep, NeverEnsureOrigin, OriginTrait, TryMapSuccess, TryWithMorphedArg,
+	UnfilteredDispatchable,
 };
 
 mod voting;
@@ -117,9 +121,9 @@ pub use preimages::{Bounded, BoundedInline, FetchResult, QueryPreimage, StorePre
 
 mod messages;
 pub use messages::{
-	EnqueueMessage, EnqueueWithOrigin, ExecuteOverweightError, HandleMessage, NoopServiceQueues,
-	ProcessMessage, ProcessMessageError, QueueFootprint, QueuePausedQuery, ServiceQueues,
-	TransformOrigin,
+	ConstServiceWeightShare, EnqueueMessage, EnqueueWithOrigin, ExecuteOverweightError,
+	HandleMessage, NoopServiceQueues, ProcessMessage, ProcessMessageError, QueueFootprint,
+	QueuePausedQuery, QueueServiceWeightShare, ServiceQueues, TransformOrigin,
 };
 
 mod safe_mode;
@@ -128,6 +132,9 @@ pub use safe_mode::{SafeMode, SafeModeError, SafeModeNotify};
 mod tx_pause;
 pub use tx_pause::{TransactionPause, TransactionPauseError};
 
+mod identity;
+pub use identity::IdentityVerifier;
+
 pub mod dynamic_params;
 
 pub mod tasks;
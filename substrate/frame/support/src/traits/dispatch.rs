@@ -18,6 +18,7 @@
 //! Traits for dealing with dispatching calls and the origin from which they are dispatched.
 
 use crate::dispatch::{DispatchResultWithPostInfo, Parameter, RawOrigin};
+use alloc::vec::Vec;
 use codec::MaxEncodedLen;
 use core::{cmp::Ordering, marker::PhantomData};
 use sp_runtime::{
@@ -449,6 +450,63 @@ pub trait UnfilteredDispatchable {
 	fn dispatch_bypass_filter(self, origin: Self::RuntimeOrigin) -> DispatchResultWithPostInfo;
 }
 
+/// Returned by [`InspectNestedCalls::any_nested_call`] if the calls are nested deeper than the
+/// given limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NestingTooDeep;
+
+/// Inspects the calls nested in wrapper calls, e.g. the calls of a batch or the call dispatched
+/// through a proxy.
+///
+/// Implemented by the pallets providing wrapper calls and for tuples of them, so that a runtime can
+/// inspect every wrapper call it contains.
+pub trait InspectNestedCalls<Call> {
+	/// The calls directly nested in `call`.
+	///
+	/// Empty if `call` is not a wrapper call known to the implementation.
+	fn nested_calls(call: &Call) -> Vec<&Call>;
+
+	/// Whether `predicate` holds for `call` or any of the calls nested in it.
+	///
+	/// Wrapper calls are inspected up to `max_depth` levels below `call`. If a wrapper call at
+	/// that depth still nests further calls, [`NestingTooDeep`] is returned, since those calls
+	/// could not be inspected.
+	fn any_nested_call(
+		call: &Call,
+		max_depth: u32,
+		predicate: impl Fn(&Call) -> bool,
+	) -> Result<bool, NestingTooDeep> {
+		let mut to_visit = Vec::from([(call, 0u32)]);
+		while let Some((call, depth)) = to_visit.pop() {
+			if predicate(call) {
+				return Ok(true)
+			}
+			let nested = Self::nested_calls(call);
+			if nested.is_empty() {
+				continue
+			}
+			if depth >= max_depth {
+				return Err(NestingTooDeep)
+			}
+			to_visit.extend(nested.into_iter().map(|nested| (nested, depth + 1)));
+		}
+		Ok(false)
+	}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(64)]
+impl<Call> InspectNestedCalls<Call> for Tuple {
+	fn nested_calls(call: &Call) -> Vec<&Call> {
+		for_tuples!( #(
+			let nested = Tuple::nested_calls(call);
+			if !nested.is_empty() {
+				return nested
+			}
+		)* );
+		Vec::new()
+	}
+}
+
 /// The trait implemented by the overarching enumeration of the different pallets' origins.
 /// Unlike `OriginTrait` impls, this does not include any kind of dispatch/call filter. Also, this
 /// trait is more flexible in terms of how it can be used: it is a `Parameter` and `Member`, so it
@@ -0,0 +1,49 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Traits for querying the verification of on-chain identities.
+
+/// Tells whether the identity of an account is verified to some level.
+///
+/// Lets pallets gate actions on identity verification without depending on how identities are
+/// stored.
+pub trait IdentityVerifier<AccountId> {
+	/// A level of verification, e.g. a judgement of a registrar.
+	type Level;
+
+	/// A party that verifies identities, e.g. the index of a registrar.
+	type Verifier;
+
+	/// Whether the identity of `who` is verified to at least `level` by any of `verifiers`.
+	///
+	/// Should return `false` if `verifiers` is empty.
+	fn has_identity_level(
+		who: &AccountId,
+		level: &Self::Level,
+		verifiers: &[Self::Verifier],
+	) -> bool;
+}
+
+/// Verifies no identity at all.
+impl<AccountId> IdentityVerifier<AccountId> for () {
+	type Level = ();
+	type Verifier = ();
+
+	fn has_identity_level(_: &AccountId, _: &(), _: &[()]) -> bool {
+		false
+	}
+}
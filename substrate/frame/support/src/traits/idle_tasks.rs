@@ -0,0 +1,171 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Background tasks which are run with the weight left at the end of a block.
+//!
+//! Pallets often have work which does not need to happen at a specific point in time, e.g. lazily
+//! deleting storage, pruning queues or re-pricing deposits. Instead of each pallet implementing
+//! its own loop in [`Hooks::on_idle`](super::Hooks::on_idle), the work can be split into
+//! [`IdleTask`]s which are run by a tuple implementing [`IdleTasks`]:
+//!
+//! - Tasks with a higher [`IdleTask::priority`] are run first.
+//! - Every task is limited to its [`IdleTask::max_weight`] per block, so that one task cannot
+//!   starve the others.
+//! - Tasks with the same priority take turns in being run first, depending on the block number.
+
+use alloc::vec::Vec;
+use impl_trait_for_tuples::impl_for_tuples;
+use sp_runtime::traits::{AtLeast32BitUnsigned, Zero};
+use sp_weights::{Weight, WeightMeter};
+
+/// A background task which is run when a block has weight left.
+pub trait IdleTask<BlockNumber> {
+	/// The priority of the task. Tasks with a higher priority are run first.
+	fn priority() -> u8 {
+		0
+	}
+
+	/// The maximum weight the task may consume in a single block.
+	fn max_weight() -> Weight;
+
+	/// Run the task in block `n`.
+	///
+	/// The task must not consume more weight than `meter` allows and should return once it has
+	/// no more work to do or not enough weight left to make progress.
+	fn run(n: BlockNumber, meter: &mut WeightMeter);
+}
+
+/// A set of [`IdleTask`]s, implemented for tuples of them.
+pub trait IdleTasks<BlockNumber> {
+	/// Run the tasks in block `n`, consuming their weight from `meter`.
+	fn run_idle_tasks(n: BlockNumber, meter: &mut WeightMeter);
+
+	/// Run the tasks in block `n` with `remaining_weight`, as done by
+	/// [`Hooks::on_idle`](super::Hooks::on_idle).
+	///
+	/// Returns the weight consumed.
+	fn on_idle(n: BlockNumber, remaining_weight: Weight) -> Weight {
+		let mut meter = WeightMeter::with_limit(remaining_weight);
+		Self::run_idle_tasks(n, &mut meter);
+		meter.consumed()
+	}
+}
+
+#[cfg_attr(all(not(feature = "tuples-96"), not(feature = "tuples-128")), impl_for_tuples(64))]
+#[cfg_attr(all(feature = "tuples-96", not(feature = "tuples-128")), impl_for_tuples(96))]
+#[cfg_attr(feature = "tuples-128", impl_for_tuples(128))]
+impl<BlockNumber: Copy + AtLeast32BitUnsigned> IdleTasks<BlockNumber> for Tuple {
+	fn run_idle_tasks(n: BlockNumber, meter: &mut WeightMeter) {
+		let mut tasks: Vec<(u8, fn() -> Weight, fn(BlockNumber, &mut WeightMeter))> =
+			Vec::from([for_tuples!( #( (
+				Tuple::priority(),
+				Tuple::max_weight as fn() -> Weight,
+				Tuple::run as fn(BlockNumber, &mut WeightMeter),
+			) ),* )]);
+		// The sort is stable, so tasks of the same priority keep their order.
+		tasks.sort_by(|a, b| b.0.cmp(&a.0));
+
+		// Rotate every group of tasks with the same priority, so that they take turns in being
+		// run first.
+		let mut start = 0;
+		while start < tasks.len() {
+			let priority = tasks[start].0;
+			let len = tasks[start..].iter().take_while(|(p, _, _)| *p == priority).count();
+			let rotation = (n % (len as u32).into())
+				.try_into()
+				.ok()
+				.expect("`n % len` always fits into `usize`, because `len` is a `usize`; qed");
+			tasks[start..start + len].rotate_left(rotation);
+			start += len;
+		}
+
+		for (_, max_weight, run) in tasks {
+			let limit = max_weight().min(meter.remaining());
+			if limit.is_zero() {
+				continue
+			}
+			let mut task_meter = WeightMeter::with_limit(limit);
+			run(n, &mut task_meter);
+			meter.consume(task_meter.consumed());
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	crate::parameter_types! {
+		pub static Runs: Vec<(&'static str, Weight)> = Default::default();
+	}
+
+	macro_rules! impl_test_task {
+		($name:ident, $priority:expr, $max_weight:expr, $consume:expr) => {
+			struct $name;
+			impl IdleTask<u32> for $name {
+				fn priority() -> u8 {
+					$priority
+				}
+				fn max_weight() -> Weight {
+					Weight::from_parts($max_weight, 0)
+				}
+				fn run(_n: u32, meter: &mut WeightMeter) {
+					let wanted = Weight::from_parts($consume, 0);
+					let used = wanted.min(meter.remaining());
+					meter.consume(used);
+					Runs::mutate(|r| r.push((stringify!($name), used)));
+				}
+			}
+		};
+	}
+
+	impl_test_task!(Low, 0, 100, 50);
+	impl_test_task!(HighA, 1, 10, 20);
+	impl_test_task!(HighB, 1, 30, 30);
+
+	#[test]
+	fn tasks_run_by_priority_within_their_budget() {
+		let consumed = <(Low, HighA, HighB)>::on_idle(0, Weight::from_parts(100, 100));
+
+		assert_eq!(consumed, Weight::from_parts(90, 0));
+		assert_eq!(
+			Runs::take(),
+			vec![
+				("HighA", Weight::from_parts(10, 0)),
+				("HighB", Weight::from_parts(30, 0)),
+				("Low", Weight::from_parts(50, 0)),
+			]
+		);
+	}
+
+	#[test]
+	fn tasks_of_the_same_priority_take_turns() {
+		<(Low, HighA, HighB)>::on_idle(1, Weight::from_parts(35, 100));
+
+		// `HighB` goes first in odd blocks and nothing is left for `Low`.
+		assert_eq!(
+			Runs::take(),
+			vec![("HighB", Weight::from_parts(30, 0)), ("HighA", Weight::from_parts(5, 0)),]
+		);
+	}
+
+	#[test]
+	fn tasks_are_skipped_without_weight() {
+		assert_eq!(<(Low, HighA)>::on_idle(0, Weight::zero()), Weight::zero());
+		assert!(Runs::take().is_empty());
+	}
+}
@@ -22,7 +22,7 @@ use codec::{Decode, Encode, FullCodec, MaxEncodedLen};
 use core::{fmt::Debug, marker::PhantomData};
 use scale_info::TypeInfo;
 use sp_core::{ConstU32, Get, TypedGet};
-use sp_runtime::{traits::Convert, BoundedSlice, RuntimeDebug};
+use sp_runtime::{traits::Convert, BoundedSlice, Perbill, RuntimeDebug};
 use sp_weights::{Weight, WeightMeter};
 
 /// Errors that can happen when attempting to process a message with
@@ -259,3 +259,29 @@ impl<Origin> QueuePausedQuery<Origin> for Tuple {
 		false
 	}
 }
+
+/// Provides the share of the servicing weight that a single queue may use.
+///
+/// A queue with a share may not consume more than this fraction of the weight which is
+/// available to a servicing round, as long as other queues are waiting to be serviced as well.
+/// This prevents a single busy queue from delaying all others.
+pub trait QueueServiceWeightShare<Origin> {
+	/// The share of the servicing weight that the queue of `origin` may use.
+	///
+	/// `None` means that the queue is not limited.
+	fn weight_share(origin: &Origin) -> Option<Perbill>;
+}
+
+impl<Origin> QueueServiceWeightShare<Origin> for () {
+	fn weight_share(_: &Origin) -> Option<Perbill> {
+		None
+	}
+}
+
+/// Limits every queue to the same share `S` of the servicing weight.
+pub struct ConstServiceWeightShare<S>(PhantomData<S>);
+impl<Origin, S: Get<Perbill>> QueueServiceWeightShare<Origin> for ConstServiceWeightShare<S> {
+	fn weight_share(_: &Origin) -> Option<Perbill> {
+		Some(S::get())
+	}
+}
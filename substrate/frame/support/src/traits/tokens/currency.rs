@@ -27,6 +27,8 @@ use super::{
 use crate::{dispatch::DispatchResult, traits::Get};
 use sp_runtime::{traits::MaybeSerializeDeserialize, DispatchError};
 
+mod managed_deposit;
+pub use managed_deposit::{DepositChange, HeldDeposits, ManagedDeposit, TrackDeposit};
 mod reservable;
 pub use reservable::{NamedReservableCurrency, ReservableCurrency};
 mod lockable;
@@ -0,0 +1,473 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A helper for managing deposits held with a [`ReservableCurrency`].
+
+use super::ReservableCurrency;
+use crate::{defensive, dispatch::DispatchResult, traits::tokens::BalanceStatus, weights::Weight};
+use codec::{FullCodec, MaxEncodedLen};
+use core::marker::PhantomData;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	DispatchError, RuntimeDebug,
+};
+
+/// How a deposit changed when it was updated with [`ManagedDeposit::rejig`].
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+pub enum DepositChange<Balance> {
+	/// The deposit did not change.
+	Unchanged,
+	/// The deposit was increased from `old` to `new`.
+	Increased { old: Balance, new: Balance },
+	/// The deposit was decreased from `old` to `new`.
+	Decreased { old: Balance, new: Balance },
+}
+
+impl<Balance: Copy> DepositChange<Balance> {
+	/// The deposit held after the change, `None` if it did not change.
+	pub fn new_deposit(&self) -> Option<Balance> {
+		match self {
+			Self::Unchanged => None,
+			Self::Increased { new, .. } | Self::Decreased { new, .. } => Some(*new),
+		}
+	}
+
+	/// Whether the deposit changed.
+	pub fn is_changed(&self) -> bool {
+		!matches!(self, Self::Unchanged)
+	}
+}
+
+/// Keeps track of the deposits held through [`ManagedDeposit`], e.g. to account for the deposits
+/// of an account across pallets.
+pub trait TrackDeposit<AccountId, Balance> {
+	/// `amount` was held as deposit of `who`.
+	///
+	/// Returning an error makes the hold fail and the deposit is released again.
+	fn on_hold(who: &AccountId, amount: Balance) -> DispatchResult;
+
+	/// `amount` of the deposit of `who` was released, slashed or repatriated.
+	fn on_release(who: &AccountId, amount: Balance);
+}
+
+impl<AccountId, Balance> TrackDeposit<AccountId, Balance> for () {
+	fn on_hold(_: &AccountId, _: Balance) -> DispatchResult {
+		Ok(())
+	}
+
+	fn on_release(_: &AccountId, _: Balance) {}
+}
+
+/// Enumerates the deposits a pallet already holds, e.g. to seed a [`TrackDeposit`] which was
+/// configured after the deposits were held.
+pub trait HeldDeposits<AccountId, Balance> {
+	/// The position of the enumeration.
+	type Cursor: FullCodec + MaxEncodedLen;
+
+	/// The entry following `cursor`, or the first entry if `cursor` is `None`.
+	///
+	/// Returns the cursor of the entry along with the account holding its deposit and the amount,
+	/// which is `None` if the deposit of the entry cannot be attributed to an account. Returns
+	/// `None` once every entry was visited.
+	fn next_deposit(
+		cursor: Option<Self::Cursor>,
+	) -> Option<(Self::Cursor, Option<(AccountId, Balance)>)>;
+
+	/// The maximum weight of a single [`Self::next_deposit`].
+	fn next_deposit_weight() -> Weight;
+}
+
+/// Holds, releases and updates deposits of `AccountId`s in `Currency`.
+///
+/// This wraps the reserve and unreserve logic pallets need for their deposits, so that the
+/// handling of edge cases is shared between them:
+/// - Holding or releasing a zero deposit is a no-op.
+/// - Releasing more than is reserved is reported defensively, since it means the pallet's
+///   bookkeeping is off, and only the amount actually released is returned.
+/// - Updating a deposit only reserves or unreserves the difference, and either fails when the
+///   account cannot afford the increase ([`Self::rejig`]) or holds as much as it can
+///   ([`Self::rejig_best_effort`]).
+///
+/// The returned [`DepositChange`] can be used by the pallet to emit an event.
+///
+/// Every change of the held deposits is reported to `Tracker`.
+pub struct ManagedDeposit<AccountId, Currency, Tracker = ()>(
+	PhantomData<(AccountId, Currency, Tracker)>,
+);
+
+impl<AccountId, Currency, Tracker> ManagedDeposit<AccountId, Currency, Tracker>
+where
+	Currency: ReservableCurrency<AccountId>,
+	Tracker: TrackDeposit<AccountId, Currency::Balance>,
+{
+	/// Hold `amount` as deposit of `who`.
+	pub fn hold(who: &AccountId, amount: Currency::Balance) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		Currency::reserve(who, amount)?;
+		Tracker::on_hold(who, amount).inspect_err(|_| {
+			Currency::unreserve(who, amount);
+		})
+	}
+
+	/// Release `amount` of the deposit of `who`.
+	///
+	/// Returns the amount that was actually released.
+	pub fn release(who: &AccountId, amount: Currency::Balance) -> Currency::Balance {
+		if amount.is_zero() {
+			return amount
+		}
+		let not_released = Currency::unreserve(who, amount);
+		if !not_released.is_zero() {
+			defensive!("Released deposit is greater than the reserved balance");
+		}
+		let released = amount.saturating_sub(not_released);
+		Tracker::on_release(who, released);
+		released
+	}
+
+	/// Slash `amount` of the deposit of `who`.
+	///
+	/// Returns the negative imbalance of the slash and the amount that could not be slashed.
+	pub fn slash(
+		who: &AccountId,
+		amount: Currency::Balance,
+	) -> (Currency::NegativeImbalance, Currency::Balance) {
+		let (imbalance, not_slashed) = Currency::slash_reserved(who, amount);
+		Tracker::on_release(who, amount.saturating_sub(not_slashed));
+		(imbalance, not_slashed)
+	}
+
+	/// Move `amount` of the deposit of `who` to the free balance of `to`.
+	///
+	/// Returns the amount that could not be moved.
+	pub fn repatriate(
+		who: &AccountId,
+		to: &AccountId,
+		amount: Currency::Balance,
+	) -> Result<Currency::Balance, DispatchError> {
+		let not_moved = Currency::repatriate_reserved(who, to, amount, BalanceStatus::Free)?;
+		Tracker::on_release(who, amount.saturating_sub(not_moved));
+		Ok(not_moved)
+	}
+
+	/// Move `amount` of the deposit of `who` to the deposit of `to`.
+	///
+	/// Fails without any change if `Tracker` rejects the deposit of `to`. Returns the amount that
+	/// could not be moved.
+	pub fn transfer(
+		who: &AccountId,
+		to: &AccountId,
+		amount: Currency::Balance,
+	) -> Result<Currency::Balance, DispatchError> {
+		if amount.is_zero() {
+			return Ok(amount)
+		}
+		Tracker::on_hold(to, amount)?;
+		let not_moved = Currency::repatriate_reserved(who, to, amount, BalanceStatus::Reserved)
+			.inspect_err(|_| Tracker::on_release(to, amount))?;
+		Tracker::on_release(who, amount.saturating_sub(not_moved));
+		if !not_moved.is_zero() {
+			Tracker::on_release(to, not_moved);
+		}
+		Ok(not_moved)
+	}
+
+	/// Update the deposit of `who` from `old` to `new`.
+	///
+	/// Only the difference is reserved or unreserved. Fails without any change if `who` cannot
+	/// afford an increase.
+	pub fn rejig(
+		who: &AccountId,
+		old: Currency::Balance,
+		new: Currency::Balance,
+	) -> Result<DepositChange<Currency::Balance>, DispatchError> {
+		if new > old {
+			Self::hold(who, new - old)?;
+			Ok(DepositChange::Increased { old, new })
+		} else if new < old {
+			let released = Self::release(who, old - new);
+			Ok(DepositChange::Decreased { old, new: old.saturating_sub(released) })
+		} else {
+			Ok(DepositChange::Unchanged)
+		}
+	}
+
+	/// Update the deposit of `who` from `old` to `new`, holding as much as possible if `who`
+	/// cannot afford an increase.
+	///
+	/// This is meant for updates which cannot fail, e.g. when re-pricing deposits in a migration
+	/// or by a permissionless call. The deposit actually held is available through
+	/// [`DepositChange::new_deposit`].
+	pub fn rejig_best_effort(
+		who: &AccountId,
+		old: Currency::Balance,
+		new: Currency::Balance,
+	) -> DepositChange<Currency::Balance> {
+		if new <= old {
+			return Self::rejig(who, old, new).unwrap_or(DepositChange::Unchanged)
+		}
+
+		let wanted = new - old;
+		if Self::hold(who, wanted).is_ok() {
+			return DepositChange::Increased { old, new }
+		}
+
+		// Keep the account alive, the reserved balance alone does not.
+		let affordable = Currency::free_balance(who)
+			.saturating_sub(Currency::minimum_balance())
+			.min(wanted);
+		if affordable.is_zero() || Self::hold(who, affordable).is_err() {
+			return DepositChange::Unchanged
+		}
+		DepositChange::Increased { old, new: old.saturating_add(affordable) }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::traits::{
+		tokens::{currency::Currency, BalanceStatus},
+		ExistenceRequirement, SignedImbalance, WithdrawReasons,
+	};
+	use core::cell::RefCell;
+	use sp_runtime::DispatchError;
+
+	std::thread_local! {
+		static BALANCES: RefCell<(u64, u64)> = RefCell::new((100, 0));
+	}
+
+	fn balances() -> (u64, u64) {
+		BALANCES.with(|b| *b.borrow())
+	}
+
+	fn set_balances(free: u64, reserved: u64) {
+		BALANCES.with(|b| *b.borrow_mut() = (free, reserved));
+	}
+
+	/// A currency with a single account having a free and a reserved balance.
+	struct MockCurrency;
+
+	impl Currency<u64> for MockCurrency {
+		type Balance = u64;
+		type PositiveImbalance = ();
+		type NegativeImbalance = ();
+
+		fn total_balance(_: &u64) -> u64 {
+			let (free, reserved) = balances();
+			free + reserved
+		}
+		fn can_slash(_: &u64, _: u64) -> bool {
+			unimplemented!()
+		}
+		fn total_issuance() -> u64 {
+			unimplemented!()
+		}
+		fn minimum_balance() -> u64 {
+			10
+		}
+		fn burn(_: u64) -> () {}
+		fn issue(_: u64) -> () {}
+		fn free_balance(_: &u64) -> u64 {
+			balances().0
+		}
+		fn ensure_can_withdraw(_: &u64, _: u64, _: WithdrawReasons, _: u64) -> DispatchResult {
+			unimplemented!()
+		}
+		fn transfer(_: &u64, _: &u64, _: u64, _: ExistenceRequirement) -> DispatchResult {
+			unimplemented!()
+		}
+		fn slash(_: &u64, _: u64) -> ((), u64) {
+			unimplemented!()
+		}
+		fn deposit_into_existing(_: &u64, _: u64) -> Result<(), DispatchError> {
+			unimplemented!()
+		}
+		fn deposit_creating(_: &u64, _: u64) -> () {}
+		fn withdraw(
+			_: &u64,
+			_: u64,
+			_: WithdrawReasons,
+			_: ExistenceRequirement,
+		) -> Result<(), DispatchError> {
+			unimplemented!()
+		}
+		fn make_free_balance_be(_: &u64, _: u64) -> SignedImbalance<u64, ()> {
+			unimplemented!()
+		}
+	}
+
+	impl ReservableCurrency<u64> for MockCurrency {
+		fn can_reserve(_: &u64, value: u64) -> bool {
+			balances().0 >= value
+		}
+		fn slash_reserved(_: &u64, _: u64) -> ((), u64) {
+			unimplemented!()
+		}
+		fn reserved_balance(_: &u64) -> u64 {
+			balances().1
+		}
+		fn reserve(who: &u64, value: u64) -> DispatchResult {
+			if !Self::can_reserve(who, value) {
+				return Err(DispatchError::Other("InsufficientBalance"))
+			}
+			let (free, reserved) = balances();
+			set_balances(free - value, reserved + value);
+			Ok(())
+		}
+		fn unreserve(_: &u64, value: u64) -> u64 {
+			let (free, reserved) = balances();
+			let actual = value.min(reserved);
+			set_balances(free + actual, reserved - actual);
+			value - actual
+		}
+		fn repatriate_reserved(
+			_: &u64,
+			_: &u64,
+			value: u64,
+			status: BalanceStatus,
+		) -> Result<u64, DispatchError> {
+			// There is a single account, so only the status of the balance can change.
+			let (free, reserved) = balances();
+			let actual = value.min(reserved);
+			if status == BalanceStatus::Free {
+				set_balances(free + actual, reserved - actual);
+			}
+			Ok(value - actual)
+		}
+	}
+
+	type Deposit = ManagedDeposit<u64, MockCurrency>;
+
+	std::thread_local! {
+		static TRACKED: RefCell<u64> = RefCell::new(0);
+	}
+
+	/// Tracks the deposits held by the single account and caps them at 50.
+	struct CappedTracker;
+
+	impl TrackDeposit<u64, u64> for CappedTracker {
+		fn on_hold(_: &u64, amount: u64) -> DispatchResult {
+			TRACKED.with(|t| {
+				let mut t = t.borrow_mut();
+				if *t + amount > 50 {
+					return Err(DispatchError::Other("CapExceeded"))
+				}
+				*t += amount;
+				Ok(())
+			})
+		}
+
+		fn on_release(_: &u64, amount: u64) {
+			TRACKED.with(|t| *t.borrow_mut() -= amount);
+		}
+	}
+
+	type TrackedDeposit = ManagedDeposit<u64, MockCurrency, CappedTracker>;
+
+	#[test]
+	fn hold_and_release_work() {
+		set_balances(100, 0);
+
+		assert_eq!(Deposit::hold(&1, 0), Ok(()));
+		assert_eq!(Deposit::hold(&1, 30), Ok(()));
+		assert_eq!(balances(), (70, 30));
+		assert!(Deposit::hold(&1, 71).is_err());
+		assert_eq!(balances(), (70, 30));
+
+		assert_eq!(Deposit::release(&1, 20), 20);
+		assert_eq!(balances(), (90, 10));
+	}
+
+	#[test]
+	#[cfg_attr(debug_assertions, should_panic)]
+	fn releasing_more_than_reserved_is_defensive() {
+		set_balances(90, 10);
+
+		assert_eq!(Deposit::release(&1, 20), 10);
+	}
+
+	#[test]
+	fn rejig_works() {
+		set_balances(100, 0);
+
+		assert_eq!(Deposit::rejig(&1, 0, 0), Ok(DepositChange::Unchanged));
+		assert_eq!(Deposit::rejig(&1, 0, 40), Ok(DepositChange::Increased { old: 0, new: 40 }));
+		assert_eq!(balances(), (60, 40));
+		assert_eq!(Deposit::rejig(&1, 40, 15), Ok(DepositChange::Decreased { old: 40, new: 15 }));
+		assert_eq!(balances(), (85, 15));
+
+		// Cannot afford the increase.
+		assert!(Deposit::rejig(&1, 15, 115).is_err());
+		assert_eq!(balances(), (85, 15));
+	}
+
+	#[test]
+	fn rejig_best_effort_holds_what_is_affordable() {
+		set_balances(50, 10);
+
+		assert_eq!(
+			Deposit::rejig_best_effort(&1, 10, 100),
+			DepositChange::Increased { old: 10, new: 50 }
+		);
+		// The minimum balance is kept free.
+		assert_eq!(balances(), (10, 50));
+
+		// Nothing more is affordable.
+		assert_eq!(Deposit::rejig_best_effort(&1, 50, 100), DepositChange::Unchanged);
+		assert_eq!(Deposit::rejig_best_effort(&1, 50, 20).new_deposit(), Some(20));
+		assert_eq!(balances(), (40, 20));
+	}
+
+	#[test]
+	fn tracker_sees_every_change_and_can_reject_holds() {
+		set_balances(100, 0);
+		TRACKED.with(|t| *t.borrow_mut() = 0);
+
+		assert_eq!(TrackedDeposit::hold(&1, 30), Ok(()));
+		assert_eq!(TRACKED.with(|t| *t.borrow()), 30);
+
+		// Rejected by the tracker, so the reserve is undone.
+		assert_eq!(TrackedDeposit::hold(&1, 30), Err(DispatchError::Other("CapExceeded")));
+		assert_eq!(balances(), (70, 30));
+
+		assert_eq!(
+			TrackedDeposit::rejig(&1, 30, 10),
+			Ok(DepositChange::Decreased { old: 30, new: 10 })
+		);
+		assert_eq!(TRACKED.with(|t| *t.borrow()), 10);
+		assert_eq!(TrackedDeposit::rejig_best_effort(&1, 10, 80), DepositChange::Unchanged);
+		assert_eq!(balances(), (90, 10));
+	}
+	#[test]
+	fn transfer_keeps_the_deposit_held_and_tracked() {
+		set_balances(100, 0);
+		TRACKED.with(|t| *t.borrow_mut() = 0);
+
+		assert_eq!(TrackedDeposit::hold(&1, 30), Ok(()));
+		// Rejected by the tracker for the receiver, so nothing moves.
+		assert_eq!(TrackedDeposit::transfer(&1, &2, 30), Err(DispatchError::Other("CapExceeded")));
+		assert_eq!(TRACKED.with(|t| *t.borrow()), 30);
+
+		assert_eq!(TrackedDeposit::transfer(&1, &2, 20), Ok(0));
+		assert_eq!(TRACKED.with(|t| *t.borrow()), 30);
+		assert_eq!(balances(), (70, 30));
+		assert_eq!(Deposit::transfer(&1, &2, 0), Ok(0));
+	}
+}
@@ -0,0 +1,250 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support for view functions.
+//!
+//! View functions are read-only queries that pallets expose to clients, so that clients don't
+//! need to know the storage layout of a pallet to query its state. Every view function is
+//! identified by a [`ViewFunctionId`], which is made up of the hashed name of the pallet and the
+//! hashed name of the view function. The runtime routes calls of the
+//! [`RuntimeViewFunction`](runtime_api::RuntimeViewFunction) runtime API by this id to the pallet
+//! implementing the view function, see [`DispatchViewFunction`].
+//!
+//! View functions are not generated by the pallet macro yet: a pallet implements
+//! [`ViewFunction`] for every query and [`DispatchViewFunction`] for itself by hand, and a
+//! runtime lists the pallets whose view functions it exposes when implementing the runtime API.
+//! View functions are not part of the metadata either, so clients must know the id, the argument
+//! and the return types of a view function from the pallet they query.
+
+use alloc::vec::Vec;
+use codec::{Decode, DecodeAll, Encode, MaxEncodedLen, Output};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+use crate::traits::PalletInfoAccess;
+
+/// The unique identifier of a view function.
+#[derive(
+	Clone, Copy, Encode, Decode, MaxEncodedLen, TypeInfo, RuntimeDebug, PartialEq, Eq, Hash,
+)]
+pub struct ViewFunctionId {
+	/// The two128 hash of the name of the pallet implementing the view function.
+	pub prefix: [u8; 16],
+	/// The two128 hash of the name of the view function.
+	pub suffix: [u8; 16],
+}
+
+impl ViewFunctionId {
+	/// The id of the view function `name` of the pallet `P`.
+	pub fn new<P: PalletInfoAccess>(name: &str) -> Self {
+		Self { prefix: P::name_hash(), suffix: sp_io::hashing::twox_128(name.as_bytes()) }
+	}
+}
+
+impl From<ViewFunctionId> for [u8; 32] {
+	fn from(id: ViewFunctionId) -> Self {
+		let mut output = [0u8; 32];
+		output[..16].copy_from_slice(&id.prefix);
+		output[16..].copy_from_slice(&id.suffix);
+		output
+	}
+}
+
+/// Error when dispatching a view function.
+#[derive(Clone, Encode, Decode, TypeInfo, RuntimeDebug, PartialEq, Eq)]
+pub enum ViewFunctionDispatchError {
+	/// The runtime does not implement view functions.
+	NotImplemented,
+	/// There is no view function with the given id.
+	NotFound(ViewFunctionId),
+	/// The input of the view function could not be decoded.
+	Codec,
+}
+
+impl From<codec::Error> for ViewFunctionDispatchError {
+	fn from(_: codec::Error) -> Self {
+		Self::Codec
+	}
+}
+
+/// A view function of a pallet.
+///
+/// The view function is invoked with the decoded value of the implementing type, which holds its
+/// arguments.
+pub trait ViewFunction: DecodeAll {
+	/// The type returned by the view function.
+	type ReturnType: Encode;
+
+	/// The id of the view function.
+	fn id() -> ViewFunctionId;
+
+	/// Invoke the view function.
+	fn invoke(self) -> Self::ReturnType;
+
+	/// Decode the arguments of the view function from `input`, invoke it and write its encoded
+	/// result into `output`.
+	fn execute<O: Output>(
+		input: &mut &[u8],
+		output: &mut O,
+	) -> Result<(), ViewFunctionDispatchError> {
+		let view_function = Self::decode_all(input)?;
+		view_function.invoke().encode_to(output);
+		Ok(())
+	}
+}
+
+/// Dispatches a view function by its id.
+///
+/// Implemented by pallets for their view functions and by tuples of pallets, which try to
+/// dispatch the view function with every member of the tuple. The tuple of all pallets exposing
+/// view functions is what a runtime passes to [`execute_view_function`].
+pub trait DispatchViewFunction {
+	/// Dispatch the view function `id` with the encoded arguments `input`, writing its encoded
+	/// result into `output`.
+	///
+	/// Returns [`ViewFunctionDispatchError::NotFound`] if no view function with the given `id` is
+	/// implemented.
+	fn dispatch_view_function<O: Output>(
+		id: &ViewFunctionId,
+		input: &mut &[u8],
+		output: &mut O,
+	) -> Result<(), ViewFunctionDispatchError>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(64)]
+impl DispatchViewFunction for Tuple {
+	fn dispatch_view_function<O: Output>(
+		id: &ViewFunctionId,
+		input: &mut &[u8],
+		output: &mut O,
+	) -> Result<(), ViewFunctionDispatchError> {
+		for_tuples!( #(
+			match Tuple::dispatch_view_function(id, input, output) {
+				Err(ViewFunctionDispatchError::NotFound(_)) => {},
+				result => return result,
+			}
+		)* );
+		Err(ViewFunctionDispatchError::NotFound(*id))
+	}
+}
+
+/// Execute the view function `id` implemented by one of the pallets `P` with the encoded
+/// arguments `input`.
+///
+/// Meant to implement the [`RuntimeViewFunction`](runtime_api::RuntimeViewFunction) runtime API.
+pub fn execute_view_function<P: DispatchViewFunction>(
+	id: ViewFunctionId,
+	input: Vec<u8>,
+) -> Result<Vec<u8>, ViewFunctionDispatchError> {
+	let mut output = Vec::new();
+	P::dispatch_view_function(&id, &mut &input[..], &mut output)?;
+	Ok(output)
+}
+
+/// The runtime API for view functions.
+pub mod runtime_api {
+	use super::*;
+
+	sp_api::decl_runtime_apis! {
+		/// Runtime API for executing view functions.
+		///
+		/// Besides calling it with `state_call`, clients can use the `state_callView` RPC.
+		pub trait RuntimeViewFunction {
+			/// Execute the view function `query_id` with the SCALE encoded arguments `input`.
+			///
+			/// Returns the SCALE encoded result of the view function.
+			fn execute_view_function(
+				query_id: ViewFunctionId,
+				input: Vec<u8>,
+			) -> Result<Vec<u8>, ViewFunctionDispatchError>;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::traits::CrateVersion;
+
+	struct Pallet<const INDEX: usize>;
+
+	impl<const INDEX: usize> PalletInfoAccess for Pallet<INDEX> {
+		fn index() -> usize {
+			INDEX
+		}
+		fn name() -> &'static str {
+			["First", "Second"][INDEX]
+		}
+		fn name_hash() -> [u8; 16] {
+			sp_io::hashing::twox_128(Self::name().as_bytes())
+		}
+		fn module_name() -> &'static str {
+			"view_functions"
+		}
+		fn crate_version() -> CrateVersion {
+			CrateVersion::new(1, 0, 0)
+		}
+	}
+
+	#[derive(Encode, Decode)]
+	struct Double<const INDEX: usize>(u32);
+
+	impl<const INDEX: usize> ViewFunction for Double<INDEX> {
+		type ReturnType = (usize, u32);
+
+		fn id() -> ViewFunctionId {
+			ViewFunctionId::new::<Pallet<INDEX>>("double")
+		}
+
+		fn invoke(self) -> Self::ReturnType {
+			(INDEX, self.0 * 2)
+		}
+	}
+
+	impl<const INDEX: usize> DispatchViewFunction for Pallet<INDEX> {
+		fn dispatch_view_function<O: Output>(
+			id: &ViewFunctionId,
+			input: &mut &[u8],
+			output: &mut O,
+		) -> Result<(), ViewFunctionDispatchError> {
+			if *id == Double::<INDEX>::id() {
+				return Double::<INDEX>::execute(input, output)
+			}
+			Err(ViewFunctionDispatchError::NotFound(*id))
+		}
+	}
+
+	type Pallets = (Pallet<0>, Pallet<1>);
+
+	#[test]
+	fn view_functions_are_routed_by_id() {
+		let execute = |id, input: u32| {
+			execute_view_function::<Pallets>(id, input.encode())
+				.map(|output| <(usize, u32)>::decode(&mut &output[..]).unwrap())
+		};
+
+		assert_eq!(execute(Double::<0>::id(), 2), Ok((0, 4)));
+		assert_eq!(execute(Double::<1>::id(), 3), Ok((1, 6)));
+
+		let unknown = ViewFunctionId::new::<Pallet<1>>("triple");
+		assert_eq!(execute(unknown, 3), Err(ViewFunctionDispatchError::NotFound(unknown)));
+		assert_eq!(
+			execute_view_function::<Pallets>(Double::<0>::id(), vec![1]),
+			Err(ViewFunctionDispatchError::Codec)
+		);
+	}
+}
@@ -20,7 +20,7 @@
 
 #![recursion_limit = "128"]
 
-use codec::Encode;
+use codec::{Decode, Encode};
 use frame_support::{derive_impl, traits::VariantCount};
 use sp_core::sr25519;
 use sp_runtime::{
@@ -272,3 +272,41 @@ fn check_unique_encodings_for_freeze_reason() {
 		variants.iter().map(|v| v.encode()).collect::<std::collections::HashSet<_>>();
 	assert_eq!(unique_encoded_variants.len(), variants.len());
 }
+
+#[test]
+fn runtime_reasons_describe_every_variant() {
+	use frame_support::reason_registry::GetReasons;
+
+	let reasons = RuntimeHoldReason::reasons();
+	// `__Ignore` variants are skipped by the encoding and are not described.
+	assert_eq!(reasons.len(), 2 + 4 * 3);
+	assert!(reasons.iter().all(|r| {
+		RuntimeHoldReason::decode(&mut &r.encoded[..]).ok().map(|v| v.encode()) ==
+			Some(r.encoded.clone())
+	}));
+
+	assert_eq!(reasons[0].pallet, "ModuleSingleInstance");
+	assert_eq!(reasons[0].reason, "ModuleSingleInstanceReason1");
+	assert_eq!(reasons[2].pallet, "ModuleMultiInstance0");
+	assert_eq!(reasons[2].reason, "ModuleMultiInstanceReason1");
+
+	let freeze_reasons = RuntimeFreezeReason::reasons();
+	assert_eq!(
+		freeze_reasons
+			.iter()
+			.map(|r| (r.pallet.as_str(), r.reason.as_str()))
+			.collect::<Vec<_>>(),
+		vec![
+			("ModuleSingleInstance", "ModuleSingleInstanceReason1"),
+			("ModuleSingleInstance", "ModuleSingleInstanceReason2"),
+			("ModuleMultiInstance0", "ModuleMultiInstanceReason1"),
+			("ModuleMultiInstance1", "ModuleMultiInstanceReason1"),
+			("ModuleMultiInstance2", "ModuleMultiInstanceReason1"),
+			("ModuleMultiInstance3", "ModuleMultiInstanceReason1"),
+		],
+	);
+	assert!(freeze_reasons.iter().all(|r| {
+		RuntimeFreezeReason::decode(&mut &r.encoded[..]).ok().map(|v| v.encode()) ==
+			Some(r.encoded.clone())
+	}));
+}
@@ -0,0 +1,108 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the event fields marked with `#[pallet::indexed]`.
+
+use frame_support::{derive_impl, metadata_ir::EventIndexedFieldIR};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	BuildStorage,
+};
+
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub fn deposit_event)]
+	pub enum Event<T: Config> {
+		Transferred {
+			#[pallet::indexed]
+			from: T::AccountId,
+			#[pallet::indexed]
+			to: T::AccountId,
+			amount: u32,
+		},
+		Touched(u32, #[pallet::indexed] T::AccountId),
+		Unindexed {
+			who: T::AccountId,
+		},
+	}
+}
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Example: pallet,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+	type Block = Block;
+}
+
+impl pallet::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+}
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::<Runtime>::default()
+		.build_storage()
+		.unwrap()
+		.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+#[test]
+fn indexed_fields_are_in_metadata_ir() {
+	let metadata = pallet::Event::<Runtime>::event_metadata::<pallet::Event<Runtime>>();
+
+	assert_eq!(
+		metadata.indexed_fields,
+		vec![
+			EventIndexedFieldIR { variant: "Transferred", index: 0, name: Some("from") },
+			EventIndexedFieldIR { variant: "Transferred", index: 1, name: Some("to") },
+			EventIndexedFieldIR { variant: "Touched", index: 1, name: None },
+		],
+	);
+}
+
+#[test]
+fn indexed_fields_are_deposited_as_topics() {
+	new_test_ext().execute_with(|| {
+		let topic = |who: u64| BlakeTwo256::hash_of(&who);
+
+		Example::deposit_event(pallet::Event::Transferred { from: 1, to: 2, amount: 10 });
+		Example::deposit_event(pallet::Event::Touched(3, 2));
+		Example::deposit_event(pallet::Event::Unindexed { who: 1 });
+
+		let topics = System::events().into_iter().map(|record| record.topics).collect::<Vec<_>>();
+		assert_eq!(topics, vec![vec![topic(1), topic(2)], vec![topic(2)], vec![]]);
+	});
+}
@@ -0,0 +1,131 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the origin requirements collected by the pallet macro.
+
+use frame_support::{
+	derive_impl,
+	origin_audit::{CallOriginRequirements, GetCallOriginRequirements, OriginRequirement},
+};
+
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::call_index(0)]
+		pub fn root(origin: OriginFor<T>) -> DispatchResult {
+			ensure_root(origin)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(1)]
+		pub fn signed(origin: OriginFor<T>) -> DispatchResult {
+			let _who = frame_system::ensure_signed(origin)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(2)]
+		pub fn admin(origin: OriginFor<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Ok(())
+		}
+
+		#[pallet::call_index(3)]
+		pub fn admin_or_signed(origin: OriginFor<T>) -> DispatchResult {
+			if let Err(origin) = <T as Config>::AdminOrigin::try_origin(origin) {
+				ensure_signed(origin)?;
+			}
+			Ok(())
+		}
+
+		#[pallet::call_index(5)]
+		pub fn unchecked(_origin: OriginFor<T>) -> DispatchResult {
+			Ok(())
+		}
+	}
+}
+
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Example: pallet,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Runtime {
+	type Block = Block;
+}
+
+impl pallet::Config for Runtime {
+	type AdminOrigin = frame_system::EnsureRoot<u64>;
+}
+
+fn requirements(
+	call: &str,
+	call_index: u8,
+	origins: Vec<OriginRequirement>,
+) -> CallOriginRequirements {
+	CallOriginRequirements { pallet: "Example".into(), call: call.into(), call_index, origins }
+}
+
+#[test]
+fn origin_requirements_are_collected() {
+	assert_eq!(
+		pallet::Pallet::<Runtime>::call_origin_requirements(),
+		vec![
+			requirements("root", 0, vec![OriginRequirement::Root]),
+			requirements("signed", 1, vec![OriginRequirement::Signed]),
+			requirements(
+				"admin",
+				2,
+				vec![OriginRequirement::EnsureOrigin("T::AdminOrigin".into())]
+			),
+			requirements(
+				"admin_or_signed",
+				3,
+				vec![
+					OriginRequirement::EnsureOrigin("<T as Config>::AdminOrigin".into()),
+					OriginRequirement::Signed
+				]
+			),
+			requirements("unchecked", 5, vec![]),
+		],
+	);
+}
+
+#[test]
+fn origin_requirements_of_all_pallets_are_collected() {
+	let all = AllPalletsWithSystem::call_origin_requirements();
+
+	let set_code = all.iter().find(|r| r.pallet == "System" && r.call == "set_code").unwrap();
+	assert_eq!(set_code.origins, vec![OriginRequirement::Root]);
+	assert_eq!(all.iter().filter(|r| r.pallet == "Example").count(), 5);
+}
@@ -0,0 +1,128 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Config;
+use alloc::vec;
+use codec::{Decode, Encode};
+use core::marker::PhantomData;
+use frame_support::{
+	pallet_prelude::TransactionSource,
+	traits::{Get, InspectNestedCalls},
+	CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	impl_tx_ext_default,
+	traits::{DispatchInfoOf, Saturating, TransactionExtension},
+	transaction_validity::InvalidTransaction,
+};
+use sp_weights::Weight;
+
+/// Limits the nesting of wrapper calls, e.g. batches of proxy calls of batches.
+///
+/// The calls nested in a wrapper call are found with `Inspector`. A transaction is invalid if
+/// its calls are nested deeper than `MaxDepth` levels below the top-level call, or if it contains
+/// more than `MaxCalls` nested calls in total. Wrapper calls usually only account for the weight
+/// of the calls they dispatch, so this bounds the work which is not accounted for, e.g. the
+/// filtering and the origin handling of every nested call.
+///
+/// # Transaction Validity
+///
+/// This extension does not influence any fields of `TransactionValidity` in case the
+/// transaction is valid.
+#[derive(Encode, Decode, DefaultNoBound, CloneNoBound, EqNoBound, PartialEqNoBound, TypeInfo)]
+#[scale_info(skip_type_params(T, Inspector, MaxDepth, MaxCalls))]
+pub struct CheckNestedCalls<T, Inspector, MaxDepth, MaxCalls>(
+	PhantomData<(T, Inspector, MaxDepth, MaxCalls)>,
+);
+
+impl<T, Inspector, MaxDepth, MaxCalls> core::fmt::Debug
+	for CheckNestedCalls<T, Inspector, MaxDepth, MaxCalls>
+{
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "CheckNestedCalls")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut core::fmt::Formatter) -> core::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T, Inspector, MaxDepth, MaxCalls> CheckNestedCalls<T, Inspector, MaxDepth, MaxCalls>
+where
+	T: Config,
+	Inspector: InspectNestedCalls<T::RuntimeCall>,
+	MaxDepth: Get<u32>,
+	MaxCalls: Get<u32>,
+{
+	/// Create new `TransactionExtension` to check the nesting of calls.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+
+	/// Check that `call` does not nest too deep or too many calls.
+	pub fn check_nesting(call: &T::RuntimeCall) -> Result<(), InvalidTransaction> {
+		let mut calls = 0u32;
+		let mut to_visit = vec![(call, 0u32)];
+		while let Some((call, depth)) = to_visit.pop() {
+			for nested in Inspector::nested_calls(call) {
+				calls.saturating_inc();
+				if depth >= MaxDepth::get() || calls > MaxCalls::get() {
+					return Err(InvalidTransaction::ExhaustsResources)
+				}
+				to_visit.push((nested, depth + 1));
+			}
+		}
+		Ok(())
+	}
+}
+
+impl<T, Inspector, MaxDepth, MaxCalls> TransactionExtension<T::RuntimeCall>
+	for CheckNestedCalls<T, Inspector, MaxDepth, MaxCalls>
+where
+	T: Config + Send + Sync,
+	Inspector: InspectNestedCalls<T::RuntimeCall> + Send + Sync + 'static,
+	MaxDepth: Get<u32> + Send + Sync + 'static,
+	MaxCalls: Get<u32> + Send + Sync + 'static,
+{
+	const IDENTIFIER: &'static str = "CheckNestedCalls";
+	type Implicit = ();
+	type Val = ();
+	type Pre = ();
+
+	fn weight(&self, _: &T::RuntimeCall) -> Weight {
+		// Only inspects the already decoded call, which is negligible compared to decoding it.
+		Weight::zero()
+	}
+
+	fn validate(
+		&self,
+		origin: <T as Config>::RuntimeOrigin,
+		call: &T::RuntimeCall,
+		_info: &DispatchInfoOf<T::RuntimeCall>,
+		_len: usize,
+		_self_implicit: Self::Implicit,
+		_inherited_implication: &impl Encode,
+		_source: TransactionSource,
+	) -> sp_runtime::traits::ValidateResult<Self::Val, T::RuntimeCall> {
+		Self::check_nesting(call)?;
+		Ok((Default::default(), (), origin))
+	}
+	impl_tx_ext_default!(T::RuntimeCall; prepare);
+}
@@ -17,6 +17,7 @@
 
 pub mod check_genesis;
 pub mod check_mortality;
+pub mod check_nested_calls;
 pub mod check_non_zero_sender;
 pub mod check_nonce;
 pub mod check_spec_version;
@@ -168,9 +168,10 @@ pub mod migrations;
 
 pub use extensions::{
 	check_genesis::CheckGenesis, check_mortality::CheckMortality,
-	check_non_zero_sender::CheckNonZeroSender, check_nonce::CheckNonce,
-	check_spec_version::CheckSpecVersion, check_tx_version::CheckTxVersion,
-	check_weight::CheckWeight, WeightInfo as ExtensionsWeightInfo,
+	check_nested_calls::CheckNestedCalls, check_non_zero_sender::CheckNonZeroSender,
+	check_nonce::CheckNonce, check_spec_version::CheckSpecVersion,
+	check_tx_version::CheckTxVersion, check_weight::CheckWeight,
+	WeightInfo as ExtensionsWeightInfo,
 };
 // Backward compatible re-export.
 pub use extensions::check_mortality::CheckMortality as CheckEra;
@@ -262,6 +263,38 @@ where
 	check_version: bool,
 }
 
+/// Refunds registered for the current extrinsic on top of the weight refunded through its
+/// post dispatch info.
+///
+/// Refunds are registered with [`Pallet::register_extrinsic_refund`] and accumulate until the
+/// extrinsic is applied, so that several transaction extensions (or the dispatched call itself)
+/// can contribute to them. The payment extension takes them in its post dispatch to correct the
+/// fee it charged.
+#[derive(
+	Clone, Copy, Eq, PartialEq, Default, RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo,
+)]
+pub struct ExtrinsicRefund {
+	/// Number of bytes of the extrinsic length that should not be charged.
+	pub length: u32,
+}
+
+impl ExtrinsicRefund {
+	/// A refund of `length` bytes of the extrinsic length fee.
+	pub fn from_length(length: u32) -> Self {
+		Self { length }
+	}
+
+	/// Add the refunds of `other` to `self`.
+	pub fn saturating_accrue(&mut self, other: Self) {
+		self.length = self.length.saturating_add(other.length);
+	}
+
+	/// Whether nothing is refunded.
+	pub fn is_zero(&self) -> bool {
+		self.length == 0
+	}
+}
+
 /// Information about the dispatch of a call, to be displayed in the
 /// [`ExtrinsicSuccess`](Event::ExtrinsicSuccess) and [`ExtrinsicFailed`](Event::ExtrinsicFailed)
 /// events.
@@ -971,6 +1004,11 @@ pub mod pallet {
 	#[pallet::getter(fn parent_hash)]
 	pub(super) type ParentHash<T: Config> = StorageValue<_, T::Hash, ValueQuery>;
 
+	/// Refunds registered for the extrinsic being applied, see [`ExtrinsicRefund`].
+	#[pallet::storage]
+	#[pallet::whitelist_storage]
+	pub type ExtrinsicRefunds<T: Config> = StorageValue<_, ExtrinsicRefund, ValueQuery>;
+
 	/// Digest of the current block, also part of the block header.
 	#[pallet::storage]
 	#[pallet::whitelist_storage]
@@ -1774,6 +1812,11 @@ impl<T: Config> Pallet<T> {
 		ExtrinsicCount::<T>::get().unwrap_or_default()
 	}
 
+	/// Gets the number of events deposited in the current block, i.e. the index of the next one.
+	pub fn event_count() -> EventIndex {
+		EventCount::<T>::get()
+	}
+
 	pub fn all_extrinsics_len() -> u32 {
 		AllExtrinsicsLen::<T>::get().unwrap_or_default()
 	}
@@ -2073,12 +2116,33 @@ impl<T: Config> Pallet<T> {
 			},
 		});
 
+		ExtrinsicRefunds::<T>::kill();
+
 		let next_extrinsic_index = Self::extrinsic_index().unwrap_or_default() + 1u32;
 
 		storage::unhashed::put(well_known_keys::EXTRINSIC_INDEX, &next_extrinsic_index);
 		ExecutionPhase::<T>::put(Phase::ApplyExtrinsic(next_extrinsic_index));
 	}
 
+	/// Register a refund for the extrinsic being applied.
+	///
+	/// The refund is added to the ones already registered. To be taken into account, it must be
+	/// registered before the post dispatch of the extension charging the fee, i.e. during the
+	/// dispatch of the call or by an extension placed before the payment extension.
+	pub fn register_extrinsic_refund(refund: ExtrinsicRefund) {
+		ExtrinsicRefunds::<T>::mutate(|refunds| refunds.saturating_accrue(refund));
+	}
+
+	/// The refunds registered so far for the extrinsic being applied.
+	pub fn extrinsic_refund() -> ExtrinsicRefund {
+		ExtrinsicRefunds::<T>::get()
+	}
+
+	/// Take the refunds registered for the extrinsic being applied.
+	pub fn take_extrinsic_refund() -> ExtrinsicRefund {
+		ExtrinsicRefunds::<T>::take()
+	}
+
 	/// To be called immediately after `note_applied_extrinsic` of the last extrinsic of the block
 	/// has been called.
 	pub fn note_finished_extrinsics() {
@@ -2147,6 +2211,17 @@ impl<T: Config> Pallet<T> {
 		Self::deposit_event(Event::UpgradeAuthorized { code_hash, check_version });
 	}
 
+	/// To be called after any origin/privilege checks. Remove the code upgrade authorization if it
+	/// is for `code_hash`, returning whether it was removed. Infallible.
+	pub fn do_revoke_authorized_upgrade(code_hash: T::Hash) -> bool {
+		let revoke = AuthorizedUpgrade::<T>::get()
+			.is_some_and(|authorization| authorization.code_hash == code_hash);
+		if revoke {
+			AuthorizedUpgrade::<T>::kill();
+		}
+		revoke
+	}
+
 	/// Apply an authorized upgrade, performing any validation checks, and remove the authorization.
 	/// Whether or not the code is set directly depends on the `OnSetCode` configuration of the
 	/// runtime.
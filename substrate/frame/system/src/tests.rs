@@ -581,6 +581,25 @@ fn event_util_functions_should_work() {
 	});
 }
 
+#[test]
+fn extrinsic_refunds_accumulate_until_the_extrinsic_is_applied() {
+	new_test_ext().execute_with(|| {
+		System::initialize(&1, &[0u8; 32].into(), &Default::default());
+		System::note_finished_initialize();
+
+		System::register_extrinsic_refund(ExtrinsicRefund::from_length(5));
+		System::register_extrinsic_refund(ExtrinsicRefund::from_length(u32::MAX));
+		assert_eq!(System::extrinsic_refund(), ExtrinsicRefund::from_length(u32::MAX));
+
+		System::note_applied_extrinsic(&Ok(().into()), Default::default());
+		assert!(System::extrinsic_refund().is_zero());
+
+		System::register_extrinsic_refund(ExtrinsicRefund::from_length(5));
+		assert_eq!(System::take_extrinsic_refund(), ExtrinsicRefund::from_length(5));
+		assert!(System::extrinsic_refund().is_zero());
+	});
+}
+
 #[test]
 fn prunes_block_hash_mappings() {
 	new_test_ext().execute_with(|| {
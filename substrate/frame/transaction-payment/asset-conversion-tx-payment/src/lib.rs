@@ -366,7 +366,7 @@ where
 				let mut actual_post_info = *post_info;
 				actual_post_info.refund(unspent_weight);
 				let actual_fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
-					len as u32,
+					pallet_transaction_payment::Pallet::<T>::refunded_length(len as u32),
 					info,
 					&actual_post_info,
 					tip,
@@ -392,7 +392,7 @@ where
 				let mut actual_post_info = *post_info;
 				actual_post_info.refund(unspent_weight);
 				let actual_fee = pallet_transaction_payment::Pallet::<T>::compute_actual_fee(
-					len as u32,
+					pallet_transaction_payment::Pallet::<T>::refunded_length(len as u32),
 					info,
 					&actual_post_info,
 					tip,
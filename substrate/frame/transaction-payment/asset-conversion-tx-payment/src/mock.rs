@@ -196,6 +196,8 @@ impl pallet_assets::Config for Runtime {
 	type ApprovalDeposit = ConstU64<0>;
 	type StringLimit = ConstU32<20>;
 	type Freezer = ();
+	type MaxFreezes = ConstU32<8>;
+	type UriLimit = ConstU32<256>;
 	type Extra = ();
 	type CallbackHandle = ();
 	type WeightInfo = ();
@@ -221,6 +223,8 @@ impl pallet_assets::Config<Instance2> for Runtime {
 	type ApprovalDeposit = ConstU64<0>;
 	type StringLimit = ConstU32<50>;
 	type Freezer = ();
+	type MaxFreezes = ConstU32<8>;
+	type UriLimit = ConstU32<256>;
 	type Extra = ();
 	type WeightInfo = ();
 	type CallbackHandle = ();
@@ -267,6 +271,9 @@ impl pallet_asset_conversion::Config for Runtime {
 	type LPFee = ConstU32<3>; // means 0.3%
 	type LiquidityWithdrawalFee = LiquidityWithdrawalFee;
 	type MaxSwapPathLength = MaxSwapPathLength;
+	type MaxOrdersPerPool = ConstU32<8>;
+	type MaxOrderFillsPerBlock = ConstU32<8>;
+	type OrderDeposit = ConstU64<100>;
 	type MintMinLiquidity = ConstU64<100>; // 100 is good enough when the main currency has 12 decimals.
 	type WeightInfo = ();
 	pallet_asset_conversion::runtime_benchmarks_enabled! {
@@ -140,6 +140,8 @@ impl pallet_assets::Config for Runtime {
 	type ApprovalDeposit = ConstU64<0>;
 	type StringLimit = ConstU32<20>;
 	type Freezer = ();
+	type MaxFreezes = ConstU32<8>;
+	type UriLimit = ConstU32<256>;
 	type Extra = ();
 	type CallbackHandle = ();
 	type WeightInfo = ();
@@ -19,10 +19,16 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::vec::Vec;
 use codec::Codec;
-use sp_runtime::traits::MaybeDisplay;
+use sp_runtime::{traits::MaybeDisplay, Perquintill};
 
-pub use pallet_transaction_payment::{FeeDetails, InclusionFee, RuntimeDispatchInfo};
+pub use pallet_transaction_payment::{
+	FeeBreakdown, FeeDetails, FeeMultiplierProjection, InclusionFee, Multiplier,
+	RuntimeDispatchInfo,
+};
 
 sp_api::decl_runtime_apis! {
 	#[api_version(4)]
@@ -33,6 +39,29 @@ sp_api::decl_runtime_apis! {
 		fn query_fee_details(uxt: Block::Extrinsic, len: u32) -> FeeDetails<Balance>;
 		fn query_weight_to_fee(weight: sp_weights::Weight) -> Balance;
 		fn query_length_to_fee(length: u32) -> Balance;
+
+		/// Simulate the fee multiplier over blocks filled up to `block_fullness` of their normal
+		/// capacity under the given fee adjustment parameters, projecting the inclusion fee of a
+		/// transaction with `weight` and `length`.
+		#[api_version(5)]
+		fn query_fee_multiplier_projection(
+			block_fullness: Vec<Perquintill>,
+			weight: sp_weights::Weight,
+			length: u32,
+			target_block_fullness: Perquintill,
+			adjustment_variable: Multiplier,
+			minimum_multiplier: Multiplier,
+		) -> Vec<FeeMultiplierProjection<Balance>>;
+
+		/// Query a breakdown of the fee of `uxt` when paying `tip`, including the fee projected
+		/// `blocks_ahead` blocks into the future under the current multiplier trend.
+		#[api_version(6)]
+		fn query_fee_breakdown(
+			uxt: Block::Extrinsic,
+			len: u32,
+			tip: Balance,
+			blocks_ahead: u32,
+		) -> FeeBreakdown<Balance>;
 	}
 
 	#[api_version(3)]
@@ -47,6 +47,9 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::vec::Vec;
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
@@ -69,7 +72,9 @@ use sp_runtime::{
 	transaction_validity::{TransactionPriority, TransactionValidityError, ValidTransaction},
 	FixedPointNumber, FixedU128, Perbill, Perquintill, RuntimeDebug,
 };
-pub use types::{FeeDetails, InclusionFee, RuntimeDispatchInfo};
+pub use types::{
+	FeeBreakdown, FeeDetails, FeeMultiplierProjection, InclusionFee, RuntimeDispatchInfo,
+};
 pub use weights::WeightInfo;
 
 #[cfg(test)]
@@ -153,6 +158,16 @@ pub trait MultiplierUpdate: Convert<Multiplier, Multiplier> {
 	fn target() -> Perquintill;
 	/// Variability factor
 	fn variability() -> Multiplier;
+	/// Compute the multiplier that follows `previous` for a block whose normal dispatch class was
+	/// filled up to `fullness`.
+	///
+	/// Unlike `convert`, this does not read the weight of the current block, which makes it
+	/// suitable to simulate the evolution of the multiplier over a hypothetical set of blocks.
+	///
+	/// Defaults to `convert`, which ignores `fullness`.
+	fn next_for_fullness(previous: Multiplier, _fullness: Perquintill) -> Multiplier {
+		Self::convert(previous)
+	}
 }
 
 impl MultiplierUpdate for () {
@@ -190,6 +205,14 @@ where
 	fn variability() -> Multiplier {
 		V::get()
 	}
+	fn next_for_fullness(previous: Multiplier, fullness: Perquintill) -> Multiplier {
+		let weights = T::BlockWeights::get();
+		let normal_max_weight =
+			weights.get(DispatchClass::Normal).max_total.unwrap_or(weights.max_block);
+		let max_limiting_dimension = normal_max_weight.ref_time();
+
+		Self::adjust(previous, (fullness * max_limiting_dimension) as u128, max_limiting_dimension)
+	}
 }
 
 impl<T, S, V, M, X> Convert<Multiplier, Multiplier> for TargetedFeeAdjustment<T, S, V, M, X>
@@ -201,13 +224,6 @@ where
 	X: Get<Multiplier>,
 {
 	fn convert(previous: Multiplier) -> Multiplier {
-		// Defensive only. The multiplier in storage should always be at most positive. Nonetheless
-		// we recover here in case of errors, because any value below this would be stale and can
-		// never change.
-		let min_multiplier = M::get();
-		let max_multiplier = X::get();
-		let previous = previous.max(min_multiplier);
-
 		let weights = T::BlockWeights::get();
 		// the computed ratio is only among the normal class.
 		let normal_max_weight =
@@ -235,35 +251,74 @@ where
 				(normal_block_weight.ref_time(), normal_max_weight.ref_time())
 			};
 
-		let target_block_fullness = S::get();
-		let adjustment_variable = V::get();
-
-		let target_weight = (target_block_fullness * max_limiting_dimension) as u128;
-		let block_weight = normal_limiting_dimension as u128;
-
-		// determines if the first_term is positive
-		let positive = block_weight >= target_weight;
-		let diff_abs = block_weight.max(target_weight) - block_weight.min(target_weight);
-
-		// defensive only, a test case assures that the maximum weight diff can fit in Multiplier
-		// without any saturation.
-		let diff = Multiplier::saturating_from_rational(diff_abs, max_limiting_dimension.max(1));
-		let diff_squared = diff.saturating_mul(diff);
-
-		let v_squared_2 = adjustment_variable.saturating_mul(adjustment_variable) /
-			Multiplier::saturating_from_integer(2);
+		Self::adjust(previous, normal_limiting_dimension as u128, max_limiting_dimension)
+	}
+}
 
-		let first_term = adjustment_variable.saturating_mul(diff);
-		let second_term = v_squared_2.saturating_mul(diff_squared);
+impl<T, S, V, M, X> TargetedFeeAdjustment<T, S, V, M, X>
+where
+	T: frame_system::Config,
+	S: Get<Perquintill>,
+	V: Get<Multiplier>,
+	M: Get<Multiplier>,
+	X: Get<Multiplier>,
+{
+	/// Adjust `previous` given the `block_weight` consumed out of `max_limiting_dimension` in the
+	/// limiting dimension of the normal dispatch class.
+	fn adjust(previous: Multiplier, block_weight: u128, max_limiting_dimension: u64) -> Multiplier {
+		adjust_multiplier(
+			previous,
+			block_weight,
+			max_limiting_dimension,
+			S::get(),
+			V::get(),
+			M::get(),
+			X::get(),
+		)
+	}
+}
 
-		if positive {
-			let excess = first_term.saturating_add(second_term).saturating_mul(previous);
-			previous.saturating_add(excess).clamp(min_multiplier, max_multiplier)
-		} else {
-			// Defensive-only: first_term > second_term. Safe subtraction.
-			let negative = first_term.saturating_sub(second_term).saturating_mul(previous);
-			previous.saturating_sub(negative).clamp(min_multiplier, max_multiplier)
-		}
+/// Adjust `previous` given the `block_weight` consumed out of `max_limiting_dimension` in the
+/// limiting dimension of the normal dispatch class, following the formula of
+/// [`TargetedFeeAdjustment`] with the given parameters.
+fn adjust_multiplier(
+	previous: Multiplier,
+	block_weight: u128,
+	max_limiting_dimension: u64,
+	target_block_fullness: Perquintill,
+	adjustment_variable: Multiplier,
+	min_multiplier: Multiplier,
+	max_multiplier: Multiplier,
+) -> Multiplier {
+	// Defensive only. The multiplier in storage should always be at most positive. Nonetheless
+	// we recover here in case of errors, because any value below this would be stale and can
+	// never change.
+	let previous = previous.max(min_multiplier);
+
+	let target_weight = (target_block_fullness * max_limiting_dimension) as u128;
+
+	// determines if the first_term is positive
+	let positive = block_weight >= target_weight;
+	let diff_abs = block_weight.max(target_weight) - block_weight.min(target_weight);
+
+	// defensive only, a test case assures that the maximum weight diff can fit in Multiplier
+	// without any saturation.
+	let diff = Multiplier::saturating_from_rational(diff_abs, max_limiting_dimension.max(1));
+	let diff_squared = diff.saturating_mul(diff);
+
+	let v_squared_2 = adjustment_variable.saturating_mul(adjustment_variable) /
+		Multiplier::saturating_from_integer(2);
+
+	let first_term = adjustment_variable.saturating_mul(diff);
+	let second_term = v_squared_2.saturating_mul(diff_squared);
+
+	if positive {
+		let excess = first_term.saturating_add(second_term).saturating_mul(previous);
+		previous.saturating_add(excess).clamp(min_multiplier, max_multiplier)
+	} else {
+		// Defensive-only: first_term > second_term. Safe subtraction.
+		let negative = first_term.saturating_sub(second_term).saturating_mul(previous);
+		previous.saturating_sub(negative).clamp(min_multiplier, max_multiplier)
 	}
 }
 
@@ -313,6 +368,10 @@ impl Default for Releases {
 /// NextFeeMultiplierOnEmpty() to provide a value when none exists in storage.
 const MULTIPLIER_DEFAULT_VALUE: Multiplier = Multiplier::from_u32(1);
 
+/// The maximum number of blocks that [`Pallet::query_fee_breakdown`] projects the fee into the
+/// future.
+pub const MAX_FEE_PROJECTION_BLOCKS: u32 = 1_000;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::pallet_prelude::*;
@@ -552,6 +611,77 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Query a breakdown of the fee of a given `call` when paying `tip`.
+	///
+	/// The fee is also projected `blocks_ahead` blocks into the future, assuming that each of
+	/// these blocks is as full as the current one. At most [`MAX_FEE_PROJECTION_BLOCKS`] blocks
+	/// are projected.
+	pub fn query_fee_breakdown<Extrinsic: sp_runtime::traits::ExtrinsicLike + GetDispatchInfo>(
+		unchecked_extrinsic: Extrinsic,
+		len: u32,
+		tip: BalanceOf<T>,
+		blocks_ahead: u32,
+	) -> FeeBreakdown<BalanceOf<T>>
+	where
+		T::RuntimeCall: Dispatchable<Info = DispatchInfo>,
+	{
+		let dispatch_info = <Extrinsic as GetDispatchInfo>::get_dispatch_info(&unchecked_extrinsic);
+		let blocks_ahead = blocks_ahead.min(MAX_FEE_PROJECTION_BLOCKS);
+
+		let multiplier = NextFeeMultiplier::<T>::get();
+		let fullness = Self::block_fullness();
+		let projected_multiplier = (0..blocks_ahead)
+			.fold(multiplier, |m, _| T::FeeMultiplierUpdate::next_for_fullness(m, fullness));
+
+		let weight = dispatch_info.total_weight();
+		let (tip, inclusion_fee, projected_inclusion_fee) =
+			if unchecked_extrinsic.is_bare() || dispatch_info.pays_fee == Pays::No {
+				// Bare extrinsics can not carry a tip.
+				let tip = if unchecked_extrinsic.is_bare() { Zero::zero() } else { tip };
+				(tip, None, None)
+			} else {
+				let class = dispatch_info.class;
+				(
+					tip,
+					Some(Self::inclusion_fee(len, weight, class, multiplier)),
+					Some(Self::inclusion_fee(len, weight, class, projected_multiplier)),
+				)
+			};
+
+		FeeBreakdown {
+			unadjusted_weight_fee: inclusion_fee
+				.as_ref()
+				.map_or_else(Zero::zero, |_| Self::weight_to_fee(weight)),
+			inclusion_fee,
+			multiplier,
+			tip,
+			conversion_rate: None,
+			blocks_ahead,
+			projected_multiplier,
+			projected_fee: FeeDetails { inclusion_fee: projected_inclusion_fee, tip }.final_fee(),
+		}
+	}
+
+	/// The portion of the normal dispatch class capacity which the current block consumed, in the
+	/// more limiting of the two weight dimensions.
+	pub fn block_fullness() -> Perquintill {
+		let weights = T::BlockWeights::get();
+		let normal_max_weight =
+			weights.get(DispatchClass::Normal).max_total.unwrap_or(weights.max_block);
+		let normal_block_weight = frame_system::Pallet::<T>::block_weight()
+			.get(DispatchClass::Normal)
+			.min(normal_max_weight);
+
+		Perquintill::from_rational(
+			normal_block_weight.ref_time(),
+			normal_max_weight.ref_time().max(1),
+		)
+		.max(Perquintill::from_rational(
+			normal_block_weight.proof_size(),
+			normal_max_weight.proof_size().max(1),
+		))
+	}
+
 	/// Query information of a dispatch class, weight, and fee of a given encoded `Call`.
 	pub fn query_call_info(call: T::RuntimeCall, len: u32) -> RuntimeDispatchInfo<BalanceOf<T>>
 	where
@@ -602,6 +732,15 @@ impl<T: Config> Pallet<T> {
 		Self::compute_fee_raw(len, info.total_weight(), tip, info.pays_fee, info.class)
 	}
 
+	/// The length of the extrinsic being applied to charge fees for.
+	///
+	/// Takes the refunds registered through
+	/// [`frame_system::Pallet::register_extrinsic_refund`] and deducts their length from `len`.
+	pub fn refunded_length(len: u32) -> u32 {
+		let refund = frame_system::Pallet::<T>::take_extrinsic_refund();
+		len.saturating_sub(refund.length)
+	}
+
 	/// Compute the actual post dispatch fee for a particular transaction.
 	///
 	/// Identical to `compute_fee` with the only difference that the post dispatch corrected
@@ -645,18 +784,9 @@ impl<T: Config> Pallet<T> {
 		class: DispatchClass,
 	) -> FeeDetails<BalanceOf<T>> {
 		if pays_fee == Pays::Yes {
-			// the adjustable part of the fee.
-			let unadjusted_weight_fee = Self::weight_to_fee(weight);
 			let multiplier = NextFeeMultiplier::<T>::get();
-			// final adjusted weight fee.
-			let adjusted_weight_fee = multiplier.saturating_mul_int(unadjusted_weight_fee);
-
-			// length fee. this is adjusted via `LengthToFee`.
-			let len_fee = Self::length_to_fee(len);
-
-			let base_fee = Self::weight_to_fee(T::BlockWeights::get().get(class).base_extrinsic);
 			FeeDetails {
-				inclusion_fee: Some(InclusionFee { base_fee, len_fee, adjusted_weight_fee }),
+				inclusion_fee: Some(Self::inclusion_fee(len, weight, class, multiplier)),
 				tip,
 			}
 		} else {
@@ -664,6 +794,76 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	fn inclusion_fee(
+		len: u32,
+		weight: Weight,
+		class: DispatchClass,
+		multiplier: Multiplier,
+	) -> InclusionFee<BalanceOf<T>> {
+		// the adjustable part of the fee.
+		let unadjusted_weight_fee = Self::weight_to_fee(weight);
+		// final adjusted weight fee.
+		let adjusted_weight_fee = multiplier.saturating_mul_int(unadjusted_weight_fee);
+
+		// length fee. this is adjusted via `LengthToFee`.
+		let len_fee = Self::length_to_fee(len);
+
+		let base_fee = Self::weight_to_fee(T::BlockWeights::get().get(class).base_extrinsic);
+		InclusionFee { base_fee, len_fee, adjusted_weight_fee }
+	}
+
+	/// Simulate the evolution of the fee multiplier over a sequence of blocks, each filled up to
+	/// the given portion of its normal dispatch class capacity.
+	///
+	/// Starting from the current [`NextFeeMultiplier`], the multiplier is adjusted once per entry
+	/// of `block_fullness` following the formula of [`TargetedFeeAdjustment`], with the given
+	/// `target_block_fullness`, `adjustment_variable` and `minimum_multiplier` and the maximum of
+	/// [`Config::FeeMultiplierUpdate`]. For every simulated block, the resulting multiplier and the
+	/// inclusion fee of a normal transaction with the given `weight` and `len` are returned. This
+	/// allows validating changes to these parameters before enacting them.
+	pub fn simulate_fee_multiplier(
+		block_fullness: Vec<Perquintill>,
+		weight: Weight,
+		len: u32,
+		target_block_fullness: Perquintill,
+		adjustment_variable: Multiplier,
+		minimum_multiplier: Multiplier,
+	) -> Vec<FeeMultiplierProjection<BalanceOf<T>>> {
+		let weights = T::BlockWeights::get();
+		let max_limiting_dimension = weights
+			.get(DispatchClass::Normal)
+			.max_total
+			.unwrap_or(weights.max_block)
+			.ref_time();
+		// `clamp` requires the maximum to be at least the minimum.
+		let maximum_multiplier = T::FeeMultiplierUpdate::max().max(minimum_multiplier);
+		let mut multiplier = NextFeeMultiplier::<T>::get();
+		block_fullness
+			.into_iter()
+			.map(|fullness| {
+				multiplier = adjust_multiplier(
+					multiplier,
+					(fullness * max_limiting_dimension) as u128,
+					max_limiting_dimension,
+					target_block_fullness,
+					adjustment_variable,
+					minimum_multiplier,
+					maximum_multiplier,
+				);
+				FeeMultiplierProjection {
+					block_fullness: fullness,
+					multiplier,
+					inclusion_fee: Self::inclusion_fee(
+						len,
+						weight,
+						DispatchClass::Normal,
+						multiplier,
+					),
+				}
+			})
+			.collect()
+	}
+
 	/// Compute the length portion of a fee by invoking the configured `LengthToFee` impl.
 	pub fn length_to_fee(length: u32) -> BalanceOf<T> {
 		T::LengthToFee::weight_to_fee(&Weight::from_parts(length as u64, 0))
@@ -970,7 +1170,8 @@ where
 				return Ok(refund)
 			},
 		};
-		let actual_fee = Pallet::<T>::compute_actual_fee(len as u32, info, &post_info, tip);
+		let len = Pallet::<T>::refunded_length(len as u32);
+		let actual_fee = Pallet::<T>::compute_actual_fee(len, info, &post_info, tip);
 		T::OnChargeTransaction::correct_and_deposit_fee(
 			&who, info, &post_info, actual_fee, tip, imbalance,
 		)?;
@@ -201,6 +201,33 @@ fn transaction_extension_transaction_payment_multiplied_refund_works() {
 		});
 }
 
+#[test]
+fn transaction_extension_transaction_payment_refunds_registered_length() {
+	ExtBuilder::default()
+		.balance_factor(10)
+		.base_weight(Weight::from_parts(5, 0))
+		.build()
+		.execute_with(|| {
+			let len = 10;
+			let mut info = info_from_weight(Weight::from_parts(100, 0));
+			let ext = Ext::from(0);
+			info.extension_weight = ext.weight(CALL);
+			ext.test_run(Some(2).into(), CALL, &info, len, 0, |_| {
+				assert_eq!(Balances::free_balance(2), 200 - 5 - 10 - 100 - 10);
+				// refunds from several sources accumulate
+				System::register_extrinsic_refund(system::ExtrinsicRefund::from_length(3));
+				System::register_extrinsic_refund(system::ExtrinsicRefund::from_length(4));
+				Ok(default_post_info())
+			})
+			.unwrap()
+			.unwrap();
+
+			// 7 of the 10 bytes of length fee are refunded
+			assert_eq!(Balances::free_balance(2), 200 - 5 - 3 - 100 - 10);
+			assert!(System::extrinsic_refund().is_zero());
+		});
+}
+
 #[test]
 fn transaction_extension_transaction_payment_is_bounded() {
 	ExtBuilder::default().balance_factor(1000).byte_fee(0).build().execute_with(|| {
@@ -877,3 +904,150 @@ fn no_fee_and_no_weight_for_other_origins() {
 		assert_eq!(post_info.actual_weight, Some(info.call_weight));
 	})
 }
+
+frame_support::parameter_types! {
+	pub const SimTargetBlockFullness: Perquintill = Perquintill::from_percent(25);
+	pub SimAdjustmentVariable: Multiplier = Multiplier::saturating_from_rational(1, 100);
+	pub SimMinimumMultiplier: Multiplier = Multiplier::saturating_from_rational(1, 10);
+	pub SimMaximumMultiplier: Multiplier = Multiplier::saturating_from_integer(10);
+}
+
+type SimFeeAdjustment = TargetedFeeAdjustment<
+	Runtime,
+	SimTargetBlockFullness,
+	SimAdjustmentVariable,
+	SimMinimumMultiplier,
+	SimMaximumMultiplier,
+>;
+
+#[test]
+fn next_for_fullness_matches_convert() {
+	ExtBuilder::default().build().execute_with(|| {
+		let previous = Multiplier::saturating_from_integer(1);
+
+		// The normal class of the mock has a `ref_time` capacity of 1024.
+		frame_system::Pallet::<Runtime>::register_extra_weight_unchecked(
+			Weight::from_parts(768, 0),
+			DispatchClass::Normal,
+		);
+
+		let next = SimFeeAdjustment::next_for_fullness(previous, Perquintill::from_percent(75));
+		assert!(next > previous);
+		assert_eq!(next, SimFeeAdjustment::convert(previous));
+
+		// Empty blocks push the multiplier down, up to the minimum.
+		let mut multiplier = previous;
+		for _ in 0..1_000 {
+			multiplier = SimFeeAdjustment::next_for_fullness(multiplier, Perquintill::zero());
+		}
+		assert_eq!(multiplier, SimMinimumMultiplier::get());
+	});
+}
+
+#[test]
+fn query_fee_breakdown_works() {
+	let call = RuntimeCall::Balances(BalancesCall::transfer_allow_death { dest: 2, value: 69 });
+	let xt = UncheckedExtrinsic::new_signed(call.clone(), 111111, (), ());
+	let info = xt.get_dispatch_info();
+	let len = xt.encode().len() as u32;
+	let unsigned_xt = UncheckedExtrinsic::<u64, _, (), ()>::new_bare(call);
+
+	ExtBuilder::default()
+		.base_weight(Weight::from_parts(5, 0))
+		.weight_fee(2)
+		.build()
+		.execute_with(|| {
+			let multiplier = Multiplier::saturating_from_rational(3, 2);
+			NextFeeMultiplier::<Runtime>::put(multiplier);
+			let weight_fee =
+				info.total_weight().min(BlockWeights::get().max_block).ref_time() as u64 * 2;
+			let inclusion_fee = InclusionFee {
+				base_fee: 5 * 2,
+				len_fee: len as u64,
+				adjusted_weight_fee: weight_fee * 3 / 2,
+			};
+
+			let breakdown = TransactionPayment::query_fee_breakdown(xt.clone(), len, 7, 0);
+			assert_eq!(
+				breakdown,
+				FeeBreakdown {
+					inclusion_fee: Some(inclusion_fee.clone()),
+					unadjusted_weight_fee: weight_fee,
+					multiplier,
+					tip: 7,
+					conversion_rate: None,
+					blocks_ahead: 0,
+					projected_multiplier: multiplier,
+					projected_fee: inclusion_fee.inclusion_fee() + 7,
+				}
+			);
+			assert_eq!(breakdown.final_fee(), breakdown.projected_fee);
+
+			// The mock runtime uses `()` as `FeeMultiplierUpdate`, which resets the multiplier.
+			let breakdown = TransactionPayment::query_fee_breakdown(xt, len, 7, 3);
+			assert_eq!(breakdown.inclusion_fee, Some(inclusion_fee));
+			assert_eq!(breakdown.projected_multiplier, Multiplier::default());
+			assert_eq!(breakdown.projected_fee, 5 * 2 + len as u64 + 7);
+
+			// Bare extrinsics pay no fee.
+			let breakdown = TransactionPayment::query_fee_breakdown(unsigned_xt, len, 7, 3);
+			assert_eq!(breakdown.inclusion_fee, None);
+			assert_eq!(breakdown.tip, 0);
+			assert_eq!(breakdown.projected_fee, 0);
+		});
+}
+
+#[test]
+fn simulate_fee_multiplier_works() {
+	ExtBuilder::default()
+		.base_weight(Weight::from_parts(100, 0))
+		.byte_fee(10)
+		.build()
+		.execute_with(|| {
+			let trajectory =
+				vec![Perquintill::one(), Perquintill::from_percent(75), Perquintill::zero()];
+			let weight = Weight::from_parts(123, 0);
+			let projections = Pallet::<Runtime>::simulate_fee_multiplier(
+				trajectory.clone(),
+				weight,
+				4,
+				SimTargetBlockFullness::get(),
+				SimAdjustmentVariable::get(),
+				SimMinimumMultiplier::get(),
+			);
+
+			// The given parameters are used instead of the `()` of the mock runtime, which would
+			// keep the multiplier at its default.
+			let mut expected = NextFeeMultiplier::<Runtime>::get();
+			assert_eq!(projections.len(), 3);
+			for (projection, fullness) in projections.into_iter().zip(trajectory) {
+				expected = SimFeeAdjustment::next_for_fullness(expected, fullness);
+				assert_eq!(projection.block_fullness, fullness);
+				assert_eq!(projection.multiplier, expected);
+				assert_eq!(
+					projection.inclusion_fee,
+					InclusionFee {
+						base_fee: 100,
+						len_fee: 40,
+						adjusted_weight_fee: projection.multiplier.saturating_mul_int(123),
+					}
+				);
+			}
+			assert!(expected != Multiplier::saturating_from_integer(1));
+
+			// The multiplier never falls below the given minimum.
+			let minimum = Multiplier::saturating_from_integer(2);
+			let projections = Pallet::<Runtime>::simulate_fee_multiplier(
+				vec![Perquintill::zero()],
+				weight,
+				4,
+				SimTargetBlockFullness::get(),
+				SimAdjustmentVariable::get(),
+				minimum,
+			);
+			assert_eq!(projections[0].multiplier, minimum);
+
+			// The simulation does not alter the stored multiplier.
+			assert_eq!(NextFeeMultiplier::<Runtime>::get(), Multiplier::saturating_from_integer(1));
+		});
+}
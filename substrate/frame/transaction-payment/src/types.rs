@@ -23,10 +23,15 @@ use serde::{Deserialize, Serialize};
 
 use scale_info::TypeInfo;
 
-use sp_runtime::traits::{AtLeast32BitUnsigned, Zero};
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Zero},
+	FixedU128, Perquintill,
+};
 
 use frame_support::dispatch::DispatchClass;
 
+use crate::Multiplier;
+
 /// The base fee and adjusted weight and length fees constitute the _inclusion fee_.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
@@ -90,6 +95,64 @@ impl<Balance: AtLeast32BitUnsigned + Copy> FeeDetails<Balance> {
 	}
 }
 
+/// The projected state of the fees after a simulated block, as returned by
+/// [`crate::Pallet::simulate_fee_multiplier`].
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FeeMultiplierProjection<Balance> {
+	/// The portion of the normal dispatch class capacity that the simulated block consumed.
+	pub block_fullness: Perquintill,
+	/// The fee multiplier in effect after the simulated block.
+	pub multiplier: Multiplier,
+	/// The inclusion fee of the queried transaction under `multiplier`.
+	pub inclusion_fee: InclusionFee<Balance>,
+}
+
+/// A breakdown of the fee of a transaction, as returned by
+/// [`crate::Pallet::query_fee_breakdown`].
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FeeBreakdown<Balance> {
+	/// The inclusion fee under the current `multiplier`, or `None` if the transaction does not pay
+	/// any fee.
+	pub inclusion_fee: Option<InclusionFee<Balance>>,
+	/// The weight fee before `multiplier` was applied to it.
+	pub unadjusted_weight_fee: Balance,
+	/// The fee multiplier which applies to the next block.
+	pub multiplier: Multiplier,
+	/// The tip paid on top of the inclusion fee.
+	pub tip: Balance,
+	/// The rate at which the fee is converted into the asset it is paid in.
+	///
+	/// `None` if the fee is paid in the native currency. This pallet always returns `None`;
+	/// runtimes which allow paying fees in other assets may fill it in.
+	pub conversion_rate: Option<FixedU128>,
+	/// The number of blocks for which the fee was projected.
+	pub blocks_ahead: u32,
+	/// The fee multiplier after `blocks_ahead` blocks which are as full as the current one.
+	pub projected_multiplier: Multiplier,
+	/// The final fee, including the tip, if the transaction is included under
+	/// `projected_multiplier`.
+	pub projected_fee: Balance,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy> FeeBreakdown<Balance> {
+	/// Returns the final fee under the current multiplier.
+	///
+	/// ```ignore
+	/// final_fee = inclusion_fee + tip;
+	/// ```
+	pub fn final_fee(&self) -> Balance {
+		self.inclusion_fee
+			.as_ref()
+			.map(|i| i.inclusion_fee())
+			.unwrap_or_else(|| Zero::zero())
+			.saturating_add(self.tip)
+	}
+}
+
 /// Information related to a dispatchable's class, weight, and fee that can be queried from the
 /// runtime.
 #[derive(Eq, PartialEq, Encode, Decode, Default, TypeInfo)]
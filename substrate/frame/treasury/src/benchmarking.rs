@@ -110,6 +110,34 @@ fn create_spend_arguments<T: Config<I>, I: 'static>(
 	(asset_kind, 100u32.into(), beneficiary, beneficiary_lookup)
 }
 
+// Create the milestones for the `spend_with_milestones` dispatchable, all expiring right after the
+// payout period.
+fn create_milestones<T: Config<I>, I: 'static>(
+	m: u32,
+) -> BoundedVec<(AssetBalanceOf<T, I>, BlockNumberFor<T>), T::MaxMilestones> {
+	let expire_at = T::BlockNumberProvider::current_block_number()
+		.saturating_add(T::PayoutPeriod::get())
+		.saturating_add(One::one());
+	BoundedVec::truncate_from((0..m).map(|_| (100u32.into(), expire_at)).collect())
+}
+
+// Create a spend held in escrow with `m` milestones, returning whether it could be created.
+fn create_escrow<T: Config<I>, I: 'static>(m: u32) -> Result<bool, BenchmarkError> {
+	let (asset_kind, _, _, beneficiary_lookup) = create_spend_arguments::<T, I>(SEED);
+	T::BalanceConverter::ensure_successful(asset_kind.clone());
+	if let Ok(origin) = T::SpendOrigin::try_successful_origin() {
+		Treasury::<T, I>::spend_with_milestones(
+			origin,
+			Box::new(asset_kind),
+			Box::new(beneficiary_lookup),
+			create_milestones::<T, I>(m),
+		)?;
+		Ok(true)
+	} else {
+		Ok(false)
+	}
+}
+
 #[instance_benchmarks]
 mod benchmarks {
 	use super::*;
@@ -350,6 +378,110 @@ mod benchmarks {
 		Ok(())
 	}
 
+	/// This benchmark is short-circuited if `SpendOrigin` cannot provide
+	/// a successful origin, in which case `spend_with_milestones` is un-callable and can use
+	/// weight=0.
+	#[benchmark]
+	fn spend_with_milestones(
+		m: Linear<1, { T::MaxMilestones::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let origin =
+			T::SpendOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let (asset_kind, _, beneficiary, beneficiary_lookup) = create_spend_arguments::<T, _>(SEED);
+		T::BalanceConverter::ensure_successful(asset_kind.clone());
+		let milestones = create_milestones::<T, _>(m);
+
+		#[extrinsic_call]
+		_(
+			origin as T::RuntimeOrigin,
+			Box::new(asset_kind.clone()),
+			Box::new(beneficiary_lookup),
+			milestones,
+		);
+
+		assert_last_event::<T, I>(
+			Event::EscrowApproved {
+				index: 0,
+				asset_kind,
+				amount: (100u32 * m).into(),
+				beneficiary,
+				milestones: m,
+			}
+			.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn release_milestone() -> Result<(), BenchmarkError> {
+		let escrow_exists = create_escrow::<T, _>(2)?;
+		let origin =
+			T::MilestoneOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[block]
+		{
+			let res = Treasury::<T, _>::release_milestone(origin as T::RuntimeOrigin, 0u32, 0u32);
+
+			if escrow_exists {
+				assert_ok!(res);
+			} else {
+				assert_err!(res, crate::Error::<T, _>::InvalidIndex);
+			}
+		}
+
+		if escrow_exists {
+			assert_last_event::<T, I>(
+				Event::MilestoneReleased { index: 0, milestone: 0, spend_index: 0 }.into(),
+			);
+		}
+		Ok(())
+	}
+
+	#[benchmark]
+	fn expire_milestone() -> Result<(), BenchmarkError> {
+		let escrow_exists = create_escrow::<T, _>(2)?;
+		if let Some(status) = Milestones::<T, I>::get(0, 0) {
+			T::BlockNumberProvider::set_block_number(status.expire_at);
+		}
+		let caller: T::AccountId = account("caller", 0, SEED);
+
+		#[block]
+		{
+			let res =
+				Treasury::<T, _>::expire_milestone(RawOrigin::Signed(caller).into(), 0u32, 0u32);
+
+			if escrow_exists {
+				assert_ok!(res);
+			} else {
+				assert_err!(res, crate::Error::<T, _>::InvalidIndex);
+			}
+		}
+
+		assert!(Milestones::<T, I>::get(0, 0).is_none());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn void_escrow(m: Linear<1, { T::MaxMilestones::get() }>) -> Result<(), BenchmarkError> {
+		let escrow_exists = create_escrow::<T, _>(m)?;
+		let origin =
+			T::RejectOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[block]
+		{
+			let res = Treasury::<T, _>::void_escrow(origin as T::RuntimeOrigin, 0u32);
+
+			if escrow_exists {
+				assert_ok!(res);
+			} else {
+				assert_err!(res, crate::Error::<T, _>::InvalidIndex);
+			}
+		}
+
+		assert!(Escrows::<T, I>::get(0).is_none());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Treasury,
 		crate::tests::ExtBuilder::default().build(),
@@ -45,6 +45,8 @@
 //! - **Pot:** Unspent funds accumulated by the treasury pallet.
 //! - **Spend** An approved proposal for transferring a specific amount of funds to a designated
 //!   beneficiary.
+//! - **Escrow** An approved spend split into milestones, each of which becomes a spend once
+//!   released.
 //!
 //! ### Example
 //!
@@ -69,6 +71,12 @@
 //! [`pallet::Config::Paymaster`]. To claim these spends, the `payout` dispatchable should be called
 //! within some temporal bounds, starting from the moment they become valid and within one
 //! [`pallet::Config::PayoutPeriod`].
+//!
+//! The `spend_with_milestones` dispatchable approves a spend held in escrow instead, split into
+//! milestones. Each milestone is released by the [`pallet::Config::MilestoneOrigin`] before it
+//! expires, turning it into a spend claimable with the `payout` dispatchable. Milestones which
+//! expire before being released are no longer payable, and can be removed by anyone with the
+//! `expire_milestone` dispatchable.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -93,7 +101,7 @@ use sp_runtime::{
 		AccountIdConversion, BlockNumberProvider, CheckedAdd, One, Saturating, StaticLookup,
 		UniqueSaturatedInto, Zero,
 	},
-	PerThing, Permill, RuntimeDebug,
+	ArithmeticError, PerThing, Permill, RuntimeDebug,
 };
 
 use frame_support::{
@@ -194,6 +202,34 @@ pub struct SpendStatus<AssetKind, AssetBalance, Beneficiary, BlockNumber, Paymen
 /// Index of an approved treasury spend.
 pub type SpendIndex = u32;
 
+/// Info regarding an approved treasury spend held in escrow until its milestones are released.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct EscrowStatus<AssetKind, Beneficiary> {
+	/// The kind of asset to be spent.
+	asset_kind: AssetKind,
+	/// The beneficiary of the spend.
+	beneficiary: Beneficiary,
+	/// The number of milestones which were neither released nor expired yet.
+	pending: u32,
+}
+
+/// Info regarding a milestone of a treasury spend held in escrow.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct MilestoneStatus<AssetBalance, BlockNumber> {
+	/// The asset amount released with the milestone.
+	amount: AssetBalance,
+	/// The block number by which the milestone has to be released.
+	expire_at: BlockNumber,
+}
+
+/// Index of an approved treasury spend held in escrow.
+pub type EscrowIndex = u32;
+
+/// Index of a milestone within a treasury spend held in escrow.
+pub type MilestoneIndex = u32;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -279,6 +315,13 @@ pub mod pallet {
 		#[pallet::constant]
 		type PayoutPeriod: Get<BlockNumberFor<Self>>;
 
+		/// The origin required for releasing the milestones of spends held in escrow.
+		type MilestoneOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum number of milestones a spend held in escrow can be split into.
+		#[pallet::constant]
+		type MaxMilestones: Get<u32>;
+
 		/// Helper type for benchmarks.
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: ArgumentsFactory<Self::AssetKind, Self::Beneficiary>;
@@ -341,6 +384,34 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The count of spends held in escrow that have been made.
+	#[pallet::storage]
+	pub(crate) type EscrowCount<T, I = ()> = StorageValue<_, EscrowIndex, ValueQuery>;
+
+	/// Spends that have been approved and are held in escrow until their milestones are released.
+	// Hasher: Twox safe since `EscrowIndex` is an internal count based index.
+	#[pallet::storage]
+	pub type Escrows<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		EscrowIndex,
+		EscrowStatus<T::AssetKind, T::Beneficiary>,
+		OptionQuery,
+	>;
+
+	/// The milestones of the spends held in escrow which were neither released nor expired yet.
+	// Hasher: Twox safe since both indices are internal count based indices.
+	#[pallet::storage]
+	pub type Milestones<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EscrowIndex,
+		Twox64Concat,
+		MilestoneIndex,
+		MilestoneStatus<AssetBalanceOf<T, I>, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
 	/// The blocknumber for the last triggered spend period.
 	#[pallet::storage]
 	pub(crate) type LastSpendPeriod<T, I = ()> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
@@ -403,6 +474,20 @@ pub mod pallet {
 		/// A spend was processed and removed from the storage. It might have been successfully
 		/// paid or it may have expired.
 		SpendProcessed { index: SpendIndex },
+		/// A new asset spend held in escrow has been approved.
+		EscrowApproved {
+			index: EscrowIndex,
+			asset_kind: T::AssetKind,
+			amount: AssetBalanceOf<T, I>,
+			beneficiary: T::Beneficiary,
+			milestones: u32,
+		},
+		/// A milestone of a spend held in escrow was released as an approved spend.
+		MilestoneReleased { index: EscrowIndex, milestone: MilestoneIndex, spend_index: SpendIndex },
+		/// A milestone of a spend held in escrow expired before being released.
+		MilestoneExpired { index: EscrowIndex, milestone: MilestoneIndex },
+		/// A spend held in escrow was voided along with its remaining milestones.
+		EscrowVoided { index: EscrowIndex },
 	}
 
 	/// Error for the treasury pallet.
@@ -431,6 +516,12 @@ pub mod pallet {
 		NotAttempted,
 		/// The payment has neither failed nor succeeded yet.
 		Inconclusive,
+		/// A spend held in escrow must be split into at least one milestone.
+		NoMilestones,
+		/// The milestone has expired and cannot be released.
+		MilestoneExpired,
+		/// The milestone has not expired yet.
+		MilestoneNotExpired,
 	}
 
 	#[pallet::hooks]
@@ -827,6 +918,227 @@ pub mod pallet {
 			Self::deposit_event(Event::<T, I>::AssetSpendVoided { index });
 			Ok(())
 		}
+
+		/// Propose and approve a spend of treasury funds held in escrow, split into milestones.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::SpendOrigin`] with the `Success` value being at least the total
+		/// amount of `asset_kind` over all milestones in the native asset. The amount of
+		/// `asset_kind` is converted for assertion using the [`Config::BalanceConverter`].
+		///
+		/// ## Details
+		///
+		/// Nothing is paid until a milestone is released with the `release_milestone`
+		/// dispatchable, which turns it into an approved spend of its amount to the beneficiary.
+		/// Milestones which are not released by their expiry can no longer be released.
+		///
+		/// ### Parameters
+		/// - `asset_kind`: An indicator of the specific asset class to be spent.
+		/// - `beneficiary`: The beneficiary of the spend.
+		/// - `milestones`: The amount of each milestone, along with the block number by which it
+		///   has to be released.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::EscrowApproved`] if successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::spend_with_milestones(milestones.len() as u32))]
+		pub fn spend_with_milestones(
+			origin: OriginFor<T>,
+			asset_kind: Box<T::AssetKind>,
+			beneficiary: Box<BeneficiaryLookupOf<T, I>>,
+			milestones: BoundedVec<(AssetBalanceOf<T, I>, BlockNumberFor<T>), T::MaxMilestones>,
+		) -> DispatchResult {
+			let max_amount = T::SpendOrigin::ensure_origin(origin)?;
+			let beneficiary = T::BeneficiaryLookup::lookup(*beneficiary)?;
+			ensure!(!milestones.is_empty(), Error::<T, I>::NoMilestones);
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let mut amount = AssetBalanceOf::<T, I>::zero();
+			for (milestone_amount, expire_at) in milestones.iter() {
+				ensure!(*expire_at > now, Error::<T, I>::MilestoneExpired);
+				amount = amount.checked_add(milestone_amount).ok_or(ArithmeticError::Overflow)?;
+			}
+
+			let native_amount =
+				T::BalanceConverter::from_asset_balance(amount, *asset_kind.clone())
+					.map_err(|_| Error::<T, I>::FailedToConvertBalance)?;
+
+			ensure!(native_amount <= max_amount, Error::<T, I>::InsufficientPermission);
+
+			with_context::<SpendContext<BalanceOf<T, I>>, _>(|v| {
+				let context = v.or_default();
+				// We group based on `max_amount`, to distinguish between different kind of
+				// origins. (assumes that all origins have different `max_amount`)
+				//
+				// Worst case is that we reject some "valid" request.
+				let spend = context.spend_in_context.entry(max_amount).or_default();
+
+				// Ensure that we don't overflow nor use more than `max_amount`
+				if spend.checked_add(&native_amount).map(|s| s > max_amount).unwrap_or(true) {
+					Err(Error::<T, I>::InsufficientPermission)
+				} else {
+					*spend = spend.saturating_add(native_amount);
+					Ok(())
+				}
+			})
+			.unwrap_or(Ok(()))?;
+
+			let index = EscrowCount::<T, I>::get();
+			for (milestone, (amount, expire_at)) in milestones.iter().enumerate() {
+				Milestones::<T, I>::insert(
+					index,
+					milestone as MilestoneIndex,
+					MilestoneStatus { amount: *amount, expire_at: *expire_at },
+				);
+			}
+			Escrows::<T, I>::insert(
+				index,
+				EscrowStatus {
+					asset_kind: *asset_kind.clone(),
+					beneficiary: beneficiary.clone(),
+					pending: milestones.len() as u32,
+				},
+			);
+			EscrowCount::<T, I>::put(index + 1);
+
+			Self::deposit_event(Event::EscrowApproved {
+				index,
+				asset_kind: *asset_kind,
+				amount,
+				beneficiary,
+				milestones: milestones.len() as u32,
+			});
+			Ok(())
+		}
+
+		/// Release a milestone of a spend held in escrow.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::MilestoneOrigin`].
+		///
+		/// ## Details
+		///
+		/// The milestone is turned into an approved spend of its amount to the beneficiary, valid
+		/// immediately. It must be claimed using the `payout` dispatchable within the
+		/// [`Config::PayoutPeriod`].
+		///
+		/// ### Parameters
+		/// - `index`: The index of the spend held in escrow.
+		/// - `milestone`: The index of the milestone within the spend.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::MilestoneReleased`] and [`Event::AssetSpendApproved`] if successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::release_milestone())]
+		pub fn release_milestone(
+			origin: OriginFor<T>,
+			index: EscrowIndex,
+			milestone: MilestoneIndex,
+		) -> DispatchResult {
+			T::MilestoneOrigin::ensure_origin(origin)?;
+			let escrow = Escrows::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let status =
+				Milestones::<T, I>::get(index, milestone).ok_or(Error::<T, I>::InvalidIndex)?;
+			let now = T::BlockNumberProvider::current_block_number();
+			ensure!(status.expire_at > now, Error::<T, I>::MilestoneExpired);
+
+			let spend_index = SpendCount::<T, I>::get();
+			let expire_at = now.saturating_add(T::PayoutPeriod::get());
+			Spends::<T, I>::insert(
+				spend_index,
+				SpendStatus {
+					asset_kind: escrow.asset_kind.clone(),
+					amount: status.amount,
+					beneficiary: escrow.beneficiary.clone(),
+					valid_from: now,
+					expire_at,
+					status: PaymentState::Pending,
+				},
+			);
+			SpendCount::<T, I>::put(spend_index + 1);
+
+			Self::deposit_event(Event::AssetSpendApproved {
+				index: spend_index,
+				asset_kind: escrow.asset_kind.clone(),
+				amount: status.amount,
+				beneficiary: escrow.beneficiary.clone(),
+				valid_from: now,
+				expire_at,
+			});
+			Self::remove_milestone(index, milestone, escrow);
+			Self::deposit_event(Event::MilestoneReleased { index, milestone, spend_index });
+			Ok(())
+		}
+
+		/// Remove a milestone of a spend held in escrow which expired before being released.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be signed.
+		///
+		/// ## Details
+		///
+		/// The spend held in escrow is removed along with its last milestone. In such instances,
+		/// transaction fees are refunded.
+		///
+		/// ### Parameters
+		/// - `index`: The index of the spend held in escrow.
+		/// - `milestone`: The index of the milestone within the spend.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::MilestoneExpired`] if successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::expire_milestone())]
+		pub fn expire_milestone(
+			origin: OriginFor<T>,
+			index: EscrowIndex,
+			milestone: MilestoneIndex,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let escrow = Escrows::<T, I>::get(index).ok_or(Error::<T, I>::InvalidIndex)?;
+			let status =
+				Milestones::<T, I>::get(index, milestone).ok_or(Error::<T, I>::InvalidIndex)?;
+			let now = T::BlockNumberProvider::current_block_number();
+			ensure!(now >= status.expire_at, Error::<T, I>::MilestoneNotExpired);
+
+			Self::remove_milestone(index, milestone, escrow);
+			Self::deposit_event(Event::<T, I>::MilestoneExpired { index, milestone });
+			Ok(Pays::No.into())
+		}
+
+		/// Void a previously approved spend held in escrow.
+		///
+		/// ## Dispatch Origin
+		///
+		/// Must be [`Config::RejectOrigin`].
+		///
+		/// ## Details
+		///
+		/// Only the milestones which were not released yet are voided. Released milestones are
+		/// regular spends which can be voided with the `void_spend` dispatchable.
+		///
+		/// ### Parameters
+		/// - `index`: The index of the spend held in escrow.
+		///
+		/// ## Events
+		///
+		/// Emits [`Event::EscrowVoided`] if successful.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::void_escrow(T::MaxMilestones::get()))]
+		pub fn void_escrow(origin: OriginFor<T>, index: EscrowIndex) -> DispatchResult {
+			T::RejectOrigin::ensure_origin(origin)?;
+			ensure!(Escrows::<T, I>::contains_key(index), Error::<T, I>::InvalidIndex);
+
+			let _ = Milestones::<T, I>::clear_prefix(index, T::MaxMilestones::get(), None);
+			Escrows::<T, I>::remove(index);
+			Self::deposit_event(Event::<T, I>::EscrowVoided { index });
+			Ok(())
+		}
 	}
 }
 
@@ -978,6 +1290,22 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		total_weight
 	}
 
+	/// Remove a milestone which was either released or expired from a spend held in escrow,
+	/// removing the spend as well once it has no milestones left.
+	fn remove_milestone(
+		index: EscrowIndex,
+		milestone: MilestoneIndex,
+		mut escrow: EscrowStatus<T::AssetKind, T::Beneficiary>,
+	) {
+		Milestones::<T, I>::remove(index, milestone);
+		escrow.pending.saturating_dec();
+		if escrow.pending.is_zero() {
+			Escrows::<T, I>::remove(index);
+		} else {
+			Escrows::<T, I>::insert(index, escrow);
+		}
+	}
+
 	/// Return the amount of money in the pot.
 	// The existential deposit is not part of the pot so treasury account never gets deleted.
 	pub fn pot() -> BalanceOf<T, I> {
@@ -991,6 +1319,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
 		Self::try_state_proposals()?;
 		Self::try_state_spends()?;
+		Self::try_state_escrows()?;
 
 		Ok(())
 	}
@@ -1064,6 +1393,47 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Ok(())
 	}
+
+	/// ## Invariants of escrow storage items
+	///
+	/// 1. [`EscrowCount`] >= Number of elements in [`Escrows`].
+	/// 2. Each entry in [`Escrows`] should be saved under a key strictly less than current
+	/// [`EscrowCount`].
+	/// 3. For each escrow entry contained in [`Escrows`], the number of its pending milestones
+	/// should be non-zero and match the number of its entries in [`Milestones`].
+	/// 4. Each entry in [`Milestones`] should belong to an entry in [`Escrows`].
+	#[cfg(any(feature = "try-runtime", test))]
+	fn try_state_escrows() -> Result<(), sp_runtime::TryRuntimeError> {
+		let current_escrow_count = EscrowCount::<T, I>::get();
+		ensure!(
+			current_escrow_count as usize >= Escrows::<T, I>::iter().count(),
+			"Actual number of escrows exceeds `EscrowCount`."
+		);
+
+		Escrows::<T, I>::iter().try_for_each(|(escrow_index, escrow)| -> DispatchResult {
+			ensure!(
+				current_escrow_count > escrow_index,
+				"`EscrowCount` should by strictly greater than any EscrowIndex used as a key for `Escrows`."
+			);
+			ensure!(
+				!escrow.pending.is_zero() &&
+					escrow.pending as usize ==
+						Milestones::<T, I>::iter_prefix(escrow_index).count(),
+				"Pending milestones of an escrow must match its entries in `Milestones`."
+			);
+			Ok(())
+		})?;
+
+		Milestones::<T, I>::iter_keys().try_for_each(|(escrow_index, _)| -> DispatchResult {
+			ensure!(
+				Escrows::<T, I>::contains_key(escrow_index),
+				"Milestones must belong to an escrow in `Escrows`."
+			);
+			Ok(())
+		})?;
+
+		Ok(())
+	}
 }
 
 impl<T: Config<I>, I: 'static> OnUnbalanced<NegativeImbalanceOf<T, I>> for Pallet<T, I> {
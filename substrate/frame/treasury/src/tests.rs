@@ -21,6 +21,7 @@
 
 use core::{cell::RefCell, marker::PhantomData};
 use sp_runtime::{
+	bounded_vec,
 	traits::{BadOrigin, Dispatchable, IdentityLookup},
 	BuildStorage,
 };
@@ -193,6 +194,8 @@ impl Config for Test {
 	type Paymaster = TestPay;
 	type BalanceConverter = MulBy<ConstU64<2>>;
 	type PayoutPeriod = SpendPayoutPeriod;
+	type MilestoneOrigin = frame_system::EnsureRoot<u128>;
+	type MaxMilestones = ConstU32<3>;
 	type BlockNumberProvider = System;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
@@ -813,6 +816,161 @@ fn check_status_works() {
 	});
 }
 
+#[test]
+fn spend_with_milestones_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		// the total amount of all milestones is checked against the spend origin.
+		assert_noop!(
+			Treasury::spend_with_milestones(
+				RuntimeOrigin::signed(10),
+				Box::new(1),
+				Box::new(6),
+				bounded_vec![(2, 10), (1, 20)],
+			),
+			Error::<Test, _>::InsufficientPermission
+		);
+		assert_noop!(
+			Treasury::spend_with_milestones(
+				RuntimeOrigin::signed(11),
+				Box::new(1),
+				Box::new(6),
+				bounded_vec![],
+			),
+			Error::<Test, _>::NoMilestones
+		);
+		assert_noop!(
+			Treasury::spend_with_milestones(
+				RuntimeOrigin::signed(11),
+				Box::new(1),
+				Box::new(6),
+				bounded_vec![(2, 10), (1, 1)],
+			),
+			Error::<Test, _>::MilestoneExpired
+		);
+
+		assert_ok!(Treasury::spend_with_milestones(
+			RuntimeOrigin::signed(11),
+			Box::new(1),
+			Box::new(6),
+			bounded_vec![(2, 10), (3, 20)],
+		));
+		System::assert_last_event(
+			Event::<Test, _>::EscrowApproved {
+				index: 0,
+				asset_kind: 1,
+				amount: 5,
+				beneficiary: 6,
+				milestones: 2,
+			}
+			.into(),
+		);
+		assert_eq!(
+			Escrows::<Test, _>::get(0),
+			Some(EscrowStatus { asset_kind: 1, beneficiary: 6, pending: 2 })
+		);
+
+		// only the milestone origin can release a milestone.
+		assert_noop!(Treasury::release_milestone(RuntimeOrigin::signed(11), 0, 1), BadOrigin);
+		assert_noop!(
+			Treasury::release_milestone(RuntimeOrigin::root(), 0, 2),
+			Error::<Test, _>::InvalidIndex
+		);
+
+		// a released milestone becomes a spend which can be claimed.
+		System::set_block_number(3);
+		assert_ok!(Treasury::release_milestone(RuntimeOrigin::root(), 0, 1));
+		System::assert_last_event(
+			Event::<Test, _>::MilestoneReleased { index: 0, milestone: 1, spend_index: 0 }.into(),
+		);
+		assert_eq!(
+			Spends::<Test, _>::get(0),
+			Some(SpendStatus {
+				asset_kind: 1,
+				amount: 3,
+				beneficiary: 6,
+				valid_from: 3,
+				expire_at: 8,
+				status: PaymentState::Pending,
+			})
+		);
+		assert_eq!(Escrows::<Test, _>::get(0).map(|escrow| escrow.pending), Some(1));
+		assert_ok!(Treasury::payout(RuntimeOrigin::signed(1), 0));
+		assert_eq!(paid(6, 1), 3);
+
+		// a milestone cannot be released twice.
+		assert_noop!(
+			Treasury::release_milestone(RuntimeOrigin::root(), 0, 1),
+			Error::<Test, _>::InvalidIndex
+		);
+
+		// the escrow is removed along with its last milestone.
+		assert_ok!(Treasury::release_milestone(RuntimeOrigin::root(), 0, 0));
+		assert_eq!(Escrows::<Test, _>::get(0), None);
+		assert_eq!(Milestones::<Test, _>::iter_prefix(0).count(), 0);
+		assert_eq!(Spends::<Test, _>::get(1).map(|spend| spend.amount), Some(2));
+	});
+}
+
+#[test]
+fn unreleased_milestones_expire() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_with_milestones(
+			RuntimeOrigin::signed(11),
+			Box::new(1),
+			Box::new(6),
+			bounded_vec![(2, 5), (3, 10)],
+		));
+		assert_noop!(
+			Treasury::expire_milestone(RuntimeOrigin::signed(1), 0, 0),
+			Error::<Test, _>::MilestoneNotExpired
+		);
+
+		System::set_block_number(5);
+		assert_noop!(
+			Treasury::release_milestone(RuntimeOrigin::root(), 0, 0),
+			Error::<Test, _>::MilestoneExpired
+		);
+		let info = Treasury::expire_milestone(RuntimeOrigin::signed(1), 0, 0).unwrap();
+		assert_eq!(info.pays_fee, Pays::No);
+		System::assert_last_event(
+			Event::<Test, _>::MilestoneExpired { index: 0, milestone: 0 }.into(),
+		);
+		assert_eq!(Milestones::<Test, _>::get(0, 0), None);
+
+		// the remaining milestone can still be released.
+		assert_ok!(Treasury::release_milestone(RuntimeOrigin::root(), 0, 1));
+		assert_eq!(Escrows::<Test, _>::get(0), None);
+	});
+}
+
+#[test]
+fn void_escrow_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Treasury::spend_with_milestones(
+			RuntimeOrigin::signed(11),
+			Box::new(1),
+			Box::new(6),
+			bounded_vec![(1, 10), (1, 10), (1, 10)],
+		));
+		assert_ok!(Treasury::release_milestone(RuntimeOrigin::root(), 0, 0));
+
+		assert_noop!(Treasury::void_escrow(RuntimeOrigin::signed(1), 0), BadOrigin);
+		assert_ok!(Treasury::void_escrow(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::<Test, _>::EscrowVoided { index: 0 }.into());
+		assert_eq!(Escrows::<Test, _>::get(0), None);
+		assert_eq!(Milestones::<Test, _>::iter_prefix(0).count(), 0);
+		// the released milestone is unaffected.
+		assert!(Spends::<Test, _>::get(0).is_some());
+		assert_noop!(
+			Treasury::void_escrow(RuntimeOrigin::root(), 0),
+			Error::<Test, _>::InvalidIndex
+		);
+	});
+}
+
 #[test]
 fn try_state_proposals_invariant_1_works() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-wiukf8gn-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `spend_with_milestones`, `release_milestone`, `expire_milestone` and `void_escrow`. Re-
+//! run the benchmarks before relying on them.
 
 // Executed Command:
 // ./target/production/substrate-node
@@ -58,6 +62,10 @@ pub trait WeightInfo {
 	fn payout() -> Weight;
 	fn check_status() -> Weight;
 	fn void_spend() -> Weight;
+	fn spend_with_milestones(m: u32, ) -> Weight;
+	fn release_milestone() -> Weight;
+	fn expire_milestone() -> Weight;
+	fn void_escrow(m: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_treasury` using the Substrate node and recommended hardware.
@@ -159,6 +167,74 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:0)
+	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::EscrowCount` (r:1 w:1)
+	/// Proof: `Treasury::EscrowCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Milestones` (r:0 w:16)
+	/// Proof: `Treasury::Milestones` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Escrows` (r:0 w:1)
+	/// Proof: `Treasury::Escrows` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 16]`.
+	fn spend_with_milestones(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114`
+		//  Estimated: `3502`
+		// Minimum execution time: 15_772_000 picoseconds.
+		Weight::from_parts(15_772_000, 3502)
+			// Standard Error: 9_214
+			.saturating_add(Weight::from_parts(1_942_337, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+	}
+	/// Storage: `Treasury::Escrows` (r:1 w:1)
+	/// Proof: `Treasury::Escrows` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Milestones` (r:1 w:1)
+	/// Proof: `Treasury::Milestones` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendCount` (r:1 w:1)
+	/// Proof: `Treasury::SpendCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Spends` (r:0 w:1)
+	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	fn release_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `305`
+		//  Estimated: `3518`
+		// Minimum execution time: 21_455_000 picoseconds.
+		Weight::from_parts(21_455_000, 3518)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Treasury::Milestones` (r:1 w:1)
+	/// Proof: `Treasury::Milestones` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Escrows` (r:1 w:1)
+	/// Proof: `Treasury::Escrows` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn expire_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `305`
+		//  Estimated: `3518`
+		// Minimum execution time: 16_113_000 picoseconds.
+		Weight::from_parts(16_113_000, 3518)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Treasury::Escrows` (r:1 w:1)
+	/// Proof: `Treasury::Escrows` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Milestones` (r:0 w:16)
+	/// Proof: `Treasury::Milestones` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 16]`.
+	fn void_escrow(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `191`
+		//  Estimated: `3518`
+		// Minimum execution time: 13_807_000 picoseconds.
+		Weight::from_parts(13_807_000, 3518)
+			// Standard Error: 8_611
+			.saturating_add(Weight::from_parts(1_203_871, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -259,4 +335,72 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `AssetRate::ConversionRateToNative` (r:1 w:0)
+	/// Proof: `AssetRate::ConversionRateToNative` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::EscrowCount` (r:1 w:1)
+	/// Proof: `Treasury::EscrowCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Milestones` (r:0 w:16)
+	/// Proof: `Treasury::Milestones` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Escrows` (r:0 w:1)
+	/// Proof: `Treasury::Escrows` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 16]`.
+	fn spend_with_milestones(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114`
+		//  Estimated: `3502`
+		// Minimum execution time: 15_772_000 picoseconds.
+		Weight::from_parts(15_772_000, 3502)
+			// Standard Error: 9_214
+			.saturating_add(Weight::from_parts(1_942_337, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(m.into())))
+	}
+	/// Storage: `Treasury::Escrows` (r:1 w:1)
+	/// Proof: `Treasury::Escrows` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Milestones` (r:1 w:1)
+	/// Proof: `Treasury::Milestones` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::SpendCount` (r:1 w:1)
+	/// Proof: `Treasury::SpendCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Spends` (r:0 w:1)
+	/// Proof: `Treasury::Spends` (`max_values`: None, `max_size`: Some(74), added: 2549, mode: `MaxEncodedLen`)
+	fn release_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `305`
+		//  Estimated: `3518`
+		// Minimum execution time: 21_455_000 picoseconds.
+		Weight::from_parts(21_455_000, 3518)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Treasury::Milestones` (r:1 w:1)
+	/// Proof: `Treasury::Milestones` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Escrows` (r:1 w:1)
+	/// Proof: `Treasury::Escrows` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn expire_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `305`
+		//  Estimated: `3518`
+		// Minimum execution time: 16_113_000 picoseconds.
+		Weight::from_parts(16_113_000, 3518)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Treasury::Escrows` (r:1 w:1)
+	/// Proof: `Treasury::Escrows` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `Treasury::Milestones` (r:0 w:16)
+	/// Proof: `Treasury::Milestones` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 16]`.
+	fn void_escrow(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `191`
+		//  Estimated: `3518`
+		// Minimum execution time: 13_807_000 picoseconds.
+		Weight::from_parts(13_807_000, 3518)
+			// Standard Error: 8_611
+			.saturating_add(Weight::from_parts(1_203_871, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(m.into())))
+	}
 }
@@ -20,6 +20,7 @@
 use super::{Pallet as TxPause, *};
 use alloc::vec;
 use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
 
 #[benchmarks]
 mod benchmarks {
@@ -50,6 +51,46 @@ mod benchmarks {
 		assert!(PausedCalls::<T>::get(full_name).is_none());
 	}
 
+	#[benchmark]
+	fn pause_call_index() {
+		let origin = T::PauseOrigin::try_successful_origin()
+			.expect("Tx-pause pallet is not usable without pause origin");
+		let index = call_index::<T>();
+		let until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, index, Some(until));
+
+		assert_eq!(PausedCallIndices::<T>::get(index), Some(Some(until)));
+	}
+
+	#[benchmark]
+	fn unpause_call_index() {
+		let unpause_origin = T::UnpauseOrigin::try_successful_origin()
+			.expect("Tx-pause pallet is not usable without pause origin");
+		let index = call_index::<T>();
+		TxPause::<T>::do_pause_call_index(index, None).unwrap();
+
+		#[extrinsic_call]
+		_(unpause_origin as T::RuntimeOrigin, index);
+
+		assert!(PausedCallIndices::<T>::get(index).is_none());
+	}
+
+	#[benchmark]
+	fn remove_expired_pause() {
+		let caller: T::AccountId = whitelisted_caller();
+		let index = call_index::<T>();
+		let until = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		TxPause::<T>::do_pause_call_index(index, Some(until)).unwrap();
+		frame_system::Pallet::<T>::set_block_number(until);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), index);
+
+		assert!(PausedCallIndices::<T>::get(index).is_none());
+	}
+
 	impl_benchmark_test_suite!(TxPause, crate::mock::new_test_ext(), crate::mock::Test);
 }
 
@@ -58,3 +99,9 @@ fn name<T: Config>() -> RuntimeCallNameOf<T> {
 	let max_len = T::MaxNameLen::get() as usize;
 	(vec![1; max_len].try_into().unwrap(), vec![1; max_len].try_into().unwrap())
 }
+
+/// A call index of a pallet other than this one.
+fn call_index<T: Config>() -> RuntimeCallIndex {
+	let own_index = <TxPause<T> as PalletInfoAccess>::index() as u8;
+	(own_index.wrapping_add(1), Some(0))
+}
@@ -38,6 +38,9 @@
 //! - Pausing is triggered using the string representation of the call.
 //! - Pauses can target a single extrinsic or an entire pallet.
 //! - Pauses can target future extrinsics or pallets.
+//! - Pauses can also target a call or an entire pallet by its index in the runtime, optionally
+//!   expiring at a given block. This allows disabling e.g. `Identity::set_subs` without a runtime
+//!   upgrade and without knowing more than its call index.
 //!
 //! ### Example
 //!
@@ -60,12 +63,21 @@
 //! Pause all calls in a pallet:
 #![doc = docify::embed!("src/tests.rs", can_pause_all_calls_in_pallet_except_on_whitelist)]
 //!
+//! Pause a call by its index until a given block:
+#![doc = docify::embed!("src/tests.rs", can_pause_call_by_index_until_expiry)]
+//!
 //! ## Low Level / Implementation Details
 //!
 //! ### Use Cost
 //!
-//! A storage map (`PausedCalls`) is used to store currently paused calls.
-//! Using the call filter will require a db read of that storage on each extrinsic.
+//! Calls paused by name are stored in `PausedCalls` and calls paused by index in
+//! `PausedCallIndices`. The call filter reads `PausedCalls` once for every call which is not
+//! whitelisted. Calls which are not paused by name are then encoded to find out their indices,
+//! which costs as much as the size of the call, and `PausedCallIndices` is read twice: once for
+//! the pallet and once for the call. Pauses with an expiry additionally read the block number.
+//!
+//! Expired pauses are not removed automatically and are read by the filter until they are removed
+//! with `remove_expired_pause`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(rustdoc::broken_intra_doc_links)]
@@ -101,6 +113,11 @@ pub type PalletCallNameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLen>;
 /// to partially or fully specify an item a variant of a  [`Config::RuntimeCall`].
 pub type RuntimeCallNameOf<T> = (PalletNameOf<T>, PalletCallNameOf<T>);
 
+/// The index of a pallet in the runtime and optionally the index of a call within that pallet.
+///
+/// Without a call index, all calls of the pallet are targeted.
+pub type RuntimeCallIndex = (u8, Option<u8>);
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -149,6 +166,13 @@ pub mod pallet {
 	pub type PausedCalls<T: Config> =
 		StorageMap<_, Blake2_128Concat, RuntimeCallNameOf<T>, (), OptionQuery>;
 
+	/// The set of calls, or entire pallets, that are paused by index.
+	///
+	/// The value is the block at which the pause expires, `None` if it does not.
+	#[pallet::storage]
+	pub type PausedCallIndices<T: Config> =
+		StorageMap<_, Twox64Concat, RuntimeCallIndex, Option<BlockNumberFor<T>>, OptionQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// The call is paused.
@@ -162,6 +186,12 @@ pub mod pallet {
 
 		// The pallet or call does not exist in the runtime.
 		NotFound,
+
+		/// The expiry of the pause is not in the future.
+		InvalidExpiry,
+
+		/// The pause did not expire yet.
+		NotExpired,
 	}
 
 	#[pallet::event]
@@ -171,6 +201,12 @@ pub mod pallet {
 		CallPaused { full_name: RuntimeCallNameOf<T> },
 		/// This pallet, or a specific call is now unpaused.
 		CallUnpaused { full_name: RuntimeCallNameOf<T> },
+		/// A call, or all calls of a pallet, are now paused by index.
+		CallIndexPaused { index: RuntimeCallIndex, until: Option<BlockNumberFor<T>> },
+		/// A call, or all calls of a pallet, are now unpaused by index.
+		CallIndexUnpaused { index: RuntimeCallIndex },
+		/// The pause of a call, or all calls of a pallet, expired and was removed.
+		CallIndexPauseExpired { index: RuntimeCallIndex },
 	}
 
 	/// Configure the initial state of this pallet in the genesis block.
@@ -216,6 +252,56 @@ pub mod pallet {
 
 			Self::do_unpause(ident).map_err(Into::into)
 		}
+
+		/// Pause a call, or all calls of a pallet, by index.
+		///
+		/// The pause is lifted automatically at block `until`, if given.
+		///
+		/// Can only be called by [`Config::PauseOrigin`].
+		/// Emits an [`Event::CallIndexPaused`] event on success.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::pause_call_index())]
+		pub fn pause_call_index(
+			origin: OriginFor<T>,
+			index: RuntimeCallIndex,
+			until: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			Self::do_pause_call_index(index, until).map_err(Into::into)
+		}
+
+		/// Un-pause a call, or all calls of a pallet, paused by index.
+		///
+		/// Can only be called by [`Config::UnpauseOrigin`].
+		/// Emits an [`Event::CallIndexUnpaused`] event on success.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::unpause_call_index())]
+		pub fn unpause_call_index(origin: OriginFor<T>, index: RuntimeCallIndex) -> DispatchResult {
+			T::UnpauseOrigin::ensure_origin(origin)?;
+
+			Self::do_unpause_call_index(index).map_err(Into::into)
+		}
+
+		/// Remove an expired pause of a call, or all calls of a pallet.
+		///
+		/// Can be called by any signed origin.
+		/// Emits an [`Event::CallIndexPauseExpired`] event on success.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::remove_expired_pause())]
+		pub fn remove_expired_pause(
+			origin: OriginFor<T>,
+			index: RuntimeCallIndex,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let until = PausedCallIndices::<T>::get(index).ok_or(Error::<T>::IsUnpaused)?;
+			ensure!(!Self::is_pause_active(until), Error::<T>::NotExpired);
+			PausedCallIndices::<T>::remove(index);
+			Self::deposit_event(Event::CallIndexPauseExpired { index });
+
+			Ok(())
+		}
 	}
 }
 
@@ -236,6 +322,58 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	pub(crate) fn do_pause_call_index(
+		index: RuntimeCallIndex,
+		until: Option<BlockNumberFor<T>>,
+	) -> Result<(), Error<T>> {
+		// SAFETY: The `TxPause` pallet can never pause itself.
+		if index.0 as usize == <Self as PalletInfoAccess>::index() {
+			return Err(Error::<T>::Unpausable)
+		}
+		if Self::is_call_index_paused(index) {
+			return Err(Error::<T>::IsPaused)
+		}
+		if until.map_or(false, |until| until <= frame_system::Pallet::<T>::block_number()) {
+			return Err(Error::<T>::InvalidExpiry)
+		}
+
+		PausedCallIndices::<T>::insert(index, until);
+		Self::deposit_event(Event::CallIndexPaused { index, until });
+
+		Ok(())
+	}
+
+	pub(crate) fn do_unpause_call_index(index: RuntimeCallIndex) -> Result<(), Error<T>> {
+		if !Self::is_call_index_paused(index) {
+			return Err(Error::<T>::IsUnpaused)
+		}
+
+		PausedCallIndices::<T>::remove(index);
+		Self::deposit_event(Event::CallIndexUnpaused { index });
+
+		Ok(())
+	}
+
+	/// Whether a pause lasting `until` is still active.
+	fn is_pause_active(until: Option<BlockNumberFor<T>>) -> bool {
+		until.map_or(true, |until| frame_system::Pallet::<T>::block_number() < until)
+	}
+
+	/// Return whether this call, or all calls of this pallet, are paused by index.
+	///
+	/// Only checks the exact `index`, use [`Self::is_paused_by_index`] to check whether a call is
+	/// filtered.
+	pub fn is_call_index_paused(index: RuntimeCallIndex) -> bool {
+		PausedCallIndices::<T>::get(index).map_or(false, Self::is_pause_active)
+	}
+
+	/// Return whether the call `call_index` of the pallet `pallet_index` is paused by index, either
+	/// on its own or as part of its pallet.
+	pub fn is_paused_by_index(pallet_index: u8, call_index: u8) -> bool {
+		Self::is_call_index_paused((pallet_index, None)) ||
+			Self::is_call_index_paused((pallet_index, Some(call_index)))
+	}
+
 	/// Return whether this call is paused.
 	pub fn is_paused(full_name: &RuntimeCallNameOf<T>) -> bool {
 		if T::WhitelistedCalls::contains(full_name) {
@@ -290,7 +428,28 @@ where
 	/// Return whether the call is allowed to be dispatched.
 	fn contains(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
 		let CallMetadata { pallet_name, function_name } = call.get_call_metadata();
-		!Pallet::<T>::is_paused_unbound(pallet_name.into(), function_name.into())
+		let full_name =
+			PalletNameOf::<T>::try_from(pallet_name.as_bytes().to_vec()).and_then(|pallet| {
+				PalletCallNameOf::<T>::try_from(function_name.as_bytes().to_vec())
+					.map(|call| (pallet, call))
+			});
+		let Ok(full_name) = full_name else { return false };
+
+		if T::WhitelistedCalls::contains(&full_name) {
+			return true
+		}
+		if Pallet::<T>::is_paused(&full_name) {
+			return false
+		}
+
+		// The encoding of a runtime call starts with the pallet index followed by the call index.
+		let (pallet_index, call_index) = call.using_encoded(|encoded| {
+			(
+				encoded.first().copied().unwrap_or_default(),
+				encoded.get(1).copied().unwrap_or_default(),
+			)
+		});
+		!Pallet::<T>::is_paused_by_index(pallet_index, call_index)
 	}
 }
 
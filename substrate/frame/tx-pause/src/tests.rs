@@ -129,8 +129,159 @@ fn can_filter_balance_in_proxy_when_paused() {
 	});
 }
 
+#[docify::export]
+#[test]
+fn can_pause_call_by_index_until_expiry() {
+	new_test_ext().execute_with(|| {
+		let transfer_index = (balances_index(), Some(0));
+
+		assert_ok!(TxPause::pause_call_index(
+			RuntimeOrigin::signed(mock::PauseOrigin::get()),
+			transfer_index,
+			Some(3),
+		));
+		System::assert_last_event(
+			Event::CallIndexPaused { index: transfer_index, until: Some(3) }.into(),
+		);
+
+		assert_err!(
+			call_transfer(2, 1).dispatch(RuntimeOrigin::signed(2)),
+			frame_system::Error::<Test>::CallFiltered
+		);
+		assert_ok!(call_transfer_keep_alive(3, 1).dispatch(RuntimeOrigin::signed(3)));
+
+		// The pause is lifted at the expiry block, anyone can then clean it up.
+		run_to(3);
+		assert_ok!(call_transfer(2, 1).dispatch(RuntimeOrigin::signed(2)));
+		assert_ok!(TxPause::remove_expired_pause(RuntimeOrigin::signed(0), transfer_index));
+		System::assert_last_event(Event::CallIndexPauseExpired { index: transfer_index }.into());
+		assert!(PausedCallIndices::<Test>::get(transfer_index).is_none());
+	});
+}
+
+#[test]
+fn can_pause_all_calls_in_pallet_by_index_except_on_whitelist() {
+	new_test_ext().execute_with(|| {
+		let pallet_index = (balances_index(), None);
+
+		assert_ok!(TxPause::pause_call_index(
+			RuntimeOrigin::signed(mock::PauseOrigin::get()),
+			pallet_index,
+			None,
+		));
+
+		assert_err!(
+			call_transfer(2, 1).dispatch(RuntimeOrigin::signed(2)),
+			frame_system::Error::<Test>::CallFiltered
+		);
+		assert_ok!(call_transfer_keep_alive(3, 1).dispatch(RuntimeOrigin::signed(3)));
+
+		// The pause does not expire.
+		run_to(10);
+		assert_err!(
+			call_transfer(2, 1).dispatch(RuntimeOrigin::signed(2)),
+			frame_system::Error::<Test>::CallFiltered
+		);
+
+		assert_ok!(TxPause::unpause_call_index(
+			RuntimeOrigin::signed(mock::UnpauseOrigin::get()),
+			pallet_index,
+		));
+		System::assert_last_event(Event::CallIndexUnpaused { index: pallet_index }.into());
+		assert_ok!(call_transfer(2, 1).dispatch(RuntimeOrigin::signed(2)));
+	});
+}
+
 // GENERAL FAIL/NEGATIVE TESTS ---------------------
 
+#[test]
+fn fails_to_pause_self_by_index() {
+	new_test_ext().execute_with(|| {
+		let own_index = <TxPause as PalletInfoAccess>::index() as u8;
+
+		assert_noop!(
+			TxPause::pause_call_index(
+				RuntimeOrigin::signed(mock::PauseOrigin::get()),
+				(own_index, None),
+				None,
+			),
+			Error::<Test>::Unpausable
+		);
+	});
+}
+
+#[test]
+fn fails_to_pause_by_index_with_invalid_origin_or_expiry() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TxPause::pause_call_index(
+				RuntimeOrigin::signed(mock::UnpauseOrigin::get()),
+				(balances_index(), None),
+				None,
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			TxPause::pause_call_index(
+				RuntimeOrigin::signed(mock::PauseOrigin::get()),
+				(balances_index(), None),
+				Some(System::block_number()),
+			),
+			Error::<Test>::InvalidExpiry
+		);
+	});
+}
+
+#[test]
+fn fails_to_pause_or_unpause_by_index_twice() {
+	new_test_ext().execute_with(|| {
+		let index = (balances_index(), Some(0));
+		assert_noop!(
+			TxPause::unpause_call_index(RuntimeOrigin::signed(mock::UnpauseOrigin::get()), index),
+			Error::<Test>::IsUnpaused
+		);
+
+		assert_ok!(TxPause::pause_call_index(
+			RuntimeOrigin::signed(mock::PauseOrigin::get()),
+			index,
+			Some(2),
+		));
+		assert_noop!(
+			TxPause::pause_call_index(RuntimeOrigin::signed(mock::PauseOrigin::get()), index, None),
+			Error::<Test>::IsPaused
+		);
+
+		// An expired pause can be replaced.
+		run_to(2);
+		assert_ok!(TxPause::pause_call_index(
+			RuntimeOrigin::signed(mock::PauseOrigin::get()),
+			index,
+			None,
+		));
+	});
+}
+
+#[test]
+fn fails_to_remove_pause_that_did_not_expire() {
+	new_test_ext().execute_with(|| {
+		let index = (balances_index(), Some(0));
+		assert_noop!(
+			TxPause::remove_expired_pause(RuntimeOrigin::signed(0), index),
+			Error::<Test>::IsUnpaused
+		);
+
+		assert_ok!(TxPause::pause_call_index(
+			RuntimeOrigin::signed(mock::PauseOrigin::get()),
+			index,
+			Some(3),
+		));
+		assert_noop!(
+			TxPause::remove_expired_pause(RuntimeOrigin::signed(0), index),
+			Error::<Test>::NotExpired
+		);
+	});
+}
+
 #[test]
 fn fails_to_pause_self() {
 	new_test_ext().execute_with(|| {
@@ -224,3 +375,7 @@ pub fn full_name<T: Config>(pallet_name: &[u8], call_name: &[u8]) -> RuntimeCall
 		call_name.to_vec().try_into().unwrap(),
 	))
 }
+
+pub fn balances_index() -> u8 {
+	<Balances as PalletInfoAccess>::index() as u8
+}
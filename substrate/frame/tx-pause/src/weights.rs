@@ -22,6 +22,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-wiukf8gn-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `pause_call_index`, `unpause_call_index` and `remove_expired_pause`. Re-run the
+//! benchmarks before relying on them.
 
 // Executed Command:
 // ./target/production/substrate-node
@@ -53,6 +57,9 @@ use core::marker::PhantomData;
 pub trait WeightInfo {
 	fn pause() -> Weight;
 	fn unpause() -> Weight;
+	fn pause_call_index() -> Weight;
+	fn unpause_call_index() -> Weight;
+	fn remove_expired_pause() -> Weight;
 }
 
 /// Weights for `pallet_tx_pause` using the Substrate node and recommended hardware.
@@ -80,6 +87,39 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `TxPause::PausedCallIndices` (r:1 w:1)
+	/// Proof: `TxPause::PausedCallIndices` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn pause_call_index() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3`
+		//  Estimated: `3481`
+		// Minimum execution time: 11_873_000 picoseconds.
+		Weight::from_parts(12_240_000, 3481)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TxPause::PausedCallIndices` (r:1 w:1)
+	/// Proof: `TxPause::PausedCallIndices` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn unpause_call_index() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `61`
+		//  Estimated: `3481`
+		// Minimum execution time: 14_562_000 picoseconds.
+		Weight::from_parts(15_031_000, 3481)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TxPause::PausedCallIndices` (r:1 w:1)
+	/// Proof: `TxPause::PausedCallIndices` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn remove_expired_pause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `61`
+		//  Estimated: `3481`
+		// Minimum execution time: 14_815_000 picoseconds.
+		Weight::from_parts(15_297_000, 3481)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -106,4 +146,37 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `TxPause::PausedCallIndices` (r:1 w:1)
+	/// Proof: `TxPause::PausedCallIndices` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn pause_call_index() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3`
+		//  Estimated: `3481`
+		// Minimum execution time: 11_873_000 picoseconds.
+		Weight::from_parts(12_240_000, 3481)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TxPause::PausedCallIndices` (r:1 w:1)
+	/// Proof: `TxPause::PausedCallIndices` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn unpause_call_index() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `61`
+		//  Estimated: `3481`
+		// Minimum execution time: 14_562_000 picoseconds.
+		Weight::from_parts(15_031_000, 3481)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TxPause::PausedCallIndices` (r:1 w:1)
+	/// Proof: `TxPause::PausedCallIndices` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn remove_expired_pause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `61`
+		//  Estimated: `3481`
+		// Minimum execution time: 14_815_000 picoseconds.
+		Weight::from_parts(15_297_000, 3481)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -0,0 +1,244 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarks for the uniques to nfts pallet.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use alloc::vec;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Hooks;
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_uniques::BenchmarkHelper;
+use sp_runtime::{traits::Zero, Permill};
+
+const SEED: u32 = 0;
+
+/// A `BoundedVec` of the maximum length.
+fn max_bounded_vec<S: Get<u32>>() -> BoundedVec<u8, S> {
+	BoundedVec::defensive_truncate_from(vec![0; S::get() as usize])
+}
+
+/// Create a frozen uniques collection with metadata, a max supply, a royalty and distinct roles.
+fn create_collection<T: Config>() -> CollectionIdOf<T> {
+	let collection = <T as pallet_uniques::Config>::Helper::collection(0);
+	let owner: T::AccountId = account("owner", 0, SEED);
+	pallet_uniques::Collection::<T>::insert(
+		&collection,
+		pallet_uniques::CollectionDetails {
+			owner: owner.clone(),
+			issuer: account("issuer", 0, SEED),
+			admin: account("admin", 0, SEED),
+			freezer: account("freezer", 0, SEED),
+			total_deposit: Zero::zero(),
+			free_holding: false,
+			items: 0,
+			item_metadatas: 0,
+			attributes: 0,
+			is_frozen: true,
+		},
+	);
+	pallet_uniques::CollectionAccount::<T>::insert(&owner, &collection, ());
+	pallet_uniques::CollectionMetadataOf::<T>::insert(
+		&collection,
+		pallet_uniques::CollectionMetadata {
+			deposit: Zero::zero(),
+			data: max_bounded_vec::<<T as pallet_uniques::Config>::StringLimit>(),
+			is_frozen: true,
+		},
+	);
+	pallet_uniques::CollectionMaxSupply::<T>::insert(&collection, u32::MAX);
+	pallet_uniques::CollectionRoyalty::<T>::insert(
+		&collection,
+		(account::<T::AccountId>("recipient", 0, SEED), Permill::one()),
+	);
+	collection
+}
+
+/// Create a uniques collection and move it to nfts, leaving its items and attributes behind.
+fn create_migrated_collection<T: Config>() -> CollectionIdOf<T> {
+	let collection = create_collection::<T>();
+	assert_eq!(
+		Pallet::<T>::step(MigrationStage::NextCollection(None)),
+		MigrationStage::Items(collection.clone())
+	);
+	collection
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn on_idle_base() {
+		Stage::<T>::put(MigrationStage::Finished);
+
+		#[block]
+		{
+			Pallet::<T>::on_idle(BlockNumberFor::<T>::zero(), Weight::MAX);
+		}
+
+		assert_eq!(Stage::<T>::get(), MigrationStage::Finished);
+	}
+
+	#[benchmark]
+	fn start_collection() {
+		let collection = create_collection::<T>();
+		let next;
+
+		#[block]
+		{
+			next = Pallet::<T>::step(MigrationStage::NextCollection(None));
+		}
+
+		assert_eq!(next, MigrationStage::Items(collection.clone()));
+		assert!(pallet_nfts::Collection::<T>::contains_key(&collection));
+	}
+
+	#[benchmark]
+	fn drop_lease() {
+		let collection = create_collection::<T>();
+		let item = <T as pallet_uniques::Config>::Helper::item(0);
+		pallet_uniques::UserOf::<T>::insert(
+			&collection,
+			&item,
+			(account::<T::AccountId>("lessee", 0, SEED), BlockNumberFor::<T>::zero()),
+		);
+		frame_system::Pallet::<T>::set_block_number(1u32.into());
+		let next;
+
+		#[block]
+		{
+			next = Pallet::<T>::step(MigrationStage::Leases(collection.clone()));
+		}
+
+		assert_eq!(next, MigrationStage::Leases(collection.clone()));
+		assert!(!pallet_uniques::UserOf::<T>::contains_key(&collection, &item));
+	}
+
+	#[benchmark]
+	fn migrate_item() {
+		let collection = create_migrated_collection::<T>();
+		let item = <T as pallet_uniques::Config>::Helper::item(0);
+		let owner: T::AccountId = account("holder", 0, SEED);
+		pallet_uniques::Item::<T>::insert(
+			&collection,
+			&item,
+			pallet_uniques::ItemDetails {
+				owner: owner.clone(),
+				approved: Some(account("delegate", 0, SEED)),
+				is_frozen: true,
+				deposit: Zero::zero(),
+			},
+		);
+		pallet_uniques::Account::<T>::insert((&owner, &collection, &item), ());
+		pallet_uniques::ItemMetadataOf::<T>::insert(
+			&collection,
+			&item,
+			pallet_uniques::ItemMetadata {
+				deposit: Zero::zero(),
+				data: max_bounded_vec::<<T as pallet_uniques::Config>::StringLimit>(),
+				is_frozen: true,
+			},
+		);
+		pallet_uniques::ItemPriceOf::<T>::insert(
+			&collection,
+			&item,
+			(Zero::zero(), Some(account::<T::AccountId>("buyer", 0, SEED))),
+		);
+		pallet_uniques::ItemAttributesApprovalOf::<T>::insert(
+			&collection,
+			&item,
+			account::<T::AccountId>("delegate", 0, SEED),
+		);
+		let next;
+
+		#[block]
+		{
+			next = Pallet::<T>::step(MigrationStage::Items(collection.clone()));
+		}
+
+		assert_eq!(next, MigrationStage::Items(collection.clone()));
+		assert!(pallet_nfts::Item::<T>::contains_key(&collection, &item));
+	}
+
+	#[benchmark]
+	fn migrate_attribute() {
+		let collection = create_migrated_collection::<T>();
+		let item = <T as pallet_uniques::Config>::Helper::item(0);
+		let key = max_bounded_vec::<<T as pallet_uniques::Config>::KeyLimit>();
+		let value = max_bounded_vec::<<T as pallet_uniques::Config>::ValueLimit>();
+		pallet_uniques::Attribute::<T>::insert(
+			(&collection, Some(item), &key),
+			(value, Zero::zero()),
+		);
+		pallet_uniques::AttributeDepositorOf::<T>::insert(
+			(&collection, item, &key),
+			account::<T::AccountId>("delegate", 0, SEED),
+		);
+		let next;
+
+		#[block]
+		{
+			next = Pallet::<T>::step(MigrationStage::Attributes(collection.clone()));
+		}
+
+		assert_eq!(next, MigrationStage::Attributes(collection.clone()));
+		assert_eq!(pallet_nfts::Collection::<T>::get(&collection).unwrap().attributes, 1);
+	}
+
+	#[benchmark]
+	fn finish_collection() {
+		let collection = create_migrated_collection::<T>();
+		let next;
+
+		#[block]
+		{
+			next = Pallet::<T>::step(MigrationStage::Attributes(collection.clone()));
+		}
+
+		assert_eq!(next, MigrationStage::NextCollection(Some(collection.clone())));
+		assert!(!pallet_uniques::Collection::<T>::contains_key(&collection));
+	}
+
+	#[benchmark]
+	fn migrate_acceptance() {
+		let collection = create_migrated_collection::<T>();
+		assert_eq!(
+			Pallet::<T>::step(MigrationStage::Attributes(collection.clone())),
+			MigrationStage::NextCollection(Some(collection.clone()))
+		);
+		// The acceptance is dropped, which is the more expensive path.
+		let who: T::AccountId = account("who", 0, SEED);
+		frame_system::Pallet::<T>::inc_providers(&who);
+		frame_system::Pallet::<T>::inc_consumers(&who).unwrap();
+		pallet_uniques::OwnershipAcceptance::<T>::insert(&who, &collection);
+		pallet_nfts::OwnershipAcceptance::<T>::insert(&who, &collection);
+		let next;
+
+		#[block]
+		{
+			next = Pallet::<T>::step(MigrationStage::Acceptances);
+		}
+
+		assert_eq!(next, MigrationStage::Acceptances);
+		assert_eq!(frame_system::Pallet::<T>::consumers(&who), 0);
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
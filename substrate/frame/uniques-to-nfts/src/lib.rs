@@ -0,0 +1,539 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Uniques To Nfts Pallet
+//!
+//! - [`Config`]
+//!
+//! ## Overview
+//!
+//! Moves the collections of [`pallet_uniques`] into [`pallet_nfts`], lazily and over as many
+//! blocks as needed, so that runtimes can retire their uniques instance.
+//!
+//! The migration runs in `on_idle` and consumes at most the remaining weight of each block. Every
+//! step moves a single piece of state, in this order:
+//! 1. each expired lease of the next collection is dropped,
+//! 2. the collection, together with its metadata, max supply, royalty and roles,
+//! 3. each item of the collection, together with its metadata, price and approval,
+//! 4. each attribute of the collection, after which the collection is removed from uniques,
+//! 5. once all collections are migrated, each pending ownership acceptance.
+//!
+//! [`Event::CollectionMigrated`] is emitted for each migrated collection and
+//! [`Event::MigrationCompleted`] once nothing is left to migrate.
+//!
+//! ### Deposits
+//!
+//! Both pallets must use the same currency, so the deposits reserved by uniques stay reserved and
+//! are only re-pointed to the equivalent nfts records:
+//! - item deposits are held by the collection owner, as they are in uniques,
+//! - attribute deposits paid by an approved delegate stay with the delegate,
+//! - all other deposits of the collection are accounted in the `owner_deposit` of the collection.
+//!
+//! ### Settings
+//!
+//! - A frozen collection has each of its items made non-transferable, so that the freezer of the
+//!   collection can still thaw them with `unlock_item_transfer`. The collection itself stays
+//!   transferable, as locking it in nfts could only be undone by the `ForceOrigin`.
+//! - A frozen item is made non-transferable and can be thawed with `unlock_item_transfer`.
+//! - Frozen metadata locks the metadata and the attributes of the collection or item.
+//! - A set max supply is locked.
+//! - Collections with free holding do not require deposits for their items.
+//! - Attributes are stored in the `CollectionOwner` namespace.
+//! - Approvals of delegates to set the attributes of an item are dropped, as nfts has no equivalent
+//!   for them.
+//!
+//! Collections whose id is already in use in nfts are skipped with [`Event::CollectionSkipped`]
+//! and left untouched in uniques. So are collections with an item leased at the time of their
+//! migration, with [`Event::LeasedCollectionSkipped`], since nfts has no leases.
+//!
+//! The calls of both pallets should be filtered until the migration is completed, and the pallet
+//! can be removed from the runtime afterwards.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	defensive,
+	traits::{DefensiveTruncateFrom, Get, Incrementable},
+	weights::{Weight, WeightMeter},
+	BoundedVec,
+};
+use pallet_nfts::{
+	AttributeNamespace, CollectionRole, CollectionRoles, CollectionSetting, CollectionSettings,
+	ItemSetting, ItemSettings,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Saturating, RuntimeDebug};
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+const LOG_TARGET: &str = "runtime::uniques-to-nfts";
+
+/// The collection id shared by both pallets.
+pub type CollectionIdOf<T> = <T as pallet_uniques::Config>::CollectionId;
+
+/// The state of the migration.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum MigrationStage<CollectionId> {
+	/// Looking for the next collection to migrate, after the given one.
+	NextCollection(Option<CollectionId>),
+	/// Dropping the expired leases of the collection before migrating it.
+	Leases(CollectionId),
+	/// Migrating the items of the collection.
+	Items(CollectionId),
+	/// Migrating the attributes of the collection.
+	Attributes(CollectionId),
+	/// Migrating the ownership acceptances.
+	Acceptances,
+	/// Nothing left to migrate.
+	Finished,
+}
+
+impl<CollectionId> Default for MigrationStage<CollectionId> {
+	fn default() -> Self {
+		Self::NextCollection(None)
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config
+		+ pallet_uniques::Config
+		+ pallet_nfts::Config<
+			CollectionId = CollectionIdOf<Self>,
+			ItemId = <Self as pallet_uniques::Config>::ItemId,
+			Currency = <Self as pallet_uniques::Config>::Currency,
+		>
+	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Weight information for the migration steps of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The current stage of the migration.
+	#[pallet::storage]
+	pub type Stage<T: Config> = StorageValue<_, MigrationStage<CollectionIdOf<T>>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A collection and its `items` were migrated.
+		CollectionMigrated { collection: CollectionIdOf<T>, items: u32 },
+		/// A collection was not migrated since its id is already in use in nfts.
+		CollectionSkipped { collection: CollectionIdOf<T> },
+		/// A collection was not migrated since its `item` is leased.
+		LeasedCollectionSkipped {
+			collection: CollectionIdOf<T>,
+			item: <T as pallet_uniques::Config>::ItemId,
+		},
+		/// Everything was migrated.
+		MigrationCompleted,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			if meter.try_consume(<T as Config>::WeightInfo::on_idle_base()).is_err() {
+				return Weight::zero()
+			}
+
+			let initial = Stage::<T>::get();
+			let mut stage = initial.clone();
+			while stage != MigrationStage::Finished &&
+				meter.try_consume(Self::step_weight(&stage)).is_ok()
+			{
+				stage = Self::step(stage);
+			}
+			if stage != initial {
+				Stage::<T>::put(stage);
+			}
+
+			meter.consumed()
+		}
+
+		fn integrity_test() {
+			assert!(
+				<T as pallet_nfts::Config>::StringLimit::get() >=
+					<T as pallet_uniques::Config>::StringLimit::get(),
+				"Metadata of uniques must fit into nfts"
+			);
+			assert!(
+				<T as pallet_nfts::Config>::KeyLimit::get() >=
+					<T as pallet_uniques::Config>::KeyLimit::get(),
+				"Attribute keys of uniques must fit into nfts"
+			);
+			assert!(
+				<T as pallet_nfts::Config>::ValueLimit::get() >=
+					<T as pallet_uniques::Config>::ValueLimit::get(),
+				"Attribute values of uniques must fit into nfts"
+			);
+			assert!(
+				<T as pallet_nfts::Config>::ApprovalsLimit::get() >= 1,
+				"The approved account of a uniques item must fit into nfts"
+			);
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The worst-case weight of the next migration step of the given `stage`.
+	pub fn step_weight(stage: &MigrationStage<CollectionIdOf<T>>) -> Weight {
+		match stage {
+			MigrationStage::NextCollection(_) => <T as Config>::WeightInfo::start_collection(),
+			MigrationStage::Leases(_) => <T as Config>::WeightInfo::drop_lease()
+				.max(<T as Config>::WeightInfo::start_collection()),
+			MigrationStage::Items(_) => <T as Config>::WeightInfo::migrate_item(),
+			MigrationStage::Attributes(_) => <T as Config>::WeightInfo::migrate_attribute()
+				.max(<T as Config>::WeightInfo::finish_collection()),
+			MigrationStage::Acceptances => <T as Config>::WeightInfo::migrate_acceptance(),
+			MigrationStage::Finished => Weight::zero(),
+		}
+	}
+
+	/// Migrate the next piece of state of the given `stage` and return the following stage.
+	pub(crate) fn step(
+		stage: MigrationStage<CollectionIdOf<T>>,
+	) -> MigrationStage<CollectionIdOf<T>> {
+		match stage {
+			MigrationStage::NextCollection(last) => {
+				let next = match last {
+					Some(last) => pallet_uniques::Collection::<T>::iter_keys_from(
+						pallet_uniques::Collection::<T>::hashed_key_for(&last),
+					)
+					.next(),
+					None => pallet_uniques::Collection::<T>::iter_keys().next(),
+				};
+				match next {
+					Some(collection) => Self::start_collection(collection),
+					None => MigrationStage::Acceptances,
+				}
+			},
+			MigrationStage::Leases(collection) => Self::drop_next_lease(collection),
+			MigrationStage::Items(collection) => Self::migrate_next_item(collection),
+			MigrationStage::Attributes(collection) => Self::migrate_next_attribute(collection),
+			MigrationStage::Acceptances => Self::migrate_next_acceptance(),
+			MigrationStage::Finished => MigrationStage::Finished,
+		}
+	}
+
+	/// Create the nfts collection for the uniques `collection`.
+	fn start_collection(collection: CollectionIdOf<T>) -> MigrationStage<CollectionIdOf<T>> {
+		let Some(details) = pallet_uniques::Collection::<T>::get(&collection) else {
+			defensive!("The collection was just read from storage");
+			return MigrationStage::NextCollection(Some(collection))
+		};
+		if pallet_nfts::Collection::<T>::contains_key(&collection) {
+			log::warn!(
+				target: LOG_TARGET,
+				"Collection {:?} is already in use in nfts, skipping it",
+				collection
+			);
+			Self::deposit_event(Event::CollectionSkipped { collection: collection.clone() });
+			return MigrationStage::NextCollection(Some(collection))
+		}
+		if pallet_uniques::UserOf::<T>::iter_prefix(&collection).next().is_some() {
+			return MigrationStage::Leases(collection)
+		}
+
+		let metadata = pallet_uniques::CollectionMetadataOf::<T>::get(&collection);
+		let max_supply = pallet_uniques::CollectionMaxSupply::<T>::get(&collection);
+		let royalty = pallet_uniques::CollectionRoyalty::<T>::get(&collection);
+
+		let mut settings = CollectionSettings::all_enabled();
+		if details.free_holding {
+			settings.0.insert(CollectionSetting::DepositRequired);
+		}
+		if metadata.as_ref().map_or(false, |metadata| metadata.is_frozen) {
+			settings.0.insert(CollectionSetting::UnlockedMetadata);
+			settings.0.insert(CollectionSetting::UnlockedAttributes);
+		}
+		if max_supply.is_some() {
+			settings.0.insert(CollectionSetting::UnlockedMaxSupply);
+		}
+
+		pallet_nfts::Collection::<T>::insert(
+			&collection,
+			pallet_nfts::CollectionDetails {
+				owner: details.owner.clone(),
+				// Item deposits are moved to their items as they are migrated.
+				owner_deposit: details.total_deposit,
+				items: 0,
+				item_metadatas: 0,
+				item_configs: 0,
+				attributes: 0,
+			},
+		);
+		pallet_nfts::CollectionConfigOf::<T>::insert(
+			&collection,
+			pallet_nfts::CollectionConfig {
+				settings,
+				max_supply,
+				mint_settings: Default::default(),
+			},
+		);
+		pallet_nfts::CollectionAccount::<T>::insert(&details.owner, &collection, ());
+		for (who, role) in [
+			(details.issuer, CollectionRole::Issuer),
+			(details.admin, CollectionRole::Admin),
+			(details.freezer, CollectionRole::Freezer),
+		] {
+			pallet_nfts::CollectionRoleOf::<T>::mutate(&collection, who, |roles| {
+				roles.get_or_insert_with(CollectionRoles::none).add_role(role)
+			});
+		}
+		if let Some(metadata) = metadata {
+			pallet_nfts::CollectionMetadataOf::<T>::insert(
+				&collection,
+				pallet_nfts::CollectionMetadata {
+					deposit: metadata.deposit,
+					data: BoundedVec::defensive_truncate_from(metadata.data.into_inner()),
+				},
+			);
+		}
+		if let Some((recipient, royalty)) = royalty {
+			pallet_nfts::CollectionRoyaltyOf::<T>::insert(
+				&collection,
+				pallet_nfts::CollectionRoyalty { recipient, royalty },
+			);
+		}
+		Self::skip_migrated_collection_ids();
+
+		MigrationStage::Items(collection)
+	}
+
+	/// Drop the next expired lease of `collection`, or skip the collection if one of its items
+	/// is still leased.
+	fn drop_next_lease(collection: CollectionIdOf<T>) -> MigrationStage<CollectionIdOf<T>> {
+		let Some((item, (_, until))) = pallet_uniques::UserOf::<T>::iter_prefix(&collection).next()
+		else {
+			return Self::start_collection(collection)
+		};
+		if until >= frame_system::Pallet::<T>::block_number() {
+			log::warn!(
+				target: LOG_TARGET,
+				"Item {:?} of collection {:?} is leased, skipping the collection",
+				item,
+				collection
+			);
+			Self::deposit_event(Event::LeasedCollectionSkipped {
+				collection: collection.clone(),
+				item,
+			});
+			return MigrationStage::NextCollection(Some(collection))
+		}
+		pallet_uniques::UserOf::<T>::remove(&collection, &item);
+
+		MigrationStage::Leases(collection)
+	}
+
+	/// Move the next item of `collection` from uniques to nfts.
+	fn migrate_next_item(collection: CollectionIdOf<T>) -> MigrationStage<CollectionIdOf<T>> {
+		let Some((item, details)) = pallet_uniques::Item::<T>::iter_prefix(&collection).next()
+		else {
+			return MigrationStage::Attributes(collection)
+		};
+		pallet_uniques::Item::<T>::remove(&collection, &item);
+		pallet_uniques::Account::<T>::remove((&details.owner, &collection, &item));
+		let metadata = pallet_uniques::ItemMetadataOf::<T>::take(&collection, &item);
+		let price = pallet_uniques::ItemPriceOf::<T>::take(&collection, &item);
+		pallet_uniques::ItemAttributesApprovalOf::<T>::remove(&collection, &item);
+
+		// A frozen collection is not carried over, so its items are frozen one by one instead.
+		let collection_frozen =
+			pallet_uniques::Collection::<T>::get(&collection).map_or(false, |c| c.is_frozen);
+
+		pallet_nfts::Collection::<T>::mutate(&collection, |maybe_collection_details| {
+			let Some(collection_details) = maybe_collection_details else {
+				defensive!("The collection is created before its items are migrated");
+				return
+			};
+
+			let mut settings = ItemSettings::all_enabled();
+			if details.is_frozen || collection_frozen {
+				settings.0.insert(ItemSetting::Transferable);
+			}
+			if let Some(metadata) = metadata {
+				if metadata.is_frozen {
+					settings.0.insert(ItemSetting::UnlockedMetadata);
+					settings.0.insert(ItemSetting::UnlockedAttributes);
+				}
+				pallet_nfts::ItemMetadataOf::<T>::insert(
+					&collection,
+					&item,
+					pallet_nfts::ItemMetadata {
+						deposit: pallet_nfts::ItemMetadataDeposit {
+							account: None,
+							amount: metadata.deposit,
+						},
+						data: BoundedVec::defensive_truncate_from(metadata.data.into_inner()),
+					},
+				);
+				collection_details.item_metadatas.saturating_inc();
+			}
+
+			let mut approvals = pallet_nfts::ApprovalsOf::<T>::default();
+			if let Some(delegate) = details.approved {
+				if approvals.try_insert(delegate, None).is_err() {
+					defensive!("`ApprovalsLimit` is checked in `integrity_test`");
+				}
+			}
+
+			collection_details.owner_deposit.saturating_reduce(details.deposit);
+			pallet_nfts::Item::<T>::insert(
+				&collection,
+				&item,
+				pallet_nfts::ItemDetails {
+					owner: details.owner.clone(),
+					approvals,
+					deposit: pallet_nfts::ItemDeposit {
+						account: collection_details.owner.clone(),
+						amount: details.deposit,
+					},
+				},
+			);
+			pallet_nfts::ItemConfigOf::<T>::insert(
+				&collection,
+				&item,
+				pallet_nfts::ItemConfig { settings },
+			);
+			pallet_nfts::Account::<T>::insert((&details.owner, &collection, &item), ());
+			if let Some(price) = price {
+				pallet_nfts::ItemPriceOf::<T>::insert(&collection, &item, price);
+			}
+			collection_details.items.saturating_inc();
+			collection_details.item_configs.saturating_inc();
+		});
+
+		MigrationStage::Items(collection)
+	}
+
+	/// Move the next attribute of `collection` from uniques to nfts, or remove the collection
+	/// from uniques once all its attributes are migrated.
+	fn migrate_next_attribute(collection: CollectionIdOf<T>) -> MigrationStage<CollectionIdOf<T>> {
+		let Some(((maybe_item, key), (value, deposit))) =
+			pallet_uniques::Attribute::<T>::iter_prefix((&collection,)).next()
+		else {
+			return Self::finish_collection(collection)
+		};
+		pallet_uniques::Attribute::<T>::remove((&collection, &maybe_item, &key));
+		// The deposit of an attribute set by an approved delegate is held by the delegate.
+		let depositor = maybe_item.and_then(|item| {
+			pallet_uniques::AttributeDepositorOf::<T>::take((&collection, item, &key))
+		});
+
+		pallet_nfts::Attribute::<T>::insert(
+			(
+				&collection,
+				maybe_item,
+				AttributeNamespace::CollectionOwner,
+				BoundedVec::<_, <T as pallet_nfts::Config>::KeyLimit>::defensive_truncate_from(
+					key.into_inner(),
+				),
+			),
+			(
+				BoundedVec::<_, <T as pallet_nfts::Config>::ValueLimit>::defensive_truncate_from(
+					value.into_inner(),
+				),
+				pallet_nfts::AttributeDeposit { account: depositor, amount: deposit },
+			),
+		);
+		pallet_nfts::Collection::<T>::mutate(&collection, |maybe_collection_details| {
+			if let Some(collection_details) = maybe_collection_details {
+				collection_details.attributes.saturating_inc();
+			}
+		});
+
+		MigrationStage::Attributes(collection)
+	}
+
+	/// Remove the migrated `collection` from uniques.
+	fn finish_collection(collection: CollectionIdOf<T>) -> MigrationStage<CollectionIdOf<T>> {
+		if let Some(details) = pallet_uniques::Collection::<T>::take(&collection) {
+			pallet_uniques::CollectionAccount::<T>::remove(&details.owner, &collection);
+		}
+		pallet_uniques::CollectionMetadataOf::<T>::remove(&collection);
+		pallet_uniques::CollectionMaxSupply::<T>::remove(&collection);
+		pallet_uniques::CollectionRoyalty::<T>::remove(&collection);
+
+		let items =
+			pallet_nfts::Collection::<T>::get(&collection).map_or(0, |details| details.items);
+		Self::deposit_event(Event::CollectionMigrated { collection: collection.clone(), items });
+
+		MigrationStage::NextCollection(Some(collection))
+	}
+
+	/// Move the next ownership acceptance from uniques to nfts.
+	///
+	/// Acceptances of collections which were not migrated, or of accounts already accepting a
+	/// collection in nfts, are dropped.
+	fn migrate_next_acceptance() -> MigrationStage<CollectionIdOf<T>> {
+		let Some((who, collection)) = pallet_uniques::OwnershipAcceptance::<T>::iter().next()
+		else {
+			log::info!(target: LOG_TARGET, "Migration from uniques to nfts completed");
+			Self::deposit_event(Event::MigrationCompleted);
+			return MigrationStage::Finished
+		};
+		pallet_uniques::OwnershipAcceptance::<T>::remove(&who);
+
+		let migrated = !pallet_uniques::Collection::<T>::contains_key(&collection) &&
+			pallet_nfts::Collection::<T>::contains_key(&collection);
+		if migrated && !pallet_nfts::OwnershipAcceptance::<T>::contains_key(&who) {
+			// The consumer reference taken by uniques is kept for nfts.
+			pallet_nfts::OwnershipAcceptance::<T>::insert(&who, collection);
+		} else {
+			frame_system::Pallet::<T>::dec_consumers(&who);
+		}
+
+		MigrationStage::Acceptances
+	}
+
+	/// Advance the next collection id of nfts past the collections migrated so far.
+	fn skip_migrated_collection_ids() {
+		let mut next = pallet_nfts::NextCollectionId::<T>::get()
+			.or(<T as pallet_nfts::Config>::CollectionId::initial_value());
+		while let Some(id) = next.as_ref() {
+			if !pallet_nfts::Collection::<T>::contains_key(id) {
+				break
+			}
+			next = id.increment();
+		}
+		pallet_nfts::NextCollectionId::<T>::set(next);
+	}
+}
@@ -0,0 +1,363 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the uniques to nfts pallet.
+
+use crate::{mock::*, Config, Event, MigrationStage, Stage, WeightInfo};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Hooks, ReservableCurrency},
+	weights::Weight,
+};
+use pallet_nfts::{
+	AttributeNamespace, CollectionConfig, CollectionRole, CollectionSetting, CollectionSettings,
+	ItemSetting, MintSettings,
+};
+use sp_runtime::{bounded_vec, Permill};
+
+fn migrate_all() {
+	UniquesToNfts::on_idle(1, Weight::MAX);
+	assert_eq!(Stage::<Test>::get(), MigrationStage::Finished);
+}
+
+#[test]
+fn collections_are_migrated_with_their_deposits() {
+	new_test_ext().execute_with(|| {
+		let (owner, admin, holder, delegate) = (account(1), account(2), account(3), account(4));
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(owner.clone()), 0, admin.clone()));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(admin.clone()), 0, 42, holder.clone()));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(admin.clone()), 0, 43, holder.clone()));
+		assert_ok!(Uniques::set_collection_metadata(
+			RuntimeOrigin::signed(owner.clone()),
+			0,
+			bounded_vec![0; 10],
+			false
+		));
+		assert_ok!(Uniques::set_metadata(
+			RuntimeOrigin::signed(owner.clone()),
+			0,
+			42,
+			bounded_vec![1; 10],
+			true
+		));
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(owner.clone()),
+			0,
+			None,
+			bounded_vec![2],
+			bounded_vec![3]
+		));
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(owner.clone()),
+			0,
+			Some(43),
+			bounded_vec![4],
+			bounded_vec![5]
+		));
+		assert_ok!(Uniques::set_price(
+			RuntimeOrigin::signed(holder.clone()),
+			0,
+			43,
+			Some(10),
+			None
+		));
+		assert_ok!(Uniques::approve_transfer(
+			RuntimeOrigin::signed(holder.clone()),
+			0,
+			43,
+			delegate.clone()
+		));
+		assert_ok!(Uniques::freeze(RuntimeOrigin::signed(admin.clone()), 0, 42));
+		let total_deposit = pallet_uniques::Collection::<Test>::get(0).unwrap().total_deposit;
+		assert_eq!(total_deposit, 32);
+		assert_eq!(Balances::reserved_balance(&owner), 32);
+
+		migrate_all();
+
+		// Nothing is left in uniques and the deposits are still reserved.
+		assert!(!pallet_uniques::Collection::<Test>::contains_key(0));
+		assert!(!pallet_uniques::CollectionAccount::<Test>::contains_key(&owner, 0));
+		assert!(!pallet_uniques::CollectionMetadataOf::<Test>::contains_key(0));
+		assert_eq!(pallet_uniques::Item::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(pallet_uniques::Attribute::<Test>::iter_prefix((0,)).count(), 0);
+		assert!(!pallet_uniques::Account::<Test>::contains_key((&holder, 0, 42)));
+		assert_eq!(Balances::reserved_balance(&owner), 32);
+
+		let details = pallet_nfts::Collection::<Test>::get(0).unwrap();
+		assert_eq!(details.owner, owner);
+		assert_eq!(details.owner_deposit, total_deposit - 2);
+		assert_eq!((details.items, details.item_metadatas, details.item_configs), (2, 1, 2));
+		assert_eq!(details.attributes, 2);
+		assert!(pallet_nfts::CollectionAccount::<Test>::contains_key(&owner, 0));
+		let roles = pallet_nfts::CollectionRoleOf::<Test>::get(0, &admin).unwrap();
+		assert!(roles.has_role(CollectionRole::Issuer));
+		assert!(roles.has_role(CollectionRole::Admin));
+		assert!(roles.has_role(CollectionRole::Freezer));
+		assert_eq!(
+			pallet_nfts::CollectionMetadataOf::<Test>::get(0).unwrap().data.into_inner(),
+			vec![0; 10]
+		);
+
+		let item = pallet_nfts::Item::<Test>::get(0, 43).unwrap();
+		assert_eq!(item.owner, holder);
+		assert!(item.approvals.contains_key(&delegate));
+		assert_eq!(item.deposit, pallet_nfts::ItemDeposit { account: owner.clone(), amount: 1 });
+		assert!(pallet_nfts::Account::<Test>::contains_key((&holder, 0, 43)));
+		assert_eq!(pallet_nfts::ItemPriceOf::<Test>::get(0, 43), Some((10, None)));
+		assert_eq!(
+			pallet_nfts::Attribute::<Test>::get((
+				0,
+				Some(43),
+				AttributeNamespace::CollectionOwner,
+				bounded_vec![4]
+			))
+			.map(|(value, _)| value.into_inner()),
+			Some(vec![5])
+		);
+
+		let config = pallet_nfts::ItemConfigOf::<Test>::get(0, 42).unwrap();
+		assert!(!config.is_setting_enabled(ItemSetting::Transferable));
+		assert!(!config.is_setting_enabled(ItemSetting::UnlockedMetadata));
+		assert!(pallet_nfts::ItemConfigOf::<Test>::get(0, 43)
+			.unwrap()
+			.is_setting_enabled(ItemSetting::Transferable));
+
+		System::assert_has_event(Event::CollectionMigrated { collection: 0, items: 2 }.into());
+		System::assert_last_event(Event::MigrationCompleted.into());
+
+		// The migrated collection is fully usable in nfts and releases every deposit.
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(holder.clone()), 0, 42, account(5)),
+			pallet_nfts::Error::<Test>::ItemLocked
+		);
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(delegate), 0, 43, account(5)));
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(account(5)), 0, 43));
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(holder), 0, 42));
+		let witness = pallet_nfts::Collection::<Test>::get(0).unwrap().destroy_witness();
+		assert_ok!(Nfts::destroy(RuntimeOrigin::signed(owner.clone()), 0, witness));
+		assert_eq!(Balances::reserved_balance(&owner), 0);
+	});
+}
+
+#[test]
+fn migration_is_spread_over_blocks() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(account(1)), 0, account(1)));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(account(1)), 0, 1, account(2)));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(account(1)), 0, 2, account(2)));
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			None,
+			bounded_vec![1],
+			bounded_vec![2]
+		));
+
+		// Not even the stage can be read.
+		assert_eq!(UniquesToNfts::on_idle(1, Weight::zero()), Weight::zero());
+
+		let one_step = || {
+			<Test as Config>::WeightInfo::on_idle_base() +
+				UniquesToNfts::step_weight(&Stage::<Test>::get())
+		};
+		let weight = one_step();
+		assert_eq!(UniquesToNfts::on_idle(1, weight), weight);
+		assert_eq!(Stage::<Test>::get(), MigrationStage::Items(0));
+		assert!(pallet_nfts::Collection::<Test>::contains_key(0));
+		assert!(pallet_uniques::Collection::<Test>::contains_key(0));
+
+		let mut stages = vec![];
+		while Stage::<Test>::get() != MigrationStage::Finished {
+			UniquesToNfts::on_idle(1, one_step());
+			stages.push(Stage::<Test>::get());
+		}
+		assert_eq!(
+			stages,
+			vec![
+				MigrationStage::Items(0),
+				MigrationStage::Items(0),
+				MigrationStage::Attributes(0),
+				MigrationStage::Attributes(0),
+				MigrationStage::NextCollection(Some(0)),
+				MigrationStage::Acceptances,
+				MigrationStage::Finished,
+			]
+		);
+		assert!(!pallet_uniques::Collection::<Test>::contains_key(0));
+		assert_eq!(pallet_nfts::Collection::<Test>::get(0).unwrap().items, 2);
+	});
+}
+
+#[test]
+fn collections_in_use_are_skipped() {
+	new_test_ext().execute_with(|| {
+		let config = CollectionConfig {
+			settings: CollectionSettings::all_enabled(),
+			max_supply: None,
+			mint_settings: MintSettings::default(),
+		};
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), account(1), config.clone()));
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(account(1)), 0, account(1)));
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(account(1)), 1, account(1)));
+		assert_ok!(Uniques::set_accept_ownership(RuntimeOrigin::signed(account(5)), Some(1)));
+		assert_ok!(Uniques::set_accept_ownership(RuntimeOrigin::signed(account(6)), Some(0)));
+
+		migrate_all();
+
+		System::assert_has_event(Event::CollectionSkipped { collection: 0 }.into());
+		System::assert_has_event(Event::CollectionMigrated { collection: 1, items: 0 }.into());
+		assert!(pallet_uniques::Collection::<Test>::contains_key(0));
+		assert!(!pallet_uniques::Collection::<Test>::contains_key(1));
+
+		// The acceptance of the migrated collection is kept, the other one is dropped.
+		assert_eq!(pallet_nfts::OwnershipAcceptance::<Test>::get(account(5)), Some(1));
+		assert_eq!(System::consumers(&account(5)), 1);
+		assert_eq!(pallet_nfts::OwnershipAcceptance::<Test>::get(account(6)), None);
+		assert_eq!(System::consumers(&account(6)), 0);
+
+		// New nfts collections do not collide with the migrated ones.
+		assert_eq!(pallet_nfts::NextCollectionId::<Test>::get(), Some(2));
+		assert_ok!(Nfts::force_create(RuntimeOrigin::root(), account(1), config));
+		assert!(pallet_nfts::Collection::<Test>::contains_key(2));
+	});
+}
+
+#[test]
+fn frozen_collections_can_still_be_thawed() {
+	new_test_ext().execute_with(|| {
+		let (owner, holder) = (account(1), account(2));
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(owner.clone()), 0, owner.clone()));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(owner.clone()), 0, 42, holder.clone()));
+		assert_ok!(Uniques::freeze_collection(RuntimeOrigin::signed(owner.clone()), 0));
+
+		migrate_all();
+
+		// The collection stays transferable and its items are frozen instead.
+		assert!(pallet_nfts::CollectionConfigOf::<Test>::get(0)
+			.unwrap()
+			.is_setting_enabled(CollectionSetting::TransferableItems));
+		assert_noop!(
+			Nfts::transfer(RuntimeOrigin::signed(holder.clone()), 0, 42, account(3)),
+			pallet_nfts::Error::<Test>::ItemLocked
+		);
+
+		// The freezer can thaw the items without the `ForceOrigin`.
+		assert_ok!(Nfts::unlock_item_transfer(RuntimeOrigin::signed(owner), 0, 42));
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(holder), 0, 42, account(3)));
+	});
+}
+
+#[test]
+fn delegated_attributes_and_royalties_are_migrated() {
+	new_test_ext().execute_with(|| {
+		let (owner, holder, delegate, recipient) = (account(1), account(2), account(3), account(4));
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(owner.clone()), 0, owner.clone()));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(owner.clone()), 0, 42, holder.clone()));
+		assert_ok!(Uniques::set_collection_royalty(
+			RuntimeOrigin::signed(owner.clone()),
+			0,
+			Some((recipient.clone(), Permill::from_percent(10)))
+		));
+		assert_ok!(Uniques::approve_item_attributes(
+			RuntimeOrigin::signed(owner.clone()),
+			0,
+			42,
+			delegate.clone()
+		));
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(delegate.clone()),
+			0,
+			Some(42),
+			bounded_vec![1],
+			bounded_vec![2]
+		));
+		assert_eq!(Balances::reserved_balance(&delegate), 3);
+		let total_deposit = pallet_uniques::Collection::<Test>::get(0).unwrap().total_deposit;
+
+		migrate_all();
+
+		assert!(!pallet_uniques::CollectionRoyalty::<Test>::contains_key(0));
+		assert!(!pallet_uniques::ItemAttributesApprovalOf::<Test>::contains_key(0, 42));
+		assert!(!pallet_uniques::AttributeDepositorOf::<Test>::contains_key((
+			0,
+			42,
+			bounded_vec![1]
+		)));
+		assert_eq!(
+			pallet_nfts::CollectionRoyaltyOf::<Test>::get(0),
+			Some(pallet_nfts::CollectionRoyalty { recipient, royalty: Permill::from_percent(10) })
+		);
+		assert_eq!(
+			pallet_nfts::Attribute::<Test>::get((
+				0,
+				Some(42),
+				AttributeNamespace::CollectionOwner,
+				bounded_vec![1]
+			))
+			.map(|(_, deposit)| deposit),
+			Some(pallet_nfts::AttributeDeposit { account: Some(delegate.clone()), amount: 3 })
+		);
+		// The deposit of the delegate is not accounted to the collection owner.
+		assert_eq!(
+			pallet_nfts::Collection::<Test>::get(0).unwrap().owner_deposit,
+			total_deposit - 1
+		);
+
+		// Destroying the collection returns the deposit to the delegate.
+		assert_ok!(Nfts::burn(RuntimeOrigin::signed(holder), 0, 42));
+		let witness = pallet_nfts::Collection::<Test>::get(0).unwrap().destroy_witness();
+		assert_ok!(Nfts::destroy(RuntimeOrigin::signed(owner.clone()), 0, witness));
+		assert_eq!(Balances::reserved_balance(&delegate), 0);
+		assert_eq!(Balances::reserved_balance(&owner), 0);
+	});
+}
+
+#[test]
+fn leased_collections_are_skipped() {
+	new_test_ext().execute_with(|| {
+		let (owner, holder, lessee) = (account(1), account(2), account(3));
+		for collection in [0, 1] {
+			assert_ok!(Uniques::create(
+				RuntimeOrigin::signed(owner.clone()),
+				collection,
+				owner.clone()
+			));
+			assert_ok!(Uniques::mint(
+				RuntimeOrigin::signed(owner.clone()),
+				collection,
+				7,
+				holder.clone()
+			));
+		}
+		assert_ok!(Uniques::lease(RuntimeOrigin::signed(holder.clone()), 0, 7, lessee.clone(), 5));
+		assert_ok!(Uniques::lease(RuntimeOrigin::signed(holder.clone()), 1, 7, lessee, 1));
+		System::set_block_number(2);
+
+		migrate_all();
+
+		// The active lease keeps its collection in uniques.
+		System::assert_has_event(Event::LeasedCollectionSkipped { collection: 0, item: 7 }.into());
+		assert!(pallet_uniques::Collection::<Test>::contains_key(0));
+		assert!(pallet_uniques::UserOf::<Test>::contains_key(0, 7));
+		assert!(!pallet_nfts::Collection::<Test>::contains_key(0));
+
+		// The expired lease is dropped and its collection migrated.
+		System::assert_has_event(Event::CollectionMigrated { collection: 1, items: 1 }.into());
+		assert!(!pallet_uniques::UserOf::<Test>::contains_key(1, 7));
+		assert_eq!(pallet_nfts::Item::<Test>::get(1, 7).unwrap().owner, holder);
+	});
+}
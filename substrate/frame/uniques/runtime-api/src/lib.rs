@@ -0,0 +1,60 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the FRAME uniques pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+use pallet_uniques::ItemSnapshot;
+
+sp_api::decl_runtime_apis! {
+	/// Runtime API for reading the collections of `pallet-uniques`.
+	pub trait CollectionApi<AccountId, CollectionId, ItemId, Price>
+	where
+		AccountId: Codec,
+		CollectionId: Codec,
+		ItemId: Codec,
+		Price: Codec,
+	{
+		/// Returns up to `limit` items of `collection` with their owners and attributes, in
+		/// storage order.
+		///
+		/// Enumeration starts right after `start`, or at the beginning when it is `None`. Pass the
+		/// last item of a page as `start` to get the next page; an empty result means there are no
+		/// more items. Every page is read from a single block state.
+		fn items_of_collection(
+			collection: CollectionId,
+			start: Option<ItemId>,
+			limit: u32,
+		) -> Vec<ItemSnapshot<ItemId, AccountId>>;
+
+		/// Returns the owner of `item` of `collection`, if it exists.
+		fn owner(collection: CollectionId, item: ItemId) -> Option<AccountId>;
+
+		/// Returns the user of `item` of `collection`, if it exists: the lessee while the item is
+		/// leased and the owner otherwise.
+		fn user_of(collection: CollectionId, item: ItemId) -> Option<AccountId>;
+
+		/// Returns the price of `item` of `collection` and the only account allowed to buy it, if
+		/// the item is for sale.
+		fn price_of(collection: CollectionId, item: ItemId) -> Option<(Price, Option<AccountId>)>;
+	}
+}
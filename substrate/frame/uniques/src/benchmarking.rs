@@ -408,6 +408,18 @@ benchmarks_instance_pallet! {
 		}.into());
 	}
 
+	set_collection_royalty {
+		let (collection, caller, caller_lookup) = create_collection::<T, I>();
+		let royalty = T::MaxRoyalty::get();
+	}: _(SystemOrigin::Signed(caller.clone()), collection.clone(), Some((caller_lookup, royalty)))
+	verify {
+		assert_last_event::<T, I>(Event::CollectionRoyaltySet {
+			collection,
+			beneficiary: caller,
+			royalty,
+		}.into());
+	}
+
 	set_price {
 		let (collection, caller, _) = create_collection::<T, I>();
 		let (item, ..) = mint_item::<T, I>(0);
@@ -429,11 +441,17 @@ benchmarks_instance_pallet! {
 		let (item, ..) = mint_item::<T, I>(0);
 		let buyer: T::AccountId = account("buyer", 0, SEED);
 		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
-		let price = ItemPrice::<T, I>::from(0u32);
+		let price = T::Currency::minimum_balance().saturating_mul(100u32.into());
+		let beneficiary: T::AccountId = account("beneficiary", 0, SEED);
+		Uniques::<T, I>::set_collection_royalty(
+			SystemOrigin::Signed(seller.clone()).into(),
+			collection.clone(),
+			Some((T::Lookup::unlookup(beneficiary), T::MaxRoyalty::get())),
+		)?;
 		let origin = SystemOrigin::Signed(seller.clone()).into();
 		Uniques::<T, I>::set_price(origin, collection.clone(), item, Some(price), Some(buyer_lookup))?;
 		T::Currency::make_free_balance_be(&buyer, DepositBalanceOf::<T, I>::max_value());
-	}: _(SystemOrigin::Signed(buyer.clone()), collection.clone(), item, price)
+	}: _(SystemOrigin::Signed(buyer.clone()), collection.clone(), item, price, T::MaxRoyalty::get())
 	verify {
 		assert_last_event::<T, I>(Event::ItemBought {
 			collection: collection.clone(),
@@ -444,5 +462,102 @@ benchmarks_instance_pallet! {
 		}.into());
 	}
 
+	mint_pre_signed {
+		let (collection, caller, caller_lookup) = create_collection::<T, I>();
+		let (signer_public, signer) = T::Helper::signer();
+		Uniques::<T, I>::set_team(
+			SystemOrigin::Signed(caller).into(),
+			collection.clone(),
+			T::Lookup::unlookup(signer.clone()),
+			caller_lookup.clone(),
+			caller_lookup,
+		)?;
+		let claimer: T::AccountId = account("claimer", 0, SEED);
+		T::Currency::make_free_balance_be(&claimer, DepositBalanceOf::<T, I>::max_value());
+		let item = T::Helper::item(0);
+		let mint_data = PreSignedMint {
+			collection: collection.clone(),
+			item,
+			only_account: Some(claimer.clone()),
+			deadline: frame_system::Pallet::<T>::block_number() + 10u32.into(),
+			mint_price: Some(ItemPrice::<T, I>::from(1u32)),
+		};
+		let message = Encode::encode(&mint_data);
+		let signature = T::Helper::sign(&signer_public, &message);
+	}: _(SystemOrigin::Signed(claimer.clone()), Box::new(mint_data), signature, signer)
+	verify {
+		assert_last_event::<T, I>(Event::Issued { collection, item, owner: claimer }.into());
+	}
+
+	mint_batch {
+		let i in 0 .. T::MaxBatchSize::get();
+		let (collection, caller, caller_lookup) = create_collection::<T, I>();
+		let items = (0..i)
+			.map(|x| (T::Helper::item(x as u16), caller_lookup.clone()))
+			.collect::<Vec<_>>();
+	}: _(SystemOrigin::Signed(caller.clone()), collection.clone(), items, true)
+	verify {
+		assert_eq!(Collection::<T, I>::get(&collection).unwrap().items, i);
+	}
+
+	transfer_batch {
+		let i in 0 .. T::MaxBatchSize::get();
+		let (collection, caller, _) = create_collection::<T, I>();
+		let target: T::AccountId = account("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		let items = (0..i)
+			.map(|x| (mint_item::<T, I>(x as u16).0, target_lookup.clone()))
+			.collect::<Vec<_>>();
+	}: _(SystemOrigin::Signed(caller.clone()), collection.clone(), items.clone(), true)
+	verify {
+		for (item, _) in items {
+			assert_eq!(Uniques::<T, I>::owner(collection.clone(), item), Some(target.clone()));
+		}
+	}
+
+	approve_item_attributes {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+	}: _(SystemOrigin::Signed(caller.clone()), collection.clone(), item, delegate_lookup)
+	verify {
+		assert_last_event::<T, I>(Event::ItemAttributesApprovalAdded { collection: collection.clone(), item, delegate }.into());
+	}
+
+	cancel_item_attributes_approval {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Uniques::<T, I>::approve_item_attributes(origin, collection.clone(), item, delegate_lookup)?;
+	}: _(SystemOrigin::Signed(caller.clone()), collection.clone(), item)
+	verify {
+		assert_last_event::<T, I>(Event::ItemAttributesApprovalRemoved { collection: collection.clone(), item, delegate }.into());
+	}
+
+	lease {
+		let (collection, caller, _) = create_collection::<T, I>();
+		let (item, ..) = mint_item::<T, I>(0);
+		let lessee: T::AccountId = account("lessee", 0, SEED);
+		let lessee_lookup = T::Lookup::unlookup(lessee.clone());
+		let until = frame_system::Pallet::<T>::block_number() + 10u32.into();
+	}: _(SystemOrigin::Signed(caller.clone()), collection.clone(), item, lessee_lookup, until)
+	verify {
+		assert_last_event::<T, I>(Event::ItemLeased { collection: collection.clone(), item, lessee, until }.into());
+	}
+
+	clear_pre_signed_mint {
+		let caller: T::AccountId = whitelisted_caller();
+		let mint_hash = T::Hashing::hash(b"mint_data");
+		let deadline = frame_system::Pallet::<T>::block_number();
+		UsedPreSignedMints::<T, I>::insert(mint_hash, deadline);
+		frame_system::Pallet::<T>::set_block_number(deadline + 1u32.into());
+	}: _(SystemOrigin::Signed(caller), mint_hash)
+	verify {
+		assert!(!UsedPreSignedMints::<T, I>::contains_key(mint_hash));
+	}
+
 	impl_benchmark_test_suite!(Uniques, crate::mock::new_test_ext(), crate::mock::Test);
 }
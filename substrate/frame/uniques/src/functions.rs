@@ -18,10 +18,16 @@
 //! Various pieces of common functionality.
 
 use super::*;
+use codec::MaxEncodedLen;
+use core::marker::PhantomData;
 use frame_support::{
 	ensure,
-	traits::{ExistenceRequirement, Get},
+	storage::with_storage_layer,
+	traits::{ExistenceRequirement, Get, IdleTask},
+	weights::{Weight, WeightMeter},
+	Identity, StorageHasher,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 use sp_runtime::{DispatchError, DispatchResult};
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -33,6 +39,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	///   ([`UnknownCollection`](crate::Error::UnknownCollection)).
 	/// - The collection is frozen, and no transfers are allowed ([`Frozen`](crate::Error::Frozen)).
 	/// - The item is locked, and transfers are not permitted ([`Locked`](crate::Error::Locked)).
+	/// - The item is leased until the current or a later block ([`Leased`](crate::Error::Leased)).
 	/// - The `with_details` closure returns an error.
 	pub fn do_transfer(
 		collection: T::CollectionId,
@@ -47,6 +54,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
 		ensure!(!collection_details.is_frozen, Error::<T, I>::Frozen);
 		ensure!(!T::Locker::is_locked(collection.clone(), item), Error::<T, I>::Locked);
+		if let Some((_, until)) = UserOf::<T, I>::get(&collection, &item) {
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(until < now, Error::<T, I>::Leased);
+		}
 
 		let mut details =
 			Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownCollection)?;
@@ -65,6 +76,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Item::<T, I>::insert(&collection, &item, &details);
 		ItemPriceOf::<T, I>::remove(&collection, &item);
+		UserOf::<T, I>::remove(&collection, &item);
 
 		Self::deposit_event(Event::Transferred {
 			collection,
@@ -91,7 +103,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	) -> DispatchResult {
 		ensure!(!Collection::<T, I>::contains_key(collection.clone()), Error::<T, I>::InUse);
 
-		T::Currency::reserve(&owner, deposit)?;
+		Deposit::<T, I>::hold(&owner, deposit)?;
 
 		Collection::<T, I>::insert(
 			collection.clone(),
@@ -149,11 +161,24 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			#[allow(deprecated)]
 			ItemPriceOf::<T, I>::remove_prefix(&collection, None);
 			CollectionMetadataOf::<T, I>::remove(&collection);
+			for ((item, key), depositor) in
+				AttributeDepositorOf::<T, I>::drain_prefix((&collection,))
+			{
+				if let Some((_, deposit)) = Attribute::<T, I>::get((&collection, Some(item), &key))
+				{
+					Deposit::<T, I>::release(&depositor, deposit);
+				}
+			}
 			#[allow(deprecated)]
 			Attribute::<T, I>::remove_prefix((&collection,), None);
+			#[allow(deprecated)]
+			ItemAttributesApprovalOf::<T, I>::remove_prefix(&collection, None);
+			#[allow(deprecated)]
+			UserOf::<T, I>::remove_prefix(&collection, None);
 			CollectionAccount::<T, I>::remove(&collection_details.owner, &collection);
-			T::Currency::unreserve(&collection_details.owner, collection_details.total_deposit);
+			Deposit::<T, I>::release(&collection_details.owner, collection_details.total_deposit);
 			CollectionMaxSupply::<T, I>::remove(&collection);
+			CollectionRoyalty::<T, I>::remove(&collection);
 
 			Self::deposit_event(Event::Destroyed { collection });
 
@@ -208,7 +233,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 					true => Zero::zero(),
 					false => T::ItemDeposit::get(),
 				};
-				T::Currency::reserve(&collection_details.owner, deposit)?;
+				Deposit::<T, I>::hold(&collection_details.owner, deposit)?;
 				collection_details.total_deposit += deposit;
 
 				let owner = owner.clone();
@@ -223,11 +248,271 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 
+	/// Pay `price` from `buyer` to `seller` for a sale of `item`, sending the royalty of the
+	/// collection, if any, to its beneficiary.
+	///
+	/// Every path selling an item must pay through this function so that royalties cannot be
+	/// bypassed. Fails if the royalty is higher than `max_royalty`.
+	///
+	/// A royalty too small to create the account of its beneficiary is paid to `seller` instead.
+	///
+	/// Emits `RoyaltyPaid` if a non-zero royalty was paid.
+	pub(crate) fn pay_for_item(
+		collection: &T::CollectionId,
+		item: T::ItemId,
+		buyer: &T::AccountId,
+		seller: &T::AccountId,
+		price: ItemPrice<T, I>,
+		max_royalty: Permill,
+	) -> DispatchResult {
+		let mut remaining = price;
+		if let Some((beneficiary, royalty)) = CollectionRoyalty::<T, I>::get(collection) {
+			ensure!(royalty <= max_royalty, Error::<T, I>::RoyaltyTooHigh);
+			let amount = royalty * price;
+			let below_minimum = T::Currency::total_balance(&beneficiary).saturating_add(amount) <
+				T::Currency::minimum_balance();
+			if !amount.is_zero() && !below_minimum {
+				T::Currency::transfer(
+					buyer,
+					&beneficiary,
+					amount,
+					ExistenceRequirement::KeepAlive,
+				)?;
+				remaining = remaining.saturating_sub(amount);
+				Self::deposit_event(Event::RoyaltyPaid {
+					collection: collection.clone(),
+					item,
+					beneficiary,
+					amount,
+				});
+			}
+		}
+		T::Currency::transfer(buyer, seller, remaining, ExistenceRequirement::KeepAlive)
+	}
+
+	/// Check that `maybe_check_owner` may set or clear attributes of `maybe_item`.
+	///
+	/// Returns the signer if it acts as the approved delegate of the item rather than as the
+	/// owner of the collection or through `ForceOrigin`.
+	pub(crate) fn check_attribute_origin(
+		collection: &T::CollectionId,
+		collection_details: &CollectionDetailsFor<T, I>,
+		maybe_item: Option<T::ItemId>,
+		maybe_check_owner: Option<&T::AccountId>,
+	) -> Result<Option<T::AccountId>, DispatchError> {
+		match maybe_check_owner {
+			Some(check_owner) if check_owner != &collection_details.owner => {
+				let approved = maybe_item.map_or(false, |item| {
+					ItemAttributesApprovalOf::<T, I>::get(collection, item).as_ref() ==
+						Some(check_owner)
+				});
+				ensure!(approved, Error::<T, I>::NoPermission);
+				Ok(Some(check_owner.clone()))
+			},
+			_ => Ok(None),
+		}
+	}
+
+	/// Lease `item` of `collection` from its `owner` to `lessee` until the block `until`.
+	///
+	/// # Errors
+	/// This function returns a dispatch error in the following cases:
+	/// - The item does not exist ([`UnknownItem`](crate::Error::UnknownItem)).
+	/// - `owner` is not the owner of the item ([`NoPermission`](crate::Error::NoPermission)).
+	/// - `until` is in the past ([`DeadlineExpired`](crate::Error::DeadlineExpired)).
+	/// - The item is leased until the current or a later block
+	///   ([`AlreadyLeased`](crate::Error::AlreadyLeased)).
+	pub fn do_lease(
+		collection: T::CollectionId,
+		item: T::ItemId,
+		owner: T::AccountId,
+		lessee: T::AccountId,
+		until: BlockNumberFor<T>,
+	) -> DispatchResult {
+		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
+		ensure!(details.owner == owner, Error::<T, I>::NoPermission);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(until >= now, Error::<T, I>::DeadlineExpired);
+		if let Some((_, current_until)) = UserOf::<T, I>::get(&collection, &item) {
+			ensure!(current_until < now, Error::<T, I>::AlreadyLeased);
+		}
+
+		UserOf::<T, I>::insert(&collection, &item, (&lessee, until));
+		Self::deposit_event(Event::ItemLeased { collection, item, lessee, until });
+		Ok(())
+	}
+
+	/// Apply `f` to every item of `items` in `collection`, together with its looked up account.
+	///
+	/// Every item is applied in its own storage layer, so a failing item leaves no changes
+	/// behind. If `all_or_nothing` is `true`, the first failure is returned. Otherwise, failing
+	/// items are skipped and reported with a `BatchItemFailed` event.
+	pub(crate) fn do_batch(
+		collection: T::CollectionId,
+		items: Vec<(T::ItemId, AccountIdLookupOf<T>)>,
+		all_or_nothing: bool,
+		mut f: impl FnMut(T::CollectionId, T::ItemId, T::AccountId) -> DispatchResult,
+	) -> DispatchResult {
+		for (item, who) in items {
+			let result = with_storage_layer(|| {
+				let who = T::Lookup::lookup(who)?;
+				f(collection.clone(), item, who)
+			});
+			if let Err(error) = result {
+				ensure!(!all_or_nothing, error);
+				Self::deposit_event(Event::BatchItemFailed {
+					collection: collection.clone(),
+					item,
+					error,
+				});
+			}
+		}
+		Ok(())
+	}
+
+	/// Mint an item from a pre-signed approval of the collection's issuer.
+	///
+	/// `mint_to` funds the item deposit and pays the `mint_price` of `mint_data`, if any, to the
+	/// collection owner, who holds the deposit like for any other mint.
+	///
+	/// # Errors
+	/// This function returns a dispatch error in the following cases:
+	/// - The approval is restricted to another account
+	///   ([`WrongOrigin`](crate::Error::WrongOrigin)).
+	/// - The deadline of the approval has passed
+	///   ([`DeadlineExpired`](crate::Error::DeadlineExpired)).
+	/// - The approval was already redeemed ([`AlreadyClaimed`](crate::Error::AlreadyClaimed)).
+	/// - `signer` is not the issuer of the collection
+	///   ([`NoPermission`](crate::Error::NoPermission)).
+	/// - Any of the errors of [`do_mint`](Self::do_mint).
+	pub(crate) fn do_mint_pre_signed(
+		mint_to: T::AccountId,
+		mint_data: PreSignedMintOf<T, I>,
+		signer: T::AccountId,
+	) -> DispatchResult {
+		let mint_hash = T::Hashing::hash_of(&mint_data);
+		ensure!(
+			!UsedPreSignedMints::<T, I>::contains_key(&mint_hash),
+			Error::<T, I>::AlreadyClaimed
+		);
+		let PreSignedMint { collection, item, only_account, deadline, mint_price } = mint_data;
+
+		if let Some(account) = only_account {
+			ensure!(account == mint_to, Error::<T, I>::WrongOrigin);
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(deadline >= now, Error::<T, I>::DeadlineExpired);
+
+		Self::do_mint(collection, item, mint_to.clone(), |collection_details| {
+			ensure!(collection_details.issuer == signer, Error::<T, I>::NoPermission);
+			let deposit = match collection_details.free_holding {
+				true => Zero::zero(),
+				false => T::ItemDeposit::get(),
+			};
+			let payment = mint_price.unwrap_or_else(Zero::zero).saturating_add(deposit);
+			if !payment.is_zero() {
+				T::Currency::transfer(
+					&mint_to,
+					&collection_details.owner,
+					payment,
+					ExistenceRequirement::KeepAlive,
+				)?;
+			}
+			Ok(())
+		})?;
+		UsedPreSignedMints::<T, I>::insert(mint_hash, deadline);
+		Ok(())
+	}
+
+	/// The weight of reading a single [`UsedPreSignedMints`] record.
+	pub(crate) fn pre_signed_mint_read_weight() -> Weight {
+		let proof_size =
+			Identity::max_len::<T::Hash>().saturating_add(BlockNumberFor::<T>::max_encoded_len());
+		T::DbWeight::get()
+			.reads(1)
+			.saturating_add(Weight::from_parts(0, proof_size as u64))
+	}
+
+	/// Remove the records of redeemed pre-signed mints whose deadline passed before `now`, using
+	/// the weight left in `meter`.
+	///
+	/// Continues with the record after the one checked last, so that all records are eventually
+	/// checked even if a single block can't check them all.
+	pub(crate) fn prune_pre_signed_mints(now: BlockNumberFor<T>, meter: &mut WeightMeter) {
+		if meter.try_consume(T::DbWeight::get().reads_writes(1, 1)).is_err() {
+			return
+		}
+
+		let start = PreSignedMintsCursor::<T, I>::get();
+		let mut records = match start {
+			Some(cursor) => UsedPreSignedMints::<T, I>::iter_from(
+				UsedPreSignedMints::<T, I>::hashed_key_for(cursor),
+			),
+			None => UsedPreSignedMints::<T, I>::iter(),
+		};
+		let mut cursor = start;
+		let mut expired = Vec::new();
+		loop {
+			if meter.try_consume(Self::pre_signed_mint_read_weight()).is_err() {
+				break
+			}
+			let Some((mint_hash, deadline)) = records.next() else {
+				// All records were checked, start over in the next block.
+				cursor = None;
+				break
+			};
+			if deadline < now {
+				if meter.try_consume(T::DbWeight::get().writes(1)).is_err() {
+					break
+				}
+				expired.push(mint_hash);
+			}
+			cursor = Some(mint_hash);
+		}
+		PreSignedMintsCursor::<T, I>::set(cursor);
+
+		for mint_hash in expired {
+			UsedPreSignedMints::<T, I>::remove(mint_hash);
+		}
+	}
+
+	/// Validates the signature of the given data with the provided signer's account ID.
+	///
+	/// # Errors
+	///
+	/// This function returns a [`WrongSignature`](crate::Error::WrongSignature) error if the
+	/// signature is invalid or the verification process fails.
+	pub fn validate_signature(
+		data: &Vec<u8>,
+		signature: &T::OffchainSignature,
+		signer: &T::AccountId,
+	) -> DispatchResult {
+		if signature.verify(&**data, &signer) {
+			return Ok(())
+		}
+
+		// NOTE: for security reasons modern UIs implicitly wrap the data requested to sign into
+		// <Bytes></Bytes>, that's why we support both wrapped and raw versions.
+		let prefix = b"<Bytes>";
+		let suffix = b"</Bytes>";
+		let mut wrapped: Vec<u8> = Vec::with_capacity(data.len() + prefix.len() + suffix.len());
+		wrapped.extend(prefix);
+		wrapped.extend(data);
+		wrapped.extend(suffix);
+
+		ensure!(signature.verify(&*wrapped, &signer), Error::<T, I>::WrongSignature);
+
+		Ok(())
+	}
+
 	/// Burn (destroy) an item from a collection.
 	///
 	/// # Errors
 	/// This function returns a `Dispatch` error in the following cases:
 	/// - The item is locked and burns are not permitted ([`Locked`](crate::Error::Locked)).
+	/// - The item is leased until the current or a later block ([`Leased`](crate::Error::Leased)).
 	/// - The collection or item does not exist
 	///   ([`UnknownCollection`](crate::Error::UnknownCollection)).
 	/// - The `with_details` closure returns an error.
@@ -237,6 +522,10 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		with_details: impl FnOnce(&CollectionDetailsFor<T, I>, &ItemDetailsFor<T, I>) -> DispatchResult,
 	) -> DispatchResult {
 		ensure!(!T::Locker::is_locked(collection.clone(), item), Error::<T, I>::Locked);
+		if let Some((_, until)) = UserOf::<T, I>::get(&collection, &item) {
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(until < now, Error::<T, I>::Leased);
+		}
 		let owner = Collection::<T, I>::try_mutate(
 			&collection,
 			|maybe_collection_details| -> Result<T::AccountId, DispatchError> {
@@ -247,7 +536,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 				with_details(collection_details, &details)?;
 
 				// Return the deposit.
-				T::Currency::unreserve(&collection_details.owner, details.deposit);
+				Deposit::<T, I>::release(&collection_details.owner, details.deposit);
 				collection_details.total_deposit.saturating_reduce(details.deposit);
 				collection_details.items.saturating_dec();
 				Ok(details.owner)
@@ -257,6 +546,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Item::<T, I>::remove(&collection, &item);
 		Account::<T, I>::remove((&owner, &collection, &item));
 		ItemPriceOf::<T, I>::remove(&collection, &item);
+		ItemAttributesApprovalOf::<T, I>::remove(&collection, &item);
+		UserOf::<T, I>::remove(&collection, &item);
 
 		Self::deposit_event(Event::Burned { collection, item, owner });
 		Ok(())
@@ -307,12 +598,15 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	///   ([`BidTooLow`](crate::Error::BidTooLow)).
 	/// - The item is set to be sold only to a specific buyer, and the provided buyer is not the
 	///   whitelisted buyer ([`NoPermission`](crate::Error::NoPermission)).
+	/// - The royalty of the collection is higher than `max_royalty`
+	///   ([`RoyaltyTooHigh`](crate::Error::RoyaltyTooHigh)).
 	/// - The currency transfer between the buyer and the owner fails for any reason.
 	pub fn do_buy_item(
 		collection: T::CollectionId,
 		item: T::ItemId,
 		buyer: T::AccountId,
 		bid_price: ItemPrice<T, I>,
+		max_royalty: Permill,
 	) -> DispatchResult {
 		let details = Item::<T, I>::get(&collection, &item).ok_or(Error::<T, I>::UnknownItem)?;
 		ensure!(details.owner != buyer, Error::<T, I>::NoPermission);
@@ -326,12 +620,7 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			ensure!(only_buyer == buyer, Error::<T, I>::NoPermission);
 		}
 
-		T::Currency::transfer(
-			&buyer,
-			&details.owner,
-			price_info.0,
-			ExistenceRequirement::KeepAlive,
-		)?;
+		Self::pay_for_item(&collection, item, &buyer, &details.owner, price_info.0, max_royalty)?;
 
 		let old_owner = details.owner.clone();
 
@@ -348,3 +637,18 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		Ok(())
 	}
 }
+
+/// Removes the records of redeemed pre-signed mints whose deadline has passed.
+///
+/// It is run in the `on_idle` hook of the pallet and may use all of the weight left in a block.
+pub struct PrunePreSignedMints<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> IdleTask<BlockNumberFor<T>> for PrunePreSignedMints<T, I> {
+	fn max_weight() -> Weight {
+		Weight::MAX
+	}
+
+	fn run(n: BlockNumberFor<T>, meter: &mut WeightMeter) {
+		Pallet::<T, I>::prune_pre_signed_mints(n, meter)
+	}
+}
@@ -44,17 +44,19 @@ pub mod weights;
 
 extern crate alloc;
 
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 use codec::{Decode, Encode};
 use frame_support::traits::{
-	tokens::Locker, BalanceStatus::Reserved, Currency, EnsureOriginWithArg, ReservableCurrency,
+	tokens::Locker, BalanceStatus::Reserved, Currency, EnsureOriginWithArg, IdleTasks,
+	ManagedDeposit, ReservableCurrency,
 };
 use frame_system::Config as SystemConfig;
 use sp_runtime::{
-	traits::{Saturating, StaticLookup, Zero},
-	ArithmeticError, RuntimeDebug,
+	traits::{Hash, IdentifyAccount, Saturating, StaticLookup, Verify, Zero},
+	ArithmeticError, Permill, RuntimeDebug,
 };
 
+pub use functions::PrunePreSignedMints;
 pub use pallet::*;
 pub use types::*;
 pub use weights::WeightInfo;
@@ -65,6 +67,9 @@ const LOG_TARGET: &str = "runtime::uniques";
 /// A type alias for the account ID type used in the dispatchable functions of this pallet.
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
+/// A type alias for managing the deposits held by this pallet.
+type Deposit<T, I> = ManagedDeposit<<T as SystemConfig>::AccountId, <T as Config<I>>::Currency>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -78,18 +83,42 @@ pub mod pallet {
 	pub struct Pallet<T, I = ()>(_);
 
 	#[cfg(feature = "runtime-benchmarks")]
-	pub trait BenchmarkHelper<CollectionId, ItemId> {
+	pub trait BenchmarkHelper<CollectionId, ItemId, Public, AccountId, Signature> {
 		fn collection(i: u16) -> CollectionId;
 		fn item(i: u16) -> ItemId;
+		fn signer() -> (Public, AccountId);
+		fn sign(signer: &Public, message: &[u8]) -> Signature;
 	}
 	#[cfg(feature = "runtime-benchmarks")]
-	impl<CollectionId: From<u16>, ItemId: From<u16>> BenchmarkHelper<CollectionId, ItemId> for () {
+	impl<CollectionId, ItemId>
+		BenchmarkHelper<
+			CollectionId,
+			ItemId,
+			sp_runtime::MultiSigner,
+			sp_runtime::AccountId32,
+			sp_runtime::MultiSignature,
+		> for ()
+	where
+		CollectionId: From<u16>,
+		ItemId: From<u16>,
+	{
 		fn collection(i: u16) -> CollectionId {
 			i.into()
 		}
 		fn item(i: u16) -> ItemId {
 			i.into()
 		}
+		fn signer() -> (sp_runtime::MultiSigner, sp_runtime::AccountId32) {
+			let public = sp_io::crypto::sr25519_generate(0.into(), None);
+			let account = sp_runtime::MultiSigner::Sr25519(public).into_account();
+			(public.into(), account)
+		}
+		fn sign(signer: &sp_runtime::MultiSigner, message: &[u8]) -> sp_runtime::MultiSignature {
+			sp_runtime::MultiSignature::Sr25519(
+				sp_io::crypto::sr25519_sign(0.into(), &signer.clone().try_into().unwrap(), message)
+					.unwrap(),
+			)
+		}
 	}
 
 	#[pallet::config]
@@ -156,9 +185,33 @@ pub mod pallet {
 		#[pallet::constant]
 		type ValueLimit: Get<u32>;
 
+		/// The highest share of the sale price that the royalty of a collection may take.
+		#[pallet::constant]
+		type MaxRoyalty: Get<Permill>;
+
+		/// The maximum number of items that can be minted or transferred in a single batch.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+
+		/// Off-Chain signature type.
+		///
+		/// Can verify whether an `Self::OffchainPublic` created a signature.
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// Off-Chain public key.
+		///
+		/// Must identify as an on-chain `Self::AccountId`.
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		/// A set of helper functions for benchmarking.
-		type Helper: BenchmarkHelper<Self::CollectionId, Self::ItemId>;
+		type Helper: BenchmarkHelper<
+			Self::CollectionId,
+			Self::ItemId,
+			Self::OffchainPublic,
+			Self::AccountId,
+			Self::OffchainSignature,
+		>;
 
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
@@ -182,7 +235,7 @@ pub mod pallet {
 	#[pallet::storage]
 	/// The items held by any given account; set out this way so that items owned by a single
 	/// account can be enumerated.
-	pub(super) type Account<T: Config<I>, I: 'static = ()> = StorageNMap<
+	pub type Account<T: Config<I>, I: 'static = ()> = StorageNMap<
 		_,
 		(
 			NMapKey<Blake2_128Concat, T::AccountId>, // owner
@@ -197,7 +250,7 @@ pub mod pallet {
 	#[pallet::storage_prefix = "ClassAccount"]
 	/// The collections owned by any given account; set out this way so that collections owned by
 	/// a single account can be enumerated.
-	pub(super) type CollectionAccount<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+	pub type CollectionAccount<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		T::AccountId,
@@ -246,7 +299,7 @@ pub mod pallet {
 
 	#[pallet::storage]
 	/// Attributes of a collection.
-	pub(super) type Attribute<T: Config<I>, I: 'static = ()> = StorageNMap<
+	pub type Attribute<T: Config<I>, I: 'static = ()> = StorageNMap<
 		_,
 		(
 			NMapKey<Blake2_128Concat, T::CollectionId>,
@@ -257,6 +310,34 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	/// The account, if any, which the collection owner approved to set attributes of an item.
+	pub type ItemAttributesApprovalOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		T::AccountId,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The account which paid the deposit of an item attribute, if it was set by an approved
+	/// delegate rather than the collection owner.
+	///
+	/// Deposits of such attributes are not part of the collection's `total_deposit`.
+	pub type AttributeDepositorOf<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::CollectionId>,
+			NMapKey<Blake2_128Concat, T::ItemId>,
+			NMapKey<Blake2_128Concat, BoundedVec<u8, T::KeyLimit>>,
+		),
+		T::AccountId,
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	/// Price of an asset instance.
 	pub type ItemPriceOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
@@ -269,11 +350,47 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	/// The account an item is leased to and the last block of the lease.
+	///
+	/// Leased items can't be transferred or burned. Expired leases are only removed once the item
+	/// is leased again, transferred or burned.
+	pub type UserOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::ItemId,
+		(T::AccountId, BlockNumberFor<T>),
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	/// Keeps track of the number of items a collection might have.
-	pub(super) type CollectionMaxSupply<T: Config<I>, I: 'static = ()> =
+	pub type CollectionMaxSupply<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, T::CollectionId, u32, OptionQuery>;
 
+	#[pallet::storage]
+	/// The beneficiary and the share of the royalty paid on every sale of an item of a
+	/// collection.
+	pub type CollectionRoyalty<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::CollectionId, (T::AccountId, Permill), OptionQuery>;
+
+	#[pallet::storage]
+	/// The hashes of the redeemed pre-signed mints and their deadlines, so that a pre-signed mint
+	/// can't be redeemed again once its item is burned.
+	///
+	/// Records whose deadline has passed are removed in `on_idle`, or can be removed right away
+	/// with [`Call::clear_pre_signed_mint`].
+	pub type UsedPreSignedMints<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, T::Hash, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::storage]
+	/// The last pre-signed mint hash whose deadline was checked in `on_idle`, if not all of them
+	/// have been checked yet.
+	pub type PreSignedMintsCursor<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, T::Hash, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -382,6 +499,42 @@ pub mod pallet {
 			seller: T::AccountId,
 			buyer: T::AccountId,
 		},
+		/// A royalty was set for a collection.
+		CollectionRoyaltySet {
+			collection: T::CollectionId,
+			beneficiary: T::AccountId,
+			royalty: Permill,
+		},
+		/// The royalty of a collection was removed.
+		CollectionRoyaltyRemoved { collection: T::CollectionId },
+		/// A royalty was paid on the sale of an item.
+		RoyaltyPaid {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			beneficiary: T::AccountId,
+			amount: ItemPrice<T, I>,
+		},
+		/// An item of a batch failed and was skipped.
+		BatchItemFailed { collection: T::CollectionId, item: T::ItemId, error: DispatchError },
+		/// A `delegate` was approved to set attributes of an `item`.
+		ItemAttributesApprovalAdded {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			delegate: T::AccountId,
+		},
+		/// The approval of a `delegate` to set attributes of an `item` was cancelled.
+		ItemAttributesApprovalRemoved {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			delegate: T::AccountId,
+		},
+		/// An `item` was leased to a `lessee` until the block `until`.
+		ItemLeased {
+			collection: T::CollectionId,
+			item: T::ItemId,
+			lessee: T::AccountId,
+			until: BlockNumberFor<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -422,6 +575,26 @@ pub mod pallet {
 		NotForSale,
 		/// The provided bid is too low.
 		BidTooLow,
+		/// The provided signature is incorrect.
+		WrongSignature,
+		/// The pre-signed mint is restricted to another account.
+		WrongOrigin,
+		/// The deadline has already expired.
+		DeadlineExpired,
+		/// The item is already leased.
+		AlreadyLeased,
+		/// The pre-signed mint was already redeemed.
+		AlreadyClaimed,
+		/// The deadline has not expired yet.
+		DeadlineNotExpired,
+		/// No redeemed pre-signed mint is recorded under the given hash.
+		UnknownPreSignedMint,
+		/// The royalty is higher than the pallet or the buyer allows.
+		RoyaltyTooHigh,
+		/// The batch holds more items than `MaxBatchSize`.
+		BatchTooLarge,
+		/// The item is leased and can't be transferred or burned before its lease ends.
+		Leased,
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -434,6 +607,58 @@ pub mod pallet {
 		pub fn collection_owner(collection: T::CollectionId) -> Option<T::AccountId> {
 			Collection::<T, I>::get(collection).map(|i| i.owner)
 		}
+
+		/// Get the user of the item, if the item exists.
+		///
+		/// This is the lessee while the item is leased and the owner otherwise.
+		pub fn user_of(collection: T::CollectionId, item: T::ItemId) -> Option<T::AccountId> {
+			let now = frame_system::Pallet::<T>::block_number();
+			match UserOf::<T, I>::get(&collection, &item) {
+				Some((lessee, until)) if now <= until => Some(lessee),
+				_ => Self::owner(collection, item),
+			}
+		}
+
+		/// Get the price of the item and the only account allowed to buy it, if the item is for
+		/// sale.
+		pub fn price_of(
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> Option<(ItemPrice<T, I>, Option<T::AccountId>)> {
+			ItemPriceOf::<T, I>::get(collection, item)
+		}
+
+		/// Get up to `limit` items of `collection` with their owners and attributes, in storage
+		/// order, starting right after `start` if given.
+		pub fn items_of_collection(
+			collection: T::CollectionId,
+			start: Option<T::ItemId>,
+			limit: u32,
+		) -> Vec<ItemSnapshot<T::ItemId, T::AccountId>> {
+			let iter = match start {
+				Some(item) => Item::<T, I>::iter_prefix_from(
+					collection.clone(),
+					Item::<T, I>::hashed_key_for(collection.clone(), item),
+				),
+				None => Item::<T, I>::iter_prefix(collection.clone()),
+			};
+			iter.take(limit as usize)
+				.map(|(item, details)| {
+					let attributes =
+						Attribute::<T, I>::iter_prefix((collection.clone(), Some(item.clone())))
+							.map(|(key, (value, _))| (key.into(), value.into()))
+							.collect();
+					ItemSnapshot { item, owner: details.owner, attributes }
+				})
+				.collect()
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_idle(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+			<(PrunePreSignedMints<T, I>,) as IdleTasks<_>>::on_idle(now, limit)
+		}
 	}
 
 	#[pallet::call]
@@ -695,20 +920,17 @@ pub mod pallet {
 					None => continue,
 				};
 				let old = details.deposit;
-				if old > deposit {
-					T::Currency::unreserve(&collection_details.owner, old - deposit);
-				} else if deposit > old {
-					if T::Currency::reserve(&collection_details.owner, deposit - old).is_err() {
-						// NOTE: No alterations made to collection_details in this iteration so far,
-						// so this is OK to do.
-						continue
-					}
-				} else {
+				let Some(new) = Deposit::<T, I>::rejig(&collection_details.owner, old, deposit)
+					.ok()
+					.and_then(|change| change.new_deposit())
+				else {
+					// NOTE: No alterations made to collection_details in this iteration so far, so
+					// this is OK to do.
 					continue
-				}
-				collection_details.total_deposit.saturating_accrue(deposit);
+				};
+				collection_details.total_deposit.saturating_accrue(new);
 				collection_details.total_deposit.saturating_reduce(old);
-				details.deposit = deposit;
+				details.deposit = new;
 				Item::<T, I>::insert(&collection, &item, &details);
 				successful.push(item);
 			}
@@ -1089,11 +1311,12 @@ pub mod pallet {
 		/// Set an attribute for a collection or item.
 		///
 		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
-		/// `collection`.
+		/// `collection`, or the delegate approved to set attributes of the `item`.
 		///
 		/// If the origin is Signed, then funds of signer are reserved according to the formula:
 		/// `MetadataDepositBase + DepositPerByte * (key.len + value.len)` taking into
-		/// account any already reserved funds.
+		/// account any already reserved funds. Any deposit previously paid for the attribute by
+		/// another account is freed.
 		///
 		/// - `collection`: The identifier of the collection whose item's metadata to set.
 		/// - `maybe_item`: The identifier of the item whose metadata to set.
@@ -1118,9 +1341,12 @@ pub mod pallet {
 
 			let mut collection_details =
 				Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
-			if let Some(check_owner) = &maybe_check_owner {
-				ensure!(check_owner == &collection_details.owner, Error::<T, I>::NoPermission);
-			}
+			let maybe_delegate = Self::check_attribute_origin(
+				&collection,
+				&collection_details,
+				maybe_item,
+				maybe_check_owner.as_ref(),
+			)?;
 			let maybe_is_frozen = match maybe_item {
 				None => CollectionMetadataOf::<T, I>::get(collection.clone()).map(|v| v.is_frozen),
 				Some(item) =>
@@ -1133,18 +1359,43 @@ pub mod pallet {
 				collection_details.attributes.saturating_inc();
 			}
 			let old_deposit = attribute.map_or(Zero::zero(), |m| m.1);
-			collection_details.total_deposit.saturating_reduce(old_deposit);
+			let old_depositor = maybe_item
+				.and_then(|item| AttributeDepositorOf::<T, I>::take((&collection, item, &key)));
 			let mut deposit = Zero::zero();
 			if !collection_details.free_holding && maybe_check_owner.is_some() {
 				deposit = T::DepositPerByte::get()
 					.saturating_mul(((key.len() + value.len()) as u32).into())
 					.saturating_add(T::AttributeDepositBase::get());
 			}
-			collection_details.total_deposit.saturating_accrue(deposit);
-			if deposit > old_deposit {
-				T::Currency::reserve(&collection_details.owner, deposit - old_deposit)?;
-			} else if deposit < old_deposit {
-				T::Currency::unreserve(&collection_details.owner, old_deposit - deposit);
+			match (old_depositor, maybe_delegate.clone()) {
+				(None, None) => {
+					collection_details.total_deposit.saturating_reduce(old_deposit);
+					collection_details.total_deposit.saturating_accrue(deposit);
+					Deposit::<T, I>::rejig(&collection_details.owner, old_deposit, deposit)?;
+				},
+				(Some(old_depositor), Some(delegate)) if old_depositor == delegate => {
+					Deposit::<T, I>::rejig(&delegate, old_deposit, deposit)?;
+				},
+				(old_depositor, maybe_delegate) => {
+					match old_depositor {
+						Some(old_depositor) =>
+							Deposit::<T, I>::release(&old_depositor, old_deposit),
+						None => {
+							collection_details.total_deposit.saturating_reduce(old_deposit);
+							Deposit::<T, I>::release(&collection_details.owner, old_deposit)
+						},
+					};
+					match maybe_delegate {
+						Some(delegate) => Deposit::<T, I>::hold(&delegate, deposit)?,
+						None => {
+							collection_details.total_deposit.saturating_accrue(deposit);
+							Deposit::<T, I>::hold(&collection_details.owner, deposit)?
+						},
+					};
+				},
+			}
+			if let (Some(item), Some(delegate)) = (maybe_item, &maybe_delegate) {
+				AttributeDepositorOf::<T, I>::insert((&collection, item, &key), delegate);
 			}
 
 			Attribute::<T, I>::insert((&collection, maybe_item, &key), (&value, deposit));
@@ -1156,9 +1407,9 @@ pub mod pallet {
 		/// Clear an attribute for a collection or item.
 		///
 		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
-		/// `collection`.
+		/// `collection`, or the delegate approved to set attributes of the `item`.
 		///
-		/// Any deposit is freed for the collection's owner.
+		/// Any deposit is freed for the account which paid it.
 		///
 		/// - `collection`: The identifier of the collection whose item's metadata to clear.
 		/// - `maybe_item`: The identifier of the item whose metadata to clear.
@@ -1181,9 +1432,12 @@ pub mod pallet {
 
 			let mut collection_details =
 				Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
-			if let Some(check_owner) = &maybe_check_owner {
-				ensure!(check_owner == &collection_details.owner, Error::<T, I>::NoPermission);
-			}
+			Self::check_attribute_origin(
+				&collection,
+				&collection_details,
+				maybe_item,
+				maybe_check_owner.as_ref(),
+			)?;
 			let maybe_is_frozen = match maybe_item {
 				None => CollectionMetadataOf::<T, I>::get(collection.clone()).map(|v| v.is_frozen),
 				Some(item) =>
@@ -1195,8 +1449,15 @@ pub mod pallet {
 				Attribute::<T, I>::take((collection.clone(), maybe_item, &key))
 			{
 				collection_details.attributes.saturating_dec();
-				collection_details.total_deposit.saturating_reduce(deposit);
-				T::Currency::unreserve(&collection_details.owner, deposit);
+				match maybe_item
+					.and_then(|item| AttributeDepositorOf::<T, I>::take((&collection, item, &key)))
+				{
+					Some(depositor) => Deposit::<T, I>::release(&depositor, deposit),
+					None => {
+						collection_details.total_deposit.saturating_reduce(deposit);
+						Deposit::<T, I>::release(&collection_details.owner, deposit)
+					},
+				};
 				Collection::<T, I>::insert(collection.clone(), &collection_details);
 				Self::deposit_event(Event::AttributeCleared { collection, maybe_item, key });
 			}
@@ -1255,11 +1516,7 @@ pub mod pallet {
 						.saturating_mul(((data.len()) as u32).into())
 						.saturating_add(T::MetadataDepositBase::get());
 				}
-				if deposit > old_deposit {
-					T::Currency::reserve(&collection_details.owner, deposit - old_deposit)?;
-				} else if deposit < old_deposit {
-					T::Currency::unreserve(&collection_details.owner, old_deposit - deposit);
-				}
+				Deposit::<T, I>::rejig(&collection_details.owner, old_deposit, deposit)?;
 				collection_details.total_deposit.saturating_accrue(deposit);
 
 				*metadata = Some(ItemMetadata { deposit, data: data.clone(), is_frozen });
@@ -1308,7 +1565,7 @@ pub mod pallet {
 					collection_details.item_metadatas.saturating_dec();
 				}
 				let deposit = metadata.take().ok_or(Error::<T, I>::UnknownCollection)?.deposit;
-				T::Currency::unreserve(&collection_details.owner, deposit);
+				Deposit::<T, I>::release(&collection_details.owner, deposit);
 				collection_details.total_deposit.saturating_reduce(deposit);
 
 				Collection::<T, I>::insert(&collection, &collection_details);
@@ -1363,11 +1620,7 @@ pub mod pallet {
 						.saturating_mul(((data.len()) as u32).into())
 						.saturating_add(T::MetadataDepositBase::get());
 				}
-				if deposit > old_deposit {
-					T::Currency::reserve(&details.owner, deposit - old_deposit)?;
-				} else if deposit < old_deposit {
-					T::Currency::unreserve(&details.owner, old_deposit - deposit);
-				}
+				Deposit::<T, I>::rejig(&details.owner, old_deposit, deposit)?;
 				details.total_deposit.saturating_accrue(deposit);
 
 				Collection::<T, I>::insert(&collection, details);
@@ -1412,7 +1665,7 @@ pub mod pallet {
 				ensure!(maybe_check_owner.is_none() || !was_frozen, Error::<T, I>::Frozen);
 
 				let deposit = metadata.take().ok_or(Error::<T, I>::UnknownCollection)?.deposit;
-				T::Currency::unreserve(&details.owner, deposit);
+				Deposit::<T, I>::release(&details.owner, deposit);
 				details.total_deposit.saturating_reduce(deposit);
 				Collection::<T, I>::insert(&collection, details);
 				Self::deposit_event(Event::CollectionMetadataCleared { collection });
@@ -1528,6 +1781,8 @@ pub mod pallet {
 		/// - `collection`: The collection of the item.
 		/// - `item`: The item the sender wants to buy.
 		/// - `bid_price`: The price the sender is willing to pay.
+		/// - `max_royalty`: The highest royalty the sender accepts to be taken from the price, so
+		///   that the royalty can't be raised between the sale being offered and the item bought.
 		///
 		/// Emits `ItemBought` on success.
 		#[pallet::call_index(25)]
@@ -1537,9 +1792,285 @@ pub mod pallet {
 			collection: T::CollectionId,
 			item: T::ItemId,
 			bid_price: ItemPrice<T, I>,
+			max_royalty: Permill,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_buy_item(collection, item, origin, bid_price, max_royalty)
+		}
+
+		/// Mint an item by providing the pre-signed approval.
+		///
+		/// Origin must be Signed and becomes the owner of the minted item. It funds the item
+		/// deposit, which is held from the collection owner like for any other mint, and pays the
+		/// `mint_price` to the collection owner, if any.
+		///
+		/// - `mint_data`: The pre-signed approval that consists of the item to be minted, who can
+		///   mint it (`None` for anyone), until what block number and at what price.
+		/// - `signature`: The signature of the `mint_data` object.
+		/// - `signer`: The `mint_data` object's signer. Should be the Issuer of the collection.
+		///
+		/// A pre-signed mint can only be redeemed once, even if its item is burned afterwards.
+		///
+		/// Emits `Issued` on success.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::mint_pre_signed())]
+		pub fn mint_pre_signed(
+			origin: OriginFor<T>,
+			mint_data: Box<PreSignedMintOf<T, I>>,
+			signature: T::OffchainSignature,
+			signer: T::AccountId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::validate_signature(&Encode::encode(&mint_data), &signature, &signer)?;
+			Self::do_mint_pre_signed(origin, *mint_data, signer)
+		}
+
+		/// Set (or reset) the royalty paid on every sale of an item of a collection.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Owner of
+		/// the `collection`.
+		///
+		/// - `collection`: The identifier of the collection to change.
+		/// - `royalty`: The beneficiary of the royalty and its share of the sale price, which must
+		///   not exceed `T::MaxRoyalty`. Pass `None` to reset the royalty.
+		///
+		/// Emits `CollectionRoyaltySet` on success if the royalty is not `None`.
+		/// Emits `CollectionRoyaltyRemoved` on success if the royalty is `None`.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::set_collection_royalty())]
+		pub fn set_collection_royalty(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			royalty: Option<(AccountIdLookupOf<T>, Permill)>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+
+			let details =
+				Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+			if let Some(check_owner) = &maybe_check_owner {
+				ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+			}
+
+			if let Some((beneficiary, royalty)) = royalty {
+				ensure!(royalty <= T::MaxRoyalty::get(), Error::<T, I>::RoyaltyTooHigh);
+				let beneficiary = T::Lookup::lookup(beneficiary)?;
+				CollectionRoyalty::<T, I>::insert(&collection, (&beneficiary, royalty));
+				Self::deposit_event(Event::CollectionRoyaltySet {
+					collection,
+					beneficiary,
+					royalty,
+				});
+			} else {
+				CollectionRoyalty::<T, I>::remove(&collection);
+				Self::deposit_event(Event::CollectionRoyaltyRemoved { collection });
+			}
+			Ok(())
+		}
+
+		/// Mint several items of a particular collection.
+		///
+		/// The origin must be Signed and the sender must be the Issuer of the `collection`.
+		///
+		/// - `collection`: The collection of the items to be minted.
+		/// - `items`: The items to be minted, each with its initial owner. At most `MaxBatchSize`.
+		/// - `all_or_nothing`: If `true`, the whole call fails as soon as any item fails to be
+		///   minted. Otherwise, failing items are skipped and reported.
+		///
+		/// Emits `Issued` for every minted item and `BatchItemFailed` for every skipped item.
+		///
+		/// Weight: `O(I)` where `I` is the number of `items`.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::mint_batch(items.len() as u32))]
+		pub fn mint_batch(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			items: Vec<(T::ItemId, AccountIdLookupOf<T>)>,
+			all_or_nothing: bool,
 		) -> DispatchResult {
 			let origin = ensure_signed(origin)?;
-			Self::do_buy_item(collection, item, origin, bid_price)
+			ensure!(items.len() <= T::MaxBatchSize::get() as usize, Error::<T, I>::BatchTooLarge);
+			let details =
+				Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+			ensure!(details.issuer == origin, Error::<T, I>::NoPermission);
+
+			Self::do_batch(collection, items, all_or_nothing, |collection, item, owner| {
+				Self::do_mint(collection, item, owner, |_| Ok(()))
+			})
+		}
+
+		/// Move several items of a collection from the sender account to others.
+		///
+		/// This resets the approved account of the items.
+		///
+		/// Origin must be Signed and, for every item, the signing account must be either:
+		/// - the Admin of the `collection`;
+		/// - the Owner of the `item`;
+		/// - the approved delegate for the `item` (in this case, the approval is reset).
+		///
+		/// - `collection`: The collection of the items to be transferred.
+		/// - `items`: The items to be transferred, each with the account to receive it. At most
+		///   `MaxBatchSize`.
+		/// - `all_or_nothing`: If `true`, the whole call fails as soon as any item fails to be
+		///   transferred. Otherwise, failing items are skipped and reported.
+		///
+		/// Emits `Transferred` for every transferred item and `BatchItemFailed` for every skipped
+		/// item.
+		///
+		/// Weight: `O(I)` where `I` is the number of `items`.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::transfer_batch(items.len() as u32))]
+		pub fn transfer_batch(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			items: Vec<(T::ItemId, AccountIdLookupOf<T>)>,
+			all_or_nothing: bool,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			ensure!(items.len() <= T::MaxBatchSize::get() as usize, Error::<T, I>::BatchTooLarge);
+
+			Self::do_batch(collection, items, all_or_nothing, |collection, item, dest| {
+				Self::do_transfer(collection, item, dest, |collection_details, details| {
+					if details.owner != origin && collection_details.admin != origin {
+						let approved = details.approved.take().map_or(false, |i| i == origin);
+						ensure!(approved, Error::<T, I>::NoPermission);
+					}
+					Ok(())
+				})
+			})
+		}
+
+		/// Approve a delegated third-party account to set and clear attributes of an item.
+		///
+		/// The delegate pays the deposits of the attributes it sets, which are freed back to it
+		/// when the attributes are cleared. Any previously approved delegate is replaced.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be the Owner of the
+		/// `collection`.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item whose attributes the delegate may set.
+		/// - `delegate`: The account to delegate permission to set attributes of the item.
+		///
+		/// Emits `ItemAttributesApprovalAdded` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::approve_item_attributes())]
+		pub fn approve_item_attributes(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			delegate: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+			let delegate = T::Lookup::lookup(delegate)?;
+
+			let collection_details =
+				Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+			if let Some(check_owner) = &maybe_check_owner {
+				ensure!(check_owner == &collection_details.owner, Error::<T, I>::NoPermission);
+			}
+			ensure!(Item::<T, I>::contains_key(&collection, &item), Error::<T, I>::UnknownItem);
+
+			ItemAttributesApprovalOf::<T, I>::insert(&collection, &item, &delegate);
+			Self::deposit_event(Event::ItemAttributesApprovalAdded { collection, item, delegate });
+			Ok(())
+		}
+
+		/// Cancel the approval of a delegate to set attributes of an item.
+		///
+		/// Attributes already set by the delegate are kept, and their deposits stay reserved
+		/// from the delegate until they are cleared.
+		///
+		/// Origin must be either `ForceOrigin` or Signed and the sender should be either the
+		/// Owner of the `collection` or the approved delegate.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item whose attributes approval is cancelled.
+		///
+		/// Emits `ItemAttributesApprovalRemoved` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::cancel_item_attributes_approval())]
+		pub fn cancel_item_attributes_approval(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+		) -> DispatchResult {
+			let maybe_check = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+
+			let collection_details =
+				Collection::<T, I>::get(&collection).ok_or(Error::<T, I>::UnknownCollection)?;
+			let delegate = ItemAttributesApprovalOf::<T, I>::get(&collection, &item)
+				.ok_or(Error::<T, I>::NoDelegate)?;
+			if let Some(check) = &maybe_check {
+				let permitted = check == &collection_details.owner || check == &delegate;
+				ensure!(permitted, Error::<T, I>::NoPermission);
+			}
+
+			ItemAttributesApprovalOf::<T, I>::remove(&collection, &item);
+			Self::deposit_event(Event::ItemAttributesApprovalRemoved {
+				collection,
+				item,
+				delegate,
+			});
+			Ok(())
+		}
+
+		/// Lease an item to another account until a given block.
+		///
+		/// The lessee becomes the user of the item, as returned by [`Pallet::user_of`], while the
+		/// ownership is unchanged. The item can't be transferred or burned until the lease expires
+		/// after the block `until`.
+		///
+		/// Origin must be Signed and must be the owner of the `item`.
+		///
+		/// - `collection`: The collection of the item.
+		/// - `item`: The item to lease.
+		/// - `lessee`: The account to lease the item to.
+		/// - `until`: The last block of the lease.
+		///
+		/// Emits `ItemLeased` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::lease())]
+		pub fn lease(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			item: T::ItemId,
+			lessee: AccountIdLookupOf<T>,
+			until: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let lessee = T::Lookup::lookup(lessee)?;
+			Self::do_lease(collection, item, origin, lessee, until)
+		}
+
+		/// Remove the record of a redeemed pre-signed mint whose deadline has passed.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `mint_hash`: The hash of the encoded pre-signed mint.
+		///
+		/// Weight: `O(1)`
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::clear_pre_signed_mint())]
+		pub fn clear_pre_signed_mint(origin: OriginFor<T>, mint_hash: T::Hash) -> DispatchResult {
+			ensure_signed(origin)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let deadline = UsedPreSignedMints::<T, I>::get(&mint_hash)
+				.ok_or(Error::<T, I>::UnknownPreSignedMint)?;
+			ensure!(deadline < now, Error::<T, I>::DeadlineNotExpired);
+			UsedPreSignedMints::<T, I>::remove(&mint_hash);
+			Ok(())
 		}
 	}
 }
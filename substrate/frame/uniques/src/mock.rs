@@ -21,10 +21,13 @@ use super::*;
 use crate as pallet_uniques;
 
 use frame_support::{
-	construct_runtime, derive_impl,
+	construct_runtime, derive_impl, parameter_types,
 	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64},
 };
-use sp_runtime::BuildStorage;
+use sp_runtime::{
+	testing::{TestSignature, UintAuthorityId},
+	BuildStorage,
+};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -48,6 +51,28 @@ impl pallet_balances::Config for Test {
 	type AccountStore = System;
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+pub struct UniquesHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<u32, u32, UintAuthorityId, u64, TestSignature> for UniquesHelper {
+	fn collection(i: u16) -> u32 {
+		i.into()
+	}
+	fn item(i: u16) -> u32 {
+		i.into()
+	}
+	fn signer() -> (UintAuthorityId, u64) {
+		(UintAuthorityId(42), 42)
+	}
+	fn sign(signer: &UintAuthorityId, message: &[u8]) -> TestSignature {
+		TestSignature(signer.0, message.to_vec())
+	}
+}
+
+parameter_types! {
+	pub const MaxRoyalty: Permill = Permill::from_percent(50);
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type CollectionId = u32;
@@ -64,9 +89,13 @@ impl Config for Test {
 	type StringLimit = ConstU32<50>;
 	type KeyLimit = ConstU32<50>;
 	type ValueLimit = ConstU32<50>;
+	type MaxRoyalty = MaxRoyalty;
+	type MaxBatchSize = ConstU32<3>;
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
 	type WeightInfo = ();
 	#[cfg(feature = "runtime-benchmarks")]
-	type Helper = ();
+	type Helper = UniquesHelper;
 }
 
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
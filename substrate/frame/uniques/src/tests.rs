@@ -18,9 +18,13 @@
 //! Tests for Uniques pallet.
 
 use crate::{mock::*, Event, *};
-use frame_support::{assert_noop, assert_ok, traits::Currency};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Currency, Get, Hooks},
+	weights::Weight,
+};
 use pallet_balances::Error as BalancesError;
-use sp_runtime::traits::Dispatchable;
+use sp_runtime::{testing::TestSignature, traits::Dispatchable};
 
 fn items() -> Vec<(u64, u32, u32)> {
 	let mut r: Vec<_> = Account::<Test>::iter().map(|x| x.0).collect();
@@ -715,7 +719,7 @@ fn approved_account_gets_reset_after_buy_item() {
 
 		assert_ok!(Uniques::set_price(RuntimeOrigin::signed(1), 0, item, Some(price), None));
 
-		assert_ok!(Uniques::buy_item(RuntimeOrigin::signed(2), 0, item, price));
+		assert_ok!(Uniques::buy_item(RuntimeOrigin::signed(2), 0, item, price, Permill::zero()));
 
 		// this shouldn't work because the item has been bough and the approved account should be
 		// reset.
@@ -966,7 +970,13 @@ fn buy_item_should_work() {
 
 		// can't buy for less
 		assert_noop!(
-			Uniques::buy_item(RuntimeOrigin::signed(user_2), collection_id, item_1, 1),
+			Uniques::buy_item(
+				RuntimeOrigin::signed(user_2),
+				collection_id,
+				item_1,
+				1,
+				Permill::zero()
+			),
 			Error::<Test>::BidTooLow
 		);
 
@@ -976,6 +986,7 @@ fn buy_item_should_work() {
 			collection_id,
 			item_1,
 			price_1 + 1,
+			Permill::zero(),
 		));
 
 		// validate the new owner & balances
@@ -986,13 +997,25 @@ fn buy_item_should_work() {
 
 		// can't buy from yourself
 		assert_noop!(
-			Uniques::buy_item(RuntimeOrigin::signed(user_1), collection_id, item_2, price_2),
+			Uniques::buy_item(
+				RuntimeOrigin::signed(user_1),
+				collection_id,
+				item_2,
+				price_2,
+				Permill::zero()
+			),
 			Error::<Test>::NoPermission
 		);
 
 		// can't buy when the item is listed for a specific buyer
 		assert_noop!(
-			Uniques::buy_item(RuntimeOrigin::signed(user_2), collection_id, item_2, price_2),
+			Uniques::buy_item(
+				RuntimeOrigin::signed(user_2),
+				collection_id,
+				item_2,
+				price_2,
+				Permill::zero()
+			),
 			Error::<Test>::NoPermission
 		);
 
@@ -1002,6 +1025,7 @@ fn buy_item_should_work() {
 			collection_id,
 			item_2,
 			price_2,
+			Permill::zero(),
 		));
 
 		assert!(events().contains(&Event::<Test>::ItemBought {
@@ -1017,7 +1041,13 @@ fn buy_item_should_work() {
 
 		// can't buy when item is not for sale
 		assert_noop!(
-			Uniques::buy_item(RuntimeOrigin::signed(user_2), collection_id, item_3, price_2),
+			Uniques::buy_item(
+				RuntimeOrigin::signed(user_2),
+				collection_id,
+				item_3,
+				price_2,
+				Permill::zero()
+			),
 			Error::<Test>::NotForSale
 		);
 
@@ -1038,6 +1068,7 @@ fn buy_item_should_work() {
 				collection: collection_id,
 				item: item_3,
 				bid_price: price_1,
+				max_royalty: Permill::zero(),
 			});
 			assert_noop!(
 				buy_item_call.dispatch(RuntimeOrigin::signed(user_2)),
@@ -1053,6 +1084,7 @@ fn buy_item_should_work() {
 				collection: collection_id,
 				item: item_3,
 				bid_price: price_1,
+				max_royalty: Permill::zero(),
 			});
 			assert_noop!(
 				buy_item_call.dispatch(RuntimeOrigin::signed(user_2)),
@@ -1099,3 +1131,519 @@ fn clear_collection_metadata_works() {
 		assert_eq!(Balances::reserved_balance(&1), 10);
 	});
 }
+
+#[test]
+fn items_of_collection_should_page_through_items() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 1));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 43, 2));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 44, 3));
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 1, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 1, 42, 1));
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(42),
+			bvec![0],
+			bvec![1]
+		));
+		assert_ok!(Uniques::set_attribute(RuntimeOrigin::signed(1), 0, None, bvec![2], bvec![3]));
+		assert_ok!(Uniques::set_price(RuntimeOrigin::signed(2), 0, 43, Some(10), Some(3)));
+
+		let first = Uniques::items_of_collection(0, None, 2);
+		assert_eq!(first.len(), 2);
+		let second = Uniques::items_of_collection(0, Some(first[1].item), 2);
+		assert_eq!(second.len(), 1);
+		assert!(Uniques::items_of_collection(0, Some(second[0].item), 2).is_empty());
+
+		let mut all: Vec<_> = first.into_iter().chain(second).collect();
+		all.sort_by_key(|snapshot| snapshot.item);
+		assert_eq!(
+			all,
+			vec![
+				ItemSnapshot { item: 42, owner: 1, attributes: vec![(vec![0], vec![1])] },
+				ItemSnapshot { item: 43, owner: 2, attributes: vec![] },
+				ItemSnapshot { item: 44, owner: 3, attributes: vec![] },
+			]
+		);
+
+		assert_eq!(Uniques::price_of(0, 43), Some((10, Some(3))));
+		assert_eq!(Uniques::price_of(0, 42), None);
+	});
+}
+
+#[test]
+fn pre_signed_mints_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		Balances::make_free_balance_be(&3, 100);
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(1), 0, 1));
+		assert_ok!(Uniques::set_team(RuntimeOrigin::signed(1), 0, 3, 1, 1));
+
+		let mint_data = PreSignedMint {
+			collection: 0,
+			item: 42,
+			only_account: Some(2),
+			deadline: 10,
+			mint_price: Some(10),
+		};
+		let signature = TestSignature(3, Encode::encode(&mint_data));
+
+		// Only the issuer can pre-sign mints.
+		assert_noop!(
+			Uniques::mint_pre_signed(
+				RuntimeOrigin::signed(2),
+				Box::new(mint_data.clone()),
+				TestSignature(1, Encode::encode(&mint_data)),
+				1
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::mint_pre_signed(
+				RuntimeOrigin::signed(2),
+				Box::new(mint_data.clone()),
+				signature.clone(),
+				1
+			),
+			Error::<Test>::WrongSignature
+		);
+		assert_noop!(
+			Uniques::mint_pre_signed(
+				RuntimeOrigin::signed(3),
+				Box::new(mint_data.clone()),
+				signature.clone(),
+				3
+			),
+			Error::<Test>::WrongOrigin
+		);
+
+		assert_ok!(Uniques::mint_pre_signed(
+			RuntimeOrigin::signed(2),
+			Box::new(mint_data.clone()),
+			signature.clone(),
+			3
+		));
+		System::assert_last_event(Event::Issued { collection: 0, item: 42, owner: 2 }.into());
+		assert_eq!(items(), vec![(2, 0, 42)]);
+		// The claimer paid the price and funded the deposit, which the collection owner holds.
+		assert_eq!(Balances::free_balance(&2), 89);
+		assert_eq!(Balances::free_balance(&1), 108);
+		assert_eq!(Balances::reserved_balance(&1), 3);
+
+		// The approval cannot be redeemed twice, not even once the item is burned.
+		assert_noop!(
+			Uniques::mint_pre_signed(
+				RuntimeOrigin::signed(2),
+				Box::new(mint_data.clone()),
+				signature.clone(),
+				3
+			),
+			Error::<Test>::AlreadyClaimed
+		);
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(2), 0, 42, None));
+		assert_noop!(
+			Uniques::mint_pre_signed(
+				RuntimeOrigin::signed(2),
+				Box::new(mint_data.clone()),
+				signature,
+				3
+			),
+			Error::<Test>::AlreadyClaimed
+		);
+		let mint_hash = <Test as frame_system::Config>::Hashing::hash_of(&mint_data);
+		assert_eq!(UsedPreSignedMints::<Test>::get(mint_hash), Some(10));
+
+		let mint_data = PreSignedMint {
+			collection: 0,
+			item: 43,
+			only_account: None,
+			deadline: 10,
+			mint_price: None,
+		};
+		let signature = TestSignature(3, Encode::encode(&mint_data));
+		System::set_block_number(11);
+		assert_noop!(
+			Uniques::mint_pre_signed(
+				RuntimeOrigin::signed(2),
+				Box::new(mint_data.clone()),
+				signature.clone(),
+				3
+			),
+			Error::<Test>::DeadlineExpired
+		);
+		System::set_block_number(10);
+		assert_ok!(Uniques::mint_pre_signed(
+			RuntimeOrigin::signed(2),
+			Box::new(mint_data),
+			signature,
+			3
+		));
+		assert_eq!(items(), vec![(2, 0, 43)]);
+
+		// The record of a redeemed approval is kept until its deadline has passed.
+		assert_noop!(
+			Uniques::clear_pre_signed_mint(RuntimeOrigin::signed(4), mint_hash),
+			Error::<Test>::DeadlineNotExpired
+		);
+		System::set_block_number(11);
+		assert_ok!(Uniques::clear_pre_signed_mint(RuntimeOrigin::signed(4), mint_hash));
+		assert!(!UsedPreSignedMints::<Test>::contains_key(mint_hash));
+		assert_noop!(
+			Uniques::clear_pre_signed_mint(RuntimeOrigin::signed(4), mint_hash),
+			Error::<Test>::UnknownPreSignedMint
+		);
+	});
+}
+
+#[test]
+fn expired_pre_signed_mints_are_pruned_over_several_blocks() {
+	new_test_ext().execute_with(|| {
+		for i in 0..3u8 {
+			let mint_hash = <Test as frame_system::Config>::Hashing::hash(&[i]);
+			UsedPreSignedMints::<Test>::insert(mint_hash, 5);
+		}
+		let unexpired = <Test as frame_system::Config>::Hashing::hash(&[3]);
+		UsedPreSignedMints::<Test>::insert(unexpired, 10);
+
+		// Records are kept until their deadline has passed.
+		Uniques::on_idle(5, Weight::MAX);
+		assert_eq!(UsedPreSignedMints::<Test>::iter().count(), 4);
+		assert_eq!(PreSignedMintsCursor::<Test>::get(), None);
+
+		// Each block only has the weight left to check a single record.
+		let db_weight = <Test as frame_system::Config>::DbWeight::get();
+		let limit = db_weight
+			.reads_writes(1, 2)
+			.saturating_add(Uniques::pre_signed_mint_read_weight());
+		for _ in 0..4 {
+			Uniques::on_idle(6, limit);
+			assert!(PreSignedMintsCursor::<Test>::get().is_some());
+		}
+		assert_eq!(UsedPreSignedMints::<Test>::iter_keys().collect::<Vec<_>>(), vec![unexpired]);
+
+		// The cursor is reset once all records were checked.
+		Uniques::on_idle(6, limit);
+		assert_eq!(PreSignedMintsCursor::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn buy_item_should_pay_royalty() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 1));
+
+		assert_noop!(
+			Uniques::set_collection_royalty(
+				RuntimeOrigin::signed(2),
+				0,
+				Some((4, Permill::from_percent(10)))
+			),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::set_collection_royalty(
+				RuntimeOrigin::signed(1),
+				0,
+				Some((4, Permill::from_percent(51)))
+			),
+			Error::<Test>::RoyaltyTooHigh
+		);
+		assert_ok!(Uniques::set_collection_royalty(
+			RuntimeOrigin::signed(1),
+			0,
+			Some((4, Permill::from_percent(10)))
+		));
+		System::assert_last_event(
+			Event::CollectionRoyaltySet {
+				collection: 0,
+				beneficiary: 4,
+				royalty: Permill::from_percent(10),
+			}
+			.into(),
+		);
+
+		assert_ok!(Uniques::set_price(RuntimeOrigin::signed(1), 0, 42, Some(50), None));
+		assert_noop!(
+			Uniques::buy_item(RuntimeOrigin::signed(2), 0, 42, 50, Permill::from_percent(5)),
+			Error::<Test>::RoyaltyTooHigh
+		);
+		assert_ok!(Uniques::buy_item(
+			RuntimeOrigin::signed(2),
+			0,
+			42,
+			50,
+			Permill::from_percent(10)
+		));
+		System::assert_has_event(
+			Event::RoyaltyPaid { collection: 0, item: 42, beneficiary: 4, amount: 5 }.into(),
+		);
+		assert_eq!(Balances::free_balance(&2), 50);
+		assert_eq!(Balances::free_balance(&4), 5);
+		assert_eq!(Balances::free_balance(&1), 145);
+
+		assert_ok!(Uniques::set_collection_royalty(RuntimeOrigin::root(), 0, None));
+		System::assert_last_event(Event::CollectionRoyaltyRemoved { collection: 0 }.into());
+		assert!(!CollectionRoyalty::<Test>::contains_key(0));
+
+		assert_ok!(Uniques::set_price(RuntimeOrigin::signed(2), 0, 42, Some(50), None));
+		assert_ok!(Uniques::buy_item(RuntimeOrigin::signed(1), 0, 42, 50, Permill::zero()));
+		assert_eq!(Balances::free_balance(&2), 100);
+		assert_eq!(Balances::free_balance(&4), 5);
+	});
+}
+
+#[test]
+fn batch_mint_and_transfer_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+
+		assert_noop!(
+			Uniques::mint_batch(RuntimeOrigin::signed(2), 0, vec![(1, 1)], true),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::mint_batch(
+				RuntimeOrigin::signed(1),
+				0,
+				vec![(1, 1), (2, 2), (3, 3), (4, 4)],
+				true
+			),
+			Error::<Test>::BatchTooLarge
+		);
+		assert_noop!(
+			Uniques::mint_batch(RuntimeOrigin::signed(1), 0, vec![(1, 1), (2, 2), (1, 3)], true),
+			Error::<Test>::AlreadyExists
+		);
+
+		// Failing items are skipped and reported.
+		assert_ok!(Uniques::mint_batch(
+			RuntimeOrigin::signed(1),
+			0,
+			vec![(1, 1), (2, 2), (1, 3)],
+			false
+		));
+		System::assert_last_event(
+			Event::BatchItemFailed {
+				collection: 0,
+				item: 1,
+				error: Error::<Test>::AlreadyExists.into(),
+			}
+			.into(),
+		);
+		assert_eq!(items(), vec![(1, 0, 1), (2, 0, 2)]);
+
+		assert_ok!(Uniques::transfer_batch(
+			RuntimeOrigin::signed(2),
+			0,
+			vec![(1, 3), (2, 3)],
+			false
+		));
+		System::assert_has_event(
+			Event::BatchItemFailed {
+				collection: 0,
+				item: 1,
+				error: Error::<Test>::NoPermission.into(),
+			}
+			.into(),
+		);
+		System::assert_last_event(
+			Event::Transferred { collection: 0, item: 2, from: 2, to: 3 }.into(),
+		);
+		assert_eq!(items(), vec![(1, 0, 1), (3, 0, 2)]);
+
+		assert_noop!(
+			Uniques::transfer_batch(RuntimeOrigin::signed(3), 0, vec![(2, 4), (1, 4)], true),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::transfer_batch(
+				RuntimeOrigin::signed(1),
+				0,
+				vec![(2, 4), (1, 4), (2, 4), (1, 4)],
+				true
+			),
+			Error::<Test>::BatchTooLarge
+		);
+		assert_ok!(Uniques::transfer_batch(
+			RuntimeOrigin::signed(1),
+			0,
+			vec![(2, 4), (1, 4)],
+			true
+		));
+		assert_eq!(items(), vec![(4, 0, 1), (4, 0, 2)]);
+	});
+}
+
+#[test]
+fn approved_delegates_can_set_item_attributes() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(1), 0, 1));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 3));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 43, 3));
+		assert_eq!(Balances::reserved_balance(&1), 4);
+
+		assert_noop!(
+			Uniques::set_attribute(RuntimeOrigin::signed(2), 0, Some(42), bvec![0], bvec![0]),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::approve_item_attributes(RuntimeOrigin::signed(3), 0, 42, 2),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::approve_item_attributes(RuntimeOrigin::signed(1), 0, 42, 2));
+		System::assert_last_event(
+			Event::ItemAttributesApprovalAdded { collection: 0, item: 42, delegate: 2 }.into(),
+		);
+
+		// The delegate pays the deposits of the attributes it sets.
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(2),
+			0,
+			Some(42),
+			bvec![0],
+			bvec![0]
+		));
+		assert_eq!(Balances::reserved_balance(&2), 3);
+		assert_eq!(Balances::reserved_balance(&1), 4);
+		assert_eq!(Collection::<Test>::get(0).unwrap().total_deposit, 4);
+		assert_noop!(
+			Uniques::set_attribute(RuntimeOrigin::signed(2), 0, None, bvec![0], bvec![0]),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::set_attribute(RuntimeOrigin::signed(2), 0, Some(43), bvec![0], bvec![0]),
+			Error::<Test>::NoPermission
+		);
+
+		// Overwriting an attribute frees the deposit of the previous depositor.
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(1),
+			0,
+			Some(42),
+			bvec![0],
+			bvec![0, 0]
+		));
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_eq!(Balances::reserved_balance(&1), 8);
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(2),
+			0,
+			Some(42),
+			bvec![0],
+			bvec![1]
+		));
+		assert_eq!(Balances::reserved_balance(&2), 3);
+		assert_eq!(Balances::reserved_balance(&1), 4);
+		assert_eq!(Collection::<Test>::get(0).unwrap().total_deposit, 4);
+
+		assert_noop!(
+			Uniques::cancel_item_attributes_approval(RuntimeOrigin::signed(3), 0, 42),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::cancel_item_attributes_approval(RuntimeOrigin::signed(2), 0, 42));
+		System::assert_last_event(
+			Event::ItemAttributesApprovalRemoved { collection: 0, item: 42, delegate: 2 }.into(),
+		);
+		assert_noop!(
+			Uniques::clear_attribute(RuntimeOrigin::signed(2), 0, Some(42), bvec![0]),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::clear_attribute(RuntimeOrigin::signed(1), 0, Some(42), bvec![0]));
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_eq!(Balances::reserved_balance(&1), 4);
+
+		// Destroying the collection frees the deposits of delegates.
+		assert_ok!(Uniques::approve_item_attributes(RuntimeOrigin::signed(1), 0, 43, 2));
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(2),
+			0,
+			Some(43),
+			bvec![0],
+			bvec![0]
+		));
+		assert_eq!(Balances::reserved_balance(&2), 3);
+		let w = Collection::<Test>::get(0).unwrap().destroy_witness();
+		assert_ok!(Uniques::destroy(RuntimeOrigin::signed(1), 0, w));
+		assert_eq!(Balances::reserved_balance(&2), 0);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert_eq!(AttributeDepositorOf::<Test>::iter().count(), 0);
+		assert_eq!(ItemAttributesApprovalOf::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn leased_items_have_a_user_until_the_deadline() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Uniques::force_create(RuntimeOrigin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 42, 1));
+		assert_eq!(Uniques::user_of(0, 42), Some(1));
+		assert_eq!(Uniques::user_of(0, 43), None);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Uniques::lease(RuntimeOrigin::signed(2), 0, 42, 3, 20),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::lease(RuntimeOrigin::signed(1), 0, 43, 3, 20),
+			Error::<Test>::UnknownItem
+		);
+		assert_noop!(
+			Uniques::lease(RuntimeOrigin::signed(1), 0, 42, 3, 9),
+			Error::<Test>::DeadlineExpired
+		);
+		assert_ok!(Uniques::lease(RuntimeOrigin::signed(1), 0, 42, 3, 20));
+		System::assert_last_event(
+			Event::ItemLeased { collection: 0, item: 42, lessee: 3, until: 20 }.into(),
+		);
+		assert_eq!(Uniques::user_of(0, 42), Some(3));
+		assert_eq!(Uniques::owner(0, 42), Some(1));
+		assert_noop!(
+			Uniques::lease(RuntimeOrigin::signed(1), 0, 42, 4, 30),
+			Error::<Test>::AlreadyLeased
+		);
+
+		// The lease lasts until the end of its last block and can then be replaced.
+		System::set_block_number(20);
+		assert_eq!(Uniques::user_of(0, 42), Some(3));
+		System::set_block_number(21);
+		assert_eq!(Uniques::user_of(0, 42), Some(1));
+		assert_ok!(Uniques::lease(RuntimeOrigin::signed(1), 0, 42, 4, 30));
+		assert_eq!(Uniques::user_of(0, 42), Some(4));
+
+		// The item can't change hands while it is leased, not even to its owner.
+		assert_noop!(Uniques::transfer(RuntimeOrigin::signed(1), 0, 42, 1), Error::<Test>::Leased);
+		assert_noop!(Uniques::transfer(RuntimeOrigin::signed(1), 0, 42, 2), Error::<Test>::Leased);
+		assert_noop!(
+			Uniques::transfer_batch(RuntimeOrigin::signed(1), 0, vec![(42, 2)], true),
+			Error::<Test>::Leased
+		);
+		assert_eq!(Uniques::user_of(0, 42), Some(4));
+
+		// Once the lease expired, transferring the item removes it.
+		System::set_block_number(31);
+		assert_ok!(Uniques::transfer(RuntimeOrigin::signed(1), 0, 42, 2));
+		assert_eq!(Uniques::user_of(0, 42), Some(2));
+		assert!(UserOf::<Test>::get(0, 42).is_none());
+
+		// Nor can it be burned while it is leased.
+		assert_ok!(Uniques::lease(RuntimeOrigin::signed(2), 0, 42, 3, 40));
+		assert_noop!(Uniques::burn(RuntimeOrigin::signed(2), 0, 42, None), Error::<Test>::Leased);
+		assert_eq!(Uniques::user_of(0, 42), Some(3));
+
+		// Burning the item once the lease expired removes it.
+		System::set_block_number(41);
+		assert_ok!(Uniques::burn(RuntimeOrigin::signed(2), 0, 42, None));
+		assert_eq!(Uniques::user_of(0, 42), None);
+		assert_eq!(UserOf::<Test>::iter().count(), 0);
+	});
+}
@@ -22,6 +22,7 @@ use frame_support::{
 	pallet_prelude::{BoundedVec, MaxEncodedLen},
 	traits::Get,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 use scale_info::TypeInfo;
 
 /// A type alias for handling balance deposits.
@@ -36,6 +37,14 @@ pub(super) type ItemDetailsFor<T, I> =
 /// A type alias to represent the price of an item.
 pub(super) type ItemPrice<T, I = ()> =
 	<<T as Config<I>>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
+/// A type alias for the pre-signed minting configuration for a specified collection.
+pub(super) type PreSignedMintOf<T, I> = PreSignedMint<
+	<T as Config<I>>::CollectionId,
+	<T as Config<I>>::ItemId,
+	<T as SystemConfig>::AccountId,
+	BlockNumberFor<T>,
+	ItemPrice<T, I>,
+>;
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct CollectionDetails<AccountId, DepositBalance> {
@@ -100,6 +109,18 @@ pub struct ItemDetails<AccountId, DepositBalance> {
 	pub deposit: DepositBalance,
 }
 
+/// An item of a collection with its owner and attributes, as enumerated by
+/// [`Pallet::items_of_collection`].
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ItemSnapshot<ItemId, AccountId> {
+	/// The item.
+	pub item: ItemId,
+	/// The owner of the item.
+	pub owner: AccountId,
+	/// The attributes of the item, as key-value pairs.
+	pub attributes: Vec<(Vec<u8>, Vec<u8>)>,
+}
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(StringLimit))]
 #[codec(mel_bound(DepositBalance: MaxEncodedLen))]
@@ -131,3 +152,19 @@ pub struct ItemMetadata<DepositBalance, StringLimit: Get<u32>> {
 	/// Whether the item metadata may be changed by a non Force origin.
 	pub is_frozen: bool,
 }
+
+/// A permission to mint an item, signed off-chain by the issuer of its collection and redeemed
+/// on-chain through [`Pallet::mint_pre_signed`].
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct PreSignedMint<CollectionId, ItemId, AccountId, Deadline, Balance> {
+	/// A collection of the item to be minted.
+	pub collection: CollectionId,
+	/// Item's ID.
+	pub item: ItemId,
+	/// Restrict the claim to a particular account.
+	pub only_account: Option<AccountId>,
+	/// The last block at which the permission can be redeemed.
+	pub deadline: Deadline,
+	/// An optional price the claimer would need to pay to the collection owner for the mint.
+	pub mint_price: Option<Balance>,
+}
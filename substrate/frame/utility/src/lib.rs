@@ -62,7 +62,7 @@ use alloc::{boxed::Box, vec::Vec};
 use codec::{Decode, Encode};
 use frame_support::{
 	dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
-	traits::{IsSubType, OriginTrait, UnfilteredDispatchable},
+	traits::{InspectNestedCalls, IsSubType, OriginTrait, UnfilteredDispatchable},
 };
 use sp_core::TypeId;
 use sp_io::hashing::blake2_256;
@@ -494,3 +494,17 @@ impl<T: Config> Pallet<T> {
 			.expect("infinite length input; no invalid inputs for type; qed")
 	}
 }
+
+impl<T: Config> InspectNestedCalls<<T as Config>::RuntimeCall> for Pallet<T> {
+	fn nested_calls(call: &<T as Config>::RuntimeCall) -> Vec<&<T as Config>::RuntimeCall> {
+		match call.is_sub_type() {
+			Some(Call::batch { calls }) |
+			Some(Call::batch_all { calls }) |
+			Some(Call::force_batch { calls }) => calls.iter().collect(),
+			Some(Call::as_derivative { call, .. }) |
+			Some(Call::dispatch_as { call, .. }) |
+			Some(Call::with_weight { call, .. }) => Vec::from([&**call]),
+			_ => Vec::new(),
+		}
+	}
+}
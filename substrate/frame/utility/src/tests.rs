@@ -914,3 +914,58 @@ fn with_weight_works() {
 		);
 	})
 }
+
+#[test]
+fn nested_calls_are_limited() {
+	use frame_support::traits::ConstU32;
+	use sp_runtime::transaction_validity::InvalidTransaction;
+
+	type CheckNestedCalls =
+		frame_system::CheckNestedCalls<Test, (Utility,), ConstU32<2>, ConstU32<4>>;
+
+	let batch = |calls| RuntimeCall::Utility(UtilityCall::batch { calls });
+	let as_derivative =
+		|call| RuntimeCall::Utility(UtilityCall::as_derivative { index: 0, call: Box::new(call) });
+
+	assert_eq!(Utility::nested_calls(&call_transfer(2, 1)), Vec::<&RuntimeCall>::new());
+	assert_eq!(
+		Utility::nested_calls(&batch(vec![call_transfer(2, 1), call_transfer(3, 1)])).len(),
+		2
+	);
+
+	// Two levels of nesting are fine.
+	assert_ok!(CheckNestedCalls::check_nesting(&as_derivative(batch(vec![call_transfer(2, 1)]))));
+	// Three are not.
+	assert_eq!(
+		CheckNestedCalls::check_nesting(&as_derivative(as_derivative(batch(vec![call_transfer(
+			2, 1
+		)])))),
+		Err(InvalidTransaction::ExhaustsResources)
+	);
+	// Neither are more than four nested calls.
+	assert_ok!(CheckNestedCalls::check_nesting(&batch(vec![call_transfer(2, 1); 4])));
+	assert_eq!(
+		CheckNestedCalls::check_nesting(&batch(vec![call_transfer(2, 1); 5])),
+		Err(InvalidTransaction::ExhaustsResources)
+	);
+}
+
+#[test]
+fn any_nested_call_is_depth_limited() {
+	use frame_support::traits::NestingTooDeep;
+
+	let batch = |calls| RuntimeCall::Utility(UtilityCall::batch { calls });
+	let as_derivative =
+		|call| RuntimeCall::Utility(UtilityCall::as_derivative { index: 0, call: Box::new(call) });
+	let is_transfer_to_3 = |call: &RuntimeCall| *call == call_transfer(3, 1);
+
+	assert_eq!(Utility::any_nested_call(&call_transfer(3, 1), 0, is_transfer_to_3), Ok(true));
+	assert_eq!(Utility::any_nested_call(&call_transfer(2, 1), 0, is_transfer_to_3), Ok(false));
+
+	let nested = as_derivative(batch(vec![call_transfer(2, 1), call_transfer(3, 1)]));
+	assert_eq!(Utility::any_nested_call(&nested, 2, is_transfer_to_3), Ok(true));
+	let other = as_derivative(batch(vec![call_transfer(2, 1)]));
+	assert_eq!(Utility::any_nested_call(&other, 2, is_transfer_to_3), Ok(false));
+	// The calls below the limit can't be inspected.
+	assert_eq!(Utility::any_nested_call(&nested, 1, is_transfer_to_3), Err(NestingTooDeep));
+}
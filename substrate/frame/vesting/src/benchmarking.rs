@@ -19,12 +19,16 @@
 
 #![cfg(feature = "runtime-benchmarks")]
 
+use alloc::vec;
 use frame_benchmarking::{v2::*, BenchmarkError};
-use frame_support::assert_ok;
+use frame_support::{assert_ok, storage::unhashed};
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use sp_runtime::traits::{Bounded, CheckedDiv, CheckedMul};
 
-use crate::*;
+use crate::{
+	migrations::{v2::LazyMigrationV1ToV2, OldVestingInfo},
+	*,
+};
 
 const SEED: u32 = 0;
 
@@ -435,6 +439,74 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn revoke_vesting_schedule(
+		l: Linear<0, { MaxLocksOf::<T>::get() - 1 }>,
+		s: Linear<1, { T::MAX_VESTING_SCHEDULES }>,
+	) -> Result<(), BenchmarkError> {
+		let grantor = account::<T::AccountId>("grantor", 0, SEED);
+		T::Currency::make_free_balance_be(&grantor, BalanceOf::<T>::max_value() / 2u32.into());
+
+		let target = account::<T::AccountId>("target", 0, SEED);
+		let target_lookup = T::Lookup::unlookup(target.clone());
+		T::Currency::make_free_balance_be(&target, T::Currency::minimum_balance());
+
+		// Give target existing locks.
+		add_locks::<T>(&target, l as u8);
+		add_vesting_schedules::<T>(&target, s - 1)?;
+
+		// The last vesting schedule is revocable.
+		let locked = T::MinVestedTransfer::get();
+		let schedule =
+			VestingInfo::new(locked, locked, 1_u32.into()).with_revocable_by(Some(grantor.clone()));
+		assert_ok!(Pallet::<T>::do_vested_transfer(&grantor, &target, schedule));
+		let schedule_index = s - 1;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(grantor.clone()), target_lookup, schedule_index);
+
+		assert_eq!(
+			Vesting::<T>::get(&target).map_or(0, |schedules| schedules.len()),
+			schedule_index as usize,
+			"Schedule count should reduce by 1"
+		);
+		frame_system::Pallet::<T>::assert_last_event(
+			<T as Config>::RuntimeEvent::from(Event::VestingRevoked {
+				account: target,
+				grantor,
+				amount: locked,
+			})
+			.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn migration_v2_step() -> Result<(), BenchmarkError> {
+		let target: T::AccountId = account("target", 0, SEED);
+		let locked = T::MinVestedTransfer::get();
+		let schedule = OldVestingInfo { locked, per_block: locked, starting_block: 1_u32.into() };
+		let schedules: BoundedVec<_, MaxVestingSchedulesGet<T>> =
+			BoundedVec::truncate_from(vec![schedule; T::MAX_VESTING_SCHEDULES as usize]);
+		// Only the schedules of `target` are left to migrate.
+		let _ = Vesting::<T>::clear(u32::MAX, None);
+		unhashed::put(&Vesting::<T>::hashed_key_for(&target), &schedules);
+		StorageVersion::<T>::put(Releases::V1);
+
+		#[block]
+		{
+			LazyMigrationV1ToV2::<T>::account_step(None);
+		}
+
+		assert_eq!(
+			Vesting::<T>::get(&target).map(|schedules| schedules.len()),
+			Some(T::MAX_VESTING_SCHEDULES as usize),
+		);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite! {
 		Pallet,
 		mock::ExtBuilder::default().existential_deposit(256).build(),
@@ -33,6 +33,10 @@
 //! either `vest` (in typical case where the sender is calling on their own behalf) or `vest_other`
 //! in case the sender is calling on another account's behalf.
 //!
+//! A vested transfer may make its schedule revocable by the source of the funds, its grantor. The
+//! grantor, or the `RevokeOrigin`, can then revoke the schedule, transferring the amount which is
+//! not vested yet back to the grantor.
+//!
 //! ## Interface
 //!
 //! This pallet implements the `VestingSchedule` trait.
@@ -42,6 +46,8 @@
 //! - `vest` - Update the lock, reducing it in line with the amount "vested" so far.
 //! - `vest_other` - Update the lock of another account, reducing it in line with the amount
 //!   "vested" so far.
+//! - `revoke_vesting_schedule` - Revoke the unvested remainder of a revocable schedule, giving it
+//!   back to its grantor.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -75,8 +81,8 @@ use frame_system::pallet_prelude::BlockNumberFor;
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{
-		AtLeast32BitUnsigned, BlockNumberProvider, Bounded, Convert, MaybeSerializeDeserialize,
-		One, Saturating, StaticLookup, Zero,
+		AtLeast32BitUnsigned, BadOrigin, BlockNumberProvider, Bounded, Convert,
+		MaybeSerializeDeserialize, One, Saturating, StaticLookup, Zero,
 	},
 	DispatchError, RuntimeDebug,
 };
@@ -90,6 +96,8 @@ type BalanceOf<T> =
 type MaxLocksOf<T> =
 	<<T as Config>::Currency as LockableCurrency<<T as frame_system::Config>::AccountId>>::MaxLocks;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+type VestingInfoOf<T> =
+	VestingInfo<BalanceOf<T>, BlockNumberFor<T>, <T as frame_system::Config>::AccountId>;
 
 const VESTING_ID: LockIdentifier = *b"vesting ";
 
@@ -99,6 +107,7 @@ const VESTING_ID: LockIdentifier = *b"vesting ";
 enum Releases {
 	V0,
 	V1,
+	V2,
 }
 
 impl Default for Releases {
@@ -131,8 +140,8 @@ impl VestingAction {
 	/// Pick the schedules that this action dictates should continue vesting undisturbed.
 	fn pick_schedules<T: Config>(
 		&self,
-		schedules: Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>,
-	) -> impl Iterator<Item = VestingInfo<BalanceOf<T>, BlockNumberFor<T>>> + '_ {
+		schedules: Vec<VestingInfoOf<T>>,
+	) -> impl Iterator<Item = VestingInfoOf<T>> + '_ {
 		schedules.into_iter().enumerate().filter_map(move |(index, schedule)| {
 			if self.should_remove(index) {
 				None
@@ -182,6 +191,9 @@ pub mod pallet {
 		/// Provider for the block number.
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
 
+		/// The origin which may revoke any revocable schedule on behalf of its grantor.
+		type RevokeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Maximum number of vesting schedules an account may have at a given moment.
 		const MAX_VESTING_SCHEDULES: u32;
 	}
@@ -207,7 +219,7 @@ pub mod pallet {
 		_,
 		Blake2_128Concat,
 		T::AccountId,
-		BoundedVec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>, MaxVestingSchedulesGet<T>>,
+		BoundedVec<VestingInfoOf<T>, MaxVestingSchedulesGet<T>>,
 	>;
 
 	/// Storage version of the pallet.
@@ -231,7 +243,7 @@ pub mod pallet {
 			use sp_runtime::traits::Saturating;
 
 			// Genesis uses the latest storage version.
-			StorageVersion::<T>::put(Releases::V1);
+			StorageVersion::<T>::put(Releases::V2);
 
 			// Generate initial vesting configuration
 			// * who - Account which we are generating vesting configuration for
@@ -269,6 +281,9 @@ pub mod pallet {
 		VestingUpdated { account: T::AccountId, unvested: BalanceOf<T> },
 		/// An \[account\] has become fully vested.
 		VestingCompleted { account: T::AccountId },
+		/// A vesting schedule of an account was revoked, and the amount which was not vested yet
+		/// was transferred back to its grantor.
+		VestingRevoked { account: T::AccountId, grantor: T::AccountId, amount: BalanceOf<T> },
 	}
 
 	/// Error for the vesting pallet.
@@ -285,6 +300,12 @@ pub mod pallet {
 		ScheduleIndexOutOfBounds,
 		/// Failed to create a new schedule because some parameter was invalid.
 		InvalidScheduleParams,
+		/// A schedule can only be made revocable by the source of the vested funds.
+		InvalidGrantor,
+		/// The vesting schedule cannot be revoked.
+		NotRevocable,
+		/// Schedules which are revocable by different grantors cannot be merged.
+		RevocabilityMismatch,
 	}
 
 	#[pallet::call]
@@ -333,7 +354,8 @@ pub mod pallet {
 		/// The dispatch origin for this call must be _Signed_.
 		///
 		/// - `target`: The account receiving the vested funds.
-		/// - `schedule`: The vesting schedule attached to the transfer.
+		/// - `schedule`: The vesting schedule attached to the transfer. It may only be revocable by
+		///   the sender.
 		///
 		/// Emits `VestingCreated`.
 		///
@@ -348,7 +370,7 @@ pub mod pallet {
 		pub fn vested_transfer(
 			origin: OriginFor<T>,
 			target: AccountIdLookupOf<T>,
-			schedule: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+			schedule: VestingInfoOf<T>,
 		) -> DispatchResult {
 			let transactor = ensure_signed(origin)?;
 			let target = T::Lookup::lookup(target)?;
@@ -361,7 +383,8 @@ pub mod pallet {
 		///
 		/// - `source`: The account whose funds should be transferred.
 		/// - `target`: The account that should be transferred the vested funds.
-		/// - `schedule`: The vesting schedule attached to the transfer.
+		/// - `schedule`: The vesting schedule attached to the transfer. It may only be revocable by
+		///   `source`.
 		///
 		/// Emits `VestingCreated`.
 		///
@@ -377,7 +400,7 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			source: AccountIdLookupOf<T>,
 			target: AccountIdLookupOf<T>,
-			schedule: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+			schedule: VestingInfoOf<T>,
 		) -> DispatchResult {
 			ensure_root(origin)?;
 			let target = T::Lookup::lookup(target)?;
@@ -402,6 +425,9 @@ pub mod pallet {
 		/// - `ending_block`: `MAX(schedule1.ending_block, schedule2.ending_block)`.
 		/// - `locked`: `schedule1.locked_at(current_block) + schedule2.locked_at(current_block)`.
 		///
+		/// Both schedules must be revocable by the same grantor, if any, which the merged schedule
+		/// is revocable by as well.
+		///
 		/// The dispatch origin for this call must be _Signed_.
 		///
 		/// - `schedule1_index`: index of the first schedule to merge.
@@ -464,6 +490,63 @@ pub mod pallet {
 			))
 			.into())
 		}
+
+		/// Revoke a revocable vesting schedule, transferring the amount which is not vested yet
+		/// back to its grantor.
+		///
+		/// The dispatch origin for this call must be either _Signed_ by the grantor of the
+		/// schedule, or `RevokeOrigin`.
+		///
+		/// - `target`: An account that has a revocable vesting schedule.
+		/// - `schedule_index`: The index of the vesting schedule to revoke.
+		///
+		/// Emits `VestingRevoked`.
+		///
+		/// NOTE: This will unlock all schedules through the current block.
+		#[pallet::call_index(6)]
+		#[pallet::weight(
+			T::WeightInfo::revoke_vesting_schedule(MaxLocksOf::<T>::get(), T::MAX_VESTING_SCHEDULES)
+		)]
+		pub fn revoke_vesting_schedule(
+			origin: OriginFor<T>,
+			target: AccountIdLookupOf<T>,
+			schedule_index: u32,
+		) -> DispatchResultWithPostInfo {
+			let maybe_grantor = T::RevokeOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+			let who = T::Lookup::lookup(target)?;
+
+			let schedules = Vesting::<T>::get(&who).ok_or(Error::<T>::NotVesting)?;
+			let schedules_count = schedules.len() as u32;
+			let schedule = schedules
+				.get(schedule_index as usize)
+				.ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+			let grantor = schedule.revocable_by().cloned().ok_or(Error::<T>::NotRevocable)?;
+			ensure!(maybe_grantor.map_or(true, |sender| sender == grantor), BadOrigin);
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let unvested = schedule.locked_at::<T::BlockNumberToBalance>(now);
+
+			let remove_action = VestingAction::Remove { index: schedule_index as usize };
+			let (schedules, locked_now) = Self::exec_action(schedules.to_vec(), remove_action)?;
+
+			Self::write_vesting(&who, schedules)?;
+			Self::write_lock(&who, locked_now);
+
+			// Some of the unvested funds may have been withdrawn for one of the reasons allowed
+			// by `UnvestedFundsAllowedWithdrawReasons`.
+			let amount = unvested.min(T::Currency::free_balance(&who));
+			T::Currency::transfer(&who, &grantor, amount, ExistenceRequirement::AllowDeath)?;
+
+			Self::deposit_event(Event::<T>::VestingRevoked { account: who, grantor, amount });
+
+			Ok(Some(T::WeightInfo::revoke_vesting_schedule(
+				MaxLocksOf::<T>::get(),
+				schedules_count,
+			))
+			.into())
+		}
 	}
 }
 
@@ -471,8 +554,7 @@ impl<T: Config> Pallet<T> {
 	// Public function for accessing vesting storage
 	pub fn vesting(
 		account: T::AccountId,
-	) -> Option<BoundedVec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>, MaxVestingSchedulesGet<T>>>
-	{
+	) -> Option<BoundedVec<VestingInfoOf<T>, MaxVestingSchedulesGet<T>>> {
 		Vesting::<T>::get(account)
 	}
 
@@ -480,9 +562,9 @@ impl<T: Config> Pallet<T> {
 	// NOTE: We assume both schedules have had funds unlocked up through the current block.
 	fn merge_vesting_info(
 		now: BlockNumberFor<T>,
-		schedule1: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
-		schedule2: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
-	) -> Option<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>> {
+		schedule1: VestingInfoOf<T>,
+		schedule2: VestingInfoOf<T>,
+	) -> Option<VestingInfoOf<T>> {
 		let schedule1_ending_block = schedule1.ending_block_as_balance::<T::BlockNumberToBalance>();
 		let schedule2_ending_block = schedule2.ending_block_as_balance::<T::BlockNumberToBalance>();
 		let now_as_balance = T::BlockNumberToBalance::convert(now);
@@ -519,7 +601,8 @@ impl<T: Config> Pallet<T> {
 			(locked / duration).max(One::one())
 		};
 
-		let schedule = VestingInfo::new(locked, per_block, starting_block);
+		let schedule = VestingInfo::new(locked, per_block, starting_block)
+			.with_revocable_by(schedule1.revocable_by().cloned());
 		debug_assert!(schedule.is_valid(), "merge_vesting_info schedule validation check failed");
 
 		Some(schedule)
@@ -529,13 +612,17 @@ impl<T: Config> Pallet<T> {
 	fn do_vested_transfer(
 		source: &T::AccountId,
 		target: &T::AccountId,
-		schedule: VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
+		schedule: VestingInfoOf<T>,
 	) -> DispatchResult {
 		// Validate user inputs.
 		ensure!(schedule.locked() >= T::MinVestedTransfer::get(), Error::<T>::AmountLow);
 		if !schedule.is_valid() {
 			return Err(Error::<T>::InvalidScheduleParams.into())
 		};
+		ensure!(
+			schedule.revocable_by().map_or(true, |grantor| grantor == source),
+			Error::<T>::InvalidGrantor
+		);
 
 		// Check we can add to this account prior to any storage writes.
 		Self::can_add_vesting_schedule(
@@ -550,12 +637,7 @@ impl<T: Config> Pallet<T> {
 		// We can't let this fail because the currency transfer has already happened.
 		// Must be successful as it has been checked before.
 		// Better to return error on failure anyway.
-		let res = Self::add_vesting_schedule(
-			target,
-			schedule.locked(),
-			schedule.per_block(),
-			schedule.starting_block(),
-		);
+		let res = Self::do_add_vesting_schedule(target, schedule);
 		debug_assert!(res.is_ok(), "Failed to add a schedule when we had to succeed.");
 
 		Ok(())
@@ -572,9 +654,9 @@ impl<T: Config> Pallet<T> {
 	///
 	/// NOTE: the amount locked does not include any schedules that are filtered out via `action`.
 	fn report_schedule_updates(
-		schedules: Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>,
+		schedules: Vec<VestingInfoOf<T>>,
 		action: VestingAction,
-	) -> (Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>, BalanceOf<T>) {
+	) -> (Vec<VestingInfoOf<T>>, BalanceOf<T>) {
 		let now = T::BlockNumberProvider::current_block_number();
 
 		let mut total_locked_now: BalanceOf<T> = Zero::zero();
@@ -611,12 +693,10 @@ impl<T: Config> Pallet<T> {
 	/// Write an accounts updated vesting schedules to storage.
 	fn write_vesting(
 		who: &T::AccountId,
-		schedules: Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>,
+		schedules: Vec<VestingInfoOf<T>>,
 	) -> Result<(), DispatchError> {
-		let schedules: BoundedVec<
-			VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
-			MaxVestingSchedulesGet<T>,
-		> = schedules.try_into().map_err(|_| Error::<T>::AtMaxVestingSchedules)?;
+		let schedules: BoundedVec<VestingInfoOf<T>, MaxVestingSchedulesGet<T>> =
+			schedules.try_into().map_err(|_| Error::<T>::AtMaxVestingSchedules)?;
 
 		if schedules.len() == 0 {
 			Vesting::<T>::remove(&who);
@@ -627,6 +707,35 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Add a vesting schedule to `who`. Is a no-op if the amount to be vested is zero.
+	fn do_add_vesting_schedule(
+		who: &T::AccountId,
+		vesting_schedule: VestingInfoOf<T>,
+	) -> DispatchResult {
+		if vesting_schedule.locked().is_zero() {
+			return Ok(())
+		}
+
+		// Check for `per_block` or `locked` of 0.
+		if !vesting_schedule.is_valid() {
+			return Err(Error::<T>::InvalidScheduleParams.into())
+		};
+
+		let mut schedules = Vesting::<T>::get(who).unwrap_or_default();
+
+		// NOTE: we must push the new schedule so that `exec_action`
+		// will give the correct new locked amount.
+		ensure!(schedules.try_push(vesting_schedule).is_ok(), Error::<T>::AtMaxVestingSchedules);
+
+		let (schedules, locked_now) =
+			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
+
+		Self::write_vesting(who, schedules)?;
+		Self::write_lock(who, locked_now);
+
+		Ok(())
+	}
+
 	/// Unlock any vested funds of `who`.
 	fn do_vest(who: T::AccountId) -> DispatchResult {
 		let schedules = Vesting::<T>::get(&who).ok_or(Error::<T>::NotVesting)?;
@@ -643,15 +752,21 @@ impl<T: Config> Pallet<T> {
 	/// Execute a `VestingAction` against the given `schedules`. Returns the updated schedules
 	/// and locked amount.
 	fn exec_action(
-		schedules: Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>,
+		schedules: Vec<VestingInfoOf<T>>,
 		action: VestingAction,
-	) -> Result<(Vec<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>>, BalanceOf<T>), DispatchError> {
+	) -> Result<(Vec<VestingInfoOf<T>>, BalanceOf<T>), DispatchError> {
 		let (schedules, locked_now) = match action {
 			VestingAction::Merge { index1: idx1, index2: idx2 } => {
 				// The schedule index is based off of the schedule ordering prior to filtering out
 				// any schedules that may be ending at this block.
-				let schedule1 = *schedules.get(idx1).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
-				let schedule2 = *schedules.get(idx2).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?;
+				let schedule1 =
+					schedules.get(idx1).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?.clone();
+				let schedule2 =
+					schedules.get(idx2).ok_or(Error::<T>::ScheduleIndexOutOfBounds)?.clone();
+				ensure!(
+					schedule1.revocable_by() == schedule2.revocable_by(),
+					Error::<T>::RevocabilityMismatch
+				);
 
 				// The length of `schedules` decreases by 2 here since we filter out 2 schedules.
 				// Thus we know below that we can push the new merged schedule without error
@@ -662,13 +777,13 @@ impl<T: Config> Pallet<T> {
 				let now = T::BlockNumberProvider::current_block_number();
 				if let Some(new_schedule) = Self::merge_vesting_info(now, schedule1, schedule2) {
 					// Merging created a new schedule so we:
-					// 1) need to add it to the accounts vesting schedule collection,
-					schedules.push(new_schedule);
+					// 1) update the locked amount to reflect the schedule we are adding,
 					// (we use `locked_at` in case this is a schedule that started in the past)
 					let new_schedule_locked =
 						new_schedule.locked_at::<T::BlockNumberToBalance>(now);
-					// and 2) update the locked amount to reflect the schedule we just added.
 					locked_now = locked_now.saturating_add(new_schedule_locked);
+					// and 2) need to add it to the accounts vesting schedule collection.
+					schedules.push(new_schedule);
 				} // In the None case there was no new schedule to account for.
 
 				(schedules, locked_now)
@@ -723,29 +838,7 @@ where
 		per_block: BalanceOf<T>,
 		starting_block: BlockNumberFor<T>,
 	) -> DispatchResult {
-		if locked.is_zero() {
-			return Ok(())
-		}
-
-		let vesting_schedule = VestingInfo::new(locked, per_block, starting_block);
-		// Check for `per_block` or `locked` of 0.
-		if !vesting_schedule.is_valid() {
-			return Err(Error::<T>::InvalidScheduleParams.into())
-		};
-
-		let mut schedules = Vesting::<T>::get(who).unwrap_or_default();
-
-		// NOTE: we must push the new schedule so that `exec_action`
-		// will give the correct new locked amount.
-		ensure!(schedules.try_push(vesting_schedule).is_ok(), Error::<T>::AtMaxVestingSchedules);
-
-		let (schedules, locked_now) =
-			Self::exec_action(schedules.to_vec(), VestingAction::Passive)?;
-
-		Self::write_vesting(who, schedules)?;
-		Self::write_lock(who, locked_now);
-
-		Ok(())
+		Self::do_add_vesting_schedule(who, VestingInfo::new(locked, per_block, starting_block))
 	}
 
 	/// Ensure we can call `add_vesting_schedule` without error. This should always
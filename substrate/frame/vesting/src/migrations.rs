@@ -20,6 +20,31 @@
 use super::*;
 use alloc::vec;
 
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// The vesting schedule of an individual account, before schedules could be revocable.
+#[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct OldVestingInfo<Balance, BlockNumber> {
+	/// Locked amount at genesis.
+	pub locked: Balance,
+	/// Amount that gets unlocked every block after `starting_block`.
+	pub per_block: Balance,
+	/// Starting block for unlocking(vesting).
+	pub starting_block: BlockNumber,
+}
+
+impl<Balance, BlockNumber> OldVestingInfo<Balance, BlockNumber>
+where
+	Balance: AtLeast32BitUnsigned + Copy,
+	BlockNumber: AtLeast32BitUnsigned + Copy + Bounded,
+{
+	/// Convert to a [`VestingInfo`] which cannot be revoked.
+	pub fn upgrade<AccountId>(self) -> VestingInfo<Balance, BlockNumber, AccountId> {
+		VestingInfo::new(self.locked, self.per_block, self.starting_block)
+	}
+}
+
 // Migration from single schedule to multiple schedules.
 pub mod v1 {
 	use super::*;
@@ -41,15 +66,11 @@ pub mod v1 {
 	pub fn migrate<T: Config>() -> Weight {
 		let mut reads_writes = 0;
 
-		Vesting::<T>::translate::<VestingInfo<BalanceOf<T>, BlockNumberFor<T>>, _>(
+		Vesting::<T>::translate::<OldVestingInfo<BalanceOf<T>, BlockNumberFor<T>>, _>(
 			|_key, vesting_info| {
 				reads_writes += 1;
-				let v: Option<
-					BoundedVec<
-						VestingInfo<BalanceOf<T>, BlockNumberFor<T>>,
-						MaxVestingSchedulesGet<T>,
-					>,
-				> = vec![vesting_info].try_into().ok();
+				let v: Option<BoundedVec<VestingInfoOf<T>, MaxVestingSchedulesGet<T>>> =
+					vec![vesting_info.upgrade()].try_into().ok();
 
 				if v.is_none() {
 					log::warn!(
@@ -94,3 +115,105 @@ pub mod v1 {
 		Ok(())
 	}
 }
+
+/// The identifier of the multi-block migrations of this pallet.
+pub const PALLET_MIGRATIONS_ID: &[u8; 14] = b"pallet-vesting";
+
+// Migration to schedules which may be revocable by their grantor.
+pub mod v2 {
+	use super::*;
+	use frame_support::{
+		migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+		storage::unhashed,
+		weights::WeightMeter,
+	};
+
+	type Schedules<T> = BoundedVec<VestingInfoOf<T>, MaxVestingSchedulesGet<T>>;
+	type OldSchedules<T> =
+		BoundedVec<OldVestingInfo<BalanceOf<T>, BlockNumberFor<T>>, MaxVestingSchedulesGet<T>>;
+
+	/// Migrates the vesting schedules to the storage layout recording the grantor which can
+	/// revoke them, the schedules of one account per step. None of the existing schedules is
+	/// revocable.
+	///
+	/// Transactions must not change the schedules while the migration runs, which the
+	/// multi-block migration framework ensures.
+	pub struct LazyMigrationV1ToV2<T>(PhantomData<T>);
+
+	impl<T: Config> LazyMigrationV1ToV2<T> {
+		// Migrate the schedules of the account following `maybe_last_key`, returning the account
+		// or `None` once all accounts are migrated.
+		pub(crate) fn account_step(maybe_last_key: Option<&T::AccountId>) -> Option<T::AccountId> {
+			let mut keys = match maybe_last_key {
+				Some(who) => Vesting::<T>::iter_keys_from(Vesting::<T>::hashed_key_for(who)),
+				None => Vesting::<T>::iter_keys(),
+			};
+			let Some(who) = keys.next() else {
+				StorageVersion::<T>::put(Releases::V2);
+				return None;
+			};
+			match unhashed::get::<OldSchedules<T>>(&Vesting::<T>::hashed_key_for(&who)) {
+				Some(schedules) => Vesting::<T>::insert(
+					&who,
+					Schedules::<T>::truncate_from(
+						schedules.into_iter().map(OldVestingInfo::upgrade).collect(),
+					),
+				),
+				None => log::warn!(
+					target: "runtime::vesting",
+					"migration: Failed to decode the vesting schedules of an account",
+				),
+			}
+			Some(who)
+		}
+	}
+
+	impl<T: Config> SteppedMigration for LazyMigrationV1ToV2<T> {
+		type Cursor = T::AccountId;
+		type Identifier = MigrationId<14>;
+
+		fn id() -> Self::Identifier {
+			MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 1, version_to: 2 }
+		}
+
+		fn step(
+			mut cursor: Option<Self::Cursor>,
+			meter: &mut WeightMeter,
+		) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+			if StorageVersion::<T>::get() != Releases::V1 {
+				return Ok(None);
+			}
+
+			let required = T::WeightInfo::migration_v2_step();
+			// If there's not enough weight left in the block for a step, return an error.
+			if meter.remaining().any_lt(required) {
+				return Err(SteppedMigrationError::InsufficientWeight { required });
+			}
+
+			while meter.try_consume(required).is_ok() {
+				cursor = Self::account_step(cursor.as_ref());
+				if cursor.is_none() {
+					break;
+				}
+			}
+			Ok(cursor)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let count = Vesting::<T>::iter_keys().count() as u32;
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let count: u32 = Decode::decode(&mut &state[..]).expect("decoded in pre_upgrade; qed");
+			ensure!(StorageVersion::<T>::get() == Releases::V2, "Storage version not updated");
+			ensure!(
+				Vesting::<T>::iter().count() as u32 == count,
+				"Vesting schedules lost in the migration"
+			);
+			Ok(())
+		}
+	}
+}
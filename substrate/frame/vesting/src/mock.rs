@@ -58,6 +58,7 @@ impl Config for Test {
 	type WeightInfo = ();
 	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
 	type BlockNumberProvider = System;
+	type RevokeOrigin = frame_system::EnsureRoot<u64>;
 }
 
 pub struct ExtBuilder {
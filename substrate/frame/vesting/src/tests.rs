@@ -1049,9 +1049,9 @@ fn multiple_schedules_from_genesis_config_errors() {
 }
 
 #[test]
-fn build_genesis_has_storage_version_v1() {
+fn build_genesis_has_storage_version_v2() {
 	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
-		assert_eq!(StorageVersion::<Test>::get(), Releases::V1);
+		assert_eq!(StorageVersion::<Test>::get(), Releases::V2);
 	});
 }
 
@@ -1079,38 +1079,38 @@ fn merge_vesting_handles_per_block_0() {
 fn vesting_info_validate_works() {
 	let min_transfer = <Test as Config>::MinVestedTransfer::get();
 	// Does not check for min transfer.
-	assert_eq!(VestingInfo::new(min_transfer - 1, 1u64, 10u64).is_valid(), true);
+	assert_eq!(VestingInfo::<_, _, u64>::new(min_transfer - 1, 1u64, 10u64).is_valid(), true);
 
 	// `locked` cannot be 0.
-	assert_eq!(VestingInfo::new(0, 1u64, 10u64).is_valid(), false);
+	assert_eq!(VestingInfo::<_, _, u64>::new(0, 1u64, 10u64).is_valid(), false);
 
 	// `per_block` cannot be 0.
-	assert_eq!(VestingInfo::new(min_transfer + 1, 0u64, 10u64).is_valid(), false);
+	assert_eq!(VestingInfo::<_, _, u64>::new(min_transfer + 1, 0u64, 10u64).is_valid(), false);
 
 	// With valid inputs it does not error.
-	assert_eq!(VestingInfo::new(min_transfer, 1u64, 10u64).is_valid(), true);
+	assert_eq!(VestingInfo::<_, _, u64>::new(min_transfer, 1u64, 10u64).is_valid(), true);
 }
 
 #[test]
 fn vesting_info_ending_block_as_balance_works() {
 	// Treats `per_block` 0 as 1.
-	let per_block_0 = VestingInfo::new(256u32, 0u32, 10u32);
+	let per_block_0 = VestingInfo::<_, _, u64>::new(256u32, 0u32, 10u32);
 	assert_eq!(per_block_0.ending_block_as_balance::<Identity>(), 256 + 10);
 
 	// `per_block >= locked` always results in a schedule ending the block after it starts
-	let per_block_gt_locked = VestingInfo::new(256u32, 256 * 2u32, 10u32);
+	let per_block_gt_locked = VestingInfo::<_, _, u64>::new(256u32, 256 * 2u32, 10u32);
 	assert_eq!(
 		per_block_gt_locked.ending_block_as_balance::<Identity>(),
 		1 + per_block_gt_locked.starting_block()
 	);
-	let per_block_eq_locked = VestingInfo::new(256u32, 256u32, 10u32);
+	let per_block_eq_locked = VestingInfo::<_, _, u64>::new(256u32, 256u32, 10u32);
 	assert_eq!(
 		per_block_gt_locked.ending_block_as_balance::<Identity>(),
 		per_block_eq_locked.ending_block_as_balance::<Identity>()
 	);
 
 	// Correctly calcs end if `locked % per_block != 0`. (We need a block to unlock the remainder).
-	let imperfect_per_block = VestingInfo::new(256u32, 250u32, 10u32);
+	let imperfect_per_block = VestingInfo::<_, _, u64>::new(256u32, 250u32, 10u32);
 	assert_eq!(
 		imperfect_per_block.ending_block_as_balance::<Identity>(),
 		imperfect_per_block.starting_block() + 2u32,
@@ -1124,11 +1124,11 @@ fn vesting_info_ending_block_as_balance_works() {
 
 #[test]
 fn per_block_works() {
-	let per_block_0 = VestingInfo::new(256u32, 0u32, 10u32);
+	let per_block_0 = VestingInfo::<_, _, u64>::new(256u32, 0u32, 10u32);
 	assert_eq!(per_block_0.per_block(), 1u32);
 	assert_eq!(per_block_0.raw_per_block(), 0u32);
 
-	let per_block_1 = VestingInfo::new(256u32, 1u32, 10u32);
+	let per_block_1 = VestingInfo::<_, _, u64>::new(256u32, 1u32, 10u32);
 	assert_eq!(per_block_1.per_block(), 1u32);
 	assert_eq!(per_block_1.raw_per_block(), 1u32);
 }
@@ -1236,3 +1236,130 @@ fn vested_transfer_impl_works() {
 		);
 	});
 }
+
+#[test]
+fn revoke_vesting_schedule_works() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		assert_eq!(Balances::free_balance(&3), ED * 30);
+		assert_eq!(Balances::free_balance(&4), ED * 40);
+		// Vests over 20 blocks, revocable by account 3.
+		let revocable_schedule = VestingInfo::new(ED * 20, ED, 10).with_revocable_by(Some(3));
+
+		// Only the source of the funds can be the grantor of the schedule.
+		assert_noop!(
+			Vesting::vested_transfer(Some(4).into(), 3, revocable_schedule),
+			Error::<Test>::InvalidGrantor
+		);
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, revocable_schedule));
+		assert_eq!(VestingStorage::<Test>::get(&4).unwrap(), vec![revocable_schedule]);
+		assert_eq!(Balances::free_balance(&3), ED * 10);
+		assert_eq!(Balances::free_balance(&4), ED * 60);
+
+		// Only the grantor or `RevokeOrigin` can revoke the schedule.
+		assert_noop!(Vesting::revoke_vesting_schedule(Some(4).into(), 4, 0), BadOrigin);
+		// Schedules which are not revocable cannot be revoked.
+		assert_noop!(
+			Vesting::revoke_vesting_schedule(RawOrigin::Root.into(), 2, 0),
+			Error::<Test>::NotRevocable
+		);
+		assert_noop!(
+			Vesting::revoke_vesting_schedule(Some(3).into(), 4, 1),
+			Error::<Test>::ScheduleIndexOutOfBounds
+		);
+
+		// Half of the schedule is vested.
+		System::set_block_number(20);
+		assert_ok!(Vesting::revoke_vesting_schedule(Some(3).into(), 4, 0));
+		System::assert_last_event(
+			Event::VestingRevoked { account: 4, grantor: 3, amount: ED * 10 }.into(),
+		);
+		// The unvested half is back to the grantor, the vested half is unlocked.
+		assert!(!<VestingStorage<Test>>::contains_key(4));
+		assert_eq!(Balances::free_balance(&3), ED * 20);
+		assert_eq!(Balances::free_balance(&4), ED * 50);
+
+		// `RevokeOrigin` can revoke on behalf of the grantor.
+		let revocable_schedule = VestingInfo::new(ED * 10, ED, 30).with_revocable_by(Some(3));
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, revocable_schedule));
+		assert_ok!(Vesting::revoke_vesting_schedule(RawOrigin::Root.into(), 4, 0));
+		System::assert_last_event(
+			Event::VestingRevoked { account: 4, grantor: 3, amount: ED * 10 }.into(),
+		);
+		assert_eq!(Balances::free_balance(&3), ED * 20);
+		assert_eq!(Balances::free_balance(&4), ED * 50);
+	});
+}
+
+#[test]
+fn merge_schedules_requires_same_grantor() {
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		let revocable_schedule = VestingInfo::new(ED * 5, ED / 4, 10).with_revocable_by(Some(3));
+		let schedule = VestingInfo::new(ED * 5, ED / 4, 10);
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, revocable_schedule));
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, schedule));
+		assert_ok!(Vesting::vested_transfer(Some(3).into(), 4, revocable_schedule));
+
+		// Schedules revocable by different grantors cannot be merged.
+		assert_noop!(
+			Vesting::merge_schedules(Some(4).into(), 0, 1),
+			Error::<Test>::RevocabilityMismatch
+		);
+
+		// The merged schedule is revocable by the grantor of both schedules.
+		assert_ok!(Vesting::merge_schedules(Some(4).into(), 0, 2));
+		let merged_schedule = VestingInfo::new(ED * 10, ED / 2, 10).with_revocable_by(Some(3));
+		assert_eq!(VestingStorage::<Test>::get(&4).unwrap(), vec![schedule, merged_schedule]);
+	});
+}
+
+#[test]
+fn schedules_are_migrated_to_v2_in_steps() {
+	use crate::migrations::{v2::LazyMigrationV1ToV2, OldVestingInfo};
+	use frame_support::{
+		migrations::{SteppedMigration, SteppedMigrationError},
+		storage::unhashed,
+		weights::WeightMeter,
+	};
+
+	ExtBuilder::default().existential_deposit(ED).build().execute_with(|| {
+		StorageVersion::<Test>::put(Releases::V1);
+		let old = OldVestingInfo { locked: ED * 5, per_block: 128, starting_block: 10 };
+		for who in [1u64, 2, 12] {
+			let schedules: BoundedVec<_, MaxVestingSchedulesGet<Test>> =
+				vec![old, old].try_into().unwrap();
+			unhashed::put(&VestingStorage::<Test>::hashed_key_for(who), &schedules);
+		}
+
+		// Not enough weight for a single step.
+		let mut meter = WeightMeter::with_limit(Weight::zero());
+		assert!(matches!(
+			LazyMigrationV1ToV2::<Test>::step(None, &mut meter),
+			Err(SteppedMigrationError::InsufficientWeight { .. })
+		));
+
+		// The schedules of two accounts are migrated per step.
+		let step_weight = <Test as Config>::WeightInfo::migration_v2_step();
+		let mut cursor = None;
+		let mut steps = 0;
+		loop {
+			let mut meter = WeightMeter::with_limit(step_weight.saturating_mul(2));
+			cursor = LazyMigrationV1ToV2::<Test>::step(cursor, &mut meter).unwrap();
+			steps += 1;
+			if cursor.is_none() {
+				break
+			}
+		}
+		assert_eq!(steps, 2);
+		assert_eq!(StorageVersion::<Test>::get(), Releases::V2);
+
+		let upgraded = VestingInfo::new(ED * 5, 128, 10);
+		for who in [1u64, 2, 12] {
+			assert_eq!(VestingStorage::<Test>::get(who).unwrap(), vec![upgraded, upgraded]);
+		}
+
+		// The migration doesn't run again.
+		let mut meter = WeightMeter::new();
+		assert!(matches!(LazyMigrationV1ToV2::<Test>::step(None, &mut meter), Ok(None)));
+		assert_eq!(meter.consumed(), Weight::zero());
+	});
+}
@@ -21,16 +21,18 @@ use super::*;
 
 /// Struct to encode the vesting schedule of an individual account.
 #[derive(Encode, Decode, Copy, Clone, PartialEq, Eq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-pub struct VestingInfo<Balance, BlockNumber> {
+pub struct VestingInfo<Balance, BlockNumber, AccountId> {
 	/// Locked amount at genesis.
 	locked: Balance,
 	/// Amount that gets unlocked every block after `starting_block`.
 	per_block: Balance,
 	/// Starting block for unlocking(vesting).
 	starting_block: BlockNumber,
+	/// The grantor of the schedule, if it can revoke the amount which is not vested yet.
+	revocable_by: Option<AccountId>,
 }
 
-impl<Balance, BlockNumber> VestingInfo<Balance, BlockNumber>
+impl<Balance, BlockNumber, AccountId> VestingInfo<Balance, BlockNumber, AccountId>
 where
 	Balance: AtLeast32BitUnsigned + Copy,
 	BlockNumber: AtLeast32BitUnsigned + Copy + Bounded,
@@ -40,8 +42,14 @@ where
 		locked: Balance,
 		per_block: Balance,
 		starting_block: BlockNumber,
-	) -> VestingInfo<Balance, BlockNumber> {
-		VestingInfo { locked, per_block, starting_block }
+	) -> VestingInfo<Balance, BlockNumber, AccountId> {
+		VestingInfo { locked, per_block, starting_block, revocable_by: None }
+	}
+
+	/// Set the grantor which can revoke the amount of the schedule which is not vested yet.
+	pub fn with_revocable_by(mut self, revocable_by: Option<AccountId>) -> Self {
+		self.revocable_by = revocable_by;
+		self
 	}
 
 	/// Validate parameters for `VestingInfo`. Note that this does not check
@@ -73,6 +81,11 @@ where
 		self.starting_block
 	}
 
+	/// The grantor which can revoke the amount of the schedule which is not vested yet, if any.
+	pub fn revocable_by(&self) -> Option<&AccountId> {
+		self.revocable_by.as_ref()
+	}
+
 	/// Amount locked at block `n`.
 	pub fn locked_at<BlockNumberToBalance: Convert<BlockNumber, Balance>>(
 		&self,
@@ -22,6 +22,12 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `runner-wiukf8gn-project-674-concurrent-0`, CPU: `Intel(R) Xeon(R) CPU @ 2.60GHz`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! NOTE: The following weights were written by hand and have not been measured with the benchmark
+//! CLI yet: `vest_locked`, `vest_unlocked`, `vest_other_locked`, `vest_other_unlocked`,
+//! `vested_transfer`, `force_vested_transfer`, `not_unlocking_merge_schedules`,
+//! `unlocking_merge_schedules`, `force_remove_vesting_schedule`, `revoke_vesting_schedule` and
+//! `migration_v2_step`. Re-run the benchmarks before relying on them.
 
 // Executed Command:
 // ./target/production/substrate-node
@@ -60,13 +66,15 @@ pub trait WeightInfo {
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight;
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight;
 	fn force_remove_vesting_schedule(l: u32, s: u32, ) -> Weight;
+	fn revoke_vesting_schedule(l: u32, s: u32, ) -> Weight;
+	fn migration_v2_step() -> Weight;
 }
 
 /// Weights for `pallet_vesting` using the Substrate node and recommended hardware.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -76,9 +84,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn vest_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `414 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 39_505_000 picoseconds.
-		Weight::from_parts(39_835_306, 4764)
+		Weight::from_parts(39_835_306, 5446)
 			// Standard Error: 1_394
 			.saturating_add(Weight::from_parts(21_450, 0).saturating_mul(l.into()))
 			// Standard Error: 2_481
@@ -87,7 +95,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -97,9 +105,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn vest_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `414 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 40_781_000 picoseconds.
-		Weight::from_parts(40_777_528, 4764)
+		Weight::from_parts(40_777_528, 5446)
 			// Standard Error: 1_209
 			.saturating_add(Weight::from_parts(35_116, 0).saturating_mul(l.into()))
 			// Standard Error: 2_151
@@ -108,7 +116,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -120,9 +128,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn vest_other_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `517 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 41_590_000 picoseconds.
-		Weight::from_parts(40_756_231, 4764)
+		Weight::from_parts(40_756_231, 5446)
 			// Standard Error: 1_420
 			.saturating_add(Weight::from_parts(45_223, 0).saturating_mul(l.into()))
 			// Standard Error: 2_527
@@ -131,7 +139,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -143,9 +151,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `517 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 43_490_000 picoseconds.
-		Weight::from_parts(43_900_384, 4764)
+		Weight::from_parts(43_900_384, 5446)
 			// Standard Error: 1_670
 			.saturating_add(Weight::from_parts(31_084, 0).saturating_mul(l.into()))
 			// Standard Error: 2_971
@@ -154,7 +162,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -166,9 +174,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `588 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 76_194_000 picoseconds.
-		Weight::from_parts(77_923_603, 4764)
+		Weight::from_parts(77_923_603, 5446)
 			// Standard Error: 2_141
 			.saturating_add(Weight::from_parts(50_161, 0).saturating_mul(l.into()))
 			// Standard Error: 3_810
@@ -177,7 +185,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -200,7 +208,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -210,9 +218,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `414 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 40_102_000 picoseconds.
-		Weight::from_parts(39_552_301, 4764)
+		Weight::from_parts(39_552_301, 5446)
 			// Standard Error: 1_309
 			.saturating_add(Weight::from_parts(37_184, 0).saturating_mul(l.into()))
 			// Standard Error: 2_418
@@ -221,7 +229,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -231,9 +239,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `414 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 42_287_000 picoseconds.
-		Weight::from_parts(41_937_484, 4764)
+		Weight::from_parts(41_937_484, 5446)
 			// Standard Error: 1_306
 			.saturating_add(Weight::from_parts(39_880, 0).saturating_mul(l.into()))
 			// Standard Error: 2_412
@@ -242,7 +250,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -254,9 +262,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn force_remove_vesting_schedule(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `588 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 46_462_000 picoseconds.
-		Weight::from_parts(46_571_504, 4764)
+		Weight::from_parts(46_571_504, 5446)
 			// Standard Error: 1_298
 			.saturating_add(Weight::from_parts(42_091, 0).saturating_mul(l.into()))
 			// Standard Error: 2_397
@@ -264,12 +272,46 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn revoke_vesting_schedule(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `690 + l * (25 ±0) + s * (36 ±0)`
+		//  Estimated: `6196`
+		// Minimum execution time: 62_118_000 picoseconds.
+		Weight::from_parts(60_374_215, 6196)
+			// Standard Error: 1_351
+			.saturating_add(Weight::from_parts(45_306, 0).saturating_mul(l.into()))
+			// Standard Error: 2_496
+			.saturating_add(Weight::from_parts(81_904, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Vesting::Vesting` (r:2 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
+	fn migration_v2_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1147`
+		//  Estimated: `9902`
+		// Minimum execution time: 21_304_000 picoseconds.
+		Weight::from_parts(22_015_000, 9902)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -279,9 +321,9 @@ impl WeightInfo for () {
 	fn vest_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `414 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 39_505_000 picoseconds.
-		Weight::from_parts(39_835_306, 4764)
+		Weight::from_parts(39_835_306, 5446)
 			// Standard Error: 1_394
 			.saturating_add(Weight::from_parts(21_450, 0).saturating_mul(l.into()))
 			// Standard Error: 2_481
@@ -290,7 +332,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -300,9 +342,9 @@ impl WeightInfo for () {
 	fn vest_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `414 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 40_781_000 picoseconds.
-		Weight::from_parts(40_777_528, 4764)
+		Weight::from_parts(40_777_528, 5446)
 			// Standard Error: 1_209
 			.saturating_add(Weight::from_parts(35_116, 0).saturating_mul(l.into()))
 			// Standard Error: 2_151
@@ -311,7 +353,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -323,9 +365,9 @@ impl WeightInfo for () {
 	fn vest_other_locked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `517 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 41_590_000 picoseconds.
-		Weight::from_parts(40_756_231, 4764)
+		Weight::from_parts(40_756_231, 5446)
 			// Standard Error: 1_420
 			.saturating_add(Weight::from_parts(45_223, 0).saturating_mul(l.into()))
 			// Standard Error: 2_527
@@ -334,7 +376,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -346,9 +388,9 @@ impl WeightInfo for () {
 	fn vest_other_unlocked(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `517 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 43_490_000 picoseconds.
-		Weight::from_parts(43_900_384, 4764)
+		Weight::from_parts(43_900_384, 5446)
 			// Standard Error: 1_670
 			.saturating_add(Weight::from_parts(31_084, 0).saturating_mul(l.into()))
 			// Standard Error: 2_971
@@ -357,7 +399,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -369,9 +411,9 @@ impl WeightInfo for () {
 	fn vested_transfer(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `588 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 76_194_000 picoseconds.
-		Weight::from_parts(77_923_603, 4764)
+		Weight::from_parts(77_923_603, 5446)
 			// Standard Error: 2_141
 			.saturating_add(Weight::from_parts(50_161, 0).saturating_mul(l.into()))
 			// Standard Error: 3_810
@@ -380,7 +422,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
@@ -403,7 +445,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -413,9 +455,9 @@ impl WeightInfo for () {
 	fn not_unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `414 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 40_102_000 picoseconds.
-		Weight::from_parts(39_552_301, 4764)
+		Weight::from_parts(39_552_301, 5446)
 			// Standard Error: 1_309
 			.saturating_add(Weight::from_parts(37_184, 0).saturating_mul(l.into()))
 			// Standard Error: 2_418
@@ -424,7 +466,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -434,9 +476,9 @@ impl WeightInfo for () {
 	fn unlocking_merge_schedules(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `414 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 42_287_000 picoseconds.
-		Weight::from_parts(41_937_484, 4764)
+		Weight::from_parts(41_937_484, 5446)
 			// Standard Error: 1_306
 			.saturating_add(Weight::from_parts(39_880, 0).saturating_mul(l.into()))
 			// Standard Error: 2_412
@@ -445,7 +487,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Vesting::Vesting` (r:1 w:1)
-	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1057), added: 3532, mode: `MaxEncodedLen`)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:1)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1 w:0)
@@ -457,9 +499,9 @@ impl WeightInfo for () {
 	fn force_remove_vesting_schedule(l: u32, s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `588 + l * (25 ±0) + s * (36 ±0)`
-		//  Estimated: `4764`
+		//  Estimated: `5446`
 		// Minimum execution time: 46_462_000 picoseconds.
-		Weight::from_parts(46_571_504, 4764)
+		Weight::from_parts(46_571_504, 5446)
 			// Standard Error: 1_298
 			.saturating_add(Weight::from_parts(42_091, 0).saturating_mul(l.into()))
 			// Standard Error: 2_397
@@ -467,4 +509,38 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Vesting::Vesting` (r:1 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:1)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:0)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `l` is `[0, 49]`.
+	/// The range of component `s` is `[1, 28]`.
+	fn revoke_vesting_schedule(l: u32, s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `690 + l * (25 ±0) + s * (36 ±0)`
+		//  Estimated: `6196`
+		// Minimum execution time: 62_118_000 picoseconds.
+		Weight::from_parts(60_374_215, 6196)
+			// Standard Error: 1_351
+			.saturating_add(Weight::from_parts(45_306, 0).saturating_mul(l.into()))
+			// Standard Error: 2_496
+			.saturating_add(Weight::from_parts(81_904, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Vesting::Vesting` (r:2 w:1)
+	/// Proof: `Vesting::Vesting` (`max_values`: None, `max_size`: Some(1981), added: 4456, mode: `MaxEncodedLen`)
+	fn migration_v2_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1147`
+		//  Estimated: `9902`
+		// Minimum execution time: 21_304_000 picoseconds.
+		Weight::from_parts(22_015_000, 9902)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -21,7 +21,7 @@
 
 use super::*;
 use frame_benchmarking::v2::*;
-use frame_support::traits::EnsureOrigin;
+use frame_support::traits::{EnsureOrigin, Hooks};
 
 #[cfg(test)]
 use crate::Pallet as Whitelist;
@@ -60,6 +60,57 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn whitelist_call_with_expiry() -> Result<(), BenchmarkError> {
+		let origin =
+			T::WhitelistOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let call_hash = Default::default();
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, call_hash, expiry);
+
+		ensure!(CallExpiry::<T>::get(call_hash) == Some(expiry), "expiry not set");
+		ensure!(T::Preimages::is_requested(&call_hash), "preimage not requested");
+		Ok(())
+	}
+
+	#[benchmark]
+	fn whitelist_calls(n: Linear<1, 100>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::WhitelistOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let call_hashes = (0..n).map(|i| T::Hashing::hash_of(&i)).collect::<Vec<_>>();
+		let expiry = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, call_hashes.clone(), Some(expiry));
+
+		for call_hash in call_hashes {
+			ensure!(WhitelistedCall::<T>::contains_key(call_hash), "call not whitelisted");
+		}
+		Ok(())
+	}
+
+	#[benchmark]
+	fn clean_expired_call() -> Result<(), BenchmarkError> {
+		let origin =
+			T::WhitelistOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let call_hash = Default::default();
+		let expiry = frame_system::Pallet::<T>::block_number();
+		Pallet::<T>::whitelist_call_with_expiry(origin, call_hash, expiry)
+			.expect("whitelisting call must be successful");
+		let now = expiry + 1u32.into();
+
+		#[block]
+		{
+			Pallet::<T>::on_idle(now, Weight::MAX);
+		}
+
+		ensure!(!WhitelistedCall::<T>::contains_key(call_hash), "whitelist not removed");
+		ensure!(!T::Preimages::is_requested(&call_hash), "preimage still requested");
+		Ok(())
+	}
+
 	// We benchmark with the maximum possible size for a call.
 	// If the resulting weight is too big, maybe it worth having a weight which depends
 	// on the size of the call, with a new witness in parameter.
@@ -28,6 +28,11 @@
 //!
 //! In the meantime the call corresponding to the hash must have been submitted to the pre-image
 //! handler [`pallet::Config::Preimages`].
+//!
+//! A call hash may be whitelisted until some block with [`Pallet::whitelist_call_with_expiry`],
+//! or together with others with [`Pallet::whitelist_calls`]. Once expired, the call can no longer
+//! be dispatched and its whitelisting is removed in `on_idle`, which checks as many expiries as
+//! the block has weight left for and continues with the others in the following blocks.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -42,16 +47,21 @@ pub use weights::WeightInfo;
 
 extern crate alloc;
 
-use alloc::boxed::Box;
-use codec::{DecodeLimit, Encode, FullCodec};
+use alloc::{boxed::Box, vec::Vec};
+use codec::{DecodeLimit, Encode, FullCodec, MaxEncodedLen};
 use frame_support::{
 	dispatch::{GetDispatchInfo, PostDispatchInfo},
 	ensure,
-	traits::{QueryPreimage, StorePreimage},
-	weights::Weight,
+	traits::{Get, InspectNestedCalls, IsSubType, QueryPreimage, StorePreimage},
+	weights::{Weight, WeightMeter},
+	StorageHasher, Twox64Concat,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 use scale_info::TypeInfo;
-use sp_runtime::traits::{Dispatchable, Hash};
+use sp_runtime::{
+	traits::{Dispatchable, Hash},
+	DispatchResult,
+};
 
 pub use pallet::*;
 
@@ -97,6 +107,7 @@ pub mod pallet {
 		CallWhitelisted { call_hash: T::Hash },
 		WhitelistedCallRemoved { call_hash: T::Hash },
 		WhitelistedCallDispatched { call_hash: T::Hash, result: DispatchResultWithPostInfo },
+		WhitelistedCallExpired { call_hash: T::Hash },
 	}
 
 	#[pallet::error]
@@ -111,11 +122,71 @@ pub mod pallet {
 		CallIsNotWhitelisted,
 		/// The call was already whitelisted; No-Op.
 		CallAlreadyWhitelisted,
+		/// The whitelisting of the call has expired.
+		CallExpired,
+		/// The expiry block has already passed.
+		ExpiryInPast,
 	}
 
 	#[pallet::storage]
 	pub type WhitelistedCall<T: Config> = StorageMap<_, Twox64Concat, T::Hash, (), OptionQuery>;
 
+	/// The last block in which a whitelisted call may be dispatched, if its whitelisting expires.
+	#[pallet::storage]
+	pub type CallExpiry<T: Config> =
+		StorageMap<_, Twox64Concat, T::Hash, BlockNumberFor<T>, OptionQuery>;
+
+	/// The last call hash whose expiry was checked in `on_idle`, if not all of them have been
+	/// checked yet.
+	#[pallet::storage]
+	pub type ExpiryCursor<T: Config> = StorageValue<_, T::Hash, OptionQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(now: BlockNumberFor<T>, limit: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(limit);
+			if meter.try_consume(T::DbWeight::get().reads_writes(1, 1)).is_err() {
+				return meter.consumed()
+			}
+
+			// continue where the previous block left off.
+			let start = ExpiryCursor::<T>::get();
+			let mut expiries = match start {
+				Some(cursor) => CallExpiry::<T>::iter_from(CallExpiry::<T>::hashed_key_for(cursor)),
+				None => CallExpiry::<T>::iter(),
+			};
+			let mut cursor = start;
+			let mut expired = Vec::new();
+			loop {
+				if meter.try_consume(Self::expiry_read_weight()).is_err() {
+					break
+				}
+				let Some((call_hash, expiry)) = expiries.next() else {
+					// all expiries were checked, start over in the next block.
+					cursor = None;
+					break
+				};
+				if expiry < now {
+					if meter.try_consume(T::WeightInfo::clean_expired_call()).is_err() {
+						break
+					}
+					expired.push(call_hash);
+				}
+				cursor = Some(call_hash);
+			}
+			ExpiryCursor::<T>::set(cursor);
+
+			for call_hash in expired {
+				CallExpiry::<T>::remove(call_hash);
+				WhitelistedCall::<T>::remove(call_hash);
+				T::Preimages::unrequest(&call_hash);
+				Self::deposit_event(Event::<T>::WhitelistedCallExpired { call_hash });
+			}
+
+			meter.consumed()
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		#[pallet::call_index(0)]
@@ -123,17 +194,7 @@ pub mod pallet {
 		pub fn whitelist_call(origin: OriginFor<T>, call_hash: T::Hash) -> DispatchResult {
 			T::WhitelistOrigin::ensure_origin(origin)?;
 
-			ensure!(
-				!WhitelistedCall::<T>::contains_key(call_hash),
-				Error::<T>::CallAlreadyWhitelisted,
-			);
-
-			WhitelistedCall::<T>::insert(call_hash, ());
-			T::Preimages::request(&call_hash);
-
-			Self::deposit_event(Event::<T>::CallWhitelisted { call_hash });
-
-			Ok(())
+			Self::do_whitelist_call(call_hash, None)
 		}
 
 		#[pallet::call_index(1)]
@@ -142,6 +203,7 @@ pub mod pallet {
 			T::WhitelistOrigin::ensure_origin(origin)?;
 
 			WhitelistedCall::<T>::take(call_hash).ok_or(Error::<T>::CallIsNotWhitelisted)?;
+			CallExpiry::<T>::remove(call_hash);
 
 			T::Preimages::unrequest(&call_hash);
 
@@ -163,10 +225,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			T::DispatchWhitelistedOrigin::ensure_origin(origin)?;
 
-			ensure!(
-				WhitelistedCall::<T>::contains_key(call_hash),
-				Error::<T>::CallIsNotWhitelisted,
-			);
+			Self::ensure_whitelisted(call_hash)?;
 
 			let call = T::Preimages::fetch(&call_hash, Some(call_encoded_len))
 				.map_err(|_| Error::<T>::UnavailablePreImage)?;
@@ -205,10 +264,7 @@ pub mod pallet {
 
 			let call_hash = T::Hashing::hash_of(&call).into();
 
-			ensure!(
-				WhitelistedCall::<T>::contains_key(call_hash),
-				Error::<T>::CallIsNotWhitelisted,
-			);
+			Self::ensure_whitelisted(call_hash)?;
 
 			let call_len = call.encoded_size() as u32;
 			let actual_weight = Self::clean_and_dispatch(call_hash, *call).map(|w| {
@@ -217,15 +273,82 @@ pub mod pallet {
 
 			Ok(actual_weight.into())
 		}
+
+		/// Whitelist a call hash until the block `expiry`, after which it can no longer be
+		/// dispatched.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::whitelist_call_with_expiry())]
+		pub fn whitelist_call_with_expiry(
+			origin: OriginFor<T>,
+			call_hash: T::Hash,
+			expiry: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::WhitelistOrigin::ensure_origin(origin)?;
+
+			Self::do_whitelist_call(call_hash, Some(expiry))
+		}
+
+		/// Whitelist several call hashes at once, expiring after the block `expiry` if given.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::whitelist_calls(call_hashes.len() as u32))]
+		pub fn whitelist_calls(
+			origin: OriginFor<T>,
+			call_hashes: Vec<T::Hash>,
+			expiry: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::WhitelistOrigin::ensure_origin(origin)?;
+
+			for call_hash in call_hashes {
+				Self::do_whitelist_call(call_hash, expiry)?;
+			}
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// The weight of reading a single [`CallExpiry`] entry.
+	pub(crate) fn expiry_read_weight() -> Weight {
+		let proof_size = Twox64Concat::max_len::<T::Hash>()
+			.saturating_add(BlockNumberFor::<T>::max_encoded_len());
+		T::DbWeight::get()
+			.reads(1)
+			.saturating_add(Weight::from_parts(0, proof_size as u64))
+	}
+
+	/// Whitelist a call hash, until the block `expiry` if given, and request its preimage.
+	fn do_whitelist_call(call_hash: T::Hash, expiry: Option<BlockNumberFor<T>>) -> DispatchResult {
+		ensure!(!WhitelistedCall::<T>::contains_key(call_hash), Error::<T>::CallAlreadyWhitelisted,);
+
+		if let Some(expiry) = expiry {
+			ensure!(expiry >= frame_system::Pallet::<T>::block_number(), Error::<T>::ExpiryInPast);
+			CallExpiry::<T>::insert(call_hash, expiry);
+		}
+		WhitelistedCall::<T>::insert(call_hash, ());
+		T::Preimages::request(&call_hash);
+
+		Self::deposit_event(Event::<T>::CallWhitelisted { call_hash });
+
+		Ok(())
+	}
+
+	/// Ensure that a call hash is whitelisted and its whitelisting has not expired.
+	fn ensure_whitelisted(call_hash: T::Hash) -> DispatchResult {
+		ensure!(WhitelistedCall::<T>::contains_key(call_hash), Error::<T>::CallIsNotWhitelisted);
+		if let Some(expiry) = CallExpiry::<T>::get(call_hash) {
+			ensure!(expiry >= frame_system::Pallet::<T>::block_number(), Error::<T>::CallExpired);
+		}
+
+		Ok(())
+	}
+
 	/// Clean whitelisting/preimage and dispatch call.
 	///
 	/// Return the call actual weight of the dispatched call if there is some.
 	fn clean_and_dispatch(call_hash: T::Hash, call: <T as Config>::RuntimeCall) -> Option<Weight> {
 		WhitelistedCall::<T>::remove(call_hash);
+		CallExpiry::<T>::remove(call_hash);
 
 		T::Preimages::unrequest(&call_hash);
 
@@ -241,3 +364,17 @@ impl<T: Config> Pallet<T> {
 		call_actual_weight
 	}
 }
+
+impl<T: Config> InspectNestedCalls<<T as Config>::RuntimeCall> for Pallet<T>
+where
+	<T as Config>::RuntimeCall: IsSubType<Call<T>>,
+{
+	/// Only the call given along with its preimage can be inspected, the preimage of a call
+	/// dispatched by hash isn't known before it is dispatched.
+	fn nested_calls(call: &<T as Config>::RuntimeCall) -> Vec<&<T as Config>::RuntimeCall> {
+		match call.is_sub_type() {
+			Some(Call::dispatch_whitelisted_call_with_preimage { call }) => Vec::from([&**call]),
+			_ => Vec::new(),
+		}
+	}
+}
@@ -22,7 +22,7 @@ use codec::Encode;
 use frame_support::{
 	assert_noop, assert_ok,
 	dispatch::GetDispatchInfo,
-	traits::{QueryPreimage, StorePreimage},
+	traits::{Get, Hooks, QueryPreimage, StorePreimage},
 	weights::Weight,
 };
 use sp_runtime::{traits::Hash, DispatchError};
@@ -223,3 +223,134 @@ fn test_whitelist_call_and_execute_decode_consumes_all() {
 		);
 	});
 }
+
+#[test]
+fn test_whitelist_call_with_expiry() {
+	new_test_ext().execute_with(|| {
+		let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		let call_weight = call.get_dispatch_info().call_weight;
+		let encoded_call = call.encode();
+		let call_encoded_len = encoded_call.len() as u32;
+		let call_hash = <Test as frame_system::Config>::Hashing::hash(&encoded_call[..]);
+		assert_ok!(Preimage::note(encoded_call.into()));
+
+		System::set_block_number(3);
+		assert_noop!(
+			Whitelist::whitelist_call_with_expiry(RuntimeOrigin::root(), call_hash, 2),
+			crate::Error::<Test>::ExpiryInPast,
+		);
+		assert_ok!(Whitelist::whitelist_call_with_expiry(RuntimeOrigin::root(), call_hash, 4));
+		assert_eq!(crate::CallExpiry::<Test>::get(call_hash), Some(4));
+
+		System::set_block_number(5);
+		assert_noop!(
+			Whitelist::dispatch_whitelisted_call(
+				RuntimeOrigin::root(),
+				call_hash,
+				call_encoded_len,
+				call_weight
+			),
+			crate::Error::<Test>::CallExpired,
+		);
+
+		// the expired whitelisting is removed once there is weight left in a block.
+		Whitelist::on_idle(5, Weight::zero());
+		assert!(crate::WhitelistedCall::<Test>::contains_key(call_hash));
+		Whitelist::on_idle(5, Weight::MAX);
+		assert!(!crate::WhitelistedCall::<Test>::contains_key(call_hash));
+		assert!(!crate::CallExpiry::<Test>::contains_key(call_hash));
+		assert!(!Preimage::is_requested(&call_hash));
+		System::assert_last_event(crate::Event::WhitelistedCallExpired { call_hash }.into());
+	});
+}
+
+#[test]
+fn test_whitelist_calls() {
+	new_test_ext().execute_with(|| {
+		let call_hashes = (0..3u8)
+			.map(|i| {
+				let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![i] });
+				<Test as frame_system::Config>::Hashing::hash(&call.encode()[..])
+			})
+			.collect::<Vec<_>>();
+
+		assert_noop!(
+			Whitelist::whitelist_calls(RuntimeOrigin::signed(1), call_hashes.clone(), None),
+			DispatchError::BadOrigin,
+		);
+		assert_ok!(Whitelist::whitelist_call(RuntimeOrigin::root(), call_hashes[2]));
+		// the batch is rejected as a whole.
+		assert_noop!(
+			Whitelist::whitelist_calls(RuntimeOrigin::root(), call_hashes.clone(), Some(10)),
+			crate::Error::<Test>::CallAlreadyWhitelisted,
+		);
+
+		assert_ok!(Whitelist::whitelist_calls(
+			RuntimeOrigin::root(),
+			call_hashes[..2].to_vec(),
+			Some(10)
+		));
+		for call_hash in &call_hashes[..2] {
+			assert!(crate::WhitelistedCall::<Test>::contains_key(call_hash));
+			assert_eq!(crate::CallExpiry::<Test>::get(call_hash), Some(10));
+			assert!(Preimage::is_requested(call_hash));
+		}
+		assert_eq!(crate::CallExpiry::<Test>::get(call_hashes[2]), None);
+
+		// removing a whitelisted call also removes its expiry.
+		assert_ok!(Whitelist::remove_whitelisted_call(RuntimeOrigin::root(), call_hashes[0]));
+		assert!(!crate::CallExpiry::<Test>::contains_key(call_hashes[0]));
+
+		// unexpired calls are kept.
+		Whitelist::on_idle(10, Weight::MAX);
+		assert!(crate::WhitelistedCall::<Test>::contains_key(call_hashes[1]));
+	});
+}
+
+#[test]
+fn test_expired_calls_are_removed_over_several_blocks() {
+	new_test_ext().execute_with(|| {
+		let call_hashes = (0..3u8)
+			.map(|i| {
+				let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![i] });
+				<Test as frame_system::Config>::Hashing::hash(&call.encode()[..])
+			})
+			.collect::<Vec<_>>();
+		assert_ok!(Whitelist::whitelist_calls(RuntimeOrigin::root(), call_hashes, Some(5)));
+
+		// each block only has the weight left to remove a single expired call.
+		let limit = <Test as frame_system::Config>::DbWeight::get()
+			.reads_writes(1, 1)
+			.saturating_add(Whitelist::expiry_read_weight())
+			.saturating_add(<() as crate::WeightInfo>::clean_expired_call());
+		for remaining in (0..3).rev() {
+			assert_eq!(Whitelist::on_idle(6, limit), limit);
+			assert_eq!(crate::CallExpiry::<Test>::iter().count(), remaining);
+			assert!(crate::ExpiryCursor::<Test>::get().is_some());
+		}
+
+		// the cursor is reset once all expiries were checked.
+		Whitelist::on_idle(6, limit);
+		assert_eq!(crate::ExpiryCursor::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn calls_dispatched_with_preimage_are_nested_calls() {
+	use frame_support::traits::InspectNestedCalls;
+
+	let call = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+	let with_preimage =
+		RuntimeCall::Whitelist(crate::Call::dispatch_whitelisted_call_with_preimage {
+			call: Box::new(call.clone()),
+		});
+	assert_eq!(Whitelist::nested_calls(&with_preimage), vec![&call]);
+
+	// The preimage of a call dispatched by hash is unknown.
+	let by_hash = RuntimeCall::Whitelist(crate::Call::dispatch_whitelisted_call {
+		call_hash: <Test as frame_system::Config>::Hashing::hash_of(&call),
+		call_encoded_len: call.encoded_size() as u32,
+		call_weight_witness: call.get_dispatch_info().call_weight,
+	});
+	assert!(Whitelist::nested_calls(&by_hash).is_empty());
+}
@@ -75,6 +75,8 @@
 extern crate alloc;
 use alloc::{string::String, vec::Vec};
 
+pub mod validation;
+
 /// The result type alias, used in build methods. `Err` contains formatted error message.
 pub type Result = core::result::Result<(), String>;
 
@@ -105,7 +107,8 @@ sp_api::decl_runtime_apis! {
 		/// is returned.
 		///
 		/// Please note that provided JSON blob must contain all `RuntimeGenesisConfig` fields, no
-		/// defaults will be used.
+		/// defaults will be used. Unknown, missing and mistyped fields are reported with their path,
+		/// see [`validation::validate_config`].
 		fn build_state(json: Vec<u8>) -> Result;
 
 		/// Returns a JSON blob representation of the built-in `RuntimeGenesisConfig` identified by
@@ -129,5 +132,12 @@ sp_api::decl_runtime_apis! {
 		/// The presets from the list can be queried with [`GenesisBuilder::get_preset`] method. If
 		/// no named presets are provided by the runtime the list is empty.
 		fn preset_names() -> Vec<PresetId>;
+
+		/// Returns the paths of all fields of the `RuntimeGenesisConfig` which can be set by a patch.
+		///
+		/// The paths are in the form `pallet.field.nested`, see
+		/// [`validation::patchable_paths`].
+		#[api_version(2)]
+		fn patchable_paths() -> Vec<String>;
 	}
 }
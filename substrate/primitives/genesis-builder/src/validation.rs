@@ -0,0 +1,229 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Validation of JSON genesis configs and patches against the default `RuntimeGenesisConfig`.
+//!
+//! The JSON representation of the default `RuntimeGenesisConfig` describes the layout of the
+//! config: every object lists all fields of the corresponding struct. A config or a patch is
+//! checked against it, so that errors are reported with the path of the offending field instead
+//! of a position in the JSON blob.
+//!
+//! The default config does not list the serde aliases of the fields, which are reported as unknown.
+//! The validation is thus only meant to explain why a config or patch failed to deserialize, not
+//! to reject it up front.
+
+use alloc::{
+	format,
+	string::{String, ToString},
+	vec::Vec,
+};
+use core::fmt;
+use serde_json::{Map, Value};
+
+/// An error found when validating a genesis config or patch.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ConfigError {
+	/// The field at `path` is not part of the config.
+	UnknownField { path: String },
+	/// The field at `path` has a value of the wrong JSON type.
+	TypeMismatch { path: String, expected: &'static str, found: &'static str },
+	/// The field at `path` is required, but missing from the config.
+	MissingField { path: String },
+}
+
+impl fmt::Display for ConfigError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::UnknownField { path } => write!(f, "unknown field `{path}`"),
+			Self::TypeMismatch { path, expected, found } =>
+				write!(f, "invalid type at `{path}`: expected {expected}, found {found}"),
+			Self::MissingField { path } => write!(f, "missing field `{path}`"),
+		}
+	}
+}
+
+/// Check that `patch` only contains fields of `default` and that their types match.
+///
+/// Fields missing from `patch` are taken from `default` when merging, so they are not reported.
+pub fn validate_patch(default: &Value, patch: &Value) -> Result<(), ConfigError> {
+	validate(default, patch, "", false)
+}
+
+/// Check that `config` contains exactly the fields of `default` and that their types match.
+pub fn validate_config(default: &Value, config: &Value) -> Result<(), ConfigError> {
+	validate(default, config, "", true)
+}
+
+/// The paths of all fields which can be set by a patch, in the form `pallet.field.nested`.
+pub fn patchable_paths(default: &Value) -> Vec<String> {
+	let mut paths = Vec::new();
+	if let Value::Object(fields) = default {
+		collect_paths(fields, "", &mut paths);
+	}
+	paths.sort();
+	paths
+}
+
+fn collect_paths(fields: &Map<String, Value>, prefix: &str, paths: &mut Vec<String>) {
+	for (key, value) in fields {
+		let path = join(prefix, key);
+		match value {
+			Value::Object(nested) if !nested.is_empty() => collect_paths(nested, &path, paths),
+			_ => paths.push(path),
+		}
+	}
+}
+
+fn join(prefix: &str, key: &str) -> String {
+	if prefix.is_empty() {
+		key.to_string()
+	} else {
+		format!("{prefix}.{key}")
+	}
+}
+
+fn type_name(value: &Value) -> &'static str {
+	match value {
+		Value::Null => "null",
+		Value::Bool(_) => "boolean",
+		Value::Number(_) => "number",
+		Value::String(_) => "string",
+		Value::Array(_) => "array",
+		Value::Object(_) => "object",
+	}
+}
+
+/// Whether `value` can deserialize into the type which serialized to `default`.
+///
+/// This is only a heuristic, the actual deserialization may still fail:
+/// - `null` is the serialization of `None` and may be replaced by any value, or replace any value.
+/// - Enums serialize unit variants as strings and other variants as objects.
+fn compatible(default: &Value, value: &Value) -> bool {
+	match (default, value) {
+		(Value::Null, _) | (_, Value::Null) => true,
+		(Value::String(_), Value::Object(_)) | (Value::Object(_), Value::String(_)) => true,
+		(default, value) => type_name(default) == type_name(value),
+	}
+}
+
+fn validate(
+	default: &Value,
+	value: &Value,
+	path: &str,
+	require_all: bool,
+) -> Result<(), ConfigError> {
+	if !compatible(default, value) {
+		return Err(ConfigError::TypeMismatch {
+			path: path.to_string(),
+			expected: type_name(default),
+			found: type_name(value),
+		})
+	}
+
+	// Only structs can be checked field by field. Maps and enums with data serialize to objects
+	// with keys which are not known from the default.
+	let (Value::Object(default_fields), Value::Object(fields)) = (default, value) else {
+		return Ok(())
+	};
+	if default_fields.is_empty() {
+		return Ok(())
+	}
+
+	for (key, field) in fields {
+		let field_path = join(path, key);
+		match default_fields.get(key) {
+			Some(default_field) => validate(default_field, field, &field_path, require_all)?,
+			None => return Err(ConfigError::UnknownField { path: field_path }),
+		}
+	}
+	if require_all {
+		if let Some(key) = default_fields.keys().find(|key| !fields.contains_key(*key)) {
+			return Err(ConfigError::MissingField { path: join(path, key) })
+		}
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	fn default() -> Value {
+		json!({
+			"balances": { "balances": [], "devAccounts": null },
+			"babe": { "epochConfig": { "c": [1, 4], "allowed_slots": "PrimaryAndSecondaryVRFSlots" } },
+			"system": {},
+		})
+	}
+
+	#[test]
+	fn patchable_paths_works() {
+		assert_eq!(
+			patchable_paths(&default()),
+			vec![
+				"babe.epochConfig.allowed_slots",
+				"babe.epochConfig.c",
+				"balances.balances",
+				"balances.devAccounts",
+				"system",
+			]
+		);
+	}
+
+	#[test]
+	fn validate_patch_reports_paths() {
+		assert_eq!(
+			validate_patch(&default(), &json!({ "balances": { "balances": [[1, 2]] } })),
+			Ok(())
+		);
+		assert_eq!(
+			validate_patch(&default(), &json!({ "balances": { "dev_accounts": [1, 2] } })),
+			Err(ConfigError::UnknownField { path: "balances.dev_accounts".into() })
+		);
+		assert_eq!(
+			validate_patch(&default(), &json!({ "babe": { "epochConfig": { "c": "1/4" } } })),
+			Err(ConfigError::TypeMismatch {
+				path: "babe.epochConfig.c".into(),
+				expected: "array",
+				found: "string",
+			})
+		);
+		// Optional fields and enum variants with data are accepted.
+		assert_eq!(
+			validate_patch(
+				&default(),
+				&json!({
+					"balances": { "devAccounts": [10, 1000, null] },
+					"babe": { "epochConfig": { "allowed_slots": { "Custom": 1 } } },
+				})
+			),
+			Ok(())
+		);
+	}
+
+	#[test]
+	fn validate_config_requires_all_fields() {
+		assert_eq!(validate_config(&default(), &default()), Ok(()));
+
+		let mut config = default();
+		config["babe"]["epochConfig"].as_object_mut().unwrap().remove("c");
+		let error = validate_config(&default(), &config).unwrap_err();
+		assert_eq!(error, ConfigError::MissingField { path: "babe.epochConfig.c".into() });
+		assert_eq!(error.to_string(), "missing field `babe.epochConfig.c`");
+	}
+}
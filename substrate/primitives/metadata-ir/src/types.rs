@@ -392,6 +392,10 @@ pub struct PalletEventMetadataIR<T: Form = MetaForm> {
 	pub ty: T::Type,
 	/// Deprecation info of the event
 	pub deprecation_info: DeprecationInfoIR<T>,
+	/// The event fields marked as indexed.
+	///
+	/// Not part of the V14 and V15 metadata, which have no place for it.
+	pub indexed_fields: Vec<EventIndexedFieldIR<T>>,
 }
 
 impl IntoPortable for PalletEventMetadataIR {
@@ -401,6 +405,33 @@ impl IntoPortable for PalletEventMetadataIR {
 		PalletEventMetadataIR {
 			ty: registry.register_type(&self.ty),
 			deprecation_info: self.deprecation_info.into_portable(registry),
+			indexed_fields: registry.map_into_portable(self.indexed_fields),
+		}
+	}
+}
+
+/// Metadata about an indexed field of a pallet event.
+///
+/// The value of an indexed field is deposited as an event topic, allowing nodes to look up the
+/// events that refer to it (e.g. all the events of an account).
+#[derive(Clone, PartialEq, Eq, Encode, Debug)]
+pub struct EventIndexedFieldIR<T: Form = MetaForm> {
+	/// Name of the event variant.
+	pub variant: T::String,
+	/// Position of the field within the variant.
+	pub index: u32,
+	/// Name of the field, `None` for tuple variants.
+	pub name: Option<T::String>,
+}
+
+impl IntoPortable for EventIndexedFieldIR {
+	type Output = EventIndexedFieldIR<PortableForm>;
+
+	fn into_portable(self, registry: &mut Registry) -> Self::Output {
+		EventIndexedFieldIR {
+			variant: self.variant.into_portable(registry),
+			index: self.index,
+			name: self.name.map(|name| name.into_portable(registry)),
 		}
 	}
 }
@@ -61,9 +61,9 @@ use std::{
 };
 
 /// Logging target
-const LOG_TARGET: &'static str = "polkadot_sdk_frame::benchmark::pallet";
+pub(super) const LOG_TARGET: &'static str = "polkadot_sdk_frame::benchmark::pallet";
 
-type SubstrateAndExtraHF<T> =
+pub(super) type SubstrateAndExtraHF<T> =
 	(sp_io::SubstrateHostFunctions, frame_benchmarking::benchmarking::HostFunctions, T);
 /// How the PoV size of a storage item should be estimated.
 #[derive(clap::ValueEnum, Debug, Eq, PartialEq, Clone, Copy)]
@@ -266,6 +266,10 @@ impl PalletCmd {
 			return self.output_from_results(&batches)
 		}
 
+		if !self.hooks.is_empty() {
+			return self.run_hooks::<Hasher, ExtraHostFunctions>()
+		}
+
 		let state_handler =
 			self.state_handler_from_cli::<SubstrateAndExtraHF<ExtraHostFunctions>>(chain_spec)?;
 		let genesis_storage =
@@ -652,7 +656,7 @@ impl PalletCmd {
 	}
 
 	/// Execute a state machine and decode its return value as `R`.
-	fn exec_state_machine<R: Decode, H: Hash, Exec: CodeExecutor>(
+	pub(super) fn exec_state_machine<R: Decode, H: Hash, Exec: CodeExecutor>(
 		mut machine: StateMachine<BenchmarkingState<H>, H, Exec>,
 		hint: &str,
 	) -> Result<R> {
@@ -665,7 +669,7 @@ impl PalletCmd {
 	}
 
 	/// Build the extension that are available for pallet benchmarks.
-	fn build_extensions<E: CodeExecutor, H: Hasher + 'static>(
+	pub(super) fn build_extensions<E: CodeExecutor, H: Hasher + 'static>(
 		exe: E,
 		maybe_recorder: Option<Recorder<H>>,
 	) -> Extensions {
@@ -688,7 +692,7 @@ impl PalletCmd {
 	///
 	/// The blob will either be loaded from the `:code` key out of the chain spec, or from a file
 	/// when specified with `--runtime`.
-	fn runtime_blob<'a, H: Hash>(
+	pub(super) fn runtime_blob<'a, H: Hash>(
 		&self,
 		state: &'a BenchmarkingState<H>,
 	) -> Result<FetchedCode<'a, BenchmarkingState<H>, H>> {
@@ -714,13 +718,13 @@ impl PalletCmd {
 	}
 
 	/// Allocation strategy for pallet benchmarking.
-	fn alloc_strategy(&self, runtime_heap_pages: Option<u64>) -> HeapAllocStrategy {
+	pub(super) fn alloc_strategy(&self, runtime_heap_pages: Option<u64>) -> HeapAllocStrategy {
 		self.heap_pages.or(runtime_heap_pages).map_or(DEFAULT_HEAP_ALLOC_STRATEGY, |p| {
 			HeapAllocStrategy::Static { extra_pages: p as _ }
 		})
 	}
 
-	fn output(
+	pub(super) fn output(
 		&self,
 		batches: &[BenchmarkBatchSplitResults],
 		storage_info: &[StorageInfo],
@@ -952,7 +956,11 @@ impl PalletCmd {
 			unreachable!("Clap should not allow both `--runtime` and `--chain` to be provided.")
 		}
 
-		if chain_spec.is_none() && self.runtime.is_none() && self.shared_params.chain.is_none() {
+		if chain_spec.is_none() &&
+			self.runtime.is_none() &&
+			self.shared_params.chain.is_none() &&
+			self.state_snapshot.is_none()
+		{
 			return Err((
 				ErrorKind::MissingRequiredArgument,
 				"Provide either a runtime via `--runtime` or a chain spec via `--chain`"
@@ -0,0 +1,319 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Benchmarking of runtime hooks against a state snapshot.
+
+use super::{
+	command::{PovModesMap, SubstrateAndExtraHF, LOG_TARGET},
+	types::ComponentRangeMap,
+	Hook, PalletCmd,
+};
+use codec::{Decode, Encode};
+use frame_benchmarking::{BenchmarkBatchSplitResults, BenchmarkList, BenchmarkResult};
+use frame_support::{traits::StorageInfo, weights::Weight};
+use remote_externalities::{Builder, Mode, OfflineConfig, RemoteExternalities, SnapshotConfig};
+use sc_cli::{execution_method_from_cli, Result};
+use sc_client_db::BenchmarkingState;
+use sc_executor::WasmExecutor;
+use sp_core::{
+	storage::{well_known_keys, ChildInfo, StateVersion, Storage, StorageChild},
+	traits::{CallContext, CodeExecutor, RuntimeCode},
+};
+use sp_inherents::InherentData;
+use sp_runtime::{
+	generic,
+	traits::{Hash, Header as HeaderT},
+	OpaqueExtrinsic,
+};
+use sp_state_machine::{Backend, IterArgs, OverlayedChanges, StateMachine};
+use sp_wasm_interface::HostFunctions;
+use std::{collections::BTreeMap, path::Path, time::Instant};
+
+/// The pallet under which the hook benchmarks are reported if no `--pallet` is given.
+const DEFAULT_PALLET: &str = "frame_executive";
+
+/// SCALE encoding of `UpgradeCheckSelect::None`, which skips all try-runtime checks.
+const UPGRADE_CHECK_SELECT_NONE: u8 = 0;
+
+type SnapshotHeader<H> = generic::Header<u32, H>;
+type SnapshotBlock<H> = generic::Block<SnapshotHeader<H>, OpaqueExtrinsic>;
+
+impl Hook {
+	/// Name of the benchmark under which the hook is reported.
+	fn benchmark_name(&self) -> &'static str {
+		match self {
+			Hook::OnRuntimeUpgrade => "on_runtime_upgrade",
+			Hook::OnInitialize => "on_initialize",
+			Hook::OnIdle => "on_idle",
+		}
+	}
+}
+
+impl PalletCmd {
+	/// Benchmarks the selected `--hooks` against the `--state-snapshot`.
+	pub(super) fn run_hooks<Hasher, ExtraHostFunctions>(&self) -> Result<()>
+	where
+		Hasher: Hash,
+		ExtraHostFunctions: HostFunctions,
+	{
+		let snapshot_path =
+			self.state_snapshot.as_ref().ok_or("A state snapshot must be provided")?;
+		let (storage, header, state_version) = load_snapshot::<Hasher>(snapshot_path)?;
+
+		let cache_size = Some(self.database_cache_size as usize);
+		let state_with_tracking = BenchmarkingState::<Hasher>::new(
+			storage.clone(),
+			cache_size,
+			// Record proof size
+			true,
+			// Enable storage tracking
+			true,
+		)?;
+		let state_without_tracking = BenchmarkingState::<Hasher>::new(
+			storage,
+			cache_size,
+			// Proof recording depends on CLI settings
+			!self.disable_proof_recording,
+			// Do not enable storage tracking
+			false,
+		)?;
+
+		let method =
+			execution_method_from_cli(self.wasm_method, self.wasmtime_instantiation_strategy);
+		let runtime = self.runtime_blob(&state_without_tracking)?;
+		let runtime_code = runtime.code()?;
+		let alloc_strategy = self.alloc_strategy(runtime_code.heap_pages);
+
+		let executor = WasmExecutor::<SubstrateAndExtraHF<ExtraHostFunctions>>::builder()
+			.with_execution_method(method)
+			.with_allow_missing_host_functions(self.allow_missing_host_functions)
+			.with_onchain_heap_alloc_strategy(alloc_strategy)
+			.with_offchain_heap_alloc_strategy(alloc_strategy)
+			.with_max_runtime_instances(2)
+			.with_runtime_cache_size(2)
+			.build();
+
+		// The storage info is only used to annotate the results, so it is fine if the runtime
+		// was not built with the `runtime-benchmarks` feature.
+		let storage_info = match Self::exec_state_machine::<
+			(Vec<BenchmarkList>, Vec<StorageInfo>),
+			_,
+			_,
+		>(
+			StateMachine::new(
+				&state_without_tracking,
+				&mut Default::default(),
+				&executor,
+				"Benchmark_benchmark_metadata",
+				&self.extra.encode(),
+				&mut Self::build_extensions(executor.clone(), state_without_tracking.recorder()),
+				&runtime_code,
+				CallContext::Offchain,
+			),
+			"get the storage info",
+		) {
+			Ok((_, storage_info)) => storage_info,
+			Err(e) => {
+				log::warn!(target: LOG_TARGET, "Storage info is not available: {e}");
+				Vec::new()
+			},
+		};
+
+		let next_header = SnapshotHeader::<Hasher>::new(
+			header.number + 1,
+			Default::default(),
+			Default::default(),
+			header.hash(),
+			Default::default(),
+		);
+		let pallet = match self.pallet.as_deref() {
+			Some(pallet) if !pallet.is_empty() && pallet != "*" => pallet,
+			_ => DEFAULT_PALLET,
+		};
+
+		let mut batches = Vec::new();
+		for hook in &self.hooks {
+			let benchmark = hook.benchmark_name();
+			log::info!(target: LOG_TARGET, "Starting benchmark: {pallet}::{benchmark}");
+
+			let measure = |state: &BenchmarkingState<Hasher>| {
+				self.measure_hook(
+					*hook,
+					state,
+					&executor,
+					&runtime_code,
+					&next_header,
+					state_version,
+				)
+			};
+
+			let db_result = measure(&state_with_tracking)?;
+			let time_results = (0..self.repeat.max(1))
+				.map(|_| measure(&state_without_tracking))
+				.collect::<Result<Vec<_>>>()?;
+
+			batches.push(BenchmarkBatchSplitResults {
+				pallet: pallet.as_bytes().to_vec(),
+				instance: pallet.as_bytes().to_vec(),
+				benchmark: benchmark.as_bytes().to_vec(),
+				time_results,
+				db_results: vec![db_result],
+			});
+		}
+
+		self.output(&batches, &storage_info, &ComponentRangeMap::new(), PovModesMap::new())
+	}
+
+	/// Executes the `hook` once on top of `state` and measures it.
+	///
+	/// Only the execution of the hook itself is measured, any preparation (like initializing the
+	/// block before `on_idle`) is committed to the state beforehand. The state is reverted to the
+	/// snapshot afterwards.
+	fn measure_hook<H: Hash, Exec: CodeExecutor + Clone + 'static>(
+		&self,
+		hook: Hook,
+		state: &BenchmarkingState<H>,
+		executor: &Exec,
+		runtime_code: &RuntimeCode<'_>,
+		next_header: &SnapshotHeader<H>,
+		state_version: StateVersion,
+	) -> Result<BenchmarkResult> {
+		let call = |changes: &mut OverlayedChanges<H>, method: &str, data: &[u8]| {
+			StateMachine::new(
+				state,
+				changes,
+				executor,
+				method,
+				data,
+				&mut Self::build_extensions(executor.clone(), state.recorder()),
+				runtime_code,
+				CallContext::Onchain,
+			)
+			.execute()
+			.map_err(|e| format!("Could not call runtime API `{method}`: {e}"))
+		};
+		let commit = |changes: &mut OverlayedChanges<H>| -> Result<()> {
+			let changes = changes.drain_storage_changes(state, state_version)?;
+			state.commit(
+				changes.transaction_storage_root,
+				changes.transaction,
+				changes.main_storage_changes,
+				changes.child_storage_changes,
+			)?;
+			Ok(())
+		};
+
+		let mut changes = OverlayedChanges::default();
+		let (method, data) = match hook {
+			Hook::OnRuntimeUpgrade =>
+				("TryRuntime_on_runtime_upgrade", UPGRADE_CHECK_SELECT_NONE.encode()),
+			Hook::OnInitialize => ("Core_initialize_block", next_header.encode()),
+			Hook::OnIdle => {
+				// `on_finalize` hooks usually expect the inherents to be applied, so a block with
+				// the timestamp inherent is built. Chains which require further inherents (like
+				// parachains) can not be benchmarked this way.
+				call(&mut changes, "Core_initialize_block", &next_header.encode())?;
+				let mut inherent_data = InherentData::new();
+				inherent_data
+					.put_data(
+						sp_timestamp::INHERENT_IDENTIFIER,
+						&sp_timestamp::Timestamp::current(),
+					)
+					.map_err(|e| format!("Could not create inherent data: {e}"))?;
+				let inherents = call(
+					&mut changes,
+					"BlockBuilder_inherent_extrinsics",
+					&inherent_data.encode(),
+				)?;
+				let inherents = Vec::<OpaqueExtrinsic>::decode(&mut &inherents[..])
+					.map_err(|e| format!("Could not decode inherents: {e}"))?;
+				for inherent in inherents {
+					call(&mut changes, "BlockBuilder_apply_extrinsic", &inherent.encode())?;
+				}
+				commit(&mut changes)?;
+				("BlockBuilder_finalize_block", Vec::new())
+			},
+		};
+		state.reset_read_write_count();
+
+		let start = Instant::now();
+		let result = call(&mut changes, method, &data);
+		let extrinsic_time = start.elapsed().as_nanos();
+		let result = result.map_err(|e| {
+			format!("{e}. Make sure that the runtime supports benchmarking the `{hook:?}` hook.")
+		})?;
+
+		if hook == Hook::OnRuntimeUpgrade {
+			if let Ok((weight, _)) = <(Weight, Weight)>::decode(&mut &result[..]) {
+				log::debug!(target: LOG_TARGET, "Runtime reported migration weight: {weight:?}");
+			}
+		}
+
+		let start = Instant::now();
+		commit(&mut changes)?;
+		let storage_root_time = start.elapsed().as_nanos();
+
+		let (reads, repeat_reads, writes, repeat_writes) = state.read_write_count();
+		let benchmark_result = BenchmarkResult {
+			components: Vec::new(),
+			extrinsic_time,
+			storage_root_time,
+			reads,
+			repeat_reads,
+			writes,
+			repeat_writes,
+			proof_size: state.proof_size().unwrap_or_default(),
+			keys: state.get_read_and_written_keys(),
+		};
+		state.wipe()?;
+
+		Ok(benchmark_result)
+	}
+}
+
+/// Loads the storage of a state snapshot, together with the header of the snapshot block.
+fn load_snapshot<H: Hash>(path: &Path) -> Result<(Storage, SnapshotHeader<H>, StateVersion)> {
+	log::info!(target: LOG_TARGET, "Loading state snapshot from {path:?}");
+	let RemoteExternalities { mut inner_ext, header } =
+		tokio::runtime::Builder::new_current_thread().build()?.block_on(
+			Builder::<SnapshotBlock<H>>::new()
+				.mode(Mode::Offline(OfflineConfig { state_snapshot: SnapshotConfig::new(path) }))
+				.build(),
+		)?;
+	let state_version = inner_ext.state_version;
+	let backend = inner_ext.as_backend();
+
+	let mut storage = Storage::default();
+	for pair in backend.pairs(Default::default())? {
+		let (key, value) = pair?;
+		if !key.starts_with(well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX) {
+			storage.top.insert(key, value);
+			continue
+		}
+
+		let child_key = &key[well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX.len()..];
+		let child_info = ChildInfo::new_default(child_key);
+		let mut args = IterArgs::default();
+		args.child_info = Some(child_info.clone());
+		let data = backend.pairs(args)?.collect::<std::result::Result<BTreeMap<_, _>, _>>()?;
+		storage
+			.children_default
+			.insert(child_key.to_vec(), StorageChild { data, child_info });
+	}
+
+	Ok((storage, header, state_version))
+}
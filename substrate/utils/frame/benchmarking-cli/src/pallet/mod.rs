@@ -16,6 +16,7 @@
 // limitations under the License.
 
 mod command;
+mod hooks;
 mod types;
 mod writer;
 
@@ -43,15 +44,30 @@ pub enum ListOutput {
 	Pallets,
 }
 
+/// Runtime hooks that can be benchmarked against a state snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+#[clap(rename_all = "kebab-case")]
+pub enum Hook {
+	/// All migrations executed by `on_runtime_upgrade`.
+	///
+	/// Requires the runtime to be built with the `try-runtime` feature.
+	OnRuntimeUpgrade,
+	/// All `on_initialize` hooks, executed as part of `Core::initialize_block`.
+	OnInitialize,
+	/// All `on_idle` and `on_finalize` hooks, executed as part of
+	/// `BlockBuilder::finalize_block`.
+	OnIdle,
+}
+
 /// Benchmark the extrinsic weight of FRAME Pallets.
 #[derive(Debug, clap::Parser)]
 pub struct PalletCmd {
 	/// Select a FRAME Pallet to benchmark, or `*` for all (in which case `extrinsic` must be `*`).
-	#[arg(short, long, value_parser = parse_pallet_name, required_unless_present_any = ["list", "json_input", "all"], default_value_if("all", "true", Some("*".into())))]
+	#[arg(short, long, value_parser = parse_pallet_name, required_unless_present_any = ["list", "json_input", "all", "hooks"], default_value_if("all", "true", Some("*".into())))]
 	pub pallet: Option<String>,
 
 	/// Select an extrinsic inside the pallet to benchmark, or `*` for all.
-	#[arg(short, long, required_unless_present_any = ["list", "json_input", "all"], default_value_if("all", "true", Some("*".into())))]
+	#[arg(short, long, required_unless_present_any = ["list", "json_input", "all", "hooks"], default_value_if("all", "true", Some("*".into())))]
 	pub extrinsic: Option<String>,
 
 	/// Comma separated list of pallets that should be excluded from the benchmark.
@@ -243,6 +259,20 @@ pub struct PalletCmd {
 	#[arg(long)]
 	pub json_input: Option<PathBuf>,
 
+	/// Benchmark the given runtime hooks instead of the pallet benchmarks.
+	///
+	/// The hooks are executed against the state given by `--state-snapshot`, using the runtime
+	/// from `--runtime` or, if not given, the one from the snapshot. The results are reported as
+	/// benchmarks of the pallet given by `--pallet` (`frame_executive` by default), so that the
+	/// weight file has the same format as the one of extrinsic benchmarks.
+	#[arg(long, value_enum, value_delimiter = ',', requires = "state_snapshot")]
+	pub hooks: Vec<Hook>,
+
+	/// Path to a state snapshot, as created by `try-runtime create-snapshot`, to run the `--hooks`
+	/// against.
+	#[arg(long, requires = "hooks", conflicts_with = "chain")]
+	pub state_snapshot: Option<PathBuf>,
+
 	/// Allow overwriting a single file with multiple results.
 	///
 	/// This exists only to restore legacy behaviour. It should never actually be needed.
@@ -80,6 +80,12 @@ struct BenchmarkData {
 	component_writes: Vec<ComponentSlope>,
 	component_calculated_proof_size: Vec<ComponentSlope>,
 	component_recorded_proof_size: Vec<ComponentSlope>,
+	/// The largest proof size recorded in any iteration.
+	#[serde(serialize_with = "string_serialize")]
+	max_recorded_proof_size: u128,
+	/// Whether the proof size recorded in any iteration exceeds the estimated proof size for the
+	/// components of that iteration.
+	proof_size_bound_exceeded: bool,
 	component_ranges: Vec<ComponentRange>,
 	comments: Vec<String>,
 	#[serde(serialize_with = "string_serialize")]
@@ -341,6 +347,24 @@ fn get_benchmark_data(
 	}
 	used_calculated_proof_size.sort_by(|a, b| a.name.cmp(&b.name));
 
+	let max_recorded_proof_size = batch
+		.db_results
+		.iter()
+		.map(|result| result.proof_size as u128)
+		.max()
+		.unwrap_or_default();
+	let proof_size_bound_exceeded = proof_size_bound_exceeded(
+		&batch.db_results,
+		base_calculated_proof_size,
+		&used_calculated_proof_size,
+	);
+	if proof_size_bound_exceeded {
+		println!(
+			"WARNING: The measured proof size of `{pallet}::{benchmark}` exceeds its estimated \
+			proof size. The worst case measured proof size is {max_recorded_proof_size} bytes."
+		);
+	}
+
 	// This puts a marker on any component which is entirely unused in the weight formula.
 	let components = batch.time_results[0]
 		.components
@@ -370,12 +394,46 @@ fn get_benchmark_data(
 		component_writes: used_writes,
 		component_calculated_proof_size: used_calculated_proof_size,
 		component_recorded_proof_size: used_recorded_proof_size,
+		max_recorded_proof_size,
+		proof_size_bound_exceeded,
 		component_ranges,
 		comments,
 		min_execution_time: extrinsic_time.minimum,
 	}
 }
 
+/// Whether the proof size recorded in any of the `results` exceeds the estimated proof size for the
+/// components of that result.
+///
+/// Benchmarks without any estimated proof size, e.g. because all their storage is ignored, never
+/// exceed it.
+fn proof_size_bound_exceeded(
+	results: &[BenchmarkResult],
+	base_calculated_proof_size: u128,
+	component_calculated_proof_size: &[ComponentSlope],
+) -> bool {
+	if base_calculated_proof_size.is_zero() &&
+		component_calculated_proof_size.iter().all(|c| c.slope.is_zero())
+	{
+		return false
+	}
+
+	results.iter().any(|result| {
+		let estimated = result.components.iter().fold(
+			base_calculated_proof_size,
+			|estimated, (name, value)| {
+				let name = name.to_string();
+				let slope = component_calculated_proof_size
+					.iter()
+					.find(|c| c.name == name)
+					.map_or(0, |c| c.slope);
+				estimated.saturating_add(slope.saturating_mul(*value as u128))
+			},
+		);
+		result.proof_size as u128 > estimated
+	})
+}
+
 /// Create weight file from benchmark data and Handlebars template.
 pub(crate) fn write_results(
 	batches: &[BenchmarkBatchSplitResults],
@@ -1246,6 +1304,29 @@ mod test {
 			bounded_pallet_benchmark.component_calculated_proof_size,
 			vec![ComponentSlope { name: "d".into(), slope: 15042, error: 0 }]
 		);
+		// The last iteration recorded the largest proof, which is within the estimation.
+		assert_eq!(bounded_pallet_benchmark.max_recorded_proof_size, 5 * 1024);
+		assert!(!bounded_pallet_benchmark.proof_size_bound_exceeded);
+	}
+
+	#[test]
+	fn proof_size_bound_exceeded_works() {
+		let results = (0..5)
+			.map(|i| BenchmarkResult {
+				components: vec![(BenchmarkParameter::n, i)],
+				proof_size: 1000 + i * 100,
+				..Default::default()
+			})
+			.collect::<Vec<_>>();
+		let slope = |slope| vec![ComponentSlope { name: "n".into(), slope, error: 0 }];
+
+		assert!(!proof_size_bound_exceeded(&results, 1000, &slope(100)));
+		assert!(!proof_size_bound_exceeded(&results, 2000, &[]));
+		// The last iteration exceeds the estimation.
+		assert!(proof_size_bound_exceeded(&results, 1000, &slope(99)));
+		assert!(proof_size_bound_exceeded(&results, 1399, &[]));
+		// Without any estimation, nothing is flagged.
+		assert!(!proof_size_bound_exceeded(&results, 0, &slope(0)));
 	}
 
 	#[test]
@@ -25,8 +25,8 @@ use sp_runtime::traits::BlakeTwo256;
 /// The Polkadot Omni benchmarker allows to benchmark the extrinsics of any Polkadot runtime. It is
 /// meant to replace the current manual integration of the `benchmark pallet` into every parachain
 /// node. This reduces duplicate code and makes maintenance for builders easier. The CLI is
-/// currently only able to benchmark extrinsics. In the future it is planned to extend this to some
-/// other areas.
+/// currently able to benchmark extrinsics, runtime hooks and migrations. In the future it is
+/// planned to extend this to some other areas.
 ///
 /// General FRAME runtimes could also be used with this benchmarker, as long as they don't utilize
 /// any host functions that are not part of the Polkadot host specification.
@@ -76,6 +76,31 @@ use sp_runtime::traits::BlakeTwo256;
 ///
 /// For the exact arguments of the `pallet` command, please refer to the `pallet` sub-module.
 ///
+/// ### Hooks and migrations
+///
+/// Runtime hooks can be benchmarked against a state snapshot, for example one created with
+/// `try-runtime create-snapshot`. To benchmark the migrations, the runtime has to be built with the
+/// `try-runtime` feature:
+///
+/// ```sh
+/// cargo build -p westend-runtime --profile production --features runtime-benchmarks,try-runtime
+/// ```
+///
+/// Then the migrations and the `on_initialize` hooks can be benchmarked with:
+///
+/// ```sh
+/// frame-omni-bencher v1 benchmark pallet \
+/// --runtime target/release/wbuild/westend-runtime/westend-runtime.compact.compressed.wasm \
+/// --state-snapshot westend.snap --hooks on-runtime-upgrade,on-initialize \
+/// --output weights/frame_executive.rs
+/// ```
+///
+/// The resulting weight file has the same format as the one of extrinsic benchmarks. The hooks are
+/// measured for the whole runtime, since they are dispatched by `frame-executive` to all pallets.
+///
+/// The `on-idle` hook is measured by finalizing a block which only contains the timestamp inherent.
+/// Chains which require further inherents, like relay chains and parachains, are not supported.
+///
 /// ## Backwards Compatibility
 ///
 /// The exposed pallet sub-command is identical as the node-integrated CLI. The only difference is
@@ -219,6 +219,25 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl cumulus_primitives_core::StorageWeightReclaimApi<Block> for Runtime {
+		fn reclaimed_proof_size() -> Option<u64> {
+			ParachainSystem::reclaimed_proof_size()
+		}
+	}
+
+	impl cumulus_primitives_core::RelayStateKeysApi<Block> for Runtime {
+		fn relay_state_keys() -> Vec<Vec<u8>> {
+			<ParachainSystem as cumulus_pallet_parachain_system::RelayStateKeys>::relay_state_keys()
+		}
+	}
+
+	impl cumulus_primitives_core::RequiredInherentsApi<Block> for Runtime {
+		fn required_inherents() -> Vec<[u8; 8]> {
+			// Add the identifiers of any inherent which is not provided by the node by default.
+			Vec::new()
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
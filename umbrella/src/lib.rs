@@ -100,6 +100,10 @@ pub use cumulus_client_consensus_proposer;
 #[cfg(feature = "cumulus-client-consensus-relay-chain")]
 pub use cumulus_client_consensus_relay_chain;
 
+/// Diagnostics explaining why parachain blocks were not backed or included.
+#[cfg(feature = "cumulus-client-diagnostics")]
+pub use cumulus_client_diagnostics;
+
 /// Cumulus-specific networking protocol.
 #[cfg(feature = "cumulus-client-network")]
 pub use cumulus_client_network;
@@ -121,6 +125,10 @@ pub use cumulus_client_service;
 #[cfg(feature = "cumulus-pallet-aura-ext")]
 pub use cumulus_pallet_aura_ext;
 
+/// Pallet to automatically renew the coretime of a parachain from its sovereign account.
+#[cfg(feature = "cumulus-pallet-coretime-renewal")]
+pub use cumulus_pallet_coretime_renewal;
+
 /// Migrates messages from the old DMP queue pallet.
 #[cfg(feature = "cumulus-pallet-dmp-queue")]
 pub use cumulus_pallet_dmp_queue;
@@ -463,6 +471,10 @@ pub use pallet_grandpa;
 #[cfg(feature = "pallet-identity")]
 pub use pallet_identity;
 
+/// Runtime API for the FRAME identity pallet.
+#[cfg(feature = "pallet-identity-runtime-api")]
+pub use pallet_identity_runtime_api;
+
 /// FRAME's I'm online pallet.
 #[cfg(feature = "pallet-im-online")]
 pub use pallet_im_online;
@@ -705,6 +717,10 @@ pub use pallet_tx_pause;
 #[cfg(feature = "pallet-uniques")]
 pub use pallet_uniques;
 
+/// Runtime API for the FRAME uniques pallet.
+#[cfg(feature = "pallet-uniques-runtime-api")]
+pub use pallet_uniques_runtime_api;
+
 /// FRAME utilities pallet.
 #[cfg(feature = "pallet-utility")]
 pub use pallet_utility;